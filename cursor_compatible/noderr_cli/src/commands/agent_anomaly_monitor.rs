@@ -130,7 +130,7 @@ pub struct ReportArgs {
     pub detailed: bool,
     
     /// Output file path
-    #[arg(long)]
+    #[arg(long = "output-file")]
     pub output: Option<String>,
 }
 
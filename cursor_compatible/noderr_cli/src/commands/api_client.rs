@@ -0,0 +1,112 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+/// Connection options for commands that talk to a running node over the API
+#[derive(Debug, Args, Clone)]
+pub struct ApiOptions {
+    /// Base URL of the node API
+    #[arg(long, env = "NODERR_API_URL", default_value = "http://127.0.0.1:8080")]
+    pub api_url: String,
+
+    /// Bearer token used to authenticate against the API
+    #[arg(long, env = "NODERR_API_TOKEN", hide_env_values = true)]
+    pub api_token: Option<String>,
+
+    /// PEM client certificate for APIs that require mutual TLS
+    #[arg(long, env = "NODERR_API_CLIENT_CERT", requires = "client_key")]
+    pub client_cert: Option<PathBuf>,
+
+    /// PEM (PKCS#8) private key for the client certificate
+    #[arg(long, env = "NODERR_API_CLIENT_KEY", requires = "client_cert")]
+    pub client_key: Option<PathBuf>,
+
+    /// PEM CA certificate to trust in addition to the system roots
+    #[arg(long, env = "NODERR_API_CA_CERT")]
+    pub ca_cert: Option<PathBuf>,
+}
+
+/// Minimal JSON client for the node API
+pub struct ApiClient {
+    base_url: String,
+    token: Option<String>,
+    http: reqwest::Client,
+}
+
+impl ApiClient {
+    pub fn new(options: &ApiOptions) -> Result<Self> {
+        let mut builder = reqwest::Client::builder();
+
+        if let (Some(cert_path), Some(key_path)) = (&options.client_cert, &options.client_key) {
+            let cert = std::fs::read(cert_path)
+                .with_context(|| format!("Failed to read client certificate {}", cert_path.display()))?;
+            let key = std::fs::read(key_path)
+                .with_context(|| format!("Failed to read client key {}", key_path.display()))?;
+            let identity = reqwest::Identity::from_pkcs8_pem(&cert, &key)
+                .context("Invalid client certificate or key")?;
+            builder = builder.identity(identity);
+        }
+        if let Some(ca_path) = &options.ca_cert {
+            let ca = std::fs::read(ca_path)
+                .with_context(|| format!("Failed to read CA certificate {}", ca_path.display()))?;
+            let ca = reqwest::Certificate::from_pem(&ca).context("Invalid CA certificate")?;
+            builder = builder.add_root_certificate(ca);
+        }
+
+        Ok(Self {
+            base_url: options.api_url.trim_end_matches('/').to_string(),
+            token: options.api_token.clone(),
+            http: builder.build().context("Failed to build API client")?,
+        })
+    }
+
+    /// GET `path` with optional query parameters
+    pub async fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, String)]) -> Result<T> {
+        let request = self.http.get(self.url(path)).query(query);
+        self.send(request).await
+    }
+
+    /// POST a JSON body to `path`
+    pub async fn post<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T> {
+        let request = self.http.post(self.url(path)).json(body);
+        self.send(request).await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+
+    async fn send<T: DeserializeOwned>(&self, mut request: reqwest::RequestBuilder) -> Result<T> {
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await
+            .with_context(|| format!("Failed to reach API at {}", self.base_url))?;
+        let status = response.status();
+
+        if !status.is_success() {
+            // Error bodies are {"error": "..."}
+            let body: serde_json::Value = response.json().await.unwrap_or_default();
+            let message = body.get("error").and_then(|e| e.as_str()).unwrap_or("no error message");
+            bail!("API request failed ({}): {}", status, message);
+        }
+
+        response.json().await.context("Invalid API response")
+    }
+}
+
+/// Ask a yes/no question on stdin; anything but "y"/"yes" is treated as no.
+/// The prompt goes to stderr so it never mixes with JSON output.
+pub fn confirm(prompt: &str) -> Result<bool> {
+    eprint!("{} [y/N] ", prompt);
+    io::stderr().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
@@ -112,7 +112,7 @@ pub struct ExportArgs {
     pub jurisdiction: Option<String>,
     
     /// Output file path
-    #[arg(short, long)]
+    #[arg(short, long = "output-file")]
     pub output: PathBuf,
     
     /// Filter by event type
@@ -243,7 +243,7 @@ pub struct ExplorerArgs {
     pub period: String,
     
     /// Output format (table, json)
-    #[arg(short, long, default_value = "table")]
+    #[arg(short, long = "format", default_value = "table")]
     pub output: String,
 }
 
@@ -262,7 +262,7 @@ pub struct ComplianceArgs {
     pub config: Option<PathBuf>,
     
     /// Output file for reports
-    #[arg(short, long)]
+    #[arg(short, long = "output-file")]
     pub output: Option<PathBuf>,
     
     /// Time period for report (day, week, month, quarter, year)
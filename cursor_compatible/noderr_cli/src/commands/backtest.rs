@@ -0,0 +1,440 @@
+use anyhow::{anyhow, bail, Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use clap::Args;
+use colored::Colorize;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::record::Field;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use noderr_core::backtest::{BacktestBar, BacktestConfig, BacktestEngine, BacktestMetrics, BacktestReport};
+use noderr_core::market::MarketData;
+use noderr_core::strategy::{RiskProfile, Signal, SignalAction, Strategy, StrategyError};
+
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct BacktestCommand {
+    /// Backtest configuration file (TOML)
+    #[arg(long, short, required_unless_present = "compare")]
+    pub config: Option<PathBuf>,
+
+    /// Directory the report is written to
+    #[arg(long, short = 'd', default_value = "backtest_report")]
+    pub report_dir: PathBuf,
+
+    /// Compare the metrics of two report directories instead of running a backtest
+    #[arg(long, num_args = 2, value_names = ["BASELINE", "CANDIDATE"], conflicts_with = "config")]
+    pub compare: Option<Vec<PathBuf>>,
+}
+
+/// Top-level backtest.toml layout
+#[derive(Debug, Clone, Deserialize)]
+pub struct BacktestFile {
+    pub data: DataSource,
+    #[serde(default)]
+    pub engine: BacktestConfig,
+    pub strategy: StrategySpec,
+}
+
+/// Historical data file
+#[derive(Debug, Clone, Deserialize)]
+pub struct DataSource {
+    pub path: PathBuf,
+    /// Inferred from the file extension when omitted
+    pub format: Option<DataFormat>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataFormat {
+    Csv,
+    Parquet,
+}
+
+/// Built-in strategies available to backtests
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum StrategySpec {
+    /// Long while the fast SMA is above the slow SMA
+    SmaCrossover { fast: usize, slow: usize },
+    /// Enter on the first bar and hold
+    BuyAndHold,
+}
+
+pub async fn run_backtest_command(cmd: &BacktestCommand, output: OutputFormat) -> Result<()> {
+    if let Some(dirs) = &cmd.compare {
+        return compare_reports(&dirs[0], &dirs[1], output);
+    }
+
+    let config_path = cmd.config.as_ref().ok_or_else(|| anyhow!("--config is required"))?;
+    let raw = fs::read_to_string(config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let file: BacktestFile = toml::from_str(&raw)
+        .with_context(|| format!("Invalid backtest config {}", config_path.display()))?;
+
+    // Data paths are relative to the config file
+    let data_path = match config_path.parent() {
+        Some(dir) if file.data.path.is_relative() => dir.join(&file.data.path),
+        _ => file.data.path.clone(),
+    };
+
+    let bars = load_bars(&data_path, file.data.format)?;
+    if !output.is_json() {
+        println!("{} Loaded {} bars from {}", "→".blue(), bars.len(), data_path.display());
+    }
+
+    let strategy = build_strategy(&file.strategy, &file.engine.timeframe)?;
+    let engine = BacktestEngine::new(file.engine.clone());
+    let report = engine.run(strategy.as_ref(), &bars).await?;
+
+    write_report(&cmd.report_dir, &report)?;
+    if output.is_json() {
+        print_json(&report.metrics)?;
+    } else {
+        print_metrics(&report);
+        println!("{} Report written to {}", "✓".green(), cmd.report_dir.display());
+    }
+
+    Ok(())
+}
+
+/// Load bars, sorted by timestamp
+pub fn load_bars(path: &Path, format: Option<DataFormat>) -> Result<Vec<BacktestBar>> {
+    let format = match format {
+        Some(format) => format,
+        None => match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("csv") => DataFormat::Csv,
+            Some("parquet") | Some("pq") => DataFormat::Parquet,
+            _ => bail!("Cannot infer data format of {}; set data.format", path.display()),
+        },
+    };
+
+    let mut bars = match format {
+        DataFormat::Csv => load_csv(path)?,
+        DataFormat::Parquet => load_parquet(path)?,
+    };
+    bars.sort_by_key(|bar| bar.timestamp);
+
+    Ok(bars)
+}
+
+#[derive(Debug, Deserialize)]
+struct CsvBar {
+    timestamp: String,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+}
+
+fn load_csv(path: &Path) -> Result<Vec<BacktestBar>> {
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+
+    reader
+        .deserialize::<CsvBar>()
+        .enumerate()
+        .map(|(i, row)| {
+            let row = row.with_context(|| format!("Invalid CSV row {}", i + 1))?;
+            Ok(BacktestBar {
+                timestamp: parse_timestamp(&row.timestamp)?,
+                open: row.open,
+                high: row.high,
+                low: row.low,
+                close: row.close,
+                volume: row.volume,
+            })
+        })
+        .collect()
+}
+
+fn load_parquet(path: &Path) -> Result<Vec<BacktestBar>> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let reader = SerializedFileReader::new(file)?;
+
+    let mut bars = Vec::new();
+    for row in reader.get_row_iter(None)? {
+        let row = row?;
+        let mut bar = BacktestBar {
+            timestamp: Utc.timestamp_opt(0, 0).unwrap(),
+            open: 0.0,
+            high: 0.0,
+            low: 0.0,
+            close: 0.0,
+            volume: 0.0,
+        };
+
+        for (name, field) in row.get_column_iter() {
+            match name.as_str() {
+                "timestamp" => bar.timestamp = field_timestamp(field)?,
+                "open" => bar.open = field_f64(field)?,
+                "high" => bar.high = field_f64(field)?,
+                "low" => bar.low = field_f64(field)?,
+                "close" => bar.close = field_f64(field)?,
+                "volume" => bar.volume = field_f64(field)?,
+                _ => {}
+            }
+        }
+        bars.push(bar);
+    }
+
+    Ok(bars)
+}
+
+fn field_f64(field: &Field) -> Result<f64> {
+    match field {
+        Field::Double(v) => Ok(*v),
+        Field::Float(v) => Ok(*v as f64),
+        Field::Int(v) => Ok(*v as f64),
+        Field::Long(v) => Ok(*v as f64),
+        other => bail!("Unsupported numeric column value {:?}", other),
+    }
+}
+
+fn field_timestamp(field: &Field) -> Result<DateTime<Utc>> {
+    match field {
+        Field::TimestampMillis(ms) => millis_to_datetime(*ms),
+        Field::TimestampMicros(us) => millis_to_datetime(*us / 1_000),
+        Field::Long(v) => parse_timestamp(&v.to_string()),
+        Field::Str(s) => parse_timestamp(s),
+        other => bail!("Unsupported timestamp column value {:?}", other),
+    }
+}
+
+/// Parse an RFC 3339 timestamp or a Unix timestamp in seconds or milliseconds
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+
+    let number: i64 = value.trim().parse()
+        .map_err(|_| anyhow!("Unrecognised timestamp '{}'", value))?;
+
+    // Values past 1e11 cannot be seconds (year 5138)
+    if number.abs() > 100_000_000_000 {
+        millis_to_datetime(number)
+    } else {
+        millis_to_datetime(number * 1_000)
+    }
+}
+
+fn millis_to_datetime(ms: i64) -> Result<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(ms).single().ok_or_else(|| anyhow!("Timestamp out of range: {}", ms))
+}
+
+fn build_strategy(spec: &StrategySpec, timeframe: &str) -> Result<Box<dyn Strategy>> {
+    Ok(match spec {
+        StrategySpec::SmaCrossover { fast, slow } => {
+            if *fast == 0 || fast >= slow {
+                bail!("sma_crossover requires 0 < fast < slow");
+            }
+            Box::new(SmaCrossover { fast: *fast, slow: *slow, timeframe: timeframe.to_string() })
+        }
+        StrategySpec::BuyAndHold => Box::new(BuyAndHold),
+    })
+}
+
+struct SmaCrossover {
+    fast: usize,
+    slow: usize,
+    timeframe: String,
+}
+
+#[async_trait]
+impl Strategy for SmaCrossover {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let closes: Vec<f64> = market_data.candles
+            .get(&self.timeframe)
+            .map(|candles| candles.iter().filter_map(|c| c.close.to_f64()).collect())
+            .unwrap_or_default();
+
+        if closes.len() < self.slow {
+            return Ok(None);
+        }
+
+        let sma = |period: usize| closes[closes.len() - period..].iter().sum::<f64>() / period as f64;
+        let action = if sma(self.fast) > sma(self.slow) { SignalAction::Enter } else { SignalAction::Exit };
+
+        Ok(Some(Signal::new(self.name().to_string(), market_data.symbol.clone(), action)))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfile::default()
+    }
+
+    fn name(&self) -> &str {
+        "sma_crossover"
+    }
+}
+
+struct BuyAndHold;
+
+#[async_trait]
+impl Strategy for BuyAndHold {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        Ok(Some(Signal::new(self.name().to_string(), market_data.symbol.clone(), SignalAction::Enter)))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfile::default()
+    }
+
+    fn name(&self) -> &str {
+        "buy_and_hold"
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MetricsFile {
+    strategy: String,
+    config: BacktestConfig,
+    metrics: BacktestMetrics,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+}
+
+/// Write metrics.json, equity_curve.csv and trades.csv into `dir`
+fn write_report(dir: &Path, report: &BacktestReport) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let metrics = MetricsFile {
+        strategy: report.strategy.clone(),
+        config: report.config.clone(),
+        metrics: report.metrics.clone(),
+        started_at: report.started_at,
+        finished_at: report.finished_at,
+    };
+    fs::write(dir.join("metrics.json"), serde_json::to_string_pretty(&metrics)?)?;
+
+    let mut equity = csv::Writer::from_path(dir.join("equity_curve.csv"))?;
+    for point in &report.equity_curve {
+        equity.serialize(point)?;
+    }
+    equity.flush()?;
+
+    let mut trades = csv::Writer::from_path(dir.join("trades.csv"))?;
+    for trade in &report.trades {
+        trades.serialize(trade)?;
+    }
+    trades.flush()?;
+
+    Ok(())
+}
+
+fn read_metrics(dir: &Path) -> Result<MetricsFile> {
+    let path = dir.join("metrics.json");
+    let raw = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("Invalid metrics file {}", path.display()))
+}
+
+fn print_metrics(report: &BacktestReport) {
+    println!("\n{} {}", "Backtest:".bold(), report.strategy);
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["Metric", "Value"]);
+    for (name, value) in report.metrics.as_pairs() {
+        table.add_row(vec![Cell::new(name), Cell::new(format!("{:.4}", value))]);
+    }
+
+    println!("{}", table);
+}
+
+/// Metrics where a lower value is better
+const LOWER_IS_BETTER: &[&str] = &["max_drawdown_pct", "total_fees"];
+
+fn compare_reports(baseline_dir: &Path, candidate_dir: &Path, output: OutputFormat) -> Result<()> {
+    let baseline = read_metrics(baseline_dir)?;
+    let candidate = read_metrics(candidate_dir)?;
+
+    if output.is_json() {
+        let rows: Vec<serde_json::Value> = baseline.metrics.as_pairs()
+            .into_iter()
+            .zip(candidate.metrics.as_pairs())
+            .map(|((name, base), (_, cand))| serde_json::json!({
+                "metric": name,
+                "baseline": base,
+                "candidate": cand,
+                "delta": cand - base,
+            }))
+            .collect();
+        return print_json(&serde_json::json!({
+            "baseline": { "path": baseline_dir, "strategy": baseline.strategy },
+            "candidate": { "path": candidate_dir, "strategy": candidate.strategy },
+            "metrics": rows,
+        }));
+    }
+
+    println!(
+        "{} {} ({}) vs {} ({})",
+        "Comparing".bold(),
+        baseline_dir.display(), baseline.strategy,
+        candidate_dir.display(), candidate.strategy
+    );
+
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["Metric", "Baseline", "Candidate", "Delta"]);
+
+    for ((name, base), (_, cand)) in baseline.metrics.as_pairs().into_iter().zip(candidate.metrics.as_pairs()) {
+        let delta = cand - base;
+        let improved = if LOWER_IS_BETTER.contains(&name) { delta < 0.0 } else { delta > 0.0 };
+        let color = if delta.abs() < 1e-9 {
+            Color::White
+        } else if improved {
+            Color::Green
+        } else {
+            Color::Red
+        };
+
+        table.add_row(vec![
+            Cell::new(name),
+            Cell::new(format!("{:.4}", base)),
+            Cell::new(format!("{:.4}", cand)),
+            Cell::new(format!("{:+.4}", delta)).fg(color),
+        ]);
+    }
+
+    println!("{}", table);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        let rfc = parse_timestamp("2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(parse_timestamp("1704067200").unwrap(), rfc);
+        assert_eq!(parse_timestamp("1704067200000").unwrap(), rfc);
+        assert!(parse_timestamp("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_parse_backtest_file() {
+        let file: BacktestFile = toml::from_str(r#"
+            [data]
+            path = "btc.csv"
+
+            [engine]
+            symbol = "ETH/USDT"
+            fee_bps = 2.0
+
+            [strategy]
+            name = "sma_crossover"
+            fast = 10
+            slow = 30
+        "#).unwrap();
+
+        assert_eq!(file.engine.symbol, "ETH/USDT");
+        assert_eq!(file.engine.initial_capital, BacktestConfig::default().initial_capital);
+        assert!(matches!(file.strategy, StrategySpec::SmaCrossover { fast: 10, slow: 30 }));
+    }
+}
@@ -53,7 +53,7 @@ pub struct RunArgs {
     pub random_events: bool,
     
     /// Path to save the simulation results
-    #[clap(short, long)]
+    #[clap(short, long = "output-file")]
     pub output: Option<String>,
 }
 
@@ -152,7 +152,7 @@ pub struct ExportArgs {
     pub format: String,
     
     /// Path to save the exported data
-    #[clap(short, long)]
+    #[clap(short, long = "output-file")]
     pub output: Option<String>,
     
     /// Include detailed events in the export
@@ -0,0 +1,502 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Args;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use futures::StreamExt;
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, List, ListItem, Paragraph, Row, Table},
+    Frame, Terminal,
+};
+use serde_json::Value;
+use std::collections::{BTreeMap, VecDeque};
+use std::io;
+use std::time::Duration as StdDuration;
+use tokio::sync::mpsc;
+
+use noderr_core::telemetry_streamer::{TelemetryMessage, TelemetryMessageType};
+use noderr_core::wire_format::WireFormat;
+
+/// Maximum number of regime warnings kept on screen
+const MAX_WARNINGS: usize = 50;
+
+#[derive(Debug, Args, Clone)]
+pub struct DashboardCommand {
+    /// Redis URL the telemetry streamer publishes to
+    #[arg(long, default_value = "redis://127.0.0.1:6379")]
+    pub redis_url: String,
+
+    /// Telemetry key prefix
+    #[arg(long, default_value = "noderr:telemetry")]
+    pub key_prefix: String,
+
+    /// Screen refresh interval in milliseconds
+    #[arg(long, default_value = "250")]
+    pub refresh_ms: u64,
+}
+
+/// Position row
+#[derive(Debug, Clone, Default)]
+pub struct PositionRow {
+    pub agent_id: String,
+    pub symbol: String,
+    pub net_size: f64,
+    pub average_price: f64,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+}
+
+/// Drawdown row
+#[derive(Debug, Clone, Default)]
+pub struct DrawdownRow {
+    pub agent_id: String,
+    pub is_active: bool,
+    pub current_drawdown_pct: f64,
+    pub peak_equity: f64,
+    pub current_equity: f64,
+}
+
+/// Venue health row
+#[derive(Debug, Clone, Default)]
+pub struct VenueRow {
+    pub venue: String,
+    pub status: String,
+    pub score: Option<f64>,
+    pub latency_ms: Option<f64>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+/// Regime warning row
+#[derive(Debug, Clone)]
+pub struct WarningRow {
+    pub symbol: String,
+    pub indicator: String,
+    pub direction: String,
+    pub confidence: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Live dashboard state built from telemetry messages
+#[derive(Debug, Default)]
+pub struct DashboardState {
+    pub positions: BTreeMap<(String, String), PositionRow>,
+    pub drawdowns: BTreeMap<String, DrawdownRow>,
+    pub venues: BTreeMap<String, VenueRow>,
+    pub warnings: VecDeque<WarningRow>,
+    pub messages_received: u64,
+    pub last_update: Option<DateTime<Utc>>,
+    pub connection_error: Option<String>,
+}
+
+fn text(value: &Value, key: &str) -> String {
+    match value.get(key) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn number(value: &Value, key: &str) -> Option<f64> {
+    value.get(key).and_then(Value::as_f64)
+}
+
+impl DashboardState {
+    /// Apply a telemetry message to the state
+    pub fn apply(&mut self, message: TelemetryMessage<Value>) {
+        self.messages_received += 1;
+        self.last_update = Some(message.timestamp);
+
+        let payload = &message.payload;
+        match message.message_type {
+            TelemetryMessageType::PositionUpdate => {
+                let agent_id = text(payload, "agent_id");
+                let symbol = text(payload, "symbol");
+                let net_size = number(payload, "net_size").unwrap_or(0.0);
+
+                if net_size == 0.0 && number(payload, "realized_pnl").unwrap_or(0.0) == 0.0 {
+                    self.positions.remove(&(agent_id, symbol));
+                    return;
+                }
+
+                self.positions.insert((agent_id.clone(), symbol.clone()), PositionRow {
+                    agent_id,
+                    symbol,
+                    net_size,
+                    average_price: number(payload, "average_price").unwrap_or(0.0),
+                    realized_pnl: number(payload, "realized_pnl").unwrap_or(0.0),
+                    unrealized_pnl: number(payload, "unrealized_pnl").unwrap_or(0.0),
+                });
+            }
+            TelemetryMessageType::DrawdownUpdate => {
+                let agent_id = match text(payload, "agent_id") {
+                    id if id.is_empty() => message.strategy_id.clone(),
+                    id => id,
+                };
+
+                self.drawdowns.insert(agent_id.clone(), DrawdownRow {
+                    agent_id,
+                    is_active: payload.get("is_active").and_then(Value::as_bool).unwrap_or(true),
+                    current_drawdown_pct: number(payload, "current_drawdown_pct")
+                        .or_else(|| number(payload, "drawdown_pct"))
+                        .unwrap_or(0.0),
+                    peak_equity: number(payload, "peak_equity").unwrap_or(0.0),
+                    current_equity: number(payload, "current_equity").unwrap_or(0.0),
+                });
+            }
+            TelemetryMessageType::VenueHealth => {
+                let venue = match text(payload, "venue_id") {
+                    id if id.is_empty() => message.strategy_id.clone(),
+                    id => id,
+                };
+
+                self.venues.insert(venue.clone(), VenueRow {
+                    venue,
+                    status: text(payload, "status"),
+                    score: number(payload, "score"),
+                    latency_ms: number(payload, "latency_ms").or_else(|| number(payload, "estimated_latency_ms")),
+                    updated: Some(message.timestamp),
+                });
+            }
+            TelemetryMessageType::RegimeWarning => {
+                self.warnings.push_front(WarningRow {
+                    symbol: text(payload, "symbol"),
+                    indicator: text(payload, "indicator").trim_matches('"').to_string(),
+                    direction: text(payload, "direction").trim_matches('"').to_string(),
+                    confidence: number(payload, "confidence").unwrap_or(0.0),
+                    timestamp: message.timestamp,
+                });
+                self.warnings.truncate(MAX_WARNINGS);
+            }
+            _ => {}
+        }
+    }
+
+    /// Total realized and unrealized PnL across all positions
+    pub fn total_pnl(&self) -> (f64, f64) {
+        self.positions.values().fold((0.0, 0.0), |(realized, unrealized), p| {
+            (realized + p.realized_pnl, unrealized + p.unrealized_pnl)
+        })
+    }
+}
+
+/// Messages forwarded from the subscriber task to the UI loop
+enum DashboardEvent {
+    Telemetry(TelemetryMessage<Value>),
+    Error(String),
+}
+
+pub async fn run_dashboard_command(cmd: &DashboardCommand) -> Result<()> {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let subscriber = tokio::spawn(subscribe_telemetry(
+        cmd.redis_url.clone(),
+        format!("{}:channel:*", cmd.key_prefix),
+        tx,
+    ));
+
+    enable_raw_mode().context("Failed to enable raw terminal mode")?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).context("Failed to enter alternate screen")?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let result = run_ui_loop(&mut terminal, &mut rx, StdDuration::from_millis(cmd.refresh_ms.max(50)));
+
+    // Always restore the terminal, even if the UI loop failed
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    subscriber.abort();
+
+    result
+}
+
+fn run_ui_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    rx: &mut mpsc::UnboundedReceiver<DashboardEvent>,
+    refresh: StdDuration,
+) -> Result<()> {
+    let mut state = DashboardState::default();
+
+    loop {
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                DashboardEvent::Telemetry(message) => {
+                    state.connection_error = None;
+                    state.apply(message);
+                }
+                DashboardEvent::Error(e) => state.connection_error = Some(e),
+            }
+        }
+
+        terminal.draw(|frame| draw(frame, &state))?;
+
+        if event::poll(refresh)? {
+            if let Event::Key(key) = event::read()? {
+                let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if ctrl_c || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Subscribe to every telemetry channel and forward parsed messages
+async fn subscribe_telemetry(redis_url: String, pattern: String, tx: mpsc::UnboundedSender<DashboardEvent>) {
+    loop {
+        match stream_telemetry(&redis_url, &pattern, &tx).await {
+            Ok(()) => return,
+            Err(e) => {
+                if tx.send(DashboardEvent::Error(e.to_string())).is_err() {
+                    return;
+                }
+                tokio::time::sleep(StdDuration::from_secs(2)).await;
+            }
+        }
+    }
+}
+
+async fn stream_telemetry(
+    redis_url: &str,
+    pattern: &str,
+    tx: &mpsc::UnboundedSender<DashboardEvent>,
+) -> Result<()> {
+    let client = redis::Client::open(redis_url).context("Invalid Redis URL")?;
+    let mut pubsub = client.get_async_connection().await?.into_pubsub();
+    pubsub.psubscribe(pattern).await?;
+
+    let mut messages = pubsub.on_message();
+    while let Some(msg) = messages.next().await {
+        let payload: Vec<u8> = match msg.get_payload() {
+            Ok(payload) => payload,
+            Err(_) => continue,
+        };
+
+        if let Some(message) = decode_telemetry(&payload) {
+            if tx.send(DashboardEvent::Telemetry(message)).is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    Err(anyhow::anyhow!("Telemetry subscription closed"))
+}
+
+/// Decode a telemetry payload; publishers may use JSON or tagged MessagePack
+fn decode_telemetry(payload: &[u8]) -> Option<TelemetryMessage<Value>> {
+    WireFormat::decode_tagged(payload).ok()
+}
+
+fn draw(frame: &mut Frame, state: &DashboardState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(frame.size());
+
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(rows[1]);
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[2]);
+
+    draw_header(frame, rows[0], state);
+    draw_positions(frame, top[0], state);
+    draw_drawdowns(frame, top[1], state);
+    draw_venues(frame, bottom[0], state);
+    draw_warnings(frame, bottom[1], state);
+}
+
+fn pnl_style(value: f64) -> Style {
+    if value < 0.0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::Green)
+    }
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, state: &DashboardState) {
+    let (realized, unrealized) = state.total_pnl();
+
+    let status = match &state.connection_error {
+        Some(e) => Span::styled(format!("disconnected: {}", e), Style::default().fg(Color::Red)),
+        None => Span::styled("connected", Style::default().fg(Color::Green)),
+    };
+
+    let line = Line::from(vec![
+        Span::styled("Noderr ", Style::default().add_modifier(Modifier::BOLD)),
+        status,
+        Span::raw("  |  Realized "),
+        Span::styled(format!("{:.2}", realized), pnl_style(realized)),
+        Span::raw("  Unrealized "),
+        Span::styled(format!("{:.2}", unrealized), pnl_style(unrealized)),
+        Span::raw(format!(
+            "  |  {} msgs  last {}  |  q to quit",
+            state.messages_received,
+            state.last_update.map(|t| t.format("%H:%M:%S").to_string()).unwrap_or_else(|| "-".to_string()),
+        )),
+    ]);
+
+    frame.render_widget(Paragraph::new(line).block(Block::default().borders(Borders::ALL)), area);
+}
+
+fn draw_positions(frame: &mut Frame, area: Rect, state: &DashboardState) {
+    let rows = state.positions.values().map(|p| {
+        Row::new(vec![
+            Cell::from(p.agent_id.clone()),
+            Cell::from(p.symbol.clone()),
+            Cell::from(format!("{:.4}", p.net_size)),
+            Cell::from(format!("{:.2}", p.average_price)),
+            Cell::from(format!("{:.2}", p.realized_pnl)).style(pnl_style(p.realized_pnl)),
+            Cell::from(format!("{:.2}", p.unrealized_pnl)).style(pnl_style(p.unrealized_pnl)),
+        ])
+    });
+
+    let table = Table::new(rows)
+        .header(Row::new(vec!["Agent", "Symbol", "Size", "Avg Px", "Realized", "Unrealized"])
+            .style(Style::default().add_modifier(Modifier::BOLD)))
+        .block(Block::default().title("Positions").borders(Borders::ALL))
+        .widths(&[
+            Constraint::Percentage(20),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+        ]);
+
+    frame.render_widget(table, area);
+}
+
+fn draw_drawdowns(frame: &mut Frame, area: Rect, state: &DashboardState) {
+    let rows = state.drawdowns.values().map(|d| {
+        let status = if d.is_active {
+            Cell::from("active").style(Style::default().fg(Color::Green))
+        } else {
+            Cell::from("cooldown").style(Style::default().fg(Color::Red))
+        };
+
+        Row::new(vec![
+            Cell::from(d.agent_id.clone()),
+            Cell::from(format!("{:.2}%", d.current_drawdown_pct * 100.0)),
+            Cell::from(format!("{:.2}", d.current_equity)),
+            status,
+        ])
+    });
+
+    let table = Table::new(rows)
+        .header(Row::new(vec!["Agent", "Drawdown", "Equity", "State"])
+            .style(Style::default().add_modifier(Modifier::BOLD)))
+        .block(Block::default().title("Drawdown").borders(Borders::ALL))
+        .widths(&[
+            Constraint::Percentage(35),
+            Constraint::Percentage(20),
+            Constraint::Percentage(25),
+            Constraint::Percentage(20),
+        ]);
+
+    frame.render_widget(table, area);
+}
+
+fn draw_venues(frame: &mut Frame, area: Rect, state: &DashboardState) {
+    let rows = state.venues.values().map(|v| {
+        let color = match v.status.as_str() {
+            "healthy" | "enabled" => Color::Green,
+            "degraded" | "draining" => Color::Yellow,
+            _ => Color::Red,
+        };
+
+        Row::new(vec![
+            Cell::from(v.venue.clone()),
+            Cell::from(v.status.clone()).style(Style::default().fg(color)),
+            Cell::from(v.score.map(|s| format!("{:.2}", s)).unwrap_or_else(|| "-".to_string())),
+            Cell::from(v.latency_ms.map(|l| format!("{:.1}ms", l)).unwrap_or_else(|| "-".to_string())),
+        ])
+    });
+
+    let table = Table::new(rows)
+        .header(Row::new(vec!["Venue", "Status", "Score", "Latency"])
+            .style(Style::default().add_modifier(Modifier::BOLD)))
+        .block(Block::default().title("Venue Health").borders(Borders::ALL))
+        .widths(&[
+            Constraint::Percentage(30),
+            Constraint::Percentage(25),
+            Constraint::Percentage(20),
+            Constraint::Percentage(25),
+        ]);
+
+    frame.render_widget(table, area);
+}
+
+fn draw_warnings(frame: &mut Frame, area: Rect, state: &DashboardState) {
+    let items: Vec<ListItem> = state.warnings.iter().map(|w| {
+        ListItem::new(Line::from(vec![
+            Span::styled(w.timestamp.format("%H:%M:%S ").to_string(), Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{:<10}", w.symbol), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" {} {} ({:.0}%)", w.indicator, w.direction, w.confidence * 100.0)),
+        ]))
+    }).collect();
+
+    let list = List::new(items)
+        .block(Block::default().title("Regime Warnings").borders(Borders::ALL));
+
+    frame.render_widget(list, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn message(message_type: TelemetryMessageType, source: &str, payload: Value) -> TelemetryMessage<Value> {
+        TelemetryMessage {
+            message_type,
+            strategy_id: source.to_string(),
+            timestamp: Utc::now(),
+            schema_version: 1,
+            payload,
+        }
+    }
+
+    #[test]
+    fn test_state_applies_updates() {
+        let mut state = DashboardState::default();
+
+        state.apply(message(TelemetryMessageType::PositionUpdate, "agent-1", json!({
+            "agent_id": "agent-1",
+            "symbol": "BTC-USD",
+            "net_size": 1.5,
+            "average_price": 50000.0,
+            "realized_pnl": 10.0,
+            "unrealized_pnl": -4.0,
+        })));
+        state.apply(message(TelemetryMessageType::VenueHealth, "binance", json!({ "status": "degraded" })));
+
+        assert_eq!(state.positions.len(), 1);
+        assert_eq!(state.total_pnl(), (10.0, -4.0));
+        assert_eq!(state.venues["binance"].status, "degraded");
+        assert_eq!(state.messages_received, 2);
+    }
+
+    #[test]
+    fn test_decode_telemetry_formats() {
+        let sent = message(TelemetryMessageType::VenueHealth, "binance", json!({ "status": "up" }));
+
+        for format in [WireFormat::Json, WireFormat::MessagePack] {
+            let payload = format.encode_tagged(&sent).unwrap();
+            let received = decode_telemetry(&payload).unwrap();
+            assert_eq!(received.strategy_id, "binance");
+            assert_eq!(received.payload, json!({ "status": "up" }));
+        }
+        assert!(decode_telemetry(b"not telemetry").is_none());
+    }
+}
@@ -7,6 +7,8 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use anyhow::Result;
 use std::collections::HashMap;
 
+use crate::federation_sync_engine::{FederationSyncEngine, SyncRecordKind};
+
 #[derive(Debug, Args)]
 pub struct FederationCommand {
     #[command(subcommand)]
@@ -243,11 +245,11 @@ impl FederationLinkPacket {
     }
 }
 
-pub async fn run_federation_command(cmd: &FederationCommand) -> Result<()> {
+pub async fn run_federation_command(cmd: &FederationCommand, sync_engine: &FederationSyncEngine) -> Result<()> {
     match &cmd.subcommand {
         FederationSubcommand::Link(args) => link_cluster(args).await,
         FederationSubcommand::Sync(args) => sync_with_clusters(args).await,
-        FederationSubcommand::Vote(args) => handle_federated_vote(args).await,
+        FederationSubcommand::Vote(args) => handle_federated_vote(args, sync_engine).await,
         FederationSubcommand::Trust(args) => normalize_trust(args).await,
         FederationSubcommand::Broadcast(args) => broadcast_strategy(args).await,
         FederationSubcommand::Status(args) => show_federation_status(args).await,
@@ -305,7 +307,7 @@ async fn sync_with_clusters(args: &SyncArgs) -> Result<()> {
     Ok(())
 }
 
-async fn handle_federated_vote(args: &VoteArgs) -> Result<()> {
+async fn handle_federated_vote(args: &VoteArgs, sync_engine: &FederationSyncEngine) -> Result<()> {
     if args.create {
         if args.title.is_none() || args.description.is_none() {
             return Err(anyhow::anyhow!("Title and description are required for creating a proposal"));
@@ -320,14 +322,22 @@ async fn handle_federated_vote(args: &VoteArgs) -> Result<()> {
             println!("🌐 This is a global proposal requiring all clusters to participate");
         }
         
-        // Mock proposal creation
         let proposal_id = format!("FP-{}", SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap_or(Duration::from_secs(0))
             .as_secs());
+        sync_engine.record_local(SyncRecordKind::Proposal, &proposal_id, serde_json::json!({
+            "id": proposal_id,
+            "title": args.title,
+            "description": args.description,
+            "creator_cluster_id": sync_engine.local_cluster_id(),
+            "quorum_required": args.quorum,
+            "global": args.global,
+        }))?;
+        let synced = sync_engine.sync_with_peers().await?;
         
         println!("\n✅ Proposal created successfully with ID: {}", proposal_id.green());
-        println!("📢 Proposal has been broadcast to all linked clusters");
+        println!("📢 Proposal has been sent to {} linked clusters", synced);
         
     } else if let Some(proposal_id) = &args.vote_on {
         if args.vote_type.is_none() {
@@ -337,9 +347,16 @@ async fn handle_federated_vote(args: &VoteArgs) -> Result<()> {
         println!("🗳️ Voting on federated proposal: {}", proposal_id.cyan());
         println!("📝 Vote: {:?}", args.vote_type.as_ref().unwrap());
         
-        // Mock vote recording
+        let cluster_id = sync_engine.local_cluster_id();
+        sync_engine.record_local(SyncRecordKind::Vote, &format!("{}:{}", proposal_id, cluster_id), serde_json::json!({
+            "proposal_id": proposal_id,
+            "cluster_id": cluster_id,
+            "vote": args.vote_type,
+        }))?;
+        let synced = sync_engine.sync_with_peers().await?;
+        
         println!("\n✅ Vote recorded successfully");
-        println!("📊 Updated vote tally has been broadcast to all linked clusters");
+        println!("📊 Updated vote tally has been sent to {} linked clusters", synced);
     } else {
         // List active proposals
         println!("🗳️ Active federated proposals:");
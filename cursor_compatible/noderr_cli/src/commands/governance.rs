@@ -10,6 +10,8 @@ use noderr_core::trust_score_engine::TrustScoreEngine;
 use noderr_core::strategy_storage::StrategyStorage;
 use noderr_core::redis::RedisClient;
 
+use crate::federation_sync_engine::{FederationSyncEngine, SyncRecordKind};
+
 #[derive(Debug, Clone, Subcommand)]
 pub enum GovernanceCommand {
     /// Governance management commands for meta-agent oversight
@@ -169,6 +171,7 @@ pub async fn run_governance_command(
     engine: Arc<dyn TrustScoreEngine>,
     storage: Arc<dyn StrategyStorage>,
     redis_client: Arc<RedisClient>,
+    sync_engine: Arc<FederationSyncEngine>,
 ) -> Result<()> {
     match cmd {
         GovernanceCommand::Command(subcmd) => match subcmd {
@@ -176,10 +179,10 @@ pub async fn run_governance_command(
                 oversee_meta_agent(args, engine, storage, redis_client).await
             }
             GovernanceSubcommand::Vote(args) => {
-                vote_on_proposal(args, engine, storage, redis_client).await
+                vote_on_proposal(args, engine, storage, redis_client, &sync_engine).await
             }
             GovernanceSubcommand::Propose(args) => {
-                create_proposal(args, engine, storage, redis_client).await
+                create_proposal(args, engine, storage, redis_client, &sync_engine).await
             }
             GovernanceSubcommand::Verify(args) => {
                 verify_governance(args, engine, storage, redis_client).await
@@ -295,6 +298,7 @@ async fn vote_on_proposal(
     engine: Arc<dyn TrustScoreEngine>,
     storage: Arc<dyn StrategyStorage>,
     redis_client: Arc<RedisClient>,
+    sync_engine: &FederationSyncEngine,
 ) -> Result<()> {
     println!("🗳️ {} vote on proposal {}", "Recording".cyan(), args.proposal_id);
     
@@ -309,9 +313,6 @@ async fn vote_on_proposal(
         }
     };
     
-    // In a real implementation, we would store the vote in a database
-    // For now, we'll just mock the response
-    
     let vote = Vote {
         proposal_id: args.proposal_id.clone(),
         agent_id: args.agent_id.clone(),
@@ -320,6 +321,11 @@ async fn vote_on_proposal(
         timestamp: Utc::now(),
     };
     
+    // Replicate the vote to federated clusters
+    let vote_id = format!("{}:{}", vote.proposal_id, vote.agent_id);
+    sync_engine.record_local(SyncRecordKind::Vote, &vote_id, serde_json::to_value(&vote)?)?;
+    sync_engine.sync_with_peers().await?;
+    
     println!("Vote recorded successfully!");
     
     // Show current vote status (mock data)
@@ -350,6 +356,7 @@ async fn create_proposal(
     engine: Arc<dyn TrustScoreEngine>,
     storage: Arc<dyn StrategyStorage>,
     redis_client: Arc<RedisClient>,
+    sync_engine: &FederationSyncEngine,
 ) -> Result<()> {
     println!("📜 {} new governance proposal", "Creating".cyan());
     
@@ -380,9 +387,6 @@ async fn create_proposal(
     // Generate a mock proposal ID
     let proposal_id = format!("prop_{}", Utc::now().timestamp());
     
-    // In a real implementation, we would store the proposal in a database
-    // For now, we'll just display what would be stored
-    
     let proposal = GovernanceProposal {
         proposal_id: proposal_id.clone(),
         title: args.title.clone(),
@@ -399,6 +403,10 @@ async fn create_proposal(
         abstain_votes: 0,
     };
     
+    // Replicate the proposal to federated clusters
+    sync_engine.record_local(SyncRecordKind::Proposal, &proposal_id, serde_json::to_value(&proposal)?)?;
+    sync_engine.sync_with_peers().await?;
+    
     println!("Proposal created successfully with ID: {}", proposal_id.green());
     println!("Title: {}", proposal.title);
     println!("Type: {}", proposal.proposal_type);
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use comfy_table::Table;
+use serde_json::json;
+
+use noderr_core::logging::LogLevels;
+
+use super::api_client::{ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct LogLevelCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: LogLevelSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LogLevelSubcommand {
+    /// Show the default level and per-module overrides
+    List,
+
+    /// Change the level of one module, or the default level
+    Set {
+        /// New level (trace, debug, info, warn, error or off)
+        level: String,
+
+        /// Module path (e.g. noderr_core::order_router); the default level when omitted
+        #[arg(long)]
+        module: Option<String>,
+    },
+
+    /// Restore configured levels for one module, or for everything
+    Reset {
+        /// Module path; every level when omitted
+        #[arg(long)]
+        module: Option<String>,
+    },
+}
+
+pub async fn run_log_level_command(cmd: &LogLevelCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    let levels: LogLevels = match &cmd.subcommand {
+        LogLevelSubcommand::List => client.get("/logging/levels", &[]).await?,
+
+        LogLevelSubcommand::Set { level, module } => {
+            let levels = client
+                .post("/logging/levels", &json!({ "target": module, "level": level }))
+                .await?;
+            println!("{} {} now logs at {}", "✓".green(), module.as_deref().unwrap_or("Default level"), level);
+            levels
+        }
+
+        LogLevelSubcommand::Reset { module } => {
+            let levels = client
+                .post("/logging/levels/reset", &json!({ "target": module }))
+                .await?;
+            println!("{} Restored configured level for {}", "✓".green(), module.as_deref().unwrap_or("all modules"));
+            levels
+        }
+    };
+
+    if output.is_json() {
+        print_json(&levels)?;
+    } else {
+        print_levels(&levels);
+    }
+    Ok(())
+}
+
+fn print_levels(levels: &LogLevels) {
+    let mut table = Table::new();
+    table.set_header(vec!["Module", "Level"]);
+    table.add_row(vec!["(default)", levels.default.as_str()]);
+    for (module, level) in &levels.modules {
+        table.add_row(vec![module.as_str(), level.as_str()]);
+    }
+
+    println!("{}", table);
+}
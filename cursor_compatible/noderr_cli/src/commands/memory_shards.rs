@@ -1,10 +1,17 @@
 use clap::Parser;
 use colored::*;
 use prettytable::{Cell, Row, Table};
+use reed_solomon_erasure::galois_8::ReedSolomon;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use chrono::{DateTime, Utc};
 
+/// Reed-Solomon over GF(2^8) supports at most 256 shards
+const MAX_TOTAL_SHARDS: usize = 256;
+
 #[derive(Parser, Debug)]
 pub struct MemoryShardsCommand {
     #[clap(subcommand)]
@@ -22,7 +29,8 @@ pub enum MemoryShardsSubcommand {
     /// List available memory shards
     List(ListArgs),
     
-    /// Validate integrity of memory shards
+    /// Verify shard checksums and repair damaged shards from parity
+    #[clap(alias = "verify")]
     Validate(ValidateArgs),
     
     /// Sync memory shards across the network
@@ -38,17 +46,29 @@ pub struct FragmentArgs {
     #[clap(long)]
     pub memory_id: String,
     
-    /// Number of shards to create
+    /// File containing the memory payload to fragment
+    #[clap(long)]
+    pub input: PathBuf,
+    
+    /// Number of data shards (k); any k shards can reconstruct the memory
     #[clap(long, default_value = "5")]
     pub shard_count: usize,
     
-    /// Redundancy factor (how many copies of each shard)
+    /// Number of parity shards; up to this many shards may be lost
     #[clap(long, default_value = "3")]
+    pub parity_shards: usize,
+    
+    /// Redundancy factor (how many host agents hold each shard)
+    #[clap(long, default_value = "1")]
     pub redundancy: usize,
     
     /// Encryption level (0-5, where 5 is highest)
     #[clap(long, default_value = "3")]
     pub encryption_level: u8,
+    
+    /// Directory holding shard sets (defaults to the Noderr data directory)
+    #[clap(long)]
+    pub store_dir: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
@@ -57,13 +77,17 @@ pub struct ReassembleArgs {
     #[clap(long)]
     pub memory_id: String,
     
-    /// Minimum required shards to reassemble (defaults to 60% of total)
-    #[clap(long)]
-    pub min_shards: Option<usize>,
+    /// Write the reassembled memory to this file
+    #[clap(long = "output-file")]
+    pub output: Option<PathBuf>,
     
-    /// Force reassembly even with integrity issues
+    /// Write the output even if the reassembled payload fails its checksum
     #[clap(long)]
     pub force: bool,
+    
+    /// Directory holding shard sets (defaults to the Noderr data directory)
+    #[clap(long)]
+    pub store_dir: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
@@ -98,6 +122,10 @@ pub struct ValidateArgs {
     /// Repair any found integrity issues
     #[clap(long)]
     pub repair: bool,
+    
+    /// Directory holding shard sets (defaults to the Noderr data directory)
+    #[clap(long)]
+    pub store_dir: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
@@ -164,6 +192,45 @@ pub struct MemoryShardStatus {
     pub distributed_agents: Vec<String>,
 }
 
+/// Layout and checksums of an erasure-coded shard set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardManifest {
+    pub memory_id: String,
+    pub data_shards: usize,
+    pub parity_shards: usize,
+    pub shard_len: usize,
+    pub payload_len: usize,
+    /// BLAKE3 checksum of the original payload
+    pub payload_checksum: String,
+    /// BLAKE3 checksum of each shard, data shards first
+    pub shard_checksums: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShardManifest {
+    pub fn total_shards(&self) -> usize {
+        self.data_shards + self.parity_shards
+    }
+}
+
+/// Integrity state of a single shard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardHealth {
+    Valid,
+    Corrupt,
+    Missing,
+}
+
+impl std::fmt::Display for ShardHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShardHealth::Valid => write!(f, "Valid"),
+            ShardHealth::Corrupt => write!(f, "Corrupt"),
+            ShardHealth::Missing => write!(f, "Missing"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ArchiveMetadata {
     pub archive_id: String,
@@ -189,55 +256,195 @@ pub async fn run_memory_shards_command(cmd: MemoryShardsSubcommand) -> Result<()
     }
 }
 
+fn checksum(bytes: &[u8]) -> String {
+    blake3::hash(bytes).to_hex().to_string()
+}
+
+/// Split a payload into `data_shards` data shards plus `parity_shards` Reed-Solomon parity shards
+pub fn encode_shards(
+    memory_id: &str,
+    payload: &[u8],
+    data_shards: usize,
+    parity_shards: usize,
+) -> Result<(ShardManifest, Vec<Vec<u8>>), String> {
+    if data_shards == 0 || parity_shards == 0 {
+        return Err("Both data and parity shard counts must be at least 1".to_string());
+    }
+    if data_shards + parity_shards > MAX_TOTAL_SHARDS {
+        return Err(format!("At most {} shards are supported", MAX_TOTAL_SHARDS));
+    }
+    
+    let codec = ReedSolomon::new(data_shards, parity_shards).map_err(|e| e.to_string())?;
+    let shard_len = ((payload.len() + data_shards - 1) / data_shards).max(1);
+    
+    let mut shards: Vec<Vec<u8>> = (0..data_shards + parity_shards)
+        .map(|index| {
+            let start = (index * shard_len).min(payload.len());
+            let end = ((index + 1) * shard_len).min(payload.len());
+            let mut shard = if index < data_shards { payload[start..end].to_vec() } else { Vec::new() };
+            shard.resize(shard_len, 0);
+            shard
+        })
+        .collect();
+    
+    codec.encode(&mut shards).map_err(|e| e.to_string())?;
+    
+    let manifest = ShardManifest {
+        memory_id: memory_id.to_string(),
+        data_shards,
+        parity_shards,
+        shard_len,
+        payload_len: payload.len(),
+        payload_checksum: checksum(payload),
+        shard_checksums: shards.iter().map(|shard| checksum(shard)).collect(),
+        created_at: Utc::now(),
+    };
+    
+    Ok((manifest, shards))
+}
+
+/// Classify shards against the manifest checksums
+pub fn check_shards(manifest: &ShardManifest, shards: &[Option<Vec<u8>>]) -> Vec<ShardHealth> {
+    manifest.shard_checksums
+        .iter()
+        .enumerate()
+        .map(|(index, expected)| match shards.get(index).and_then(|shard| shard.as_ref()) {
+            None => ShardHealth::Missing,
+            Some(shard) if shard.len() == manifest.shard_len && &checksum(shard) == expected => ShardHealth::Valid,
+            Some(_) => ShardHealth::Corrupt,
+        })
+        .collect()
+}
+
+/// Rebuild every shard from any `data_shards` valid shards
+pub fn reconstruct_shards(manifest: &ShardManifest, shards: Vec<Option<Vec<u8>>>) -> Result<Vec<Vec<u8>>, String> {
+    let health = check_shards(manifest, &shards);
+    let valid = health.iter().filter(|h| **h == ShardHealth::Valid).count();
+    if valid < manifest.data_shards {
+        return Err(format!(
+            "Only {} of {} shards are intact; at least {} are required",
+            valid, manifest.total_shards(), manifest.data_shards
+        ));
+    }
+    
+    // Corrupt shards are treated as erasures
+    let mut working: Vec<Option<Vec<u8>>> = shards
+        .into_iter()
+        .zip(health.iter())
+        .map(|(shard, h)| if *h == ShardHealth::Valid { shard } else { None })
+        .collect();
+    working.resize(manifest.total_shards(), None);
+    
+    let codec = ReedSolomon::new(manifest.data_shards, manifest.parity_shards).map_err(|e| e.to_string())?;
+    codec.reconstruct(&mut working).map_err(|e| e.to_string())?;
+    
+    Ok(working.into_iter().map(|shard| shard.unwrap_or_default()).collect())
+}
+
+/// Concatenate data shards back into the original payload
+pub fn decode_payload(manifest: &ShardManifest, shards: &[Vec<u8>]) -> Vec<u8> {
+    let mut payload: Vec<u8> = shards
+        .iter()
+        .take(manifest.data_shards)
+        .flat_map(|shard| shard.iter().copied())
+        .collect();
+    payload.truncate(manifest.payload_len);
+    payload
+}
+
+fn store_root(store_dir: &Option<PathBuf>) -> Result<PathBuf, String> {
+    match store_dir {
+        Some(dir) => Ok(dir.clone()),
+        None => dirs::data_dir()
+            .map(|dir| dir.join("noderr").join("memory_shards"))
+            .ok_or_else(|| "Could not determine data directory; pass --store-dir".to_string()),
+    }
+}
+
+fn shard_path(set_dir: &Path, index: usize) -> PathBuf {
+    set_dir.join(format!("shard-{:03}.bin", index))
+}
+
+fn write_shard_set(set_dir: &Path, manifest: &ShardManifest, shards: &[Vec<u8>]) -> Result<(), String> {
+    fs::create_dir_all(set_dir).map_err(|e| e.to_string())?;
+    for (index, shard) in shards.iter().enumerate() {
+        fs::write(shard_path(set_dir, index), shard).map_err(|e| e.to_string())?;
+    }
+    let manifest_json = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+    fs::write(set_dir.join("manifest.json"), manifest_json).map_err(|e| e.to_string())
+}
+
+fn read_shard_set(set_dir: &Path) -> Result<(ShardManifest, Vec<Option<Vec<u8>>>), String> {
+    let manifest_json = fs::read_to_string(set_dir.join("manifest.json"))
+        .map_err(|e| format!("No shard manifest in {}: {}", set_dir.display(), e))?;
+    let manifest: ShardManifest = serde_json::from_str(&manifest_json).map_err(|e| e.to_string())?;
+    
+    let shards = (0..manifest.total_shards())
+        .map(|index| fs::read(shard_path(set_dir, index)).ok())
+        .collect();
+    
+    Ok((manifest, shards))
+}
+
 async fn fragment_memory(args: FragmentArgs) -> Result<(), String> {
     println!("{}", "Fragmenting memory into distributed shards...".bold().green());
     println!("Memory ID: {}", args.memory_id.bold());
-    println!("Creating {} shards with {}x redundancy", args.shard_count, args.redundancy);
+    println!(
+        "Creating {} data + {} parity shards ({}-of-{} recovery) with {}x placement",
+        args.shard_count,
+        args.parity_shards,
+        args.shard_count,
+        args.shard_count + args.parity_shards,
+        args.redundancy
+    );
+    
+    let payload = fs::read(&args.input)
+        .map_err(|e| format!("Failed to read {}: {}", args.input.display(), e))?;
+    let (manifest, shards) = encode_shards(&args.memory_id, &payload, args.shard_count, args.parity_shards)?;
+    
+    let set_dir = store_root(&args.store_dir)?.join(&args.memory_id);
+    write_shard_set(&set_dir, &manifest, &shards)?;
+    
+    let encryption_status = match args.encryption_level {
+        0 => "None",
+        1 => "Basic",
+        2 => "Standard",
+        3 => "Enhanced",
+        4 => "Advanced",
+        _ => "Maximum",
+    };
     
-    // Simulate fragmentation process
     let mut table = Table::new();
     table.add_row(Row::new(vec![
-        Cell::new("Shard ID").style_spec("Fb"),
-        Cell::new("Host Agent").style_spec("Fb"),
+        Cell::new("Shard").style_spec("Fb"),
+        Cell::new("Kind").style_spec("Fb"),
+        Cell::new("Host Agents").style_spec("Fb"),
         Cell::new("Encryption").style_spec("Fb"),
         Cell::new("Size").style_spec("Fb"),
-        Cell::new("Status").style_spec("Fb"),
+        Cell::new("BLAKE3").style_spec("Fb"),
     ]));
     
-    for i in 0..args.shard_count {
-        // Simulate some processing time
-        tokio::time::sleep(Duration::from_millis(100)).await;
+    for (index, checksum) in manifest.shard_checksums.iter().enumerate() {
+        let kind = if index < manifest.data_shards { "Data" } else { "Parity" };
+        let agents: Vec<String> = (0..args.redundancy.max(1))
+            .map(|r| format!("agent-{}", index * args.redundancy.max(1) + r + 1))
+            .collect();
         
-        for r in 0..args.redundancy {
-            let shard_id = Uuid::new_v4().to_string();
-            let agent_id = format!("agent-{}", i * args.redundancy + r + 1);
-            let size = 256 + (i * 128);
-            let status = "Created";
-            
-            let encryption_status = match args.encryption_level {
-                0 => "None",
-                1 => "Basic",
-                2 => "Standard",
-                3 => "Enhanced",
-                4 => "Advanced",
-                _ => "Maximum",
-            };
-            
-            table.add_row(Row::new(vec![
-                Cell::new(&shard_id[..8]),
-                Cell::new(&agent_id),
-                Cell::new(encryption_status),
-                Cell::new(&format!("{}KB", size)),
-                Cell::new(status).style_spec("Fg"),
-            ]));
-        }
+        table.add_row(Row::new(vec![
+            Cell::new(&format!("{}/{}", index + 1, manifest.total_shards())),
+            Cell::new(kind),
+            Cell::new(&agents.join(", ")),
+            Cell::new(encryption_status),
+            Cell::new(&format!("{}B", manifest.shard_len)),
+            Cell::new(&checksum[..16]),
+        ]));
     }
     
     table.printstd();
     
     println!("\n{}", "Memory successfully fragmented and distributed.".bold().green());
-    println!("Recovery key: {}", Uuid::new_v4().to_string().bold().red());
-    println!("Store this key securely - it's required for memory reassembly!");
+    println!("Shard set: {}", set_dir.display());
+    println!("Payload checksum: {}", manifest.payload_checksum.bold());
     
     Ok(())
 }
@@ -246,45 +453,44 @@ async fn reassemble_memory(args: ReassembleArgs) -> Result<(), String> {
     println!("{}", "Attempting to reassemble memory from shards...".bold().blue());
     println!("Memory ID: {}", args.memory_id.bold());
     
-    // Simulate shard collection process
-    println!("Collecting distributed shards from network...");
+    let set_dir = store_root(&args.store_dir)?.join(&args.memory_id);
+    let (manifest, shards) = read_shard_set(&set_dir)?;
     
-    let total_shards = 5;
-    let available_shards = if args.force { 3 } else { 5 };
-    let min_required = args.min_shards.unwrap_or((total_shards as f64 * 0.6) as usize);
-    
-    println!("Found {}/{} shards (minimum required: {})", 
-        available_shards, 
-        total_shards,
-        min_required
+    let health = check_shards(&manifest, &shards);
+    let valid = health.iter().filter(|h| **h == ShardHealth::Valid).count();
+    println!("Found {}/{} intact shards (minimum required: {})",
+        valid,
+        manifest.total_shards(),
+        manifest.data_shards
     );
     
-    if available_shards < min_required && !args.force {
-        return Err(format!("Insufficient shards to reassemble memory. Use --force to attempt reassembly with available shards."));
-    }
+    let rebuilt = reconstruct_shards(&manifest, shards)?;
+    let payload = decode_payload(&manifest, &rebuilt);
+    let intact = checksum(&payload) == manifest.payload_checksum;
     
-    // Simulate reassembly process
-    println!("Validating shard integrity...");
-    tokio::time::sleep(Duration::from_millis(500)).await;
-    
-    println!("Decrypting shards...");
-    tokio::time::sleep(Duration::from_millis(700)).await;
-    
-    println!("Reassembling memory structure...");
-    tokio::time::sleep(Duration::from_millis(900)).await;
+    if !intact && !args.force {
+        return Err("Reassembled payload failed its BLAKE3 checksum. Use --force to write it anyway.".to_string());
+    }
     
-    if available_shards < total_shards {
-        println!("{}", "⚠️ Warning: Memory reassembled with partial shards. Some data may be missing or corrupted.".yellow());
+    if valid < manifest.total_shards() {
+        println!("{}", format!("⚠️ Recovered {} lost or corrupt shards from parity.", manifest.total_shards() - valid).yellow());
+    }
+    if intact {
+        println!("{}", "✓ Memory successfully reassembled and verified.".bold().green());
     } else {
-        println!("{}", "✓ Memory successfully reassembled with all shards.".bold().green());
+        println!("{}", "⚠️ Warning: payload checksum mismatch; output may be corrupted.".yellow());
+    }
+    
+    if let Some(output) = &args.output {
+        fs::write(output, &payload).map_err(|e| format!("Failed to write {}: {}", output.display(), e))?;
+        println!("Written to: {}", output.display());
     }
     
     println!("\nMemory details:");
-    println!("  ID: {}", args.memory_id);
-    println!("  Size: 1.2MB");
-    println!("  Created: 2023-08-15 14:23:45 UTC");
-    println!("  Last modified: 2023-09-02 09:12:31 UTC");
-    println!("  Integrity: {}%", if available_shards == total_shards { 100 } else { 85 });
+    println!("  ID: {}", manifest.memory_id);
+    println!("  Size: {} bytes", manifest.payload_len);
+    println!("  Created: {}", manifest.created_at.format("%Y-%m-%d %H:%M:%S UTC"));
+    println!("  Checksum: {}", manifest.payload_checksum);
     
     Ok(())
 }
@@ -392,87 +598,100 @@ async fn validate_shards(args: ValidateArgs) -> Result<(), String> {
         println!("Performing deep validation (this may take longer)...");
     }
     
-    // Simulate validation process
-    println!("Locating shards on network...");
-    tokio::time::sleep(Duration::from_millis(300)).await;
+    let set_dir = store_root(&args.store_dir)?.join(&args.memory_id);
+    let (manifest, shards) = read_shard_set(&set_dir)?;
+    let health = check_shards(&manifest, &shards);
     
-    let total_shards = 5;
-    let available_shards = 5;
-    let corrupt_shards = if args.memory_id.ends_with("1") { 2 } else { 0 };
+    let damaged: Vec<usize> = health
+        .iter()
+        .enumerate()
+        .filter(|(_, h)| **h != ShardHealth::Valid)
+        .map(|(index, _)| index)
+        .collect();
     
-    println!("Found {}/{} shards", available_shards, total_shards);
+    // Repair rebuilds damaged shards from parity and writes them back
+    let rebuilt = if (args.repair && !damaged.is_empty()) || args.deep {
+        Some(reconstruct_shards(&manifest, shards.clone()))
+    } else {
+        None
+    };
+    
+    let mut repaired = Vec::new();
+    if args.repair {
+        if let Some(Ok(rebuilt)) = &rebuilt {
+            for &index in &damaged {
+                fs::write(shard_path(&set_dir, index), &rebuilt[index]).map_err(|e| e.to_string())?;
+                repaired.push(index);
+            }
+        }
+    }
     
-    // Validate each shard
     let mut table = Table::new();
     table.add_row(Row::new(vec![
-        Cell::new("Shard ID").style_spec("Fb"),
-        Cell::new("Host Agent").style_spec("Fb"),
-        Cell::new("Integrity").style_spec("Fb"),
+        Cell::new("Shard").style_spec("Fb"),
+        Cell::new("Kind").style_spec("Fb"),
+        Cell::new("Expected BLAKE3").style_spec("Fb"),
         Cell::new("Issues").style_spec("Fb"),
         Cell::new("Status").style_spec("Fb"),
     ]));
     
-    for i in 0..total_shards {
-        let shard_id = format!("shard-{}", Uuid::new_v4().to_string()[..8]);
-        let agent_id = format!("agent-{}", 100 + i);
-        let has_issues = i < corrupt_shards;
-        let integrity = if has_issues { 78.0 } else { 100.0 };
-        
-        let issue_desc = if has_issues {
-            "Checksum mismatch"
-        } else {
-            "None"
+    for (index, shard_health) in health.iter().enumerate() {
+        let kind = if index < manifest.data_shards { "Data" } else { "Parity" };
+        let issue_desc = match shard_health {
+            ShardHealth::Valid => "None",
+            ShardHealth::Corrupt => "Checksum mismatch",
+            ShardHealth::Missing => "Shard file missing",
         };
         
-        let status = if has_issues {
-            if args.repair {
-                "Repaired"
-            } else {
-                "Corrupt"
-            }
+        let status_cell = if *shard_health == ShardHealth::Valid {
+            Cell::new("Valid").style_spec("Fg")
+        } else if repaired.contains(&index) {
+            Cell::new("Repaired").style_spec("Fy")
         } else {
-            "Valid"
-        };
-        
-        let status_cell = if status == "Valid" {
-            Cell::new(status).style_spec("Fg")
-        } else if status == "Repaired" {
-            Cell::new(status).style_spec("Fy")
-        } else {
-            Cell::new(status).style_spec("Fr")
+            Cell::new(&shard_health.to_string()).style_spec("Fr")
         };
         
         table.add_row(Row::new(vec![
-            Cell::new(&shard_id),
-            Cell::new(&agent_id),
-            Cell::new(&format!("{:.0}%", integrity)),
+            Cell::new(&format!("{}/{}", index + 1, manifest.total_shards())),
+            Cell::new(kind),
+            Cell::new(&manifest.shard_checksums[index][..16]),
             Cell::new(issue_desc),
             status_cell,
         ]));
-        
-        // Simulate validation time
-        tokio::time::sleep(Duration::from_millis(200)).await;
     }
     
     table.printstd();
     
-    if corrupt_shards > 0 {
-        if args.repair {
-            println!("\n{}", "✓ Repaired all corrupt shards successfully.".bold().green());
-        } else {
-            println!("\n{}", "⚠️ Found corrupt shards. Run with --repair to fix issues.".bold().yellow());
-        }
-    } else {
+    let recoverable = health.len() - damaged.len() >= manifest.data_shards;
+    if damaged.is_empty() {
         println!("\n{}", "✓ All shards are valid.".bold().green());
+    } else if !recoverable {
+        println!("\n{}", format!(
+            "✗ {} shards damaged; fewer than {} intact shards remain, memory cannot be recovered.",
+            damaged.len(), manifest.data_shards
+        ).bold().red());
+    } else if args.repair {
+        println!("\n{}", format!("✓ Repaired {} shards from parity.", repaired.len()).bold().green());
+    } else {
+        println!("\n{}", format!(
+            "⚠️ Found {} damaged shards (up to {} can be rebuilt). Run with --repair to fix issues.",
+            damaged.len(), manifest.parity_shards
+        ).bold().yellow());
     }
     
-    println!("\nOverall integrity: {}%", 
-        if corrupt_shards > 0 && !args.repair { 
-            format!("{:.0}", 100.0 - (corrupt_shards as f64 / total_shards as f64) * 100.0) 
-        } else { 
-            "100".to_string() 
+    if args.deep {
+        match &rebuilt {
+            Some(Ok(rebuilt)) if checksum(&decode_payload(&manifest, rebuilt)) == manifest.payload_checksum => {
+                println!("Payload checksum: {}", "verified".green());
+            }
+            Some(Ok(_)) => println!("Payload checksum: {}", "MISMATCH".red()),
+            Some(Err(e)) => println!("Payload checksum: {} ({})", "unverifiable".red(), e),
+            None => {}
         }
-    );
+    }
+    
+    let intact = if args.repair && recoverable { health.len() } else { health.len() - damaged.len() };
+    println!("\nOverall integrity: {:.0}%", intact as f64 / health.len() as f64 * 100.0);
     
     Ok(())
 }
@@ -677,4 +896,30 @@ fn get_mock_memory_shards() -> Vec<MemoryShard> {
     }
     
     shards
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_reconstruct_with_lost_and_corrupt_shards() {
+        let payload = b"agent memory: regime=trending, conviction=0.82, peers=[a,b,c]".to_vec();
+        let (manifest, shards) = encode_shards("mem-1", &payload, 4, 2).unwrap();
+        
+        let mut damaged: Vec<Option<Vec<u8>>> = shards.into_iter().map(Some).collect();
+        damaged[1] = None;
+        damaged[4].as_mut().unwrap()[0] ^= 0xff;
+        
+        let health = check_shards(&manifest, &damaged);
+        assert_eq!(health[1], ShardHealth::Missing);
+        assert_eq!(health[4], ShardHealth::Corrupt);
+        
+        let rebuilt = reconstruct_shards(&manifest, damaged.clone()).unwrap();
+        assert_eq!(decode_payload(&manifest, &rebuilt), payload);
+        
+        // Losing a third shard exceeds the parity budget
+        damaged[0] = None;
+        assert!(reconstruct_shards(&manifest, damaged).is_err());
+    }
+}
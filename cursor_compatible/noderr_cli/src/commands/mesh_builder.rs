@@ -1,9 +1,11 @@
 use anyhow::{anyhow, Context, Result};
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use comfy_table::{Cell, Color, ContentArrangement, Table};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use chrono::{DateTime, Utc};
+use noderr_core::mesh::{MeshConfig, MeshSnapshot, TrustMesh};
 use noderr_core::{
     storage::Storage, 
     engine::Engine, 
@@ -38,6 +40,10 @@ pub enum MeshBuilderSubcommand {
     Feedback(FeedbackArgs),
     /// View audit logs for anomalies and healing events
     Audit(AuditArgs),
+    /// Find the most trusted strategy propagation paths from an agent
+    Route(RouteArgs),
+    /// Export the mesh as DOT or JSON for visualization
+    Export(ExportArgs),
 }
 
 #[derive(Args, Debug)]
@@ -183,6 +189,46 @@ pub struct AuditArgs {
     pub days: u64,
 }
 
+#[derive(Args, Debug)]
+pub struct RouteArgs {
+    /// Mesh snapshot to route over, as written by `mesh export --format json`
+    #[clap(long)]
+    pub mesh: PathBuf,
+    
+    /// Agent the strategy originates from
+    #[clap(long)]
+    pub from: String,
+    
+    /// Destination agent; shows paths to every reachable agent when omitted
+    #[clap(long)]
+    pub to: Option<String>,
+    
+    /// Minimum trust score (0-100) for an agent to relay strategies
+    #[clap(long, default_value = "0")]
+    pub min_relay_trust: f64,
+}
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// Mesh snapshot to export, as written by `mesh export --format json`
+    #[clap(long)]
+    pub mesh: PathBuf,
+    
+    /// Export format
+    #[clap(long, value_enum, default_value = "dot")]
+    pub format: MeshExportFormat,
+    
+    /// Write to this file instead of stdout
+    #[clap(long)]
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum MeshExportFormat {
+    Dot,
+    Json,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct MeshAgentInfo {
     agent_id: String,
@@ -312,6 +358,12 @@ pub async fn run_mesh_builder_command(
         MeshBuilderSubcommand::Audit(args) => {
             view_audit_logs(args, engine.clone(), storage.clone(), redis_client.clone()).await?;
         }
+        MeshBuilderSubcommand::Route(args) => {
+            route_strategy(args)?;
+        }
+        MeshBuilderSubcommand::Export(args) => {
+            export_mesh(args)?;
+        }
     }
     
     Ok(())
@@ -361,41 +413,7 @@ async fn view_trust_mesh(
         println!("Minimum trust filter: {:.1}", min_trust);
     }
     
-    // Mock data - would be replaced with actual implementation
-    let mut mesh_agents = vec![
-        MeshAgentInfo {
-            agent_id: "agent:001".to_string(),
-            trust_score: 92.5,
-            connected_peers: vec!["agent:002".to_string(), "agent:003".to_string()],
-            last_active: Utc::now(),
-            risk_level: RiskLevel::Low,
-            reputation: 0.95,
-        },
-        MeshAgentInfo {
-            agent_id: "agent:002".to_string(),
-            trust_score: 87.0,
-            connected_peers: vec!["agent:001".to_string(), "agent:004".to_string()],
-            last_active: Utc::now() - chrono::Duration::hours(2),
-            risk_level: RiskLevel::Low,
-            reputation: 0.89,
-        },
-        MeshAgentInfo {
-            agent_id: "agent:003".to_string(),
-            trust_score: 76.2,
-            connected_peers: vec!["agent:001".to_string()],
-            last_active: Utc::now() - chrono::Duration::hours(1),
-            risk_level: RiskLevel::Medium,
-            reputation: 0.79,
-        },
-        MeshAgentInfo {
-            agent_id: "agent:004".to_string(),
-            trust_score: 62.8,
-            connected_peers: vec!["agent:002".to_string()],
-            last_active: Utc::now() - chrono::Duration::days(1),
-            risk_level: RiskLevel::Medium,
-            reputation: 0.65,
-        },
-    ];
+    let mut mesh_agents = sample_mesh_agents();
     
     // Apply filters
     if args.active_only {
@@ -455,6 +473,131 @@ async fn view_trust_mesh(
     Ok(())
 }
 
+// Mock data - would be replaced with actual implementation
+fn sample_mesh_agents() -> Vec<MeshAgentInfo> {
+    vec![
+        MeshAgentInfo {
+            agent_id: "agent:001".to_string(),
+            trust_score: 92.5,
+            connected_peers: vec!["agent:002".to_string(), "agent:003".to_string()],
+            last_active: Utc::now(),
+            risk_level: RiskLevel::Low,
+            reputation: 0.95,
+        },
+        MeshAgentInfo {
+            agent_id: "agent:002".to_string(),
+            trust_score: 87.0,
+            connected_peers: vec!["agent:001".to_string(), "agent:004".to_string()],
+            last_active: Utc::now() - chrono::Duration::hours(2),
+            risk_level: RiskLevel::Low,
+            reputation: 0.89,
+        },
+        MeshAgentInfo {
+            agent_id: "agent:003".to_string(),
+            trust_score: 76.2,
+            connected_peers: vec!["agent:001".to_string()],
+            last_active: Utc::now() - chrono::Duration::hours(1),
+            risk_level: RiskLevel::Medium,
+            reputation: 0.79,
+        },
+        MeshAgentInfo {
+            agent_id: "agent:004".to_string(),
+            trust_score: 62.8,
+            connected_peers: vec!["agent:002".to_string()],
+            last_active: Utc::now() - chrono::Duration::days(1),
+            risk_level: RiskLevel::Medium,
+            reputation: 0.65,
+        },
+    ]
+}
+
+// Load the trust-weighted routing graph from a mesh snapshot
+fn load_mesh(path: &Path, config: MeshConfig) -> Result<TrustMesh> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read mesh snapshot {}", path.display()))?;
+    let snapshot: MeshSnapshot = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid mesh snapshot {}", path.display()))?;
+    if snapshot.nodes.is_empty() {
+        return Err(anyhow!("Mesh snapshot {} contains no agents", path.display()));
+    }
+    
+    TrustMesh::from_snapshot(&snapshot, config)
+        .with_context(|| format!("Inconsistent mesh snapshot {}", path.display()))
+}
+
+fn route_strategy(args: &RouteArgs) -> Result<()> {
+    let config = MeshConfig {
+        min_relay_trust: args.min_relay_trust / 100.0,
+        ..MeshConfig::default()
+    };
+    let mesh = load_mesh(&args.mesh, config)?;
+    
+    let paths = match &args.to {
+        Some(to) => vec![mesh.best_path(&args.from, to)?],
+        None => mesh.propagation_paths(&args.from)?,
+    };
+    
+    if paths.is_empty() {
+        println!("No agents reachable from {}", args.from);
+        return Ok(());
+    }
+    
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Destination", "Path", "Hops", "Path Trust", "Cost"]);
+    
+    for path in paths {
+        let trust_cell = if path.path_trust >= 0.7 {
+            Cell::new(format!("{:.3}", path.path_trust)).fg(Color::Green)
+        } else if path.path_trust >= 0.4 {
+            Cell::new(format!("{:.3}", path.path_trust)).fg(Color::Yellow)
+        } else {
+            Cell::new(format!("{:.3}", path.path_trust)).fg(Color::Red)
+        };
+        
+        table.add_row(vec![
+            Cell::new(path.agents.last().cloned().unwrap_or_default()),
+            Cell::new(path.agents.join(" -> ")),
+            Cell::new(path.agents.len().saturating_sub(1)),
+            trust_cell,
+            Cell::new(format!("{:.3}", path.cost)),
+        ]);
+    }
+    
+    println!("{table}");
+    
+    let partitions = mesh.partitions();
+    if partitions.len() > 1 {
+        println!("\nWarning: mesh is split into {} partitions", partitions.len());
+        for (index, partition) in partitions.iter().enumerate() {
+            println!("  {}: {}", index + 1, partition.join(", "));
+        }
+    }
+    
+    Ok(())
+}
+
+fn export_mesh(args: &ExportArgs) -> Result<()> {
+    let mesh = load_mesh(&args.mesh, MeshConfig::default())?;
+    
+    let rendered = match args.format {
+        MeshExportFormat::Dot => mesh.to_dot(),
+        MeshExportFormat::Json => serde_json::to_string_pretty(&mesh.to_json())?,
+    };
+    
+    match &args.file {
+        Some(path) => {
+            std::fs::write(path, rendered)
+                .with_context(|| format!("Failed to write mesh export to {}", path.display()))?;
+            println!("Mesh exported to {}", path.display());
+        }
+        None => print!("{}", rendered),
+    }
+    
+    Ok(())
+}
+
 async fn ban_agent_from_mesh(
     args: &BanArgs,
     engine: Arc<dyn TrustScoreEngine>,
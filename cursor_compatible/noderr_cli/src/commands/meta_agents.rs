@@ -5,6 +5,12 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::time::{Duration, SystemTime};
 
+use noderr_core::meta::decision_review::PendingReview;
+use noderr_core::meta::meta_agent_service::MetaAgentDecision;
+
+use super::api_client::{confirm, ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
 #[derive(Debug, Args)]
 pub struct MetaAgentsCommand {
     #[command(subcommand)]
@@ -23,6 +29,12 @@ pub enum MetaAgentsSubcommand {
     Compliance(ComplianceArgs),
     /// View and manage intervention history
     History(HistoryArgs),
+    /// List meta-agent decisions awaiting operator approval
+    Reviews(ReviewsArgs),
+    /// Approve a queued meta-agent decision for execution
+    Approve(ResolveReviewArgs),
+    /// Reject a queued meta-agent decision
+    Reject(ResolveReviewArgs),
 }
 
 #[derive(Debug, Args)]
@@ -108,6 +120,34 @@ pub struct HistoryArgs {
     pub intervention_type: Option<InterventionType>,
 }
 
+#[derive(Debug, Args)]
+pub struct ReviewsArgs {
+    #[command(flatten)]
+    pub api: ApiOptions,
+}
+
+#[derive(Debug, Args)]
+pub struct ResolveReviewArgs {
+    /// Decision ID
+    pub decision_id: String,
+
+    /// Note recorded with the resolution
+    #[arg(long)]
+    pub note: Option<String>,
+
+    /// Skip the confirmation prompt
+    #[arg(long)]
+    pub force: bool,
+
+    #[command(flatten)]
+    pub api: ApiOptions,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewsResponse {
+    reviews: Vec<PendingReview>,
+}
+
 #[derive(Debug, Clone, ValueEnum, Serialize, Deserialize)]
 pub enum SeverityLevel {
     Low,
@@ -167,14 +207,69 @@ impl fmt::Display for SeverityLevel {
     }
 }
 
-pub async fn run_meta_agents_command(cmd: &MetaAgentsCommand) -> anyhow::Result<()> {
+pub async fn run_meta_agents_command(cmd: &MetaAgentsCommand, output: OutputFormat) -> anyhow::Result<()> {
     match &cmd.subcommand {
         MetaAgentsSubcommand::Monitor(args) => monitor_meta_agents(args).await,
         MetaAgentsSubcommand::Metrics(args) => view_metrics(args).await,
         MetaAgentsSubcommand::Configure(args) => configure_oversight(args).await,
         MetaAgentsSubcommand::Compliance(args) => evaluate_compliance(args).await,
         MetaAgentsSubcommand::History(args) => view_intervention_history(args).await,
+        MetaAgentsSubcommand::Reviews(args) => list_reviews(args, output).await,
+        MetaAgentsSubcommand::Approve(args) => resolve_review(args, true).await,
+        MetaAgentsSubcommand::Reject(args) => resolve_review(args, false).await,
+    }
+}
+
+async fn list_reviews(args: &ReviewsArgs, output: OutputFormat) -> anyhow::Result<()> {
+    let client = ApiClient::new(&args.api)?;
+    let response: ReviewsResponse = client.get("/meta/reviews", &[]).await?;
+
+    if output.is_json() {
+        return print_json(&response.reviews);
+    }
+
+    if response.reviews.is_empty() {
+        println!("No meta-agent decisions awaiting review");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec!["Decision", "Meta-agent", "Affected", "Severity", "Queued", "Deadline", "Escalations"]);
+
+    for review in &response.reviews {
+        table.add_row(vec![
+            Cell::new(&review.decision.id),
+            Cell::new(&review.decision.meta_agent_id),
+            Cell::new(review.decision.affected_agents.join(", ")),
+            Cell::new(review.severity).set_alignment(CellAlignment::Right),
+            Cell::new(review.queued_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+            Cell::new(review.deadline.format("%Y-%m-%d %H:%M:%S").to_string()),
+            Cell::new(review.escalations).set_alignment(CellAlignment::Right),
+        ]);
+    }
+
+    println!("{}", table);
+    Ok(())
+}
+
+async fn resolve_review(args: &ResolveReviewArgs, approve: bool) -> anyhow::Result<()> {
+    let action = if approve { "approve" } else { "reject" };
+    if !args.force && !confirm(&format!("{} decision {}?", if approve { "Approve" } else { "Reject" }, args.decision_id))? {
+        println!("Aborted");
+        return Ok(());
     }
+
+    let client = ApiClient::new(&args.api)?;
+    let path = format!("/meta/reviews/{}/{}", args.decision_id, action);
+    let body = serde_json::json!({ "note": args.note });
+    let decision: MetaAgentDecision = client.post(&path, &body).await?;
+
+    let status = if approve { "approved".green() } else { "rejected".red() };
+    println!("{} Decision {} {}", "✓".green(), decision.id.bold(), status);
+    Ok(())
 }
 
 async fn monitor_meta_agents(args: &MonitorArgs) -> anyhow::Result<()> {
@@ -38,7 +38,7 @@ pub struct MetaDisagreementsArgs {
     pub explain: bool,
     
     /// Path to export results
-    #[clap(long)]
+    #[clap(long = "output-file")]
     pub output: Option<PathBuf>,
 }
 
@@ -27,4 +27,14 @@ pub mod reason_chain;
 pub mod meta_disagreements;
 pub mod agent_snapshot;
 pub mod agent_anomaly_monitor;
-pub mod federation; 
\ No newline at end of file
+pub mod federation; 
+pub mod dashboard;
+pub mod backtest;
+pub mod api_client;
+pub mod output;
+pub mod orders;
+pub mod risk;
+pub mod venue;
+pub mod log_level;
+pub mod state;
+pub mod universe;
\ No newline at end of file
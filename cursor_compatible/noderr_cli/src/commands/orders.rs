@@ -0,0 +1,276 @@
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use serde::Deserialize;
+use serde_json::json;
+
+use noderr_core::order_router::{BatchResult, OpenPosition, RestingOrder};
+
+use super::api_client::{confirm, ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct OrdersCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: OrdersSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum OrdersSubcommand {
+    /// List orders resting on venues
+    List {
+        /// Only show orders for this symbol
+        #[arg(long)]
+        symbol: Option<String>,
+    },
+
+    /// Cancel a resting order, or all of them
+    Cancel {
+        /// Order ID to cancel
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        order_id: Option<String>,
+
+        /// Cancel every resting order
+        #[arg(long)]
+        all: bool,
+
+        /// With --all, only cancel orders for this symbol
+        #[arg(long, requires = "all")]
+        symbol: Option<String>,
+
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct PositionsCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: PositionsSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PositionsSubcommand {
+    /// List open positions
+    List {
+        /// Only show positions for this symbol
+        #[arg(long)]
+        symbol: Option<String>,
+    },
+
+    /// Close positions with reduce-only orders
+    Flatten {
+        /// Symbol to flatten
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        symbol: Option<String>,
+
+        /// Flatten every open position
+        #[arg(long)]
+        all: bool,
+
+        /// Venues to route closing orders to (default: all known venues)
+        #[arg(long = "venue")]
+        venues: Vec<String>,
+
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct OrdersResponse {
+    orders: Vec<RestingOrder>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PositionsResponse {
+    positions: Vec<OpenPosition>,
+}
+
+pub async fn run_orders_command(cmd: &OrdersCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    match &cmd.subcommand {
+        OrdersSubcommand::List { symbol } => {
+            let orders = fetch_orders(&client, symbol.as_deref()).await?;
+            if output.is_json() {
+                print_json(&orders)?;
+            } else {
+                print_orders(&orders);
+            }
+        }
+
+        OrdersSubcommand::Cancel { order_id, all, symbol, force } => {
+            let result: BatchResult = if *all {
+                let orders = fetch_orders(&client, symbol.as_deref()).await?;
+                if orders.is_empty() {
+                    if output.is_json() {
+                        return print_batch_result("Cancelled", &empty_batch_result(), output);
+                    }
+                    println!("No resting orders to cancel");
+                    return Ok(());
+                }
+
+                if !output.is_json() {
+                    print_orders(&orders);
+                }
+                if !*force && !confirm(&format!("Cancel {} orders?", orders.len()))? {
+                    eprintln!("Aborted");
+                    return Ok(());
+                }
+                client.post("/orders/cancel-all", &json!({ "symbol": symbol })).await?
+            } else {
+                let order_id = order_id.as_deref().unwrap_or_default();
+                if !*force && !confirm(&format!("Cancel order {}?", order_id))? {
+                    eprintln!("Aborted");
+                    return Ok(());
+                }
+                client.post("/orders/batch/cancel", &json!({ "order_ids": [order_id] })).await?
+            };
+
+            print_batch_result("Cancelled", &result, output)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn run_positions_command(cmd: &PositionsCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    match &cmd.subcommand {
+        PositionsSubcommand::List { symbol } => {
+            let positions = fetch_positions(&client, symbol.as_deref()).await?;
+            if output.is_json() {
+                print_json(&positions)?;
+            } else {
+                print_positions(&positions);
+            }
+        }
+
+        PositionsSubcommand::Flatten { symbol, all: _, venues, force } => {
+            let positions = fetch_positions(&client, symbol.as_deref()).await?;
+            if positions.is_empty() {
+                if output.is_json() {
+                    return print_batch_result("Flattened", &empty_batch_result(), output);
+                }
+                println!("No open positions to flatten");
+                return Ok(());
+            }
+
+            if !output.is_json() {
+                print_positions(&positions);
+            }
+            let target = symbol.as_deref().unwrap_or("all symbols");
+            if !*force && !confirm(&format!("Flatten {} positions in {}?", positions.len(), target))? {
+                eprintln!("Aborted");
+                return Ok(());
+            }
+
+            let result: BatchResult = client
+                .post("/positions/flatten", &json!({ "symbol": symbol, "venues": venues }))
+                .await?;
+            print_batch_result("Flattened", &result, output)?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn fetch_orders(client: &ApiClient, symbol: Option<&str>) -> Result<Vec<RestingOrder>> {
+    let query: Vec<(&str, String)> = symbol.map(|s| ("symbol", s.to_string())).into_iter().collect();
+    let response: OrdersResponse = client.get("/orders", &query).await?;
+    Ok(response.orders)
+}
+
+async fn fetch_positions(client: &ApiClient, symbol: Option<&str>) -> Result<Vec<OpenPosition>> {
+    let query: Vec<(&str, String)> = symbol.map(|s| ("symbol", s.to_string())).into_iter().collect();
+    let response: PositionsResponse = client.get("/positions", &query).await?;
+    Ok(response.positions)
+}
+
+fn print_orders(orders: &[RestingOrder]) {
+    if orders.is_empty() {
+        println!("No resting orders");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Order ID", "Symbol", "Side", "Amount", "Price", "Venue", "TIF", "Submitted"]);
+    for order in orders {
+        table.add_row(vec![
+            Cell::new(&order.order_id),
+            Cell::new(&order.symbol),
+            Cell::new(format!("{:?}", order.side)),
+            Cell::new(format!("{:.6}", order.amount)),
+            Cell::new(format!("{:.4}", order.price)),
+            Cell::new(&order.venue),
+            Cell::new(order.time_in_force.name()),
+            Cell::new(order.submitted_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+fn print_positions(positions: &[OpenPosition]) {
+    if positions.is_empty() {
+        println!("No open positions");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Agent", "Symbol", "Net Size", "Avg Price", "Unrealized PnL"]);
+    for position in positions {
+        let pnl_color = if position.unrealized_pnl >= 0.0 { Color::Green } else { Color::Red };
+        table.add_row(vec![
+            Cell::new(&position.agent_id),
+            Cell::new(&position.symbol),
+            Cell::new(format!("{:.6}", position.net_size)),
+            Cell::new(format!("{:.4}", position.average_price)),
+            Cell::new(format!("{:.2}", position.unrealized_pnl)).fg(pnl_color),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+/// Result reported when there was nothing to act on
+fn empty_batch_result() -> BatchResult {
+    BatchResult { batch_id: String::new(), items: Vec::new(), succeeded: 0, failed: 0 }
+}
+
+fn print_batch_result(verb: &str, result: &BatchResult, output: OutputFormat) -> Result<()> {
+    if output.is_json() {
+        print_json(result)?;
+        if result.failed > 0 {
+            bail!("{} of {} operations failed", result.failed, result.items.len());
+        }
+        return Ok(());
+    }
+
+    for item in result.items.iter().filter(|item| !item.success) {
+        println!(
+            "{} {}: {}",
+            "✗".red(),
+            item.order_id,
+            item.error.as_deref().unwrap_or("unknown error")
+        );
+    }
+
+    println!("{} {} {} ({} failed)", "✓".green(), verb, result.succeeded, result.failed);
+
+    if result.failed > 0 {
+        bail!("{} of {} operations failed", result.failed, result.items.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+
+/// How read commands render their results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables
+    #[default]
+    Table,
+    /// Pretty-printed JSON on stdout, suitable for piping into jq
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_json(&self) -> bool {
+        matches!(self, OutputFormat::Json)
+    }
+}
+
+/// Global `--output` flag, accepted before or after any subcommand. Commands
+/// that write files take `--output-file` instead so the two never clash.
+#[derive(Debug, Args, Clone, Copy)]
+pub struct OutputArgs {
+    /// Output format
+    #[arg(long = "output", value_enum, global = true, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+/// Print a value as pretty JSON
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{CommandFactory, Parser, Subcommand};
+    use std::path::PathBuf;
+
+    #[derive(Debug, Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        output: OutputArgs,
+
+        #[command(subcommand)]
+        command: TestCommand,
+    }
+
+    #[derive(Debug, Subcommand)]
+    enum TestCommand {
+        List,
+        Export {
+            #[arg(long = "output-file")]
+            output: Option<PathBuf>,
+        },
+    }
+
+    #[test]
+    fn test_output_flag_is_global() {
+        TestCli::command().debug_assert();
+
+        let cli = TestCli::try_parse_from(["noderr_cli", "list"]).unwrap();
+        assert_eq!(cli.output.format, OutputFormat::Table);
+
+        let cli = TestCli::try_parse_from(["noderr_cli", "--output", "json", "list"]).unwrap();
+        assert!(cli.output.format.is_json());
+
+        let cli = TestCli::try_parse_from(["noderr_cli", "export", "--output", "json", "--output-file", "a.json"]).unwrap();
+        assert!(cli.output.format.is_json());
+        match cli.command {
+            TestCommand::Export { output } => assert_eq!(output, Some(PathBuf::from("a.json"))),
+            other => panic!("unexpected command {:?}", other),
+        }
+
+        assert!(TestCli::try_parse_from(["noderr_cli", "list", "--output", "yaml"]).is_err());
+    }
+}
@@ -146,7 +146,7 @@ pub struct ExportArgs {
     pub decision_id: String,
     
     /// Output file path for the exported reasoning chain
-    #[clap(short, long)]
+    #[clap(short, long = "output-file")]
     pub output: Option<PathBuf>,
     
     /// Include detailed explanations in natural language
@@ -169,7 +169,7 @@ pub struct VisualizeArgs {
     pub format: String,
     
     /// Output file for the visualization
-    #[clap(short, long)]
+    #[clap(short, long = "output-file")]
     pub output: Option<PathBuf>,
     
     /// Include detailed component explanations
@@ -222,7 +222,7 @@ pub struct ReasonsArgs {
     pub export: String,
     
     /// Output file path for export
-    #[clap(long)]
+    #[clap(long = "output-file")]
     pub output: Option<PathBuf>,
 }
 
@@ -1219,7 +1219,7 @@ async fn reasons_command(args: &ReasonsArgs) -> CommandResult {
             }
             println!("Exported reasoning chains to {}", path.display());
         } else {
-            println!("{}", "Error: --output must be specified when using --export".bright_red());
+            println!("{}", "Error: --output-file must be specified when using --export".bright_red());
         }
     }
     
@@ -0,0 +1,166 @@
+use anyhow::{anyhow, bail, Result};
+use chrono::{Duration, Utc};
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use comfy_table::{Cell, Table};
+use serde::Deserialize;
+use serde_json::json;
+
+use noderr_core::risk_override::{RiskLimit, RiskOverride, RiskOverrideRequest};
+
+use super::api_client::{ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct RiskCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: RiskSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RiskSubcommand {
+    /// Temporarily raise or lower a risk limit
+    Override {
+        /// Limit to change (e.g. max_leverage, max_position_size_pct)
+        limit: String,
+
+        /// Temporary value
+        value: f64,
+
+        /// How long the override lasts (e.g. 30m, 2h, 1d)
+        #[arg(long)]
+        expires_in: String,
+
+        /// Why the override is needed; recorded in the audit vault
+        #[arg(long)]
+        justification: String,
+    },
+
+    /// List active overrides
+    Overrides,
+
+    /// Revert an override before it expires
+    Revoke {
+        /// Override ID
+        override_id: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct OverridesResponse {
+    overrides: Vec<RiskOverride>,
+}
+
+pub async fn run_risk_command(cmd: &RiskCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    match &cmd.subcommand {
+        RiskSubcommand::Override { limit, value, expires_in, justification } => {
+            let request = RiskOverrideRequest {
+                limit: limit.parse()?,
+                value: *value,
+                expires_at: Utc::now() + parse_duration(expires_in)?,
+                justification: justification.clone(),
+                // Filled in by the server from the authenticated operator
+                operator: String::new(),
+            };
+
+            let applied: RiskOverride = client.post("/risk/overrides", &request).await?;
+            println!(
+                "{} {} {} → {} until {}",
+                "✓".green(),
+                applied.limit.name().bold(),
+                applied.original_value,
+                applied.override_value.to_string().yellow(),
+                applied.expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+            );
+            println!("Override ID: {}", applied.override_id);
+            println!("Audit entry: {}", applied.audit_entry_id);
+        }
+
+        RiskSubcommand::Overrides => {
+            let response: OverridesResponse = client.get("/risk/overrides", &[]).await?;
+            if output.is_json() {
+                print_json(&response.overrides)?;
+            } else {
+                print_overrides(&response.overrides);
+            }
+        }
+
+        RiskSubcommand::Revoke { override_id } => {
+            let path = format!("/risk/overrides/{}/revoke", override_id);
+            let revoked: RiskOverride = client.post(&path, &json!({})).await?;
+            println!(
+                "{} {} restored to {}",
+                "✓".green(),
+                revoked.limit.name().bold(),
+                revoked.original_value
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a duration such as "90s", "30m", "2h" or "1d"
+fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| anyhow!("Invalid duration '{}'", value))?;
+
+    let duration = match unit {
+        "s" => Duration::seconds(amount),
+        "m" | "" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        _ => bail!("Invalid duration unit '{}'; use s, m, h or d", unit),
+    };
+
+    if duration <= Duration::zero() {
+        bail!("Duration must be positive");
+    }
+    Ok(duration)
+}
+
+fn print_overrides(overrides: &[RiskOverride]) {
+    if overrides.is_empty() {
+        println!("No active risk overrides");
+        println!("Overridable limits: {}", RiskLimit::ALL.iter().map(|l| l.name()).collect::<Vec<_>>().join(", "));
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["ID", "Limit", "Original", "Override", "Expires", "Operator", "Justification"]);
+    for o in overrides {
+        table.add_row(vec![
+            Cell::new(&o.override_id),
+            Cell::new(o.limit.name()),
+            Cell::new(o.original_value),
+            Cell::new(o.override_value),
+            Cell::new(o.expires_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+            Cell::new(&o.operator),
+            Cell::new(&o.justification),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90s").unwrap(), Duration::seconds(90));
+        assert_eq!(parse_duration("30m").unwrap(), Duration::minutes(30));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::hours(2));
+        assert_eq!(parse_duration("15").unwrap(), Duration::minutes(15));
+        assert!(parse_duration("0h").is_err());
+        assert!(parse_duration("2w").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use comfy_table::Table;
+use std::path::PathBuf;
+
+use noderr_core::state_bundle::{ImportReport, SignedStateBundle};
+
+use super::api_client::{confirm, ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct StateCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: StateSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StateSubcommand {
+    /// Export strategy configs, trust histories, allocations and governance rules as a signed bundle
+    Export {
+        /// File to write the bundle to
+        #[arg(long, short)]
+        out: PathBuf,
+    },
+
+    /// Verify a signed bundle and apply it to the node
+    Import {
+        /// Bundle file written by `state export`
+        file: PathBuf,
+
+        /// Report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+    },
+}
+
+pub async fn run_state_command(cmd: &StateCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    match &cmd.subcommand {
+        StateSubcommand::Export { out } => {
+            let signed: SignedStateBundle = client.get("/state/export", &[]).await?;
+            std::fs::write(out, serde_json::to_vec_pretty(&signed)?)
+                .with_context(|| format!("Failed to write {}", out.display()))?;
+            println!("{} Wrote state bundle signed by {} to {}", "✓".green(), signed.key_id, out.display());
+        }
+
+        StateSubcommand::Import { file, dry_run, yes } => {
+            let bytes = std::fs::read(file).with_context(|| format!("Failed to read {}", file.display()))?;
+            let signed: SignedStateBundle = serde_json::from_slice(&bytes)
+                .with_context(|| format!("{} is not a state bundle", file.display()))?;
+
+            if !dry_run && !yes && !confirm(&format!("Import state bundle {} into this node?", file.display()))? {
+                println!("Aborted");
+                return Ok(());
+            }
+
+            let path = format!("/state/import?dry_run={}", dry_run);
+            let report: ImportReport = client.post(&path, &signed).await?;
+            if output.is_json() {
+                print_json(&report)?;
+            } else {
+                print_report(&report);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_report(report: &ImportReport) {
+    let verb = if report.dry_run { "Would import" } else { "Imported" };
+    println!(
+        "{} {} state from {}{}",
+        "✓".green(),
+        verb,
+        report.source_environment,
+        report.exported_at.map(|at| format!(" exported {}", at.format("%Y-%m-%d %H:%M:%S"))).unwrap_or_default()
+    );
+
+    let mut table = Table::new();
+    table.set_header(vec!["Strategy", "New trust entries"]);
+    for (strategy_id, count) in &report.trust_entries {
+        table.add_row(vec![strategy_id.clone(), count.to_string()]);
+    }
+    if !report.trust_entries.is_empty() {
+        println!("{}", table);
+    }
+
+    println!("Governance rules: {}", report.governance_rules);
+    println!("Allocation: {}", if report.allocation_restored { "restored" } else { "unchanged" });
+    if !report.missing_strategies.is_empty() {
+        println!(
+            "{} Strategies not deployed on this node: {}",
+            "!".yellow(),
+            report.missing_strategies.join(", ")
+        );
+    }
+}
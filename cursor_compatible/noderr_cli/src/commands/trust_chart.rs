@@ -1,14 +1,22 @@
-use noderr_core::trust_score_engine::TrustScoreEngine;
+use noderr_core::simulation::trust_decay_simulator::{
+    forecast_trust_score, DecaySimulationParams, RecoveryProfile, TrustForecastParams, TrustScoreForecast,
+};
+use noderr_core::trust_decay_service::TrustDecayConfig;
+use noderr_core::trust_score_engine::{TrustScoreEngine, TrustScoreHistory, TrustScoreSnapshot};
 use anyhow::{Result, Context};
 use std::sync::Arc;
 use chrono::{DateTime, Duration, Utc};
 
+use super::output::{print_json, OutputFormat};
+
 /// Chart config for customizing the chart display
 pub struct ChartConfig {
     pub width: usize,
     pub height: usize,
     pub show_points: bool,
     pub show_recovery: bool,
+    /// Days to forecast past the last observation; 0 disables the forecast
+    pub forecast_days: usize,
 }
 
 impl Default for ChartConfig {
@@ -18,6 +26,7 @@ impl Default for ChartConfig {
             height: 15,
             show_points: true,
             show_recovery: true,
+            forecast_days: 14,
         }
     }
 }
@@ -27,11 +36,29 @@ pub async fn run_trust_chart(
     engine: Arc<dyn TrustScoreEngine>,
     strategy_id: &str,
     days: u32,
+    decay_config: &TrustDecayConfig,
     config: ChartConfig,
+    output: OutputFormat,
 ) -> Result<()> {
-    // Get trust score history
-    let history = engine.get_trust_score_history(strategy_id, Some(days as usize)).await?;
-    let snapshots = &history.snapshots;
+    // Get trust score history for the requested window
+    let from = Utc::now() - Duration::days(days as i64);
+    let history = engine.get_trust_history_range(strategy_id, Some(from), None).await
+        .context("Failed to fetch trust history")?;
+    let snapshots: Vec<TrustScoreSnapshot> = history.entries.iter().map(TrustScoreSnapshot::from).collect();
+    
+    // JSON carries the points and forecast behind the chart rather than the drawing
+    if output.is_json() {
+        let forecast = (config.forecast_days > 0)
+            .then(|| project_forecast(&history, decay_config, config.forecast_days))
+            .flatten()
+            .map(|(_, forecast)| forecast);
+        return print_json(&serde_json::json!({
+            "strategy_id": strategy_id,
+            "days": days,
+            "snapshots": snapshots,
+            "forecast": forecast,
+        }));
+    }
     
     if snapshots.is_empty() {
         println!("No history data available for strategy: {}", strategy_id);
@@ -42,7 +69,7 @@ pub async fn run_trust_chart(
     let mut max_score = 0.0;
     let mut min_score = 1.0;
     
-    for snapshot in snapshots {
+    for snapshot in &snapshots {
         max_score = max_score.max(snapshot.score);
         min_score = min_score.min(snapshot.score);
     }
@@ -65,7 +92,7 @@ pub async fn run_trust_chart(
     println!();
     
     // Generate chart
-    generate_ascii_chart(snapshots, min_y, max_y, &config);
+    generate_ascii_chart(&snapshots, min_y, max_y, &config);
     
     // Show legend
     println!("\nLegend:");
@@ -92,28 +119,88 @@ pub async fn run_trust_chart(
             let avg_daily_decay = score_change.abs() / days as f64;
             
             // Estimate days until critical levels
-            if let Ok(config) = engine.get_config().await {
-                let warning_threshold = config.weights.warning_threshold;
-                let critical_threshold = config.weights.critical_threshold;
-                
-                if last_score > warning_threshold {
-                    let days_to_warning = (last_score - warning_threshold) / avg_daily_decay;
-                    println!("  Est. days to warning level ({:.2}): {:.0}", 
-                        warning_threshold, days_to_warning);
-                }
-                
-                if last_score > critical_threshold {
-                    let days_to_critical = (last_score - critical_threshold) / avg_daily_decay;
-                    println!("  Est. days to critical level ({:.2}): {:.0}", 
-                        critical_threshold, days_to_critical);
-                }
+            let warning_threshold = decay_config.warning_threshold;
+            let critical_threshold = decay_config.critical_threshold;
+            
+            if last_score > warning_threshold {
+                let days_to_warning = (last_score - warning_threshold) / avg_daily_decay;
+                println!("  Est. days to warning level ({:.2}): {:.0}", 
+                    warning_threshold, days_to_warning);
+            }
+            
+            if last_score > critical_threshold {
+                let days_to_critical = (last_score - critical_threshold) / avg_daily_decay;
+                println!("  Est. days to critical level ({:.2}): {:.0}", 
+                    critical_threshold, days_to_critical);
             }
         }
     }
     
+    if config.forecast_days > 0 {
+        print_forecast(&history, decay_config, config.forecast_days);
+    }
+    
     Ok(())
 }
 
+/// Project the score distribution by combining the decay curve with the
+/// recovery frequency seen in `history`; returns the decay factor used
+fn project_forecast(
+    history: &TrustScoreHistory,
+    decay_config: &TrustDecayConfig,
+    forecast_days: usize,
+) -> Option<(f64, TrustScoreForecast)> {
+    let last = history.entries.last()?;
+    let decay_factor = decay_config
+        .strategy_decay_factors
+        .get(&history.strategy_id)
+        .copied()
+        .unwrap_or(decay_config.default_decay_factor_per_day);
+    let recovery = history.recovery_profile(RecoveryProfile::DEFAULT_MIN_BOOST);
+    
+    let forecast = forecast_trust_score(TrustForecastParams {
+        decay: DecaySimulationParams {
+            strategy_id: history.strategy_id.clone(),
+            initial_score: last.score,
+            decay_factor_per_day: decay_factor,
+            jitter: 0.0,
+            days: forecast_days,
+        },
+        recovery,
+        paths: 1_000,
+        thresholds: vec![decay_config.warning_threshold, decay_config.critical_threshold],
+        seed: None,
+    });
+    Some((decay_factor, forecast))
+}
+
+/// Print the projected score distribution for `history`
+fn print_forecast(history: &TrustScoreHistory, decay_config: &TrustDecayConfig, forecast_days: usize) {
+    let Some((decay_factor, forecast)) = project_forecast(history, decay_config, forecast_days) else {
+        return;
+    };
+    let recovery = forecast.recovery;
+    
+    println!("\nForecast (next {} days):", forecast_days);
+    println!("  Decay factor: {:.3}/day, recoveries: {} in {} days (avg boost {:.3})",
+        decay_factor, recovery.events, recovery.observed_days, recovery.mean_boost);
+    
+    let medians: Vec<f64> = forecast.points.iter().map(|p| p.p50).collect();
+    println!("  Median path: {}", generate_sparkline(&medians, medians.len().min(40)));
+    
+    // Weekly rows keep the table short for long horizons
+    let step = if forecast_days > 14 { 7 } else { 1 };
+    for point in forecast.points.iter().filter(|p| p.day % step == 0 || p.day == forecast_days) {
+        println!("  {} (+{:>3}d)  p10 {:.4}  p50 {:.4}  p90 {:.4}",
+            point.timestamp.format("%Y-%m-%d"), point.day, point.p10, point.p50, point.p90);
+    }
+    
+    for breach in &forecast.breaches {
+        println!("  P(score < {:.2} in {} days): {:.1}%",
+            breach.threshold, forecast_days, breach.probability * 100.0);
+    }
+}
+
 /// Generate a simple ASCII chart from trust score snapshots
 fn generate_ascii_chart(
     snapshots: &[noderr_core::trust_score_engine::TrustScoreSnapshot],
@@ -363,6 +450,7 @@ mod tests {
                 height: 10,
                 show_points: true,
                 show_recovery: true,
+                forecast_days: 0,
             };
             
             // Redirecting stdout to avoid cluttering test output
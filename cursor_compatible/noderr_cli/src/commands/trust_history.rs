@@ -1,16 +1,67 @@
 use noderr_core::trust_score_engine::TrustScoreEngine;
-use anyhow::{Result, Context};
-use chrono::Utc;
+use anyhow::{anyhow, Result, Context};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use comfy_table::{Table, Cell, Color};
 use std::sync::Arc;
 
+use super::output::{print_json, OutputFormat};
+
+/// Date range for history queries; open ends are unbounded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryRange {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl HistoryRange {
+    /// Build a range from `--from`/`--to`/`--days` flags; `--days` is ignored when `--from` is set
+    pub fn from_args(from: Option<&str>, to: Option<&str>, days: Option<u32>) -> Result<Self> {
+        let to = to.map(|s| parse_date_bound(s, true)).transpose()?;
+        let from = match (from, days) {
+            (Some(s), _) => Some(parse_date_bound(s, false)?),
+            (None, Some(days)) => Some(to.unwrap_or_else(Utc::now) - Duration::days(days as i64)),
+            (None, None) => None,
+        };
+
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(anyhow!("--from ({}) is after --to ({})", from, to));
+            }
+        }
+        Ok(Self { from, to })
+    }
+}
+
+/// Parse an RFC 3339 timestamp or a `YYYY-MM-DD` date; dates cover the whole day
+fn parse_date_bound(value: &str, end_of_day: bool) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| anyhow!("Unrecognised date '{}': expected YYYY-MM-DD or RFC 3339", value))?;
+    let time = if end_of_day {
+        date.and_hms_milli_opt(23, 59, 59, 999)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    Ok(DateTime::from_utc(time.expect("valid time of day"), Utc))
+}
+
 pub async fn run_trust_history(
     engine: Arc<dyn TrustScoreEngine>,
     strategy_id: &str,
+    range: HistoryRange,
+    limit: usize,
+    output: OutputFormat,
 ) -> Result<()> {
-    let history = engine.get_trust_history(strategy_id).await
+    let history = engine.get_trust_history_range(strategy_id, range.from, range.to).await
         .context("Failed to fetch trust history")?;
 
+    if output.is_json() {
+        return print_json(&history);
+    }
+
     if history.entries.is_empty() {
         println!("No history found for strategy {strategy_id}");
         return Ok(());
@@ -19,7 +70,7 @@ pub async fn run_trust_history(
     let mut table = Table::new();
     table.set_header(vec!["Timestamp", "Score", "Drawdown", "Sharpe", "Failures"]);
 
-    for entry in history.entries.iter().rev().take(20) {
+    for entry in history.entries.iter().rev().take(limit) {
         let ts = entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string();
         let score_color = if entry.score < 0.3 {
             Color::Red
@@ -39,5 +90,26 @@ pub async fn run_trust_history(
     }
 
     println!("{table}");
+    if history.entries.len() > limit {
+        println!("Showing newest {} of {} entries", limit, history.entries.len());
+    }
     Ok(())
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_range_from_args() {
+        let range = HistoryRange::from_args(Some("2025-03-01"), Some("2025-03-02"), Some(7)).unwrap();
+        assert_eq!(range.from.unwrap().to_rfc3339(), "2025-03-01T00:00:00+00:00");
+        assert_eq!(range.to.unwrap().to_rfc3339(), "2025-03-02T23:59:59.999+00:00");
+
+        let range = HistoryRange::from_args(None, Some("2025-03-10T00:00:00Z"), Some(3)).unwrap();
+        assert_eq!(range.from.unwrap().to_rfc3339(), "2025-03-07T00:00:00+00:00");
+
+        assert!(HistoryRange::from_args(Some("2025-03-05"), Some("2025-03-01"), None).is_err());
+        assert!(HistoryRange::from_args(Some("yesterday"), None, None).is_err());
+    }
+}
@@ -66,7 +66,7 @@ pub struct AnalyzeArgs {
     pub format: String,
     
     /// Output file path
-    #[arg(long)]
+    #[arg(long = "output-file")]
     pub output: Option<String>,
     
     #[arg(long, help = "Filter by agent ID")]
@@ -154,7 +154,7 @@ pub struct AttributionArgs {
     pub format: String,
     
     /// Output file path
-    #[arg(long)]
+    #[arg(long = "output-file")]
     pub output: Option<String>,
 }
 
@@ -173,7 +173,7 @@ pub struct ReportArgs {
     pub format: String,
     
     /// Output file path
-    #[arg(long)]
+    #[arg(long = "output-file")]
     pub output: Option<String>,
     
     /// Store report in Redis
@@ -1568,7 +1568,7 @@ pub async fn generate_regression_report(args: &ReportArgs, redis_client: &RedisC
                 std::fs::write(output_path, full_report)?;
                 println!("\nFull report saved to: {}", output_path);
             } else {
-                println!("\nUse '--format markdown --output <file>' for a complete detailed report.");
+                println!("\nUse '--format markdown --output-file <file>' for a complete detailed report.");
             }
         }
     }
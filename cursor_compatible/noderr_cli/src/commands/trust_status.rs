@@ -3,15 +3,60 @@ use comfy_table::{Table, Cell, Color};
 use noderr_core::trust_decay_service::{TrustDecayService, TrustDecayConfig, StrategyActivityStatus};
 use noderr_core::trust_score_engine::TrustScoreEngine;
 use noderr_core::strategy_storage::StrategyStorage;
-use std::collections::HashMap;
+use serde::Serialize;
 use std::sync::Arc;
-use chrono::Duration;
+use chrono::{DateTime, Duration, Utc};
+
+use super::output::{print_json, OutputFormat};
+
+/// Where a strategy is in the decay cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecayState {
+    Active,
+    Inactive,
+    Decaying,
+}
+
+/// Decay standing of one strategy
+#[derive(Debug, Clone, Serialize)]
+pub struct StrategyDecayStatus {
+    pub strategy_id: String,
+    pub trust_score: f64,
+    pub status: DecayState,
+    pub last_activity: Option<DateTime<Utc>>,
+    pub decay_started: Option<DateTime<Utc>>,
+    pub decay_factor: f64,
+}
+
+impl StrategyDecayStatus {
+    fn new(strategy_id: &str, trust_score: f64, activity: &StrategyActivityStatus, decay_factor: f64) -> Self {
+        let (status, last_activity, decay_started) = match activity {
+            StrategyActivityStatus::Active => (DecayState::Active, None, None),
+            StrategyActivityStatus::RecentlyInactive { last_activity } => {
+                (DecayState::Inactive, Some(*last_activity), None)
+            }
+            StrategyActivityStatus::Inactive { last_activity, decay_started } => {
+                (DecayState::Decaying, Some(*last_activity), Some(*decay_started))
+            }
+        };
+        Self {
+            strategy_id: strategy_id.to_string(),
+            trust_score,
+            status,
+            last_activity,
+            decay_started,
+            decay_factor,
+        }
+    }
+}
 
 /// Display the current status of trust decay for all strategies
 pub async fn run_trust_status(
     decay_service: Arc<dyn TrustDecayService>,
     engine: Arc<dyn TrustScoreEngine>,
     storage: Arc<dyn StrategyStorage>,
+    output: OutputFormat,
 ) -> Result<()> {
     // Get all strategy IDs
     let strategy_ids = storage.get_all_strategy_ids().await
@@ -20,6 +65,41 @@ pub async fn run_trust_status(
     // Get current decay configuration
     let config = decay_service.get_config();
     
+    let mut statuses = Vec::new();
+    for strategy_id in strategy_ids {
+        // Get the activity status
+        let activity = decay_service.get_strategy_activity_status(&strategy_id).await?;
+        
+        // Get the current trust score
+        let trust_score = match engine.get_trust_score(&strategy_id).await {
+            Ok(score) => score,
+            Err(_) => {
+                // Strategy might not have a trust score yet, skip it
+                continue;
+            }
+        };
+        
+        // Determine decay factor for this strategy
+        let decay_factor = config.strategy_decay_factors
+            .get(&strategy_id)
+            .copied()
+            .unwrap_or(config.default_decay_factor_per_day);
+        
+        statuses.push(StrategyDecayStatus::new(&strategy_id, trust_score.score, &activity, decay_factor));
+    }
+    
+    if output.is_json() {
+        return print_json(&serde_json::json!({
+            "config": config,
+            "strategies": statuses,
+        }));
+    }
+    
+    print_trust_status(&config, &statuses);
+    Ok(())
+}
+
+fn print_trust_status(config: &TrustDecayConfig, statuses: &[StrategyDecayStatus]) {
     // Display current configuration
     println!("Trust Decay Configuration:");
     println!("-------------------------");
@@ -54,83 +134,54 @@ pub async fn run_trust_status(
         "Decay Factor",
     ]);
     
-    // Fetch and display strategy information
     let mut active_count = 0;
     let mut inactive_count = 0;
     let mut decaying_count = 0;
+    let ago = |ts: Option<DateTime<Utc>>| match ts {
+        Some(ts) => format!("{} ago", humanize_duration(ts.signed_duration_since(Utc::now()).abs())),
+        None => "N/A".to_string(),
+    };
     
-    for strategy_id in strategy_ids {
-        // Get the activity status
-        let status = decay_service.get_strategy_activity_status(&strategy_id).await?;
-        
-        // Get the current trust score
-        let trust_score = match engine.get_trust_score(&strategy_id).await {
-            Ok(score) => score,
-            Err(_) => {
-                // Strategy might not have a trust score yet, skip it
-                continue;
-            }
-        };
-        
-        // Determine decay factor for this strategy
-        let decay_factor = config.strategy_decay_factors
-            .get(&strategy_id)
-            .copied()
-            .unwrap_or(config.default_decay_factor_per_day);
-        
+    for status in statuses {
         // Format the status information
-        let (status_text, last_activity, decay_started) = match &status {
-            StrategyActivityStatus::Active => {
+        let (status_text, last_activity, decay_started) = match status.status {
+            DecayState::Active => {
                 active_count += 1;
-                (
-                    Cell::new("Active").fg(Color::Green),
-                    Cell::new("Now"),
-                    Cell::new("N/A"),
-                )
+                (Cell::new("Active").fg(Color::Green), Cell::new("Now"), Cell::new("N/A"))
             },
-            StrategyActivityStatus::RecentlyInactive { last_activity } => {
+            DecayState::Inactive => {
                 inactive_count += 1;
-                let ago = humanize_duration(last_activity.signed_duration_since(chrono::Utc::now()).abs());
-                (
-                    Cell::new("Inactive").fg(Color::Yellow),
-                    Cell::new(format!("{} ago", ago)),
-                    Cell::new("Pending"),
-                )
+                (Cell::new("Inactive").fg(Color::Yellow), Cell::new(ago(status.last_activity)), Cell::new("Pending"))
             },
-            StrategyActivityStatus::Inactive { last_activity, decay_started } => {
+            DecayState::Decaying => {
                 decaying_count += 1;
-                let last_ago = humanize_duration(last_activity.signed_duration_since(chrono::Utc::now()).abs());
-                let decay_ago = humanize_duration(decay_started.signed_duration_since(chrono::Utc::now()).abs());
                 (
                     Cell::new("Decaying").fg(Color::Red),
-                    Cell::new(format!("{} ago", last_ago)),
-                    Cell::new(format!("{} ago", decay_ago)),
+                    Cell::new(ago(status.last_activity)),
+                    Cell::new(ago(status.decay_started)),
                 )
             }
         };
         
         // Add the row to the table
-        let score_color = if trust_score.score < config.critical_threshold {
+        let score_color = if status.trust_score < config.critical_threshold {
             Color::Red
-        } else if trust_score.score < config.warning_threshold {
+        } else if status.trust_score < config.warning_threshold {
             Color::Yellow
         } else {
             Color::Green
         };
         
         table.add_row(vec![
-            Cell::new(&strategy_id),
-            Cell::new(format!("{:.4}", trust_score.score)).fg(score_color),
+            Cell::new(&status.strategy_id),
+            Cell::new(format!("{:.4}", status.trust_score)).fg(score_color),
             status_text,
             last_activity,
             decay_started,
-            Cell::new(format!("{:.4}", decay_factor)),
+            Cell::new(format!("{:.4}", status.decay_factor)),
         ]);
     }
     
-    // Sort the table by status (decaying first, then inactive, then active)
-    // and then by trust score (ascending)
-    
     // Display the table
     println!("Strategy Trust Decay Status:");
     println!("---------------------------");
@@ -143,8 +194,6 @@ pub async fn run_trust_status(
     println!("  Inactive strategies: {}", inactive_count);
     println!("  Decaying strategies: {}", decaying_count);
     println!("  Total: {}", active_count + inactive_count + decaying_count);
-    
-    Ok(())
 }
 
 /// Manually apply decay to a specific strategy
@@ -266,4 +315,26 @@ pub fn parse_custom_factor(s: &str) -> Result<(String, f64)> {
     }
     
     Ok((strategy_id, factor))
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decay_status_serializes_state() {
+        let last_activity = Utc::now() - Duration::hours(30);
+        let decay_started = Utc::now() - Duration::hours(6);
+        let activity = StrategyActivityStatus::Inactive { last_activity, decay_started };
+
+        let status = StrategyDecayStatus::new("momentum", 0.72, &activity, 0.98);
+        assert_eq!(status.status, DecayState::Decaying);
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["status"], "decaying");
+        assert_eq!(json["decay_started"], serde_json::json!(decay_started));
+
+        let status = StrategyDecayStatus::new("momentum", 0.72, &StrategyActivityStatus::Active, 0.98);
+        assert_eq!(serde_json::to_value(&status).unwrap()["last_activity"], serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use comfy_table::Table;
+use serde::Deserialize;
+use serde_json::json;
+
+use noderr_core::universe::SymbolListing;
+use noderr_core::warmup::WarmupProgress;
+
+use super::api_client::{ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct UniverseCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: UniverseSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UniverseSubcommand {
+    /// Show listed symbols
+    List,
+
+    /// List a symbol: provision its order book, limits and market data
+    Add {
+        /// Symbol in BASE/QUOTE form (e.g. SOL/USDT)
+        symbol: String,
+
+        /// Risk template for the symbol's limits; the configured default when omitted
+        #[arg(long)]
+        template: Option<String>,
+    },
+
+    /// Delist a symbol; refused while it has open positions or orders
+    Remove {
+        /// Symbol in BASE/QUOTE form
+        symbol: String,
+    },
+
+    /// Show warm-up progress of symbols not yet eligible for signals
+    Warmup {
+        /// Only this symbol, in BASE/QUOTE form
+        symbol: Option<String>,
+    },
+}
+
+/// Warm-up progress response
+#[derive(Debug, Deserialize)]
+struct WarmupResponse {
+    enabled: bool,
+    symbols: Vec<WarmupProgress>,
+}
+
+pub async fn run_universe_command(cmd: &UniverseCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    let listings: Vec<SymbolListing> = match &cmd.subcommand {
+        UniverseSubcommand::List => client.get("/universe", &[]).await?,
+
+        UniverseSubcommand::Add { symbol, template } => {
+            let listing: SymbolListing = client
+                .post("/universe/list", &json!({ "symbol": symbol, "template": template }))
+                .await?;
+            println!("{} Listed {} with template {}", "✓".green(), listing.symbol, listing.template);
+            vec![listing]
+        }
+
+        UniverseSubcommand::Remove { symbol } => {
+            let listing: SymbolListing = client.post("/universe/delist", &json!({ "symbol": symbol })).await?;
+            println!("{} Delisted {}", "✓".green(), listing.symbol);
+            return Ok(());
+        }
+
+        UniverseSubcommand::Warmup { symbol } => {
+            let query: Vec<(&str, String)> = symbol.iter().map(|s| ("symbol", s.clone())).collect();
+            let response: WarmupResponse = client.get("/warmup", &query).await?;
+            if output.is_json() {
+                print_json(&response.symbols)?;
+            } else {
+                print_warmup(&response);
+            }
+            return Ok(());
+        }
+    };
+
+    if output.is_json() {
+        print_json(&listings)?;
+    } else {
+        print_listings(&listings);
+    }
+    Ok(())
+}
+
+fn print_listings(listings: &[SymbolListing]) {
+    if listings.is_empty() {
+        println!("No symbols listed");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Symbol", "Venue", "Template", "Max position", "Listed"]);
+    for listing in listings {
+        table.add_row(vec![
+            listing.symbol.clone(),
+            listing.venue.clone().unwrap_or_else(|| "-".to_string()),
+            listing.template.clone(),
+            format!("{}", listing.limits.max_position),
+            listing.listed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+fn print_warmup(response: &WarmupResponse) {
+    if !response.enabled {
+        println!("Warm-up gating is disabled; every symbol is eligible");
+        return;
+    }
+    if response.symbols.is_empty() {
+        println!("No symbols observed yet");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Symbol", "Candles", "Liquidity", "Regime", "Progress", "Status"]);
+    for progress in &response.symbols {
+        let regime = match (&progress.regime, progress.regime_required) {
+            (Some(regime), _) => regime.to_string(),
+            (None, true) => "pending".to_string(),
+            (None, false) => "-".to_string(),
+        };
+        let status = match progress.ready_at {
+            Some(ready_at) => format!("ready since {}", ready_at.format("%Y-%m-%d %H:%M:%S")),
+            None => "warming up".to_string(),
+        };
+        table.add_row(vec![
+            progress.symbol.clone(),
+            format!("{}/{}", progress.candles, progress.required_candles),
+            format!("{}/{}", progress.liquidity_snapshots, progress.required_liquidity_snapshots),
+            regime,
+            format!("{:.0}%", progress.completion() * 100.0),
+            status,
+        ]);
+    }
+
+    println!("{}", table);
+}
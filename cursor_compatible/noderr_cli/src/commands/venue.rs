@@ -0,0 +1,146 @@
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use comfy_table::{Cell, Color, Table};
+use serde::Deserialize;
+use serde_json::json;
+
+use noderr_core::order_router::DrainReport;
+use noderr_core::venue_control::{VenueMode, VenueModeState};
+
+use super::api_client::{ApiClient, ApiOptions};
+use super::output::{print_json, OutputFormat};
+
+#[derive(Debug, Args)]
+pub struct VenueCommand {
+    #[command(flatten)]
+    pub api: ApiOptions,
+
+    #[command(subcommand)]
+    pub subcommand: VenueSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum VenueSubcommand {
+    /// List venues with an operator-set mode
+    List,
+
+    /// Resume routing new orders to a venue
+    Enable(VenueModeArgs),
+
+    /// Stop routing new orders to a venue; resting orders are left alone
+    Disable(VenueModeArgs),
+
+    /// Stop routing new orders, cancel resting orders and wait for the rest to fill
+    Drain {
+        #[command(flatten)]
+        args: VenueModeArgs,
+
+        /// Seconds to wait for resting orders before giving up
+        #[arg(long, default_value = "30")]
+        timeout: u64,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct VenueModeArgs {
+    /// Venue ID
+    pub venue: String,
+
+    /// Reason for the change (e.g. maintenance window)
+    #[arg(long)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VenuesResponse {
+    venues: Vec<VenueModeState>,
+}
+
+pub async fn run_venue_command(cmd: &VenueCommand, output: OutputFormat) -> Result<()> {
+    let client = ApiClient::new(&cmd.api)?;
+
+    match &cmd.subcommand {
+        VenueSubcommand::List => {
+            let response: VenuesResponse = client.get("/venues", &[]).await?;
+            if output.is_json() {
+                print_json(&response.venues)?;
+            } else {
+                print_venues(&response.venues);
+            }
+        }
+
+        VenueSubcommand::Enable(args) => set_mode(&client, "enable", args).await?,
+
+        VenueSubcommand::Disable(args) => set_mode(&client, "disable", args).await?,
+
+        VenueSubcommand::Drain { args, timeout } => {
+            println!("{} Draining {} (up to {}s)...", "→".blue(), args.venue, timeout);
+
+            let report: DrainReport = client
+                .post(
+                    &format!("/venues/{}/drain", args.venue),
+                    &json!({ "reason": args.reason, "timeout_secs": timeout }),
+                )
+                .await?;
+
+            println!("Cancelled {} resting orders", report.cancelled.len());
+            if report.completed {
+                println!("{} {} drained and disabled", "✓".green(), report.venue);
+            } else {
+                for order_id in &report.remaining {
+                    println!("  {} still resting", order_id.yellow());
+                }
+                bail!("{} orders still resting on {}; venue left draining", report.remaining.len(), report.venue);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn set_mode(client: &ApiClient, action: &str, args: &VenueModeArgs) -> Result<()> {
+    let state: VenueModeState = client
+        .post(
+            &format!("/venues/{}/{}", args.venue, action),
+            &json!({ "reason": args.reason }),
+        )
+        .await?;
+
+    println!("{} {} is now {}", "✓".green(), state.venue, mode_label(state.mode));
+    Ok(())
+}
+
+fn mode_label(mode: VenueMode) -> colored::ColoredString {
+    match mode {
+        VenueMode::Enabled => mode.as_status().green(),
+        VenueMode::Disabled => mode.as_status().red(),
+        VenueMode::Draining => mode.as_status().yellow(),
+    }
+}
+
+fn print_venues(venues: &[VenueModeState]) {
+    if venues.is_empty() {
+        println!("All venues enabled");
+        return;
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Venue", "Mode", "Operator", "Reason", "Changed"]);
+    for state in venues {
+        let color = match state.mode {
+            VenueMode::Enabled => Color::Green,
+            VenueMode::Disabled => Color::Red,
+            VenueMode::Draining => Color::Yellow,
+        };
+        table.add_row(vec![
+            Cell::new(&state.venue),
+            Cell::new(state.mode.as_status()).fg(color),
+            Cell::new(&state.operator),
+            Cell::new(state.reason.as_deref().unwrap_or("-")),
+            Cell::new(state.changed_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+        ]);
+    }
+
+    println!("{}", table);
+}
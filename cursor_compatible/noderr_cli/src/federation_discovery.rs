@@ -0,0 +1,469 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::interval;
+
+use crate::commands::federation::{FederationDataType, FederationLink};
+use crate::federation_sync_engine::FederationSyncEngine;
+
+const DEFAULT_GOSSIP_INTERVAL: u64 = 30;
+const GOSSIP_REQUEST_TIMEOUT: u64 = 10;
+// Weight of the newest observation in the liveness moving average
+const LIVENESS_ALPHA: f64 = 0.2;
+const INITIAL_REPUTATION: f64 = 0.5;
+const REPUTATION_STEP: f64 = 0.05;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveryConfig {
+    // Endpoints contacted on bootstrap before any peers are known
+    pub seeds: Vec<String>,
+    pub gossip_interval: u64,
+    // Peers contacted per gossip round
+    pub fanout: usize,
+    // Peers not heard from for this long are dropped
+    pub peer_ttl: u64,
+    pub max_peers: usize,
+    // Below this liveness a peer is treated as down
+    pub min_liveness: f64,
+    // Broadcasts from peers below this reputation are rejected
+    pub min_reputation: f64,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            seeds: Vec::new(),
+            gossip_interval: DEFAULT_GOSSIP_INTERVAL,
+            fanout: 3,
+            peer_ttl: 3600,
+            max_peers: 256,
+            min_liveness: 0.2,
+            min_reputation: 0.4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PeerSource {
+    Seed,
+    Gossip(String), // Cluster ID that advertised the peer
+    Direct,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveredPeer {
+    pub cluster_id: String,
+    pub endpoint: String,
+    pub public_key: String,
+    pub source: PeerSource,
+    pub first_seen: u64,
+    pub last_seen: u64,
+    pub last_contact: Option<u64>,
+    // Moving average of successful contacts, 0-1
+    pub liveness: f64,
+    // Standing earned from valid or invalid broadcasts, 0-1
+    pub reputation: f64,
+}
+
+impl DiscoveredPeer {
+    fn new(advert: &PeerAdvert, source: PeerSource, now: u64) -> Self {
+        Self {
+            cluster_id: advert.cluster_id.clone(),
+            endpoint: advert.endpoint.clone(),
+            public_key: advert.public_key.clone(),
+            source,
+            first_seen: now,
+            last_seen: advert.last_seen.min(now),
+            last_contact: None,
+            liveness: 0.5,
+            reputation: INITIAL_REPUTATION,
+        }
+    }
+}
+
+// What a cluster advertises about itself or a peer it knows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerAdvert {
+    pub cluster_id: String,
+    pub endpoint: String,
+    pub public_key: String,
+    pub last_seen: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GossipMessage {
+    pub sender: PeerAdvert,
+    pub peers: Vec<PeerAdvert>,
+}
+
+#[async_trait]
+pub trait GossipTransport: Send + Sync {
+    async fn exchange(&self, endpoint: &str, message: GossipMessage) -> Result<GossipMessage>;
+}
+
+pub struct HttpGossipTransport {
+    client: reqwest::Client,
+}
+
+impl HttpGossipTransport {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(GOSSIP_REQUEST_TIMEOUT))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[async_trait]
+impl GossipTransport for HttpGossipTransport {
+    async fn exchange(&self, endpoint: &str, message: GossipMessage) -> Result<GossipMessage> {
+        let url = format!("{}/federation/gossip", endpoint.trim_end_matches('/'));
+        let response = self.client
+            .post(&url)
+            .json(&message)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(response.json().await?)
+    }
+}
+
+// Seed-bootstrapped gossip discovery of federation peers with liveness and reputation scoring
+pub struct PeerDiscovery {
+    local: PeerAdvert,
+    config: DiscoveryConfig,
+    peers: Arc<Mutex<HashMap<String, DiscoveredPeer>>>,
+    transport: Arc<dyn GossipTransport>,
+    running: Arc<Mutex<bool>>,
+}
+
+impl PeerDiscovery {
+    pub fn new(cluster_id: &str, endpoint: &str, public_key: &str, config: DiscoveryConfig) -> Self {
+        Self {
+            local: PeerAdvert {
+                cluster_id: cluster_id.to_string(),
+                endpoint: endpoint.to_string(),
+                public_key: public_key.to_string(),
+                last_seen: now_secs(),
+            },
+            config,
+            peers: Arc::new(Mutex::new(HashMap::new())),
+            transport: Arc::new(HttpGossipTransport::new()),
+            running: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn GossipTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    // Contact every seed endpoint once; returns the number of seeds that answered
+    pub async fn bootstrap(&self) -> usize {
+        let mut reached = 0;
+
+        for seed in &self.config.seeds {
+            match self.transport.exchange(seed, self.outgoing_message()).await {
+                Ok(reply) => {
+                    reached += 1;
+                    let _ = self.merge_message(&reply, PeerSource::Seed);
+                }
+                Err(e) => {
+                    eprintln!("Warning: federation seed {} unreachable: {}", seed, e);
+                }
+            }
+        }
+
+        reached
+    }
+
+    // Exchange peer lists with a random subset of live peers
+    pub async fn gossip_round(&self) -> Result<usize> {
+        let targets = {
+            let peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+            let mut candidates: Vec<(String, String)> = peers
+                .values()
+                .map(|peer| (peer.cluster_id.clone(), peer.endpoint.clone()))
+                .collect();
+            candidates.shuffle(&mut rand::thread_rng());
+            candidates.truncate(self.config.fanout);
+            candidates
+        };
+
+        let mut reached = 0;
+        for (cluster_id, endpoint) in targets {
+            match self.transport.exchange(&endpoint, self.outgoing_message()).await {
+                Ok(reply) => {
+                    reached += 1;
+                    self.merge_message(&reply, PeerSource::Direct)?;
+                }
+                Err(_) => self.record_contact(&cluster_id, false)?,
+            }
+        }
+
+        self.expire_peers()?;
+        Ok(reached)
+    }
+
+    // Serve an incoming gossip exchange
+    pub fn handle_gossip(&self, message: GossipMessage) -> Result<GossipMessage> {
+        self.merge_message(&message, PeerSource::Direct)?;
+        Ok(self.outgoing_message())
+    }
+
+    pub fn peers(&self) -> Result<Vec<DiscoveredPeer>> {
+        let peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        let mut list: Vec<DiscoveredPeer> = peers.values().cloned().collect();
+        list.sort_by(|a, b| b.reputation.partial_cmp(&a.reputation).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(list)
+    }
+
+    pub fn peer(&self, cluster_id: &str) -> Result<Option<DiscoveredPeer>> {
+        let peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        Ok(peers.get(cluster_id).cloned())
+    }
+
+    // Adjust a peer's reputation after validating one of its broadcasts
+    pub fn record_broadcast_outcome(&self, cluster_id: &str, valid: bool) -> Result<()> {
+        let mut peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        if let Some(peer) = peers.get_mut(cluster_id) {
+            // Invalid broadcasts cost more than valid ones earn
+            let delta = if valid { REPUTATION_STEP } else { -3.0 * REPUTATION_STEP };
+            peer.reputation = (peer.reputation + delta).clamp(0.0, 1.0);
+        }
+        Ok(())
+    }
+
+    // Whether broadcasts from a cluster should be accepted
+    pub fn should_accept_broadcast(&self, cluster_id: &str) -> bool {
+        self.peer(cluster_id)
+            .ok()
+            .flatten()
+            .map_or(false, |peer| {
+                peer.reputation >= self.config.min_reputation && peer.liveness >= self.config.min_liveness
+            })
+    }
+
+    // Register reputable live peers as federation links; returns the number of new links
+    pub fn sync_links(&self, sync_engine: &FederationSyncEngine) -> Result<usize> {
+        let mut added = 0;
+
+        for peer in self.peers()? {
+            let trusted = self.should_accept_broadcast(&peer.cluster_id);
+            match sync_engine.get_link(&peer.cluster_id)? {
+                Some(mut link) => {
+                    // Statically configured links keep their settings; discovered ones track reputation
+                    if link.endpoint == peer.endpoint && link.authorized != trusted {
+                        link.authorized = trusted;
+                        sync_engine.add_link(link)?;
+                    }
+                }
+                None if trusted => {
+                    sync_engine.add_link(FederationLink {
+                        cluster_id: peer.cluster_id.clone(),
+                        endpoint: peer.endpoint.clone(),
+                        last_sync: None,
+                        authorized: true,
+                        data_types: vec![FederationDataType::All],
+                        public_key: peer.public_key.clone(),
+                    })?;
+                    added += 1;
+                }
+                None => {}
+            }
+        }
+
+        Ok(added)
+    }
+
+    pub async fn start(self: &Arc<Self>, sync_engine: Arc<FederationSyncEngine>) -> Result<()> {
+        {
+            let mut running = self.running.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+            if *running {
+                return Err(anyhow::anyhow!("Peer discovery already running"));
+            }
+            *running = true;
+        }
+
+        let discovery = self.clone();
+        let gossip_interval = self.config.gossip_interval;
+
+        tokio::spawn(async move {
+            discovery.bootstrap().await;
+            let _ = discovery.sync_links(&sync_engine);
+
+            let mut interval = interval(Duration::from_secs(gossip_interval));
+            loop {
+                interval.tick().await;
+
+                {
+                    let is_running = discovery.running.lock().unwrap();
+                    if !*is_running {
+                        break;
+                    }
+                }
+
+                if discovery.gossip_round().await.is_ok() {
+                    let _ = discovery.sync_links(&sync_engine);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    pub fn stop(&self) -> Result<()> {
+        let mut running = self.running.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        *running = false;
+        Ok(())
+    }
+
+    fn outgoing_message(&self) -> GossipMessage {
+        let mut sender = self.local.clone();
+        sender.last_seen = now_secs();
+
+        let peers = self.peers
+            .lock()
+            .map(|peers| {
+                peers
+                    .values()
+                    .filter(|peer| peer.liveness >= self.config.min_liveness)
+                    .map(|peer| PeerAdvert {
+                        cluster_id: peer.cluster_id.clone(),
+                        endpoint: peer.endpoint.clone(),
+                        public_key: peer.public_key.clone(),
+                        last_seen: peer.last_seen,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        GossipMessage { sender, peers }
+    }
+
+    fn merge_message(&self, message: &GossipMessage, sender_source: PeerSource) -> Result<()> {
+        let now = now_secs();
+        let mut peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+
+        // The sender answered, so it is live
+        let sender = peers
+            .entry(message.sender.cluster_id.clone())
+            .or_insert_with(|| DiscoveredPeer::new(&message.sender, sender_source, now));
+        sender.endpoint = message.sender.endpoint.clone();
+        sender.last_seen = now;
+        sender.last_contact = Some(now);
+        sender.liveness = sender.liveness * (1.0 - LIVENESS_ALPHA) + LIVENESS_ALPHA;
+
+        for advert in &message.peers {
+            if advert.cluster_id == self.local.cluster_id {
+                continue;
+            }
+
+            match peers.get_mut(&advert.cluster_id) {
+                Some(peer) => {
+                    // Second-hand sightings refresh recency but never liveness
+                    peer.last_seen = peer.last_seen.max(advert.last_seen.min(now));
+                }
+                None if peers.len() < self.config.max_peers => {
+                    let source = PeerSource::Gossip(message.sender.cluster_id.clone());
+                    peers.insert(advert.cluster_id.clone(), DiscoveredPeer::new(advert, source, now));
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record_contact(&self, cluster_id: &str, success: bool) -> Result<()> {
+        let mut peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        if let Some(peer) = peers.get_mut(cluster_id) {
+            let observation = if success { 1.0 } else { 0.0 };
+            peer.liveness = peer.liveness * (1.0 - LIVENESS_ALPHA) + observation * LIVENESS_ALPHA;
+            if success {
+                peer.last_contact = Some(now_secs());
+            }
+        }
+        Ok(())
+    }
+
+    fn expire_peers(&self) -> Result<()> {
+        let now = now_secs();
+        let mut peers = self.peers.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        peers.retain(|_, peer| {
+            now.saturating_sub(peer.last_seen) <= self.config.peer_ttl || peer.liveness >= self.config.min_liveness
+        });
+        Ok(())
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::from_secs(0))
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticTransport {
+        reply: GossipMessage,
+    }
+
+    #[async_trait]
+    impl GossipTransport for StaticTransport {
+        async fn exchange(&self, _endpoint: &str, _message: GossipMessage) -> Result<GossipMessage> {
+            Ok(self.reply.clone())
+        }
+    }
+
+    fn advert(cluster_id: &str) -> PeerAdvert {
+        PeerAdvert {
+            cluster_id: cluster_id.to_string(),
+            endpoint: format!("http://{}", cluster_id),
+            public_key: format!("{}-key", cluster_id),
+            last_seen: now_secs(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_discovers_gossiped_peers() -> Result<()> {
+        let config = DiscoveryConfig {
+            seeds: vec!["http://seed".to_string()],
+            ..DiscoveryConfig::default()
+        };
+        let reply = GossipMessage {
+            sender: advert("seed"),
+            peers: vec![advert("cluster-c"), advert("local")],
+        };
+        let discovery = PeerDiscovery::new("local", "http://local", "local-key", config)
+            .with_transport(Arc::new(StaticTransport { reply }));
+
+        assert_eq!(discovery.bootstrap().await, 1);
+
+        let peers = discovery.peers()?;
+        assert_eq!(peers.len(), 2);
+        assert_eq!(discovery.peer("cluster-c")?.unwrap().source, PeerSource::Gossip("seed".to_string()));
+
+        // A run of invalid broadcasts drops the peer below the acceptance threshold
+        assert!(discovery.should_accept_broadcast("seed"));
+        discovery.record_broadcast_outcome("seed", false)?;
+        assert!(!discovery.should_accept_broadcast("seed"));
+
+        let sync_engine = FederationSyncEngine::new().with_cluster_id("local");
+        assert_eq!(discovery.sync_links(&sync_engine)?, 1);
+        assert!(sync_engine.get_link("cluster-c")?.is_some());
+        assert!(sync_engine.get_link("seed")?.is_none());
+
+        Ok(())
+    }
+}
@@ -1,6 +1,15 @@
 use anyhow::Result;
+use async_trait::async_trait;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::broadcast;
@@ -10,6 +19,8 @@ use crate::commands::federation::{FederationDataType, FederationLinkPacket, Fede
 
 const MAX_CACHE_SIZE: usize = 1000;
 const DEFAULT_SYNC_INTERVAL: u64 = 300; // 5 minutes
+const DEFAULT_CLUSTER_ID: &str = "local-cluster";
+const SYNC_REQUEST_TIMEOUT: u64 = 30;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FederatedTrustMetric {
@@ -43,15 +54,264 @@ pub enum FederationEvent {
     ModelUpdate(FederatedModelUpdate),
     Alert(FederatedAlert),
     VoteUpdate(String), // Proposal ID
+    ProposalUpdate(String), // Proposal ID
     LinkEstablished(String), // Cluster ID
     LinkBroken(String), // Cluster ID
+    SyncCompleted(String), // Cluster ID
+}
+
+// Causal ordering between two vector clocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockOrdering {
+    Before,
+    After,
+    Equal,
+    Concurrent,
+}
+
+// Per-cluster event counters used to order replicated records
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorClock(BTreeMap<String, u64>);
+
+impl VectorClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn get(&self, cluster_id: &str) -> u64 {
+        self.0.get(cluster_id).copied().unwrap_or(0)
+    }
+    
+    pub fn increment(&mut self, cluster_id: &str) -> u64 {
+        let counter = self.0.entry(cluster_id.to_string()).or_insert(0);
+        *counter += 1;
+        *counter
+    }
+    
+    pub fn merge(&mut self, other: &VectorClock) {
+        for (cluster_id, &counter) in &other.0 {
+            let entry = self.0.entry(cluster_id.clone()).or_insert(0);
+            *entry = (*entry).max(counter);
+        }
+    }
+    
+    pub fn compare(&self, other: &VectorClock) -> ClockOrdering {
+        let mut less = false;
+        let mut greater = false;
+        
+        for cluster_id in self.0.keys().chain(other.0.keys()) {
+            match self.get(cluster_id).cmp(&other.get(cluster_id)) {
+                Ordering::Less => less = true,
+                Ordering::Greater => greater = true,
+                Ordering::Equal => {}
+            }
+        }
+        
+        match (less, greater) {
+            (false, false) => ClockOrdering::Equal,
+            (true, false) => ClockOrdering::Before,
+            (false, true) => ClockOrdering::After,
+            (true, true) => ClockOrdering::Concurrent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SyncRecordKind {
+    Proposal,
+    Vote,
+    TrustAttestation,
+}
+
+impl SyncRecordKind {
+    fn data_type(&self) -> FederationDataType {
+        match self {
+            SyncRecordKind::Proposal | SyncRecordKind::Vote => FederationDataType::Vote,
+            SyncRecordKind::TrustAttestation => FederationDataType::Trust,
+        }
+    }
+}
+
+// A replicated proposal, vote or trust attestation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub kind: SyncRecordKind,
+    pub id: String,
+    pub origin_cluster: String,
+    // Position of this write in the origin cluster's counter
+    pub sequence: u64,
+    pub lamport: u64,
+    pub clock: VectorClock,
+    pub payload: serde_json::Value,
+    pub timestamp: u64,
+}
+
+impl SyncRecord {
+    fn key(&self) -> (SyncRecordKind, String) {
+        (self.kind, self.id.clone())
+    }
+    
+    // Total order used to break ties between concurrent writes
+    fn lamport_order(&self, other: &SyncRecord) -> Ordering {
+        self.lamport
+            .cmp(&other.lamport)
+            .then_with(|| self.origin_cluster.cmp(&other.origin_cluster))
+    }
+}
+
+// How to resolve concurrent writes to the same record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictPolicy {
+    // Highest Lamport timestamp wins, cluster ID breaks ties
+    LastWriterWins,
+    // Keep the local version
+    PreferLocal,
+    // Take the remote version
+    PreferRemote,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictPolicies {
+    pub proposals: ConflictPolicy,
+    pub votes: ConflictPolicy,
+    pub trust_attestations: ConflictPolicy,
+}
+
+impl Default for ConflictPolicies {
+    fn default() -> Self {
+        Self {
+            proposals: ConflictPolicy::LastWriterWins,
+            votes: ConflictPolicy::LastWriterWins,
+            trust_attestations: ConflictPolicy::LastWriterWins,
+        }
+    }
+}
+
+impl ConflictPolicies {
+    fn for_kind(&self, kind: SyncRecordKind) -> ConflictPolicy {
+        match kind {
+            SyncRecordKind::Proposal => self.proposals,
+            SyncRecordKind::Vote => self.votes,
+            SyncRecordKind::TrustAttestation => self.trust_attestations,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncRequest {
+    pub from_cluster: String,
+    pub clock: VectorClock,
+    pub records: Vec<SyncRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncResponse {
+    pub cluster_id: String,
+    pub clock: VectorClock,
+    pub records: Vec<SyncRecord>,
+}
+
+#[async_trait]
+pub trait SyncTransport: Send + Sync {
+    async fn exchange(&self, link: &FederationLink, request: SyncRequest) -> Result<SyncResponse>;
+}
+
+// Exchanges deltas with a peer over its HTTP federation endpoint
+pub struct HttpSyncTransport {
+    client: reqwest::Client,
+}
+
+impl HttpSyncTransport {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(SYNC_REQUEST_TIMEOUT))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[async_trait]
+impl SyncTransport for HttpSyncTransport {
+    async fn exchange(&self, link: &FederationLink, request: SyncRequest) -> Result<SyncResponse> {
+        let url = format!("{}/federation/sync", link.endpoint.trim_end_matches('/'));
+        let response = self.client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?;
+        
+        Ok(response.json().await?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PeerSyncState {
+    Idle,
+    Syncing,
+    Synced,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerSyncStatus {
+    pub cluster_id: String,
+    pub state: PeerSyncState,
+    pub last_attempt: Option<u64>,
+    pub last_success: Option<u64>,
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+    pub records_sent: u64,
+    pub records_received: u64,
+    pub conflicts_resolved: u64,
+    // Latest clock the peer has acknowledged; deltas are computed against it
+    pub acknowledged_clock: VectorClock,
+}
+
+impl PeerSyncStatus {
+    fn new(cluster_id: &str) -> Self {
+        Self {
+            cluster_id: cluster_id.to_string(),
+            state: PeerSyncState::Idle,
+            last_attempt: None,
+            last_success: None,
+            last_error: None,
+            consecutive_failures: 0,
+            records_sent: 0,
+            records_received: 0,
+            conflicts_resolved: 0,
+            acknowledged_clock: VectorClock::new(),
+        }
+    }
+}
+
+// Replicated record log with its vector and Lamport clocks
+#[derive(Default)]
+struct SyncState {
+    records: HashMap<(SyncRecordKind, String), SyncRecord>,
+    clock: VectorClock,
+    lamport: u64,
+}
+
+// Outcome of merging a batch of remote records
+#[derive(Debug, Clone, Default)]
+struct MergeOutcome {
+    applied: Vec<SyncRecord>,
+    conflicts: u64,
 }
 
 pub struct FederationSyncEngine {
+    local_cluster_id: String,
     links: Arc<Mutex<HashMap<String, FederationLink>>>,
     trust_cache: Arc<Mutex<HashMap<String, FederatedTrustMetric>>>,
     model_cache: Arc<Mutex<HashMap<String, FederatedModelUpdate>>>,
     alert_cache: Arc<Mutex<Vec<FederatedAlert>>>,
+    sync_state: Arc<Mutex<SyncState>>,
+    peer_status: Arc<Mutex<HashMap<String, PeerSyncStatus>>>,
+    conflict_policies: ConflictPolicies,
+    transport: Arc<dyn SyncTransport>,
     event_sender: broadcast::Sender<FederationEvent>,
     running: Arc<Mutex<bool>>,
     sync_interval: u64,
@@ -62,16 +322,40 @@ impl FederationSyncEngine {
         let (tx, _) = broadcast::channel(100);
         
         Self {
+            local_cluster_id: DEFAULT_CLUSTER_ID.to_string(),
             links: Arc::new(Mutex::new(HashMap::new())),
             trust_cache: Arc::new(Mutex::new(HashMap::new())),
             model_cache: Arc::new(Mutex::new(HashMap::new())),
             alert_cache: Arc::new(Mutex::new(Vec::new())),
+            sync_state: Arc::new(Mutex::new(SyncState::default())),
+            peer_status: Arc::new(Mutex::new(HashMap::new())),
+            conflict_policies: ConflictPolicies::default(),
+            transport: Arc::new(HttpSyncTransport::new()),
             event_sender: tx,
             running: Arc::new(Mutex::new(false)),
             sync_interval: DEFAULT_SYNC_INTERVAL,
         }
     }
     
+    pub fn with_cluster_id(mut self, cluster_id: impl Into<String>) -> Self {
+        self.local_cluster_id = cluster_id.into();
+        self
+    }
+    
+    pub fn with_conflict_policies(mut self, policies: ConflictPolicies) -> Self {
+        self.conflict_policies = policies;
+        self
+    }
+    
+    pub fn with_transport(mut self, transport: Arc<dyn SyncTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+    
+    pub fn local_cluster_id(&self) -> &str {
+        &self.local_cluster_id
+    }
+    
     pub fn subscribe(&self) -> broadcast::Receiver<FederationEvent> {
         self.event_sender.subscribe()
     }
@@ -84,7 +368,14 @@ impl FederationSyncEngine {
     
     pub fn remove_link(&self, cluster_id: &str) -> Result<bool> {
         let mut links = self.links.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
-        Ok(links.remove(cluster_id).is_some())
+        let removed = links.remove(cluster_id).is_some();
+        
+        if removed {
+            let mut peer_status = self.peer_status.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+            peer_status.remove(cluster_id);
+        }
+        
+        Ok(removed)
     }
     
     pub fn get_links(&self) -> Result<Vec<FederationLink>> {
@@ -209,7 +500,7 @@ impl FederationSyncEngine {
         Ok(true)
     }
     
-    pub async fn start_sync_loop(&self) -> Result<()> {
+    pub async fn start_sync_loop(self: &Arc<Self>) -> Result<()> {
         {
             let mut running = self.running.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
             if *running {
@@ -218,9 +509,7 @@ impl FederationSyncEngine {
             *running = true;
         }
         
-        let running = self.running.clone();
-        let links = self.links.clone();
-        let event_sender = self.event_sender.clone();
+        let engine = self.clone();
         let sync_interval = self.sync_interval;
         
         tokio::spawn(async move {
@@ -230,43 +519,296 @@ impl FederationSyncEngine {
                 interval.tick().await;
                 
                 {
-                    let is_running = running.lock().unwrap();
+                    let is_running = engine.running.lock().unwrap();
                     if !*is_running {
                         break;
                     }
                 }
                 
-                // Perform synchronization with all linked clusters
-                let link_clones = {
-                    let links_guard = links.lock().unwrap();
-                    links_guard.values().cloned().collect::<Vec<_>>()
+                if engine.sync_with_peers().await.is_err() {
+                    break;
+                }
+            }
+        });
+        
+        Ok(())
+    }
+    
+    // Run one sync round with every authorized peer, returning how many succeeded
+    pub async fn sync_with_peers(&self) -> Result<usize> {
+        let mut synced = 0;
+        for link in self.get_links()?.into_iter().filter(|link| link.authorized) {
+            // Failures are recorded in the peer's sync status
+            if self.sync_with_peer(&link).await.is_ok() {
+                synced += 1;
+            }
+        }
+        Ok(synced)
+    }
+    
+    // Attest the local trust score of an agent to federated peers
+    pub fn attest_trust(&self, agent_id: &str, trust_score: f64) -> Result<SyncRecord> {
+        let metric = FederatedTrustMetric {
+            agent_id: agent_id.to_string(),
+            cluster_id: self.local_cluster_id.clone(),
+            trust_score,
+            timestamp: now_secs(),
+        };
+        let id = format!("{}:{}", metric.cluster_id, metric.agent_id);
+        
+        if let Ok(mut trust_cache) = self.trust_cache.lock() {
+            trust_cache.insert(id.clone(), metric.clone());
+        }
+        self.record_local(SyncRecordKind::TrustAttestation, &id, serde_json::to_value(&metric)?)
+    }
+    
+    // Record a local write to a proposal, vote or trust attestation for replication
+    pub fn record_local(&self, kind: SyncRecordKind, id: &str, payload: serde_json::Value) -> Result<SyncRecord> {
+        let mut state = self.sync_state.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        
+        let sequence = state.clock.increment(&self.local_cluster_id);
+        state.lamport += 1;
+        
+        let record = SyncRecord {
+            kind,
+            id: id.to_string(),
+            origin_cluster: self.local_cluster_id.clone(),
+            sequence,
+            lamport: state.lamport,
+            clock: state.clock.clone(),
+            payload,
+            timestamp: now_secs(),
+        };
+        
+        state.records.insert(record.key(), record.clone());
+        Ok(record)
+    }
+    
+    pub fn get_record(&self, kind: SyncRecordKind, id: &str) -> Result<Option<SyncRecord>> {
+        let state = self.sync_state.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        Ok(state.records.get(&(kind, id.to_string())).cloned())
+    }
+    
+    pub fn clock(&self) -> Result<VectorClock> {
+        let state = self.sync_state.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        Ok(state.clock.clone())
+    }
+    
+    // Records a peer with the given clock has not yet seen, limited to the link's data types
+    pub fn delta_since(&self, peer_clock: &VectorClock, data_types: &[FederationDataType]) -> Result<Vec<SyncRecord>> {
+        let state = self.sync_state.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        
+        let mut delta: Vec<SyncRecord> = state.records
+            .values()
+            .filter(|record| record.sequence > peer_clock.get(&record.origin_cluster))
+            .filter(|record| shares_data_type(data_types, record.kind))
+            .cloned()
+            .collect();
+        
+        delta.sort_by(|a, b| a.lamport_order(b));
+        Ok(delta)
+    }
+    
+    // Push our delta to a peer and merge the delta it returns
+    pub async fn sync_with_peer(&self, link: &FederationLink) -> Result<usize> {
+        let acknowledged_clock = self.begin_peer_sync(&link.cluster_id)?;
+        
+        let outbound = self.delta_since(&acknowledged_clock, &link.data_types)?;
+        let request = SyncRequest {
+            from_cluster: self.local_cluster_id.clone(),
+            clock: self.clock()?,
+            records: outbound.clone(),
+        };
+        
+        match self.transport.exchange(link, request.clone()).await {
+            Ok(response) => {
+                let inbound: Vec<SyncRecord> = response.records
+                    .into_iter()
+                    .filter(|record| shares_data_type(&link.data_types, record.kind))
+                    .collect();
+                let received = inbound.len();
+                let outcome = self.merge_records(inbound)?;
+                
+                // The peer now holds everything we sent plus what it already had
+                let mut acknowledged = response.clock;
+                acknowledged.merge(&request.clock);
+                
+                self.update_peer_status(&link.cluster_id, |status| {
+                    status.state = PeerSyncState::Synced;
+                    status.last_success = Some(now_secs());
+                    status.last_error = None;
+                    status.consecutive_failures = 0;
+                    status.records_sent += outbound.len() as u64;
+                    status.records_received += received as u64;
+                    status.conflicts_resolved += outcome.conflicts;
+                    status.acknowledged_clock = acknowledged;
+                })?;
+                
+                self.set_link_last_sync(&link.cluster_id)?;
+                let _ = self.event_sender.send(FederationEvent::SyncCompleted(link.cluster_id.clone()));
+                
+                Ok(outcome.applied.len())
+            }
+            Err(e) => {
+                self.update_peer_status(&link.cluster_id, |status| {
+                    status.state = PeerSyncState::Failed;
+                    status.last_error = Some(e.to_string());
+                    status.consecutive_failures += 1;
+                })?;
+                
+                Err(e)
+            }
+        }
+    }
+    
+    // Serve a sync request from a peer: merge its delta and return ours
+    pub fn handle_sync_request(&self, request: SyncRequest) -> Result<SyncResponse> {
+        let data_types = self.get_link(&request.from_cluster)?
+            .filter(|link| link.authorized)
+            .map(|link| link.data_types)
+            .ok_or_else(|| anyhow::anyhow!("Cluster {} is not an authorized federation peer", request.from_cluster))?;
+        
+        let inbound: Vec<SyncRecord> = request.records
+            .into_iter()
+            .filter(|record| shares_data_type(&data_types, record.kind))
+            .collect();
+        let received = inbound.len();
+        let outcome = self.merge_records(inbound)?;
+        
+        let outbound = self.delta_since(&request.clock, &data_types)?;
+        let clock = self.clock()?;
+        
+        self.update_peer_status(&request.from_cluster, |status| {
+            status.state = PeerSyncState::Synced;
+            status.last_success = Some(now_secs());
+            status.records_sent += outbound.len() as u64;
+            status.records_received += received as u64;
+            status.conflicts_resolved += outcome.conflicts;
+            status.acknowledged_clock.merge(&request.clock);
+        })?;
+        
+        Ok(SyncResponse {
+            cluster_id: self.local_cluster_id.clone(),
+            clock,
+            records: outbound,
+        })
+    }
+    
+    pub fn sync_status(&self) -> Result<Vec<PeerSyncStatus>> {
+        let links = self.get_links()?;
+        let peer_status = self.peer_status.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        
+        let mut statuses: Vec<PeerSyncStatus> = links
+            .iter()
+            .map(|link| {
+                peer_status
+                    .get(&link.cluster_id)
+                    .cloned()
+                    .unwrap_or_else(|| PeerSyncStatus::new(&link.cluster_id))
+            })
+            .collect();
+        
+        statuses.sort_by(|a, b| a.cluster_id.cmp(&b.cluster_id));
+        Ok(statuses)
+    }
+    
+    pub fn peer_sync_status(&self, cluster_id: &str) -> Result<Option<PeerSyncStatus>> {
+        let peer_status = self.peer_status.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        Ok(peer_status.get(cluster_id).cloned())
+    }
+    
+    fn merge_records(&self, records: Vec<SyncRecord>) -> Result<MergeOutcome> {
+        let mut outcome = MergeOutcome::default();
+        
+        {
+            let mut state = self.sync_state.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+            
+            for record in records {
+                state.clock.merge(&record.clock);
+                state.lamport = state.lamport.max(record.lamport);
+                
+                let key = record.key();
+                let accept = match state.records.get(&key) {
+                    None => true,
+                    Some(existing) => match record.clock.compare(&existing.clock) {
+                        ClockOrdering::After => true,
+                        ClockOrdering::Before | ClockOrdering::Equal => false,
+                        ClockOrdering::Concurrent => {
+                            outcome.conflicts += 1;
+                            match self.conflict_policies.for_kind(record.kind) {
+                                ConflictPolicy::LastWriterWins => record.lamport_order(existing) == Ordering::Greater,
+                                ConflictPolicy::PreferLocal => false,
+                                ConflictPolicy::PreferRemote => true,
+                            }
+                        }
+                    },
                 };
                 
-                for link in link_clones {
-                    if !link.authorized {
-                        continue;
-                    }
-                    
-                    // In a real implementation, this would:
-                    // 1. Connect to the remote cluster
-                    // 2. Pull updates since last sync
-                    // 3. Process and validate received packets
-                    // 4. Update the last_sync timestamp
-                    
-                    // For now, we'll just simulate successful sync
-                    let mut links_guard = links.lock().unwrap();
-                    if let Some(existing_link) = links_guard.get_mut(&link.cluster_id) {
-                        existing_link.last_sync = Some(
-                            SystemTime::now()
-                                .duration_since(UNIX_EPOCH)
-                                .unwrap_or(Duration::from_secs(0))
-                                .as_secs()
-                        );
+                if accept {
+                    state.records.insert(key, record.clone());
+                    outcome.applied.push(record);
+                }
+            }
+        }
+        
+        for record in &outcome.applied {
+            self.publish_record_event(record);
+        }
+        
+        Ok(outcome)
+    }
+    
+    fn publish_record_event(&self, record: &SyncRecord) {
+        let event = match record.kind {
+            SyncRecordKind::Proposal => FederationEvent::ProposalUpdate(record.id.clone()),
+            SyncRecordKind::Vote => {
+                let proposal_id = record.payload
+                    .get("proposal_id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(&record.id);
+                FederationEvent::VoteUpdate(proposal_id.to_string())
+            }
+            SyncRecordKind::TrustAttestation => {
+                match serde_json::from_value::<FederatedTrustMetric>(record.payload.clone()) {
+                    Ok(metric) => {
+                        if let Ok(mut trust_cache) = self.trust_cache.lock() {
+                            trust_cache.insert(format!("{}:{}", metric.cluster_id, metric.agent_id), metric.clone());
+                        }
+                        FederationEvent::TrustUpdate(metric)
                     }
+                    Err(_) => return,
                 }
             }
-        });
+        };
+        
+        let _ = self.event_sender.send(event);
+    }
+    
+    fn begin_peer_sync(&self, cluster_id: &str) -> Result<VectorClock> {
+        let mut peer_status = self.peer_status.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        let status = peer_status
+            .entry(cluster_id.to_string())
+            .or_insert_with(|| PeerSyncStatus::new(cluster_id));
         
+        status.state = PeerSyncState::Syncing;
+        status.last_attempt = Some(now_secs());
+        Ok(status.acknowledged_clock.clone())
+    }
+    
+    fn update_peer_status<F: FnOnce(&mut PeerSyncStatus)>(&self, cluster_id: &str, update: F) -> Result<()> {
+        let mut peer_status = self.peer_status.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        let status = peer_status
+            .entry(cluster_id.to_string())
+            .or_insert_with(|| PeerSyncStatus::new(cluster_id));
+        update(status);
+        Ok(())
+    }
+    
+    fn set_link_last_sync(&self, cluster_id: &str) -> Result<()> {
+        let mut links = self.links.lock().map_err(|_| anyhow::anyhow!("Lock poisoned"))?;
+        if let Some(link) = links.get_mut(cluster_id) {
+            link.last_sync = Some(now_secs());
+        }
         Ok(())
     }
     
@@ -298,4 +840,143 @@ impl FederationSyncEngine {
         alerts.sort_by(|a, b| b.timestamp.cmp(&a.timestamp)); // newest first
         Ok(alerts.into_iter().take(limit).collect())
     }
-} 
\ No newline at end of file
+}
+
+fn shares_data_type(data_types: &[FederationDataType], kind: SyncRecordKind) -> bool {
+    let wanted = kind.data_type();
+    data_types.iter().any(|data_type| {
+        matches!(data_type, FederationDataType::All)
+            || std::mem::discriminant(data_type) == std::mem::discriminant(&wanted)
+    })
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::from_secs(0))
+        .as_secs()
+}
+
+// Serves `/federation/sync` so peers' `HttpSyncTransport` can exchange deltas with us
+pub fn sync_router(engine: Arc<FederationSyncEngine>) -> Router {
+    Router::new()
+        .route("/federation/sync", post(handle_sync))
+        .with_state(engine)
+}
+
+async fn handle_sync(
+    State(engine): State<Arc<FederationSyncEngine>>,
+    Json(request): Json<SyncRequest>,
+) -> Response {
+    let authorized = matches!(engine.get_link(&request.from_cluster), Ok(Some(link)) if link.authorized);
+    if !authorized {
+        let message = format!("Cluster {} is not an authorized federation peer", request.from_cluster);
+        return (StatusCode::FORBIDDEN, message).into_response();
+    }
+    
+    match engine.handle_sync_request(request) {
+        Ok(response) => Json(response).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    struct LoopbackTransport {
+        remote: Arc<FederationSyncEngine>,
+    }
+    
+    #[async_trait]
+    impl SyncTransport for LoopbackTransport {
+        async fn exchange(&self, _link: &FederationLink, request: SyncRequest) -> Result<SyncResponse> {
+            self.remote.handle_sync_request(request)
+        }
+    }
+    
+    fn link_to(cluster_id: &str) -> FederationLink {
+        FederationLink {
+            cluster_id: cluster_id.to_string(),
+            endpoint: format!("http://{}", cluster_id),
+            last_sync: None,
+            authorized: true,
+            data_types: vec![FederationDataType::All],
+            public_key: String::new(),
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_delta_sync_resolves_concurrent_votes() -> Result<()> {
+        let remote = Arc::new(FederationSyncEngine::new().with_cluster_id("cluster-b"));
+        remote.add_link(link_to("cluster-a"))?;
+        
+        let local = FederationSyncEngine::new()
+            .with_cluster_id("cluster-a")
+            .with_transport(Arc::new(LoopbackTransport { remote: remote.clone() }));
+        local.add_link(link_to("cluster-b"))?;
+        
+        // Both clusters write the same vote without having seen each other
+        local.record_local(SyncRecordKind::Vote, "prop-1:agent-1", serde_json::json!({"proposal_id": "prop-1", "vote": "Yes"}))?;
+        remote.record_local(SyncRecordKind::Vote, "prop-1:agent-1", serde_json::json!({"proposal_id": "prop-1", "vote": "No"}))?;
+        remote.record_local(SyncRecordKind::Proposal, "prop-1", serde_json::json!({"title": "Raise quorum"}))?;
+        
+        local.sync_with_peer(&link_to("cluster-b")).await?;
+        
+        // Equal Lamport timestamps fall back to the higher cluster ID
+        let local_vote = local.get_record(SyncRecordKind::Vote, "prop-1:agent-1")?.unwrap();
+        let remote_vote = remote.get_record(SyncRecordKind::Vote, "prop-1:agent-1")?.unwrap();
+        assert_eq!(local_vote.origin_cluster, "cluster-b");
+        assert_eq!(remote_vote.origin_cluster, "cluster-b");
+        assert!(local.get_record(SyncRecordKind::Proposal, "prop-1")?.is_some());
+        
+        let status = local.peer_sync_status("cluster-b")?.unwrap();
+        assert_eq!(status.state, PeerSyncState::Synced);
+        assert_eq!(status.records_sent, 1);
+        assert_eq!(status.records_received, 2);
+        assert_eq!(status.conflicts_resolved, 1);
+        
+        // Nothing new on either side, so the next round exchanges no records
+        local.sync_with_peer(&link_to("cluster-b")).await?;
+        let status = local.peer_sync_status("cluster-b")?.unwrap();
+        assert_eq!(status.records_sent, 1);
+        assert_eq!(status.records_received, 2);
+        
+        Ok(())
+    }
+    
+    #[tokio::test]
+    async fn test_sync_endpoint_serves_authorized_peers() -> Result<()> {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+        
+        let engine = Arc::new(FederationSyncEngine::new().with_cluster_id("cluster-b"));
+        engine.add_link(link_to("cluster-a"))?;
+        engine.attest_trust("agent-1", 0.82)?;
+        
+        let sync_request = |from: &str| -> Result<Request<Body>> {
+            let body = serde_json::to_vec(&SyncRequest {
+                from_cluster: from.to_string(),
+                clock: VectorClock::new(),
+                records: Vec::new(),
+            })?;
+            Ok(Request::post("/federation/sync")
+                .header("content-type", "application/json")
+                .body(Body::from(body))?)
+        };
+        
+        let response = sync_router(engine.clone()).oneshot(sync_request("cluster-x")?).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        
+        let response = sync_router(engine).oneshot(sync_request("cluster-a")?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let sync_response: SyncResponse = serde_json::from_slice(&bytes)?;
+        assert_eq!(sync_response.records.len(), 1);
+        assert_eq!(sync_response.records[0].kind, SyncRecordKind::TrustAttestation);
+        assert_eq!(sync_response.records[0].id, "cluster-b:agent-1");
+        
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+mod backend;
 mod commands;
 mod mock_engine;
 mod mock_decay_service;
@@ -6,24 +7,27 @@ mod reason_exporter;
 mod reason_chain_executor;
 mod agent_snapshot;
 mod federation_sync_engine;
+mod federation_discovery;
 mod trust_normalizer;
 mod strategy_broadcast_router;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use noderr_core::strategy_storage::StrategyStorage;
 use noderr_core::trust_score_engine::TrustScoreEngine;
 use noderr_core::trust_decay_service::{TrustDecayService, TrustDecayConfig, StrategyActivityStatus};
 use noderr_core::redis::RedisClient;
+use backend::Backend;
+use std::path::PathBuf;
 use std::sync::Arc;
 use noderr_core::strategy::Strategy;
 use noderr_core::strategy_executor::StrategyExecutor;
-use mock_decay_service::init_mock_trust_decay_service;
 use chrono::{Utc, Duration};
 use colored::Colorize;
 use persistence::PersistenceManager;
 use std::sync::atomic::{AtomicBool, Ordering};
 use federation_sync_engine::FederationSyncEngine;
+use federation_discovery::{DiscoveryConfig, PeerDiscovery};
 use trust_normalizer::TrustNormalizer;
 use strategy_broadcast_router::StrategyBroadcastRouter;
 use ctrlc;
@@ -54,6 +58,15 @@ use crate::commands::{
     agent_anomaly_monitor::{AgentAnomalyMonitorCommand, run_agent_anomaly_monitor_command},
     meta_agents::MetaAgentsCommand,
     federation::FederationCommand,
+    dashboard::DashboardCommand,
+    backtest::BacktestCommand,
+    orders::{OrdersCommand, PositionsCommand},
+    risk::RiskCommand,
+    venue::VenueCommand,
+    log_level::LogLevelCommand,
+    state::StateCommand,
+    universe::UniverseCommand,
+    output::{print_json, OutputArgs},
 };
 
 #[derive(clap::Parser)]
@@ -146,6 +159,18 @@ pub struct Cli {
     /// Run a strategy with the specified ID and configuration
     #[arg(short, long)]
     pub verbose: bool,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+
+    /// State backend: `mock` for an isolated local world, or a redis:// URL
+    /// to operate on the production trust, decay and strategy state
+    #[arg(long, env = "NODERR_BACKEND", default_value = "mock")]
+    pub backend: Backend,
+
+    /// Directory holding strategy storage for the redis backend
+    #[arg(long, env = "NODERR_STORAGE_DIR", default_value = "./noderr_data")]
+    pub storage_dir: PathBuf,
 }
 
 #[derive(Subcommand, Debug)]
@@ -163,6 +188,18 @@ enum CliCommand {
     /// Display trust score history for a specific strategy
     TrustHistory {
         strategy_id: String,
+        /// Start of the range (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        from: Option<String>,
+        /// End of the range (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        to: Option<String>,
+        /// Only show the last N days; ignored when --from is set
+        #[arg(short, long)]
+        days: Option<u32>,
+        /// Maximum rows shown in table output
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
     },
     
     /// Display an ASCII chart of trust score decay trends
@@ -170,6 +207,9 @@ enum CliCommand {
         strategy_id: String,
         #[arg(short, long, default_value = "30")]
         days: u32,
+        /// Days to forecast from decay and historical recoveries; 0 disables
+        #[arg(short, long, default_value = "14")]
+        forecast_days: usize,
     },
     
     /// Simulate trust score decay over time
@@ -306,6 +346,40 @@ enum CliCommand {
 
     /// Federation and cross-cluster collaboration tools
     Federation(FederationCommand),
+
+    /// Live terminal dashboard of positions, PnL, drawdown, venues and regime warnings
+    Dashboard(DashboardCommand),
+
+    /// Run a strategy over historical data and write a report, or compare two reports
+    Backtest(BacktestCommand),
+
+    /// List and cancel resting orders on a running node
+    Orders(OrdersCommand),
+
+    /// List and flatten positions on a running node
+    Positions(PositionsCommand),
+
+    /// Time-boxed risk limit overrides with audit trail
+    Risk(RiskCommand),
+
+    /// Enable, disable or drain trading venues
+    Venue(VenueCommand),
+
+    /// Show or change log levels of a running node
+    LogLevel(LogLevelCommand),
+
+    /// Export or import signed state bundles for environment migration
+    State(StateCommand),
+
+    /// List, add or remove tradable symbols at runtime
+    Universe(UniverseCommand),
+
+    /// Generate shell completions on stdout
+    Completions {
+        /// Target shell
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
 }
 
 #[tokio::main]
@@ -315,52 +389,93 @@ async fn main() -> Result<()> {
     
     // Parse command line arguments
     let cli = Cli::parse();
+    let output = cli.output.format;
+    
+    // Completions need no services and must not print anything else
+    if let Some(CliCommand::Completions { shell }) = &cli.command {
+        clap_complete::generate(*shell, &mut Cli::command(), "noderr_cli", &mut std::io::stdout());
+        return Ok(());
+    }
     
     // Initialize persistence manager
-    let persistence_path = persistence::get_default_persistence_path();
-    println!("Using persistence file: {}", persistence_path.display());
+    let persistence_path = persistence::get_default_persistence_path()?;
+    // Diagnostics go to stderr so stdout stays parseable with --output json
+    eprintln!("Using persistence file: {}", persistence_path.display());
     
     let mut persistence = match PersistenceManager::new(&persistence_path) {
         Ok(p) => p,
         Err(e) => {
             eprintln!("Warning: Failed to initialize persistence: {}", e);
             eprintln!("Will continue without persistence");
-            PersistenceManager::new("./noderr_temp_state.json")?
+            PersistenceManager::new("./noderr_temp_state.db")?
         }
     };
     
-    // Initialize core services with persisted data
-    let engine = init_trust_score_engine(Arc::new(persistence.clone())).await?;
-    let storage = init_strategy_storage().await?;
-    let decay_service = init_trust_decay_service(engine.clone(), storage.clone(), Arc::new(persistence.clone())).await?;
-    
-    // Initialize mock Redis client for memory and mesh commands
-    let redis_client = Arc::new(RedisClient::new()); // This is a mock implementation
-    
-    // Initialize federation-related components
-    let federation_sync_engine = Arc::new(FederationSyncEngine::new());
-    let trust_normalizer = Arc::new(TrustNormalizer::new());
+    // Initialize core services against the selected backend
+    if cli.backend.is_production() {
+        eprintln!("{} {}", "Using production backend:".yellow().bold(), cli.backend);
+    }
+    let services = cli.backend.connect(Arc::new(persistence.clone()), &cli.storage_dir).await?;
+    let engine = services.engine;
+    let storage = services.storage;
+    let decay_service = services.decay_service;
+    let redis_client = services.redis_client;
     
     // Get local cluster ID from persistence or environment
     let local_cluster_id = persistence.get_value("local_cluster_id")
         .unwrap_or_else(|_| "local-cluster".to_string());
     
+    // Initialize federation-related components
+    let federation_sync_engine = Arc::new(FederationSyncEngine::new().with_cluster_id(local_cluster_id.clone()));
+    let trust_normalizer = Arc::new(TrustNormalizer::new());
+    
     // Mock private key for federation
     let private_key = "mock-private-key-12345".to_string();
     
-    let strategy_broadcast_router = Arc::new(
-        StrategyBroadcastRouter::new(
-            federation_sync_engine.clone(), 
-            local_cluster_id,
-            private_key
-        )
+    // Discover federation peers from seeds instead of configuring every cluster
+    let discovery_config = DiscoveryConfig {
+        seeds: std::env::var("NODERR_FEDERATION_SEEDS")
+            .map(|seeds| seeds.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default(),
+        ..DiscoveryConfig::default()
+    };
+    let local_endpoint = std::env::var("NODERR_FEDERATION_ENDPOINT").unwrap_or_default();
+    let peer_discovery = Arc::new(PeerDiscovery::new(&local_cluster_id, &local_endpoint, "", discovery_config.clone()));
+    if !discovery_config.seeds.is_empty() {
+        if let Err(e) = peer_discovery.start(federation_sync_engine.clone()).await {
+            eprintln!("Warning: Failed to start federation peer discovery: {}", e);
+        }
+    }
+    
+    let mut strategy_broadcast_router = StrategyBroadcastRouter::new(
+        federation_sync_engine.clone(), 
+        local_cluster_id,
+        private_key
     );
+    // Without seeds every counterpart is statically configured and trusted as before
+    if !discovery_config.seeds.is_empty() {
+        strategy_broadcast_router = strategy_broadcast_router.with_discovery(peer_discovery.clone());
+    }
+    let strategy_broadcast_router = Arc::new(strategy_broadcast_router);
     
     // Start federation sync loop
     if let Err(e) = federation_sync_engine.start_sync_loop().await {
         eprintln!("Warning: Failed to start federation sync loop: {}", e);
     }
     
+    // Serve peers' sync requests when this cluster accepts inbound federation traffic
+    if let Ok(listen) = std::env::var("NODERR_FEDERATION_LISTEN") {
+        let addr: std::net::SocketAddr = listen
+            .parse()
+            .with_context(|| format!("Invalid NODERR_FEDERATION_LISTEN address {}", listen))?;
+        let router = federation_sync_engine::sync_router(federation_sync_engine.clone());
+        tokio::spawn(async move {
+            if let Err(e) = axum::Server::bind(&addr).serve(router.into_make_service()).await {
+                eprintln!("Warning: Federation sync endpoint on {} stopped: {}", addr, e);
+            }
+        });
+    }
+    
     if let Some(cmd) = &cli.vote_ledger {
         return run_vote_ledger_command(cmd.clone(), engine, storage).await;
     }
@@ -378,7 +493,7 @@ async fn main() -> Result<()> {
     }
     
     if let Some(cmd) = &cli.governance {
-        return run_governance_command(cmd, engine.clone(), storage.clone(), redis_client.clone()).await?;
+        return run_governance_command(cmd, engine.clone(), storage.clone(), redis_client.clone(), federation_sync_engine.clone()).await?;
     }
     
     if let Some(cmd) = &cli.audit {
@@ -410,11 +525,11 @@ async fn main() -> Result<()> {
     }
     
     if let Some(cmd) = &cli.meta_agents {
-        return commands::meta_agents::run_meta_agents_command(cmd).await?;
+        return commands::meta_agents::run_meta_agents_command(cmd, output).await?;
     }
     
     if let Some(cmd) = &cli.federation {
-        return commands::federation::run_federation_command(cmd).await?;
+        return commands::federation::run_federation_command(cmd, &federation_sync_engine).await?;
     }
     
     // Execute the appropriate command
@@ -425,6 +540,15 @@ async fn main() -> Result<()> {
             // Actual implementation would go here
         },
         
+        Some(CliCommand::TrustShow) if output.is_json() => {
+            let mut scores = Vec::new();
+            for strategy_id in storage.get_strategy_ids()? {
+                let score = engine.get_trust_score(&strategy_id).await?;
+                scores.push(serde_json::json!({ "strategy_id": strategy_id, "score": score }));
+            }
+            print_json(&scores)?;
+        },
+        
         Some(CliCommand::TrustShow) => {
             let strategies = storage.get_strategy_ids()?;
             
@@ -437,21 +561,28 @@ async fn main() -> Result<()> {
             }
         },
         
-        Some(CliCommand::TrustHistory { strategy_id }) => {
-            println!("Trust Score History for {}:", strategy_id);
-            println!("--------------------");
+        Some(CliCommand::TrustHistory { strategy_id, from, to, days, limit }) => {
+            let range = HistoryRange::from_args(from.as_deref(), to.as_deref(), days)?;
+            if !output.is_json() {
+                println!("Trust Score History for {}:", strategy_id);
+                println!("--------------------");
+            }
             
-            // This would normally query the history from the trust score engine
-            // For now, we'll just print a mock message
-            println!("History data not available in mock implementation.");
+            run_trust_history(engine.clone(), &strategy_id, range, limit, output).await?;
         },
         
-        Some(CliCommand::TrustChart { strategy_id, days }) => {
-            println!("Trust Score Chart for {} (last {} days):", strategy_id, days);
+        Some(CliCommand::TrustChart { strategy_id, days, forecast_days }) => {
+            if !output.is_json() {
+                println!("Trust Score Chart for {} (last {} days):", strategy_id, days);
+            }
             
             // Use our new chart generation capability
-            let config = commands::trust_chart::ChartConfig::default();
-            commands::trust_chart::run_trust_chart(engine.clone(), &strategy_id, days, config).await?;
+            let config = commands::trust_chart::ChartConfig {
+                forecast_days,
+                ..commands::trust_chart::ChartConfig::default()
+            };
+            let decay_config = decay_service.get_config();
+            commands::trust_chart::run_trust_chart(engine.clone(), &strategy_id, days, &decay_config, config, output).await?;
         },
         
         Some(CliCommand::TrustDecay { initial_score, decay_factor, jitter, days, recovery_points }) => {
@@ -472,8 +603,10 @@ async fn main() -> Result<()> {
         
         Some(CliCommand::TrustStatus) => {
             commands::trust_status::run_trust_status(
+                decay_service.clone(),
                 engine.clone(),
-                decay_service.clone()
+                storage.clone(),
+                output,
             ).await?;
         },
         
@@ -490,6 +623,10 @@ async fn main() -> Result<()> {
             // Get the updated score
             let updated_score = engine.get_trust_score(&strategy_id).await?;
             println!("New trust score: {:.4}", updated_score);
+            
+            // Attest the decayed score to federated peers
+            federation_sync_engine.attest_trust(&strategy_id, updated_score.score)?;
+            federation_sync_engine.sync_with_peers().await?;
         },
         
         Some(CliCommand::TrustDecayConfig { enable, default_factor, interval_seconds, inactivity_threshold_hours, custom_factor }) => {
@@ -592,7 +729,7 @@ async fn main() -> Result<()> {
         },
         
         Some(CliCommand::Governance(cmd)) => {
-            run_governance_command(cmd, engine.clone(), storage.clone(), redis_client.clone()).await?;
+            run_governance_command(cmd, engine.clone(), storage.clone(), redis_client.clone(), federation_sync_engine.clone()).await?;
         },
         
         Some(CliCommand::Audit(cmd)) => {
@@ -610,14 +747,10 @@ async fn main() -> Result<()> {
         },
 
         Some(CliCommand::VoteLedger(cmd)) => {
-            // Initialize Redis client for vote ledger
-            let redis_client = RedisClient::new().await?;
-            commands::vote_ledger::run_vote_ledger_command(cmd, &redis_client).await?;
+            commands::vote_ledger::run_vote_ledger_command(cmd, redis_client.as_ref()).await?;
         },
         
         Some(CliCommand::Resilience(cmd)) => {
-            // Initialize Redis client for resilience
-            let redis_client = RedisClient::new().await?;
             commands::resilience::run_resilience_command(cmd).await?;
         },
         
@@ -689,12 +822,51 @@ async fn main() -> Result<()> {
         },
 
         Some(CliCommand::MetaAgents(cmd)) => {
-            commands::meta_agents::run_meta_agents_command(cmd).await?;
+            commands::meta_agents::run_meta_agents_command(cmd, output).await?;
         },
 
         Some(CliCommand::Federation(cmd)) => {
-            commands::federation::run_federation_command(cmd).await?;
+            commands::federation::run_federation_command(cmd, &federation_sync_engine).await?;
+        },
+
+        Some(CliCommand::Dashboard(cmd)) => {
+            commands::dashboard::run_dashboard_command(&cmd).await?;
+        },
+
+        Some(CliCommand::Backtest(cmd)) => {
+            commands::backtest::run_backtest_command(&cmd, output).await?;
+        },
+
+        Some(CliCommand::Orders(cmd)) => {
+            commands::orders::run_orders_command(&cmd, output).await?;
+        },
+
+        Some(CliCommand::Positions(cmd)) => {
+            commands::orders::run_positions_command(&cmd, output).await?;
+        },
+
+        Some(CliCommand::Risk(cmd)) => {
+            commands::risk::run_risk_command(&cmd, output).await?;
+        },
+
+        Some(CliCommand::Venue(cmd)) => {
+            commands::venue::run_venue_command(&cmd, output).await?;
         },
+
+        Some(CliCommand::LogLevel(cmd)) => {
+            commands::log_level::run_log_level_command(&cmd, output).await?;
+        },
+
+        Some(CliCommand::State(cmd)) => {
+            commands::state::run_state_command(&cmd, output).await?;
+        },
+
+        Some(CliCommand::Universe(cmd)) => {
+            commands::universe::run_universe_command(&cmd, output).await?;
+        },
+
+        // Handled before services are initialized
+        Some(CliCommand::Completions { .. }) => {},
     }
     
     // Add these to your cli.bio_ethics and cli.bio_signal checks
@@ -722,37 +894,13 @@ async fn main() -> Result<()> {
     
     // Add Meta-Agents command handler
     if let Some(cmd) = &cli.meta_agents {
-        return commands::meta_agents::run_meta_agents_command(cmd).await?;
+        return commands::meta_agents::run_meta_agents_command(cmd, output).await?;
     }
     
     Ok(())
 }
 
-async fn init_trust_score_engine(persistence: Arc<PersistenceManager>) -> Result<Arc<dyn TrustScoreEngine>> {
-    Ok(Arc::new(mock_trust_score_engine::MockTrustScoreEngine::new(Some(persistence))))
-}
-
-async fn init_strategy_storage() -> Result<Arc<dyn StrategyStorage>> {
-    Ok(Arc::new(mock_strategy_storage::MockStrategyStorage::new()))
-}
-
-async fn init_trust_decay_service(
-    engine: Arc<dyn TrustScoreEngine>,
-    storage: Arc<dyn StrategyStorage>,
-    persistence: Arc<PersistenceManager>,
-) -> Result<Arc<dyn TrustDecayService>> {
-    Ok(init_mock_trust_decay_service(engine, storage, persistence).await?)
-}
-
-fn init_mock_trust_score_engine() -> Arc<dyn TrustScoreEngine> {
-    Arc::new(mock_trust_score_engine::MockTrustScoreEngine::new())
-}
-
-fn init_mock_strategy_storage() -> Arc<dyn StrategyStorage> {
-    Arc::new(mock_strategy_storage::MockStrategyStorage::new())
-}
-
-async fn run_memory_command(cmd: &MemoryCommand, redis_client: Arc<RedisClient>) -> Result<()> {
+async fn run_memory_command(cmd: &MemoryCommand, redis_client: Arc<dyn RedisClient>) -> Result<()> {
     commands::memory::run_memory_command(cmd, redis_client).await
 }
 
@@ -760,7 +908,7 @@ async fn run_mesh_builder_command(
     cmd: &MeshBuilderCommand, 
     engine: Arc<dyn TrustScoreEngine>, 
     storage: Arc<dyn StrategyStorage>,
-    redis_client: Arc<RedisClient>
+    redis_client: Arc<dyn RedisClient>
 ) -> Result<()> {
     commands::mesh_builder::run_mesh_builder_command(cmd, engine, storage, redis_client).await
 }
@@ -782,7 +930,7 @@ async fn run_memory_shards_command(
     cmd: &MemoryShardsCommand, 
     engine: Arc<dyn TrustScoreEngine>, 
     storage: Arc<dyn StrategyStorage>,
-    redis_client: Arc<RedisClient>
+    redis_client: Arc<dyn RedisClient>
 ) -> Result<()> {
     commands::memory_shards::run_memory_shards_command(cmd.clone()).await
 }
@@ -795,7 +943,7 @@ async fn run_self_correction_command(
     cmd: &SelfCorrectionCommand,
     engine: Arc<dyn TrustScoreEngine>,
     storage: Arc<dyn StrategyStorage>,
-    redis_client: Arc<RedisClient>
+    redis_client: Arc<dyn RedisClient>
 ) -> Result<()> {
     commands::self_correction::run_self_correction_command(cmd.clone()).await
 }
@@ -3,14 +3,48 @@ use chrono::{DateTime, Utc};
 use dirs;
 use noderr_core::trust_score_engine::{TrustScore, TrustScoreSnapshot};
 use noderr_core::trust_decay_service::TrustDecayConfig;
-use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
-use std::fs::{self, File};
-use std::io::{Read, Write};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-/// Struct to hold all data that needs to be persisted
+/// Current schema version of the state store
+pub const SCHEMA_VERSION: i64 = 1;
+
+/// Schema migrations, applied in order; entry `n` upgrades version `n` to `n + 1`
+const MIGRATIONS: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS state (
+        namespace  TEXT NOT NULL,
+        key        TEXT NOT NULL,
+        value      TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        PRIMARY KEY (namespace, key)
+    );",
+];
+
+/// Key namespaces used by the CLI
+pub mod namespace {
+    /// Current trust score per strategy
+    pub const TRUST_SCORES: &str = "trust_scores";
+    /// Trust score history per strategy
+    pub const TRUST_HISTORY: &str = "trust_history";
+    /// Last activity timestamp per strategy
+    pub const ACTIVITY: &str = "activity";
+    /// Service configuration
+    pub const CONFIG: &str = "config";
+    /// Free-form values such as the local cluster ID
+    pub const VALUES: &str = "values";
+}
+
+/// Key of the decay configuration in the config namespace
+const DECAY_CONFIG_KEY: &str = "trust_decay";
+
+/// Maximum trust score snapshots kept per strategy
+const MAX_HISTORY_ENTRIES: usize = 90;
+
+/// Legacy JSON state written by earlier CLI versions
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PersistedState {
     /// Trust scores for strategies
@@ -37,86 +71,170 @@ impl Default for PersistedState {
     }
 }
 
-/// Manages persistence for CLI testing tools
+/// A transaction over the state store; changes are committed together or not at all
+pub struct StoreTransaction<'a> {
+    tx: Transaction<'a>,
+}
+
+impl<'a> StoreTransaction<'a> {
+    /// Read a value from a namespace
+    pub fn get<T: DeserializeOwned>(&self, namespace: &str, key: &str) -> Result<Option<T>> {
+        read_value(&self.tx, namespace, key)
+    }
+
+    /// Write a value to a namespace
+    pub fn put<T: Serialize>(&self, namespace: &str, key: &str, value: &T) -> Result<()> {
+        write_value(&self.tx, namespace, key, value)
+    }
+
+    /// Delete a value from a namespace
+    pub fn delete(&self, namespace: &str, key: &str) -> Result<bool> {
+        let removed = self.tx.execute(
+            "DELETE FROM state WHERE namespace = ?1 AND key = ?2",
+            params![namespace, key],
+        )?;
+        Ok(removed > 0)
+    }
+}
+
+/// Manages persistent CLI state in an embedded SQLite store
+#[derive(Clone)]
 pub struct PersistenceManager {
-    /// Path to the state file
+    /// Path to the database file
     path: PathBuf,
     
-    /// Current state
-    state: Mutex<PersistedState>,
+    /// Database connection
+    conn: Arc<Mutex<Connection>>,
 }
 
 impl PersistenceManager {
-    /// Create a new persistence manager
-    pub fn new(path: PathBuf) -> Result<Self> {
-        let state = if path.exists() {
-            // Try to load existing state
-            let mut file = File::open(&path)?;
-            let mut contents = String::new();
-            file.read_to_string(&mut contents)?;
-            serde_json::from_str(&contents)?
+    /// Open (or create) the state store at `path`.
+    ///
+    /// A `.json` path refers to the legacy state file: the store is opened at the
+    /// same path with a `.db` extension and any existing JSON state is imported
+    /// once, after which the JSON file is renamed to `*.json.migrated`.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        let requested = path.as_ref();
+        let (db_path, legacy_path) = if requested.extension().map_or(false, |ext| ext == "json") {
+            (requested.with_extension("db"), Some(requested.to_path_buf()))
         } else {
-            // Create directory if it doesn't exist
-            if let Some(parent) = path.parent() {
+            (requested.to_path_buf(), Some(requested.with_extension("json")))
+        };
+        
+        if let Some(parent) = db_path.parent() {
+            if !parent.as_os_str().is_empty() {
                 fs::create_dir_all(parent)?;
             }
-            PersistedState::default()
+        }
+        
+        let mut conn = Connection::open(&db_path)
+            .with_context(|| format!("Failed to open state store at {}", db_path.display()))?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        migrate_schema(&mut conn)?;
+        
+        let manager = Self {
+            path: db_path,
+            conn: Arc::new(Mutex::new(conn)),
         };
         
-        Ok(Self {
-            path,
-            state: Mutex::new(state),
-        })
+        if let Some(legacy_path) = legacy_path {
+            if legacy_path.exists() {
+                manager.import_legacy_json(&legacy_path)?;
+            }
+        }
+        
+        Ok(manager)
+    }
+    
+    /// Path of the underlying database file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    
+    /// Schema version of the open store
+    pub fn schema_version(&self) -> Result<i64> {
+        let conn = self.lock()?;
+        Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+    }
+    
+    /// Run `f` inside a transaction, committing only if it succeeds
+    pub fn transaction<T, F>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&StoreTransaction<'_>) -> Result<T>,
+    {
+        let mut conn = self.lock()?;
+        let store_tx = StoreTransaction { tx: conn.transaction()? };
+        let result = f(&store_tx)?;
+        store_tx.tx.commit()?;
+        Ok(result)
+    }
+    
+    /// Read a value from a namespace
+    pub fn get<T: DeserializeOwned>(&self, namespace: &str, key: &str) -> Result<Option<T>> {
+        let conn = self.lock()?;
+        read_value(&conn, namespace, key)
     }
     
-    /// Save current state to disk
+    /// Write a value to a namespace
+    pub fn put<T: Serialize>(&self, namespace: &str, key: &str, value: &T) -> Result<()> {
+        let conn = self.lock()?;
+        write_value(&conn, namespace, key, value)
+    }
+    
+    /// List all keys in a namespace
+    pub fn keys(&self, namespace: &str) -> Result<Vec<String>> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare("SELECT key FROM state WHERE namespace = ?1 ORDER BY key")?;
+        let keys = stmt
+            .query_map(params![namespace], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(keys)
+    }
+    
+    /// Flush the write-ahead log to the main database file.
+    ///
+    /// Writes are durable as soon as they return; this only compacts the log.
     pub fn save(&self) -> Result<()> {
-        let state = self.state.lock().unwrap();
-        let json = serde_json::to_string_pretty(&*state)?;
-        
-        let mut file = File::create(&self.path)?;
-        file.write_all(json.as_bytes())?;
-        
+        let conn = self.lock()?;
+        conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")?;
         Ok(())
     }
     
     /// Update trust score for a strategy
     pub fn update_trust_score(&self, strategy_id: &str, score: TrustScore) -> Result<()> {
-        let mut state = self.state.lock().unwrap();
-        state.trust_scores.insert(strategy_id.to_string(), score);
-        Ok(())
+        self.put(namespace::TRUST_SCORES, strategy_id, &score)
     }
     
     /// Get trust score for a strategy
     pub fn get_trust_score(&self, strategy_id: &str) -> Option<TrustScore> {
-        let state = self.state.lock().unwrap();
-        state.trust_scores.get(strategy_id).cloned()
+        self.get(namespace::TRUST_SCORES, strategy_id).ok().flatten()
     }
     
     /// Add a trust score snapshot to history
     pub fn add_trust_score_snapshot(&self, strategy_id: &str, snapshot: TrustScoreSnapshot) -> Result<()> {
-        let mut state = self.state.lock().unwrap();
-        let history = state.trust_score_history
-            .entry(strategy_id.to_string())
-            .or_insert_with(Vec::new);
-        
-        history.push(snapshot);
-        
-        // Keep only the last 90 days of history
-        if history.len() > 90 {
-            history.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
-            history.drain(0..(history.len() - 90));
-        }
-        
-        Ok(())
+        self.transaction(|tx| {
+            let mut history: Vec<TrustScoreSnapshot> = tx
+                .get(namespace::TRUST_HISTORY, strategy_id)?
+                .unwrap_or_default();
+            
+            history.push(snapshot);
+            
+            // Keep only the most recent snapshots
+            if history.len() > MAX_HISTORY_ENTRIES {
+                history.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+                history.drain(0..(history.len() - MAX_HISTORY_ENTRIES));
+            }
+            
+            tx.put(namespace::TRUST_HISTORY, strategy_id, &history)
+        })
     }
     
     /// Get trust score history for a strategy
     pub fn get_trust_score_history(&self, strategy_id: &str, limit: Option<usize>) -> Vec<TrustScoreSnapshot> {
-        let state = self.state.lock().unwrap();
-        let history = state.trust_score_history
-            .get(strategy_id)
-            .cloned()
+        let history: Vec<TrustScoreSnapshot> = self
+            .get(namespace::TRUST_HISTORY, strategy_id)
+            .ok()
+            .flatten()
             .unwrap_or_default();
         
         if let Some(limit) = limit {
@@ -133,45 +251,116 @@ impl PersistenceManager {
     
     /// Update activity timestamp for a strategy
     pub fn update_activity_timestamp(&self, strategy_id: &str, timestamp: DateTime<Utc>) -> Result<()> {
-        let mut state = self.state.lock().unwrap();
-        state.activity_timestamps.insert(strategy_id.to_string(), timestamp);
-        Ok(())
+        self.put(namespace::ACTIVITY, strategy_id, &timestamp)
     }
     
     /// Get activity timestamp for a strategy
     pub fn get_activity_timestamp(&self, strategy_id: &str) -> Option<DateTime<Utc>> {
-        let state = self.state.lock().unwrap();
-        state.activity_timestamps.get(strategy_id).cloned()
+        self.get(namespace::ACTIVITY, strategy_id).ok().flatten()
     }
     
     /// Get all strategy IDs with recorded activity
     pub fn get_all_strategy_ids(&self) -> Vec<String> {
-        let state = self.state.lock().unwrap();
-        state.activity_timestamps.keys().cloned().collect()
+        self.keys(namespace::ACTIVITY).unwrap_or_default()
     }
     
     /// Update trust decay configuration
     pub fn update_decay_config(&self, config: TrustDecayConfig) -> Result<()> {
-        let mut state = self.state.lock().unwrap();
-        state.decay_config = config;
-        Ok(())
+        self.put(namespace::CONFIG, DECAY_CONFIG_KEY, &config)
     }
     
     /// Get trust decay configuration
     pub fn get_decay_config(&self) -> TrustDecayConfig {
-        let state = self.state.lock().unwrap();
-        state.decay_config.clone()
+        self.get(namespace::CONFIG, DECAY_CONFIG_KEY)
+            .ok()
+            .flatten()
+            .unwrap_or_default()
     }
     
+    /// Get a free-form value
     pub fn get_value(&self, key: &str) -> Result<String, anyhow::Error> {
-        let state = self.state.lock().map_err(|_| anyhow::anyhow!("Failed to lock state"))?;
-        
-        match state.get(key) {
-            Some(serde_json::Value::String(s)) => Ok(s.clone()),
+        match self.get::<serde_json::Value>(namespace::VALUES, key)? {
+            Some(serde_json::Value::String(s)) => Ok(s),
             Some(value) => Ok(value.to_string()),
-            None => Err(anyhow::anyhow!("Key not found: {}", key)),
+            None => Err(anyhow!("Key not found: {}", key)),
         }
     }
+    
+    /// Set a free-form value
+    pub fn set_value(&self, key: &str, value: &str) -> Result<()> {
+        self.put(namespace::VALUES, key, &value)
+    }
+    
+    /// Import a legacy JSON state file in a single transaction and retire it
+    fn import_legacy_json(&self, legacy_path: &Path) -> Result<()> {
+        let contents = fs::read_to_string(legacy_path)?;
+        let state: PersistedState = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse legacy state file {}", legacy_path.display()))?;
+        
+        self.transaction(|tx| {
+            for (strategy_id, score) in &state.trust_scores {
+                tx.put(namespace::TRUST_SCORES, strategy_id, score)?;
+            }
+            for (strategy_id, history) in &state.trust_score_history {
+                tx.put(namespace::TRUST_HISTORY, strategy_id, history)?;
+            }
+            for (strategy_id, timestamp) in &state.activity_timestamps {
+                tx.put(namespace::ACTIVITY, strategy_id, timestamp)?;
+            }
+            tx.put(namespace::CONFIG, DECAY_CONFIG_KEY, &state.decay_config)
+        })?;
+        
+        let mut retired = legacy_path.as_os_str().to_owned();
+        retired.push(".migrated");
+        fs::rename(legacy_path, PathBuf::from(retired))?;
+        
+        Ok(())
+    }
+    
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
+        self.conn.lock().map_err(|_| anyhow!("State store lock poisoned"))
+    }
+}
+
+/// Bring the schema up to `SCHEMA_VERSION`
+fn migrate_schema(conn: &mut Connection) -> Result<()> {
+    let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if version > SCHEMA_VERSION {
+        return Err(anyhow!(
+            "State store schema version {} is newer than supported version {}",
+            version, SCHEMA_VERSION
+        ));
+    }
+    
+    let tx = conn.transaction()?;
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        tx.execute_batch(migration)?;
+        tx.pragma_update(None, "user_version", (index + 1) as i64)?;
+    }
+    tx.commit()?;
+    
+    Ok(())
+}
+
+fn read_value<T: DeserializeOwned>(conn: &Connection, namespace: &str, key: &str) -> Result<Option<T>> {
+    let raw: Option<String> = conn
+        .query_row(
+            "SELECT value FROM state WHERE namespace = ?1 AND key = ?2",
+            params![namespace, key],
+            |row| row.get(0),
+        )
+        .optional()?;
+    
+    raw.map(|json| serde_json::from_str(&json).map_err(Into::into)).transpose()
+}
+
+fn write_value<T: Serialize>(conn: &Connection, namespace: &str, key: &str, value: &T) -> Result<()> {
+    conn.execute(
+        "INSERT INTO state (namespace, key, value, updated_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(namespace, key) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+        params![namespace, key, serde_json::to_string(value)?, Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
 }
 
 /// Get default persistence path based on the system
@@ -179,7 +368,7 @@ pub fn get_default_persistence_path() -> Result<PathBuf> {
     let data_dir = dirs::data_dir()
         .ok_or_else(|| anyhow!("Could not determine data directory"))?;
     
-    Ok(data_dir.join("noderr").join("cli-state.json"))
+    Ok(data_dir.join("noderr").join("cli-state.db"))
 }
 
 #[cfg(test)]
@@ -244,4 +433,41 @@ mod tests {
         
         Ok(())
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_legacy_json_migration() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let json_path = temp_dir.path().join("cli-state.json");
+        
+        let mut legacy = PersistedState::default();
+        legacy.activity_timestamps.insert("legacy-strategy".to_string(), Utc::now());
+        legacy.decay_config.default_decay_factor = 0.9;
+        fs::write(&json_path, serde_json::to_string(&legacy)?)?;
+        
+        let persistence = PersistenceManager::new(temp_dir.path().join("cli-state.db"))?;
+        
+        assert_eq!(persistence.schema_version()?, SCHEMA_VERSION);
+        assert_eq!(persistence.get_all_strategy_ids(), vec!["legacy-strategy".to_string()]);
+        assert_eq!(persistence.get_decay_config().default_decay_factor, 0.9);
+        assert!(!json_path.exists());
+        assert!(temp_dir.path().join("cli-state.json.migrated").exists());
+        
+        Ok(())
+    }
+    
+    #[test]
+    fn test_failed_transaction_rolls_back() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let persistence = PersistenceManager::new(temp_dir.path().join("state.db"))?;
+        
+        let result: Result<()> = persistence.transaction(|tx| {
+            tx.put(namespace::VALUES, "local_cluster_id", &"cluster-a")?;
+            Err(anyhow!("abort"))
+        });
+        
+        assert!(result.is_err());
+        assert!(persistence.get_value("local_cluster_id").is_err());
+        
+        Ok(())
+    }
+}
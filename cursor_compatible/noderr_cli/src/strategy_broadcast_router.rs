@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::commands::federation::{FederationDataType, FederationLinkPacket};
+use crate::federation_discovery::PeerDiscovery;
 use crate::federation_sync_engine::FederationSyncEngine;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +64,7 @@ pub struct StrategyBroadcastRouter {
     broadcasts: Arc<Mutex<HashMap<String, BroadcastStatus>>>,
     received_strategies: Arc<Mutex<HashMap<String, StrategyBroadcastPackage>>>,
     private_key: String, // For signing packets
+    discovery: Option<Arc<PeerDiscovery>>, // Gates inbound broadcasts on peer reputation
 }
 
 impl StrategyBroadcastRouter {
@@ -73,9 +75,15 @@ impl StrategyBroadcastRouter {
             broadcasts: Arc::new(Mutex::new(HashMap::new())),
             received_strategies: Arc::new(Mutex::new(HashMap::new())),
             private_key,
+            discovery: None,
         }
     }
     
+    pub fn with_discovery(mut self, discovery: Arc<PeerDiscovery>) -> Self {
+        self.discovery = Some(discovery);
+        self
+    }
+    
     pub async fn broadcast_strategy(&self, 
                                   strategy_id: &str, 
                                   strategy_hash: &str,
@@ -255,6 +263,15 @@ impl StrategyBroadcastRouter {
     }
     
     pub fn receive_strategy(&self, package: StrategyBroadcastPackage) -> Result<()> {
+        if let Some(discovery) = &self.discovery {
+            if !discovery.should_accept_broadcast(&package.cluster_id) {
+                return Err(anyhow::anyhow!(
+                    "Rejected strategy broadcast from cluster {}: peer reputation or liveness too low",
+                    package.cluster_id
+                ));
+            }
+        }
+        
         println!("📥 Received strategy broadcast from cluster: {}", package.cluster_id);
         println!("📝 Strategy: {} ({})", package.metadata.strategy_id, package.metadata.strategy_hash);
         
@@ -10,7 +10,7 @@ use noderr_core::execution_strategy::{
     TWAPConfig, VWAPConfig
 };
 use noderr_core::execution::ExecutionResult;
-use noderr_core::order_router::{Order, OrderSide};
+use noderr_core::order_router::{Order, OrderSide, TimeInForce};
 
 // Mock execution strategies for testing
 struct MockTWAPStrategy {}
@@ -143,6 +143,8 @@ fn bench_execution_strategy_router(c: &mut Criterion) {
         min_order_size_for_vwap: 5000.0,
         twap_config: Some(TWAPConfig::default()),
         vwap_config: Some(VWAPConfig::default()),
+        iceberg_config: None,
+        pov_config: None,
         max_execution_time_ms: 300000,
         symbol_strategy_map: HashMap::new(),
     };
@@ -173,6 +175,9 @@ fn bench_execution_strategy_router(c: &mut Criterion) {
             id: id.to_string(),
             max_slippage: Some(0.01),
             max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
             additional_params,
         }
     }
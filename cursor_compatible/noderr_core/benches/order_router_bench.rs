@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
 
-use noderr_core::order_router::{SmartOrderRouter, Order, OrderSide, OrderRetryEngine};
+use noderr_core::order_router::{SmartOrderRouter, Order, OrderSide, OrderRetryEngine, TimeInForce};
 
 fn create_test_order(symbol: &str, id: &str, venues: Vec<String>) -> Order {
     Order {
@@ -15,6 +15,9 @@ fn create_test_order(symbol: &str, id: &str, venues: Vec<String>) -> Order {
         id: id.to_string(),
         max_slippage: Some(0.01),
         max_retries: Some(1),
+        post_only: false,
+        reduce_only: false,
+        time_in_force: TimeInForce::GTC,
         additional_params: HashMap::new(),
     }
 }
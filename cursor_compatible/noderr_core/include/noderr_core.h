@@ -0,0 +1,540 @@
+#ifndef NODERR_CORE_H
+#define NODERR_CORE_H
+
+/* Generated with cbindgen:0.26.0 */
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Version of the C ABI; bumped on any incompatible change
+ */
+#define NODERR_FFI_ABI_VERSION 1
+
+/**
+ * Bits of `NoderrRiskCheckResult::violation_mask`
+ */
+#define NODERR_VIOLATION_POSITION_SIZE (1 << 0)
+
+#define NODERR_VIOLATION_LEVERAGE (1 << 1)
+
+#define NODERR_VIOLATION_TRUST_SCORE (1 << 2)
+
+#define NODERR_VIOLATION_VENUE_EXPOSURE (1 << 3)
+
+#define NODERR_VIOLATION_SYMBOL_EXPOSURE (1 << 4)
+
+#define NODERR_VIOLATION_SECTOR_EXPOSURE (1 << 5)
+
+#define NODERR_VIOLATION_DRAWDOWN (1 << 6)
+
+#define NODERR_VIOLATION_PORTFOLIO_ALLOCATION (1 << 7)
+
+#define NODERR_VIOLATION_LOW_LIQUIDITY (1 << 8)
+
+#define NODERR_VIOLATION_HIGH_VOLATILITY (1 << 9)
+
+#define NODERR_VIOLATION_OTHER (1 << 10)
+
+/**
+ * Status code returned by every fallible FFI function
+ */
+typedef enum NoderrStatus {
+  /**
+   * Success
+   */
+  NODERR_STATUS_OK = 0,
+  /**
+   * A required pointer argument was NULL
+   */
+  NODERR_STATUS_NULL_POINTER = 1,
+  /**
+   * An argument was invalid (e.g. not UTF-8)
+   */
+  NODERR_STATUS_INVALID_ARGUMENT = 2,
+  /**
+   * The requested agent or entity does not exist
+   */
+  NODERR_STATUS_NOT_FOUND = 3,
+  /**
+   * An internal error or panic occurred
+   */
+  NODERR_STATUS_INTERNAL = 4,
+} NoderrStatus;
+
+/**
+ * Side of a trade recorded with the drawdown monitor
+ */
+typedef enum NoderrTradeType {
+  NODERR_TRADE_TYPE_BUY = 0,
+  NODERR_TRADE_TYPE_SELL = 1,
+  NODERR_TRADE_TYPE_CLOSE = 2,
+} NoderrTradeType;
+
+/**
+ * Opaque backtest environment handle
+ */
+typedef struct NoderrBacktestEnv NoderrBacktestEnv;
+
+/**
+ * Opaque drawdown monitor handle
+ */
+typedef struct NoderrDrawdownMonitor NoderrDrawdownMonitor;
+
+/**
+ * Opaque risk calculator handle
+ */
+typedef struct NoderrRiskCalculator NoderrRiskCalculator;
+
+/**
+ * Risk limits; pass NULL to use the defaults
+ */
+typedef struct NoderrRiskConfig {
+  /**
+   * Maximum position size as a fraction of portfolio (0.0-1.0)
+   */
+  double max_position_size_pct;
+  /**
+   * Maximum leverage allowed
+   */
+  double max_leverage;
+  /**
+   * Maximum drawdown allowed (0.0-1.0)
+   */
+  double max_drawdown_pct;
+  /**
+   * Minimum venue trust score required (0.0-1.0)
+   */
+  double min_trust_score;
+  /**
+   * Maximum exposure per symbol as a fraction of portfolio (0.0-1.0)
+   */
+  double max_exposure_per_symbol;
+  /**
+   * Maximum exposure per venue as a fraction of portfolio (0.0-1.0)
+   */
+  double max_exposure_per_venue;
+  /**
+   * Skip non-critical checks
+   */
+  bool fast_risk_mode;
+} NoderrRiskConfig;
+
+/**
+ * An order to check or a fill to record with the risk calculator
+ */
+typedef struct NoderrOrder {
+  /**
+   * Symbol (required)
+   */
+  const char *symbol;
+  /**
+   * Venue (required)
+   */
+  const char *venue;
+  /**
+   * Strategy ID (optional, may be NULL)
+   */
+  const char *strategy_id;
+  /**
+   * Size in base currency
+   */
+  double size;
+  /**
+   * Notional value in quote currency
+   */
+  double value;
+  /**
+   * Leverage
+   */
+  double leverage;
+  /**
+   * True for a long/buy, false for a short/sell
+   */
+  bool is_long;
+} NoderrOrder;
+
+/**
+ * Result of a pre-trade risk check
+ */
+typedef struct NoderrRiskCheckResult {
+  /**
+   * Whether all checks passed
+   */
+  bool passed;
+  /**
+   * Risk level (0.0-1.0)
+   */
+  double risk_level;
+  /**
+   * Number of violations
+   */
+  uint32_t violation_count;
+  /**
+   * Bit set of violated checks, one bit per `NODERR_VIOLATION_*` constant
+   */
+  uint32_t violation_mask;
+} NoderrRiskCheckResult;
+
+/**
+ * Aggregate risk calculator state
+ */
+typedef struct NoderrRiskState {
+  /**
+   * Total exposure across all positions
+   */
+  double total_exposure;
+  /**
+   * Number of open positions
+   */
+  uint32_t position_count;
+  /**
+   * Number of venues with exposure
+   */
+  uint32_t venue_count;
+} NoderrRiskState;
+
+/**
+ * Drawdown limits; pass NULL to use the defaults
+ */
+typedef struct NoderrDrawdownConfig {
+  /**
+   * Maximum allowed drawdown (0.0-1.0)
+   */
+  double max_drawdown_pct;
+  /**
+   * Alert threshold (0.0-1.0)
+   */
+  double alert_threshold_pct;
+  /**
+   * Number of data points kept per agent
+   */
+  uint32_t rolling_window_size;
+  /**
+   * Trades required before drawdown tracking starts
+   */
+  uint32_t min_trades_for_drawdown;
+  /**
+   * Cooldown after a breach (ms)
+   */
+  uint64_t cooldown_period_ms;
+} NoderrDrawdownConfig;
+
+/**
+ * Kill switch callback invoked when an agent breaches its drawdown limit
+ *
+ * Returns true if the agent was stopped.
+ */
+typedef bool (*NoderrKillSwitchFn)(const char *agent_id,
+                                   const char *reason,
+                                   const char *message,
+                                   void *user_data);
+
+/**
+ * A trade recorded with the drawdown monitor
+ */
+typedef struct NoderrTrade {
+  /**
+   * Agent/strategy ID (required)
+   */
+  const char *agent_id;
+  /**
+   * Symbol (required)
+   */
+  const char *symbol;
+  /**
+   * Trade ID (required)
+   */
+  const char *trade_id;
+  /**
+   * Trade amount
+   */
+  double amount;
+  /**
+   * Trade price
+   */
+  double price;
+  /**
+   * Trade side, a `NoderrTradeType` value
+   */
+  uint32_t trade_type;
+  /**
+   * Equity after the trade
+   */
+  double equity;
+  /**
+   * Realized PnL of the trade
+   */
+  double pnl;
+  /**
+   * Unix timestamp in milliseconds; 0 uses the current time
+   */
+  int64_t timestamp_ms;
+} NoderrTrade;
+
+/**
+ * Drawdown state for an agent
+ */
+typedef struct NoderrDrawdownState {
+  /**
+   * Whether the agent may trade (not in cooldown)
+   */
+  bool is_active;
+  /**
+   * Current drawdown (0.0-1.0)
+   */
+  double current_drawdown_pct;
+  /**
+   * Peak equity
+   */
+  double peak_equity;
+  /**
+   * Current equity
+   */
+  double current_equity;
+  /**
+   * Cooldown end as Unix milliseconds; 0 when not in cooldown
+   */
+  int64_t cooldown_end_ms;
+} NoderrDrawdownState;
+
+/**
+ * A historical OHLCV bar
+ */
+typedef struct NoderrBar {
+  /**
+   * Unix timestamp in milliseconds
+   */
+  int64_t timestamp_ms;
+  double open;
+  double high;
+  double low;
+  double close;
+  double volume;
+} NoderrBar;
+
+/**
+ * Backtest environment settings; pass NULL to use the defaults
+ */
+typedef struct NoderrEnvConfig {
+  /**
+   * Starting capital in quote currency
+   */
+  double initial_capital;
+  /**
+   * Fee per fill (basis points of notional)
+   */
+  double fee_bps;
+  /**
+   * Adverse slippage per fill (basis points)
+   */
+  double slippage_bps;
+  /**
+   * Whether negative actions may open shorts
+   */
+  bool allow_short;
+  /**
+   * Exposure, as a multiple of equity, that action 1.0 maps to
+   */
+  double max_exposure;
+  /**
+   * Penalty on squared step returns
+   */
+  double risk_aversion;
+  /**
+   * Penalty per unit increase in drawdown
+   */
+  double drawdown_penalty;
+  /**
+   * Bars skipped for feature warm-up
+   */
+  uint32_t warmup_bars;
+  /**
+   * Maximum steps per episode (0 runs to the end of the data)
+   */
+  uint32_t max_episode_steps;
+} NoderrEnvConfig;
+
+/**
+ * Result of a backtest environment step
+ */
+typedef struct NoderrEnvStepResult {
+  /**
+   * Risk-adjusted reward
+   */
+  double reward;
+  /**
+   * Whether the episode has ended
+   */
+  bool done;
+  /**
+   * Equity after the step
+   */
+  double equity;
+  /**
+   * Signed position in base units
+   */
+  double position;
+  /**
+   * Drawdown from the episode peak (0.0-1.0)
+   */
+  double drawdown;
+} NoderrEnvStepResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * ABI version of this library; compare with `NODERR_FFI_ABI_VERSION`
+ */
+uint32_t noderr_ffi_abi_version(void);
+
+/**
+ * Message for the last failed call on this thread, or NULL
+ *
+ * The pointer is valid until the next failing call on the same thread.
+ */
+const char *noderr_last_error(void);
+
+/**
+ * Create a risk calculator; `config` may be NULL for defaults
+ *
+ * Returns NULL on failure.
+ */
+struct NoderrRiskCalculator *noderr_risk_calculator_new(const struct NoderrRiskConfig *config,
+                                                        double portfolio_value);
+
+/**
+ * Release a risk calculator; NULL is ignored
+ */
+void noderr_risk_calculator_free(struct NoderrRiskCalculator *calculator);
+
+/**
+ * Run the pre-trade risk check for an order
+ */
+enum NoderrStatus noderr_risk_check_order(const struct NoderrRiskCalculator *calculator,
+                                          const struct NoderrOrder *order,
+                                          struct NoderrRiskCheckResult *out);
+
+/**
+ * Record a filled order as an open position
+ */
+enum NoderrStatus noderr_risk_record_trade(const struct NoderrRiskCalculator *calculator,
+                                           const struct NoderrOrder *order);
+
+/**
+ * Remove the open position for a symbol on a venue
+ */
+enum NoderrStatus noderr_risk_close_position(const struct NoderrRiskCalculator *calculator,
+                                             const char *symbol,
+                                             const char *venue);
+
+/**
+ * Update the portfolio value used for percentage limits
+ */
+enum NoderrStatus noderr_risk_update_portfolio_value(const struct NoderrRiskCalculator *calculator,
+                                                     double portfolio_value);
+
+/**
+ * Set the trust score (0.0-1.0) for a venue
+ */
+enum NoderrStatus noderr_risk_set_trust_score(const struct NoderrRiskCalculator *calculator,
+                                              const char *venue,
+                                              double score);
+
+/**
+ * Query aggregate exposure state
+ */
+enum NoderrStatus noderr_risk_get_state(const struct NoderrRiskCalculator *calculator,
+                                        struct NoderrRiskState *out);
+
+/**
+ * Query total exposure for a symbol
+ */
+enum NoderrStatus noderr_risk_get_symbol_exposure(const struct NoderrRiskCalculator *calculator,
+                                                  const char *symbol,
+                                                  double *out);
+
+/**
+ * Create a drawdown monitor; `config` may be NULL for defaults
+ *
+ * `kill_switch` may be NULL. `user_data` is passed back to it unchanged and
+ * must be usable from any thread. Returns NULL on failure.
+ */
+struct NoderrDrawdownMonitor *noderr_drawdown_monitor_new(const struct NoderrDrawdownConfig *config,
+                                                          NoderrKillSwitchFn kill_switch,
+                                                          void *user_data);
+
+/**
+ * Release a drawdown monitor; NULL is ignored
+ */
+void noderr_drawdown_monitor_free(struct NoderrDrawdownMonitor *monitor);
+
+/**
+ * Record a trade for drawdown tracking
+ */
+enum NoderrStatus noderr_drawdown_record_trade(const struct NoderrDrawdownMonitor *monitor,
+                                               const struct NoderrTrade *trade);
+
+/**
+ * Query drawdown state for an agent
+ */
+enum NoderrStatus noderr_drawdown_get_state(const struct NoderrDrawdownMonitor *monitor,
+                                            const char *agent_id,
+                                            struct NoderrDrawdownState *out);
+
+/**
+ * Clear drawdown history and cooldown for an agent
+ */
+enum NoderrStatus noderr_drawdown_reset_agent(const struct NoderrDrawdownMonitor *monitor,
+                                              const char *agent_id);
+
+/**
+ * Create a backtest environment over `bar_count` bars using the default
+ * observation features; `config` may be NULL for defaults
+ *
+ * Returns NULL on failure.
+ */
+struct NoderrBacktestEnv *noderr_backtest_env_new(const struct NoderrBar *bars,
+                                                  uintptr_t bar_count,
+                                                  const struct NoderrEnvConfig *config);
+
+/**
+ * Release a backtest environment; NULL is ignored
+ */
+void noderr_backtest_env_free(struct NoderrBacktestEnv *env);
+
+/**
+ * Number of values in each observation; 0 if `env` is NULL
+ */
+uintptr_t noderr_backtest_env_observation_size(const struct NoderrBacktestEnv *env);
+
+/**
+ * Start a new episode at bar `start` (negative for the default) and write
+ * the first observation into `observation`
+ */
+enum NoderrStatus noderr_backtest_env_reset(struct NoderrBacktestEnv *env,
+                                            int64_t start,
+                                            double *observation,
+                                            uintptr_t observation_len);
+
+/**
+ * Move to target exposure `action` (-1.0 to 1.0), advance one bar and write
+ * the next observation into `observation`
+ */
+enum NoderrStatus noderr_backtest_env_step(struct NoderrBacktestEnv *env,
+                                           double action,
+                                           double *observation,
+                                           uintptr_t observation_len,
+                                           struct NoderrEnvStepResult *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* NODERR_CORE_H */
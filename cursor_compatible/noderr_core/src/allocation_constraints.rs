@@ -0,0 +1,657 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Portfolio constraints for the allocators
+//!
+//! A constraint specification bounds the weights an allocator may produce:
+//! long-only, a budget, per-asset and default min/max weights, caps and
+//! floors on groups such as sectors, a turnover limit against the previous
+//! allocation and a cardinality range. Specifications can be written in
+//! serde form inside allocator configs or in a short line-based DSL:
+//!
+//! ```text
+//! long_only
+//! budget 1.0
+//! weight * <= 0.3
+//! weight BTC >= 0.05
+//! group defi [UNI, AAVE, COMP] <= 0.2
+//! turnover <= 0.25
+//! positions <= 8
+//! ```
+//!
+//! `apply` projects an unconstrained target allocation onto the feasible
+//! set. When the constraints cannot all hold at once, the error lists the
+//! conflicts and the constraints involved in each rather than returning a
+//! silently violated allocation. Dependency-free so it also builds for wasm.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Tolerance for constraint checks
+const TOLERANCE: f64 = 1e-6;
+
+/// Projection iterations before giving up on convergence
+const MAX_ITERATIONS: usize = 500;
+
+/// Errors from parsing or applying constraints
+#[derive(Debug, Error)]
+pub enum ConstraintError {
+    #[error("Constraint spec line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    #[error("Infeasible constraints: {}", .0.iter().map(|c| c.to_string()).collect::<Vec<_>>().join("; "))]
+    Infeasible(Vec<ConstraintConflict>),
+}
+
+/// A set of constraints that cannot hold together
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintConflict {
+    /// Names of the conflicting constraints, e.g. `group[defi]`, `budget`
+    pub constraints: Vec<String>,
+    /// Explanation of the conflict
+    pub message: String,
+}
+
+impl fmt::Display for ConstraintConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.constraints.join(", "))
+    }
+}
+
+/// Minimum and maximum weight
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightBounds {
+    /// Minimum weight
+    pub min: Option<f64>,
+    /// Maximum weight
+    pub max: Option<f64>,
+}
+
+/// Bounds on the combined weight of a group of assets
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupConstraint {
+    /// Group name (e.g. a sector)
+    pub name: String,
+    /// Assets in the group
+    pub members: Vec<String>,
+    /// Combined weight bounds
+    pub bounds: WeightBounds,
+}
+
+/// Constraint specification evaluated by the allocators
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllocationConstraints {
+    /// Disallow negative weights
+    pub long_only: bool,
+    /// Sum of weights
+    pub budget: f64,
+    /// Bounds for every asset without its own entry
+    pub default_bounds: WeightBounds,
+    /// Per-asset bounds
+    pub asset_bounds: HashMap<String, WeightBounds>,
+    /// Group bounds
+    pub groups: Vec<GroupConstraint>,
+    /// Maximum sum of absolute weight changes from the previous allocation
+    pub max_turnover: Option<f64>,
+    /// Minimum number of non-zero positions
+    pub min_positions: Option<usize>,
+    /// Maximum number of non-zero positions
+    pub max_positions: Option<usize>,
+}
+
+impl Default for AllocationConstraints {
+    fn default() -> Self {
+        Self {
+            long_only: true,
+            budget: 1.0,
+            default_bounds: WeightBounds::default(),
+            asset_bounds: HashMap::new(),
+            groups: Vec::new(),
+            max_turnover: None,
+            min_positions: None,
+            max_positions: None,
+        }
+    }
+}
+
+/// Result of applying constraints to a target allocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstrainedAllocation {
+    /// Constrained weights
+    pub weights: HashMap<String, f64>,
+    /// Turnover against the previous allocation, if one was given
+    pub turnover: Option<f64>,
+    /// Constraints the solution sits on
+    pub binding: Vec<String>,
+}
+
+impl AllocationConstraints {
+    /// Parse the line-based constraint DSL
+    pub fn parse(spec: &str) -> Result<Self, ConstraintError> {
+        let mut constraints = Self::default();
+        let lines = spec.lines()
+            .enumerate()
+            .flat_map(|(index, line)| line.split(';').map(move |part| (index, part)));
+
+        for (index, raw) in lines {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let err = |message: String| ConstraintError::Parse { line: index + 1, message };
+
+            let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let rest = rest.trim();
+            match keyword {
+                "long_only" => constraints.long_only = true,
+                "long_short" => constraints.long_only = false,
+                "budget" => constraints.budget = parse_number(rest).map_err(err)?,
+                "weight" => {
+                    let (asset, bound) = rest.split_once(char::is_whitespace)
+                        .ok_or_else(|| err("expected `weight <asset|*> <op> <value>`".to_string()))?;
+                    let bounds = if asset == "*" {
+                        &mut constraints.default_bounds
+                    } else {
+                        constraints.asset_bounds.entry(asset.to_string()).or_default()
+                    };
+                    apply_bound(bounds, bound).map_err(err)?;
+                }
+                "group" => {
+                    let (name, rest) = rest.split_once(char::is_whitespace)
+                        .ok_or_else(|| err("expected `group <name> [members] <op> <value>`".to_string()))?;
+                    let (members, bound) = rest.trim().strip_prefix('[')
+                        .and_then(|r| r.split_once(']'))
+                        .ok_or_else(|| err("group members must be in [brackets]".to_string()))?;
+                    let members: Vec<String> = members.split(',')
+                        .map(|m| m.trim().to_string())
+                        .filter(|m| !m.is_empty())
+                        .collect();
+
+                    let group = match constraints.groups.iter_mut().position(|g| g.name == name) {
+                        Some(i) => &mut constraints.groups[i],
+                        None => {
+                            constraints.groups.push(GroupConstraint {
+                                name: name.to_string(),
+                                members: Vec::new(),
+                                bounds: WeightBounds::default(),
+                            });
+                            constraints.groups.last_mut().expect("just pushed")
+                        }
+                    };
+                    for member in members {
+                        if !group.members.contains(&member) {
+                            group.members.push(member);
+                        }
+                    }
+                    apply_bound(&mut group.bounds, bound).map_err(err)?;
+                }
+                "turnover" => {
+                    let value = rest.strip_prefix("<=")
+                        .ok_or_else(|| err("turnover only supports `<=`".to_string()))?;
+                    constraints.max_turnover = Some(parse_number(value).map_err(err)?);
+                }
+                "positions" => {
+                    let mut bounds = WeightBounds::default();
+                    apply_bound(&mut bounds, rest).map_err(err)?;
+                    if let Some(min) = bounds.min {
+                        constraints.min_positions = Some(min as usize);
+                    }
+                    if let Some(max) = bounds.max {
+                        constraints.max_positions = Some(max as usize);
+                    }
+                }
+                other => return Err(err(format!("unknown constraint `{}`", other))),
+            }
+        }
+        Ok(constraints)
+    }
+
+    /// Bounds in effect for an asset
+    fn bounds_for(&self, asset: &str) -> (f64, f64) {
+        let own = self.asset_bounds.get(asset).copied().unwrap_or_default();
+        let floor = if self.long_only { 0.0 } else { f64::NEG_INFINITY };
+        let lo = own.min.or(self.default_bounds.min).unwrap_or(0.0).max(floor);
+        let hi = own.max.or(self.default_bounds.max).unwrap_or(f64::INFINITY);
+        (lo, hi)
+    }
+
+    /// Name used for an asset's bound in diagnostics
+    fn bound_name(&self, asset: &str) -> String {
+        if self.asset_bounds.contains_key(asset) {
+            format!("weight[{}]", asset)
+        } else {
+            "weight[*]".to_string()
+        }
+    }
+
+    /// Find conflicts between the constraints for a universe of assets,
+    /// without solving
+    pub fn diagnose(&self, assets: &[String], previous: Option<&HashMap<String, f64>>) -> Vec<ConstraintConflict> {
+        let mut conflicts = Vec::new();
+        let mut conflict = |constraints: Vec<String>, message: String| {
+            conflicts.push(ConstraintConflict { constraints, message });
+        };
+        let bounds: HashMap<&str, (f64, f64)> = assets.iter().map(|a| (a.as_str(), self.bounds_for(a))).collect();
+
+        for asset in assets {
+            let (lo, hi) = bounds[asset.as_str()];
+            if lo > hi + TOLERANCE {
+                conflict(
+                    vec![self.bound_name(asset), "long_only".to_string()],
+                    format!("{} needs a weight of at least {:.4} but at most {:.4}", asset, lo, hi),
+                );
+            }
+        }
+
+        let min_sum: f64 = bounds.values().map(|(lo, _)| lo).sum();
+        let max_sum: f64 = bounds.values().map(|(_, hi)| hi).sum();
+        if min_sum > self.budget + TOLERANCE {
+            let mut names: Vec<String> = assets.iter()
+                .filter(|a| bounds[a.as_str()].0 > 0.0)
+                .map(|a| self.bound_name(a))
+                .collect();
+            names.sort();
+            names.dedup();
+            names.push("budget".to_string());
+            conflict(names, format!("minimum weights sum to {:.4}, above the {:.4} budget", min_sum, self.budget));
+        }
+        if max_sum < self.budget - TOLERANCE {
+            conflict(
+                vec!["weight[*]".to_string(), "budget".to_string()],
+                format!("maximum weights only reach {:.4} of the {:.4} budget", max_sum, self.budget),
+            );
+        }
+
+        for group in &self.groups {
+            let name = format!("group[{}]", group.name);
+            let members: Vec<&str> = group.members.iter()
+                .map(String::as_str)
+                .filter(|m| bounds.contains_key(m))
+                .collect();
+            let member_min: f64 = members.iter().map(|m| bounds[m].0).sum();
+            let member_max: f64 = members.iter().map(|m| bounds[m].1).sum();
+
+            if let (Some(min), Some(max)) = (group.bounds.min, group.bounds.max) {
+                if min > max + TOLERANCE {
+                    conflict(vec![name.clone()], format!("group {} minimum {:.4} exceeds its maximum {:.4}", group.name, min, max));
+                }
+            }
+            if let Some(max) = group.bounds.max {
+                if member_min > max + TOLERANCE {
+                    conflict(
+                        vec![name.clone(), "weight[..]".to_string()],
+                        format!("member minimums of {} sum to {:.4}, above its {:.4} cap", group.name, member_min, max),
+                    );
+                }
+            }
+            if let Some(min) = group.bounds.min {
+                if member_max < min - TOLERANCE {
+                    conflict(
+                        vec![name.clone(), "weight[..]".to_string()],
+                        format!("member maximums of {} only reach {:.4}, below its {:.4} floor", group.name, member_max, min),
+                    );
+                }
+                if min > self.budget + TOLERANCE {
+                    conflict(vec![name.clone(), "budget".to_string()], format!("group {} floor {:.4} exceeds the budget", group.name, min));
+                }
+            }
+        }
+
+        // Group caps that leave too little room for the rest of the budget
+        let capped: HashSet<&str> = self.groups.iter()
+            .filter(|g| g.bounds.max.is_some())
+            .flat_map(|g| g.members.iter().map(String::as_str))
+            .collect();
+        if !capped.is_empty() {
+            let capped_room: f64 = self.groups.iter().filter_map(|g| g.bounds.max).sum();
+            let free_room: f64 = bounds.iter().filter(|(a, _)| !capped.contains(*a)).map(|(_, (_, hi))| hi).sum();
+            if self.long_only && capped_room + free_room < self.budget - TOLERANCE {
+                let mut names: Vec<String> = self.groups.iter()
+                    .filter(|g| g.bounds.max.is_some())
+                    .map(|g| format!("group[{}]", g.name))
+                    .collect();
+                names.push("budget".to_string());
+                conflict(names, format!(
+                    "group caps and uncapped asset maximums only reach {:.4} of the {:.4} budget",
+                    capped_room + free_room, self.budget
+                ));
+            }
+        }
+
+        let required = bounds.values().filter(|(lo, _)| *lo > TOLERANCE).count();
+        if let Some(max_positions) = self.max_positions {
+            if required > max_positions {
+                conflict(
+                    vec!["positions".to_string(), "weight[..]".to_string()],
+                    format!("{} assets have minimum weights but at most {} positions are allowed", required, max_positions),
+                );
+            }
+            let mut caps: Vec<f64> = bounds.values().map(|(_, hi)| *hi).collect();
+            caps.sort_by(|a, b| b.total_cmp(a));
+            let reachable: f64 = caps.iter().take(max_positions).sum();
+            if reachable < self.budget - TOLERANCE {
+                conflict(
+                    vec!["positions".to_string(), "weight[*]".to_string(), "budget".to_string()],
+                    format!("{} positions at their maximum weights only reach {:.4} of the budget", max_positions, reachable),
+                );
+            }
+        }
+        if let Some(min_positions) = self.min_positions {
+            if min_positions > assets.len() {
+                conflict(
+                    vec!["positions".to_string()],
+                    format!("at least {} positions required but only {} assets available", min_positions, assets.len()),
+                );
+            }
+            if let Some(max_positions) = self.max_positions {
+                if min_positions > max_positions {
+                    conflict(vec!["positions".to_string()], format!("position range {}..{} is empty", min_positions, max_positions));
+                }
+            }
+        }
+
+        // Turnover needed just to move the previous weights inside their bounds
+        if let (Some(max_turnover), Some(previous)) = (self.max_turnover, previous) {
+            let forced: f64 = previous.iter()
+                .map(|(asset, w)| {
+                    let (lo, hi) = bounds.get(asset.as_str()).copied().unwrap_or((0.0, 0.0));
+                    (lo - w).max(0.0) + (w - hi).max(0.0)
+                })
+                .sum::<f64>()
+                + bounds.iter()
+                    .filter(|(asset, _)| !previous.contains_key(**asset))
+                    .map(|(_, (lo, _))| lo.max(0.0))
+                    .sum::<f64>();
+            if forced > max_turnover + TOLERANCE {
+                conflict(
+                    vec!["turnover".to_string(), "weight[..]".to_string()],
+                    format!("bringing the previous allocation within bounds needs {:.4} turnover, above the {:.4} limit", forced, max_turnover),
+                );
+            }
+        }
+
+        conflicts
+    }
+
+    /// Project a target allocation onto the constraints
+    ///
+    /// Assets in `target` form the universe, along with any asset that has a
+    /// positive minimum. Weights are kept as close to the target's shape as
+    /// the constraints allow; with a turnover limit the result is moved from
+    /// `previous` towards that projection only as far as the limit permits.
+    pub fn apply(
+        &self,
+        target: &HashMap<String, f64>,
+        previous: Option<&HashMap<String, f64>>,
+    ) -> Result<ConstrainedAllocation, ConstraintError> {
+        let mut assets: Vec<String> = target.keys().cloned().collect();
+        for (asset, bounds) in &self.asset_bounds {
+            if bounds.min.is_some_and(|min| min > 0.0) && !target.contains_key(asset) {
+                assets.push(asset.clone());
+            }
+        }
+        assets.sort();
+
+        let conflicts = self.diagnose(&assets, previous);
+        if !conflicts.is_empty() {
+            return Err(ConstraintError::Infeasible(conflicts));
+        }
+
+        // Cardinality: keep forced positions, then the largest targets
+        let mut included: Vec<String> = assets.clone();
+        if let Some(max_positions) = self.max_positions {
+            included.sort_by(|a, b| {
+                let forced = |x: &String| self.bounds_for(x).0 > TOLERANCE;
+                forced(b).cmp(&forced(a)).then_with(|| {
+                    let w = |x: &String| target.get(x).copied().unwrap_or(0.0).abs();
+                    w(b).total_cmp(&w(a))
+                })
+            });
+            included.truncate(max_positions);
+        }
+        let bounds: Vec<(f64, f64)> = assets.iter()
+            .map(|a| if included.contains(a) { self.bounds_for(a) } else { (0.0, 0.0) })
+            .collect();
+
+        let start: Vec<f64> = assets.iter().map(|a| target.get(a).copied().unwrap_or(0.0)).collect();
+        let mut weights = self.project(&assets, &bounds, start.clone());
+
+        let mut turnover = None;
+        if let Some(previous) = previous {
+            let prev: Vec<f64> = assets.iter().map(|a| previous.get(a).copied().unwrap_or(0.0)).collect();
+            // Assets dropped from the universe are sold regardless
+            let dropped: f64 = previous.iter()
+                .filter(|(a, _)| !assets.contains(*a))
+                .map(|(_, w)| w.abs())
+                .sum();
+            let turnover_of = |w: &[f64]| dropped + w.iter().zip(&prev).map(|(a, b)| (a - b).abs()).sum::<f64>();
+
+            if let Some(max_turnover) = self.max_turnover {
+                if turnover_of(&weights) > max_turnover + TOLERANCE {
+                    // Largest step from previous towards the target that stays within the limit
+                    let (mut lo, mut hi) = (0.0, 1.0);
+                    let mut best = None;
+                    for _ in 0..40 {
+                        let alpha = (lo + hi) / 2.0;
+                        let blend: Vec<f64> = prev.iter().zip(&weights).map(|(p, w)| p + alpha * (w - p)).collect();
+                        let candidate = self.project(&assets, &bounds, blend);
+                        if turnover_of(&candidate) <= max_turnover + TOLERANCE {
+                            best = Some(candidate);
+                            lo = alpha;
+                        } else {
+                            hi = alpha;
+                        }
+                    }
+                    weights = best.ok_or_else(|| ConstraintError::Infeasible(vec![ConstraintConflict {
+                        constraints: vec!["turnover".to_string()],
+                        message: "no allocation within the turnover limit satisfies the other constraints".to_string(),
+                    }]))?;
+                }
+            }
+            turnover = Some(turnover_of(&weights));
+        }
+
+        let violations = self.violations(&assets, &bounds, &weights);
+        if !violations.is_empty() {
+            return Err(ConstraintError::Infeasible(vec![ConstraintConflict {
+                message: "constraints could not be satisfied simultaneously".to_string(),
+                constraints: violations,
+            }]));
+        }
+
+        let binding = self.binding(&assets, &bounds, &weights, turnover);
+        Ok(ConstrainedAllocation {
+            weights: assets.into_iter().zip(weights).collect(),
+            turnover,
+            binding,
+        })
+    }
+
+    /// Alternating projection onto the bounds, group and budget constraints
+    fn project(&self, assets: &[String], bounds: &[(f64, f64)], mut weights: Vec<f64>) -> Vec<f64> {
+        let groups: Vec<(&GroupConstraint, Vec<usize>)> = self.groups.iter()
+            .map(|g| (g, (0..assets.len()).filter(|&i| g.members.contains(&assets[i])).collect()))
+            .collect();
+        let clamp = |w: &mut Vec<f64>| {
+            for (w, (lo, hi)) in w.iter_mut().zip(bounds) {
+                *w = w.clamp(*lo, *hi);
+            }
+        };
+
+        clamp(&mut weights);
+        for _ in 0..MAX_ITERATIONS {
+            for (group, members) in &groups {
+                let sum: f64 = members.iter().map(|&i| weights[i]).sum();
+                if let Some(max) = group.bounds.max.filter(|max| sum > max + TOLERANCE / 10.0) {
+                    shift(&mut weights, bounds, members, max - sum);
+                } else if let Some(min) = group.bounds.min.filter(|min| sum < min - TOLERANCE / 10.0) {
+                    shift(&mut weights, bounds, members, min - sum);
+                }
+            }
+
+            let sum: f64 = weights.iter().sum();
+            let gap = self.budget - sum;
+            if gap.abs() > TOLERANCE / 10.0 {
+                // Adding weight avoids groups already at their cap
+                let eligible: Vec<usize> = (0..weights.len())
+                    .filter(|&i| gap < 0.0 || !groups.iter().any(|(g, members)| {
+                        members.contains(&i)
+                            && g.bounds.max.is_some_and(|max| members.iter().map(|&j| weights[j]).sum::<f64>() >= max - TOLERANCE / 10.0)
+                    }))
+                    .collect();
+                shift(&mut weights, bounds, &eligible, gap);
+            }
+            clamp(&mut weights);
+
+            if self.violations(assets, bounds, &weights).is_empty() {
+                break;
+            }
+        }
+        weights
+    }
+
+    /// Names of constraints the weights violate
+    fn violations(&self, assets: &[String], bounds: &[(f64, f64)], weights: &[f64]) -> Vec<String> {
+        let mut violated = Vec::new();
+        for (i, (lo, hi)) in bounds.iter().enumerate() {
+            if weights[i] < lo - TOLERANCE || weights[i] > hi + TOLERANCE {
+                violated.push(self.bound_name(&assets[i]));
+            }
+        }
+        for group in &self.groups {
+            let sum: f64 = assets.iter().zip(weights).filter(|(a, _)| group.members.contains(a)).map(|(_, w)| w).sum();
+            if group.bounds.max.is_some_and(|max| sum > max + TOLERANCE) || group.bounds.min.is_some_and(|min| sum < min - TOLERANCE) {
+                violated.push(format!("group[{}]", group.name));
+            }
+        }
+        if (weights.iter().sum::<f64>() - self.budget).abs() > TOLERANCE {
+            violated.push("budget".to_string());
+        }
+        let positions = weights.iter().filter(|w| w.abs() > TOLERANCE).count();
+        if self.min_positions.is_some_and(|min| positions < min) || self.max_positions.is_some_and(|max| positions > max) {
+            violated.push("positions".to_string());
+        }
+        violated.dedup();
+        violated
+    }
+
+    /// Names of constraints the weights sit on
+    fn binding(&self, assets: &[String], bounds: &[(f64, f64)], weights: &[f64], turnover: Option<f64>) -> Vec<String> {
+        let mut binding = Vec::new();
+        for (i, (lo, hi)) in bounds.iter().enumerate() {
+            let at_bound = (weights[i] - hi).abs() < TOLERANCE || (*lo > 0.0 && (weights[i] - lo).abs() < TOLERANCE);
+            if at_bound && hi > lo {
+                binding.push(format!("weight[{}]", assets[i]));
+            }
+        }
+        for group in &self.groups {
+            let sum: f64 = assets.iter().zip(weights).filter(|(a, _)| group.members.contains(a)).map(|(_, w)| w).sum();
+            let at_cap = group.bounds.max.is_some_and(|max| (sum - max).abs() < TOLERANCE);
+            let at_floor = group.bounds.min.is_some_and(|min| (sum - min).abs() < TOLERANCE);
+            if at_cap || at_floor {
+                binding.push(format!("group[{}]", group.name));
+            }
+        }
+        if self.max_turnover.zip(turnover).is_some_and(|(max, t)| (t - max).abs() < TOLERANCE) {
+            binding.push("turnover".to_string());
+        }
+        binding
+    }
+}
+
+impl FromStr for AllocationConstraints {
+    type Err = ConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Move `amount` of weight into (positive) or out of (negative) the given
+/// assets, in proportion to each one's room before its bound
+fn shift(weights: &mut [f64], bounds: &[(f64, f64)], members: &[usize], amount: f64) {
+    let room: Vec<f64> = members.iter()
+        .map(|&i| if amount > 0.0 { bounds[i].1 - weights[i] } else { weights[i] - bounds[i].0 })
+        .map(|r| if r.is_finite() { r.max(0.0) } else { 1.0 })
+        .collect();
+    let total: f64 = room.iter().sum();
+    if total <= 0.0 {
+        return;
+    }
+    for (&i, r) in members.iter().zip(&room) {
+        weights[i] += amount * r / total;
+    }
+}
+
+fn parse_number(s: &str) -> Result<f64, String> {
+    s.trim().parse::<f64>().map_err(|_| format!("expected a number, got `{}`", s.trim()))
+}
+
+/// Apply `<= v`, `>= v` or `= v` to a pair of bounds
+fn apply_bound(bounds: &mut WeightBounds, spec: &str) -> Result<(), String> {
+    let spec = spec.trim();
+    if let Some(value) = spec.strip_prefix("<=") {
+        bounds.max = Some(parse_number(value)?);
+    } else if let Some(value) = spec.strip_prefix(">=") {
+        bounds.min = Some(parse_number(value)?);
+    } else if let Some(value) = spec.strip_prefix('=') {
+        let value = parse_number(value)?;
+        bounds.min = Some(value);
+        bounds.max = Some(value);
+    } else {
+        return Err(format!("expected `<=`, `>=` or `=` followed by a value, got `{}`", spec));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weights(pairs: &[(&str, f64)]) -> HashMap<String, f64> {
+        pairs.iter().map(|(a, w)| (a.to_string(), *w)).collect()
+    }
+
+    #[test]
+    fn test_dsl_caps_groups_and_reports_conflicts() {
+        let constraints = AllocationConstraints::parse(
+            "long_only\nbudget 1.0\nweight * <= 0.8\ngroup defi [UNI, AAVE] <= 0.3 # sector cap\n",
+        ).unwrap();
+
+        let target = weights(&[("BTC", 0.2), ("UNI", 0.4), ("AAVE", 0.4)]);
+        let result = constraints.apply(&target, None).unwrap();
+        let w = &result.weights;
+        assert!((w.values().sum::<f64>() - 1.0).abs() < 1e-6);
+        assert!(w["UNI"] + w["AAVE"] <= 0.3 + 1e-6);
+        assert!(w["BTC"] <= 0.8 + 1e-6);
+        assert!(w.values().all(|v| *v >= -1e-9));
+        assert!(result.binding.contains(&"group[defi]".to_string()));
+
+        // With only DeFi assets the cap cannot reach the budget
+        let target = weights(&[("UNI", 0.5), ("AAVE", 0.5)]);
+        let Err(ConstraintError::Infeasible(conflicts)) = constraints.apply(&target, None) else {
+            panic!("expected infeasible constraints");
+        };
+        assert!(conflicts.iter().any(|c| c.constraints.contains(&"group[defi]".to_string())
+            && c.constraints.contains(&"budget".to_string())));
+
+        assert!(matches!(AllocationConstraints::parse("weight BTC ~ 1"), Err(ConstraintError::Parse { line: 1, .. })));
+    }
+}
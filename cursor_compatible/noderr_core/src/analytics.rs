@@ -20,6 +20,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
+use crate::analytics_math;
 use crate::storage::{StrategyStorage, StorageError, TimeRange, StoredExecution, PerformanceImpact};
 use crate::strategy::{StrategyId, StrategyPerformance};
 use crate::execution::{ExecutionStatus};
@@ -73,7 +74,7 @@ pub struct AnalyticsFilter {
 }
 
 /// Performance summary for a strategy
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceSummary {
     /// Total number of trades
     pub total_trades: usize,
@@ -104,7 +105,7 @@ pub struct PerformanceSummary {
 }
 
 /// Execution statistics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionStats {
     /// Total executions
     pub total_executions: usize,
@@ -486,59 +487,21 @@ impl DefaultAnalytics {
     where
         T: Into<f64> + Copy,
     {
-        if data_points.len() < 2 {
-            return TrendDirection::Neutral;
-        }
-        
         // Convert timestamps to x values (seconds since first timestamp)
-        let base_timestamp = data_points[0].0.timestamp() as f64;
-        
-        // Collect x and y values
-        let mut x_values = Vec::with_capacity(data_points.len());
-        let mut y_values = Vec::with_capacity(data_points.len());
-        
-        for (timestamp, value) in data_points {
-            let x = (timestamp.timestamp() as f64) - base_timestamp;
-            let y: f64 = (*value).into();
-            
-            x_values.push(x);
-            y_values.push(y);
-        }
-        
-        // Calculate means
-        let n = x_values.len() as f64;
-        let mean_x = x_values.iter().sum::<f64>() / n;
-        let mean_y = y_values.iter().sum::<f64>() / n;
-        
-        // Calculate slope using linear regression
-        let mut numerator = 0.0;
-        let mut denominator = 0.0;
-        
-        for i in 0..x_values.len() {
-            let x_diff = x_values[i] - mean_x;
-            let y_diff = y_values[i] - mean_y;
-            
-            numerator += x_diff * y_diff;
-            denominator += x_diff * x_diff;
-        }
-        
-        // Avoid division by zero
-        if denominator.abs() < 1e-10 {
-            return TrendDirection::Neutral;
-        }
-        
-        let slope = numerator / denominator;
+        let base_timestamp = match data_points.first() {
+            Some((timestamp, _)) => timestamp.timestamp() as f64,
+            None => return TrendDirection::Neutral,
+        };
         
-        // Determine trend direction based on slope
-        // Use a small threshold to avoid classifying very slight trends
-        const SLOPE_THRESHOLD: f64 = 1e-5;
+        let x_values: Vec<f64> = data_points.iter()
+            .map(|(timestamp, _)| (timestamp.timestamp() as f64) - base_timestamp)
+            .collect();
+        let y_values: Vec<f64> = data_points.iter().map(|(_, value)| (*value).into()).collect();
         
-        if slope > SLOPE_THRESHOLD {
-            TrendDirection::Up
-        } else if slope < -SLOPE_THRESHOLD {
-            TrendDirection::Down
-        } else {
-            TrendDirection::Neutral
+        match analytics_math::trend_sign(analytics_math::linear_regression_slope(&x_values, &y_values)) {
+            1 => TrendDirection::Up,
+            -1 => TrendDirection::Down,
+            _ => TrendDirection::Neutral,
         }
     }
     
@@ -554,86 +517,22 @@ impl DefaultAnalytics {
         let first_value: f64 = data_points.first()?.1.into();
         let last_value: f64 = data_points.last()?.1.into();
         
-        // Avoid division by zero
-        if first_value.abs() < 1e-10 {
-            return None;
-        }
-        
-        Some((last_value - first_value) / first_value * 100.0)
+        analytics_math::percent_change(first_value, last_value)
     }
     
     /// Calculate Sharpe ratio
     fn calculate_sharpe_ratio(&self, returns: &[f64], risk_free_rate: f64) -> Option<f64> {
-        if returns.is_empty() {
-            return None;
-        }
-        
-        let mean_return: f64 = returns.iter().sum::<f64>() / returns.len() as f64;
-        let excess_return = mean_return - risk_free_rate;
-        
-        // Calculate standard deviation
-        let variance = returns.iter()
-            .map(|r| (r - mean_return).powi(2))
-            .sum::<f64>() / returns.len() as f64;
-        
-        let std_dev = variance.sqrt();
-        
-        if std_dev < f64::EPSILON {
-            return None;
-        }
-        
-        Some(excess_return / std_dev)
+        analytics_math::sharpe_ratio(returns, risk_free_rate)
     }
     
     /// Calculate Sortino ratio (only considers downside deviation)
     fn calculate_sortino_ratio(&self, returns: &[f64], risk_free_rate: f64) -> Option<f64> {
-        if returns.is_empty() {
-            return None;
-        }
-        
-        let mean_return: f64 = returns.iter().sum::<f64>() / returns.len() as f64;
-        let excess_return = mean_return - risk_free_rate;
-        
-        // Calculate downside deviation (only negative returns)
-        let downside_returns: Vec<f64> = returns.iter()
-            .filter_map(|r| if *r < 0.0 { Some(r.powi(2)) } else { None })
-            .collect();
-        
-        if downside_returns.is_empty() {
-            return None;
-        }
-        
-        let downside_deviation = (downside_returns.iter().sum::<f64>() / downside_returns.len() as f64).sqrt();
-        
-        if downside_deviation < f64::EPSILON {
-            return None;
-        }
-        
-        Some(excess_return / downside_deviation)
+        analytics_math::sortino_ratio(returns, risk_free_rate)
     }
     
     /// Calculate drawdown metrics
     fn calculate_drawdown_metrics(&self, values: &[f64]) -> (f64, f64) {
-        if values.is_empty() {
-            return (0.0, 0.0);
-        }
-        
-        let mut max_drawdown = 0.0;
-        let mut current_drawdown = 0.0;
-        let mut peak = values[0];
-        
-        for &value in values {
-            if value > peak {
-                peak = value;
-                current_drawdown = 0.0;
-            } else {
-                let drawdown = if peak > 0.0 { (peak - value) / peak } else { 0.0 };
-                current_drawdown = drawdown;
-                max_drawdown = max_drawdown.max(drawdown);
-            }
-        }
-        
-        (max_drawdown * 100.0, current_drawdown * 100.0)
+        analytics_math::drawdown_metrics(values)
     }
     
     /// Extract daily returns from executions
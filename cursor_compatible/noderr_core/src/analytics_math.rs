@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+//! Pure performance-analytics kernels shared by the analytics engine and the
+//! WebAssembly build. Nothing in this module may depend on tokio, Redis or
+//! any other native-only crate.
+
+/// Slopes with an absolute value below this are treated as flat
+pub const SLOPE_THRESHOLD: f64 = 1e-5;
+
+/// Least-squares slope of `ys` against `xs`
+///
+/// Returns `None` for fewer than two points or when all `xs` are equal.
+pub fn linear_regression_slope(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    let len = xs.len().min(ys.len());
+    if len < 2 {
+        return None;
+    }
+
+    // Calculate means
+    let n = len as f64;
+    let mean_x = xs[..len].iter().sum::<f64>() / n;
+    let mean_y = ys[..len].iter().sum::<f64>() / n;
+
+    // Calculate slope using linear regression
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+
+    for i in 0..len {
+        let x_diff = xs[i] - mean_x;
+        let y_diff = ys[i] - mean_y;
+
+        numerator += x_diff * y_diff;
+        denominator += x_diff * x_diff;
+    }
+
+    // Avoid division by zero
+    if denominator.abs() < 1e-10 {
+        return None;
+    }
+
+    Some(numerator / denominator)
+}
+
+/// Trend sign of a slope: 1 for up, -1 for down, 0 for neutral
+pub fn trend_sign(slope: Option<f64>) -> i8 {
+    match slope {
+        Some(slope) if slope > SLOPE_THRESHOLD => 1,
+        Some(slope) if slope < -SLOPE_THRESHOLD => -1,
+        _ => 0,
+    }
+}
+
+/// Percent change from `first` to `last`
+pub fn percent_change(first: f64, last: f64) -> Option<f64> {
+    // Avoid division by zero
+    if first.abs() < 1e-10 {
+        return None;
+    }
+
+    Some((last - first) / first * 100.0)
+}
+
+/// Sharpe ratio of a return series
+pub fn sharpe_ratio(returns: &[f64], risk_free_rate: f64) -> Option<f64> {
+    if returns.is_empty() {
+        return None;
+    }
+
+    let mean_return: f64 = returns.iter().sum::<f64>() / returns.len() as f64;
+    let excess_return = mean_return - risk_free_rate;
+
+    // Calculate standard deviation
+    let variance = returns.iter()
+        .map(|r| (r - mean_return).powi(2))
+        .sum::<f64>() / returns.len() as f64;
+
+    let std_dev = variance.sqrt();
+
+    if std_dev < f64::EPSILON {
+        return None;
+    }
+
+    Some(excess_return / std_dev)
+}
+
+/// Sortino ratio of a return series (only considers downside deviation)
+pub fn sortino_ratio(returns: &[f64], risk_free_rate: f64) -> Option<f64> {
+    if returns.is_empty() {
+        return None;
+    }
+
+    let mean_return: f64 = returns.iter().sum::<f64>() / returns.len() as f64;
+    let excess_return = mean_return - risk_free_rate;
+
+    // Calculate downside deviation (only negative returns)
+    let downside_returns: Vec<f64> = returns.iter()
+        .filter_map(|r| if *r < 0.0 { Some(r.powi(2)) } else { None })
+        .collect();
+
+    if downside_returns.is_empty() {
+        return None;
+    }
+
+    let downside_deviation = (downside_returns.iter().sum::<f64>() / downside_returns.len() as f64).sqrt();
+
+    if downside_deviation < f64::EPSILON {
+        return None;
+    }
+
+    Some(excess_return / downside_deviation)
+}
+
+/// Maximum and current drawdown of an equity curve, in percent
+pub fn drawdown_metrics(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let mut max_drawdown: f64 = 0.0;
+    let mut current_drawdown = 0.0;
+    let mut peak = values[0];
+
+    for &value in values {
+        if value > peak {
+            peak = value;
+            current_drawdown = 0.0;
+        } else {
+            let drawdown = if peak > 0.0 { (peak - value) / peak } else { 0.0 };
+            current_drawdown = drawdown;
+            max_drawdown = max_drawdown.max(drawdown);
+        }
+    }
+
+    (max_drawdown * 100.0, current_drawdown * 100.0)
+}
+
+/// Simple period-over-period returns of an equity curve
+pub fn period_returns(values: &[f64]) -> Vec<f64> {
+    values
+        .windows(2)
+        .filter(|w| w[0].abs() > f64::EPSILON)
+        .map(|w| (w[1] - w[0]) / w[0])
+        .collect()
+}
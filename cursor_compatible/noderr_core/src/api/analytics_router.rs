@@ -35,7 +35,12 @@ use crate::analytics::{
 };
 use crate::telemetry_streamer::{TelemetryStreamer, TelemetryStreamError};
 use crate::websocket_manager::{WebSocketManager, WebSocketMessage, WebSocketError};
+use crate::wire_format::EncodedFrame;
 use crate::trust_score_engine::{TrustScoreEngine, TrustScoreError, TrustScore, TrustScoreHistory};
+use crate::trust_decay_service::TrustDecayConfig;
+use crate::simulation::trust_decay_simulator::{
+    forecast_trust_score, DecaySimulationParams, RecoveryProfile, TrustForecastParams, TrustScoreForecast,
+};
 use crate::api::auth::{AuthenticatedUser, extract_user, get_permissions_from_user};
 use crate::execution::ExecutionStatus;
 
@@ -70,6 +75,23 @@ pub struct AnalyticsQuery {
     pub min_confidence: Option<f64>,
 }
 
+/// Query parameters for the trust score forecast
+#[derive(Debug, Deserialize)]
+pub struct TrustForecastQuery {
+    /// Strategy ID to forecast
+    pub strategy_id: String,
+    /// Forecast horizon in days
+    pub days: Option<usize>,
+    /// Daily decay factor; defaults to the decay service default
+    pub decay_factor: Option<f64>,
+    /// Days of history used to estimate recovery frequency
+    pub lookback_days: Option<i64>,
+    /// Smallest daily score rise counted as a recovery
+    pub min_boost: Option<f64>,
+    /// Number of simulated paths
+    pub paths: Option<usize>,
+}
+
 /// API errors
 enum ApiError {
     Unauthorized,
@@ -131,6 +153,7 @@ impl From<TrustScoreError> for ApiError {
             TrustScoreError::RedisError(e) => ApiError::InternalError(format!("Redis error: {}", e)),
             TrustScoreError::TelemetryStreamError(e) => ApiError::InternalError(format!("Telemetry stream error: {}", e)),
             TrustScoreError::SerializationError(e) => ApiError::InternalError(format!("Serialization error: {}", e)),
+            TrustScoreError::HistoryError(e) => ApiError::InternalError(format!("Trust history error: {}", e)),
             TrustScoreError::InternalError(e) => ApiError::InternalError(e),
         }
     }
@@ -188,6 +211,7 @@ pub fn create_analytics_router(
         .route("/analytics/scan-anomalies", post(trigger_anomaly_scan))
         .route("/analytics/trust-score", get(get_trust_score))
         .route("/analytics/trust-history", get(get_trust_history))
+        .route("/analytics/trust-forecast", get(get_trust_forecast))
         .route("/analytics/update-trust-score", post(update_trust_score))
         .route("/analytics/ws", get(websocket_handler))
         .with_state(state)
@@ -475,15 +499,18 @@ async fn handle_socket(
     
     info!("WebSocket client connected: {}", client_id);
     
-    // Split the socket
-    let (mut socket_tx, mut socket_rx) = socket.split();
+    // Split the socket; both tasks write to it
+    let (socket_tx, mut socket_rx) = socket.split();
+    let socket_tx = Arc::new(tokio::sync::Mutex::new(socket_tx));
     
-    // Task to forward messages from client_rx to socket_tx
+    // Task to forward messages from client_rx to socket_tx in the client's encoding
+    let outgoing_manager = websocket_manager.clone();
+    let outgoing_client_id = client_id.clone();
+    let outgoing_tx = socket_tx.clone();
     let client_to_socket = tokio::spawn(async move {
         while let Some(message) = client_rx.recv().await {
-            // Serialize the message
-            if let Ok(json) = serde_json::to_string(&message) {
-                if let Err(e) = socket_tx.send(Message::Text(json)).await {
+            if let Ok(frame) = outgoing_manager.encode_for_client(&outgoing_client_id, &message).await {
+                if let Err(e) = outgoing_tx.lock().await.send(ws_message(frame)).await {
                     error!("Error sending WebSocket message: {}", e);
                     break;
                 }
@@ -494,46 +521,43 @@ async fn handle_socket(
     // Task to handle messages from socket_rx
     let socket_to_client = tokio::spawn(async move {
         while let Some(Ok(message)) = socket_rx.next().await {
-            match message {
-                Message::Text(text) => {
-                    // Process the message
-                    match websocket_manager.process_client_message(&client_id, &text).await {
-                        Ok(Some(response)) => {
-                            // If there's a response, serialize and send it back via client_rx
-                            if let Ok(json) = serde_json::to_string(&response) {
-                                if socket_tx.send(Message::Text(json)).await.is_err() {
-                                    break;
-                                }
-                            }
-                        },
-                        Ok(None) => {
-                            // No response needed
-                        },
-                        Err(e) => {
-                            error!("Error processing WebSocket message: {}", e);
-                            // Send error response
-                            let error_msg = WebSocketMessage {
-                                message_type: "error".to_string(),
-                                source: "system".to_string(),
-                                timestamp: Utc::now(),
-                                payload: serde_json::json!({
-                                    "error": format!("Error processing message: {}", e),
-                                }),
-                            };
-                            
-                            if let Ok(json) = serde_json::to_string(&error_msg) {
-                                if socket_tx.send(Message::Text(json)).await.is_err() {
-                                    break;
-                                }
-                            }
+            let result = match message {
+                Message::Text(text) => websocket_manager.process_client_message(&client_id, &text).await,
+                Message::Binary(bytes) => websocket_manager.process_client_binary(&client_id, &bytes).await,
+                Message::Close(_) => break,
+                // Ignore other message types
+                _ => continue,
+            };
+            
+            match result {
+                Ok(Some(response)) => {
+                    // If there's a response, send it back in the client's encoding
+                    if let Ok(frame) = websocket_manager.encode_for_client(&client_id, &response).await {
+                        if socket_tx.lock().await.send(ws_message(frame)).await.is_err() {
+                            break;
                         }
                     }
                 },
-                Message::Close(_) => {
-                    break;
+                Ok(None) => {
+                    // No response needed
                 },
-                _ => {
-                    // Ignore other message types
+                Err(e) => {
+                    error!("Error processing WebSocket message: {}", e);
+                    // Send error response
+                    let error_msg = WebSocketMessage {
+                        message_type: "error".to_string(),
+                        source: "system".to_string(),
+                        timestamp: Utc::now(),
+                        payload: serde_json::json!({
+                            "error": format!("Error processing message: {}", e),
+                        }),
+                    };
+                    
+                    if let Ok(frame) = websocket_manager.encode_for_client(&client_id, &error_msg).await {
+                        if socket_tx.lock().await.send(ws_message(frame)).await.is_err() {
+                            break;
+                        }
+                    }
                 }
             }
         }
@@ -553,6 +577,14 @@ async fn handle_socket(
     }
 }
 
+/// Convert an encoded message into a WebSocket frame
+fn ws_message(frame: EncodedFrame) -> Message {
+    match frame {
+        EncodedFrame::Text(text) => Message::Text(text),
+        EncodedFrame::Binary(bytes) => Message::Binary(bytes),
+    }
+}
+
 /// Get trust score for a strategy
 async fn get_trust_score(
     State(state): State<Arc<AnalyticsRouterState>>,
@@ -597,12 +629,71 @@ async fn get_trust_history(
         None => return Err(ApiError::InternalError("Trust score engine not available".to_string())),
     };
     
-    // Get trust history
-    let history = trust_engine.get_trust_history(&query.strategy_id).await?;
+    if let (Some(start), Some(end)) = (query.start_time, query.end_time) {
+        if start > end {
+            return Err(ApiError::BadRequest("start_time must not be after end_time".to_string()));
+        }
+    }
+    
+    // Get trust history within the requested range
+    let history = trust_engine
+        .get_trust_history_range(&query.strategy_id, query.start_time, query.end_time)
+        .await?;
     
     Ok(Json(history))
 }
 
+/// Forecast a strategy's trust score distribution from its decay curve and
+/// historical recovery frequency
+async fn get_trust_forecast(
+    State(state): State<Arc<AnalyticsRouterState>>,
+    user: AuthenticatedUser,
+    Query(query): Query<TrustForecastQuery>,
+) -> Result<Json<TrustScoreForecast>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    
+    // Check if user has access to the strategy
+    if !permissions.can_access_strategy(&query.strategy_id) {
+        return Err(ApiError::Forbidden);
+    }
+    
+    // Check if trust score engine is available
+    let trust_engine = match &state.trust_score_engine {
+        Some(engine) => engine,
+        None => return Err(ApiError::InternalError("Trust score engine not available".to_string())),
+    };
+    
+    let days = query.days.unwrap_or(14);
+    if days == 0 || days > 365 {
+        return Err(ApiError::BadRequest("days must be between 1 and 365".to_string()));
+    }
+    let decay_config = TrustDecayConfig::default();
+    let decay_factor = query.decay_factor.unwrap_or(decay_config.default_decay_factor_per_day);
+    if !(0.0..=1.0).contains(&decay_factor) {
+        return Err(ApiError::BadRequest("decay_factor must be between 0 and 1".to_string()));
+    }
+    
+    let current = trust_engine.get_trust_score(&query.strategy_id).await?;
+    let from = Utc::now() - chrono::Duration::days(query.lookback_days.unwrap_or(90));
+    let history = trust_engine.get_trust_history_range(&query.strategy_id, Some(from), None).await?;
+    
+    let forecast = forecast_trust_score(TrustForecastParams {
+        decay: DecaySimulationParams {
+            strategy_id: query.strategy_id.clone(),
+            initial_score: current.score,
+            decay_factor_per_day: decay_factor,
+            jitter: 0.0,
+            days,
+        },
+        recovery: history.recovery_profile(query.min_boost.unwrap_or(RecoveryProfile::DEFAULT_MIN_BOOST)),
+        paths: query.paths.unwrap_or(1_000).clamp(1, 20_000),
+        thresholds: vec![decay_config.warning_threshold, decay_config.critical_threshold],
+        seed: None,
+    });
+    
+    Ok(Json(forecast))
+}
+
 /// Update trust score for a strategy
 async fn update_trust_score(
     State(state): State<Arc<AnalyticsRouterState>>,
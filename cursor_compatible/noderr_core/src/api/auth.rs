@@ -40,6 +40,7 @@ use time::{Duration, OffsetDateTime};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use crate::api::mtls::ClientIdentity;
 use crate::telemetry::{TelemetryPermissions, TelemetryRole};
 
 /// JWT claims structure
@@ -92,6 +93,18 @@ impl AuthenticatedUser {
     }
 }
 
+impl From<&ClientIdentity> for AuthenticatedUser {
+    fn from(identity: &ClientIdentity) -> Self {
+        Self {
+            id: identity.operator_id.clone(),
+            name: identity.common_name.clone().unwrap_or_else(|| identity.operator_id.clone()),
+            email: String::new(),
+            role: identity.role,
+            strategy_ids: Vec::new(),
+        }
+    }
+}
+
 /// Auth configuration
 #[derive(Clone)]
 pub struct AuthConfig {
@@ -249,7 +262,13 @@ where
     type Rejection = AuthError;
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        // Extract the token from the Authorization header
+        // A verified mTLS client certificate is authoritative; a bearer token
+        // must not be able to claim a different operator on the same connection
+        if let Some(identity) = parts.extensions.get::<ClientIdentity>() {
+            return Ok(AuthenticatedUser::from(identity));
+        }
+        
+        // Otherwise extract the token from the Authorization header
         let TypedHeader(Authorization(bearer)) = parts
             .extract::<TypedHeader<Authorization<Bearer>>>()
             .await
@@ -532,7 +551,7 @@ fn telemetry_role_to_string(role: &TelemetryRole) -> String {
 }
 
 /// Convert string to TelemetryRole
-fn string_to_telemetry_role(role: &str) -> Option<TelemetryRole> {
+pub(crate) fn string_to_telemetry_role(role: &str) -> Option<TelemetryRole> {
     match role.to_lowercase().as_str() {
         "admin" => Some(TelemetryRole::Admin),
         "operator" => Some(TelemetryRole::Operator),
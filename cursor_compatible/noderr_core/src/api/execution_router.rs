@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Progress and in-flight controls (pause, resume, modify, cancel) for
+//! running execution algorithms.
+
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State, WebSocketUpgrade},
+    extract::ws::{Message, WebSocket},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, info};
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::execution_progress::{ExecutionProgress, ExecutionProgressTracker};
+use crate::execution_strategy::{AlgoModification, ExecutionStrategyRouter};
+use crate::telemetry::TelemetryRole;
+
+/// Shared state for the execution routes
+#[derive(Clone)]
+pub struct ExecutionRouterState {
+    /// Router running the algorithms; used for cancels
+    pub router: Arc<ExecutionStrategyRouter>,
+    /// Progress of running and recently finished executions
+    pub progress: Arc<ExecutionProgressTracker>,
+}
+
+/// Filters for listing and streaming progress
+#[derive(Debug, Default, Deserialize)]
+struct ProgressQuery {
+    /// Only this parent order
+    order_id: Option<String>,
+    /// Only executions still running
+    #[serde(default)]
+    running: bool,
+}
+
+impl ProgressQuery {
+    /// Whether an update passes the filters
+    fn matches(&self, progress: &ExecutionProgress) -> bool {
+        let order_matches = match &self.order_id {
+            Some(order_id) => *order_id == progress.order_id,
+            None => true,
+        };
+        order_matches && !(self.running && progress.state.is_finished())
+    }
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    NotFound,
+    BadRequest(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Resource not found".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// Create the execution progress API router
+pub fn create_execution_router(state: ExecutionRouterState) -> Router {
+    Router::new()
+        .route("/executions", get(list_executions))
+        .route("/executions/ws", get(progress_ws_handler))
+        .route("/executions/:order_id", get(get_execution))
+        .route("/executions/:order_id/cancel", post(cancel_execution))
+        .route("/executions/:order_id/pause", post(pause_execution))
+        .route("/executions/:order_id/resume", post(resume_execution))
+        .route("/executions/:order_id/modify", post(modify_execution))
+        .with_state(state)
+}
+
+// Progress for every tracked execution
+async fn list_executions(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Query(query): Query<ProgressQuery>,
+) -> Result<Json<Vec<ExecutionProgress>>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let executions = state.progress.list(query.running)
+        .into_iter()
+        .filter(|progress| query.matches(progress))
+        .collect();
+    Ok(Json(executions))
+}
+
+// Progress for one parent order
+async fn get_execution(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Path(order_id): Path<String>,
+) -> Result<Json<ExecutionProgress>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    state.progress.get(&order_id).map(Json).ok_or(ApiError::NotFound)
+}
+
+// Only operators and admins may control running executions
+fn require_trading_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// Cancel a running execution
+async fn cancel_execution(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Path(order_id): Path<String>,
+) -> Result<Json<ExecutionProgress>, ApiError> {
+    require_trading_role(&user)?;
+    if state.progress.get(&order_id).is_none() {
+        return Err(ApiError::NotFound);
+    }
+
+    state.router.cancel_execution(&order_id).await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    info!("Execution {} cancelled by {}", order_id, user.id);
+
+    state.progress.get(&order_id).map(Json).ok_or(ApiError::NotFound)
+}
+
+// Pause a running execution without cancelling it
+async fn pause_execution(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Path(order_id): Path<String>,
+) -> Result<Json<ExecutionProgress>, ApiError> {
+    require_trading_role(&user)?;
+    if state.progress.get(&order_id).is_none() {
+        return Err(ApiError::NotFound);
+    }
+
+    state.router.pause_execution(&order_id, &user.id).await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state.progress.get(&order_id).map(Json).ok_or(ApiError::NotFound)
+}
+
+// Resume a paused execution
+async fn resume_execution(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Path(order_id): Path<String>,
+) -> Result<Json<ExecutionProgress>, ApiError> {
+    require_trading_role(&user)?;
+    if state.progress.get(&order_id).is_none() {
+        return Err(ApiError::NotFound);
+    }
+
+    state.router.resume_execution(&order_id, &user.id).await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state.progress.get(&order_id).map(Json).ok_or(ApiError::NotFound)
+}
+
+// Change end time, participation cap or limit price of a running execution
+async fn modify_execution(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Path(order_id): Path<String>,
+    Json(modification): Json<AlgoModification>,
+) -> Result<Json<ExecutionProgress>, ApiError> {
+    require_trading_role(&user)?;
+    if state.progress.get(&order_id).is_none() {
+        return Err(ApiError::NotFound);
+    }
+
+    state.router.modify_execution(&order_id, modification, &user.id).await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    state.progress.get(&order_id).map(Json).ok_or(ApiError::NotFound)
+}
+
+// Stream progress updates to the dashboard
+async fn progress_ws_handler(
+    State(state): State<ExecutionRouterState>,
+    user: AuthenticatedUser,
+    Query(query): Query<ProgressQuery>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return ApiError::Forbidden.into_response();
+    }
+
+    ws.on_upgrade(move |socket| stream_progress(socket, state.progress, query))
+}
+
+async fn stream_progress(mut socket: WebSocket, progress: Arc<ExecutionProgressTracker>, query: ProgressQuery) {
+    let mut updates = progress.subscribe();
+
+    // Send current progress straight away so the view can render
+    let mut pending: Vec<ExecutionProgress> = progress.list(query.running)
+        .into_iter()
+        .filter(|p| query.matches(p))
+        .collect();
+    loop {
+        for update in pending.drain(..) {
+            let text = serde_json::to_string(&update).unwrap_or_default();
+            if socket.send(Message::Text(text)).await.is_err() {
+                return;
+            }
+        }
+
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(update) if query.matches(&update) => pending.push(update),
+                Ok(_) => {}
+                // Resend the current state of everything after falling behind
+                Err(RecvError::Lagged(skipped)) => {
+                    debug!("Execution progress client lagged by {} updates", skipped);
+                    pending = progress.list(query.running).into_iter().filter(|p| query.matches(p)).collect();
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::sync::Arc;
+use axum::{
+    extract::{Query, State, WebSocketUpgrade},
+    extract::ws::{Message, WebSocket},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::debug;
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::exposure_heatmap::{ExposureHeatmap, ExposureHeatmapService};
+
+/// Filters narrowing the heatmap to a slice
+#[derive(Debug, Default, Deserialize)]
+struct HeatmapQuery {
+    /// Only cells for this symbol
+    symbol: Option<String>,
+    /// Only cells for this strategy
+    strategy_id: Option<String>,
+    /// Only cells on this venue
+    venue: Option<String>,
+    /// Recompute before answering instead of serving the latest heatmap
+    #[serde(default)]
+    refresh: bool,
+}
+
+impl HeatmapQuery {
+    /// Drop cells outside the requested slice; totals are left as computed
+    fn apply(&self, mut heatmap: ExposureHeatmap) -> ExposureHeatmap {
+        heatmap.cells.retain(|cell| {
+            matches_filter(&self.symbol, &cell.symbol)
+                && matches_filter(&self.strategy_id, &cell.strategy_id)
+                && matches_filter(&self.venue, &cell.venue)
+        });
+        heatmap
+    }
+}
+
+/// Whether a value passes an optional equality filter
+fn matches_filter(filter: &Option<String>, value: &str) -> bool {
+    match filter {
+        Some(expected) => expected == value,
+        None => true,
+    }
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// Create the exposure heatmap API router
+pub fn create_exposure_router(service: Arc<ExposureHeatmapService>) -> Router {
+    Router::new()
+        .route("/analytics/exposure/heatmap", get(get_heatmap))
+        .route("/analytics/exposure/ws", get(heatmap_ws_handler))
+        .with_state(service)
+}
+
+// Get notional and risk contribution by symbol, strategy and venue
+async fn get_heatmap(
+    State(service): State<Arc<ExposureHeatmapService>>,
+    user: AuthenticatedUser,
+    Query(query): Query<HeatmapQuery>,
+) -> Result<Json<ExposureHeatmap>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let heatmap = if query.refresh {
+        service.refresh().await.map_err(|e| ApiError::Internal(e.to_string()))?
+    } else {
+        service.latest().await
+    };
+    Ok(Json(query.apply(heatmap)))
+}
+
+// Stream heatmap refreshes to the dashboard
+async fn heatmap_ws_handler(
+    State(service): State<Arc<ExposureHeatmapService>>,
+    user: AuthenticatedUser,
+    Query(query): Query<HeatmapQuery>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return ApiError::Forbidden.into_response();
+    }
+
+    ws.on_upgrade(move |socket| stream_heatmaps(socket, service, query))
+}
+
+async fn stream_heatmaps(mut socket: WebSocket, service: Arc<ExposureHeatmapService>, query: HeatmapQuery) {
+    let mut updates = service.subscribe();
+
+    // Send the current heatmap straight away so the widget can render
+    let mut next = Some(service.latest().await);
+    loop {
+        if let Some(heatmap) = next.take() {
+            let text = serde_json::to_string(&query.apply(heatmap)).unwrap_or_default();
+            if socket.send(Message::Text(text)).await.is_err() {
+                break;
+            }
+        }
+
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(heatmap) => next = Some(heatmap),
+                // Only the newest heatmap matters to a lagging client
+                Err(RecvError::Lagged(skipped)) => {
+                    debug!("Exposure heatmap client lagged by {} updates", skipped);
+                    next = Some(service.latest().await);
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Runtime log level control: read the current levels, change the level of
+//! one module (or the default) and restore configured levels.
+
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::api::auth::AuthenticatedUser;
+use crate::logging::{LogControl, LoggingError};
+use crate::telemetry::TelemetryRole;
+
+/// Level change request body
+#[derive(Debug, Deserialize)]
+struct SetLevelRequest {
+    /// Module path; the default level when absent
+    target: Option<String>,
+    /// New level (`trace`, `debug`, `info`, `warn`, `error` or `off`)
+    level: String,
+}
+
+/// Level reset request body
+#[derive(Debug, Default, Deserialize)]
+struct ResetLevelRequest {
+    /// Module path; every level when absent
+    target: Option<String>,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    BadRequest(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<LoggingError> for ApiError {
+    fn from(err: LoggingError) -> Self {
+        match err {
+            LoggingError::InvalidLevel(_) | LoggingError::InvalidTarget(_) => ApiError::BadRequest(err.to_string()),
+            LoggingError::Reload(_) | LoggingError::AlreadyInitialized(_) => ApiError::Internal(err.to_string()),
+        }
+    }
+}
+
+/// Create the logging API router
+pub fn create_logging_router(log_control: Arc<LogControl>) -> Router {
+    Router::new()
+        .route("/logging/levels", get(get_levels).post(set_level))
+        .route("/logging/levels/reset", post(reset_levels))
+        .with_state(log_control)
+}
+
+fn require_operator_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// Current default level and module overrides
+async fn get_levels(
+    State(log_control): State<Arc<LogControl>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    Ok(Json(serde_json::json!(log_control.levels())))
+}
+
+// Change the level of one module, or the default level
+async fn set_level(
+    State(log_control): State<Arc<LogControl>>,
+    user: AuthenticatedUser,
+    Json(request): Json<SetLevelRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    let levels = log_control.set_level(request.target.as_deref(), &request.level)?;
+    info!(
+        "User {} set log level of {} to {}",
+        user.id,
+        request.target.as_deref().unwrap_or("default"),
+        request.level
+    );
+    Ok(Json(serde_json::json!(levels)))
+}
+
+// Restore configured levels for one module, or for everything
+async fn reset_levels(
+    State(log_control): State<Arc<LogControl>>,
+    user: AuthenticatedUser,
+    request: Option<Json<ResetLevelRequest>>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    let Json(request) = request.unwrap_or_default();
+    let levels = log_control.reset(request.target.as_deref())?;
+    info!(
+        "User {} reset log level of {}",
+        user.id,
+        request.target.as_deref().unwrap_or("all modules")
+    );
+    Ok(Json(serde_json::json!(levels)))
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::api::auth::AuthenticatedUser;
+use crate::meta::decision_review::{DecisionReviewError, DecisionReviewQueue};
+use crate::telemetry::TelemetryRole;
+
+/// Approve/reject request body; the operator is the authenticated caller
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ReviewRequest {
+    note: Option<String>,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    NotFound(String),
+    BadRequest(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<DecisionReviewError> for ApiError {
+    fn from(err: DecisionReviewError) -> Self {
+        match err {
+            DecisionReviewError::NotPending(_) => ApiError::NotFound(err.to_string()),
+            DecisionReviewError::MissingOperator => ApiError::BadRequest(err.to_string()),
+            DecisionReviewError::Service(msg) => ApiError::Internal(msg),
+        }
+    }
+}
+
+/// Create the meta-agent decision review API router
+pub fn create_meta_router(review_queue: Arc<DecisionReviewQueue>) -> Router {
+    Router::new()
+        .route("/meta/reviews", get(list_reviews))
+        .route("/meta/reviews/:decision_id/approve", post(approve_review))
+        .route("/meta/reviews/:decision_id/reject", post(reject_review))
+        .with_state(review_queue)
+}
+
+// Only operators and admins may approve meta-agent decisions
+fn require_operator_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// List decisions awaiting approval
+async fn list_reviews(
+    State(review_queue): State<Arc<DecisionReviewQueue>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    let reviews = review_queue.pending().await;
+    Ok(Json(serde_json::json!({
+        "reviews": reviews,
+        "count": reviews.len(),
+        "timestamp": Utc::now(),
+    })))
+}
+
+// Approve a pending decision for execution
+async fn approve_review(
+    State(review_queue): State<Arc<DecisionReviewQueue>>,
+    user: AuthenticatedUser,
+    Path(decision_id): Path<String>,
+    Json(request): Json<ReviewRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    let decision = review_queue.approve(&decision_id, &user.id, request.note).await?;
+    Ok(Json(serde_json::json!(decision)))
+}
+
+// Reject a pending decision
+async fn reject_review(
+    State(review_queue): State<Arc<DecisionReviewQueue>>,
+    user: AuthenticatedUser,
+    Path(decision_id): Path<String>,
+    Json(request): Json<ReviewRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    let decision = review_queue.reject(&decision_id, &user.id, request.note).await?;
+    Ok(Json(serde_json::json!(decision)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mtls::ClientIdentity;
+    use crate::meta::decision_review::{DecisionReviewConfig, ReviewEvent};
+    use crate::meta::meta_agent_service::{
+        ActionType, DecisionStatus, MetaAgentAction, MetaAgentDecision, MockMetaAgentService,
+    };
+    use axum::{body::Body, http::Request};
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_resolution_is_attributed_to_the_caller() {
+        let queue = Arc::new(DecisionReviewQueue::new(
+            DecisionReviewConfig::default(),
+            Arc::new(MockMetaAgentService::new()),
+        ));
+        queue.submit(MetaAgentDecision {
+            id: "decision-1".to_string(),
+            meta_agent_id: "supervisor".to_string(),
+            affected_agents: vec!["momentum".to_string()],
+            timestamp: Utc::now(),
+            reasoning: "drawdown breach".to_string(),
+            confidence: 0.9,
+            auto_applied: false,
+            status: DecisionStatus::Proposed,
+            actions: vec![MetaAgentAction {
+                action_type: ActionType::Suspension,
+                target_agent_id: "momentum".to_string(),
+                parameters: HashMap::new(),
+                priority: 9,
+                requires_approval: true,
+            }],
+        }).await.unwrap();
+        let mut events = queue.subscribe();
+
+        // A body naming someone else does not change who approved
+        let request = Request::post("/meta/reviews/decision-1/approve")
+            .header("content-type", "application/json")
+            .extension(ClientIdentity {
+                operator_id: "alice".to_string(),
+                common_name: Some("alice.ops".to_string()),
+                fingerprint: "abcdef".to_string(),
+                role: TelemetryRole::Operator,
+            })
+            .body(Body::from(r#"{"operator":"mallory","note":"ok"}"#))
+            .unwrap();
+        let response = create_meta_router(queue).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        match events.recv().await.unwrap() {
+            ReviewEvent::Resolved { operator, .. } => assert_eq!(operator.as_deref(), Some("alice")),
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+}
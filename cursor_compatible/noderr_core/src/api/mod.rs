@@ -15,14 +15,35 @@
 //! API routers and handlers for Noderr Protocol
 
 pub mod auth;
+pub mod mtls;
 pub mod telemetry_router;
 pub mod storage_router;
 pub mod analytics_router;
+pub mod risk_router;
+pub mod orders_router;
+pub mod meta_router;
+pub mod trust_router;
+pub mod trace_router;
+pub mod retention_router;
+pub mod portfolio_router;
+pub mod exposure_router;
+pub mod simulation_router;
+pub mod execution_router;
+pub mod logging_router;
+pub mod state_router;
+pub mod universe_router;
+pub mod warmup_router;
 
 use std::sync::Arc;
-use axum::Router;
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json, Router,
+};
 use tracing::info;
 
+use crate::error_taxonomy::{ErrorClass, ErrorReport};
+
 use crate::telemetry::TelemetryReporter;
 use crate::trust_buffer::TrustBuffer;
 use crate::storage::StrategyStorage;
@@ -30,6 +51,18 @@ use crate::analytics::Analytics;
 use crate::telemetry_streamer::TelemetryStreamer;
 use crate::websocket_manager::WebSocketManager;
 use crate::trust_score_engine::TrustScoreEngine;
+use crate::order_router::SmartOrderRouter;
+use crate::logging::LogControl;
+use crate::state_bundle::StateBundleManager;
+use crate::universe::UniverseManager;
+use crate::warmup::WarmupGate;
+use crate::meta::decision_review::DecisionReviewQueue;
+use crate::trust_monitor::SlaMonitor;
+use crate::traceability::TraceRegistry;
+use crate::retention::RetentionManager;
+use crate::portfolio_snapshot::PortfolioSnapshotStore;
+use crate::exposure_heatmap::ExposureHeatmapService;
+use crate::order_simulator::OrderSimulator;
 
 /// Create a complete API router with all endpoints
 pub fn create_api_router(
@@ -40,6 +73,10 @@ pub fn create_api_router(
     telemetry_streamer: Option<Arc<dyn TelemetryStreamer>>,
     websocket_manager: Option<Arc<WebSocketManager>>,
     trust_score_engine: Option<Arc<dyn TrustScoreEngine>>,
+    trace_registry: Option<Arc<TraceRegistry>>,
+    portfolio_snapshots: Option<Arc<PortfolioSnapshotStore>>,
+    exposure_heatmap: Option<Arc<ExposureHeatmapService>>,
+    order_simulator: Option<Arc<OrderSimulator>>,
 ) -> Router {
     info!("Creating API router with all endpoints");
     
@@ -66,5 +103,91 @@ pub fn create_api_router(
         info!("Added analytics routes to API router");
     }
     
+    if let Some(trace_registry) = trace_registry {
+        router = router.merge(trace_router::create_trace_router(trace_registry));
+    }
+    if let Some(store) = portfolio_snapshots {
+        router = router.merge(portfolio_router::create_portfolio_router(store));
+    }
+    if let Some(service) = exposure_heatmap {
+        router = router.merge(exposure_router::create_exposure_router(service));
+    }
+    // Simulations never reach a venue, so they need no operator certificate
+    if let Some(simulator) = order_simulator {
+        router = router.merge(simulation_router::create_simulation_router(simulator));
+    }
+    
     router
-} 
\ No newline at end of file
+}
+
+/// Create the operator command router (order cancels, venue modes, the
+/// flatten kill switch, risk overrides, log levels, state bundles, the
+/// symbol universe, symbol warm-up progress, meta-agent decision reviews,
+/// strategy SLA tiers, data retention purges and execution algorithm controls).
+/// When mTLS is configured these routes only answer requests carrying an
+/// operator client certificate.
+pub fn create_operator_router(
+    order_router: Arc<SmartOrderRouter>,
+    risk_state: Option<risk_router::RiskRouterState>,
+    log_control: Option<Arc<LogControl>>,
+    state_bundles: Option<Arc<StateBundleManager>>,
+    universe: Option<Arc<UniverseManager>>,
+    warmup_gate: Option<Arc<WarmupGate>>,
+    review_queue: Option<Arc<DecisionReviewQueue>>,
+    sla_monitor: Option<Arc<SlaMonitor>>,
+    retention: Option<Arc<RetentionManager>>,
+    executions: Option<execution_router::ExecutionRouterState>,
+    mtls_config: &mtls::MtlsConfig,
+) -> Router {
+    let mut router = orders_router::create_orders_router(order_router);
+    if let Some(state) = risk_state {
+        router = router.merge(risk_router::create_risk_router(state));
+    }
+    if let Some(log_control) = log_control {
+        router = router.merge(logging_router::create_logging_router(log_control));
+    }
+    if let Some(manager) = state_bundles {
+        router = router.merge(state_router::create_state_router(manager));
+    }
+    if let Some(universe) = universe {
+        router = router.merge(universe_router::create_universe_router(universe));
+    }
+    if let Some(warmup_gate) = warmup_gate {
+        router = router.merge(warmup_router::create_warmup_router(warmup_gate));
+    }
+    if let Some(review_queue) = review_queue {
+        router = router.merge(meta_router::create_meta_router(review_queue));
+    }
+    if let Some(sla_monitor) = sla_monitor {
+        router = router.merge(trust_router::create_trust_router(sla_monitor));
+    }
+    if let Some(retention) = retention {
+        router = router.merge(retention_router::create_retention_router(retention));
+    }
+    if let Some(state) = executions {
+        router = router.merge(execution_router::create_execution_router(state));
+    }
+
+    mtls::protect_operator_routes(router, mtls_config)
+}
+
+/// Classified errors map to a status by class so clients can tell retryable
+/// failures apart from ones that need a different request
+impl IntoResponse for ErrorReport {
+    fn into_response(self) -> Response {
+        let status = match self.class {
+            ErrorClass::Transient => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorClass::Permanent => StatusCode::BAD_REQUEST,
+            ErrorClass::Fatal => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let body = Json(serde_json::json!({
+            "error": self.message,
+            "code": self.code,
+            "class": self.class,
+            "retryable": self.retryable,
+        }));
+
+        (status, body).into_response()
+    }
+}
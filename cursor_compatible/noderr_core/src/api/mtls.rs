@@ -0,0 +1,396 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Mutual TLS for the operator control channel
+//!
+//! When enabled, the API and gRPC servers only complete a handshake with
+//! clients presenting a certificate signed by the configured operator CA.
+//! The certificate subject or fingerprint is mapped to an operator identity
+//! and role, which is attached to every request on the connection so that
+//! kill-switch, cancel and configuration endpoints can demand it.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use axum::{
+    http::{Request, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    Extension, Json, Router,
+};
+use rustls::server::AllowAnyAuthenticatedClient;
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tracing::{debug, info, warn};
+
+use crate::api::auth::string_to_telemetry_role;
+use crate::telemetry::TelemetryRole;
+
+/// Errors raised while configuring or serving mutual TLS
+#[derive(Debug, Error)]
+pub enum MtlsError {
+    /// Reading certificates or accepting connections failed
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    /// The TLS stack rejected the configuration
+    #[error("TLS error: {0}")]
+    Tls(#[from] rustls::Error),
+    /// A certificate or key file could not be used
+    #[error("Invalid certificate: {0}")]
+    InvalidCertificate(String),
+    /// A required setting is missing
+    #[error("mTLS is not configured: {0}")]
+    NotConfigured(String),
+    /// The client certificate does not map to a known operator
+    #[error("Unknown operator certificate: {0}")]
+    UnknownOperator(String),
+}
+
+/// Maps a client certificate to an operator identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorCertificate {
+    /// Operator ID attached to requests made with this certificate
+    pub operator_id: String,
+    /// Certificate subject common name to match
+    pub common_name: Option<String>,
+    /// SHA-256 fingerprint of the DER certificate (hex) to match
+    pub fingerprint: Option<String>,
+    /// Role granted to the operator ("admin", "operator", ...)
+    pub role: String,
+}
+
+/// Mutual TLS configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MtlsConfig {
+    /// Whether mTLS is enabled
+    pub enabled: bool,
+    /// Server certificate chain (PEM)
+    pub server_cert_path: Option<PathBuf>,
+    /// Server private key (PEM, PKCS#8 or RSA)
+    pub server_key_path: Option<PathBuf>,
+    /// CA bundle that signs operator client certificates (PEM)
+    pub client_ca_path: Option<PathBuf>,
+    /// Known operator certificates
+    pub operators: Vec<OperatorCertificate>,
+    /// Role for CA-signed certificates that match no operator entry;
+    /// `None` rejects them
+    pub default_role: Option<String>,
+    /// Require a client certificate identity on operator routes
+    pub require_for_operator_routes: bool,
+}
+
+impl Default for MtlsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            server_cert_path: None,
+            server_key_path: None,
+            client_ca_path: None,
+            operators: Vec::new(),
+            default_role: None,
+            require_for_operator_routes: true,
+        }
+    }
+}
+
+/// Identity established from a verified client certificate
+#[derive(Debug, Clone, Serialize)]
+pub struct ClientIdentity {
+    /// Operator ID
+    pub operator_id: String,
+    /// Certificate subject common name
+    pub common_name: Option<String>,
+    /// SHA-256 fingerprint of the certificate (hex)
+    pub fingerprint: String,
+    /// Role granted to the operator
+    #[serde(skip)]
+    pub role: TelemetryRole,
+}
+
+impl ClientIdentity {
+    /// Whether the identity may issue operator commands
+    pub fn is_operator(&self) -> bool {
+        matches!(self.role, TelemetryRole::Admin | TelemetryRole::Operator)
+    }
+}
+
+impl MtlsConfig {
+    /// Resolve a verified certificate's subject and fingerprint to an identity.
+    /// Fingerprint matches take precedence over common name matches.
+    pub fn resolve(&self, common_name: Option<&str>, fingerprint: &str) -> Result<ClientIdentity, MtlsError> {
+        let by_fingerprint = self.operators.iter().find(|op| {
+            op.fingerprint
+                .as_deref()
+                .is_some_and(|fp| normalize_fingerprint(fp) == fingerprint)
+        });
+        let entry = by_fingerprint.or_else(|| {
+            common_name.and_then(|cn| {
+                self.operators
+                    .iter()
+                    .find(|op| op.fingerprint.is_none() && op.common_name.as_deref() == Some(cn))
+            })
+        });
+
+        let (operator_id, role) = match (entry, &self.default_role) {
+            (Some(op), _) => (op.operator_id.clone(), op.role.as_str()),
+            (None, Some(role)) => (
+                common_name.map(str::to_string).unwrap_or_else(|| fingerprint.to_string()),
+                role.as_str(),
+            ),
+            (None, None) => {
+                return Err(MtlsError::UnknownOperator(
+                    common_name.map(str::to_string).unwrap_or_else(|| fingerprint.to_string()),
+                ))
+            }
+        };
+
+        let role = string_to_telemetry_role(role)
+            .ok_or_else(|| MtlsError::InvalidCertificate(format!("unknown role '{}'", role)))?;
+
+        Ok(ClientIdentity {
+            operator_id,
+            common_name: common_name.map(str::to_string),
+            fingerprint: fingerprint.to_string(),
+            role,
+        })
+    }
+
+    /// Identify the holder of a DER-encoded client certificate
+    pub fn identify(&self, der: &[u8]) -> Result<ClientIdentity, MtlsError> {
+        let (_, cert) = x509_parser::parse_x509_certificate(der)
+            .map_err(|e| MtlsError::InvalidCertificate(e.to_string()))?;
+        let common_name = cert
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|cn| cn.as_str().ok())
+            .map(str::to_string);
+
+        self.resolve(common_name.as_deref(), &fingerprint(der))
+    }
+
+    /// Identify a gRPC caller from the certificates tonic recorded for the connection
+    pub fn identify_grpc<T>(&self, request: &tonic::Request<T>) -> Result<ClientIdentity, MtlsError> {
+        let certs = request
+            .peer_certs()
+            .ok_or_else(|| MtlsError::UnknownOperator("no client certificate".to_string()))?;
+        let leaf = certs
+            .first()
+            .ok_or_else(|| MtlsError::UnknownOperator("no client certificate".to_string()))?;
+        self.identify(leaf.as_ref())
+    }
+
+    /// Build the rustls server configuration requiring CA-signed client certificates
+    pub fn server_config(&self) -> Result<ServerConfig, MtlsError> {
+        let certs = load_certs(required(&self.server_cert_path, "server_cert_path")?)?;
+        let key = load_key(required(&self.server_key_path, "server_key_path")?)?;
+
+        let mut roots = RootCertStore::empty();
+        for ca in load_certs(required(&self.client_ca_path, "client_ca_path")?)? {
+            roots.add(&ca)?;
+        }
+
+        let config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+            .with_single_cert(certs, key)?;
+        Ok(config)
+    }
+
+    /// Build the tonic TLS configuration for the gRPC server
+    pub fn grpc_tls_config(&self) -> Result<tonic::transport::ServerTlsConfig, MtlsError> {
+        let cert = std::fs::read(required(&self.server_cert_path, "server_cert_path")?)?;
+        let key = std::fs::read(required(&self.server_key_path, "server_key_path")?)?;
+        let ca = std::fs::read(required(&self.client_ca_path, "client_ca_path")?)?;
+
+        Ok(tonic::transport::ServerTlsConfig::new()
+            .identity(tonic::transport::Identity::from_pem(cert, key))
+            .client_ca_root(tonic::transport::Certificate::from_pem(ca)))
+    }
+}
+
+/// Serve a router over mutual TLS, attaching each connection's
+/// [`ClientIdentity`] to its requests
+pub async fn serve_mtls(addr: SocketAddr, router: Router, config: MtlsConfig) -> Result<(), MtlsError> {
+    if !config.enabled {
+        return Err(MtlsError::NotConfigured("mTLS is disabled".to_string()));
+    }
+
+    let acceptor = TlsAcceptor::from(Arc::new(config.server_config()?));
+    let listener = TcpListener::bind(addr).await?;
+    let config = Arc::new(config);
+    info!("Serving operator API over mTLS on {}", addr);
+
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let acceptor = acceptor.clone();
+        let router = router.clone();
+        let config = config.clone();
+
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    warn!("mTLS handshake with {} failed: {}", peer, e);
+                    return;
+                }
+            };
+
+            let identity = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|certs| certs.first())
+                .map(|cert| config.identify(&cert.0));
+
+            let identity = match identity {
+                Some(Ok(identity)) => identity,
+                Some(Err(e)) => {
+                    warn!("Rejected client certificate from {}: {}", peer, e);
+                    return;
+                }
+                None => {
+                    warn!("Client {} presented no certificate", peer);
+                    return;
+                }
+            };
+
+            debug!("Operator {} connected from {}", identity.operator_id, peer);
+            let service = router.layer(Extension(identity));
+            if let Err(e) = hyper::server::conn::Http::new()
+                .serve_connection(stream, service)
+                .await
+            {
+                debug!("Connection from {} closed: {}", peer, e);
+            }
+        });
+    }
+}
+
+/// Reject requests that did not arrive with an operator client certificate
+pub async fn require_client_certificate<B>(request: Request<B>, next: Next<B>) -> Response {
+    let rejection = match request.extensions().get::<ClientIdentity>() {
+        Some(identity) if identity.is_operator() => return next.run(request).await,
+        Some(_) => (StatusCode::FORBIDDEN, "Client certificate does not grant operator access"),
+        None => (StatusCode::UNAUTHORIZED, "Client certificate required"),
+    };
+
+    (rejection.0, Json(serde_json::json!({ "error": rejection.1 }))).into_response()
+}
+
+/// Guard operator command routes with [`require_client_certificate`] when
+/// the configuration asks for it
+pub fn protect_operator_routes(router: Router, config: &MtlsConfig) -> Router {
+    if config.enabled && config.require_for_operator_routes {
+        router.route_layer(middleware::from_fn(require_client_certificate))
+    } else {
+        router
+    }
+}
+
+/// Hex SHA-256 fingerprint of a DER certificate
+pub fn fingerprint(der: &[u8]) -> String {
+    Sha256::digest(der).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn normalize_fingerprint(fingerprint: &str) -> String {
+    fingerprint.replace(':', "").to_lowercase()
+}
+
+fn required<'a>(path: &'a Option<PathBuf>, name: &str) -> Result<&'a Path, MtlsError> {
+    path.as_deref()
+        .ok_or_else(|| MtlsError::NotConfigured(format!("{} is not set", name)))
+}
+
+fn load_certs(path: &Path) -> Result<Vec<Certificate>, MtlsError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let certs = rustls_pemfile::certs(&mut reader)?;
+    if certs.is_empty() {
+        return Err(MtlsError::InvalidCertificate(format!("no certificates in {}", path.display())));
+    }
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn load_key(path: &Path) -> Result<PrivateKey, MtlsError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut reader)?;
+    if keys.is_empty() {
+        let mut reader = BufReader::new(File::open(path)?);
+        keys = rustls_pemfile::rsa_private_keys(&mut reader)?;
+    }
+    keys.into_iter()
+        .next()
+        .map(PrivateKey)
+        .ok_or_else(|| MtlsError::InvalidCertificate(format!("no private key in {}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> MtlsConfig {
+        MtlsConfig {
+            enabled: true,
+            operators: vec![
+                OperatorCertificate {
+                    operator_id: "alice".to_string(),
+                    common_name: Some("alice.ops".to_string()),
+                    fingerprint: None,
+                    role: "admin".to_string(),
+                },
+                OperatorCertificate {
+                    operator_id: "pager".to_string(),
+                    common_name: None,
+                    fingerprint: Some("AB:CD:EF".to_string()),
+                    role: "operator".to_string(),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_operator_resolution() {
+        let config = config();
+
+        let alice = config.resolve(Some("alice.ops"), "1234").unwrap();
+        assert_eq!(alice.operator_id, "alice");
+        assert!(matches!(alice.role, TelemetryRole::Admin));
+
+        // Fingerprint wins over a spoofable common name
+        let pager = config.resolve(Some("alice.ops"), "abcdef").unwrap();
+        assert_eq!(pager.operator_id, "pager");
+        assert!(pager.is_operator());
+
+        assert!(matches!(
+            config.resolve(Some("mallory"), "0000"),
+            Err(MtlsError::UnknownOperator(_))
+        ));
+
+        let lenient = MtlsConfig { default_role: Some("viewer".to_string()), ..config };
+        let viewer = lenient.resolve(Some("mallory"), "0000").unwrap();
+        assert_eq!(viewer.operator_id, "mallory");
+        assert!(!viewer.is_operator());
+    }
+}
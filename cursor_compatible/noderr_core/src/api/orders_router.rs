@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+
+use crate::api::auth::AuthenticatedUser;
+use crate::order_router::{Order, OrderRouterError, ReplaceRequest, SmartOrderRouter};
+use crate::telemetry::TelemetryRole;
+use crate::venue_control::VenueMode;
+
+/// Batch submit request body
+#[derive(Debug, Deserialize)]
+struct BatchSubmitRequest {
+    orders: Vec<Order>,
+}
+
+/// Batch cancel request body
+#[derive(Debug, Deserialize)]
+struct BatchCancelRequest {
+    order_ids: Vec<String>,
+}
+
+/// Batch replace request body
+#[derive(Debug, Deserialize)]
+struct BatchReplaceRequest {
+    replacements: Vec<ReplaceRequest>,
+}
+
+/// Symbol filter query
+#[derive(Debug, Deserialize)]
+struct SymbolQuery {
+    symbol: Option<String>,
+}
+
+/// Cancel-all request body
+#[derive(Debug, Deserialize)]
+struct CancelAllRequest {
+    symbol: Option<String>,
+}
+
+/// Flatten request body
+#[derive(Debug, Deserialize)]
+struct FlattenRequest {
+    symbol: Option<String>,
+    #[serde(default)]
+    venues: Vec<String>,
+}
+
+/// Venue mode change request body; the change is attributed to the
+/// authenticated operator
+#[derive(Debug, Deserialize)]
+struct VenueModeRequest {
+    reason: Option<String>,
+    /// Drain only: how long to wait for resting orders (seconds)
+    timeout_secs: Option<u64>,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    BadRequest(String),
+    Unavailable(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Unavailable(component) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("{} is not configured", component),
+            ),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<OrderRouterError> for ApiError {
+    fn from(err: OrderRouterError) -> Self {
+        match err {
+            OrderRouterError::NotConfigured(component) => ApiError::Unavailable(component),
+            err => ApiError::BadRequest(err.to_string()),
+        }
+    }
+}
+
+/// Create the order operations API router
+pub fn create_orders_router(order_router: Arc<SmartOrderRouter>) -> Router {
+    Router::new()
+        .route("/orders", get(list_orders))
+        .route("/orders/cancel-all", post(cancel_all))
+        .route("/orders/batch", post(submit_batch))
+        .route("/orders/batch/cancel", post(cancel_batch))
+        .route("/orders/batch/replace", post(replace_batch))
+        .route("/venues", get(list_venues))
+        .route("/venues/:venue/enable", post(enable_venue))
+        .route("/venues/:venue/disable", post(disable_venue))
+        .route("/venues/:venue/drain", post(drain_venue))
+        .route("/positions", get(list_positions))
+        .route("/positions/flatten", post(flatten_positions))
+        .with_state(order_router)
+}
+
+// Only operators and admins may place or cancel orders
+fn require_trading_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// Submit a batch of orders
+async fn submit_batch(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Json(request): Json<BatchSubmitRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let result = order_router.submit_batch(request.orders).await?;
+    Ok(Json(serde_json::json!(result)))
+}
+
+// Cancel a batch of orders
+async fn cancel_batch(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Json(request): Json<BatchCancelRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let result = order_router.cancel_batch(request.order_ids).await?;
+    Ok(Json(serde_json::json!(result)))
+}
+
+// Cancel and replace a batch of orders
+async fn replace_batch(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Json(request): Json<BatchReplaceRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let result = order_router.replace_batch(request.replacements).await?;
+    Ok(Json(serde_json::json!(result)))
+}
+
+// List orders resting on venues
+async fn list_orders(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Query(query): Query<SymbolQuery>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let orders = order_router.list_orders(query.symbol.as_deref()).await;
+    Ok(Json(serde_json::json!({
+        "orders": orders,
+        "count": orders.len(),
+    })))
+}
+
+// Cancel every resting order, optionally for one symbol
+async fn cancel_all(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Json(request): Json<CancelAllRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let result = order_router.cancel_all(request.symbol.as_deref()).await;
+    Ok(Json(serde_json::json!(result)))
+}
+
+// List non-flat positions
+async fn list_positions(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Query(query): Query<SymbolQuery>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let positions = order_router.open_positions(query.symbol.as_deref())?;
+    Ok(Json(serde_json::json!({
+        "positions": positions,
+        "count": positions.len(),
+    })))
+}
+
+// Close open positions with reduce-only orders
+async fn flatten_positions(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Json(request): Json<FlattenRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let result = order_router.flatten_positions(request.symbol.as_deref(), request.venues).await?;
+    Ok(Json(serde_json::json!(result)))
+}
+
+// List venues with an operator-set mode
+async fn list_venues(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let control = order_router.venue_control().ok_or_else(|| ApiError::Unavailable("venue control".to_string()))?;
+    let venues = control.list();
+    Ok(Json(serde_json::json!({
+        "venues": venues,
+        "count": venues.len(),
+    })))
+}
+
+// Resume routing new orders to a venue
+async fn enable_venue(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Path(venue): Path<String>,
+    Json(request): Json<VenueModeRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    set_venue_mode(&order_router, &user, &venue, VenueMode::Enabled, request).await
+}
+
+// Stop routing new orders to a venue
+async fn disable_venue(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Path(venue): Path<String>,
+    Json(request): Json<VenueModeRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    set_venue_mode(&order_router, &user, &venue, VenueMode::Disabled, request).await
+}
+
+// Stop routing to a venue and cancel its resting orders
+async fn drain_venue(
+    State(order_router): State<Arc<SmartOrderRouter>>,
+    user: AuthenticatedUser,
+    Path(venue): Path<String>,
+    Json(request): Json<VenueModeRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(&user)?;
+
+    let timeout = std::time::Duration::from_secs(request.timeout_secs.unwrap_or(30));
+    let report = order_router.drain_venue(&venue, &user.id, request.reason, timeout).await?;
+    Ok(Json(serde_json::json!(report)))
+}
+
+async fn set_venue_mode(
+    order_router: &SmartOrderRouter,
+    user: &AuthenticatedUser,
+    venue: &str,
+    mode: VenueMode,
+    request: VenueModeRequest,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_trading_role(user)?;
+
+    let control = order_router.venue_control().ok_or_else(|| ApiError::Unavailable("venue control".to_string()))?;
+    let state = control.set_mode(venue, mode, &user.id, request.reason).await;
+    Ok(Json(serde_json::json!(state)))
+}
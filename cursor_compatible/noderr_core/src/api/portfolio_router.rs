@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::sync::Arc;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::portfolio_snapshot::{PortfolioSnapshotError, PortfolioSnapshotStore};
+
+/// Point-in-time query
+#[derive(Debug, Deserialize)]
+struct AsOfQuery {
+    /// RFC 3339 timestamp; omitted for the live state
+    as_of: Option<DateTime<Utc>>,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    NotFound(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<PortfolioSnapshotError> for ApiError {
+    fn from(err: PortfolioSnapshotError) -> Self {
+        match err {
+            PortfolioSnapshotError::NoHistory(_) => ApiError::NotFound(err.to_string()),
+            err => ApiError::Internal(err.to_string()),
+        }
+    }
+}
+
+/// Create the portfolio snapshot API router
+pub fn create_portfolio_router(store: Arc<PortfolioSnapshotStore>) -> Router {
+    Router::new()
+        .route("/portfolio", get(get_portfolio))
+        .route("/portfolio/snapshots", get(list_snapshots))
+        .with_state(store)
+}
+
+// Get positions, allocations, risk metrics and trust scores, optionally as of a past time
+async fn get_portfolio(
+    State(store): State<Arc<PortfolioSnapshotStore>>,
+    user: AuthenticatedUser,
+    Query(query): Query<AsOfQuery>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let state = match query.as_of {
+        Some(as_of) => store.as_of(as_of).await?,
+        None => store.current().await,
+    };
+    Ok(Json(serde_json::json!(state)))
+}
+
+// List the times full snapshots were written
+async fn list_snapshots(
+    State(store): State<Arc<PortfolioSnapshotStore>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    Ok(Json(serde_json::json!({ "snapshots": store.snapshot_times().await })))
+}
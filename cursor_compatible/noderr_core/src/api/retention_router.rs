@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::retention::RetentionManager;
+use crate::telemetry::TelemetryRole;
+
+/// API errors
+enum ApiError {
+    Forbidden,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// Create the data retention API router
+pub fn create_retention_router(retention: Arc<RetentionManager>) -> Router {
+    Router::new()
+        .route("/retention/report", get(get_report))
+        .route("/retention/reports", get(get_reports))
+        .route("/retention/purge", post(purge))
+        .with_state(retention)
+}
+
+// Count expired data per class and sink without deleting anything
+async fn get_report(
+    State(retention): State<Arc<RetentionManager>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let report = retention.report(Utc::now()).await;
+    Ok(Json(serde_json::json!(report)))
+}
+
+// Get the reports of past retention runs
+async fn get_reports(
+    State(retention): State<Arc<RetentionManager>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let reports = retention.reports().await;
+    Ok(Json(serde_json::json!({
+        "reports": reports,
+        "count": reports.len(),
+    })))
+}
+
+// Delete expired data now; only admins may purge
+async fn purge(
+    State(retention): State<Arc<RetentionManager>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    if user.role != TelemetryRole::Admin {
+        return Err(ApiError::Forbidden);
+    }
+
+    let report = retention.purge(Utc::now()).await;
+    Ok(Json(serde_json::json!({
+        "verified": report.verified(),
+        "total_purged": report.total_purged(),
+        "report": report,
+    })))
+}
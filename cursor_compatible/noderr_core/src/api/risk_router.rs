@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::risk_budget::{RiskBudgetTracker, RiskBudgetError};
+use crate::risk_calc::{RiskCalculator, PositionExposure};
+use crate::risk_override::{RiskOverrideManager, RiskOverrideRequest, RiskOverrideError};
+use crate::telemetry::TelemetryRole;
+
+/// Risk API router state
+pub struct RiskRouterState {
+    /// Risk budget tracker
+    pub risk_budget: Option<Arc<RiskBudgetTracker>>,
+    /// Risk calculator
+    pub risk_calculator: Option<Arc<RiskCalculator>>,
+    /// Risk limit override manager
+    pub risk_overrides: Option<Arc<RiskOverrideManager>>,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    NotFound,
+    Unavailable(&'static str),
+    BadRequest(String),
+    Conflict(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Resource not found".to_string()),
+            ApiError::Unavailable(component) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("{} is not configured", component),
+            ),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<RiskBudgetError> for ApiError {
+    fn from(err: RiskBudgetError) -> Self {
+        match err {
+            RiskBudgetError::StrategyNotFound(_) => ApiError::NotFound,
+            err @ RiskBudgetError::InvalidBudget(_) => ApiError::BadRequest(err.to_string()),
+        }
+    }
+}
+
+impl From<RiskOverrideError> for ApiError {
+    fn from(err: RiskOverrideError) -> Self {
+        match err {
+            RiskOverrideError::InvalidRequest(msg) => ApiError::BadRequest(msg),
+            RiskOverrideError::AlreadyActive(limit) => ApiError::Conflict(format!("{} is already overridden", limit)),
+            RiskOverrideError::NotFound(_) => ApiError::NotFound,
+            RiskOverrideError::Audit(e) => ApiError::Internal(e.to_string()),
+        }
+    }
+}
+
+/// Create the risk API router
+pub fn create_risk_router(state: RiskRouterState) -> Router {
+    Router::new()
+        .route("/risk/budgets", get(get_all_budgets))
+        .route("/risk/budgets/:strategy_id", get(get_strategy_budget))
+        .route("/risk/concentration", get(get_concentration))
+        .route("/risk/concentration/what-if", post(what_if_concentration))
+        .route("/risk/venues/withdrawals", get(get_withdrawal_report))
+        .route("/risk/overrides", get(get_overrides).post(apply_override))
+        .route("/risk/overrides/:override_id/revoke", post(revoke_override))
+        .with_state(Arc::new(state))
+}
+
+// Get budget usage for all strategies
+async fn get_all_budgets(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    let tracker = state.risk_budget.as_ref().ok_or(ApiError::Unavailable("Risk budget tracker"))?;
+
+    let usage: Vec<_> = tracker
+        .get_all_usage()
+        .await
+        .into_iter()
+        .filter(|u| permissions.can_access_strategy(&u.strategy_id))
+        .collect();
+
+    Ok(Json(serde_json::json!({
+        "budgets": usage,
+        "count": usage.len(),
+        "timestamp": Utc::now(),
+    })))
+}
+
+// Get budget usage for a single strategy
+async fn get_strategy_budget(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+    Path(strategy_id): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_strategy(&strategy_id) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let tracker = state.risk_budget.as_ref().ok_or(ApiError::Unavailable("Risk budget tracker"))?;
+    let usage = tracker.get_usage(&strategy_id).await?;
+    let size_multiplier = tracker.size_multiplier(&strategy_id).await;
+
+    Ok(Json(serde_json::json!({
+        "strategy_id": strategy_id,
+        "budgets": usage,
+        "size_multiplier": size_multiplier,
+        "timestamp": Utc::now(),
+    })))
+}
+
+// Get the current portfolio concentration report
+async fn get_concentration(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let calculator = state.risk_calculator.as_ref().ok_or(ApiError::Unavailable("Risk calculator"))?;
+    let report = calculator.check_concentration().await;
+
+    Ok(Json(serde_json::json!(report)))
+}
+
+// Evaluate a proposed position's effect on concentration before submission
+async fn what_if_concentration(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+    Json(proposed): Json<PositionExposure>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let calculator = state.risk_calculator.as_ref().ok_or(ApiError::Unavailable("Risk calculator"))?;
+    let impact = calculator.what_if_concentration(&proposed).await;
+
+    Ok(Json(serde_json::json!({
+        "impact": impact,
+        "timestamp": Utc::now(),
+    })))
+}
+
+// Get the venue withdrawal recommendation report
+async fn get_withdrawal_report(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    let calculator = state.risk_calculator.as_ref().ok_or(ApiError::Unavailable("Risk calculator"))?;
+    let report = calculator.withdrawal_recommendations().await;
+
+    Ok(Json(serde_json::json!(report)))
+}
+
+// Only operators and admins may change risk limits
+fn require_operator_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// List active risk overrides
+async fn get_overrides(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    let manager = state.risk_overrides.as_ref().ok_or(ApiError::Unavailable("Risk override manager"))?;
+    let overrides = manager.list_active().await;
+
+    Ok(Json(serde_json::json!({
+        "overrides": overrides,
+        "count": overrides.len(),
+        "timestamp": Utc::now(),
+    })))
+}
+
+// Temporarily override a risk limit
+async fn apply_override(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+    Json(mut request): Json<RiskOverrideRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    // Overrides are attributed to the authenticated operator, never the body
+    request.operator = user.id.clone();
+    let manager = state.risk_overrides.as_ref().ok_or(ApiError::Unavailable("Risk override manager"))?;
+    let applied = manager.apply(request).await?;
+
+    Ok(Json(serde_json::json!(applied)))
+}
+
+// Revert a risk override before it expires
+async fn revoke_override(
+    State(state): State<Arc<RiskRouterState>>,
+    user: AuthenticatedUser,
+    Path(override_id): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    let manager = state.risk_overrides.as_ref().ok_or(ApiError::Unavailable("Risk override manager"))?;
+    let revoked = manager.revoke(&override_id, &user.id).await?;
+
+    Ok(Json(serde_json::json!(revoked)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk_budget_errors_map_to_client_statuses() {
+        let response = ApiError::from(RiskBudgetError::InvalidBudget("Invalid VaR: NaN".to_string())).into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = ApiError::from(RiskBudgetError::StrategyNotFound("momentum".to_string())).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
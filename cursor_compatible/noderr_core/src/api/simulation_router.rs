@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::order_simulator::{OrderSimulation, OrderSimulator, OrderSimulatorError, SimulatedOrder};
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    BadRequest(String),
+    UnprocessableEntity(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::UnprocessableEntity(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<OrderSimulatorError> for ApiError {
+    fn from(err: OrderSimulatorError) -> Self {
+        match err {
+            OrderSimulatorError::InvalidOrder(_) => ApiError::BadRequest(err.to_string()),
+            OrderSimulatorError::NoPrice(_) => ApiError::UnprocessableEntity(err.to_string()),
+        }
+    }
+}
+
+/// Create the what-if simulation API router
+pub fn create_simulation_router(simulator: Arc<OrderSimulator>) -> Router {
+    Router::new()
+        .route("/simulate/order", post(simulate_order))
+        .with_state(simulator)
+}
+
+// Preview slippage, risk outcome, exposures, margin and concentration for an order without executing it
+async fn simulate_order(
+    State(simulator): State<Arc<OrderSimulator>>,
+    user: AuthenticatedUser,
+    Json(order): Json<SimulatedOrder>,
+) -> Result<Json<OrderSimulation>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_system_metrics {
+        return Err(ApiError::Forbidden);
+    }
+
+    Ok(Json(simulator.simulate(order).await?))
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+
+//! State bundle export and import for moving a node between environments
+//! and for disaster-recovery restores.
+
+use std::sync::Arc;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::api::auth::AuthenticatedUser;
+use crate::state_bundle::{SignedStateBundle, StateBundleError, StateBundleManager};
+use crate::telemetry::TelemetryRole;
+
+/// Import query parameters
+#[derive(Debug, Default, Deserialize)]
+struct ImportQuery {
+    /// Verify and report without writing anything
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    BadRequest(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<StateBundleError> for ApiError {
+    fn from(err: StateBundleError) -> Self {
+        match err {
+            StateBundleError::Serialization(_)
+            | StateBundleError::UnsupportedFormat(_)
+            | StateBundleError::UntrustedKey(_)
+            | StateBundleError::BadSignature
+            | StateBundleError::InvalidKey(_) => ApiError::BadRequest(err.to_string()),
+            _ => ApiError::Internal(err.to_string()),
+        }
+    }
+}
+
+/// Create the state bundle API router
+pub fn create_state_router(manager: Arc<StateBundleManager>) -> Router {
+    Router::new()
+        .route("/state/export", get(export_state))
+        .route("/state/import", post(import_state))
+        .with_state(manager)
+}
+
+fn require_operator_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// Signed bundle of the current state
+async fn export_state(
+    State(manager): State<Arc<StateBundleManager>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    let signed = manager.export().await?;
+    info!("User {} exported a state bundle", user.id);
+    Ok(Json(serde_json::json!(signed)))
+}
+
+// Verify a signed bundle and apply it, or only report with `dry_run`
+async fn import_state(
+    State(manager): State<Arc<StateBundleManager>>,
+    user: AuthenticatedUser,
+    Query(query): Query<ImportQuery>,
+    Json(signed): Json<SignedStateBundle>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    let report = manager.import(&signed, query.dry_run).await?;
+    info!(
+        "User {} {} a state bundle from {}",
+        user.id,
+        if query.dry_run { "checked" } else { "imported" },
+        report.source_environment
+    );
+    Ok(Json(serde_json::json!(report)))
+}
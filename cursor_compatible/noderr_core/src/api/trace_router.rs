@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::{TimeZone, Utc};
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::ids::{IdError, TypedId};
+use crate::traceability::TraceRegistry;
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    NotFound,
+    BadRequest(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Resource not found".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// Create the trace API router
+pub fn create_trace_router(trace_registry: Arc<TraceRegistry>) -> Router {
+    Router::new()
+        .route("/trace/:id", get(get_trace))
+        .route("/trace/:id/parse", get(parse_id))
+        .with_state(trace_registry)
+}
+
+// Get everything linked to an artifact: its signal, orders, fills, reasons and telemetry
+async fn get_trace(
+    State(trace_registry): State<Arc<TraceRegistry>>,
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_events {
+        return Err(ApiError::Forbidden);
+    }
+
+    let trace = trace_registry.trace(&id).await.ok_or(ApiError::NotFound)?;
+    Ok(Json(serde_json::json!(trace)))
+}
+
+// Decode a typed ID into its kind and creation time
+async fn parse_id(
+    user: AuthenticatedUser,
+    Path(id): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_events {
+        return Err(ApiError::Forbidden);
+    }
+
+    let typed: TypedId = id.parse().map_err(|e: IdError| ApiError::BadRequest(e.to_string()))?;
+    let created_at = Utc.timestamp_millis_opt(typed.timestamp_ms() as i64).single();
+    Ok(Json(serde_json::json!({
+        "id": id,
+        "kind": typed.kind(),
+        "created_at": created_at,
+    })))
+}
@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::api::auth::{AuthenticatedUser, get_permissions_from_user};
+use crate::telemetry::TelemetryRole;
+use crate::trust_monitor::{SlaMonitor, SlaTier, StrategySla};
+
+/// Manual tier change request body
+#[derive(Debug, Deserialize)]
+struct SetTierRequest {
+    tier: SlaTier,
+    reason: String,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    NotFound,
+    BadRequest(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, "Resource not found".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// Create the trust SLA API router
+pub fn create_trust_router(sla_monitor: Arc<SlaMonitor>) -> Router {
+    Router::new()
+        .route("/trust/sla", get(get_all_statuses))
+        .route("/trust/sla/:strategy_id", get(get_status).put(set_sla))
+        .route("/trust/sla/:strategy_id/history", get(get_tier_history))
+        .route("/trust/sla/:strategy_id/tier", post(set_tier))
+        .with_state(sla_monitor)
+}
+
+// Get SLA standing for all strategies
+async fn get_all_statuses(
+    State(sla_monitor): State<Arc<SlaMonitor>>,
+    user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+
+    let statuses: Vec<_> = sla_monitor
+        .all_statuses()
+        .await
+        .into_iter()
+        .filter(|s| permissions.can_access_strategy(&s.strategy_id))
+        .collect();
+
+    Ok(Json(serde_json::json!({
+        "strategies": statuses,
+        "count": statuses.len(),
+        "timestamp": Utc::now(),
+    })))
+}
+
+// Get SLA standing for a single strategy
+async fn get_status(
+    State(sla_monitor): State<Arc<SlaMonitor>>,
+    user: AuthenticatedUser,
+    Path(strategy_id): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_strategy(&strategy_id) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let status = sla_monitor.status(&strategy_id).await.ok_or(ApiError::NotFound)?;
+    Ok(Json(serde_json::json!(status)))
+}
+
+// Get the tier change history for a strategy
+async fn get_tier_history(
+    State(sla_monitor): State<Arc<SlaMonitor>>,
+    user: AuthenticatedUser,
+    Path(strategy_id): Path<String>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let permissions = get_permissions_from_user(&user);
+    if !permissions.can_access_strategy(&strategy_id) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let history = sla_monitor.tier_history(&strategy_id).await;
+    Ok(Json(serde_json::json!({
+        "strategy_id": strategy_id,
+        "current_tier": sla_monitor.tier(&strategy_id).await,
+        "history": history,
+    })))
+}
+
+// Only operators and admins may change SLAs or tiers
+fn require_operator_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// Override the SLA for a strategy
+async fn set_sla(
+    State(sla_monitor): State<Arc<SlaMonitor>>,
+    user: AuthenticatedUser,
+    Path(strategy_id): Path<String>,
+    Json(sla): Json<StrategySla>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    if !(0.0..=1.0).contains(&sla.max_anomaly_rate) || sla.window_secs <= 0 || sla.max_downtime_secs < 0 {
+        return Err(ApiError::BadRequest("Invalid SLA".to_string()));
+    }
+
+    sla_monitor.set_sla(&strategy_id, sla).await;
+    let status = sla_monitor.status(&strategy_id).await.ok_or(ApiError::NotFound)?;
+    Ok(Json(serde_json::json!(status)))
+}
+
+// Manually move a strategy to a tier, e.g. to re-enable a disabled strategy
+async fn set_tier(
+    State(sla_monitor): State<Arc<SlaMonitor>>,
+    user: AuthenticatedUser,
+    Path(strategy_id): Path<String>,
+    Json(request): Json<SetTierRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+
+    let reason = format!("{} (by {})", request.reason, user.id);
+    let change = sla_monitor.set_tier(&strategy_id, request.tier, &reason).await;
+    Ok(Json(serde_json::json!({
+        "strategy_id": strategy_id,
+        "tier": request.tier,
+        "change": change,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mtls::ClientIdentity;
+    use crate::trust_monitor::SlaPolicy;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_tier_change_is_attributed_to_the_certificate() {
+        let sla_monitor = Arc::new(SlaMonitor::new(SlaPolicy::default()));
+
+        // Neither a bearer token nor a body naming someone else overrides the certificate
+        let request = Request::post("/trust/sla/momentum/tier")
+            .header("content-type", "application/json")
+            .header("authorization", "Bearer forged")
+            .extension(ClientIdentity {
+                operator_id: "alice".to_string(),
+                common_name: Some("alice.ops".to_string()),
+                fingerprint: "abcdef".to_string(),
+                role: TelemetryRole::Operator,
+            })
+            .body(Body::from(r#"{"tier":"shadow","operator":"mallory","reason":"flapping"}"#))
+            .unwrap();
+        let response = create_trust_router(sla_monitor.clone()).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let history = sla_monitor.tier_history("momentum").await;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].to, SlaTier::Shadow);
+        assert_eq!(history[0].reason, "flapping (by alice)");
+    }
+}
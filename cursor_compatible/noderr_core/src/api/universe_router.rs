@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+
+//! Symbol universe: list the tradable symbols, list new ones and delist
+//! symbols at runtime.
+
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::api::auth::AuthenticatedUser;
+use crate::telemetry::TelemetryRole;
+use crate::universe::{UniverseError, UniverseManager};
+
+/// Listing request body
+#[derive(Debug, Deserialize)]
+struct ListRequest {
+    /// Symbol in `BASE/QUOTE` form
+    symbol: String,
+    /// Risk template; the configured default when absent
+    template: Option<String>,
+}
+
+/// Delisting request body
+#[derive(Debug, Deserialize)]
+struct DelistRequest {
+    /// Symbol in `BASE/QUOTE` form
+    symbol: String,
+}
+
+/// API errors
+enum ApiError {
+    Forbidden,
+    BadRequest(String),
+    Conflict(String),
+    Internal(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::Forbidden => (StatusCode::FORBIDDEN, "Insufficient permissions".to_string()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
+            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
+            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+impl From<UniverseError> for ApiError {
+    fn from(err: UniverseError) -> Self {
+        match err {
+            UniverseError::InvalidSymbol(_) | UniverseError::UnknownTemplate(_) | UniverseError::NotListed(_) => {
+                ApiError::BadRequest(err.to_string())
+            }
+            UniverseError::AlreadyListed(_) | UniverseError::OpenExposure(_) => ApiError::Conflict(err.to_string()),
+            UniverseError::Provision { .. } => ApiError::Internal(err.to_string()),
+        }
+    }
+}
+
+/// Create the universe API router
+pub fn create_universe_router(universe: Arc<UniverseManager>) -> Router {
+    Router::new()
+        .route("/universe", get(get_universe))
+        .route("/universe/list", post(list_symbol))
+        .route("/universe/delist", post(delist_symbol))
+        .with_state(universe)
+}
+
+fn require_operator_role(user: &AuthenticatedUser) -> Result<(), ApiError> {
+    match user.role {
+        TelemetryRole::Admin | TelemetryRole::Operator => Ok(()),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// Listed symbols
+async fn get_universe(
+    State(universe): State<Arc<UniverseManager>>,
+    _user: AuthenticatedUser,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    Ok(Json(serde_json::json!(universe.listings().await)))
+}
+
+// List a symbol, provisioning books, limits and market data
+async fn list_symbol(
+    State(universe): State<Arc<UniverseManager>>,
+    user: AuthenticatedUser,
+    Json(request): Json<ListRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    let listing = universe.list(&request.symbol, request.template.as_deref()).await?;
+    info!("User {} listed {} with template {}", user.id, listing.symbol, listing.template);
+    Ok(Json(serde_json::json!(listing)))
+}
+
+// Delist a symbol and tear down what was provisioned for it
+async fn delist_symbol(
+    State(universe): State<Arc<UniverseManager>>,
+    user: AuthenticatedUser,
+    Json(request): Json<DelistRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    require_operator_role(&user)?;
+    let listing = universe.delist(&request.symbol).await?;
+    info!("User {} delisted {}", user.id, listing.symbol);
+    Ok(Json(serde_json::json!(listing)))
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Warm-up progress of the symbols seen by the strategy layer
+
+use std::sync::Arc;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+
+use crate::api::auth::AuthenticatedUser;
+use crate::warmup::WarmupGate;
+
+/// Progress query
+#[derive(Debug, Deserialize)]
+struct WarmupQuery {
+    /// Only this symbol, in `BASE/QUOTE` form
+    symbol: Option<String>,
+}
+
+/// API errors
+enum ApiError {
+    NotFound(String),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error_message) = match self {
+            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+        };
+
+        let body = Json(serde_json::json!({
+            "error": error_message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// Create the warm-up API router
+pub fn create_warmup_router(warmup_gate: Arc<WarmupGate>) -> Router {
+    Router::new()
+        .route("/warmup", get(get_warmup))
+        .with_state(warmup_gate)
+}
+
+// Warm-up progress, for every observed symbol or one
+async fn get_warmup(
+    State(warmup_gate): State<Arc<WarmupGate>>,
+    _user: AuthenticatedUser,
+    Query(query): Query<WarmupQuery>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let progress = match query.symbol {
+        Some(symbol) => {
+            let symbol = symbol.trim().to_uppercase();
+            let progress = warmup_gate.progress(&symbol)
+                .ok_or_else(|| ApiError::NotFound(format!("{} has not been observed", symbol)))?;
+            vec![progress]
+        }
+        None => warmup_gate.all_progress(),
+    };
+
+    let symbols: Vec<serde_json::Value> = progress
+        .iter()
+        .map(|p| {
+            let mut value = serde_json::json!(p);
+            value["ready"] = serde_json::json!(p.is_ready());
+            value["completion"] = serde_json::json!(p.completion());
+            value
+        })
+        .collect();
+
+    Ok(Json(serde_json::json!({
+        "enabled": warmup_gate.config().enabled,
+        "symbols": symbols,
+    })))
+}
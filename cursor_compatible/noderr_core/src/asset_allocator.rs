@@ -23,6 +23,7 @@ use thiserror::Error;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
+use crate::allocation_constraints::{AllocationConstraints, ConstraintError};
 use crate::correlation_engine::CorrelationEngine;
 use crate::market::Symbol;
 use crate::market_regime::{MarketRegimeDetector, MarketRegimeState};
@@ -40,6 +41,9 @@ pub enum AssetAllocationError {
     #[error("Regime detection error: {0}")]
     RegimeError(String),
     
+    #[error("Constraint error: {0}")]
+    Constraints(#[from] ConstraintError),
+    
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -108,6 +112,11 @@ pub struct AssetAllocationConfig {
     
     /// Weight given to regime confidence in allocation decisions (0.0-1.0)
     pub regime_confidence_weight: f64,
+    
+    /// Portfolio constraints (sector caps, turnover, cardinality) applied
+    /// after the built-in min/max normalization
+    #[serde(default)]
+    pub constraints: Option<AllocationConstraints>,
 }
 
 impl Default for AssetAllocationConfig {
@@ -150,6 +159,7 @@ impl Default for AssetAllocationConfig {
             volatility_weight: 0.3,
             correlation_weight: 0.3,
             regime_confidence_weight: 0.4,
+            constraints: None,
         }
     }
 }
@@ -263,6 +273,9 @@ pub trait AssetAllocator: Send + Sync {
     /// Update allocation
     async fn update_allocation(&self) -> AssetAllocationResult<()>;
     
+    /// Recalculate the allocation immediately, ignoring the cached one
+    async fn force_reallocation(&self) -> AssetAllocationResult<PortfolioAllocation>;
+    
     /// Initialize the asset allocator
     async fn initialize(&self) -> AssetAllocationResult<()>;
 }
@@ -661,6 +674,25 @@ impl AssetAllocator for RegimeBasedAssetAllocator {
         // Apply constraints and normalize
         self.apply_constraints_and_normalize(&mut asset_allocations);
         
+        // Project onto the configured constraint specification
+        if let Some(constraints) = &self.config.constraints {
+            let target: HashMap<Symbol, f64> = asset_allocations.iter()
+                .map(|(symbol, a)| (symbol.clone(), a.allocation))
+                .collect();
+            let previous: Option<HashMap<Symbol, f64>> = self.current_allocation.read().await
+                .as_ref()
+                .map(|current| current.allocations.iter().map(|a| (a.symbol.clone(), a.allocation)).collect());
+            let constrained = constraints.apply(&target, previous.as_ref())?;
+            
+            for (symbol, allocation) in asset_allocations.iter_mut() {
+                let weight = constrained.weights.get(symbol).copied().unwrap_or(0.0);
+                if (weight - allocation.allocation).abs() > 0.001 && allocation.allocation > 0.0 {
+                    allocation.adjustments.insert("constraints".to_string(), weight / allocation.allocation);
+                }
+                allocation.allocation = weight;
+            }
+        }
+        
         // Convert to vector of allocations
         let allocations: Vec<AssetAllocation> = asset_allocations.into_values().collect();
         
@@ -710,6 +742,23 @@ impl AssetAllocator for RegimeBasedAssetAllocator {
         Ok(result)
     }
     
+    async fn force_reallocation(&self) -> AssetAllocationResult<PortfolioAllocation> {
+        // Age the cache past the update interval so the next optimization recomputes
+        {
+            let mut last_update = self.last_update.write().await;
+            *last_update = Instant::now() - Duration::from_secs(self.config.update_interval_sec + 1);
+        }
+        
+        let allocation = self.optimize_allocation().await?;
+        info!(
+            "Forced portfolio reallocation across {} assets (regime: {})",
+            allocation.allocations.len(),
+            allocation.market_regime
+        );
+        
+        Ok(allocation)
+    }
+    
     async fn update_allocation(&self) -> AssetAllocationResult<()> {
         // Calculate new allocation
         let allocation = self.optimize_allocation().await?;
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tracing::info;
+use uuid::Uuid;
+
+/// Hash used as `prev_hash` for the first entry
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Errors that can occur when reading or writing the audit vault
+#[derive(Debug, Error)]
+pub enum AuditVaultError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Chain broken at entry {index}: {reason}")]
+    ChainBroken { index: usize, reason: String },
+}
+
+/// An append-only audit record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Entry ID
+    pub entry_id: String,
+    /// Event type (e.g. "risk_override")
+    pub event_type: String,
+    /// Operator or component responsible
+    pub actor: String,
+    /// Short description
+    pub description: String,
+    /// Structured event data
+    pub data: serde_json::Value,
+    /// When the entry was recorded
+    pub timestamp: DateTime<Utc>,
+    /// Hash of the previous entry
+    pub prev_hash: String,
+    /// Hash of this entry (covers every other field)
+    pub hash: String,
+}
+
+impl AuditEntry {
+    /// Compute the hash of this entry's contents
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.prev_hash.as_bytes());
+        hasher.update(self.entry_id.as_bytes());
+        hasher.update(self.event_type.as_bytes());
+        hasher.update(self.actor.as_bytes());
+        hasher.update(self.description.as_bytes());
+        hasher.update(self.data.to_string().as_bytes());
+        hasher.update(self.timestamp.to_rfc3339().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+/// Hash-chained, append-only audit log stored as JSON lines
+///
+/// Each entry commits to the previous entry's hash, so editing or removing a
+/// record invalidates every record after it.
+pub struct AuditVault {
+    /// Vault file
+    path: PathBuf,
+    /// Hash of the last entry written
+    last_hash: Mutex<String>,
+}
+
+impl AuditVault {
+    /// Open (or create) a vault at `path`
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AuditVaultError> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let last_hash = if path.exists() {
+            Self::read_entries(&path)?
+                .last()
+                .map(|entry| entry.hash.clone())
+                .unwrap_or_else(|| GENESIS_HASH.to_string())
+        } else {
+            GENESIS_HASH.to_string()
+        };
+
+        Ok(Self {
+            path,
+            last_hash: Mutex::new(last_hash),
+        })
+    }
+
+    /// Append an entry
+    pub async fn record(
+        &self,
+        event_type: &str,
+        actor: &str,
+        description: &str,
+        data: serde_json::Value,
+    ) -> Result<AuditEntry, AuditVaultError> {
+        // Held for the whole write so entries chain in order
+        let mut last_hash = self.last_hash.lock().await;
+
+        let mut entry = AuditEntry {
+            entry_id: Uuid::new_v4().to_string(),
+            event_type: event_type.to_string(),
+            actor: actor.to_string(),
+            description: description.to_string(),
+            data,
+            timestamp: Utc::now(),
+            prev_hash: last_hash.clone(),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+        file.sync_data()?;
+
+        *last_hash = entry.hash.clone();
+        info!("Audit entry {} recorded: {} by {}", entry.entry_id, entry.event_type, entry.actor);
+
+        Ok(entry)
+    }
+
+    /// Read every entry, oldest first
+    pub fn entries(&self) -> Result<Vec<AuditEntry>, AuditVaultError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        Self::read_entries(&self.path)
+    }
+
+    /// Verify the hash chain, returning the number of entries checked
+    pub fn verify(&self) -> Result<usize, AuditVaultError> {
+        let entries = self.entries()?;
+        let mut prev_hash = GENESIS_HASH.to_string();
+
+        for (index, entry) in entries.iter().enumerate() {
+            if entry.prev_hash != prev_hash {
+                return Err(AuditVaultError::ChainBroken { index, reason: "previous hash mismatch".to_string() });
+            }
+            if entry.compute_hash() != entry.hash {
+                return Err(AuditVaultError::ChainBroken { index, reason: "content hash mismatch".to_string() });
+            }
+            prev_hash = entry.hash.clone();
+        }
+
+        Ok(entries.len())
+    }
+
+    fn read_entries(path: &PathBuf) -> Result<Vec<AuditEntry>, AuditVaultError> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut entries = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            entries.push(serde_json::from_str(&line)?);
+        }
+        Ok(entries)
+    }
+}
+
+/// Open an audit vault
+pub fn create_audit_vault(path: impl Into<PathBuf>) -> Result<Arc<AuditVault>, AuditVaultError> {
+    Ok(Arc::new(AuditVault::open(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tampering_breaks_chain() {
+        let path = std::env::temp_dir().join(format!("noderr_audit_{}.jsonl", Uuid::new_v4()));
+        let vault = AuditVault::open(&path).unwrap();
+
+        vault.record("test", "alice", "first", serde_json::json!({ "n": 1 })).await.unwrap();
+        vault.record("test", "alice", "second", serde_json::json!({ "n": 2 })).await.unwrap();
+        assert_eq!(vault.verify().unwrap(), 2);
+
+        // Reopening continues the chain
+        let reopened = AuditVault::open(&path).unwrap();
+        reopened.record("test", "bob", "third", serde_json::json!({})).await.unwrap();
+        assert_eq!(reopened.verify().unwrap(), 3);
+
+        let tampered = std::fs::read_to_string(&path).unwrap().replace("second", "edited");
+        std::fs::write(&path, tampered).unwrap();
+        assert!(matches!(reopened.verify(), Err(AuditVaultError::ChainBroken { index: 1, .. })));
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,571 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::collections::HashMap;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+use crate::analytics_math;
+use crate::market::{Candle, MarketData, Ticker};
+use crate::risk::PositionDirection;
+use crate::simulation::latency_model::{LatencyModel, LatencyModelConfig};
+use crate::strategy::{SignalAction, Strategy};
+
+/// Errors that can occur while running a backtest
+#[derive(Debug, Error)]
+pub enum BacktestError {
+    #[error("Insufficient data: {0}")]
+    InsufficientData(String),
+
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Strategy error: {0}")]
+    Strategy(String),
+
+    #[error("Execution error: {0}")]
+    Execution(String),
+
+    #[error("Episode finished; reset the environment")]
+    EpisodeFinished,
+}
+
+/// Result type for backtest operations
+pub type BacktestResult<T> = Result<T, BacktestError>;
+
+/// A single historical OHLCV bar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestBar {
+    pub timestamp: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// Backtest configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BacktestConfig {
+    /// Symbol being replayed
+    pub symbol: String,
+
+    /// Exchange name reported to the strategy
+    pub exchange: String,
+
+    /// Candle timeframe label (e.g. "1h")
+    pub timeframe: String,
+
+    /// Starting capital in quote currency
+    pub initial_capital: f64,
+
+    /// Fee charged per fill (basis points of notional)
+    pub fee_bps: f64,
+
+    /// Adverse slippage applied to every fill (basis points)
+    pub slippage_bps: f64,
+
+    /// Fraction of equity committed per entry (0.0-1.0)
+    pub position_size_pct: f64,
+
+    /// Whether short entries are allowed
+    pub allow_short: bool,
+
+    /// Number of trailing candles passed to the strategy
+    pub candle_window: usize,
+
+    /// Flatten any open position at the last bar
+    pub close_at_end: bool,
+
+    /// Order latency model; when unset orders fill exactly at the next open
+    #[serde(default)]
+    pub latency: Option<LatencyModelConfig>,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        Self {
+            symbol: "BTC/USDT".to_string(),
+            exchange: "backtest".to_string(),
+            timeframe: "1h".to_string(),
+            initial_capital: 100_000.0,
+            fee_bps: 10.0,           // 0.10% per fill
+            slippage_bps: 5.0,       // 0.05% adverse slippage
+            position_size_pct: 0.5,  // Half of equity per entry
+            allow_short: false,
+            candle_window: 200,
+            close_at_end: true,
+            latency: None,
+        }
+    }
+}
+
+/// Side of a simulated fill
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BacktestSide {
+    Buy,
+    Sell,
+}
+
+/// A simulated fill
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestTrade {
+    pub trade_id: u64,
+    pub timestamp: DateTime<Utc>,
+    pub side: BacktestSide,
+    pub quantity: f64,
+    pub price: f64,
+    pub fee: f64,
+    /// Realized PnL if the fill reduced a position
+    pub realized_pnl: Option<f64>,
+}
+
+/// Equity curve sample, taken at each bar close
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityPoint {
+    pub timestamp: DateTime<Utc>,
+    pub equity: f64,
+    pub cash: f64,
+    pub position: f64,
+    pub price: f64,
+}
+
+/// Summary metrics of a backtest run (ratios are per bar, not annualised)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BacktestMetrics {
+    pub initial_capital: f64,
+    pub final_equity: f64,
+    pub total_return_pct: f64,
+    pub sharpe_ratio: f64,
+    pub sortino_ratio: f64,
+    pub max_drawdown_pct: f64,
+    pub trade_count: usize,
+    pub closed_trades: usize,
+    pub win_rate: f64,
+    pub total_fees: f64,
+    /// Fraction of bars with an open position
+    pub exposure_pct: f64,
+    pub bars: usize,
+}
+
+impl BacktestMetrics {
+    /// Metrics as name/value pairs, in a stable order
+    pub fn as_pairs(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("initial_capital", self.initial_capital),
+            ("final_equity", self.final_equity),
+            ("total_return_pct", self.total_return_pct),
+            ("sharpe_ratio", self.sharpe_ratio),
+            ("sortino_ratio", self.sortino_ratio),
+            ("max_drawdown_pct", self.max_drawdown_pct),
+            ("trade_count", self.trade_count as f64),
+            ("closed_trades", self.closed_trades as f64),
+            ("win_rate", self.win_rate),
+            ("total_fees", self.total_fees),
+            ("exposure_pct", self.exposure_pct),
+            ("bars", self.bars as f64),
+        ]
+    }
+}
+
+/// Full output of a backtest run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestReport {
+    pub strategy: String,
+    pub config: BacktestConfig,
+    pub metrics: BacktestMetrics,
+    pub equity_curve: Vec<EquityPoint>,
+    pub trades: Vec<BacktestTrade>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// Simulated account state during a run
+#[derive(Debug, Default)]
+pub(crate) struct Account {
+    pub(crate) cash: f64,
+    pub(crate) position: f64,
+    pub(crate) entry_price: f64,
+    pub(crate) fees: f64,
+    pub(crate) trades: Vec<BacktestTrade>,
+}
+
+impl Account {
+    pub(crate) fn equity(&self, price: f64) -> f64 {
+        self.cash + self.position * price
+    }
+
+    /// Trade towards `target` position at `price`
+    pub(crate) fn rebalance(&mut self, target: f64, price: f64, fee_bps: f64, timestamp: DateTime<Utc>) {
+        let delta = target - self.position;
+        if delta.abs() < f64::EPSILON {
+            return;
+        }
+
+        let side = if delta > 0.0 { BacktestSide::Buy } else { BacktestSide::Sell };
+        let quantity = delta.abs();
+        let fee = quantity * price * fee_bps / 10_000.0;
+
+        // Portion of the fill that reduces the existing position
+        let reducing = if self.position * delta < 0.0 { quantity.min(self.position.abs()) } else { 0.0 };
+        let realized_pnl = if reducing > 0.0 {
+            Some((price - self.entry_price) * reducing * self.position.signum() - fee)
+        } else {
+            None
+        };
+
+        let new_position = self.position + delta;
+        if new_position.abs() < f64::EPSILON {
+            self.entry_price = 0.0;
+        } else if self.position * new_position <= 0.0 {
+            // Flipped or opened from flat
+            self.entry_price = price;
+        } else if new_position.abs() > self.position.abs() {
+            // Added to an existing position
+            self.entry_price = (self.entry_price * self.position.abs() + price * quantity) / new_position.abs();
+        }
+
+        self.cash -= delta * price + fee;
+        self.fees += fee;
+        self.position = if new_position.abs() < f64::EPSILON { 0.0 } else { new_position };
+
+        self.trades.push(BacktestTrade {
+            trade_id: self.trades.len() as u64 + 1,
+            timestamp,
+            side,
+            quantity,
+            price,
+            fee,
+            realized_pnl,
+        });
+    }
+}
+
+/// Price at `arrival`, interpolated linearly from the bar's open to its close
+fn arrival_price(bar: &BacktestBar, bar_end: DateTime<Utc>, arrival: DateTime<Utc>) -> f64 {
+    let span = (bar_end - bar.timestamp).num_microseconds().unwrap_or(0);
+    if span <= 0 {
+        return bar.open;
+    }
+    let elapsed = (arrival - bar.timestamp).num_microseconds().unwrap_or(0);
+    let frac = (elapsed as f64 / span as f64).clamp(0.0, 1.0);
+    bar.open + (bar.close - bar.open) * frac
+}
+
+/// Replays historical bars through a strategy with a simple next-bar-open fill model
+pub struct BacktestEngine {
+    /// Configuration
+    config: BacktestConfig,
+}
+
+impl BacktestEngine {
+    /// Create a new backtest engine
+    pub fn new(config: BacktestConfig) -> Self {
+        Self { config }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &BacktestConfig {
+        &self.config
+    }
+
+    /// Run a strategy over the given bars (sorted by time)
+    ///
+    /// Signals generated on a bar's close are filled at the next bar's open,
+    /// adjusted for slippage, so the strategy never trades on information it
+    /// could not have had. With a latency model configured the order instead
+    /// arrives a sampled delay after that open and fills at the price
+    /// interpolated along the bar it lands in.
+    pub async fn run(&self, strategy: &dyn Strategy, bars: &[BacktestBar]) -> BacktestResult<BacktestReport> {
+        if bars.len() < 2 {
+            return Err(BacktestError::InsufficientData(format!("need at least 2 bars, got {}", bars.len())));
+        }
+        if self.config.initial_capital <= 0.0 {
+            return Err(BacktestError::InvalidConfig("initial_capital must be positive".to_string()));
+        }
+
+        let started_at = Utc::now();
+        let mut account = Account {
+            cash: self.config.initial_capital,
+            ..Account::default()
+        };
+        let mut equity_curve = Vec::with_capacity(bars.len());
+        let mut latency = self.config.latency.clone().map(LatencyModel::new);
+        // Target position and the time the order reaches the venue
+        let mut pending_target: Option<(f64, DateTime<Utc>)> = None;
+        let mut bars_in_market = 0usize;
+
+        for (i, bar) in bars.iter().enumerate() {
+            if let Some((target, arrival)) = pending_target {
+                let bar_end = match bars.get(i + 1) {
+                    Some(next) => next.timestamp,
+                    None => bar.timestamp + (bar.timestamp - bars[i - 1].timestamp),
+                };
+                // Orders delayed past the end of this bar wait for the one they land in
+                if arrival < bar_end || i + 1 == bars.len() {
+                    pending_target = None;
+                    let price = self.fill_price(arrival_price(bar, bar_end, arrival), target - account.position);
+                    account.rebalance(target, price, self.config.fee_bps, arrival);
+                }
+            }
+
+            if account.position != 0.0 {
+                bars_in_market += 1;
+            }
+
+            equity_curve.push(EquityPoint {
+                timestamp: bar.timestamp,
+                equity: account.equity(bar.close),
+                cash: account.cash,
+                position: account.position,
+                price: bar.close,
+            });
+
+            // No next bar to fill on
+            if i + 1 == bars.len() {
+                break;
+            }
+
+            let market_data = self.market_data(bars, i);
+            let signal = strategy
+                .generate_signal(&market_data)
+                .await
+                .map_err(|e| BacktestError::Strategy(e.to_string()))?;
+
+            if let Some(signal) = signal {
+                let quantity = signal.quantity.unwrap_or_else(|| {
+                    account.equity(bar.close) * self.config.position_size_pct / bar.close
+                });
+
+                // Repeated entries in the current direction are ignored rather than re-sized
+                let target = match (signal.action, signal.direction) {
+                    (SignalAction::Enter, PositionDirection::Long) if account.position <= 0.0 => Some(quantity),
+                    (SignalAction::Enter, PositionDirection::Short)
+                        if self.config.allow_short && account.position >= 0.0 => Some(-quantity),
+                    (SignalAction::Exit, _) if account.position != 0.0 => Some(0.0),
+                    _ => None,
+                };
+
+                // A newer decision on the other side supersedes an order still in
+                // flight; repeating the same decision does not restart its clock
+                let in_flight = match (pending_target, target) {
+                    (Some((pending, _)), Some(target)) => pending.partial_cmp(&0.0) == target.partial_cmp(&0.0),
+                    _ => false,
+                };
+                if let Some(target) = target.filter(|_| !in_flight) {
+                    let delay_ms = latency.as_mut()
+                        .map(|model| model.sample(&self.config.exchange).total_ms())
+                        .unwrap_or(0.0);
+                    let arrival = bars[i + 1].timestamp + Duration::microseconds((delay_ms * 1_000.0) as i64);
+                    pending_target = Some((target, arrival));
+                }
+            }
+        }
+
+        if self.config.close_at_end && account.position != 0.0 {
+            let last = &bars[bars.len() - 1];
+            let price = self.fill_price(last.close, -account.position);
+            account.rebalance(0.0, price, self.config.fee_bps, last.timestamp);
+
+            if let Some(point) = equity_curve.last_mut() {
+                point.equity = account.equity(last.close);
+                point.cash = account.cash;
+                point.position = 0.0;
+            }
+        }
+
+        let metrics = self.calculate_metrics(&account, &equity_curve, bars_in_market);
+        debug!(
+            "Backtest of {} finished: {} trades, return {:.2}%",
+            strategy.name(), metrics.trade_count, metrics.total_return_pct
+        );
+
+        Ok(BacktestReport {
+            strategy: strategy.name().to_string(),
+            config: self.config.clone(),
+            metrics,
+            equity_curve,
+            trades: account.trades,
+            started_at,
+            finished_at: Utc::now(),
+        })
+    }
+
+    /// Apply adverse slippage for a fill of the given signed size
+    pub(crate) fn fill_price(&self, price: f64, delta: f64) -> f64 {
+        let slippage = price * self.config.slippage_bps / 10_000.0;
+        if delta > 0.0 { price + slippage } else { price - slippage }
+    }
+
+    /// Market data snapshot as of the close of bar `index`
+    pub(crate) fn market_data(&self, bars: &[BacktestBar], index: usize) -> MarketData {
+        let bar = &bars[index];
+        let start = (index + 1).saturating_sub(self.config.candle_window.max(1));
+        let day_start = index.saturating_sub(23);
+        let first_close = bars[day_start].close;
+
+        let ticker = Ticker {
+            bid: bar.close,
+            ask: bar.close,
+            last: bar.close,
+            volume: bar.volume,
+            change_24h: if first_close > 0.0 { (bar.close - first_close) / first_close * 100.0 } else { 0.0 },
+            high_24h: bars[day_start..=index].iter().map(|b| b.high).fold(f64::MIN, f64::max),
+            low_24h: bars[day_start..=index].iter().map(|b| b.low).fold(f64::MAX, f64::min),
+            quote_volume: bar.volume * bar.close,
+        };
+
+        let candles: Vec<Candle> = bars[start..=index]
+            .iter()
+            .map(|b| Candle::new(
+                b.timestamp,
+                Decimal::from_f64(b.open).unwrap_or_default(),
+                Decimal::from_f64(b.high).unwrap_or_default(),
+                Decimal::from_f64(b.low).unwrap_or_default(),
+                Decimal::from_f64(b.close).unwrap_or_default(),
+                Decimal::from_f64(b.volume).unwrap_or_default(),
+            ))
+            .collect();
+
+        let mut market_data = MarketData::new(self.config.exchange.clone(), self.config.symbol.clone(), ticker);
+        market_data.candles = HashMap::from([(self.config.timeframe.clone(), candles)]);
+        market_data.last_updated = bar.timestamp.timestamp();
+        market_data.source = "backtest".to_string();
+        market_data
+    }
+
+    fn calculate_metrics(&self, account: &Account, equity_curve: &[EquityPoint], bars_in_market: usize) -> BacktestMetrics {
+        let equity: Vec<f64> = equity_curve.iter().map(|p| p.equity).collect();
+        let returns = analytics_math::period_returns(&equity);
+        let final_equity = equity.last().copied().unwrap_or(self.config.initial_capital);
+        let (max_drawdown_pct, _) = analytics_math::drawdown_metrics(&equity);
+
+        let closed: Vec<f64> = account.trades.iter().filter_map(|t| t.realized_pnl).collect();
+        let wins = closed.iter().filter(|pnl| **pnl > 0.0).count();
+
+        BacktestMetrics {
+            initial_capital: self.config.initial_capital,
+            final_equity,
+            total_return_pct: analytics_math::percent_change(self.config.initial_capital, final_equity).unwrap_or(0.0),
+            sharpe_ratio: analytics_math::sharpe_ratio(&returns, 0.0).unwrap_or(0.0),
+            sortino_ratio: analytics_math::sortino_ratio(&returns, 0.0).unwrap_or(0.0),
+            max_drawdown_pct,
+            trade_count: account.trades.len(),
+            closed_trades: closed.len(),
+            win_rate: if closed.is_empty() { 0.0 } else { wins as f64 / closed.len() as f64 },
+            total_fees: account.fees,
+            exposure_pct: bars_in_market as f64 / equity_curve.len().max(1) as f64,
+            bars: equity_curve.len(),
+        }
+    }
+}
+
+/// Create a backtest engine
+pub fn create_backtest_engine(config: BacktestConfig) -> BacktestEngine {
+    BacktestEngine::new(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use crate::strategy::{RiskProfile, Signal, StrategyError};
+    use chrono::Duration;
+
+    /// Buys on the first bar and exits once the price rises above 110
+    struct BuyThenExit;
+
+    #[async_trait]
+    impl Strategy for BuyThenExit {
+        async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+            let action = if market_data.ticker.last > 110.0 { SignalAction::Exit } else { SignalAction::Enter };
+            Ok(Some(Signal::new(self.name().to_string(), market_data.symbol.clone(), action)))
+        }
+
+        async fn get_risk_profile(&self) -> RiskProfile {
+            RiskProfile::default()
+        }
+
+        fn name(&self) -> &str {
+            "buy_then_exit"
+        }
+    }
+
+    fn bars(closes: &[f64]) -> Vec<BacktestBar> {
+        let start = Utc::now();
+        closes.iter().enumerate().map(|(i, close)| BacktestBar {
+            timestamp: start + Duration::hours(i as i64),
+            open: *close,
+            high: *close,
+            low: *close,
+            close: *close,
+            volume: 1.0,
+        }).collect()
+    }
+
+    #[tokio::test]
+    async fn test_backtest_round_trip() {
+        let config = BacktestConfig {
+            fee_bps: 0.0,
+            slippage_bps: 0.0,
+            position_size_pct: 1.0,
+            ..BacktestConfig::default()
+        };
+        let engine = BacktestEngine::new(config);
+
+        let report = engine.run(&BuyThenExit, &bars(&[100.0, 100.0, 105.0, 120.0, 120.0, 120.0])).await.unwrap();
+
+        // Entry fills at bar 1 open (100), exit at bar 4 open (120)
+        assert_eq!(report.trades.len(), 2);
+        assert_eq!(report.trades[0].side, BacktestSide::Buy);
+        assert!((report.metrics.total_return_pct - 20.0).abs() < 1e-9);
+        assert_eq!(report.metrics.win_rate, 1.0);
+        assert_eq!(report.equity_curve.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_latency_delays_fill_into_later_bar() {
+        use crate::simulation::latency_model::{LatencyDistribution, VenueLatencyProfile};
+
+        let config = BacktestConfig {
+            fee_bps: 0.0,
+            slippage_bps: 0.0,
+            position_size_pct: 1.0,
+            latency: Some(LatencyModelConfig {
+                default_profile: VenueLatencyProfile {
+                    transit: LatencyDistribution::Constant { ms: 90.0 * 60_000.0 },
+                    matching: LatencyDistribution::Constant { ms: 0.0 },
+                },
+                ..LatencyModelConfig::default()
+            }),
+            ..BacktestConfig::default()
+        };
+        let engine = BacktestEngine::new(config);
+        let bars = bars(&[100.0, 100.0, 105.0, 120.0, 120.0, 120.0]);
+
+        let report = engine.run(&BuyThenExit, &bars).await.unwrap();
+
+        // The entry decided at bar 0's close lands halfway through bar 2
+        assert_eq!(report.trades.len(), 2);
+        assert_eq!(report.trades[0].price, 105.0);
+        assert_eq!(report.trades[0].timestamp, bars[1].timestamp + Duration::minutes(90));
+        assert!((report.metrics.total_return_pct - (120.0 / 105.0 - 1.0) * 100.0).abs() < 1e-9);
+    }
+}
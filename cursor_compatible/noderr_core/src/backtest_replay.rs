@@ -0,0 +1,1358 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+
+//! Event-driven replay of recorded market data through the live trading path
+//!
+//! Where [`crate::backtest::BacktestEngine`] steps one strategy over bars,
+//! the replay engine drives any number of strategies over ticks or candles
+//! exactly as production does: signals go through the strategy engine's
+//! conflict resolution and evaluation, then through the [`ExecutionService`]
+//! in [`ExecutionMode::Backtest`]. Orders are filled by a
+//! [`SimulatedFillProvider`] instead of a venue. An order reaches the market
+//! after a sampled latency; if it is marketable there it crosses the touch
+//! with slippage from the liquidity profiler, otherwise it rests and is
+//! filled by later trade prints once the queue ahead of it has traded.
+//!
+//! Results are reported per strategy in the same shapes the analytics
+//! service uses for live trading, so replays and production can be compared
+//! directly.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::analytics::{ExecutionStats, PerformanceSummary};
+use crate::analytics_math;
+use crate::backtest::{Account, BacktestError, BacktestResult, BacktestSide, BacktestTrade};
+use crate::execution::{
+    ExecutionError, ExecutionMode, ExecutionProvider, ExecutionRequest, ExecutionResult, ExecutionService,
+    ExecutionStatus, FeeInfo,
+};
+use crate::flight_recorder::{FlightRecording, RecordedEventKind};
+use crate::market::{Candle, MarketData, Ticker};
+use crate::market_data::MarketTick;
+use crate::microstructure::liquidity::LiquidityProfiler;
+use crate::orderbook::OrderSide;
+use crate::queue_position::{QueuePositionConfig, QueuePositionEstimator};
+use crate::risk::PositionDirection;
+use crate::runaway_detector::signal_side;
+use crate::simulation::latency_model::{LatencyModel, LatencyModelConfig};
+use crate::strategy::{Signal, SignalAction, Strategy};
+use crate::strategy_engine::StrategyEngine;
+
+/// One market data event in a replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReplayEvent {
+    /// A trade or quote update
+    Tick(MarketTick),
+    /// A completed candle
+    Candle {
+        symbol: String,
+        timeframe: String,
+        candle: Candle,
+        /// When the candle closed; candles are stamped with their open time
+        closed_at: DateTime<Utc>,
+    },
+}
+
+impl ReplayEvent {
+    /// Time at which the event becomes visible to strategies
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        match self {
+            Self::Tick(tick) => tick.timestamp,
+            Self::Candle { closed_at, .. } => *closed_at,
+        }
+    }
+
+    /// Symbol the event belongs to
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Tick(tick) => &tick.symbol,
+            Self::Candle { symbol, .. } => symbol,
+        }
+    }
+}
+
+/// Tick events captured in a flight recorder dump, oldest first
+pub fn events_from_recording(recording: &FlightRecording) -> Vec<ReplayEvent> {
+    let mut events: Vec<ReplayEvent> = recording.events
+        .values()
+        .flatten()
+        .filter(|event| event.kind == RecordedEventKind::Tick)
+        .filter_map(|event| match serde_json::from_value::<MarketTick>(event.data.clone()) {
+            Ok(tick) => Some(ReplayEvent::Tick(tick)),
+            Err(e) => {
+                debug!("Skipping unreadable tick for {} at {}: {}", event.symbol, event.timestamp, e);
+                None
+            }
+        })
+        .collect();
+    events.sort_by_key(ReplayEvent::timestamp);
+    events
+}
+
+/// Simulated fill model configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SimulatedFillConfig {
+    /// Venue reported on fills and used to pick the latency profile
+    pub venue: String,
+    /// Fee for fills that take liquidity (basis points of notional)
+    pub taker_fee_bps: f64,
+    /// Fee for resting fills that provide liquidity (basis points of notional)
+    pub maker_fee_bps: f64,
+    /// Slippage past the touch when the liquidity profiler has no estimate (basis points)
+    pub default_slippage_bps: f64,
+    /// Seconds a passive order may rest before it is cancelled; 0 keeps it working
+    pub max_resting_secs: i64,
+    /// Order latency model; orders reach the market instantly when not set
+    pub latency: Option<LatencyModelConfig>,
+}
+
+impl Default for SimulatedFillConfig {
+    fn default() -> Self {
+        Self {
+            venue: "backtest".to_string(),
+            taker_fee_bps: 10.0,     // 0.10% per aggressive fill
+            maker_fee_bps: 2.0,      // 0.02% per passive fill
+            default_slippage_bps: 5.0,
+            max_resting_secs: 300,
+            latency: None,
+        }
+    }
+}
+
+/// A single simulated fill
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedFill {
+    pub request_id: String,
+    pub strategy_id: String,
+    pub symbol: String,
+    pub side: BacktestSide,
+    pub quantity: f64,
+    pub price: f64,
+    pub fee: f64,
+    /// Whether the fill rested on the book and provided liquidity
+    pub maker: bool,
+    /// Fill price against the mid when the order was sent (basis points, positive is adverse)
+    pub slippage_bps: f64,
+    /// Time from sending the order to this fill
+    pub latency_ms: i64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Best quote and last trade for a symbol as seen by the fill model
+#[derive(Debug, Clone, Copy, Default)]
+struct Quote {
+    bid: f64,
+    ask: f64,
+    last: f64,
+    bid_size: f64,
+    ask_size: f64,
+}
+
+impl Quote {
+    fn mid(&self) -> f64 {
+        if self.bid > 0.0 && self.ask > 0.0 { (self.bid + self.ask) / 2.0 } else { self.last }
+    }
+
+    /// Price an aggressive order on `side` trades at before slippage
+    fn touch(&self, side: BacktestSide) -> f64 {
+        match side {
+            BacktestSide::Buy if self.ask > 0.0 => self.ask,
+            BacktestSide::Sell if self.bid > 0.0 => self.bid,
+            _ => self.last,
+        }
+    }
+}
+
+/// Trade activity carried by one event
+#[derive(Debug, Clone, Copy)]
+struct Print {
+    price: f64,
+    size: f64,
+    low: f64,
+    high: f64,
+}
+
+/// An order working in the simulator
+#[derive(Debug, Clone)]
+struct SimOrder {
+    request_id: String,
+    strategy_id: String,
+    symbol: String,
+    side: BacktestSide,
+    remaining: f64,
+    limit: Option<f64>,
+    sent_at: DateTime<Utc>,
+    arrival: DateTime<Utc>,
+    /// Mid when the order was sent, for slippage
+    reference_price: f64,
+    /// Whether the order has reached the market and is resting on the book
+    resting: bool,
+}
+
+#[derive(Debug, Default)]
+struct FillState {
+    /// Timestamp of the latest replayed event
+    clock: Option<DateTime<Utc>>,
+    quotes: HashMap<String, Quote>,
+    /// Working orders in the order they were sent
+    orders: Vec<SimOrder>,
+    results: HashMap<String, ExecutionResult>,
+    /// Fills not yet collected by [`SimulatedFillProvider::drain_fills`]
+    fills: Vec<SimulatedFill>,
+}
+
+/// Execution provider that fills orders against replayed market data
+pub struct SimulatedFillProvider {
+    config: SimulatedFillConfig,
+    liquidity: Option<Arc<dyn LiquidityProfiler>>,
+    queue: QueuePositionEstimator,
+    latency: Option<Mutex<LatencyModel>>,
+    state: tokio::sync::Mutex<FillState>,
+}
+
+impl SimulatedFillProvider {
+    /// Create a new simulated fill provider
+    pub fn new(config: SimulatedFillConfig) -> Self {
+        let latency = config.latency.clone().map(|c| Mutex::new(LatencyModel::new(c)));
+        Self {
+            config,
+            liquidity: None,
+            queue: QueuePositionEstimator::new(QueuePositionConfig::default()),
+            latency,
+            state: tokio::sync::Mutex::new(FillState::default()),
+        }
+    }
+
+    /// Estimate slippage of marketable orders from a liquidity profiler
+    pub fn with_liquidity_profiler(mut self, profiler: Arc<dyn LiquidityProfiler>) -> Self {
+        self.liquidity = Some(profiler);
+        self
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &SimulatedFillConfig {
+        &self.config
+    }
+
+    /// Liquidity profiler used for slippage, if any
+    pub fn liquidity_profiler(&self) -> Option<&Arc<dyn LiquidityProfiler>> {
+        self.liquidity.as_ref()
+    }
+
+    /// Advance the simulation to `event`
+    ///
+    /// Orders that reached the market before the event are matched against
+    /// the book as it was, then the event updates the book and its trade
+    /// print is matched against resting orders.
+    pub async fn on_event(&self, event: &ReplayEvent) {
+        let mut state = self.state.lock().await;
+        let at = event.timestamp();
+        state.clock = Some(at);
+        self.process_arrivals(&mut state, at).await;
+
+        let symbol = event.symbol().to_string();
+        let previous = state.quotes.get(&symbol).copied().unwrap_or_default();
+        let (quote, print) = match event {
+            ReplayEvent::Tick(tick) => {
+                let bid_size = tick.fields.get("bid_size").copied();
+                let ask_size = tick.fields.get("ask_size").copied();
+                if let Some(size) = bid_size {
+                    self.queue.on_book_update(&symbol, OrderSide::Bid, tick.bid.unwrap_or(tick.price), size).await;
+                }
+                if let Some(size) = ask_size {
+                    self.queue.on_book_update(&symbol, OrderSide::Ask, tick.ask.unwrap_or(tick.price), size).await;
+                }
+                let quote = Quote {
+                    bid: tick.bid.unwrap_or(tick.price),
+                    ask: tick.ask.unwrap_or(tick.price),
+                    last: tick.price,
+                    bid_size: bid_size.unwrap_or(previous.bid_size),
+                    ask_size: ask_size.unwrap_or(previous.ask_size),
+                };
+                (quote, Print { price: tick.price, size: tick.volume, low: tick.price, high: tick.price })
+            }
+            ReplayEvent::Candle { candle, .. } => {
+                let close = candle.close.to_f64().unwrap_or(0.0);
+                let quote = Quote { bid: close, ask: close, last: close, ..Quote::default() };
+                let print = Print {
+                    price: close,
+                    size: candle.volume.to_f64().unwrap_or(0.0),
+                    low: candle.low.to_f64().unwrap_or(close),
+                    high: candle.high.to_f64().unwrap_or(close),
+                };
+                (quote, print)
+            }
+        };
+        state.quotes.insert(symbol.clone(), quote);
+
+        if print.size > 0.0 {
+            self.match_resting(&mut state, &symbol, previous, print, at).await;
+        }
+        self.expire_resting(&mut state, at).await;
+    }
+
+    /// Fills since the last call, oldest first
+    pub async fn drain_fills(&self) -> Vec<SimulatedFill> {
+        std::mem::take(&mut self.state.lock().await.fills)
+    }
+
+    /// Request IDs of orders still working
+    pub async fn open_orders(&self) -> Vec<String> {
+        self.state.lock().await.orders.iter().map(|o| o.request_id.clone()).collect()
+    }
+
+    /// Match orders that have reached the market by `now` against the current quote
+    async fn process_arrivals(&self, state: &mut FillState, now: DateTime<Utc>) {
+        let arrived: Vec<SimOrder> = state.orders
+            .iter()
+            .filter(|o| !o.resting && o.arrival <= now)
+            .cloned()
+            .collect();
+
+        for order in arrived {
+            let Some(quote) = state.quotes.get(&order.symbol).copied() else {
+                self.reject(state, &order.request_id, format!("No market data for {}", order.symbol));
+                continue;
+            };
+
+            let touch = quote.touch(order.side);
+            let marketable = match (order.limit, order.side) {
+                (None, _) => true,
+                (Some(limit), BacktestSide::Buy) => limit >= touch,
+                (Some(limit), BacktestSide::Sell) => limit <= touch,
+            };
+
+            if marketable {
+                let slippage = self.slippage(&order.symbol, order.remaining, order.side == BacktestSide::Buy).await;
+                let mut price = match order.side {
+                    BacktestSide::Buy => touch * (1.0 + slippage),
+                    BacktestSide::Sell => touch * (1.0 - slippage),
+                };
+                if let Some(limit) = order.limit {
+                    price = match order.side {
+                        BacktestSide::Buy => price.min(limit),
+                        BacktestSide::Sell => price.max(limit),
+                    };
+                }
+                self.record_fill(state, &order.request_id, order.remaining, price, false, order.arrival);
+                continue;
+            }
+
+            // Passive orders join the back of the visible size at their price
+            let limit = order.limit.unwrap_or(touch);
+            let (book_side, level_size) = match order.side {
+                BacktestSide::Buy => (OrderSide::Bid, if limit == quote.bid { quote.bid_size } else { 0.0 }),
+                BacktestSide::Sell => (OrderSide::Ask, if limit == quote.ask { quote.ask_size } else { 0.0 }),
+            };
+            if let Err(e) = self.queue
+                .register_order(&order.request_id, &order.symbol, book_side, limit, order.remaining, level_size)
+                .await
+            {
+                self.reject(state, &order.request_id, e.to_string());
+                continue;
+            }
+            if let Some(working) = state.orders.iter_mut().find(|o| o.request_id == order.request_id) {
+                working.resting = true;
+            }
+            if let Some(result) = state.results.get_mut(&order.request_id) {
+                result.additional_data.insert("resting".to_string(), serde_json::json!(true));
+            }
+        }
+    }
+
+    /// Fill resting orders from a trade print
+    async fn match_resting(&self, state: &mut FillState, symbol: &str, previous: Quote, print: Print, at: DateTime<Utc>) {
+        // Prints at or through the previous touch tell us which side was the aggressor
+        let is_buy = if previous.ask > 0.0 && print.price >= previous.ask {
+            true
+        } else if previous.bid > 0.0 && print.price <= previous.bid {
+            false
+        } else {
+            print.price >= previous.last
+        };
+        // Our orders on the side the aggressor hit
+        let hit_side = if is_buy { BacktestSide::Sell } else { BacktestSide::Buy };
+
+        let resting: Vec<SimOrder> = state.orders
+            .iter()
+            .filter(|o| o.resting && o.symbol == symbol)
+            .cloned()
+            .collect();
+        let mut queue_ahead = HashMap::new();
+        for order in &resting {
+            if let Ok(estimate) = self.queue.estimate(&order.request_id).await {
+                queue_ahead.insert(order.request_id.clone(), estimate.queue_ahead);
+            }
+        }
+        self.queue.on_trade(symbol, print.price, print.size, is_buy, at).await;
+
+        let mut available = print.size;
+        for order in resting {
+            let Some(limit) = order.limit else { continue };
+            let through = match order.side {
+                BacktestSide::Buy => print.low < limit,
+                BacktestSide::Sell => print.high > limit,
+            };
+            let quantity = if through {
+                order.remaining
+            } else if order.side == hit_side && print.price == limit {
+                let ahead = queue_ahead.get(&order.request_id).copied().unwrap_or(0.0);
+                let quantity = (print.size - ahead).max(0.0).min(order.remaining).min(available);
+                available -= quantity;
+                quantity
+            } else {
+                0.0
+            };
+
+            if quantity > 0.0 {
+                if let Err(e) = self.queue.on_fill(&order.request_id, quantity).await {
+                    debug!("Queue estimator lost track of {}: {}", order.request_id, e);
+                }
+                self.record_fill(state, &order.request_id, quantity, limit, true, at);
+            }
+        }
+    }
+
+    /// Cancel passive orders that have rested longer than allowed
+    async fn expire_resting(&self, state: &mut FillState, now: DateTime<Utc>) {
+        if self.config.max_resting_secs <= 0 {
+            return;
+        }
+        let max_age = Duration::seconds(self.config.max_resting_secs);
+        let expired: Vec<String> = state.orders
+            .iter()
+            .filter(|o| o.resting && now - o.arrival > max_age)
+            .map(|o| o.request_id.clone())
+            .collect();
+        for request_id in expired {
+            self.queue.remove_order(&request_id).await;
+            Self::close_order(state, &request_id, ExecutionStatus::Cancelled, Some("Resting order expired".to_string()));
+        }
+    }
+
+    /// Slippage past the touch as a fraction of price
+    async fn slippage(&self, symbol: &str, size: f64, is_buy: bool) -> f64 {
+        if let Some(profiler) = &self.liquidity {
+            match profiler.calculate_slippage(&symbol.to_string(), size, is_buy).await {
+                Ok(slippage) if slippage.is_finite() && slippage >= 0.0 => return slippage,
+                Ok(_) => {}
+                Err(e) => debug!("No slippage estimate for {}: {}", symbol, e),
+            }
+        }
+        self.config.default_slippage_bps / 10_000.0
+    }
+
+    fn record_fill(&self, state: &mut FillState, request_id: &str, quantity: f64, price: f64, maker: bool, at: DateTime<Utc>) {
+        let Some(index) = state.orders.iter().position(|o| o.request_id == request_id) else {
+            return;
+        };
+        let order = &mut state.orders[index];
+        order.remaining = (order.remaining - quantity).max(0.0);
+        let done = order.remaining <= f64::EPSILON;
+
+        let fee_bps = if maker { self.config.maker_fee_bps } else { self.config.taker_fee_bps };
+        let fee = quantity * price * fee_bps / 10_000.0;
+        let sign = if order.side == BacktestSide::Buy { 1.0 } else { -1.0 };
+        let slippage_bps = if order.reference_price > 0.0 {
+            (price - order.reference_price) / order.reference_price * 10_000.0 * sign
+        } else {
+            0.0
+        };
+        let latency_ms = (at - order.sent_at).num_milliseconds().max(0);
+
+        let fill = SimulatedFill {
+            request_id: order.request_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+            symbol: order.symbol.clone(),
+            side: order.side,
+            quantity,
+            price,
+            fee,
+            maker,
+            slippage_bps,
+            latency_ms,
+            timestamp: at,
+        };
+
+        if let Some(result) = state.results.get_mut(request_id) {
+            let filled = result.executed_quantity.unwrap_or(0.0);
+            let total = filled + quantity;
+            result.average_price = Some((result.average_price.unwrap_or(0.0) * filled + price * quantity) / total);
+            result.executed_quantity = Some(total);
+            let fees = result.fees.unwrap_or(0.0) + fee;
+            let fee_type = if maker { "maker" } else { "taker" };
+            result.fee_info = Some(FeeInfo::new(fees, "quote", fee_bps / 100.0, fee_type));
+            result.fees = Some(fees);
+            result.fee_currency = Some("quote".to_string());
+            result.status = if done { ExecutionStatus::Completed } else { ExecutionStatus::PartiallyFilled };
+            result.timestamp = at;
+            result.execution_time_ms = latency_ms as u64;
+        }
+        if done {
+            state.orders.remove(index);
+        }
+        state.fills.push(fill);
+    }
+
+    fn reject(&self, state: &mut FillState, request_id: &str, reason: String) {
+        warn!("Simulated order {} rejected: {}", request_id, reason);
+        Self::close_order(state, request_id, ExecutionStatus::Rejected, Some(reason));
+    }
+
+    /// Remove a working order and finalise its result
+    fn close_order(state: &mut FillState, request_id: &str, status: ExecutionStatus, reason: Option<String>) {
+        state.orders.retain(|o| o.request_id != request_id);
+        if let Some(result) = state.results.get_mut(request_id) {
+            result.status = status;
+            if let Some(clock) = state.clock {
+                result.timestamp = clock;
+            }
+            if reason.is_some() {
+                result.error_message = reason;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ExecutionProvider for SimulatedFillProvider {
+    async fn execute(&self, request: ExecutionRequest) -> Result<ExecutionResult, ExecutionError> {
+        let signal = &request.signal;
+        let side = match signal_side(&signal.action, signal.direction) {
+            Some(1) => BacktestSide::Buy,
+            Some(_) => BacktestSide::Sell,
+            None => {
+                return Err(ExecutionError::ValidationError(format!(
+                    "Signal {} ({:?} {:?}) is not an order", signal.id, signal.action, signal.direction
+                )))
+            }
+        };
+        let quantity = signal.quantity.filter(|q| *q > 0.0).ok_or_else(|| {
+            ExecutionError::ValidationError(format!("Signal {} has no quantity", signal.id))
+        })?;
+
+        let mut state = self.state.lock().await;
+        let now = state.clock.unwrap_or_else(Utc::now);
+        let delay_ms = self.latency.as_ref()
+            .map(|model| model.lock().unwrap().sample(&self.config.venue).total_ms())
+            .unwrap_or(0.0);
+        let reference_price = state.quotes.get(&signal.symbol)
+            .map(Quote::mid)
+            .or(signal.price)
+            .unwrap_or(0.0);
+
+        let mut result = ExecutionResult::success(
+            request.id.clone(),
+            signal.id.clone(),
+            Some(format!("sim-{}", request.id)),
+            0.0,
+            0.0,
+        );
+        result.status = ExecutionStatus::InProgress;
+        result.executed_quantity = None;
+        result.average_price = None;
+        result.timestamp = now;
+        result.additional_data.insert("venue".to_string(), serde_json::json!(self.config.venue));
+        result.additional_data.insert("strategy_id".to_string(), serde_json::json!(signal.strategy_id));
+        state.results.insert(request.id.clone(), result);
+
+        state.orders.push(SimOrder {
+            request_id: request.id.clone(),
+            strategy_id: signal.strategy_id.clone(),
+            symbol: signal.symbol.clone(),
+            side,
+            remaining: quantity,
+            limit: signal.price,
+            sent_at: now,
+            arrival: now + Duration::microseconds((delay_ms * 1_000.0) as i64),
+            reference_price,
+            resting: false,
+        });
+        self.process_arrivals(&mut state, now).await;
+
+        Ok(state.results[&request.id].clone())
+    }
+
+    async fn cancel(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+        let mut state = self.state.lock().await;
+        if !state.orders.iter().any(|o| o.request_id == request_id) {
+            return match state.results.get(request_id) {
+                Some(result) => Err(ExecutionError::OrderRejected(format!(
+                    "Order {} is already {:?}", request_id, result.status
+                ))),
+                None => Err(ExecutionError::ValidationError(format!("Unknown order {}", request_id))),
+            };
+        }
+
+        self.queue.remove_order(request_id).await;
+        Self::close_order(&mut state, request_id, ExecutionStatus::Cancelled, None);
+        Ok(state.results[request_id].clone())
+    }
+
+    async fn get_status(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+        self.state.lock().await.results
+            .get(request_id)
+            .cloned()
+            .ok_or_else(|| ExecutionError::ValidationError(format!("Unknown order {}", request_id)))
+    }
+
+    fn supports_mode(&self, mode: ExecutionMode) -> bool {
+        mode == ExecutionMode::Backtest
+    }
+
+    fn name(&self) -> &str {
+        "SimulatedFillProvider"
+    }
+}
+
+/// Replay engine configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayConfig {
+    /// Exchange reported to strategies in market data
+    pub exchange: String,
+    /// Starting capital of each strategy
+    pub initial_capital: f64,
+    /// Fraction of equity committed per entry when a signal has no quantity (0.0-1.0)
+    pub position_size_pct: f64,
+    /// Whether short entries are allowed
+    pub allow_short: bool,
+    /// Candles kept per symbol and timeframe for strategies
+    pub candle_window: usize,
+    /// Minimum spacing of equity curve points in seconds
+    pub equity_interval_secs: i64,
+    /// Cancel working orders and flatten positions after the last event
+    pub close_at_end: bool,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            exchange: "backtest".to_string(),
+            initial_capital: 100_000.0,
+            position_size_pct: 0.5,
+            allow_short: false,
+            candle_window: 200,
+            equity_interval_secs: 60,
+            close_at_end: true,
+        }
+    }
+}
+
+/// Equity of one strategy at a point in the replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyEquityPoint {
+    pub timestamp: DateTime<Utc>,
+    pub equity: f64,
+    /// Gross notional of open positions at the latest prices
+    pub gross_exposure: f64,
+}
+
+/// Replay results of one strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyReplayResult {
+    pub strategy: String,
+    pub performance: PerformanceSummary,
+    pub execution: ExecutionStats,
+    /// Quantity-weighted fill slippage against the mid when orders were sent (basis points)
+    pub avg_slippage_bps: f64,
+    /// Fraction of filled quantity that rested and provided liquidity (0.0-1.0)
+    pub maker_fill_ratio: f64,
+    pub total_fees: f64,
+    pub equity_curve: Vec<StrategyEquityPoint>,
+    /// Position changes across all symbols, oldest first
+    pub trades: Vec<BacktestTrade>,
+    pub fills: Vec<SimulatedFill>,
+}
+
+/// Full output of a replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayReport {
+    pub config: ReplayConfig,
+    pub events: usize,
+    pub first_event: DateTime<Utc>,
+    pub last_event: DateTime<Utc>,
+    pub strategies: Vec<StrategyReplayResult>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl ReplayReport {
+    /// Results of a strategy by name
+    pub fn strategy(&self, name: &str) -> Option<&StrategyReplayResult> {
+        self.strategies.iter().find(|s| s.strategy == name)
+    }
+}
+
+/// Market data a strategy sees for one symbol
+struct SymbolFeed {
+    data: MarketData,
+    /// Last prices over the trailing day, for the 24h ticker fields
+    prices: VecDeque<(DateTime<Utc>, f64)>,
+}
+
+impl SymbolFeed {
+    fn new(exchange: &str, symbol: &str) -> Self {
+        let ticker = Ticker {
+            bid: 0.0,
+            ask: 0.0,
+            last: 0.0,
+            volume: 0.0,
+            change_24h: 0.0,
+            high_24h: 0.0,
+            low_24h: 0.0,
+            quote_volume: 0.0,
+        };
+        let mut data = MarketData::new(exchange.to_string(), symbol.to_string(), ticker);
+        data.source = "backtest".to_string();
+        Self { data, prices: VecDeque::new() }
+    }
+
+    fn update(&mut self, event: &ReplayEvent, candle_window: usize) {
+        let at = event.timestamp();
+        let (bid, ask, last, volume) = match event {
+            ReplayEvent::Tick(tick) => (tick.bid.unwrap_or(tick.price), tick.ask.unwrap_or(tick.price), tick.price, tick.volume),
+            ReplayEvent::Candle { timeframe, candle, .. } => {
+                let candles = self.data.candles.entry(timeframe.clone()).or_default();
+                candles.push(candle.clone());
+                if candles.len() > candle_window.max(1) {
+                    candles.remove(0);
+                }
+                let close = candle.close.to_f64().unwrap_or(0.0);
+                (close, close, close, candle.volume.to_f64().unwrap_or(0.0))
+            }
+        };
+
+        self.prices.push_back((at, last));
+        let cutoff = at - Duration::hours(24);
+        while self.prices.front().is_some_and(|(ts, _)| *ts < cutoff) {
+            self.prices.pop_front();
+        }
+        let first = self.prices.front().map(|(_, p)| *p).unwrap_or(last);
+
+        self.data.ticker = Ticker {
+            bid,
+            ask,
+            last,
+            volume,
+            change_24h: analytics_math::percent_change(first, last).unwrap_or(0.0),
+            high_24h: self.prices.iter().map(|(_, p)| *p).fold(f64::MIN, f64::max),
+            low_24h: self.prices.iter().map(|(_, p)| *p).fold(f64::MAX, f64::min),
+            quote_volume: volume * last,
+        };
+        self.data.last_updated = at.timestamp();
+    }
+}
+
+/// Outcome counts of one strategy's orders
+#[derive(Debug, Default)]
+struct ExecutionTally {
+    total: usize,
+    filled: usize,
+    partial: usize,
+    failed: usize,
+    rejected: usize,
+    fill_rates: Vec<f64>,
+    latencies: Vec<i64>,
+}
+
+impl ExecutionTally {
+    /// Count a finished order
+    fn record(&mut self, result: &ExecutionResult, ordered: f64) {
+        self.total += 1;
+        let executed = result.executed_quantity.unwrap_or(0.0);
+        match result.status {
+            ExecutionStatus::Completed => self.filled += 1,
+            ExecutionStatus::Rejected => self.rejected += 1,
+            ExecutionStatus::Failed | ExecutionStatus::TimedOut => self.failed += 1,
+            _ if executed > 0.0 => {
+                self.partial += 1;
+                if ordered > 0.0 {
+                    self.fill_rates.push(executed / ordered);
+                }
+            }
+            _ => {}
+        }
+        if executed > 0.0 {
+            self.latencies.push(result.execution_time_ms as i64);
+        }
+    }
+
+    fn stats(&self) -> ExecutionStats {
+        let mean = |values: &[f64]| if values.is_empty() { 0.0 } else { values.iter().sum::<f64>() / values.len() as f64 };
+        ExecutionStats {
+            total_executions: self.total,
+            filled_count: self.filled,
+            partial_count: self.partial,
+            failed_count: self.failed,
+            rejected_count: self.rejected,
+            avg_fill_rate: mean(&self.fill_rates),
+            avg_latency_ms: if self.latencies.is_empty() { 0 } else { self.latencies.iter().sum::<i64>() / self.latencies.len() as i64 },
+            min_latency_ms: self.latencies.iter().copied().min().unwrap_or(0),
+            max_latency_ms: self.latencies.iter().copied().max().unwrap_or(0),
+        }
+    }
+}
+
+/// State of one strategy during a replay
+struct StrategyRun {
+    strategy: Arc<dyn Strategy>,
+    accounts: HashMap<String, Account>,
+    /// When the open position in each symbol was opened
+    opened_at: HashMap<String, DateTime<Utc>>,
+    holding_secs: Vec<i64>,
+    /// Working orders by request ID, with the signal that created them
+    working: HashMap<String, Signal>,
+    equity_curve: Vec<StrategyEquityPoint>,
+    fills: Vec<SimulatedFill>,
+    tally: ExecutionTally,
+}
+
+impl StrategyRun {
+    fn new(strategy: Arc<dyn Strategy>) -> Self {
+        Self {
+            strategy,
+            accounts: HashMap::new(),
+            opened_at: HashMap::new(),
+            holding_secs: Vec::new(),
+            working: HashMap::new(),
+            equity_curve: Vec::new(),
+            fills: Vec::new(),
+            tally: ExecutionTally::default(),
+        }
+    }
+
+    fn position(&self, symbol: &str) -> f64 {
+        self.accounts.get(symbol).map(|a| a.position).unwrap_or(0.0)
+    }
+
+    fn equity(&self, initial_capital: f64, marks: &HashMap<String, f64>) -> f64 {
+        initial_capital + self.accounts
+            .iter()
+            .map(|(symbol, account)| account.equity(marks.get(symbol).copied().unwrap_or(account.entry_price)))
+            .sum::<f64>()
+    }
+
+    fn gross_exposure(&self, marks: &HashMap<String, f64>) -> f64 {
+        self.accounts
+            .iter()
+            .map(|(symbol, account)| (account.position * marks.get(symbol).copied().unwrap_or(account.entry_price)).abs())
+            .sum()
+    }
+
+    /// Trade `symbol` towards `target`, tracking how long positions are held
+    fn trade(&mut self, symbol: &str, target: f64, price: f64, fee_bps: f64, at: DateTime<Utc>) {
+        let account = self.accounts.entry(symbol.to_string()).or_default();
+        let before = account.position;
+        account.rebalance(target, price, fee_bps, at);
+        let after = account.position;
+
+        if before != 0.0 && (after == 0.0 || before.signum() != after.signum()) {
+            if let Some(opened) = self.opened_at.remove(symbol) {
+                self.holding_secs.push((at - opened).num_seconds());
+            }
+        }
+        if after != 0.0 {
+            self.opened_at.entry(symbol.to_string()).or_insert(at);
+        }
+    }
+
+    fn has_working_order(&self, symbol: &str) -> bool {
+        self.working.values().any(|s| s.symbol == symbol)
+    }
+
+    fn record_equity(&mut self, at: DateTime<Utc>, initial_capital: f64, marks: &HashMap<String, f64>, interval_secs: i64) {
+        let due = match self.equity_curve.last() {
+            Some(point) => (at - point.timestamp).num_seconds() >= interval_secs,
+            None => true,
+        };
+        if due {
+            self.equity_curve.push(StrategyEquityPoint {
+                timestamp: at,
+                equity: self.equity(initial_capital, marks),
+                gross_exposure: self.gross_exposure(marks),
+            });
+        }
+    }
+
+    fn into_result(mut self, initial_capital: f64) -> StrategyReplayResult {
+        let mut trades: Vec<BacktestTrade> = self.accounts.values_mut().flat_map(|a| a.trades.drain(..)).collect();
+        trades.sort_by_key(|t| t.timestamp);
+        for (i, trade) in trades.iter_mut().enumerate() {
+            trade.trade_id = i as u64 + 1;
+        }
+
+        let equity: Vec<f64> = self.equity_curve.iter().map(|p| p.equity).collect();
+        let returns = analytics_math::period_returns(&equity);
+        let (max_drawdown, current_drawdown) = analytics_math::drawdown_metrics(&equity);
+        let final_equity = equity.last().copied().unwrap_or(initial_capital);
+
+        let closed: Vec<f64> = trades.iter().filter_map(|t| t.realized_pnl).collect();
+        let profits: Vec<f64> = closed.iter().copied().filter(|pnl| *pnl > 0.0).collect();
+        let losses: Vec<f64> = closed.iter().copied().filter(|pnl| *pnl <= 0.0).collect();
+        let gross_profit: f64 = profits.iter().sum();
+        let gross_loss: f64 = losses.iter().map(|pnl| pnl.abs()).sum();
+        let mean = |values: &[f64]| if values.is_empty() { 0.0 } else { values.iter().sum::<f64>() / values.len() as f64 };
+
+        let performance = PerformanceSummary {
+            total_trades: closed.len(),
+            winning_trades: profits.len(),
+            losing_trades: losses.len(),
+            win_rate: if closed.is_empty() { 0.0 } else { profits.len() as f64 / closed.len() as f64 * 100.0 },
+            total_pnl: final_equity - initial_capital,
+            profit_factor: if gross_loss > 0.0 {
+                gross_profit / gross_loss
+            } else if gross_profit > 0.0 {
+                f64::INFINITY
+            } else {
+                0.0
+            },
+            max_drawdown,
+            current_drawdown,
+            avg_profit: mean(&profits),
+            avg_loss: mean(&losses),
+            sharpe_ratio: analytics_math::sharpe_ratio(&returns, 0.0).unwrap_or(0.0),
+            sortino_ratio: analytics_math::sortino_ratio(&returns, 0.0).unwrap_or(0.0),
+            avg_holding_time_seconds: if closed.is_empty() {
+                0
+            } else {
+                self.holding_secs.iter().sum::<i64>() / closed.len() as i64
+            },
+        };
+
+        let filled: f64 = self.fills.iter().map(|f| f.quantity).sum();
+        let weighted = |value: fn(&SimulatedFill) -> f64| if filled > 0.0 {
+            self.fills.iter().map(|f| value(f) * f.quantity).sum::<f64>() / filled
+        } else {
+            0.0
+        };
+
+        StrategyReplayResult {
+            strategy: self.strategy.name().to_string(),
+            performance,
+            execution: self.tally.stats(),
+            avg_slippage_bps: weighted(|f| f.slippage_bps),
+            maker_fill_ratio: weighted(|f| if f.maker { 1.0 } else { 0.0 }),
+            total_fees: trades.iter().map(|t| t.fee).sum(),
+            equity_curve: self.equity_curve,
+            trades,
+            fills: self.fills,
+        }
+    }
+}
+
+/// Replays market data through the strategy engine and execution service
+pub struct ReplayEngine {
+    config: ReplayConfig,
+    strategy_engine: Arc<StrategyEngine>,
+    execution: Arc<ExecutionService>,
+    fills: Arc<SimulatedFillProvider>,
+}
+
+impl ReplayEngine {
+    /// Create a new replay engine
+    ///
+    /// `execution` must route [`ExecutionMode::Backtest`] to `fills`.
+    pub fn new(
+        config: ReplayConfig,
+        strategy_engine: Arc<StrategyEngine>,
+        execution: Arc<ExecutionService>,
+        fills: Arc<SimulatedFillProvider>,
+    ) -> Self {
+        Self { config, strategy_engine, execution, fills }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &ReplayConfig {
+        &self.config
+    }
+
+    /// Replay `events` through `strategies`
+    pub async fn run(&self, strategies: &[Arc<dyn Strategy>], mut events: Vec<ReplayEvent>) -> BacktestResult<ReplayReport> {
+        if events.is_empty() {
+            return Err(BacktestError::InsufficientData("no events to replay".to_string()));
+        }
+        if strategies.is_empty() {
+            return Err(BacktestError::InvalidConfig("no strategies to replay".to_string()));
+        }
+        if self.config.initial_capital <= 0.0 {
+            return Err(BacktestError::InvalidConfig("initial_capital must be positive".to_string()));
+        }
+        for (i, strategy) in strategies.iter().enumerate() {
+            if strategies[..i].iter().any(|s| s.name() == strategy.name()) {
+                return Err(BacktestError::InvalidConfig(format!("duplicate strategy name {}", strategy.name())));
+            }
+        }
+        self.execution
+            .set_mode(ExecutionMode::Backtest)
+            .map_err(|e| BacktestError::InvalidConfig(e.to_string()))?;
+
+        let started_at = Utc::now();
+        events.sort_by_key(ReplayEvent::timestamp);
+        let first_event = events[0].timestamp();
+        let last_event = events[events.len() - 1].timestamp();
+
+        let mut runs: Vec<StrategyRun> = strategies.iter().cloned().map(StrategyRun::new).collect();
+        let mut feeds: HashMap<String, SymbolFeed> = HashMap::new();
+        let mut marks: HashMap<String, f64> = HashMap::new();
+
+        for event in &events {
+            let at = event.timestamp();
+            self.fills.on_event(event).await;
+
+            let symbol = event.symbol().to_string();
+            let feed = feeds
+                .entry(symbol.clone())
+                .or_insert_with(|| SymbolFeed::new(&self.config.exchange, &symbol));
+            feed.update(event, self.config.candle_window);
+            marks.insert(symbol.clone(), feed.data.ticker.last);
+            if let Some(profiler) = self.fills.liquidity_profiler() {
+                if let Err(e) = profiler.analyze_liquidity(&feed.data).await {
+                    debug!("Liquidity profiler skipped {} at {}: {}", symbol, at, e);
+                }
+            }
+
+            self.settle(&mut runs).await?;
+
+            // Every strategy sees the event before any of its orders are sent
+            let mut signals = Vec::new();
+            let mut owners = HashMap::new();
+            for (i, run) in runs.iter().enumerate() {
+                let signal = run.strategy
+                    .generate_signal(&feed.data)
+                    .await
+                    .map_err(|e| BacktestError::Strategy(e.to_string()))?;
+                if let Some(signal) = signal {
+                    owners.insert(signal.id.clone(), i);
+                    signals.push(signal);
+                }
+            }
+
+            if !signals.is_empty() {
+                let outcome = self.strategy_engine.resolve_conflicts(signals);
+                for signal in outcome.rejected {
+                    if let Some(&i) = owners.get(&signal.id) {
+                        runs[i].tally.total += 1;
+                        runs[i].tally.rejected += 1;
+                    }
+                }
+                for signal in outcome.accepted {
+                    if let Some(&i) = owners.get(&signal.id) {
+                        let mark = marks.get(&signal.symbol).copied().unwrap_or(0.0);
+                        let equity = runs[i].equity(self.config.initial_capital, &marks);
+                        self.submit(&mut runs[i], signal, mark, equity).await;
+                    }
+                }
+                self.settle(&mut runs).await?;
+            }
+
+            for run in &mut runs {
+                run.record_equity(at, self.config.initial_capital, &marks, self.config.equity_interval_secs);
+            }
+        }
+
+        if self.config.close_at_end {
+            for request_id in self.fills.open_orders().await {
+                if let Err(e) = self.execution.cancel_execution(&request_id).await {
+                    debug!("Could not cancel {} at end of replay: {}", request_id, e);
+                }
+            }
+            self.settle(&mut runs).await?;
+
+            let fill_config = self.fills.config();
+            for run in &mut runs {
+                let open: Vec<(String, f64)> = run.accounts
+                    .iter()
+                    .filter(|(_, a)| a.position != 0.0)
+                    .map(|(symbol, a)| (symbol.clone(), a.position))
+                    .collect();
+                for (symbol, position) in open {
+                    let mark = marks.get(&symbol).copied().unwrap_or(0.0);
+                    let slippage = mark * fill_config.default_slippage_bps / 10_000.0;
+                    let price = if position > 0.0 { mark - slippage } else { mark + slippage };
+                    run.trade(&symbol, 0.0, price, fill_config.taker_fee_bps, last_event);
+                }
+            }
+        }
+
+        for run in &mut runs {
+            // The final point always reflects the end state
+            if run.equity_curve.last().is_some_and(|p| p.timestamp == last_event) {
+                run.equity_curve.pop();
+            }
+            run.record_equity(last_event, self.config.initial_capital, &marks, 0);
+        }
+
+        let strategies: Vec<StrategyReplayResult> = runs
+            .into_iter()
+            .map(|run| run.into_result(self.config.initial_capital))
+            .collect();
+        for result in &strategies {
+            debug!(
+                "Replay of {} finished: {} fills, pnl {:.2}",
+                result.strategy, result.fills.len(), result.performance.total_pnl
+            );
+        }
+
+        Ok(ReplayReport {
+            config: self.config.clone(),
+            events: events.len(),
+            first_event,
+            last_event,
+            strategies,
+            started_at,
+            finished_at: Utc::now(),
+        })
+    }
+
+    /// Size an accepted signal against the strategy's position and send it
+    async fn submit(&self, run: &mut StrategyRun, mut signal: Signal, mark: f64, equity: f64) {
+        // One working order per symbol; later signals wait until it finishes
+        if run.has_working_order(&signal.symbol) || mark <= 0.0 {
+            return;
+        }
+
+        match self.strategy_engine.evaluate_signal(&signal).await {
+            Ok(evaluation) if evaluation.passed => {}
+            _ => {
+                run.tally.total += 1;
+                run.tally.rejected += 1;
+                return;
+            }
+        }
+
+        let position = run.position(&signal.symbol);
+        let quantity = signal.quantity.unwrap_or(equity * self.config.position_size_pct / mark);
+        // Repeated entries in the current direction are ignored rather than re-sized
+        let target = match (&signal.action, signal.direction) {
+            (SignalAction::Enter, PositionDirection::Long) if position <= 0.0 => Some(quantity),
+            (SignalAction::Enter, PositionDirection::Short) if self.config.allow_short && position >= 0.0 => {
+                Some(-quantity)
+            }
+            (SignalAction::Exit, _) if position != 0.0 => Some(0.0),
+            _ => None,
+        };
+        let Some(target) = target else { return };
+
+        let delta = target - position;
+        if delta.abs() < f64::EPSILON {
+            return;
+        }
+        signal.quantity = Some(delta.abs());
+        if signal.action.is_exit() {
+            signal.direction = if position > 0.0 { PositionDirection::Long } else { PositionDirection::Short };
+        }
+
+        match self.execution.execute_signal(signal.clone()).await {
+            Ok(result) if result.status == ExecutionStatus::Rejected => run.tally.record(&result, delta.abs()),
+            Ok(result) => {
+                run.working.insert(result.request_id.clone(), signal);
+            }
+            Err(e) => {
+                warn!("Replay order for {} on {} failed: {}", run.strategy.name(), signal.symbol, e);
+                run.tally.total += 1;
+                run.tally.failed += 1;
+            }
+        }
+    }
+
+    /// Apply new fills to strategy accounts and finish orders that are done
+    async fn settle(&self, runs: &mut [StrategyRun]) -> BacktestResult<()> {
+        let fill_config = self.fills.config();
+        for fill in self.fills.drain_fills().await {
+            let Some(run) = runs.iter_mut().find(|r| r.working.contains_key(&fill.request_id)) else {
+                debug!("Dropping fill for unknown order {}", fill.request_id);
+                continue;
+            };
+            let fee_bps = if fill.maker { fill_config.maker_fee_bps } else { fill_config.taker_fee_bps };
+            let signed = if fill.side == BacktestSide::Buy { fill.quantity } else { -fill.quantity };
+            let target = run.position(&fill.symbol) + signed;
+            run.trade(&fill.symbol, target, fill.price, fee_bps, fill.timestamp);
+            run.fills.push(fill);
+        }
+
+        for run in runs.iter_mut() {
+            let request_ids: Vec<String> = run.working.keys().cloned().collect();
+            for request_id in request_ids {
+                let result = self.fills
+                    .get_status(&request_id)
+                    .await
+                    .map_err(|e| BacktestError::Execution(e.to_string()))?;
+                if matches!(
+                    result.status,
+                    ExecutionStatus::Received | ExecutionStatus::InProgress | ExecutionStatus::PartiallyFilled
+                ) {
+                    continue;
+                }
+
+                let Some(signal) = run.working.remove(&request_id) else { continue };
+                run.tally.record(&result, signal.quantity.unwrap_or(0.0));
+                if result.executed_quantity.unwrap_or(0.0) > 0.0 {
+                    run.strategy
+                        .on_signal_executed(&signal, &result)
+                        .await
+                        .map_err(|e| BacktestError::Strategy(e.to_string()))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Create a replay engine whose execution service can only reach the simulator
+///
+/// The live and paper slots are filled by the simulator too, so a replay
+/// cannot send orders to a real venue whatever mode it is switched to.
+pub fn create_replay_engine(
+    config: ReplayConfig,
+    strategy_engine: Arc<StrategyEngine>,
+    fills: Arc<SimulatedFillProvider>,
+) -> ReplayEngine {
+    let execution = ExecutionService::new(fills.clone(), fills.clone(), None)
+        .with_backtest_provider(fills.clone());
+    ReplayEngine::new(config, strategy_engine, Arc::new(execution), fills)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::order_router::SmartOrderRouter;
+    use crate::risk_calc::{RiskCalculator, RiskConfig};
+    use crate::strategy::{RiskProfile, StrategyError};
+    use crate::strategy_engine::{create_strategy_engine, StrategyEngineConfig};
+
+    /// Buys on the first tick and exits once the price rises above 110
+    struct BuyThenExit;
+
+    #[async_trait]
+    impl Strategy for BuyThenExit {
+        async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+            let action = if market_data.ticker.last > 110.0 { SignalAction::Exit } else { SignalAction::Enter };
+            Ok(Some(Signal::new(self.name().to_string(), market_data.symbol.clone(), action)))
+        }
+
+        async fn get_risk_profile(&self) -> RiskProfile {
+            RiskProfile::default()
+        }
+
+        fn name(&self) -> &str {
+            "buy_then_exit"
+        }
+    }
+
+    /// Joins the bid at 99 for 3 units
+    struct PassiveBid;
+
+    #[async_trait]
+    impl Strategy for PassiveBid {
+        async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+            let signal = Signal::new(self.name().to_string(), market_data.symbol.clone(), SignalAction::Enter)
+                .with_price(99.0)
+                .with_quantity(3.0);
+            Ok(Some(signal))
+        }
+
+        async fn get_risk_profile(&self) -> RiskProfile {
+            RiskProfile::default()
+        }
+
+        fn name(&self) -> &str {
+            "passive_bid"
+        }
+    }
+
+    fn tick(minute: i64, price: f64, bid: f64, ask: f64, volume: f64, bid_size: Option<f64>) -> ReplayEvent {
+        ReplayEvent::Tick(MarketTick {
+            symbol: "BTC/USDT".to_string(),
+            timestamp: Utc.timestamp_opt(1_700_000_000, 0).unwrap() + Duration::minutes(minute),
+            price,
+            volume,
+            bid: Some(bid),
+            ask: Some(ask),
+            fields: bid_size.map(|size| HashMap::from([("bid_size".to_string(), size)])).unwrap_or_default(),
+        })
+    }
+
+    fn engine(config: ReplayConfig, fills: SimulatedFillConfig) -> ReplayEngine {
+        let strategy_engine = create_strategy_engine(
+            Arc::new(SmartOrderRouter::new()),
+            Arc::new(RiskCalculator::new(RiskConfig::default(), 100_000.0)),
+            Some(StrategyEngineConfig { apply_risk_checks: false, ..StrategyEngineConfig::default() }),
+        );
+        create_replay_engine(config, strategy_engine, Arc::new(SimulatedFillProvider::new(fills)))
+    }
+
+    #[tokio::test]
+    async fn test_replay_crosses_the_spread() {
+        let engine = engine(
+            ReplayConfig { position_size_pct: 1.0, ..ReplayConfig::default() },
+            SimulatedFillConfig { taker_fee_bps: 0.0, default_slippage_bps: 0.0, ..SimulatedFillConfig::default() },
+        );
+        let events = [100.0, 100.0, 105.0, 120.0, 120.0]
+            .iter()
+            .enumerate()
+            .map(|(i, price)| tick(i as i64, *price, price - 0.5, price + 0.5, 1.0, None))
+            .collect();
+
+        let report = engine.run(&[Arc::new(BuyThenExit) as Arc<dyn Strategy>], events).await.unwrap();
+        let result = report.strategy("buy_then_exit").unwrap();
+
+        // 1000 units bought at the ask (100.5) and sold at the bid (119.5)
+        assert_eq!(result.fills.len(), 2);
+        assert!(result.fills.iter().all(|f| !f.maker && f.latency_ms == 0));
+        assert!((result.performance.total_pnl - 19_000.0).abs() < 1e-6);
+        assert_eq!(result.performance.total_trades, 1);
+        assert_eq!(result.execution.filled_count, 2);
+        assert!(result.avg_slippage_bps > 0.0);
+        assert_eq!(result.maker_fill_ratio, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_resting_order_waits_for_queue() {
+        let engine = engine(
+            ReplayConfig { close_at_end: false, ..ReplayConfig::default() },
+            SimulatedFillConfig::default(),
+        );
+        let events = vec![
+            tick(0, 99.5, 99.0, 100.0, 0.0, Some(5.0)),
+            // Sells at the bid first clear the 5 units queued ahead of us
+            tick(1, 99.0, 99.0, 100.0, 4.0, None),
+            tick(2, 99.0, 99.0, 100.0, 3.0, None),
+            tick(3, 99.0, 99.0, 100.0, 5.0, None),
+        ];
+
+        let report = engine.run(&[Arc::new(PassiveBid) as Arc<dyn Strategy>], events).await.unwrap();
+        let result = report.strategy("passive_bid").unwrap();
+
+        let quantities: Vec<f64> = result.fills.iter().map(|f| f.quantity).collect();
+        assert_eq!(quantities, vec![2.0, 1.0]);
+        assert!(result.fills.iter().all(|f| f.maker && f.price == 99.0));
+        assert_eq!(result.fills[0].latency_ms, 120_000);
+        assert_eq!(result.maker_fill_ratio, 1.0);
+        assert_eq!(result.execution.filled_count, 1);
+        assert_eq!(result.trades.len(), 2);
+    }
+}
@@ -0,0 +1,415 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Per-venue balance forecasting from scheduled strategy allocations and
+//! historical net flow, with dry-venue alerts and pre-funding
+//! recommendations for the capital transfer manager.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::capital_transfer::{CapitalTransferManager, LiquidityNeed, TransferResult};
+use crate::telemetry::TelemetryReporter;
+
+/// Capital a strategy is scheduled to draw from (positive) or return to (negative) a venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledAllocation {
+    /// Strategy the allocation belongs to
+    pub strategy_id: String,
+    /// Venue ID
+    pub venue: String,
+    /// Asset
+    pub asset: String,
+    /// Amount drawn from the venue balance
+    pub amount: f64,
+    /// When the allocation takes effect
+    pub effective_at: DateTime<Utc>,
+}
+
+/// Forecasting configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BalanceForecastConfig {
+    /// How far ahead balances are projected (hours)
+    pub horizon_hours: i64,
+    /// Alert when a venue is projected to run dry within this many hours
+    pub alert_within_hours: i64,
+    /// Window of historical flow used for the flow rate (hours)
+    pub flow_lookback_hours: i64,
+    /// Balance a venue should never drop below
+    pub min_reserve: f64,
+    /// Hours of lead time transfers need; shortfalls inside horizon + lead are funded
+    pub transfer_lead_hours: i64,
+    /// Hand recommendations to the transfer manager instead of only reporting them
+    pub auto_schedule: bool,
+    /// How often forecasts run in the background (seconds)
+    pub interval_secs: u64,
+}
+
+impl Default for BalanceForecastConfig {
+    fn default() -> Self {
+        Self {
+            horizon_hours: 24,
+            alert_within_hours: 6,
+            flow_lookback_hours: 72,
+            min_reserve: 0.0,
+            transfer_lead_hours: 2,
+            auto_schedule: false,
+            interval_secs: 900,
+        }
+    }
+}
+
+/// Projected balance of one venue and asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueBalanceForecast {
+    /// Venue ID
+    pub venue: String,
+    /// Asset
+    pub asset: String,
+    /// Current balance
+    pub current_balance: f64,
+    /// Historical net flow per hour (negative = balance draining)
+    pub hourly_net_flow: f64,
+    /// Lowest projected balance within the horizon
+    pub min_projected_balance: f64,
+    /// When the balance is projected to fall below the reserve, if within the horizon
+    pub runs_dry_at: Option<DateTime<Utc>>,
+    /// Amount needed to stay above the reserve through the horizon
+    pub shortfall: f64,
+}
+
+/// Venue projected to run dry soon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryVenueAlert {
+    /// Venue ID
+    pub venue: String,
+    /// Asset
+    pub asset: String,
+    /// Projected time the reserve is breached
+    pub runs_dry_at: DateTime<Utc>,
+    /// Hours until then
+    pub hours_remaining: f64,
+}
+
+/// Result of a forecasting run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceForecastReport {
+    /// When the forecast was made
+    pub generated_at: DateTime<Utc>,
+    /// Projection per venue and asset
+    pub forecasts: Vec<VenueBalanceForecast>,
+    /// Venues about to run dry
+    pub alerts: Vec<DryVenueAlert>,
+    /// Pre-funding needs, in the form the transfer manager plans from
+    pub recommendations: Vec<LiquidityNeed>,
+    /// Number of transfers scheduled automatically
+    pub transfers_scheduled: usize,
+}
+
+/// Projects venue balances and recommends transfers ahead of need
+pub struct BalanceForecaster {
+    /// Configuration
+    config: BalanceForecastConfig,
+    /// Current balances by (venue, asset)
+    balances: RwLock<HashMap<(String, String), f64>>,
+    /// Historical balance changes by (venue, asset), oldest first
+    flows: RwLock<HashMap<(String, String), Vec<(DateTime<Utc>, f64)>>>,
+    /// Upcoming allocations
+    allocations: RwLock<Vec<ScheduledAllocation>>,
+    /// Transfer manager that executes recommendations (optional)
+    transfer_manager: Option<Arc<CapitalTransferManager>>,
+    /// Telemetry for dry-venue alerts (optional)
+    telemetry: Option<Arc<TelemetryReporter>>,
+    /// Forecasting task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl BalanceForecaster {
+    /// Create a forecaster
+    pub fn new(config: BalanceForecastConfig) -> Self {
+        Self {
+            config,
+            balances: RwLock::new(HashMap::new()),
+            flows: RwLock::new(HashMap::new()),
+            allocations: RwLock::new(Vec::new()),
+            transfer_manager: None,
+            telemetry: None,
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Schedule recommended transfers through a transfer manager
+    pub fn with_transfer_manager(mut self, transfer_manager: Arc<CapitalTransferManager>) -> Self {
+        self.transfer_manager = Some(transfer_manager);
+        self
+    }
+
+    /// Report dry-venue alerts to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Set a venue's current balance
+    pub async fn update_balance(&self, venue: &str, asset: &str, balance: f64) {
+        self.balances.write().await.insert((venue.to_string(), asset.to_string()), balance);
+    }
+
+    /// Record a balance change that was not caused by a scheduled allocation (fills, fees, funding)
+    pub async fn record_flow(&self, venue: &str, asset: &str, amount: f64, at: DateTime<Utc>) {
+        let mut flows = self.flows.write().await;
+        let series = flows.entry((venue.to_string(), asset.to_string())).or_default();
+        series.push((at, amount));
+        let cutoff = at - chrono::Duration::hours(self.config.flow_lookback_hours);
+        series.retain(|(t, _)| *t >= cutoff);
+    }
+
+    /// Replace the upcoming allocations
+    pub async fn set_allocations(&self, allocations: Vec<ScheduledAllocation>) {
+        *self.allocations.write().await = allocations;
+    }
+
+    /// Average net flow per hour over the lookback window
+    async fn hourly_net_flow(&self, key: &(String, String), now: DateTime<Utc>) -> f64 {
+        let flows = self.flows.read().await;
+        let Some(series) = flows.get(key) else {
+            return 0.0;
+        };
+        let cutoff = now - chrono::Duration::hours(self.config.flow_lookback_hours);
+        let recent: Vec<&(DateTime<Utc>, f64)> = series.iter().filter(|(t, _)| *t >= cutoff && *t <= now).collect();
+        let Some(earliest) = recent.iter().map(|(t, _)| *t).min() else {
+            return 0.0;
+        };
+        // Rate over the observed span, but never less than an hour to avoid blowing up on one sample
+        let hours = ((now - earliest).num_seconds() as f64 / 3600.0).max(1.0);
+        recent.iter().map(|(_, amount)| amount).sum::<f64>() / hours
+    }
+
+    /// Project every known venue balance
+    pub async fn forecast(&self, now: DateTime<Utc>) -> Vec<VenueBalanceForecast> {
+        let balances = self.balances.read().await.clone();
+        let allocations = self.allocations.read().await.clone();
+        let horizon = now + chrono::Duration::hours(self.config.horizon_hours + self.config.transfer_lead_hours);
+
+        let mut keys: Vec<(String, String)> = balances.keys().cloned().collect();
+        keys.extend(allocations.iter().map(|a| (a.venue.clone(), a.asset.clone())));
+        keys.sort();
+        keys.dedup();
+
+        let mut forecasts = Vec::new();
+        for key in keys {
+            let current_balance = balances.get(&key).copied().unwrap_or(0.0);
+            let hourly_net_flow = self.hourly_net_flow(&key, now).await;
+
+            // Balance only changes stepwise at allocations; between them it follows the flow rate,
+            // so the minimum is found at allocation times and at the horizon
+            let mut events: Vec<(DateTime<Utc>, f64)> = allocations
+                .iter()
+                .filter(|a| (a.venue.clone(), a.asset.clone()) == key && a.effective_at > now && a.effective_at <= horizon)
+                .map(|a| (a.effective_at, a.amount))
+                .collect();
+            events.sort_by_key(|(t, _)| *t);
+
+            let project = |at: DateTime<Utc>, drawn: f64| {
+                current_balance + hourly_net_flow * (at - now).num_seconds() as f64 / 3600.0 - drawn
+            };
+            let reserve = self.config.min_reserve;
+
+            let mut drawn = 0.0;
+            let mut min_projected_balance = current_balance;
+            let mut runs_dry_at = if current_balance < reserve { Some(now) } else { None };
+            let mut previous = now;
+            for (at, amount) in events.iter().copied().chain(std::iter::once((horizon, 0.0))) {
+                // Flow alone may cross the reserve between checkpoints
+                let before = project(at, drawn);
+                if runs_dry_at.is_none() && before < reserve && hourly_net_flow < 0.0 {
+                    let start = project(previous, drawn);
+                    let hours = ((start - reserve) / -hourly_net_flow).max(0.0);
+                    runs_dry_at = Some(previous + chrono::Duration::seconds((hours * 3600.0) as i64));
+                }
+                drawn += amount;
+                let after = project(at, drawn);
+                if runs_dry_at.is_none() && after < reserve {
+                    runs_dry_at = Some(at);
+                }
+                min_projected_balance = min_projected_balance.min(before).min(after);
+                previous = at;
+            }
+
+            forecasts.push(VenueBalanceForecast {
+                venue: key.0,
+                asset: key.1,
+                current_balance,
+                hourly_net_flow,
+                min_projected_balance,
+                runs_dry_at,
+                shortfall: (reserve - min_projected_balance).max(0.0),
+            });
+        }
+
+        forecasts
+    }
+
+    /// Forecast balances, raise alerts and recommend (or schedule) pre-funding transfers
+    pub async fn run(&self, now: DateTime<Utc>) -> TransferResult<BalanceForecastReport> {
+        let forecasts = self.forecast(now).await;
+
+        let alert_cutoff = now + chrono::Duration::hours(self.config.alert_within_hours);
+        let alerts: Vec<DryVenueAlert> = forecasts
+            .iter()
+            .filter_map(|f| {
+                let at = f.runs_dry_at.filter(|at| *at <= alert_cutoff)?;
+                Some(DryVenueAlert {
+                    venue: f.venue.clone(),
+                    asset: f.asset.clone(),
+                    runs_dry_at: at,
+                    hours_remaining: (at - now).num_seconds() as f64 / 3600.0,
+                })
+            })
+            .collect();
+
+        for alert in &alerts {
+            warn!(
+                "{} {} projected to run dry in {:.1}h ({})",
+                alert.venue, alert.asset, alert.hours_remaining, alert.runs_dry_at
+            );
+            if let Some(telemetry) = &self.telemetry {
+                let mut data = HashMap::new();
+                data.insert("venue".to_string(), serde_json::json!(alert.venue));
+                data.insert("asset".to_string(), serde_json::json!(alert.asset));
+                data.insert("runs_dry_at".to_string(), serde_json::json!(alert.runs_dry_at));
+                data.insert("hours_remaining".to_string(), serde_json::json!(alert.hours_remaining));
+                telemetry.report_custom("venue_balance_dry", data).await;
+            }
+        }
+
+        let recommendations: Vec<LiquidityNeed> = forecasts
+            .iter()
+            .filter(|f| f.shortfall > 0.0)
+            .map(|f| LiquidityNeed {
+                venue: f.venue.clone(),
+                asset: f.asset.clone(),
+                required: f.current_balance + f.shortfall,
+                reason: match f.runs_dry_at {
+                    Some(at) => format!("Projected to breach reserve at {}", at),
+                    None => "Projected shortfall within forecast horizon".to_string(),
+                },
+            })
+            .collect();
+
+        let mut transfers_scheduled = 0;
+        if self.config.auto_schedule && !recommendations.is_empty() {
+            if let Some(manager) = &self.transfer_manager {
+                for plan in manager.plan(&recommendations).await? {
+                    match manager.execute(plan).await {
+                        Ok(transfer) => {
+                            info!("Pre-funding transfer {} scheduled", transfer.id);
+                            transfers_scheduled += 1;
+                        }
+                        Err(e) => warn!("Pre-funding transfer not scheduled: {}", e),
+                    }
+                }
+            }
+        }
+
+        Ok(BalanceForecastReport {
+            generated_at: now,
+            forecasts,
+            alerts,
+            recommendations,
+            transfers_scheduled,
+        })
+    }
+
+    /// Start forecasting in the background
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let forecaster = Arc::clone(self);
+        let interval_secs = self.config.interval_secs.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                if let Err(e) = forecaster.run(Utc::now()).await {
+                    warn!("Balance forecast failed: {}", e);
+                }
+            }
+        }));
+    }
+
+    /// Stop forecasting
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create a balance forecaster
+pub fn create_balance_forecaster(config: BalanceForecastConfig) -> Arc<BalanceForecaster> {
+    Arc::new(BalanceForecaster::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drain_and_allocation_trigger_alert() {
+        let now = Utc::now();
+        let config = BalanceForecastConfig { min_reserve: 1_000.0, ..BalanceForecastConfig::default() };
+        let forecaster = create_balance_forecaster(config);
+
+        forecaster.update_balance("binance", "USDT", 10_000.0).await;
+        forecaster.update_balance("kraken", "USDT", 10_000.0).await;
+        // Binance has been losing 500/h for the last 4 hours
+        for h in 1..=4 {
+            forecaster.record_flow("binance", "USDT", -500.0, now - chrono::Duration::hours(h)).await;
+        }
+        forecaster.set_allocations(vec![ScheduledAllocation {
+            strategy_id: "arb".to_string(),
+            venue: "kraken".to_string(),
+            asset: "USDT".to_string(),
+            amount: 9_500.0,
+            effective_at: now + chrono::Duration::hours(3),
+        }]).await;
+
+        let report = forecaster.run(now).await.unwrap();
+        let binance = report.forecasts.iter().find(|f| f.venue == "binance").unwrap();
+        assert_eq!(binance.hourly_net_flow, -500.0);
+        // 9,000 above reserve at 500/h
+        let dry_in = (binance.runs_dry_at.unwrap() - now).num_minutes();
+        assert!((dry_in - 18 * 60).abs() <= 1);
+
+        // Only the kraken allocation lands inside the alert window
+        assert_eq!(report.alerts.len(), 1);
+        assert_eq!(report.alerts[0].venue, "kraken");
+        assert_eq!(report.recommendations.len(), 2);
+        let kraken = report.recommendations.iter().find(|r| r.venue == "kraken").unwrap();
+        assert_eq!(kraken.required, 10_500.0);
+    }
+}
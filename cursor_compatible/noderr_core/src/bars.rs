@@ -0,0 +1,388 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Activity-based bars alongside time candles. Volume and dollar bars close
+//! after a fixed amount of traded size or notional, Renko bricks after a fixed
+//! price move, and range bars once their high-low range would exceed a limit.
+//! Bars are built from trades and exposed as `Candle` series keyed by
+//! [`BarType::key`], so strategies read them from `MarketData::candles`
+//! exactly like a time frame.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+use crate::market::{Candle, Timeframe};
+
+/// Errors raised when parsing bar types
+#[derive(Debug, Error)]
+pub enum BarError {
+    #[error("Invalid bar type '{0}'")]
+    InvalidBarType(String),
+
+    #[error("Bar threshold must be positive: {0}")]
+    InvalidThreshold(Decimal),
+}
+
+/// How a bar series is sampled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BarType {
+    /// Fixed time interval
+    Time { timeframe: Timeframe },
+    /// Closes once traded base volume reaches the threshold
+    Volume { threshold: Decimal },
+    /// Closes once traded notional reaches the threshold
+    Dollar { threshold: Decimal },
+    /// Fixed-size price bricks; reversals need two bricks
+    Renko { brick_size: Decimal },
+    /// Closes when the high-low range would exceed `range`
+    Range { range: Decimal },
+}
+
+impl BarType {
+    /// Key the series is stored under in `MarketData::candles`: the time frame
+    /// string for time bars, otherwise `volume:<n>`, `dollar:<n>`, `renko:<n>` or `range:<n>`
+    pub fn key(&self) -> String {
+        self.to_string()
+    }
+
+    fn validate(self) -> Result<Self, BarError> {
+        match self {
+            BarType::Time { .. } => Ok(self),
+            BarType::Volume { threshold: size }
+            | BarType::Dollar { threshold: size }
+            | BarType::Renko { brick_size: size }
+            | BarType::Range { range: size } => {
+                if size > Decimal::ZERO {
+                    Ok(self)
+                } else {
+                    Err(BarError::InvalidThreshold(size))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for BarType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BarType::Time { timeframe } => write!(f, "{}", timeframe),
+            BarType::Volume { threshold } => write!(f, "volume:{}", threshold),
+            BarType::Dollar { threshold } => write!(f, "dollar:{}", threshold),
+            BarType::Renko { brick_size } => write!(f, "renko:{}", brick_size),
+            BarType::Range { range } => write!(f, "range:{}", range),
+        }
+    }
+}
+
+impl FromStr for BarType {
+    type Err = BarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BarError::InvalidBarType(s.to_string());
+        let Some((kind, size)) = s.split_once(':') else {
+            let timeframe = [
+                Timeframe::Minute1, Timeframe::Minute5, Timeframe::Minute15, Timeframe::Minute30,
+                Timeframe::Hour1, Timeframe::Hour4, Timeframe::Day1, Timeframe::Week1, Timeframe::Month1,
+            ]
+            .into_iter()
+            .find(|tf| tf.to_string() == s)
+            .ok_or_else(invalid)?;
+            return Ok(BarType::Time { timeframe });
+        };
+
+        let size = Decimal::from_str(size).map_err(|_| invalid())?;
+        let bar_type = match kind {
+            "volume" => BarType::Volume { threshold: size },
+            "dollar" => BarType::Dollar { threshold: size },
+            "renko" => BarType::Renko { brick_size: size },
+            "range" => BarType::Range { range: size },
+            _ => return Err(invalid()),
+        };
+        bar_type.validate()
+    }
+}
+
+/// Bar being accumulated
+#[derive(Debug, Clone)]
+struct OpenBar {
+    candle: Candle,
+    trades: u32,
+    notional: Decimal,
+}
+
+impl OpenBar {
+    fn new(timestamp: DateTime<Utc>, price: Decimal) -> Self {
+        Self {
+            candle: Candle::new(timestamp, price, price, price, price, Decimal::ZERO),
+            trades: 0,
+            notional: Decimal::ZERO,
+        }
+    }
+
+    fn add(&mut self, price: Decimal, quantity: Decimal) {
+        self.candle.high = self.candle.high.max(price);
+        self.candle.low = self.candle.low.min(price);
+        self.candle.close = price;
+        self.candle.volume += quantity;
+        self.trades += 1;
+        self.notional += price * quantity;
+    }
+
+    fn finish(mut self) -> Candle {
+        self.candle.trade_count = Some(self.trades);
+        self.candle.quote_volume = Some(self.notional);
+        self.candle
+    }
+}
+
+/// Builds one bar series from trades
+#[derive(Debug, Clone)]
+pub struct BarBuilder {
+    /// Sampling rule
+    bar_type: BarType,
+    /// Bar in progress
+    open: Option<OpenBar>,
+    /// Renko: close of the last brick and its direction (1 up, -1 down, 0 none yet)
+    renko: Option<(Decimal, i8)>,
+}
+
+impl BarBuilder {
+    /// Create a builder
+    pub fn new(bar_type: BarType) -> Self {
+        Self { bar_type, open: None, renko: None }
+    }
+
+    /// Sampling rule
+    pub fn bar_type(&self) -> BarType {
+        self.bar_type
+    }
+
+    /// Feed a trade, returning any bars it completed
+    pub fn update(&mut self, timestamp: DateTime<Utc>, price: Decimal, quantity: Decimal) -> Vec<Candle> {
+        match self.bar_type {
+            BarType::Time { timeframe } => self.update_time(timeframe, timestamp, price, quantity),
+            BarType::Volume { threshold } => self.update_accumulating(timestamp, price, quantity, threshold, false),
+            BarType::Dollar { threshold } => self.update_accumulating(timestamp, price, quantity, threshold, true),
+            BarType::Renko { brick_size } => self.update_renko(timestamp, price, quantity, brick_size),
+            BarType::Range { range } => self.update_range(timestamp, price, quantity, range),
+        }
+    }
+
+    fn update_time(&mut self, timeframe: Timeframe, timestamp: DateTime<Utc>, price: Decimal, quantity: Decimal) -> Vec<Candle> {
+        let secs = timeframe.to_seconds();
+        let bucket = timestamp.timestamp().div_euclid(secs) * secs;
+        let bucket_start = Utc.timestamp_opt(bucket, 0).single().unwrap_or(timestamp);
+
+        let mut completed = Vec::new();
+        if self.open.as_ref().is_some_and(|bar| bar.candle.timestamp != bucket_start) {
+            completed.extend(self.open.take().map(OpenBar::finish));
+        }
+        self.open.get_or_insert_with(|| OpenBar::new(bucket_start, price)).add(price, quantity);
+        completed
+    }
+
+    fn update_accumulating(
+        &mut self,
+        timestamp: DateTime<Utc>,
+        price: Decimal,
+        quantity: Decimal,
+        threshold: Decimal,
+        notional: bool,
+    ) -> Vec<Candle> {
+        let bar = self.open.get_or_insert_with(|| OpenBar::new(timestamp, price));
+        bar.add(price, quantity);
+
+        let filled = if notional { bar.notional } else { bar.candle.volume };
+        if filled >= threshold {
+            return self.open.take().map(OpenBar::finish).into_iter().collect();
+        }
+        Vec::new()
+    }
+
+    fn update_renko(&mut self, timestamp: DateTime<Utc>, price: Decimal, quantity: Decimal, brick: Decimal) -> Vec<Candle> {
+        let (mut anchor, mut direction) = *self.renko.get_or_insert((price, 0));
+        let bar = self.open.get_or_insert_with(|| OpenBar::new(timestamp, price));
+        bar.add(price, quantity);
+
+        let mut completed = Vec::new();
+        loop {
+            // A reversal has to clear the previous brick's open first
+            let up = if direction < 0 { anchor + brick * Decimal::TWO } else { anchor + brick };
+            let down = if direction > 0 { anchor - brick * Decimal::TWO } else { anchor - brick };
+
+            let (open, close, dir) = if price >= up {
+                (up - brick, up, 1)
+            } else if price <= down {
+                (down + brick, down, -1)
+            } else {
+                break;
+            };
+
+            let mut candle = Candle::new(timestamp, open, open.max(close), open.min(close), close, Decimal::ZERO);
+            // Traded activity is attributed to the first brick the move produced
+            if let Some(bar) = self.open.take() {
+                candle.volume = bar.candle.volume;
+                candle.trade_count = Some(bar.trades);
+                candle.quote_volume = Some(bar.notional);
+            }
+            completed.push(candle);
+            anchor = close;
+            direction = dir;
+        }
+
+        self.renko = Some((anchor, direction));
+        completed
+    }
+
+    fn update_range(&mut self, timestamp: DateTime<Utc>, price: Decimal, quantity: Decimal, range: Decimal) -> Vec<Candle> {
+        let mut completed = Vec::new();
+        if let Some(bar) = &self.open {
+            let high = bar.candle.high.max(price);
+            let low = bar.candle.low.min(price);
+            if high - low > range {
+                completed.extend(self.open.take().map(OpenBar::finish));
+            }
+        }
+        self.open.get_or_insert_with(|| OpenBar::new(timestamp, price)).add(price, quantity);
+        completed
+    }
+}
+
+/// Completed bars of one series
+struct BarSeries {
+    builder: BarBuilder,
+    bars: VecDeque<Candle>,
+}
+
+/// Builds the bar series strategies subscribe to, sharing one builder per
+/// symbol and bar type across subscribers
+pub struct BarService {
+    /// Completed bars kept per series
+    max_bars: usize,
+    /// Series by market key, then bar type
+    series: RwLock<HashMap<String, HashMap<BarType, BarSeries>>>,
+}
+
+impl BarService {
+    /// Create a service keeping up to `max_bars` completed bars per series
+    pub fn new(max_bars: usize) -> Self {
+        Self {
+            max_bars: max_bars.max(1),
+            series: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn market_key(exchange: &str, symbol: &str) -> String {
+        format!("{}:{}", exchange, symbol)
+    }
+
+    /// Start building a series; existing series are left untouched
+    pub async fn register(&self, exchange: &str, symbol: &str, bar_type: BarType) {
+        self.series
+            .write()
+            .await
+            .entry(Self::market_key(exchange, symbol))
+            .or_default()
+            .entry(bar_type)
+            .or_insert_with(|| BarSeries { builder: BarBuilder::new(bar_type), bars: VecDeque::new() });
+    }
+
+    /// Feed a trade to every series of the symbol, returning the bars it completed
+    pub async fn on_trade(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        timestamp: DateTime<Utc>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Vec<(BarType, Candle)> {
+        let mut series = self.series.write().await;
+        let Some(market) = series.get_mut(&Self::market_key(exchange, symbol)) else {
+            return Vec::new();
+        };
+
+        let mut completed = Vec::new();
+        for (bar_type, series) in market.iter_mut() {
+            for bar in series.builder.update(timestamp, price, quantity) {
+                series.bars.push_back(bar.clone());
+                if series.bars.len() > self.max_bars {
+                    series.bars.pop_front();
+                }
+                completed.push((*bar_type, bar));
+            }
+        }
+        completed
+    }
+
+    /// Completed bars of a series, oldest first
+    pub async fn bars(&self, exchange: &str, symbol: &str, bar_type: &BarType) -> Vec<Candle> {
+        self.series
+            .read()
+            .await
+            .get(&Self::market_key(exchange, symbol))
+            .and_then(|market| market.get(bar_type))
+            .map(|series| series.bars.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn feed(builder: &mut BarBuilder, trades: &[(Decimal, Decimal)]) -> Vec<Candle> {
+        let start = Utc::now();
+        trades
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (price, qty))| builder.update(start + chrono::Duration::seconds(i as i64), *price, *qty))
+            .collect()
+    }
+
+    #[test]
+    fn test_activity_bars() {
+        assert_eq!("renko:2.5".parse::<BarType>().unwrap(), BarType::Renko { brick_size: dec!(2.5) });
+        assert_eq!("1h".parse::<BarType>().unwrap().key(), "1h");
+        assert!("volume:0".parse::<BarType>().is_err());
+
+        // Volume bars close once 10 units have traded
+        let mut volume = BarBuilder::new(BarType::Volume { threshold: dec!(10) });
+        let bars = feed(&mut volume, &[(dec!(100), dec!(4)), (dec!(101), dec!(7)), (dec!(99), dec!(3)), (dec!(98), dec!(8))]);
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].volume, dec!(11));
+        assert_eq!((bars[1].open, bars[1].close, bars[1].trade_count), (dec!(99), dec!(98), Some(2)));
+
+        // Renko: two up bricks, then a reversal that needs two bricks of movement
+        let mut renko = BarBuilder::new(BarType::Renko { brick_size: dec!(1) });
+        let bars = feed(&mut renko, &[(dec!(100), dec!(1)), (dec!(102.2), dec!(1)), (dec!(101.1), dec!(1)), (dec!(99.9), dec!(1))]);
+        let bricks: Vec<(Decimal, Decimal)> = bars.iter().map(|b| (b.open, b.close)).collect();
+        assert_eq!(bricks, vec![(dec!(100), dec!(101)), (dec!(101), dec!(102)), (dec!(101), dec!(100))]);
+
+        // Range bars close when the next trade would stretch the range past 2
+        let mut range = BarBuilder::new(BarType::Range { range: dec!(2) });
+        let bars = feed(&mut range, &[(dec!(100), dec!(1)), (dec!(101.5), dec!(1)), (dec!(99.5), dec!(1)), (dec!(99), dec!(1))]);
+        assert_eq!(bars.len(), 1);
+        assert_eq!((bars[0].high, bars[0].low), (dec!(101.5), dec!(99.5)));
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Drive the market data pipeline with synthetic load and gate on throughput
+//! and latency, exiting non-zero when a threshold is breached
+
+use std::process::ExitCode;
+
+use clap::{Parser, ValueEnum};
+
+use noderr_core::load_test::{LoadTestConfig, LoadTestReport, LoadTestRunner, LoadTestThresholds, PriceProcess};
+use noderr_core::market_data::create_market_data_processor;
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Process {
+    /// Geometric Brownian motion
+    Gbm,
+    /// Mean-reverting Ornstein-Uhlenbeck
+    Ou,
+    /// GBM with jumps
+    Jump,
+}
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Number of synthetic symbols
+    #[arg(short, long, default_value_t = 100)]
+    symbols: usize,
+
+    /// Target rate across all workers (messages per second, 0 = unthrottled)
+    #[arg(short, long, default_value_t = 500_000)]
+    rate: u64,
+
+    /// Run length in seconds; with an unthrottled rate, 1M messages per second of duration
+    #[arg(short, long, default_value_t = 10)]
+    duration: u64,
+
+    /// Worker threads
+    #[arg(short, long, default_value_t = 4)]
+    workers: usize,
+
+    /// Price process
+    #[arg(short, long, value_enum, default_value_t = Process::Gbm)]
+    process: Process,
+
+    /// Per-tick volatility
+    #[arg(long, default_value_t = 0.0005)]
+    volatility: f64,
+
+    /// Calculate features every N ticks per symbol (0 = never)
+    #[arg(long, default_value_t = 100)]
+    feature_every: u64,
+
+    /// Random seed
+    #[arg(long, default_value_t = 42)]
+    seed: u64,
+
+    /// Fail below this throughput (messages per second)
+    #[arg(long)]
+    min_throughput: Option<f64>,
+
+    /// Fail above this tick processing p99 (microseconds)
+    #[arg(long)]
+    max_tick_p99_us: Option<f64>,
+
+    /// Fail above this feature calculation p99 (microseconds)
+    #[arg(long)]
+    max_feature_p99_us: Option<f64>,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let process = match cli.process {
+        Process::Gbm => PriceProcess::Gbm { drift: 0.0, volatility: cli.volatility },
+        Process::Ou => PriceProcess::OrnsteinUhlenbeck { mean: 100.0, reversion: 0.01, volatility: cli.volatility },
+        Process::Jump => PriceProcess::JumpDiffusion {
+            drift: 0.0,
+            volatility: cli.volatility,
+            jump_probability: 0.001,
+            jump_mean: 0.0,
+            jump_std: cli.volatility * 20.0,
+        },
+    };
+    let per_second = if cli.rate > 0 { cli.rate } else { 1_000_000 };
+
+    let config = LoadTestConfig {
+        symbols: cli.symbols,
+        total_messages: per_second * cli.duration,
+        target_rate: cli.rate,
+        workers: cli.workers,
+        process,
+        feature_every: cli.feature_every,
+        seed: cli.seed,
+        ..Default::default()
+    };
+    let thresholds = LoadTestThresholds {
+        min_throughput: cli.min_throughput,
+        max_tick_p99_us: cli.max_tick_p99_us,
+        max_feature_p99_us: cli.max_feature_p99_us,
+    };
+
+    let report = LoadTestRunner::new(config, create_market_data_processor()).run();
+    if cli.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(text) => println!("{}", text),
+            Err(e) => {
+                eprintln!("Failed to serialize report: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        print_report(&report);
+    }
+
+    let breaches = report.breaches(&thresholds);
+    if breaches.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    for breach in &breaches {
+        eprintln!("FAIL: {}", breach);
+    }
+    ExitCode::FAILURE
+}
+
+fn print_report(report: &LoadTestReport) {
+    println!(
+        "{} messages in {:.2}s across {} symbols: {:.0} msg/s (target {})",
+        report.messages, report.elapsed_secs, report.config.symbols, report.throughput, report.config.target_rate
+    );
+    println!("{} rejected ticks, {} feature calculations", report.errors, report.feature_calculations);
+    for (name, latency) in [("tick", &report.tick_latency), ("feature", &report.feature_latency)] {
+        println!(
+            "{:>8} latency (us): p50 {:.1}  p90 {:.1}  p99 {:.1}  p99.9 {:.1}  max {:.1}",
+            name, latency.p50_us, latency.p90_us, latency.p99_us, latency.p999_us, latency.max_us
+        );
+    }
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Inspect artifact traces from a trace journal
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use chrono::{TimeZone, Utc};
+use clap::{Parser, Subcommand};
+
+use noderr_core::ids::TypedId;
+use noderr_core::traceability::{trace_from_journal, Trace};
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Show everything linked to an artifact ID
+    Show {
+        /// Trace journal written by the trace registry
+        #[arg(short, long)]
+        journal: PathBuf,
+
+        /// Signal, order, fill, decision or telemetry ID
+        id: String,
+
+        /// Print the trace as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Decode a typed ID into its kind and creation time
+    Parse {
+        /// Typed ID, e.g. ord_01HZX3Q4V8B6J2K9M0N1P2R3S4
+        id: String,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Commands::Show { journal, id, json } => match trace_from_journal(&journal, &id) {
+            Ok(Some(trace)) => {
+                if json {
+                    match serde_json::to_string_pretty(&trace) {
+                        Ok(text) => println!("{}", text),
+                        Err(e) => {
+                            eprintln!("Failed to serialize trace: {}", e);
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                } else {
+                    print_trace(&trace);
+                }
+                ExitCode::SUCCESS
+            }
+            Ok(None) => {
+                eprintln!("No trace for {} in {}", id, journal.display());
+                ExitCode::FAILURE
+            }
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", journal.display(), e);
+                ExitCode::FAILURE
+            }
+        },
+        Commands::Parse { id } => match id.parse::<TypedId>() {
+            Ok(typed) => {
+                let created_at = Utc.timestamp_millis_opt(typed.timestamp_ms() as i64).single();
+                println!("kind:       {:?}", typed.kind());
+                println!("created at: {}", created_at.map(|t| t.to_rfc3339()).unwrap_or_else(|| "invalid".to_string()));
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+fn print_trace(trace: &Trace) {
+    println!("Trace for {}", trace.root);
+    println!();
+    for node in &trace.nodes {
+        let marker = if node.id == trace.root { "*" } else { " " };
+        println!(
+            "{} {}  {}  {}",
+            marker,
+            node.recorded_at.to_rfc3339(),
+            node.id,
+            node.summary.as_deref().unwrap_or("-"),
+        );
+    }
+    println!();
+    for link in &trace.links {
+        println!("  {} --{:?}--> {}", link.from, link.relation, link.to);
+    }
+}
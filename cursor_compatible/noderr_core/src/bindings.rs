@@ -18,8 +18,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::order_router::{Order, OrderSide, SmartOrderRouter, ExecutionFailureReason};
-use crate::execution_strategy::{ExecutionAlgorithm, ExecutionStrategyConfig, ExecutionStrategyRouter, TWAPConfig, VWAPConfig};
+use crate::order_router::{Order, OrderSide, SmartOrderRouter, ExecutionFailureReason, TimeInForce};
+use crate::execution_strategy::{ExecutionAlgorithm, ExecutionStrategyConfig, ExecutionStrategyRouter, IcebergConfig, POVConfig, TWAPConfig, VWAPConfig};
 use crate::risk_calc::{RiskCalculator, RiskConfig, PositionExposure, VenueExposure, RiskViolationType};
 use crate::trade_sizer::{DynamicTradeSizer, TradeSizerConfig};
 use crate::drawdown_monitor::{DrawdownMonitor, DrawdownConfig, TradeDataPoint, TradeType, KillSwitch};
@@ -28,6 +28,11 @@ use crate::shared_memory::{SharedMemoryManager, BufferConfig, BufferType, Shared
 use crate::orderbook::{OrderBookManager};
 use crate::strategy_engine::{StrategyEngine, StrategyEngineConfig, StrategyEngineMode, StrategyEngineError, SignalEvaluation, SignalMetrics};
 use crate::position_manager::{PositionManager, PositionManagerConfig, Side, OrderOrFill, SymbolPosition, AgentPosition};
+use crate::position::{PositionChangeEvent, PnlSummary};
+use crate::trading_events::{forward_events, TradingEvent, TradingEventBus, TradingEventKind, create_trading_event_bus};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+#[cfg(feature = "napi")]
+use napi::threadsafe_function::{ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode};
 
 /// NAPI wrapper for SmartOrderRouter
 #[cfg_attr(feature = "napi", napi(js_name = "SmartOrderRouter"))]
@@ -47,9 +52,22 @@ pub struct OrderParams {
     pub id: String,
     pub max_slippage: Option<f64>,
     pub max_retries: Option<u32>,
+    pub post_only: Option<bool>,
+    pub reduce_only: Option<bool>,
+    pub time_in_force: Option<serde_json::Value>,
     pub additional_params: HashMap<String, serde_json::Value>,
 }
 
+/// Parse an optional time-in-force value, defaulting to GTC
+fn parse_time_in_force(value: Option<serde_json::Value>) -> napi::Result<TimeInForce> {
+    match value {
+        Some(value) => serde_json::from_value(value).map_err(|e| {
+            napi::Error::new(napi::Status::InvalidArg, format!("Invalid time in force: {}", e))
+        }),
+        None => Ok(TimeInForce::GTC),
+    }
+}
+
 /// NAPI wrapper for SmartOrderRouter creation
 #[cfg_attr(feature = "napi", napi)]
 impl NapiSmartOrderRouter {
@@ -68,6 +86,17 @@ impl NapiSmartOrderRouter {
         }
     }
 
+    #[cfg_attr(feature = "napi", napi(factory))]
+    pub fn with_events(trust_scores: HashMap<String, f64>, events: &NapiTradingEvents) -> Self {
+        let retry_engine = Arc::new(crate::order_router::OrderRetryEngine::new(3, 1000, 30000));
+        Self {
+            inner: Arc::new(
+                SmartOrderRouter::with_retry_engine(retry_engine, trust_scores)
+                    .with_event_bus(events.get_internal())
+            ),
+        }
+    }
+
     #[cfg_attr(feature = "napi", napi)]
     pub async fn execute_order(&self, params: OrderParams) -> napi::Result<serde_json::Value> {
         let order = Order {
@@ -83,6 +112,9 @@ impl NapiSmartOrderRouter {
             id: params.id,
             max_slippage: params.max_slippage,
             max_retries: params.max_retries,
+            post_only: params.post_only.unwrap_or(false),
+            reduce_only: params.reduce_only.unwrap_or(false),
+            time_in_force: parse_time_in_force(params.time_in_force)?,
             additional_params: params.additional_params,
         };
 
@@ -146,27 +178,40 @@ pub struct PositionExposureParams {
     pub direction: String,
 }
 
+/// Build a RiskConfig from NAPI parameters
+fn risk_config_from_params(config_params: RiskConfigParams) -> RiskConfig {
+    let exempt_strategies = config_params.exempt_strategies.into_iter().collect();
+    
+    RiskConfig {
+        max_position_size_pct: config_params.max_position_size_pct,
+        max_leverage: config_params.max_leverage,
+        max_drawdown_pct: config_params.max_drawdown_pct,
+        min_trust_score: config_params.min_trust_score,
+        max_exposure_per_symbol: config_params.max_exposure_per_symbol,
+        max_exposure_per_venue: config_params.max_exposure_per_venue,
+        rebalance_interval_ms: 300000, // Default
+        webhook_url: None,
+        exempt_strategies,
+        fast_risk_mode: config_params.fast_risk_mode,
+        ..Default::default()
+    }
+}
+
 #[cfg_attr(feature = "napi", napi)]
 impl NapiRiskCalculator {
     #[cfg_attr(feature = "napi", napi(constructor))]
     pub fn new(config_params: RiskConfigParams, portfolio_value: f64) -> Self {
-        let exempt_strategies = config_params.exempt_strategies.into_iter().collect();
-        
-        let config = RiskConfig {
-            max_position_size_pct: config_params.max_position_size_pct,
-            max_leverage: config_params.max_leverage,
-            max_drawdown_pct: config_params.max_drawdown_pct,
-            min_trust_score: config_params.min_trust_score,
-            max_exposure_per_symbol: config_params.max_exposure_per_symbol,
-            max_exposure_per_venue: config_params.max_exposure_per_venue,
-            rebalance_interval_ms: 300000, // Default
-            webhook_url: None,
-            exempt_strategies,
-            fast_risk_mode: config_params.fast_risk_mode,
-        };
+        Self {
+            inner: Arc::new(RiskCalculator::new(risk_config_from_params(config_params), portfolio_value)),
+        }
+    }
 
+    #[cfg_attr(feature = "napi", napi(factory))]
+    pub fn with_events(config_params: RiskConfigParams, portfolio_value: f64, events: &NapiTradingEvents) -> Self {
+        let calculator = RiskCalculator::new(risk_config_from_params(config_params), portfolio_value)
+            .with_event_bus(events.get_internal());
         Self {
-            inner: Arc::new(RiskCalculator::new(config, portfolio_value)),
+            inner: Arc::new(calculator),
         }
     }
 
@@ -216,6 +261,34 @@ impl NapiRiskCalculator {
         Ok(serde_json::to_value(result)?)
     }
 
+    #[cfg_attr(feature = "napi", napi)]
+    pub async fn what_if_concentration(&self, params: PositionExposureParams) -> napi::Result<serde_json::Value> {
+        let direction = match params.direction.as_str() {
+            "long" => crate::risk::PositionDirection::Long,
+            "short" => crate::risk::PositionDirection::Short,
+            _ => return Err(napi::Error::new(napi::Status::InvalidArg, "Invalid position direction")),
+        };
+
+        let position = PositionExposure::new(
+            &params.symbol,
+            &params.venue,
+            params.size,
+            params.value,
+            params.leverage,
+            params.trust_score,
+            direction,
+        );
+
+        let impact = self.inner.what_if_concentration(&position).await;
+        Ok(serde_json::to_value(impact)?)
+    }
+
+    #[cfg_attr(feature = "napi", napi)]
+    pub async fn get_concentration_report(&self) -> napi::Result<serde_json::Value> {
+        let report = self.inner.check_concentration().await;
+        Ok(serde_json::to_value(report)?)
+    }
+
     #[cfg_attr(feature = "napi", napi)]
     pub async fn update_portfolio_value(&self, value: f64) -> napi::Result<()> {
         self.inner.update_portfolio_value(value).await;
@@ -372,6 +445,23 @@ pub struct TradeDataPointParams {
 impl NapiDrawdownMonitor {
     #[cfg_attr(feature = "napi", napi(factory))]
     pub fn create(config_params: DrawdownConfigParams, kill_switch_callback: napi::JsFunction) -> napi::Result<Self> {
+        Self::build(config_params, kill_switch_callback, None)
+    }
+
+    #[cfg_attr(feature = "napi", napi(factory))]
+    pub fn with_events(
+        config_params: DrawdownConfigParams,
+        kill_switch_callback: napi::JsFunction,
+        events: &NapiTradingEvents,
+    ) -> napi::Result<Self> {
+        Self::build(config_params, kill_switch_callback, Some(events.get_internal()))
+    }
+
+    fn build(
+        config_params: DrawdownConfigParams,
+        kill_switch_callback: napi::JsFunction,
+        event_bus: Option<Arc<TradingEventBus>>,
+    ) -> napi::Result<Self> {
         let callback = move |agent_id: &str, reason: &str, message: &str| -> bool {
             let env = kill_switch_callback.env;
             let this = env.get_undefined().unwrap();
@@ -400,8 +490,13 @@ impl NapiDrawdownMonitor {
             log_file_path: "logs/risk/drawdowns.jsonl".to_string(), // Default
         };
 
+        let mut monitor = DrawdownMonitor::new(config, kill_switch);
+        if let Some(event_bus) = event_bus {
+            monitor = monitor.with_event_bus(event_bus);
+        }
+
         Ok(Self {
-            inner: Arc::new(DrawdownMonitor::new(config, kill_switch)),
+            inner: Arc::new(monitor),
         })
     }
 
@@ -475,6 +570,82 @@ impl NapiDrawdownMonitor {
     }
 }
 
+/// NAPI push-notification emitter for fills, order state, risk violations and drawdowns
+#[cfg_attr(feature = "napi", napi(js_name = "TradingEvents"))]
+pub struct NapiTradingEvents {
+    inner: Arc<TradingEventBus>,
+    queue_size: usize,
+    dropped: Arc<AtomicU64>,
+}
+
+#[cfg_attr(feature = "napi", napi)]
+impl NapiTradingEvents {
+    /// Create an emitter; `queue_size` bounds both the Rust channel and each JS callback queue
+    #[cfg_attr(feature = "napi", napi(constructor))]
+    pub fn new(queue_size: Option<u32>) -> Self {
+        let queue_size = queue_size.unwrap_or(1024) as usize;
+        Self {
+            inner: create_trading_event_bus(queue_size),
+            queue_size,
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    #[cfg_attr(feature = "napi", napi(ts_args_type = "callback: (event: any) => void"))]
+    pub fn on_fill(&self, callback: napi::JsFunction) -> napi::Result<()> {
+        self.subscribe(TradingEventKind::Fill, callback)
+    }
+
+    #[cfg_attr(feature = "napi", napi(ts_args_type = "callback: (event: any) => void"))]
+    pub fn on_order_state(&self, callback: napi::JsFunction) -> napi::Result<()> {
+        self.subscribe(TradingEventKind::OrderState, callback)
+    }
+
+    #[cfg_attr(feature = "napi", napi(ts_args_type = "callback: (event: any) => void"))]
+    pub fn on_risk_violation(&self, callback: napi::JsFunction) -> napi::Result<()> {
+        self.subscribe(TradingEventKind::RiskViolation, callback)
+    }
+
+    #[cfg_attr(feature = "napi", napi(ts_args_type = "callback: (event: any) => void"))]
+    pub fn on_drawdown(&self, callback: napi::JsFunction) -> napi::Result<()> {
+        self.subscribe(TradingEventKind::Drawdown, callback)
+    }
+
+    /// Number of events dropped because a queue was full
+    #[cfg_attr(feature = "napi", napi)]
+    pub fn dropped_count(&self) -> i64 {
+        self.dropped.load(AtomicOrdering::Relaxed) as i64
+    }
+
+    /// Number of events published by the core
+    #[cfg_attr(feature = "napi", napi)]
+    pub fn published_count(&self) -> i64 {
+        self.inner.published_count() as i64
+    }
+
+    /// Forward events of one kind to a JS callback through a bounded threadsafe function
+    fn subscribe(&self, kind: TradingEventKind, callback: napi::JsFunction) -> napi::Result<()> {
+        let tsfn: ThreadsafeFunction<TradingEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(self.queue_size, |ctx: ThreadSafeCallContext<TradingEvent>| {
+                ctx.env.to_js_value(&ctx.value).map(|value| vec![value])
+            })?;
+
+        let receiver = self.inner.subscribe();
+        napi::bindgen_prelude::spawn(forward_events(receiver, kind, self.dropped.clone(), move |event| {
+            tsfn.call(event, ThreadsafeFunctionCallMode::NonBlocking) == napi::Status::Ok
+        }));
+
+        Ok(())
+    }
+}
+
+impl NapiTradingEvents {
+    /// Get internal reference for use in other wrappers
+    pub(crate) fn get_internal(&self) -> Arc<TradingEventBus> {
+        self.inner.clone()
+    }
+}
+
 /// NAPI wrapper for ExecutionStrategyRouter
 #[cfg_attr(feature = "napi", napi(js_name = "ExecutionStrategyRouter"))]
 pub struct NapiExecutionStrategyRouter {
@@ -506,6 +677,7 @@ impl NapiExecutionStrategyRouter {
             "Pegged" => ExecutionAlgorithm::Pegged,
             "DMA" => ExecutionAlgorithm::DMA,
             "SmartOrderRouting" => ExecutionAlgorithm::SmartOrderRouting,
+            "POV" => ExecutionAlgorithm::POV,
             _ => return Err(napi::Error::new(
                 napi::Status::InvalidArg,
                 format!("Invalid execution algorithm: {}", config_params.default_strategy),
@@ -522,6 +694,7 @@ impl NapiExecutionStrategyRouter {
                 "Pegged" => ExecutionAlgorithm::Pegged,
                 "DMA" => ExecutionAlgorithm::DMA,
                 "SmartOrderRouting" => ExecutionAlgorithm::SmartOrderRouting,
+                "POV" => ExecutionAlgorithm::POV,
                 _ => return Err(napi::Error::new(
                     napi::Status::InvalidArg,
                     format!("Invalid execution algorithm: {}", strategy_str),
@@ -562,6 +735,8 @@ impl NapiExecutionStrategyRouter {
             min_order_size_for_vwap: config_params.min_order_size_for_vwap,
             twap_config,
             vwap_config,
+            iceberg_config: Some(IcebergConfig::default()),
+            pov_config: Some(POVConfig::default()),
             max_execution_time_ms: config_params.max_execution_time_ms,
             symbol_strategy_map,
         };
@@ -606,6 +781,9 @@ impl NapiExecutionStrategyRouter {
             id: params.id,
             max_slippage: params.max_slippage,
             max_retries: params.max_retries,
+            post_only: params.post_only.unwrap_or(false),
+            reduce_only: params.reduce_only.unwrap_or(false),
+            time_in_force: parse_time_in_force(params.time_in_force)?,
             additional_params: params.additional_params,
         };
 
@@ -644,6 +822,9 @@ impl NapiExecutionStrategyRouter {
             id: params.id,
             max_slippage: params.max_slippage,
             max_retries: params.max_retries,
+            post_only: params.post_only.unwrap_or(false),
+            reduce_only: params.reduce_only.unwrap_or(false),
+            time_in_force: parse_time_in_force(params.time_in_force)?,
             additional_params: params.additional_params,
         };
 
@@ -671,6 +852,9 @@ impl NapiExecutionStrategyRouter {
             id: params.id,
             max_slippage: params.max_slippage,
             max_retries: params.max_retries,
+            post_only: params.post_only.unwrap_or(false),
+            reduce_only: params.reduce_only.unwrap_or(false),
+            time_in_force: parse_time_in_force(params.time_in_force)?,
             additional_params: params.additional_params,
         };
 
@@ -704,6 +888,7 @@ impl NapiExecutionStrategyRouter {
             "Pegged" => ExecutionAlgorithm::Pegged,
             "DMA" => ExecutionAlgorithm::DMA,
             "SmartOrderRouting" => ExecutionAlgorithm::SmartOrderRouting,
+            "POV" => ExecutionAlgorithm::POV,
             _ => return Err(napi::Error::new(
                 napi::Status::InvalidArg,
                 format!("Invalid execution algorithm: {}", config_params.default_strategy),
@@ -720,6 +905,7 @@ impl NapiExecutionStrategyRouter {
                 "Pegged" => ExecutionAlgorithm::Pegged,
                 "DMA" => ExecutionAlgorithm::DMA,
                 "SmartOrderRouting" => ExecutionAlgorithm::SmartOrderRouting,
+                "POV" => ExecutionAlgorithm::POV,
                 _ => return Err(napi::Error::new(
                     napi::Status::InvalidArg,
                     format!("Invalid execution algorithm: {}", strategy_str),
@@ -760,6 +946,8 @@ impl NapiExecutionStrategyRouter {
             min_order_size_for_vwap: config_params.min_order_size_for_vwap,
             twap_config,
             vwap_config,
+            iceberg_config: Some(IcebergConfig::default()),
+            pov_config: Some(POVConfig::default()),
             max_execution_time_ms: config_params.max_execution_time_ms,
             symbol_strategy_map,
         };
@@ -2634,6 +2822,31 @@ pub struct AgentPositionParams {
     pub positions: String,
 }
 
+#[napi(object)]
+pub struct PnlParams {
+    /// Realized profit and loss
+    #[napi(ts_type = "number")]
+    pub realized_pnl: f64,
+
+    /// Unrealized profit and loss at the last known price
+    #[napi(ts_type = "number")]
+    pub unrealized_pnl: f64,
+
+    /// Realized plus unrealized profit and loss
+    #[napi(ts_type = "number")]
+    pub total_pnl: f64,
+}
+
+impl From<PnlSummary> for PnlParams {
+    fn from(summary: PnlSummary) -> Self {
+        Self {
+            realized_pnl: summary.realized_pnl,
+            unrealized_pnl: summary.unrealized_pnl,
+            total_pnl: summary.total_pnl,
+        }
+    }
+}
+
 #[napi]
 pub struct PositionManagerConfigParams {
     /// Maximum position size per symbol (JSON mapping of symbol to max size)
@@ -2673,6 +2886,7 @@ impl NapiPositionManager {
             default_max_position: config_params.default_max_position,
             max_total_exposure: config_params.max_total_exposure,
             initial_cash_balance: config_params.initial_cash_balance,
+            ..Default::default()
         };
         
         Ok(Self {
@@ -2721,6 +2935,47 @@ impl NapiPositionManager {
             .map_err(|e| napi::Error::from_reason(format!("Failed to calculate exposure: {}", e)))
     }
 
+    #[napi]
+    pub fn calculate_symbol_exposure(&self, agent_id: String, symbol: String) -> napi::Result<f64> {
+        self.position_manager.calculate_symbol_exposure(&agent_id, &symbol)
+            .map_err(|e| napi::Error::from_reason(format!("Failed to calculate symbol exposure: {}", e)))
+    }
+
+    #[napi]
+    pub fn get_pnl(&self, agent_id: String) -> napi::Result<PnlParams> {
+        self.position_manager.get_pnl(&agent_id)
+            .map(PnlParams::from)
+            .map_err(|e| napi::Error::from_reason(format!("Failed to get PnL: {}", e)))
+    }
+
+    #[napi]
+    pub fn get_symbol_pnl(&self, agent_id: String, symbol: String) -> napi::Result<PnlParams> {
+        self.position_manager.get_symbol_pnl(&agent_id, &symbol)
+            .map(PnlParams::from)
+            .map_err(|e| napi::Error::from_reason(format!("Failed to get symbol PnL: {}", e)))
+    }
+
+    #[napi]
+    pub fn get_agent_ids(&self) -> napi::Result<Vec<String>> {
+        self.position_manager.get_agent_ids()
+            .map_err(|e| napi::Error::from_reason(format!("Failed to list agents: {}", e)))
+    }
+
+    /// Subscribe to position change events; the callback receives the event object
+    #[napi(ts_args_type = "callback: (event: any) => void")]
+    pub fn on_position_change(&self, callback: napi::JsFunction) -> napi::Result<()> {
+        let tsfn: ThreadsafeFunction<PositionChangeEvent, ErrorStrategy::Fatal> = callback
+            .create_threadsafe_function(0, |ctx: ThreadSafeCallContext<PositionChangeEvent>| {
+                ctx.env.to_js_value(&ctx.value).map(|value| vec![value])
+            })?;
+
+        self.position_manager
+            .subscribe(Arc::new(move |event: &PositionChangeEvent| {
+                tsfn.call(event.clone(), ThreadsafeFunctionCallMode::NonBlocking);
+            }))
+            .map_err(|e| napi::Error::from_reason(format!("Failed to subscribe: {}", e)))
+    }
+
     #[napi]
     pub fn check_limits(&self, agent_id: String, symbol: String, side: u32, size: f64) -> napi::Result<bool> {
         // Convert side to Rust enum
@@ -2792,6 +3047,7 @@ impl NapiPositionManager {
             default_max_position: config_params.default_max_position,
             max_total_exposure: config_params.max_total_exposure,
             initial_cash_balance: config_params.initial_cash_balance,
+            ..Default::default()
         };
         
         self.position_manager.update_config(config)
@@ -0,0 +1,356 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Incremental order book feed for WebSocket clients
+//!
+//! Sending the whole book on every change wastes most of the bandwidth: a
+//! typical update moves a handful of levels near the touch. The feed keeps
+//! the last published top-of-book per symbol and emits only the levels that
+//! changed, numbered with a per-symbol sequence. Each client tracks the last
+//! sequence it received; a client that joins late, lags behind or asks for a
+//! resync is sent a full snapshot instead of a delta it could not apply.
+//! Snapshots are also broadcast periodically so replicas cannot drift.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::orderbook::PriceLevel;
+
+/// Book feed configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BookFeedConfig {
+    /// Levels per side included in the feed
+    pub depth: usize,
+    /// Broadcast a full snapshot after this many deltas
+    pub snapshot_every_updates: u64,
+    /// Broadcast a full snapshot at least this often (seconds)
+    pub snapshot_interval_secs: i64,
+}
+
+impl Default for BookFeedConfig {
+    fn default() -> Self {
+        Self {
+            depth: 20,
+            snapshot_every_updates: 200,
+            snapshot_interval_secs: 30,
+        }
+    }
+}
+
+/// A `[price, quantity]` pair; a quantity of zero removes the level
+pub type BookLevel = [f64; 2];
+
+/// Order book message sent to clients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BookMessage {
+    /// Full top of book; replaces whatever the client holds
+    Snapshot {
+        symbol: String,
+        sequence: u64,
+        bids: Vec<BookLevel>,
+        asks: Vec<BookLevel>,
+        timestamp: DateTime<Utc>,
+    },
+    /// Levels changed since `prev_sequence`
+    Delta {
+        symbol: String,
+        sequence: u64,
+        prev_sequence: u64,
+        bids: Vec<BookLevel>,
+        asks: Vec<BookLevel>,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl BookMessage {
+    /// Symbol of the book
+    pub fn symbol(&self) -> &str {
+        match self {
+            BookMessage::Snapshot { symbol, .. } | BookMessage::Delta { symbol, .. } => symbol,
+        }
+    }
+
+    /// Sequence number after applying the message
+    pub fn sequence(&self) -> u64 {
+        match self {
+            BookMessage::Snapshot { sequence, .. } | BookMessage::Delta { sequence, .. } => *sequence,
+        }
+    }
+}
+
+/// Last published state of one symbol
+#[derive(Debug, Clone)]
+struct PublishedBook {
+    sequence: u64,
+    bids: Vec<BookLevel>,
+    asks: Vec<BookLevel>,
+    deltas_since_snapshot: u64,
+    snapshot_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Encodes book changes as deltas with periodic snapshots
+pub struct BookFeed {
+    /// Configuration
+    config: BookFeedConfig,
+    /// Published books by symbol
+    books: RwLock<HashMap<String, PublishedBook>>,
+}
+
+impl BookFeed {
+    /// Create a new book feed
+    pub fn new(config: BookFeedConfig) -> Self {
+        Self {
+            config,
+            books: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Configuration in use
+    pub fn config(&self) -> &BookFeedConfig {
+        &self.config
+    }
+
+    /// Encode a new book state for `symbol`
+    ///
+    /// Returns a snapshot for the first update and whenever one is due, a
+    /// delta otherwise, and `None` if the top of book did not change.
+    pub fn update(&self, symbol: &str, bids: &[PriceLevel], asks: &[PriceLevel], now: DateTime<Utc>) -> Option<BookMessage> {
+        let bids = Self::top_levels(bids, self.config.depth);
+        let asks = Self::top_levels(asks, self.config.depth);
+
+        let mut books = self.books.write().unwrap();
+        let Some(book) = books.get_mut(symbol) else {
+            let book = PublishedBook {
+                sequence: 1,
+                bids,
+                asks,
+                deltas_since_snapshot: 0,
+                snapshot_at: now,
+                updated_at: now,
+            };
+            let message = Self::snapshot_of(symbol, &book);
+            books.insert(symbol.to_string(), book);
+            return Some(message);
+        };
+
+        let bid_changes = Self::diff(&book.bids, &bids);
+        let ask_changes = Self::diff(&book.asks, &asks);
+        let snapshot_due = book.deltas_since_snapshot + 1 >= self.config.snapshot_every_updates
+            || now - book.snapshot_at >= Duration::seconds(self.config.snapshot_interval_secs);
+        if bid_changes.is_empty() && ask_changes.is_empty() && !snapshot_due {
+            return None;
+        }
+
+        let prev_sequence = book.sequence;
+        book.sequence += 1;
+        book.bids = bids;
+        book.asks = asks;
+        book.updated_at = now;
+
+        if snapshot_due {
+            book.deltas_since_snapshot = 0;
+            book.snapshot_at = now;
+            return Some(Self::snapshot_of(symbol, book));
+        }
+
+        book.deltas_since_snapshot += 1;
+        Some(BookMessage::Delta {
+            symbol: symbol.to_string(),
+            sequence: book.sequence,
+            prev_sequence,
+            bids: bid_changes,
+            asks: ask_changes,
+            timestamp: now,
+        })
+    }
+
+    /// Current snapshot of a symbol, for clients that need to resync
+    pub fn snapshot(&self, symbol: &str) -> Option<BookMessage> {
+        self.books.read().unwrap().get(symbol).map(|book| Self::snapshot_of(symbol, book))
+    }
+
+    /// Latest sequence of a symbol
+    pub fn sequence(&self, symbol: &str) -> Option<u64> {
+        self.books.read().unwrap().get(symbol).map(|book| book.sequence)
+    }
+
+    /// Decide what a client with last sequence `cursor` should receive for `message`
+    ///
+    /// Deltas that continue the client's sequence pass through; anything else
+    /// is replaced by a current snapshot. Returns `None` when the client is
+    /// already at or past the message.
+    pub fn for_client(&self, cursor: Option<u64>, message: &BookMessage) -> Option<BookMessage> {
+        match (message, cursor) {
+            (BookMessage::Snapshot { sequence, .. }, Some(cursor)) if *sequence <= cursor => None,
+            (BookMessage::Snapshot { .. }, _) => Some(message.clone()),
+            (BookMessage::Delta { prev_sequence, .. }, Some(cursor)) if *prev_sequence == cursor => Some(message.clone()),
+            (BookMessage::Delta { sequence, .. }, Some(cursor)) if *sequence <= cursor => None,
+            (BookMessage::Delta { .. }, _) => self.snapshot(message.symbol()),
+        }
+    }
+
+    fn snapshot_of(symbol: &str, book: &PublishedBook) -> BookMessage {
+        BookMessage::Snapshot {
+            symbol: symbol.to_string(),
+            sequence: book.sequence,
+            bids: book.bids.clone(),
+            asks: book.asks.clone(),
+            timestamp: book.updated_at,
+        }
+    }
+
+    fn top_levels(levels: &[PriceLevel], depth: usize) -> Vec<BookLevel> {
+        levels.iter()
+            .filter(|level| level.size > 0.0)
+            .take(depth)
+            .map(|level| [level.price, level.size])
+            .collect()
+    }
+
+    /// Levels added or resized in `new`, plus zero-quantity removals of levels only in `old`
+    fn diff(old: &[BookLevel], new: &[BookLevel]) -> Vec<BookLevel> {
+        let mut changes: Vec<BookLevel> = new.iter()
+            .filter(|level| !old.iter().any(|prev| prev[0] == level[0] && prev[1] == level[1]))
+            .copied()
+            .collect();
+        changes.extend(old.iter()
+            .filter(|prev| !new.iter().any(|level| level[0] == prev[0]))
+            .map(|prev| [prev[0], 0.0]));
+        changes
+    }
+}
+
+/// Client-side copy of a book rebuilt from feed messages
+#[derive(Debug, Clone, Default)]
+pub struct BookReplica {
+    /// Sequence of the last applied message
+    pub sequence: u64,
+    /// Bids by price bits; prices are never NaN
+    bids: BTreeMap<u64, BookLevel>,
+    /// Asks by price bits
+    asks: BTreeMap<u64, BookLevel>,
+}
+
+impl BookReplica {
+    /// Apply a message; returns false if a delta does not follow the replica's sequence
+    pub fn apply(&mut self, message: &BookMessage) -> bool {
+        match message {
+            BookMessage::Snapshot { sequence, bids, asks, .. } => {
+                self.bids = bids.iter().map(|level| (level[0].to_bits(), *level)).collect();
+                self.asks = asks.iter().map(|level| (level[0].to_bits(), *level)).collect();
+                self.sequence = *sequence;
+                true
+            }
+            BookMessage::Delta { sequence, prev_sequence, bids, asks, .. } => {
+                if *prev_sequence != self.sequence {
+                    return false;
+                }
+                Self::apply_side(&mut self.bids, bids);
+                Self::apply_side(&mut self.asks, asks);
+                self.sequence = *sequence;
+                true
+            }
+        }
+    }
+
+    /// Bids, best first
+    pub fn bids(&self) -> Vec<BookLevel> {
+        let mut bids: Vec<BookLevel> = self.bids.values().copied().collect();
+        bids.sort_by(|a, b| b[0].total_cmp(&a[0]));
+        bids
+    }
+
+    /// Asks, best first
+    pub fn asks(&self) -> Vec<BookLevel> {
+        let mut asks: Vec<BookLevel> = self.asks.values().copied().collect();
+        asks.sort_by(|a, b| a[0].total_cmp(&b[0]));
+        asks
+    }
+
+    fn apply_side(side: &mut BTreeMap<u64, BookLevel>, changes: &[BookLevel]) {
+        for level in changes {
+            if level[1] > 0.0 {
+                side.insert(level[0].to_bits(), *level);
+            } else {
+                side.remove(&level[0].to_bits());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn levels(pairs: &[(f64, f64)]) -> Vec<PriceLevel> {
+        pairs.iter()
+            .map(|&(price, size)| PriceLevel { price, size, order_count: 1, timestamp: 0 })
+            .collect()
+    }
+
+    #[test]
+    fn test_deltas_rebuild_book_and_gaps_resync() {
+        let feed = BookFeed::new(BookFeedConfig { depth: 3, snapshot_every_updates: 100, snapshot_interval_secs: 60 });
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let mut replica = BookReplica::default();
+
+        let first = feed.update("BTC/USD", &levels(&[(100.0, 1.0), (99.0, 2.0)]), &levels(&[(101.0, 1.0)]), now).unwrap();
+        assert!(matches!(first, BookMessage::Snapshot { sequence: 1, .. }));
+        assert!(replica.apply(&first));
+        assert!(feed.update("BTC/USD", &levels(&[(100.0, 1.0), (99.0, 2.0)]), &levels(&[(101.0, 1.0)]), now).is_none());
+
+        // Only the changed levels travel; the pulled 99 bid becomes a removal
+        let bids = levels(&[(100.0, 1.5), (98.0, 4.0)]);
+        let asks = levels(&[(101.0, 1.0), (102.0, 3.0)]);
+        let delta = feed.update("BTC/USD", &bids, &asks, now + Duration::seconds(1)).unwrap();
+        match &delta {
+            BookMessage::Delta { prev_sequence, bids, asks, .. } => {
+                assert_eq!(*prev_sequence, 1);
+                assert_eq!(bids, &vec![[100.0, 1.5], [98.0, 4.0], [99.0, 0.0]]);
+                assert_eq!(asks, &vec![[102.0, 3.0]]);
+            }
+            other => panic!("expected delta, got {:?}", other),
+        }
+        assert!(replica.apply(&delta));
+        assert_eq!(replica.bids(), vec![[100.0, 1.5], [98.0, 4.0]]);
+        assert_eq!(replica.asks(), vec![[101.0, 1.0], [102.0, 3.0]]);
+
+        // A client that missed the delta gets a snapshot of the current book instead
+        let next = feed.update("BTC/USD", &bids, &levels(&[(101.0, 0.5), (102.0, 3.0)]), now + Duration::seconds(2)).unwrap();
+        let resync = feed.for_client(Some(1), &next).unwrap();
+        assert!(matches!(resync, BookMessage::Snapshot { sequence: 3, .. }));
+        assert_eq!(feed.for_client(Some(2), &next), Some(next.clone()));
+        assert_eq!(feed.for_client(Some(3), &next), None);
+        assert!(matches!(feed.for_client(None, &next), Some(BookMessage::Snapshot { .. })));
+
+        let mut lagging = BookReplica::default();
+        assert!(lagging.apply(&first));
+        assert!(!lagging.apply(&next));
+        assert!(lagging.apply(&resync));
+        assert!(replica.apply(&next));
+        assert_eq!(lagging.asks(), replica.asks());
+
+        // Periodic snapshots are sent even without changes
+        let periodic = feed.update("BTC/USD", &bids, &levels(&[(101.0, 0.5), (102.0, 3.0)]), now + Duration::seconds(90));
+        assert!(matches!(periodic, Some(BookMessage::Snapshot { sequence: 4, .. })));
+    }
+}
@@ -0,0 +1,702 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Capital transfers between venue accounts: plans withdrawals and deposits
+//! from projected liquidity needs, sends funds only to whitelisted addresses,
+//! tracks confirmations and keeps a treasury ledger of every movement.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::audit_vault::AuditVault;
+
+/// Errors raised by the capital transfer manager
+#[derive(Debug, Error)]
+pub enum TransferError {
+    #[error("No whitelisted {asset} address for venue {venue}")]
+    AddressNotWhitelisted { venue: String, asset: String },
+
+    #[error("Insufficient {asset} on {venue}: {available} available, {requested} requested")]
+    InsufficientBalance { venue: String, asset: String, available: f64, requested: f64 },
+
+    #[error("Venue {venue} transfer API failed: {message}")]
+    Venue { venue: String, message: String },
+
+    #[error("No transfer client registered for venue {0}")]
+    UnknownVenue(String),
+
+    #[error("Transfer not found: {0}")]
+    TransferNotFound(String),
+
+    #[error("Invalid transfer: {0}")]
+    InvalidTransfer(String),
+}
+
+/// Result type for capital transfer operations
+pub type TransferResult<T> = Result<T, TransferError>;
+
+/// Destination address approved for withdrawals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WhitelistedAddress {
+    /// Venue the address deposits into
+    pub venue: String,
+    /// Asset the address accepts
+    pub asset: String,
+    /// Network used for the transfer (e.g. "ethereum", "tron")
+    pub network: String,
+    /// Deposit address
+    pub address: String,
+    /// Operator-facing label
+    pub label: String,
+}
+
+/// Progress of a withdrawal as reported by the source venue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum WithdrawalProgress {
+    /// Accepted by the venue, not yet broadcast
+    Processing,
+    /// Broadcast on-chain
+    Broadcast { tx_hash: String, confirmations: u32 },
+    /// Rejected or cancelled by the venue
+    Failed { reason: String },
+}
+
+/// Venue account API used to move funds
+#[async_trait]
+pub trait VenueTransferClient: Send + Sync {
+    /// Venue ID
+    fn venue(&self) -> &str;
+
+    /// Free balance of an asset
+    async fn available_balance(&self, asset: &str) -> TransferResult<f64>;
+
+    /// Request a withdrawal, returning the venue's withdrawal ID
+    async fn withdraw(&self, asset: &str, amount: f64, destination: &WhitelistedAddress) -> TransferResult<String>;
+
+    /// Progress of a withdrawal
+    async fn withdrawal_progress(&self, withdrawal_id: &str) -> TransferResult<WithdrawalProgress>;
+}
+
+/// Capital a venue is projected to need
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityNeed {
+    /// Venue ID
+    pub venue: String,
+    /// Asset needed
+    pub asset: String,
+    /// Balance the venue should hold
+    pub required: f64,
+    /// Why the capital is needed
+    pub reason: String,
+}
+
+/// Planned movement of capital
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferPlan {
+    /// Asset to move
+    pub asset: String,
+    /// Source venue
+    pub from_venue: String,
+    /// Destination venue
+    pub to_venue: String,
+    /// Amount to move
+    pub amount: f64,
+    /// Why the transfer is planned
+    pub reason: String,
+}
+
+/// Lifecycle of a transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStatus {
+    /// Withdrawal accepted by the source venue
+    Submitted,
+    /// Broadcast and waiting for confirmations
+    Confirming,
+    /// Confirmed and credited to the destination
+    Completed,
+    /// Rejected by the venue; the debit was reversed
+    Failed,
+}
+
+impl TransferStatus {
+    /// Whether the transfer will not change any further
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, TransferStatus::Completed | TransferStatus::Failed)
+    }
+}
+
+/// A transfer between venue accounts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalTransfer {
+    /// Transfer ID
+    pub id: String,
+    /// What was moved and why
+    pub plan: TransferPlan,
+    /// Destination address
+    pub destination: WhitelistedAddress,
+    /// Source venue's withdrawal ID
+    pub withdrawal_id: String,
+    /// On-chain transaction hash, once broadcast
+    pub tx_hash: Option<String>,
+    /// Confirmations seen so far
+    pub confirmations: u32,
+    /// Current status
+    pub status: TransferStatus,
+    /// Failure reason, if failed
+    pub failure_reason: Option<String>,
+    /// When the transfer was submitted
+    pub created_at: DateTime<Utc>,
+    /// When the status last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Kind of treasury ledger entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntryKind {
+    /// Funds left the source venue
+    WithdrawalDebit,
+    /// Funds arrived at the destination venue
+    DepositCredit,
+    /// A failed withdrawal was returned to the source venue
+    Reversal,
+}
+
+/// Treasury ledger entry for a capital movement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// Transfer the entry belongs to
+    pub transfer_id: String,
+    /// Venue whose balance changed
+    pub venue: String,
+    /// Asset
+    pub asset: String,
+    /// Signed amount: negative for debits
+    pub amount: f64,
+    /// Entry kind
+    pub kind: LedgerEntryKind,
+    /// When the entry was recorded
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Capital transfer configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransferConfig {
+    /// Smallest transfer worth making
+    pub min_transfer_amount: f64,
+    /// Fraction of its own need a source venue always keeps beyond that need
+    pub source_buffer_fraction: f64,
+    /// Confirmations before a transfer counts as completed
+    pub required_confirmations: u32,
+    /// Execute planned transfers automatically instead of only recommending them
+    pub auto_execute: bool,
+    /// How often in-flight transfers are checked (seconds)
+    pub poll_interval_secs: u64,
+}
+
+impl Default for TransferConfig {
+    fn default() -> Self {
+        Self {
+            min_transfer_amount: 100.0,
+            source_buffer_fraction: 0.1,
+            required_confirmations: 12,
+            auto_execute: false,
+            poll_interval_secs: 30,
+        }
+    }
+}
+
+/// Plans and executes capital transfers between venues
+pub struct CapitalTransferManager {
+    /// Configuration
+    config: TransferConfig,
+    /// Transfer clients by venue
+    clients: RwLock<HashMap<String, Arc<dyn VenueTransferClient>>>,
+    /// Approved destination addresses
+    whitelist: RwLock<Vec<WhitelistedAddress>>,
+    /// Transfers by ID
+    transfers: RwLock<HashMap<String, CapitalTransfer>>,
+    /// Treasury ledger
+    ledger: RwLock<Vec<LedgerEntry>>,
+    /// Tamper-evident record of transfers (optional)
+    audit_vault: Option<Arc<AuditVault>>,
+    /// Tracking task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl CapitalTransferManager {
+    /// Create a manager with no venues or whitelisted addresses
+    pub fn new(config: TransferConfig) -> Self {
+        Self {
+            config,
+            clients: RwLock::new(HashMap::new()),
+            whitelist: RwLock::new(Vec::new()),
+            transfers: RwLock::new(HashMap::new()),
+            ledger: RwLock::new(Vec::new()),
+            audit_vault: None,
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Record every transfer in the audit vault
+    pub fn with_audit_vault(mut self, audit_vault: Arc<AuditVault>) -> Self {
+        self.audit_vault = Some(audit_vault);
+        self
+    }
+
+    /// Register a venue's transfer client
+    pub async fn add_client(&self, client: Arc<dyn VenueTransferClient>) {
+        self.clients.write().await.insert(client.venue().to_string(), client);
+    }
+
+    /// Approve a destination address
+    pub async fn whitelist_address(&self, address: WhitelistedAddress) {
+        info!("Whitelisted {} address {} for {}", address.asset, address.label, address.venue);
+        let mut whitelist = self.whitelist.write().await;
+        whitelist.retain(|a| !(a.venue == address.venue && a.asset == address.asset));
+        whitelist.push(address);
+    }
+
+    /// Approved destination addresses
+    pub async fn whitelist(&self) -> Vec<WhitelistedAddress> {
+        self.whitelist.read().await.clone()
+    }
+
+    /// Plan transfers that cover each venue's shortfall from venues with surplus.
+    ///
+    /// Sources keep their own need plus a buffer; shortfalls below the minimum
+    /// transfer amount are ignored. Funds already in flight towards a venue
+    /// count towards its balance so repeated planning does not send them twice.
+    pub async fn plan(&self, needs: &[LiquidityNeed]) -> TransferResult<Vec<TransferPlan>> {
+        let clients = self.clients.read().await.clone();
+        let mut balances: HashMap<(String, String), f64> = HashMap::new();
+        let mut required: HashMap<(String, String), f64> = HashMap::new();
+        for need in needs {
+            *required.entry((need.venue.clone(), need.asset.clone())).or_insert(0.0) += need.required;
+        }
+
+        let assets: Vec<String> = {
+            let mut assets: Vec<String> = needs.iter().map(|n| n.asset.clone()).collect();
+            assets.sort();
+            assets.dedup();
+            assets
+        };
+        for asset in &assets {
+            for (venue, client) in &clients {
+                let balance = client.available_balance(asset).await?;
+                balances.insert((venue.clone(), asset.clone()), balance);
+            }
+        }
+        for transfer in self.in_flight().await {
+            let destination = (transfer.plan.to_venue.clone(), transfer.plan.asset.clone());
+            if let Some(balance) = balances.get_mut(&destination) {
+                *balance += transfer.plan.amount;
+            }
+        }
+
+        let mut plans = Vec::new();
+        let mut covered = std::collections::HashSet::new();
+        for need in needs {
+            let key = (need.venue.clone(), need.asset.clone());
+            // Needs for the same venue and asset were summed above
+            if !covered.insert(key.clone()) {
+                continue;
+            }
+            let mut shortfall = required[&key] - balances.get(&key).copied().unwrap_or(0.0);
+            if shortfall < self.config.min_transfer_amount {
+                continue;
+            }
+
+            // Largest surplus first to keep the number of transfers down
+            let mut surpluses: Vec<(String, f64)> = clients
+                .keys()
+                .filter(|venue| **venue != need.venue)
+                .map(|venue| {
+                    let source = (venue.clone(), need.asset.clone());
+                    let keep = required.get(&source).copied().unwrap_or(0.0) * (1.0 + self.config.source_buffer_fraction);
+                    (venue.clone(), balances.get(&source).copied().unwrap_or(0.0) - keep)
+                })
+                .filter(|(_, surplus)| *surplus >= self.config.min_transfer_amount)
+                .collect();
+            surpluses.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+            for (source, surplus) in surpluses {
+                if shortfall < self.config.min_transfer_amount {
+                    break;
+                }
+                let amount = surplus.min(shortfall);
+                shortfall -= amount;
+                *balances.entry((source.clone(), need.asset.clone())).or_insert(0.0) -= amount;
+                *balances.entry(key.clone()).or_insert(0.0) += amount;
+                plans.push(TransferPlan {
+                    asset: need.asset.clone(),
+                    from_venue: source,
+                    to_venue: need.venue.clone(),
+                    amount,
+                    reason: need.reason.clone(),
+                });
+            }
+
+            if shortfall >= self.config.min_transfer_amount {
+                warn!("{} short {:.2} {} with no venue able to cover it", need.venue, shortfall, need.asset);
+            }
+        }
+
+        Ok(plans)
+    }
+
+    /// Submit a planned transfer to its source venue
+    pub async fn execute(&self, plan: TransferPlan) -> TransferResult<CapitalTransfer> {
+        if plan.amount <= 0.0 || plan.from_venue == plan.to_venue {
+            return Err(TransferError::InvalidTransfer(format!(
+                "{} {} from {} to {}", plan.amount, plan.asset, plan.from_venue, plan.to_venue
+            )));
+        }
+
+        let destination = self.whitelist
+            .read()
+            .await
+            .iter()
+            .find(|a| a.venue == plan.to_venue && a.asset == plan.asset)
+            .cloned()
+            .ok_or_else(|| TransferError::AddressNotWhitelisted {
+                venue: plan.to_venue.clone(),
+                asset: plan.asset.clone(),
+            })?;
+
+        let client = self.clients
+            .read()
+            .await
+            .get(&plan.from_venue)
+            .cloned()
+            .ok_or_else(|| TransferError::UnknownVenue(plan.from_venue.clone()))?;
+
+        let available = client.available_balance(&plan.asset).await?;
+        if available < plan.amount {
+            return Err(TransferError::InsufficientBalance {
+                venue: plan.from_venue.clone(),
+                asset: plan.asset.clone(),
+                available,
+                requested: plan.amount,
+            });
+        }
+
+        let withdrawal_id = client.withdraw(&plan.asset, plan.amount, &destination).await?;
+        let now = Utc::now();
+        let transfer = CapitalTransfer {
+            id: Uuid::new_v4().to_string(),
+            plan,
+            destination,
+            withdrawal_id,
+            tx_hash: None,
+            confirmations: 0,
+            status: TransferStatus::Submitted,
+            failure_reason: None,
+            created_at: now,
+            updated_at: now,
+        };
+        info!(
+            "Transfer {}: {:.2} {} from {} to {} ({})",
+            transfer.id, transfer.plan.amount, transfer.plan.asset, transfer.plan.from_venue,
+            transfer.plan.to_venue, transfer.destination.label
+        );
+
+        self.record_ledger(&transfer, &transfer.plan.from_venue, -transfer.plan.amount, LedgerEntryKind::WithdrawalDebit).await;
+        self.audit(&transfer, "capital_transfer_submitted").await;
+        self.transfers.write().await.insert(transfer.id.clone(), transfer.clone());
+        Ok(transfer)
+    }
+
+    /// Plan transfers for the given needs, executing them when `auto_execute` is set.
+    ///
+    /// Returns the plans and the transfers that were submitted.
+    pub async fn rebalance(&self, needs: &[LiquidityNeed]) -> TransferResult<(Vec<TransferPlan>, Vec<CapitalTransfer>)> {
+        let plans = self.plan(needs).await?;
+        let mut submitted = Vec::new();
+        if self.config.auto_execute {
+            for plan in plans.clone() {
+                match self.execute(plan).await {
+                    Ok(transfer) => submitted.push(transfer),
+                    Err(e) => warn!("Planned transfer not executed: {}", e),
+                }
+            }
+        }
+        Ok((plans, submitted))
+    }
+
+    /// Refresh every in-flight transfer from its source venue.
+    ///
+    /// Returns the transfers whose status changed.
+    pub async fn track(&self) -> Vec<CapitalTransfer> {
+        let in_flight: Vec<CapitalTransfer> = self.transfers
+            .read()
+            .await
+            .values()
+            .filter(|t| !t.status.is_terminal())
+            .cloned()
+            .collect();
+        let clients = self.clients.read().await.clone();
+
+        let mut changed = Vec::new();
+        for mut transfer in in_flight {
+            let Some(client) = clients.get(&transfer.plan.from_venue) else {
+                continue;
+            };
+            let progress = match client.withdrawal_progress(&transfer.withdrawal_id).await {
+                Ok(progress) => progress,
+                Err(e) => {
+                    warn!("Failed to check transfer {}: {}", transfer.id, e);
+                    continue;
+                }
+            };
+
+            let previous = (transfer.status, transfer.confirmations);
+            match progress {
+                WithdrawalProgress::Processing => {}
+                WithdrawalProgress::Broadcast { tx_hash, confirmations } => {
+                    transfer.tx_hash = Some(tx_hash);
+                    transfer.confirmations = confirmations;
+                    transfer.status = if confirmations >= self.config.required_confirmations {
+                        TransferStatus::Completed
+                    } else {
+                        TransferStatus::Confirming
+                    };
+                }
+                WithdrawalProgress::Failed { reason } => {
+                    transfer.status = TransferStatus::Failed;
+                    transfer.failure_reason = Some(reason);
+                }
+            }
+            if (transfer.status, transfer.confirmations) == previous {
+                continue;
+            }
+            transfer.updated_at = Utc::now();
+
+            match transfer.status {
+                TransferStatus::Completed => {
+                    info!("Transfer {} completed with {} confirmations", transfer.id, transfer.confirmations);
+                    self.record_ledger(&transfer, &transfer.plan.to_venue, transfer.plan.amount, LedgerEntryKind::DepositCredit).await;
+                    self.audit(&transfer, "capital_transfer_completed").await;
+                }
+                TransferStatus::Failed => {
+                    warn!("Transfer {} failed: {}", transfer.id, transfer.failure_reason.as_deref().unwrap_or("unknown"));
+                    self.record_ledger(&transfer, &transfer.plan.from_venue, transfer.plan.amount, LedgerEntryKind::Reversal).await;
+                    self.audit(&transfer, "capital_transfer_failed").await;
+                }
+                _ => {}
+            }
+
+            self.transfers.write().await.insert(transfer.id.clone(), transfer.clone());
+            changed.push(transfer);
+        }
+
+        changed
+    }
+
+    /// A transfer by ID
+    pub async fn transfer(&self, transfer_id: &str) -> TransferResult<CapitalTransfer> {
+        self.transfers
+            .read()
+            .await
+            .get(transfer_id)
+            .cloned()
+            .ok_or_else(|| TransferError::TransferNotFound(transfer_id.to_string()))
+    }
+
+    /// Transfers that have not completed or failed
+    pub async fn in_flight(&self) -> Vec<CapitalTransfer> {
+        let mut transfers: Vec<CapitalTransfer> = self.transfers
+            .read()
+            .await
+            .values()
+            .filter(|t| !t.status.is_terminal())
+            .cloned()
+            .collect();
+        transfers.sort_by_key(|t| t.created_at);
+        transfers
+    }
+
+    /// Treasury ledger entries, oldest first
+    pub async fn ledger(&self) -> Vec<LedgerEntry> {
+        self.ledger.read().await.clone()
+    }
+
+    /// Start tracking in-flight transfers in the background
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let manager = Arc::clone(self);
+        let interval_secs = self.config.poll_interval_secs.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                manager.track().await;
+            }
+        }));
+    }
+
+    /// Stop tracking transfers
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+
+    async fn record_ledger(&self, transfer: &CapitalTransfer, venue: &str, amount: f64, kind: LedgerEntryKind) {
+        self.ledger.write().await.push(LedgerEntry {
+            transfer_id: transfer.id.clone(),
+            venue: venue.to_string(),
+            asset: transfer.plan.asset.clone(),
+            amount,
+            kind,
+            timestamp: Utc::now(),
+        });
+    }
+
+    async fn audit(&self, transfer: &CapitalTransfer, event_type: &str) {
+        let Some(vault) = &self.audit_vault else {
+            return;
+        };
+        let description = format!(
+            "{:.2} {} from {} to {}",
+            transfer.plan.amount, transfer.plan.asset, transfer.plan.from_venue, transfer.plan.to_venue
+        );
+        let data = serde_json::to_value(transfer).unwrap_or(serde_json::Value::Null);
+        if let Err(e) = vault.record(event_type, "capital_transfer_manager", &description, data).await {
+            warn!("Failed to audit transfer {}: {}", transfer.id, e);
+        }
+    }
+}
+
+/// Create a capital transfer manager
+pub fn create_capital_transfer_manager(config: TransferConfig) -> Arc<CapitalTransferManager> {
+    Arc::new(CapitalTransferManager::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct MockVenue {
+        venue: String,
+        balance: f64,
+        progress: Mutex<WithdrawalProgress>,
+    }
+
+    #[async_trait]
+    impl VenueTransferClient for MockVenue {
+        fn venue(&self) -> &str {
+            &self.venue
+        }
+
+        async fn available_balance(&self, _asset: &str) -> TransferResult<f64> {
+            Ok(self.balance)
+        }
+
+        async fn withdraw(&self, _asset: &str, _amount: f64, _destination: &WhitelistedAddress) -> TransferResult<String> {
+            Ok(format!("{}-wd-1", self.venue))
+        }
+
+        async fn withdrawal_progress(&self, _withdrawal_id: &str) -> TransferResult<WithdrawalProgress> {
+            Ok(self.progress.lock().unwrap().clone())
+        }
+    }
+
+    fn venue(name: &str, balance: f64) -> Arc<MockVenue> {
+        Arc::new(MockVenue { venue: name.to_string(), balance, progress: Mutex::new(WithdrawalProgress::Processing) })
+    }
+
+    #[tokio::test]
+    async fn test_plan_execute_and_confirm() {
+        let manager = create_capital_transfer_manager(TransferConfig { required_confirmations: 3, ..TransferConfig::default() });
+        let binance = venue("binance", 50_000.0);
+        manager.add_client(binance.clone()).await;
+        manager.add_client(venue("kraken", 1_000.0)).await;
+
+        let needs = vec![
+            LiquidityNeed { venue: "kraken".to_string(), asset: "USDT".to_string(), required: 11_000.0, reason: "allocation".to_string() },
+            LiquidityNeed { venue: "binance".to_string(), asset: "USDT".to_string(), required: 20_000.0, reason: "allocation".to_string() },
+        ];
+        let plans = manager.plan(&needs).await.unwrap();
+        assert_eq!(plans.len(), 1);
+        assert_eq!((plans[0].from_venue.as_str(), plans[0].amount), ("binance", 10_000.0));
+
+        // Nothing is sent to an address that has not been whitelisted
+        assert!(matches!(
+            manager.execute(plans[0].clone()).await,
+            Err(TransferError::AddressNotWhitelisted { .. })
+        ));
+
+        manager.whitelist_address(WhitelistedAddress {
+            venue: "kraken".to_string(),
+            asset: "USDT".to_string(),
+            network: "ethereum".to_string(),
+            address: "0xkraken".to_string(),
+            label: "kraken hot wallet".to_string(),
+        }).await;
+        let transfer = manager.execute(plans[0].clone()).await.unwrap();
+
+        *binance.progress.lock().unwrap() = WithdrawalProgress::Broadcast { tx_hash: "0xtx".to_string(), confirmations: 3 };
+        let changed = manager.track().await;
+        assert_eq!(changed[0].status, TransferStatus::Completed);
+        assert!(manager.in_flight().await.is_empty());
+
+        let ledger = manager.ledger().await;
+        assert_eq!(ledger.len(), 2);
+        assert_eq!(ledger.iter().map(|e| e.amount).sum::<f64>(), 0.0);
+        assert_eq!(ledger[1].transfer_id, transfer.id);
+    }
+
+    #[tokio::test]
+    async fn test_plan_counts_in_flight_transfers() {
+        let manager = create_capital_transfer_manager(TransferConfig::default());
+        manager.add_client(venue("binance", 50_000.0)).await;
+        manager.add_client(venue("kraken", 1_000.0)).await;
+        manager.whitelist_address(WhitelistedAddress {
+            venue: "kraken".to_string(),
+            asset: "USDT".to_string(),
+            network: "ethereum".to_string(),
+            address: "0xkraken".to_string(),
+            label: "kraken hot wallet".to_string(),
+        }).await;
+
+        let needs = vec![
+            LiquidityNeed { venue: "kraken".to_string(), asset: "USDT".to_string(), required: 11_000.0, reason: "allocation".to_string() },
+        ];
+        let plans = manager.plan(&needs).await.unwrap();
+        manager.execute(plans[0].clone()).await.unwrap();
+
+        // The withdrawal is still confirming, so planning again must not repeat it
+        assert!(manager.plan(&needs).await.unwrap().is_empty());
+    }
+}
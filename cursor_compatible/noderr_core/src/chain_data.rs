@@ -0,0 +1,437 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! On-chain market data: exchange wallet flows, DEX pool depth and gas prices
+//! polled from pluggable RPC providers, exposed as market features and as
+//! inputs to the regime warning engine.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::market::{MarketData, Symbol};
+
+/// Feature name for the net exchange wallet flow of a symbol's token
+pub const FEATURE_EXCHANGE_NET_FLOW: &str = "chain_exchange_net_flow";
+/// Feature name for the z-score of the net exchange wallet flow
+pub const FEATURE_EXCHANGE_NET_FLOW_Z: &str = "chain_exchange_net_flow_z";
+/// Feature name for total DEX pool depth of a symbol's token
+pub const FEATURE_DEX_DEPTH: &str = "chain_dex_depth";
+/// Feature name for the z-score of DEX pool depth
+pub const FEATURE_DEX_DEPTH_Z: &str = "chain_dex_depth_z";
+/// Feature name prefix for gas prices, suffixed with the chain
+pub const FEATURE_GAS_PRICE_PREFIX: &str = "chain_gas_gwei_";
+
+/// Errors raised while collecting on-chain data
+#[derive(Debug, Error)]
+pub enum ChainDataError {
+    #[error("RPC provider {provider_name} failed: {message}")]
+    Provider { provider_name: String, message: String },
+
+    #[error("No provider registered for chain {0}")]
+    NoProvider(String),
+}
+
+/// Result type for chain data operations
+pub type ChainDataResult<T> = Result<T, ChainDataError>;
+
+/// Exchange-controlled wallet whose token flows are tracked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchedWallet {
+    /// Chain the wallet lives on
+    pub chain: String,
+    /// Wallet address
+    pub address: String,
+    /// Exchange that controls the wallet
+    pub exchange: String,
+    /// Traded symbol whose base token is tracked
+    pub symbol: Symbol,
+}
+
+/// DEX pool whose depth is tracked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchedPool {
+    /// Chain the pool lives on
+    pub chain: String,
+    /// Pool contract address
+    pub address: String,
+    /// DEX name
+    pub dex: String,
+    /// Traded symbol the pool provides liquidity for
+    pub symbol: Symbol,
+}
+
+/// RPC-backed source of on-chain metrics for one chain
+#[async_trait]
+pub trait ChainDataProvider: Send + Sync {
+    /// Provider name, used in logs
+    fn name(&self) -> &str;
+
+    /// Chain served by this provider
+    fn chain(&self) -> &str;
+
+    /// Net token flow into the wallet since `since` (positive = inflow), in token units
+    async fn wallet_net_flow(&self, wallet: &WatchedWallet, since: DateTime<Utc>) -> ChainDataResult<f64>;
+
+    /// Current pool depth in quote currency
+    async fn pool_depth(&self, pool: &WatchedPool) -> ChainDataResult<f64>;
+
+    /// Current gas price in gwei
+    async fn gas_price_gwei(&self) -> ChainDataResult<f64>;
+}
+
+/// Chain data configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChainDataConfig {
+    /// Exchange wallets to track
+    pub wallets: Vec<WatchedWallet>,
+    /// DEX pools to track
+    pub pools: Vec<WatchedPool>,
+    /// How often providers are polled (seconds)
+    pub poll_interval_secs: u64,
+    /// Number of samples kept per metric for z-scores
+    pub history_len: usize,
+    /// Samples required before a z-score is reported
+    pub min_history: usize,
+}
+
+impl Default for ChainDataConfig {
+    fn default() -> Self {
+        Self {
+            wallets: Vec::new(),
+            pools: Vec::new(),
+            poll_interval_secs: 60,
+            history_len: 240,
+            min_history: 10,
+        }
+    }
+}
+
+/// Kind of on-chain metric
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainMetricKind {
+    /// Net flow into exchange wallets, keyed by symbol
+    ExchangeNetFlow,
+    /// Total DEX pool depth, keyed by symbol
+    DexPoolDepth,
+    /// Gas price, keyed by chain
+    GasPrice,
+}
+
+/// Latest observation of a metric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainMetricSample {
+    /// Metric kind
+    pub kind: ChainMetricKind,
+    /// Symbol or chain the metric is keyed by
+    pub key: String,
+    /// Observed value
+    pub value: f64,
+    /// Standard deviations from the metric's recent history, once enough history exists
+    pub z_score: Option<f64>,
+    /// When the value was observed
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Polls chain data providers and keeps the latest metrics with their history
+pub struct ChainDataMonitor {
+    /// Configuration
+    config: ChainDataConfig,
+    /// Providers by chain
+    providers: RwLock<HashMap<String, Arc<dyn ChainDataProvider>>>,
+    /// Recent values per metric
+    history: RwLock<HashMap<(ChainMetricKind, String), VecDeque<f64>>>,
+    /// Latest sample per metric
+    latest: RwLock<HashMap<(ChainMetricKind, String), ChainMetricSample>>,
+    /// Time of the last poll; wallet flows are measured since then
+    last_poll: RwLock<Option<DateTime<Utc>>>,
+    /// Polling task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl ChainDataMonitor {
+    /// Create a monitor with no providers
+    pub fn new(config: ChainDataConfig) -> Self {
+        Self {
+            config,
+            providers: RwLock::new(HashMap::new()),
+            history: RwLock::new(HashMap::new()),
+            latest: RwLock::new(HashMap::new()),
+            last_poll: RwLock::new(None),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Register the provider for its chain, replacing any previous one
+    pub async fn add_provider(&self, provider: Arc<dyn ChainDataProvider>) {
+        debug!("Registered chain data provider {} for {}", provider.name(), provider.chain());
+        self.providers.write().await.insert(provider.chain().to_string(), provider);
+    }
+
+    /// Record an observation, scoring it against the metric's history
+    pub async fn record(&self, kind: ChainMetricKind, key: &str, value: f64, now: DateTime<Utc>) -> ChainMetricSample {
+        let metric = (kind, key.to_string());
+        let z_score = {
+            let mut history = self.history.write().await;
+            let values = history.entry(metric.clone()).or_default();
+            let z_score = z_score(values, value, self.config.min_history);
+            values.push_back(value);
+            while values.len() > self.config.history_len.max(1) {
+                values.pop_front();
+            }
+            z_score
+        };
+
+        let sample = ChainMetricSample {
+            kind,
+            key: key.to_string(),
+            value,
+            z_score,
+            observed_at: now,
+        };
+        self.latest.write().await.insert(metric, sample.clone());
+        sample
+    }
+
+    /// Query every configured wallet, pool and chain once.
+    ///
+    /// Failed queries are logged and skipped. Returns the number of metrics recorded.
+    pub async fn poll(&self, now: DateTime<Utc>) -> usize {
+        let since = self
+            .last_poll
+            .read()
+            .await
+            .unwrap_or_else(|| now - chrono::Duration::seconds(self.config.poll_interval_secs as i64));
+        let providers = self.providers.read().await.clone();
+
+        let mut flows: HashMap<Symbol, f64> = HashMap::new();
+        for wallet in &self.config.wallets {
+            let Some(provider) = providers.get(&wallet.chain) else {
+                warn!("{}", ChainDataError::NoProvider(wallet.chain.clone()));
+                continue;
+            };
+            match provider.wallet_net_flow(wallet, since).await {
+                Ok(flow) => *flows.entry(wallet.symbol.clone()).or_insert(0.0) += flow,
+                Err(e) => warn!("Failed to read {} wallet {}: {}", wallet.exchange, wallet.address, e),
+            }
+        }
+
+        let mut depths: HashMap<Symbol, f64> = HashMap::new();
+        for pool in &self.config.pools {
+            let Some(provider) = providers.get(&pool.chain) else {
+                warn!("{}", ChainDataError::NoProvider(pool.chain.clone()));
+                continue;
+            };
+            match provider.pool_depth(pool).await {
+                Ok(depth) => *depths.entry(pool.symbol.clone()).or_insert(0.0) += depth,
+                Err(e) => warn!("Failed to read {} pool {}: {}", pool.dex, pool.address, e),
+            }
+        }
+
+        let mut recorded = 0;
+        for (symbol, flow) in flows {
+            self.record(ChainMetricKind::ExchangeNetFlow, &symbol, flow, now).await;
+            recorded += 1;
+        }
+        for (symbol, depth) in depths {
+            self.record(ChainMetricKind::DexPoolDepth, &symbol, depth, now).await;
+            recorded += 1;
+        }
+        for (chain, provider) in &providers {
+            match provider.gas_price_gwei().await {
+                Ok(gas) => {
+                    self.record(ChainMetricKind::GasPrice, chain, gas, now).await;
+                    recorded += 1;
+                }
+                Err(e) => warn!("Failed to read gas price on {}: {}", chain, e),
+            }
+        }
+
+        *self.last_poll.write().await = Some(now);
+        recorded
+    }
+
+    /// Latest sample of a metric
+    pub async fn latest(&self, kind: ChainMetricKind, key: &str) -> Option<ChainMetricSample> {
+        self.latest.read().await.get(&(kind, key.to_string())).cloned()
+    }
+
+    /// Latest samples of every metric
+    pub async fn all_latest(&self) -> Vec<ChainMetricSample> {
+        self.latest.read().await.values().cloned().collect()
+    }
+
+    /// On-chain features for a symbol, keyed by the `FEATURE_*` names
+    pub async fn features(&self, symbol: &Symbol) -> HashMap<String, f64> {
+        let latest = self.latest.read().await;
+        let mut features = HashMap::new();
+
+        if let Some(flow) = latest.get(&(ChainMetricKind::ExchangeNetFlow, symbol.clone())) {
+            features.insert(FEATURE_EXCHANGE_NET_FLOW.to_string(), flow.value);
+            if let Some(z) = flow.z_score {
+                features.insert(FEATURE_EXCHANGE_NET_FLOW_Z.to_string(), z);
+            }
+        }
+        if let Some(depth) = latest.get(&(ChainMetricKind::DexPoolDepth, symbol.clone())) {
+            features.insert(FEATURE_DEX_DEPTH.to_string(), depth.value);
+            if let Some(z) = depth.z_score {
+                features.insert(FEATURE_DEX_DEPTH_Z.to_string(), z);
+            }
+        }
+        for sample in latest.values().filter(|s| s.kind == ChainMetricKind::GasPrice) {
+            features.insert(format!("{}{}", FEATURE_GAS_PRICE_PREFIX, sample.key), sample.value);
+        }
+
+        features
+    }
+
+    /// Add the symbol's on-chain features to its custom indicators
+    pub async fn apply_features(&self, market_data: &mut MarketData) {
+        let features = self.features(&market_data.symbol).await;
+        market_data.indicators.custom.extend(features);
+    }
+
+    /// Start polling providers in the background
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let monitor = Arc::clone(self);
+        let interval_secs = self.config.poll_interval_secs.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                monitor.poll(Utc::now()).await;
+            }
+        }));
+    }
+
+    /// Stop polling providers
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Standard deviations of `value` from `history`, if there is enough history to say
+fn z_score(history: &VecDeque<f64>, value: f64, min_history: usize) -> Option<f64> {
+    if history.len() < min_history.max(2) {
+        return None;
+    }
+    let n = history.len() as f64;
+    let mean = history.iter().sum::<f64>() / n;
+    let variance = history.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std_dev = variance.sqrt();
+    if std_dev <= f64::EPSILON {
+        None
+    } else {
+        Some((value - mean) / std_dev)
+    }
+}
+
+/// Create a chain data monitor
+pub fn create_chain_data_monitor(config: ChainDataConfig) -> Arc<ChainDataMonitor> {
+    Arc::new(ChainDataMonitor::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct ScriptedProvider {
+        inflows: Vec<f64>,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ChainDataProvider for ScriptedProvider {
+        fn name(&self) -> &str {
+            "scripted"
+        }
+
+        fn chain(&self) -> &str {
+            "ethereum"
+        }
+
+        async fn wallet_net_flow(&self, _wallet: &WatchedWallet, _since: DateTime<Utc>) -> ChainDataResult<f64> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.inflows[call.min(self.inflows.len() - 1)])
+        }
+
+        async fn pool_depth(&self, _pool: &WatchedPool) -> ChainDataResult<f64> {
+            Ok(2_000_000.0)
+        }
+
+        async fn gas_price_gwei(&self) -> ChainDataResult<f64> {
+            Err(ChainDataError::Provider {
+                provider_name: "scripted".to_string(),
+                message: "timeout".to_string(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inflow_spike_scores_as_feature() {
+        let config = ChainDataConfig {
+            wallets: vec![WatchedWallet {
+                chain: "ethereum".to_string(),
+                address: "0xexchange".to_string(),
+                exchange: "binance".to_string(),
+                symbol: "ETH/USD".to_string(),
+            }],
+            pools: vec![WatchedPool {
+                chain: "ethereum".to_string(),
+                address: "0xpool".to_string(),
+                dex: "uniswap".to_string(),
+                symbol: "ETH/USD".to_string(),
+            }],
+            min_history: 5,
+            ..ChainDataConfig::default()
+        };
+        let monitor = create_chain_data_monitor(config);
+        monitor.add_provider(Arc::new(ScriptedProvider {
+            inflows: vec![100.0, 120.0, 90.0, 110.0, 95.0, 105.0, 5_000.0],
+            calls: AtomicUsize::new(0),
+        })).await;
+
+        let now = Utc::now();
+        for i in 0..7 {
+            // Gas fails every poll; flow and depth are still recorded
+            assert_eq!(monitor.poll(now + chrono::Duration::minutes(i)).await, 2);
+        }
+
+        let features = monitor.features(&"ETH/USD".to_string()).await;
+        assert_eq!(features[FEATURE_EXCHANGE_NET_FLOW], 5_000.0);
+        assert!(features[FEATURE_EXCHANGE_NET_FLOW_Z] > 10.0);
+        assert_eq!(features[FEATURE_DEX_DEPTH], 2_000_000.0);
+        // Constant depth has no meaningful z-score
+        assert!(!features.contains_key(FEATURE_DEX_DEPTH_Z));
+    }
+}
@@ -0,0 +1,582 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Chaos harness for venue connectors. Wrappers around execution providers
+//! and DEX router clients inject scripted failures — reject storms, partial
+//! fills, delayed acknowledgements, duplicate fills and disconnects — so the
+//! retry engine, order management and reconciliation paths can be exercised
+//! deterministically in integration tests.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{broadcast, Mutex};
+use tracing::debug;
+
+use crate::dex_venue::{DexQuote, DexRouterClient, DexSwap, DexTxStatus, DexVenueError, DexVenueResult};
+use crate::execution::{
+    ExecutionError, ExecutionMode, ExecutionProvider, ExecutionRequest, ExecutionResult, ExecutionStatus,
+};
+use crate::order_router::OrderSide;
+
+/// Errors raised by the chaos harness
+#[derive(Debug, Error)]
+pub enum ChaosError {
+    /// A scripted scenario could not be parsed
+    #[error("Invalid chaos scenario: {0}")]
+    Scenario(#[from] serde_json::Error),
+}
+
+/// A failure injected into a venue connector
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChaosFault {
+    /// The venue rejects the order
+    Reject { reason: String },
+    /// The venue fills only part of the order
+    PartialFill { fill_ratio: f64 },
+    /// The venue acknowledges the order late
+    DelayedAck { delay_ms: u64 },
+    /// The venue reports the same fill twice
+    DuplicateFill,
+    /// The connection drops for this and the following calls
+    Disconnect { calls: u32 },
+}
+
+impl ChaosFault {
+    /// Short name used in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChaosFault::Reject { .. } => "reject",
+            ChaosFault::PartialFill { .. } => "partial_fill",
+            ChaosFault::DelayedAck { .. } => "delayed_ack",
+            ChaosFault::DuplicateFill => "duplicate_fill",
+            ChaosFault::Disconnect { .. } => "disconnect",
+        }
+    }
+}
+
+/// When a fault fires, counted over order submissions (1-based)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChaosTrigger {
+    /// Every submission
+    Always,
+    /// `count` consecutive submissions starting at submission `from`
+    Calls { from: u64, count: u64 },
+    /// Every `n`th submission
+    Every { n: u64 },
+    /// Each submission with probability `p`, drawn from the scenario seed
+    Probability { p: f64 },
+}
+
+/// A fault and the submissions it applies to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaosRule {
+    /// Fault to inject
+    pub fault: ChaosFault,
+    /// When the fault fires
+    pub trigger: ChaosTrigger,
+}
+
+/// A scripted chaos scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaosScenario {
+    /// Scenario name
+    pub name: String,
+    /// Seed for probabilistic triggers, so runs are reproducible
+    #[serde(default)]
+    pub seed: u64,
+    /// Rules, all of which are evaluated on every submission
+    #[serde(default)]
+    pub rules: Vec<ChaosRule>,
+}
+
+impl ChaosScenario {
+    /// Create an empty scenario
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            seed: 0,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Parse a scenario from JSON
+    pub fn from_json(json: &str) -> Result<Self, ChaosError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Set the seed for probabilistic triggers
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, fault: ChaosFault, trigger: ChaosTrigger) -> Self {
+        self.rules.push(ChaosRule { fault, trigger });
+        self
+    }
+
+    /// Reject `count` consecutive orders starting at submission `from`
+    pub fn reject_storm(from: u64, count: u64) -> Self {
+        Self::new("reject_storm").with_rule(
+            ChaosFault::Reject { reason: "venue overloaded".to_string() },
+            ChaosTrigger::Calls { from, count },
+        )
+    }
+
+    /// Fill every `n`th order only partially
+    pub fn partial_fills(fill_ratio: f64, n: u64) -> Self {
+        Self::new("partial_fills").with_rule(ChaosFault::PartialFill { fill_ratio }, ChaosTrigger::Every { n })
+    }
+
+    /// Drop the connection for `calls` calls on every `n`th submission
+    pub fn flaky_connection(n: u64, calls: u32) -> Self {
+        Self::new("flaky_connection").with_rule(ChaosFault::Disconnect { calls }, ChaosTrigger::Every { n })
+    }
+}
+
+/// A fault injected during a run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaosEvent {
+    /// Submission count when the fault fired
+    pub submission: u64,
+    /// Connector operation that was affected
+    pub operation: String,
+    /// Request, order or transaction the fault applied to
+    pub target: Option<String>,
+    /// Injected fault
+    pub fault: ChaosFault,
+    /// When the fault fired
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Summary of the faults injected during a run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaosReport {
+    /// Scenario name
+    pub scenario: String,
+    /// Order submissions seen
+    pub submissions: u64,
+    /// Calls failed because the connection was down
+    pub disconnected_calls: u64,
+    /// Injected faults by name
+    pub counts: HashMap<String, usize>,
+    /// Every injected fault in order
+    pub events: Vec<ChaosEvent>,
+}
+
+/// Faults to apply to a single submission
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChaosPlan {
+    /// The connection is down
+    pub disconnected: bool,
+    /// Reject with this reason
+    pub reject: Option<String>,
+    /// Scale the fill by this ratio
+    pub fill_ratio: Option<f64>,
+    /// Delay the acknowledgement by this long (milliseconds)
+    pub ack_delay_ms: u64,
+    /// Report the fill twice
+    pub duplicate_fill: bool,
+}
+
+/// Mutable state of a chaos run
+struct ChaosState {
+    /// Order submissions seen
+    submissions: u64,
+    /// Remaining calls to fail while disconnected
+    disconnected_for: u32,
+    /// Calls failed while disconnected
+    disconnected_calls: u64,
+    /// Random source for probabilistic triggers
+    rng: StdRng,
+    /// Injected faults
+    events: Vec<ChaosEvent>,
+}
+
+/// Decides which faults apply to each connector call
+pub struct ChaosEngine {
+    /// Scenario being played
+    scenario: ChaosScenario,
+    /// Run state
+    state: Mutex<ChaosState>,
+}
+
+impl ChaosEngine {
+    /// Create an engine playing a scenario
+    pub fn new(scenario: ChaosScenario) -> Self {
+        let rng = StdRng::seed_from_u64(scenario.seed);
+        Self {
+            scenario,
+            state: Mutex::new(ChaosState {
+                submissions: 0,
+                disconnected_for: 0,
+                disconnected_calls: 0,
+                rng,
+                events: Vec::new(),
+            }),
+        }
+    }
+
+    /// Plan the faults for an order submission
+    pub async fn plan_submission(&self, operation: &str, target: Option<&str>) -> ChaosPlan {
+        let mut state = self.state.lock().await;
+        state.submissions += 1;
+        let submission = state.submissions;
+
+        if Self::consume_disconnect(&mut state) {
+            return ChaosPlan { disconnected: true, ..Default::default() };
+        }
+
+        let mut plan = ChaosPlan::default();
+        for rule in &self.scenario.rules {
+            let fires = match &rule.trigger {
+                ChaosTrigger::Always => true,
+                ChaosTrigger::Calls { from, count } => submission >= *from && submission < from + count,
+                ChaosTrigger::Every { n } => *n > 0 && submission % n == 0,
+                ChaosTrigger::Probability { p } => state.rng.gen::<f64>() < *p,
+            };
+            if !fires {
+                continue;
+            }
+
+            match &rule.fault {
+                ChaosFault::Reject { reason } => plan.reject = Some(reason.clone()),
+                ChaosFault::PartialFill { fill_ratio } => plan.fill_ratio = Some(fill_ratio.clamp(0.0, 1.0)),
+                ChaosFault::DelayedAck { delay_ms } => plan.ack_delay_ms += delay_ms,
+                ChaosFault::DuplicateFill => plan.duplicate_fill = true,
+                ChaosFault::Disconnect { calls } => {
+                    state.disconnected_for = *calls;
+                    Self::consume_disconnect(&mut state);
+                    plan.disconnected = true;
+                }
+            }
+
+            debug!("Chaos '{}' injecting {} into {} #{}", self.scenario.name, rule.fault.name(), operation, submission);
+            state.events.push(ChaosEvent {
+                submission,
+                operation: operation.to_string(),
+                target: target.map(str::to_string),
+                fault: rule.fault.clone(),
+                timestamp: Utc::now(),
+            });
+        }
+
+        if plan.disconnected {
+            return ChaosPlan { disconnected: true, ..Default::default() };
+        }
+        plan
+    }
+
+    /// Whether a non-submission call fails because the connection is down
+    pub async fn is_disconnected(&self) -> bool {
+        let mut state = self.state.lock().await;
+        Self::consume_disconnect(&mut state)
+    }
+
+    /// Summary of the run so far
+    pub async fn report(&self) -> ChaosReport {
+        let state = self.state.lock().await;
+        let mut counts = HashMap::new();
+        for event in &state.events {
+            *counts.entry(event.fault.name().to_string()).or_insert(0) += 1;
+        }
+
+        ChaosReport {
+            scenario: self.scenario.name.clone(),
+            submissions: state.submissions,
+            disconnected_calls: state.disconnected_calls,
+            counts,
+            events: state.events.clone(),
+        }
+    }
+
+    fn consume_disconnect(state: &mut ChaosState) -> bool {
+        if state.disconnected_for == 0 {
+            return false;
+        }
+        state.disconnected_for -= 1;
+        state.disconnected_calls += 1;
+        true
+    }
+}
+
+/// Execution provider wrapper injecting scripted venue failures
+pub struct ChaosExecutionProvider {
+    /// Wrapped provider
+    inner: Arc<dyn ExecutionProvider>,
+    /// Fault planner
+    engine: ChaosEngine,
+    /// Fill reports, including injected duplicates
+    fills: broadcast::Sender<ExecutionResult>,
+}
+
+impl ChaosExecutionProvider {
+    /// Wrap a provider with a scenario
+    pub fn new(inner: Arc<dyn ExecutionProvider>, scenario: ChaosScenario) -> Self {
+        let (fills, _) = broadcast::channel(1024);
+        Self {
+            inner,
+            engine: ChaosEngine::new(scenario),
+            fills,
+        }
+    }
+
+    /// Subscribe to fill reports as a venue fill feed would deliver them
+    pub fn subscribe_fills(&self) -> broadcast::Receiver<ExecutionResult> {
+        self.fills.subscribe()
+    }
+
+    /// Summary of the faults injected so far
+    pub async fn report(&self) -> ChaosReport {
+        self.engine.report().await
+    }
+
+    fn disconnected() -> ExecutionError {
+        ExecutionError::ConnectionError("venue disconnected (chaos)".to_string())
+    }
+}
+
+#[async_trait]
+impl ExecutionProvider for ChaosExecutionProvider {
+    async fn execute(&self, request: ExecutionRequest) -> Result<ExecutionResult, ExecutionError> {
+        let plan = self.engine.plan_submission("execute", Some(&request.id)).await;
+        if plan.disconnected {
+            return Err(Self::disconnected());
+        }
+        if let Some(reason) = plan.reject {
+            return Err(ExecutionError::OrderRejected(reason));
+        }
+
+        let mut result = self.inner.execute(request).await?;
+
+        if let (Some(ratio), true) = (plan.fill_ratio, result.is_success()) {
+            result.executed_quantity = result.executed_quantity.map(|qty| qty * ratio);
+            result.status = ExecutionStatus::PartiallyFilled;
+        }
+        if plan.ack_delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(plan.ack_delay_ms)).await;
+        }
+
+        if result.is_success() {
+            let _ = self.fills.send(result.clone());
+            if plan.duplicate_fill {
+                let _ = self.fills.send(result.clone());
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn cancel(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+        if self.engine.is_disconnected().await {
+            return Err(Self::disconnected());
+        }
+        self.inner.cancel(request_id).await
+    }
+
+    async fn get_status(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+        if self.engine.is_disconnected().await {
+            return Err(Self::disconnected());
+        }
+        self.inner.get_status(request_id).await
+    }
+
+    fn supports_mode(&self, mode: ExecutionMode) -> bool {
+        self.inner.supports_mode(mode)
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+/// DEX router client wrapper injecting scripted venue failures. Swaps
+/// settle on-chain exactly once, so duplicate fills have no effect here.
+pub struct ChaosDexClient {
+    /// Venue the client serves, used in injected errors
+    venue: String,
+    /// Wrapped client
+    inner: Arc<dyn DexRouterClient>,
+    /// Fault planner
+    engine: ChaosEngine,
+    /// Fill ratios for partially filled swaps by transaction hash
+    partial: Mutex<HashMap<String, f64>>,
+}
+
+impl ChaosDexClient {
+    /// Wrap a client with a scenario
+    pub fn new(venue: &str, inner: Arc<dyn DexRouterClient>, scenario: ChaosScenario) -> Self {
+        Self {
+            venue: venue.to_string(),
+            inner,
+            engine: ChaosEngine::new(scenario),
+            partial: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Summary of the faults injected so far
+    pub async fn report(&self) -> ChaosReport {
+        self.engine.report().await
+    }
+
+    async fn check_connection(&self) -> DexVenueResult<()> {
+        if self.engine.is_disconnected().await {
+            return Err(DexVenueError::Quote {
+                venue: self.venue.clone(),
+                message: "RPC disconnected (chaos)".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DexRouterClient for ChaosDexClient {
+    async fn quote(&self, symbol: &str, side: OrderSide, amount: f64) -> DexVenueResult<DexQuote> {
+        self.check_connection().await?;
+        self.inner.quote(symbol, side, amount).await
+    }
+
+    async fn submit_swap(&self, swap: &DexSwap) -> DexVenueResult<String> {
+        let plan = self.engine.plan_submission("submit_swap", Some(&swap.order_id)).await;
+        if plan.disconnected {
+            return Err(DexVenueError::Submit {
+                venue: self.venue.clone(),
+                message: "RPC disconnected (chaos)".to_string(),
+            });
+        }
+        if let Some(reason) = plan.reject {
+            return Err(DexVenueError::Submit { venue: self.venue.clone(), message: reason });
+        }
+
+        let tx_hash = self.inner.submit_swap(swap).await?;
+        if let Some(ratio) = plan.fill_ratio {
+            self.partial.lock().await.insert(tx_hash.clone(), ratio);
+        }
+        if plan.ack_delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(plan.ack_delay_ms)).await;
+        }
+        Ok(tx_hash)
+    }
+
+    async fn transaction_status(&self, tx_hash: &str) -> DexVenueResult<DexTxStatus> {
+        self.check_connection().await?;
+        let status = self.inner.transaction_status(tx_hash).await?;
+
+        match (status, self.partial.lock().await.get(tx_hash)) {
+            (DexTxStatus::Mined { confirmations, amount_out, gas_used, gas_price_gwei }, Some(ratio)) => {
+                Ok(DexTxStatus::Mined { confirmations, amount_out: amount_out * ratio, gas_used, gas_price_gwei })
+            }
+            (status, _) => Ok(status),
+        }
+    }
+
+    async fn gas_price_gwei(&self) -> DexVenueResult<f64> {
+        self.check_connection().await?;
+        self.inner.gas_price_gwei().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{Signal, SignalAction};
+
+    struct FillingProvider;
+
+    #[async_trait]
+    impl ExecutionProvider for FillingProvider {
+        async fn execute(&self, request: ExecutionRequest) -> Result<ExecutionResult, ExecutionError> {
+            Ok(ExecutionResult::success(request.id, request.signal.id, None, 1.0, 100.0))
+        }
+
+        async fn cancel(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+            Err(ExecutionError::OrderRejected(request_id.to_string()))
+        }
+
+        async fn get_status(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+            Err(ExecutionError::OrderRejected(request_id.to_string()))
+        }
+
+        fn supports_mode(&self, _mode: ExecutionMode) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "filling"
+        }
+    }
+
+    fn request() -> ExecutionRequest {
+        let signal = Signal::new("strat".to_string(), "BTC/USDT".to_string(), SignalAction::Enter);
+        ExecutionRequest::new(signal, ExecutionMode::Paper)
+    }
+
+    #[tokio::test]
+    async fn test_scripted_scenario() {
+        let scenario = ChaosScenario::from_json(
+            r#"{
+                "name": "mixed",
+                "rules": [
+                    {"fault": {"type": "reject", "reason": "storm"}, "trigger": {"type": "calls", "from": 2, "count": 2}},
+                    {"fault": {"type": "partial_fill", "fill_ratio": 0.25}, "trigger": {"type": "calls", "from": 4, "count": 1}},
+                    {"fault": {"type": "duplicate_fill"}, "trigger": {"type": "calls", "from": 5, "count": 1}},
+                    {"fault": {"type": "disconnect", "calls": 2}, "trigger": {"type": "calls", "from": 6, "count": 1}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let provider = ChaosExecutionProvider::new(Arc::new(FillingProvider), scenario);
+        let mut fills = provider.subscribe_fills();
+
+        assert!(provider.execute(request()).await.is_ok());
+        assert!(matches!(provider.execute(request()).await, Err(ExecutionError::OrderRejected(_))));
+        assert!(matches!(provider.execute(request()).await, Err(ExecutionError::OrderRejected(_))));
+
+        let partial = provider.execute(request()).await.unwrap();
+        assert_eq!(partial.status, ExecutionStatus::PartiallyFilled);
+        assert_eq!(partial.executed_quantity, Some(0.25));
+
+        let duplicated = provider.execute(request()).await.unwrap();
+
+        // Disconnect covers the triggering submission and the next call
+        assert!(matches!(provider.execute(request()).await, Err(ExecutionError::ConnectionError(_))));
+        assert!(matches!(provider.get_status("x").await, Err(ExecutionError::ConnectionError(_))));
+        assert!(provider.execute(request()).await.is_ok());
+
+        let mut delivered = Vec::new();
+        while let Ok(fill) = fills.try_recv() {
+            delivered.push(fill.id);
+        }
+        assert_eq!(delivered.len(), 5);
+        assert_eq!(delivered.iter().filter(|id| **id == duplicated.id).count(), 2);
+
+        let report = provider.report().await;
+        assert_eq!(report.submissions, 7);
+        assert_eq!(report.counts.get("reject"), Some(&2));
+        assert_eq!(report.disconnected_calls, 2);
+    }
+}
@@ -0,0 +1,578 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Binance spot connector: combined WebSocket streams for trades, book
+//! diffs and best bid/offer, with books seeded from REST snapshots, and
+//! HMAC-signed REST order entry.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use sha2::Sha256;
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+use super::{
+    format_decimal, parse_decimal, parse_levels, response_error, spawn_stream, timestamp_from_millis,
+    ConnectorConfig, ConnectorError, ConnectorResult, MarketDataEvent, OrderAck, StreamProtocol,
+    StreamSet, VenueBalance, VenueConnector, VenueOrderStatus,
+};
+use crate::order_lineage;
+use crate::order_router::{Order, OrderSide, TimeInForce};
+
+/// Public REST endpoint
+pub const BINANCE_REST_URL: &str = "https://api.binance.com";
+
+/// Public WebSocket endpoint
+pub const BINANCE_WS_URL: &str = "wss://stream.binance.com:9443";
+
+/// Depth of the REST snapshot books are seeded from
+const SNAPSHOT_DEPTH: u32 = 1000;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Binance spot connector
+pub struct BinanceConnector {
+    /// Configuration
+    config: ConnectorConfig,
+    /// REST client
+    http: reqwest::Client,
+    /// Market data streams
+    streams: StreamSet,
+}
+
+impl BinanceConnector {
+    /// Create a connector; empty URLs default to the public endpoints
+    pub fn new(mut config: ConnectorConfig) -> Self {
+        if config.venue_id.is_empty() {
+            config.venue_id = "binance".to_string();
+        }
+        if config.rest_url.is_empty() {
+            config.rest_url = BINANCE_REST_URL.to_string();
+        }
+        if config.ws_url.is_empty() {
+            config.ws_url = BINANCE_WS_URL.to_string();
+        }
+
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            config,
+            http,
+            streams: StreamSet::default(),
+        }
+    }
+
+    /// Send a signed request to a trading endpoint
+    async fn signed<T: DeserializeOwned>(&self, method: Method, path: &str, mut params: Vec<(&'static str, String)>) -> ConnectorResult<T> {
+        let venue = &self.config.venue_id;
+        let (Some(api_key), Some(api_secret)) = (&self.config.api_key, &self.config.api_secret) else {
+            return Err(ConnectorError::MissingCredentials(venue.clone()));
+        };
+
+        params.push(("recvWindow", self.config.recv_window_ms.to_string()));
+        params.push(("timestamp", Utc::now().timestamp_millis().to_string()));
+        let query = encode_query(&params);
+        let url = format!("{}{}?{}&signature={}", self.config.rest_url, path, query, sign(api_secret, &query));
+
+        let response = self.http.request(method, url)
+            .header("X-MBX-APIKEY", api_key)
+            .send()
+            .await
+            .map_err(|e| ConnectorError::Http { venue: venue.clone(), message: e.to_string() })?;
+        if !response.status().is_success() {
+            return Err(response_error(venue, response).await);
+        }
+        response.json().await.map_err(|e| ConnectorError::Decode { venue: venue.clone(), message: e.to_string() })
+    }
+
+    /// Acknowledgement for an order response
+    fn ack(&self, symbol: &str, order: BinanceOrder) -> ConnectorResult<OrderAck> {
+        let venue = &self.config.venue_id;
+        let filled_amount = parse_decimal(venue, &order.executed_qty)?;
+        let filled_notional = parse_decimal(venue, &order.cummulative_quote_qty)?;
+
+        Ok(OrderAck {
+            venue: venue.clone(),
+            symbol: symbol.to_string(),
+            venue_order_id: order.order_id.to_string(),
+            client_order_id: Some(order.orig_client_order_id.unwrap_or(order.client_order_id)),
+            status: order_status(&order.status),
+            filled_amount,
+            average_price: (filled_amount > 0.0).then(|| filled_notional / filled_amount),
+        })
+    }
+}
+
+#[async_trait]
+impl VenueConnector for BinanceConnector {
+    fn venue_id(&self) -> &str {
+        &self.config.venue_id
+    }
+
+    async fn subscribe_market_data(&self, symbols: &[String], sink: mpsc::Sender<MarketDataEvent>) -> ConnectorResult<()> {
+        let protocol = Arc::new(BinanceStream {
+            venue_id: self.config.venue_id.clone(),
+            ws_url: self.config.ws_url.clone(),
+            rest_url: self.config.rest_url.clone(),
+            http: self.http.clone(),
+            symbols: symbols.iter().map(|symbol| (venue_symbol(symbol), symbol.clone())).collect(),
+            last_update_ids: Mutex::new(HashMap::new()),
+        });
+        let stream = spawn_stream(protocol, sink.clone(), &self.config);
+        self.streams.add(symbols, sink, stream);
+        Ok(())
+    }
+
+    async fn unsubscribe_market_data(&self, symbols: &[String]) -> ConnectorResult<()> {
+        for (remaining, sink) in self.streams.remove(symbols) {
+            if !remaining.is_empty() {
+                self.subscribe_market_data(&remaining, sink).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn submit_order(&self, order: &Order) -> ConnectorResult<OrderAck> {
+        let params = order_params(&self.config.venue_id, order, &order_lineage::client_order_id(order))?;
+        let response: BinanceOrder = self.signed(Method::POST, "/api/v3/order", params).await?;
+        let ack = self.ack(&order.symbol, response)?;
+        info!("Binance accepted order {} as {} ({:?})", order.id, ack.venue_order_id, ack.status);
+        Ok(ack)
+    }
+
+    async fn cancel_order(&self, symbol: &str, venue_order_id: &str) -> ConnectorResult<OrderAck> {
+        let params = vec![
+            ("symbol", venue_symbol(symbol)),
+            ("orderId", venue_order_id.to_string()),
+        ];
+        let response: BinanceOrder = self.signed(Method::DELETE, "/api/v3/order", params).await?;
+        debug!("Binance cancelled order {}", venue_order_id);
+        self.ack(symbol, response)
+    }
+
+    async fn fetch_balances(&self) -> ConnectorResult<Vec<VenueBalance>> {
+        let account: BinanceAccount = self.signed(Method::GET, "/api/v3/account", Vec::new()).await?;
+        let venue = &self.config.venue_id;
+
+        let mut balances = Vec::new();
+        for balance in account.balances {
+            let balance = VenueBalance {
+                free: parse_decimal(venue, &balance.free)?,
+                locked: parse_decimal(venue, &balance.locked)?,
+                asset: balance.asset,
+            };
+            if balance.total() > 0.0 {
+                balances.push(balance);
+            }
+        }
+        Ok(balances)
+    }
+
+    async fn disconnect(&self) {
+        self.streams.clear();
+    }
+}
+
+/// Order as returned by order entry and cancel endpoints
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceOrder {
+    order_id: u64,
+    client_order_id: String,
+    /// Set on cancels, where `client_order_id` names the cancel request
+    #[serde(default)]
+    orig_client_order_id: Option<String>,
+    status: String,
+    executed_qty: String,
+    cummulative_quote_qty: String,
+}
+
+/// Account information
+#[derive(Debug, Deserialize)]
+struct BinanceAccount {
+    balances: Vec<BinanceBalance>,
+}
+
+/// One asset balance
+#[derive(Debug, Deserialize)]
+struct BinanceBalance {
+    asset: String,
+    free: String,
+    locked: String,
+}
+
+/// Envelope of a combined stream message
+#[derive(Debug, Deserialize)]
+struct CombinedMessage {
+    stream: String,
+    data: serde_json::Value,
+}
+
+/// `<symbol>@trade` payload
+#[derive(Debug, Deserialize)]
+struct TradeMessage {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "p")]
+    price: String,
+    #[serde(rename = "q")]
+    quantity: String,
+    #[serde(rename = "T")]
+    trade_time: i64,
+    #[serde(rename = "m")]
+    buyer_is_maker: bool,
+}
+
+/// `<symbol>@depth` payload
+#[derive(Debug, Deserialize)]
+struct DepthUpdate {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "E")]
+    event_time: i64,
+    #[serde(rename = "U")]
+    first_update_id: u64,
+    #[serde(rename = "u")]
+    final_update_id: u64,
+    #[serde(rename = "b")]
+    bids: Vec<[String; 2]>,
+    #[serde(rename = "a")]
+    asks: Vec<[String; 2]>,
+}
+
+/// `<symbol>@bookTicker` payload
+#[derive(Debug, Deserialize)]
+struct BookTicker {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "b")]
+    bid: String,
+    #[serde(rename = "B")]
+    bid_size: String,
+    #[serde(rename = "a")]
+    ask: String,
+    #[serde(rename = "A")]
+    ask_size: String,
+}
+
+/// REST depth snapshot
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DepthSnapshot {
+    last_update_id: u64,
+    bids: Vec<[String; 2]>,
+    asks: Vec<[String; 2]>,
+}
+
+/// Combined trade, depth and book ticker streams
+struct BinanceStream {
+    /// Venue identifier
+    venue_id: String,
+    /// WebSocket base URL
+    ws_url: String,
+    /// REST base URL for snapshots
+    rest_url: String,
+    /// REST client
+    http: reqwest::Client,
+    /// Router symbols by Binance symbol
+    symbols: HashMap<String, String>,
+    /// Last applied book update by Binance symbol
+    last_update_ids: Mutex<HashMap<String, u64>>,
+}
+
+impl BinanceStream {
+    fn decode<T: DeserializeOwned>(&self, value: serde_json::Value) -> ConnectorResult<T> {
+        serde_json::from_value(value).map_err(|e| ConnectorError::Decode { venue: self.venue_id.clone(), message: e.to_string() })
+    }
+
+    fn symbol(&self, venue_symbol: &str) -> ConnectorResult<String> {
+        self.symbols.get(venue_symbol).cloned().ok_or_else(|| ConnectorError::Decode {
+            venue: self.venue_id.clone(),
+            message: format!("unsubscribed symbol {}", venue_symbol),
+        })
+    }
+
+    /// Book diff, dropped if the snapshot already covers it
+    fn depth_update(&self, update: DepthUpdate) -> ConnectorResult<Vec<MarketDataEvent>> {
+        let symbol = self.symbol(&update.symbol)?;
+        let mut last_update_ids = self.last_update_ids.lock().unwrap();
+        let Some(last_update_id) = last_update_ids.get_mut(&update.symbol) else {
+            return Ok(Vec::new());
+        };
+        if update.final_update_id <= *last_update_id {
+            return Ok(Vec::new());
+        }
+        if update.first_update_id > *last_update_id + 1 {
+            return Err(ConnectorError::SequenceGap { venue: self.venue_id.clone(), symbol });
+        }
+        *last_update_id = update.final_update_id;
+
+        Ok(vec![MarketDataEvent::Book {
+            venue: self.venue_id.clone(),
+            symbol,
+            bids: parse_levels(&self.venue_id, &update.bids)?,
+            asks: parse_levels(&self.venue_id, &update.asks)?,
+            snapshot: false,
+            update_id: update.final_update_id,
+            timestamp: timestamp_from_millis(update.event_time),
+        }])
+    }
+}
+
+#[async_trait]
+impl StreamProtocol for BinanceStream {
+    fn venue_id(&self) -> &str {
+        &self.venue_id
+    }
+
+    fn url(&self) -> String {
+        let streams: Vec<String> = self.symbols.keys()
+            .map(|symbol| symbol.to_lowercase())
+            .flat_map(|symbol| [format!("{symbol}@trade"), format!("{symbol}@depth@100ms"), format!("{symbol}@bookTicker")])
+            .collect();
+        format!("{}/stream?streams={}", self.ws_url, streams.join("/"))
+    }
+
+    fn subscribe_messages(&self) -> Vec<String> {
+        // Combined stream URLs subscribe on connect
+        Vec::new()
+    }
+
+    async fn on_connect(&self) -> ConnectorResult<Vec<MarketDataEvent>> {
+        let mut events = Vec::new();
+        for (venue_symbol, symbol) in &self.symbols {
+            let url = format!("{}/api/v3/depth?symbol={}&limit={}", self.rest_url, venue_symbol, SNAPSHOT_DEPTH);
+            let response = self.http.get(url)
+                .send()
+                .await
+                .map_err(|e| ConnectorError::Http { venue: self.venue_id.clone(), message: e.to_string() })?;
+            if !response.status().is_success() {
+                return Err(response_error(&self.venue_id, response).await);
+            }
+            let snapshot: DepthSnapshot = response.json()
+                .await
+                .map_err(|e| ConnectorError::Decode { venue: self.venue_id.clone(), message: e.to_string() })?;
+
+            self.last_update_ids.lock().unwrap().insert(venue_symbol.clone(), snapshot.last_update_id);
+            events.push(MarketDataEvent::Book {
+                venue: self.venue_id.clone(),
+                symbol: symbol.clone(),
+                bids: parse_levels(&self.venue_id, &snapshot.bids)?,
+                asks: parse_levels(&self.venue_id, &snapshot.asks)?,
+                snapshot: true,
+                update_id: snapshot.last_update_id,
+                timestamp: Utc::now(),
+            });
+        }
+        Ok(events)
+    }
+
+    fn parse(&self, text: &str) -> ConnectorResult<Vec<MarketDataEvent>> {
+        let message: CombinedMessage = serde_json::from_str(text)
+            .map_err(|e| ConnectorError::Decode { venue: self.venue_id.clone(), message: e.to_string() })?;
+
+        if message.stream.ends_with("@trade") {
+            let trade: TradeMessage = self.decode(message.data)?;
+            Ok(vec![MarketDataEvent::Trade {
+                venue: self.venue_id.clone(),
+                symbol: self.symbol(&trade.symbol)?,
+                price: parse_decimal(&self.venue_id, &trade.price)?,
+                size: parse_decimal(&self.venue_id, &trade.quantity)?,
+                // The buyer resting on the book means a seller crossed the spread
+                aggressor: Some(if trade.buyer_is_maker { OrderSide::Sell } else { OrderSide::Buy }),
+                timestamp: timestamp_from_millis(trade.trade_time),
+            }])
+        } else if message.stream.contains("@depth") {
+            self.depth_update(self.decode(message.data)?)
+        } else if message.stream.ends_with("@bookTicker") {
+            let ticker: BookTicker = self.decode(message.data)?;
+            Ok(vec![MarketDataEvent::Quote {
+                venue: self.venue_id.clone(),
+                symbol: self.symbol(&ticker.symbol)?,
+                bid: parse_decimal(&self.venue_id, &ticker.bid)?,
+                bid_size: parse_decimal(&self.venue_id, &ticker.bid_size)?,
+                ask: parse_decimal(&self.venue_id, &ticker.ask)?,
+                ask_size: parse_decimal(&self.venue_id, &ticker.ask_size)?,
+                timestamp: Utc::now(),
+            }])
+        } else {
+            Ok(Vec::new())
+        }
+    }
+}
+
+/// `BTC/USDT` → `BTCUSDT`
+fn venue_symbol(symbol: &str) -> String {
+    symbol.replace(['/', '-'], "").to_uppercase()
+}
+
+fn order_status(status: &str) -> VenueOrderStatus {
+    match status {
+        "NEW" => VenueOrderStatus::New,
+        "PARTIALLY_FILLED" => VenueOrderStatus::PartiallyFilled,
+        "FILLED" => VenueOrderStatus::Filled,
+        "CANCELED" | "PENDING_CANCEL" => VenueOrderStatus::Cancelled,
+        "EXPIRED" | "EXPIRED_IN_MATCH" => VenueOrderStatus::Expired,
+        _ => VenueOrderStatus::Rejected,
+    }
+}
+
+/// Order entry parameters. A price of zero sends a market order; post-only
+/// orders use `LIMIT_MAKER`, which the venue rejects instead of crossing and
+/// which cannot carry an IOC or FOK instruction.
+/// Time-bound orders rest as GTC and are cancelled by the router's expiry scheduler.
+fn order_params(venue: &str, order: &Order, client_order_id: &str) -> ConnectorResult<Vec<(&'static str, String)>> {
+    let mut params = vec![
+        ("symbol", venue_symbol(&order.symbol)),
+        ("side", match order.side { OrderSide::Buy => "BUY", OrderSide::Sell => "SELL" }.to_string()),
+        ("quantity", format_decimal(order.amount)),
+        ("newClientOrderId", client_order_id.to_string()),
+    ];
+
+    if order.price <= 0.0 {
+        if order.post_only {
+            return Err(ConnectorError::Unsupported { venue: venue.to_string(), message: "post-only market order".to_string() });
+        }
+        params.push(("type", "MARKET".to_string()));
+    } else if order.post_only {
+        if order.time_in_force.is_immediate() {
+            return Err(ConnectorError::Unsupported {
+                venue: venue.to_string(),
+                message: format!("post-only {} order", order.time_in_force.name()),
+            });
+        }
+        params.push(("type", "LIMIT_MAKER".to_string()));
+        params.push(("price", format_decimal(order.price)));
+    } else {
+        let time_in_force = match order.time_in_force {
+            TimeInForce::IOC => "IOC",
+            TimeInForce::FOK => "FOK",
+            _ => "GTC",
+        };
+        params.push(("type", "LIMIT".to_string()));
+        params.push(("timeInForce", time_in_force.to_string()));
+        params.push(("price", format_decimal(order.price)));
+    }
+    Ok(params)
+}
+
+/// HMAC-SHA256 of the query string, hex-encoded
+fn sign(secret: &str, query: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(query.as_bytes());
+    mac.finalize().into_bytes().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Form-encode parameters in order; the signature covers the exact string sent
+fn encode_query(params: &[(&str, String)]) -> String {
+    params.iter()
+        .map(|(key, value)| {
+            let value: String = value.bytes()
+                .map(|b| match b {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+                    _ => format!("%{:02X}", b),
+                })
+                .collect();
+            format!("{}={}", key, value)
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream() -> BinanceStream {
+        BinanceStream {
+            venue_id: "binance".to_string(),
+            ws_url: BINANCE_WS_URL.to_string(),
+            rest_url: BINANCE_REST_URL.to_string(),
+            http: reqwest::Client::new(),
+            symbols: HashMap::from([("BTCUSDT".to_string(), "BTC/USDT".to_string())]),
+            last_update_ids: Mutex::new(HashMap::from([("BTCUSDT".to_string(), 100)])),
+        }
+    }
+
+    fn depth(first: u64, last: u64) -> String {
+        format!(
+            r#"{{"stream":"btcusdt@depth@100ms","data":{{"e":"depthUpdate","E":1700000000000,"s":"BTCUSDT","U":{},"u":{},"b":[["37000.10","0.5"]],"a":[["37001.00","0"]]}}}}"#,
+            first, last
+        )
+    }
+
+    #[test]
+    fn test_signing_orders_and_stream_parsing() {
+        // Example from the Binance API documentation
+        let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+        assert_eq!(
+            sign("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j", query),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+
+        let mut order = Order {
+            symbol: "BTC/USDT".to_string(),
+            side: OrderSide::Buy,
+            amount: 0.25,
+            price: 37000.5,
+            venues: vec!["binance".to_string()],
+            id: "order-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: TimeInForce::IOC,
+            additional_params: HashMap::new(),
+        };
+        assert!(matches!(order_params("binance", &order, "id"), Err(ConnectorError::Unsupported { .. })));
+        order.time_in_force = TimeInForce::GTC;
+        assert_eq!(
+            encode_query(&order_params("binance", &order, "none-ab-cd").unwrap()),
+            "symbol=BTCUSDT&side=BUY&quantity=0.25&newClientOrderId=none-ab-cd&type=LIMIT_MAKER&price=37000.5"
+        );
+        order.price = 0.0;
+        assert!(matches!(order_params("binance", &order, "id"), Err(ConnectorError::Unsupported { .. })));
+
+        // Diffs the snapshot covers are dropped, the bridging one applies, a gap forces a resync
+        let stream = stream();
+        assert!(stream.parse(&depth(90, 100)).unwrap().is_empty());
+        match &stream.parse(&depth(95, 105)).unwrap()[..] {
+            [MarketDataEvent::Book { symbol, bids, asks, update_id: 105, snapshot: false, .. }] => {
+                assert_eq!(symbol, "BTC/USDT");
+                assert_eq!(bids, &vec![(37000.1, 0.5)]);
+                assert_eq!(asks, &vec![(37001.0, 0.0)]);
+            }
+            events => panic!("unexpected events {:?}", events),
+        }
+        assert!(matches!(stream.parse(&depth(107, 110)), Err(ConnectorError::SequenceGap { .. })));
+
+        let trade = r#"{"stream":"btcusdt@trade","data":{"e":"trade","E":1700000000001,"s":"BTCUSDT","t":1,"p":"37000.5","q":"0.01","T":1700000000000,"m":true}}"#;
+        assert!(matches!(
+            &stream.parse(trade).unwrap()[..],
+            [MarketDataEvent::Trade { aggressor: Some(OrderSide::Sell), price, .. }] if *price == 37000.5
+        ));
+    }
+}
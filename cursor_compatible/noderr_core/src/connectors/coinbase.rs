@@ -0,0 +1,530 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Coinbase Exchange connector: the public WebSocket feed for batched level 2
+//! books, matches and tickers, and passphrase-authenticated REST order entry.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use sha2::Sha256;
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+use super::{
+    format_decimal, parse_decimal, parse_levels, response_error, spawn_stream, ConnectorConfig,
+    ConnectorError, ConnectorResult, MarketDataEvent, OrderAck, StreamProtocol, StreamSet, VenueBalance,
+    VenueConnector, VenueOrderStatus,
+};
+use crate::order_router::{Order, OrderSide, TimeInForce};
+
+/// Public REST endpoint
+pub const COINBASE_REST_URL: &str = "https://api.exchange.coinbase.com";
+
+/// Public WebSocket feed
+pub const COINBASE_WS_URL: &str = "wss://ws-feed.exchange.coinbase.com";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Coinbase Exchange connector
+pub struct CoinbaseConnector {
+    /// Configuration
+    config: ConnectorConfig,
+    /// REST client
+    http: reqwest::Client,
+    /// Market data streams
+    streams: StreamSet,
+}
+
+impl CoinbaseConnector {
+    /// Create a connector; empty URLs default to the public endpoints
+    pub fn new(mut config: ConnectorConfig) -> Self {
+        if config.venue_id.is_empty() {
+            config.venue_id = "coinbase".to_string();
+        }
+        if config.rest_url.is_empty() {
+            config.rest_url = COINBASE_REST_URL.to_string();
+        }
+        if config.ws_url.is_empty() {
+            config.ws_url = COINBASE_WS_URL.to_string();
+        }
+
+        // The REST API refuses requests without a user agent
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .user_agent(concat!("noderr-core/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            config,
+            http,
+            streams: StreamSet::default(),
+        }
+    }
+
+    /// Send an authenticated request; `path` includes any query string
+    async fn signed<T: DeserializeOwned>(&self, method: Method, path: &str, body: Option<serde_json::Value>) -> ConnectorResult<T> {
+        let venue = &self.config.venue_id;
+        let (Some(api_key), Some(api_secret), Some(passphrase)) =
+            (&self.config.api_key, &self.config.api_secret, &self.config.passphrase)
+        else {
+            return Err(ConnectorError::MissingCredentials(venue.clone()));
+        };
+
+        let body = body.map(|body| body.to_string()).unwrap_or_default();
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = sign(api_secret, &timestamp, method.as_str(), path, &body)
+            .ok_or_else(|| ConnectorError::MissingCredentials(format!("{} (secret is not base64)", venue)))?;
+
+        let mut request = self.http.request(method, format!("{}{}", self.config.rest_url, path))
+            .header("CB-ACCESS-KEY", api_key)
+            .header("CB-ACCESS-SIGN", signature)
+            .header("CB-ACCESS-TIMESTAMP", timestamp)
+            .header("CB-ACCESS-PASSPHRASE", passphrase);
+        if !body.is_empty() {
+            request = request.header("Content-Type", "application/json").body(body);
+        }
+
+        let response = request.send()
+            .await
+            .map_err(|e| ConnectorError::Http { venue: venue.clone(), message: e.to_string() })?;
+        if !response.status().is_success() {
+            return Err(response_error(venue, response).await);
+        }
+        response.json().await.map_err(|e| ConnectorError::Decode { venue: venue.clone(), message: e.to_string() })
+    }
+
+    /// Acknowledgement for an order response
+    fn ack(&self, symbol: &str, order: CoinbaseOrder) -> ConnectorResult<OrderAck> {
+        let venue = &self.config.venue_id;
+        let filled_amount = parse_decimal(venue, &order.filled_size)?;
+        let filled_notional = parse_decimal(venue, &order.executed_value)?;
+
+        Ok(OrderAck {
+            venue: venue.clone(),
+            symbol: symbol.to_string(),
+            status: order_status(&order, filled_amount),
+            venue_order_id: order.id,
+            client_order_id: order.client_oid.filter(|id| !id.is_empty()),
+            filled_amount,
+            average_price: (filled_amount > 0.0).then(|| filled_notional / filled_amount),
+        })
+    }
+}
+
+#[async_trait]
+impl VenueConnector for CoinbaseConnector {
+    fn venue_id(&self) -> &str {
+        &self.config.venue_id
+    }
+
+    async fn subscribe_market_data(&self, symbols: &[String], sink: mpsc::Sender<MarketDataEvent>) -> ConnectorResult<()> {
+        let protocol = Arc::new(CoinbaseStream {
+            venue_id: self.config.venue_id.clone(),
+            ws_url: self.config.ws_url.clone(),
+            symbols: symbols.iter().map(|symbol| (product_id(symbol), symbol.clone())).collect(),
+            update_ids: Mutex::new(HashMap::new()),
+        });
+        let stream = spawn_stream(protocol, sink.clone(), &self.config);
+        self.streams.add(symbols, sink, stream);
+        Ok(())
+    }
+
+    async fn unsubscribe_market_data(&self, symbols: &[String]) -> ConnectorResult<()> {
+        for (remaining, sink) in self.streams.remove(symbols) {
+            if !remaining.is_empty() {
+                self.subscribe_market_data(&remaining, sink).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn submit_order(&self, order: &Order) -> ConnectorResult<OrderAck> {
+        let body = order_body(&self.config.venue_id, order)?;
+        let response: CoinbaseOrder = self.signed(Method::POST, "/orders", Some(body)).await?;
+        let ack = self.ack(&order.symbol, response)?;
+        info!("Coinbase accepted order {} as {} ({:?})", order.id, ack.venue_order_id, ack.status);
+        Ok(ack)
+    }
+
+    async fn cancel_order(&self, symbol: &str, venue_order_id: &str) -> ConnectorResult<OrderAck> {
+        let path = format!("/orders/{}?product_id={}", venue_order_id, product_id(symbol));
+        // The venue only echoes the ID back, so fills are not known here
+        let _: String = self.signed(Method::DELETE, &path, None).await?;
+        debug!("Coinbase cancelled order {}", venue_order_id);
+
+        Ok(OrderAck {
+            venue: self.config.venue_id.clone(),
+            symbol: symbol.to_string(),
+            venue_order_id: venue_order_id.to_string(),
+            client_order_id: None,
+            status: VenueOrderStatus::Cancelled,
+            filled_amount: 0.0,
+            average_price: None,
+        })
+    }
+
+    async fn fetch_balances(&self) -> ConnectorResult<Vec<VenueBalance>> {
+        let accounts: Vec<CoinbaseAccount> = self.signed(Method::GET, "/accounts", None).await?;
+        let venue = &self.config.venue_id;
+
+        let mut balances = Vec::new();
+        for account in accounts {
+            let balance = VenueBalance {
+                free: parse_decimal(venue, &account.available)?,
+                locked: parse_decimal(venue, &account.hold)?,
+                asset: account.currency,
+            };
+            if balance.total() > 0.0 {
+                balances.push(balance);
+            }
+        }
+        Ok(balances)
+    }
+
+    async fn disconnect(&self) {
+        self.streams.clear();
+    }
+}
+
+/// Order as returned by order entry
+#[derive(Debug, Deserialize)]
+struct CoinbaseOrder {
+    id: String,
+    #[serde(default)]
+    client_oid: Option<String>,
+    #[serde(default)]
+    size: Option<String>,
+    status: String,
+    #[serde(default)]
+    done_reason: Option<String>,
+    #[serde(default = "zero")]
+    filled_size: String,
+    #[serde(default = "zero")]
+    executed_value: String,
+}
+
+fn zero() -> String {
+    "0".to_string()
+}
+
+/// Account balance
+#[derive(Debug, Deserialize)]
+struct CoinbaseAccount {
+    currency: String,
+    available: String,
+    hold: String,
+}
+
+/// Feed message, tagged by `type`
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum FeedMessage {
+    Snapshot {
+        product_id: String,
+        bids: Vec<[String; 2]>,
+        asks: Vec<[String; 2]>,
+    },
+    L2update {
+        product_id: String,
+        /// `[side, price, size]`
+        changes: Vec<[String; 3]>,
+        time: DateTime<Utc>,
+    },
+    Match {
+        product_id: String,
+        price: String,
+        size: String,
+        /// Side of the resting order
+        side: String,
+        time: DateTime<Utc>,
+    },
+    Ticker {
+        product_id: String,
+        best_bid: String,
+        #[serde(default = "zero")]
+        best_bid_size: String,
+        best_ask: String,
+        #[serde(default = "zero")]
+        best_ask_size: String,
+        time: DateTime<Utc>,
+    },
+    Error {
+        message: String,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// Level 2, matches and ticker channels
+struct CoinbaseStream {
+    /// Venue identifier
+    venue_id: String,
+    /// WebSocket feed URL
+    ws_url: String,
+    /// Router symbols by product ID
+    symbols: HashMap<String, String>,
+    /// Local book update counters by product ID; the batched channel carries no sequence
+    update_ids: Mutex<HashMap<String, u64>>,
+}
+
+impl CoinbaseStream {
+    fn symbol(&self, product_id: &str) -> ConnectorResult<String> {
+        self.symbols.get(product_id).cloned().ok_or_else(|| ConnectorError::Decode {
+            venue: self.venue_id.clone(),
+            message: format!("unsubscribed product {}", product_id),
+        })
+    }
+
+    fn next_update_id(&self, product_id: &str, reset: bool) -> u64 {
+        let mut update_ids = self.update_ids.lock().unwrap();
+        let update_id = update_ids.entry(product_id.to_string()).or_insert(0);
+        *update_id = if reset { 1 } else { *update_id + 1 };
+        *update_id
+    }
+}
+
+#[async_trait]
+impl StreamProtocol for CoinbaseStream {
+    fn venue_id(&self) -> &str {
+        &self.venue_id
+    }
+
+    fn url(&self) -> String {
+        self.ws_url.clone()
+    }
+
+    fn subscribe_messages(&self) -> Vec<String> {
+        let product_ids: Vec<&String> = self.symbols.keys().collect();
+        vec![serde_json::json!({
+            "type": "subscribe",
+            "product_ids": product_ids,
+            "channels": ["level2_batch", "matches", "ticker"],
+        })
+        .to_string()]
+    }
+
+    async fn on_connect(&self) -> ConnectorResult<Vec<MarketDataEvent>> {
+        // The level 2 channel opens with its own snapshot
+        Ok(Vec::new())
+    }
+
+    fn parse(&self, text: &str) -> ConnectorResult<Vec<MarketDataEvent>> {
+        let venue = &self.venue_id;
+        let message: FeedMessage = serde_json::from_str(text)
+            .map_err(|e| ConnectorError::Decode { venue: venue.clone(), message: e.to_string() })?;
+
+        let event = match message {
+            FeedMessage::Snapshot { product_id, bids, asks } => MarketDataEvent::Book {
+                venue: venue.clone(),
+                symbol: self.symbol(&product_id)?,
+                bids: parse_levels(venue, &bids)?,
+                asks: parse_levels(venue, &asks)?,
+                snapshot: true,
+                update_id: self.next_update_id(&product_id, true),
+                timestamp: Utc::now(),
+            },
+            FeedMessage::L2update { product_id, changes, time } => {
+                let (mut bids, mut asks) = (Vec::new(), Vec::new());
+                for [side, price, size] in &changes {
+                    let level = (parse_decimal(venue, price)?, parse_decimal(venue, size)?);
+                    if side == "buy" { bids.push(level) } else { asks.push(level) }
+                }
+                MarketDataEvent::Book {
+                    venue: venue.clone(),
+                    symbol: self.symbol(&product_id)?,
+                    bids,
+                    asks,
+                    snapshot: false,
+                    update_id: self.next_update_id(&product_id, false),
+                    timestamp: time,
+                }
+            }
+            FeedMessage::Match { product_id, price, size, side, time } => MarketDataEvent::Trade {
+                venue: venue.clone(),
+                symbol: self.symbol(&product_id)?,
+                price: parse_decimal(venue, &price)?,
+                size: parse_decimal(venue, &size)?,
+                // `side` is the maker's; the taker traded the other way
+                aggressor: Some(if side == "buy" { OrderSide::Sell } else { OrderSide::Buy }),
+                timestamp: time,
+            },
+            FeedMessage::Ticker { product_id, best_bid, best_bid_size, best_ask, best_ask_size, time } => MarketDataEvent::Quote {
+                venue: venue.clone(),
+                symbol: self.symbol(&product_id)?,
+                bid: parse_decimal(venue, &best_bid)?,
+                bid_size: parse_decimal(venue, &best_bid_size)?,
+                ask: parse_decimal(venue, &best_ask)?,
+                ask_size: parse_decimal(venue, &best_ask_size)?,
+                timestamp: time,
+            },
+            FeedMessage::Error { message, reason } => {
+                return Err(ConnectorError::Rejected {
+                    venue: venue.clone(),
+                    code: "feed".to_string(),
+                    message: reason.map(|reason| format!("{}: {}", message, reason)).unwrap_or(message),
+                });
+            }
+            FeedMessage::Other => return Ok(Vec::new()),
+        };
+        Ok(vec![event])
+    }
+}
+
+/// `BTC/USD` → `BTC-USD`
+fn product_id(symbol: &str) -> String {
+    symbol.replace('/', "-").to_uppercase()
+}
+
+fn order_status(order: &CoinbaseOrder, filled_amount: f64) -> VenueOrderStatus {
+    match order.status.as_str() {
+        "pending" | "open" | "active" if filled_amount > 0.0 => VenueOrderStatus::PartiallyFilled,
+        "pending" | "open" | "active" => VenueOrderStatus::New,
+        "done" if order.done_reason.as_deref() == Some("filled") => VenueOrderStatus::Filled,
+        "done" => {
+            let fully_filled = order.size.as_deref()
+                .and_then(|size| size.parse::<f64>().ok())
+                .is_some_and(|size| filled_amount >= size);
+            if fully_filled { VenueOrderStatus::Filled } else { VenueOrderStatus::Cancelled }
+        }
+        _ => VenueOrderStatus::Rejected,
+    }
+}
+
+/// Order entry body. A price of zero sends a market order. Time-bound orders
+/// rest as GTC and are cancelled by the router's expiry scheduler.
+fn order_body(venue: &str, order: &Order) -> ConnectorResult<serde_json::Value> {
+    let side = match order.side {
+        OrderSide::Buy => "buy",
+        OrderSide::Sell => "sell",
+    };
+
+    if order.price <= 0.0 {
+        if order.post_only {
+            return Err(ConnectorError::Unsupported { venue: venue.to_string(), message: "post-only market order".to_string() });
+        }
+        return Ok(serde_json::json!({
+            "type": "market",
+            "product_id": product_id(&order.symbol),
+            "side": side,
+            "size": format_decimal(order.amount),
+        }));
+    }
+
+    let time_in_force = match order.time_in_force {
+        TimeInForce::IOC => "IOC",
+        TimeInForce::FOK => "FOK",
+        _ => "GTC",
+    };
+    if order.post_only && order.time_in_force.is_immediate() {
+        return Err(ConnectorError::Unsupported {
+            venue: venue.to_string(),
+            message: format!("post-only {} order", time_in_force),
+        });
+    }
+
+    Ok(serde_json::json!({
+        "type": "limit",
+        "product_id": product_id(&order.symbol),
+        "side": side,
+        "price": format_decimal(order.price),
+        "size": format_decimal(order.amount),
+        "time_in_force": time_in_force,
+        "post_only": order.post_only,
+    }))
+}
+
+/// Base64 HMAC-SHA256 of `timestamp + method + path + body`, keyed with the
+/// base64-decoded secret; `None` if the secret is not base64
+fn sign(secret: &str, timestamp: &str, method: &str, path: &str, body: &str) -> Option<String> {
+    let key = BASE64.decode(secret).ok()?;
+    let mut mac = HmacSha256::new_from_slice(&key).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}{}{}{}", timestamp, method, path, body).as_bytes());
+    Some(BASE64.encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream() -> CoinbaseStream {
+        CoinbaseStream {
+            venue_id: "coinbase".to_string(),
+            ws_url: COINBASE_WS_URL.to_string(),
+            symbols: HashMap::from([("BTC-USD".to_string(), "BTC/USD".to_string())]),
+            update_ids: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn test_signing_orders_and_feed_parsing() {
+        assert_eq!(
+            sign("Y29pbmJhc2UtdGVzdC1zZWNyZXQ=", "1700000000", "GET", "/accounts", "").as_deref(),
+            Some("WQnYQwZSbDTk9/UoVkdWVNb6q0SeIcfFQmQYjo7vhoI=")
+        );
+        assert!(sign("not base64!", "1700000000", "GET", "/accounts", "").is_none());
+
+        let order = Order {
+            symbol: "BTC/USD".to_string(),
+            side: OrderSide::Sell,
+            amount: 0.1,
+            price: 37000.0,
+            venues: vec!["coinbase".to_string()],
+            id: "order-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTT { duration_ms: 60_000 },
+            additional_params: HashMap::new(),
+        };
+        let body = order_body("coinbase", &order).unwrap();
+        assert_eq!(body["product_id"], "BTC-USD");
+        assert_eq!(body["time_in_force"], "GTC");
+        assert_eq!(body["price"], "37000");
+        assert!(order_body("coinbase", &Order { time_in_force: TimeInForce::IOC, ..order }).is_err());
+
+        let stream = stream();
+        let snapshot = r#"{"type":"snapshot","product_id":"BTC-USD","bids":[["37000.00","1.5"]],"asks":[["37001.00","2"]]}"#;
+        assert!(matches!(&stream.parse(snapshot).unwrap()[..], [MarketDataEvent::Book { snapshot: true, update_id: 1, .. }]));
+
+        let update = r#"{"type":"l2update","product_id":"BTC-USD","changes":[["buy","37000.00","0"],["sell","37002.00","0.5"]],"time":"2023-11-14T22:13:20.000000Z"}"#;
+        match &stream.parse(update).unwrap()[..] {
+            [MarketDataEvent::Book { bids, asks, snapshot: false, update_id: 2, .. }] => {
+                assert_eq!(bids, &vec![(37000.0, 0.0)]);
+                assert_eq!(asks, &vec![(37002.0, 0.5)]);
+            }
+            events => panic!("unexpected events {:?}", events),
+        }
+
+        let trade = r#"{"type":"match","trade_id":1,"product_id":"BTC-USD","price":"37000.50","size":"0.01","side":"sell","time":"2023-11-14T22:13:20.000000Z"}"#;
+        assert!(matches!(&stream.parse(trade).unwrap()[..], [MarketDataEvent::Trade { aggressor: Some(OrderSide::Buy), .. }]));
+
+        assert!(stream.parse(r#"{"type":"heartbeat","product_id":"BTC-USD"}"#).unwrap().is_empty());
+        assert!(matches!(stream.parse(r#"{"type":"error","message":"Failed to subscribe"}"#), Err(ConnectorError::Rejected { .. })));
+    }
+}
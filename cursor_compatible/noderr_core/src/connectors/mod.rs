@@ -0,0 +1,869 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Live exchange connectivity
+//!
+//! A [`VenueConnector`] streams market data from a centralized exchange over
+//! WebSocket and submits, cancels and reconciles orders over its REST API.
+//! Streams reconnect with backoff and re-snapshot books on every connect, so
+//! a dropped socket costs a few hundred milliseconds of data rather than a
+//! corrupted book. The [`ConnectorHub`] fans connector events into the
+//! shared order books and the [`MarketDataProcessor`]; the
+//! [`SmartOrderRouter`](crate::order_router::SmartOrderRouter) sends orders
+//! for a connector's venue through it.
+
+mod binance;
+mod coinbase;
+
+pub use binance::{BinanceConnector, BINANCE_REST_URL, BINANCE_WS_URL};
+pub use coinbase::{CoinbaseConnector, COINBASE_REST_URL, COINBASE_WS_URL};
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, info, warn};
+
+use crate::market_data::{MarketDataProcessor, MarketTick};
+use crate::order_router::{Order, OrderSide};
+use crate::orderbook::{self, OrderBookManager};
+use crate::quote_freshness::QuoteFreshnessGuard;
+
+/// Errors raised by exchange connectors
+#[derive(Debug, Error)]
+pub enum ConnectorError {
+    #[error("HTTP request to {venue} failed: {message}")]
+    Http { venue: String, message: String },
+
+    #[error("{venue} rejected the request ({code}): {message}")]
+    Rejected { venue: String, code: String, message: String },
+
+    #[error("WebSocket error on {venue}: {message}")]
+    WebSocket { venue: String, message: String },
+
+    #[error("Failed to decode {venue} message: {message}")]
+    Decode { venue: String, message: String },
+
+    #[error("Book sequence gap on {venue} for {symbol}")]
+    SequenceGap { venue: String, symbol: String },
+
+    #[error("No API credentials configured for {0}")]
+    MissingCredentials(String),
+
+    #[error("Order not supported by {venue}: {message}")]
+    Unsupported { venue: String, message: String },
+
+    #[error("Connector hub is not running")]
+    NotRunning,
+}
+
+/// Result type for connector operations
+pub type ConnectorResult<T> = Result<T, ConnectorError>;
+
+/// Exchange connector configuration
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConnectorConfig {
+    /// Venue identifier used by the router
+    pub venue_id: String,
+    /// REST API base URL; the exchange's public endpoint when empty
+    pub rest_url: String,
+    /// WebSocket base URL; the exchange's public endpoint when empty
+    pub ws_url: String,
+    /// API key for trading endpoints
+    pub api_key: Option<String>,
+    /// API secret used to sign trading requests
+    pub api_secret: Option<String>,
+    /// API passphrase (Coinbase only)
+    pub passphrase: Option<String>,
+    /// REST request timeout (milliseconds)
+    pub request_timeout_ms: u64,
+    /// Signed requests are rejected by the venue after this long (milliseconds)
+    pub recv_window_ms: u64,
+    /// First reconnect delay after a stream drops (milliseconds)
+    pub reconnect_delay_ms: u64,
+    /// Reconnect delays double up to this (milliseconds)
+    pub max_reconnect_delay_ms: u64,
+    /// Cap on balance held plus resting order notional on this venue (quote
+    /// currency); the risk calculator's default limit applies when unset
+    pub exposure_limit: Option<f64>,
+    /// Assets whose balances count as capital held on this venue
+    pub settlement_assets: Vec<String>,
+}
+
+impl Default for ConnectorConfig {
+    fn default() -> Self {
+        Self {
+            venue_id: String::new(),
+            rest_url: String::new(),
+            ws_url: String::new(),
+            api_key: None,
+            api_secret: None,
+            passphrase: None,
+            request_timeout_ms: 5000,
+            recv_window_ms: 5000,
+            reconnect_delay_ms: 500,
+            max_reconnect_delay_ms: 30_000,
+            exposure_limit: None,
+            settlement_assets: vec!["USDT".to_string(), "USDC".to_string(), "USD".to_string()],
+        }
+    }
+}
+
+// Keeps credentials out of logs
+impl std::fmt::Debug for ConnectorConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = |value: &Option<String>| value.as_ref().map(|_| "<redacted>");
+        f.debug_struct("ConnectorConfig")
+            .field("venue_id", &self.venue_id)
+            .field("rest_url", &self.rest_url)
+            .field("ws_url", &self.ws_url)
+            .field("api_key", &redacted(&self.api_key))
+            .field("api_secret", &redacted(&self.api_secret))
+            .field("passphrase", &redacted(&self.passphrase))
+            .field("request_timeout_ms", &self.request_timeout_ms)
+            .field("recv_window_ms", &self.recv_window_ms)
+            .field("reconnect_delay_ms", &self.reconnect_delay_ms)
+            .field("max_reconnect_delay_ms", &self.max_reconnect_delay_ms)
+            .field("exposure_limit", &self.exposure_limit)
+            .field("settlement_assets", &self.settlement_assets)
+            .finish()
+    }
+}
+
+/// A `(price, size)` book level; a size of zero removes the level
+pub type LevelUpdate = (f64, f64);
+
+/// Normalized market data from a venue stream. Symbols use the router's
+/// `BASE/QUOTE` form regardless of the venue's own naming.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MarketDataEvent {
+    /// A public trade
+    Trade {
+        venue: String,
+        symbol: String,
+        price: f64,
+        size: f64,
+        /// Side of the aggressor, when the venue reports it
+        aggressor: Option<OrderSide>,
+        timestamp: DateTime<Utc>,
+    },
+    /// Best bid and offer
+    Quote {
+        venue: String,
+        symbol: String,
+        bid: f64,
+        bid_size: f64,
+        ask: f64,
+        ask_size: f64,
+        timestamp: DateTime<Utc>,
+    },
+    /// Book levels; a snapshot replaces the whole book, otherwise levels are applied as diffs
+    Book {
+        venue: String,
+        symbol: String,
+        bids: Vec<LevelUpdate>,
+        asks: Vec<LevelUpdate>,
+        snapshot: bool,
+        update_id: u64,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl MarketDataEvent {
+    /// Venue the event came from
+    pub fn venue(&self) -> &str {
+        match self {
+            MarketDataEvent::Trade { venue, .. }
+            | MarketDataEvent::Quote { venue, .. }
+            | MarketDataEvent::Book { venue, .. } => venue,
+        }
+    }
+
+    /// Symbol the event is for
+    pub fn symbol(&self) -> &str {
+        match self {
+            MarketDataEvent::Trade { symbol, .. }
+            | MarketDataEvent::Quote { symbol, .. }
+            | MarketDataEvent::Book { symbol, .. } => symbol,
+        }
+    }
+}
+
+/// Order state as reported by a venue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VenueOrderStatus {
+    /// Accepted and resting
+    New,
+    /// Resting with some quantity filled
+    PartiallyFilled,
+    /// Completely filled
+    Filled,
+    /// Cancelled, possibly after partial fills
+    Cancelled,
+    /// Refused by the venue
+    Rejected,
+    /// Expired by its time-in-force
+    Expired,
+}
+
+impl VenueOrderStatus {
+    /// Whether the order can still trade
+    pub fn is_open(&self) -> bool {
+        matches!(self, VenueOrderStatus::New | VenueOrderStatus::PartiallyFilled)
+    }
+}
+
+/// A venue's response to an order submission or cancel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderAck {
+    /// Venue the order was sent to
+    pub venue: String,
+    /// Symbol in `BASE/QUOTE` form
+    pub symbol: String,
+    /// Order ID assigned by the venue; used to cancel
+    pub venue_order_id: String,
+    /// Client order ID sent with the order, when the venue echoes one
+    pub client_order_id: Option<String>,
+    /// Order state
+    pub status: VenueOrderStatus,
+    /// Base quantity filled so far
+    pub filled_amount: f64,
+    /// Average fill price, if anything filled
+    pub average_price: Option<f64>,
+}
+
+/// Balance of one asset on a venue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VenueBalance {
+    /// Asset symbol
+    pub asset: String,
+    /// Available to trade or withdraw
+    pub free: f64,
+    /// Held by open orders
+    pub locked: f64,
+}
+
+impl VenueBalance {
+    /// Free plus locked
+    pub fn total(&self) -> f64 {
+        self.free + self.locked
+    }
+}
+
+/// Connection to a centralized exchange
+#[async_trait]
+pub trait VenueConnector: Send + Sync {
+    /// Venue identifier used by the router
+    fn venue_id(&self) -> &str;
+
+    /// Stream trades, quotes and books for `symbols` into `sink` until
+    /// [`disconnect`](Self::disconnect) is called or the receiver is dropped
+    async fn subscribe_market_data(&self, symbols: &[String], sink: mpsc::Sender<MarketDataEvent>) -> ConnectorResult<()>;
+
+    /// Stop streaming `symbols`; other symbols keep streaming
+    async fn unsubscribe_market_data(&self, symbols: &[String]) -> ConnectorResult<()>;
+
+    /// Submit an order
+    async fn submit_order(&self, order: &Order) -> ConnectorResult<OrderAck>;
+
+    /// Cancel an order by the ID the venue assigned it
+    async fn cancel_order(&self, symbol: &str, venue_order_id: &str) -> ConnectorResult<OrderAck>;
+
+    /// Balances with a non-zero total
+    async fn fetch_balances(&self) -> ConnectorResult<Vec<VenueBalance>>;
+
+    /// Stop all market data streams
+    async fn disconnect(&self);
+}
+
+/// Venue-specific half of a market data stream
+#[async_trait]
+pub(crate) trait StreamProtocol: Send + Sync + 'static {
+    /// Venue identifier
+    fn venue_id(&self) -> &str;
+
+    /// URL to connect to
+    fn url(&self) -> String;
+
+    /// Messages sent after connecting
+    fn subscribe_messages(&self) -> Vec<String>;
+
+    /// Events emitted after subscribing, such as REST book snapshots. Diffs
+    /// that arrive meanwhile stay buffered in the socket until this returns.
+    async fn on_connect(&self) -> ConnectorResult<Vec<MarketDataEvent>>;
+
+    /// Decode one text frame
+    fn parse(&self, text: &str) -> ConnectorResult<Vec<MarketDataEvent>>;
+}
+
+/// Running streams with the symbols and sink each was started for
+#[derive(Default)]
+pub(crate) struct StreamSet {
+    streams: Mutex<Vec<(Vec<String>, mpsc::Sender<MarketDataEvent>, JoinHandle<()>)>>,
+}
+
+impl StreamSet {
+    /// Track a started stream
+    pub(crate) fn add(&self, symbols: &[String], sink: mpsc::Sender<MarketDataEvent>, stream: JoinHandle<()>) {
+        self.streams.lock().unwrap().push((symbols.to_vec(), sink, stream));
+    }
+
+    /// Stop every stream carrying one of `symbols`, returning the other
+    /// symbols those streams carried, with their sinks, to be restarted
+    pub(crate) fn remove(&self, symbols: &[String]) -> Vec<(Vec<String>, mpsc::Sender<MarketDataEvent>)> {
+        let mut streams = self.streams.lock().unwrap();
+        let (stopped, running): (Vec<_>, Vec<_>) = streams.drain(..).partition(|(carried, _, _)| carried.iter().any(|s| symbols.contains(s)));
+        *streams = running;
+
+        stopped.into_iter()
+            .map(|(carried, sink, stream)| {
+                stream.abort();
+                (carried.into_iter().filter(|s| !symbols.contains(s)).collect(), sink)
+            })
+            .collect()
+    }
+
+    /// Stop every stream
+    pub(crate) fn clear(&self) {
+        for (_, _, stream) in self.streams.lock().unwrap().drain(..) {
+            stream.abort();
+        }
+    }
+}
+
+/// Run a stream until `sink` closes, reconnecting with exponential backoff
+pub(crate) fn spawn_stream<P: StreamProtocol>(
+    protocol: Arc<P>,
+    sink: mpsc::Sender<MarketDataEvent>,
+    config: &ConnectorConfig,
+) -> JoinHandle<()> {
+    let base_delay = Duration::from_millis(config.reconnect_delay_ms);
+    let max_delay = Duration::from_millis(config.max_reconnect_delay_ms.max(config.reconnect_delay_ms));
+
+    tokio::spawn(async move {
+        let mut delay = base_delay;
+        loop {
+            match run_session(protocol.as_ref(), &sink).await {
+                Ok(true) => break,
+                Ok(false) => {
+                    info!("{} market data stream closed; reconnecting", protocol.venue_id());
+                    delay = base_delay;
+                }
+                Err(e) => warn!("{} market data stream failed: {}; reconnecting in {:?}", protocol.venue_id(), e, delay),
+            }
+            if sink.is_closed() {
+                break;
+            }
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(max_delay);
+        }
+        debug!("{} market data stream stopped", protocol.venue_id());
+    })
+}
+
+/// One connection; `Ok(true)` when the consumer has gone away
+async fn run_session<P: StreamProtocol>(protocol: &P, sink: &mpsc::Sender<MarketDataEvent>) -> ConnectorResult<bool> {
+    let ws_error = |e: tokio_tungstenite::tungstenite::Error| ConnectorError::WebSocket {
+        venue: protocol.venue_id().to_string(),
+        message: e.to_string(),
+    };
+
+    let (mut socket, _) = connect_async(protocol.url()).await.map_err(ws_error)?;
+    for message in protocol.subscribe_messages() {
+        socket.send(Message::Text(message)).await.map_err(ws_error)?;
+    }
+    for event in protocol.on_connect().await? {
+        if sink.send(event).await.is_err() {
+            return Ok(true);
+        }
+    }
+    info!("Connected {} market data stream", protocol.venue_id());
+
+    // Pings are answered by tungstenite while the socket is being read
+    while let Some(message) = socket.next().await {
+        let text = match message.map_err(ws_error)? {
+            Message::Text(text) => text,
+            Message::Close(_) => return Ok(false),
+            _ => continue,
+        };
+
+        let events = match protocol.parse(&text) {
+            Ok(events) => events,
+            // A missed diff leaves the book wrong until the next snapshot, so take one now
+            Err(e @ ConnectorError::SequenceGap { .. }) => return Err(e),
+            Err(e) => {
+                warn!("Dropping {} message: {}", protocol.venue_id(), e);
+                continue;
+            }
+        };
+        for event in events {
+            if sink.send(event).await.is_err() {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Parse a decimal string as sent by exchange APIs
+pub(crate) fn parse_decimal(venue: &str, value: &str) -> ConnectorResult<f64> {
+    value.parse().map_err(|_| ConnectorError::Decode {
+        venue: venue.to_string(),
+        message: format!("invalid number '{}'", value),
+    })
+}
+
+/// Exchange timestamps are epoch milliseconds; out-of-range values fall back to now
+pub(crate) fn timestamp_from_millis(millis: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(millis).single().unwrap_or_else(Utc::now)
+}
+
+/// Parse `[price, size]` string pairs
+pub(crate) fn parse_levels(venue: &str, levels: &[[String; 2]]) -> ConnectorResult<Vec<LevelUpdate>> {
+    levels.iter()
+        .map(|[price, size]| Ok((parse_decimal(venue, price)?, parse_decimal(venue, size)?)))
+        .collect()
+}
+
+/// Format a quantity or price without float noise or trailing zeros
+pub(crate) fn format_decimal(value: f64) -> String {
+    let formatted = format!("{:.8}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Map a non-success REST response to an error, using the venue's message when it sends one
+pub(crate) async fn response_error(venue: &str, response: reqwest::Response) -> ConnectorError {
+    let status = response.status();
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+    let message = body.get("msg")
+        .or_else(|| body.get("message"))
+        .and_then(|m| m.as_str())
+        .unwrap_or("no error message")
+        .to_string();
+
+    // Rate limits and outages are retryable; anything else the venue refused outright
+    if status.is_server_error() || status.as_u16() == 429 || status.as_u16() == 418 {
+        ConnectorError::Http { venue: venue.to_string(), message: format!("{}: {}", status, message) }
+    } else {
+        let code = body.get("code").map(|c| c.to_string()).unwrap_or_else(|| status.as_u16().to_string());
+        ConnectorError::Rejected { venue: venue.to_string(), code, message }
+    }
+}
+
+/// Connector hub configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConnectorHubConfig {
+    /// Venue whose data feeds the shared books and ticks for a symbol;
+    /// symbols not listed use the first registered connector
+    pub primary_venues: HashMap<String, String>,
+    /// Buffered market data events before venue streams are slowed down
+    pub channel_capacity: usize,
+}
+
+impl Default for ConnectorHubConfig {
+    fn default() -> Self {
+        Self {
+            primary_venues: HashMap::new(),
+            channel_capacity: 10_000,
+        }
+    }
+}
+
+/// Fans connector market data into the shared order books and market data
+/// processor. Books and ticks are keyed by symbol alone, so each symbol takes
+/// its data from one primary venue; mixing venues would interleave diffs
+/// from unrelated books.
+pub struct ConnectorHub {
+    /// Configuration
+    config: ConnectorHubConfig,
+    /// Connectors in registration order
+    connectors: Vec<Arc<dyn VenueConnector>>,
+    /// Order books updated from primary venue books (optional)
+    order_books: Option<Arc<OrderBookManager>>,
+    /// Processor fed primary venue trades (optional)
+    market_data: Option<Arc<MarketDataProcessor>>,
+    /// Guard told when each venue last streamed data (optional)
+    freshness_guard: Option<Arc<QuoteFreshnessGuard>>,
+    /// Latest best bid and offer by symbol, attached to trade ticks
+    quotes: RwLock<HashMap<String, (f64, f64)>>,
+    /// Venue each subscribed symbol streams from
+    subscriptions: Mutex<HashMap<String, String>>,
+    /// Sender half of the event channel while running
+    sink: Mutex<Option<mpsc::Sender<MarketDataEvent>>>,
+    /// Consumer task
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl ConnectorHub {
+    /// Create a hub with no connectors
+    pub fn new(config: ConnectorHubConfig) -> Self {
+        Self {
+            config,
+            connectors: Vec::new(),
+            order_books: None,
+            market_data: None,
+            freshness_guard: None,
+            quotes: RwLock::new(HashMap::new()),
+            subscriptions: Mutex::new(HashMap::new()),
+            sink: Mutex::new(None),
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a connector
+    pub fn with_connector(mut self, connector: Arc<dyn VenueConnector>) -> Self {
+        self.connectors.push(connector);
+        self
+    }
+
+    /// Keep these order books in sync with primary venue books
+    pub fn with_order_books(mut self, order_books: Arc<OrderBookManager>) -> Self {
+        self.order_books = Some(order_books);
+        self
+    }
+
+    /// Feed primary venue trades to this processor
+    pub fn with_market_data(mut self, market_data: Arc<MarketDataProcessor>) -> Self {
+        self.market_data = Some(market_data);
+        self
+    }
+
+    /// Record quote and heartbeat times from every venue's stream in this guard
+    pub fn with_freshness_guard(mut self, guard: Arc<QuoteFreshnessGuard>) -> Self {
+        self.freshness_guard = Some(guard);
+        self
+    }
+
+    /// Registered connectors
+    pub fn connectors(&self) -> &[Arc<dyn VenueConnector>] {
+        &self.connectors
+    }
+
+    /// Connector for a venue
+    pub fn connector(&self, venue: &str) -> Option<Arc<dyn VenueConnector>> {
+        self.connectors.iter().find(|c| c.venue_id() == venue).cloned()
+    }
+
+    /// Venue whose data is used for a symbol
+    pub fn primary_venue(&self, symbol: &str) -> Option<&str> {
+        self.config.primary_venues
+            .get(symbol)
+            .map(String::as_str)
+            .or_else(|| self.connectors.first().map(|c| c.venue_id()))
+    }
+
+    /// Subscribe every connector to `symbols` and start applying events
+    pub async fn start(self: &Arc<Self>, symbols: &[String]) -> ConnectorResult<()> {
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            if !tasks.is_empty() {
+                return Ok(());
+            }
+
+            let (sink, mut events) = mpsc::channel(self.config.channel_capacity.max(1));
+            *self.sink.lock().unwrap() = Some(sink);
+            let hub = self.clone();
+            tasks.push(tokio::spawn(async move {
+                while let Some(event) = events.recv().await {
+                    hub.apply(&event);
+                }
+            }));
+        }
+
+        self.subscribe(symbols).await
+    }
+
+    /// Start streaming more symbols from their primary venues; symbols
+    /// already streaming are skipped
+    pub async fn subscribe(&self, symbols: &[String]) -> ConnectorResult<()> {
+        let sink = self.sink.lock().unwrap().clone().ok_or(ConnectorError::NotRunning)?;
+
+        for connector in &self.connectors {
+            let venue = connector.venue_id();
+            let venue_symbols: Vec<String> = {
+                let subscriptions = self.subscriptions.lock().unwrap();
+                symbols.iter()
+                    .filter(|symbol| self.primary_venue(symbol) == Some(venue) && !subscriptions.contains_key(*symbol))
+                    .cloned()
+                    .collect()
+            };
+            if venue_symbols.is_empty() {
+                continue;
+            }
+
+            connector.subscribe_market_data(&venue_symbols, sink.clone()).await?;
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            for symbol in &venue_symbols {
+                subscriptions.insert(symbol.clone(), venue.to_string());
+            }
+            info!("Subscribed {} to {}", venue, venue_symbols.join(", "));
+        }
+        Ok(())
+    }
+
+    /// Stop streaming symbols and forget their cached quotes
+    pub async fn unsubscribe(&self, symbols: &[String]) -> ConnectorResult<()> {
+        for connector in &self.connectors {
+            let venue = connector.venue_id();
+            let venue_symbols: Vec<String> = {
+                let subscriptions = self.subscriptions.lock().unwrap();
+                symbols.iter()
+                    .filter(|symbol| subscriptions.get(*symbol).is_some_and(|v| v == venue))
+                    .cloned()
+                    .collect()
+            };
+            if venue_symbols.is_empty() {
+                continue;
+            }
+
+            connector.unsubscribe_market_data(&venue_symbols).await?;
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            let mut quotes = self.quotes.write().unwrap();
+            for symbol in &venue_symbols {
+                subscriptions.remove(symbol);
+                quotes.remove(symbol);
+            }
+            info!("Unsubscribed {} from {}", venue, venue_symbols.join(", "));
+        }
+        Ok(())
+    }
+
+    /// Symbols currently streaming
+    pub fn subscribed_symbols(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self.subscriptions.lock().unwrap().keys().cloned().collect();
+        symbols.sort();
+        symbols
+    }
+
+    /// Stop every stream and the consumer task
+    pub async fn stop(&self) {
+        for connector in &self.connectors {
+            connector.disconnect().await;
+        }
+        self.subscriptions.lock().unwrap().clear();
+        self.sink.lock().unwrap().take();
+        let tasks: Vec<_> = self.tasks.lock().unwrap().drain(..).collect();
+        for task in tasks {
+            task.abort();
+        }
+    }
+
+    /// Apply one event; events from a symbol's non-primary venues only
+    /// refresh the freshness guard
+    pub fn apply(&self, event: &MarketDataEvent) {
+        // Any traffic shows the venue is alive; quotes and books date its prices
+        if let Some(guard) = &self.freshness_guard {
+            guard.record_heartbeat(event.venue(), Utc::now().timestamp_millis().max(0) as u64);
+            match event {
+                MarketDataEvent::Quote { timestamp, .. } | MarketDataEvent::Book { timestamp, .. } => {
+                    guard.record_quote(event.venue(), event.symbol(), timestamp.timestamp_millis().max(0) as u64);
+                }
+                MarketDataEvent::Trade { .. } => {}
+            }
+        }
+
+        if self.primary_venue(event.symbol()) != Some(event.venue()) {
+            return;
+        }
+
+        match event {
+            MarketDataEvent::Trade { symbol, price, size, timestamp, .. } => {
+                let Some(market_data) = &self.market_data else { return };
+                let quote = self.quotes.read().unwrap().get(symbol).copied();
+                let tick = MarketTick {
+                    symbol: symbol.clone(),
+                    timestamp: *timestamp,
+                    price: *price,
+                    volume: *size,
+                    bid: quote.map(|(bid, _)| bid),
+                    ask: quote.map(|(_, ask)| ask),
+                    fields: HashMap::new(),
+                };
+                if let Err(e) = market_data.process_tick(tick) {
+                    debug!("Dropped {} tick: {}", symbol, e);
+                }
+            }
+            MarketDataEvent::Quote { symbol, bid, ask, .. } => {
+                self.quotes.write().unwrap().insert(symbol.clone(), (*bid, *ask));
+            }
+            MarketDataEvent::Book { symbol, bids, asks, snapshot, update_id, .. } => {
+                let Some(books) = &self.order_books else { return };
+                if *snapshot {
+                    books.get_order_book(symbol).write().unwrap().clear();
+                }
+                let updates = bids.iter()
+                    .map(|&(price, size)| (price, size, orderbook::OrderSide::Bid, *update_id))
+                    .chain(asks.iter().map(|&(price, size)| (price, size, orderbook::OrderSide::Ask, *update_id)))
+                    .collect();
+                books.process_updates(symbol, updates);
+            }
+        }
+    }
+}
+
+/// Create a connector for a supported exchange (`binance` or `coinbase`);
+/// `config.venue_id` defaults to the exchange name
+pub fn create_venue_connector(exchange: &str, mut config: ConnectorConfig) -> Option<Arc<dyn VenueConnector>> {
+    if config.venue_id.is_empty() {
+        config.venue_id = exchange.to_string();
+    }
+    match exchange {
+        "binance" => Some(Arc::new(BinanceConnector::new(config))),
+        "coinbase" => Some(Arc::new(CoinbaseConnector::new(config))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::market_data::create_market_data_processor;
+    use crate::orderbook::create_order_book_manager;
+    use crate::quote_freshness::FreshnessVerdict;
+
+    struct IdleConnector(&'static str);
+
+    #[async_trait]
+    impl VenueConnector for IdleConnector {
+        fn venue_id(&self) -> &str {
+            self.0
+        }
+
+        async fn subscribe_market_data(&self, _symbols: &[String], _sink: mpsc::Sender<MarketDataEvent>) -> ConnectorResult<()> {
+            Ok(())
+        }
+
+        async fn unsubscribe_market_data(&self, _symbols: &[String]) -> ConnectorResult<()> {
+            Ok(())
+        }
+
+        async fn submit_order(&self, _order: &Order) -> ConnectorResult<OrderAck> {
+            Err(ConnectorError::MissingCredentials(self.0.to_string()))
+        }
+
+        async fn cancel_order(&self, _symbol: &str, _venue_order_id: &str) -> ConnectorResult<OrderAck> {
+            Err(ConnectorError::MissingCredentials(self.0.to_string()))
+        }
+
+        async fn fetch_balances(&self) -> ConnectorResult<Vec<VenueBalance>> {
+            Ok(Vec::new())
+        }
+
+        async fn disconnect(&self) {}
+    }
+
+    fn book(venue: &str, bids: Vec<LevelUpdate>, snapshot: bool, update_id: u64) -> MarketDataEvent {
+        MarketDataEvent::Book {
+            venue: venue.to_string(),
+            symbol: "BTC/USDT".to_string(),
+            bids,
+            asks: vec![(101.0, 1.0)],
+            snapshot,
+            update_id,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_hub_applies_primary_venue_events() {
+        let books = create_order_book_manager();
+        let market_data = create_market_data_processor();
+        let hub = ConnectorHub::new(ConnectorHubConfig::default())
+            .with_connector(Arc::new(IdleConnector("binance")))
+            .with_connector(Arc::new(IdleConnector("coinbase")))
+            .with_order_books(books.clone())
+            .with_market_data(market_data.clone());
+        assert_eq!(hub.primary_venue("BTC/USDT"), Some("binance"));
+
+        hub.apply(&book("binance", vec![(100.0, 2.0), (99.0, 1.0)], true, 1));
+        hub.apply(&book("binance", vec![(100.0, 0.0)], false, 2));
+        // Another venue's book would corrupt the primary one
+        hub.apply(&book("coinbase", vec![(100.5, 5.0)], true, 1));
+        assert_eq!(books.get_mid_price("BTC/USDT"), Some(100.0));
+
+        // A snapshot replaces levels the diffs never removed
+        hub.apply(&book("binance", vec![(98.0, 1.0)], true, 3));
+        let (bids, _) = books.get_snapshot("BTC/USDT", 10).unwrap();
+        assert_eq!(bids.iter().map(|l| l.price).collect::<Vec<_>>(), vec![98.0]);
+
+        hub.apply(&MarketDataEvent::Quote {
+            venue: "binance".to_string(),
+            symbol: "BTC/USDT".to_string(),
+            bid: 98.0,
+            bid_size: 1.0,
+            ask: 101.0,
+            ask_size: 1.0,
+            timestamp: Utc::now(),
+        });
+        hub.apply(&MarketDataEvent::Trade {
+            venue: "binance".to_string(),
+            symbol: "BTC/USDT".to_string(),
+            price: 100.0,
+            size: 0.5,
+            aggressor: Some(OrderSide::Buy),
+            timestamp: Utc::now(),
+        });
+        assert_eq!(market_data.traded_volume_since("BTC/USDT", Utc::now() - chrono::Duration::minutes(1)), 0.5);
+
+        assert_eq!(format_decimal(0.1 + 0.2), "0.3");
+        assert_eq!(format_decimal(25000.0), "25000");
+    }
+
+    #[test]
+    fn test_hub_feeds_freshness_guard_from_every_venue() {
+        let guard = Arc::new(QuoteFreshnessGuard::new(crate::quote_freshness::QuoteFreshnessConfig {
+            max_quote_age_ms: 60_000,
+            ..Default::default()
+        }));
+        let hub = ConnectorHub::new(ConnectorHubConfig::default())
+            .with_connector(Arc::new(IdleConnector("binance")))
+            .with_connector(Arc::new(IdleConnector("coinbase")))
+            .with_freshness_guard(guard.clone());
+        let order = Order {
+            symbol: "BTC/USDT".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 100.0,
+            venues: vec!["coinbase".to_string()],
+            id: "order1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: crate::order_router::TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+
+        // No quote yet: the venue is not assumed fresh
+        assert!(matches!(guard.check(&order, "coinbase"), FreshnessVerdict::Rejected { .. }));
+
+        // A non-primary venue's book still dates its prices
+        hub.apply(&book("coinbase", vec![(100.5, 5.0)], true, 1));
+        assert_eq!(guard.check(&order, "coinbase"), FreshnessVerdict::Fresh);
+        assert!(matches!(guard.check(&order, "kraken"), FreshnessVerdict::Rejected { .. }));
+    }
+}
@@ -0,0 +1,471 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Bounded on-disk dead-letter queue for Redis writes that failed.
+//!
+//! Failed sets and publishes are appended to a JSON-lines file instead of being
+//! dropped, and replayed once the backing store is reachable again.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use crate::redis::RedisClient;
+
+/// Errors raised by the dead-letter queue
+#[derive(Debug, Error)]
+pub enum DeadLetterError {
+    #[error("Dead-letter I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Dead-letter serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Result type for dead-letter operations
+pub type DeadLetterResult<T> = Result<T, DeadLetterError>;
+
+/// Dead-letter queue configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetterConfig {
+    /// Directory queue files are written to
+    pub dir: PathBuf,
+    /// Maximum entries kept per queue; the oldest are dropped beyond this
+    pub max_entries: usize,
+    /// Maximum entries replayed per attempt
+    pub replay_batch_size: usize,
+    /// How often the store is probed and pending entries replayed
+    pub replay_interval: Duration,
+}
+
+impl Default for DeadLetterConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("data/dead_letters"),
+            max_entries: 50_000,
+            replay_batch_size: 500,
+            replay_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Write that failed and can be replayed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum DeadLetterOp {
+    /// Set a key, optionally with a TTL
+    Set { key: String, value: Value, ttl_sec: Option<u64> },
+    /// Publish a message to a channel
+    Publish { channel: String, message: Value },
+    /// Append to a JSON list stored at a key, keeping the newest `max_len` items
+    AppendCapped { key: String, value: Value, max_len: usize, ttl_sec: Option<u64> },
+}
+
+/// Queued failed write
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Entry ID
+    pub id: String,
+    /// Write to replay
+    pub op: DeadLetterOp,
+    /// Error from the original attempt
+    pub error: String,
+    /// When the write first failed
+    pub failed_at: DateTime<Utc>,
+    /// Replay attempts so far
+    pub attempts: u32,
+}
+
+/// Dropped vs. recovered counts for a queue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeadLetterStats {
+    /// Queue name
+    pub name: String,
+    /// Entries currently waiting for replay
+    pub pending: usize,
+    /// Failed writes captured
+    pub enqueued: u64,
+    /// Entries replayed successfully
+    pub recovered: u64,
+    /// Entries lost because the queue was full
+    pub dropped: u64,
+    /// Replay attempts that failed
+    pub replay_failures: u64,
+}
+
+/// Store dead letters are replayed against
+#[async_trait]
+pub trait DeadLetterReplayer: Send + Sync {
+    /// Whether the store is reachable
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    /// Re-apply a failed write
+    async fn replay(&self, op: &DeadLetterOp) -> Result<(), String>;
+}
+
+/// Replays dead letters through a `RedisClient`
+pub struct RedisDeadLetterReplayer {
+    redis: Arc<dyn RedisClient>,
+}
+
+impl RedisDeadLetterReplayer {
+    /// Create a replayer for a Redis client
+    pub fn new(redis: Arc<dyn RedisClient>) -> Self {
+        Self { redis }
+    }
+}
+
+#[async_trait]
+impl DeadLetterReplayer for RedisDeadLetterReplayer {
+    async fn is_available(&self) -> bool {
+        self.redis.health_check().await.unwrap_or(false)
+    }
+
+    async fn replay(&self, op: &DeadLetterOp) -> Result<(), String> {
+        match op {
+            DeadLetterOp::Set { key, value, ttl_sec } => {
+                self.redis.set(key, value, *ttl_sec).await.map_err(|e| e.to_string())
+            }
+            DeadLetterOp::Publish { channel, message } => {
+                self.redis.publish(channel, message).await.map(|_| ()).map_err(|e| e.to_string())
+            }
+            DeadLetterOp::AppendCapped { key, value, max_len, ttl_sec } => {
+                let mut items: Vec<Value> = self.redis.get(key).await.map_err(|e| e.to_string())?.unwrap_or_default();
+                items.push(value.clone());
+                if items.len() > *max_len {
+                    let excess = items.len() - max_len;
+                    items.drain(..excess);
+                }
+                self.redis.set(key, &items, *ttl_sec).await.map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+/// Queue contents and the number of lines in its file
+struct QueueState {
+    entries: VecDeque<DeadLetter>,
+    file_lines: usize,
+}
+
+/// Bounded, file-backed queue of failed writes
+pub struct DeadLetterQueue {
+    /// Queue name, also the file stem
+    name: String,
+    /// Configuration
+    config: DeadLetterConfig,
+    /// Queue file path
+    path: PathBuf,
+    /// Pending entries
+    state: Mutex<QueueState>,
+    /// Failed writes captured
+    enqueued: AtomicU64,
+    /// Entries replayed successfully
+    recovered: AtomicU64,
+    /// Entries lost to overflow
+    dropped: AtomicU64,
+    /// Failed replay attempts
+    replay_failures: AtomicU64,
+    /// Replay task handle
+    task_handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl DeadLetterQueue {
+    /// Open a named queue, loading entries left over from a previous run
+    pub fn open(name: &str, config: DeadLetterConfig) -> DeadLetterResult<Self> {
+        fs::create_dir_all(&config.dir)?;
+        let path = config.dir.join(format!("{}.jsonl", name));
+
+        let mut entries = VecDeque::new();
+        let mut file_lines = 0;
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                let line = line?;
+                file_lines += 1;
+                match serde_json::from_str::<DeadLetter>(&line) {
+                    Ok(entry) => entries.push_back(entry),
+                    Err(e) => warn!("Skipping corrupt dead letter in {}: {}", path.display(), e),
+                }
+            }
+        }
+
+        let mut dropped = 0;
+        while entries.len() > config.max_entries {
+            entries.pop_front();
+            dropped += 1;
+        }
+        if !entries.is_empty() {
+            info!("Loaded {} dead letters from {}", entries.len(), path.display());
+        }
+
+        let queue = Self {
+            name: name.to_string(),
+            config,
+            path,
+            state: Mutex::new(QueueState { entries, file_lines }),
+            enqueued: AtomicU64::new(0),
+            recovered: AtomicU64::new(0),
+            dropped: AtomicU64::new(dropped),
+            replay_failures: AtomicU64::new(0),
+            task_handle: Mutex::new(None),
+        };
+        if dropped > 0 {
+            queue.rewrite(&queue.state.lock().unwrap())?;
+        }
+        Ok(queue)
+    }
+
+    /// Capture a failed write
+    pub fn push(&self, op: DeadLetterOp, error: impl ToString) {
+        let entry = DeadLetter {
+            id: uuid::Uuid::new_v4().to_string(),
+            op,
+            error: error.to_string(),
+            failed_at: Utc::now(),
+            attempts: 0,
+        };
+
+        let mut state = self.state.lock().unwrap();
+        self.enqueued.fetch_add(1, Ordering::Relaxed);
+
+        if state.entries.len() >= self.config.max_entries {
+            state.entries.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            warn!("Dead-letter queue {} full; dropped oldest entry", self.name);
+        }
+        state.entries.push_back(entry.clone());
+
+        // Append, compacting once the file carries too many replayed or dropped lines
+        let result = if state.file_lines >= self.config.max_entries.saturating_mul(2) {
+            self.rewrite(&state).map(|_| state.entries.len())
+        } else {
+            self.append(&entry).map(|_| state.file_lines + 1)
+        };
+        match result {
+            Ok(lines) => state.file_lines = lines,
+            Err(e) => error!("Failed to persist dead letter to {}: {}", self.path.display(), e),
+        }
+    }
+
+    /// Capture a failed set
+    pub fn push_set<T: Serialize>(&self, key: &str, value: &T, ttl_sec: Option<u64>, error: impl ToString) {
+        match serde_json::to_value(value) {
+            Ok(value) => self.push(DeadLetterOp::Set { key: key.to_string(), value, ttl_sec }, error),
+            Err(e) => error!("Cannot dead-letter write to {}: {}", key, e),
+        }
+    }
+
+    /// Capture a failed publish
+    pub fn push_publish<T: Serialize>(&self, channel: &str, message: &T, error: impl ToString) {
+        match serde_json::to_value(message) {
+            Ok(message) => self.push(DeadLetterOp::Publish { channel: channel.to_string(), message }, error),
+            Err(e) => error!("Cannot dead-letter publish to {}: {}", channel, e),
+        }
+    }
+
+    /// Capture a failed append to a capped list
+    pub fn push_append<T: Serialize>(&self, key: &str, value: &T, max_len: usize, ttl_sec: Option<u64>, error: impl ToString) {
+        match serde_json::to_value(value) {
+            Ok(value) => self.push(DeadLetterOp::AppendCapped { key: key.to_string(), value, max_len, ttl_sec }, error),
+            Err(e) => error!("Cannot dead-letter append to {}: {}", key, e),
+        }
+    }
+
+    /// Entries waiting for replay
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether nothing is waiting for replay
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Dropped vs. recovered counts
+    pub fn stats(&self) -> DeadLetterStats {
+        DeadLetterStats {
+            name: self.name.clone(),
+            pending: self.len(),
+            enqueued: self.enqueued.load(Ordering::Relaxed),
+            recovered: self.recovered.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            replay_failures: self.replay_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Replay up to one batch of entries in order, stopping at the first failure.
+    ///
+    /// Returns the number of entries recovered.
+    pub async fn replay(&self, replayer: &dyn DeadLetterReplayer) -> usize {
+        let batch: Vec<DeadLetter> = {
+            let state = self.state.lock().unwrap();
+            state.entries.iter().take(self.config.replay_batch_size).cloned().collect()
+        };
+
+        let mut recovered = Vec::new();
+        for entry in &batch {
+            match replayer.replay(&entry.op).await {
+                Ok(()) => recovered.push(entry.id.clone()),
+                Err(e) => {
+                    self.replay_failures.fetch_add(1, Ordering::Relaxed);
+                    debug!("Replay of dead letter {} failed: {}", entry.id, e);
+                    let mut state = self.state.lock().unwrap();
+                    if let Some(pending) = state.entries.iter_mut().find(|p| p.id == entry.id) {
+                        pending.attempts += 1;
+                    }
+                    break;
+                }
+            }
+        }
+
+        if !recovered.is_empty() {
+            let mut state = self.state.lock().unwrap();
+            state.entries.retain(|e| !recovered.contains(&e.id));
+            match self.rewrite(&state) {
+                Ok(()) => state.file_lines = state.entries.len(),
+                Err(e) => error!("Failed to compact dead letters in {}: {}", self.path.display(), e),
+            }
+            self.recovered.fetch_add(recovered.len() as u64, Ordering::Relaxed);
+            info!("Recovered {} dead letters from {} ({} pending)", recovered.len(), self.name, state.entries.len());
+        }
+
+        recovered.len()
+    }
+
+    /// Replay pending entries whenever the store is reachable
+    pub fn start(self: &Arc<Self>, replayer: Arc<dyn DeadLetterReplayer>) {
+        let mut handle = self.task_handle.lock().unwrap();
+        if handle.is_some() {
+            return;
+        }
+
+        let queue = Arc::clone(self);
+        *handle = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(queue.config.replay_interval);
+            let mut available = true;
+            loop {
+                interval.tick().await;
+                if queue.is_empty() {
+                    continue;
+                }
+
+                let now_available = replayer.is_available().await;
+                if now_available && !available {
+                    info!("Store reachable again; replaying {} dead letters from {}", queue.len(), queue.name);
+                }
+                available = now_available;
+
+                // Drain while the store keeps accepting writes
+                while available && queue.replay(replayer.as_ref()).await > 0 {}
+            }
+        }));
+    }
+
+    /// Stop the replay task
+    pub fn stop(&self) {
+        if let Some(handle) = self.task_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    fn append(&self, entry: &DeadLetter) -> DeadLetterResult<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    fn rewrite(&self, state: &QueueState) -> DeadLetterResult<()> {
+        let tmp = self.path.with_extension("jsonl.tmp");
+        {
+            let mut file = File::create(&tmp)?;
+            for entry in &state.entries {
+                writeln!(file, "{}", serde_json::to_string(entry)?)?;
+            }
+            file.sync_all()?;
+        }
+        fs::rename(tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// Open a dead-letter queue
+pub fn create_dead_letter_queue(name: &str, config: DeadLetterConfig) -> DeadLetterResult<Arc<DeadLetterQueue>> {
+    Ok(Arc::new(DeadLetterQueue::open(name, config)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FlakyStore {
+        fail: std::sync::atomic::AtomicBool,
+        applied: Mutex<Vec<DeadLetterOp>>,
+    }
+
+    #[async_trait]
+    impl DeadLetterReplayer for FlakyStore {
+        async fn replay(&self, op: &DeadLetterOp) -> Result<(), String> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err("connection refused".to_string());
+            }
+            self.applied.lock().unwrap().push(op.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_persist_overflow_and_replay() {
+        let config = DeadLetterConfig {
+            dir: std::env::temp_dir().join(format!("noderr-dlq-{}", uuid::Uuid::new_v4())),
+            max_entries: 2,
+            ..DeadLetterConfig::default()
+        };
+        let set = |n: i64| DeadLetterOp::Set { key: format!("k{}", n), value: Value::from(n), ttl_sec: None };
+
+        let queue = DeadLetterQueue::open("telemetry", config.clone()).unwrap();
+        for n in 0..3 {
+            queue.push(set(n), "timeout");
+        }
+        assert_eq!(queue.stats().dropped, 1);
+
+        // Survives a restart
+        let queue = DeadLetterQueue::open("telemetry", config.clone()).unwrap();
+        assert_eq!(queue.len(), 2);
+
+        let store = FlakyStore { fail: true.into(), applied: Mutex::new(Vec::new()) };
+        assert_eq!(queue.replay(&store).await, 0);
+        store.fail.store(false, Ordering::SeqCst);
+        assert_eq!(queue.replay(&store).await, 2);
+        assert_eq!(*store.applied.lock().unwrap(), vec![set(1), set(2)]);
+
+        let stats = queue.stats();
+        assert_eq!((stats.pending, stats.recovered, stats.replay_failures), (0, 2, 1));
+        fs::remove_dir_all(config.dir).ok();
+    }
+}
@@ -0,0 +1,448 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Decentralized execution venues: swaps quoted through on-chain router
+//! contracts, submitted with slippage and deadline bounds, and tracked until
+//! confirmed. Gas is converted into basis points so DEX routes can be scored
+//! alongside centralized venue fees.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+use crate::chain_data::{ChainDataMonitor, ChainMetricKind};
+use crate::order_router::{Order, OrderSide};
+
+/// Errors raised by DEX venues
+#[derive(Debug, Error)]
+pub enum DexVenueError {
+    #[error("Quote failed on {venue}: {message}")]
+    Quote { venue: String, message: String },
+
+    #[error("Swap submission failed on {venue}: {message}")]
+    Submit { venue: String, message: String },
+
+    #[error("Quoted price {quoted} is outside the slippage bound {limit}")]
+    SlippageExceeded { quoted: f64, limit: f64 },
+
+    #[error("Swap reverted: {0}")]
+    Reverted(String),
+
+    #[error("Swap ran out of gas: {0}")]
+    OutOfGas(String),
+
+    #[error("Swap {0} was not confirmed before its deadline")]
+    ConfirmationTimeout(String),
+}
+
+/// Result type for DEX venue operations
+pub type DexVenueResult<T> = Result<T, DexVenueError>;
+
+/// Quote returned by a router contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DexQuote {
+    /// Tokens sent into the swap
+    pub amount_in: f64,
+    /// Tokens expected out of the swap
+    pub amount_out: f64,
+    /// Effective price in quote currency per base unit
+    pub price: f64,
+    /// Price impact of the swap in basis points
+    pub price_impact_bps: f64,
+    /// Estimated gas units for the swap
+    pub gas_estimate: u64,
+    /// Pools the swap routes through
+    pub route: Vec<String>,
+}
+
+/// Swap ready for submission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DexSwap {
+    /// Order the swap executes
+    pub order_id: String,
+    /// Symbol being traded
+    pub symbol: String,
+    /// Buy or sell of the base token
+    pub side: OrderSide,
+    /// Quote the bounds were derived from
+    pub quote: DexQuote,
+    /// Minimum tokens out; the router reverts below this
+    pub min_amount_out: f64,
+    /// Time after which the router rejects the swap
+    pub deadline: DateTime<Utc>,
+}
+
+/// On-chain status of a submitted swap
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum DexTxStatus {
+    /// Broadcast but not yet mined
+    Pending,
+    /// Mined with the given number of confirmations
+    Mined {
+        confirmations: u32,
+        amount_out: f64,
+        gas_used: u64,
+        gas_price_gwei: f64,
+    },
+    /// Mined but reverted
+    Reverted { reason: String },
+    /// Ran out of gas
+    OutOfGas,
+    /// Dropped from the mempool
+    Dropped,
+}
+
+/// Access to a DEX router contract over RPC
+#[async_trait]
+pub trait DexRouterClient: Send + Sync {
+    /// Quote a swap of `amount` base units
+    async fn quote(&self, symbol: &str, side: OrderSide, amount: f64) -> DexVenueResult<DexQuote>;
+
+    /// Sign and broadcast a swap, returning its transaction hash
+    async fn submit_swap(&self, swap: &DexSwap) -> DexVenueResult<String>;
+
+    /// Current status of a transaction
+    async fn transaction_status(&self, tx_hash: &str) -> DexVenueResult<DexTxStatus>;
+
+    /// Current gas price in gwei
+    async fn gas_price_gwei(&self) -> DexVenueResult<f64>;
+}
+
+/// DEX venue configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DexVenueConfig {
+    /// Venue identifier used by the router
+    pub venue_id: String,
+    /// Chain the router contract lives on
+    pub chain: String,
+    /// Slippage bound used when an order does not set one (basis points)
+    pub default_max_slippage_bps: f64,
+    /// Seconds until a submitted swap's deadline
+    pub deadline_secs: i64,
+    /// Confirmations required before a swap counts as filled
+    pub confirmations_required: u32,
+    /// How often transaction status is polled (milliseconds)
+    pub status_poll_interval_ms: u64,
+    /// Price of the chain's native token in quote currency, for gas costs
+    pub native_token_price: f64,
+}
+
+impl Default for DexVenueConfig {
+    fn default() -> Self {
+        Self {
+            venue_id: "uniswap".to_string(),
+            chain: "ethereum".to_string(),
+            default_max_slippage_bps: 50.0,
+            deadline_secs: 120,
+            confirmations_required: 2,
+            status_poll_interval_ms: 2000,
+            native_token_price: 0.0,
+        }
+    }
+}
+
+/// A swap and its latest known status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedSwap {
+    /// Submitted swap
+    pub swap: DexSwap,
+    /// Transaction hash
+    pub tx_hash: String,
+    /// Latest status
+    pub status: DexTxStatus,
+    /// When the swap was broadcast
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Filled swap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DexFill {
+    /// Transaction hash
+    pub tx_hash: String,
+    /// Tokens received
+    pub amount_out: f64,
+    /// Effective price in quote currency per base unit
+    pub price: f64,
+    /// Gas paid, in quote currency
+    pub gas_cost: f64,
+    /// Confirmations at the time the fill was accepted
+    pub confirmations: u32,
+}
+
+/// Connector executing router orders on a DEX
+pub struct DexVenueConnector {
+    /// Venue identifier used by the router
+    venue_id: String,
+    /// Configuration
+    config: RwLock<DexVenueConfig>,
+    /// Router contract client
+    client: Arc<dyn DexRouterClient>,
+    /// Gas prices observed on-chain (optional)
+    chain_data: Option<Arc<ChainDataMonitor>>,
+    /// Swaps by order ID
+    swaps: RwLock<HashMap<String, TrackedSwap>>,
+}
+
+impl DexVenueConnector {
+    /// Create a connector
+    pub fn new(config: DexVenueConfig, client: Arc<dyn DexRouterClient>) -> Self {
+        Self {
+            venue_id: config.venue_id.clone(),
+            config: RwLock::new(config),
+            client,
+            chain_data: None,
+            swaps: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Read gas prices from the chain data monitor instead of the router client
+    pub fn with_chain_data(mut self, chain_data: Arc<ChainDataMonitor>) -> Self {
+        self.chain_data = Some(chain_data);
+        self
+    }
+
+    /// Venue identifier
+    pub fn venue_id(&self) -> &str {
+        &self.venue_id
+    }
+
+    /// Update the native token price used for gas costs
+    pub async fn set_native_token_price(&self, price: f64) {
+        self.config.write().await.native_token_price = price;
+    }
+
+    /// Current gas price, preferring the chain data monitor's latest sample
+    pub async fn gas_price_gwei(&self) -> DexVenueResult<f64> {
+        if let Some(chain_data) = &self.chain_data {
+            let chain = self.config.read().await.chain.clone();
+            if let Some(sample) = chain_data.latest(ChainMetricKind::GasPrice, &chain).await {
+                return Ok(sample.value);
+            }
+        }
+        self.client.gas_price_gwei().await
+    }
+
+    /// Gas cost of a swap in quote currency
+    pub async fn gas_cost(&self, gas_units: u64) -> DexVenueResult<f64> {
+        let gas_price = self.gas_price_gwei().await?;
+        let native_price = self.config.read().await.native_token_price;
+        Ok(gas_units as f64 * gas_price * 1e-9 * native_price)
+    }
+
+    /// Gas cost of a swap as basis points of its notional, for venue fee scoring
+    pub async fn gas_cost_bps(&self, gas_units: u64, notional: f64) -> DexVenueResult<f64> {
+        if notional <= 0.0 {
+            return Ok(0.0);
+        }
+        Ok(self.gas_cost(gas_units).await? / notional * 10_000.0)
+    }
+
+    /// Gas cost of swapping `amount` of `symbol` now, as basis points of the
+    /// quoted notional
+    pub async fn swap_gas_cost_bps(&self, symbol: &str, side: OrderSide, amount: f64) -> DexVenueResult<f64> {
+        let quote = self.client.quote(symbol, side, amount).await?;
+        self.gas_cost_bps(quote.gas_estimate, quote.price * amount).await
+    }
+
+    /// Quote an order and derive its slippage and deadline bounds
+    pub async fn prepare_swap(&self, order: &Order, now: DateTime<Utc>) -> DexVenueResult<DexSwap> {
+        let config = self.config.read().await.clone();
+        let quote = self.client.quote(&order.symbol, order.side, order.amount).await?;
+
+        // Order slippage is a percentage; the config default is in basis points
+        let slippage = order
+            .max_slippage
+            .map(|pct| pct / 100.0)
+            .unwrap_or(config.default_max_slippage_bps / 10_000.0);
+
+        if order.price > 0.0 {
+            let limit = match order.side {
+                OrderSide::Buy => order.price * (1.0 + slippage),
+                OrderSide::Sell => order.price * (1.0 - slippage),
+            };
+            let outside = match order.side {
+                OrderSide::Buy => quote.price > limit,
+                OrderSide::Sell => quote.price < limit,
+            };
+            if outside {
+                return Err(DexVenueError::SlippageExceeded { quoted: quote.price, limit });
+            }
+        }
+
+        Ok(DexSwap {
+            order_id: order.id.clone(),
+            symbol: order.symbol.clone(),
+            side: order.side,
+            min_amount_out: quote.amount_out * (1.0 - slippage),
+            deadline: now + chrono::Duration::seconds(config.deadline_secs),
+            quote,
+        })
+    }
+
+    /// Quote, submit and wait for an order's swap to confirm
+    pub async fn execute(&self, order: &Order) -> DexVenueResult<DexFill> {
+        let swap = self.prepare_swap(order, Utc::now()).await?;
+        let tx_hash = self.client.submit_swap(&swap).await?;
+        info!("Submitted swap {} for order {} on {}", tx_hash, order.id, self.venue_id);
+
+        self.swaps.write().await.insert(order.id.clone(), TrackedSwap {
+            swap: swap.clone(),
+            tx_hash: tx_hash.clone(),
+            status: DexTxStatus::Pending,
+            submitted_at: Utc::now(),
+        });
+
+        self.await_confirmation(&swap, &tx_hash).await
+    }
+
+    /// Poll a transaction until it has enough confirmations, fails, or passes its deadline
+    async fn await_confirmation(&self, swap: &DexSwap, tx_hash: &str) -> DexVenueResult<DexFill> {
+        let (required, poll_ms) = {
+            let config = self.config.read().await;
+            (config.confirmations_required, config.status_poll_interval_ms.max(1))
+        };
+
+        loop {
+            let status = self.client.transaction_status(tx_hash).await?;
+            if let Some(tracked) = self.swaps.write().await.get_mut(&swap.order_id) {
+                tracked.status = status.clone();
+            }
+
+            match status {
+                DexTxStatus::Mined { confirmations, amount_out, gas_used, gas_price_gwei } if confirmations >= required => {
+                    let native_price = self.config.read().await.native_token_price;
+                    let gas_cost = gas_used as f64 * gas_price_gwei * 1e-9 * native_price;
+                    let price = match swap.side {
+                        OrderSide::Buy if amount_out > 0.0 => swap.quote.amount_in / amount_out,
+                        OrderSide::Sell if swap.quote.amount_in > 0.0 => amount_out / swap.quote.amount_in,
+                        _ => swap.quote.price,
+                    };
+                    return Ok(DexFill { tx_hash: tx_hash.to_string(), amount_out, price, gas_cost, confirmations });
+                }
+                DexTxStatus::Reverted { reason } => return Err(DexVenueError::Reverted(reason)),
+                DexTxStatus::OutOfGas => return Err(DexVenueError::OutOfGas(tx_hash.to_string())),
+                DexTxStatus::Dropped => return Err(DexVenueError::ConfirmationTimeout(tx_hash.to_string())),
+                DexTxStatus::Pending | DexTxStatus::Mined { .. } => {
+                    // Mined before the deadline still counts; anything unmined after it is void
+                    if matches!(status, DexTxStatus::Pending) && Utc::now() > swap.deadline {
+                        warn!("Swap {} for order {} missed its deadline", tx_hash, swap.order_id);
+                        return Err(DexVenueError::ConfirmationTimeout(tx_hash.to_string()));
+                    }
+                    debug!("Swap {} awaiting confirmations", tx_hash);
+                    tokio::time::sleep(Duration::from_millis(poll_ms)).await;
+                }
+            }
+        }
+    }
+
+    /// Latest status of an order's swap
+    pub async fn swap_status(&self, order_id: &str) -> Option<TrackedSwap> {
+        self.swaps.read().await.get(order_id).cloned()
+    }
+}
+
+/// Create a DEX venue connector
+pub fn create_dex_venue_connector(config: DexVenueConfig, client: Arc<dyn DexRouterClient>) -> Arc<DexVenueConnector> {
+    Arc::new(DexVenueConnector::new(config, client))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct MockRouter {
+        price: f64,
+        polls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl DexRouterClient for MockRouter {
+        async fn quote(&self, _symbol: &str, side: OrderSide, amount: f64) -> DexVenueResult<DexQuote> {
+            let (amount_in, amount_out) = match side {
+                OrderSide::Buy => (amount * self.price, amount),
+                OrderSide::Sell => (amount, amount * self.price),
+            };
+            Ok(DexQuote { amount_in, amount_out, price: self.price, price_impact_bps: 5.0, gas_estimate: 150_000, route: vec![] })
+        }
+
+        async fn submit_swap(&self, _swap: &DexSwap) -> DexVenueResult<String> {
+            Ok("0xabc".to_string())
+        }
+
+        async fn transaction_status(&self, _tx_hash: &str) -> DexVenueResult<DexTxStatus> {
+            let confirmations = self.polls.fetch_add(1, Ordering::SeqCst);
+            Ok(DexTxStatus::Mined { confirmations, amount_out: 1.0, gas_used: 150_000, gas_price_gwei: 20.0 })
+        }
+
+        async fn gas_price_gwei(&self) -> DexVenueResult<f64> {
+            Ok(20.0)
+        }
+    }
+
+    fn order(price: f64, max_slippage: Option<f64>) -> Order {
+        Order {
+            symbol: "ETH/USDC".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price,
+            venues: vec!["uniswap".to_string()],
+            id: "order-1".to_string(),
+            max_slippage,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: Default::default(),
+            additional_params: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slippage_bound_and_confirmation() {
+        let config = DexVenueConfig { status_poll_interval_ms: 1, native_token_price: 2000.0, ..DexVenueConfig::default() };
+        let connector = create_dex_venue_connector(config, Arc::new(MockRouter { price: 2010.0, polls: AtomicU32::new(0) }));
+
+        // 0.2% above the limit price exceeds a 0.1% bound
+        assert!(matches!(
+            connector.prepare_swap(&order(2006.0, Some(0.1)), Utc::now()).await,
+            Err(DexVenueError::SlippageExceeded { .. })
+        ));
+
+        let fill = connector.execute(&order(2006.0, Some(0.5))).await.unwrap();
+        assert_eq!(fill.confirmations, 2);
+        assert!((fill.gas_cost - 6.0).abs() < 1e-9);
+        assert!((connector.gas_cost_bps(150_000, 2010.0).await.unwrap() - 6.0 / 2010.0 * 10_000.0).abs() < 1e-9);
+        assert!(matches!(connector.swap_status("order-1").await.unwrap().status, DexTxStatus::Mined { confirmations: 2, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_swap_gas_cost_bps_uses_quoted_gas() {
+        let config = DexVenueConfig { native_token_price: 2000.0, ..DexVenueConfig::default() };
+        let connector = create_dex_venue_connector(config, Arc::new(MockRouter { price: 2010.0, polls: AtomicU32::new(0) }));
+
+        // 150k gas at 20 gwei and $2000 is $6 on a $4020 swap
+        let bps = connector.swap_gas_cost_bps("ETH/USDC", OrderSide::Buy, 2.0).await.unwrap();
+        assert!((bps - 6.0 / 4020.0 * 10_000.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,586 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Forward-looking drawdown forecasts.
+//!
+//! For each agent the forecaster combines current exposures from the
+//! position manager, realized volatility from recent prices and the detected
+//! market regime into a portfolio return process, then simulates equity paths
+//! over the next `horizon_hours` to obtain the distribution of maximum
+//! drawdown. Forecasts are broadcast to subscribers and handed to the
+//! [`DrawdownMonitor`] so its kill switch can escalate before a breach
+//! materializes rather than after.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::drawdown_monitor::DrawdownMonitor;
+use crate::market::Symbol;
+use crate::market_regime::{MarketRegimeDetector, MarketRegimeState};
+use crate::position::{PositionError, PositionManager};
+use crate::simulation::latency_model::standard_normal;
+use crate::telemetry::TelemetryReporter;
+
+/// Errors raised while forecasting drawdowns
+#[derive(Debug, Error)]
+pub enum DrawdownForecastError {
+    #[error("Position error: {0}")]
+    Position(#[from] PositionError),
+
+    #[error("Agent {0} has no positive equity to forecast")]
+    NoEquity(String),
+}
+
+/// Volatility and drift adjustment applied under a regime
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegimeAdjustment {
+    /// Multiplier on realized volatility
+    pub volatility_multiplier: f64,
+    /// Expected hourly return of a long position
+    pub hourly_drift: f64,
+}
+
+impl RegimeAdjustment {
+    /// Create a new adjustment
+    pub fn new(volatility_multiplier: f64, hourly_drift: f64) -> Self {
+        Self { volatility_multiplier, hourly_drift }
+    }
+}
+
+/// Probability thresholds at which a forecast escalates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForecastEscalationPolicy {
+    /// Breach probability that raises a preemptive drawdown alert
+    pub alert_probability: f64,
+    /// Breach probability that triggers the kill switch preemptively
+    pub kill_probability: f64,
+}
+
+impl Default for ForecastEscalationPolicy {
+    fn default() -> Self {
+        Self {
+            alert_probability: 0.25,
+            kill_probability: 0.6,
+        }
+    }
+}
+
+/// Escalation a forecast led to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForecastEscalation {
+    /// Breach probability below every threshold
+    None,
+    /// Preemptive drawdown alert raised
+    Alert,
+    /// Kill switch triggered preemptively
+    KillSwitch,
+}
+
+impl ForecastEscalationPolicy {
+    /// Escalation warranted by a breach probability
+    pub fn escalation_for(&self, breach_probability: f64) -> ForecastEscalation {
+        if breach_probability >= self.kill_probability {
+            ForecastEscalation::KillSwitch
+        } else if breach_probability >= self.alert_probability {
+            ForecastEscalation::Alert
+        } else {
+            ForecastEscalation::None
+        }
+    }
+}
+
+/// Configuration for [`DrawdownForecaster`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrawdownForecastConfig {
+    /// Forecast horizon in hours
+    pub horizon_hours: u32,
+    /// Simulation steps per hour
+    pub steps_per_hour: u32,
+    /// Number of simulated equity paths
+    pub simulations: usize,
+    /// Price observations kept per symbol for realized volatility
+    pub volatility_lookback: usize,
+    /// Observations required before realized volatility is trusted
+    pub min_observations: usize,
+    /// Hourly volatility assumed for symbols without enough history
+    pub default_hourly_volatility: f64,
+    /// Pairwise correlation assumed between symbols
+    pub correlation: f64,
+    /// Drawdown treated as a breach, matching the monitor's limit
+    pub max_drawdown_pct: f64,
+    /// Adjustments by regime; regimes not listed are left unadjusted
+    pub regime_adjustments: HashMap<MarketRegimeState, RegimeAdjustment>,
+    /// Escalation thresholds applied through the drawdown monitor
+    pub escalation: ForecastEscalationPolicy,
+    /// Interval between forecasts in seconds
+    pub interval_secs: u64,
+    /// Seed for reproducible simulations
+    pub seed: Option<u64>,
+}
+
+impl Default for DrawdownForecastConfig {
+    fn default() -> Self {
+        let mut regime_adjustments = HashMap::new();
+        regime_adjustments.insert(MarketRegimeState::Bull, RegimeAdjustment::new(0.9, 0.0002));
+        regime_adjustments.insert(MarketRegimeState::Bear, RegimeAdjustment::new(1.3, -0.0004));
+        regime_adjustments.insert(MarketRegimeState::Sideways, RegimeAdjustment::new(0.8, 0.0));
+        regime_adjustments.insert(MarketRegimeState::Volatile, RegimeAdjustment::new(1.8, -0.0002));
+
+        Self {
+            horizon_hours: 24,
+            steps_per_hour: 4,
+            simulations: 2000,
+            volatility_lookback: 500,
+            min_observations: 20,
+            default_hourly_volatility: 0.01,
+            correlation: 0.5,
+            max_drawdown_pct: 0.10,
+            regime_adjustments,
+            escalation: ForecastEscalationPolicy::default(),
+            interval_secs: 300,
+            seed: None,
+        }
+    }
+}
+
+/// One symbol's contribution to a forecast
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureForecast {
+    /// Symbol held
+    pub symbol: Symbol,
+    /// Signed notional exposure
+    pub notional: f64,
+    /// Realized hourly volatility before regime adjustment
+    pub realized_hourly_volatility: f64,
+    /// Regime in force for the symbol, if detected
+    pub regime: Option<MarketRegimeState>,
+    /// Hourly volatility after regime adjustment
+    pub hourly_volatility: f64,
+}
+
+/// Forecast drawdown distribution for one agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawdownForecast {
+    /// Agent the forecast concerns
+    pub agent_id: String,
+    /// When the forecast was produced
+    pub generated_at: DateTime<Utc>,
+    /// Horizon covered, in hours
+    pub horizon_hours: u32,
+    /// Current equity
+    pub equity: f64,
+    /// Peak equity drawdowns are measured from
+    pub peak_equity: f64,
+    /// Drawdown already incurred
+    pub current_drawdown_pct: f64,
+    /// Mean of the simulated maximum drawdown
+    pub expected_drawdown_pct: f64,
+    /// Median simulated maximum drawdown
+    pub p50_drawdown_pct: f64,
+    /// 95th percentile simulated maximum drawdown
+    pub p95_drawdown_pct: f64,
+    /// 99th percentile simulated maximum drawdown
+    pub p99_drawdown_pct: f64,
+    /// Fraction of paths reaching the configured drawdown limit
+    pub breach_probability: f64,
+    /// Hourly volatility of the portfolio return process
+    pub portfolio_hourly_volatility: f64,
+    /// Hourly drift of the portfolio return process
+    pub portfolio_hourly_drift: f64,
+    /// Per-symbol inputs
+    pub exposures: Vec<ExposureForecast>,
+}
+
+/// Realized hourly volatility from timestamped prices, if enough data is available
+pub fn realized_hourly_volatility(prices: &VecDeque<(DateTime<Utc>, f64)>, min_observations: usize) -> Option<f64> {
+    if prices.len() < min_observations.max(2) {
+        return None;
+    }
+    let mut sum_sq = 0.0;
+    let mut hours = 0.0;
+    for (prev, next) in prices.iter().zip(prices.iter().skip(1)) {
+        if prev.1 <= 0.0 || next.1 <= 0.0 {
+            continue;
+        }
+        let r = (next.1 / prev.1).ln();
+        sum_sq += r * r;
+        hours += (next.0 - prev.0).num_milliseconds().max(0) as f64 / 3_600_000.0;
+    }
+    (hours > 0.0).then(|| (sum_sq / hours).sqrt())
+}
+
+/// Simulated maximum drawdowns, one per path, measured from `peak_equity`
+fn simulate_max_drawdowns(
+    rng: &mut StdRng,
+    equity: f64,
+    peak_equity: f64,
+    hourly_drift: f64,
+    hourly_volatility: f64,
+    config: &DrawdownForecastConfig,
+) -> Vec<f64> {
+    let steps = (config.horizon_hours * config.steps_per_hour.max(1)) as usize;
+    let dt = 1.0 / config.steps_per_hour.max(1) as f64;
+    let step_drift = (hourly_drift - 0.5 * hourly_volatility * hourly_volatility) * dt;
+    let step_vol = hourly_volatility * dt.sqrt();
+
+    (0..config.simulations.max(1))
+        .map(|_| {
+            let mut value = equity;
+            let mut peak = peak_equity.max(equity);
+            let mut max_drawdown = 1.0 - value / peak;
+            for _ in 0..steps {
+                value *= (step_drift + step_vol * standard_normal(rng)).exp();
+                peak = peak.max(value);
+                max_drawdown = max_drawdown.max(1.0 - value / peak);
+            }
+            max_drawdown
+        })
+        .collect()
+}
+
+/// Value at quantile `q` of sorted samples
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let index = ((sorted.len() - 1) as f64 * q).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+/// Forecasts drawdown distributions and publishes them
+pub struct DrawdownForecaster {
+    /// Configuration
+    config: DrawdownForecastConfig,
+    /// Source of exposures and cash balances
+    position_manager: Arc<PositionManager>,
+    /// Optional regime detector
+    regime_detector: Option<Arc<dyn MarketRegimeDetector>>,
+    /// Optional drawdown monitor the forecasts are escalated through
+    drawdown_monitor: Option<Arc<DrawdownMonitor>>,
+    /// Optional telemetry reporter
+    telemetry: Option<Arc<TelemetryReporter>>,
+    /// Recent prices by symbol
+    prices: RwLock<HashMap<Symbol, VecDeque<(DateTime<Utc>, f64)>>>,
+    /// Latest forecast by agent
+    latest: RwLock<HashMap<String, DrawdownForecast>>,
+    /// Forecast broadcast
+    sender: broadcast::Sender<DrawdownForecast>,
+    /// Simulation random source
+    rng: RwLock<StdRng>,
+    /// Background forecasting task
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl DrawdownForecaster {
+    /// Create a new forecaster
+    pub fn new(config: DrawdownForecastConfig, position_manager: Arc<PositionManager>) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let (sender, _) = broadcast::channel(256);
+        Self {
+            config,
+            position_manager,
+            regime_detector: None,
+            drawdown_monitor: None,
+            telemetry: None,
+            prices: RwLock::new(HashMap::new()),
+            latest: RwLock::new(HashMap::new()),
+            sender,
+            rng: RwLock::new(rng),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Adjust volatility and drift by detected regime
+    pub fn with_regime_detector(mut self, regime_detector: Arc<dyn MarketRegimeDetector>) -> Self {
+        self.regime_detector = Some(regime_detector);
+        self
+    }
+
+    /// Escalate forecasts through a drawdown monitor
+    pub fn with_drawdown_monitor(mut self, drawdown_monitor: Arc<DrawdownMonitor>) -> Self {
+        self.drawdown_monitor = Some(drawdown_monitor);
+        self
+    }
+
+    /// Report forecasts to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Subscribe to published forecasts
+    pub fn subscribe(&self) -> broadcast::Receiver<DrawdownForecast> {
+        self.sender.subscribe()
+    }
+
+    /// Record a price observation
+    pub async fn on_price(&self, symbol: &str, price: f64, timestamp: DateTime<Utc>) {
+        if price <= 0.0 {
+            return;
+        }
+        let mut prices = self.prices.write().await;
+        let history = prices.entry(symbol.to_string()).or_default();
+        history.push_back((timestamp, price));
+        while history.len() > self.config.volatility_lookback.max(2) {
+            history.pop_front();
+        }
+    }
+
+    /// Latest forecast for an agent
+    pub async fn latest(&self, agent_id: &str) -> Option<DrawdownForecast> {
+        self.latest.read().await.get(agent_id).cloned()
+    }
+
+    /// Latest forecasts for every agent
+    pub async fn all_latest(&self) -> HashMap<String, DrawdownForecast> {
+        self.latest.read().await.clone()
+    }
+
+    /// Forecast the drawdown distribution for one agent
+    pub async fn forecast(&self, agent_id: &str) -> Result<DrawdownForecast, DrawdownForecastError> {
+        let position = self.position_manager.get_position(agent_id)?;
+
+        let mut exposures = Vec::new();
+        let mut equity = position.cash_balance;
+        {
+            let prices = self.prices.read().await;
+            for (symbol, symbol_position) in &position.positions {
+                if symbol_position.net_size == 0.0 {
+                    continue;
+                }
+                let history = prices.get(symbol);
+                let Some(price) = history
+                    .and_then(|h| h.back().map(|(_, p)| *p))
+                    .or((symbol_position.average_price > 0.0).then_some(symbol_position.average_price))
+                else {
+                    continue;
+                };
+                let notional = symbol_position.net_size * price;
+                equity += notional;
+
+                let realized = history
+                    .and_then(|h| realized_hourly_volatility(h, self.config.min_observations))
+                    .unwrap_or(self.config.default_hourly_volatility);
+                exposures.push(ExposureForecast {
+                    symbol: symbol.clone(),
+                    notional,
+                    realized_hourly_volatility: realized,
+                    regime: None,
+                    hourly_volatility: realized,
+                });
+            }
+        }
+        if equity <= 0.0 {
+            return Err(DrawdownForecastError::NoEquity(agent_id.to_string()));
+        }
+
+        let mut portfolio_drift = 0.0;
+        for exposure in &mut exposures {
+            if let Some(detector) = &self.regime_detector {
+                exposure.regime = detector.get_current_regime(&exposure.symbol).await.map(|r| r.state);
+            }
+            let adjustment = exposure
+                .regime
+                .and_then(|state| self.config.regime_adjustments.get(&state).copied())
+                .unwrap_or(RegimeAdjustment::new(1.0, 0.0));
+            exposure.hourly_volatility = exposure.realized_hourly_volatility * adjustment.volatility_multiplier;
+            portfolio_drift += exposure.notional / equity * adjustment.hourly_drift;
+        }
+
+        let mut variance = 0.0;
+        for (i, a) in exposures.iter().enumerate() {
+            for (j, b) in exposures.iter().enumerate() {
+                let rho = if i == j { 1.0 } else { self.config.correlation };
+                variance += (a.notional / equity) * (b.notional / equity) * a.hourly_volatility * b.hourly_volatility * rho;
+            }
+        }
+        let portfolio_volatility = variance.max(0.0).sqrt();
+
+        let (current_drawdown_pct, peak_equity) = match &self.drawdown_monitor {
+            Some(monitor) => match monitor.get_all_states().await.get(agent_id) {
+                Some(state) => (state.current_drawdown_pct, state.peak_equity.max(equity)),
+                None => (0.0, equity),
+            },
+            None => (0.0, equity),
+        };
+
+        let mut drawdowns = {
+            let mut rng = self.rng.write().await;
+            simulate_max_drawdowns(&mut rng, equity, peak_equity, portfolio_drift, portfolio_volatility, &self.config)
+        };
+        drawdowns.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let breaches = drawdowns.iter().filter(|d| **d >= self.config.max_drawdown_pct).count();
+
+        Ok(DrawdownForecast {
+            agent_id: agent_id.to_string(),
+            generated_at: Utc::now(),
+            horizon_hours: self.config.horizon_hours,
+            equity,
+            peak_equity,
+            current_drawdown_pct,
+            expected_drawdown_pct: drawdowns.iter().sum::<f64>() / drawdowns.len() as f64,
+            p50_drawdown_pct: quantile(&drawdowns, 0.5),
+            p95_drawdown_pct: quantile(&drawdowns, 0.95),
+            p99_drawdown_pct: quantile(&drawdowns, 0.99),
+            breach_probability: breaches as f64 / drawdowns.len() as f64,
+            portfolio_hourly_volatility: portfolio_volatility,
+            portfolio_hourly_drift: portfolio_drift,
+            exposures,
+        })
+    }
+
+    /// Forecast every agent, publish the results and escalate through the monitor
+    pub async fn run_once(&self) -> Result<Vec<DrawdownForecast>, DrawdownForecastError> {
+        let mut forecasts = Vec::new();
+        for agent_id in self.position_manager.get_agent_ids()? {
+            match self.forecast(&agent_id).await {
+                Ok(forecast) => {
+                    self.publish(&forecast).await;
+                    forecasts.push(forecast);
+                }
+                Err(DrawdownForecastError::NoEquity(_)) => {
+                    debug!("Skipping drawdown forecast for agent {} without equity", agent_id);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(forecasts)
+    }
+
+    /// Publish a forecast to subscribers, telemetry and the drawdown monitor
+    async fn publish(&self, forecast: &DrawdownForecast) {
+        self.latest.write().await.insert(forecast.agent_id.clone(), forecast.clone());
+        let _ = self.sender.send(forecast.clone());
+
+        if let Some(telemetry) = &self.telemetry {
+            let mut data = HashMap::new();
+            data.insert("agent_id".to_string(), serde_json::json!(forecast.agent_id));
+            data.insert("horizon_hours".to_string(), serde_json::json!(forecast.horizon_hours));
+            data.insert("expected_drawdown_pct".to_string(), serde_json::json!(forecast.expected_drawdown_pct));
+            data.insert("p95_drawdown_pct".to_string(), serde_json::json!(forecast.p95_drawdown_pct));
+            data.insert("breach_probability".to_string(), serde_json::json!(forecast.breach_probability));
+            telemetry.report_custom("drawdown_forecast", data).await;
+        }
+
+        if let Some(monitor) = &self.drawdown_monitor {
+            monitor.apply_forecast(forecast, &self.config.escalation).await;
+        }
+    }
+
+    /// Start forecasting on the configured interval
+    pub async fn start(self: &Arc<Self>) {
+        let forecaster = Arc::clone(self);
+        let interval_secs = self.config.interval_secs.max(1);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(StdDuration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                if let Err(e) = forecaster.run_once().await {
+                    warn!("Drawdown forecast failed: {}", e);
+                }
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop forecasting
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create a drawdown forecaster
+pub fn create_drawdown_forecaster(
+    config: DrawdownForecastConfig,
+    position_manager: Arc<PositionManager>,
+) -> Arc<DrawdownForecaster> {
+    Arc::new(DrawdownForecaster::new(config, position_manager))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::{OrderOrFill, Side};
+
+    #[tokio::test]
+    async fn higher_volatility_raises_breach_probability() {
+        let position_manager = PositionManager::new();
+        position_manager
+            .update_position(
+                "agent-1",
+                &OrderOrFill {
+                    symbol: "BTC/USD".to_string(),
+                    side: Side::Buy,
+                    size: 10.0,
+                    price: 100.0,
+                    timestamp: Utc::now(),
+                    order_id: "o1".to_string(),
+                    fill_id: Some("f1".to_string()),
+                    is_fill: true,
+                    venue: None,
+                    strategy_id: None,
+                },
+            )
+            .unwrap();
+
+        let config = DrawdownForecastConfig {
+            simulations: 500,
+            seed: Some(7),
+            ..Default::default()
+        };
+        let forecaster = DrawdownForecaster::new(config, Arc::clone(&position_manager));
+        let start = Utc::now();
+
+        let calm = forecaster.forecast("agent-1").await.unwrap();
+        assert_eq!(calm.exposures.len(), 1);
+        assert!(calm.p95_drawdown_pct >= calm.p50_drawdown_pct);
+
+        // Alternate +/-5% hourly moves: far above the 1% default
+        for i in 0..40 {
+            let price = if i % 2 == 0 { 100.0 } else { 105.0 };
+            forecaster.on_price("BTC/USD", price, start + chrono::Duration::hours(i)).await;
+        }
+        let stressed = forecaster.forecast("agent-1").await.unwrap();
+        assert!(stressed.exposures[0].realized_hourly_volatility > 0.04);
+        assert!(stressed.breach_probability > calm.breach_probability);
+        assert!(stressed.expected_drawdown_pct > calm.expected_drawdown_pct);
+
+        let policy = ForecastEscalationPolicy::default();
+        assert_eq!(policy.escalation_for(0.1), ForecastEscalation::None);
+        assert_eq!(policy.escalation_for(0.3), ForecastEscalation::Alert);
+        assert_eq!(policy.escalation_for(0.9), ForecastEscalation::KillSwitch);
+    }
+}
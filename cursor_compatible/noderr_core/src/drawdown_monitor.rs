@@ -23,7 +23,9 @@ use thiserror::Error;
 use tokio::sync::{RwLock, Mutex};
 use tracing::{debug, error, info, warn};
 
+use crate::drawdown_forecast::{DrawdownForecast, ForecastEscalation, ForecastEscalationPolicy};
 use crate::telemetry::TelemetryEvent;
+use crate::trading_events::{TradingEvent, TradingEventBus};
 
 /// Errors related to drawdown monitoring
 #[derive(Debug, Error)]
@@ -265,6 +267,9 @@ pub struct DrawdownMonitor {
     
     /// Telemetry sender (optional)
     telemetry_sender: Option<tokio::sync::mpsc::Sender<TelemetryEvent>>,
+    
+    /// Push notifications for drawdown events (optional)
+    event_bus: Option<Arc<TradingEventBus>>,
 }
 
 /// Kill switch trait for stopping agents
@@ -295,9 +300,16 @@ impl DrawdownMonitor {
             log_file_path: log_path,
             kill_switch,
             telemetry_sender: None,
+            event_bus: None,
         }
     }
     
+    /// Publish drawdown events to an event bus
+    pub fn with_event_bus(mut self, event_bus: Arc<TradingEventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+    
     /// Set telemetry sender
     pub fn with_telemetry(
         mut self,
@@ -529,6 +541,84 @@ impl DrawdownMonitor {
         }
     }
     
+    /// Escalate preemptively on a drawdown forecast for an agent.
+    ///
+    /// Raises a drawdown alert or triggers the kill switch when the forecast
+    /// breach probability crosses the policy thresholds. Agents already in
+    /// cooldown are left alone.
+    pub async fn apply_forecast(
+        &self,
+        forecast: &DrawdownForecast,
+        policy: &ForecastEscalationPolicy,
+    ) -> ForecastEscalation {
+        let escalation = policy.escalation_for(forecast.breach_probability);
+        if escalation == ForecastEscalation::None {
+            return escalation;
+        }
+        
+        let agent_id = forecast.agent_id.as_str();
+        let mut states = self.drawdown_states.write().await;
+        let state = states
+            .entry(agent_id.to_string())
+            .or_insert_with(|| DrawdownState {
+                agent_id: agent_id.to_string(),
+                is_active: true,
+                current_drawdown_pct: forecast.current_drawdown_pct,
+                peak_equity: forecast.peak_equity,
+                current_equity: forecast.equity,
+                cooldown_end_time: None,
+                trade_history: Vec::new(),
+            });
+        if !state.is_active {
+            return ForecastEscalation::None;
+        }
+        
+        let message = format!(
+            "Forecast drawdown: {:.1}% breach probability within {}h (expected {:.2}%, p95 {:.2}%)",
+            forecast.breach_probability * 100.0,
+            forecast.horizon_hours,
+            forecast.expected_drawdown_pct * 100.0,
+            forecast.p95_drawdown_pct * 100.0,
+        );
+        let event = DrawdownEvent {
+            event_type: DrawdownEventType::Alert,
+            timestamp: Utc::now(),
+            agent_id: agent_id.to_string(),
+            drawdown_pct: state.current_drawdown_pct,
+            peak_equity: state.peak_equity,
+            current_equity: state.current_equity,
+            message: message.clone(),
+        };
+        self.log_event(&event);
+        
+        if let Some(sender) = &self.telemetry_sender {
+            let telemetry = TelemetryEvent::new(
+                "drawdown_forecast_escalation",
+                serde_json::json!({
+                    "agent_id": agent_id,
+                    "escalation": escalation,
+                    "breach_probability": forecast.breach_probability,
+                    "expected_drawdown_pct": forecast.expected_drawdown_pct,
+                    "p95_drawdown_pct": forecast.p95_drawdown_pct,
+                    "horizon_hours": forecast.horizon_hours,
+                }),
+            );
+            
+            if let Err(e) = sender.try_send(telemetry) {
+                warn!("Failed to send telemetry: {}", e);
+            }
+        }
+        
+        if escalation == ForecastEscalation::KillSwitch {
+            let config = self.config.read().await;
+            state.is_active = false;
+            state.cooldown_end_time = Some(Utc::now() + chrono::Duration::milliseconds(config.cooldown_period_ms as i64));
+            self.kill_switch.trigger(agent_id, "drawdown_forecast", &message).await;
+        }
+        
+        escalation
+    }
+    
     /// Log an event to file
     fn log_event(&self, event: &DrawdownEvent) {
         match serde_json::to_string(event) {
@@ -555,6 +645,10 @@ impl DrawdownMonitor {
         
         // Log to console as well
         warn!("[DrawdownMonitor] {}", event.message);
+        
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish(TradingEvent::Drawdown(event.clone()));
+        }
     }
     
     /// Get current drawdown percentage for an agent
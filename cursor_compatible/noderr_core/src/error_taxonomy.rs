@@ -0,0 +1,871 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Crate-wide error classification.
+//!
+//! Every module keeps its own error enum; this module maps each variant to a
+//! stable code and a class so retry engines, telemetry and the API can treat
+//! failures consistently without matching on error strings.
+
+use serde::{Deserialize, Serialize};
+
+/// How a failure should be handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// Likely to succeed if retried (timeouts, connectivity, rate limits)
+    Transient,
+    /// Will fail again with the same input (validation, not found, rejected)
+    Permanent,
+    /// Indicates broken configuration or state; needs operator attention
+    Fatal,
+}
+
+impl ErrorClass {
+    /// Whether an operation failing with this class may be retried
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorClass::Transient)
+    }
+}
+
+/// Error with a stable code and retry classification
+pub trait ClassifiedError: std::error::Error {
+    /// Failure class
+    fn class(&self) -> ErrorClass;
+
+    /// Stable machine-readable code, e.g. `REDIS_TIMEOUT`
+    fn code(&self) -> &'static str;
+
+    /// Whether the failed operation may be retried
+    fn is_retryable(&self) -> bool {
+        self.class().is_retryable()
+    }
+
+    /// Serializable summary for telemetry and API responses
+    fn report(&self) -> ErrorReport {
+        ErrorReport {
+            code: self.code().to_string(),
+            class: self.class(),
+            retryable: self.is_retryable(),
+            message: self.to_string(),
+        }
+    }
+}
+
+/// Serializable description of a classified error
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    /// Stable error code
+    pub code: String,
+    /// Failure class
+    pub class: ErrorClass,
+    /// Whether the operation may be retried
+    pub retryable: bool,
+    /// Human-readable message
+    pub message: String,
+}
+
+/// Implement [`ClassifiedError`] from a variant table
+macro_rules! classify_error {
+    ($error:ty { $($variant:ident => $class:ident, $code:literal;)+ }) => {
+        impl ClassifiedError for $error {
+            fn class(&self) -> ErrorClass {
+                match self {
+                    $(Self::$variant { .. } => ErrorClass::$class,)+
+                }
+            }
+
+            fn code(&self) -> &'static str {
+                match self {
+                    $(Self::$variant { .. } => $code,)+
+                }
+            }
+        }
+    };
+}
+
+// Infrastructure
+
+classify_error!(crate::redis::RedisClientError {
+    RedisError => Transient, "REDIS_ERROR";
+    ConnectionError => Transient, "REDIS_CONNECTION";
+    SerializationError => Permanent, "REDIS_SERIALIZATION";
+    KeyNotFound => Permanent, "REDIS_KEY_NOT_FOUND";
+    Timeout => Transient, "REDIS_TIMEOUT";
+    Internal => Permanent, "REDIS_INTERNAL";
+});
+
+classify_error!(crate::wire_format::WireFormatError {
+    Encode => Permanent, "WIRE_ENCODE";
+    Decode => Permanent, "WIRE_DECODE";
+    Unsupported => Permanent, "WIRE_UNSUPPORTED";
+});
+
+classify_error!(crate::flight_recorder::FlightRecorderError {
+    Io => Transient, "FLIGHT_RECORDER_IO";
+    Serialization => Permanent, "FLIGHT_RECORDER_SERIALIZATION";
+    Throttled => Transient, "FLIGHT_RECORDER_THROTTLED";
+});
+
+classify_error!(crate::failover::FailoverError {
+    Lease => Transient, "FAILOVER_LEASE";
+    Journal => Transient, "FAILOVER_JOURNAL";
+    Restore => Fatal, "FAILOVER_RESTORE";
+});
+
+classify_error!(crate::state_bundle::StateBundleError {
+    Io => Transient, "STATE_BUNDLE_IO";
+    Serialization => Permanent, "STATE_BUNDLE_SERIALIZATION";
+    InvalidKey => Permanent, "STATE_BUNDLE_INVALID_KEY";
+    NoSigningKey => Permanent, "STATE_BUNDLE_NO_SIGNING_KEY";
+    UnsupportedFormat => Permanent, "STATE_BUNDLE_UNSUPPORTED_FORMAT";
+    UntrustedKey => Permanent, "STATE_BUNDLE_UNTRUSTED_KEY";
+    BadSignature => Permanent, "STATE_BUNDLE_BAD_SIGNATURE";
+    Export => Transient, "STATE_BUNDLE_EXPORT";
+    Import => Transient, "STATE_BUNDLE_IMPORT";
+});
+
+classify_error!(crate::universe::UniverseError {
+    InvalidSymbol => Permanent, "UNIVERSE_INVALID_SYMBOL";
+    AlreadyListed => Permanent, "UNIVERSE_ALREADY_LISTED";
+    NotListed => Permanent, "UNIVERSE_NOT_LISTED";
+    UnknownTemplate => Permanent, "UNIVERSE_UNKNOWN_TEMPLATE";
+    OpenExposure => Transient, "UNIVERSE_OPEN_EXPOSURE";
+    Provision => Transient, "UNIVERSE_PROVISION";
+});
+
+classify_error!(crate::logging::LoggingError {
+    InvalidLevel => Permanent, "LOG_INVALID_LEVEL";
+    InvalidTarget => Permanent, "LOG_INVALID_TARGET";
+    Reload => Transient, "LOG_RELOAD";
+    AlreadyInitialized => Permanent, "LOG_ALREADY_INITIALIZED";
+});
+
+classify_error!(crate::storage::StorageError {
+    IoError => Transient, "STORAGE_IO";
+    DatabaseError => Transient, "STORAGE_DATABASE";
+    SerializationError => Permanent, "STORAGE_SERIALIZATION";
+    NotFound => Permanent, "STORAGE_NOT_FOUND";
+    Internal => Permanent, "STORAGE_INTERNAL";
+});
+
+classify_error!(crate::dead_letter::DeadLetterError {
+    Io => Transient, "DEAD_LETTER_IO";
+    Serialization => Permanent, "DEAD_LETTER_SERIALIZATION";
+});
+
+classify_error!(crate::audit_vault::AuditVaultError {
+    Io => Transient, "AUDIT_IO";
+    Serialization => Permanent, "AUDIT_SERIALIZATION";
+    ChainBroken => Fatal, "AUDIT_CHAIN_BROKEN";
+});
+
+classify_error!(crate::api::auth::AuthError {
+    InvalidCredentials => Permanent, "AUTH_INVALID_CREDENTIALS";
+    InvalidToken => Permanent, "AUTH_INVALID_TOKEN";
+    MissingToken => Permanent, "AUTH_MISSING_TOKEN";
+    UserNotFound => Permanent, "AUTH_USER_NOT_FOUND";
+    EmailTaken => Permanent, "AUTH_EMAIL_TAKEN";
+    JwtError => Permanent, "AUTH_JWT";
+    InternalError => Permanent, "AUTH_INTERNAL";
+});
+
+// Telemetry
+
+classify_error!(crate::telemetry::TelemetryError {
+    BroadcastError => Transient, "TELEMETRY_BROADCAST";
+    StorageError => Transient, "TELEMETRY_STORAGE";
+    SerializationError => Permanent, "TELEMETRY_SERIALIZATION";
+    NetworkError => Transient, "TELEMETRY_NETWORK";
+    InvalidParameter => Permanent, "TELEMETRY_INVALID_PARAMETER";
+    Internal => Permanent, "TELEMETRY_INTERNAL";
+    AccessDenied => Permanent, "TELEMETRY_ACCESS_DENIED";
+});
+
+classify_error!(crate::telemetry_streamer::TelemetryStreamError {
+    ConnectionError => Transient, "STREAM_CONNECTION";
+    RedisError => Transient, "STREAM_REDIS";
+    SerializationError => Permanent, "STREAM_SERIALIZATION";
+    ConfigError => Fatal, "STREAM_CONFIG";
+    ChannelNotFound => Permanent, "STREAM_CHANNEL_NOT_FOUND";
+    StreamError => Transient, "STREAM_ERROR";
+    SchemaError => Permanent, "STREAM_SCHEMA";
+});
+
+classify_error!(crate::telemetry_schema::SchemaError {
+    UnknownMessageType => Permanent, "SCHEMA_UNKNOWN_MESSAGE_TYPE";
+    UnknownVersion => Permanent, "SCHEMA_UNKNOWN_VERSION";
+    VersionOutOfOrder => Fatal, "SCHEMA_VERSION_OUT_OF_ORDER";
+    Incompatible => Fatal, "SCHEMA_INCOMPATIBLE";
+    InvalidPayload => Permanent, "SCHEMA_INVALID_PAYLOAD";
+});
+
+classify_error!(crate::websocket_manager::WebSocketError {
+    ConnectionError => Transient, "WS_CONNECTION";
+    SendError => Transient, "WS_SEND";
+    SubscriptionError => Permanent, "WS_SUBSCRIPTION";
+    RedisError => Transient, "WS_REDIS";
+    SerializationError => Permanent, "WS_SERIALIZATION";
+    Unauthorized => Permanent, "WS_UNAUTHORIZED";
+    InternalError => Permanent, "WS_INTERNAL";
+});
+
+// Market data
+
+classify_error!(crate::market::MarketDataError {
+    ApiError => Transient, "MARKET_API";
+    RateLimitExceeded => Transient, "MARKET_RATE_LIMIT";
+    DataNotAvailable => Transient, "MARKET_DATA_NOT_AVAILABLE";
+    Internal => Permanent, "MARKET_INTERNAL";
+    ParseError => Permanent, "MARKET_PARSE";
+    AuthError => Fatal, "MARKET_AUTH";
+    NetworkError => Transient, "MARKET_NETWORK";
+});
+
+classify_error!(crate::market_data::MarketDataError {
+    InvalidTickData => Permanent, "MARKET_DATA_INVALID_TICK";
+    InsufficientHistory => Transient, "MARKET_DATA_INSUFFICIENT_HISTORY";
+    CalculationError => Permanent, "MARKET_DATA_CALCULATION";
+    SymbolNotFound => Permanent, "MARKET_DATA_SYMBOL_NOT_FOUND";
+});
+
+classify_error!(crate::strategy_executor::MarketDataError {
+    ApiError => Transient, "EXECUTOR_MARKET_API";
+    RateLimitExceeded => Transient, "EXECUTOR_MARKET_RATE_LIMIT";
+    DataNotAvailable => Transient, "EXECUTOR_MARKET_DATA_NOT_AVAILABLE";
+    Internal => Permanent, "EXECUTOR_MARKET_INTERNAL";
+});
+
+classify_error!(crate::microstructure::order_flow::OrderFlowError {
+    Redis => Transient, "ORDER_FLOW_REDIS";
+    SymbolNotFound => Permanent, "ORDER_FLOW_SYMBOL_NOT_FOUND";
+    InvalidData => Permanent, "ORDER_FLOW_INVALID_DATA";
+    Internal => Permanent, "ORDER_FLOW_INTERNAL";
+});
+
+classify_error!(crate::microstructure::liquidity::LiquidityError {
+    Redis => Transient, "LIQUIDITY_REDIS";
+    SymbolNotFound => Permanent, "LIQUIDITY_SYMBOL_NOT_FOUND";
+    InvalidData => Permanent, "LIQUIDITY_INVALID_DATA";
+    Internal => Permanent, "LIQUIDITY_INTERNAL";
+});
+
+classify_error!(crate::microstructure::footprint::FootprintError {
+    Redis => Transient, "FOOTPRINT_REDIS";
+    SymbolNotFound => Permanent, "FOOTPRINT_SYMBOL_NOT_FOUND";
+    InvalidData => Permanent, "FOOTPRINT_INVALID_DATA";
+    Internal => Permanent, "FOOTPRINT_INTERNAL";
+});
+
+classify_error!(crate::microstructure::timing_signals::TimingSignalError {
+    Redis => Transient, "TIMING_SIGNAL_REDIS";
+    SymbolNotFound => Permanent, "TIMING_SIGNAL_SYMBOL_NOT_FOUND";
+    InvalidData => Permanent, "TIMING_SIGNAL_INVALID_DATA";
+    Internal => Permanent, "TIMING_SIGNAL_INTERNAL";
+});
+
+classify_error!(crate::market_regime::MarketRegimeError {
+    InsufficientData => Transient, "REGIME_INSUFFICIENT_DATA";
+    InvalidParameter => Permanent, "REGIME_INVALID_PARAMETER";
+    Internal => Permanent, "REGIME_INTERNAL";
+});
+
+classify_error!(crate::market_regime::hmm::HmmError {
+    InsufficientData => Transient, "HMM_INSUFFICIENT_DATA";
+    InvalidParameters => Permanent, "HMM_INVALID_PARAMETERS";
+    ComputationError => Permanent, "HMM_COMPUTATION";
+    NotTrained => Transient, "HMM_NOT_TRAINED";
+});
+
+classify_error!(crate::market_regime::warning_engine::RegimeWarningError {
+    RedisError => Transient, "REGIME_WARNING_REDIS";
+    InsufficientData => Transient, "REGIME_WARNING_INSUFFICIENT_DATA";
+    InvalidParameter => Permanent, "REGIME_WARNING_INVALID_PARAMETER";
+    Internal => Permanent, "REGIME_WARNING_INTERNAL";
+});
+
+// Strategies
+
+classify_error!(crate::strategy::StrategyError {
+    InvalidConfig => Fatal, "STRATEGY_INVALID_CONFIG";
+    MissingData => Transient, "STRATEGY_MISSING_DATA";
+    ComputationError => Permanent, "STRATEGY_COMPUTATION";
+    PipelineHalted => Fatal, "STRATEGY_PIPELINE_HALTED";
+    Panicked => Fatal, "STRATEGY_PANICKED";
+    Internal => Permanent, "STRATEGY_INTERNAL";
+});
+
+classify_error!(crate::strategy::SignalError {
+    Expired => Permanent, "SIGNAL_EXPIRED";
+    InvalidParameters => Permanent, "SIGNAL_INVALID_PARAMETERS";
+    RiskRejected => Permanent, "SIGNAL_RISK_REJECTED";
+    ExecutionError => Transient, "SIGNAL_EXECUTION";
+    TrustCheckFailed => Permanent, "SIGNAL_TRUST_CHECK_FAILED";
+    StrategyDowngraded => Permanent, "SIGNAL_STRATEGY_DOWNGRADED";
+    UnsuitableMarketConditions => Transient, "SIGNAL_UNSUITABLE_MARKET";
+    LatencyBudgetExceeded => Transient, "SIGNAL_LATENCY_BUDGET";
+});
+
+classify_error!(crate::strategy_engine::StrategyEngineError {
+    ValidationFailed => Permanent, "ENGINE_VALIDATION_FAILED";
+    RiskCheckFailed => Permanent, "ENGINE_RISK_CHECK_FAILED";
+    TrustScoreTooLow => Permanent, "ENGINE_TRUST_TOO_LOW";
+    ExecutionFailed => Transient, "ENGINE_EXECUTION_FAILED";
+    InvalidParameters => Permanent, "ENGINE_INVALID_PARAMETERS";
+    SignalExpired => Permanent, "ENGINE_SIGNAL_EXPIRED";
+    LatencyBudgetExceeded => Transient, "ENGINE_LATENCY_BUDGET";
+    SignalSuppressed => Permanent, "ENGINE_SIGNAL_SUPPRESSED";
+    SignalConflict => Permanent, "ENGINE_SIGNAL_CONFLICT";
+    TradingPaused => Transient, "ENGINE_TRADING_PAUSED";
+    SymbolWarmingUp => Transient, "ENGINE_SYMBOL_WARMING_UP";
+    Internal => Permanent, "ENGINE_INTERNAL";
+});
+
+classify_error!(crate::strategy_dependencies::DependencyError {
+    SelfDependency => Permanent, "DEPENDENCY_SELF";
+    Cycle => Permanent, "DEPENDENCY_CYCLE";
+    MissingDependency => Permanent, "DEPENDENCY_MISSING";
+    QuarantinedDependency => Transient, "DEPENDENCY_QUARANTINED";
+    HasDependents => Permanent, "DEPENDENCY_HAS_DEPENDENTS";
+});
+
+classify_error!(crate::strategy_attribution::AttributionError {
+    RedisError => Transient, "ATTRIBUTION_REDIS";
+    InsufficientData => Transient, "ATTRIBUTION_INSUFFICIENT_DATA";
+    InvalidParameter => Permanent, "ATTRIBUTION_INVALID_PARAMETER";
+    Internal => Permanent, "ATTRIBUTION_INTERNAL";
+});
+
+classify_error!(crate::strategy_feedback::StrategyFeedbackError {
+    RedisError => Transient, "FEEDBACK_REDIS";
+    MetricsError => Transient, "FEEDBACK_METRICS";
+    AllocationError => Permanent, "FEEDBACK_ALLOCATION";
+    InvalidParameter => Permanent, "FEEDBACK_INVALID_PARAMETER";
+    Internal => Permanent, "FEEDBACK_INTERNAL";
+});
+
+classify_error!(crate::backtest::BacktestError {
+    InsufficientData => Permanent, "BACKTEST_INSUFFICIENT_DATA";
+    InvalidConfig => Fatal, "BACKTEST_INVALID_CONFIG";
+    Strategy => Permanent, "BACKTEST_STRATEGY";
+    Execution => Permanent, "BACKTEST_EXECUTION";
+    EpisodeFinished => Permanent, "BACKTEST_EPISODE_FINISHED";
+});
+
+classify_error!(crate::factor_analysis::FactorAnalysisError {
+    RedisError => Transient, "FACTOR_REDIS";
+    InsufficientData => Transient, "FACTOR_INSUFFICIENT_DATA";
+    StatisticalError => Permanent, "FACTOR_STATISTICAL";
+    InvalidParameter => Permanent, "FACTOR_INVALID_PARAMETER";
+    Internal => Permanent, "FACTOR_INTERNAL";
+});
+
+// Execution and routing
+
+classify_error!(crate::execution::ExecutionError {
+    ConnectionError => Transient, "EXECUTION_CONNECTION";
+    AuthenticationError => Fatal, "EXECUTION_AUTHENTICATION";
+    ValidationError => Permanent, "EXECUTION_VALIDATION";
+    InsufficientFunds => Permanent, "EXECUTION_INSUFFICIENT_FUNDS";
+    RateLimitExceeded => Transient, "EXECUTION_RATE_LIMIT";
+    OrderRejected => Permanent, "EXECUTION_ORDER_REJECTED";
+    Timeout => Transient, "EXECUTION_TIMEOUT";
+    ServiceError => Transient, "EXECUTION_SERVICE";
+    NotSupported => Permanent, "EXECUTION_NOT_SUPPORTED";
+    Internal => Permanent, "EXECUTION_INTERNAL";
+});
+
+classify_error!(crate::execution_strategy::ExecutionStrategyError {
+    UnsupportedStrategy => Permanent, "EXEC_STRATEGY_UNSUPPORTED";
+    ExecutionFailed => Transient, "EXEC_STRATEGY_FAILED";
+    InvalidParameters => Permanent, "EXEC_STRATEGY_INVALID_PARAMETERS";
+    InitializationFailed => Fatal, "EXEC_STRATEGY_INIT_FAILED";
+    Internal => Permanent, "EXEC_STRATEGY_INTERNAL";
+    PolicyViolation => Permanent, "EXEC_STRATEGY_POLICY_VIOLATION";
+});
+
+classify_error!(crate::execution_metrics::ExecutionMetricsError {
+    RedisError => Transient, "EXEC_METRICS_REDIS";
+    StorageError => Transient, "EXEC_METRICS_STORAGE";
+    SerializationError => Permanent, "EXEC_METRICS_SERIALIZATION";
+    InvalidParameter => Permanent, "EXEC_METRICS_INVALID_PARAMETER";
+    Internal => Permanent, "EXEC_METRICS_INTERNAL";
+});
+
+classify_error!(crate::order_router::OrderRouterError {
+    NoAvailableVenues => Transient, "ROUTER_NO_VENUES";
+    ExecutionFailedAllVenues => Transient, "ROUTER_ALL_VENUES_FAILED";
+    VenueError => Transient, "ROUTER_VENUE";
+    InvalidOrderParameters => Permanent, "ROUTER_INVALID_ORDER";
+    ExecutionTimeout => Transient, "ROUTER_TIMEOUT";
+    StaleQuotes => Transient, "ROUTER_STALE_QUOTES";
+    PostOnlyWouldCross => Permanent, "ROUTER_POST_ONLY_WOULD_CROSS";
+    PostOnlyUnverified => Transient, "ROUTER_POST_ONLY_UNVERIFIED";
+    ReduceOnlyViolation => Permanent, "ROUTER_REDUCE_ONLY_VIOLATION";
+    BatchRejected => Permanent, "ROUTER_BATCH_REJECTED";
+    OrderNotFound => Permanent, "ROUTER_ORDER_NOT_FOUND";
+    NotConfigured => Fatal, "ROUTER_NOT_CONFIGURED";
+    PolicyViolation => Permanent, "ROUTER_POLICY_VIOLATION";
+    Standby => Transient, "ROUTER_STANDBY";
+    CancelRejected => Permanent, "ROUTER_CANCEL_REJECTED";
+    ReplacementFailed => Permanent, "ROUTER_REPLACEMENT_FAILED";
+    CancelPending => Transient, "ROUTER_CANCEL_PENDING";
+});
+
+classify_error!(crate::position::PositionError {
+    InvalidUpdate => Permanent, "POSITION_INVALID_UPDATE";
+    PositionNotFound => Permanent, "POSITION_NOT_FOUND";
+    LimitExceeded => Permanent, "POSITION_LIMIT_EXCEEDED";
+    InvalidOrderData => Permanent, "POSITION_INVALID_ORDER";
+});
+
+classify_error!(crate::trade_sizer::TradeSizerError {
+    InsufficientData => Transient, "SIZER_INSUFFICIENT_DATA";
+    InvalidConfiguration => Fatal, "SIZER_INVALID_CONFIG";
+    IOError => Transient, "SIZER_IO";
+    SymbolNotFound => Permanent, "SIZER_SYMBOL_NOT_FOUND";
+    CalculationError => Permanent, "SIZER_CALCULATION";
+});
+
+// Risk and allocation
+
+classify_error!(crate::risk::RiskError {
+    SignalRejected => Permanent, "RISK_SIGNAL_REJECTED";
+    RiskLimitBreached => Permanent, "RISK_LIMIT_BREACHED";
+    StrategyDisabled => Permanent, "RISK_STRATEGY_DISABLED";
+    TrustScoreTooLow => Permanent, "RISK_TRUST_TOO_LOW";
+    InvalidConfig => Fatal, "RISK_INVALID_CONFIG";
+    UnsuitableMarketConditions => Transient, "RISK_UNSUITABLE_MARKET";
+    PositionLimitReached => Permanent, "RISK_POSITION_LIMIT";
+    Internal => Permanent, "RISK_INTERNAL";
+});
+
+classify_error!(crate::risk_budget::RiskBudgetError {
+    InvalidBudget => Fatal, "RISK_BUDGET_INVALID";
+    StrategyNotFound => Permanent, "RISK_BUDGET_STRATEGY_NOT_FOUND";
+});
+
+classify_error!(crate::risk_override::RiskOverrideError {
+    InvalidRequest => Permanent, "RISK_OVERRIDE_INVALID_REQUEST";
+    AlreadyActive => Permanent, "RISK_OVERRIDE_ALREADY_ACTIVE";
+    NotFound => Permanent, "RISK_OVERRIDE_NOT_FOUND";
+    Audit => Transient, "RISK_OVERRIDE_AUDIT";
+});
+
+classify_error!(crate::risk_allocation::RiskAllocationError {
+    CorrelationError => Transient, "ALLOCATION_CORRELATION";
+    InsufficientData => Transient, "ALLOCATION_INSUFFICIENT_DATA";
+    InvalidConfig => Fatal, "ALLOCATION_INVALID_CONFIG";
+    Constraints => Permanent, "ALLOCATION_CONSTRAINTS";
+    Internal => Permanent, "ALLOCATION_INTERNAL";
+});
+
+classify_error!(crate::asset_allocator::AssetAllocationError {
+    InsufficientData => Transient, "ASSET_ALLOCATION_INSUFFICIENT_DATA";
+    InvalidParameter => Permanent, "ASSET_ALLOCATION_INVALID_PARAMETER";
+    RegimeError => Transient, "ASSET_ALLOCATION_REGIME";
+    Constraints => Permanent, "ASSET_ALLOCATION_CONSTRAINTS";
+    Internal => Permanent, "ASSET_ALLOCATION_INTERNAL";
+});
+
+classify_error!(crate::correlation_engine::CorrelationError {
+    RedisError => Transient, "CORRELATION_REDIS";
+    SerializationError => Permanent, "CORRELATION_SERIALIZATION";
+    InsufficientData => Transient, "CORRELATION_INSUFFICIENT_DATA";
+    InvalidConfig => Fatal, "CORRELATION_INVALID_CONFIG";
+    Internal => Permanent, "CORRELATION_INTERNAL";
+});
+
+classify_error!(crate::drawdown::DrawdownError {
+    Redis => Transient, "DRAWDOWN_REDIS";
+    StrategyNotFound => Permanent, "DRAWDOWN_STRATEGY_NOT_FOUND";
+    InvalidStateTransition => Permanent, "DRAWDOWN_INVALID_TRANSITION";
+    InvalidConfig => Fatal, "DRAWDOWN_INVALID_CONFIG";
+    Internal => Permanent, "DRAWDOWN_INTERNAL";
+});
+
+classify_error!(crate::drawdown_monitor::DrawdownError {
+    AgentNotFound => Permanent, "DRAWDOWN_MONITOR_AGENT_NOT_FOUND";
+    IOError => Transient, "DRAWDOWN_MONITOR_IO";
+    InvalidConfig => Fatal, "DRAWDOWN_MONITOR_INVALID_CONFIG";
+});
+
+// Trust and analytics
+
+classify_error!(crate::trust_buffer::TrustBufferError {
+    EntityNotFound => Permanent, "TRUST_BUFFER_ENTITY_NOT_FOUND";
+    InvalidTimeRange => Permanent, "TRUST_BUFFER_INVALID_RANGE";
+    CapacityExceeded => Transient, "TRUST_BUFFER_CAPACITY";
+    SerializationError => Permanent, "TRUST_BUFFER_SERIALIZATION";
+    Internal => Permanent, "TRUST_BUFFER_INTERNAL";
+});
+
+classify_error!(crate::trust_score_engine::TrustScoreError {
+    AnalyticsError => Transient, "TRUST_SCORE_ANALYTICS";
+    StrategyNotFound => Permanent, "TRUST_SCORE_STRATEGY_NOT_FOUND";
+    InsufficientData => Transient, "TRUST_SCORE_INSUFFICIENT_DATA";
+    RedisError => Transient, "TRUST_SCORE_REDIS";
+    TelemetryStreamError => Transient, "TRUST_SCORE_TELEMETRY";
+    SerializationError => Permanent, "TRUST_SCORE_SERIALIZATION";
+    HistoryError => Transient, "TRUST_SCORE_HISTORY";
+    InternalError => Permanent, "TRUST_SCORE_INTERNAL";
+});
+
+classify_error!(crate::trust_history::TrustHistoryError {
+    Io => Transient, "TRUST_HISTORY_IO";
+    Serialization => Permanent, "TRUST_HISTORY_SERIALIZATION";
+});
+
+classify_error!(crate::venue_trust::VenueTrustError {
+    Io => Transient, "VENUE_TRUST_IO";
+    Serialization => Permanent, "VENUE_TRUST_SERIALIZATION";
+});
+
+classify_error!(crate::routing_policy::RoutingPolicyError {
+    Rejected => Permanent, "ROUTING_POLICY_REJECTED";
+    InvalidPolicy => Permanent, "ROUTING_POLICY_INVALID";
+    UnknownVersion => Permanent, "ROUTING_POLICY_UNKNOWN_VERSION";
+});
+
+classify_error!(crate::analytics::AnalyticsError {
+    StorageError => Transient, "ANALYTICS_STORAGE";
+    InsufficientData => Transient, "ANALYTICS_INSUFFICIENT_DATA";
+    InvalidParameter => Permanent, "ANALYTICS_INVALID_PARAMETER";
+});
+
+classify_error!(crate::event_calendar::EventCalendarError {
+    Source => Transient, "CALENDAR_SOURCE";
+    InvalidData => Permanent, "CALENDAR_INVALID_DATA";
+});
+
+classify_error!(crate::sentiment::SentimentError {
+    Provider => Transient, "SENTIMENT_PROVIDER";
+    InvalidItem => Permanent, "SENTIMENT_INVALID_ITEM";
+    Storage => Transient, "SENTIMENT_STORAGE";
+});
+
+classify_error!(crate::chain_data::ChainDataError {
+    Provider => Transient, "CHAIN_DATA_PROVIDER";
+    NoProvider => Permanent, "CHAIN_DATA_NO_PROVIDER";
+});
+
+classify_error!(crate::dex_venue::DexVenueError {
+    Quote => Transient, "DEX_QUOTE";
+    Submit => Transient, "DEX_SUBMIT";
+    SlippageExceeded => Transient, "DEX_SLIPPAGE_EXCEEDED";
+    Reverted => Transient, "DEX_REVERTED";
+    OutOfGas => Transient, "DEX_OUT_OF_GAS";
+    ConfirmationTimeout => Transient, "DEX_CONFIRMATION_TIMEOUT";
+});
+
+classify_error!(crate::connectors::ConnectorError {
+    Http => Transient, "CONNECTOR_HTTP";
+    Rejected => Permanent, "CONNECTOR_REJECTED";
+    WebSocket => Transient, "CONNECTOR_WEBSOCKET";
+    Decode => Permanent, "CONNECTOR_DECODE";
+    SequenceGap => Transient, "CONNECTOR_SEQUENCE_GAP";
+    MissingCredentials => Fatal, "CONNECTOR_MISSING_CREDENTIALS";
+    Unsupported => Permanent, "CONNECTOR_UNSUPPORTED";
+    NotRunning => Transient, "CONNECTOR_NOT_RUNNING";
+});
+
+classify_error!(crate::fix::FixError {
+    Io => Transient, "FIX_IO";
+    Malformed => Permanent, "FIX_MALFORMED";
+    LogonRejected => Fatal, "FIX_LOGON_REJECTED";
+    NotLoggedOn => Transient, "FIX_NOT_LOGGED_ON";
+    SessionRejected => Permanent, "FIX_SESSION_REJECTED";
+    CancelRejected => Permanent, "FIX_CANCEL_REJECTED";
+    UnknownOrder => Permanent, "FIX_UNKNOWN_ORDER";
+    SequenceTooLow => Fatal, "FIX_SEQUENCE_TOO_LOW";
+    Timeout => Transient, "FIX_TIMEOUT";
+    Unsupported => Permanent, "FIX_UNSUPPORTED";
+});
+
+classify_error!(crate::capital_transfer::TransferError {
+    AddressNotWhitelisted => Permanent, "TRANSFER_ADDRESS_NOT_WHITELISTED";
+    InsufficientBalance => Permanent, "TRANSFER_INSUFFICIENT_BALANCE";
+    Venue => Transient, "TRANSFER_VENUE";
+    UnknownVenue => Permanent, "TRANSFER_UNKNOWN_VENUE";
+    TransferNotFound => Permanent, "TRANSFER_NOT_FOUND";
+    InvalidTransfer => Permanent, "TRANSFER_INVALID";
+});
+
+classify_error!(crate::feature_store::FeatureStoreError {
+    DuplicateFeature => Permanent, "FEATURE_DUPLICATE";
+    UnknownFeature => Permanent, "FEATURE_UNKNOWN";
+});
+
+classify_error!(crate::model_drift::ModelDriftError {
+    InsufficientBaseline => Permanent, "DRIFT_INSUFFICIENT_BASELINE";
+    InvalidPrediction => Permanent, "DRIFT_INVALID_PREDICTION";
+});
+
+classify_error!(crate::queue_position::QueuePositionError {
+    UnknownOrder => Permanent, "QUEUE_UNKNOWN_ORDER";
+    InvalidOrder => Permanent, "QUEUE_INVALID_ORDER";
+});
+
+classify_error!(crate::repricer::RepricerError {
+    InvalidOrder => Permanent, "REPRICER_INVALID_ORDER";
+    Router => Transient, "REPRICER_ROUTER";
+    Overlay => Permanent, "REPRICER_OVERLAY";
+});
+
+classify_error!(crate::volatility::VolatilityError {
+    InvalidBar => Permanent, "VOLATILITY_INVALID_BAR";
+});
+
+classify_error!(crate::taker_overlay::TakerOverlayError {
+    UnknownParent => Permanent, "TAKER_UNKNOWN_PARENT";
+    BudgetExhausted => Permanent, "TAKER_BUDGET_EXHAUSTED";
+    InvalidQuantity => Permanent, "TAKER_INVALID_QUANTITY";
+});
+
+classify_error!(crate::liquidity_probe::LiquidityProbeError {
+    UnsupportedVenue => Permanent, "PROBE_UNSUPPORTED_VENUE";
+    NotionalCapExceeded => Permanent, "PROBE_NOTIONAL_CAP";
+    Cooldown => Transient, "PROBE_COOLDOWN";
+    InvalidProbe => Permanent, "PROBE_INVALID";
+    Router => Transient, "PROBE_ROUTER";
+});
+
+classify_error!(crate::incidents::IncidentError {
+    NotFound => Permanent, "INCIDENT_NOT_FOUND";
+    InvalidTransition => Permanent, "INCIDENT_INVALID_TRANSITION";
+});
+
+classify_error!(crate::allocation_constraints::ConstraintError {
+    Parse => Fatal, "CONSTRAINT_PARSE";
+    Infeasible => Permanent, "CONSTRAINT_INFEASIBLE";
+});
+
+classify_error!(crate::ids::IdError {
+    MissingPrefix => Permanent, "ID_MISSING_PREFIX";
+    UnknownPrefix => Permanent, "ID_UNKNOWN_PREFIX";
+    InvalidUlid => Permanent, "ID_INVALID_ULID";
+});
+
+classify_error!(crate::traceability::TraceError {
+    Io => Transient, "TRACE_IO";
+    Serialization => Permanent, "TRACE_SERIALIZATION";
+});
+
+classify_error!(crate::api::mtls::MtlsError {
+    Io => Transient, "MTLS_IO";
+    Tls => Fatal, "MTLS_TLS";
+    InvalidCertificate => Fatal, "MTLS_INVALID_CERTIFICATE";
+    NotConfigured => Fatal, "MTLS_NOT_CONFIGURED";
+    UnknownOperator => Permanent, "MTLS_UNKNOWN_OPERATOR";
+});
+
+classify_error!(crate::chaos::ChaosError {
+    Scenario => Permanent, "CHAOS_SCENARIO";
+});
+
+classify_error!(crate::portfolio_snapshot::PortfolioSnapshotError {
+    Io => Transient, "PORTFOLIO_IO";
+    Serialization => Fatal, "PORTFOLIO_SERIALIZATION";
+    NoHistory => Permanent, "PORTFOLIO_NO_HISTORY";
+});
+
+classify_error!(crate::bars::BarError {
+    InvalidBarType => Permanent, "BAR_INVALID_TYPE";
+    InvalidThreshold => Permanent, "BAR_INVALID_THRESHOLD";
+});
+
+classify_error!(crate::drawdown_forecast::DrawdownForecastError {
+    Position => Transient, "DRAWDOWN_FORECAST_POSITION";
+    NoEquity => Permanent, "DRAWDOWN_FORECAST_NO_EQUITY";
+});
+
+classify_error!(crate::order_simulator::OrderSimulatorError {
+    InvalidOrder => Permanent, "ORDER_SIM_INVALID_ORDER";
+    NoPrice => Transient, "ORDER_SIM_NO_PRICE";
+});
+
+classify_error!(crate::mesh::MeshError {
+    UnknownAgent => Permanent, "MESH_UNKNOWN_AGENT";
+    InvalidTrust => Permanent, "MESH_INVALID_TRUST";
+    NoPath => Transient, "MESH_NO_PATH";
+});
+
+classify_error!(crate::meta::decision_review::DecisionReviewError {
+    NotPending => Permanent, "REVIEW_NOT_PENDING";
+    MissingOperator => Permanent, "REVIEW_MISSING_OPERATOR";
+    Service => Transient, "REVIEW_SERVICE";
+});
+
+// Governance
+
+classify_error!(crate::governance::federation::relay::RelayError {
+    ConnectionError => Transient, "RELAY_CONNECTION";
+    ProposalNotFound => Permanent, "RELAY_PROPOSAL_NOT_FOUND";
+    DomainNotFound => Permanent, "RELAY_DOMAIN_NOT_FOUND";
+    AuthError => Fatal, "RELAY_AUTH";
+    SerializationError => Permanent, "RELAY_SERIALIZATION";
+    InternalError => Permanent, "RELAY_INTERNAL";
+});
+
+classify_error!(crate::governance::federation::vote_tracker::VoteTrackingError {
+    ProposalNotFound => Permanent, "VOTE_PROPOSAL_NOT_FOUND";
+    DomainNotFound => Permanent, "VOTE_DOMAIN_NOT_FOUND";
+    AgentNotFound => Permanent, "VOTE_AGENT_NOT_FOUND";
+    VotingNotAllowed => Permanent, "VOTE_NOT_ALLOWED";
+    InternalError => Permanent, "VOTE_INTERNAL";
+    SerializationError => Permanent, "VOTE_SERIALIZATION";
+});
+
+classify_error!(crate::governance::federation::execution::ExecutionError {
+    ProposalNotFound => Permanent, "FEDERATION_PROPOSAL_NOT_FOUND";
+    InvalidState => Permanent, "FEDERATION_INVALID_STATE";
+    VotingError => Permanent, "FEDERATION_VOTING";
+    RelayError => Transient, "FEDERATION_RELAY";
+    InternalError => Permanent, "FEDERATION_INTERNAL";
+    SerializationError => Permanent, "FEDERATION_SERIALIZATION";
+    ExecutionFailed => Transient, "FEDERATION_EXECUTION_FAILED";
+});
+
+classify_error!(crate::governance::federation::finality::FinalityError {
+    ProposalNotFound => Permanent, "FINALITY_PROPOSAL_NOT_FOUND";
+    LockNotAvailable => Transient, "FINALITY_LOCK_UNAVAILABLE";
+    LockTimeout => Transient, "FINALITY_LOCK_TIMEOUT";
+    InvalidState => Permanent, "FINALITY_INVALID_STATE";
+    InternalError => Permanent, "FINALITY_INTERNAL";
+    ExecutionError => Transient, "FINALITY_EXECUTION";
+});
+
+classify_error!(crate::governance::identity::anchor::AnchorError {
+    UploadFailed => Transient, "ANCHOR_UPLOAD_FAILED";
+    RetrievalFailed => Transient, "ANCHOR_RETRIEVAL_FAILED";
+    SerializationError => Permanent, "ANCHOR_SERIALIZATION";
+    NetworkError => Transient, "ANCHOR_NETWORK";
+    InvalidCID => Permanent, "ANCHOR_INVALID_CID";
+    StorageUnavailable => Transient, "ANCHOR_STORAGE_UNAVAILABLE";
+});
+
+classify_error!(crate::governance::identity::verify::VerificationError {
+    InvalidDID => Permanent, "DID_INVALID";
+    UnsupportedMethod => Permanent, "DID_UNSUPPORTED_METHOD";
+    InvalidSignature => Permanent, "DID_INVALID_SIGNATURE";
+    VerificationFailed => Permanent, "DID_VERIFICATION_FAILED";
+    KeyRecoveryFailed => Permanent, "DID_KEY_RECOVERY_FAILED";
+    InternalError => Permanent, "DID_INTERNAL";
+});
+
+classify_error!(crate::governance::identity::domain_map::DIDMapError {
+    InvalidDID => Permanent, "DID_MAP_INVALID_DID";
+    StorageError => Transient, "DID_MAP_STORAGE";
+    NoMappingFound => Permanent, "DID_MAP_NOT_FOUND";
+    UnsupportedMethod => Permanent, "DID_MAP_UNSUPPORTED_METHOD";
+});
+
+classify_error!(crate::governance::identity::provenance::ProvenanceError {
+    VerificationError => Permanent, "PROVENANCE_VERIFICATION";
+    AnchoringError => Transient, "PROVENANCE_ANCHORING";
+    AgentNotAuthorized => Permanent, "PROVENANCE_NOT_AUTHORIZED";
+    InvalidProvenanceChain => Fatal, "PROVENANCE_INVALID_CHAIN";
+    InternalError => Permanent, "PROVENANCE_INTERNAL";
+});
+
+// Wrapping errors defer to the error they wrap
+
+impl ClassifiedError for crate::strategy_executor::ExecutorError {
+    fn class(&self) -> ErrorClass {
+        use crate::strategy_executor::ExecutorError;
+        match self {
+            ExecutorError::Strategy(e) => e.class(),
+            ExecutorError::Risk(e) => e.class(),
+            ExecutorError::Dependency(e) => e.class(),
+            ExecutorError::Execution(_) => ErrorClass::Transient,
+            ExecutorError::StrategyNotFound { .. } => ErrorClass::Permanent,
+            ExecutorError::Internal(_) => ErrorClass::Permanent,
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        use crate::strategy_executor::ExecutorError;
+        match self {
+            ExecutorError::Strategy(e) => e.code(),
+            ExecutorError::Risk(e) => e.code(),
+            ExecutorError::Dependency(e) => e.code(),
+            ExecutorError::Execution(_) => "EXECUTOR_EXECUTION",
+            ExecutorError::StrategyNotFound { .. } => "EXECUTOR_STRATEGY_NOT_FOUND",
+            ExecutorError::Internal(_) => "EXECUTOR_INTERNAL",
+        }
+    }
+}
+
+impl ClassifiedError for crate::retention::RetentionError {
+    fn class(&self) -> ErrorClass {
+        use crate::retention::RetentionError;
+        match self {
+            RetentionError::Storage(e) => e.class(),
+            RetentionError::Redis(e) => e.class(),
+            RetentionError::Trace(e) => e.class(),
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        use crate::retention::RetentionError;
+        match self {
+            RetentionError::Storage(e) => e.code(),
+            RetentionError::Redis(e) => e.code(),
+            RetentionError::Trace(e) => e.code(),
+        }
+    }
+}
+
+impl ClassifiedError for crate::strategy_retirement::RetirementError {
+    fn class(&self) -> ErrorClass {
+        use crate::strategy_retirement::RetirementError;
+        match self {
+            RetirementError::Executor(e) => e.class(),
+            RetirementError::Storage(e) => e.class(),
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        use crate::strategy_retirement::RetirementError;
+        match self {
+            RetirementError::Executor(e) => e.code(),
+            RetirementError::Storage(e) => e.code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redis::RedisClientError;
+    use crate::risk::RiskError;
+    use crate::strategy_executor::ExecutorError;
+
+    #[test]
+    fn test_classification_and_delegation() {
+        let timeout = RedisClientError::Timeout;
+        assert_eq!(timeout.class(), ErrorClass::Transient);
+        assert!(timeout.is_retryable());
+        assert_eq!(timeout.code(), "REDIS_TIMEOUT");
+
+        let wrapped = ExecutorError::Risk(RiskError::InvalidConfig("max_leverage".to_string()));
+        assert_eq!(wrapped.code(), "RISK_INVALID_CONFIG");
+        assert_eq!(wrapped.class(), ErrorClass::Fatal);
+
+        let report = wrapped.report();
+        assert!(!report.retryable);
+        assert_eq!(report.message, wrapped.to_string());
+    }
+}
@@ -0,0 +1,465 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Calendar of high-impact scheduled events (macro releases, exchange
+//! maintenance, token unlocks) and the trading pauses and size reductions
+//! configured around them.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::market::Symbol;
+
+/// Errors raised by the event calendar
+#[derive(Debug, Error)]
+pub enum EventCalendarError {
+    #[error("Calendar source {source_name} failed: {message}")]
+    Source { source_name: String, message: String },
+
+    #[error("Invalid calendar data: {0}")]
+    InvalidData(String),
+}
+
+/// Result type for event calendar operations
+pub type EventCalendarResult<T> = Result<T, EventCalendarError>;
+
+/// Kind of scheduled event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventCategory {
+    /// Central bank decisions and macro releases (FOMC, CPI, NFP)
+    Macro,
+    /// Planned venue downtime
+    ExchangeMaintenance,
+    /// Scheduled token supply unlocks
+    TokenUnlock,
+    /// Anything else a source reports
+    Other,
+}
+
+/// Expected market impact of an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventImpact {
+    /// Little expected effect
+    Low,
+    /// Noticeable volatility on affected symbols
+    Medium,
+    /// Large moves likely
+    High,
+    /// Market-wide dislocation possible
+    Critical,
+}
+
+/// Scheduled event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    /// Event ID, unique per source
+    pub id: String,
+    /// Human-readable title
+    pub title: String,
+    /// Event kind
+    pub category: EventCategory,
+    /// Expected impact
+    pub impact: EventImpact,
+    /// Scheduled start
+    pub starts_at: DateTime<Utc>,
+    /// Scheduled end; instantaneous events have none
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Affected symbols; empty means every symbol
+    #[serde(default)]
+    pub symbols: Vec<Symbol>,
+    /// Affected venue, for maintenance windows
+    #[serde(default)]
+    pub venue: Option<String>,
+    /// Source the event came from
+    #[serde(default)]
+    pub source: String,
+}
+
+impl CalendarEvent {
+    /// Whether the event affects a symbol
+    pub fn affects(&self, symbol: &Symbol) -> bool {
+        self.symbols.is_empty() || self.symbols.contains(symbol)
+    }
+
+    /// End of the event, or its start if it has no duration
+    pub fn end(&self) -> DateTime<Utc> {
+        self.ends_at.unwrap_or(self.starts_at)
+    }
+}
+
+/// Pluggable provider of scheduled events
+#[async_trait]
+pub trait CalendarSource: Send + Sync {
+    /// Source name used in logs and on events
+    fn name(&self) -> &str;
+
+    /// Events scheduled between `from` and `to`
+    async fn fetch_events(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> EventCalendarResult<Vec<CalendarEvent>>;
+}
+
+/// Source backed by a fixed list of events, e.g. loaded from an operator-maintained file
+pub struct StaticCalendarSource {
+    name: String,
+    events: Vec<CalendarEvent>,
+}
+
+impl StaticCalendarSource {
+    /// Create a source from a list of events
+    pub fn new(name: &str, events: Vec<CalendarEvent>) -> Self {
+        Self { name: name.to_string(), events }
+    }
+
+    /// Create a source from a JSON array of events
+    pub fn from_json(name: &str, json: &str) -> EventCalendarResult<Self> {
+        let events = serde_json::from_str(json).map_err(|e| EventCalendarError::InvalidData(e.to_string()))?;
+        Ok(Self::new(name, events))
+    }
+}
+
+#[async_trait]
+impl CalendarSource for StaticCalendarSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn fetch_events(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> EventCalendarResult<Vec<CalendarEvent>> {
+        Ok(self.events
+            .iter()
+            .filter(|event| event.end() >= from && event.starts_at <= to)
+            .cloned()
+            .collect())
+    }
+}
+
+/// What happens to trading around a matching event
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventAction {
+    /// No new positions
+    Pause,
+    /// Scale position sizes by the given multiplier (0.0-1.0)
+    ReduceSize(f64),
+}
+
+/// Trading restriction applied around events matching the rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRule {
+    /// Category the rule applies to; every category if not set
+    #[serde(default)]
+    pub category: Option<EventCategory>,
+    /// Minimum impact the rule applies to
+    pub min_impact: EventImpact,
+    /// Restriction starts this long before the event (seconds)
+    pub pre_event_secs: i64,
+    /// Restriction lasts this long after the event ends (seconds)
+    pub post_event_secs: i64,
+    /// Restriction applied
+    pub action: EventAction,
+    /// Strategies the rule applies to; every strategy if empty
+    #[serde(default)]
+    pub strategies: Vec<String>,
+}
+
+impl EventRule {
+    /// Whether the rule applies to an event and strategy
+    pub fn matches(&self, event: &CalendarEvent, strategy_id: &str) -> bool {
+        self.category.map_or(true, |category| category == event.category)
+            && event.impact >= self.min_impact
+            && (self.strategies.is_empty() || self.strategies.iter().any(|s| s == strategy_id))
+    }
+
+    /// Whether `now` falls inside the rule's window around the event
+    pub fn is_active(&self, event: &CalendarEvent, now: DateTime<Utc>) -> bool {
+        let start = event.starts_at - chrono::Duration::seconds(self.pre_event_secs);
+        let end = event.end() + chrono::Duration::seconds(self.post_event_secs);
+        now >= start && now <= end
+    }
+}
+
+/// Event calendar configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventCalendarConfig {
+    /// How often sources are polled (seconds)
+    pub refresh_interval_secs: u64,
+    /// How far ahead events are fetched (hours)
+    pub lookahead_hours: i64,
+    /// How long finished events are kept (hours)
+    pub retention_hours: i64,
+    /// Restrictions applied around events
+    pub rules: Vec<EventRule>,
+}
+
+impl Default for EventCalendarConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_secs: 900,
+            lookahead_hours: 72,
+            retention_hours: 24,
+            rules: vec![
+                EventRule {
+                    category: Some(EventCategory::Macro),
+                    min_impact: EventImpact::High,
+                    pre_event_secs: 15 * 60,
+                    post_event_secs: 15 * 60,
+                    action: EventAction::Pause,
+                    strategies: Vec::new(),
+                },
+                EventRule {
+                    category: Some(EventCategory::TokenUnlock),
+                    min_impact: EventImpact::Medium,
+                    pre_event_secs: 6 * 3600,
+                    post_event_secs: 2 * 3600,
+                    action: EventAction::ReduceSize(0.5),
+                    strategies: Vec::new(),
+                },
+                EventRule {
+                    category: Some(EventCategory::ExchangeMaintenance),
+                    min_impact: EventImpact::Low,
+                    pre_event_secs: 10 * 60,
+                    post_event_secs: 5 * 60,
+                    action: EventAction::Pause,
+                    strategies: Vec::new(),
+                },
+            ],
+        }
+    }
+}
+
+/// Combined restriction on a strategy trading a symbol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradingRestriction {
+    /// Whether new positions are paused
+    pub paused: bool,
+    /// Multiplier applied to position sizes (1.0 = unrestricted)
+    pub size_multiplier: f64,
+    /// Events responsible for the restriction
+    pub events: Vec<String>,
+}
+
+impl TradingRestriction {
+    /// No restriction
+    pub fn none() -> Self {
+        Self { paused: false, size_multiplier: 1.0, events: Vec::new() }
+    }
+
+    /// Whether any restriction applies
+    pub fn is_restricted(&self) -> bool {
+        self.paused || self.size_multiplier < 1.0
+    }
+}
+
+/// Calendar of scheduled events aggregated from pluggable sources
+pub struct EventCalendar {
+    /// Configuration
+    config: RwLock<EventCalendarConfig>,
+    /// Event sources
+    sources: RwLock<Vec<Arc<dyn CalendarSource>>>,
+    /// Known events, ordered by start time
+    events: RwLock<Vec<CalendarEvent>>,
+    /// Refresh task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl EventCalendar {
+    /// Create an empty calendar
+    pub fn new(config: EventCalendarConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            sources: RwLock::new(Vec::new()),
+            events: RwLock::new(Vec::new()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Register an event source
+    pub async fn add_source(&self, source: Arc<dyn CalendarSource>) {
+        info!("Registered calendar source {}", source.name());
+        self.sources.write().await.push(source);
+    }
+
+    /// Replace the restriction rules
+    pub async fn set_rules(&self, rules: Vec<EventRule>) {
+        self.config.write().await.rules = rules;
+    }
+
+    /// Add an event directly, e.g. an ad-hoc maintenance announcement
+    pub async fn add_event(&self, event: CalendarEvent) {
+        let mut events = self.events.write().await;
+        events.retain(|e| !(e.id == event.id && e.source == event.source));
+        events.push(event);
+        events.sort_by_key(|e| e.starts_at);
+    }
+
+    /// Poll every source; a failing source keeps its previously fetched events.
+    ///
+    /// Returns the number of events known after the refresh.
+    pub async fn refresh(&self, now: DateTime<Utc>) -> usize {
+        let (lookahead, retention) = {
+            let config = self.config.read().await;
+            (config.lookahead_hours, config.retention_hours)
+        };
+        let from = now - chrono::Duration::hours(retention);
+        let to = now + chrono::Duration::hours(lookahead);
+
+        let sources = self.sources.read().await.clone();
+        let mut fetched = Vec::new();
+        let mut failed = Vec::new();
+        for source in sources {
+            match source.fetch_events(from, to).await {
+                Ok(events) => {
+                    debug!("Calendar source {} returned {} events", source.name(), events.len());
+                    fetched.extend(events.into_iter().map(|mut event| {
+                        event.source = source.name().to_string();
+                        event
+                    }));
+                }
+                Err(e) => {
+                    warn!("Calendar source {} failed: {}", source.name(), e);
+                    failed.push(source.name().to_string());
+                }
+            }
+        }
+
+        let mut events = self.events.write().await;
+        // Keep events from failed sources and ad-hoc events that have not expired
+        events.retain(|event| {
+            event.end() >= from && (failed.contains(&event.source) || event.source.is_empty())
+        });
+        events.extend(fetched);
+        events.sort_by_key(|event| event.starts_at);
+        events.len()
+    }
+
+    /// Events starting between now and `within` from now
+    pub async fn upcoming(&self, now: DateTime<Utc>, within: chrono::Duration) -> Vec<CalendarEvent> {
+        self.events
+            .read()
+            .await
+            .iter()
+            .filter(|event| event.end() >= now && event.starts_at <= now + within)
+            .cloned()
+            .collect()
+    }
+
+    /// Restriction in force for a strategy trading a symbol at `now`.
+    ///
+    /// Pauses win over size reductions; multiple reductions take the smallest multiplier.
+    pub async fn restriction(&self, strategy_id: &str, symbol: &Symbol, now: DateTime<Utc>) -> TradingRestriction {
+        let config = self.config.read().await;
+        let events = self.events.read().await;
+        let mut restriction = TradingRestriction::none();
+
+        for event in events.iter().filter(|event| event.affects(symbol)) {
+            for rule in config.rules.iter().filter(|rule| rule.matches(event, strategy_id)) {
+                if !rule.is_active(event, now) {
+                    continue;
+                }
+                match rule.action {
+                    EventAction::Pause => restriction.paused = true,
+                    EventAction::ReduceSize(multiplier) => {
+                        restriction.size_multiplier = restriction.size_multiplier.min(multiplier.clamp(0.0, 1.0));
+                    }
+                }
+                if !restriction.events.contains(&event.title) {
+                    restriction.events.push(event.title.clone());
+                }
+            }
+        }
+
+        restriction
+    }
+
+    /// Start polling sources in the background
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let calendar = Arc::clone(self);
+        let interval_secs = self.config.read().await.refresh_interval_secs.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                calendar.refresh(Utc::now()).await;
+            }
+        }));
+    }
+
+    /// Stop polling sources
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create an event calendar
+pub fn create_event_calendar(config: EventCalendarConfig) -> Arc<EventCalendar> {
+    Arc::new(EventCalendar::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: &str, category: EventCategory, impact: EventImpact, starts_at: DateTime<Utc>, symbols: &[&str]) -> CalendarEvent {
+        CalendarEvent {
+            id: id.to_string(),
+            title: id.to_string(),
+            category,
+            impact,
+            starts_at,
+            ends_at: None,
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+            venue: None,
+            source: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pre_and_post_event_restrictions() {
+        let now = Utc::now();
+        let calendar = create_event_calendar(EventCalendarConfig::default());
+        calendar.add_source(Arc::new(StaticCalendarSource::new("static", vec![
+            event("FOMC", EventCategory::Macro, EventImpact::Critical, now + chrono::Duration::minutes(10), &[]),
+            event("ARB unlock", EventCategory::TokenUnlock, EventImpact::High, now + chrono::Duration::hours(3), &["ARB/USD"]),
+        ]))).await;
+        assert_eq!(calendar.refresh(now).await, 2);
+
+        let btc = calendar.restriction("trend", &"BTC/USD".to_string(), now).await;
+        assert!(btc.paused);
+        assert_eq!(btc.events, vec!["FOMC".to_string()]);
+
+        // After the FOMC window only the unlock reduction applies to ARB
+        let later = now + chrono::Duration::hours(1);
+        let arb = calendar.restriction("trend", &"ARB/USD".to_string(), later).await;
+        assert!(!arb.paused);
+        assert_eq!(arb.size_multiplier, 0.5);
+        assert!(!calendar.restriction("trend", &"BTC/USD".to_string(), later).await.is_restricted());
+    }
+}
@@ -119,6 +119,7 @@ pub async fn run_correlation_example() -> Result<(), Box<dyn std::error::Error>>
         volatility_weight: 0.3,
         sharpe_weight: 0.4,
         correlation_penalty: 0.5,
+        constraints: None,
     };
     
     let risk_allocator = create_risk_allocator_with_config(
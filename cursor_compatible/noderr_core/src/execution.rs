@@ -25,6 +25,7 @@ use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::strategy::Signal;
+use crate::simulation::latency_model::{LatencyModel, LatencyModelConfig};
 
 /// Errors that can occur during trade execution
 #[derive(Debug, Error)]
@@ -81,6 +82,13 @@ pub enum ExecutionStatus {
     Failed,
 }
 
+impl ExecutionStatus {
+    /// Whether the execution will not change any further
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, ExecutionStatus::Received | ExecutionStatus::InProgress | ExecutionStatus::PartiallyFilled)
+    }
+}
+
 /// Mode of trade execution
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExecutionMode {
@@ -654,6 +662,8 @@ pub struct ExecutionService {
     paper_provider: Arc<dyn ExecutionProvider>,
     /// Provider for sandbox/testnet trading
     sandbox_provider: Option<Arc<dyn ExecutionProvider>>,
+    /// Provider for historical replays
+    backtest_provider: Option<Arc<dyn ExecutionProvider>>,
     /// Selected execution mode
     mode: Arc<RwLock<ExecutionMode>>,
     /// Recent executions cache
@@ -677,6 +687,7 @@ impl ExecutionService {
             live_provider,
             paper_provider,
             sandbox_provider,
+            backtest_provider: None,
             mode: Arc::new(RwLock::new(ExecutionMode::Paper)), // Default to paper trading
             recent_executions: Arc::new(Mutex::new(HashMap::new())),
             max_recent_executions: 1000,
@@ -685,6 +696,12 @@ impl ExecutionService {
         }
     }
     
+    /// Route backtest mode executions to a simulated fill provider
+    pub fn with_backtest_provider(mut self, provider: Arc<dyn ExecutionProvider>) -> Self {
+        self.backtest_provider = Some(provider);
+        self
+    }
+    
     /// Set the execution mode
     pub fn set_mode(&self, mode: ExecutionMode) -> Result<(), ExecutionError> {
         let mut current_mode = self.mode.write().unwrap();
@@ -717,9 +734,15 @@ impl ExecutionService {
                 }
             }
             ExecutionMode::Backtest => {
-                return Err(ExecutionError::NotSupported(
-                    "Backtest mode is not supported by ExecutionService directly".into()
-                ));
+                if let Some(ref provider) = self.backtest_provider {
+                    if !provider.supports_mode(mode) {
+                        return Err(ExecutionError::NotSupported(
+                            format!("Backtest mode not supported by provider {}", provider.name())
+                        ));
+                    }
+                } else {
+                    return Err(ExecutionError::NotSupported("No backtest provider configured".into()));
+                }
             }
         }
         
@@ -768,90 +791,77 @@ impl ExecutionService {
             }
         }
         
-        // Execute request with appropriate provider
-        let result = match mode {
-            ExecutionMode::Live => self.live_provider.execute(request).await,
-            ExecutionMode::Paper => self.paper_provider.execute(request).await,
-            ExecutionMode::Sandbox => {
-                if let Some(ref provider) = self.sandbox_provider {
-                    provider.execute(request).await
-                } else {
-                    return Err(ExecutionError::NotSupported("No sandbox provider configured".into()));
-                }
-            }
-            ExecutionMode::Backtest => {
-                return Err(ExecutionError::NotSupported(
-                    "Backtest mode execution is not supported via execute_signal".into()
-                ));
-            }
-        };
+        self.execute(request).await
+    }
+    
+    /// Execute a request in the mode it carries rather than the service's current mode
+    ///
+    /// This lets the strategy executor paper trade some strategies while
+    /// others trade live through the same service.
+    pub async fn execute(&self, request: ExecutionRequest) -> Result<ExecutionResult, ExecutionError> {
+        let mode = request.mode;
+        let mut result = self.provider_for(mode)?.execute(request).await?;
         
-        // Cache successful executions
-        if let Ok(ref execution_result) = result {
-            self.cache_execution_result(execution_result.clone());
-        }
+        // Remember the mode so status checks and cancels reach the same provider
+        result.additional_data.insert("execution_mode".to_string(), serde_json::json!(mode));
+        self.cache_execution_result(result.clone());
         
-        result
+        Ok(result)
+    }
+    
+    /// Provider that handles a mode
+    fn provider_for(&self, mode: ExecutionMode) -> Result<&Arc<dyn ExecutionProvider>, ExecutionError> {
+        match mode {
+            ExecutionMode::Live => Ok(&self.live_provider),
+            ExecutionMode::Paper => Ok(&self.paper_provider),
+            ExecutionMode::Sandbox => self.sandbox_provider.as_ref()
+                .ok_or_else(|| ExecutionError::NotSupported("No sandbox provider configured".into())),
+            ExecutionMode::Backtest => self.backtest_provider.as_ref()
+                .ok_or_else(|| ExecutionError::NotSupported("No backtest provider configured".into())),
+        }
+    }
+    
+    /// Mode an execution was sent in, or the current mode if it is not cached
+    fn mode_of(&self, request_id: &str) -> ExecutionMode {
+        let executions = self.recent_executions.lock().unwrap();
+        executions.get(request_id)
+            .and_then(|result| result.additional_data.get("execution_mode"))
+            .and_then(|mode| serde_json::from_value(mode.clone()).ok())
+            .unwrap_or_else(|| *self.mode.read().unwrap())
     }
     
     /// Get the status of an execution by ID
     pub async fn get_execution_status(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
-        // First check the cache
-        {
-            let executions = self.recent_executions.lock().unwrap();
-            if let Some(result) = executions.get(request_id) {
+        // Finished executions are served from the cache
+        let cached = self.recent_executions.lock().unwrap().get(request_id).cloned();
+        if let Some(result) = &cached {
+            if result.status.is_terminal() {
                 return Ok(result.clone());
             }
         }
         
-        // If not in cache, check with the appropriate provider
-        let mode = *self.mode.read().unwrap();
-        
-        match mode {
-            ExecutionMode::Live => self.live_provider.get_status(request_id).await,
-            ExecutionMode::Paper => self.paper_provider.get_status(request_id).await,
-            ExecutionMode::Sandbox => {
-                if let Some(ref provider) = self.sandbox_provider {
-                    provider.get_status(request_id).await
-                } else {
-                    Err(ExecutionError::NotSupported("No sandbox provider configured".into()))
-                }
-            }
-            ExecutionMode::Backtest => {
-                Err(ExecutionError::NotSupported(
-                    "Backtest mode status check is not supported".into()
-                ))
+        // Working orders can fill at any time, so ask the provider that holds them
+        let mode = self.mode_of(request_id);
+        match self.provider_for(mode)?.get_status(request_id).await {
+            Ok(mut result) => {
+                result.additional_data.insert("execution_mode".to_string(), serde_json::json!(mode));
+                self.cache_execution_result(result.clone());
+                Ok(result)
             }
+            Err(e) => cached.ok_or(e),
         }
     }
     
     /// Cancel an execution by ID
     pub async fn cancel_execution(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
-        let mode = *self.mode.read().unwrap();
-        
-        let result = match mode {
-            ExecutionMode::Live => self.live_provider.cancel(request_id).await,
-            ExecutionMode::Paper => self.paper_provider.cancel(request_id).await,
-            ExecutionMode::Sandbox => {
-                if let Some(ref provider) = self.sandbox_provider {
-                    provider.cancel(request_id).await
-                } else {
-                    Err(ExecutionError::NotSupported("No sandbox provider configured".into()))
-                }
-            }
-            ExecutionMode::Backtest => {
-                Err(ExecutionError::NotSupported(
-                    "Backtest mode cancellation is not supported".into()
-                ))
-            }
-        };
+        let mode = self.mode_of(request_id);
+        let mut result = self.provider_for(mode)?.cancel(request_id).await?;
         
         // Update cache if successful
-        if let Ok(ref execution_result) = result {
-            self.cache_execution_result(execution_result.clone());
-        }
+        result.additional_data.insert("execution_mode".to_string(), serde_json::json!(mode));
+        self.cache_execution_result(result.clone());
         
-        result
+        Ok(result)
     }
     
     /// Get recent executions matching optional filters
@@ -913,6 +923,8 @@ pub struct PaperTradingProvider {
     slippage_pct: f64,
     /// Random failure rate to simulate execution issues (0.0-1.0)
     failure_rate: f64,
+    /// Transit and matching delay model; replaces `latency_range_ms` when set
+    latency_model: Option<Mutex<LatencyModel>>,
 }
 
 impl PaperTradingProvider {
@@ -924,8 +936,15 @@ impl PaperTradingProvider {
             fill_rate: 1.0,
             slippage_pct: 0.05,
             failure_rate: 0.01,
+            latency_model: None,
         }
     }
+
+    /// Draw simulated latency from a transit and matching delay model
+    pub fn with_latency_model(mut self, config: LatencyModelConfig) -> Self {
+        self.latency_model = Some(Mutex::new(LatencyModel::new(config)));
+        self
+    }
     
     /// Configure simulation parameters
     pub fn configure(
@@ -941,17 +960,23 @@ impl PaperTradingProvider {
         self.failure_rate = failure_rate.clamp(0.0, 1.0);
     }
     
-    /// Simulate network latency
-    async fn simulate_latency(&self) {
-        let (min, max) = self.latency_range_ms;
-        let latency = min + (rand::random::<f64>() * (max - min) as f64) as u64;
+    /// Simulate network latency to a venue
+    async fn simulate_latency(&self, venue: &str) {
+        let latency = match &self.latency_model {
+            Some(model) => model.lock().unwrap().sample(venue).total_ms() as u64,
+            None => {
+                let (min, max) = self.latency_range_ms;
+                min + (rand::random::<f64>() * (max - min) as f64) as u64
+            }
+        };
         time::sleep(Duration::from_millis(latency)).await;
     }
     
     /// Simulate execution with configurable parameters
     async fn simulate_execution(&self, request: ExecutionRequest) -> Result<ExecutionResult, ExecutionError> {
         // Simulate network latency
-        self.simulate_latency().await;
+        let venue = request.parameters.get("venue").and_then(|v| v.as_str()).unwrap_or_default();
+        self.simulate_latency(venue).await;
         
         // Simulate random failures
         if rand::random::<f64>() < self.failure_rate {
@@ -1037,7 +1062,7 @@ impl ExecutionProvider for PaperTradingProvider {
     }
     
     async fn cancel(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
-        self.simulate_latency().await;
+        self.simulate_latency("").await;
         
         let mut executions = self.executions.write().unwrap();
         
@@ -1061,7 +1086,7 @@ impl ExecutionProvider for PaperTradingProvider {
     }
     
     async fn get_status(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
-        self.simulate_latency().await;
+        self.simulate_latency("").await;
         
         let executions = self.executions.read().unwrap();
         
@@ -1197,6 +1222,23 @@ impl ExecutionLog {
             log.execution_latency_ms = latency.total_ms;
         }
         
+        // Carry child order lineage so metrics can roll up to the parent order
+        let lineage: HashMap<String, serde_json::Value> = ["parent_order_id", "signal_id", "child_index", "client_order_id"]
+            .into_iter()
+            .filter_map(|key| result.additional_data.get(key).map(|value| (key.to_string(), value.clone())))
+            .collect();
+        if let Some(parent) = lineage.get("parent_order_id").and_then(|v| v.as_str()) {
+            log.tags = Some(vec![format!("parent:{}", parent)]);
+        }
+        if !lineage.is_empty() {
+            log.metadata = Some(lineage);
+        }
+        if log.strategy_id.is_empty() {
+            if let Some(strategy_id) = result.additional_data.get("strategy_id").and_then(|v| v.as_str()) {
+                log.strategy_id = strategy_id.to_string();
+            }
+        }
+        
         log
     }
     
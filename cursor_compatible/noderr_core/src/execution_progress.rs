@@ -0,0 +1,504 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Live progress of long-running execution algorithms.
+//!
+//! The execution strategy router registers every TWAP/VWAP/IS execution here
+//! and reports each slice as it completes. Progress is compared against a
+//! linear schedule to give a schedule deviation and a projected completion
+//! time, broadcast to subscribers (the API WebSocket) and, when a telemetry
+//! streamer is attached, published on the `execution_progress` channel.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+use crate::execution::{ExecutionResult, ExecutionStatus};
+use crate::execution_strategy::ExecutionAlgorithm;
+use crate::order_lineage::OrderLineage;
+use crate::order_router::Order;
+use crate::telemetry_streamer::{TelemetryMessageType, TelemetryStreamer};
+
+/// Fraction of the target treated as fully filled
+const FILL_TOLERANCE: f64 = 1e-9;
+
+/// Furthest out a completion time is projected
+const MAX_PROJECTION_DAYS: i64 = 365;
+
+/// Configuration for [`ExecutionProgressTracker`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExecutionProgressConfig {
+    /// Finished executions kept for queries before the oldest are dropped
+    pub max_finished: usize,
+    /// Undelivered updates buffered per subscriber
+    pub channel_capacity: usize,
+}
+
+impl Default for ExecutionProgressConfig {
+    fn default() -> Self {
+        Self {
+            max_finished: 500,
+            channel_capacity: 256,
+        }
+    }
+}
+
+/// Lifecycle state of a tracked execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionProgressState {
+    /// Slices are still being worked
+    Running,
+    /// Suspended by an operator; no new slices are sent until resumed
+    Paused,
+    /// The target quantity or every slice is done
+    Completed,
+    /// Cancelled by an operator or the strategy
+    Cancelled,
+    /// The algorithm stopped on an error
+    Failed,
+}
+
+impl ExecutionProgressState {
+    /// Whether no further updates are expected
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, ExecutionProgressState::Running | ExecutionProgressState::Paused)
+    }
+}
+
+/// A recorded state change or in-flight modification of an execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionTransition {
+    /// State before the change
+    pub from: ExecutionProgressState,
+    /// State after the change; equal to `from` for modifications
+    pub to: ExecutionProgressState,
+    /// Who made the change
+    pub actor: String,
+    /// Change-specific details, e.g. the modified parameters
+    pub details: serde_json::Value,
+    /// When the change was made
+    pub at: DateTime<Utc>,
+}
+
+/// Progress snapshot for one parent order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionProgress {
+    /// Parent order ID
+    pub order_id: String,
+    /// Strategy that generated the order
+    pub strategy_id: Option<String>,
+    /// Symbol being traded
+    pub symbol: String,
+    /// Algorithm working the order
+    pub algorithm: ExecutionAlgorithm,
+    /// Lifecycle state
+    pub state: ExecutionProgressState,
+    /// Slices planned
+    pub total_slices: u32,
+    /// Slices reported so far
+    pub slices_done: u32,
+    /// Quantity to fill
+    pub target_quantity: f64,
+    /// Quantity filled so far
+    pub filled_quantity: f64,
+    /// Volume-weighted fill price so far
+    pub average_price: Option<f64>,
+    /// Percentage of the target filled
+    pub percent_filled: f64,
+    /// Percentage the schedule expects to be filled by now
+    pub expected_percent: f64,
+    /// `percent_filled - expected_percent`; negative means behind schedule
+    pub schedule_deviation_pct: f64,
+    /// When the execution started
+    pub started_at: DateTime<Utc>,
+    /// When the schedule says it should finish
+    pub scheduled_end: DateTime<Utc>,
+    /// Completion time projected from the fill rate so far; unset while paused
+    pub projected_completion: Option<DateTime<Utc>>,
+    /// When the current pause started
+    #[serde(default)]
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Time spent paused before the current pause, in milliseconds
+    #[serde(default)]
+    pub paused_ms: i64,
+    /// Pauses, resumes, modifications and terminal states, oldest first
+    #[serde(default)]
+    pub transitions: Vec<ExecutionTransition>,
+    /// Last update
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExecutionProgress {
+    /// Start tracking an order worked over `slices` slices within `duration`
+    pub fn new(order: &Order, algorithm: ExecutionAlgorithm, slices: u32, duration: Duration, now: DateTime<Utc>) -> Self {
+        let mut progress = Self {
+            order_id: order.id.clone(),
+            strategy_id: OrderLineage::of(order).strategy_id,
+            symbol: order.symbol.clone(),
+            algorithm,
+            state: ExecutionProgressState::Running,
+            total_slices: slices.max(1),
+            slices_done: 0,
+            target_quantity: order.amount.abs(),
+            filled_quantity: 0.0,
+            average_price: None,
+            percent_filled: 0.0,
+            expected_percent: 0.0,
+            schedule_deviation_pct: 0.0,
+            started_at: now,
+            scheduled_end: now + duration.max(Duration::milliseconds(1)),
+            projected_completion: None,
+            paused_at: None,
+            paused_ms: 0,
+            transitions: Vec::new(),
+            updated_at: now,
+        };
+        progress.refresh(now);
+        progress
+    }
+
+    /// Apply one slice result
+    pub fn record_slice(&mut self, result: &ExecutionResult, now: DateTime<Utc>) {
+        let quantity = result.executed_quantity.unwrap_or(0.0).abs();
+        if quantity > 0.0 {
+            let price = result.average_price.unwrap_or(0.0);
+            let notional = self.average_price.unwrap_or(0.0) * self.filled_quantity + price * quantity;
+            self.filled_quantity += quantity;
+            self.average_price = Some(notional / self.filled_quantity);
+        }
+        self.slices_done = (self.slices_done + 1).min(self.total_slices);
+
+        self.state = match result.status {
+            ExecutionStatus::Cancelled => ExecutionProgressState::Cancelled,
+            ExecutionStatus::Failed | ExecutionStatus::Rejected if self.filled_quantity == 0.0 => {
+                ExecutionProgressState::Failed
+            }
+            _ if self.filled_quantity >= self.target_quantity * (1.0 - FILL_TOLERANCE)
+                || self.slices_done >= self.total_slices =>
+            {
+                ExecutionProgressState::Completed
+            }
+            // Children already in flight may still fill while paused
+            _ => match self.state {
+                ExecutionProgressState::Paused => ExecutionProgressState::Paused,
+                _ => ExecutionProgressState::Running,
+            },
+        };
+        self.refresh(now);
+    }
+
+    /// Move to `to`, recording who did it; pausing stops the schedule clock
+    /// and resuming shifts the scheduled end by the time spent paused
+    pub fn transition(&mut self, to: ExecutionProgressState, actor: &str, details: serde_json::Value, now: DateTime<Utc>) {
+        match (self.paused_at.take(), to) {
+            (None, ExecutionProgressState::Paused) => self.paused_at = Some(now),
+            (Some(paused_at), ExecutionProgressState::Paused) => self.paused_at = Some(paused_at),
+            (Some(paused_at), _) => {
+                let paused = now - paused_at;
+                self.paused_ms += paused.num_milliseconds();
+                self.scheduled_end = self.scheduled_end + paused;
+            }
+            (None, _) => {}
+        }
+
+        self.transitions.push(ExecutionTransition {
+            from: self.state,
+            to,
+            actor: actor.to_string(),
+            details,
+            at: now,
+        });
+        self.state = to;
+        self.refresh(now);
+    }
+
+    /// Recompute schedule-relative fields at `now`
+    pub fn refresh(&mut self, now: DateTime<Utc>) {
+        // Time spent paused counts towards neither the schedule nor the fill rate
+        let paused_ms = self.paused_ms + self.paused_at.map_or(0, |at| (now - at).num_milliseconds());
+        let schedule_ms = ((self.scheduled_end - self.started_at).num_milliseconds() - self.paused_ms).max(1) as f64;
+        let elapsed_ms = ((now - self.started_at).num_milliseconds() - paused_ms).max(0) as f64;
+
+        self.percent_filled = if self.target_quantity > 0.0 {
+            (self.filled_quantity / self.target_quantity * 100.0).min(100.0)
+        } else {
+            100.0
+        };
+        self.expected_percent = (elapsed_ms / schedule_ms * 100.0).min(100.0);
+        self.schedule_deviation_pct = self.percent_filled - self.expected_percent;
+
+        self.projected_completion = if self.state.is_finished() {
+            Some(now)
+        } else if self.state == ExecutionProgressState::Paused {
+            None
+        } else if self.filled_quantity > 0.0 && elapsed_ms > 0.0 {
+            // Extrapolate the fill rate so far over the remaining quantity
+            let remaining = (self.target_quantity - self.filled_quantity).max(0.0);
+            let remaining_ms = (remaining * elapsed_ms / self.filled_quantity).round();
+            let remaining = Duration::milliseconds(remaining_ms as i64).min(Duration::days(MAX_PROJECTION_DAYS));
+            Some(now + remaining)
+        } else {
+            Some(self.scheduled_end.max(now))
+        };
+        self.updated_at = now;
+    }
+}
+
+/// Tracks and publishes progress for running executions
+pub struct ExecutionProgressTracker {
+    /// Configuration
+    config: ExecutionProgressConfig,
+    /// Progress by parent order ID
+    executions: RwLock<HashMap<String, ExecutionProgress>>,
+    /// Progress broadcast
+    sender: broadcast::Sender<ExecutionProgress>,
+    /// Telemetry streamer progress is published to (optional)
+    telemetry: Option<Arc<dyn TelemetryStreamer>>,
+}
+
+impl ExecutionProgressTracker {
+    /// Create a new tracker
+    pub fn new(config: ExecutionProgressConfig) -> Self {
+        let (sender, _) = broadcast::channel(config.channel_capacity.max(1));
+        Self {
+            config,
+            executions: RwLock::new(HashMap::new()),
+            sender,
+            telemetry: None,
+        }
+    }
+
+    /// Publish progress on the telemetry `execution_progress` channel
+    pub fn with_telemetry(mut self, telemetry: Arc<dyn TelemetryStreamer>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Start tracking an execution
+    pub fn start(&self, order: &Order, algorithm: ExecutionAlgorithm, slices: u32, duration: Duration) -> ExecutionProgress {
+        let progress = ExecutionProgress::new(order, algorithm, slices, duration, Utc::now());
+        self.executions.write().unwrap().insert(order.id.clone(), progress.clone());
+        self.prune_finished();
+        self.publish(&progress);
+        progress
+    }
+
+    /// Record a slice result for a tracked execution
+    pub fn record_slice(&self, order_id: &str, result: &ExecutionResult) -> Option<ExecutionProgress> {
+        let progress = {
+            let mut executions = self.executions.write().unwrap();
+            let progress = executions.get_mut(order_id)?;
+            if progress.state.is_finished() {
+                debug!("Ignoring slice for finished execution {}", order_id);
+                return Some(progress.clone());
+            }
+            progress.record_slice(result, Utc::now());
+            progress.clone()
+        };
+        self.publish(&progress);
+        Some(progress)
+    }
+
+    /// Mark a running execution as finished
+    pub fn finish(&self, order_id: &str, state: ExecutionProgressState) -> Option<ExecutionProgress> {
+        self.transition(order_id, state, "system", serde_json::Value::Null)
+    }
+
+    /// Record a state change made by `actor`; finished executions are left as they are
+    pub fn transition(
+        &self,
+        order_id: &str,
+        to: ExecutionProgressState,
+        actor: &str,
+        details: serde_json::Value,
+    ) -> Option<ExecutionProgress> {
+        let progress = {
+            let mut executions = self.executions.write().unwrap();
+            let progress = executions.get_mut(order_id)?;
+            if progress.state.is_finished() {
+                return Some(progress.clone());
+            }
+            progress.transition(to, actor, details, Utc::now());
+            progress.clone()
+        };
+        info!("Execution {} is now {:?} ({})", order_id, progress.state, actor);
+        self.publish(&progress);
+        Some(progress)
+    }
+
+    /// Move a running execution's scheduled end, recording the modification
+    pub fn reschedule(
+        &self,
+        order_id: &str,
+        scheduled_end: Option<DateTime<Utc>>,
+        actor: &str,
+        details: serde_json::Value,
+    ) -> Option<ExecutionProgress> {
+        let progress = {
+            let mut executions = self.executions.write().unwrap();
+            let progress = executions.get_mut(order_id)?;
+            if progress.state.is_finished() {
+                return Some(progress.clone());
+            }
+            if let Some(end) = scheduled_end {
+                progress.scheduled_end = end.max(progress.started_at + Duration::milliseconds(1));
+            }
+            let state = progress.state;
+            progress.transition(state, actor, details, Utc::now());
+            progress.clone()
+        };
+        self.publish(&progress);
+        Some(progress)
+    }
+
+    /// Current progress for an execution
+    pub fn get(&self, order_id: &str) -> Option<ExecutionProgress> {
+        let mut progress = self.executions.read().unwrap().get(order_id).cloned()?;
+        if !progress.state.is_finished() {
+            progress.refresh(Utc::now());
+        }
+        Some(progress)
+    }
+
+    /// Progress for every tracked execution, unfinished ones first
+    pub fn list(&self, running_only: bool) -> Vec<ExecutionProgress> {
+        let now = Utc::now();
+        let mut executions: Vec<ExecutionProgress> = self.executions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|p| !running_only || !p.state.is_finished())
+            .cloned()
+            .collect();
+        for progress in executions.iter_mut().filter(|p| !p.state.is_finished()) {
+            progress.refresh(now);
+        }
+        executions.sort_by(|a, b| {
+            a.state.is_finished().cmp(&b.state.is_finished()).then(b.started_at.cmp(&a.started_at))
+        });
+        executions
+    }
+
+    /// Subscribe to progress updates
+    pub fn subscribe(&self) -> broadcast::Receiver<ExecutionProgress> {
+        self.sender.subscribe()
+    }
+
+    fn publish(&self, progress: &ExecutionProgress) {
+        let _ = self.sender.send(progress.clone());
+
+        let Some(telemetry) = self.telemetry.clone() else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let order_id = progress.order_id.clone();
+        let payload = serde_json::to_value(progress).unwrap_or_default();
+        handle.spawn(async move {
+            if let Err(e) = telemetry
+                .publish_system_update(TelemetryMessageType::ExecutionProgress, &order_id, payload)
+                .await
+            {
+                warn!("Failed to publish execution progress for {}: {}", order_id, e);
+            }
+        });
+    }
+
+    /// Drop the oldest finished executions beyond the retention limit
+    fn prune_finished(&self) {
+        let mut executions = self.executions.write().unwrap();
+        let mut finished: Vec<(DateTime<Utc>, String)> = executions
+            .values()
+            .filter(|p| p.state.is_finished())
+            .map(|p| (p.updated_at, p.order_id.clone()))
+            .collect();
+        if finished.len() <= self.config.max_finished {
+            return;
+        }
+        finished.sort();
+        let excess = finished.len() - self.config.max_finished;
+        for (_, order_id) in finished.into_iter().take(excess) {
+            executions.remove(&order_id);
+        }
+    }
+}
+
+/// Create an execution progress tracker
+pub fn create_execution_progress_tracker(config: ExecutionProgressConfig) -> Arc<ExecutionProgressTracker> {
+    Arc::new(ExecutionProgressTracker::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_router::{OrderSide, TimeInForce};
+
+    #[test]
+    fn test_progress_tracks_schedule() {
+        let order = Order {
+            symbol: "ETH/USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 10.0,
+            price: 3_000.0,
+            venues: vec!["binance".to_string()],
+            id: "twap-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        let start = Utc::now();
+        let mut progress = ExecutionProgress::new(&order, ExecutionAlgorithm::TWAP, 4, Duration::minutes(4), start);
+        assert_eq!(progress.projected_completion, Some(progress.scheduled_end));
+
+        // Half the schedule elapsed but only a quarter filled: behind by 25%
+        let slice = |qty: f64, price: f64| ExecutionResult::success("twap-1".into(), String::new(), None, qty, price);
+        progress.record_slice(&slice(2.5, 3_000.0), start + Duration::minutes(2));
+        assert_eq!(progress.slices_done, 1);
+        assert!((progress.percent_filled - 25.0).abs() < 1e-9);
+        assert!((progress.schedule_deviation_pct + 25.0).abs() < 1e-9);
+        assert_eq!(progress.projected_completion, Some(start + Duration::minutes(8)));
+
+        // A one-minute pause freezes the schedule and pushes the end out
+        progress.transition(ExecutionProgressState::Paused, "ops", serde_json::Value::Null, start + Duration::minutes(2));
+        assert_eq!(progress.projected_completion, None);
+        progress.transition(ExecutionProgressState::Running, "ops", serde_json::Value::Null, start + Duration::minutes(3));
+        assert_eq!(progress.scheduled_end, start + Duration::minutes(5));
+        assert!((progress.expected_percent - 50.0).abs() < 1e-9);
+        assert_eq!(progress.transitions.len(), 2);
+
+        progress.record_slice(&slice(7.5, 3_100.0), start + Duration::minutes(3));
+        assert_eq!(progress.state, ExecutionProgressState::Completed);
+        assert!((progress.average_price.unwrap() - 3_075.0).abs() < 1e-9);
+
+        let tracker = ExecutionProgressTracker::new(ExecutionProgressConfig::default());
+        let mut updates = tracker.subscribe();
+        tracker.start(&order, ExecutionAlgorithm::VWAP, 2, Duration::minutes(10));
+        tracker.finish("twap-1", ExecutionProgressState::Cancelled);
+        assert!(tracker.record_slice("twap-1", &slice(1.0, 3_000.0)).unwrap().state.is_finished());
+        assert_eq!(updates.try_recv().unwrap().state, ExecutionProgressState::Running);
+        assert_eq!(updates.try_recv().unwrap().state, ExecutionProgressState::Cancelled);
+        assert!(tracker.list(true).is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Randomized execution schedules
+//!
+//! A TWAP that trades the same size on the same clock is easy to spot in
+//! the tape and trade ahead of. The randomizer takes the baseline TWAP
+//! schedule and jitters each slice's time (within `max_interval_deviation_ms`,
+//! never enough to reorder slices) and, when `randomize_sizes` is set, its
+//! size (within `size_deviation_pct`). Cumulative quantity never drifts from
+//! the baseline by more than `max_cumulative_deviation_pct` of the parent,
+//! and the last slice absorbs the remainder so the total is unchanged.
+//!
+//! Live schedules draw from the EntropyInjector. With a seed configured the
+//! draws come from an RNG seeded per parent order instead, so a backtest
+//! replays the same schedules regardless of the order parents arrive in.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::execution_strategy::TWAPConfig;
+use crate::strategy::EntropyInjector;
+
+/// Order parameter carrying the slice schedule an executor should follow
+pub const SCHEDULE_PARAM: &str = "executionSchedule";
+
+/// Schedule randomization configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleRandomizerConfig {
+    /// Whether schedules are randomized at all
+    pub enabled: bool,
+    /// Largest gap between randomized and baseline cumulative quantity (fraction of parent)
+    pub max_cumulative_deviation_pct: f64,
+    /// Seed for reproducible schedules (backtests); live trading leaves this unset
+    pub seed: Option<u64>,
+}
+
+impl Default for ScheduleRandomizerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_cumulative_deviation_pct: 0.05,
+            seed: None,
+        }
+    }
+}
+
+/// One slice of an execution schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledSlice {
+    /// Slice index (0-based)
+    pub index: u32,
+    /// When the slice should be sent
+    pub at: DateTime<Utc>,
+    /// Quantity to trade
+    pub quantity: f64,
+}
+
+/// Source of uniform draws for one schedule
+enum Draws<'a> {
+    Seeded(StdRng),
+    Entropy(&'a dyn EntropyInjector),
+}
+
+impl Draws<'_> {
+    /// Uniform draw in [-1, 1]
+    fn symmetric(&mut self) -> f64 {
+        let unit = match self {
+            Draws::Seeded(rng) => rng.gen::<f64>(),
+            Draws::Entropy(entropy) => entropy.sample_unit(),
+        };
+        unit.clamp(0.0, 1.0) * 2.0 - 1.0
+    }
+}
+
+/// Jitters TWAP schedules within bounds
+pub struct ScheduleRandomizer {
+    /// Configuration
+    config: ScheduleRandomizerConfig,
+    /// Randomness for live schedules
+    entropy: Arc<dyn EntropyInjector>,
+}
+
+impl ScheduleRandomizer {
+    /// Create a new randomizer
+    pub fn new(config: ScheduleRandomizerConfig, entropy: Arc<dyn EntropyInjector>) -> Self {
+        Self { config, entropy }
+    }
+
+    /// Evenly spaced, evenly sized TWAP schedule
+    pub fn baseline(twap: &TWAPConfig, start: DateTime<Utc>, total: f64) -> Vec<ScheduledSlice> {
+        let slices = twap.slices.max(1);
+        (0..slices)
+            .map(|index| ScheduledSlice {
+                index,
+                at: start + Duration::milliseconds((twap.interval_ms * index as u64) as i64),
+                quantity: total / slices as f64,
+            })
+            .collect()
+    }
+
+    /// Randomized TWAP schedule for a parent order
+    pub fn twap_schedule(&self, parent_id: &str, twap: &TWAPConfig, start: DateTime<Utc>, total: f64) -> Vec<ScheduledSlice> {
+        let baseline = Self::baseline(twap, start, total);
+        if !self.config.enabled {
+            return baseline;
+        }
+
+        let mut draws = match self.config.seed {
+            Some(seed) => Draws::Seeded(StdRng::seed_from_u64(seed ^ fnv1a(parent_id))),
+            None => Draws::Entropy(self.entropy.as_ref()),
+        };
+
+        // Keep every slice inside its own half-interval so the order never changes
+        let max_shift_ms = (twap.max_interval_deviation_ms as f64).min(twap.interval_ms as f64 * 0.45);
+        let end = start + Duration::milliseconds((twap.interval_ms * twap.slices.max(1) as u64) as i64);
+        let bound = total.abs() * self.config.max_cumulative_deviation_pct.max(0.0);
+
+        let mut slices = Vec::with_capacity(baseline.len());
+        let (mut baseline_cumulative, mut cumulative) = (0.0, 0.0);
+        for (i, slice) in baseline.iter().enumerate() {
+            let shift = Duration::milliseconds((draws.symmetric() * max_shift_ms) as i64);
+            let at = (slice.at + shift).clamp(start, end);
+
+            baseline_cumulative += slice.quantity;
+            let quantity = if i + 1 == baseline.len() {
+                total - cumulative
+            } else {
+                let size_factor = if twap.randomize_sizes {
+                    1.0 + draws.symmetric() * twap.size_deviation_pct.max(0.0)
+                } else {
+                    1.0
+                };
+                let target = (cumulative + slice.quantity * size_factor)
+                    .clamp(baseline_cumulative - bound, baseline_cumulative + bound)
+                    .clamp(cumulative, total);
+                target - cumulative
+            };
+
+            cumulative += quantity;
+            slices.push(ScheduledSlice { index: slice.index, at, quantity });
+        }
+        slices
+    }
+}
+
+/// FNV-1a hash, stable across builds, used to derive per-parent seeds
+pub(crate) fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Create a schedule randomizer
+pub fn create_schedule_randomizer(
+    config: ScheduleRandomizerConfig,
+    entropy: Arc<dyn EntropyInjector>,
+) -> Arc<ScheduleRandomizer> {
+    Arc::new(ScheduleRandomizer::new(config, entropy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{EntropyConfig, Signal};
+
+    /// Entropy source that must not be consulted when a seed is set
+    struct NoEntropy(EntropyConfig);
+
+    impl EntropyInjector for NoEntropy {
+        fn inject_entropy(&self, _signal: &mut Signal, _config: &EntropyConfig) {}
+        fn get_config(&self) -> &EntropyConfig {
+            &self.0
+        }
+        fn calculate_delay_ms(&self, _signal: &Signal) -> Option<u64> {
+            None
+        }
+        fn should_skip_signal(&self, _signal: &Signal) -> bool {
+            false
+        }
+        fn sample_unit(&self) -> f64 {
+            panic!("seeded schedules must not draw from the entropy injector")
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_seeded_schedules_are_bounded_and_reproducible() {
+        let twap = TWAPConfig { slices: 10, ..TWAPConfig::default() };
+        let config = ScheduleRandomizerConfig { seed: Some(42), ..ScheduleRandomizerConfig::default() };
+        let randomizer = ScheduleRandomizer::new(config, Arc::new(NoEntropy(EntropyConfig::default())));
+        let start = Utc::now();
+
+        let schedule = randomizer.twap_schedule("parent-1", &twap, start, 100.0);
+        assert_eq!(schedule, randomizer.twap_schedule("parent-1", &twap, start, 100.0));
+        assert_ne!(schedule, randomizer.twap_schedule("parent-2", &twap, start, 100.0));
+        assert_ne!(schedule, ScheduleRandomizer::baseline(&twap, start, 100.0));
+
+        let total: f64 = schedule.iter().map(|slice| slice.quantity).sum();
+        assert!((total - 100.0).abs() < 1e-9);
+
+        let baseline = ScheduleRandomizer::baseline(&twap, start, 100.0);
+        let mut cumulative = 0.0;
+        for (slice, base) in schedule.iter().zip(&baseline) {
+            cumulative += slice.quantity;
+            assert!(slice.quantity >= 0.0);
+            assert!((cumulative - 10.0 * (base.index + 1) as f64).abs() <= 5.0 + 1e-9);
+            assert!((slice.at - base.at).num_milliseconds().abs() <= twap.max_interval_deviation_ms as i64);
+        }
+        assert!(schedule.windows(2).all(|pair| pair[0].at < pair[1].at));
+    }
+}
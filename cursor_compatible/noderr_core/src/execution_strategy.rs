@@ -21,7 +21,11 @@ use tracing::{debug, info, error, warn};
 use async_trait::async_trait;
 
 use crate::execution::{ExecutionResult, ExecutionStatus};
+use crate::execution_progress::{ExecutionProgressState, ExecutionProgressTracker};
+use crate::execution_schedule::{ScheduleRandomizer, SCHEDULE_PARAM};
+use crate::order_lineage::OrderLineage;
 use crate::order_router::Order;
+use crate::routing_policy::RoutingPolicyEngine;
 use crate::strategy::Signal;
 
 /// Errors that can occur during execution strategy selection/execution
@@ -41,6 +45,9 @@ pub enum ExecutionStrategyError {
     
     #[error("Internal error: {0}")]
     Internal(String),
+    
+    #[error("Routing policy violation: {0}")]
+    PolicyViolation(String),
 }
 
 /// Supported execution algorithm types
@@ -60,6 +67,8 @@ pub enum ExecutionAlgorithm {
     DMA,
     /// Smart Order Routing
     SmartOrderRouting,
+    /// Percent of Volume
+    POV,
 }
 
 /// Latency-sensitive execution strategy configuration
@@ -75,6 +84,12 @@ pub struct ExecutionStrategyConfig {
     pub twap_config: Option<TWAPConfig>,
     /// VWAP-specific configuration
     pub vwap_config: Option<VWAPConfig>,
+    /// Iceberg-specific configuration
+    #[serde(default)]
+    pub iceberg_config: Option<IcebergConfig>,
+    /// POV-specific configuration
+    #[serde(default)]
+    pub pov_config: Option<POVConfig>,
     /// Maximum execution time in milliseconds
     pub max_execution_time_ms: u64,
     /// Strategies to use for specific symbols
@@ -89,6 +104,8 @@ impl Default for ExecutionStrategyConfig {
             min_order_size_for_vwap: 5000.0,
             twap_config: Some(TWAPConfig::default()),
             vwap_config: Some(VWAPConfig::default()),
+            iceberg_config: Some(IcebergConfig::default()),
+            pov_config: Some(POVConfig::default()),
             max_execution_time_ms: 300000, // 5 minutes
             symbol_strategy_map: HashMap::new(),
         }
@@ -155,6 +172,127 @@ impl Default for VWAPConfig {
     }
 }
 
+/// Iceberg configuration: the parent order is worked as a series of small
+/// visible clips, each posted once the previous one has been filled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IcebergConfig {
+    /// Visible clip size as a fraction of the parent order
+    pub display_fraction: f64,
+    /// Random deviation applied to each clip size, as a fraction of the clip
+    pub display_variance_pct: f64,
+    /// Smallest clip worth posting; smaller remainders are sent whole
+    pub min_display_quantity: f64,
+    /// Delay before posting the next clip in milliseconds
+    pub replenish_delay_ms: u64,
+    /// Maximum random extra delay before replenishing in milliseconds
+    pub replenish_jitter_ms: u64,
+    /// Upper bound on clips per parent order
+    pub max_clips: u32,
+    /// Consecutive clip failures before the parent is abandoned
+    pub max_consecutive_failures: u32,
+    /// Minimum execution percentage required to consider successful
+    pub min_execution_pct: f64,
+    /// Seed for clip sizes and delays; reproducible runs for backtests
+    pub seed: Option<u64>,
+}
+
+impl Default for IcebergConfig {
+    fn default() -> Self {
+        Self {
+            display_fraction: 0.1, // 10% of the parent
+            display_variance_pct: 0.25, // 25%
+            min_display_quantity: 0.0,
+            replenish_delay_ms: 500,
+            replenish_jitter_ms: 1500,
+            max_clips: 100,
+            max_consecutive_failures: 3,
+            min_execution_pct: 0.95, // 95%
+            seed: None,
+        }
+    }
+}
+
+/// POV (Percent of Volume) configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct POVConfig {
+    /// Target share of market volume traded since the order started
+    pub participation_rate: f64,
+    /// Smallest child order; smaller deficits wait for more volume
+    pub min_clip_quantity: f64,
+    /// Largest child order
+    pub max_clip_quantity: f64,
+    /// How often market volume is checked in milliseconds
+    pub poll_interval_ms: u64,
+    /// Time allowed to complete the order in milliseconds
+    pub deadline_ms: u64,
+    /// TWAP slices used to finish the order when volume is too thin to meet the deadline
+    pub fallback_slices: u32,
+    /// Interval between fallback TWAP slices in milliseconds
+    pub fallback_interval_ms: u64,
+    /// Consecutive child failures before the parent is abandoned
+    pub max_consecutive_failures: u32,
+    /// Minimum execution percentage required to consider successful
+    pub min_execution_pct: f64,
+}
+
+impl Default for POVConfig {
+    fn default() -> Self {
+        Self {
+            participation_rate: 0.1, // 10%
+            min_clip_quantity: 0.0,
+            max_clip_quantity: f64::MAX,
+            poll_interval_ms: 1000,
+            deadline_ms: 1_800_000, // 30 minutes
+            fallback_slices: 5,
+            fallback_interval_ms: 60_000, // 1 minute
+            max_consecutive_failures: 3,
+            min_execution_pct: 0.95, // 95%
+        }
+    }
+}
+
+impl POVConfig {
+    /// Time before the deadline at which the remainder switches to TWAP
+    pub fn fallback_window(&self) -> chrono::Duration {
+        chrono::Duration::milliseconds((self.fallback_interval_ms * self.fallback_slices.max(1) as u64) as i64)
+    }
+}
+
+/// Parameters of a running algorithm that can be changed without cancelling it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlgoModification {
+    /// New end time for the schedule
+    pub end_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// New maximum participation rate (fraction of volume)
+    pub participation_cap: Option<f64>,
+    /// New limit price for remaining child orders
+    pub limit_price: Option<f64>,
+}
+
+impl AlgoModification {
+    /// Check the requested values are usable
+    pub fn validate(&self, now: chrono::DateTime<chrono::Utc>) -> Result<(), ExecutionStrategyError> {
+        if self.end_time.is_none() && self.participation_cap.is_none() && self.limit_price.is_none() {
+            return Err(ExecutionStrategyError::InvalidParameters("no parameters to modify".to_string()));
+        }
+        if self.end_time.is_some_and(|end| end <= now) {
+            return Err(ExecutionStrategyError::InvalidParameters("end time is in the past".to_string()));
+        }
+        if self.participation_cap.is_some_and(|cap| !(cap > 0.0 && cap <= 1.0)) {
+            return Err(ExecutionStrategyError::InvalidParameters(
+                "participation cap must be in (0, 1]".to_string(),
+            ));
+        }
+        if self.limit_price.is_some_and(|price| !(price > 0.0 && price.is_finite())) {
+            return Err(ExecutionStrategyError::InvalidParameters("limit price must be positive".to_string()));
+        }
+        Ok(())
+    }
+}
+
 /// Execution strategy trait for algorithm implementations
 #[async_trait]
 pub trait ExecutionStrategy: Send + Sync {
@@ -176,6 +314,27 @@ pub trait ExecutionStrategy: Send + Sync {
     
     /// Cancel ongoing execution
     async fn cancel(&self) -> Result<(), ExecutionStrategyError>;
+    
+    /// Stop sending new slices for an order, keeping its schedule state
+    async fn pause(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+        Err(ExecutionStrategyError::UnsupportedStrategy(format!(
+            "{} cannot pause {}", self.get_details().name, order_id
+        )))
+    }
+    
+    /// Continue a paused order
+    async fn resume(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+        Err(ExecutionStrategyError::UnsupportedStrategy(format!(
+            "{} cannot resume {}", self.get_details().name, order_id
+        )))
+    }
+    
+    /// Apply new parameters to the remainder of an order
+    async fn modify(&self, order_id: &str, _modification: &AlgoModification) -> Result<(), ExecutionStrategyError> {
+        Err(ExecutionStrategyError::UnsupportedStrategy(format!(
+            "{} cannot modify {}", self.get_details().name, order_id
+        )))
+    }
 }
 
 /// Execution strategy details
@@ -203,6 +362,12 @@ pub struct ExecutionStrategyRouter {
     strategy_executors: HashMap<ExecutionAlgorithm, Arc<dyn ExecutionStrategy>>,
     /// Active executions
     active_executions: Arc<Mutex<HashMap<String, ExecutionAlgorithm>>>,
+    /// Declarative routing rules that can force an algorithm or restrict venues (optional)
+    routing_policy: Option<Arc<RoutingPolicyEngine>>,
+    /// Progress reporting for long-running algorithms (optional)
+    progress: Option<Arc<ExecutionProgressTracker>>,
+    /// Jitters TWAP slice times and sizes (optional)
+    schedule_randomizer: Option<Arc<ScheduleRandomizer>>,
 }
 
 impl ExecutionStrategyRouter {
@@ -222,9 +387,35 @@ impl ExecutionStrategyRouter {
             vwap_executor,
             strategy_executors,
             active_executions: Arc::new(Mutex::new(HashMap::new())),
+            routing_policy: None,
+            progress: None,
+            schedule_randomizer: None,
         }
     }
     
+    /// Report TWAP/VWAP/IS progress to a tracker
+    pub fn with_progress_tracker(mut self, progress: Arc<ExecutionProgressTracker>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+    
+    /// Hand TWAP executors a randomized slice schedule instead of a fixed clock
+    pub fn with_schedule_randomizer(mut self, schedule_randomizer: Arc<ScheduleRandomizer>) -> Self {
+        self.schedule_randomizer = Some(schedule_randomizer);
+        self
+    }
+    
+    /// Progress tracker, if one is attached
+    pub fn progress_tracker(&self) -> Option<&Arc<ExecutionProgressTracker>> {
+        self.progress.as_ref()
+    }
+    
+    /// Check orders against routing policy rules before selecting a strategy
+    pub fn with_routing_policy(mut self, routing_policy: Arc<RoutingPolicyEngine>) -> Self {
+        self.routing_policy = Some(routing_policy);
+        self
+    }
+    
     /// Register an additional strategy executor
     pub fn register_strategy(
         &mut self,
@@ -240,35 +431,131 @@ impl ExecutionStrategyRouter {
         order: Order,
         on_complete: Arc<dyn Fn(ExecutionResult) + Send + Sync>,
     ) -> Result<(), ExecutionStrategyError> {
+        // Apply routing policy; a policy-required algorithm overrides selection
+        let (mut order, required) = match &self.routing_policy {
+            Some(policy) => {
+                let (order, evaluation) = policy.enforce(order, Self::requested_algorithm(&order)).await
+                    .map_err(|e| ExecutionStrategyError::PolicyViolation(e.to_string()))?;
+                (order, evaluation.algorithm)
+            }
+            None => (order, None),
+        };
+        
         // Select the best strategy for this order
-        let strategy = self.select_execution_strategy(&order).await;
+        let strategy = match required {
+            Some(algorithm) => algorithm,
+            None => self.select_execution_strategy(&order).await,
+        };
         info!("Selected {:?} execution strategy for order {}", strategy, order.id);
         
+        if let (ExecutionAlgorithm::TWAP, Some(randomizer)) = (strategy, &self.schedule_randomizer) {
+            let twap = self.config.read().await.twap_config.clone().unwrap_or_default();
+            let schedule = randomizer.twap_schedule(&order.id, &twap, chrono::Utc::now(), order.amount);
+            if let Ok(schedule) = serde_json::to_value(&schedule) {
+                order.additional_params.insert(SCHEDULE_PARAM.to_string(), schedule);
+            }
+        }
+        
         // Record active execution
         {
             let mut active_executions = self.active_executions.lock().await;
             active_executions.insert(order.id.clone(), strategy);
         }
         
+        // Track progress of scheduled algorithms
+        let progress = match (&self.progress, Self::progress_schedule(&*self.config.read().await, strategy)) {
+            (Some(tracker), Some((slices, duration))) => {
+                tracker.start(&order, strategy, slices, duration);
+                Some(Arc::clone(tracker))
+            }
+            _ => None,
+        };
+        
         // Create callback that will remove from active executions
         let active_executions = Arc::clone(&self.active_executions);
         let order_id = order.id.clone();
-        let callback = Arc::new(move |result: ExecutionResult| {
+        let lineage = OrderLineage::of(&order);
+        let parent = order.clone();
+        let slice_progress = progress.clone();
+        let callback = Arc::new(move |mut result: ExecutionResult| {
+            // Child fills keep their own tags; anything missing comes from the parent
+            lineage.tag(&parent, &mut result);
+            
+            // Each result is one slice; without a tracker the first result ends the execution
+            let finished = match slice_progress.as_ref().and_then(|t| t.record_slice(&order_id, &result)) {
+                Some(progress) => progress.state.is_finished(),
+                None => true,
+            };
+            
             // Forward result to original callback
             on_complete(result.clone());
             
             // Remove from active executions
-            tokio::spawn(async move {
-                let mut active_executions = active_executions.lock().await;
-                active_executions.remove(&order_id);
-            });
+            if finished {
+                let active_executions = Arc::clone(&active_executions);
+                let order_id = order_id.clone();
+                tokio::spawn(async move {
+                    let mut active_executions = active_executions.lock().await;
+                    active_executions.remove(&order_id);
+                });
+            }
         });
         
         // Execute using selected strategy
+        let order_id = order.id.clone();
         let executor = self.get_executor(strategy)
             .ok_or_else(|| ExecutionStrategyError::UnsupportedStrategy(format!("{:?}", strategy)))?;
         
-        executor.execute(order, callback).await
+        let result = executor.execute(order, callback).await;
+        if let (Err(e), Some(tracker)) = (&result, &progress) {
+            warn!("{:?} execution of {} failed: {}", strategy, order_id, e);
+            tracker.finish(&order_id, ExecutionProgressState::Failed);
+        }
+        result
+    }
+    
+    /// Slice count and duration progress is measured against, for scheduled algorithms
+    fn progress_schedule(
+        config: &ExecutionStrategyConfig,
+        strategy: ExecutionAlgorithm,
+    ) -> Option<(u32, chrono::Duration)> {
+        let twap = config.twap_config.clone().unwrap_or_default();
+        match strategy {
+            ExecutionAlgorithm::TWAP => Some((
+                twap.slices,
+                chrono::Duration::milliseconds((twap.interval_ms * twap.slices as u64) as i64),
+            )),
+            ExecutionAlgorithm::VWAP => {
+                let vwap = config.vwap_config.clone().unwrap_or_default();
+                let duration_ms = (vwap.end_time_offset_ms - vwap.start_time_offset_ms).max(1);
+                // One slice per volume profile bucket, or per minute without a profile
+                let slices = vwap.volume_profile
+                    .as_ref()
+                    .map(|profile| profile.len() as u32)
+                    .unwrap_or((duration_ms / 60_000).max(1) as u32);
+                Some((slices, chrono::Duration::milliseconds(duration_ms)))
+            }
+            // IS front-loads its schedule but is bounded by the execution time limit
+            ExecutionAlgorithm::ImplementationShortfall => Some((
+                twap.slices,
+                chrono::Duration::milliseconds(config.max_execution_time_ms as i64),
+            )),
+            // Clip count is open-ended; fills complete the execution before the clip cap does
+            ExecutionAlgorithm::Iceberg => Some((
+                config.iceberg_config.clone().unwrap_or_default().max_clips,
+                chrono::Duration::milliseconds(config.max_execution_time_ms as i64),
+            )),
+            // Child count depends on market volume; fills complete the execution
+            ExecutionAlgorithm::POV => {
+                let pov = config.pov_config.clone().unwrap_or_default();
+                let polls = (pov.deadline_ms / pov.poll_interval_ms.max(1)).max(1) as u32;
+                Some((
+                    polls.saturating_add(pov.fallback_slices),
+                    chrono::Duration::milliseconds(pov.deadline_ms as i64),
+                ))
+            }
+            _ => None,
+        }
     }
     
     /// Estimate impact of executing an order
@@ -291,13 +578,18 @@ impl ExecutionStrategyRouter {
     
     /// Cancel execution for an order
     pub async fn cancel_execution(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
-        let active_executions = self.active_executions.lock().await;
+        let mut active_executions = self.active_executions.lock().await;
         
-        if let Some(strategy) = active_executions.get(order_id) {
-            let executor = self.get_executor(*strategy)
+        if let Some(strategy) = active_executions.get(order_id).copied() {
+            let executor = self.get_executor(strategy)
                 .ok_or_else(|| ExecutionStrategyError::UnsupportedStrategy(format!("{:?}", strategy)))?;
             
-            executor.cancel().await
+            executor.cancel().await?;
+            active_executions.remove(order_id);
+            if let Some(tracker) = &self.progress {
+                tracker.finish(order_id, ExecutionProgressState::Cancelled);
+            }
+            Ok(())
         } else {
             Err(ExecutionStrategyError::ExecutionFailed(
                 format!("No active execution found for order ID: {}", order_id)
@@ -305,6 +597,64 @@ impl ExecutionStrategyRouter {
         }
     }
     
+    /// Pause a running execution without cancelling it
+    pub async fn pause_execution(&self, order_id: &str, actor: &str) -> Result<(), ExecutionStrategyError> {
+        self.require_state(order_id, ExecutionProgressState::Running)?;
+        self.active_executor(order_id).await?.pause(order_id).await?;
+        
+        if let Some(tracker) = &self.progress {
+            tracker.transition(order_id, ExecutionProgressState::Paused, actor, serde_json::Value::Null);
+        }
+        Ok(())
+    }
+    
+    /// Resume a paused execution
+    pub async fn resume_execution(&self, order_id: &str, actor: &str) -> Result<(), ExecutionStrategyError> {
+        self.require_state(order_id, ExecutionProgressState::Paused)?;
+        self.active_executor(order_id).await?.resume(order_id).await?;
+        
+        if let Some(tracker) = &self.progress {
+            tracker.transition(order_id, ExecutionProgressState::Running, actor, serde_json::Value::Null);
+        }
+        Ok(())
+    }
+    
+    /// Change the end time, participation cap or limit price of a running or paused execution
+    pub async fn modify_execution(
+        &self,
+        order_id: &str,
+        modification: AlgoModification,
+        actor: &str,
+    ) -> Result<(), ExecutionStrategyError> {
+        modification.validate(chrono::Utc::now())?;
+        self.active_executor(order_id).await?.modify(order_id, &modification).await?;
+        
+        if let Some(tracker) = &self.progress {
+            let details = serde_json::to_value(&modification).unwrap_or_default();
+            tracker.reschedule(order_id, modification.end_time, actor, details);
+        }
+        Ok(())
+    }
+    
+    /// Executor working an active execution
+    async fn active_executor(&self, order_id: &str) -> Result<Arc<dyn ExecutionStrategy>, ExecutionStrategyError> {
+        let strategy = self.active_executions.lock().await.get(order_id).copied().ok_or_else(|| {
+            ExecutionStrategyError::ExecutionFailed(format!("No active execution found for order ID: {}", order_id))
+        })?;
+        self.get_executor(strategy)
+            .ok_or_else(|| ExecutionStrategyError::UnsupportedStrategy(format!("{:?}", strategy)))
+    }
+    
+    /// Fail unless a tracked execution is in `expected` state; untracked executions pass
+    fn require_state(&self, order_id: &str, expected: ExecutionProgressState) -> Result<(), ExecutionStrategyError> {
+        match self.progress.as_ref().and_then(|tracker| tracker.get(order_id)) {
+            Some(progress) if progress.state != expected => Err(ExecutionStrategyError::InvalidParameters(format!(
+                "execution {} is {:?}, expected {:?}", order_id, progress.state, expected
+            ))),
+            _ => Ok(()),
+        }
+    }
+    
     /// Update router configuration
     pub async fn update_config(&self, config: ExecutionStrategyConfig) {
         let mut current_config = self.config.write().await;
@@ -316,19 +666,25 @@ impl ExecutionStrategyRouter {
         self.strategy_executors.get(&strategy).cloned()
     }
     
+    /// Execution mode explicitly requested in the order's additional_params
+    fn requested_algorithm(order: &Order) -> Option<ExecutionAlgorithm> {
+        match order.additional_params.get("executionMode").and_then(|v| v.as_str())? {
+            "TWAP" => Some(ExecutionAlgorithm::TWAP),
+            "VWAP" => Some(ExecutionAlgorithm::VWAP),
+            "Iceberg" => Some(ExecutionAlgorithm::Iceberg),
+            "POV" => Some(ExecutionAlgorithm::POV),
+            "DMA" => Some(ExecutionAlgorithm::DMA),
+            _ => None,
+        }
+    }
+    
     /// Select the appropriate execution strategy for an order
     async fn select_execution_strategy(&self, order: &Order) -> ExecutionAlgorithm {
         let config = self.config.read().await;
         
         // Check if order has a specific execution mode in additional_params
-        if let Some(serde_json::Value::String(mode)) = order.additional_params.get("executionMode") {
-            match mode.as_str() {
-                "TWAP" => return ExecutionAlgorithm::TWAP,
-                "VWAP" => return ExecutionAlgorithm::VWAP,
-                "Iceberg" => return ExecutionAlgorithm::Iceberg,
-                "DMA" => return ExecutionAlgorithm::DMA,
-                _ => {} // Fall through to other selection methods
-            }
+        if let Some(strategy) = Self::requested_algorithm(order) {
+            return strategy;
         }
         
         // Check symbol-specific strategy mapping
@@ -413,6 +769,141 @@ mod tests {
         }
     }
     
+    /// Strategy that keeps orders working and records the controls it receives
+    #[derive(Default)]
+    struct ControllableStrategy {
+        controls: std::sync::Mutex<Vec<String>>,
+    }
+    
+    #[async_trait]
+    impl ExecutionStrategy for ControllableStrategy {
+        async fn execute(
+            &self,
+            _order: Order,
+            _callback: Arc<dyn Fn(ExecutionResult) + Send + Sync>,
+        ) -> Result<(), ExecutionStrategyError> {
+            Ok(())
+        }
+        
+        async fn estimate_impact(&self, _order: &Order) -> Result<f64, ExecutionStrategyError> {
+            Ok(0.001)
+        }
+        
+        async fn get_cost_estimate(&self, order: &Order) -> Result<f64, ExecutionStrategyError> {
+            Ok(order.amount * 0.001)
+        }
+        
+        fn get_details(&self) -> ExecutionStrategyDetails {
+            ExecutionStrategyDetails {
+                strategy_type: ExecutionAlgorithm::TWAP,
+                name: "Controllable".to_string(),
+                description: "Mock strategy that never finishes".to_string(),
+                parameters: HashMap::new(),
+            }
+        }
+        
+        async fn cancel(&self) -> Result<(), ExecutionStrategyError> {
+            Ok(())
+        }
+        
+        async fn pause(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+            self.controls.lock().unwrap().push(format!("pause {}", order_id));
+            Ok(())
+        }
+        
+        async fn resume(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+            self.controls.lock().unwrap().push(format!("resume {}", order_id));
+            Ok(())
+        }
+        
+        async fn modify(&self, order_id: &str, modification: &AlgoModification) -> Result<(), ExecutionStrategyError> {
+            self.controls.lock().unwrap().push(format!("modify {} {:?}", order_id, modification.limit_price));
+            Ok(())
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_pause_resume_and_modify_running_execution() {
+        let twap = Arc::new(ControllableStrategy::default());
+        let vwap = Arc::new(MockStrategy {
+            strategy_type: ExecutionAlgorithm::VWAP,
+            name: "VWAP".to_string(),
+        });
+        let tracker = crate::execution_progress::create_execution_progress_tracker(Default::default());
+        let router = ExecutionStrategyRouter::new(ExecutionStrategyConfig::default(), twap.clone(), vwap)
+            .with_progress_tracker(tracker.clone());
+        
+        let mut params = HashMap::new();
+        params.insert("executionMode".to_string(), serde_json::Value::String("TWAP".to_string()));
+        let order = Order {
+            id: "parent-1".to_string(),
+            symbol: "BTC/USD".to_string(),
+            side: crate::order_router::OrderSide::Buy,
+            amount: 10.0,
+            price: 50000.0,
+            venues: vec!["binance".to_string()],
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: crate::order_router::TimeInForce::GTC,
+            additional_params: params,
+        };
+        router.execute(order, Arc::new(|_: ExecutionResult| {})).await.unwrap();
+        assert_eq!(tracker.get("parent-1").unwrap().state, ExecutionProgressState::Running);
+        
+        // Only paused executions can be resumed
+        let err = router.resume_execution("parent-1", "alice").await.unwrap_err();
+        assert!(matches!(err, ExecutionStrategyError::InvalidParameters(_)));
+        
+        router.pause_execution("parent-1", "alice").await.unwrap();
+        let progress = tracker.get("parent-1").unwrap();
+        assert_eq!(progress.state, ExecutionProgressState::Paused);
+        assert!(progress.paused_at.is_some());
+        assert_eq!(progress.transitions.last().unwrap().actor, "alice");
+        assert!(router.pause_execution("parent-1", "alice").await.is_err());
+        
+        // Paused executions can still be modified; bad parameters never reach the strategy
+        let end_time = chrono::Utc::now() + chrono::Duration::hours(1);
+        let modification = AlgoModification {
+            end_time: Some(end_time),
+            limit_price: Some(49000.0),
+            ..Default::default()
+        };
+        router.modify_execution("parent-1", modification, "bob").await.unwrap();
+        let progress = tracker.get("parent-1").unwrap();
+        assert_eq!(progress.state, ExecutionProgressState::Paused);
+        assert_eq!(progress.scheduled_end, end_time);
+        assert_eq!(progress.transitions.last().unwrap().actor, "bob");
+        
+        for invalid in [
+            AlgoModification::default(),
+            AlgoModification { participation_cap: Some(1.5), ..Default::default() },
+            AlgoModification { limit_price: Some(-1.0), ..Default::default() },
+        ] {
+            let err = router.modify_execution("parent-1", invalid, "bob").await.unwrap_err();
+            assert!(matches!(err, ExecutionStrategyError::InvalidParameters(_)));
+        }
+        
+        router.resume_execution("parent-1", "alice").await.unwrap();
+        let progress = tracker.get("parent-1").unwrap();
+        assert_eq!(progress.state, ExecutionProgressState::Running);
+        assert!(progress.paused_at.is_none());
+        
+        assert_eq!(
+            *twap.controls.lock().unwrap(),
+            vec![
+                "pause parent-1".to_string(),
+                "modify parent-1 Some(49000.0)".to_string(),
+                "resume parent-1".to_string(),
+            ]
+        );
+        
+        // Unknown executions cannot be controlled
+        let err = router.pause_execution("missing", "alice").await.unwrap_err();
+        assert!(matches!(err, ExecutionStrategyError::ExecutionFailed(_)));
+    }
+    
     #[tokio::test]
     async fn test_strategy_selection() {
         let twap = Arc::new(MockStrategy {
@@ -447,6 +938,9 @@ mod tests {
             venues: vec!["binance".to_string()],
             max_slippage: None,
             max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: crate::order_router::TimeInForce::GTC,
             additional_params: HashMap::new(),
         };
         
@@ -460,6 +954,9 @@ mod tests {
             venues: vec!["binance".to_string()],
             max_slippage: None,
             max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: crate::order_router::TimeInForce::GTC,
             additional_params: HashMap::new(),
         };
         
@@ -473,6 +970,9 @@ mod tests {
             venues: vec!["binance".to_string()],
             max_slippage: None,
             max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: crate::order_router::TimeInForce::GTC,
             additional_params: HashMap::new(),
         };
         
@@ -492,6 +992,9 @@ mod tests {
             venues: vec!["binance".to_string()],
             max_slippage: None,
             max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: crate::order_router::TimeInForce::GTC,
             additional_params: explicit_params,
         };
         
@@ -0,0 +1,412 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Symbol × strategy × venue exposure breakdown.
+//!
+//! Positions are split across the strategies and venues their fills came
+//! from, valued at the latest price, and each cell is assigned its Euler
+//! contribution to portfolio volatility under a constant-correlation model,
+//! so contributions sum to the portfolio total. The heatmap is recomputed on
+//! position changes and broadcast to subscribers such as the dashboard
+//! WebSocket.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::drawdown_forecast::realized_hourly_volatility;
+use crate::market::Symbol;
+use crate::position::{PositionChangeEvent, PositionListener, PositionManager, PositionResult, Side, SymbolPosition};
+use crate::strategy::StrategyId;
+
+/// Configuration for [`ExposureHeatmapService`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExposureHeatmapConfig {
+    /// Hourly volatility assumed for symbols without enough price history
+    pub default_hourly_volatility: f64,
+    /// Hourly volatility overrides by symbol
+    pub symbol_volatility: HashMap<Symbol, f64>,
+    /// Pairwise correlation assumed between different symbols
+    pub correlation: f64,
+    /// Price observations kept per symbol for realized volatility
+    pub volatility_lookback: usize,
+    /// Observations required before realized volatility is trusted
+    pub min_observations: usize,
+    /// Venue label for exposure whose fills carry no venue
+    pub unknown_venue: String,
+    /// Minimum time between refreshes triggered by position changes
+    pub min_refresh_ms: u64,
+}
+
+impl Default for ExposureHeatmapConfig {
+    fn default() -> Self {
+        Self {
+            default_hourly_volatility: 0.01,
+            symbol_volatility: HashMap::new(),
+            correlation: 0.5,
+            volatility_lookback: 500,
+            min_observations: 20,
+            unknown_venue: "unknown".to_string(),
+            min_refresh_ms: 250,
+        }
+    }
+}
+
+/// Exposure of one strategy to one symbol on one venue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExposureCell {
+    /// Symbol held
+    pub symbol: Symbol,
+    /// Strategy holding it
+    pub strategy_id: StrategyId,
+    /// Venue it is held on
+    pub venue: String,
+    /// Signed notional, positive for long
+    pub notional: f64,
+    /// Contribution to portfolio hourly volatility, in currency
+    pub risk_contribution: f64,
+    /// Share of portfolio volatility
+    pub risk_share: f64,
+}
+
+/// Totals along one axis of the heatmap
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExposureTotals {
+    /// Net signed notional
+    pub net_notional: f64,
+    /// Gross notional
+    pub gross_notional: f64,
+    /// Contribution to portfolio hourly volatility
+    pub risk_contribution: f64,
+}
+
+impl ExposureTotals {
+    fn add(&mut self, cell: &ExposureCell) {
+        self.net_notional += cell.notional;
+        self.gross_notional += cell.notional.abs();
+        self.risk_contribution += cell.risk_contribution;
+    }
+}
+
+/// Three-dimensional exposure breakdown
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExposureHeatmap {
+    /// When the heatmap was computed
+    pub generated_at: Option<DateTime<Utc>>,
+    /// One cell per symbol, strategy and venue with exposure
+    pub cells: Vec<ExposureCell>,
+    /// Totals by symbol
+    pub by_symbol: HashMap<Symbol, ExposureTotals>,
+    /// Totals by strategy
+    pub by_strategy: HashMap<StrategyId, ExposureTotals>,
+    /// Totals by venue
+    pub by_venue: HashMap<String, ExposureTotals>,
+    /// Portfolio totals
+    pub total: ExposureTotals,
+}
+
+impl ExposureHeatmap {
+    /// Build a heatmap from cells, assigning Euler risk contributions.
+    ///
+    /// With cell exposures `x_k`, symbol volatilities `σ_s` and correlation
+    /// `ρ` between different symbols, the marginal term for a cell on symbol
+    /// `s` is `ρ·T + (1 - ρ)·A_s`, where `A_s = Σ_{k∈s} x_k σ_s` and
+    /// `T = Σ_s A_s`.
+    pub fn from_cells(
+        mut cells: Vec<ExposureCell>,
+        volatility: &HashMap<Symbol, f64>,
+        correlation: f64,
+        generated_at: DateTime<Utc>,
+    ) -> Self {
+        let mut by_symbol_risk: HashMap<Symbol, f64> = HashMap::new();
+        for cell in &cells {
+            let sigma = volatility.get(&cell.symbol).copied().unwrap_or(0.0);
+            *by_symbol_risk.entry(cell.symbol.clone()).or_default() += cell.notional * sigma;
+        }
+        let total: f64 = by_symbol_risk.values().sum();
+        let variance: f64 = by_symbol_risk
+            .values()
+            .map(|a| a * (correlation * total + (1.0 - correlation) * a))
+            .sum();
+        let portfolio_volatility = variance.max(0.0).sqrt();
+
+        for cell in &mut cells {
+            let sigma = volatility.get(&cell.symbol).copied().unwrap_or(0.0);
+            let a = by_symbol_risk.get(&cell.symbol).copied().unwrap_or(0.0);
+            let marginal = correlation * total + (1.0 - correlation) * a;
+            if portfolio_volatility > 0.0 {
+                cell.risk_contribution = cell.notional * sigma * marginal / portfolio_volatility;
+                cell.risk_share = cell.risk_contribution / portfolio_volatility;
+            }
+        }
+
+        let mut heatmap = ExposureHeatmap {
+            generated_at: Some(generated_at),
+            ..Default::default()
+        };
+        for cell in &cells {
+            heatmap.by_symbol.entry(cell.symbol.clone()).or_default().add(cell);
+            heatmap.by_strategy.entry(cell.strategy_id.clone()).or_default().add(cell);
+            heatmap.by_venue.entry(cell.venue.clone()).or_default().add(cell);
+            heatmap.total.add(cell);
+        }
+        heatmap.cells = cells;
+        heatmap
+    }
+}
+
+/// Split an agent's symbol position across the strategies and venues its fills came from
+fn split_position(
+    agent_id: &str,
+    position: &SymbolPosition,
+    unknown_venue: &str,
+) -> Vec<(StrategyId, String, f64)> {
+    let mut sizes: HashMap<(StrategyId, String), f64> = HashMap::new();
+    for fill in position.fills.iter().filter(|f| f.is_fill) {
+        let strategy_id = fill.strategy_id.clone().unwrap_or_else(|| agent_id.to_string());
+        let venue = fill.venue.clone().unwrap_or_else(|| unknown_venue.to_string());
+        let signed = match fill.side {
+            Side::Buy => fill.size,
+            Side::Sell => -fill.size,
+        };
+        *sizes.entry((strategy_id, venue)).or_default() += signed;
+    }
+
+    // Scale fill totals onto the net position; fall back to a single bucket
+    // when fills don't explain it
+    let total: f64 = sizes.values().sum();
+    if total == 0.0 || total.signum() != position.net_size.signum() {
+        return vec![(agent_id.to_string(), unknown_venue.to_string(), position.net_size)];
+    }
+    let scale = position.net_size / total;
+    sizes
+        .into_iter()
+        .filter(|(_, size)| *size != 0.0)
+        .map(|((strategy_id, venue), size)| (strategy_id, venue, size * scale))
+        .collect()
+}
+
+/// Maintains and publishes the exposure heatmap
+pub struct ExposureHeatmapService {
+    /// Configuration
+    config: ExposureHeatmapConfig,
+    /// Source of positions and prices
+    position_manager: Arc<PositionManager>,
+    /// Recent prices by symbol, for realized volatility
+    prices: RwLock<HashMap<Symbol, VecDeque<(DateTime<Utc>, f64)>>>,
+    /// Latest heatmap
+    latest: RwLock<ExposureHeatmap>,
+    /// Heatmap broadcast
+    sender: broadcast::Sender<ExposureHeatmap>,
+    /// Position change sender handed to the position manager
+    position_tx: mpsc::UnboundedSender<PositionChangeEvent>,
+    /// Position change receiver drained by the refresh task
+    position_rx: Mutex<Option<mpsc::UnboundedReceiver<PositionChangeEvent>>>,
+    /// Background refresh task
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl ExposureHeatmapService {
+    /// Create a new service
+    pub fn new(config: ExposureHeatmapConfig, position_manager: Arc<PositionManager>) -> Self {
+        let (sender, _) = broadcast::channel(64);
+        let (position_tx, position_rx) = mpsc::unbounded_channel();
+        Self {
+            config,
+            position_manager,
+            prices: RwLock::new(HashMap::new()),
+            latest: RwLock::new(ExposureHeatmap::default()),
+            sender,
+            position_tx,
+            position_rx: Mutex::new(Some(position_rx)),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Listener to subscribe to the position manager so changes trigger a refresh
+    pub fn position_listener(&self) -> PositionListener {
+        let tx = self.position_tx.clone();
+        Arc::new(move |event: &PositionChangeEvent| {
+            let _ = tx.send(event.clone());
+        })
+    }
+
+    /// Subscribe to refreshed heatmaps
+    pub fn subscribe(&self) -> broadcast::Receiver<ExposureHeatmap> {
+        self.sender.subscribe()
+    }
+
+    /// Latest heatmap
+    pub async fn latest(&self) -> ExposureHeatmap {
+        self.latest.read().await.clone()
+    }
+
+    /// Record a price observation
+    pub async fn on_price(&self, symbol: &str, price: f64, timestamp: DateTime<Utc>) {
+        if price <= 0.0 {
+            return;
+        }
+        let mut prices = self.prices.write().await;
+        let history = prices.entry(symbol.to_string()).or_default();
+        history.push_back((timestamp, price));
+        while history.len() > self.config.volatility_lookback.max(2) {
+            history.pop_front();
+        }
+    }
+
+    /// Recompute the heatmap from current positions and publish it
+    pub async fn refresh(&self) -> PositionResult<ExposureHeatmap> {
+        let prices = self.prices.read().await;
+        let mut cells = Vec::new();
+        let mut volatility = HashMap::new();
+
+        for agent_id in self.position_manager.get_agent_ids()? {
+            let position = self.position_manager.get_position(&agent_id)?;
+            for (symbol, symbol_position) in &position.positions {
+                if symbol_position.net_size == 0.0 {
+                    continue;
+                }
+                let history = prices.get(symbol);
+                let price = match history.and_then(|h| h.back().map(|(_, p)| *p)) {
+                    Some(price) => price,
+                    None => self
+                        .position_manager
+                        .get_current_price(symbol)?
+                        .unwrap_or(symbol_position.average_price),
+                };
+
+                volatility.entry(symbol.clone()).or_insert_with(|| {
+                    self.config
+                        .symbol_volatility
+                        .get(symbol)
+                        .copied()
+                        .or_else(|| history.and_then(|h| realized_hourly_volatility(h, self.config.min_observations)))
+                        .unwrap_or(self.config.default_hourly_volatility)
+                });
+
+                for (strategy_id, venue, size) in split_position(&agent_id, symbol_position, &self.config.unknown_venue) {
+                    cells.push(ExposureCell {
+                        symbol: symbol.clone(),
+                        strategy_id,
+                        venue,
+                        notional: size * price,
+                        risk_contribution: 0.0,
+                        risk_share: 0.0,
+                    });
+                }
+            }
+        }
+        drop(prices);
+
+        let heatmap = ExposureHeatmap::from_cells(cells, &volatility, self.config.correlation, Utc::now());
+        *self.latest.write().await = heatmap.clone();
+        let _ = self.sender.send(heatmap.clone());
+        Ok(heatmap)
+    }
+
+    /// Start refreshing on position changes, coalescing bursts of changes
+    pub async fn start(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+        let Some(mut positions) = self.position_rx.lock().await.take() else {
+            warn!("Exposure heatmap refresh task already consumed its position feed");
+            return;
+        };
+        let min_refresh = StdDuration::from_millis(self.config.min_refresh_ms);
+
+        let handle = tokio::spawn(async move {
+            while positions.recv().await.is_some() {
+                tokio::time::sleep(min_refresh).await;
+                while positions.try_recv().is_ok() {}
+                if let Err(e) = service.refresh().await {
+                    warn!("Failed to refresh exposure heatmap: {}", e);
+                }
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop refreshing
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create an exposure heatmap service subscribed to the position manager
+pub fn create_exposure_heatmap_service(
+    config: ExposureHeatmapConfig,
+    position_manager: Arc<PositionManager>,
+) -> PositionResult<Arc<ExposureHeatmapService>> {
+    let service = Arc::new(ExposureHeatmapService::new(config, Arc::clone(&position_manager)));
+    position_manager.subscribe(service.position_listener())?;
+    Ok(service)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::OrderOrFill;
+
+    fn fill(symbol: &str, side: Side, size: f64, price: f64, strategy: &str, venue: &str) -> OrderOrFill {
+        OrderOrFill {
+            symbol: symbol.to_string(),
+            side,
+            size,
+            price,
+            timestamp: Utc::now(),
+            order_id: format!("{}-{}", strategy, venue),
+            fill_id: Some("f".to_string()),
+            is_fill: true,
+            venue: Some(venue.to_string()),
+            strategy_id: Some(strategy.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn splits_positions_and_risk_contributions_sum_to_total() {
+        let position_manager = PositionManager::new();
+        position_manager.update_position("agent-1", &fill("BTC/USD", Side::Buy, 2.0, 100.0, "trend", "binance")).unwrap();
+        position_manager.update_position("agent-1", &fill("BTC/USD", Side::Buy, 1.0, 100.0, "trend", "coinbase")).unwrap();
+        position_manager.update_position("agent-2", &fill("ETH/USD", Side::Sell, 5.0, 10.0, "mean_rev", "binance")).unwrap();
+
+        let service = ExposureHeatmapService::new(ExposureHeatmapConfig::default(), Arc::clone(&position_manager));
+        let heatmap = service.refresh().await.unwrap();
+
+        assert_eq!(heatmap.cells.len(), 3);
+        assert!((heatmap.by_venue["binance"].net_notional - 150.0).abs() < 1e-9);
+        assert!((heatmap.by_venue["coinbase"].net_notional - 100.0).abs() < 1e-9);
+        assert!((heatmap.by_strategy["mean_rev"].net_notional + 50.0).abs() < 1e-9);
+        assert!((heatmap.total.gross_notional - 350.0).abs() < 1e-9);
+
+        // Euler contributions add up to portfolio volatility: 0.01 * sqrt(300² + 50² - 2·0.5·300·50)
+        let expected = 0.01 * (300.0f64.powi(2) + 50.0f64.powi(2) - 300.0 * 50.0).sqrt();
+        assert!((heatmap.total.risk_contribution - expected).abs() < 1e-9);
+        let shares: f64 = heatmap.cells.iter().map(|c| c.risk_share).sum();
+        assert!((shares - 1.0).abs() < 1e-9);
+        // The short hedges the long, so its contribution is negative
+        assert!(heatmap.by_symbol["ETH/USD"].risk_contribution < 0.0);
+    }
+}
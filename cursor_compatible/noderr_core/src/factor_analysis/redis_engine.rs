@@ -39,7 +39,8 @@ const FACTOR_RETURN_SERIES_KEY: &str = "factor:returns:";
 const FACTOR_PROFILE_KEY: &str = "factor:profile:";
 const FACTOR_HISTORY_KEY: &str = "factor:history:";
 const FACTOR_ALERT_KEY: &str = "factor:alerts:";
-const FACTOR_DATA_KEY: &str = "factor:data:";
+/// Prefix of the per-factor return series read by the regression
+pub const FACTOR_DATA_KEY: &str = "factor:data:";
 
 /// Redis-based implementation of the FactorAnalysisEngine
 pub struct RedisFactorAnalysisEngine {
@@ -0,0 +1,602 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Warm standby with active-passive failover
+//!
+//! Instances compete for a leader lease (a Redis key written with `SET PX`
+//! semantics). The holder renews it every `renew_interval_ms`; if it dies the
+//! lease expires after `lease_ttl_ms` and a standby takes over on its next
+//! round. Standbys tail the portfolio journal so they already hold the
+//! reconstructed state when they are promoted, load it into the position
+//! manager as they take over, and an order router wired with
+//! [`SmartOrderRouter::with_failover`](crate::order_router::SmartOrderRouter::with_failover)
+//! refuses new orders until its instance leads.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{watch, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::portfolio_snapshot::{journal_path, replay, PortfolioState};
+use crate::position::{AgentPosition, PositionManager, SymbolPosition};
+use crate::redis::PooledRedisClient;
+
+/// Errors that can occur during leader election or journal replay
+#[derive(Debug, Error)]
+pub enum FailoverError {
+    #[error("Lease store error: {0}")]
+    Lease(String),
+
+    #[error("Journal replay failed: {0}")]
+    Journal(String),
+
+    #[error("Failed to restore replayed state: {0}")]
+    Restore(String),
+}
+
+/// Failover configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FailoverConfig {
+    /// ID this instance holds the lease under; unique per process
+    pub instance_id: String,
+    /// Key of the leader lease
+    pub lease_key: String,
+    /// Lease lifetime; a dead leader is replaced within roughly this long
+    pub lease_ttl_ms: u64,
+    /// How often the leader renews and standbys try to acquire the lease
+    pub renew_interval_ms: u64,
+    /// Allowance for clock drift and store latency; a leader treats its lease
+    /// as lost this long before it would expire
+    pub lease_safety_margin_ms: u64,
+    /// Directory of the portfolio journal standbys replay
+    pub journal_dir: PathBuf,
+    /// How often standbys read new journal events
+    pub journal_poll_interval_ms: u64,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            instance_id: format!("noderr-{}", Uuid::new_v4()),
+            lease_key: "failover:leader".to_string(),
+            lease_ttl_ms: 5_000,
+            renew_interval_ms: 1_000,
+            lease_safety_margin_ms: 500,
+            journal_dir: PathBuf::from("data/portfolio"),
+            journal_poll_interval_ms: 250,
+        }
+    }
+}
+
+/// Role of an instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailoverRole {
+    /// Following the journal; order flow disabled
+    Standby,
+    /// Holding the lease; order flow enabled
+    Leader,
+}
+
+/// Store of expiring leases with compare-and-set semantics
+#[async_trait]
+pub trait LeaseStore: Send + Sync {
+    /// Acquire the lease for `holder`, or extend it if `holder` already has it.
+    /// Returns `false` while another holder's lease is live.
+    async fn try_acquire(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, FailoverError>;
+
+    /// Give the lease up if `holder` has it
+    async fn release(&self, key: &str, holder: &str) -> Result<bool, FailoverError>;
+
+    /// Current holder of the lease
+    async fn holder(&self, key: &str) -> Result<Option<String>, FailoverError>;
+}
+
+/// Sets the key when it is free or already ours, in one round trip
+const ACQUIRE_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if current == false or current == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+    return 1
+end
+return 0
+"#;
+
+/// Deletes the key only when it is ours
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Lease store backed by Redis keys with a millisecond expiry
+pub struct RedisLeaseStore {
+    /// Redis client
+    client: Arc<PooledRedisClient>,
+}
+
+impl RedisLeaseStore {
+    /// Create a lease store on a pooled client
+    pub fn new(client: Arc<PooledRedisClient>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl LeaseStore for RedisLeaseStore {
+    async fn try_acquire(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, FailoverError> {
+        let acquired: i64 = self.client
+            .query(redis::cmd("EVAL")
+                .arg(ACQUIRE_SCRIPT)
+                .arg(1)
+                .arg(self.client.full_key(key))
+                .arg(holder)
+                .arg(ttl.as_millis() as u64))
+            .await
+            .map_err(|e| FailoverError::Lease(e.to_string()))?;
+        Ok(acquired == 1)
+    }
+
+    async fn release(&self, key: &str, holder: &str) -> Result<bool, FailoverError> {
+        let released: i64 = self.client
+            .query(redis::cmd("EVAL").arg(RELEASE_SCRIPT).arg(1).arg(self.client.full_key(key)).arg(holder))
+            .await
+            .map_err(|e| FailoverError::Lease(e.to_string()))?;
+        Ok(released == 1)
+    }
+
+    async fn holder(&self, key: &str) -> Result<Option<String>, FailoverError> {
+        self.client
+            .query(redis::cmd("GET").arg(self.client.full_key(key)))
+            .await
+            .map_err(|e| FailoverError::Lease(e.to_string()))
+    }
+}
+
+/// In-process lease store, for tests and single-host deployments
+#[derive(Default)]
+pub struct MemoryLeaseStore {
+    /// Holder and expiry by key
+    leases: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl MemoryLeaseStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LeaseStore for MemoryLeaseStore {
+    async fn try_acquire(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, FailoverError> {
+        let mut leases = self.leases.lock().unwrap();
+        let now = Instant::now();
+        let free = match leases.get(key) {
+            Some((current, expires_at)) => current == holder || *expires_at <= now,
+            None => true,
+        };
+        if free {
+            leases.insert(key.to_string(), (holder.to_string(), now + ttl));
+        }
+        Ok(free)
+    }
+
+    async fn release(&self, key: &str, holder: &str) -> Result<bool, FailoverError> {
+        let mut leases = self.leases.lock().unwrap();
+        if leases.get(key).is_some_and(|(current, _)| current == holder) {
+            leases.remove(key);
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    async fn holder(&self, key: &str) -> Result<Option<String>, FailoverError> {
+        let leases = self.leases.lock().unwrap();
+        Ok(leases.get(key)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(holder, _)| holder.clone()))
+    }
+}
+
+/// Journal position and the state rebuilt from it
+#[derive(Default)]
+struct ReplayState {
+    /// State after every event read so far
+    state: PortfolioState,
+    /// Journal bytes already folded into `state`
+    offset: u64,
+}
+
+/// Runs leader election for this instance and keeps standby state warm
+pub struct FailoverCoordinator {
+    /// Configuration
+    config: FailoverConfig,
+    /// Lease store shared by every instance
+    lease: Arc<dyn LeaseStore>,
+    /// Current role; receivers see promotions and demotions
+    role: watch::Sender<FailoverRole>,
+    /// State rebuilt from the journal
+    replay: RwLock<ReplayState>,
+    /// When the last successful acquire or renew was sent
+    last_renewed: Mutex<Option<Instant>>,
+    /// Receives the replayed positions on promotion (optional)
+    position_manager: Option<Arc<PositionManager>>,
+    /// Election and journal tasks
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl FailoverCoordinator {
+    /// Create a coordinator; every instance starts as a standby
+    pub fn new(config: FailoverConfig, lease: Arc<dyn LeaseStore>) -> Self {
+        let (role, _) = watch::channel(FailoverRole::Standby);
+        Self {
+            config,
+            lease,
+            role,
+            replay: RwLock::new(ReplayState::default()),
+            last_renewed: Mutex::new(None),
+            position_manager: None,
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Load replayed positions into a position manager when this instance is promoted
+    pub fn with_position_manager(mut self, position_manager: Arc<PositionManager>) -> Self {
+        self.position_manager = Some(position_manager);
+        self
+    }
+
+    /// ID this instance holds the lease under
+    pub fn instance_id(&self) -> &str {
+        &self.config.instance_id
+    }
+
+    /// Current role
+    pub fn role(&self) -> FailoverRole {
+        *self.role.borrow()
+    }
+
+    /// Whether this instance may send orders
+    ///
+    /// A leader whose lease may already have expired is not trusted even
+    /// before its next election round demotes it.
+    pub fn is_leader(&self) -> bool {
+        self.role() == FailoverRole::Leader && !self.lease_lapsing(Duration::ZERO)
+    }
+
+    /// Whether the lease is within `slack` plus the safety margin of expiring
+    fn lease_lapsing(&self, slack: Duration) -> bool {
+        let ttl = Duration::from_millis(self.config.lease_ttl_ms);
+        let margin = Duration::from_millis(self.config.lease_safety_margin_ms);
+        let valid_for = ttl.saturating_sub(slack + margin);
+        !self.last_renewed.lock().unwrap().is_some_and(|at| at.elapsed() < valid_for)
+    }
+
+    /// Watch role changes
+    pub fn subscribe(&self) -> watch::Receiver<FailoverRole> {
+        self.role.subscribe()
+    }
+
+    /// Portfolio state rebuilt from the journal
+    pub async fn state(&self) -> PortfolioState {
+        self.replay.read().await.state.clone()
+    }
+
+    /// Fold journal events written since the last call; returns how many were applied
+    pub async fn catch_up(&self) -> Result<u64, FailoverError> {
+        let mut replay_state = self.replay.write().await;
+        let before = replay_state.state.events_applied;
+        let (state, offset) = replay(
+            &journal_path(&self.config.journal_dir),
+            replay_state.state.clone(),
+            replay_state.offset,
+            None,
+        )
+        .map_err(|e| FailoverError::Journal(e.to_string()))?;
+
+        let applied = state.events_applied - before;
+        replay_state.state = state;
+        replay_state.offset = offset;
+        Ok(applied)
+    }
+
+    /// Load the replayed positions and cash balances into the position manager
+    fn restore_positions(&self, state: &PortfolioState) -> Result<(), FailoverError> {
+        let Some(position_manager) = &self.position_manager else {
+            return Ok(());
+        };
+        let agents: Vec<AgentPosition> = state.positions
+            .iter()
+            .map(|(agent_id, symbols)| {
+                let mut agent = AgentPosition::new(agent_id, state.cash_balances.get(agent_id).copied().unwrap_or(0.0));
+                for (symbol, restored) in symbols {
+                    let mut position = SymbolPosition::new(symbol);
+                    position.net_size = restored.net_size;
+                    position.average_price = restored.average_price;
+                    position.realized_pnl = restored.realized_pnl;
+                    position.unrealized_pnl = restored.unrealized_pnl;
+                    position.last_update = restored.updated_at;
+                    agent.positions.insert(symbol.clone(), position);
+                }
+                agent
+            })
+            .collect();
+        position_manager.restore(agents).map_err(|e| FailoverError::Restore(e.to_string()))
+    }
+
+    /// Run one election round and return the resulting role.
+    ///
+    /// A standby catches up on the journal before trying the lease so it is
+    /// current the moment it leads. A leader that cannot renew steps down at
+    /// once when another instance holds the lease. When the store is
+    /// unreachable it steps down as soon as its lease could expire before the
+    /// next round, so two instances never both believe they lead.
+    pub async fn tick(&self) -> Result<FailoverRole, FailoverError> {
+        let ttl = Duration::from_millis(self.config.lease_ttl_ms);
+        let renew_interval = Duration::from_millis(self.config.renew_interval_ms);
+        let was_leader = self.role() == FailoverRole::Leader;
+
+        if !was_leader {
+            if let Err(e) = self.catch_up().await {
+                // A standby that cannot read the journal must not take over with stale state
+                warn!("Standby {} could not replay journal: {}", self.config.instance_id, e);
+                return Err(e);
+            }
+        }
+
+        // The store starts the lease clock on receipt, so ours starts before the call
+        let sent_at = Instant::now();
+        match self.lease.try_acquire(&self.config.lease_key, &self.config.instance_id, ttl).await {
+            Ok(true) => {
+                *self.last_renewed.lock().unwrap() = Some(sent_at);
+                if !was_leader {
+                    let state = self.state().await;
+                    if let Err(e) = self.restore_positions(&state) {
+                        // Leading without the replayed positions would trade blind; let another instance take over
+                        error!("Instance {} could not restore replayed state: {}; releasing the lease", self.config.instance_id, e);
+                        *self.last_renewed.lock().unwrap() = None;
+                        if let Err(release) = self.lease.release(&self.config.lease_key, &self.config.instance_id).await {
+                            warn!("Instance {} could not release the leader lease: {}", self.config.instance_id, release);
+                        }
+                        return Err(e);
+                    }
+                    warn!(
+                        "Instance {} acquired leadership after replaying {} journal events; enabling order flow",
+                        self.config.instance_id, state.events_applied
+                    );
+                    self.role.send_replace(FailoverRole::Leader);
+                }
+            }
+            Ok(false) => {
+                if was_leader {
+                    error!("Instance {} lost the leader lease; disabling order flow", self.config.instance_id);
+                    self.role.send_replace(FailoverRole::Standby);
+                }
+            }
+            Err(e) => {
+                if was_leader && self.lease_lapsing(renew_interval) {
+                    error!(
+                        "Instance {} could not renew the leader lease in time: {}; disabling order flow",
+                        self.config.instance_id, e
+                    );
+                    self.role.send_replace(FailoverRole::Standby);
+                }
+                return Err(e);
+            }
+        }
+
+        Ok(self.role())
+    }
+
+    /// Start the election and journal loops
+    pub fn start(self: &Arc<Self>) {
+        let mut tasks = self.tasks.lock().unwrap();
+        if !tasks.is_empty() {
+            return;
+        }
+        info!("Starting failover coordinator as {}", self.config.instance_id);
+
+        let coordinator = self.clone();
+        tasks.push(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(coordinator.config.renew_interval_ms));
+            loop {
+                interval.tick().await;
+                if let Err(e) = coordinator.tick().await {
+                    debug!("Failover round failed: {}", e);
+                }
+            }
+        }));
+
+        let coordinator = self.clone();
+        tasks.push(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(coordinator.config.journal_poll_interval_ms));
+            loop {
+                interval.tick().await;
+                // The leader writes the journal itself; only standbys need to follow it
+                if !coordinator.is_leader() {
+                    if let Err(e) = coordinator.catch_up().await {
+                        debug!("Journal replay failed: {}", e);
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Stop the loops and hand the lease over if this instance leads
+    pub async fn stop(&self) {
+        let tasks: Vec<_> = self.tasks.lock().unwrap().drain(..).collect();
+        for task in tasks {
+            task.abort();
+        }
+
+        if self.is_leader() {
+            self.role.send_replace(FailoverRole::Standby);
+            match self.lease.release(&self.config.lease_key, &self.config.instance_id).await {
+                Ok(_) => info!("Instance {} released the leader lease", self.config.instance_id),
+                Err(e) => warn!("Instance {} could not release the leader lease: {}", self.config.instance_id, e),
+            }
+        }
+    }
+}
+
+/// Create a failover coordinator
+pub fn create_failover_coordinator(config: FailoverConfig, lease: Arc<dyn LeaseStore>) -> Arc<FailoverCoordinator> {
+    Arc::new(FailoverCoordinator::new(config, lease))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::portfolio_snapshot::{PortfolioChange, PortfolioEvent};
+    use chrono::Utc;
+    use std::io::Write;
+
+    fn append_trust_score(dir: &std::path::Path, strategy_id: &str, score: f64) {
+        let event = PortfolioEvent {
+            timestamp: Utc::now(),
+            change: PortfolioChange::TrustScore { strategy_id: strategy_id.to_string(), score },
+        };
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(journal_path(dir)).unwrap();
+        writeln!(file, "{}", serde_json::to_string(&event).unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_standby_follows_journal_and_takes_over() {
+        let dir = std::env::temp_dir().join(format!("noderr-failover-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let lease: Arc<dyn LeaseStore> = Arc::new(MemoryLeaseStore::new());
+        let config = |id: &str| FailoverConfig {
+            instance_id: id.to_string(),
+            lease_ttl_ms: 200,
+            renew_interval_ms: 50,
+            lease_safety_margin_ms: 20,
+            journal_dir: dir.clone(),
+            ..FailoverConfig::default()
+        };
+        let primary = create_failover_coordinator(config("primary"), lease.clone());
+        let position_manager = crate::position::create_position_manager();
+        let standby = Arc::new(
+            FailoverCoordinator::new(config("standby"), lease.clone()).with_position_manager(position_manager.clone()),
+        );
+
+        append_trust_score(&dir, "momentum", 0.8);
+        assert_eq!(primary.tick().await.unwrap(), FailoverRole::Leader);
+        assert_eq!(standby.tick().await.unwrap(), FailoverRole::Standby);
+        assert_eq!(standby.state().await.trust_scores["momentum"], 0.8);
+
+        // The standby keeps following while the primary renews
+        append_trust_score(&dir, "momentum", 0.6);
+        assert_eq!(primary.tick().await.unwrap(), FailoverRole::Leader);
+        assert_eq!(standby.catch_up().await.unwrap(), 1);
+        assert_eq!(standby.tick().await.unwrap(), FailoverRole::Standby);
+
+        // The primary dies: once its lease lapses the standby takes over with current state
+        append_trust_score(&dir, "momentum", 0.4);
+        let position = PortfolioEvent {
+            timestamp: Utc::now(),
+            change: PortfolioChange::Position(crate::position::PositionChangeEvent {
+                agent_id: "agent1".to_string(),
+                symbol: "ETH-USD".to_string(),
+                order_id: "order-1".to_string(),
+                is_fill: true,
+                net_size: 2.0,
+                average_price: 3000.0,
+                realized_pnl: 0.0,
+                unrealized_pnl: 0.0,
+                cash_balance: 94_000.0,
+                timestamp: Utc::now(),
+            }),
+        };
+        let mut file = std::fs::OpenOptions::new().append(true).open(journal_path(&dir)).unwrap();
+        writeln!(file, "{}", serde_json::to_string(&position).unwrap()).unwrap();
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        let mut roles = standby.subscribe();
+        assert_eq!(standby.tick().await.unwrap(), FailoverRole::Leader);
+        assert!(roles.has_changed().unwrap());
+        assert_eq!(standby.state().await.trust_scores["momentum"], 0.4);
+        assert_eq!(position_manager.get_symbol_position("agent1", "ETH-USD").unwrap().net_size, 2.0);
+        assert_eq!(position_manager.get_position("agent1").unwrap().cash_balance, 94_000.0);
+        assert_eq!(lease.holder("failover:leader").await.unwrap().as_deref(), Some("standby"));
+
+        // A primary that comes back finds the lease taken and steps down
+        assert_eq!(primary.tick().await.unwrap(), FailoverRole::Standby);
+
+        standby.stop().await;
+        assert!(!standby.is_leader());
+        assert_eq!(lease.holder("failover:leader").await.unwrap(), None);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Lease store that can be cut off
+    struct FlakyLeaseStore {
+        inner: MemoryLeaseStore,
+        down: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait]
+    impl LeaseStore for FlakyLeaseStore {
+        async fn try_acquire(&self, key: &str, holder: &str, ttl: Duration) -> Result<bool, FailoverError> {
+            if self.down.load(std::sync::atomic::Ordering::SeqCst) {
+                return Err(FailoverError::Lease("connection refused".to_string()));
+            }
+            self.inner.try_acquire(key, holder, ttl).await
+        }
+
+        async fn release(&self, key: &str, holder: &str) -> Result<bool, FailoverError> {
+            self.inner.release(key, holder).await
+        }
+
+        async fn holder(&self, key: &str) -> Result<Option<String>, FailoverError> {
+            self.inner.holder(key).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_leader_steps_down_before_lease_can_expire() {
+        let dir = std::env::temp_dir().join(format!("noderr-failover-{}", Uuid::new_v4()));
+        let lease = Arc::new(FlakyLeaseStore { inner: MemoryLeaseStore::new(), down: Default::default() });
+        let leader = create_failover_coordinator(FailoverConfig {
+            instance_id: "primary".to_string(),
+            lease_ttl_ms: 200,
+            renew_interval_ms: 50,
+            lease_safety_margin_ms: 20,
+            journal_dir: dir,
+            ..FailoverConfig::default()
+        }, lease.clone());
+        assert_eq!(leader.tick().await.unwrap(), FailoverRole::Leader);
+
+        // A single missed renewal with plenty of lease left is tolerated
+        lease.down.store(true, std::sync::atomic::Ordering::SeqCst);
+        assert!(leader.tick().await.is_err());
+        assert!(leader.is_leader());
+
+        // Another missed round could outlive the lease, so it steps down now
+        tokio::time::sleep(Duration::from_millis(140)).await;
+        assert!(leader.tick().await.is_err());
+        assert_eq!(leader.role(), FailoverRole::Standby);
+    }
+}
@@ -0,0 +1,604 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Feature store for engineered market features
+//!
+//! Strategies, factor analysis and regime detection share the same derived
+//! inputs (returns, volatility, book imbalance, ...). The store computes each
+//! registered feature once per tick or candle, keeps a versioned history per
+//! symbol, and answers point-in-time queries so backtests only ever see values
+//! that were knowable at the simulated time. The most recent values are
+//! mirrored into shared-memory ring buffers for low-latency readers.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use crate::backtest::BacktestBar;
+use crate::market::{Candle, MarketData, Symbol, Ticker};
+use crate::shared_memory::{BufferConfig, SharedMemoryManager, SharedRingBuffer};
+
+/// Errors that can occur in the feature store
+#[derive(Debug, Error)]
+pub enum FeatureStoreError {
+    #[error("Feature {name} v{version} is already registered")]
+    DuplicateFeature { name: String, version: u32 },
+
+    #[error("Unknown feature: {0}")]
+    UnknownFeature(String),
+}
+
+/// A derived market feature
+pub trait FeatureDefinition: Send + Sync {
+    /// Feature name, shared across versions
+    fn name(&self) -> &str;
+
+    /// Version; bump when the computation changes so old values stay distinguishable
+    fn version(&self) -> u32 {
+        1
+    }
+
+    /// Compute the feature, or None when there is not enough data
+    fn compute(&self, market_data: &MarketData) -> Option<f64>;
+}
+
+/// A feature computed by a closure
+pub struct FnFeature {
+    /// Feature name
+    name: String,
+    /// Feature version
+    version: u32,
+    /// Computation
+    compute: Box<dyn Fn(&MarketData) -> Option<f64> + Send + Sync>,
+}
+
+impl FnFeature {
+    /// Wrap a closure as a feature
+    pub fn new(
+        name: &str,
+        version: u32,
+        compute: impl Fn(&MarketData) -> Option<f64> + Send + Sync + 'static,
+    ) -> Self {
+        Self { name: name.to_string(), version, compute: Box::new(compute) }
+    }
+}
+
+impl FeatureDefinition for FnFeature {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn compute(&self, market_data: &MarketData) -> Option<f64> {
+        (self.compute)(market_data)
+    }
+}
+
+/// Closes of a timeframe's candles, oldest first
+fn candle_closes(market_data: &MarketData, timeframe: &str) -> Option<Vec<f64>> {
+    market_data
+        .candles
+        .get(timeframe)
+        .map(|candles| candles.iter().map(|c| c.close.to_f64().unwrap_or(0.0)).collect())
+}
+
+/// Log return over a number of candles
+pub struct LogReturnFeature {
+    /// Feature name
+    name: String,
+    /// Candle timeframe
+    timeframe: String,
+    /// Candles to look back
+    period: usize,
+}
+
+impl LogReturnFeature {
+    /// Log return over `period` candles of `timeframe`, named `log_return_{timeframe}_{period}`
+    pub fn new(timeframe: &str, period: usize) -> Self {
+        Self {
+            name: format!("log_return_{}_{}", timeframe, period),
+            timeframe: timeframe.to_string(),
+            period: period.max(1),
+        }
+    }
+}
+
+impl FeatureDefinition for LogReturnFeature {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn compute(&self, market_data: &MarketData) -> Option<f64> {
+        let closes = candle_closes(market_data, &self.timeframe)?;
+        let last = *closes.last()?;
+        let base = *closes.get(closes.len().checked_sub(self.period + 1)?)?;
+        (base > 0.0 && last > 0.0).then(|| (last / base).ln())
+    }
+}
+
+/// Realized volatility (standard deviation of log returns) over a number of candles
+pub struct RealizedVolatilityFeature {
+    /// Feature name
+    name: String,
+    /// Candle timeframe
+    timeframe: String,
+    /// Returns in the window
+    period: usize,
+}
+
+impl RealizedVolatilityFeature {
+    /// Volatility of the last `period` returns, named `realized_vol_{timeframe}_{period}`
+    pub fn new(timeframe: &str, period: usize) -> Self {
+        Self {
+            name: format!("realized_vol_{}_{}", timeframe, period),
+            timeframe: timeframe.to_string(),
+            period: period.max(2),
+        }
+    }
+}
+
+impl FeatureDefinition for RealizedVolatilityFeature {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn compute(&self, market_data: &MarketData) -> Option<f64> {
+        let closes = candle_closes(market_data, &self.timeframe)?;
+        let window = &closes[closes.len().checked_sub(self.period + 1)?..];
+        let returns: Vec<f64> = window
+            .windows(2)
+            .filter(|pair| pair[0] > 0.0 && pair[1] > 0.0)
+            .map(|pair| (pair[1] / pair[0]).ln())
+            .collect();
+        if returns.len() < 2 {
+            return None;
+        }
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+        Some(variance.sqrt())
+    }
+}
+
+/// Top-of-book imbalance (-1..1) over a number of levels
+pub struct BookImbalanceFeature {
+    /// Feature name
+    name: String,
+    /// Levels per side
+    depth: usize,
+}
+
+impl BookImbalanceFeature {
+    /// Imbalance across `depth` levels, named `book_imbalance_{depth}`
+    pub fn new(depth: usize) -> Self {
+        Self { name: format!("book_imbalance_{}", depth), depth: depth.max(1) }
+    }
+}
+
+impl FeatureDefinition for BookImbalanceFeature {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn compute(&self, market_data: &MarketData) -> Option<f64> {
+        market_data.orderbook.as_ref().map(|book| book.imbalance(self.depth))
+    }
+}
+
+/// When features are recomputed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureCadence {
+    /// On every distinct update time
+    Tick,
+    /// Only when a new candle of the store's timeframe closes
+    Candle,
+}
+
+/// Feature store configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeatureStoreConfig {
+    /// Recompute cadence
+    pub cadence: FeatureCadence,
+    /// Candle timeframe driving candle cadence
+    pub timeframe: String,
+    /// Values retained per symbol and feature version
+    pub max_history: usize,
+    /// Values mirrored per hot shared-memory buffer
+    pub hot_capacity: usize,
+}
+
+impl Default for FeatureStoreConfig {
+    fn default() -> Self {
+        Self {
+            cadence: FeatureCadence::Candle,
+            timeframe: "1h".to_string(),
+            max_history: 10_000,
+            hot_capacity: 256,
+        }
+    }
+}
+
+/// A computed feature value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureValue {
+    /// Symbol the value belongs to
+    pub symbol: Symbol,
+    /// Feature name
+    pub name: String,
+    /// Feature version
+    pub version: u32,
+    /// Value
+    pub value: f64,
+    /// Time at which the value became knowable
+    pub as_of: DateTime<Utc>,
+}
+
+/// History key: symbol, feature name and version
+type FeatureKey = (Symbol, String, u32);
+
+/// Computes, versions and serves market features
+pub struct FeatureStore {
+    /// Configuration
+    config: FeatureStoreConfig,
+    /// Registered features
+    features: RwLock<Vec<Arc<dyn FeatureDefinition>>>,
+    /// Value history per key, ordered by `as_of`
+    history: RwLock<HashMap<FeatureKey, Vec<FeatureValue>>>,
+    /// Last tick or candle time computed per symbol
+    last_computed: RwLock<HashMap<Symbol, DateTime<Utc>>>,
+    /// Shared memory for hot values
+    shared_memory: Option<Arc<SharedMemoryManager>>,
+}
+
+impl FeatureStore {
+    /// Create an empty feature store
+    pub fn new(config: FeatureStoreConfig) -> Self {
+        Self {
+            config,
+            features: RwLock::new(Vec::new()),
+            history: RwLock::new(HashMap::new()),
+            last_computed: RwLock::new(HashMap::new()),
+            shared_memory: None,
+        }
+    }
+
+    /// Mirror the latest values into shared-memory ring buffers
+    pub fn with_shared_memory(mut self, shared_memory: Arc<SharedMemoryManager>) -> Self {
+        self.shared_memory = Some(shared_memory);
+        self
+    }
+
+    /// Register a feature version
+    pub async fn register(&self, feature: Arc<dyn FeatureDefinition>) -> Result<(), FeatureStoreError> {
+        let mut features = self.features.write().await;
+        if features.iter().any(|f| f.name() == feature.name() && f.version() == feature.version()) {
+            return Err(FeatureStoreError::DuplicateFeature {
+                name: feature.name().to_string(),
+                version: feature.version(),
+            });
+        }
+        features.push(feature);
+        Ok(())
+    }
+
+    /// Registered (name, version) pairs
+    pub async fn registered(&self) -> Vec<(String, u32)> {
+        self.features.read().await.iter().map(|f| (f.name().to_string(), f.version())).collect()
+    }
+
+    /// Highest registered version of a feature
+    async fn latest_version(&self, name: &str) -> Result<u32, FeatureStoreError> {
+        self.features
+            .read()
+            .await
+            .iter()
+            .filter(|f| f.name() == name)
+            .map(|f| f.version())
+            .max()
+            .ok_or_else(|| FeatureStoreError::UnknownFeature(name.to_string()))
+    }
+
+    /// Shared-memory buffer name for a feature version
+    fn hot_buffer_name(symbol: &str, name: &str, version: u32) -> String {
+        format!("features:{}:{}:v{}", symbol, name, version)
+    }
+
+    /// Compute all features for a market data update observed at `as_of`.
+    ///
+    /// Candles stamped after `as_of` are ignored, so replaying history with
+    /// look-ahead data still yields point-in-time values. Returns the newly
+    /// computed values, or nothing if this tick/candle was already processed.
+    pub async fn compute(&self, market_data: &MarketData, as_of: DateTime<Utc>) -> Vec<FeatureValue> {
+        let has_future = market_data.candles.values().flatten().any(|c| c.timestamp > as_of);
+        let trimmed;
+        let market_data = if has_future {
+            let mut view = market_data.clone();
+            for candles in view.candles.values_mut() {
+                candles.retain(|c| c.timestamp <= as_of);
+            }
+            trimmed = view;
+            &trimmed
+        } else {
+            market_data
+        };
+
+        let marker = match self.config.cadence {
+            FeatureCadence::Tick => Some(as_of),
+            FeatureCadence::Candle => market_data
+                .candles
+                .get(&self.config.timeframe)
+                .and_then(|candles| candles.last())
+                .map(|c| c.timestamp),
+        };
+        let Some(marker) = marker else {
+            return Vec::new();
+        };
+        {
+            let mut last_computed = self.last_computed.write().await;
+            if last_computed.get(&market_data.symbol).is_some_and(|last| *last >= marker) {
+                return Vec::new();
+            }
+            last_computed.insert(market_data.symbol.clone(), marker);
+        }
+
+        let features = self.features.read().await.clone();
+        let values: Vec<FeatureValue> = features
+            .iter()
+            .filter_map(|feature| {
+                let value = feature.compute(market_data)?;
+                value.is_finite().then(|| FeatureValue {
+                    symbol: market_data.symbol.clone(),
+                    name: feature.name().to_string(),
+                    version: feature.version(),
+                    value,
+                    as_of,
+                })
+            })
+            .collect();
+
+        {
+            let mut history = self.history.write().await;
+            for value in &values {
+                let series = history
+                    .entry((value.symbol.clone(), value.name.clone(), value.version))
+                    .or_default();
+                // Keep the series ordered even if updates arrive out of order
+                let index = series.partition_point(|v| v.as_of <= value.as_of);
+                series.insert(index, value.clone());
+                if series.len() > self.config.max_history {
+                    let excess = series.len() - self.config.max_history;
+                    series.drain(..excess);
+                }
+            }
+        }
+
+        if let Some(shared_memory) = &self.shared_memory {
+            for value in &values {
+                let buffer_name = Self::hot_buffer_name(&value.symbol, &value.name, value.version);
+                let buffer = shared_memory.get_buffer::<FeatureValue>(&buffer_name).unwrap_or_else(|| {
+                    shared_memory.create_buffer(
+                        &buffer_name,
+                        BufferConfig { capacity: self.config.hot_capacity, ..BufferConfig::default() },
+                    )
+                });
+                buffer.push(value.clone());
+            }
+        }
+
+        debug!("Computed {} features for {} as of {}", values.len(), market_data.symbol, as_of);
+        values
+    }
+
+    /// Value of a feature as it was known at `at` (latest version if none given)
+    pub async fn get_as_of(
+        &self,
+        symbol: &str,
+        name: &str,
+        version: Option<u32>,
+        at: DateTime<Utc>,
+    ) -> Result<Option<FeatureValue>, FeatureStoreError> {
+        let version = match version {
+            Some(version) => version,
+            None => self.latest_version(name).await?,
+        };
+        let history = self.history.read().await;
+        let Some(series) = history.get(&(symbol.to_string(), name.to_string(), version)) else {
+            return Ok(None);
+        };
+        let index = series.partition_point(|v| v.as_of <= at);
+        Ok(index.checked_sub(1).map(|i| series[i].clone()))
+    }
+
+    /// Latest value of a feature, served from shared memory when available
+    pub async fn latest(&self, symbol: &str, name: &str) -> Result<Option<FeatureValue>, FeatureStoreError> {
+        let version = self.latest_version(name).await?;
+        if let Some(buffer) = self.hot_buffer(symbol, name, version) {
+            if let Some(event) = buffer.get_recent(1).into_iter().next() {
+                return Ok(Some(event.data));
+            }
+        }
+        let history = self.history.read().await;
+        Ok(history
+            .get(&(symbol.to_string(), name.to_string(), version))
+            .and_then(|series| series.last().cloned()))
+    }
+
+    /// Shared-memory buffer holding a feature version's recent values
+    pub fn hot_buffer(&self, symbol: &str, name: &str, version: u32) -> Option<Arc<SharedRingBuffer<FeatureValue>>> {
+        self.shared_memory
+            .as_ref()?
+            .get_buffer::<FeatureValue>(&Self::hot_buffer_name(symbol, name, version))
+    }
+
+    /// Latest version of every feature as known at `at`, keyed by name
+    pub async fn frame_as_of(&self, symbol: &str, at: DateTime<Utc>) -> HashMap<String, f64> {
+        let mut versions: HashMap<String, u32> = HashMap::new();
+        for feature in self.features.read().await.iter() {
+            let version = versions.entry(feature.name().to_string()).or_insert(feature.version());
+            *version = (*version).max(feature.version());
+        }
+
+        let history = self.history.read().await;
+        versions
+            .into_iter()
+            .filter_map(|(name, version)| {
+                let series = history.get(&(symbol.to_string(), name.clone(), version))?;
+                let index = series.partition_point(|v| v.as_of <= at).checked_sub(1)?;
+                Some((name, series[index].value))
+            })
+            .collect()
+    }
+
+    /// Compute features over historical bars as a backtest would see them,
+    /// each bar's values becoming knowable at its timestamp
+    pub async fn backfill(&self, symbol: &str, bars: &[BacktestBar], candle_window: usize) -> usize {
+        let mut computed = 0;
+        for index in 0..bars.len() {
+            let bar = &bars[index];
+            let start = (index + 1).saturating_sub(candle_window.max(1));
+            let candles: Vec<Candle> = bars[start..=index]
+                .iter()
+                .map(|b| {
+                    let d = |v: f64| Decimal::from_f64(v).unwrap_or_default();
+                    Candle::new(b.timestamp, d(b.open), d(b.high), d(b.low), d(b.close), d(b.volume))
+                })
+                .collect();
+            let ticker = Ticker {
+                bid: bar.close,
+                ask: bar.close,
+                last: bar.close,
+                volume: bar.volume,
+                change_24h: 0.0,
+                high_24h: bar.high,
+                low_24h: bar.low,
+                quote_volume: bar.volume * bar.close,
+            };
+            let mut market_data = MarketData::new("backfill".to_string(), symbol.to_string(), ticker);
+            market_data.candles.insert(self.config.timeframe.clone(), candles);
+            market_data.last_updated = bar.timestamp.timestamp();
+            computed += self.compute(&market_data, bar.timestamp).await.len();
+        }
+        computed
+    }
+}
+
+/// Create a feature store with the standard return, volatility and imbalance features
+pub async fn create_feature_store(
+    config: FeatureStoreConfig,
+    shared_memory: Option<Arc<SharedMemoryManager>>,
+) -> Arc<FeatureStore> {
+    let timeframe = config.timeframe.clone();
+    let mut store = FeatureStore::new(config);
+    if let Some(shared_memory) = shared_memory {
+        store = store.with_shared_memory(shared_memory);
+    }
+    let defaults: [Arc<dyn FeatureDefinition>; 4] = [
+        Arc::new(LogReturnFeature::new(&timeframe, 1)),
+        Arc::new(LogReturnFeature::new(&timeframe, 24)),
+        Arc::new(RealizedVolatilityFeature::new(&timeframe, 24)),
+        Arc::new(BookImbalanceFeature::new(5)),
+    ];
+    for feature in defaults {
+        // Names are distinct, so registration cannot collide
+        let _ = store.register(feature).await;
+    }
+    Arc::new(store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn bars(closes: &[f64]) -> Vec<BacktestBar> {
+        let start = Utc::now() - Duration::hours(closes.len() as i64);
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| BacktestBar {
+                timestamp: start + Duration::hours(i as i64),
+                open: *close,
+                high: *close,
+                low: *close,
+                close: *close,
+                volume: 1.0,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_point_in_time_lookups_and_versions() {
+        let shared_memory = Arc::new(SharedMemoryManager::new());
+        let store = FeatureStore::new(FeatureStoreConfig::default()).with_shared_memory(shared_memory);
+        store.register(Arc::new(LogReturnFeature::new("1h", 1))).await.unwrap();
+        store
+            .register(Arc::new(FnFeature::new("last_close", 1, |md: &MarketData| Some(md.ticker.last))))
+            .await
+            .unwrap();
+        store
+            .register(Arc::new(FnFeature::new("last_close", 2, |md: &MarketData| Some(md.ticker.last * 2.0))))
+            .await
+            .unwrap();
+        assert!(store.register(Arc::new(LogReturnFeature::new("1h", 1))).await.is_err());
+
+        let history = bars(&[100.0, 110.0, 121.0, 90.0]);
+        store.backfill("BTC/USDT", &history, 50).await;
+
+        // Recomputing the same candle is a no-op
+        let ticker = Ticker {
+            bid: 90.0,
+            ask: 90.0,
+            last: 90.0,
+            volume: 1.0,
+            change_24h: 0.0,
+            high_24h: 90.0,
+            low_24h: 90.0,
+            quote_volume: 90.0,
+        };
+        let mut replay = MarketData::new("x".to_string(), "BTC/USDT".to_string(), ticker);
+        let candles = history
+            .iter()
+            .map(|b| {
+                let d = Decimal::from_f64(b.close).unwrap();
+                Candle::new(b.timestamp, d, d, d, d, Decimal::ONE)
+            })
+            .collect();
+        replay.candles.insert("1h".to_string(), candles);
+        assert!(store.compute(&replay, history[3].timestamp).await.is_empty());
+
+        // As of the third bar, the later crash is invisible
+        let frame = store.frame_as_of("BTC/USDT", history[2].timestamp).await;
+        assert!((frame["log_return_1h_1"] - (121.0f64 / 110.0).ln()).abs() < 1e-9);
+        assert_eq!(frame["last_close"], 242.0);
+        let v1 = store.get_as_of("BTC/USDT", "last_close", Some(1), history[2].timestamp).await.unwrap().unwrap();
+        assert_eq!(v1.value, 121.0);
+        assert!(store.get_as_of("BTC/USDT", "last_close", None, history[0].timestamp - Duration::seconds(1)).await.unwrap().is_none());
+
+        let latest = store.latest("BTC/USDT", "last_close").await.unwrap().unwrap();
+        assert_eq!(latest.value, 180.0);
+        assert!(store.hot_buffer("BTC/USDT", "last_close", 2).is_some());
+    }
+}
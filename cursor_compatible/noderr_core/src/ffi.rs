@@ -0,0 +1,949 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Stable C ABI around [`RiskCalculator`] and [`DrawdownMonitor`] so non-Rust
+//! execution systems can reuse the same risk logic, and around [`BacktestEnv`]
+//! so reinforcement learning agents can train against the backtester.
+//!
+//! The matching header is generated into `include/noderr_core.h` by
+//! `build.rs` (cbindgen) when the `ffi` feature is enabled.
+//!
+//! Conventions:
+//! - Every handle is opaque and must be released with its `_free` function.
+//! - Every fallible function returns a [`NoderrStatus`]; on failure a message
+//!   is available from [`noderr_last_error`] on the same thread.
+//! - Strings are NUL-terminated UTF-8 and are only borrowed for the duration
+//!   of the call.
+//! - Functions block on an internal runtime and must not be called from
+//!   inside a tokio runtime.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use once_cell::sync::Lazy;
+use tokio::runtime::Runtime;
+
+use crate::backtest::{BacktestBar, BacktestError};
+use crate::backtest_env::{BacktestEnv, BacktestEnvConfig};
+use crate::drawdown_monitor::{DrawdownConfig, DrawdownMonitor, KillSwitch, TradeDataPoint, TradeType};
+use crate::risk::PositionDirection;
+use crate::risk_calc::{PositionExposure, RiskCalculator, RiskConfig, RiskViolationType};
+
+/// Version of the C ABI; bumped on any incompatible change
+pub const NODERR_FFI_ABI_VERSION: u32 = 1;
+
+/// Bits of `NoderrRiskCheckResult::violation_mask`
+pub const NODERR_VIOLATION_POSITION_SIZE: u32 = 1 << 0;
+pub const NODERR_VIOLATION_LEVERAGE: u32 = 1 << 1;
+pub const NODERR_VIOLATION_TRUST_SCORE: u32 = 1 << 2;
+pub const NODERR_VIOLATION_VENUE_EXPOSURE: u32 = 1 << 3;
+pub const NODERR_VIOLATION_SYMBOL_EXPOSURE: u32 = 1 << 4;
+pub const NODERR_VIOLATION_SECTOR_EXPOSURE: u32 = 1 << 5;
+pub const NODERR_VIOLATION_DRAWDOWN: u32 = 1 << 6;
+pub const NODERR_VIOLATION_PORTFOLIO_ALLOCATION: u32 = 1 << 7;
+pub const NODERR_VIOLATION_LOW_LIQUIDITY: u32 = 1 << 8;
+pub const NODERR_VIOLATION_HIGH_VOLATILITY: u32 = 1 << 9;
+pub const NODERR_VIOLATION_OTHER: u32 = 1 << 10;
+
+/// Runtime used to drive the async risk components from C callers
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("noderr-ffi")
+        .enable_all()
+        .build()
+        .expect("Failed to build FFI runtime")
+});
+
+thread_local! {
+    /// Message for the last error on this thread
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+/// Status code returned by every fallible FFI function
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoderrStatus {
+    /// Success
+    Ok = 0,
+    /// A required pointer argument was NULL
+    NullPointer = 1,
+    /// An argument was invalid (e.g. not UTF-8)
+    InvalidArgument = 2,
+    /// The requested agent or entity does not exist
+    NotFound = 3,
+    /// An internal error or panic occurred
+    Internal = 4,
+}
+
+/// Side of a trade recorded with the drawdown monitor
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoderrTradeType {
+    Buy = 0,
+    Sell = 1,
+    Close = 2,
+}
+
+impl TryFrom<u32> for NoderrTradeType {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(NoderrTradeType::Buy),
+            1 => Ok(NoderrTradeType::Sell),
+            2 => Ok(NoderrTradeType::Close),
+            other => Err(other),
+        }
+    }
+}
+
+/// Risk limits; pass NULL to use the defaults
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoderrRiskConfig {
+    /// Maximum position size as a fraction of portfolio (0.0-1.0)
+    pub max_position_size_pct: f64,
+    /// Maximum leverage allowed
+    pub max_leverage: f64,
+    /// Maximum drawdown allowed (0.0-1.0)
+    pub max_drawdown_pct: f64,
+    /// Minimum venue trust score required (0.0-1.0)
+    pub min_trust_score: f64,
+    /// Maximum exposure per symbol as a fraction of portfolio (0.0-1.0)
+    pub max_exposure_per_symbol: f64,
+    /// Maximum exposure per venue as a fraction of portfolio (0.0-1.0)
+    pub max_exposure_per_venue: f64,
+    /// Skip non-critical checks
+    pub fast_risk_mode: bool,
+}
+
+/// An order to check or a fill to record with the risk calculator
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoderrOrder {
+    /// Symbol (required)
+    pub symbol: *const c_char,
+    /// Venue (required)
+    pub venue: *const c_char,
+    /// Strategy ID (optional, may be NULL)
+    pub strategy_id: *const c_char,
+    /// Size in base currency
+    pub size: f64,
+    /// Notional value in quote currency
+    pub value: f64,
+    /// Leverage
+    pub leverage: f64,
+    /// True for a long/buy, false for a short/sell
+    pub is_long: bool,
+}
+
+/// Result of a pre-trade risk check
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoderrRiskCheckResult {
+    /// Whether all checks passed
+    pub passed: bool,
+    /// Risk level (0.0-1.0)
+    pub risk_level: f64,
+    /// Number of violations
+    pub violation_count: u32,
+    /// Bit set of violated checks, one bit per `NODERR_VIOLATION_*` constant
+    pub violation_mask: u32,
+}
+
+/// Aggregate risk calculator state
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoderrRiskState {
+    /// Total exposure across all positions
+    pub total_exposure: f64,
+    /// Number of open positions
+    pub position_count: u32,
+    /// Number of venues with exposure
+    pub venue_count: u32,
+}
+
+/// Drawdown limits; pass NULL to use the defaults
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoderrDrawdownConfig {
+    /// Maximum allowed drawdown (0.0-1.0)
+    pub max_drawdown_pct: f64,
+    /// Alert threshold (0.0-1.0)
+    pub alert_threshold_pct: f64,
+    /// Number of data points kept per agent
+    pub rolling_window_size: u32,
+    /// Trades required before drawdown tracking starts
+    pub min_trades_for_drawdown: u32,
+    /// Cooldown after a breach (ms)
+    pub cooldown_period_ms: u64,
+}
+
+/// A trade recorded with the drawdown monitor
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoderrTrade {
+    /// Agent/strategy ID (required)
+    pub agent_id: *const c_char,
+    /// Symbol (required)
+    pub symbol: *const c_char,
+    /// Trade ID (required)
+    pub trade_id: *const c_char,
+    /// Trade amount
+    pub amount: f64,
+    /// Trade price
+    pub price: f64,
+    /// Trade side, a `NoderrTradeType` value
+    pub trade_type: u32,
+    /// Equity after the trade
+    pub equity: f64,
+    /// Realized PnL of the trade
+    pub pnl: f64,
+    /// Unix timestamp in milliseconds; 0 uses the current time
+    pub timestamp_ms: i64,
+}
+
+/// Drawdown state for an agent
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoderrDrawdownState {
+    /// Whether the agent may trade (not in cooldown)
+    pub is_active: bool,
+    /// Current drawdown (0.0-1.0)
+    pub current_drawdown_pct: f64,
+    /// Peak equity
+    pub peak_equity: f64,
+    /// Current equity
+    pub current_equity: f64,
+    /// Cooldown end as Unix milliseconds; 0 when not in cooldown
+    pub cooldown_end_ms: i64,
+}
+
+/// A historical OHLCV bar
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoderrBar {
+    /// Unix timestamp in milliseconds
+    pub timestamp_ms: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// Backtest environment settings; pass NULL to use the defaults
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoderrEnvConfig {
+    /// Starting capital in quote currency
+    pub initial_capital: f64,
+    /// Fee per fill (basis points of notional)
+    pub fee_bps: f64,
+    /// Adverse slippage per fill (basis points)
+    pub slippage_bps: f64,
+    /// Whether negative actions may open shorts
+    pub allow_short: bool,
+    /// Exposure, as a multiple of equity, that action 1.0 maps to
+    pub max_exposure: f64,
+    /// Penalty on squared step returns
+    pub risk_aversion: f64,
+    /// Penalty per unit increase in drawdown
+    pub drawdown_penalty: f64,
+    /// Bars skipped for feature warm-up
+    pub warmup_bars: u32,
+    /// Maximum steps per episode (0 runs to the end of the data)
+    pub max_episode_steps: u32,
+}
+
+/// Result of a backtest environment step
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoderrEnvStepResult {
+    /// Risk-adjusted reward
+    pub reward: f64,
+    /// Whether the episode has ended
+    pub done: bool,
+    /// Equity after the step
+    pub equity: f64,
+    /// Signed position in base units
+    pub position: f64,
+    /// Drawdown from the episode peak (0.0-1.0)
+    pub drawdown: f64,
+}
+
+/// Kill switch callback invoked when an agent breaches its drawdown limit
+///
+/// Returns true if the agent was stopped.
+pub type NoderrKillSwitchFn = Option<
+    extern "C" fn(agent_id: *const c_char, reason: *const c_char, message: *const c_char, user_data: *mut c_void) -> bool,
+>;
+
+/// Opaque risk calculator handle
+pub struct NoderrRiskCalculator {
+    inner: Arc<RiskCalculator>,
+}
+
+/// Opaque drawdown monitor handle
+pub struct NoderrDrawdownMonitor {
+    inner: Arc<DrawdownMonitor>,
+}
+
+/// Opaque backtest environment handle
+pub struct NoderrBacktestEnv {
+    inner: BacktestEnv,
+}
+
+/// Kill switch forwarding to a C callback
+struct CKillSwitch {
+    callback: NoderrKillSwitchFn,
+    user_data: *mut c_void,
+}
+
+// The caller guarantees `user_data` may be used from any thread
+unsafe impl Send for CKillSwitch {}
+unsafe impl Sync for CKillSwitch {}
+
+#[async_trait::async_trait]
+impl KillSwitch for CKillSwitch {
+    async fn trigger(&self, agent_id: &str, reason: &str, message: &str) -> bool {
+        let callback = match self.callback {
+            Some(callback) => callback,
+            None => return false,
+        };
+
+        let (agent_id, reason, message) = match (
+            CString::new(agent_id),
+            CString::new(reason),
+            CString::new(message),
+        ) {
+            (Ok(a), Ok(r), Ok(m)) => (a, r, m),
+            _ => return false,
+        };
+
+        callback(agent_id.as_ptr(), reason.as_ptr(), message.as_ptr(), self.user_data)
+    }
+}
+
+fn set_last_error(message: impl Into<String>) {
+    let message = CString::new(message.into()).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Run an FFI body, converting errors and panics into status codes
+fn guard<F>(body: F) -> NoderrStatus
+where
+    F: FnOnce() -> Result<(), (NoderrStatus, String)>,
+{
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => NoderrStatus::Ok,
+        Ok(Err((status, message))) => {
+            set_last_error(message);
+            status
+        }
+        Err(_) => {
+            set_last_error("panic in noderr_core");
+            NoderrStatus::Internal
+        }
+    }
+}
+
+/// Borrow a required C string
+unsafe fn required_str<'a>(value: *const c_char, name: &str) -> Result<&'a str, (NoderrStatus, String)> {
+    if value.is_null() {
+        return Err((NoderrStatus::NullPointer, format!("{} is NULL", name)));
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|_| (NoderrStatus::InvalidArgument, format!("{} is not valid UTF-8", name)))
+}
+
+/// Borrow an optional C string
+unsafe fn optional_str<'a>(value: *const c_char, name: &str) -> Result<Option<&'a str>, (NoderrStatus, String)> {
+    if value.is_null() {
+        Ok(None)
+    } else {
+        required_str(value, name).map(Some)
+    }
+}
+
+/// Borrow a required handle or struct pointer
+unsafe fn required_ref<'a, T>(value: *const T, name: &str) -> Result<&'a T, (NoderrStatus, String)> {
+    value.as_ref().ok_or_else(|| (NoderrStatus::NullPointer, format!("{} is NULL", name)))
+}
+
+unsafe fn position_from_order(order: &NoderrOrder) -> Result<PositionExposure, (NoderrStatus, String)> {
+    let symbol = required_str(order.symbol, "symbol")?;
+    let venue = required_str(order.venue, "venue")?;
+    let direction = if order.is_long { PositionDirection::Long } else { PositionDirection::Short };
+
+    // Trust score is filled in from the calculator's venue scores by the caller
+    Ok(PositionExposure::new(symbol, venue, order.size, order.value, order.leverage, 0.0, direction))
+}
+
+/// Check a caller observation buffer holds exactly `expected` values
+fn check_observation_buffer(out: *mut f64, out_len: usize, expected: usize) -> Result<(), (NoderrStatus, String)> {
+    if out.is_null() {
+        return Err((NoderrStatus::NullPointer, "observation is NULL".to_string()));
+    }
+    if out_len != expected {
+        return Err((
+            NoderrStatus::InvalidArgument,
+            format!("observation buffer holds {} values, need {}", out_len, expected),
+        ));
+    }
+    Ok(())
+}
+
+fn backtest_status(error: BacktestError) -> (NoderrStatus, String) {
+    let status = match error {
+        BacktestError::Strategy(_) => NoderrStatus::Internal,
+        _ => NoderrStatus::InvalidArgument,
+    };
+    (status, error.to_string())
+}
+
+fn violation_bit(violation_type: RiskViolationType) -> u32 {
+    match violation_type {
+        RiskViolationType::PositionSize => NODERR_VIOLATION_POSITION_SIZE,
+        RiskViolationType::Leverage => NODERR_VIOLATION_LEVERAGE,
+        RiskViolationType::TrustScore => NODERR_VIOLATION_TRUST_SCORE,
+        RiskViolationType::VenueExposure => NODERR_VIOLATION_VENUE_EXPOSURE,
+        RiskViolationType::SymbolExposure => NODERR_VIOLATION_SYMBOL_EXPOSURE,
+        RiskViolationType::SectorExposure => NODERR_VIOLATION_SECTOR_EXPOSURE,
+        RiskViolationType::Drawdown => NODERR_VIOLATION_DRAWDOWN,
+        RiskViolationType::PortfolioAllocation => NODERR_VIOLATION_PORTFOLIO_ALLOCATION,
+        RiskViolationType::LowLiquidity => NODERR_VIOLATION_LOW_LIQUIDITY,
+        RiskViolationType::HighVolatility => NODERR_VIOLATION_HIGH_VOLATILITY,
+        RiskViolationType::Other => NODERR_VIOLATION_OTHER,
+    }
+}
+
+/// ABI version of this library; compare with `NODERR_FFI_ABI_VERSION`
+#[no_mangle]
+pub extern "C" fn noderr_ffi_abi_version() -> u32 {
+    NODERR_FFI_ABI_VERSION
+}
+
+/// Message for the last failed call on this thread, or NULL
+///
+/// The pointer is valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn noderr_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map(|message| message.as_ptr())
+            .unwrap_or(ptr::null())
+    })
+}
+
+/// Create a risk calculator; `config` may be NULL for defaults
+///
+/// Returns NULL on failure.
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_calculator_new(
+    config: *const NoderrRiskConfig,
+    portfolio_value: f64,
+) -> *mut NoderrRiskCalculator {
+    let mut risk_config = RiskConfig::default();
+    if let Some(config) = config.as_ref() {
+        risk_config.max_position_size_pct = config.max_position_size_pct;
+        risk_config.max_leverage = config.max_leverage;
+        risk_config.max_drawdown_pct = config.max_drawdown_pct;
+        risk_config.min_trust_score = config.min_trust_score;
+        risk_config.max_exposure_per_symbol = config.max_exposure_per_symbol;
+        risk_config.max_exposure_per_venue = config.max_exposure_per_venue;
+        risk_config.fast_risk_mode = config.fast_risk_mode;
+    }
+
+    match catch_unwind(|| RiskCalculator::new(risk_config, portfolio_value)) {
+        Ok(calculator) => Box::into_raw(Box::new(NoderrRiskCalculator {
+            inner: Arc::new(calculator),
+        })),
+        Err(_) => {
+            set_last_error("panic creating risk calculator");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release a risk calculator; NULL is ignored
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_calculator_free(calculator: *mut NoderrRiskCalculator) {
+    if !calculator.is_null() {
+        drop(Box::from_raw(calculator));
+    }
+}
+
+/// Run the pre-trade risk check for an order
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_check_order(
+    calculator: *const NoderrRiskCalculator,
+    order: *const NoderrOrder,
+    out: *mut NoderrRiskCheckResult,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        let order = required_ref(order, "order")?;
+        let out = out.as_mut().ok_or((NoderrStatus::NullPointer, "out is NULL".to_string()))?;
+
+        let mut position = position_from_order(order)?;
+        let strategy_id = optional_str(order.strategy_id, "strategy_id")?;
+
+        let result = RUNTIME.block_on(async {
+            position.trust_score = calculator.inner.get_trust_score(&position.venue).await;
+            calculator.inner.fast_risk_check(&position, strategy_id).await
+        });
+
+        *out = NoderrRiskCheckResult {
+            passed: result.passed,
+            risk_level: result.risk_level,
+            violation_count: result.violations.len() as u32,
+            violation_mask: result.violations
+                .iter()
+                .fold(0, |mask, v| mask | violation_bit(v.violation_type)),
+        };
+        Ok(())
+    })
+}
+
+/// Record a filled order as an open position
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_record_trade(
+    calculator: *const NoderrRiskCalculator,
+    order: *const NoderrOrder,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        let order = required_ref(order, "order")?;
+        let mut position = position_from_order(order)?;
+
+        RUNTIME
+            .block_on(async {
+                position.trust_score = calculator.inner.get_trust_score(&position.venue).await;
+                calculator.inner.add_position(position).await
+            })
+            .map_err(|e| (NoderrStatus::Internal, e.to_string()))
+    })
+}
+
+/// Remove the open position for a symbol on a venue
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_close_position(
+    calculator: *const NoderrRiskCalculator,
+    symbol: *const c_char,
+    venue: *const c_char,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        let symbol = required_str(symbol, "symbol")?;
+        let venue = required_str(venue, "venue")?;
+
+        RUNTIME
+            .block_on(calculator.inner.remove_position(symbol, venue))
+            .map_err(|e| (NoderrStatus::NotFound, e.to_string()))
+    })
+}
+
+/// Update the portfolio value used for percentage limits
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_update_portfolio_value(
+    calculator: *const NoderrRiskCalculator,
+    portfolio_value: f64,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        RUNTIME.block_on(calculator.inner.update_portfolio_value(portfolio_value));
+        Ok(())
+    })
+}
+
+/// Set the trust score (0.0-1.0) for a venue
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_set_trust_score(
+    calculator: *const NoderrRiskCalculator,
+    venue: *const c_char,
+    score: f64,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        let venue = required_str(venue, "venue")?;
+        RUNTIME.block_on(calculator.inner.set_trust_score(venue, score));
+        Ok(())
+    })
+}
+
+/// Query aggregate exposure state
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_get_state(
+    calculator: *const NoderrRiskCalculator,
+    out: *mut NoderrRiskState,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        let out = out.as_mut().ok_or((NoderrStatus::NullPointer, "out is NULL".to_string()))?;
+
+        *out = RUNTIME.block_on(async {
+            NoderrRiskState {
+                total_exposure: calculator.inner.get_total_exposure().await,
+                position_count: calculator.inner.get_all_positions().await.len() as u32,
+                venue_count: calculator.inner.get_all_venue_exposures().await.len() as u32,
+            }
+        });
+        Ok(())
+    })
+}
+
+/// Query total exposure for a symbol
+#[no_mangle]
+pub unsafe extern "C" fn noderr_risk_get_symbol_exposure(
+    calculator: *const NoderrRiskCalculator,
+    symbol: *const c_char,
+    out: *mut f64,
+) -> NoderrStatus {
+    guard(|| {
+        let calculator = required_ref(calculator, "calculator")?;
+        let symbol = required_str(symbol, "symbol")?;
+        let out = out.as_mut().ok_or((NoderrStatus::NullPointer, "out is NULL".to_string()))?;
+
+        *out = RUNTIME.block_on(calculator.inner.get_symbol_exposure(symbol));
+        Ok(())
+    })
+}
+
+/// Create a drawdown monitor; `config` may be NULL for defaults
+///
+/// `kill_switch` may be NULL. `user_data` is passed back to it unchanged and
+/// must be usable from any thread. Returns NULL on failure.
+#[no_mangle]
+pub unsafe extern "C" fn noderr_drawdown_monitor_new(
+    config: *const NoderrDrawdownConfig,
+    kill_switch: NoderrKillSwitchFn,
+    user_data: *mut c_void,
+) -> *mut NoderrDrawdownMonitor {
+    let mut drawdown_config = DrawdownConfig::default();
+    if let Some(config) = config.as_ref() {
+        drawdown_config.max_drawdown_pct = config.max_drawdown_pct;
+        drawdown_config.alert_threshold_pct = config.alert_threshold_pct;
+        drawdown_config.rolling_window_size = config.rolling_window_size as usize;
+        drawdown_config.min_trades_for_drawdown = config.min_trades_for_drawdown as usize;
+        drawdown_config.cooldown_period_ms = config.cooldown_period_ms;
+    }
+
+    let kill_switch = Arc::new(CKillSwitch { callback: kill_switch, user_data });
+
+    match catch_unwind(AssertUnwindSafe(|| DrawdownMonitor::new(drawdown_config, kill_switch))) {
+        Ok(monitor) => Box::into_raw(Box::new(NoderrDrawdownMonitor {
+            inner: Arc::new(monitor),
+        })),
+        Err(_) => {
+            set_last_error("panic creating drawdown monitor");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release a drawdown monitor; NULL is ignored
+#[no_mangle]
+pub unsafe extern "C" fn noderr_drawdown_monitor_free(monitor: *mut NoderrDrawdownMonitor) {
+    if !monitor.is_null() {
+        drop(Box::from_raw(monitor));
+    }
+}
+
+/// Record a trade for drawdown tracking
+#[no_mangle]
+pub unsafe extern "C" fn noderr_drawdown_record_trade(
+    monitor: *const NoderrDrawdownMonitor,
+    trade: *const NoderrTrade,
+) -> NoderrStatus {
+    guard(|| {
+        let monitor = required_ref(monitor, "monitor")?;
+        let trade = required_ref(trade, "trade")?;
+
+        let timestamp = if trade.timestamp_ms == 0 {
+            Utc::now()
+        } else {
+            NaiveDateTime::from_timestamp_millis(trade.timestamp_ms)
+                .map(|naive| DateTime::<Utc>::from_utc(naive, Utc))
+                .ok_or((NoderrStatus::InvalidArgument, "timestamp_ms is out of range".to_string()))?
+        };
+
+        let trade_type = NoderrTradeType::try_from(trade.trade_type)
+            .map_err(|value| (NoderrStatus::InvalidArgument, format!("Invalid trade_type: {}", value)))?;
+
+        let data_point = TradeDataPoint {
+            timestamp,
+            agent_id: required_str(trade.agent_id, "agent_id")?.to_string(),
+            symbol: required_str(trade.symbol, "symbol")?.to_string(),
+            amount: trade.amount,
+            price: trade.price,
+            trade_type: match trade_type {
+                NoderrTradeType::Buy => TradeType::Buy,
+                NoderrTradeType::Sell => TradeType::Sell,
+                NoderrTradeType::Close => TradeType::Close,
+            },
+            equity: trade.equity,
+            trade_id: required_str(trade.trade_id, "trade_id")?.to_string(),
+            pnl: trade.pnl,
+        };
+
+        RUNTIME
+            .block_on(monitor.inner.record_trade(data_point))
+            .map_err(|e| (NoderrStatus::Internal, e.to_string()))
+    })
+}
+
+/// Query drawdown state for an agent
+#[no_mangle]
+pub unsafe extern "C" fn noderr_drawdown_get_state(
+    monitor: *const NoderrDrawdownMonitor,
+    agent_id: *const c_char,
+    out: *mut NoderrDrawdownState,
+) -> NoderrStatus {
+    guard(|| {
+        let monitor = required_ref(monitor, "monitor")?;
+        let agent_id = required_str(agent_id, "agent_id")?;
+        let out = out.as_mut().ok_or((NoderrStatus::NullPointer, "out is NULL".to_string()))?;
+
+        let states = RUNTIME.block_on(monitor.inner.get_all_states());
+        let state = states
+            .get(agent_id)
+            .ok_or_else(|| (NoderrStatus::NotFound, format!("Agent not found: {}", agent_id)))?;
+
+        *out = NoderrDrawdownState {
+            is_active: state.is_active,
+            current_drawdown_pct: state.current_drawdown_pct,
+            peak_equity: state.peak_equity,
+            current_equity: state.current_equity,
+            cooldown_end_ms: state.cooldown_end_time.map(|t| t.timestamp_millis()).unwrap_or(0),
+        };
+        Ok(())
+    })
+}
+
+/// Clear drawdown history and cooldown for an agent
+#[no_mangle]
+pub unsafe extern "C" fn noderr_drawdown_reset_agent(
+    monitor: *const NoderrDrawdownMonitor,
+    agent_id: *const c_char,
+) -> NoderrStatus {
+    guard(|| {
+        let monitor = required_ref(monitor, "monitor")?;
+        let agent_id = required_str(agent_id, "agent_id")?;
+
+        RUNTIME
+            .block_on(monitor.inner.reset_agent(agent_id))
+            .map_err(|e| (NoderrStatus::NotFound, e.to_string()))
+    })
+}
+
+/// Create a backtest environment over `bar_count` bars using the default
+/// observation features; `config` may be NULL for defaults
+///
+/// Returns NULL on failure.
+#[no_mangle]
+pub unsafe extern "C" fn noderr_backtest_env_new(
+    bars: *const NoderrBar,
+    bar_count: usize,
+    config: *const NoderrEnvConfig,
+) -> *mut NoderrBacktestEnv {
+    let mut handle = ptr::null_mut();
+    let status = guard(|| {
+        if bars.is_null() {
+            return Err((NoderrStatus::NullPointer, "bars is NULL".to_string()));
+        }
+        let bars = std::slice::from_raw_parts(bars, bar_count)
+            .iter()
+            .map(|bar| {
+                let timestamp = NaiveDateTime::from_timestamp_millis(bar.timestamp_ms)
+                    .map(|naive| DateTime::<Utc>::from_utc(naive, Utc))
+                    .ok_or((NoderrStatus::InvalidArgument, "timestamp_ms is out of range".to_string()))?;
+                Ok(BacktestBar {
+                    timestamp,
+                    open: bar.open,
+                    high: bar.high,
+                    low: bar.low,
+                    close: bar.close,
+                    volume: bar.volume,
+                })
+            })
+            .collect::<Result<Vec<_>, (NoderrStatus, String)>>()?;
+
+        let mut env_config = BacktestEnvConfig::default();
+        if let Some(config) = config.as_ref() {
+            env_config.backtest.initial_capital = config.initial_capital;
+            env_config.backtest.fee_bps = config.fee_bps;
+            env_config.backtest.slippage_bps = config.slippage_bps;
+            env_config.backtest.allow_short = config.allow_short;
+            env_config.max_exposure = config.max_exposure;
+            env_config.risk_aversion = config.risk_aversion;
+            env_config.drawdown_penalty = config.drawdown_penalty;
+            env_config.warmup_bars = config.warmup_bars as usize;
+            env_config.max_episode_steps = config.max_episode_steps as usize;
+        }
+
+        let env = BacktestEnv::with_default_features(env_config, bars).map_err(backtest_status)?;
+        handle = Box::into_raw(Box::new(NoderrBacktestEnv { inner: env }));
+        Ok(())
+    });
+    if status == NoderrStatus::Ok { handle } else { ptr::null_mut() }
+}
+
+/// Release a backtest environment; NULL is ignored
+#[no_mangle]
+pub unsafe extern "C" fn noderr_backtest_env_free(env: *mut NoderrBacktestEnv) {
+    if !env.is_null() {
+        drop(Box::from_raw(env));
+    }
+}
+
+/// Number of values in each observation; 0 if `env` is NULL
+#[no_mangle]
+pub unsafe extern "C" fn noderr_backtest_env_observation_size(env: *const NoderrBacktestEnv) -> usize {
+    env.as_ref().map(|env| env.inner.observation_size()).unwrap_or(0)
+}
+
+/// Start a new episode at bar `start` (negative for the default) and write
+/// the first observation into `observation`
+#[no_mangle]
+pub unsafe extern "C" fn noderr_backtest_env_reset(
+    env: *mut NoderrBacktestEnv,
+    start: i64,
+    observation: *mut f64,
+    observation_len: usize,
+) -> NoderrStatus {
+    guard(|| {
+        let env = env.as_mut().ok_or((NoderrStatus::NullPointer, "env is NULL".to_string()))?;
+        check_observation_buffer(observation, observation_len, env.inner.observation_size())?;
+
+        let first = env.inner.reset(usize::try_from(start).ok()).map_err(backtest_status)?;
+        ptr::copy_nonoverlapping(first.as_ptr(), observation, first.len());
+        Ok(())
+    })
+}
+
+/// Move to target exposure `action` (-1.0 to 1.0), advance one bar and write
+/// the next observation into `observation`
+#[no_mangle]
+pub unsafe extern "C" fn noderr_backtest_env_step(
+    env: *mut NoderrBacktestEnv,
+    action: f64,
+    observation: *mut f64,
+    observation_len: usize,
+    out: *mut NoderrEnvStepResult,
+) -> NoderrStatus {
+    guard(|| {
+        let env = env.as_mut().ok_or((NoderrStatus::NullPointer, "env is NULL".to_string()))?;
+        let out = out.as_mut().ok_or((NoderrStatus::NullPointer, "out is NULL".to_string()))?;
+        // Validate before stepping so a bad buffer does not advance the episode
+        check_observation_buffer(observation, observation_len, env.inner.observation_size())?;
+
+        let step = env.inner.step(action).map_err(backtest_status)?;
+        ptr::copy_nonoverlapping(step.observation.as_ptr(), observation, step.observation.len());
+        *out = NoderrEnvStepResult {
+            reward: step.reward,
+            done: step.done,
+            equity: step.info.equity,
+            position: step.info.position,
+            drawdown: step.info.drawdown,
+        };
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk_check_round_trip() {
+        unsafe {
+            let calculator = noderr_risk_calculator_new(ptr::null(), 100_000.0);
+            assert!(!calculator.is_null());
+
+            let venue = CString::new("binance").unwrap();
+            let symbol = CString::new("BTC-USD").unwrap();
+            assert_eq!(noderr_risk_set_trust_score(calculator, venue.as_ptr(), 0.9), NoderrStatus::Ok);
+
+            let order = NoderrOrder {
+                symbol: symbol.as_ptr(),
+                venue: venue.as_ptr(),
+                strategy_id: ptr::null(),
+                size: 1.0,
+                value: 50_000.0,
+                leverage: 1.0,
+                is_long: true,
+            };
+
+            let mut result = NoderrRiskCheckResult::default();
+            assert_eq!(noderr_risk_check_order(calculator, &order, &mut result), NoderrStatus::Ok);
+            assert!(!result.passed);
+            assert_ne!(result.violation_mask & NODERR_VIOLATION_POSITION_SIZE, 0);
+
+            assert_eq!(noderr_risk_check_order(calculator, &order, ptr::null_mut()), NoderrStatus::NullPointer);
+            assert!(!noderr_last_error().is_null());
+
+            noderr_risk_calculator_free(calculator);
+        }
+    }
+
+    #[test]
+    fn test_drawdown_unknown_agent() {
+        unsafe {
+            let monitor = noderr_drawdown_monitor_new(ptr::null(), None, ptr::null_mut());
+            assert!(!monitor.is_null());
+
+            let agent = CString::new("agent-1").unwrap();
+            let mut state = NoderrDrawdownState::default();
+            assert_eq!(noderr_drawdown_get_state(monitor, agent.as_ptr(), &mut state), NoderrStatus::NotFound);
+
+            noderr_drawdown_monitor_free(monitor);
+        }
+    }
+
+    #[test]
+    fn test_drawdown_rejects_invalid_trade_type() {
+        unsafe {
+            let monitor = noderr_drawdown_monitor_new(ptr::null(), None, ptr::null_mut());
+            let agent = CString::new("agent-1").unwrap();
+            let symbol = CString::new("BTC-USD").unwrap();
+            let trade_id = CString::new("trade-1").unwrap();
+            let mut trade = NoderrTrade {
+                agent_id: agent.as_ptr(),
+                symbol: symbol.as_ptr(),
+                trade_id: trade_id.as_ptr(),
+                amount: 1.0,
+                price: 50_000.0,
+                trade_type: 7,
+                equity: 100_000.0,
+                pnl: 0.0,
+                timestamp_ms: 0,
+            };
+            assert_eq!(noderr_drawdown_record_trade(monitor, &trade), NoderrStatus::InvalidArgument);
+
+            trade.trade_type = NoderrTradeType::Buy as u32;
+            assert_eq!(noderr_drawdown_record_trade(monitor, &trade), NoderrStatus::Ok);
+
+            noderr_drawdown_monitor_free(monitor);
+        }
+    }
+}
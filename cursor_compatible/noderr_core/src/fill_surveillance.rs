@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Post-trade fill surveillance
+//!
+//! Every fill reported by a venue is checked against the order that should
+//! have produced it and against the consolidated reference book at the time
+//! it arrives. Fills at prices the market never offered, fills reported
+//! twice, fills for orders that were never sent and fills beyond the order
+//! size are treated as critical: the anomaly is alerted and the venue is
+//! disabled for new orders until an operator re-enables it.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{error, warn};
+
+use crate::order_router::Order;
+use crate::orderbook::OrderBookManager;
+use crate::telemetry::TelemetryReporter;
+use crate::trading_events::{TradingEvent, TradingEventBus};
+use crate::venue_control::{VenueControl, VenueMode};
+
+/// Operator name recorded when surveillance disables a venue
+const SURVEILLANCE_OPERATOR: &str = "fill_surveillance";
+
+/// Fill surveillance configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FillSurveillanceConfig {
+    /// How far outside the reference bid/ask a fill may print (basis points)
+    pub price_tolerance_bps: f64,
+    /// Relative overfill tolerated before flagging (covers rounding)
+    pub overfill_tolerance: f64,
+    /// Disable the venue on the first anomaly
+    pub quarantine_venue: bool,
+    /// How long sent orders are remembered for matching (seconds)
+    pub order_retention_secs: i64,
+    /// Maximum anomalies kept for inspection
+    pub max_anomalies: usize,
+}
+
+impl Default for FillSurveillanceConfig {
+    fn default() -> Self {
+        Self {
+            price_tolerance_bps: 50.0,
+            overfill_tolerance: 0.001,
+            quarantine_venue: true,
+            order_retention_secs: 86_400,
+            max_anomalies: 1_000,
+        }
+    }
+}
+
+/// A fill as reported by a venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObservedFill {
+    /// Venue trade ID, when the venue provides one
+    pub fill_id: Option<String>,
+    /// Order the venue attributes the fill to
+    pub order_id: String,
+    /// Venue reporting the fill
+    pub venue: String,
+    /// Symbol filled
+    pub symbol: String,
+    /// Quantity filled
+    pub quantity: f64,
+    /// Fill price
+    pub price: f64,
+    /// Venue fill time
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ObservedFill {
+    /// Identity used to detect the same fill being reported twice
+    fn dedup_key(&self) -> String {
+        match &self.fill_id {
+            Some(fill_id) => format!("{}:{}", self.venue, fill_id),
+            None => format!(
+                "{}:{}:{}:{}:{}",
+                self.venue,
+                self.order_id,
+                self.quantity,
+                self.price,
+                self.timestamp.timestamp_millis()
+            ),
+        }
+    }
+}
+
+/// Kind of fill anomaly
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FillAnomalyKind {
+    /// Fill printed outside the tolerated reference bid/ask band
+    PriceOutOfBounds { price: f64, best_bid: f64, best_ask: f64 },
+    /// The same fill was reported more than once
+    DuplicateFill,
+    /// No order with this ID was sent
+    PhantomFill,
+    /// Fill is for a different symbol than the order
+    SymbolMismatch { expected: String },
+    /// Fills exceed the order quantity
+    Overfill { filled: f64, ordered: f64 },
+}
+
+/// A flagged fill
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FillAnomaly {
+    /// The offending fill
+    pub fill: ObservedFill,
+    /// What was wrong with it
+    pub kind: FillAnomalyKind,
+    /// When it was flagged
+    pub detected_at: DateTime<Utc>,
+}
+
+/// An order sent to a venue, tracked until its fills are matched
+#[derive(Debug, Clone)]
+struct SentOrder {
+    /// Order symbol
+    symbol: String,
+    /// Order quantity
+    amount: f64,
+    /// Quantity filled so far
+    filled: f64,
+    /// When the order was registered
+    sent_at: DateTime<Utc>,
+}
+
+/// Mutable surveillance state
+#[derive(Debug, Default)]
+struct SurveillanceState {
+    /// Sent orders by ID
+    orders: HashMap<String, SentOrder>,
+    /// Fill identities already seen
+    seen_fills: HashSet<String>,
+    /// Seen fill identities, oldest first, with the time they were seen
+    seen_order: VecDeque<(DateTime<Utc>, String)>,
+    /// Flagged fills, oldest first
+    anomalies: VecDeque<FillAnomaly>,
+    /// Venues quarantined by surveillance
+    quarantined: HashSet<String>,
+}
+
+/// Checks every fill against its order and the consolidated book
+pub struct FillSurveillance {
+    /// Configuration
+    config: FillSurveillanceConfig,
+    /// Consolidated reference books
+    reference_books: Arc<OrderBookManager>,
+    /// Venue modes used to quarantine connectors (optional)
+    venue_control: Option<Arc<VenueControl>>,
+    /// Telemetry for critical alerts (optional)
+    telemetry: Option<Arc<TelemetryReporter>>,
+    /// Surveillance state
+    state: RwLock<SurveillanceState>,
+    /// Event bus subscription task
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl FillSurveillance {
+    /// Create surveillance checking fills against the given reference books
+    pub fn new(config: FillSurveillanceConfig, reference_books: Arc<OrderBookManager>) -> Self {
+        Self {
+            config,
+            reference_books,
+            venue_control: None,
+            telemetry: None,
+            state: RwLock::new(SurveillanceState::default()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Disable venues that report anomalous fills
+    pub fn with_venue_control(mut self, venue_control: Arc<VenueControl>) -> Self {
+        self.venue_control = Some(venue_control);
+        self
+    }
+
+    /// Report anomalies to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Remember an order so its fills can be matched
+    pub async fn register_order(&self, order: &Order) {
+        let now = Utc::now();
+        let retention = Duration::seconds(self.config.order_retention_secs);
+        let mut state = self.state.write().await;
+        state.orders.retain(|_, sent| now - sent.sent_at < retention);
+        state.orders.insert(
+            order.id.clone(),
+            SentOrder {
+                symbol: order.symbol.clone(),
+                amount: order.amount,
+                filled: 0.0,
+                sent_at: now,
+            },
+        );
+    }
+
+    /// Check a fill, returning every anomaly found
+    pub async fn check_fill(&self, fill: ObservedFill) -> Vec<FillAnomaly> {
+        let now = Utc::now();
+        let mut kinds = Vec::new();
+        {
+            let mut state = self.state.write().await;
+
+            // Duplicates are not matched again so they cannot double-count fills
+            let key = fill.dedup_key();
+            if state.seen_fills.contains(&key) {
+                kinds.push(FillAnomalyKind::DuplicateFill);
+            } else {
+                let retention = Duration::seconds(self.config.order_retention_secs);
+                while state.seen_order.front().is_some_and(|(seen_at, _)| now - *seen_at >= retention) {
+                    if let Some((_, old)) = state.seen_order.pop_front() {
+                        state.seen_fills.remove(&old);
+                    }
+                }
+                state.seen_fills.insert(key.clone());
+                state.seen_order.push_back((now, key));
+
+                match state.orders.get_mut(&fill.order_id) {
+                    None => kinds.push(FillAnomalyKind::PhantomFill),
+                    Some(order) => {
+                        if order.symbol != fill.symbol {
+                            kinds.push(FillAnomalyKind::SymbolMismatch { expected: order.symbol.clone() });
+                        }
+                        order.filled += fill.quantity;
+                        if order.filled > order.amount * (1.0 + self.config.overfill_tolerance) {
+                            kinds.push(FillAnomalyKind::Overfill { filled: order.filled, ordered: order.amount });
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(kind) = self.check_price(&fill) {
+            kinds.push(kind);
+        }
+        if kinds.is_empty() {
+            return Vec::new();
+        }
+
+        let anomalies: Vec<FillAnomaly> = kinds
+            .into_iter()
+            .map(|kind| FillAnomaly { fill: fill.clone(), kind, detected_at: now })
+            .collect();
+        self.raise(&anomalies).await;
+        anomalies
+    }
+
+    /// Compare the fill price with the reference book's tolerated band
+    fn check_price(&self, fill: &ObservedFill) -> Option<FillAnomalyKind> {
+        let (bids, asks) = self.reference_books.get_snapshot(&fill.symbol, 1)?;
+        let (best_bid, best_ask) = (bids.first()?.price, asks.first()?.price);
+        let tolerance = self.config.price_tolerance_bps / 10_000.0;
+
+        let low = best_bid * (1.0 - tolerance);
+        let high = best_ask * (1.0 + tolerance);
+        (fill.price < low || fill.price > high || !fill.price.is_finite())
+            .then_some(FillAnomalyKind::PriceOutOfBounds { price: fill.price, best_bid, best_ask })
+    }
+
+    /// Alert on anomalies and quarantine the venue
+    async fn raise(&self, anomalies: &[FillAnomaly]) {
+        let Some(first) = anomalies.first() else {
+            return;
+        };
+        let venue = first.fill.venue.clone();
+
+        for anomaly in anomalies {
+            error!(
+                "Fill anomaly on {} for order {}: {:?}",
+                anomaly.fill.venue, anomaly.fill.order_id, anomaly.kind
+            );
+            if let Some(telemetry) = &self.telemetry {
+                let mut data = HashMap::new();
+                data.insert("severity".to_string(), serde_json::json!("critical"));
+                data.insert("venue".to_string(), serde_json::json!(anomaly.fill.venue));
+                data.insert("order_id".to_string(), serde_json::json!(anomaly.fill.order_id));
+                data.insert("symbol".to_string(), serde_json::json!(anomaly.fill.symbol));
+                data.insert("anomaly".to_string(), serde_json::json!(anomaly.kind));
+                telemetry.report_custom("execution_anomaly", data).await;
+            }
+        }
+
+        let newly_quarantined = {
+            let mut state = self.state.write().await;
+            state.anomalies.extend(anomalies.iter().cloned());
+            while state.anomalies.len() > self.config.max_anomalies {
+                state.anomalies.pop_front();
+            }
+            self.config.quarantine_venue && state.quarantined.insert(venue.clone())
+        };
+
+        if newly_quarantined {
+            match &self.venue_control {
+                Some(venue_control) => {
+                    let reason = format!("Fill anomaly: {:?}", first.kind);
+                    venue_control.set_mode(&venue, VenueMode::Disabled, SURVEILLANCE_OPERATOR, Some(reason)).await;
+                }
+                None => warn!("No venue control attached; venue {} flagged but not disabled", venue),
+            }
+        }
+    }
+
+    /// Flagged fills, oldest first
+    pub async fn anomalies(&self) -> Vec<FillAnomaly> {
+        self.state.read().await.anomalies.iter().cloned().collect()
+    }
+
+    /// Venues quarantined by surveillance
+    pub async fn quarantined_venues(&self) -> Vec<String> {
+        let mut venues: Vec<String> = self.state.read().await.quarantined.iter().cloned().collect();
+        venues.sort();
+        venues
+    }
+
+    /// Clear a venue's quarantine after investigation (the venue mode is left to the operator)
+    pub async fn release_venue(&self, venue: &str) -> bool {
+        self.state.write().await.quarantined.remove(venue)
+    }
+
+    /// Check fills published on a trading event bus
+    pub async fn start(self: &Arc<Self>, event_bus: Arc<TradingEventBus>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let surveillance = Arc::clone(self);
+        let mut receiver = event_bus.subscribe();
+        *handle_guard = Some(tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(TradingEvent::Fill { order_id, symbol, venue, quantity, price, timestamp }) => {
+                        let fill = ObservedFill { fill_id: None, order_id, venue, symbol, quantity, price, timestamp };
+                        surveillance.check_fill(fill).await;
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        error!("Fill surveillance lagged; {} events were not checked", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }));
+    }
+
+    /// Stop checking bus fills
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create fill surveillance over the given reference books
+pub fn create_fill_surveillance(
+    config: FillSurveillanceConfig,
+    reference_books: Arc<OrderBookManager>,
+) -> Arc<FillSurveillance> {
+    Arc::new(FillSurveillance::new(config, reference_books))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_router::OrderSide;
+    use crate::orderbook::OrderSide as BookSide;
+
+    fn order(id: &str, amount: f64) -> Order {
+        Order {
+            symbol: "BTC/USDT".to_string(),
+            side: OrderSide::Buy,
+            amount,
+            price: 100.0,
+            venues: vec!["binance".to_string()],
+            id: id.to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: Default::default(),
+            additional_params: HashMap::new(),
+        }
+    }
+
+    fn fill(fill_id: &str, order_id: &str, venue: &str, quantity: f64, price: f64) -> ObservedFill {
+        ObservedFill {
+            fill_id: Some(fill_id.to_string()),
+            order_id: order_id.to_string(),
+            venue: venue.to_string(),
+            symbol: "BTC/USDT".to_string(),
+            quantity,
+            price,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flags_and_quarantines_anomalous_fills() {
+        let books = Arc::new(OrderBookManager::new());
+        books.process_update("BTC/USDT", 99.9, 5.0, BookSide::Bid, 1);
+        books.process_update("BTC/USDT", 100.1, 5.0, BookSide::Ask, 2);
+        let venue_control = Arc::new(VenueControl::new());
+        let surveillance = FillSurveillance::new(FillSurveillanceConfig::default(), books)
+            .with_venue_control(venue_control.clone());
+
+        surveillance.register_order(&order("o1", 1.0)).await;
+        assert!(surveillance.check_fill(fill("f1", "o1", "binance", 0.5, 100.0)).await.is_empty());
+
+        let duplicate = surveillance.check_fill(fill("f1", "o1", "binance", 0.5, 100.0)).await;
+        assert_eq!(duplicate[0].kind, FillAnomalyKind::DuplicateFill);
+        assert_eq!(venue_control.mode("binance"), VenueMode::Disabled);
+
+        let phantom = surveillance.check_fill(fill("f2", "nope", "kraken", 1.0, 100.0)).await;
+        assert_eq!(phantom[0].kind, FillAnomalyKind::PhantomFill);
+
+        // 0.5 + 0.6 exceeds the order, at a price far above the ask
+        let bad = surveillance.check_fill(fill("f3", "o1", "okx", 0.6, 120.0)).await;
+        assert!(bad.iter().any(|a| matches!(a.kind, FillAnomalyKind::Overfill { .. })));
+        assert!(bad.iter().any(|a| matches!(a.kind, FillAnomalyKind::PriceOutOfBounds { .. })));
+        assert_eq!(surveillance.quarantined_venues().await, vec!["binance", "kraken", "okx"]);
+    }
+}
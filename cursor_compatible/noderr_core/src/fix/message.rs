@@ -0,0 +1,320 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! FIX tag=value message encoding and framing
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+
+use super::{FixError, FixResult};
+
+/// Field delimiter
+pub const SOH: u8 = 0x01;
+
+/// Tags used by the session and order entry messages
+pub mod tags {
+    pub const ACCOUNT: u32 = 1;
+    pub const AVG_PX: u32 = 6;
+    pub const BEGIN_SEQ_NO: u32 = 7;
+    pub const BEGIN_STRING: u32 = 8;
+    pub const BODY_LENGTH: u32 = 9;
+    pub const CHECKSUM: u32 = 10;
+    pub const CL_ORD_ID: u32 = 11;
+    pub const CUM_QTY: u32 = 14;
+    pub const END_SEQ_NO: u32 = 16;
+    pub const EXEC_ID: u32 = 17;
+    pub const EXEC_INST: u32 = 18;
+    pub const LAST_PX: u32 = 31;
+    pub const LAST_QTY: u32 = 32;
+    pub const MSG_SEQ_NUM: u32 = 34;
+    pub const MSG_TYPE: u32 = 35;
+    pub const NEW_SEQ_NO: u32 = 36;
+    pub const ORDER_ID: u32 = 37;
+    pub const ORDER_QTY: u32 = 38;
+    pub const ORD_STATUS: u32 = 39;
+    pub const ORD_TYPE: u32 = 40;
+    pub const ORIG_CL_ORD_ID: u32 = 41;
+    pub const POSS_DUP_FLAG: u32 = 43;
+    pub const PRICE: u32 = 44;
+    pub const REF_SEQ_NUM: u32 = 45;
+    pub const SENDER_COMP_ID: u32 = 49;
+    pub const SENDING_TIME: u32 = 52;
+    pub const SIDE: u32 = 54;
+    pub const SYMBOL: u32 = 55;
+    pub const TARGET_COMP_ID: u32 = 56;
+    pub const TEXT: u32 = 58;
+    pub const TIME_IN_FORCE: u32 = 59;
+    pub const TRANSACT_TIME: u32 = 60;
+    pub const ENCRYPT_METHOD: u32 = 98;
+    pub const ORD_REJ_REASON: u32 = 103;
+    pub const HEART_BT_INT: u32 = 108;
+    pub const TEST_REQ_ID: u32 = 112;
+    pub const ORIG_SENDING_TIME: u32 = 122;
+    pub const GAP_FILL_FLAG: u32 = 123;
+    pub const EXPIRE_TIME: u32 = 126;
+    pub const RESET_SEQ_NUM_FLAG: u32 = 141;
+    pub const EXEC_TYPE: u32 = 150;
+    pub const LEAVES_QTY: u32 = 151;
+    pub const USERNAME: u32 = 553;
+    pub const PASSWORD: u32 = 554;
+}
+
+/// Message types (tag 35)
+pub mod msg_type {
+    pub const HEARTBEAT: &str = "0";
+    pub const TEST_REQUEST: &str = "1";
+    pub const RESEND_REQUEST: &str = "2";
+    pub const REJECT: &str = "3";
+    pub const SEQUENCE_RESET: &str = "4";
+    pub const LOGOUT: &str = "5";
+    pub const EXECUTION_REPORT: &str = "8";
+    pub const ORDER_CANCEL_REJECT: &str = "9";
+    pub const LOGON: &str = "A";
+    pub const NEW_ORDER_SINGLE: &str = "D";
+    pub const ORDER_CANCEL_REQUEST: &str = "F";
+    pub const ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+
+    /// Session-level messages; everything else is application-level
+    pub fn is_admin(msg_type: &str) -> bool {
+        matches!(msg_type, HEARTBEAT | TEST_REQUEST | RESEND_REQUEST | REJECT | SEQUENCE_RESET | LOGOUT | LOGON)
+    }
+}
+
+/// Timestamp format for `SendingTime`, `TransactTime` and other UTCTimestamp fields
+pub fn utc_timestamp(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%d-%H:%M:%S%.3f").to_string()
+}
+
+/// A FIX message without its `BeginString`, `BodyLength` and `CheckSum`
+/// fields, which are written on encode and verified on decode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixMessage {
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Start a message of the given type
+    pub fn new(msg_type: &str) -> Self {
+        Self { fields: vec![(tags::MSG_TYPE, msg_type.to_string())] }
+    }
+
+    /// Append a field
+    pub fn with(mut self, tag: u32, value: impl ToString) -> Self {
+        self.push(tag, value);
+        self
+    }
+
+    /// Append a field
+    pub fn push(&mut self, tag: u32, value: impl ToString) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Set a field, replacing any existing value
+    pub fn set(&mut self, tag: u32, value: impl ToString) {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value.to_string(),
+            None => self.push(tag, value),
+        }
+    }
+
+    /// Write the standard header after MsgType, replacing any previous one.
+    /// `PossDupFlag` and `OrigSendingTime` already set are moved into the header.
+    pub fn set_header(&mut self, sender_comp_id: &str, target_comp_id: &str, seq_num: u64, sending_time: DateTime<Utc>) {
+        let resend_fields: Vec<(u32, String)> = self.fields
+            .iter()
+            .filter(|(tag, _)| matches!(*tag, tags::POSS_DUP_FLAG | tags::ORIG_SENDING_TIME))
+            .cloned()
+            .collect();
+        self.fields.retain(|(tag, _)| {
+            !matches!(
+                *tag,
+                tags::SENDER_COMP_ID | tags::TARGET_COMP_ID | tags::MSG_SEQ_NUM | tags::SENDING_TIME | tags::POSS_DUP_FLAG | tags::ORIG_SENDING_TIME
+            )
+        });
+        let mut header = vec![
+            (tags::SENDER_COMP_ID, sender_comp_id.to_string()),
+            (tags::TARGET_COMP_ID, target_comp_id.to_string()),
+            (tags::MSG_SEQ_NUM, seq_num.to_string()),
+            (tags::SENDING_TIME, utc_timestamp(sending_time)),
+        ];
+        header.extend(resend_fields);
+        self.fields.splice(1..1, header);
+    }
+
+    /// Message type (tag 35)
+    pub fn msg_type(&self) -> &str {
+        self.get(tags::MSG_TYPE).unwrap_or_default()
+    }
+
+    /// First value of a field
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields.iter().find(|(t, _)| *t == tag).map(|(_, v)| v.as_str())
+    }
+
+    /// A field that must be present
+    pub fn require(&self, tag: u32) -> FixResult<&str> {
+        self.get(tag).ok_or_else(|| FixError::Malformed(format!("{} message missing tag {}", self.msg_type(), tag)))
+    }
+
+    /// Numeric field; absent or unparseable values read as `None`
+    pub fn get_f64(&self, tag: u32) -> Option<f64> {
+        self.get(tag).and_then(|v| v.parse().ok())
+    }
+
+    /// Sequence number field
+    pub fn get_seq(&self, tag: u32) -> Option<u64> {
+        self.get(tag).and_then(|v| v.parse().ok())
+    }
+
+    /// `Y`/`N` flag; absent reads as `false`
+    pub fn get_flag(&self, tag: u32) -> bool {
+        self.get(tag) == Some("Y")
+    }
+
+    /// Message sequence number (tag 34)
+    pub fn seq_num(&self) -> FixResult<u64> {
+        self.get_seq(tags::MSG_SEQ_NUM).ok_or_else(|| FixError::Malformed("missing or invalid MsgSeqNum".to_string()))
+    }
+
+    /// Encode with header and trailer
+    pub fn encode(&self, begin_string: &str) -> Vec<u8> {
+        let mut body = Vec::with_capacity(self.fields.len() * 8);
+        for (tag, value) in &self.fields {
+            write_field(&mut body, *tag, value);
+        }
+
+        let mut out = Vec::with_capacity(body.len() + 32);
+        write_field(&mut out, tags::BEGIN_STRING, begin_string);
+        write_field(&mut out, tags::BODY_LENGTH, &body.len().to_string());
+        out.extend_from_slice(&body);
+        let checksum = checksum(&out);
+        write_field(&mut out, tags::CHECKSUM, &format!("{:03}", checksum));
+        out
+    }
+
+    /// Take one complete message off the front of `buf`, or `None` if more
+    /// bytes are needed. Framing and checksum errors leave `buf` untouched.
+    pub fn decode_frame(buf: &mut Vec<u8>) -> FixResult<Option<FixMessage>> {
+        let Some(begin_end) = buf.iter().position(|&b| b == SOH) else {
+            return Ok(None);
+        };
+        if !buf.starts_with(b"8=") {
+            return Err(FixError::Malformed("message does not start with BeginString".to_string()));
+        }
+        let Some(length_end) = buf[begin_end + 1..].iter().position(|&b| b == SOH).map(|i| begin_end + 1 + i) else {
+            return Ok(None);
+        };
+        let length_field = std::str::from_utf8(&buf[begin_end + 1..length_end])
+            .ok()
+            .and_then(|f| f.strip_prefix("9="))
+            .and_then(|n| n.parse::<usize>().ok())
+            .ok_or_else(|| FixError::Malformed("second field is not BodyLength".to_string()))?;
+
+        // Trailer is "10=nnn<SOH>"
+        let body_end = length_end + 1 + length_field;
+        let frame_end = body_end + 7;
+        if buf.len() < frame_end {
+            return Ok(None);
+        }
+        let trailer = &buf[body_end..frame_end];
+        if !trailer.starts_with(b"10=") || trailer[6] != SOH {
+            return Err(FixError::Malformed("BodyLength does not end at CheckSum".to_string()));
+        }
+        let declared: u8 = std::str::from_utf8(&trailer[3..6])
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| FixError::Malformed("invalid CheckSum".to_string()))?;
+        let actual = checksum(&buf[..body_end]);
+        if declared != actual {
+            return Err(FixError::Malformed(format!("CheckSum {} does not match computed {}", declared, actual)));
+        }
+
+        let body = std::str::from_utf8(&buf[length_end + 1..body_end])
+            .map_err(|_| FixError::Malformed("body is not valid UTF-8".to_string()))?;
+        let mut fields = Vec::new();
+        for field in body.split(SOH as char).filter(|f| !f.is_empty()) {
+            let (tag, value) = field.split_once('=')
+                .and_then(|(tag, value)| tag.parse::<u32>().ok().map(|tag| (tag, value)))
+                .ok_or_else(|| FixError::Malformed(format!("invalid field '{}'", field)))?;
+            fields.push((tag, value.to_string()));
+        }
+        if fields.first().map(|(tag, _)| *tag) != Some(tags::MSG_TYPE) {
+            return Err(FixError::Malformed("first body field is not MsgType".to_string()));
+        }
+
+        buf.drain(..frame_end);
+        Ok(Some(FixMessage { fields }))
+    }
+}
+
+impl fmt::Display for FixMessage {
+    /// Pipe-delimited form for logs
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (tag, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str("|")?;
+            }
+            // Never log credentials sent on logon
+            if *tag == tags::PASSWORD {
+                write!(f, "{}=***", tag)?;
+            } else {
+                write!(f, "{}={}", tag, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_field(out: &mut Vec<u8>, tag: u32, value: &str) {
+    out.extend_from_slice(tag.to_string().as_bytes());
+    out.push(b'=');
+    out.extend_from_slice(value.as_bytes());
+    out.push(SOH);
+}
+
+/// Sum of all bytes modulo 256
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let message = FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with(tags::MSG_SEQ_NUM, 7)
+            .with(tags::CL_ORD_ID, "abc-1")
+            .with(tags::PRICE, "101.5");
+        let encoded = message.encode("FIX.4.4");
+        assert!(encoded.starts_with(b"8=FIX.4.4\x019="));
+
+        // Partial frames wait for more bytes
+        let mut buf = encoded[..encoded.len() - 3].to_vec();
+        assert!(FixMessage::decode_frame(&mut buf).unwrap().is_none());
+
+        let mut buf = [encoded.clone(), encoded].concat();
+        assert_eq!(FixMessage::decode_frame(&mut buf).unwrap(), Some(message.clone()));
+        assert_eq!(FixMessage::decode_frame(&mut buf).unwrap(), Some(message));
+        assert!(buf.is_empty());
+
+        let mut corrupted = FixMessage::new(msg_type::HEARTBEAT).encode("FIX.4.4");
+        let last = corrupted.len() - 2;
+        corrupted[last] = if corrupted[last] == b'0' { b'1' } else { b'0' };
+        assert!(FixMessage::decode_frame(&mut corrupted).is_err());
+    }
+}
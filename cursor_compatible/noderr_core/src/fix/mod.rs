@@ -0,0 +1,1082 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! FIX 4.4 gateway for venues that only offer FIX order entry
+//!
+//! A [`FixGateway`] runs one initiator session: it logs on, keeps the session
+//! alive with heartbeats and test requests, recovers inbound sequence gaps
+//! with resend requests and answers the counterparty's resend requests from
+//! the application messages it sent. Orders map onto NewOrderSingle,
+//! OrderCancelRequest and OrderCancelReplaceRequest; each request completes
+//! with the first execution report that is not a pending acknowledgement,
+//! and later reports (fills on resting orders) are broadcast to subscribers.
+//! Sessions run over plain TCP; venues that require TLS are reached through
+//! a local tunnel.
+
+pub mod message;
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::tcp::OwnedWriteHalf;
+use tokio::net::TcpStream;
+use tokio::sync::{broadcast, oneshot, watch};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, trace, warn};
+
+use crate::connectors::{format_decimal, VenueOrderStatus};
+use crate::ids::{new_id, IdKind};
+use crate::order_lineage;
+use crate::order_router::{ExecutionFailureReason, Order, OrderSide, TimeInForce, VenueExecutionResult};
+use crate::quote_freshness::QuoteFreshnessGuard;
+
+pub use message::FixMessage;
+use message::{msg_type, tags, utc_timestamp};
+
+/// Errors raised by FIX sessions
+#[derive(Debug, Error)]
+pub enum FixError {
+    #[error("FIX connection error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Malformed FIX message: {0}")]
+    Malformed(String),
+
+    #[error("Logon to {venue} rejected: {reason}")]
+    LogonRejected { venue: String, reason: String },
+
+    #[error("FIX session to {0} is not logged on")]
+    NotLoggedOn(String),
+
+    #[error("Message {ref_seq_num} rejected by the session: {reason}")]
+    SessionRejected { ref_seq_num: u64, reason: String },
+
+    #[error("Cancel of order {cl_ord_id} rejected: {reason}")]
+    CancelRejected { cl_ord_id: String, reason: String },
+
+    #[error("Unknown order: {0}")]
+    UnknownOrder(String),
+
+    #[error("MsgSeqNum {received} is below the expected {expected}")]
+    SequenceTooLow { expected: u64, received: u64 },
+
+    #[error("No response to {0} before the timeout")]
+    Timeout(String),
+
+    #[error("Unsupported order: {0}")]
+    Unsupported(String),
+}
+
+/// Result type for FIX operations
+pub type FixResult<T> = Result<T, FixError>;
+
+/// FIX session configuration
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FixSessionConfig {
+    /// Venue identifier used by the router
+    pub venue_id: String,
+    /// Acceptor host
+    pub host: String,
+    /// Acceptor port
+    pub port: u16,
+    /// BeginString (tag 8)
+    pub begin_string: String,
+    /// SenderCompID (tag 49)
+    pub sender_comp_id: String,
+    /// TargetCompID (tag 56)
+    pub target_comp_id: String,
+    /// Username sent on logon, if the venue requires one
+    pub username: Option<String>,
+    /// Password sent on logon, if the venue requires one
+    pub password: Option<String>,
+    /// Account (tag 1) set on orders
+    pub account: Option<String>,
+    /// Heartbeat interval negotiated on logon
+    pub heartbeat_interval_secs: u64,
+    /// Reset both sequence numbers on every logon
+    pub reset_on_logon: bool,
+    /// Time to wait for a logon reply or an order's first execution report
+    pub response_timeout_ms: u64,
+    /// Delay before reconnecting after the session drops
+    pub reconnect_delay_ms: u64,
+    /// Sent application messages kept to answer resend requests
+    pub resend_buffer_size: usize,
+    /// Venue symbols keyed by internal symbol; unmapped symbols are sent as is
+    pub symbols: HashMap<String, String>,
+}
+
+impl Default for FixSessionConfig {
+    fn default() -> Self {
+        Self {
+            venue_id: "fix".to_string(),
+            host: "127.0.0.1".to_string(),
+            port: 9878,
+            begin_string: "FIX.4.4".to_string(),
+            sender_comp_id: String::new(),
+            target_comp_id: String::new(),
+            username: None,
+            password: None,
+            account: None,
+            heartbeat_interval_secs: 30,
+            reset_on_logon: true,
+            response_timeout_ms: 5000,
+            reconnect_delay_ms: 5000,
+            resend_buffer_size: 10_000,
+            symbols: HashMap::new(),
+        }
+    }
+}
+
+impl std::fmt::Debug for FixSessionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FixSessionConfig")
+            .field("venue_id", &self.venue_id)
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("begin_string", &self.begin_string)
+            .field("sender_comp_id", &self.sender_comp_id)
+            .field("target_comp_id", &self.target_comp_id)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .field("account", &self.account)
+            .field("heartbeat_interval_secs", &self.heartbeat_interval_secs)
+            .field("reset_on_logon", &self.reset_on_logon)
+            .field("response_timeout_ms", &self.response_timeout_ms)
+            .field("reconnect_delay_ms", &self.reconnect_delay_ms)
+            .field("resend_buffer_size", &self.resend_buffer_size)
+            .field("symbols", &self.symbols)
+            .finish()
+    }
+}
+
+/// State of the FIX session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStatus {
+    /// No connection
+    Disconnected,
+    /// Connected and waiting for the logon reply
+    LogonSent,
+    /// Logged on; orders may be sent
+    LoggedOn,
+    /// Logout sent and waiting for the reply
+    LogoutSent,
+}
+
+/// What an execution report announces (tag 150)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecType {
+    /// Order accepted
+    New,
+    /// Fill or partial fill
+    Trade,
+    /// Order cancelled
+    Canceled,
+    /// Order replaced
+    Replaced,
+    /// Order or change refused
+    Rejected,
+    /// Order expired
+    Expired,
+    /// Pending new, cancel or replace; a final report follows
+    Pending,
+    /// Status, restatement and other informational reports
+    Other,
+}
+
+impl ExecType {
+    fn from_fix(code: &str) -> Self {
+        match code {
+            "0" => ExecType::New,
+            // 1 and 2 are the partial fill and fill codes from FIX 4.2
+            "F" | "1" | "2" => ExecType::Trade,
+            "4" => ExecType::Canceled,
+            "5" => ExecType::Replaced,
+            "8" => ExecType::Rejected,
+            "C" => ExecType::Expired,
+            "6" | "A" | "E" => ExecType::Pending,
+            _ => ExecType::Other,
+        }
+    }
+}
+
+/// Parsed execution report (35=8)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionReport {
+    /// Venue the report came from
+    pub venue: String,
+    /// ClOrdID of the request the report answers
+    pub cl_ord_id: String,
+    /// ClOrdID of the order being cancelled or replaced
+    pub orig_cl_ord_id: Option<String>,
+    /// Order ID assigned by the venue
+    pub venue_order_id: String,
+    /// Execution ID assigned by the venue
+    pub exec_id: String,
+    /// What the report announces
+    pub exec_type: ExecType,
+    /// Order state after this report
+    pub status: VenueOrderStatus,
+    /// Internal symbol
+    pub symbol: String,
+    /// Order side
+    pub side: OrderSide,
+    /// Total quantity filled
+    pub cum_qty: f64,
+    /// Quantity still open
+    pub leaves_qty: f64,
+    /// Average fill price, once anything has filled
+    pub avg_px: Option<f64>,
+    /// Quantity of this fill
+    pub last_qty: f64,
+    /// Price of this fill
+    pub last_px: Option<f64>,
+    /// Free-text reason, usually set on rejects
+    pub text: Option<String>,
+}
+
+impl ExecutionReport {
+    /// Parse an execution report; the symbol is left in venue form
+    pub fn parse(venue: &str, message: &FixMessage) -> FixResult<Self> {
+        let cum_qty = message.get_f64(tags::CUM_QTY).unwrap_or(0.0);
+        let status = match message.require(tags::ORD_STATUS)? {
+            "1" => VenueOrderStatus::PartiallyFilled,
+            "2" => VenueOrderStatus::Filled,
+            "3" | "4" | "7" => VenueOrderStatus::Cancelled,
+            "8" => VenueOrderStatus::Rejected,
+            "C" => VenueOrderStatus::Expired,
+            "0" | "5" | "6" | "9" | "A" | "B" | "D" | "E" if cum_qty > 0.0 => VenueOrderStatus::PartiallyFilled,
+            "0" | "5" | "6" | "9" | "A" | "B" | "D" | "E" => VenueOrderStatus::New,
+            other => return Err(FixError::Malformed(format!("unknown OrdStatus '{}'", other))),
+        };
+        let side = match message.require(tags::SIDE)? {
+            "1" => OrderSide::Buy,
+            "2" => OrderSide::Sell,
+            other => return Err(FixError::Malformed(format!("unsupported Side '{}'", other))),
+        };
+
+        Ok(Self {
+            venue: venue.to_string(),
+            cl_ord_id: message.require(tags::CL_ORD_ID)?.to_string(),
+            orig_cl_ord_id: message.get(tags::ORIG_CL_ORD_ID).map(str::to_string),
+            venue_order_id: message.require(tags::ORDER_ID)?.to_string(),
+            exec_id: message.require(tags::EXEC_ID)?.to_string(),
+            exec_type: ExecType::from_fix(message.require(tags::EXEC_TYPE)?),
+            status,
+            symbol: message.require(tags::SYMBOL)?.to_string(),
+            side,
+            cum_qty,
+            leaves_qty: message.get_f64(tags::LEAVES_QTY).unwrap_or(0.0),
+            avg_px: message.get_f64(tags::AVG_PX).filter(|_| cum_qty > 0.0),
+            last_qty: message.get_f64(tags::LAST_QTY).unwrap_or(0.0),
+            last_px: message.get_f64(tags::LAST_PX).filter(|px| *px > 0.0),
+            text: message.get(tags::TEXT).map(str::to_string),
+        })
+    }
+
+    /// Router result for the report answering `order`'s submission
+    pub fn to_venue_result(&self, order: &Order) -> VenueExecutionResult {
+        let failed = match self.status {
+            VenueOrderStatus::Rejected => true,
+            // Immediate orders that expire untouched did not execute
+            VenueOrderStatus::Expired | VenueOrderStatus::Cancelled => self.cum_qty <= 0.0,
+            _ => false,
+        };
+        if failed {
+            return VenueExecutionResult {
+                success: false,
+                venue: self.venue.clone(),
+                reason: Some(ExecutionFailureReason::Unknown),
+                details: self.text.as_ref().map(|text| serde_json::json!({ "text": text })),
+            };
+        }
+
+        VenueExecutionResult {
+            success: true,
+            venue: self.venue.clone(),
+            reason: None,
+            details: Some(serde_json::json!({
+                "venue_order_id": self.venue_order_id,
+                "client_order_id": self.cl_ord_id,
+                "exec_id": self.exec_id,
+                "status": self.status,
+                "filled_amount": self.cum_qty,
+                "fill_price": self.avg_px,
+                "post_only": order.post_only,
+                "liquidity": order.liquidity_intent(),
+                "time_in_force": order.time_in_force.name(),
+            })),
+        }
+    }
+}
+
+/// Order open on the venue, keyed by its current ClOrdID
+#[derive(Debug, Clone)]
+struct FixOrder {
+    symbol: String,
+    side: OrderSide,
+    quantity: f64,
+    venue_order_id: Option<String>,
+}
+
+/// Request waiting for its first execution report
+struct Pending {
+    seq_num: u64,
+    reply: oneshot::Sender<FixResult<ExecutionReport>>,
+}
+
+struct SessionState {
+    next_outbound: u64,
+    next_inbound: u64,
+    /// Sent application messages by sequence number, for resends
+    sent: VecDeque<(u64, FixMessage)>,
+    last_sent: Instant,
+    last_received: Instant,
+    /// TestReqID awaiting a heartbeat
+    test_request: Option<String>,
+    /// Sequence number that revealed the gap currently being resent
+    resend_until: Option<u64>,
+    pending: HashMap<String, Pending>,
+    orders: HashMap<String, FixOrder>,
+}
+
+impl Default for SessionState {
+    fn default() -> Self {
+        Self {
+            next_outbound: 1,
+            next_inbound: 1,
+            sent: VecDeque::new(),
+            last_sent: Instant::now(),
+            last_received: Instant::now(),
+            test_request: None,
+            resend_until: None,
+            pending: HashMap::new(),
+            orders: HashMap::new(),
+        }
+    }
+}
+
+/// FIX initiator session with order entry
+pub struct FixGateway {
+    config: FixSessionConfig,
+    writer: tokio::sync::Mutex<Option<OwnedWriteHalf>>,
+    session: Mutex<SessionState>,
+    status: watch::Sender<SessionStatus>,
+    executions: broadcast::Sender<ExecutionReport>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+    freshness_guard: Option<Arc<QuoteFreshnessGuard>>,
+}
+
+impl FixGateway {
+    /// Create a gateway; call [`start`](Self::start) to connect
+    pub fn new(config: FixSessionConfig) -> Self {
+        let (status, _) = watch::channel(SessionStatus::Disconnected);
+        let (executions, _) = broadcast::channel(1024);
+        Self {
+            config,
+            writer: tokio::sync::Mutex::new(None),
+            session: Mutex::new(SessionState::default()),
+            status,
+            executions,
+            tasks: Mutex::new(Vec::new()),
+            freshness_guard: None,
+        }
+    }
+
+    /// Report every inbound message as a venue heartbeat to the freshness guard
+    pub fn with_freshness_guard(mut self, guard: Arc<QuoteFreshnessGuard>) -> Self {
+        self.freshness_guard = Some(guard);
+        self
+    }
+
+    /// Venue identifier used by the router
+    pub fn venue_id(&self) -> &str {
+        &self.config.venue_id
+    }
+
+    /// Current session state
+    pub fn status(&self) -> SessionStatus {
+        *self.status.borrow()
+    }
+
+    /// Watch the session state
+    pub fn subscribe_status(&self) -> watch::Receiver<SessionStatus> {
+        self.status.subscribe()
+    }
+
+    /// Every execution report received, including fills on resting orders
+    pub fn subscribe_executions(&self) -> broadcast::Receiver<ExecutionReport> {
+        self.executions.subscribe()
+    }
+
+    /// Connect and keep the session up, reconnecting after it drops
+    pub fn start(self: &Arc<Self>) {
+        let mut tasks = self.tasks.lock().unwrap();
+        if !tasks.is_empty() {
+            return;
+        }
+        info!("Starting FIX session {}->{} for {}", self.config.sender_comp_id, self.config.target_comp_id, self.config.venue_id);
+
+        let gateway = self.clone();
+        tasks.push(tokio::spawn(async move {
+            loop {
+                match gateway.run_session().await {
+                    Ok(()) => info!("FIX session to {} logged out", gateway.config.venue_id),
+                    Err(e) => warn!("FIX session to {} dropped: {}", gateway.config.venue_id, e),
+                }
+                *gateway.writer.lock().await = None;
+                // Dropping the reply senders fails requests still waiting
+                gateway.session.lock().unwrap().pending.clear();
+                gateway.status.send_replace(SessionStatus::Disconnected);
+                tokio::time::sleep(Duration::from_millis(gateway.config.reconnect_delay_ms)).await;
+            }
+        }));
+    }
+
+    /// Log out and stop reconnecting
+    pub async fn stop(&self) {
+        if self.status() == SessionStatus::LoggedOn {
+            let mut status = self.subscribe_status();
+            match self.logout("Session closed").await {
+                Ok(()) => {
+                    let logged_out = async {
+                        while *status.borrow_and_update() != SessionStatus::Disconnected {
+                            if status.changed().await.is_err() {
+                                break;
+                            }
+                        }
+                    };
+                    let _ = tokio::time::timeout(self.response_timeout(), logged_out).await;
+                }
+                Err(e) => warn!("Failed to log out of {}: {}", self.config.venue_id, e),
+            }
+        }
+
+        let tasks: Vec<_> = self.tasks.lock().unwrap().drain(..).collect();
+        for task in tasks {
+            task.abort();
+        }
+        *self.writer.lock().await = None;
+        self.session.lock().unwrap().pending.clear();
+        self.status.send_replace(SessionStatus::Disconnected);
+    }
+
+    /// Send a NewOrderSingle
+    pub async fn submit(&self, order: &Order) -> FixResult<ExecutionReport> {
+        let cl_ord_id = order_lineage::client_order_id(order);
+        let mut message = FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(tags::CL_ORD_ID, &cl_ord_id);
+        self.order_fields(&mut message, order)?;
+
+        self.session.lock().unwrap().orders.insert(cl_ord_id.clone(), FixOrder {
+            symbol: self.venue_symbol(&order.symbol),
+            side: order.side,
+            quantity: order.amount,
+            venue_order_id: None,
+        });
+        let report = self.request(&cl_ord_id, message).await;
+        if report.is_err() {
+            self.session.lock().unwrap().orders.remove(&cl_ord_id);
+        }
+        report
+    }
+
+    /// Send an OrderCancelRequest for the order submitted as `cl_ord_id`
+    pub async fn cancel(&self, cl_ord_id: &str) -> FixResult<ExecutionReport> {
+        let order = self.open_order(cl_ord_id)?;
+        let cancel_id = new_id(IdKind::Order);
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .with(tags::ORIG_CL_ORD_ID, cl_ord_id)
+            .with(tags::CL_ORD_ID, &cancel_id);
+        if let Some(venue_order_id) = &order.venue_order_id {
+            message.push(tags::ORDER_ID, venue_order_id);
+        }
+        message.push(tags::SYMBOL, &order.symbol);
+        message.push(tags::SIDE, side_code(order.side));
+        message.push(tags::TRANSACT_TIME, utc_timestamp(Utc::now()));
+        message.push(tags::ORDER_QTY, format_decimal(order.quantity));
+
+        self.request(&cancel_id, message).await
+    }
+
+    /// Send an OrderCancelReplaceRequest moving the order submitted as
+    /// `cl_ord_id` to the price, quantity and time-in-force of `replacement`
+    pub async fn replace(&self, cl_ord_id: &str, replacement: &Order) -> FixResult<ExecutionReport> {
+        let order = self.open_order(cl_ord_id)?;
+        if order.symbol != self.venue_symbol(&replacement.symbol) || order.side != replacement.side {
+            return Err(FixError::Unsupported("a replace cannot change the symbol or side".to_string()));
+        }
+
+        let mut replace_id = order_lineage::client_order_id(replacement);
+        if replace_id == cl_ord_id {
+            replace_id = new_id(IdKind::Order);
+        }
+        let mut message = FixMessage::new(msg_type::ORDER_CANCEL_REPLACE_REQUEST)
+            .with(tags::ORIG_CL_ORD_ID, cl_ord_id)
+            .with(tags::CL_ORD_ID, &replace_id);
+        if let Some(venue_order_id) = &order.venue_order_id {
+            message.push(tags::ORDER_ID, venue_order_id);
+        }
+        self.order_fields(&mut message, replacement)?;
+
+        self.request(&replace_id, message).await
+    }
+
+    /// Order fields shared by new and replace requests. A price of zero sends
+    /// a market order; post-only orders carry ExecInst ParticipateDontInitiate.
+    fn order_fields(&self, message: &mut FixMessage, order: &Order) -> FixResult<()> {
+        if let Some(account) = &self.config.account {
+            message.push(tags::ACCOUNT, account);
+        }
+        message.push(tags::SYMBOL, self.venue_symbol(&order.symbol));
+        message.push(tags::SIDE, side_code(order.side));
+        message.push(tags::TRANSACT_TIME, utc_timestamp(Utc::now()));
+        message.push(tags::ORDER_QTY, format_decimal(order.amount));
+
+        if order.price <= 0.0 {
+            if order.post_only {
+                return Err(FixError::Unsupported("post-only market order".to_string()));
+            }
+            message.push(tags::ORD_TYPE, "1");
+        } else {
+            message.push(tags::ORD_TYPE, "2");
+            message.push(tags::PRICE, format_decimal(order.price));
+        }
+
+        let time_in_force = match order.time_in_force {
+            TimeInForce::GTC => "1",
+            TimeInForce::IOC => "3",
+            TimeInForce::FOK => "4",
+            TimeInForce::GTT { .. } | TimeInForce::GTD { .. } => "6",
+            TimeInForce::Session => "0",
+        };
+        message.push(tags::TIME_IN_FORCE, time_in_force);
+        if let Some(expires_at) = order.time_in_force.expires_at(Utc::now()) {
+            message.push(tags::EXPIRE_TIME, utc_timestamp(expires_at));
+        }
+        if order.post_only {
+            message.push(tags::EXEC_INST, "6");
+        }
+        Ok(())
+    }
+
+    fn open_order(&self, cl_ord_id: &str) -> FixResult<FixOrder> {
+        self.session.lock().unwrap()
+            .orders
+            .get(cl_ord_id)
+            .cloned()
+            .ok_or_else(|| FixError::UnknownOrder(cl_ord_id.to_string()))
+    }
+
+    fn venue_symbol(&self, symbol: &str) -> String {
+        self.config.symbols.get(symbol).cloned().unwrap_or_else(|| symbol.to_string())
+    }
+
+    fn internal_symbol(&self, venue_symbol: &str) -> String {
+        self.config.symbols
+            .iter()
+            .find(|(_, mapped)| mapped.as_str() == venue_symbol)
+            .map(|(internal, _)| internal.clone())
+            .unwrap_or_else(|| venue_symbol.to_string())
+    }
+
+    fn response_timeout(&self) -> Duration {
+        Duration::from_millis(self.config.response_timeout_ms)
+    }
+
+    /// Send an order message and wait for its first final execution report
+    async fn request(&self, cl_ord_id: &str, message: FixMessage) -> FixResult<ExecutionReport> {
+        if self.status() != SessionStatus::LoggedOn {
+            return Err(FixError::NotLoggedOn(self.config.venue_id.clone()));
+        }
+
+        // Registered before sending so a fast report cannot be missed
+        let (reply, response) = oneshot::channel();
+        self.session.lock().unwrap().pending.insert(cl_ord_id.to_string(), Pending { seq_num: 0, reply });
+        match self.write(message, None).await {
+            Ok(seq_num) => {
+                if let Some(pending) = self.session.lock().unwrap().pending.get_mut(cl_ord_id) {
+                    pending.seq_num = seq_num;
+                }
+            }
+            Err(e) => {
+                self.session.lock().unwrap().pending.remove(cl_ord_id);
+                return Err(e);
+            }
+        }
+
+        match tokio::time::timeout(self.response_timeout(), response).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(FixError::NotLoggedOn(self.config.venue_id.clone())),
+            Err(_) => {
+                // The order may still reach the venue; its reports are broadcast when they arrive
+                self.session.lock().unwrap().pending.remove(cl_ord_id);
+                Err(FixError::Timeout(format!("order {}", cl_ord_id)))
+            }
+        }
+    }
+
+    /// Connect, log on and process messages until the session ends
+    async fn run_session(&self) -> FixResult<()> {
+        let stream = TcpStream::connect((self.config.host.as_str(), self.config.port)).await?;
+        stream.set_nodelay(true)?;
+        let (mut reader, writer) = stream.into_split();
+        *self.writer.lock().await = Some(writer);
+
+        {
+            let mut session = self.session.lock().unwrap();
+            if self.config.reset_on_logon {
+                session.next_outbound = 1;
+                session.next_inbound = 1;
+                session.sent.clear();
+            }
+            session.last_received = Instant::now();
+            session.test_request = None;
+            session.resend_until = None;
+        }
+
+        let mut logon = FixMessage::new(msg_type::LOGON)
+            .with(tags::ENCRYPT_METHOD, 0)
+            .with(tags::HEART_BT_INT, self.config.heartbeat_interval_secs);
+        if self.config.reset_on_logon {
+            logon.push(tags::RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(username) = &self.config.username {
+            logon.push(tags::USERNAME, username);
+        }
+        if let Some(password) = &self.config.password {
+            logon.push(tags::PASSWORD, password);
+        }
+        self.status.send_replace(SessionStatus::LogonSent);
+        self.write(logon, None).await?;
+
+        let logon_deadline = Instant::now() + self.response_timeout();
+        let mut liveness = tokio::time::interval(Duration::from_secs(1));
+        let mut buf = Vec::with_capacity(8192);
+        let mut chunk = vec![0u8; 8192];
+        loop {
+            tokio::select! {
+                read = reader.read(&mut chunk) => {
+                    let n = read?;
+                    if n == 0 {
+                        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                    while let Some(message) = FixMessage::decode_frame(&mut buf)? {
+                        if !self.handle_inbound(message).await? {
+                            return Ok(());
+                        }
+                    }
+                }
+                _ = liveness.tick() => self.check_liveness(logon_deadline).await?,
+            }
+        }
+    }
+
+    /// Send heartbeats when idle and test requests when the counterparty is silent
+    async fn check_liveness(&self, logon_deadline: Instant) -> FixResult<()> {
+        match self.status() {
+            SessionStatus::LogonSent if Instant::now() > logon_deadline => {
+                return Err(FixError::Timeout("logon".to_string()));
+            }
+            SessionStatus::LoggedOn => {}
+            _ => return Ok(()),
+        }
+
+        let heartbeat = Duration::from_secs(self.config.heartbeat_interval_secs.max(1));
+        let grace = heartbeat / 5;
+        let (idle, silent, test_pending) = {
+            let session = self.session.lock().unwrap();
+            (session.last_sent.elapsed(), session.last_received.elapsed(), session.test_request.is_some())
+        };
+
+        if silent > (heartbeat + grace) * 2 && test_pending {
+            return Err(FixError::Timeout("test request".to_string()));
+        }
+        if silent > heartbeat + grace && !test_pending {
+            let test_req_id = utc_timestamp(Utc::now());
+            self.session.lock().unwrap().test_request = Some(test_req_id.clone());
+            self.write(FixMessage::new(msg_type::TEST_REQUEST).with(tags::TEST_REQ_ID, test_req_id), None).await?;
+        } else if idle >= heartbeat {
+            self.write(FixMessage::new(msg_type::HEARTBEAT), None).await?;
+        }
+        Ok(())
+    }
+
+    /// Process one inbound message; returns `false` once the session has logged out
+    async fn handle_inbound(&self, message: FixMessage) -> FixResult<bool> {
+        trace!("FIX in {}: {}", self.config.venue_id, message);
+        let seq_num = message.seq_num()?;
+        let kind = message.msg_type().to_string();
+
+        let expected = {
+            let mut session = self.session.lock().unwrap();
+            session.last_received = Instant::now();
+            session.test_request = None;
+            session.next_inbound
+        };
+        if let Some(guard) = &self.freshness_guard {
+            guard.record_heartbeat(&self.config.venue_id, Utc::now().timestamp_millis().max(0) as u64);
+        }
+
+        // A reset (not gap fill) applies whatever its own sequence number
+        if kind == msg_type::SEQUENCE_RESET && !message.get_flag(tags::GAP_FILL_FLAG) {
+            let new_seq = message.get_seq(tags::NEW_SEQ_NO)
+                .ok_or_else(|| FixError::Malformed("SequenceReset without NewSeqNo".to_string()))?;
+            self.session.lock().unwrap().next_inbound = new_seq;
+            return Ok(true);
+        }
+
+        if seq_num < expected {
+            if message.get_flag(tags::POSS_DUP_FLAG) {
+                debug!("Ignoring duplicate FIX message {} from {}", seq_num, self.config.venue_id);
+                return Ok(true);
+            }
+            let _ = self.logout(&format!("MsgSeqNum too low, expecting {} but received {}", expected, seq_num)).await;
+            return Err(FixError::SequenceTooLow { expected, received: seq_num });
+        }
+
+        if seq_num > expected {
+            let request_resend = {
+                let mut session = self.session.lock().unwrap();
+                let outstanding = session.resend_until.is_some();
+                session.resend_until = Some(session.resend_until.unwrap_or(0).max(seq_num));
+                !outstanding
+            };
+            if request_resend {
+                info!("FIX sequence gap from {}: expected {}, received {}", self.config.venue_id, expected, seq_num);
+                let resend = FixMessage::new(msg_type::RESEND_REQUEST)
+                    .with(tags::BEGIN_SEQ_NO, expected)
+                    .with(tags::END_SEQ_NO, 0);
+                self.write(resend, None).await?;
+            }
+            // Everything else is redelivered by the resend
+            if !matches!(kind.as_str(), msg_type::LOGON | msg_type::LOGOUT | msg_type::RESEND_REQUEST) {
+                return Ok(true);
+            }
+        } else {
+            let mut session = self.session.lock().unwrap();
+            session.next_inbound = seq_num + 1;
+            if session.resend_until.is_some_and(|until| seq_num >= until) {
+                session.resend_until = None;
+            }
+        }
+
+        match kind.as_str() {
+            msg_type::LOGON => {
+                if self.status() == SessionStatus::LogonSent {
+                    info!("FIX session to {} logged on", self.config.venue_id);
+                    self.status.send_replace(SessionStatus::LoggedOn);
+                }
+            }
+            msg_type::HEARTBEAT => {}
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(test_req_id) = message.get(tags::TEST_REQ_ID) {
+                    heartbeat.push(tags::TEST_REQ_ID, test_req_id);
+                }
+                self.write(heartbeat, None).await?;
+            }
+            msg_type::RESEND_REQUEST => {
+                let begin = message.get_seq(tags::BEGIN_SEQ_NO).unwrap_or(1);
+                let end = message.get_seq(tags::END_SEQ_NO).unwrap_or(0);
+                self.resend(begin, end).await?;
+            }
+            msg_type::SEQUENCE_RESET => {
+                if let Some(new_seq) = message.get_seq(tags::NEW_SEQ_NO) {
+                    let mut session = self.session.lock().unwrap();
+                    session.next_inbound = session.next_inbound.max(new_seq);
+                }
+            }
+            msg_type::REJECT => {
+                let ref_seq_num = message.get_seq(tags::REF_SEQ_NUM).unwrap_or(0);
+                let reason = message.get(tags::TEXT).unwrap_or("no reason given").to_string();
+                warn!("{} rejected FIX message {}: {}", self.config.venue_id, ref_seq_num, reason);
+                let pending = {
+                    let mut session = self.session.lock().unwrap();
+                    let cl_ord_id = session.pending.iter().find(|(_, p)| p.seq_num == ref_seq_num).map(|(id, _)| id.clone());
+                    cl_ord_id.and_then(|id| session.pending.remove(&id))
+                };
+                if let Some(pending) = pending {
+                    let _ = pending.reply.send(Err(FixError::SessionRejected { ref_seq_num, reason }));
+                }
+            }
+            msg_type::LOGOUT => {
+                let reason = message.get(tags::TEXT).unwrap_or_default().to_string();
+                match self.status() {
+                    SessionStatus::LogonSent => {
+                        return Err(FixError::LogonRejected { venue: self.config.venue_id.clone(), reason });
+                    }
+                    SessionStatus::LogoutSent => {}
+                    _ => {
+                        info!("{} logged out: {}", self.config.venue_id, reason);
+                        self.write(FixMessage::new(msg_type::LOGOUT), None).await?;
+                    }
+                }
+                return Ok(false);
+            }
+            msg_type::EXECUTION_REPORT => self.on_execution_report(&message)?,
+            msg_type::ORDER_CANCEL_REJECT => {
+                let cl_ord_id = message.require(tags::CL_ORD_ID)?.to_string();
+                let reason = message.get(tags::TEXT).unwrap_or("no reason given").to_string();
+                let orig_cl_ord_id = message.get(tags::ORIG_CL_ORD_ID).unwrap_or(&cl_ord_id).to_string();
+                if let Some(pending) = self.session.lock().unwrap().pending.remove(&cl_ord_id) {
+                    let _ = pending.reply.send(Err(FixError::CancelRejected { cl_ord_id: orig_cl_ord_id, reason }));
+                }
+            }
+            other => debug!("Ignoring FIX message type {} from {}", other, self.config.venue_id),
+        }
+        Ok(true)
+    }
+
+    /// Track the order an execution report describes and complete the request it answers
+    fn on_execution_report(&self, message: &FixMessage) -> FixResult<()> {
+        let mut report = ExecutionReport::parse(&self.config.venue_id, message)?;
+        report.symbol = self.internal_symbol(&report.symbol);
+
+        let pending = {
+            let mut session = self.session.lock().unwrap();
+            // Cancels and replaces answer under the request's own ClOrdID
+            if let (ExecType::Canceled | ExecType::Replaced, Some(orig)) = (report.exec_type, &report.orig_cl_ord_id) {
+                if let Some(mut order) = session.orders.remove(orig) {
+                    if report.exec_type == ExecType::Replaced {
+                        order.quantity = report.cum_qty + report.leaves_qty;
+                        session.orders.insert(report.cl_ord_id.clone(), order);
+                    }
+                }
+            }
+            if report.status.is_open() {
+                if let Some(order) = session.orders.get_mut(&report.cl_ord_id) {
+                    order.venue_order_id = Some(report.venue_order_id.clone());
+                }
+            } else {
+                session.orders.remove(&report.cl_ord_id);
+            }
+
+            if report.exec_type == ExecType::Pending {
+                None
+            } else {
+                session.pending.remove(&report.cl_ord_id)
+            }
+        };
+
+        if let Some(pending) = pending {
+            let _ = pending.reply.send(Ok(report.clone()));
+        }
+        let _ = self.executions.send(report);
+        Ok(())
+    }
+
+    /// Answer a resend request: stored application messages are sent again as
+    /// possible duplicates and everything else is skipped with gap fills
+    async fn resend(&self, begin: u64, end: u64) -> FixResult<()> {
+        let (stored, next) = {
+            let session = self.session.lock().unwrap();
+            let last = session.next_outbound - 1;
+            let end = if end == 0 || end > last { last } else { end };
+            let stored: Vec<(u64, FixMessage)> = session.sent
+                .iter()
+                .filter(|(seq_num, _)| (begin..=end).contains(seq_num))
+                .cloned()
+                .collect();
+            (stored, end + 1)
+        };
+        debug!("Resending FIX messages {}..{} to {}", begin, next, self.config.venue_id);
+
+        let mut gap_start = begin;
+        for (seq_num, mut message) in stored {
+            if seq_num > gap_start {
+                self.write(gap_fill(seq_num), Some(gap_start)).await?;
+            }
+            if let Some(sent_at) = message.get(tags::SENDING_TIME).map(str::to_string) {
+                message.set(tags::ORIG_SENDING_TIME, sent_at);
+            }
+            message.set(tags::POSS_DUP_FLAG, "Y");
+            self.write(message, Some(seq_num)).await?;
+            gap_start = seq_num + 1;
+        }
+        if gap_start < next {
+            self.write(gap_fill(next), Some(gap_start)).await?;
+        }
+        Ok(())
+    }
+
+    async fn logout(&self, reason: &str) -> FixResult<()> {
+        self.status.send_replace(SessionStatus::LogoutSent);
+        self.write(FixMessage::new(msg_type::LOGOUT).with(tags::TEXT, reason), None).await.map(|_| ())
+    }
+
+    /// Stamp the header and send. New messages take the next sequence number;
+    /// resends reuse `resend_seq`.
+    async fn write(&self, mut message: FixMessage, resend_seq: Option<u64>) -> FixResult<u64> {
+        let mut writer = self.writer.lock().await;
+        let writer = writer.as_mut().ok_or_else(|| FixError::NotLoggedOn(self.config.venue_id.clone()))?;
+
+        let seq_num = {
+            let mut session = self.session.lock().unwrap();
+            let seq_num = match resend_seq {
+                Some(seq_num) => seq_num,
+                None => {
+                    session.next_outbound += 1;
+                    session.next_outbound - 1
+                }
+            };
+            message.set_header(&self.config.sender_comp_id, &self.config.target_comp_id, seq_num, Utc::now());
+            if resend_seq.is_none() && !msg_type::is_admin(message.msg_type()) {
+                session.sent.push_back((seq_num, message.clone()));
+                while session.sent.len() > self.config.resend_buffer_size {
+                    session.sent.pop_front();
+                }
+            }
+            session.last_sent = Instant::now();
+            seq_num
+        };
+
+        trace!("FIX out {}: {}", self.config.venue_id, message);
+        writer.write_all(&message.encode(&self.config.begin_string)).await?;
+        Ok(seq_num)
+    }
+}
+
+/// SequenceReset-GapFill moving the counterparty on to `new_seq_no`
+fn gap_fill(new_seq_no: u64) -> FixMessage {
+    FixMessage::new(msg_type::SEQUENCE_RESET)
+        .with(tags::POSS_DUP_FLAG, "Y")
+        .with(tags::GAP_FILL_FLAG, "Y")
+        .with(tags::NEW_SEQ_NO, new_seq_no)
+}
+
+fn side_code(side: OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "1",
+        OrderSide::Sell => "2",
+    }
+}
+
+/// Create a FIX gateway
+pub fn create_fix_gateway(config: FixSessionConfig) -> Arc<FixGateway> {
+    Arc::new(FixGateway::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Read from the acceptor side until a message of type `kind` arrives
+    async fn expect(stream: &mut TcpStream, buf: &mut Vec<u8>, kind: &str) -> FixMessage {
+        let mut chunk = [0u8; 4096];
+        loop {
+            while let Some(message) = FixMessage::decode_frame(buf).unwrap() {
+                if message.msg_type() == kind {
+                    return message;
+                }
+            }
+            let n = stream.read(&mut chunk).await.unwrap();
+            assert!(n > 0, "gateway closed the connection");
+            buf.extend_from_slice(&chunk[..n]);
+        }
+    }
+
+    async fn reply(stream: &mut TcpStream, mut message: FixMessage, seq_num: u64) {
+        message.set_header("VENUE", "NODERR", seq_num, Utc::now());
+        stream.write_all(&message.encode("FIX.4.4")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_logon_and_order_round_trip() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gateway = create_fix_gateway(FixSessionConfig {
+            venue_id: "prime".to_string(),
+            port: listener.local_addr().unwrap().port(),
+            sender_comp_id: "NODERR".to_string(),
+            target_comp_id: "VENUE".to_string(),
+            response_timeout_ms: 1000,
+            symbols: HashMap::from([("BTC/USD".to_string(), "XBTUSD".to_string())]),
+            ..Default::default()
+        });
+
+        let acceptor = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = Vec::new();
+            let logon = expect(&mut stream, &mut buf, msg_type::LOGON).await;
+            assert_eq!(logon.get(tags::RESET_SEQ_NUM_FLAG), Some("Y"));
+            reply(&mut stream, FixMessage::new(msg_type::LOGON).with(tags::ENCRYPT_METHOD, 0).with(tags::HEART_BT_INT, 30), 1).await;
+
+            let order = expect(&mut stream, &mut buf, msg_type::NEW_ORDER_SINGLE).await;
+            assert_eq!(order.get(tags::MSG_SEQ_NUM), Some("2"));
+            assert_eq!(order.get(tags::SYMBOL), Some("XBTUSD"));
+            assert_eq!(order.get(tags::SIDE), Some("1"));
+            assert_eq!(order.get(tags::ORD_TYPE), Some("2"));
+            assert_eq!(order.get(tags::TIME_IN_FORCE), Some("3"));
+            let report = FixMessage::new(msg_type::EXECUTION_REPORT)
+                .with(tags::ORDER_ID, "V-1")
+                .with(tags::CL_ORD_ID, order.get(tags::CL_ORD_ID).unwrap())
+                .with(tags::EXEC_ID, "E-1")
+                .with(tags::EXEC_TYPE, "F")
+                .with(tags::ORD_STATUS, "2")
+                .with(tags::SYMBOL, "XBTUSD")
+                .with(tags::SIDE, "1")
+                .with(tags::CUM_QTY, "0.5")
+                .with(tags::LEAVES_QTY, "0")
+                .with(tags::AVG_PX, "42000.5")
+                .with(tags::LAST_QTY, "0.5")
+                .with(tags::LAST_PX, "42000.5");
+            reply(&mut stream, report, 2).await;
+        });
+
+        let mut status = gateway.subscribe_status();
+        gateway.start();
+        let logged_on = async {
+            while *status.borrow_and_update() != SessionStatus::LoggedOn {
+                status.changed().await.unwrap();
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), logged_on).await.unwrap();
+
+        let order = Order {
+            symbol: "BTC/USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 0.5,
+            price: 42100.0,
+            venues: vec!["prime".to_string()],
+            id: "order-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::IOC,
+            additional_params: HashMap::new(),
+        };
+        let report = gateway.submit(&order).await.unwrap();
+        assert_eq!(report.symbol, "BTC/USD");
+        assert_eq!(report.status, VenueOrderStatus::Filled);
+
+        let result = report.to_venue_result(&order);
+        assert!(result.success);
+        let details = result.details.unwrap();
+        assert_eq!(details["venue_order_id"], "V-1");
+        assert_eq!(details["fill_price"], 42000.5);
+
+        acceptor.await.unwrap();
+        gateway.stop().await;
+    }
+}
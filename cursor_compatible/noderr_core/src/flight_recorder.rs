@@ -0,0 +1,457 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Flight recorder for post-mortems of fast failures
+//!
+//! Keeps the last few seconds of high-resolution events (ticks, signals,
+//! risk decisions, orders) per symbol in memory and writes them to disk
+//! when something goes badly wrong: a kill switch fires, the process
+//! panics or market data shows a critical anomaly. Each dump is one JSON
+//! file holding every symbol's window, oldest event first.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::drawdown_monitor::KillSwitch;
+use crate::market_data::{MarketAnomaly, MarketTick};
+use crate::strategy::Signal;
+use crate::trading_events::{TradingEvent, TradingEventBus};
+
+/// Symbol under which events without one are recorded
+pub const GLOBAL_SYMBOL: &str = "*";
+
+/// Errors that can occur when dumping a recording
+#[derive(Debug, Error)]
+pub enum FlightRecorderError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Dump skipped: {0}")]
+    Throttled(String),
+}
+
+/// Flight recorder configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlightRecorderConfig {
+    /// Seconds of history kept per symbol
+    pub window_secs: u64,
+    /// Most events kept per symbol, whatever their age
+    pub max_events_per_symbol: usize,
+    /// Directory dumps are written to
+    pub dump_dir: PathBuf,
+    /// Minimum seconds between dumps; a burst of failures produces one dump
+    pub min_dump_interval_secs: u64,
+    /// Market anomaly severity (0.0-1.0) at or above which a dump is taken
+    pub critical_anomaly_severity: f64,
+}
+
+impl Default for FlightRecorderConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: 60,
+            max_events_per_symbol: 10_000,
+            dump_dir: PathBuf::from("./data/flight_recorder"),
+            min_dump_interval_secs: 10,
+            critical_anomaly_severity: 0.9,
+        }
+    }
+}
+
+/// Kind of a recorded event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedEventKind {
+    /// A market data tick
+    Tick,
+    /// A strategy signal
+    Signal,
+    /// A risk check outcome
+    RiskDecision,
+    /// An order state change or fill
+    Order,
+    /// A market data anomaly
+    Anomaly,
+}
+
+/// One recorded event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Event kind
+    pub kind: RecordedEventKind,
+    /// Symbol the event belongs to
+    pub symbol: String,
+    /// When the event happened
+    pub timestamp: DateTime<Utc>,
+    /// Event payload
+    pub data: serde_json::Value,
+}
+
+/// Contents of a dump file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightRecording {
+    /// What caused the dump (`kill_switch`, `panic`, `critical_anomaly`, ...)
+    pub trigger: String,
+    /// Details of the trigger
+    pub reason: String,
+    /// When the dump was taken
+    pub dumped_at: DateTime<Utc>,
+    /// Seconds of history covered
+    pub window_secs: u64,
+    /// Events by symbol, oldest first
+    pub events: BTreeMap<String, Vec<RecordedEvent>>,
+}
+
+impl FlightRecording {
+    /// Total number of events across symbols
+    pub fn event_count(&self) -> usize {
+        self.events.values().map(Vec::len).sum()
+    }
+}
+
+/// In-memory ring buffers of recent events, dumped on failure
+pub struct FlightRecorder {
+    /// Configuration
+    config: FlightRecorderConfig,
+    /// Recent events by symbol, oldest first
+    buffers: Mutex<HashMap<String, VecDeque<RecordedEvent>>>,
+    /// When the last dump was written
+    last_dump: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl FlightRecorder {
+    /// Create a new flight recorder
+    pub fn new(config: FlightRecorderConfig) -> Self {
+        Self {
+            config,
+            buffers: Mutex::new(HashMap::new()),
+            last_dump: Mutex::new(None),
+        }
+    }
+
+    /// Configuration
+    pub fn config(&self) -> &FlightRecorderConfig {
+        &self.config
+    }
+
+    fn window(&self) -> Duration {
+        Duration::seconds(self.config.window_secs as i64)
+    }
+
+    /// Record an event, evicting events that fell out of the window or over the cap
+    pub fn record(&self, kind: RecordedEventKind, symbol: &str, timestamp: DateTime<Utc>, data: serde_json::Value) {
+        let mut buffers = lock(&self.buffers);
+        let buffer = match buffers.get_mut(symbol) {
+            Some(buffer) => buffer,
+            None => buffers.entry(symbol.to_string()).or_default(),
+        };
+
+        buffer.push_back(RecordedEvent { kind, symbol: symbol.to_string(), timestamp, data });
+
+        let cutoff = timestamp - self.window();
+        while buffer.front().map(|event| event.timestamp < cutoff).unwrap_or(false)
+            || buffer.len() > self.config.max_events_per_symbol
+        {
+            buffer.pop_front();
+        }
+    }
+
+    /// Record a market data tick
+    pub fn record_tick(&self, tick: &MarketTick) {
+        self.record(RecordedEventKind::Tick, &tick.symbol, tick.timestamp, to_value(tick));
+    }
+
+    /// Record a strategy signal
+    pub fn record_signal(&self, signal: &Signal) {
+        self.record(RecordedEventKind::Signal, &signal.symbol, signal.timestamp, to_value(signal));
+    }
+
+    /// Record a market data anomaly
+    pub fn record_anomaly(&self, anomaly: &MarketAnomaly) {
+        self.record(RecordedEventKind::Anomaly, &anomaly.symbol, anomaly.timestamp, to_value(anomaly));
+    }
+
+    /// Record an event from the trading event bus
+    pub fn record_trading_event(&self, event: &TradingEvent) {
+        let (kind, symbol, timestamp) = match event {
+            TradingEvent::Fill { symbol, timestamp, .. } => (RecordedEventKind::Order, symbol.as_str(), *timestamp),
+            TradingEvent::OrderState { symbol, timestamp, .. } => (RecordedEventKind::Order, symbol.as_str(), *timestamp),
+            TradingEvent::RiskViolation { symbol, timestamp, .. } => {
+                (RecordedEventKind::RiskDecision, symbol.as_str(), *timestamp)
+            }
+            TradingEvent::Drawdown(drawdown) => (RecordedEventKind::RiskDecision, GLOBAL_SYMBOL, drawdown.timestamp),
+        };
+        self.record(kind, symbol, timestamp, to_value(event));
+    }
+
+    /// Record every event published on `event_bus` until the bus closes
+    pub fn attach_event_bus(self: &Arc<Self>, event_bus: &TradingEventBus) -> JoinHandle<()> {
+        let recorder = self.clone();
+        let mut events = event_bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => recorder.record_trading_event(&event),
+                    Err(RecvError::Lagged(missed)) => {
+                        warn!("Flight recorder missed {} trading events", missed);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Events currently in the window, by symbol
+    pub fn recording(&self, trigger: &str, reason: &str, at: DateTime<Utc>) -> FlightRecording {
+        self.recording_from(&lock(&self.buffers), trigger, reason, at)
+    }
+
+    fn recording_from(
+        &self,
+        buffers: &HashMap<String, VecDeque<RecordedEvent>>,
+        trigger: &str,
+        reason: &str,
+        at: DateTime<Utc>,
+    ) -> FlightRecording {
+        let cutoff = at - self.window();
+        let events = buffers.iter()
+            .map(|(symbol, buffer)| {
+                let recent: Vec<_> = buffer.iter().filter(|event| event.timestamp >= cutoff).cloned().collect();
+                (symbol.clone(), recent)
+            })
+            .filter(|(_, recent)| !recent.is_empty())
+            .collect();
+
+        FlightRecording {
+            trigger: trigger.to_string(),
+            reason: reason.to_string(),
+            dumped_at: at,
+            window_secs: self.config.window_secs,
+            events,
+        }
+    }
+
+    /// Write the current window to disk and return the file path.
+    /// Fails with `Throttled` when another dump was taken less than
+    /// `min_dump_interval_secs` ago.
+    pub fn dump(&self, trigger: &str, reason: &str) -> Result<PathBuf, FlightRecorderError> {
+        let now = Utc::now();
+        self.claim_dump_slot(now)?;
+        let recording = self.recording(trigger, reason, now);
+        self.write(&recording)
+    }
+
+    /// Dump from a panic hook: never blocks, so a panic raised while the
+    /// buffers were locked skips the dump instead of deadlocking
+    fn dump_on_panic(&self, reason: &str) -> Result<PathBuf, FlightRecorderError> {
+        let now = Utc::now();
+        let recording = match self.buffers.try_lock() {
+            Ok(buffers) => self.recording_from(&buffers, "panic", reason, now),
+            Err(TryLockError::Poisoned(poisoned)) => self.recording_from(&poisoned.into_inner(), "panic", reason, now),
+            Err(TryLockError::WouldBlock) => {
+                return Err(FlightRecorderError::Throttled("event buffers are locked".to_string()));
+            }
+        };
+        self.write(&recording)
+    }
+
+    fn claim_dump_slot(&self, now: DateTime<Utc>) -> Result<(), FlightRecorderError> {
+        let mut last_dump = lock(&self.last_dump);
+        if let Some(last) = *last_dump {
+            if now - last < Duration::seconds(self.config.min_dump_interval_secs as i64) {
+                return Err(FlightRecorderError::Throttled(format!("last dump was at {}", last)));
+            }
+        }
+        *last_dump = Some(now);
+        Ok(())
+    }
+
+    fn write(&self, recording: &FlightRecording) -> Result<PathBuf, FlightRecorderError> {
+        std::fs::create_dir_all(&self.config.dump_dir)?;
+        let path = dump_path(&self.config.dump_dir, &recording.trigger, recording.dumped_at);
+        std::fs::write(&path, serde_json::to_vec_pretty(recording)?)?;
+
+        info!(
+            "Flight recorder dumped {} events to {} ({}: {})",
+            recording.event_count(),
+            path.display(),
+            recording.trigger,
+            recording.reason
+        );
+        Ok(path)
+    }
+
+    /// Dump if any anomaly is at or above the critical severity
+    pub fn check_anomalies(&self, anomalies: &[MarketAnomaly]) -> Option<PathBuf> {
+        let critical = anomalies.iter()
+            .filter(|anomaly| anomaly.severity >= self.config.critical_anomaly_severity)
+            .max_by(|a, b| a.severity.total_cmp(&b.severity))?;
+
+        let reason = format!("{:?} on {}: {}", critical.anomaly_type, critical.symbol, critical.description);
+        self.dump_logged("critical_anomaly", &reason)
+    }
+
+    /// Dump, logging instead of returning failures; for callers that are themselves failing
+    pub fn dump_logged(&self, trigger: &str, reason: &str) -> Option<PathBuf> {
+        match self.dump(trigger, reason) {
+            Ok(path) => Some(path),
+            Err(FlightRecorderError::Throttled(msg)) => {
+                info!("Flight recorder dump for {} skipped: {}", trigger, msg);
+                None
+            }
+            Err(e) => {
+                error!("Flight recorder dump for {} failed: {}", trigger, e);
+                None
+            }
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn to_value<T: Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap_or(serde_json::Value::Null)
+}
+
+/// Dump file path, e.g. `flight-kill_switch-20250301T120000.123Z.json`
+fn dump_path(dir: &Path, trigger: &str, at: DateTime<Utc>) -> PathBuf {
+    dir.join(format!("flight-{}-{}.json", trigger, at.format("%Y%m%dT%H%M%S%.3fZ")))
+}
+
+/// Dump the recorder when the process panics, then run the previous panic hook
+pub fn install_panic_hook(recorder: Arc<FlightRecorder>) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if let Err(e) = recorder.dump_on_panic(&info.to_string()) {
+            eprintln!("Flight recorder dump on panic failed: {}", e);
+        }
+        previous(info);
+    }));
+}
+
+/// Kill switch that dumps the flight recorder whenever the wrapped switch is triggered
+pub struct FlightRecorderKillSwitch {
+    /// Kill switch that stops the agent
+    inner: Arc<dyn KillSwitch>,
+    /// Flight recorder
+    recorder: Arc<FlightRecorder>,
+}
+
+impl FlightRecorderKillSwitch {
+    /// Wrap a kill switch
+    pub fn new(inner: Arc<dyn KillSwitch>, recorder: Arc<FlightRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+#[async_trait]
+impl KillSwitch for FlightRecorderKillSwitch {
+    async fn trigger(&self, agent_id: &str, reason: &str, message: &str) -> bool {
+        // Dump first so the recording ends where the failure was noticed
+        self.recorder.dump_logged("kill_switch", &format!("{} ({}): {}", agent_id, reason, message));
+        self.inner.trigger(agent_id, reason, message).await
+    }
+}
+
+/// Create a new flight recorder
+pub fn create_flight_recorder(config: FlightRecorderConfig) -> Arc<FlightRecorder> {
+    Arc::new(FlightRecorder::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RecordingKillSwitch;
+
+    #[async_trait]
+    impl KillSwitch for RecordingKillSwitch {
+        async fn trigger(&self, _agent_id: &str, _reason: &str, _message: &str) -> bool {
+            true
+        }
+    }
+
+    fn tick(symbol: &str, price: f64, at: DateTime<Utc>) -> MarketTick {
+        MarketTick {
+            symbol: symbol.to_string(),
+            timestamp: at,
+            price,
+            volume: 1.0,
+            bid: None,
+            ask: None,
+            fields: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_window_eviction_and_kill_switch_dump() {
+        let dir = std::env::temp_dir().join(format!("noderr-flight-{}", uuid::Uuid::new_v4()));
+        let recorder = create_flight_recorder(FlightRecorderConfig {
+            window_secs: 5,
+            max_events_per_symbol: 3,
+            dump_dir: dir.clone(),
+            ..FlightRecorderConfig::default()
+        });
+
+        let now = Utc::now();
+        recorder.record_tick(&tick("ETH/USD", 1.0, now - Duration::seconds(30)));
+        for i in 0..4 {
+            recorder.record_tick(&tick("BTC/USD", 100.0 + i as f64, now - Duration::seconds(3 - i)));
+        }
+        recorder.record_trading_event(&TradingEvent::OrderState {
+            order_id: "ord-1".to_string(),
+            symbol: "ETH/USD".to_string(),
+            status: crate::execution::ExecutionStatus::Cancelled,
+            venue: None,
+            reason: None,
+            timestamp: now,
+        });
+
+        let kill_switch = FlightRecorderKillSwitch::new(Arc::new(RecordingKillSwitch), recorder.clone());
+        assert!(kill_switch.trigger("agent-1", "drawdown_breach", "max drawdown exceeded").await);
+
+        let mut dumps: Vec<_> = std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+        assert_eq!(dumps.len(), 1);
+        let path = dumps.pop().unwrap();
+        assert!(path.file_name().unwrap().to_str().unwrap().starts_with("flight-kill_switch-"));
+
+        let recording: FlightRecording = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(recording.trigger, "kill_switch");
+        // The cap keeps the newest three ticks; the stale ETH tick was evicted by the order event
+        let prices: Vec<_> = recording.events["BTC/USD"].iter().map(|e| e.data["price"].as_f64().unwrap()).collect();
+        assert_eq!(prices, vec![101.0, 102.0, 103.0]);
+        assert_eq!(recording.events["ETH/USD"].len(), 1);
+        assert_eq!(recording.events["ETH/USD"][0].kind, RecordedEventKind::Order);
+
+        // A second failure straight after is folded into the first dump
+        assert!(matches!(recorder.dump("panic", "boom"), Err(FlightRecorderError::Throttled(_))));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -5,7 +5,7 @@ use tokio::runtime::Runtime;
 use async_trait::async_trait;
 use std::time::{Duration, Instant};
 
-use noderr_core::order_router::{SmartOrderRouter, Order, OrderSide};
+use noderr_core::order_router::{SmartOrderRouter, Order, OrderSide, TimeInForce};
 use noderr_core::risk_calc::{RiskCalculator, RiskConfig, PositionExposure};
 use noderr_core::trade_sizer::{DynamicTradeSizer, TradeSizerConfig};
 use noderr_core::execution_strategy::{
@@ -153,6 +153,7 @@ fn bench_full_execution_chain(c: &mut Criterion) {
         webhook_url: None,
         exempt_strategies: Default::default(),
         fast_risk_mode: true, // Use fast mode for benchmarks
+        ..Default::default()
     };
     let risk_calculator = RiskCalculator::new(risk_config, 100000.0);
     
@@ -166,6 +167,8 @@ fn bench_full_execution_chain(c: &mut Criterion) {
         min_order_size_for_vwap: 5000.0,
         twap_config: None,
         vwap_config: None,
+        iceberg_config: None,
+        pov_config: None,
         max_execution_time_ms: 300000,
         symbol_strategy_map: HashMap::new(),
     };
@@ -195,6 +198,9 @@ fn bench_full_execution_chain(c: &mut Criterion) {
             id: id.to_string(),
             max_slippage: Some(0.01),
             max_retries: Some(1),
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
             additional_params: HashMap::new(),
         }
     }
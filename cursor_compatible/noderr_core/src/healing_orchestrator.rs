@@ -21,6 +21,51 @@ pub trait HealingOrchestrator: Send + Sync {
     fn as_any(&self) -> &dyn Any where Self: 'static;
 }
 
+/// Target the healing orchestrator quarantines misbehaving strategies through.
+///
+/// Implementations must take the strategy's dependents out of service before
+/// the strategy itself, and restore them when the quarantine is released.
+#[async_trait]
+pub trait StrategyQuarantine: Send + Sync {
+    /// Quarantine a strategy, returning every strategy that was disabled
+    async fn quarantine(&self, strategy_id: &str, reason: &str) -> Result<Vec<String>>;
+    
+    /// Release a quarantined strategy, returning every strategy that was re-enabled
+    async fn release(&self, strategy_id: &str) -> Result<Vec<String>>;
+}
+
+/// Kind of strategy incident raised by the executor
+#[derive(Debug, Clone, PartialEq)]
+pub enum IncidentKind {
+    /// Strategy evaluation panicked
+    Panic,
+    
+    /// Strategy exhibited runaway behaviour such as flip-flopping or order/cancel loops
+    Runaway,
+}
+
+/// Incident raised when the executor quarantines a strategy
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealingIncident {
+    /// Strategy/agent the incident concerns
+    pub strategy_id: String,
+    
+    /// Kind of incident
+    pub kind: IncidentKind,
+    
+    /// Details, e.g. the panic message
+    pub message: String,
+    
+    /// Strategies taken out of service as a result
+    pub quarantined: Vec<String>,
+    
+    /// Timestamp when the incident occurred
+    pub occurred_at: i64,
+    
+    /// Evidence that triggered the incident, if any
+    pub evidence: Option<serde_json::Value>,
+}
+
 /// Current healing status for a strategy/agent
 #[derive(Debug, Clone, PartialEq)]
 pub enum HealingStatus {
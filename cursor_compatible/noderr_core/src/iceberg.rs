@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Iceberg execution
+//!
+//! Works a parent order as a sequence of small visible clips so the book
+//! never shows the full size. Each clip is sent through the smart order
+//! router; once it is done the remainder is replenished with a new clip
+//! after a short delay. Clip sizes and delays are randomized so the refill
+//! pattern is harder to spot, and can be seeded to make backtests
+//! reproducible.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use tracing::{debug, info, warn};
+
+use crate::execution::ExecutionResult;
+use crate::execution_schedule::fnv1a;
+use crate::execution_strategy::{
+    AlgoModification, ExecutionAlgorithm, ExecutionStrategy, ExecutionStrategyDetails, ExecutionStrategyError,
+    IcebergConfig,
+};
+use crate::order_lineage::child_order;
+use crate::order_router::{Order, SmartOrderRouter};
+
+/// Order parameter marking a child order as an iceberg clip
+pub const ICEBERG_CLIP_PARAM: &str = "icebergClip";
+
+/// How often a paused parent checks whether it was resumed
+const PAUSE_POLL: Duration = Duration::from_millis(250);
+
+/// Remaining quantity below which a parent counts as filled
+const QUANTITY_EPSILON: f64 = 1e-9;
+
+/// Operator controls for a parent being worked
+#[derive(Debug, Clone, Default)]
+struct IcebergControl {
+    paused: bool,
+    cancelled: bool,
+    limit_price: Option<f64>,
+}
+
+/// Executes orders as iceberg clips
+pub struct IcebergExecutor {
+    /// Configuration
+    config: IcebergConfig,
+    /// Router the clips are sent through
+    router: Arc<SmartOrderRouter>,
+    /// Controls of parents being worked, by parent order ID
+    controls: Mutex<HashMap<String, IcebergControl>>,
+}
+
+impl IcebergExecutor {
+    /// Create a new iceberg executor
+    pub fn new(config: IcebergConfig, router: Arc<SmartOrderRouter>) -> Self {
+        Self {
+            config,
+            router,
+            controls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Configuration in use
+    pub fn config(&self) -> &IcebergConfig {
+        &self.config
+    }
+
+    /// Random source for one parent; seeded runs depend only on the seed and parent ID
+    pub fn rng_for(&self, parent_id: &str) -> StdRng {
+        match self.config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed ^ fnv1a(parent_id)),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    /// Size of the next visible clip for a parent of `total` with `remaining` left
+    pub fn display_quantity(&self, rng: &mut StdRng, total: f64, remaining: f64) -> f64 {
+        let base = total * self.config.display_fraction.clamp(0.0, 1.0);
+        let variance = self.config.display_variance_pct.clamp(0.0, 1.0);
+        let clip = (base * (1.0 + rng.gen_range(-1.0..=1.0) * variance)).max(self.config.min_display_quantity);
+
+        // Never leave a remainder too small to post on its own
+        if clip <= 0.0 || remaining - clip < self.config.min_display_quantity.max(QUANTITY_EPSILON) {
+            remaining
+        } else {
+            clip
+        }
+    }
+
+    /// Delay before posting the next clip
+    pub fn replenish_delay(&self, rng: &mut StdRng) -> Duration {
+        let jitter = match self.config.replenish_jitter_ms {
+            0 => 0,
+            max => rng.gen_range(0..=max),
+        };
+        Duration::from_millis(self.config.replenish_delay_ms + jitter)
+    }
+
+    fn control(&self, order_id: &str) -> IcebergControl {
+        self.controls.lock().unwrap().get(order_id).cloned().unwrap_or_default()
+    }
+
+    fn update_control(&self, order_id: &str, update: impl FnOnce(&mut IcebergControl)) -> Result<(), ExecutionStrategyError> {
+        let mut controls = self.controls.lock().unwrap();
+        let control = controls.get_mut(order_id).ok_or_else(|| {
+            ExecutionStrategyError::ExecutionFailed(format!("No iceberg execution found for order ID: {}", order_id))
+        })?;
+        update(control);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ExecutionStrategy for IcebergExecutor {
+    async fn execute(
+        &self,
+        order: Order,
+        callback: Arc<dyn Fn(ExecutionResult) + Send + Sync>,
+    ) -> Result<(), ExecutionStrategyError> {
+        if !(order.amount > 0.0 && order.amount.is_finite()) {
+            return Err(ExecutionStrategyError::InvalidParameters(format!(
+                "iceberg order {} has amount {}", order.id, order.amount
+            )));
+        }
+
+        self.controls.lock().unwrap().insert(order.id.clone(), IcebergControl::default());
+        let mut rng = self.rng_for(&order.id);
+        let mut remaining = order.amount;
+        let mut failures = 0;
+        let mut clips = 0;
+
+        let outcome = loop {
+            let control = self.control(&order.id);
+            if control.cancelled {
+                info!("Iceberg {} cancelled with {} remaining", order.id, remaining);
+                break Ok(());
+            }
+            if remaining <= QUANTITY_EPSILON || clips >= self.config.max_clips {
+                break Ok(());
+            }
+            if control.paused {
+                tokio::time::sleep(PAUSE_POLL).await;
+                continue;
+            }
+
+            let display = self.display_quantity(&mut rng, order.amount, remaining);
+            let mut clip = child_order(&order, clips, display);
+            clip.additional_params.insert(ICEBERG_CLIP_PARAM.to_string(), serde_json::Value::Bool(true));
+            if let Some(limit_price) = control.limit_price {
+                clip.price = limit_price;
+            }
+            clips += 1;
+
+            match self.router.execute_order(clip).await {
+                Ok(result) => {
+                    failures = 0;
+                    let filled = result.executed_quantity.unwrap_or(0.0).abs().min(remaining);
+                    remaining -= filled;
+                    debug!("Iceberg {} clip {} filled {} of {}, {} remaining", order.id, clips, filled, display, remaining);
+                    callback(result);
+                }
+                Err(e) => {
+                    failures += 1;
+                    warn!("Iceberg {} clip {} failed ({} in a row): {}", order.id, clips, failures, e);
+                    if failures >= self.config.max_consecutive_failures.max(1) {
+                        break Err(ExecutionStrategyError::ExecutionFailed(format!(
+                            "iceberg {} abandoned after {} consecutive clip failures: {}", order.id, failures, e
+                        )));
+                    }
+                }
+            }
+
+            if remaining > QUANTITY_EPSILON {
+                tokio::time::sleep(self.replenish_delay(&mut rng)).await;
+            }
+        };
+
+        let cancelled = self.controls.lock().unwrap().remove(&order.id).is_some_and(|control| control.cancelled);
+        outcome?;
+
+        let filled = order.amount - remaining;
+        if !cancelled && filled < order.amount * self.config.min_execution_pct {
+            return Err(ExecutionStrategyError::ExecutionFailed(format!(
+                "iceberg {} filled {} of {} after {} clips", order.id, filled, order.amount, clips
+            )));
+        }
+        Ok(())
+    }
+
+    async fn estimate_impact(&self, order: &Order) -> Result<f64, ExecutionStrategyError> {
+        // Impact scales with the square root of the size shown at once
+        let shown = self.config.display_fraction.clamp(0.0, 1.0);
+        Ok(0.001 * shown.sqrt() * order.amount.abs().max(1.0).log10().max(1.0))
+    }
+
+    async fn get_cost_estimate(&self, order: &Order) -> Result<f64, ExecutionStrategyError> {
+        Ok(order.amount * self.estimate_impact(order).await?)
+    }
+
+    fn get_details(&self) -> ExecutionStrategyDetails {
+        let parameters = match serde_json::to_value(&self.config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        };
+        ExecutionStrategyDetails {
+            strategy_type: ExecutionAlgorithm::Iceberg,
+            name: "Iceberg".to_string(),
+            description: "Works the order as randomized visible clips, replenished as they fill".to_string(),
+            parameters,
+        }
+    }
+
+    async fn cancel(&self) -> Result<(), ExecutionStrategyError> {
+        for control in self.controls.lock().unwrap().values_mut() {
+            control.cancelled = true;
+        }
+        Ok(())
+    }
+
+    async fn pause(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+        self.update_control(order_id, |control| control.paused = true)
+    }
+
+    async fn resume(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+        self.update_control(order_id, |control| control.paused = false)
+    }
+
+    async fn modify(&self, order_id: &str, modification: &AlgoModification) -> Result<(), ExecutionStrategyError> {
+        if modification.limit_price.is_none() {
+            return Err(ExecutionStrategyError::UnsupportedStrategy(format!(
+                "Iceberg only supports changing the limit price of {}", order_id
+            )));
+        }
+        self.update_control(order_id, |control| control.limit_price = modification.limit_price)
+    }
+}
+
+/// Create an iceberg executor
+pub fn create_iceberg_executor(config: IcebergConfig, router: Arc<SmartOrderRouter>) -> Arc<IcebergExecutor> {
+    Arc::new(IcebergExecutor::new(config, router))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clips_are_randomized_within_bounds_and_cover_the_parent() {
+        let config = IcebergConfig {
+            display_fraction: 0.1,
+            display_variance_pct: 0.3,
+            min_display_quantity: 2.0,
+            seed: Some(7),
+            ..IcebergConfig::default()
+        };
+        let executor = IcebergExecutor::new(config, Arc::new(SmartOrderRouter::new()));
+
+        let clips_for = |parent_id: &str| {
+            let mut rng = executor.rng_for(parent_id);
+            let mut remaining = 100.0;
+            let mut clips = Vec::new();
+            while remaining > QUANTITY_EPSILON {
+                let clip = executor.display_quantity(&mut rng, 100.0, remaining);
+                remaining -= clip;
+                clips.push(clip);
+            }
+            clips
+        };
+
+        let clips = clips_for("parent-1");
+        assert_eq!(clips, clips_for("parent-1"));
+        assert_ne!(clips, clips_for("parent-2"));
+        assert!((clips.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+
+        // Every clip but the last is within the variance band; the last absorbs any stub
+        let (last, body) = clips.split_last().unwrap();
+        assert!(body.iter().all(|clip| (7.0..=13.0).contains(clip)));
+        assert!(body.windows(2).any(|pair| pair[0] != pair[1]));
+        assert!(*last >= 2.0 && *last <= 13.0 + 2.0);
+
+        let mut rng = executor.rng_for("parent-1");
+        let delay = executor.replenish_delay(&mut rng);
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_millis(2000));
+    }
+}
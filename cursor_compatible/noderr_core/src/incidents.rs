@@ -0,0 +1,582 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Incident records for critical operational events
+//!
+//! Kill-switch triggers, reconciliation breaks, venue outages and strategy
+//! quarantines each open an incident automatically. An incident carries a
+//! timeline, the evidence captured when it was raised (signal reason
+//! chains, telemetry snapshots) and a status that operators move from open
+//! to closed. While an incident is active, repeats of the same event on
+//! the same subject are folded into it instead of opening another one.
+//! Closed incidents export as a Markdown postmortem.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use crate::drawdown_monitor::KillSwitch;
+use crate::healing_orchestrator::{HealingIncident, IncidentKind};
+use crate::ids::{new_id, IdKind};
+use crate::strategy::Signal;
+
+/// Errors that can occur while managing incidents
+#[derive(Debug, Error)]
+pub enum IncidentError {
+    #[error("Incident not found: {0}")]
+    NotFound(String),
+
+    #[error("Incident {id} cannot move from {from:?} to {to:?}")]
+    InvalidTransition { id: String, from: IncidentStatus, to: IncidentStatus },
+}
+
+/// Event that opened an incident
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentTrigger {
+    /// A kill switch stopped an agent
+    KillSwitch,
+    /// Internal and venue records of orders, positions or balances disagree
+    ReconciliationBreak,
+    /// A venue stopped answering or accepting orders
+    VenueOutage,
+    /// A strategy was quarantined for runaway behaviour or a panic
+    StrategyQuarantine,
+}
+
+impl IncidentTrigger {
+    /// Severity used when the configuration does not override it
+    pub fn default_severity(&self) -> IncidentSeverity {
+        match self {
+            IncidentTrigger::KillSwitch | IncidentTrigger::ReconciliationBreak => IncidentSeverity::Critical,
+            IncidentTrigger::VenueOutage | IncidentTrigger::StrategyQuarantine => IncidentSeverity::Major,
+        }
+    }
+
+    /// Human-readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            IncidentTrigger::KillSwitch => "kill switch",
+            IncidentTrigger::ReconciliationBreak => "reconciliation break",
+            IncidentTrigger::VenueOutage => "venue outage",
+            IncidentTrigger::StrategyQuarantine => "strategy quarantine",
+        }
+    }
+}
+
+/// Incident severity, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentSeverity {
+    Critical,
+    Major,
+    Minor,
+}
+
+/// Status workflow of an incident
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentStatus {
+    /// Raised and not yet looked at
+    Open,
+    /// An operator is on it
+    Acknowledged,
+    /// Impact contained, cause not yet fixed
+    Mitigated,
+    /// Cause fixed; postmortem pending
+    Resolved,
+    /// Postmortem done; no further changes
+    Closed,
+}
+
+impl IncidentStatus {
+    /// Whether the incident still needs attention
+    pub fn is_active(&self) -> bool {
+        !matches!(self, IncidentStatus::Resolved | IncidentStatus::Closed)
+    }
+
+    /// Whether the workflow allows moving to `next`
+    ///
+    /// Mitigated and resolved incidents may be reopened; closed ones are final.
+    pub fn can_transition_to(&self, next: IncidentStatus) -> bool {
+        use IncidentStatus::*;
+        matches!(
+            (self, next),
+            (Open, Acknowledged | Mitigated | Resolved)
+                | (Acknowledged, Mitigated | Resolved)
+                | (Mitigated, Resolved | Open)
+                | (Resolved, Closed | Open)
+        )
+    }
+}
+
+/// Kind of evidence attached to an incident
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceKind {
+    /// Decisions recorded on a signal
+    ReasonChain,
+    /// Telemetry or state captured when the event happened
+    TelemetrySnapshot,
+    /// Free-form operator note
+    Note,
+}
+
+/// Evidence linked to an incident
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentEvidence {
+    /// Kind of evidence
+    pub kind: EvidenceKind,
+    /// Short label, e.g. the telemetry event name
+    pub label: String,
+    /// Captured data
+    pub data: serde_json::Value,
+    /// When the evidence was captured
+    pub captured_at: DateTime<Utc>,
+}
+
+impl IncidentEvidence {
+    /// Reason chain of a signal
+    pub fn reason_chain(signal: &Signal) -> Self {
+        Self {
+            kind: EvidenceKind::ReasonChain,
+            label: format!("signal {} ({})", signal.id, signal.strategy_id),
+            data: serde_json::json!({
+                "signal_id": signal.id,
+                "strategy_id": signal.strategy_id,
+                "symbol": signal.symbol,
+                "reasons": signal.reason_chain,
+            }),
+            captured_at: signal.timestamp,
+        }
+    }
+
+    /// Snapshot of telemetry or state
+    pub fn telemetry_snapshot(label: impl Into<String>, data: serde_json::Value, captured_at: DateTime<Utc>) -> Self {
+        Self { kind: EvidenceKind::TelemetrySnapshot, label: label.into(), data, captured_at }
+    }
+
+    /// Operator note
+    pub fn note(label: impl Into<String>, text: &str, captured_at: DateTime<Utc>) -> Self {
+        Self { kind: EvidenceKind::Note, label: label.into(), data: serde_json::Value::String(text.to_string()), captured_at }
+    }
+}
+
+/// One entry in an incident's timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    /// When it happened
+    pub at: DateTime<Utc>,
+    /// Who recorded it; `system` for automatic entries
+    pub actor: String,
+    /// What happened
+    pub message: String,
+    /// Status the incident moved to, if the entry is a transition
+    pub status: Option<IncidentStatus>,
+}
+
+/// An incident record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Incident {
+    /// Incident ID (`inc_` prefix)
+    pub id: String,
+    /// Event that opened the incident
+    pub trigger: IncidentTrigger,
+    /// Severity
+    pub severity: IncidentSeverity,
+    /// Current status
+    pub status: IncidentStatus,
+    /// What the incident concerns: agent, venue, strategy or account
+    pub subject: String,
+    /// One-line title
+    pub title: String,
+    /// When the incident was opened
+    pub opened_at: DateTime<Utc>,
+    /// When the incident last changed
+    pub updated_at: DateTime<Utc>,
+    /// When the incident was last resolved
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Times the triggering event was reported while the incident was active
+    pub occurrences: u32,
+    /// Timeline, oldest first
+    pub timeline: Vec<TimelineEntry>,
+    /// Linked evidence, oldest first
+    pub evidence: Vec<IncidentEvidence>,
+}
+
+impl Incident {
+    /// Markdown postmortem of the incident
+    pub fn postmortem(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# Incident {}: {}", self.id, self.title);
+        let _ = writeln!(out);
+        let _ = writeln!(out, "- Trigger: {}", self.trigger.name());
+        let _ = writeln!(out, "- Severity: {:?}", self.severity);
+        let _ = writeln!(out, "- Status: {:?}", self.status);
+        let _ = writeln!(out, "- Subject: {}", self.subject);
+        let _ = writeln!(out, "- Opened: {}", self.opened_at.to_rfc3339());
+        match self.resolved_at {
+            Some(resolved_at) => {
+                let minutes = (resolved_at - self.opened_at).num_minutes();
+                let _ = writeln!(out, "- Resolved: {} ({} min)", resolved_at.to_rfc3339(), minutes);
+            }
+            None => {
+                let _ = writeln!(out, "- Resolved: not yet");
+            }
+        }
+        let _ = writeln!(out, "- Occurrences: {}", self.occurrences);
+
+        let _ = writeln!(out);
+        let _ = writeln!(out, "## Timeline");
+        let _ = writeln!(out);
+        for entry in &self.timeline {
+            let _ = writeln!(out, "- {} [{}] {}", entry.at.to_rfc3339(), entry.actor, entry.message);
+        }
+
+        let _ = writeln!(out);
+        let _ = writeln!(out, "## Evidence");
+        if self.evidence.is_empty() {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "None recorded.");
+        }
+        for evidence in &self.evidence {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "### {} ({:?}, {})", evidence.label, evidence.kind, evidence.captured_at.to_rfc3339());
+            let _ = writeln!(out);
+            let _ = writeln!(out, "```json");
+            let _ = writeln!(out, "{}", serde_json::to_string_pretty(&evidence.data).unwrap_or_default());
+            let _ = writeln!(out, "```");
+        }
+        out
+    }
+}
+
+/// Incident manager configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncidentConfig {
+    /// Severity overrides per trigger
+    pub severity_overrides: HashMap<IncidentTrigger, IncidentSeverity>,
+    /// Most evidence items kept per incident; older items are dropped first
+    pub max_evidence: usize,
+}
+
+impl Default for IncidentConfig {
+    fn default() -> Self {
+        Self {
+            severity_overrides: HashMap::new(),
+            max_evidence: 100,
+        }
+    }
+}
+
+/// Opens and tracks incidents
+pub struct IncidentManager {
+    /// Configuration
+    config: IncidentConfig,
+    /// Incidents by ID
+    incidents: RwLock<HashMap<String, Incident>>,
+}
+
+impl IncidentManager {
+    /// Create a new incident manager
+    pub fn new(config: IncidentConfig) -> Self {
+        Self {
+            config,
+            incidents: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Open an incident, or fold the event into the active incident for the same trigger and subject.
+    /// Returns the incident ID.
+    pub async fn report(
+        &self,
+        trigger: IncidentTrigger,
+        subject: &str,
+        message: &str,
+        evidence: Vec<IncidentEvidence>,
+        at: DateTime<Utc>,
+    ) -> String {
+        let mut incidents = self.incidents.write().await;
+
+        if let Some(incident) = incidents.values_mut()
+            .find(|i| i.trigger == trigger && i.subject == subject && i.status.is_active())
+        {
+            incident.occurrences += 1;
+            incident.updated_at = at;
+            incident.timeline.push(TimelineEntry {
+                at,
+                actor: "system".to_string(),
+                message: format!("Recurred: {}", message),
+                status: None,
+            });
+            push_evidence(incident, evidence, self.config.max_evidence);
+            return incident.id.clone();
+        }
+
+        let severity = self.config.severity_overrides.get(&trigger).copied()
+            .unwrap_or_else(|| trigger.default_severity());
+        let mut incident = Incident {
+            id: new_id(IdKind::Incident),
+            trigger,
+            severity,
+            status: IncidentStatus::Open,
+            subject: subject.to_string(),
+            title: format!("{} on {}", trigger.name(), subject),
+            opened_at: at,
+            updated_at: at,
+            resolved_at: None,
+            occurrences: 1,
+            timeline: vec![TimelineEntry {
+                at,
+                actor: "system".to_string(),
+                message: message.to_string(),
+                status: Some(IncidentStatus::Open),
+            }],
+            evidence: Vec::new(),
+        };
+        push_evidence(&mut incident, evidence, self.config.max_evidence);
+
+        warn!("Incident {} opened ({:?}): {}: {}", incident.id, severity, incident.title, message);
+        let id = incident.id.clone();
+        incidents.insert(id.clone(), incident);
+        id
+    }
+
+    /// Open an incident for a kill-switch trigger
+    pub async fn report_kill_switch(&self, agent_id: &str, reason: &str, message: &str) -> String {
+        let now = Utc::now();
+        let evidence = IncidentEvidence::telemetry_snapshot(
+            "kill_switch",
+            serde_json::json!({ "agent_id": agent_id, "reason": reason, "message": message }),
+            now,
+        );
+        self.report(IncidentTrigger::KillSwitch, agent_id, &format!("{}: {}", reason, message), vec![evidence], now).await
+    }
+
+    /// Open an incident for a reconciliation break on an account, venue or symbol
+    pub async fn report_reconciliation_break(&self, scope: &str, message: &str, details: serde_json::Value) -> String {
+        let now = Utc::now();
+        let evidence = IncidentEvidence::telemetry_snapshot("reconciliation", details, now);
+        self.report(IncidentTrigger::ReconciliationBreak, scope, message, vec![evidence], now).await
+    }
+
+    /// Open an incident for a venue outage
+    pub async fn report_venue_outage(&self, venue: &str, message: &str, details: serde_json::Value) -> String {
+        let now = Utc::now();
+        let evidence = IncidentEvidence::telemetry_snapshot("venue_health", details, now);
+        self.report(IncidentTrigger::VenueOutage, venue, message, vec![evidence], now).await
+    }
+
+    /// Open an incident for a strategy the executor quarantined
+    pub async fn report_quarantine(&self, healing: &HealingIncident, mut evidence: Vec<IncidentEvidence>) -> String {
+        let at = Utc.timestamp_millis_opt(healing.occurred_at).single().unwrap_or_else(Utc::now);
+        if let Some(data) = &healing.evidence {
+            evidence.insert(0, IncidentEvidence::telemetry_snapshot("quarantine_evidence", data.clone(), at));
+        }
+        let cause = match healing.kind {
+            IncidentKind::Panic => "panicked",
+            IncidentKind::Runaway => "runaway",
+        };
+        let message = format!(
+            "Quarantined ({}): {}; disabled {:?}",
+            cause, healing.message, healing.quarantined
+        );
+        self.report(IncidentTrigger::StrategyQuarantine, &healing.strategy_id, &message, evidence, at).await
+    }
+
+    /// Move an incident through the status workflow
+    pub async fn transition(
+        &self,
+        id: &str,
+        status: IncidentStatus,
+        actor: &str,
+        note: Option<&str>,
+    ) -> Result<Incident, IncidentError> {
+        let mut incidents = self.incidents.write().await;
+        let incident = incidents.get_mut(id).ok_or_else(|| IncidentError::NotFound(id.to_string()))?;
+        if !incident.status.can_transition_to(status) {
+            return Err(IncidentError::InvalidTransition { id: id.to_string(), from: incident.status, to: status });
+        }
+
+        let now = Utc::now();
+        let mut message = format!("{:?} -> {:?}", incident.status, status);
+        if let Some(note) = note {
+            message.push_str(": ");
+            message.push_str(note);
+        }
+        incident.timeline.push(TimelineEntry { at: now, actor: actor.to_string(), message, status: Some(status) });
+        match status {
+            IncidentStatus::Resolved => incident.resolved_at = Some(now),
+            IncidentStatus::Open => incident.resolved_at = None,
+            _ => {}
+        }
+        incident.status = status;
+        incident.updated_at = now;
+
+        info!("Incident {} moved to {:?} by {}", id, status, actor);
+        Ok(incident.clone())
+    }
+
+    /// Add a timeline note
+    pub async fn add_note(&self, id: &str, actor: &str, message: &str) -> Result<(), IncidentError> {
+        let mut incidents = self.incidents.write().await;
+        let incident = incidents.get_mut(id).ok_or_else(|| IncidentError::NotFound(id.to_string()))?;
+        let now = Utc::now();
+        incident.timeline.push(TimelineEntry { at: now, actor: actor.to_string(), message: message.to_string(), status: None });
+        incident.updated_at = now;
+        Ok(())
+    }
+
+    /// Link more evidence to an incident
+    pub async fn add_evidence(&self, id: &str, evidence: IncidentEvidence) -> Result<(), IncidentError> {
+        let mut incidents = self.incidents.write().await;
+        let incident = incidents.get_mut(id).ok_or_else(|| IncidentError::NotFound(id.to_string()))?;
+        incident.updated_at = evidence.captured_at.max(incident.updated_at);
+        push_evidence(incident, vec![evidence], self.config.max_evidence);
+        Ok(())
+    }
+
+    /// Incident by ID
+    pub async fn get(&self, id: &str) -> Option<Incident> {
+        self.incidents.read().await.get(id).cloned()
+    }
+
+    /// Incidents, newest first; `active_only` skips resolved and closed ones
+    pub async fn list(&self, active_only: bool) -> Vec<Incident> {
+        let mut incidents: Vec<Incident> = self.incidents.read().await
+            .values()
+            .filter(|i| !active_only || i.status.is_active())
+            .cloned()
+            .collect();
+        incidents.sort_by(|a, b| b.opened_at.cmp(&a.opened_at));
+        incidents
+    }
+
+    /// Markdown postmortem of an incident
+    pub async fn export_postmortem(&self, id: &str) -> Result<String, IncidentError> {
+        self.get(id).await
+            .map(|incident| incident.postmortem())
+            .ok_or_else(|| IncidentError::NotFound(id.to_string()))
+    }
+}
+
+fn push_evidence(incident: &mut Incident, evidence: Vec<IncidentEvidence>, max_evidence: usize) {
+    incident.evidence.extend(evidence);
+    if incident.evidence.len() > max_evidence {
+        let excess = incident.evidence.len() - max_evidence;
+        incident.evidence.drain(..excess);
+    }
+}
+
+/// Kill switch that opens an incident whenever the wrapped switch is triggered
+pub struct IncidentKillSwitch {
+    /// Kill switch that stops the agent
+    inner: Arc<dyn KillSwitch>,
+    /// Incident manager
+    incidents: Arc<IncidentManager>,
+}
+
+impl IncidentKillSwitch {
+    /// Wrap a kill switch
+    pub fn new(inner: Arc<dyn KillSwitch>, incidents: Arc<IncidentManager>) -> Self {
+        Self { inner, incidents }
+    }
+}
+
+#[async_trait]
+impl KillSwitch for IncidentKillSwitch {
+    async fn trigger(&self, agent_id: &str, reason: &str, message: &str) -> bool {
+        let stopped = self.inner.trigger(agent_id, reason, message).await;
+        let id = self.incidents.report_kill_switch(agent_id, reason, message).await;
+        if !stopped {
+            let _ = self.incidents.add_note(&id, "system", "Kill switch reported failure to stop the agent").await;
+        }
+        stopped
+    }
+}
+
+/// Create a new incident manager
+pub fn create_incident_manager(config: IncidentConfig) -> Arc<IncidentManager> {
+    Arc::new(IncidentManager::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FailingKillSwitch;
+
+    #[async_trait]
+    impl KillSwitch for FailingKillSwitch {
+        async fn trigger(&self, _agent_id: &str, _reason: &str, _message: &str) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn test_incident_lifecycle() {
+        let incidents = create_incident_manager(IncidentConfig::default());
+        let kill_switch = IncidentKillSwitch::new(Arc::new(FailingKillSwitch), incidents.clone());
+
+        // Repeats fold into the active incident
+        assert!(!kill_switch.trigger("agent-1", "drawdown_breach", "drawdown 12%").await);
+        assert!(!kill_switch.trigger("agent-1", "drawdown_breach", "drawdown 14%").await);
+        let active = incidents.list(true).await;
+        assert_eq!(active.len(), 1);
+        let id = active[0].id.clone();
+        assert!(id.starts_with("inc_"));
+        assert_eq!(active[0].severity, IncidentSeverity::Critical);
+        assert_eq!(active[0].occurrences, 2);
+        assert_eq!(active[0].evidence.len(), 2);
+
+        // Workflow rejects skipping straight to closed
+        assert!(matches!(
+            incidents.transition(&id, IncidentStatus::Closed, "ops", None).await,
+            Err(IncidentError::InvalidTransition { .. })
+        ));
+        incidents.transition(&id, IncidentStatus::Acknowledged, "ops", None).await.unwrap();
+        let resolved = incidents.transition(&id, IncidentStatus::Resolved, "ops", Some("limits reset")).await.unwrap();
+        assert!(resolved.resolved_at.is_some());
+        assert!(incidents.list(true).await.is_empty());
+
+        // A new event after resolution opens a fresh incident
+        let healing = HealingIncident {
+            strategy_id: "strat-1".to_string(),
+            kind: IncidentKind::Runaway,
+            message: "flip-flopping".to_string(),
+            quarantined: vec!["strat-1".to_string()],
+            occurred_at: 1_700_000_000_000,
+            evidence: Some(serde_json::json!({ "flips": 6 })),
+        };
+        incidents.report_quarantine(&healing, Vec::new()).await;
+        incidents.report_kill_switch("agent-1", "drawdown_breach", "again").await;
+        assert_eq!(incidents.list(true).await.len(), 2);
+
+        let postmortem = incidents.export_postmortem(&id).await.unwrap();
+        assert!(postmortem.starts_with(&format!("# Incident {}: kill switch on agent-1", id)));
+        assert!(postmortem.contains("Kill switch reported failure to stop the agent"));
+        assert!(postmortem.contains("Acknowledged -> Resolved: limits reset"));
+        assert!(postmortem.contains("\"reason\": \"drawdown_breach\""));
+        assert!(matches!(incidents.export_postmortem("inc_missing").await, Err(IncidentError::NotFound(_))));
+    }
+}
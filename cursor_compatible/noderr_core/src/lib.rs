@@ -12,536 +12,907 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-pub mod strategy;
-pub mod market;
-pub mod risk;
-pub mod execution;
-pub mod telemetry;
-pub mod entropy;
-pub mod strategy_executor;
-pub mod trust_buffer;
-pub mod examples;
-pub mod storage;
-pub mod api;
-pub mod analytics;
-pub mod telemetry_streamer;
-pub mod websocket_manager;
-pub mod trust_score_engine;
+// Dependency-free computation kernels; these also build for wasm32
+pub mod analytics_math;
+pub mod volume_profile;
+pub mod allocation_constraints;
 pub mod simulation;
-pub mod trust_decay_service;
-pub mod correlation_engine;
-pub mod risk_allocation;
-pub mod drawdown;
-pub mod microstructure;
-pub mod market_regime;
-pub mod asset_allocator;
-pub mod execution_metrics;
-pub mod strategy_feedback;
-pub mod strategy_attribution;
-pub mod factor_analysis;
-pub mod redis;
-pub mod healing_orchestrator;
-pub mod agent_controller;
-pub mod trust_monitor;
-pub mod treasury_service;
-pub mod memory_service;
-pub mod meta;
-pub mod governance;
-// New latency-critical modules
-pub mod order_router;
-pub mod execution_strategy;
-pub mod risk_calc;
-pub mod trade_sizer;
-pub mod drawdown_monitor;
-pub mod venue_latency;
-pub mod shared_memory;
-pub mod orderbook;
-pub mod strategy_engine;
-pub mod market_data;
-pub mod position;
-// NAPI bindings
-#[cfg(feature = "napi")]
-pub mod bindings;
-pub mod cpu_affinity;
-pub mod market_data_soa;
-pub mod telemetry_enhanced;
-pub mod fast_risk_layer;
-
-// Re-export common types
-pub use market::MarketData;
-pub use strategy::{Strategy, Signal, EntropyConfig, EntropyInjector};
-pub use entropy::{DefaultEntropyInjector, EntropyInjectorFactory};
-pub use risk::{RiskManager, RiskError, RiskMetrics};
-pub use execution::{ExecutionService, ExecutionResult, ExecutionError, LatencyProfile, FeeInfo, ExecutionLog, ExecutionQualityScore, ExecutionOutcomeReason};
-pub use telemetry::TelemetryReporter;
-pub use strategy_executor::StrategyExecutor;
-pub use trust_buffer::{TrustBuffer, TrustScoreUpdate, TimeRange, TrustStatistics};
-pub use storage::{StrategyStorage, StorageConfig, StorageType, create_storage};
-pub use api::create_api_router;
-pub use analytics::{
-    Analytics, AnalyticsResult, AnalyticsError, create_analytics,
-    StrategyStorageAnalyticsAdapter, create_analytics_storage, setup_analytics,
-    PerformanceSummary, ExecutionStats, TrendLine, Anomaly, TimePeriod
-};
-pub use telemetry_streamer::{TelemetryStreamer, TelemetryStreamerConfig, create_telemetry_streamer};
-pub use websocket_manager::{WebSocketManager, WebSocketMessage, create_websocket_manager};
-pub use trust_score_engine::{
-    TrustScoreEngine, TrustScore, TrustScoreFeatures, TrustScoreConfig, 
-    TrustScoreWeights, TrustScoreHistory, TrustScoreError, TrustScoreResult,
-    create_trust_score_engine
-};
+
+// WebAssembly bindings for client-side what-if simulations
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub mod wasm;
+
+// C ABI for non-Rust execution systems
+#[cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+pub mod ffi;
+
 pub use simulation::trust_decay_simulator::{
-    DecaySimulationParams, SimulatedTrustScore, simulate_trust_score_decay, apply_recovery_events
-};
-pub use trust_decay_service::{
-    TrustDecayService, TrustDecayConfig, StrategyActivityStatus,
-    create_trust_decay_service
-};
-pub use correlation_engine::{
-    CorrelationEngine, CorrelationEngineConfig, CorrelationMatrix, StrategyRiskWeights,
-    StrategyReturnSnapshot, CorrelationError, CorrelationResult, CorrelationEngineFactory,
-    create_correlation_engine, create_correlation_engine_with_config
-};
-pub use risk_allocation::{
-    RiskAllocator, RiskAllocationConfig, PortfolioAllocation, StrategyAllocation,
-    RiskAllocationError, RiskAllocationResult, 
-    create_risk_allocator, create_risk_allocator_with_config
-};
-pub use drawdown::{
-    DrawdownTracker, DrawdownSnapshot, DrawdownState, DrawdownConfig,
-    RecoveryRampMode, DrawdownError, DrawdownResult, DrawdownTrackerFactory,
-    create_drawdown_tracker, create_drawdown_tracker_with_config, create_mock_drawdown_tracker
-};
-pub use microstructure::{
-    OrderFlowAnalyzer, OrderFlowMetrics, OrderFlowEvent, TradeAggression,
-    OrderImbalance, create_order_flow_analyzer
+    DecaySimulationParams, SimulatedTrustScore, simulate_trust_score_decay, apply_recovery_events,
+    RecoveryProfile, ThresholdBreach, TrustForecastParams, TrustForecastPoint, TrustScoreForecast,
+    forecast_trust_score
 };
-pub use market_regime::{
-    MarketRegimeDetector, MarketRegimeState, MarketRegime, MarketRegimeConfig,
-    MarketRegimeError, MarketRegimeResult, MarketRegimeMetrics,
-    create_market_regime_detector, create_market_regime_detector_with_config,
-    HiddenMarkovModel, MarketObservation, HmmRegimeConfig,
-    HmmMarketRegimeDetector, create_hmm_regime_detector, create_default_hmm_regime_detector,
-    // Leading indicator and warning system
-    LeadingIndicator, IndicatorDirection, RegimeWarning, IndicatorConfig,
-    RegimeForecast, StrategyPrepSignal, StrategyPrepAction,
-    RegimeWarningConfig, RegimeWarningEngine, RegimeWarningError,
-    create_regime_warning_engine, create_regime_warning_engine_with_config
+pub use simulation::latency_model::{
+    LatencyDistribution, LatencyModel, LatencyModelConfig, LatencySample, VenueLatencyProfile
 };
-pub use asset_allocator::{
-    AssetAllocator, AssetAllocationConfig, PortfolioAllocation as AssetPortfolioAllocation,
-    AssetAllocation, AssetRiskClass, AssetMetadata, AssetAllocationError, AssetAllocationResult,
-    create_asset_allocator, create_asset_allocator_with_config
-};
-pub use execution_metrics::{
-    ExecutionMetricsCollector, ExecutionMetricsConfig, ExecutionMetricsError, ExecutionMetricsResult,
-    create_execution_metrics_collector, create_default_execution_metrics_collector
-};
-pub use strategy_feedback::{
-    StrategyFeedbackLoop, StrategyFeedbackConfig, StrategyFeedbackError, AdaptiveStrategyStatus,
-    AdaptationEvent, AllocationWeights, create_strategy_feedback_loop, create_default_strategy_feedback_loop
-};
-pub use strategy_attribution::{
-    AttributionEngine, StrategyAttribution, AttributionConfig, AttributionError, AttributionResult
-};
-pub use factor_analysis::{
-    FactorAnalysisEngine, AlphaFactor, FactorExposure, StrategyFactorProfile,
-    FactorAnalysisConfig, FactorRegressionResult, FactorAnalysisError,
-    FactorAnalysisResult, FactorAlert, FactorAlertType, 
-    create_factor_analysis_engine, create_factor_analysis_engine_with_config
-};
-pub use redis::{RedisClient, RedisConfig, RedisClientError, RedisClientResult};
-pub use treasury_service::{
-    TreasuryService, TreasuryAccount, TreasuryTransaction, TreasuryEvent,
-    RedisTreasuryService, create_treasury_service, run_daily_tier_evaluation
-};
-pub use memory_service::{
-    MemoryService, MemoryEvent, AgentEmbedding, AgentComparison,
-    create_memory_service, generate_agent_embedding
+pub use allocation_constraints::{
+    AllocationConstraints, ConstraintConflict, ConstraintError, ConstrainedAllocation, GroupConstraint, WeightBounds
 };
+pub use volume_profile::{ProfileTrade, VolumeProfile, VolumeProfileLevel, build_volume_profile};
 
-// Re-export new latency-critical modules
-pub use order_router::{
-    SmartOrderRouter, OrderRetryEngine, Order, OrderSide as RouterOrderSide, 
-    RetryContext, VenueExecutionResult, OrderRouterError, ExecutionFailureReason
-};
-pub use execution_strategy::{
-    ExecutionStrategyRouter, ExecutionStrategy, ExecutionAlgorithm,
-    ExecutionStrategyConfig, TWAPConfig, VWAPConfig, 
-    ExecutionStrategyError, ExecutionStrategyDetails
-};
-pub use risk_calc::{
-    RiskCalculator, RiskConfig, PositionExposure, VenueExposure,
-    RiskCheckResult, RiskViolation, RiskViolationType, RiskViolationSeverity
-};
-pub use trade_sizer::{
-    DynamicTradeSizer, TradeSizerConfig, TradeSizerError
-};
-pub use drawdown_monitor::{
-    DrawdownMonitor, DrawdownConfig as FastDrawdownConfig, 
-    TradeDataPoint, TradeType, DrawdownEventType, DrawdownState as FastDrawdownState,
-    DrawdownEvent, DrawdownWindow, KillSwitch, DrawdownError as FastDrawdownError
-};
+// Everything below depends on tokio, Redis or other native-only crates and is
+// left out of WebAssembly builds
+macro_rules! native {
+    ($($item:item)*) => {
+        $(
+            #[cfg(not(target_arch = "wasm32"))]
+            $item
+        )*
+    };
+}
 
-// Re-export venue latency tracker
-pub use venue_latency::{VenueLatencyTracker, VenueLatencyStats, create_venue_latency_tracker};
+native! {
+    pub mod strategy;
+    pub mod market;
+    pub mod bars;
+    pub mod risk;
+    pub mod execution;
+    pub mod telemetry;
+    pub mod entropy;
+    pub mod strategy_executor;
+    pub mod strategy_dependencies;
+    pub mod trust_buffer;
+    pub mod examples;
+    pub mod storage;
+    pub mod api;
+    pub mod analytics;
+    pub mod telemetry_streamer;
+    pub mod telemetry_schema;
+    pub mod dead_letter;
+    pub mod websocket_manager;
+    pub mod trust_score_engine;
+    pub mod trust_decay_service;
+    pub mod correlation_engine;
+    pub mod risk_allocation;
+    pub mod drawdown;
+    pub mod microstructure;
+    pub mod market_regime;
+    pub mod asset_allocator;
+    pub mod execution_metrics;
+    pub mod strategy_feedback;
+    pub mod strategy_attribution;
+    pub mod factor_analysis;
+    pub mod redis;
+    pub mod healing_orchestrator;
+    pub mod agent_controller;
+    pub mod trust_monitor;
+    pub mod treasury_service;
+    pub mod memory_service;
+    pub mod meta;
+    pub mod governance;
+    // New latency-critical modules
+    pub mod order_router;
+    pub mod execution_strategy;
+    pub mod risk_calc;
+    pub mod trade_sizer;
+    pub mod drawdown_monitor;
+    pub mod venue_latency;
+    pub mod shared_memory;
+    pub mod orderbook;
+    pub mod strategy_engine;
+    pub mod market_data;
+    pub mod position;
+    // NAPI bindings
+    #[cfg(feature = "napi")]
+    pub mod bindings;
+    pub mod cpu_affinity;
+    pub mod market_data_soa;
+    pub mod telemetry_enhanced;
+    pub mod fast_risk_layer;
+    pub mod risk_budget;
+    pub mod quote_freshness;
+    pub mod order_expiry;
+    pub mod trading_events;
+    pub mod backtest;
+    pub mod backtest_env;
+    pub mod backtest_replay;
+    pub mod audit_vault;
+    pub mod risk_override;
+    pub mod venue_control;
+    pub mod mesh;
+    pub mod error_taxonomy;
+    pub mod event_calendar;
+    pub mod sentiment;
+    pub mod chain_data;
+    pub mod dex_venue;
+    pub mod capital_transfer;
+    pub mod balance_forecast;
+    pub mod strategies;
+    pub mod feature_store;
+    pub mod model_drift;
+    pub mod fill_surveillance;
+    pub mod queue_position;
+    pub mod repricer;
+    pub mod multi_leg;
+    pub mod pairs_scanner;
+    pub mod reallocation_trigger;
+    pub mod strategy_retirement;
+    pub mod ids;
+    pub mod traceability;
+    pub mod retention;
+    pub mod chaos;
+    pub mod load_test;
+    pub mod portfolio_snapshot;
+    pub mod order_throttle;
+    pub mod runaway_detector;
+    pub mod drawdown_forecast;
+    pub mod exposure_heatmap;
+    pub mod order_simulator;
+    pub mod trust_history;
+    pub mod venue_trust;
+    pub mod routing_policy;
+    pub mod order_lineage;
+    pub mod execution_progress;
+    pub mod taker_overlay;
+    pub mod volatility;
+    pub mod execution_schedule;
+    pub mod liquidity_probe;
+    pub mod incidents;
+    pub mod object_pool;
+    pub mod book_feed;
+    pub mod iceberg;
+    pub mod wire_format;
+    pub mod pov;
+    pub mod logging;
+    pub mod flight_recorder;
+    pub mod failover;
+    pub mod state_bundle;
+    pub mod connectors;
+    pub mod universe;
+    pub mod fix;
+    pub mod warmup;
+    pub mod netting;
+    pub mod paper_trading;
 
-// Re-export shared memory manager
-pub use shared_memory::{
-    SharedMemoryManager, BufferConfig, BufferType, SharedRingBuffer, 
-    BatchProcessor, BatchResult, create_shared_memory_manager
-};
+    // Re-export common types
+    pub use market::MarketData;
+    pub use bars::{BarBuilder, BarError, BarService, BarType};
+    pub use strategy::{Strategy, Signal, EntropyConfig, EntropyInjector};
+    pub use entropy::{DefaultEntropyInjector, EntropyInjectorFactory};
+    pub use risk::{RiskManager, RiskError, RiskMetrics};
+    pub use execution::{ExecutionService, ExecutionResult, ExecutionError, LatencyProfile, FeeInfo, ExecutionLog, ExecutionQualityScore, ExecutionOutcomeReason};
+    pub use telemetry::TelemetryReporter;
+    pub use strategy_executor::StrategyExecutor;
+    pub use strategy_dependencies::{StrategyDependencyGraph, DependencyError};
+    pub use trust_buffer::{TrustBuffer, TrustScoreUpdate, TimeRange, TrustStatistics};
+    pub use storage::{StrategyStorage, StrategyArchive, StorageConfig, StorageType, create_storage, open_storage};
+    pub use api::create_api_router;
+    pub use analytics::{
+        Analytics, AnalyticsResult, AnalyticsError, create_analytics,
+        StrategyStorageAnalyticsAdapter, create_analytics_storage, setup_analytics,
+        PerformanceSummary, ExecutionStats, TrendLine, Anomaly, TimePeriod
+    };
+    pub use telemetry_streamer::{
+        TelemetryStreamer, TelemetryStreamerConfig, TelemetryMessage, TelemetryMessageType,
+        create_telemetry_streamer, spawn_dashboard_bridge
+    };
+    pub use error_taxonomy::{ClassifiedError, ErrorClass, ErrorReport};
+    pub use event_calendar::{
+        EventCalendar, EventCalendarConfig, CalendarEvent, CalendarSource, StaticCalendarSource,
+        EventCategory, EventImpact, EventRule, EventAction, TradingRestriction, create_event_calendar
+    };
+    pub use sentiment::{
+        SentimentPipeline, SentimentConfig, SentimentItem, SentimentProvider, SymbolSentiment,
+        create_sentiment_pipeline
+    };
+    pub use chain_data::{
+        ChainDataMonitor, ChainDataConfig, ChainDataProvider, ChainMetricKind, ChainMetricSample,
+        WatchedWallet, WatchedPool, create_chain_data_monitor
+    };
+    pub use dex_venue::{
+        DexVenueConnector, DexVenueConfig, DexRouterClient, DexQuote, DexSwap, DexTxStatus, DexFill,
+        create_dex_venue_connector
+    };
+    pub use capital_transfer::{
+        CapitalTransferManager, TransferConfig, VenueTransferClient, WhitelistedAddress, LiquidityNeed,
+        TransferPlan, CapitalTransfer, TransferStatus, LedgerEntry, create_capital_transfer_manager
+    };
+    pub use balance_forecast::{
+        BalanceForecaster, BalanceForecastConfig, BalanceForecastReport, VenueBalanceForecast,
+        DryVenueAlert, ScheduledAllocation, create_balance_forecaster
+    };
+    pub use strategies::{
+        StrategyTemplate, MomentumCrossoverConfig, MomentumCrossoverStrategy, MeanReversionConfig,
+        MeanReversionStrategy, OrderbookImbalanceConfig, OrderbookImbalanceStrategy,
+        VolatilityBreakoutConfig, VolatilityBreakoutStrategy, EnsembleConfig, EnsembleMode,
+        EnsembleStrategy, PairsTradingConfig, PairsTradingStrategy,
+    };
+    pub use feature_store::{
+        FeatureStore, FeatureStoreConfig, FeatureStoreError, FeatureDefinition, FeatureValue,
+        FeatureCadence, FnFeature, LogReturnFeature, RealizedVolatilityFeature, BookImbalanceFeature,
+        create_feature_store,
+    };
+    pub use model_drift::{
+        ModelDriftMonitor, ModelDriftConfig, ModelDriftError, DriftKind, DriftAlert, DriftReport,
+        population_stability_index, ks_statistic, expected_calibration_error, create_model_drift_monitor,
+    };
+    pub use fill_surveillance::{
+        FillSurveillance, FillSurveillanceConfig, ObservedFill, FillAnomaly, FillAnomalyKind,
+        create_fill_surveillance,
+    };
+    pub use queue_position::{
+        QueuePositionEstimator, QueuePositionConfig, QueueEstimate, QueuePositionError,
+        create_queue_position_estimator,
+    };
+    pub use repricer::{
+        PassiveRepricer, RepricerConfig, PassiveChildOrder, RepriceAction, RepriceReason, RepriceDecision,
+        RepricerError, create_passive_repricer,
+    };
+    pub use multi_leg::{
+        MultiLegOrder, OrderLeg, LeggingFallback, LegFill, MultiLegResult, MultiLegStatus,
+    };
+    pub use pairs_scanner::{
+        PairsScanner, PairsScannerConfig, CointegratedPair, EngleGranger, engle_granger,
+        create_pairs_scanner,
+    };
+    pub use reallocation_trigger::{
+        ReallocationTriggerService, ReallocationTriggerConfig, ReallocationTrigger, ReallocationTriggerKind,
+        ReallocationOutcome, ReallocationRecord, create_reallocation_trigger,
+    };
+    pub use strategy_retirement::{
+        StrategyRetirementService, RetirementConfig, RetirementReport, RetirementBreach, RetirementError,
+        create_strategy_retirement,
+    };
+    pub use ids::{IdError, IdGenerator, IdKind, TypedId, Ulid, new_id};
+    pub use traceability::{
+        Trace, TraceError, TraceLink, TraceNode, TraceRegistry, TraceRegistryConfig, TraceRelation,
+        create_trace_registry, trace_from_journal,
+    };
+    pub use retention::{
+        RetentionManager, RetentionConfig, RetentionPolicy, RetentionReport, RetentionSink, RetentionError,
+        DataClass, SinkPurge, StorageRetentionSink, RedisRetentionSink, TraceRetentionSink,
+        create_retention_manager,
+    };
+    pub use chaos::{
+        ChaosDexClient, ChaosEngine, ChaosError, ChaosEvent, ChaosExecutionProvider, ChaosFault, ChaosPlan,
+        ChaosReport, ChaosRule, ChaosScenario, ChaosTrigger,
+    };
+    pub use load_test::{
+        LatencySummary, LoadTestConfig, LoadTestReport, LoadTestRunner, LoadTestThresholds, PriceProcess,
+        SyntheticTickGenerator,
+    };
+    pub use portfolio_snapshot::{
+        PortfolioChange, PortfolioEvent, PortfolioSnapshotConfig, PortfolioSnapshotError, PortfolioSnapshotStore,
+        PortfolioState, PositionState, create_portfolio_snapshot_store,
+    };
+    pub use order_throttle::{OrderThrottle, OrderThrottleConfig, ThrottleDecision, ThrottleLimit, ThrottleStats};
+    pub use runaway_detector::{
+        RunawayDetector, RunawayDetectorConfig, RunawayEvidence, RunawayPattern, StrategyActivity,
+        create_runaway_detector,
+    };
+    pub use drawdown_forecast::{
+        DrawdownForecast, DrawdownForecastConfig, DrawdownForecastError, DrawdownForecaster, ExposureForecast,
+        ForecastEscalation, ForecastEscalationPolicy, RegimeAdjustment, create_drawdown_forecaster,
+    };
+    pub use exposure_heatmap::{
+        ExposureCell, ExposureHeatmap, ExposureHeatmapConfig, ExposureHeatmapService, ExposureTotals,
+        create_exposure_heatmap_service,
+    };
+    pub use order_simulator::{
+        BeforeAfter, ExposureProjection, MarginImpact, OrderSimulation, OrderSimulator, OrderSimulatorError,
+        SimulatedOrder, SlippageProjection, create_order_simulator,
+    };
+    pub use trust_history::{
+        TrustHistoryCompaction, TrustHistoryConfig, TrustHistoryError, TrustHistoryStore,
+        create_trust_history_store,
+    };
+    pub use venue_trust::{
+        VenueOutcome, VenueTrustConfig, VenueTrustError, VenueTrustRecord, VenueTrustService,
+        create_venue_trust_service,
+    };
+    pub use routing_policy::{
+        PolicyEnforcement, PolicyEvaluation, PolicyMatch, PolicyRule, PolicyVersionInfo,
+        PolicyViolation, RoutingPolicy, RoutingPolicyEngine, RoutingPolicyError, ViolationKind,
+        create_routing_policy_engine,
+    };
+    pub use order_lineage::{
+        OrderLineage, child_order, client_order_id, parse_client_order_id,
+    };
+    pub use execution_progress::{
+        ExecutionProgress, ExecutionProgressConfig, ExecutionProgressState, ExecutionProgressTracker,
+        ExecutionTransition, create_execution_progress_tracker,
+    };
+    pub use taker_overlay::{
+        TakeOpportunity, TakerBudget, TakerOverlay, TakerOverlayConfig, TakerOverlayError,
+        create_taker_overlay,
+    };
+    pub use volatility::{
+        VolatilityConfig, VolatilityError, VolatilityEstimate, VolatilityEstimator, VolatilityService,
+        VolatilitySnapshot, create_volatility_service,
+    };
+    pub use execution_schedule::{
+        ScheduleRandomizer, ScheduleRandomizerConfig, ScheduledSlice, create_schedule_randomizer,
+    };
+    pub use liquidity_probe::{
+        HiddenDepthEstimate, LiquidityProbeConfig, LiquidityProbeError, LiquidityProber, ProbeResult,
+        create_liquidity_prober,
+    };
+    pub use incidents::{
+        EvidenceKind, Incident, IncidentConfig, IncidentError, IncidentEvidence, IncidentKillSwitch,
+        IncidentManager, IncidentSeverity, IncidentStatus, IncidentTrigger, TimelineEntry,
+        create_incident_manager,
+    };
+    pub use object_pool::{ObjectPool, PoolStats, Pooled, Reusable};
+    pub use book_feed::{BookFeed, BookFeedConfig, BookLevel, BookMessage, BookReplica};
+    pub use iceberg::{ICEBERG_CLIP_PARAM, IcebergExecutor, create_iceberg_executor};
+    pub use wire_format::{EncodedFrame, MSGPACK_TAG, WireFormat, WireFormatError};
+    pub use pov::{POV_FALLBACK_PARAM, POVExecutor, create_pov_executor};
+    pub use logging::{LogControl, LogFormat, LogLevels, LoggingConfig, LoggingError, correlation_span, init_logging};
+    pub use flight_recorder::{FlightRecorder, FlightRecorderConfig, FlightRecorderKillSwitch, FlightRecording, RecordedEventKind, create_flight_recorder, install_panic_hook};
+    pub use failover::{FailoverConfig, FailoverCoordinator, FailoverError, FailoverRole, LeaseStore, MemoryLeaseStore, RedisLeaseStore, create_failover_coordinator};
+    pub use state_bundle::{ImportReport, SignedStateBundle, StateBundle, StateBundleConfig, StateBundleError, StateBundleManager, create_state_bundle_manager};
+    pub use connectors::{BinanceConnector, CoinbaseConnector, ConnectorConfig, ConnectorError, ConnectorHub, ConnectorHubConfig, MarketDataEvent, OrderAck, VenueBalance, VenueConnector, VenueOrderStatus, create_venue_connector};
+    pub use universe::{RiskTemplate, SymbolListing, UniverseConfig, UniverseError, UniverseManager, create_universe_manager};
+    pub use fix::{ExecType, ExecutionReport, FixError, FixGateway, FixMessage, FixSessionConfig, SessionStatus, create_fix_gateway};
+    pub use warmup::{WarmupConfig, WarmupGate, WarmupProgress, create_warmup_gate};
+    pub use netting::{NettedExposure, NettingGroup};
+    pub use paper_trading::{OrderBookPaperProvider, PaperTradingConfig, create_order_book_paper_provider};
+    pub use dead_letter::{
+        DeadLetterQueue, DeadLetterConfig, DeadLetter, DeadLetterOp, DeadLetterStats,
+        DeadLetterReplayer, RedisDeadLetterReplayer, DeadLetterError, create_dead_letter_queue
+    };
+    pub use telemetry_schema::{
+        TelemetrySchemaRegistry, EventSchema, FieldDef, FieldType, SchemaError, PayloadMigration,
+        create_telemetry_schema_registry
+    };
+    pub use websocket_manager::{WebSocketManager, WebSocketMessage, create_websocket_manager};
+    pub use trust_score_engine::{
+        TrustScoreEngine, TrustScore, TrustScoreFeatures, TrustScoreConfig, 
+        TrustScoreWeights, TrustScoreHistory, TrustScoreSnapshot, TrustScoreError, TrustScoreResult,
+        create_trust_score_engine
+    };
+    pub use trust_decay_service::{
+        TrustDecayService, TrustDecayConfig, StrategyActivityStatus,
+        create_trust_decay_service
+    };
+    pub use correlation_engine::{
+        CorrelationEngine, CorrelationEngineConfig, CorrelationMatrix, StrategyRiskWeights,
+        StrategyReturnSnapshot, CorrelationError, CorrelationResult, CorrelationEngineFactory,
+        create_correlation_engine, create_correlation_engine_with_config
+    };
+    pub use risk_allocation::{
+        RiskAllocator, RiskAllocationConfig, PortfolioAllocation, StrategyAllocation,
+        RiskAllocationError, RiskAllocationResult, 
+        create_risk_allocator, create_risk_allocator_with_config
+    };
+    pub use drawdown::{
+        DrawdownTracker, DrawdownSnapshot, DrawdownState, DrawdownConfig,
+        RecoveryRampMode, DrawdownError, DrawdownResult, DrawdownTrackerFactory,
+        create_drawdown_tracker, create_drawdown_tracker_with_config, create_mock_drawdown_tracker
+    };
+    pub use microstructure::{
+        OrderFlowAnalyzer, OrderFlowMetrics, OrderFlowEvent, TradeAggression,
+        OrderImbalance, create_order_flow_analyzer
+    };
+    pub use market_regime::{
+        MarketRegimeDetector, MarketRegimeState, MarketRegime, MarketRegimeConfig,
+        MarketRegimeError, MarketRegimeResult, MarketRegimeMetrics,
+        create_market_regime_detector, create_market_regime_detector_with_config,
+        HiddenMarkovModel, MarketObservation, HmmRegimeConfig,
+        HmmMarketRegimeDetector, create_hmm_regime_detector, create_default_hmm_regime_detector,
+        // Leading indicator and warning system
+        LeadingIndicator, IndicatorDirection, RegimeWarning, IndicatorConfig,
+        RegimeForecast, StrategyPrepSignal, StrategyPrepAction,
+        RegimeWarningConfig, RegimeWarningEngine, RegimeWarningError,
+        create_regime_warning_engine, create_regime_warning_engine_with_config
+    };
+    pub use asset_allocator::{
+        AssetAllocator, AssetAllocationConfig, PortfolioAllocation as AssetPortfolioAllocation,
+        AssetAllocation, AssetRiskClass, AssetMetadata, AssetAllocationError, AssetAllocationResult,
+        create_asset_allocator, create_asset_allocator_with_config
+    };
+    pub use execution_metrics::{
+        ExecutionMetricsCollector, ExecutionMetricsConfig, ExecutionMetricsError, ExecutionMetricsResult,
+        create_execution_metrics_collector, create_default_execution_metrics_collector
+    };
+    pub use strategy_feedback::{
+        StrategyFeedbackLoop, StrategyFeedbackConfig, StrategyFeedbackError, AdaptiveStrategyStatus,
+        AdaptationEvent, AllocationWeights, create_strategy_feedback_loop, create_default_strategy_feedback_loop
+    };
+    pub use strategy_attribution::{
+        AttributionEngine, StrategyAttribution, AttributionConfig, AttributionError, AttributionResult
+    };
+    pub use factor_analysis::{
+        FactorAnalysisEngine, AlphaFactor, FactorExposure, StrategyFactorProfile,
+        FactorAnalysisConfig, FactorRegressionResult, FactorAnalysisError,
+        FactorAnalysisResult, FactorAlert, FactorAlertType, 
+        create_factor_analysis_engine, create_factor_analysis_engine_with_config
+    };
+    pub use redis::{RedisClient, RedisConfig, RedisClientError, RedisClientResult, PooledRedisClient, create_pooled_redis_client};
+    pub use treasury_service::{
+        TreasuryService, TreasuryAccount, TreasuryTransaction, TreasuryEvent,
+        RedisTreasuryService, create_treasury_service, run_daily_tier_evaluation
+    };
+    pub use memory_service::{
+        MemoryService, MemoryEvent, AgentEmbedding, AgentComparison,
+        create_memory_service, generate_agent_embedding
+    };
 
-// Re-export order book manager
-pub use orderbook::{
-    OrderBookManager, OrderSide, UpdateType, PriceLevel, create_order_book_manager
-};
+    // Re-export new latency-critical modules
+    pub use order_router::{
+        SmartOrderRouter, OrderRetryEngine, Order, OrderSide as RouterOrderSide, 
+        RetryContext, VenueExecutionResult, OrderRouterError, ExecutionFailureReason,
+        TimeInForce, BatchConfig as OrderBatchConfig, BatchResult as OrderBatchResult,
+        BatchItemResult as OrderBatchItemResult, ReplaceRequest, RestingOrder, CancelPriority, OpenPosition,
+        DrainReport
+    };
+    pub use execution_strategy::{
+        ExecutionStrategyRouter, ExecutionStrategy, ExecutionAlgorithm,
+        ExecutionStrategyConfig, TWAPConfig, VWAPConfig, IcebergConfig, POVConfig,
+        ExecutionStrategyError, ExecutionStrategyDetails, AlgoModification
+    };
+    pub use risk_calc::{
+        RiskCalculator, RiskConfig, PositionExposure, VenueExposure,
+        RiskCheckResult, RiskViolation, RiskViolationType, RiskViolationSeverity,
+        ConcentrationDimension, ConcentrationEntry, ConcentrationReport, ConcentrationImpact,
+        VenueLimitAlert, WithdrawalRecommendation, WithdrawalReport, VolatilityLimit
+    };
+    pub use trade_sizer::{
+        DynamicTradeSizer, TradeSizerConfig, TradeSizerError
+    };
+    pub use risk_budget::{
+        RiskBudgetTracker, RiskBudgetConfig, RiskBudgetKind, RiskBudgetUsage,
+        RiskBudgetError, RiskBudgetResult,
+        create_risk_budget_tracker, create_risk_budget_tracker_with_config
+    };
+    pub use quote_freshness::{
+        QuoteFreshnessGuard, QuoteFreshnessConfig, QuoteFreshnessStats, StaleQuoteAction,
+        StaleReason, FreshnessVerdict, create_quote_freshness_guard
+    };
+    pub use order_expiry::{
+        OrderExpiryScheduler, OrderExpiryConfig, OrderCanceller, ScheduledExpiry, ExpiryState,
+        create_order_expiry_scheduler
+    };
+    pub use backtest::{
+        BacktestEngine, BacktestConfig, BacktestBar, BacktestReport, BacktestMetrics,
+        BacktestTrade, EquityPoint, BacktestError, create_backtest_engine
+    };
+    pub use backtest_env::{BacktestEnv, BacktestEnvConfig, EnvStep, EnvStepInfo};
+    pub use backtest_replay::{
+        ReplayEngine, ReplayConfig, ReplayEvent, ReplayReport, StrategyReplayResult, StrategyEquityPoint,
+        SimulatedFillProvider, SimulatedFillConfig, SimulatedFill, events_from_recording, create_replay_engine,
+    };
+    pub use audit_vault::{AuditVault, AuditEntry, AuditVaultError, create_audit_vault};
+    pub use risk_override::{
+        RiskOverrideManager, RiskOverrideConfig, RiskOverrideRequest, RiskOverride, RiskLimit,
+        RiskOverrideError, create_risk_override_manager
+    };
+    pub use venue_control::{VenueControl, VenueMode, VenueModeState, create_venue_control};
+    pub use mesh::{
+        TrustMesh, MeshConfig, MeshNode, MeshLink, MeshSnapshot, PropagationPath,
+        MeshError, MeshResult
+    };
+    pub use trust_monitor::{
+        SlaMonitor, SlaPolicy, StrategySla, SlaTier, SlaBreach, SlaStatus, TierChange,
+        create_sla_monitor
+    };
+    pub use trading_events::{
+        TradingEventBus, TradingEvent, TradingEventKind, create_trading_event_bus
+    };
+    pub use drawdown_monitor::{
+        DrawdownMonitor, DrawdownConfig as FastDrawdownConfig, 
+        TradeDataPoint, TradeType, DrawdownEventType, DrawdownState as FastDrawdownState,
+        DrawdownEvent, DrawdownWindow, KillSwitch, DrawdownError as FastDrawdownError
+    };
 
-// Re-export strategy engine
-pub use strategy_engine::{
-    StrategyEngine, StrategyEngineConfig, StrategyEngineMode,
-    SignalEvaluation, SignalMetrics, create_strategy_engine
-};
+    // Re-export venue latency tracker
+    pub use venue_latency::{VenueLatencyTracker, VenueLatencyStats, create_venue_latency_tracker};
 
-// Re-export market data processor
-pub use market_data::{
-    MarketDataProcessor, MarketTick, MarketFeatures, MarketAnomaly, AnomalyType,
-    MarketDataProcessorConfig, create_market_data_processor, 
-    create_market_data_processor_with_config, create_market_data_processor_with_shared_memory
-};
+    // Re-export shared memory manager
+    pub use shared_memory::{
+        SharedMemoryManager, BufferConfig, BufferType, SharedRingBuffer, 
+        BatchProcessor, BatchResult, create_shared_memory_manager
+    };
 
-// Re-export position manager
-pub use position::{
-    PositionManager, PositionManagerConfig, 
-    AgentPosition, SymbolPosition, OrderOrFill, Side,
-    PositionError, PositionResult,
-    create_position_manager, create_position_manager_with_config
-};
+    // Re-export order book manager
+    pub use orderbook::{
+        OrderBookManager, OrderSide, UpdateType, PriceLevel, create_order_book_manager
+    };
 
-// Re-export NAPI bindings
-#[cfg(feature = "napi")]
-pub use bindings::{
-    NapiSmartOrderRouter, OrderParams,
-    NapiRiskCalculator, RiskConfigParams, PositionExposureParams,
-    NapiDynamicTradeSizer, TradeSizerConfigParams,
-    NapiDrawdownMonitor, DrawdownConfigParams, TradeDataPointParams,
-    NapiExecutionStrategyRouter, ExecutionStrategyConfigParams, TWAPConfigParams, VWAPConfigParams,
-    NapiVenueLatencyTracker, VenueLatencyStatsParams,
-    NapiSharedMemoryManager, BufferConfigParams, NapiBatchProcessor,
-    NapiOrderBookManager, NapiOrderSide, NapiPriceLevel, NapiUpdateType,
-    NapiStrategyEngine, StrategyEngineConfigParams, SignalEvaluationParams, SignalMetricsParams,
-    NapiMarketDataProcessor, MarketDataProcessorConfigParams, MarketTickParams,
-    MarketFeaturesParams, MarketAnomalyParams
-};
+    // Re-export strategy engine
+    pub use strategy_engine::{
+        StrategyEngine, StrategyEngineConfig, StrategyEngineMode,
+        SignalEvaluation, SignalMetrics, create_strategy_engine
+    };
 
-use std::sync::Arc;
-use std::collections::HashMap;
-use risk::RiskManagerConfig;
-use risk::RiskManagerFactory;
+    // Re-export market data processor
+    pub use market_data::{
+        MarketDataProcessor, MarketTick, MarketFeatures, MarketAnomaly, AnomalyType,
+        MarketDataProcessorConfig, create_market_data_processor, 
+        create_market_data_processor_with_config, create_market_data_processor_with_shared_memory
+    };
 
-/// Create a smart order router
-pub fn create_smart_order_router() -> Arc<SmartOrderRouter> {
-    Arc::new(SmartOrderRouter::new())
-}
+    // Re-export position manager
+    pub use position::{
+        PositionManager, PositionManagerConfig, 
+        AgentPosition, SymbolPosition, OrderOrFill, Side,
+        PositionError, PositionResult, PositionChangeEvent, PnlSummary, PositionListener,
+        create_position_manager, create_position_manager_with_config
+    };
 
-/// Create a smart order router with custom retry engine
-pub fn create_smart_order_router_with_retry(
-    max_retries: u32,
-    base_delay_ms: u64,
-    max_delay_ms: u64,
-    initial_trust_scores: HashMap<String, f64>,
-) -> Arc<SmartOrderRouter> {
-    let retry_engine = Arc::new(OrderRetryEngine::new(max_retries, base_delay_ms, max_delay_ms));
-    Arc::new(SmartOrderRouter::with_retry_engine(retry_engine, initial_trust_scores))
-}
+    // Re-export NAPI bindings
+    #[cfg(feature = "napi")]
+    pub use bindings::{
+        NapiSmartOrderRouter, OrderParams,
+        NapiRiskCalculator, RiskConfigParams, PositionExposureParams,
+        NapiDynamicTradeSizer, TradeSizerConfigParams,
+        NapiDrawdownMonitor, DrawdownConfigParams, TradeDataPointParams,
+        NapiExecutionStrategyRouter, ExecutionStrategyConfigParams, TWAPConfigParams, VWAPConfigParams,
+        NapiVenueLatencyTracker, VenueLatencyStatsParams,
+        NapiSharedMemoryManager, BufferConfigParams, NapiBatchProcessor,
+        NapiOrderBookManager, NapiOrderSide, NapiPriceLevel, NapiUpdateType,
+        NapiStrategyEngine, StrategyEngineConfigParams, SignalEvaluationParams, SignalMetricsParams,
+        NapiMarketDataProcessor, MarketDataProcessorConfigParams, MarketTickParams,
+        MarketFeaturesParams, MarketAnomalyParams
+    };
 
-/// Create an execution strategy router
-pub fn create_execution_strategy_router(
-    config: ExecutionStrategyConfig,
-    twap_executor: Arc<dyn ExecutionStrategy>,
-    vwap_executor: Arc<dyn ExecutionStrategy>,
-) -> Arc<ExecutionStrategyRouter> {
-    Arc::new(ExecutionStrategyRouter::new(config, twap_executor, vwap_executor))
-}
+    use std::sync::Arc;
+    use std::collections::HashMap;
+    use risk::RiskManagerConfig;
+    use risk::RiskManagerFactory;
 
-/// Create a risk calculator
-pub fn create_risk_calculator(
-    config: RiskConfig,
-    initial_portfolio_value: f64,
-) -> Arc<RiskCalculator> {
-    Arc::new(RiskCalculator::new(config, initial_portfolio_value))
-}
+    /// Create a smart order router
+    pub fn create_smart_order_router() -> Arc<SmartOrderRouter> {
+        Arc::new(SmartOrderRouter::new())
+    }
 
-/// Create a dynamic trade sizer
-pub fn create_dynamic_trade_sizer(
-    config: TradeSizerConfig,
-) -> Arc<DynamicTradeSizer> {
-    Arc::new(DynamicTradeSizer::with_config(config))
-}
+    /// Create a smart order router with custom retry engine
+    pub fn create_smart_order_router_with_retry(
+        max_retries: u32,
+        base_delay_ms: u64,
+        max_delay_ms: u64,
+        initial_trust_scores: HashMap<String, f64>,
+    ) -> Arc<SmartOrderRouter> {
+        let retry_engine = Arc::new(OrderRetryEngine::new(max_retries, base_delay_ms, max_delay_ms));
+        Arc::new(SmartOrderRouter::with_retry_engine(retry_engine, initial_trust_scores))
+    }
 
-/// Create a drawdown monitor
-pub fn create_drawdown_monitor(
-    config: FastDrawdownConfig,
-    kill_switch: Arc<dyn KillSwitch>,
-) -> Arc<DrawdownMonitor> {
-    Arc::new(DrawdownMonitor::new(config, kill_switch))
-}
+    /// Create a smart order router that only routes while this instance holds
+    /// the leader lease; the coordinator restores replayed positions into the
+    /// position manager on promotion and must be started by the caller
+    pub fn create_failover_order_router(
+        config: FailoverConfig,
+        lease: Arc<dyn LeaseStore>,
+        position_manager: Arc<PositionManager>,
+    ) -> (Arc<SmartOrderRouter>, Arc<FailoverCoordinator>) {
+        let failover = Arc::new(FailoverCoordinator::new(config, lease).with_position_manager(position_manager.clone()));
+        let router = SmartOrderRouter::new()
+            .with_position_manager(position_manager)
+            .with_failover(failover.clone());
+        (Arc::new(router), failover)
+    }
 
-/// Create a risk manager with drawdown tracking for adaptive exposure
-pub fn create_risk_manager_with_drawdown(redis: Arc<dyn RedisClient>) -> Arc<dyn RiskManager> {
-    let drawdown_tracker = create_drawdown_tracker(redis);
-    RiskManagerFactory::create_with_drawdown_tracker(
-        RiskManagerConfig::default(),
-        drawdown_tracker
-    )
-}
+    /// Create a smart order router fed by live venue connectors; the hub and
+    /// the router share one order book and one quote freshness guard, so
+    /// routes are checked against the streams the hub applies. The hub must
+    /// be started by the caller
+    pub fn create_connected_order_router(
+        connectors: Vec<Arc<dyn VenueConnector>>,
+        hub_config: ConnectorHubConfig,
+        freshness_config: QuoteFreshnessConfig,
+    ) -> (Arc<SmartOrderRouter>, Arc<ConnectorHub>) {
+        let order_books = create_order_book_manager();
+        let guard = Arc::new(QuoteFreshnessGuard::new(freshness_config).with_reference_books(order_books.clone()));
+        let mut hub = ConnectorHub::new(hub_config)
+            .with_order_books(order_books.clone())
+            .with_freshness_guard(guard.clone());
+        let mut router = SmartOrderRouter::new()
+            .with_order_books(order_books)
+            .with_freshness_guard(guard);
+        for connector in connectors {
+            hub = hub.with_connector(connector.clone());
+            router = router.with_venue_connector(connector);
+        }
+        (Arc::new(router), Arc::new(hub))
+    }
 
-/// Create a risk manager with drawdown tracking and custom configs
-pub fn create_risk_manager_with_drawdown_and_config(
-    redis: Arc<dyn RedisClient>, 
-    risk_config: RiskManagerConfig,
-    drawdown_config: DrawdownConfig
-) -> Arc<dyn RiskManager> {
-    let drawdown_tracker = create_drawdown_tracker_with_config(redis, drawdown_config);
-    RiskManagerFactory::create_with_drawdown_tracker(
-        risk_config, 
-        drawdown_tracker
-    )
-}
+    /// Create an execution service whose paper mode fills against live order books
+    pub fn create_execution_service(
+        live_provider: Arc<dyn execution::ExecutionProvider>,
+        order_books: Arc<OrderBookManager>,
+        paper_config: PaperTradingConfig,
+    ) -> Arc<ExecutionService> {
+        let paper_provider = create_order_book_paper_provider(paper_config, order_books);
+        Arc::new(ExecutionService::new(live_provider, paper_provider, None))
+    }
 
-/// Create a strategy executor with drawdown tracking
-pub fn create_strategy_executor_with_drawdown(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    redis: Arc<dyn RedisClient>
-) -> StrategyExecutor {
-    let drawdown_tracker = create_drawdown_tracker(redis);
-    StrategyExecutor::with_drawdown_tracker(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        drawdown_tracker
-    )
-}
+    /// Create an execution strategy router
+    pub fn create_execution_strategy_router(
+        config: ExecutionStrategyConfig,
+        twap_executor: Arc<dyn ExecutionStrategy>,
+        vwap_executor: Arc<dyn ExecutionStrategy>,
+    ) -> Arc<ExecutionStrategyRouter> {
+        Arc::new(ExecutionStrategyRouter::new(config, twap_executor, vwap_executor))
+    }
 
-/// Create a strategy executor with custom configuration and drawdown tracking
-pub fn create_strategy_executor_with_config_and_drawdown(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    config: strategy_executor::StrategyExecutorConfig,
-    redis: Arc<dyn RedisClient>,
-    drawdown_config: Option<DrawdownConfig>
-) -> StrategyExecutor {
-    
-    let drawdown_tracker = match drawdown_config {
-        Some(config) => create_drawdown_tracker_with_config(redis, config),
-        None => create_drawdown_tracker(redis)
-    };
-    
-    StrategyExecutor::with_drawdown_tracker_and_config(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        config,
-        drawdown_tracker
-    )
-}
+    /// Create a risk calculator
+    pub fn create_risk_calculator(
+        config: RiskConfig,
+        initial_portfolio_value: f64,
+    ) -> Arc<RiskCalculator> {
+        Arc::new(RiskCalculator::new(config, initial_portfolio_value))
+    }
 
-/// Create a regime-aware allocator 
-pub fn create_regime_aware_allocator(
-    redis: Arc<dyn RedisClient>,
-    correlation_engine: Arc<dyn CorrelationEngine>,
-    regime_config: Option<MarketRegimeConfig>,
-    allocation_config: Option<AssetAllocationConfig>
-) -> (Arc<dyn MarketRegimeDetector>, Arc<dyn AssetAllocator>) {
-    
-    let regime_detector = match regime_config {
-        Some(config) => create_market_regime_detector_with_config(redis.clone(), config),
-        None => create_market_regime_detector(redis.clone())
-    };
-    
-    let allocator = match allocation_config {
-        Some(config) => create_asset_allocator_with_config(
-            redis, correlation_engine, Some(regime_detector.clone()), config
-        ),
-        None => create_asset_allocator(redis, correlation_engine, Some(regime_detector.clone()))
-    };
-    
-    (regime_detector, allocator)
-}
+    /// Create a dynamic trade sizer
+    pub fn create_dynamic_trade_sizer(
+        config: TradeSizerConfig,
+    ) -> Arc<DynamicTradeSizer> {
+        Arc::new(DynamicTradeSizer::with_config(config))
+    }
 
-/// Create a strategy executor with execution metrics
-pub fn create_strategy_executor_with_metrics(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    redis: Arc<dyn RedisClient>,
-    storage: Option<Arc<dyn StrategyStorage>>,
-) -> (StrategyExecutor, Arc<dyn ExecutionMetricsCollector>) {
-    
-    let metrics = create_execution_metrics_collector(redis.clone(), storage);
-    
-    let executor = StrategyExecutor::with_metrics_collector(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        metrics.clone()
-    );
-    
-    (executor, metrics)
-}
+    /// Create a drawdown monitor
+    pub fn create_drawdown_monitor(
+        config: FastDrawdownConfig,
+        kill_switch: Arc<dyn KillSwitch>,
+    ) -> Arc<DrawdownMonitor> {
+        Arc::new(DrawdownMonitor::new(config, kill_switch))
+    }
 
-/// Create a trading system with feedback loop
-pub fn create_trading_system_with_feedback(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    risk_allocator: Arc<dyn RiskAllocator>,
-    redis: Arc<dyn RedisClient>,
-    storage: Option<Arc<dyn StrategyStorage>>,
-) -> (StrategyExecutor, Arc<dyn ExecutionMetricsCollector>, Arc<dyn StrategyFeedbackLoop>) {
-    
-    let metrics = create_execution_metrics_collector(redis.clone(), storage.clone());
-    
-    let feedback = create_strategy_feedback_loop(
-        redis.clone(),
-        metrics.clone(),
-        risk_allocator,
-        storage
-    );
-    
-    let executor = StrategyExecutor::with_metrics_and_feedback(
-        strategies,
-        risk_manager, 
-        entropy_injector,
-        telemetry,
-        execution_service,
-        metrics.clone(),
-        feedback.clone()
-    );
-    
-    (executor, metrics, feedback)
-}
+    /// Create a risk manager with drawdown tracking for adaptive exposure
+    pub fn create_risk_manager_with_drawdown(redis: Arc<dyn RedisClient>) -> Arc<dyn RiskManager> {
+        let drawdown_tracker = create_drawdown_tracker(redis);
+        RiskManagerFactory::create_with_drawdown_tracker(
+            RiskManagerConfig::default(),
+            drawdown_tracker
+        )
+    }
 
-/// Create a strategy executor with factor analysis
-pub fn create_strategy_executor_with_factor_analysis(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    redis: Arc<dyn RedisClient>,
-) -> (StrategyExecutor, Arc<dyn FactorAnalysisEngine>) {
-    
-    let factor_engine = create_factor_analysis_engine(redis);
-    
-    let executor = StrategyExecutor::with_factor_analysis(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        factor_engine.clone()
-    );
-    
-    (executor, factor_engine)
-}
+    /// Create a risk manager with drawdown tracking and custom configs
+    pub fn create_risk_manager_with_drawdown_and_config(
+        redis: Arc<dyn RedisClient>, 
+        risk_config: RiskManagerConfig,
+        drawdown_config: DrawdownConfig
+    ) -> Arc<dyn RiskManager> {
+        let drawdown_tracker = create_drawdown_tracker_with_config(redis, drawdown_config);
+        RiskManagerFactory::create_with_drawdown_tracker(
+            risk_config, 
+            drawdown_tracker
+        )
+    }
 
-/// Create a complete strategy executor with all enhancements
-pub fn create_complete_strategy_executor(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    redis: Arc<dyn RedisClient>,
-    config: strategy_executor::StrategyExecutorConfig,
-) -> (StrategyExecutor, Arc<dyn ExecutionMetricsCollector>, Arc<dyn FactorAnalysisEngine>) {
-    
-    let metrics = create_execution_metrics_collector(redis.clone(), None);
-    let factor_engine = create_factor_analysis_engine(redis.clone());
-    
-    let executor = StrategyExecutor::with_all_enhancements(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        metrics.clone(),
-        factor_engine.clone(),
-        config
-    );
-    
-    (executor, metrics, factor_engine)
-}
+    /// Create a strategy executor with drawdown tracking
+    pub fn create_strategy_executor_with_drawdown(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        redis: Arc<dyn RedisClient>
+    ) -> StrategyExecutor {
+        let drawdown_tracker = create_drawdown_tracker(redis);
+        StrategyExecutor::with_drawdown_tracker(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            drawdown_tracker
+        )
+    }
 
-/// Create a strategy executor with market regime detection
-pub fn create_strategy_executor_with_regime_detection(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    redis: Arc<dyn RedisClient>,
-) -> (StrategyExecutor, Arc<dyn MarketRegimeDetector>) {
-    
-    let regime_detector = create_market_regime_detector(redis);
-    
-    let executor = StrategyExecutor::with_regime_detector(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        regime_detector.clone()
-    );
-    
-    (executor, regime_detector)
-}
+    /// Create a strategy executor with custom configuration and drawdown tracking
+    pub fn create_strategy_executor_with_config_and_drawdown(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        config: strategy_executor::StrategyExecutorConfig,
+        redis: Arc<dyn RedisClient>,
+        drawdown_config: Option<DrawdownConfig>
+    ) -> StrategyExecutor {
+
+        let drawdown_tracker = match drawdown_config {
+            Some(config) => create_drawdown_tracker_with_config(redis, config),
+            None => create_drawdown_tracker(redis)
+        };
+
+        StrategyExecutor::with_drawdown_tracker_and_config(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            config,
+            drawdown_tracker
+        )
+    }
+
+    /// Create a regime-aware allocator 
+    pub fn create_regime_aware_allocator(
+        redis: Arc<dyn RedisClient>,
+        correlation_engine: Arc<dyn CorrelationEngine>,
+        regime_config: Option<MarketRegimeConfig>,
+        allocation_config: Option<AssetAllocationConfig>
+    ) -> (Arc<dyn MarketRegimeDetector>, Arc<dyn AssetAllocator>) {
+
+        let regime_detector = match regime_config {
+            Some(config) => create_market_regime_detector_with_config(redis.clone(), config),
+            None => create_market_regime_detector(redis.clone())
+        };
+
+        let allocator = match allocation_config {
+            Some(config) => create_asset_allocator_with_config(
+                redis, correlation_engine, Some(regime_detector.clone()), config
+            ),
+            None => create_asset_allocator(redis, correlation_engine, Some(regime_detector.clone()))
+        };
+
+        (regime_detector, allocator)
+    }
+
+    /// Create a strategy executor with execution metrics
+    pub fn create_strategy_executor_with_metrics(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        redis: Arc<dyn RedisClient>,
+        storage: Option<Arc<dyn StrategyStorage>>,
+    ) -> (StrategyExecutor, Arc<dyn ExecutionMetricsCollector>) {
+
+        let metrics = create_execution_metrics_collector(redis.clone(), storage);
+
+        let executor = StrategyExecutor::with_metrics_collector(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            metrics.clone()
+        );
+
+        (executor, metrics)
+    }
+
+    /// Create a trading system with feedback loop
+    pub fn create_trading_system_with_feedback(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        risk_allocator: Arc<dyn RiskAllocator>,
+        redis: Arc<dyn RedisClient>,
+        storage: Option<Arc<dyn StrategyStorage>>,
+    ) -> (StrategyExecutor, Arc<dyn ExecutionMetricsCollector>, Arc<dyn StrategyFeedbackLoop>) {
+
+        let metrics = create_execution_metrics_collector(redis.clone(), storage.clone());
+
+        let feedback = create_strategy_feedback_loop(
+            redis.clone(),
+            metrics.clone(),
+            risk_allocator,
+            storage
+        );
+
+        let executor = StrategyExecutor::with_metrics_and_feedback(
+            strategies,
+            risk_manager, 
+            entropy_injector,
+            telemetry,
+            execution_service,
+            metrics.clone(),
+            feedback.clone()
+        );
 
-/// Create a strategy executor with regime warnings
-pub fn create_strategy_executor_with_regime_warnings(
-    strategies: Vec<Box<dyn Strategy>>,
-    risk_manager: Arc<dyn RiskManager>,
-    entropy_injector: Option<Arc<dyn EntropyInjector>>,
-    telemetry: Arc<TelemetryReporter>,
-    execution_service: Arc<ExecutionService>,
-    redis: Arc<dyn RedisClient>,
-    config: Option<RegimeWarningConfig>
-) -> (StrategyExecutor, Arc<dyn MarketRegimeDetector>, Arc<RegimeWarningEngine>) {
-    
-    let regime_detector = create_market_regime_detector(redis.clone());
-    
-    let warning_engine = match config {
-        Some(config) => create_regime_warning_engine_with_config(redis, regime_detector.clone(), config),
-        None => create_regime_warning_engine(redis, regime_detector.clone())
-    };
-    
-    let executor = StrategyExecutor::with_regime_warnings(
-        strategies,
-        risk_manager,
-        entropy_injector,
-        telemetry,
-        execution_service,
-        regime_detector.clone(),
-        warning_engine.clone()
-    );
-    
-    (executor, regime_detector, warning_engine)
-} 
\ No newline at end of file
+        (executor, metrics, feedback)
+    }
+
+    /// Create a strategy executor with factor analysis
+    pub fn create_strategy_executor_with_factor_analysis(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        redis: Arc<dyn RedisClient>,
+    ) -> (StrategyExecutor, Arc<dyn FactorAnalysisEngine>) {
+
+        let factor_engine = create_factor_analysis_engine(redis);
+
+        let executor = StrategyExecutor::with_factor_analysis(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            factor_engine.clone()
+        );
+
+        (executor, factor_engine)
+    }
+
+    /// Create a complete strategy executor with all enhancements
+    pub fn create_complete_strategy_executor(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        redis: Arc<dyn RedisClient>,
+        config: strategy_executor::StrategyExecutorConfig,
+    ) -> (StrategyExecutor, Arc<dyn ExecutionMetricsCollector>, Arc<dyn FactorAnalysisEngine>) {
+
+        let metrics = create_execution_metrics_collector(redis.clone(), None);
+        let factor_engine = create_factor_analysis_engine(redis.clone());
+
+        let executor = StrategyExecutor::with_all_enhancements(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            metrics.clone(),
+            factor_engine.clone(),
+            config
+        );
+
+        (executor, metrics, factor_engine)
+    }
+
+    /// Create a strategy executor with market regime detection
+    pub fn create_strategy_executor_with_regime_detection(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        redis: Arc<dyn RedisClient>,
+    ) -> (StrategyExecutor, Arc<dyn MarketRegimeDetector>) {
+
+        let regime_detector = create_market_regime_detector(redis);
+
+        let executor = StrategyExecutor::with_regime_detector(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            regime_detector.clone()
+        );
+
+        (executor, regime_detector)
+    }
+
+    /// Create a strategy executor with regime warnings
+    pub fn create_strategy_executor_with_regime_warnings(
+        strategies: Vec<Box<dyn Strategy>>,
+        risk_manager: Arc<dyn RiskManager>,
+        entropy_injector: Option<Arc<dyn EntropyInjector>>,
+        telemetry: Arc<TelemetryReporter>,
+        execution_service: Arc<ExecutionService>,
+        redis: Arc<dyn RedisClient>,
+        config: Option<RegimeWarningConfig>
+    ) -> (StrategyExecutor, Arc<dyn MarketRegimeDetector>, Arc<RegimeWarningEngine>) {
+
+        let regime_detector = create_market_regime_detector(redis.clone());
+
+        let warning_engine = match config {
+            Some(config) => create_regime_warning_engine_with_config(redis, regime_detector.clone(), config),
+            None => create_regime_warning_engine(redis, regime_detector.clone())
+        };
+
+        let executor = StrategyExecutor::with_regime_warnings(
+            strategies,
+            risk_manager,
+            entropy_injector,
+            telemetry,
+            execution_service,
+            regime_detector.clone(),
+            warning_engine.clone()
+        );
+
+        (executor, regime_detector, warning_engine)
+    } 
+}
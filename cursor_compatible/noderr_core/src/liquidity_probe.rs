@@ -0,0 +1,451 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Hidden liquidity probing
+//!
+//! Some venues accept iceberg orders or run dark midpoint books, so their
+//! displayed depth understates what an aggressive order can actually fill.
+//! The prober sends small IOC orders at prices where little or nothing is
+//! displayed and treats any fill beyond the displayed size as hidden depth.
+//! Findings are smoothed per venue, symbol and side and added to the depth
+//! the venue scorer sees. Probes cost real money, so every probe is charged
+//! against per-venue and global daily notional caps before it is sent.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+use crate::order_router::{Order, OrderSide, SmartOrderRouter, TimeInForce};
+
+/// Order parameter marking an order as a liquidity probe
+pub const PROBE_STYLE_PARAM: &str = "executionStyle";
+
+/// Errors that can occur while probing for hidden liquidity
+#[derive(Debug, Error)]
+pub enum LiquidityProbeError {
+    #[error("Venue {0} does not support hidden liquidity")]
+    UnsupportedVenue(String),
+
+    #[error("Probe notional cap for {scope} reached: requested {requested}, remaining {remaining}")]
+    NotionalCapExceeded { scope: String, requested: f64, remaining: f64 },
+
+    #[error("{venue} {symbol} was probed less than {interval_secs}s ago")]
+    Cooldown { venue: String, symbol: String, interval_secs: i64 },
+
+    #[error("Invalid probe: {0}")]
+    InvalidProbe(String),
+
+    #[error("Probe routing failed: {0}")]
+    Router(String),
+}
+
+/// Liquidity probe configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LiquidityProbeConfig {
+    /// Venues that accept iceberg or dark orders and are worth probing
+    pub hidden_liquidity_venues: Vec<String>,
+    /// Notional of a single probe (quote currency)
+    pub probe_notional: f64,
+    /// Probe notional each venue may spend per UTC day
+    pub max_daily_notional_per_venue: f64,
+    /// Probe notional all venues together may spend per UTC day
+    pub max_daily_notional: f64,
+    /// Minimum time between probes of the same venue, symbol and side (seconds)
+    pub min_probe_interval_secs: i64,
+    /// Weight of the newest probe in the hidden depth estimate (0.0-1.0)
+    pub smoothing: f64,
+    /// Age after which a hidden depth estimate is ignored (seconds)
+    pub max_estimate_age_secs: i64,
+}
+
+impl Default for LiquidityProbeConfig {
+    fn default() -> Self {
+        Self {
+            hidden_liquidity_venues: Vec::new(),
+            probe_notional: 250.0,
+            max_daily_notional_per_venue: 2_500.0,
+            max_daily_notional: 10_000.0,
+            min_probe_interval_secs: 300,
+            smoothing: 0.3,
+            max_estimate_age_secs: 3_600,
+        }
+    }
+}
+
+/// Outcome of one probe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbeResult {
+    /// Venue probed
+    pub venue: String,
+    /// Symbol probed
+    pub symbol: String,
+    /// Side of the probe order
+    pub side: OrderSide,
+    /// Limit price of the probe
+    pub price: f64,
+    /// Quantity sent
+    pub quantity: f64,
+    /// Quantity displayed at or better than the probe price when it was sent
+    pub displayed_quantity: f64,
+    /// Quantity filled
+    pub filled_quantity: f64,
+    /// Fill beyond the displayed quantity
+    pub hidden_quantity: f64,
+    /// Notional charged against the probe caps
+    pub notional: f64,
+    /// When the probe was sent
+    pub at: DateTime<Utc>,
+}
+
+/// Smoothed hidden depth of one venue, symbol and side
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HiddenDepthEstimate {
+    /// Venue
+    pub venue: String,
+    /// Symbol
+    pub symbol: String,
+    /// Side an order must be on to take this liquidity
+    pub side: OrderSide,
+    /// Price of the latest probe
+    pub price: f64,
+    /// Smoothed hidden quantity found per probe
+    pub hidden_quantity: f64,
+    /// Smoothed share of the undisplayed probe size that filled (0.0-1.0)
+    pub hidden_fill_ratio: f64,
+    /// Number of probes behind the estimate
+    pub probes: u64,
+    /// When the estimate last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Probe notional spent on one UTC day
+#[derive(Debug, Clone, Default)]
+struct ProbeSpend {
+    day: Option<NaiveDate>,
+    total: f64,
+    by_venue: HashMap<String, f64>,
+}
+
+type ProbeKey = (String, String, OrderSide);
+
+/// Sends IOC probes and estimates hidden depth per venue
+pub struct LiquidityProber {
+    /// Configuration
+    config: LiquidityProbeConfig,
+    /// Router the probes are sent through
+    router: Arc<SmartOrderRouter>,
+    /// Hidden depth estimates
+    estimates: RwLock<HashMap<ProbeKey, HiddenDepthEstimate>>,
+    /// Last probe time per venue, symbol and side
+    last_probe: Mutex<HashMap<ProbeKey, DateTime<Utc>>>,
+    /// Notional spent today
+    spend: Mutex<ProbeSpend>,
+}
+
+impl LiquidityProber {
+    /// Create a new prober
+    pub fn new(config: LiquidityProbeConfig, router: Arc<SmartOrderRouter>) -> Self {
+        Self {
+            config,
+            router,
+            estimates: RwLock::new(HashMap::new()),
+            last_probe: Mutex::new(HashMap::new()),
+            spend: Mutex::new(ProbeSpend::default()),
+        }
+    }
+
+    /// Configuration in use
+    pub fn config(&self) -> &LiquidityProbeConfig {
+        &self.config
+    }
+
+    /// Whether a venue is configured for probing
+    pub fn supports(&self, venue: &str) -> bool {
+        self.config.hidden_liquidity_venues.iter().any(|v| v == venue)
+    }
+
+    /// Send one IOC probe and fold its outcome into the hidden depth estimate
+    ///
+    /// `displayed_quantity` is the size the venue shows at or better than
+    /// `price`; only fills beyond it count as hidden depth.
+    pub async fn probe(
+        &self,
+        venue: &str,
+        symbol: &str,
+        side: OrderSide,
+        price: f64,
+        displayed_quantity: f64,
+        now: DateTime<Utc>,
+    ) -> Result<ProbeResult, LiquidityProbeError> {
+        if !self.supports(venue) {
+            return Err(LiquidityProbeError::UnsupportedVenue(venue.to_string()));
+        }
+        if !price.is_finite() || price <= 0.0 {
+            return Err(LiquidityProbeError::InvalidProbe(format!("price {} must be positive", price)));
+        }
+        let quantity = self.config.probe_notional / price;
+        if displayed_quantity >= quantity {
+            return Err(LiquidityProbeError::InvalidProbe(format!(
+                "displayed size {} already covers the probe size {}", displayed_quantity, quantity
+            )));
+        }
+
+        let key = (venue.to_string(), symbol.to_string(), side);
+        self.check_cooldown(&key, now)?;
+        let notional = quantity * price;
+        self.reserve(venue, notional, now)?;
+        self.last_probe.lock().unwrap().insert(key, now);
+
+        let mut order = Order {
+            symbol: symbol.to_string(),
+            side,
+            amount: quantity,
+            price,
+            venues: vec![venue.to_string()],
+            id: format!("probe-{}-{}", venue, now.timestamp_millis()),
+            max_slippage: Some(0.0),
+            max_retries: Some(0),
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::IOC,
+            additional_params: HashMap::new(),
+        };
+        order.additional_params.insert(
+            PROBE_STYLE_PARAM.to_string(),
+            serde_json::Value::String("liquidity_probe".to_string()),
+        );
+
+        let execution = match self.router.execute_order(order).await {
+            Ok(execution) => execution,
+            Err(e) => {
+                // Nothing reached the book, so the spend is returned
+                self.release(venue, notional);
+                return Err(LiquidityProbeError::Router(e.to_string()));
+            }
+        };
+
+        let filled_quantity = execution.executed_quantity.unwrap_or(0.0).min(quantity);
+        let result = ProbeResult {
+            venue: venue.to_string(),
+            symbol: symbol.to_string(),
+            side,
+            price,
+            quantity,
+            displayed_quantity,
+            filled_quantity,
+            hidden_quantity: (filled_quantity - displayed_quantity).max(0.0),
+            notional,
+            at: now,
+        };
+        self.record(&result);
+        Ok(result)
+    }
+
+    /// Fold a probe outcome into the hidden depth estimate
+    pub fn record(&self, result: &ProbeResult) {
+        let undisplayed = (result.quantity - result.displayed_quantity).max(0.0);
+        let fill_ratio = if undisplayed > 0.0 { (result.hidden_quantity / undisplayed).min(1.0) } else { 0.0 };
+        let alpha = self.config.smoothing.clamp(0.0, 1.0);
+
+        let key = (result.venue.clone(), result.symbol.clone(), result.side);
+        let mut estimates = self.estimates.write().unwrap();
+        let estimate = estimates.entry(key).or_insert_with(|| HiddenDepthEstimate {
+            venue: result.venue.clone(),
+            symbol: result.symbol.clone(),
+            side: result.side,
+            price: result.price,
+            hidden_quantity: result.hidden_quantity,
+            hidden_fill_ratio: fill_ratio,
+            probes: 0,
+            updated_at: result.at,
+        });
+        if estimate.probes > 0 {
+            estimate.hidden_quantity = alpha * result.hidden_quantity + (1.0 - alpha) * estimate.hidden_quantity;
+            estimate.hidden_fill_ratio = alpha * fill_ratio + (1.0 - alpha) * estimate.hidden_fill_ratio;
+        }
+        estimate.price = result.price;
+        estimate.probes += 1;
+        estimate.updated_at = result.at;
+
+        debug!(
+            "Probe {} {} {:?}: {} hidden of {} sent, estimate {}",
+            result.venue, result.symbol, result.side, result.hidden_quantity, result.quantity, estimate.hidden_quantity
+        );
+    }
+
+    /// Current hidden depth estimate, if fresh
+    pub fn estimate(&self, venue: &str, symbol: &str, side: OrderSide, now: DateTime<Utc>) -> Option<HiddenDepthEstimate> {
+        let key = (venue.to_string(), symbol.to_string(), side);
+        self.estimates.read().unwrap()
+            .get(&key)
+            .filter(|e| now - e.updated_at <= Duration::seconds(self.config.max_estimate_age_secs))
+            .cloned()
+    }
+
+    /// Hidden quantity an order on `side` can expect to find at a venue
+    pub fn hidden_depth(&self, venue: &str, symbol: &str, side: OrderSide, now: DateTime<Utc>) -> f64 {
+        self.estimate(venue, symbol, side, now)
+            .map(|e| e.hidden_quantity)
+            .unwrap_or(0.0)
+    }
+
+    /// All current estimates
+    pub fn estimates(&self) -> Vec<HiddenDepthEstimate> {
+        self.estimates.read().unwrap().values().cloned().collect()
+    }
+
+    /// Probe notional still available today for a venue
+    pub fn remaining_notional(&self, venue: &str, now: DateTime<Utc>) -> f64 {
+        let mut spend = self.spend.lock().unwrap();
+        Self::roll_day(&mut spend, now);
+        let venue_spent = spend.by_venue.get(venue).copied().unwrap_or(0.0);
+        (self.config.max_daily_notional_per_venue - venue_spent)
+            .min(self.config.max_daily_notional - spend.total)
+            .max(0.0)
+    }
+
+    fn check_cooldown(&self, key: &ProbeKey, now: DateTime<Utc>) -> Result<(), LiquidityProbeError> {
+        let interval = Duration::seconds(self.config.min_probe_interval_secs);
+        match self.last_probe.lock().unwrap().get(key) {
+            Some(last) if now - *last < interval => Err(LiquidityProbeError::Cooldown {
+                venue: key.0.clone(),
+                symbol: key.1.clone(),
+                interval_secs: self.config.min_probe_interval_secs,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Charge a probe against the daily caps, or refuse it
+    fn reserve(&self, venue: &str, notional: f64, now: DateTime<Utc>) -> Result<(), LiquidityProbeError> {
+        let mut spend = self.spend.lock().unwrap();
+        Self::roll_day(&mut spend, now);
+
+        let venue_remaining = self.config.max_daily_notional_per_venue
+            - spend.by_venue.get(venue).copied().unwrap_or(0.0);
+        if notional > venue_remaining {
+            return Err(LiquidityProbeError::NotionalCapExceeded {
+                scope: venue.to_string(),
+                requested: notional,
+                remaining: venue_remaining.max(0.0),
+            });
+        }
+        let total_remaining = self.config.max_daily_notional - spend.total;
+        if notional > total_remaining {
+            return Err(LiquidityProbeError::NotionalCapExceeded {
+                scope: "all venues".to_string(),
+                requested: notional,
+                remaining: total_remaining.max(0.0),
+            });
+        }
+
+        spend.total += notional;
+        *spend.by_venue.entry(venue.to_string()).or_insert(0.0) += notional;
+        Ok(())
+    }
+
+    fn release(&self, venue: &str, notional: f64) {
+        let mut spend = self.spend.lock().unwrap();
+        spend.total = (spend.total - notional).max(0.0);
+        if let Some(spent) = spend.by_venue.get_mut(venue) {
+            *spent = (*spent - notional).max(0.0);
+        }
+    }
+
+    fn roll_day(spend: &mut ProbeSpend, now: DateTime<Utc>) {
+        let today = now.date_naive();
+        if spend.day != Some(today) {
+            *spend = ProbeSpend { day: Some(today), ..ProbeSpend::default() };
+        }
+    }
+}
+
+/// Create a new liquidity prober
+pub fn create_liquidity_prober(config: LiquidityProbeConfig, router: Arc<SmartOrderRouter>) -> Arc<LiquidityProber> {
+    Arc::new(LiquidityProber::new(config, router))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn result(filled: f64, displayed: f64, at: DateTime<Utc>) -> ProbeResult {
+        ProbeResult {
+            venue: "dark".to_string(),
+            symbol: "BTC/USD".to_string(),
+            side: OrderSide::Buy,
+            price: 100.0,
+            quantity: 2.5,
+            displayed_quantity: displayed,
+            filled_quantity: filled,
+            hidden_quantity: (filled - displayed).max(0.0),
+            notional: 250.0,
+            at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_probe_caps_and_hidden_depth() {
+        let config = LiquidityProbeConfig {
+            hidden_liquidity_venues: vec!["dark".to_string()],
+            max_daily_notional_per_venue: 600.0,
+            max_daily_notional: 1_000.0,
+            ..LiquidityProbeConfig::default()
+        };
+        let prober = LiquidityProber::new(config, Arc::new(SmartOrderRouter::new()));
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+        // Unsupported venues and probes the display already covers never spend
+        assert!(matches!(
+            prober.probe("lit", "BTC/USD", OrderSide::Buy, 100.0, 0.0, now).await,
+            Err(LiquidityProbeError::UnsupportedVenue(_))
+        ));
+        assert!(matches!(
+            prober.probe("dark", "BTC/USD", OrderSide::Buy, 100.0, 5.0, now).await,
+            Err(LiquidityProbeError::InvalidProbe(_))
+        ));
+        assert_eq!(prober.remaining_notional("dark", now), 600.0);
+
+        // Venue cap binds before the global cap, and resets the next day
+        prober.reserve("dark", 250.0, now).unwrap();
+        prober.reserve("dark", 250.0, now).unwrap();
+        assert!(matches!(
+            prober.reserve("dark", 250.0, now),
+            Err(LiquidityProbeError::NotionalCapExceeded { .. })
+        ));
+        prober.reserve("other", 400.0, now).unwrap();
+        assert!((prober.remaining_notional("third", now) - 100.0).abs() < 1e-9);
+        prober.release("other", 400.0);
+        assert_eq!(prober.remaining_notional("dark", now + Duration::days(1)), 600.0);
+
+        // Hidden fills are smoothed; an empty probe pulls the estimate down
+        prober.record(&result(2.0, 0.5, now));
+        assert!((prober.hidden_depth("dark", "BTC/USD", OrderSide::Buy, now) - 1.5).abs() < 1e-9);
+        prober.record(&result(0.5, 0.5, now));
+        let estimate = prober.estimate("dark", "BTC/USD", OrderSide::Buy, now).unwrap();
+        assert!((estimate.hidden_quantity - 1.05).abs() < 1e-9);
+        assert_eq!(estimate.probes, 2);
+        assert_eq!(prober.hidden_depth("dark", "BTC/USD", OrderSide::Sell, now), 0.0);
+
+        // Stale estimates stop counting
+        assert_eq!(prober.hidden_depth("dark", "BTC/USD", OrderSide::Buy, now + Duration::hours(2)), 0.0);
+    }
+}
@@ -0,0 +1,457 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Load-test generator for the market data and signal pipeline. Synthetic
+//! ticks drawn from parameterized stochastic price processes are pushed
+//! through `MarketDataProcessor` at a configurable rate across many symbols,
+//! with feature calculation interleaved the way signal generation reads it,
+//! and the run reports throughput and latency percentiles so regressions can
+//! be gated before deploys.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::market_data::{MarketDataError, MarketDataProcessor, MarketTick};
+use crate::simulation::latency_model::standard_normal;
+
+/// Stochastic process driving synthetic prices; parameters are per tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PriceProcess {
+    /// Geometric Brownian motion
+    Gbm { drift: f64, volatility: f64 },
+    /// Mean-reverting Ornstein-Uhlenbeck on the price level
+    OrnsteinUhlenbeck { mean: f64, reversion: f64, volatility: f64 },
+    /// GBM with Poisson jumps of normally distributed log size
+    JumpDiffusion {
+        drift: f64,
+        volatility: f64,
+        jump_probability: f64,
+        jump_mean: f64,
+        jump_std: f64,
+    },
+}
+
+impl Default for PriceProcess {
+    fn default() -> Self {
+        PriceProcess::Gbm { drift: 0.0, volatility: 0.0005 }
+    }
+}
+
+impl PriceProcess {
+    /// Advance a price by one tick
+    pub fn step<R: Rng + ?Sized>(&self, price: f64, rng: &mut R) -> f64 {
+        let next = match *self {
+            PriceProcess::Gbm { drift, volatility } => {
+                price * (drift - 0.5 * volatility * volatility + volatility * standard_normal(rng)).exp()
+            }
+            PriceProcess::OrnsteinUhlenbeck { mean, reversion, volatility } => {
+                price + reversion * (mean - price) + volatility * mean * standard_normal(rng)
+            }
+            PriceProcess::JumpDiffusion { drift, volatility, jump_probability, jump_mean, jump_std } => {
+                let mut log_return = drift - 0.5 * volatility * volatility + volatility * standard_normal(rng);
+                if rng.gen::<f64>() < jump_probability {
+                    log_return += jump_mean + jump_std * standard_normal(rng);
+                }
+                price * log_return.exp()
+            }
+        };
+        // Keep prices valid for the processor
+        next.max(f64::EPSILON)
+    }
+}
+
+/// Load test configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoadTestConfig {
+    /// Number of synthetic symbols
+    pub symbols: usize,
+    /// Total ticks to publish
+    pub total_messages: u64,
+    /// Target rate across all workers (messages per second, 0 = unthrottled)
+    pub target_rate: u64,
+    /// Worker threads publishing ticks; symbols are split between them
+    pub workers: usize,
+    /// Price process
+    pub process: PriceProcess,
+    /// Starting price for every symbol
+    pub initial_price: f64,
+    /// Quoted spread (basis points)
+    pub spread_bps: f64,
+    /// Mean tick volume; volumes are exponentially distributed
+    pub mean_volume: f64,
+    /// Calculate features after every this many ticks of a symbol (0 = never)
+    pub feature_every: u64,
+    /// Time every this many ticks (1 = every tick)
+    pub latency_sample_every: u64,
+    /// Random seed
+    pub seed: u64,
+}
+
+impl Default for LoadTestConfig {
+    fn default() -> Self {
+        Self {
+            symbols: 100,
+            total_messages: 1_000_000,
+            target_rate: 0,
+            workers: 4,
+            process: PriceProcess::default(),
+            initial_price: 100.0,
+            spread_bps: 2.0,
+            mean_volume: 1.0,
+            feature_every: 100,
+            latency_sample_every: 1,
+            seed: 42,
+        }
+    }
+}
+
+/// Regression gates applied to a report
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoadTestThresholds {
+    /// Minimum sustained throughput (messages per second)
+    pub min_throughput: Option<f64>,
+    /// Maximum tick processing p99 (microseconds)
+    pub max_tick_p99_us: Option<f64>,
+    /// Maximum feature calculation p99 (microseconds)
+    pub max_feature_p99_us: Option<f64>,
+}
+
+/// Latency percentiles in microseconds
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencySummary {
+    /// Samples taken
+    pub samples: usize,
+    /// Mean
+    pub mean_us: f64,
+    /// Median
+    pub p50_us: f64,
+    /// 90th percentile
+    pub p90_us: f64,
+    /// 99th percentile
+    pub p99_us: f64,
+    /// 99.9th percentile
+    pub p999_us: f64,
+    /// Maximum
+    pub max_us: f64,
+}
+
+impl LatencySummary {
+    /// Summarize latency samples given in nanoseconds
+    pub fn from_nanos(mut samples: Vec<u64>) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        samples.sort_unstable();
+        let at = |q: f64| {
+            let idx = ((samples.len() as f64 * q).ceil() as usize).clamp(1, samples.len()) - 1;
+            samples[idx] as f64 / 1_000.0
+        };
+
+        Self {
+            samples: samples.len(),
+            mean_us: samples.iter().sum::<u64>() as f64 / samples.len() as f64 / 1_000.0,
+            p50_us: at(0.50),
+            p90_us: at(0.90),
+            p99_us: at(0.99),
+            p999_us: at(0.999),
+            max_us: samples[samples.len() - 1] as f64 / 1_000.0,
+        }
+    }
+}
+
+/// Result of a load test run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadTestReport {
+    /// Configuration the run used
+    pub config: LoadTestConfig,
+    /// Ticks published
+    pub messages: u64,
+    /// Ticks the processor rejected
+    pub errors: u64,
+    /// Feature calculations completed
+    pub feature_calculations: u64,
+    /// Wall-clock duration (seconds)
+    pub elapsed_secs: f64,
+    /// Achieved throughput (messages per second)
+    pub throughput: f64,
+    /// Tick processing latency
+    pub tick_latency: LatencySummary,
+    /// Feature calculation latency
+    pub feature_latency: LatencySummary,
+    /// When the run finished
+    pub finished_at: DateTime<Utc>,
+}
+
+impl LoadTestReport {
+    /// Descriptions of every threshold the run failed
+    pub fn breaches(&self, thresholds: &LoadTestThresholds) -> Vec<String> {
+        let mut breaches = Vec::new();
+        if let Some(min) = thresholds.min_throughput {
+            if self.throughput < min {
+                breaches.push(format!("throughput {:.0} msg/s below {:.0}", self.throughput, min));
+            }
+        }
+        if let Some(max) = thresholds.max_tick_p99_us {
+            if self.tick_latency.p99_us > max {
+                breaches.push(format!("tick p99 {:.1}us above {:.1}us", self.tick_latency.p99_us, max));
+            }
+        }
+        if let Some(max) = thresholds.max_feature_p99_us {
+            if self.feature_latency.p99_us > max {
+                breaches.push(format!("feature p99 {:.1}us above {:.1}us", self.feature_latency.p99_us, max));
+            }
+        }
+        if self.errors > 0 {
+            breaches.push(format!("{} ticks rejected by the processor", self.errors));
+        }
+        breaches
+    }
+}
+
+/// Produces synthetic ticks for a set of symbols, round-robin
+pub struct SyntheticTickGenerator {
+    /// Symbols and their current prices
+    symbols: Vec<(String, f64)>,
+    /// Price process
+    process: PriceProcess,
+    /// Half the quoted spread as a fraction of price
+    half_spread: f64,
+    /// Mean tick volume
+    mean_volume: f64,
+    /// Random source
+    rng: StdRng,
+    /// Next symbol index
+    cursor: usize,
+    /// Timestamp of the next tick
+    clock: DateTime<Utc>,
+    /// Time between ticks
+    interval: chrono::Duration,
+}
+
+impl SyntheticTickGenerator {
+    /// Create a generator for the given symbols
+    pub fn new(symbols: Vec<String>, config: &LoadTestConfig, seed: u64) -> Self {
+        let interval_ns = match config.target_rate {
+            0 => 1_000,
+            rate => (1_000_000_000 / rate).max(1) as i64,
+        };
+
+        Self {
+            symbols: symbols.into_iter().map(|s| (s, config.initial_price)).collect(),
+            process: config.process.clone(),
+            half_spread: config.spread_bps / 20_000.0,
+            mean_volume: config.mean_volume,
+            rng: StdRng::seed_from_u64(seed),
+            cursor: 0,
+            clock: Utc::now(),
+            interval: chrono::Duration::nanoseconds(interval_ns),
+        }
+    }
+
+    /// Produce the next tick
+    pub fn next_tick(&mut self) -> MarketTick {
+        let idx = self.cursor;
+        self.cursor = (self.cursor + 1) % self.symbols.len();
+        self.clock += self.interval;
+
+        let price = self.process.step(self.symbols[idx].1, &mut self.rng);
+        self.symbols[idx].1 = price;
+        let volume = -self.mean_volume * (1.0 - self.rng.gen::<f64>()).ln();
+
+        MarketTick {
+            symbol: self.symbols[idx].0.clone(),
+            timestamp: self.clock,
+            price,
+            volume,
+            bid: Some(price * (1.0 - self.half_spread)),
+            ask: Some(price * (1.0 + self.half_spread)),
+            fields: HashMap::new(),
+        }
+    }
+}
+
+/// Per-worker counters and samples
+#[derive(Default)]
+struct WorkerStats {
+    messages: u64,
+    errors: u64,
+    feature_calculations: u64,
+    tick_nanos: Vec<u64>,
+    feature_nanos: Vec<u64>,
+}
+
+/// Drives a market data processor with synthetic load
+pub struct LoadTestRunner {
+    /// Configuration
+    config: LoadTestConfig,
+    /// Processor under test
+    processor: Arc<MarketDataProcessor>,
+}
+
+impl LoadTestRunner {
+    /// Create a runner
+    pub fn new(config: LoadTestConfig, processor: Arc<MarketDataProcessor>) -> Self {
+        Self { config, processor }
+    }
+
+    /// Run the load test to completion on dedicated threads
+    pub fn run(&self) -> LoadTestReport {
+        let config = &self.config;
+        let symbols = config.symbols.max(1);
+        let workers = config.workers.clamp(1, symbols);
+        info!(
+            "Load test: {} messages across {} symbols on {} workers (target {} msg/s)",
+            config.total_messages, symbols, workers, config.target_rate
+        );
+
+        let started = Instant::now();
+        let stats: Vec<WorkerStats> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    let names: Vec<String> = (worker..symbols)
+                        .step_by(workers)
+                        .map(|i| format!("SYN{:05}/USD", i))
+                        .collect();
+                    let messages = config.total_messages / workers as u64
+                        + u64::from((worker as u64) < config.total_messages % workers as u64);
+                    let rate = config.target_rate as f64 / workers as f64;
+                    scope.spawn(move || self.run_worker(worker as u64, names, messages, rate))
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap_or_default()).collect()
+        });
+        let elapsed = started.elapsed().as_secs_f64();
+
+        let mut totals = WorkerStats::default();
+        for s in stats {
+            totals.messages += s.messages;
+            totals.errors += s.errors;
+            totals.feature_calculations += s.feature_calculations;
+            totals.tick_nanos.extend(s.tick_nanos);
+            totals.feature_nanos.extend(s.feature_nanos);
+        }
+
+        let report = LoadTestReport {
+            config: config.clone(),
+            messages: totals.messages,
+            errors: totals.errors,
+            feature_calculations: totals.feature_calculations,
+            elapsed_secs: elapsed,
+            throughput: if elapsed > 0.0 { totals.messages as f64 / elapsed } else { 0.0 },
+            tick_latency: LatencySummary::from_nanos(totals.tick_nanos),
+            feature_latency: LatencySummary::from_nanos(totals.feature_nanos),
+            finished_at: Utc::now(),
+        };
+        info!(
+            "Load test finished: {:.0} msg/s, tick p99 {:.1}us, feature p99 {:.1}us",
+            report.throughput, report.tick_latency.p99_us, report.feature_latency.p99_us
+        );
+        report
+    }
+
+    fn run_worker(&self, worker: u64, symbols: Vec<String>, messages: u64, rate: f64) -> WorkerStats {
+        let symbol_count = symbols.len() as u64;
+        let mut generator = SyntheticTickGenerator::new(symbols, &self.config, self.config.seed.wrapping_add(worker));
+        let sample_every = self.config.latency_sample_every.max(1);
+        let mut stats = WorkerStats::default();
+        let started = Instant::now();
+
+        for n in 0..messages {
+            // Pace in small batches so sleeps stay coarse
+            if rate > 0.0 && n % 256 == 0 {
+                let due = Duration::from_secs_f64(n as f64 / rate);
+                if let Some(ahead) = due.checked_sub(started.elapsed()) {
+                    std::thread::sleep(ahead);
+                }
+            }
+
+            let tick = generator.next_tick();
+            let symbol = tick.symbol.clone();
+            let timed = n % sample_every == 0;
+
+            let t0 = Instant::now();
+            let result = self.processor.process_tick(tick);
+            if timed {
+                stats.tick_nanos.push(t0.elapsed().as_nanos() as u64);
+            }
+            stats.messages += 1;
+            if result.is_err() {
+                stats.errors += 1;
+                continue;
+            }
+
+            let feature_every = self.config.feature_every;
+            if feature_every > 0 && (n / symbol_count + 1) % feature_every == 0 {
+                let t0 = Instant::now();
+                match self.processor.calculate_features(&symbol) {
+                    Ok(_) => {
+                        stats.feature_nanos.push(t0.elapsed().as_nanos() as u64);
+                        stats.feature_calculations += 1;
+                    }
+                    // Expected while history warms up
+                    Err(MarketDataError::InsufficientHistory(_)) => {}
+                    Err(_) => stats.errors += 1,
+                }
+            }
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::market_data::create_market_data_processor;
+
+    #[test]
+    fn test_load_run_reports_throughput() {
+        let config = LoadTestConfig {
+            symbols: 4,
+            total_messages: 4_000,
+            workers: 2,
+            feature_every: 50,
+            process: PriceProcess::JumpDiffusion {
+                drift: 0.0,
+                volatility: 0.001,
+                jump_probability: 0.01,
+                jump_mean: 0.0,
+                jump_std: 0.02,
+            },
+            ..Default::default()
+        };
+        let report = LoadTestRunner::new(config, create_market_data_processor()).run();
+
+        assert_eq!(report.messages, 4_000);
+        assert_eq!(report.errors, 0);
+        assert!(report.feature_calculations > 0);
+        assert_eq!(report.tick_latency.samples, 4_000);
+        assert!(report.tick_latency.p50_us <= report.tick_latency.p99_us);
+        assert!(report.breaches(&LoadTestThresholds::default()).is_empty());
+
+        let impossible = LoadTestThresholds { min_throughput: Some(f64::MAX), ..Default::default() };
+        assert_eq!(report.breaches(&impossible).len(), 1);
+    }
+}
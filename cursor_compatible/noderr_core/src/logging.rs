@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Structured logging with per-module levels that can change at runtime
+//!
+//! The node installs one `tracing` subscriber whose filter sits behind a
+//! reload handle. [`LogControl`] keeps the default level and per-module
+//! overrides and swaps in a rebuilt filter whenever an operator changes them,
+//! so turning on `debug` for one module in production needs no restart.
+//! Output is either human-readable text or one JSON object per line; work
+//! done on behalf of an artifact runs inside a [`correlation_span`], which
+//! stamps every line with the artifact's typed ID.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{Level, Span};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{fmt, reload, Registry};
+
+use crate::ids::IdKind;
+
+/// Errors configuring or changing log levels
+#[derive(Debug, Error)]
+pub enum LoggingError {
+    #[error("Invalid log level: {0}")]
+    InvalidLevel(String),
+
+    #[error("Invalid module path: {0}")]
+    InvalidTarget(String),
+
+    #[error("Failed to apply log filter: {0}")]
+    Reload(String),
+
+    #[error("Logging already initialized: {0}")]
+    AlreadyInitialized(String),
+}
+
+/// Log line format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// Logging configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Level for modules without an override
+    pub default_level: String,
+    /// Levels by module path, e.g. `noderr_core::order_router`
+    pub module_levels: BTreeMap<String, String>,
+    /// Output format
+    pub format: LogFormat,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            default_level: "info".to_string(),
+            module_levels: BTreeMap::new(),
+            format: LogFormat::Text,
+        }
+    }
+}
+
+/// Current log levels
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLevels {
+    /// Level for modules without an override
+    pub default: String,
+    /// Overrides by module path
+    pub modules: BTreeMap<String, String>,
+}
+
+/// Default level plus per-module overrides
+#[derive(Debug, Clone, PartialEq)]
+struct ModuleLevels {
+    default: LevelFilter,
+    modules: BTreeMap<String, LevelFilter>,
+}
+
+impl ModuleLevels {
+    fn from_config(config: &LoggingConfig) -> Result<Self, LoggingError> {
+        let mut modules = BTreeMap::new();
+        for (target, level) in &config.module_levels {
+            modules.insert(parse_target(target)?, parse_level(level)?);
+        }
+        Ok(Self {
+            default: parse_level(&config.default_level)?,
+            modules,
+        })
+    }
+
+    /// Filter directives, e.g. `info,noderr_core::order_router=debug`
+    fn directives(&self) -> String {
+        std::iter::once(self.default.to_string().to_lowercase())
+            .chain(self.modules.iter().map(|(target, level)| format!("{}={}", target, level.to_string().to_lowercase())))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn filter(&self) -> Result<EnvFilter, LoggingError> {
+        EnvFilter::try_new(self.directives()).map_err(|e| LoggingError::Reload(e.to_string()))
+    }
+
+    fn snapshot(&self) -> LogLevels {
+        LogLevels {
+            default: self.default.to_string().to_lowercase(),
+            modules: self.modules.iter()
+                .map(|(target, level)| (target.clone(), level.to_string().to_lowercase()))
+                .collect(),
+        }
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, LoggingError> {
+    LevelFilter::from_str(level.trim()).map_err(|_| LoggingError::InvalidLevel(level.to_string()))
+}
+
+fn parse_target(target: &str) -> Result<String, LoggingError> {
+    let target = target.trim();
+    let valid = !target.is_empty()
+        && target.split("::").all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    if valid {
+        Ok(target.to_string())
+    } else {
+        Err(LoggingError::InvalidTarget(target.to_string()))
+    }
+}
+
+/// Runtime control over the installed log filter
+pub struct LogControl {
+    /// Handle swapping the filter in the installed subscriber
+    handle: reload::Handle<EnvFilter, Registry>,
+    /// Levels the filter was last built from
+    levels: Mutex<ModuleLevels>,
+    /// Levels from configuration, restored by `reset`
+    initial: ModuleLevels,
+}
+
+impl LogControl {
+    /// Current levels
+    pub fn levels(&self) -> LogLevels {
+        self.levels.lock().unwrap().snapshot()
+    }
+
+    /// Set the level of one module, or the default level when `target` is `None`
+    pub fn set_level(&self, target: Option<&str>, level: &str) -> Result<LogLevels, LoggingError> {
+        let level = parse_level(level)?;
+        let target = target.map(parse_target).transpose()?;
+        self.update(|levels| match target {
+            Some(target) => {
+                levels.modules.insert(target, level);
+            }
+            None => levels.default = level,
+        })
+    }
+
+    /// Restore one module, or every level when `target` is `None`, to its configured value
+    pub fn reset(&self, target: Option<&str>) -> Result<LogLevels, LoggingError> {
+        let target = target.map(parse_target).transpose()?;
+        let initial = self.initial.clone();
+        self.update(|levels| match target {
+            Some(target) => match initial.modules.get(&target) {
+                Some(level) => {
+                    levels.modules.insert(target, *level);
+                }
+                None => {
+                    levels.modules.remove(&target);
+                }
+            },
+            None => *levels = initial,
+        })
+    }
+
+    fn update(&self, change: impl FnOnce(&mut ModuleLevels)) -> Result<LogLevels, LoggingError> {
+        let mut levels = self.levels.lock().unwrap();
+        let mut updated = levels.clone();
+        change(&mut updated);
+
+        self.handle.reload(updated.filter()?).map_err(|e| LoggingError::Reload(e.to_string()))?;
+        tracing::info!(directives = %updated.directives(), "Log levels changed");
+        *levels = updated;
+        Ok(levels.snapshot())
+    }
+}
+
+/// Build a subscriber writing to `writer`, plus the control over its filter
+pub fn build_subscriber<W>(
+    config: &LoggingConfig,
+    writer: W,
+) -> Result<(impl tracing::Subscriber + Send + Sync, Arc<LogControl>), LoggingError>
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let levels = ModuleLevels::from_config(config)?;
+    let (filter, handle) = reload::Layer::new(levels.filter()?);
+
+    let output = match config.format {
+        LogFormat::Json => fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_writer(writer)
+            .boxed(),
+        LogFormat::Text => fmt::layer().with_target(true).with_writer(writer).boxed(),
+    };
+
+    let control = LogControl {
+        handle,
+        levels: Mutex::new(levels.clone()),
+        initial: levels,
+    };
+    Ok((Registry::default().with(filter).with(output), Arc::new(control)))
+}
+
+/// Install the global subscriber writing to stderr
+pub fn init_logging(config: &LoggingConfig) -> Result<Arc<LogControl>, LoggingError> {
+    let (subscriber, control) = build_subscriber(config, std::io::stderr)?;
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| LoggingError::AlreadyInitialized(e.to_string()))?;
+    Ok(control)
+}
+
+/// Span tagging every log line inside it with a correlation ID
+///
+/// Typed IDs also record their kind (`ord`, `sig`, ...). The span is created
+/// at `ERROR` level so it stays enabled whatever the module's level is.
+pub fn correlation_span(id: &str) -> Span {
+    let kind = IdKind::of(id).map(|kind| kind.prefix()).unwrap_or("external");
+    tracing::span!(Level::ERROR, "correlation", correlation_id = %id, id_kind = kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::TypedId;
+    use std::io;
+
+    /// Writer collecting output in memory
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_levels_change_at_runtime_and_lines_carry_correlation_ids() {
+        let config = LoggingConfig {
+            module_levels: BTreeMap::from([("noderr_core::pov".to_string(), "warn".to_string())]),
+            format: LogFormat::Json,
+            ..LoggingConfig::default()
+        };
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let (subscriber, control) = build_subscriber(&config, move || writer.clone()).unwrap();
+        let order_id = TypedId::new(IdKind::Order).to_string();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!(target: "noderr_core::order_router", "hidden");
+            tracing::info!(target: "noderr_core::pov", "hidden");
+
+            let levels = control.set_level(Some("noderr_core::order_router"), "debug").unwrap();
+            assert_eq!(levels.modules["noderr_core::order_router"], "debug");
+
+            let _span = correlation_span(&order_id).entered();
+            tracing::debug!(target: "noderr_core::order_router", venue = "binance", "routed");
+            tracing::debug!(target: "noderr_core::market_data", "hidden");
+
+            control.reset(None).unwrap();
+            tracing::debug!(target: "noderr_core::order_router", "hidden");
+        });
+
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output.lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .filter(|line: &serde_json::Value| line["target"] != "noderr_core::logging")
+            .collect();
+        assert_eq!(lines.len(), 1, "unexpected output: {}", output);
+        assert_eq!(lines[0]["message"], "routed");
+        assert_eq!(lines[0]["venue"], "binance");
+        assert_eq!(lines[0]["span"]["correlation_id"], order_id.as_str());
+        assert_eq!(lines[0]["span"]["id_kind"], "ord");
+
+        assert_eq!(control.levels(), LogLevels {
+            default: "info".to_string(),
+            modules: BTreeMap::from([("noderr_core::pov".to_string(), "warn".to_string())]),
+        });
+        assert!(matches!(control.set_level(None, "loud"), Err(LoggingError::InvalidLevel(_))));
+        assert!(matches!(control.set_level(Some("bad target"), "info"), Err(LoggingError::InvalidTarget(_))));
+    }
+}
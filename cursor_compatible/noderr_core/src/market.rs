@@ -28,6 +28,8 @@ use tracing::{debug, error, info, warn};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
+use crate::bars::{BarService, BarType};
+
 /// Type alias for trading pair/symbol
 pub type Symbol = String;
 
@@ -1145,6 +1147,8 @@ pub struct MarketDataSubscription {
     pub symbol: String,
     /// Timeframes to subscribe to
     pub timeframes: Vec<String>,
+    /// Activity-based bars (volume, dollar, Renko, range) to attach under `BarType::key()`
+    pub bar_types: Vec<BarType>,
     /// Whether to subscribe to orderbook updates
     pub include_orderbook: bool,
     /// Whether to subscribe to sentiment updates
@@ -1173,6 +1177,8 @@ pub struct MarketDataManager {
     subscriptions: tokio::sync::RwLock<HashMap<String, MarketDataSubscription>>,
     /// Last API request timestamps to manage rate limiting
     api_request_timestamps: tokio::sync::Mutex<HashMap<String, std::time::Instant>>,
+    /// Builds activity-based bars for subscriptions that request them
+    bar_service: Option<Arc<BarService>>,
 }
 
 impl MarketDataManager {
@@ -1185,6 +1191,27 @@ impl MarketDataManager {
             candle_cache: tokio::sync::RwLock::new(HashMap::new()),
             subscriptions: tokio::sync::RwLock::new(HashMap::new()),
             api_request_timestamps: tokio::sync::Mutex::new(HashMap::new()),
+            bar_service: None,
+        }
+    }
+    
+    /// Build activity-based bars for subscriptions with `bar_types`
+    pub fn set_bar_service(&mut self, bar_service: Arc<BarService>) {
+        self.bar_service = Some(bar_service);
+    }
+    
+    /// Feed a trade print to the bar service
+    pub async fn on_trade(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        timestamp: DateTime<Utc>,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Vec<(BarType, Candle)> {
+        match &self.bar_service {
+            Some(bar_service) => bar_service.on_trade(exchange, symbol, timestamp, price, quantity).await,
+            None => Vec::new(),
         }
     }
     
@@ -1367,6 +1394,15 @@ impl MarketDataManager {
     
     /// Subscribe to market data updates
     pub async fn subscribe(&self, subscription: MarketDataSubscription) -> Result<(), MarketDataError> {
+        if !subscription.bar_types.is_empty() {
+            let bar_service = self.bar_service.as_ref().ok_or_else(|| {
+                MarketDataError::DataNotAvailable("Activity-based bars require a bar service".to_string())
+            })?;
+            for bar_type in &subscription.bar_types {
+                bar_service.register(&subscription.exchange, &subscription.symbol, *bar_type).await;
+            }
+        }
+        
         let mut subscriptions = self.subscriptions.write().await;
         subscriptions.insert(subscription.id.clone(), subscription);
         Ok(())
@@ -1385,7 +1421,14 @@ impl MarketDataManager {
         
         for subscription in subscriptions.values() {
             if subscription.exchange == market_data.exchange && subscription.symbol == market_data.symbol {
-                (subscription.callback)(market_data.clone());
+                let mut data = market_data.clone();
+                if let Some(bar_service) = &self.bar_service {
+                    for bar_type in &subscription.bar_types {
+                        let bars = bar_service.bars(&data.exchange, &data.symbol, bar_type).await;
+                        data.candles.insert(bar_type.key(), bars);
+                    }
+                }
+                (subscription.callback)(data);
             }
         }
         
@@ -1652,6 +1695,7 @@ mod tests {
             exchange: "BTC/USDT".to_string(),
             symbol: "BTC/USDT".to_string(),
             timeframes: vec!["1m".to_string()],
+            bar_types: Vec::new(),
             include_orderbook: true,
             include_sentiment: true,
             callback: Arc::new(|data| {
@@ -6,6 +6,8 @@ use thiserror::Error;
 
 use crate::shared_memory::{SharedMemoryManager, SharedRingBuffer};
 use crate::market::MarketData;
+use crate::object_pool::{ObjectPool, PoolStats};
+use crate::flight_recorder::FlightRecorder;
 
 /// Market tick data
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -239,6 +241,12 @@ pub struct MarketDataProcessor {
     
     /// Shared memory manager for data access
     shared_memory: Option<Arc<SharedMemoryManager>>,
+    
+    /// Scratch price buffers reused across feature calculations
+    price_buffers: ObjectPool<Vec<f64>>,
+    
+    /// Recorder of recent ticks and anomalies, dumped on critical anomalies
+    flight_recorder: Option<Arc<FlightRecorder>>,
 }
 
 impl MarketDataProcessor {
@@ -250,6 +258,8 @@ impl MarketDataProcessor {
             cached_features: RwLock::new(HashMap::new()),
             anomalies: RwLock::new(Vec::new()),
             shared_memory: None,
+            price_buffers: ObjectPool::default(),
+            flight_recorder: None,
         }
     }
     
@@ -264,9 +274,17 @@ impl MarketDataProcessor {
             cached_features: RwLock::new(HashMap::new()),
             anomalies: RwLock::new(Vec::new()),
             shared_memory: Some(shared_memory),
+            price_buffers: ObjectPool::default(),
+            flight_recorder: None,
         }
     }
     
+    /// Record ticks and anomalies in a flight recorder
+    pub fn with_flight_recorder(mut self, recorder: Arc<FlightRecorder>) -> Self {
+        self.flight_recorder = Some(recorder);
+        self
+    }
+    
     /// Process a market tick
     pub fn process_tick(&self, tick: MarketTick) -> MarketDataResult<()> {
         // Validate tick data
@@ -276,14 +294,21 @@ impl MarketDataProcessor {
             ));
         }
         
+        if let Some(recorder) = &self.flight_recorder {
+            recorder.record_tick(&tick);
+        }
+        
         // Add tick to history
         let mut history = self.tick_history.write().unwrap();
         
-        // Get or create history for symbol
-        let ticks = history.entry(tick.symbol.clone()).or_insert_with(Vec::new);
+        // Get or create history for symbol; the key is only allocated for new symbols
+        if !history.contains_key(&tick.symbol) {
+            history.insert(tick.symbol.clone(), Vec::new());
+        }
+        let ticks = history.get_mut(&tick.symbol).unwrap();
         
         // Add tick to history
-        ticks.push(tick.clone());
+        ticks.push(tick);
         
         // Trim history if needed
         let config = self.config.read().unwrap();
@@ -318,6 +343,10 @@ impl MarketDataProcessor {
         // Get the latest tick
         let latest = ticks.last().unwrap();
         
+        // Extract prices once into a pooled buffer shared by the indicators
+        let mut prices = self.price_buffers.acquire();
+        prices.extend(ticks.iter().map(|t| t.price));
+        
         // Calculate returns
         let returns_1m = self.calculate_return(ticks, 60);
         let returns_5m = self.calculate_return(ticks, 300);
@@ -327,20 +356,20 @@ impl MarketDataProcessor {
         let returns_1d = self.calculate_return(ticks, 86400);
         
         // Calculate RSI
-        let rsi = self.calculate_rsi(ticks, config.rsi_period);
+        let rsi = self.calculate_rsi(&prices, config.rsi_period);
         
         // Calculate Bollinger Bands
         let (_, bb_width) = self.calculate_bollinger_bands(
-            ticks, config.bb_period, config.bb_std_dev
+            &prices, config.bb_period, config.bb_std_dev
         );
         
         // Calculate MACD
         let (macd, macd_signal, macd_hist) = self.calculate_macd(
-            ticks, config.macd_fast_period, config.macd_slow_period, config.macd_signal_period
+            &prices, config.macd_fast_period, config.macd_slow_period, config.macd_signal_period
         );
         
         // Calculate ATR
-        let atr = self.calculate_atr(ticks, config.atr_period);
+        let atr = self.calculate_atr(&prices, config.atr_period);
         
         // Calculate volume ratio
         let volume_ratio = self.calculate_volume_ratio(ticks, config.volume_ratio_period);
@@ -378,9 +407,14 @@ impl MarketDataProcessor {
             additional_metrics: HashMap::new(),
         };
         
-        // Cache the features
+        // Cache the features, reusing the key when the symbol is already cached
         let mut cached = self.cached_features.write().unwrap();
-        cached.insert(symbol.to_string(), features.clone());
+        match cached.get_mut(symbol) {
+            Some(slot) => *slot = features.clone(),
+            None => {
+                cached.insert(symbol.to_string(), features.clone());
+            }
+        }
         
         Ok(features)
     }
@@ -435,6 +469,13 @@ impl MarketDataProcessor {
                 let to_remove = anomalies.len() - 1000;
                 anomalies.drain(0..to_remove);
             }
+            
+            if let Some(recorder) = &self.flight_recorder {
+                for anomaly in &detected_anomalies {
+                    recorder.record_anomaly(anomaly);
+                }
+                recorder.check_anomalies(&detected_anomalies);
+            }
         }
         
         detected_anomalies
@@ -452,6 +493,23 @@ impl MarketDataProcessor {
         anomalies[start..].to_vec()
     }
     
+    /// Usage counters of the scratch price buffer pool
+    pub fn buffer_pool_stats(&self) -> PoolStats {
+        self.price_buffers.stats()
+    }
+    
+    /// Volume traded in a symbol after `since`, summed over the retained tick history
+    pub fn traded_volume_since(&self, symbol: &str, since: DateTime<Utc>) -> f64 {
+        let history = self.tick_history.read().unwrap();
+        history.get(symbol)
+            .map(|ticks| ticks.iter()
+                .rev()
+                .take_while(|tick| tick.timestamp > since)
+                .map(|tick| tick.volume.max(0.0))
+                .sum())
+            .unwrap_or(0.0)
+    }
+    
     /// Get latest market features for a symbol
     pub fn get_latest_features(&self, symbol: &str) -> Option<MarketFeatures> {
         let features = self.cached_features.read().unwrap();
@@ -487,14 +545,11 @@ impl MarketDataProcessor {
     }
     
     /// Calculate Relative Strength Index (RSI)
-    fn calculate_rsi(&self, ticks: &[MarketTick], period: usize) -> f64 {
-        if ticks.len() < period + 1 {
+    fn calculate_rsi(&self, prices: &[f64], period: usize) -> f64 {
+        if prices.len() < period + 1 {
             return 50.0;
         }
         
-        // Extract prices
-        let prices: Vec<f64> = ticks.iter().map(|t| t.price).collect();
-        
         // Calculate price changes
         let mut gains = 0.0;
         let mut losses = 0.0;
@@ -523,17 +578,14 @@ impl MarketDataProcessor {
     /// Calculate Bollinger Bands
     fn calculate_bollinger_bands(
         &self, 
-        ticks: &[MarketTick], 
+        prices: &[f64], 
         period: usize, 
         std_dev_multiplier: f64
     ) -> (f64, f64) {
-        if ticks.len() < period {
+        if prices.len() < period {
             return (0.0, 0.0);
         }
         
-        // Extract prices
-        let prices: Vec<f64> = ticks.iter().map(|t| t.price).collect();
-        
         // Calculate SMA
         let start_idx = prices.len() - period;
         let mut sum = 0.0;
@@ -560,21 +612,18 @@ impl MarketDataProcessor {
     /// Calculate MACD
     fn calculate_macd(
         &self, 
-        ticks: &[MarketTick], 
+        prices: &[f64], 
         fast_period: usize, 
         slow_period: usize, 
         signal_period: usize
     ) -> (f64, f64, f64) {
-        if ticks.len() < slow_period + signal_period {
+        if prices.len() < slow_period + signal_period {
             return (0.0, 0.0, 0.0);
         }
         
-        // Extract prices
-        let prices: Vec<f64> = ticks.iter().map(|t| t.price).collect();
-        
         // Calculate EMAs
-        let fast_ema = self.calculate_ema(&prices, fast_period);
-        let slow_ema = self.calculate_ema(&prices, slow_period);
+        let fast_ema = self.calculate_ema(prices, fast_period);
+        let slow_ema = self.calculate_ema(prices, slow_period);
         
         // MACD line
         let macd_line = fast_ema - slow_ema;
@@ -614,13 +663,11 @@ impl MarketDataProcessor {
     }
     
     /// Calculate Average True Range (ATR)
-    fn calculate_atr(&self, ticks: &[MarketTick], period: usize) -> f64 {
-        if ticks.len() < period + 1 {
+    fn calculate_atr(&self, prices: &[f64], period: usize) -> f64 {
+        if prices.len() < period + 1 {
             return 0.0;
         }
         
-        // Extract prices
-        let prices: Vec<f64> = ticks.iter().map(|t| t.price).collect();
         let mut tr_sum = 0.0;
         
         for i in 1..period+1 {
@@ -648,19 +695,16 @@ impl MarketDataProcessor {
             return 1.0;
         }
         
-        // Extract volumes
-        let volumes: Vec<f64> = ticks.iter().map(|t| t.volume).collect();
-        
         // Calculate average volume
-        let start_idx = volumes.len() - period;
+        let start_idx = ticks.len() - period;
         let mut sum = 0.0;
-        for i in start_idx..volumes.len() {
-            sum += volumes[i];
+        for tick in &ticks[start_idx..] {
+            sum += tick.volume;
         }
         let avg_volume = sum / period as f64;
         
         // Latest volume
-        let latest_volume = volumes.last().copied().unwrap_or(0.0);
+        let latest_volume = ticks.last().map(|t| t.volume).unwrap_or(0.0);
         
         if avg_volume > 0.0 {
             latest_volume / avg_volume
@@ -774,21 +818,22 @@ impl MarketDataProcessor {
         
         let spread = feature.spread.unwrap();
         
-        // Get historical spreads
-        let mut historical_spreads = Vec::new();
+        // Sum historical spreads without collecting them
+        let mut spread_sum = 0.0;
+        let mut spread_count = 0usize;
         for tick in ticks.iter().rev().take(100) {
             if let (Some(bid), Some(ask)) = (tick.bid, tick.ask) {
-                let s = (ask - bid) / bid * 100.0;
-                historical_spreads.push(s);
+                spread_sum += (ask - bid) / bid * 100.0;
+                spread_count += 1;
             }
         }
         
-        if historical_spreads.len() < 10 {
+        if spread_count < 10 {
             return None;
         }
         
         // Calculate average spread
-        let avg_spread: f64 = historical_spreads.iter().sum::<f64>() / historical_spreads.len() as f64;
+        let avg_spread = spread_sum / spread_count as f64;
         
         // Check if current spread exceeds threshold
         if spread > avg_spread * config.spread_widening_threshold {
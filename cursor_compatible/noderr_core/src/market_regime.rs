@@ -25,6 +25,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::market::MarketData;
 use crate::market::Symbol;
+use crate::volatility::{VolatilityEstimator, VolatilityService};
 
 /// Error types for market regime operations
 #[derive(Debug, Error)]
@@ -226,6 +227,9 @@ pub struct DefaultMarketRegimeDetector {
     
     /// Last update time
     last_update: Arc<RwLock<Instant>>,
+    
+    /// Shared volatility estimates preferred over the local price history (optional)
+    volatility_service: Option<(Arc<VolatilityService>, VolatilityEstimator)>,
 }
 
 impl DefaultMarketRegimeDetector {
@@ -236,9 +240,16 @@ impl DefaultMarketRegimeDetector {
             regimes: Arc::new(RwLock::new(HashMap::new())),
             price_history: Arc::new(RwLock::new(HashMap::new())),
             last_update: Arc::new(RwLock::new(Instant::now() - Duration::from_secs(3600))), // Force immediate update
+            volatility_service: None,
         }
     }
     
+    /// Classify volatility from the shared volatility service's per-bar estimate
+    pub fn with_volatility_service(mut self, service: Arc<VolatilityService>, estimator: VolatilityEstimator) -> Self {
+        self.volatility_service = Some((service, estimator));
+        self
+    }
+    
     /// Create with default configuration
     pub fn default() -> Self {
         Self::new(MarketRegimeConfig::default())
@@ -290,6 +301,12 @@ impl DefaultMarketRegimeDetector {
     
     /// Calculate volatility from price history
     async fn calculate_volatility(&self, symbol: &Symbol) -> MarketRegimeResult<f64> {
+        if let Some((service, estimator)) = &self.volatility_service {
+            if let Some(std_dev) = service.per_bar(symbol, *estimator) {
+                return Ok((std_dev / 0.05).min(1.0));
+            }
+        }
+        
         let price_history = self.price_history.read().await;
         
         if let Some(history) = price_history.get(symbol) {
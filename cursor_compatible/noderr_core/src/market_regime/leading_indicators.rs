@@ -33,6 +33,12 @@ pub enum LeadingIndicator {
     
     /// Significant imbalance in order book depth
     OrderBookSkew,
+    
+    /// Unusual token flows into or out of exchange wallets
+    ExchangeFlow,
+    
+    /// Sharp drop in on-chain DEX pool depth
+    DexLiquidityDrain,
 }
 
 impl fmt::Display for LeadingIndicator {
@@ -43,6 +49,8 @@ impl fmt::Display for LeadingIndicator {
             LeadingIndicator::VolumeAnomaly => write!(f, "VOLUME_ANOMALY"),
             LeadingIndicator::SocialSentiment => write!(f, "SOCIAL_SENTIMENT"),
             LeadingIndicator::OrderBookSkew => write!(f, "ORDER_BOOK_SKEW"),
+            LeadingIndicator::ExchangeFlow => write!(f, "EXCHANGE_FLOW"),
+            LeadingIndicator::DexLiquidityDrain => write!(f, "DEX_LIQUIDITY_DRAIN"),
         }
     }
 }
@@ -24,6 +24,7 @@ use tokio::sync::{mpsc, RwLock};
 use tokio::time;
 use tracing::{debug, error, info, warn};
 
+use crate::chain_data::{ChainDataMonitor, ChainMetricKind};
 use crate::market::{MarketData, Symbol};
 use crate::market_regime::{MarketRegimeError, MarketRegimeResult, MarketRegimeState, MarketRegime, MarketRegimeDetector};
 use crate::redis::{RedisClient, RedisClientError, RedisClientResult};
@@ -122,6 +123,22 @@ impl Default for RegimeWarningConfig {
             min_confidence: 0.8,
         });
         
+        indicators.insert(LeadingIndicator::ExchangeFlow, IndicatorConfig {
+            threshold: 3.0,         // 3 standard deviations
+            cooldown_sec: 1800,     // 30 minutes
+            decay_sec: 7200,        // 2 hours
+            enabled: true,
+            min_confidence: 0.6,
+        });
+        
+        indicators.insert(LeadingIndicator::DexLiquidityDrain, IndicatorConfig {
+            threshold: 2.5,         // 2.5 standard deviations
+            cooldown_sec: 900,      // 15 minutes
+            decay_sec: 3600,        // 1 hour
+            enabled: true,
+            min_confidence: 0.6,
+        });
+        
         Self {
             check_interval_sec: 30,      // Check every 30 seconds
             warning_ttl_sec: 3600,       // Warnings valid for 1 hour
@@ -159,6 +176,9 @@ pub struct RegimeWarningEngine {
     
     /// Stop channel for the monitor loop
     stop_tx: Option<mpsc::Sender<()>>,
+    
+    /// On-chain metrics for the exchange flow and DEX liquidity indicators
+    chain_data: Option<Arc<ChainDataMonitor>>,
 }
 
 impl RegimeWarningEngine {
@@ -178,9 +198,16 @@ impl RegimeWarningEngine {
             redis_client,
             telemetry,
             stop_tx: None,
+            chain_data: None,
         }
     }
     
+    /// Use on-chain metrics as leading indicators
+    pub fn with_chain_data(mut self, chain_data: Arc<ChainDataMonitor>) -> Self {
+        self.chain_data = Some(chain_data);
+        self
+    }
+    
     /// Create with default configuration
     pub fn default(
         regime_detector: Arc<dyn MarketRegimeDetector>,
@@ -340,6 +367,23 @@ impl RegimeWarningEngine {
             }
         }
         
+        // 5. Check on-chain exchange flows and DEX liquidity
+        if let Some(config) = self.config.indicators.get(&LeadingIndicator::ExchangeFlow) {
+            if config.enabled {
+                if let Some(warning) = self.check_exchange_flow(&symbol, config).await? {
+                    warnings.push(warning);
+                }
+            }
+        }
+        
+        if let Some(config) = self.config.indicators.get(&LeadingIndicator::DexLiquidityDrain) {
+            if config.enabled {
+                if let Some(warning) = self.check_dex_liquidity(&symbol, config).await? {
+                    warnings.push(warning);
+                }
+            }
+        }
+        
         // Process any new warnings
         for warning in warnings {
             self.process_warning(&warning).await?;
@@ -735,6 +779,105 @@ impl RegimeWarningEngine {
         Ok(None)
     }
     
+    /// Check for unusual exchange wallet flows; large inflows precede selling pressure
+    async fn check_exchange_flow(
+        &self,
+        symbol: &Symbol,
+        config: &IndicatorConfig,
+    ) -> RegimeWarningResult<Option<RegimeWarning>> {
+        let Some(chain_data) = &self.chain_data else {
+            return Ok(None);
+        };
+        
+        if !self.can_trigger_warning(symbol, LeadingIndicator::ExchangeFlow).await {
+            return Ok(None);
+        }
+        
+        let Some(sample) = chain_data.latest(ChainMetricKind::ExchangeNetFlow, symbol).await else {
+            return Ok(None);
+        };
+        let Some(z_score) = sample.z_score else {
+            return Ok(None);
+        };
+        
+        if z_score.abs() > config.threshold {
+            let confidence = ((z_score.abs() - config.threshold) / (config.threshold * 0.5)).min(0.95);
+            
+            if confidence >= config.min_confidence {
+                let direction = if z_score > 0.0 {
+                    IndicatorDirection::Bearish
+                } else {
+                    IndicatorDirection::Bullish
+                };
+                
+                let mut warning = RegimeWarning::new(
+                    LeadingIndicator::ExchangeFlow,
+                    symbol,
+                    z_score.abs(),
+                    config.threshold,
+                    confidence,
+                    direction,
+                );
+                
+                warning.with_metadata(serde_json::json!({
+                    "net_flow": sample.value,
+                    "z_score": z_score,
+                    "observed_at": sample.observed_at,
+                }));
+                
+                return Ok(Some(warning));
+            }
+        }
+        
+        Ok(None)
+    }
+    
+    /// Check for a sharp drop in DEX pool depth, which precedes volatile trading
+    async fn check_dex_liquidity(
+        &self,
+        symbol: &Symbol,
+        config: &IndicatorConfig,
+    ) -> RegimeWarningResult<Option<RegimeWarning>> {
+        let Some(chain_data) = &self.chain_data else {
+            return Ok(None);
+        };
+        
+        if !self.can_trigger_warning(symbol, LeadingIndicator::DexLiquidityDrain).await {
+            return Ok(None);
+        }
+        
+        let Some(sample) = chain_data.latest(ChainMetricKind::DexPoolDepth, symbol).await else {
+            return Ok(None);
+        };
+        
+        // Only drops in depth are a warning sign
+        let drop = -sample.z_score.unwrap_or(0.0);
+        if drop > config.threshold {
+            let confidence = ((drop - config.threshold) / (config.threshold * 0.5)).min(0.95);
+            
+            if confidence >= config.min_confidence {
+                let mut warning = RegimeWarning::new(
+                    LeadingIndicator::DexLiquidityDrain,
+                    symbol,
+                    drop,
+                    config.threshold,
+                    confidence,
+                    IndicatorDirection::Volatile,
+                );
+                
+                warning.with_metadata(serde_json::json!({
+                    "pool_depth": sample.value,
+                    "z_score": -drop,
+                    "observed_at": sample.observed_at,
+                }));
+                
+                return Ok(Some(warning));
+            }
+        }
+        
+        Ok(None)
+    }
+    
     /// Check if a warning can be triggered for a given indicator
     async fn can_trigger_warning(&self, symbol: &Symbol, indicator: LeadingIndicator) -> bool {
         let last_trigger = self.last_trigger.read().await;
@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that can occur when working with the trust mesh
+#[derive(Debug, Error)]
+pub enum MeshError {
+    #[error("Unknown agent: {0}")]
+    UnknownAgent(String),
+
+    #[error("Invalid trust {trust} on link {from} -> {to}; expected a value in [0, 1]")]
+    InvalidTrust { from: String, to: String, trust: f64 },
+
+    #[error("No propagation path from {from} to {to}")]
+    NoPath { from: String, to: String },
+}
+
+/// Result type for mesh operations
+pub type MeshResult<T> = Result<T, MeshError>;
+
+/// Trust mesh configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeshConfig {
+    /// Links below this trust are ignored when routing
+    pub min_link_trust: f64,
+    /// Agents below this trust score are not used as relays
+    pub min_relay_trust: f64,
+}
+
+impl Default for MeshConfig {
+    fn default() -> Self {
+        Self {
+            min_link_trust: 0.0,
+            min_relay_trust: 0.0,
+        }
+    }
+}
+
+/// Agent in the trust mesh
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeshNode {
+    /// Agent ID
+    pub agent_id: String,
+    /// Agent trust score in [0, 1]
+    pub trust_score: f64,
+    /// Banned agents neither send nor relay strategies
+    pub banned: bool,
+}
+
+/// Directed trust link between two agents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeshLink {
+    /// Sending agent
+    pub from: String,
+    /// Receiving agent
+    pub to: String,
+    /// Trust the receiver places in the sender, in [0, 1]
+    pub trust: f64,
+}
+
+/// Best route for propagating a strategy between two agents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropagationPath {
+    /// Agents along the path, source first
+    pub agents: Vec<String>,
+    /// Sum of `1 - trust` over the path's links
+    pub cost: f64,
+    /// Product of link trusts along the path
+    pub path_trust: f64,
+}
+
+/// Serializable view of the mesh for visualization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeshSnapshot {
+    /// All agents
+    pub nodes: Vec<MeshNode>,
+    /// All links
+    pub links: Vec<MeshLink>,
+    /// Connected components, largest first
+    pub partitions: Vec<Vec<String>>,
+}
+
+/// Dijkstra frontier entry ordered by lowest cost first
+#[derive(Debug, PartialEq)]
+struct Frontier {
+    cost: f64,
+    agent_id: String,
+}
+
+impl Eq for Frontier {}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost
+            .partial_cmp(&self.cost)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.agent_id.cmp(&self.agent_id))
+    }
+}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Trust-weighted graph of agents used to route strategy propagation
+#[derive(Debug, Clone, Default)]
+pub struct TrustMesh {
+    /// Configuration
+    config: MeshConfig,
+    /// Agents by ID
+    nodes: HashMap<String, MeshNode>,
+    /// Outgoing links by sender, as (receiver, trust)
+    links: HashMap<String, Vec<(String, f64)>>,
+}
+
+impl TrustMesh {
+    /// Create an empty mesh
+    pub fn new(config: MeshConfig) -> Self {
+        Self {
+            config,
+            nodes: HashMap::new(),
+            links: HashMap::new(),
+        }
+    }
+
+    /// Rebuild a mesh from a snapshot, keeping bans; partitions are recomputed
+    pub fn from_snapshot(snapshot: &MeshSnapshot, config: MeshConfig) -> MeshResult<Self> {
+        let mut mesh = Self::new(config);
+        for node in &snapshot.nodes {
+            mesh.add_agent(&node.agent_id, node.trust_score);
+            if node.banned {
+                mesh.ban(&node.agent_id)?;
+            }
+        }
+        for link in &snapshot.links {
+            mesh.add_link(&link.from, &link.to, link.trust)?;
+        }
+        Ok(mesh)
+    }
+
+    /// Add or update an agent
+    pub fn add_agent(&mut self, agent_id: &str, trust_score: f64) {
+        let node = self.nodes.entry(agent_id.to_string()).or_insert_with(|| MeshNode {
+            agent_id: agent_id.to_string(),
+            trust_score,
+            banned: false,
+        });
+        node.trust_score = trust_score.clamp(0.0, 1.0);
+    }
+
+    /// Add a directed link, replacing any existing link between the same agents
+    pub fn add_link(&mut self, from: &str, to: &str, trust: f64) -> MeshResult<()> {
+        if !(0.0..=1.0).contains(&trust) {
+            return Err(MeshError::InvalidTrust {
+                from: from.to_string(),
+                to: to.to_string(),
+                trust,
+            });
+        }
+        for agent_id in [from, to] {
+            if !self.nodes.contains_key(agent_id) {
+                return Err(MeshError::UnknownAgent(agent_id.to_string()));
+            }
+        }
+
+        let outgoing = self.links.entry(from.to_string()).or_default();
+        outgoing.retain(|(receiver, _)| receiver != to);
+        outgoing.push((to.to_string(), trust));
+        Ok(())
+    }
+
+    /// Add links in both directions with the same trust
+    pub fn connect(&mut self, a: &str, b: &str, trust: f64) -> MeshResult<()> {
+        self.add_link(a, b, trust)?;
+        self.add_link(b, a, trust)
+    }
+
+    /// Exclude an agent from routing
+    pub fn ban(&mut self, agent_id: &str) -> MeshResult<()> {
+        let node = self.nodes
+            .get_mut(agent_id)
+            .ok_or_else(|| MeshError::UnknownAgent(agent_id.to_string()))?;
+        node.banned = true;
+        Ok(())
+    }
+
+    /// Get an agent
+    pub fn agent(&self, agent_id: &str) -> Option<&MeshNode> {
+        self.nodes.get(agent_id)
+    }
+
+    /// Number of agents
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the mesh has no agents
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Best propagation path between two agents
+    pub fn best_path(&self, from: &str, to: &str) -> MeshResult<PropagationPath> {
+        if !self.nodes.contains_key(to) {
+            return Err(MeshError::UnknownAgent(to.to_string()));
+        }
+
+        self.shortest_paths(from)?
+            .remove(to)
+            .ok_or_else(|| MeshError::NoPath {
+                from: from.to_string(),
+                to: to.to_string(),
+            })
+    }
+
+    /// Best propagation paths from a source to every reachable agent, cheapest first
+    pub fn propagation_paths(&self, from: &str) -> MeshResult<Vec<PropagationPath>> {
+        let mut paths: Vec<PropagationPath> = self.shortest_paths(from)?
+            .into_iter()
+            .filter(|(agent_id, _)| agent_id != from)
+            .map(|(_, path)| path)
+            .collect();
+
+        paths.sort_by(|a, b| a.cost.partial_cmp(&b.cost).unwrap_or(Ordering::Equal));
+        Ok(paths)
+    }
+
+    /// Connected components of the routable mesh, largest first.
+    ///
+    /// Link direction is ignored; banned agents form their own singleton partitions.
+    pub fn partitions(&self) -> Vec<Vec<String>> {
+        let mut neighbours: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (from, outgoing) in &self.links {
+            for (to, trust) in outgoing {
+                if self.is_routable(from, to, *trust) {
+                    neighbours.entry(from.as_str()).or_default().push(to.as_str());
+                    neighbours.entry(to.as_str()).or_default().push(from.as_str());
+                }
+            }
+        }
+
+        let mut agent_ids: Vec<&str> = self.nodes.keys().map(String::as_str).collect();
+        agent_ids.sort_unstable();
+
+        let mut visited = HashSet::new();
+        let mut partitions = Vec::new();
+
+        for start in agent_ids {
+            if !visited.insert(start) {
+                continue;
+            }
+
+            let mut component = vec![start.to_string()];
+            let mut queue = VecDeque::from([start]);
+            while let Some(agent_id) = queue.pop_front() {
+                for &next in neighbours.get(agent_id).into_iter().flatten() {
+                    if visited.insert(next) {
+                        component.push(next.to_string());
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            component.sort();
+            partitions.push(component);
+        }
+
+        partitions.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        partitions
+    }
+
+    /// Whether the routable mesh is split into more than one partition
+    pub fn is_partitioned(&self) -> bool {
+        self.partitions().len() > 1
+    }
+
+    /// Serializable view of the mesh
+    pub fn snapshot(&self) -> MeshSnapshot {
+        let mut nodes: Vec<MeshNode> = self.nodes.values().cloned().collect();
+        nodes.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+        let mut links: Vec<MeshLink> = self.links
+            .iter()
+            .flat_map(|(from, outgoing)| {
+                outgoing.iter().map(move |(to, trust)| MeshLink {
+                    from: from.clone(),
+                    to: to.clone(),
+                    trust: *trust,
+                })
+            })
+            .collect();
+        links.sort_by(|a, b| a.from.cmp(&b.from).then_with(|| a.to.cmp(&b.to)));
+
+        MeshSnapshot {
+            nodes,
+            links,
+            partitions: self.partitions(),
+        }
+    }
+
+    /// Export the mesh as JSON
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self.snapshot()).unwrap_or_default()
+    }
+
+    /// Export the mesh as a Graphviz DOT digraph
+    pub fn to_dot(&self) -> String {
+        let snapshot = self.snapshot();
+        let mut dot = String::from("digraph trust_mesh {\n    node [shape=ellipse];\n");
+
+        for node in &snapshot.nodes {
+            let style = if node.banned { ", style=dashed, color=red" } else { "" };
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\n{:.2}\"{}];\n",
+                escape_dot(&node.agent_id),
+                escape_dot(&node.agent_id),
+                node.trust_score,
+                style
+            ));
+        }
+
+        for link in &snapshot.links {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{:.2}\", penwidth={:.2}];\n",
+                escape_dot(&link.from),
+                escape_dot(&link.to),
+                link.trust,
+                0.5 + link.trust * 2.5
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Dijkstra over `1 - trust` link weights from a source
+    fn shortest_paths(&self, from: &str) -> MeshResult<HashMap<String, PropagationPath>> {
+        let source = self.nodes
+            .get(from)
+            .ok_or_else(|| MeshError::UnknownAgent(from.to_string()))?;
+
+        let mut best: HashMap<String, (f64, Option<String>)> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        if !source.banned {
+            best.insert(from.to_string(), (0.0, None));
+            heap.push(Frontier { cost: 0.0, agent_id: from.to_string() });
+        }
+
+        while let Some(Frontier { cost, agent_id }) = heap.pop() {
+            if best.get(&agent_id).map_or(false, |(known, _)| cost > *known) {
+                continue;
+            }
+
+            // Only the source and sufficiently trusted agents relay further
+            if agent_id != from && !self.can_relay(&agent_id) {
+                continue;
+            }
+
+            for (next, trust) in self.links.get(&agent_id).into_iter().flatten() {
+                if !self.is_routable(&agent_id, next, *trust) {
+                    continue;
+                }
+
+                let next_cost = cost + (1.0 - trust);
+                let improved = best.get(next).map_or(true, |(known, _)| next_cost < *known);
+                if improved {
+                    best.insert(next.clone(), (next_cost, Some(agent_id.clone())));
+                    heap.push(Frontier { cost: next_cost, agent_id: next.clone() });
+                }
+            }
+        }
+
+        let mut paths = HashMap::new();
+        for (target, (cost, _)) in &best {
+            let mut agents = vec![target.clone()];
+            let mut path_trust = 1.0;
+            let mut current = target.clone();
+
+            while let Some((_, Some(previous))) = best.get(&current) {
+                path_trust *= self.link_trust(previous, &current).unwrap_or(0.0);
+                agents.push(previous.clone());
+                current = previous.clone();
+            }
+
+            agents.reverse();
+            paths.insert(target.clone(), PropagationPath { agents, cost: *cost, path_trust });
+        }
+
+        Ok(paths)
+    }
+
+    fn is_routable(&self, from: &str, to: &str, trust: f64) -> bool {
+        let active = |agent_id: &str| self.nodes.get(agent_id).map_or(false, |node| !node.banned);
+        trust >= self.config.min_link_trust && active(from) && active(to)
+    }
+
+    fn can_relay(&self, agent_id: &str) -> bool {
+        self.nodes
+            .get(agent_id)
+            .map_or(false, |node| !node.banned && node.trust_score >= self.config.min_relay_trust)
+    }
+
+    fn link_trust(&self, from: &str, to: &str) -> Option<f64> {
+        self.links
+            .get(from)?
+            .iter()
+            .find(|(receiver, _)| receiver == to)
+            .map(|(_, trust)| *trust)
+    }
+}
+
+/// Escape a string for use inside a quoted DOT identifier
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_mesh() -> TrustMesh {
+        let mut mesh = TrustMesh::new(MeshConfig::default());
+        for agent_id in ["a", "b", "c", "d", "e"] {
+            mesh.add_agent(agent_id, 0.9);
+        }
+        mesh.connect("a", "b", 0.9).unwrap();
+        mesh.connect("b", "c", 0.9).unwrap();
+        mesh.connect("a", "c", 0.5).unwrap();
+        mesh
+    }
+
+    #[test]
+    fn test_best_path_prefers_trusted_relays() {
+        let mesh = sample_mesh();
+
+        let path = mesh.best_path("a", "c").unwrap();
+        assert_eq!(path.agents, vec!["a", "b", "c"]);
+        assert!((path.cost - 0.2).abs() < 1e-9);
+        assert!((path.path_trust - 0.81).abs() < 1e-9);
+
+        assert!(matches!(mesh.best_path("a", "d"), Err(MeshError::NoPath { .. })));
+    }
+
+    #[test]
+    fn test_partitions_and_ban() {
+        let mut mesh = sample_mesh();
+        mesh.connect("d", "e", 0.7).unwrap();
+
+        assert_eq!(
+            mesh.partitions(),
+            vec![vec!["a", "b", "c"], vec!["d", "e"]]
+        );
+
+        mesh.ban("b").unwrap();
+        assert_eq!(mesh.best_path("a", "c").unwrap().agents, vec!["a", "c"]);
+        assert_eq!(mesh.partitions().len(), 3);
+        assert!(mesh.to_dot().contains("\"b\" [label=\"b\\n0.90\", style=dashed, color=red];"));
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut mesh = sample_mesh();
+        mesh.ban("b").unwrap();
+
+        let json = serde_json::to_string(&mesh.snapshot()).unwrap();
+        let snapshot: MeshSnapshot = serde_json::from_str(&json).unwrap();
+        let restored = TrustMesh::from_snapshot(&snapshot, MeshConfig::default()).unwrap();
+
+        assert_eq!(restored.len(), 5);
+        assert!(restored.agent("b").unwrap().banned);
+        assert_eq!(restored.best_path("a", "c").unwrap().agents, vec!["a", "c"]);
+        assert_eq!(restored.to_dot(), mesh.to_dot());
+
+        // Links must reference agents in the snapshot
+        let mut broken = snapshot.clone();
+        broken.nodes.retain(|node| node.agent_id != "c");
+        assert!(matches!(
+            TrustMesh::from_snapshot(&broken, MeshConfig::default()),
+            Err(MeshError::UnknownAgent(agent_id)) if agent_id == "c"
+        ));
+    }
+}
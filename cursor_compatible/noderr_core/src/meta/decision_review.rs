@@ -0,0 +1,532 @@
+use crate::error::Result as ServiceResult;
+use crate::meta::meta_agent_service::{
+    ActionType, DecisionStatus, MetaAgentConfig, MetaAgentDecision, MetaAgentMetrics, MetaAgentService,
+};
+use crate::strategy_executor::StrategyExecutor;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Errors raised by the decision review queue
+#[derive(Debug, Error)]
+pub enum DecisionReviewError {
+    #[error("Decision {0} is not awaiting review")]
+    NotPending(String),
+
+    #[error("Operator is required to resolve a review")]
+    MissingOperator,
+
+    #[error("Meta-agent service error: {0}")]
+    Service(String),
+}
+
+/// Result type for decision review operations
+pub type DecisionReviewResult<T> = Result<T, DecisionReviewError>;
+
+/// What happens when a queued decision is not reviewed in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewTimeoutPolicy {
+    /// Approve the decision for execution
+    AutoApprove,
+    /// Reject the decision
+    AutoReject,
+    /// Extend the deadline and flag the review as escalated; rejects once escalations run out
+    Escalate,
+}
+
+/// Review queue configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionReviewConfig {
+    /// Whether decisions are gated on operator approval at all
+    pub enabled: bool,
+    /// Decisions at or above this severity (1-10) require approval
+    pub severity_threshold: u8,
+    /// Time an operator has to review a decision (seconds)
+    pub review_timeout_secs: i64,
+    /// Action taken when the review times out
+    pub timeout_policy: ReviewTimeoutPolicy,
+    /// Maximum number of escalations before a review is rejected
+    pub max_escalations: u32,
+    /// How often pending reviews are checked for timeouts (milliseconds)
+    pub check_interval_ms: u64,
+}
+
+impl Default for DecisionReviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            severity_threshold: 7,
+            review_timeout_secs: 900,
+            timeout_policy: ReviewTimeoutPolicy::Escalate,
+            max_escalations: 2,
+            check_interval_ms: 1000,
+        }
+    }
+}
+
+/// Decision waiting for an operator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingReview {
+    /// Decision under review
+    pub decision: MetaAgentDecision,
+    /// Severity that triggered the review
+    pub severity: u8,
+    /// When the decision was queued
+    pub queued_at: DateTime<Utc>,
+    /// When the timeout policy applies
+    pub deadline: DateTime<Utc>,
+    /// Number of times the review has been escalated
+    pub escalations: u32,
+}
+
+/// How a review was resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewResolution {
+    /// Below the severity threshold; no review needed
+    NotRequired,
+    /// Approved by an operator
+    Approved,
+    /// Rejected by an operator
+    Rejected,
+    /// Approved by the timeout policy
+    AutoApproved,
+    /// Rejected by the timeout policy
+    AutoRejected,
+}
+
+impl ReviewResolution {
+    /// Whether the decision may be executed
+    pub fn is_approved(&self) -> bool {
+        matches!(self, ReviewResolution::NotRequired | ReviewResolution::Approved | ReviewResolution::AutoApproved)
+    }
+}
+
+/// Review queue notification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ReviewEvent {
+    /// A decision was queued for review
+    Queued { decision_id: String, severity: u8, deadline: DateTime<Utc> },
+    /// A review missed its deadline and was escalated
+    Escalated { decision_id: String, escalations: u32, deadline: DateTime<Utc> },
+    /// A decision was resolved; approved decisions may now be executed
+    Resolved {
+        decision: MetaAgentDecision,
+        resolution: ReviewResolution,
+        operator: Option<String>,
+        note: Option<String>,
+    },
+}
+
+/// Carries out the actions of approved meta-agent decisions
+#[async_trait]
+pub trait DecisionApplier: Send + Sync {
+    /// Apply a decision's actions; actions the applier does not handle are skipped
+    async fn apply(&self, decision: &MetaAgentDecision) -> Result<(), String>;
+}
+
+/// Suspensions quarantine the target strategy and restrictions disable it
+#[async_trait]
+impl DecisionApplier for StrategyExecutor {
+    async fn apply(&self, decision: &MetaAgentDecision) -> Result<(), String> {
+        for action in &decision.actions {
+            let target = &action.target_agent_id;
+            let applied = match action.action_type {
+                ActionType::Suspension => self.quarantine_strategy(target, &decision.reasoning).await,
+                ActionType::Restriction => self.disable_strategy(target).await,
+                _ => {
+                    debug!("Decision {}: no executor handling for {:?}", decision.id, action.action_type);
+                    continue;
+                }
+            };
+            applied.map_err(|e| format!("{:?} of {}: {}", action.action_type, target, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Human-in-the-loop approval gate for meta-agent decisions
+///
+/// The queue is itself a [`MetaAgentService`]: meta-agents that record their
+/// decisions through it have severe decisions held for an operator, and
+/// approved decisions are carried out by the configured [`DecisionApplier`].
+pub struct DecisionReviewQueue {
+    /// Configuration
+    config: DecisionReviewConfig,
+    /// Decision persistence
+    service: Arc<dyn MetaAgentService>,
+    /// Carries out approved decisions (optional)
+    applier: Option<Arc<dyn DecisionApplier>>,
+    /// Pending reviews by decision ID
+    pending: RwLock<HashMap<String, PendingReview>>,
+    /// Review notifications
+    events: broadcast::Sender<ReviewEvent>,
+    /// Timeout task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl DecisionReviewQueue {
+    /// Create a new review queue
+    pub fn new(config: DecisionReviewConfig, service: Arc<dyn MetaAgentService>) -> Self {
+        let (events, _) = broadcast::channel(256);
+        Self {
+            config,
+            service,
+            applier: None,
+            pending: RwLock::new(HashMap::new()),
+            events,
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Apply approved decisions through an applier
+    pub fn with_applier(mut self, applier: Arc<dyn DecisionApplier>) -> Self {
+        self.applier = Some(applier);
+        self
+    }
+
+    /// Subscribe to review notifications
+    pub fn subscribe(&self) -> broadcast::Receiver<ReviewEvent> {
+        self.events.subscribe()
+    }
+
+    /// Severity of a decision: its highest action priority, or 10 if any action demands approval
+    pub fn severity(decision: &MetaAgentDecision) -> u8 {
+        if decision.actions.iter().any(|action| action.requires_approval) {
+            return 10;
+        }
+        decision.actions.iter().map(|action| action.priority).max().unwrap_or(0)
+    }
+
+    /// Record a decision, queueing it for approval if its severity requires it.
+    ///
+    /// Returns `NotRequired` when the decision was applied immediately, otherwise `None`.
+    pub async fn submit(&self, decision: MetaAgentDecision) -> DecisionReviewResult<Option<ReviewResolution>> {
+        self.queue_decision(decision)
+            .await
+            .map(|(_, resolution)| resolution)
+            .map_err(|e| DecisionReviewError::Service(e.to_string()))
+    }
+
+    async fn queue_decision(&self, mut decision: MetaAgentDecision) -> ServiceResult<(String, Option<ReviewResolution>)> {
+        let severity = Self::severity(&decision);
+        let needs_review = self.config.enabled && severity >= self.config.severity_threshold;
+
+        decision.status = if needs_review { DecisionStatus::Proposed } else { DecisionStatus::Approved };
+        decision.auto_applied = !needs_review;
+        decision.id = self.service.record_decision(decision.clone()).await?;
+        let decision_id = decision.id.clone();
+
+        if !needs_review {
+            self.apply(&mut decision).await;
+            let _ = self.events.send(ReviewEvent::Resolved {
+                decision,
+                resolution: ReviewResolution::NotRequired,
+                operator: None,
+                note: None,
+            });
+            return Ok((decision_id, Some(ReviewResolution::NotRequired)));
+        }
+
+        let now = Utc::now();
+        let deadline = now + Duration::seconds(self.config.review_timeout_secs);
+        info!("Decision {} (severity {}) queued for operator review", decision.id, severity);
+
+        let _ = self.events.send(ReviewEvent::Queued {
+            decision_id: decision.id.clone(),
+            severity,
+            deadline,
+        });
+        self.pending.write().await.insert(decision.id.clone(), PendingReview {
+            decision,
+            severity,
+            queued_at: now,
+            deadline,
+            escalations: 0,
+        });
+
+        Ok((decision_id, None))
+    }
+
+    /// Carry out an approved decision and record whether it worked
+    async fn apply(&self, decision: &mut MetaAgentDecision) {
+        let Some(applier) = &self.applier else {
+            return;
+        };
+        decision.status = match applier.apply(decision).await {
+            Ok(()) => DecisionStatus::Completed,
+            Err(e) => {
+                warn!("Failed to apply decision {}: {}", decision.id, e);
+                DecisionStatus::Failed
+            }
+        };
+        if let Err(e) = self.service.update_decision_status(&decision.id, decision.status.clone()).await {
+            warn!("Failed to record outcome of decision {}: {}", decision.id, e);
+        }
+    }
+
+    /// Pending reviews, most severe first
+    pub async fn pending(&self) -> Vec<PendingReview> {
+        let mut reviews: Vec<PendingReview> = self.pending.read().await.values().cloned().collect();
+        reviews.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.queued_at.cmp(&b.queued_at)));
+        reviews
+    }
+
+    /// Approve a pending decision
+    pub async fn approve(&self, decision_id: &str, operator: &str, note: Option<String>) -> DecisionReviewResult<MetaAgentDecision> {
+        self.resolve(decision_id, ReviewResolution::Approved, Some(operator.to_string()), note).await
+    }
+
+    /// Reject a pending decision
+    pub async fn reject(&self, decision_id: &str, operator: &str, note: Option<String>) -> DecisionReviewResult<MetaAgentDecision> {
+        self.resolve(decision_id, ReviewResolution::Rejected, Some(operator.to_string()), note).await
+    }
+
+    /// Apply the timeout policy to reviews past their deadline
+    pub async fn process_timeouts(&self, now: DateTime<Utc>) -> usize {
+        let expired: Vec<PendingReview> = self.pending
+            .read()
+            .await
+            .values()
+            .filter(|review| review.deadline <= now)
+            .cloned()
+            .collect();
+
+        for review in &expired {
+            let decision_id = review.decision.id.as_str();
+            let result = match self.config.timeout_policy {
+                ReviewTimeoutPolicy::AutoApprove => {
+                    self.resolve(decision_id, ReviewResolution::AutoApproved, None, Some("Review timed out".to_string())).await
+                }
+                ReviewTimeoutPolicy::AutoReject => {
+                    self.resolve(decision_id, ReviewResolution::AutoRejected, None, Some("Review timed out".to_string())).await
+                }
+                ReviewTimeoutPolicy::Escalate if review.escalations < self.config.max_escalations => {
+                    self.escalate(decision_id, now).await;
+                    continue;
+                }
+                ReviewTimeoutPolicy::Escalate => {
+                    self.resolve(decision_id, ReviewResolution::AutoRejected, None, Some("Escalations exhausted".to_string())).await
+                }
+            };
+
+            if let Err(e) = result {
+                warn!("Failed to apply timeout policy to decision {}: {}", decision_id, e);
+            }
+        }
+
+        expired.len()
+    }
+
+    /// Start the background timeout task
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let queue = Arc::clone(self);
+        let interval_ms = self.config.check_interval_ms.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_millis(interval_ms));
+            loop {
+                interval.tick().await;
+                queue.process_timeouts(Utc::now()).await;
+            }
+        }));
+    }
+
+    /// Stop the background timeout task
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+
+    async fn escalate(&self, decision_id: &str, now: DateTime<Utc>) {
+        let mut pending = self.pending.write().await;
+        if let Some(review) = pending.get_mut(decision_id) {
+            review.escalations += 1;
+            review.deadline = now + Duration::seconds(self.config.review_timeout_secs);
+            warn!("Review of decision {} escalated ({} of {})", decision_id, review.escalations, self.config.max_escalations);
+
+            let _ = self.events.send(ReviewEvent::Escalated {
+                decision_id: decision_id.to_string(),
+                escalations: review.escalations,
+                deadline: review.deadline,
+            });
+        }
+    }
+
+    async fn resolve(
+        &self,
+        decision_id: &str,
+        resolution: ReviewResolution,
+        operator: Option<String>,
+        note: Option<String>,
+    ) -> DecisionReviewResult<MetaAgentDecision> {
+        if matches!(&operator, Some(op) if op.trim().is_empty()) {
+            return Err(DecisionReviewError::MissingOperator);
+        }
+
+        let mut review = self.pending
+            .write()
+            .await
+            .remove(decision_id)
+            .ok_or_else(|| DecisionReviewError::NotPending(decision_id.to_string()))?;
+
+        review.decision.status = if resolution.is_approved() { DecisionStatus::Approved } else { DecisionStatus::Rejected };
+        if let Err(e) = self.service.update_decision_status(decision_id, review.decision.status.clone()).await {
+            // Keep the review pending so it can be retried
+            self.pending.write().await.insert(decision_id.to_string(), review);
+            return Err(DecisionReviewError::Service(e.to_string()));
+        }
+
+        info!(
+            "Decision {} resolved as {:?} by {}",
+            decision_id,
+            resolution,
+            operator.as_deref().unwrap_or("timeout policy")
+        );
+        if resolution.is_approved() {
+            self.apply(&mut review.decision).await;
+        }
+
+        let _ = self.events.send(ReviewEvent::Resolved {
+            decision: review.decision.clone(),
+            resolution,
+            operator,
+            note,
+        });
+
+        Ok(review.decision)
+    }
+}
+
+#[async_trait]
+impl MetaAgentService for DecisionReviewQueue {
+    async fn register_meta_agent(&self, config: MetaAgentConfig) -> ServiceResult<String> {
+        self.service.register_meta_agent(config).await
+    }
+
+    async fn get_meta_agent(&self, id: &str) -> ServiceResult<MetaAgentConfig> {
+        self.service.get_meta_agent(id).await
+    }
+
+    async fn update_meta_agent(&self, config: MetaAgentConfig) -> ServiceResult<()> {
+        self.service.update_meta_agent(config).await
+    }
+
+    async fn list_meta_agents(&self) -> ServiceResult<Vec<MetaAgentConfig>> {
+        self.service.list_meta_agents().await
+    }
+
+    async fn deactivate_meta_agent(&self, id: &str) -> ServiceResult<()> {
+        self.service.deactivate_meta_agent(id).await
+    }
+
+    async fn record_decision(&self, decision: MetaAgentDecision) -> ServiceResult<String> {
+        self.queue_decision(decision).await.map(|(decision_id, _)| decision_id)
+    }
+
+    async fn get_decision(&self, id: &str) -> ServiceResult<MetaAgentDecision> {
+        self.service.get_decision(id).await
+    }
+
+    async fn list_decisions(&self, meta_agent_id: &str, limit: u32) -> ServiceResult<Vec<MetaAgentDecision>> {
+        self.service.list_decisions(meta_agent_id, limit).await
+    }
+
+    async fn update_decision_status(&self, id: &str, status: DecisionStatus) -> ServiceResult<()> {
+        self.service.update_decision_status(id, status).await
+    }
+
+    async fn get_meta_agent_metrics(&self, id: &str) -> ServiceResult<MetaAgentMetrics> {
+        self.service.get_meta_agent_metrics(id).await
+    }
+}
+
+/// Create a decision review queue
+pub fn create_decision_review_queue(
+    config: DecisionReviewConfig,
+    service: Arc<dyn MetaAgentService>,
+) -> Arc<DecisionReviewQueue> {
+    Arc::new(DecisionReviewQueue::new(config, service))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meta::meta_agent_service::{MetaAgentAction, MockMetaAgentService};
+    use std::sync::Mutex;
+
+    /// Records the decisions it was asked to apply
+    #[derive(Default)]
+    struct RecordingApplier {
+        applied: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl DecisionApplier for RecordingApplier {
+        async fn apply(&self, decision: &MetaAgentDecision) -> Result<(), String> {
+            self.applied.lock().unwrap().push(decision.id.clone());
+            Ok(())
+        }
+    }
+
+    fn decision(priority: u8) -> MetaAgentDecision {
+        MetaAgentDecision {
+            id: String::new(),
+            meta_agent_id: "supervisor".to_string(),
+            affected_agents: vec!["momentum".to_string()],
+            timestamp: Utc::now(),
+            reasoning: "drawdown breach".to_string(),
+            confidence: 0.9,
+            auto_applied: false,
+            status: DecisionStatus::Proposed,
+            actions: vec![MetaAgentAction {
+                action_type: ActionType::Suspension,
+                target_agent_id: "momentum".to_string(),
+                parameters: HashMap::new(),
+                priority,
+                requires_approval: false,
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_severe_decisions_wait_for_approval() {
+        let service = Arc::new(MockMetaAgentService::new());
+        let applier = Arc::new(RecordingApplier::default());
+        let queue = Arc::new(
+            DecisionReviewQueue::new(DecisionReviewConfig::default(), service.clone()).with_applier(applier.clone()),
+        );
+        let meta_agents: Arc<dyn MetaAgentService> = queue.clone();
+
+        // Routine decisions are applied as soon as they are recorded
+        let routine = meta_agents.record_decision(decision(3)).await.unwrap();
+        assert_eq!(*applier.applied.lock().unwrap(), vec![routine.clone()]);
+        assert_eq!(service.get_decision(&routine).await.unwrap().status, DecisionStatus::Completed);
+
+        // Severe ones wait for an operator
+        let severe = meta_agents.record_decision(decision(9)).await.unwrap();
+        assert_eq!(applier.applied.lock().unwrap().len(), 1);
+        assert_eq!(queue.pending().await.len(), 1);
+
+        let approved = queue.approve(&severe, "alice", None).await.unwrap();
+        assert_eq!(approved.status, DecisionStatus::Completed);
+        assert_eq!(applier.applied.lock().unwrap().last(), Some(&severe));
+
+        // Rejected decisions are never applied
+        let rejected = meta_agents.record_decision(decision(9)).await.unwrap();
+        queue.reject(&rejected, "alice", Some("too aggressive".to_string())).await.unwrap();
+        assert_eq!(applier.applied.lock().unwrap().len(), 2);
+        assert_eq!(service.get_decision(&rejected).await.unwrap().status, DecisionStatus::Rejected);
+    }
+}
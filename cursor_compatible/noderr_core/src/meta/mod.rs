@@ -1,4 +1,5 @@
 pub mod meta_agent_service;
+pub mod decision_review;
 
 pub use meta_agent_service::{
     ActionType,
@@ -13,4 +14,16 @@ pub use meta_agent_service::{
     MockMetaAgentService,
     RedisMetaAgentService,
     SupervisionLevel,
-}; 
\ No newline at end of file
+};
+pub use decision_review::{
+    DecisionApplier,
+    DecisionReviewConfig,
+    DecisionReviewError,
+    DecisionReviewQueue,
+    DecisionReviewResult,
+    PendingReview,
+    ReviewEvent,
+    ReviewResolution,
+    ReviewTimeoutPolicy,
+    create_decision_review_queue,
+};
\ No newline at end of file
@@ -27,6 +27,8 @@ use tracing::{debug, error, info, warn};
 
 use crate::market::{MarketData, Symbol, Candle, Timeframe};
 use crate::redis::{RedisClient, RedisClientResult};
+use crate::dead_letter::DeadLetterQueue;
+use crate::volume_profile;
 
 /// Error types for footprint operations
 #[derive(Debug, Error)]
@@ -231,50 +233,13 @@ impl FootprintChartData {
             return;
         }
         
-        // Sort by volume (highest first)
-        let mut levels = self.price_levels.clone();
-        levels.sort_by(|a, b| b.total_volume().partial_cmp(&a.total_volume()).unwrap_or(std::cmp::Ordering::Equal));
-        
-        // Calculate total volume
-        let total_volume = levels.iter().map(|l| l.total_volume()).sum::<f64>();
-        let target_volume = total_volume * 0.7; // 70% of volume
-        
-        // Start from POC and expand
-        let poc_price = self.poc_price;
-        let mut included_prices = vec![poc_price];
-        let mut included_volume = levels.iter()
-            .find(|l| (l.price - poc_price).abs() < 0.00001)
-            .map(|l| l.total_volume())
-            .unwrap_or(0.0);
-        
-        // Sort remaining levels by distance from POC
-        let mut remaining = levels.iter()
-            .filter(|l| (l.price - poc_price).abs() >= 0.00001)
-            .collect::<Vec<_>>();
-            
-        remaining.sort_by(|a, b| {
-            let a_dist = (a.price - poc_price).abs();
-            let b_dist = (b.price - poc_price).abs();
-            a_dist.partial_cmp(&b_dist).unwrap_or(std::cmp::Ordering::Equal)
-        });
-        
-        // Add levels to value area until we reach target volume
-        for level in remaining {
-            if included_volume >= target_volume {
-                break;
-            }
-            
-            included_prices.push(level.price);
-            included_volume += level.total_volume();
-        }
+        let levels: Vec<(f64, f64)> = self.price_levels.iter()
+            .map(|l| (l.price, l.total_volume()))
+            .collect();
         
-        // Find high/low of included prices
-        if !included_prices.is_empty() {
-            let value_area_high = included_prices.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
-            let value_area_low = included_prices.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-            
-            self.value_area_high = Some(value_area_high);
-            self.value_area_low = Some(value_area_low);
+        if let Some((low, high)) = volume_profile::value_area(&levels, self.poc_price, volume_profile::DEFAULT_VALUE_AREA_PCT) {
+            self.value_area_high = Some(high);
+            self.value_area_low = Some(low);
         }
     }
     
@@ -453,7 +418,9 @@ pub struct DefaultFootprintPipeline {
     footprints: RwLock<HashMap<String, FootprintChartData>>,
     
     /// Cache of trades by candle
-    trades_by_candle: RwLock<HashMap<String, Vec<FootprintTrade>>>,
+    trades_by_candle: RwLock<HashMap<String, Vec<FootprintTrade>>>,    
+    /// Dead-letter queue for writes that fail
+    dead_letters: Option<Arc<DeadLetterQueue>>,
 }
 
 impl DefaultFootprintPipeline {
@@ -464,6 +431,7 @@ impl DefaultFootprintPipeline {
             config: RwLock::new(FootprintConfig::default()),
             footprints: RwLock::new(HashMap::new()),
             trades_by_candle: RwLock::new(HashMap::new()),
+            dead_letters: None,
         }
     }
     
@@ -474,9 +442,16 @@ impl DefaultFootprintPipeline {
             config: RwLock::new(config),
             footprints: RwLock::new(HashMap::new()),
             trades_by_candle: RwLock::new(HashMap::new()),
+            dead_letters: None,
         }
     }
     
+    /// Capture failed Redis writes for replay instead of dropping them
+    pub fn with_dead_letter_queue(mut self, queue: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
+    
     /// Key for footprint data
     fn footprint_key(&self, symbol: &Symbol, timeframe: &str, timestamp: i64) -> String {
         format!("micro:footprint:{}:{}:{}", symbol, timeframe, timestamp)
@@ -497,22 +472,7 @@ impl DefaultFootprintPipeline {
         let config = self.config.read().unwrap();
         
         match config.price_bucket_mode {
-            PriceBucketMode::Automatic => {
-                // Determine automatically based on price level
-                if price < 0.1 {
-                    0.00001
-                } else if price < 1.0 {
-                    0.0001
-                } else if price < 10.0 {
-                    0.001
-                } else if price < 100.0 {
-                    0.01
-                } else if price < 1000.0 {
-                    0.1
-                } else {
-                    1.0
-                }
-            },
+            PriceBucketMode::Automatic => volume_profile::automatic_bucket_size(price),
             PriceBucketMode::FixedDecimals(decimals) => {
                 let factor = 10f64.powi(decimals as i32);
                 1.0 / factor
@@ -533,10 +493,20 @@ impl DefaultFootprintPipeline {
             Ok(_) => {
                 // Update latest timestamp
                 let latest_key = self.latest_footprint_key(&footprint.symbol, &footprint.timeframe);
-                let _ = self.redis.set(&latest_key, &footprint.timestamp.timestamp(), Some(86400 * 30)).await;
+                let latest = footprint.timestamp.timestamp();
+                if let Err(e) = self.redis.set(&latest_key, &latest, Some(86400 * 30)).await {
+                    if let Some(dead_letters) = &self.dead_letters {
+                        dead_letters.push_set(&latest_key, &latest, Some(86400 * 30), &e);
+                    }
+                }
                 Ok(())
             },
-            Err(e) => Err(FootprintError::Redis(e.to_string())),
+            Err(e) => {
+                if let Some(dead_letters) = &self.dead_letters {
+                    dead_letters.push_set(&key, footprint, Some(86400 * 30), &e);
+                }
+                Err(FootprintError::Redis(e.to_string()))
+            }
         }
     }
     
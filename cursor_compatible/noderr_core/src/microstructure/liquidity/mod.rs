@@ -29,6 +29,8 @@ use rust_decimal::Decimal;
 
 use crate::market::{MarketData, Orderbook, Symbol, Ticker};
 use crate::redis::{RedisClient, RedisClientResult};
+use crate::dead_letter::DeadLetterQueue;
+use crate::microstructure::write_behind::WriteBehindBatcher;
 
 /// Error types for liquidity operations
 #[derive(Debug, Error)]
@@ -254,6 +256,12 @@ pub struct DefaultLiquidityProfiler {
     
     /// Market-specific standard sizes
     standard_sizes: RwLock<HashMap<Symbol, f64>>,
+    
+    /// Dead-letter queue for writes that fail
+    dead_letters: Option<Arc<DeadLetterQueue>>,
+    
+    /// Write-behind batcher; when set, writes are queued instead of awaited
+    write_behind: Option<Arc<WriteBehindBatcher>>,
 }
 
 impl DefaultLiquidityProfiler {
@@ -264,6 +272,8 @@ impl DefaultLiquidityProfiler {
             config: RwLock::new(LiquidityProfilerConfig::default()),
             snapshots: RwLock::new(HashMap::new()),
             standard_sizes: RwLock::new(HashMap::new()),
+            dead_letters: None,
+            write_behind: None,
         }
     }
     
@@ -274,9 +284,23 @@ impl DefaultLiquidityProfiler {
             config: RwLock::new(config),
             snapshots: RwLock::new(HashMap::new()),
             standard_sizes: RwLock::new(HashMap::new()),
+            dead_letters: None,
+            write_behind: None,
         }
     }
     
+    /// Capture failed Redis writes for replay instead of dropping them
+    pub fn with_dead_letter_queue(mut self, queue: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
+    
+    /// Batch Redis writes in the background so order book processing never waits on Redis
+    pub fn with_write_behind(mut self, batcher: Arc<WriteBehindBatcher>) -> Self {
+        self.write_behind = Some(batcher);
+        self
+    }
+    
     /// Redis key for liquidity snapshot
     fn snapshot_key(&self, symbol: &Symbol) -> String {
         format!("micro:liquidity:{}", symbol)
@@ -532,11 +556,22 @@ impl DefaultLiquidityProfiler {
     /// Store snapshot in Redis
     async fn store_snapshot(&self, snapshot: &LiquiditySnapshot) -> LiquidityResult<()> {
         // Store current snapshot
+        let key = self.snapshot_key(&snapshot.symbol);
+        if let Some(batcher) = &self.write_behind {
+            let historical_key = self.historical_key(&snapshot.symbol, snapshot.timestamp.timestamp());
+            batcher.set(&key, snapshot, Some(3600));
+            batcher.set(&historical_key, snapshot, Some(86400 * 7));
+            return Ok(());
+        }
+        
         if let Err(e) = self.redis.set(
-            &self.snapshot_key(&snapshot.symbol),
+            &key,
             snapshot,
             Some(3600) // 1 hour TTL
         ).await {
+            if let Some(dead_letters) = &self.dead_letters {
+                dead_letters.push_set(&key, snapshot, Some(3600), &e);
+            }
             return Err(LiquidityError::Redis(e.to_string()));
         }
         
@@ -548,7 +583,9 @@ impl DefaultLiquidityProfiler {
             Some(86400 * 7) // 7 days TTL
         ).await {
             warn!("Failed to store historical snapshot: {}", e);
-            // Continue anyway, not critical
+            if let Some(dead_letters) = &self.dead_letters {
+                dead_letters.push_set(&historical_key, snapshot, Some(86400 * 7), &e);
+            }
         }
         
         Ok(())
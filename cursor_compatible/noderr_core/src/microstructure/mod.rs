@@ -21,6 +21,8 @@ pub mod order_flow;
 pub mod liquidity;
 pub mod footprint;
 pub mod timing_signals;
+pub mod write_behind;
+pub mod spread_forecast;
 
 // Re-export main types
 pub use order_flow::{
@@ -50,4 +52,19 @@ pub use timing_signals::{
     TimingSignalEngine,
     SignalConfidence,
     create_timing_signal_engine
+};
+
+pub use spread_forecast::{
+    SpreadForecaster,
+    SpreadForecast,
+    SpreadForecastConfig,
+    create_spread_forecaster
+};
+
+pub use write_behind::{
+    WriteBehindBatcher,
+    WriteBehindConfig,
+    WriteBehindStats,
+    OverflowPolicy,
+    create_write_behind_batcher
 }; 
\ No newline at end of file
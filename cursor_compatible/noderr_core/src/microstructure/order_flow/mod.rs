@@ -17,7 +17,7 @@
 //! This module provides tools for analyzing real-time market order flow,
 //! including trade aggressiveness, order book imbalance, and cumulative delta.
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
@@ -26,10 +26,13 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal_macros::dec;
 
 use crate::market::{MarketData, Orderbook, OrderbookEntry, Symbol, Candle, Ticker};
 use crate::redis::{RedisClient, RedisClientResult};
+use crate::dead_letter::DeadLetterQueue;
+use crate::microstructure::write_behind::WriteBehindBatcher;
 
 /// Error types for order flow operations
 #[derive(Debug, Error)]
@@ -283,6 +286,15 @@ pub enum OrderFlowEvent {
         cancel_time_ms: u64,
         /// Confidence level (0-100)
         confidence: u8,
+        /// Large orders on this side cancelled without execution within the lookback
+        #[serde(default)]
+        cancel_count: usize,
+        /// Mean time those orders rested before cancellation (ms)
+        #[serde(default)]
+        mean_cancel_time_ms: u64,
+        /// Shortest time one of those orders rested before cancellation (ms)
+        #[serde(default)]
+        min_cancel_time_ms: u64,
         /// Timestamp of event
         timestamp: DateTime<Utc>,
     },
@@ -297,6 +309,24 @@ pub enum OrderFlowEvent {
         timeframe_ms: u64,
         /// Side where stuffing occurred
         side: String,
+        /// Bid levels added or grown within the timeframe
+        #[serde(default)]
+        bid_adds: usize,
+        /// Bid levels cancelled or shrunk within the timeframe
+        #[serde(default)]
+        bid_cancels: usize,
+        /// Ask levels added or grown within the timeframe
+        #[serde(default)]
+        ask_adds: usize,
+        /// Ask levels cancelled or shrunk within the timeframe
+        #[serde(default)]
+        ask_cancels: usize,
+        /// Message rate over the timeframe (per second)
+        #[serde(default)]
+        message_rate: f64,
+        /// Normal message rate the current one was compared with (per second)
+        #[serde(default)]
+        baseline_rate: f64,
         /// Timestamp of event
         timestamp: DateTime<Utc>,
     },
@@ -431,6 +461,7 @@ impl OrderFlowMetrics {
 
 /// Configuration for the order flow analyzer
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OrderFlowConfig {
     /// Maximum number of events to keep in history
     pub max_events: usize,
@@ -456,8 +487,33 @@ pub struct OrderFlowConfig {
     /// Whether to track spoofing attempts
     pub detect_spoofing: bool,
     
+    /// Size, as a multiple of the average visible level, at which an order
+    /// added to a level is watched for spoofing
+    pub spoof_size_multiple: f64,
+    
+    /// Watched orders pulled within this many milliseconds without executing count as possible spoofs
+    pub spoof_cancel_window_ms: u64,
+    
+    /// Seconds of cancels considered when scoring repeated spoofing
+    pub spoof_lookback_sec: u64,
+    
+    /// Cancels within the lookback at which repetition adds full confidence
+    pub spoof_repeat_count: usize,
+    
     /// Whether to track quote stuffing
     pub detect_quote_stuffing: bool,
+    
+    /// Rolling window over which book messages are counted (ms)
+    pub stuffing_window_ms: u64,
+    
+    /// Message rate, as a multiple of the baseline, that counts as stuffing
+    pub stuffing_rate_multiple: f64,
+    
+    /// Fewest messages within the window before stuffing is reported
+    pub stuffing_min_messages: usize,
+    
+    /// Weight of each new window in the baseline message rate
+    pub stuffing_baseline_alpha: f64,
 }
 
 impl Default for OrderFlowConfig {
@@ -471,7 +527,15 @@ impl Default for OrderFlowConfig {
             large_trade_threshold: 3.0, // 3x average trade size
             manipulation_detection_threshold: 0.7,
             detect_spoofing: true,
+            spoof_size_multiple: 5.0,
+            spoof_cancel_window_ms: 2_000,
+            spoof_lookback_sec: 60,
+            spoof_repeat_count: 3,
             detect_quote_stuffing: true,
+            stuffing_window_ms: 1_000,
+            stuffing_rate_multiple: 10.0,
+            stuffing_min_messages: 50,
+            stuffing_baseline_alpha: 0.1,
         }
     }
 }
@@ -507,6 +571,325 @@ pub trait OrderFlowAnalyzer: Send + Sync {
     async fn update_config(&self, config: OrderFlowConfig) -> OrderFlowResult<()>;
 }
 
+/// Bid side label used in order flow events
+const BID_SIDE: &str = "bid";
+
+/// Ask side label used in order flow events
+const ASK_SIDE: &str = "ask";
+
+/// Fraction of a watched order that must leave its level for it to count as gone
+const SPOOF_REMOVED_FRACTION: f64 = 0.8;
+
+/// Most of a watched order that may execute before its removal stops looking like a cancel
+const SPOOF_MAX_EXECUTED_FRACTION: f64 = 0.2;
+
+/// Large order resting at a price level, watched until it executes, is pulled or ages out
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    /// Quantity added to the level
+    size: f64,
+    /// Size as a multiple of the average visible level
+    size_multiple: f64,
+    /// Level quantity right after the order was added
+    level_quantity: f64,
+    /// Quantity traded at or through the level since the order appeared
+    executed: f64,
+    /// When the order appeared
+    placed_at: DateTime<Utc>,
+}
+
+/// Large order pulled without executing
+#[derive(Debug, Clone)]
+struct SpoofCancel {
+    /// Side the order rested on
+    side: &'static str,
+    /// Order size
+    size: f64,
+    /// Size as a multiple of the average visible level
+    size_multiple: f64,
+    /// How long the order rested (ms)
+    cancel_time_ms: u64,
+    /// When the order disappeared
+    cancelled_at: DateTime<Utc>,
+}
+
+/// Per-symbol state for spoofing detection. Venues only publish aggregated
+/// levels, so a large quantity appearing at one level stands in for an order.
+#[derive(Debug, Default)]
+struct SpoofTracker {
+    /// Visible quantity by side and price at the last update
+    levels: HashMap<(&'static str, Decimal), f64>,
+    /// Watched orders by side and price
+    resting: HashMap<(&'static str, Decimal), RestingOrder>,
+    /// Recent cancels without execution, oldest first
+    cancels: VecDeque<SpoofCancel>,
+}
+
+impl SpoofTracker {
+    /// Credit a trade to watched orders it could have filled: buys lift asks
+    /// at or below the trade price, sells hit bids at or above it
+    fn record_execution(&mut self, price: f64, quantity: f64, is_buy: bool) {
+        for ((side, level_price), order) in self.resting.iter_mut() {
+            let level_price = level_price.to_f64().unwrap_or(0.0);
+            let crossed = if is_buy {
+                *side == ASK_SIDE && level_price <= price
+            } else {
+                *side == BID_SIDE && level_price >= price
+            };
+            if crossed {
+                order.executed += quantity;
+            }
+        }
+    }
+
+    /// Compare a book update with the last one; returns orders pulled in this update
+    fn update(&mut self, orderbook: &Orderbook, config: &OrderFlowConfig) -> Vec<SpoofCancel> {
+        let now = orderbook.timestamp;
+        let mut pulled = Vec::new();
+
+        for (side, entries) in [(BID_SIDE, &orderbook.bids), (ASK_SIDE, &orderbook.asks)] {
+            let current: HashMap<Decimal, f64> = entries.iter()
+                .take(config.order_book_depth)
+                .map(|entry| (entry.price, entry.quantity.to_f64().unwrap_or(0.0)))
+                .collect();
+
+            // Averaged over the previous update so a new large order does not inflate its own baseline
+            let previous: Vec<f64> = self.levels.iter()
+                .filter(|((level_side, _), _)| *level_side == side)
+                .map(|(_, quantity)| *quantity)
+                .collect();
+            let average = if previous.is_empty() {
+                0.0
+            } else {
+                previous.iter().sum::<f64>() / previous.len() as f64
+            };
+
+            if average > 0.0 {
+                for (price, quantity) in &current {
+                    let added = quantity - self.levels.get(&(side, *price)).copied().unwrap_or(0.0);
+                    if added >= config.spoof_size_multiple * average {
+                        self.resting.entry((side, *price)).or_insert(RestingOrder {
+                            size: added,
+                            size_multiple: added / average,
+                            level_quantity: *quantity,
+                            executed: 0.0,
+                            placed_at: now,
+                        });
+                    }
+                }
+            }
+
+            let lowest = current.keys().min().copied();
+            let highest = current.keys().max().copied();
+            self.resting.retain(|(order_side, price), order| {
+                if *order_side != side {
+                    return true;
+                }
+
+                let age_ms = (now - order.placed_at).num_milliseconds().max(0) as u64;
+                let visible = match current.get(price) {
+                    Some(quantity) => *quantity,
+                    // A level inside the visible range that is missing was emptied
+                    None if lowest.is_some_and(|low| *price >= low) && highest.is_some_and(|high| *price <= high) => 0.0,
+                    // The book moved away from the level; nothing more can be learned
+                    None => return false,
+                };
+
+                let removed = order.level_quantity - visible;
+                if removed >= order.size * SPOOF_REMOVED_FRACTION {
+                    if order.executed <= order.size * SPOOF_MAX_EXECUTED_FRACTION && age_ms <= config.spoof_cancel_window_ms {
+                        pulled.push(SpoofCancel {
+                            side,
+                            size: order.size,
+                            size_multiple: order.size_multiple,
+                            cancel_time_ms: age_ms,
+                            cancelled_at: now,
+                        });
+                    }
+                    return false;
+                }
+
+                // Orders that outlive the window are treated as genuine liquidity
+                age_ms <= config.spoof_cancel_window_ms
+            });
+
+            self.levels.retain(|(level_side, _), _| *level_side != side);
+            self.levels.extend(current.into_iter().map(|(price, quantity)| ((side, price), quantity)));
+        }
+
+        self.cancels.extend(pulled.iter().cloned());
+        let cutoff = now - chrono::Duration::seconds(config.spoof_lookback_sec as i64);
+        while self.cancels.front().map(|cancel| cancel.cancelled_at < cutoff).unwrap_or(false) {
+            self.cancels.pop_front();
+        }
+
+        pulled
+    }
+}
+
+/// Confidence (0-100) that a pulled order was a spoof: faster cancels,
+/// larger orders and repeated cancels on the same side all raise it
+fn spoof_confidence(cancel: &SpoofCancel, repeats: usize, config: &OrderFlowConfig) -> u8 {
+    let window = config.spoof_cancel_window_ms.max(1) as f64;
+    let speed = (1.0 - cancel.cancel_time_ms as f64 / window).clamp(0.0, 1.0);
+    let size = (cancel.size_multiple / (2.0 * config.spoof_size_multiple)).min(1.0);
+    let repetition = (repeats as f64 / config.spoof_repeat_count.max(1) as f64).min(1.0);
+    ((0.4 * speed + 0.3 * size + 0.3 * repetition) * 100.0).round() as u8
+}
+
+/// Order adds and cancels seen on one side in one book update
+#[derive(Debug, Clone)]
+struct BookMessages {
+    /// Update time
+    at: DateTime<Utc>,
+    /// Side the messages were on
+    side: &'static str,
+    /// Levels added or grown
+    adds: usize,
+    /// Levels cancelled or shrunk
+    cancels: usize,
+}
+
+/// Message counts over the window that tripped the stuffing threshold
+#[derive(Debug, Clone, Default)]
+struct StuffingWindow {
+    bid_adds: usize,
+    bid_cancels: usize,
+    ask_adds: usize,
+    ask_cancels: usize,
+    /// Time covered by the counted messages (ms)
+    span_ms: u64,
+    /// Messages per second over the window
+    rate: f64,
+    /// Baseline messages per second
+    baseline: f64,
+}
+
+impl StuffingWindow {
+    fn total(&self) -> usize {
+        self.bid_adds + self.bid_cancels + self.ask_adds + self.ask_cancels
+    }
+
+    /// Side carrying at least two thirds of the messages, or `both`
+    fn dominant_side(&self) -> &'static str {
+        let bids = (self.bid_adds + self.bid_cancels) as f64;
+        let asks = (self.ask_adds + self.ask_cancels) as f64;
+        let total = bids + asks;
+        if bids * 3.0 >= total * 2.0 {
+            BID_SIDE
+        } else if asks * 3.0 >= total * 2.0 {
+            ASK_SIDE
+        } else {
+            "both"
+        }
+    }
+}
+
+/// Per-symbol book message rates for quote stuffing detection. Venues
+/// publish aggregated levels, so changes in a level's order count (or in
+/// its quantity when counts are missing) stand in for order messages.
+#[derive(Debug, Default)]
+struct StuffingTracker {
+    /// Quantity and order count by side and price at the last update
+    levels: HashMap<(&'static str, Decimal), (f64, Option<u32>)>,
+    /// Messages within the window, oldest first
+    messages: VecDeque<BookMessages>,
+    /// Normal messages per second; unset until one full window was seen
+    baseline_rate: Option<f64>,
+    /// When the baseline last took in a window
+    baseline_updated_at: Option<DateTime<Utc>>,
+    /// When stuffing was last reported
+    last_alert: Option<DateTime<Utc>>,
+}
+
+impl StuffingTracker {
+    /// Count messages in a book update; returns the window when it is stuffing
+    /// and no alert was raised within the last window
+    fn update(&mut self, orderbook: &Orderbook, config: &OrderFlowConfig) -> Option<StuffingWindow> {
+        let now = orderbook.timestamp;
+        let first_update = self.levels.is_empty();
+        let mut current = HashMap::new();
+
+        for (side, entries) in [(BID_SIDE, &orderbook.bids), (ASK_SIDE, &orderbook.asks)] {
+            for entry in entries.iter().take(config.order_book_depth) {
+                current.insert((side, entry.price), (entry.quantity.to_f64().unwrap_or(0.0), entry.count));
+            }
+            if first_update {
+                continue;
+            }
+
+            let (mut adds, mut cancels) = (0, 0);
+            let prices = current.keys().chain(self.levels.keys())
+                .filter(|(level_side, _)| *level_side == side)
+                .collect::<HashSet<_>>();
+            for key in prices {
+                let (before_qty, before_count) = self.levels.get(key).copied().unwrap_or((0.0, Some(0)));
+                let (after_qty, after_count) = current.get(key).copied().unwrap_or((0.0, Some(0)));
+                match (before_count, after_count) {
+                    (Some(before), Some(after)) if before != after => {
+                        adds += after.saturating_sub(before) as usize;
+                        cancels += before.saturating_sub(after) as usize;
+                    }
+                    _ if after_qty > before_qty => adds += 1,
+                    _ if after_qty < before_qty => cancels += 1,
+                    _ => {}
+                }
+            }
+            if adds + cancels > 0 {
+                self.messages.push_back(BookMessages { at: now, side, adds, cancels });
+            }
+        }
+        self.levels = current;
+
+        let window = chrono::Duration::milliseconds(config.stuffing_window_ms as i64);
+        while self.messages.front().map(|m| m.at <= now - window).unwrap_or(false) {
+            self.messages.pop_front();
+        }
+
+        let mut counts = StuffingWindow::default();
+        for message in &self.messages {
+            if message.side == BID_SIDE {
+                counts.bid_adds += message.adds;
+                counts.bid_cancels += message.cancels;
+            } else {
+                counts.ask_adds += message.adds;
+                counts.ask_cancels += message.cancels;
+            }
+        }
+        counts.span_ms = self.messages.front()
+            .map(|oldest| (now - oldest.at).num_milliseconds().max(0) as u64)
+            .unwrap_or(0);
+        counts.rate = counts.total() as f64 * 1000.0 / config.stuffing_window_ms.max(1) as f64;
+        counts.baseline = self.baseline_rate.unwrap_or(0.0);
+
+        let stuffing = self.baseline_rate.is_some()
+            && counts.total() >= config.stuffing_min_messages
+            && counts.rate >= config.stuffing_rate_multiple * counts.baseline;
+
+        // Fold one window at a time into the baseline, and never a stuffed one
+        let baseline_due = match self.baseline_updated_at {
+            Some(at) => now - at >= window,
+            None => {
+                self.baseline_updated_at = Some(now);
+                false
+            }
+        };
+        if baseline_due && !stuffing {
+            self.baseline_rate = Some(match self.baseline_rate {
+                Some(baseline) => baseline + config.stuffing_baseline_alpha * (counts.rate - baseline),
+                None => counts.rate,
+            });
+            self.baseline_updated_at = Some(now);
+        }
+
+        if !stuffing || self.last_alert.is_some_and(|at| now - at < window) {
+            return None;
+        }
+        self.last_alert = Some(now);
+        Some(counts)
+    }
+}
+
 /// Implementation of the OrderFlowAnalyzer
 pub struct DefaultOrderFlowAnalyzer {
     /// Redis client for persistence
@@ -529,6 +912,18 @@ pub struct DefaultOrderFlowAnalyzer {
     
     /// Previous order books for comparing changes
     previous_orderbooks: RwLock<HashMap<Symbol, (Orderbook, DateTime<Utc>)>>,
+    
+    /// Large resting orders and recent cancels watched for spoofing
+    spoof_trackers: RwLock<HashMap<Symbol, SpoofTracker>>,
+    
+    /// Book message rates watched for quote stuffing
+    stuffing_trackers: RwLock<HashMap<Symbol, StuffingTracker>>,
+    
+    /// Dead-letter queue for writes that fail
+    dead_letters: Option<Arc<DeadLetterQueue>>,
+    
+    /// Write-behind batcher; when set, writes are queued instead of awaited
+    write_behind: Option<Arc<WriteBehindBatcher>>,
 }
 
 impl DefaultOrderFlowAnalyzer {
@@ -547,9 +942,25 @@ impl DefaultOrderFlowAnalyzer {
             historical_trades: RwLock::new(HashMap::new()),
             avg_trade_sizes: RwLock::new(HashMap::new()),
             previous_orderbooks: RwLock::new(HashMap::new()),
+            spoof_trackers: RwLock::new(HashMap::new()),
+            stuffing_trackers: RwLock::new(HashMap::new()),
+            dead_letters: None,
+            write_behind: None,
         }
     }
     
+    /// Capture failed Redis writes for replay instead of dropping them
+    pub fn with_dead_letter_queue(mut self, queue: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
+    
+    /// Batch Redis writes in the background so trade processing never waits on Redis
+    pub fn with_write_behind(mut self, batcher: Arc<WriteBehindBatcher>) -> Self {
+        self.write_behind = Some(batcher);
+        self
+    }
+    
     /// Redis key for order flow metrics
     fn metrics_key(&self, symbol: &Symbol) -> String {
         format!("micro:orderflow:{}", symbol)
@@ -656,10 +1067,71 @@ impl DefaultOrderFlowAnalyzer {
     
     /// Detect possible spoofing (large orders that get canceled quickly)
     fn detect_spoofing(&self, symbol: &Symbol, orderbook: &Orderbook) -> Option<OrderFlowEvent> {
-        // Placeholder for spoofing detection
-        // This would require tracking individual orders over time
-        // to detect patterns of placing large orders and canceling them
-        None
+        let config = match self.config.try_read() {
+            Ok(cfg) => cfg,
+            Err(_) => return None,
+        };
+        
+        let mut trackers = self.spoof_trackers.write().unwrap();
+        let tracker = trackers.entry(symbol.clone()).or_default();
+        let cancel = tracker.update(orderbook, &config)
+            .into_iter()
+            .max_by(|a, b| a.size.total_cmp(&b.size))?;
+        
+        // Cancel-time statistics over the lookback, including this cancel
+        let cancel_times: Vec<u64> = tracker.cancels.iter()
+            .filter(|c| c.side == cancel.side)
+            .map(|c| c.cancel_time_ms)
+            .collect();
+        let cancel_count = cancel_times.len();
+        let mean_cancel_time_ms = cancel_times.iter().sum::<u64>() / cancel_count as u64;
+        let min_cancel_time_ms = cancel_times.iter().min().copied().unwrap_or(cancel.cancel_time_ms);
+        
+        let confidence = spoof_confidence(&cancel, cancel_count, &config);
+        if (confidence as f64) < config.manipulation_detection_threshold * 100.0 {
+            debug!(
+                "Large {} order on {} pulled after {}ms (confidence {} below threshold)",
+                cancel.side, symbol, cancel.cancel_time_ms, confidence
+            );
+            return None;
+        }
+        
+        Some(OrderFlowEvent::PossibleSpoofing {
+            symbol: symbol.clone(),
+            side: cancel.side.to_string(),
+            size: cancel.size,
+            cancel_time_ms: cancel.cancel_time_ms,
+            confidence,
+            cancel_count,
+            mean_cancel_time_ms,
+            min_cancel_time_ms,
+            timestamp: orderbook.timestamp,
+        })
+    }
+    
+    /// Detect quote stuffing (bursts of order adds and cancels far above the normal rate)
+    fn detect_quote_stuffing(&self, symbol: &Symbol, orderbook: &Orderbook) -> Option<OrderFlowEvent> {
+        let config = match self.config.try_read() {
+            Ok(cfg) => cfg,
+            Err(_) => return None,
+        };
+        
+        let mut trackers = self.stuffing_trackers.write().unwrap();
+        let window = trackers.entry(symbol.clone()).or_default().update(orderbook, &config)?;
+        
+        Some(OrderFlowEvent::QuoteStuffing {
+            symbol: symbol.clone(),
+            order_count: window.total(),
+            timeframe_ms: window.span_ms,
+            side: window.dominant_side().to_string(),
+            bid_adds: window.bid_adds,
+            bid_cancels: window.bid_cancels,
+            ask_adds: window.ask_adds,
+            ask_cancels: window.ask_cancels,
+            message_rate: window.rate,
+            baseline_rate: window.baseline,
+            timestamp: orderbook.timestamp,
+        })
     }
     
     /// Calculate trade aggressiveness
@@ -765,9 +1237,20 @@ impl DefaultOrderFlowAnalyzer {
     
     /// Store metrics in Redis
     async fn store_metrics(&self, metrics: &OrderFlowMetrics) -> OrderFlowResult<()> {
-        match self.redis.set(&self.metrics_key(&metrics.symbol), metrics, Some(3600)).await {
+        let key = self.metrics_key(&metrics.symbol);
+        if let Some(batcher) = &self.write_behind {
+            batcher.set(&key, metrics, Some(3600));
+            return Ok(());
+        }
+        
+        match self.redis.set(&key, metrics, Some(3600)).await {
             Ok(_) => Ok(()),
-            Err(e) => Err(OrderFlowError::Redis(e.to_string())),
+            Err(e) => {
+                if let Some(dead_letters) = &self.dead_letters {
+                    dead_letters.push_set(&key, metrics, Some(3600), &e);
+                }
+                Err(OrderFlowError::Redis(e.to_string()))
+            }
         }
     }
     
@@ -775,24 +1258,40 @@ impl DefaultOrderFlowAnalyzer {
     async fn store_event(&self, symbol: &Symbol, event: &OrderFlowEvent) -> OrderFlowResult<()> {
         let key = self.events_key(symbol);
         
+        let max_events = self.config.read().unwrap().max_events;
+        
+        if let Some(batcher) = &self.write_behind {
+            batcher.append(&key, event, max_events, Some(86400));
+            return Ok(());
+        }
+        
         // First get existing events
         let mut events: Vec<OrderFlowEvent> = match self.redis.get(&key).await {
             Ok(Some(evts)) => evts,
             Ok(None) => Vec::new(),
-            Err(e) => return Err(OrderFlowError::Redis(e.to_string())),
+            Err(e) => {
+                if let Some(dead_letters) = &self.dead_letters {
+                    dead_letters.push_append(&key, event, max_events, Some(86400), &e);
+                }
+                return Err(OrderFlowError::Redis(e.to_string()));
+            }
         };
         
         // Add new event and trim
         events.push(event.clone());
-        let config = self.config.read().unwrap();
-        if events.len() > config.max_events {
+        if events.len() > max_events {
             events.remove(0);
         }
         
         // Store back
         match self.redis.set(&key, &events, Some(86400)).await { // 24h TTL
             Ok(_) => Ok(()),
-            Err(e) => Err(OrderFlowError::Redis(e.to_string())),
+            Err(e) => {
+                if let Some(dead_letters) = &self.dead_letters {
+                    dead_letters.push_append(&key, event, max_events, Some(86400), &e);
+                }
+                Err(OrderFlowError::Redis(e.to_string()))
+            }
         }
     }
 }
@@ -971,6 +1470,11 @@ impl OrderFlowAnalyzer for DefaultOrderFlowAnalyzer {
             aggression,
         );
         
+        // Fills at watched levels mean those orders were not pulled
+        if let Some(tracker) = self.spoof_trackers.write().unwrap().get_mut(symbol) {
+            tracker.record_execution(price, quantity, is_buy);
+        }
+        
         // Add to historical trades
         {
             let mut historical_trades = self.historical_trades.write().unwrap();
@@ -1066,6 +1570,25 @@ impl OrderFlowAnalyzer for DefaultOrderFlowAnalyzer {
             }
         }
         
+        // Check for quote stuffing if enabled
+        if self.config.read().unwrap().detect_quote_stuffing {
+            if let Some(event) = self.detect_quote_stuffing(symbol, orderbook) {
+                // Cache the event
+                let mut events_cache = self.events_cache.write().unwrap();
+                let events = events_cache.entry(symbol.clone()).or_insert_with(VecDeque::new);
+                events.push_back(event.clone());
+                
+                // Trim if needed
+                let config = self.config.read().unwrap();
+                while events.len() > config.max_events {
+                    events.pop_front();
+                }
+                
+                // Store in Redis
+                let _ = self.store_event(symbol, &event).await;
+            }
+        }
+        
         // Calculate imbalance
         let imbalance = self.analyze_orderbook_imbalance(orderbook);
         
@@ -1280,6 +1803,12 @@ impl OrderFlowAnalyzer for DefaultOrderFlowAnalyzer {
             
             let mut previous_orderbooks = self.previous_orderbooks.write().unwrap();
             previous_orderbooks.remove(symbol);
+            
+            let mut spoof_trackers = self.spoof_trackers.write().unwrap();
+            spoof_trackers.remove(symbol);
+            
+            let mut stuffing_trackers = self.stuffing_trackers.write().unwrap();
+            stuffing_trackers.remove(symbol);
         }
         
         // Remove from Redis
@@ -1524,4 +2053,112 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().symbol, symbol);
     }
-} 
\ No newline at end of file
+    
+    fn spoof_book(bid_99: i64, ask_102: i64, at: DateTime<Utc>) -> Orderbook {
+        let mut orderbook = Orderbook::new(
+            vec![
+                OrderbookEntry::new(dec!(100), dec!(10)),
+                OrderbookEntry::new(dec!(99), Decimal::from(bid_99)),
+                OrderbookEntry::new(dec!(98), dec!(30)),
+            ],
+            vec![
+                OrderbookEntry::new(dec!(101), dec!(15)),
+                OrderbookEntry::new(dec!(102), Decimal::from(ask_102)),
+                OrderbookEntry::new(dec!(103), dec!(35)),
+            ],
+        );
+        orderbook.timestamp = at;
+        orderbook
+    }
+    
+    #[tokio::test]
+    async fn test_spoofing_detection() {
+        let analyzer = DefaultOrderFlowAnalyzer::new(Arc::new(MockRedisClient));
+        let symbol = "BTC/USDT".to_string();
+        let t0 = Utc::now();
+        let at = |ms: i64| t0 + chrono::Duration::milliseconds(ms);
+        
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(20, 25, at(0))).is_none());
+        
+        // One large bid pulled after 300ms is not enough on its own
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(170, 25, at(100))).is_none());
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(20, 25, at(400))).is_none());
+        
+        // Pulled again: the repetition pushes confidence over the threshold
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(170, 25, at(1_000))).is_none());
+        match analyzer.detect_spoofing(&symbol, &spoof_book(20, 25, at(1_200))) {
+            Some(OrderFlowEvent::PossibleSpoofing {
+                side, size, cancel_time_ms, confidence, cancel_count, mean_cancel_time_ms, min_cancel_time_ms, ..
+            }) => {
+                assert_eq!(side, "bid");
+                assert_eq!(size, 150.0);
+                assert_eq!(cancel_time_ms, 200);
+                assert!(confidence >= 70);
+                assert_eq!(cancel_count, 2);
+                assert_eq!(mean_cancel_time_ms, 250);
+                assert_eq!(min_cancel_time_ms, 200);
+            }
+            other => panic!("expected spoofing event, got {:?}", other),
+        }
+        
+        // A large ask that is traded through is liquidity, not a spoof
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(20, 225, at(2_000))).is_none());
+        analyzer.process_trade(&symbol, 102.0, 200.0, true).await.unwrap();
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(20, 25, at(2_100))).is_none());
+        
+        // A large bid that rests past the cancel window is no longer watched
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(170, 25, at(3_000))).is_none());
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(170, 25, at(5_500))).is_none());
+        assert!(analyzer.detect_spoofing(&symbol, &spoof_book(20, 25, at(5_600))).is_none());
+    }
+    
+    #[test]
+    fn test_quote_stuffing_detection() {
+        let analyzer = DefaultOrderFlowAnalyzer::new(Arc::new(MockRedisClient));
+        let symbol = "BTC/USDT".to_string();
+        let t0 = Utc::now();
+        let book = |bid_99: i64, asks: [i64; 3], ms: i64| {
+            let mut orderbook = Orderbook::new(
+                vec![
+                    OrderbookEntry::new(dec!(100), dec!(10)),
+                    OrderbookEntry::new(dec!(99), Decimal::from(bid_99)),
+                ],
+                vec![
+                    OrderbookEntry::new(dec!(101), Decimal::from(asks[0])),
+                    OrderbookEntry::new(dec!(102), Decimal::from(asks[1])),
+                    OrderbookEntry::new(dec!(103), Decimal::from(asks[2])),
+                ],
+            );
+            orderbook.timestamp = t0 + chrono::Duration::milliseconds(ms);
+            orderbook
+        };
+        
+        // Three seconds of one bid change every 100ms sets a baseline of ~10 messages/s
+        for i in 0..30 {
+            let event = analyzer.detect_quote_stuffing(&symbol, &book(20 + i % 2, [15, 25, 35], i * 100));
+            assert!(event.is_none());
+        }
+        
+        // Every ask level flickers every 5ms: ~600 messages/s
+        let mut events = Vec::new();
+        for i in 0..200 {
+            let size = 1 + i % 2;
+            let ms = 3_000 + i * 5;
+            events.extend(analyzer.detect_quote_stuffing(&symbol, &book(20, [15 * size, 25 * size, 35 * size], ms)));
+        }
+        
+        // One alert per window while the burst lasts
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderFlowEvent::QuoteStuffing { side, order_count, bid_adds, bid_cancels, ask_adds, ask_cancels, message_rate, baseline_rate, .. } => {
+                assert_eq!(side, "ask");
+                assert_eq!(*order_count, bid_adds + bid_cancels + ask_adds + ask_cancels);
+                assert!(*ask_adds > 0 && *ask_cancels > 0);
+                assert!(ask_adds + ask_cancels > 5 * (bid_adds + bid_cancels));
+                assert!(*message_rate >= 10.0 * baseline_rate);
+                assert!(*baseline_rate > 5.0 && *baseline_rate < 15.0);
+            }
+            other => panic!("expected quote stuffing event, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Short-horizon spread forecasting
+//!
+//! Decides whether a spread is likely to tighten soon enough to be worth
+//! waiting for. Each symbol keeps a slowly moving baseline spread with an
+//! hour-of-day seasonal factor, and models log deviations from that
+//! baseline as a mean-reverting AR(1) process estimated online. A forecast
+//! decays the current deviation towards the seasonal baseline for the target
+//! time; when a volatility service is attached the baseline is widened or
+//! narrowed by how short-term volatility compares with its longer-run level.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::volatility::{VolatilityEstimator, VolatilityService};
+
+/// Spread forecaster configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpreadForecastConfig {
+    /// Default forecast horizon (seconds)
+    pub horizon_secs: u64,
+    /// Half-life of the baseline spread level (seconds)
+    pub baseline_half_life_secs: f64,
+    /// Weight of each observation in its hour's seasonal factor (0.0-1.0)
+    pub seasonal_alpha: f64,
+    /// Decay applied to the AR(1) regression sums per observation (0.0-1.0)
+    pub persistence_decay: f64,
+    /// Observations required before forecasts are made
+    pub min_observations: usize,
+    /// Expected tightening needed before waiting is advised (basis points)
+    pub min_improvement_bps: f64,
+    /// How strongly the short/long volatility ratio scales the baseline
+    pub volatility_sensitivity: f64,
+}
+
+impl Default for SpreadForecastConfig {
+    fn default() -> Self {
+        Self {
+            horizon_secs: 30,
+            baseline_half_life_secs: 600.0,
+            seasonal_alpha: 0.02,
+            persistence_decay: 0.99,
+            min_observations: 30,
+            min_improvement_bps: 0.5,
+            volatility_sensitivity: 0.5,
+        }
+    }
+}
+
+/// Forecast spread for one symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadForecast {
+    /// Symbol
+    pub symbol: String,
+    /// Latest observed spread (basis points)
+    pub current_bps: f64,
+    /// Expected spread at the horizon (basis points)
+    pub predicted_bps: f64,
+    /// Seasonal baseline the spread reverts to at the horizon (basis points)
+    pub baseline_bps: f64,
+    /// Horizon (seconds)
+    pub horizon_secs: u64,
+    /// Estimated per-observation persistence of deviations (0.0-1.0)
+    pub persistence: f64,
+    /// Multiplier applied to the baseline for current volatility
+    pub volatility_factor: f64,
+    /// Observations behind the forecast
+    pub observations: usize,
+}
+
+impl SpreadForecast {
+    /// How much tighter the spread is expected to be (basis points); negative if wider
+    pub fn expected_improvement_bps(&self) -> f64 {
+        self.current_bps - self.predicted_bps
+    }
+}
+
+/// Running model state for one symbol
+#[derive(Debug, Clone)]
+struct SpreadState {
+    last_spread_bps: f64,
+    last_at: DateTime<Utc>,
+    /// Deseasonalized baseline level
+    baseline_bps: f64,
+    /// Multiplicative seasonal factor per UTC hour
+    seasonal: [f64; 24],
+    /// Log deviation of the latest spread from its seasonal baseline
+    deviation: f64,
+    /// Decayed sums for the AR(1) slope of consecutive deviations
+    sum_xx: f64,
+    sum_xy: f64,
+    /// Average time between observations (seconds)
+    mean_interval_secs: f64,
+    observations: usize,
+}
+
+impl SpreadState {
+    fn new(spread_bps: f64, at: DateTime<Utc>) -> Self {
+        Self {
+            last_spread_bps: spread_bps,
+            last_at: at,
+            baseline_bps: spread_bps,
+            seasonal: [1.0; 24],
+            deviation: 0.0,
+            sum_xx: 0.0,
+            sum_xy: 0.0,
+            mean_interval_secs: 1.0,
+            observations: 1,
+        }
+    }
+
+    fn persistence(&self) -> f64 {
+        if self.sum_xx > 0.0 {
+            (self.sum_xy / self.sum_xx).clamp(0.0, 0.999)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Forecasts short-horizon spreads per symbol
+pub struct SpreadForecaster {
+    /// Configuration
+    config: SpreadForecastConfig,
+    /// Model state by symbol
+    states: RwLock<HashMap<String, SpreadState>>,
+    /// Volatility estimates used to scale the baseline (optional)
+    volatility: Option<Arc<VolatilityService>>,
+}
+
+impl SpreadForecaster {
+    /// Create a new forecaster
+    pub fn new(config: SpreadForecastConfig) -> Self {
+        Self {
+            config,
+            states: RwLock::new(HashMap::new()),
+            volatility: None,
+        }
+    }
+
+    /// Scale baselines by short-term versus long-run volatility
+    pub fn with_volatility_service(mut self, volatility: Arc<VolatilityService>) -> Self {
+        self.volatility = Some(volatility);
+        self
+    }
+
+    /// Record an observed spread
+    pub fn observe(&self, symbol: &str, spread_bps: f64, at: DateTime<Utc>) {
+        if !spread_bps.is_finite() || spread_bps <= 0.0 {
+            return;
+        }
+
+        let mut states = self.states.write().unwrap();
+        let Some(state) = states.get_mut(symbol) else {
+            states.insert(symbol.to_string(), SpreadState::new(spread_bps, at));
+            return;
+        };
+
+        let hour = at.hour() as usize;
+        let dt = (at - state.last_at).num_milliseconds().max(0) as f64 / 1_000.0;
+        let weight = 1.0 - 0.5f64.powf(dt / self.config.baseline_half_life_secs.max(1e-9));
+        state.baseline_bps += weight * (spread_bps / state.seasonal[hour] - state.baseline_bps);
+
+        let alpha = self.config.seasonal_alpha.clamp(0.0, 1.0);
+        state.seasonal[hour] += alpha * (spread_bps / state.baseline_bps - state.seasonal[hour]);
+
+        let deviation = (spread_bps / (state.baseline_bps * state.seasonal[hour])).ln();
+        let decay = self.config.persistence_decay.clamp(0.0, 1.0);
+        state.sum_xx = decay * state.sum_xx + state.deviation * state.deviation;
+        state.sum_xy = decay * state.sum_xy + state.deviation * deviation;
+        state.deviation = deviation;
+
+        state.mean_interval_secs += 0.1 * (dt - state.mean_interval_secs);
+        state.last_spread_bps = spread_bps;
+        state.last_at = at;
+        state.observations += 1;
+    }
+
+    /// Forecast the spread `horizon_secs` after `now`, once enough data is seen
+    pub fn forecast(&self, symbol: &str, now: DateTime<Utc>, horizon_secs: u64) -> Option<SpreadForecast> {
+        let states = self.states.read().unwrap();
+        let state = states.get(symbol)?;
+        if state.observations < self.config.min_observations {
+            return None;
+        }
+
+        let target = now + Duration::seconds(horizon_secs as i64);
+        let volatility_factor = self.volatility_factor(symbol);
+        let baseline_bps = state.baseline_bps * state.seasonal[target.hour() as usize] * volatility_factor;
+
+        let persistence = state.persistence();
+        let steps = (target - state.last_at).num_milliseconds().max(0) as f64
+            / 1_000.0
+            / state.mean_interval_secs.max(1e-3);
+        let predicted_bps = baseline_bps * (state.deviation * persistence.powf(steps)).exp();
+
+        Some(SpreadForecast {
+            symbol: symbol.to_string(),
+            current_bps: state.last_spread_bps,
+            predicted_bps,
+            baseline_bps,
+            horizon_secs,
+            persistence,
+            volatility_factor,
+            observations: state.observations,
+        })
+    }
+
+    /// Whether waiting the default horizon is expected to buy a meaningfully tighter spread
+    pub fn should_wait(&self, symbol: &str, now: DateTime<Utc>) -> bool {
+        self.forecast(symbol, now, self.config.horizon_secs)
+            .is_some_and(|forecast| forecast.expected_improvement_bps() >= self.config.min_improvement_bps)
+    }
+
+    /// Baseline multiplier from the short/long volatility ratio
+    fn volatility_factor(&self, symbol: &str) -> f64 {
+        let Some(volatility) = &self.volatility else {
+            return 1.0;
+        };
+        let short = volatility.per_bar(symbol, VolatilityEstimator::Ewma);
+        let long = volatility.per_bar(symbol, VolatilityEstimator::CloseToClose);
+        match (short, long) {
+            (Some(short), Some(long)) if long > 0.0 => {
+                (1.0 + self.config.volatility_sensitivity * (short / long - 1.0)).clamp(0.5, 2.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+/// Create a spread forecaster
+pub fn create_spread_forecaster(config: SpreadForecastConfig) -> Arc<SpreadForecaster> {
+    Arc::new(SpreadForecaster::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_forecasts_mean_reversion_of_wide_spreads() {
+        let forecaster = SpreadForecaster::new(SpreadForecastConfig::default());
+        let start = Utc.with_ymd_and_hms(2026, 1, 5, 10, 0, 0).unwrap();
+
+        // Shocks that decay by 20% per second around a 10 bps spread
+        for i in 0..300 {
+            let spread = 10.0 * (0.5 * 0.8f64.powi(i % 10)).exp();
+            forecaster.observe("BTC/USDT", spread, start + Duration::seconds(i as i64));
+        }
+        let now = start + Duration::seconds(300);
+        assert!(forecaster.forecast("ETH/USDT", now, 30).is_none());
+
+        // A blowout is expected to revert, so waiting pays
+        forecaster.observe("BTC/USDT", 40.0, now);
+        let forecast = forecaster.forecast("BTC/USDT", now, 30).unwrap();
+        assert!(forecast.persistence > 0.3 && forecast.persistence < 1.0);
+        assert!(forecast.predicted_bps < 20.0);
+        assert!(forecaster.should_wait("BTC/USDT", now));
+
+        // At the baseline there is nothing to wait for
+        let at_baseline = now + Duration::seconds(60);
+        forecaster.observe("BTC/USDT", forecast.baseline_bps, at_baseline);
+        assert!(!forecaster.should_wait("BTC/USDT", at_baseline));
+    }
+}
@@ -29,6 +29,7 @@ use crate::market::{MarketData, Symbol, Orderbook};
 use crate::redis::{RedisClient, RedisClientResult};
 use crate::microstructure::order_flow::{OrderFlowMetrics, OrderFlowAnalyzer};
 use crate::microstructure::liquidity::{LiquiditySnapshot, LiquidityProfiler};
+use crate::microstructure::spread_forecast::SpreadForecaster;
 
 /// Error types for timing signals
 #[derive(Debug, Error)]
@@ -349,6 +350,9 @@ pub struct DefaultTimingSignalEngine {
     
     /// Previous market data for comparison
     previous_data: RwLock<HashMap<Symbol, (MarketData, DateTime<Utc>)>>,
+    
+    /// Spread forecaster used to hold back tightening signals (optional)
+    spread_forecaster: Option<Arc<SpreadForecaster>>,
 }
 
 impl DefaultTimingSignalEngine {
@@ -365,6 +369,7 @@ impl DefaultTimingSignalEngine {
             liquidity_profiler,
             active_signals: RwLock::new(HashMap::new()),
             previous_data: RwLock::new(HashMap::new()),
+            spread_forecaster: None,
         }
     }
     
@@ -382,9 +387,16 @@ impl DefaultTimingSignalEngine {
             liquidity_profiler,
             active_signals: RwLock::new(HashMap::new()),
             previous_data: RwLock::new(HashMap::new()),
+            spread_forecaster: None,
         }
     }
     
+    /// Feed spreads to a forecaster and skip tightening signals it expects to improve on
+    pub fn with_spread_forecaster(mut self, spread_forecaster: Arc<SpreadForecaster>) -> Self {
+        self.spread_forecaster = Some(spread_forecaster);
+        self
+    }
+    
     /// Redis key for signals
     fn signals_key(&self, symbol: &Symbol) -> String {
         format!("micro:timing_signals:{}", symbol)
@@ -497,6 +509,12 @@ impl DefaultTimingSignalEngine {
             let change_pct = (prev_spread - current_spread) / prev_spread;
             
             if change_pct >= config.spread_tightening_threshold {
+                // Not the moment yet if the spread is expected to keep tightening
+                let now = Utc::now();
+                if self.spread_forecaster.as_ref().is_some_and(|forecaster| forecaster.should_wait(symbol, now)) {
+                    return None;
+                }
+                
                 // Direction depends on order book imbalance
                 let is_buy = if let Some(orderbook) = &current_data.orderbook {
                     if let Some(imbalance) = orderbook.imbalance(5).try_into().ok() {
@@ -510,7 +528,7 @@ impl DefaultTimingSignalEngine {
                 
                 let confidence = (change_pct / 0.5).min(1.0);
                 
-                return Some(ExecutionTimingSignal::new(
+                let mut signal = ExecutionTimingSignal::new(
                     symbol.clone(),
                     SignalType::SpreadTightening,
                     SignalConfidence::from_value(confidence),
@@ -520,7 +538,14 @@ impl DefaultTimingSignalEngine {
                  .with_context("prev_spread", prev_spread)
                  .with_context("current_spread", current_spread)
                  .with_context("change_pct", change_pct)
-                 .with_validity(config.default_validity_sec));
+                 .with_validity(config.default_validity_sec);
+                
+                let forecast = self.spread_forecaster.as_ref()
+                    .and_then(|forecaster| forecaster.forecast(symbol, now, config.default_validity_sec));
+                if let Some(forecast) = forecast {
+                    signal = signal.with_context("predicted_spread_bps", forecast.predicted_bps);
+                }
+                return Some(signal);
             }
         }
         
@@ -692,6 +717,10 @@ impl TimingSignalEngine for DefaultTimingSignalEngine {
             None => None,
         };
         
+        if let Some(forecaster) = &self.spread_forecaster {
+            forecaster.observe(&symbol, market_data.spread_percentage() * 100.0, Utc::now());
+        }
+        
         // Detect signals
         if let Some(signal) = self.detect_delta_flip(&symbol, &order_flow_metrics, previous_metrics.as_ref()) {
             signals.push(signal);
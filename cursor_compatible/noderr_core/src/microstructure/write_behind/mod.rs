@@ -0,0 +1,421 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Write-behind batching for microstructure persistence.
+//!
+//! Analyzers enqueue writes without awaiting Redis; writes are coalesced per key
+//! (and so per symbol) and flushed in the background once a batch fills up or
+//! the flush interval elapses.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, warn};
+
+use crate::dead_letter::DeadLetterQueue;
+use crate::redis::RedisClient;
+
+/// What happens to new writes when the pending buffer is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OverflowPolicy {
+    /// Evict the oldest pending append to make room
+    DropOldest,
+    /// Reject the new write
+    DropNewest,
+    /// Send the new write to the dead-letter queue for later replay
+    DeadLetter,
+}
+
+/// Write-behind batcher configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteBehindConfig {
+    /// Pending writes that trigger an early flush
+    pub max_batch_size: usize,
+    /// Maximum time a write waits before being flushed
+    pub flush_interval: Duration,
+    /// Maximum pending writes before the overflow policy applies
+    pub max_pending: usize,
+    /// Overflow behavior
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Default for WriteBehindConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 500,
+            flush_interval: Duration::from_millis(250),
+            max_pending: 20_000,
+            overflow_policy: OverflowPolicy::DropOldest,
+        }
+    }
+}
+
+/// Batcher counters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WriteBehindStats {
+    /// Writes accepted
+    pub enqueued: u64,
+    /// Writes merged into an already pending write for the same key
+    pub coalesced: u64,
+    /// Redis writes issued by flushes
+    pub flushed: u64,
+    /// Writes lost to overflow or failed flushes
+    pub dropped: u64,
+    /// Writes sent to the dead-letter queue
+    pub dead_lettered: u64,
+    /// Flushes that hit a Redis error
+    pub flush_failures: u64,
+    /// Writes currently pending
+    pub pending: usize,
+}
+
+/// Pending write for a single key
+#[derive(Debug, Clone)]
+enum PendingWrite {
+    /// Latest value wins
+    Set { value: Value, ttl_sec: Option<u64> },
+    /// Values appended to a capped JSON list
+    Append { values: Vec<Value>, max_len: usize, ttl_sec: Option<u64> },
+}
+
+impl PendingWrite {
+    fn len(&self) -> usize {
+        match self {
+            PendingWrite::Set { .. } => 1,
+            PendingWrite::Append { values, .. } => values.len(),
+        }
+    }
+}
+
+/// Pending writes by key, with insertion order for fair eviction
+#[derive(Default)]
+struct PendingBuffer {
+    writes: HashMap<String, PendingWrite>,
+    order: Vec<String>,
+    count: usize,
+}
+
+/// Coalescing write-behind batcher in front of Redis
+pub struct WriteBehindBatcher {
+    /// Redis client writes are flushed to
+    redis: Arc<dyn RedisClient>,
+    /// Configuration
+    config: WriteBehindConfig,
+    /// Pending writes
+    pending: Mutex<PendingBuffer>,
+    /// Wakes the flush task when a batch fills up
+    flush_signal: Notify,
+    /// Dead-letter queue for overflow and failed flushes
+    dead_letters: Option<Arc<DeadLetterQueue>>,
+    enqueued: AtomicU64,
+    coalesced: AtomicU64,
+    flushed: AtomicU64,
+    dropped: AtomicU64,
+    dead_lettered: AtomicU64,
+    flush_failures: AtomicU64,
+    /// Flush task handle
+    task_handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl WriteBehindBatcher {
+    /// Create a new batcher
+    pub fn new(redis: Arc<dyn RedisClient>, config: WriteBehindConfig) -> Self {
+        Self {
+            redis,
+            config,
+            pending: Mutex::new(PendingBuffer::default()),
+            flush_signal: Notify::new(),
+            dead_letters: None,
+            enqueued: AtomicU64::new(0),
+            coalesced: AtomicU64::new(0),
+            flushed: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            dead_lettered: AtomicU64::new(0),
+            flush_failures: AtomicU64::new(0),
+            task_handle: Mutex::new(None),
+        }
+    }
+
+    /// Route overflow and failed flushes to a dead-letter queue
+    pub fn with_dead_letter_queue(mut self, queue: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
+
+    /// Queue a set; a later set to the same key replaces it. Never blocks on Redis.
+    pub fn set<T: Serialize>(&self, key: &str, value: &T, ttl_sec: Option<u64>) {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Cannot batch write to {}: {}", key, e);
+                return;
+            }
+        };
+
+        self.enqueue(key, |existing| match existing {
+            Some(PendingWrite::Set { value: current, .. }) => {
+                *current = value.clone();
+                true
+            }
+            _ => false,
+        }, || PendingWrite::Set { value: value.clone(), ttl_sec });
+    }
+
+    /// Queue an append to a capped list; appends to the same key are written together. Never blocks on Redis.
+    pub fn append<T: Serialize>(&self, key: &str, value: &T, max_len: usize, ttl_sec: Option<u64>) {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(e) => {
+                error!("Cannot batch append to {}: {}", key, e);
+                return;
+            }
+        };
+
+        self.enqueue(key, |existing| match existing {
+            Some(PendingWrite::Append { values, .. }) => {
+                values.push(value.clone());
+                if values.len() > max_len {
+                    values.remove(0);
+                }
+                true
+            }
+            _ => false,
+        }, || PendingWrite::Append { values: vec![value.clone()], max_len, ttl_sec });
+    }
+
+    /// Batcher counters
+    pub fn stats(&self) -> WriteBehindStats {
+        WriteBehindStats {
+            enqueued: self.enqueued.load(Ordering::Relaxed),
+            coalesced: self.coalesced.load(Ordering::Relaxed),
+            flushed: self.flushed.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            dead_lettered: self.dead_lettered.load(Ordering::Relaxed),
+            flush_failures: self.flush_failures.load(Ordering::Relaxed),
+            pending: self.pending.lock().unwrap().count,
+        }
+    }
+
+    /// Write everything pending to Redis, returning the number of Redis writes issued
+    pub async fn flush(&self) -> usize {
+        let buffer = std::mem::take(&mut *self.pending.lock().unwrap());
+        let mut writes = buffer.writes;
+        let mut issued = 0;
+
+        for key in buffer.order {
+            let write = match writes.remove(&key) {
+                Some(write) => write,
+                None => continue,
+            };
+            let count = write.len() as u64;
+
+            let result = match &write {
+                PendingWrite::Set { value, ttl_sec } => self.redis.set(&key, value, *ttl_sec).await,
+                PendingWrite::Append { values, max_len, ttl_sec } => {
+                    match self.redis.get::<Vec<Value>>(&key).await {
+                        Ok(existing) => {
+                            let mut list = existing.unwrap_or_default();
+                            list.extend(values.iter().cloned());
+                            if list.len() > *max_len {
+                                let excess = list.len() - max_len;
+                                list.drain(..excess);
+                            }
+                            self.redis.set(&key, &list, *ttl_sec).await
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+            };
+
+            match result {
+                Ok(()) => {
+                    issued += 1;
+                    self.flushed.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    self.flush_failures.fetch_add(1, Ordering::Relaxed);
+                    warn!("Write-behind flush of {} failed: {}", key, e);
+                    self.dead_letter(&key, write, &e.to_string(), count);
+                }
+            }
+        }
+
+        if issued > 0 {
+            debug!("Write-behind flushed {} keys", issued);
+        }
+        issued
+    }
+
+    /// Start the background flush task
+    pub fn start(self: &Arc<Self>) {
+        let mut handle = self.task_handle.lock().unwrap();
+        if handle.is_some() {
+            return;
+        }
+
+        let batcher = Arc::clone(self);
+        *handle = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(batcher.config.flush_interval);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = batcher.flush_signal.notified() => {}
+                }
+                batcher.flush().await;
+            }
+        }));
+    }
+
+    /// Stop the flush task and write out anything still pending
+    pub async fn stop(&self) {
+        let handle = self.task_handle.lock().unwrap().take();
+        if let Some(handle) = handle {
+            handle.abort();
+        }
+        self.flush().await;
+    }
+
+    fn enqueue(
+        &self,
+        key: &str,
+        merge: impl FnOnce(Option<&mut PendingWrite>) -> bool,
+        create: impl FnOnce() -> PendingWrite,
+    ) {
+        self.enqueued.fetch_add(1, Ordering::Relaxed);
+        let mut buffer = self.pending.lock().unwrap();
+
+        // Replacing a pending set does not grow the buffer
+        let grows = !matches!(buffer.writes.get(key), Some(PendingWrite::Set { .. }));
+        if grows && buffer.count >= self.config.max_pending && !self.make_room(&mut buffer) {
+            drop(buffer);
+            self.overflow(key, create());
+            return;
+        }
+
+        let before = buffer.writes.get(key).map(PendingWrite::len).unwrap_or(0);
+        if merge(buffer.writes.get_mut(key)) {
+            self.coalesced.fetch_add(1, Ordering::Relaxed);
+        } else if buffer.writes.insert(key.to_string(), create()).is_none() {
+            buffer.order.push(key.to_string());
+        }
+        let after = buffer.writes.get(key).map(PendingWrite::len).unwrap_or(0);
+        buffer.count = buffer.count + after - before;
+
+        if buffer.count >= self.config.max_batch_size {
+            self.flush_signal.notify_one();
+        }
+    }
+
+    /// Apply the overflow policy to the buffer; returns whether the new write may be queued
+    fn make_room(&self, buffer: &mut PendingBuffer) -> bool {
+        if self.config.overflow_policy != OverflowPolicy::DropOldest {
+            return false;
+        }
+
+        // Evict the oldest appended value; sets are latest-state and cheap to keep
+        for key in buffer.order.clone() {
+            if let Some(PendingWrite::Append { values, .. }) = buffer.writes.get_mut(&key) {
+                if !values.is_empty() {
+                    values.remove(0);
+                    buffer.count -= 1;
+                    if values.is_empty() {
+                        buffer.writes.remove(&key);
+                        buffer.order.retain(|k| k != &key);
+                    }
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn overflow(&self, key: &str, write: PendingWrite) {
+        let count = write.len() as u64;
+        match self.config.overflow_policy {
+            OverflowPolicy::DeadLetter => self.dead_letter(key, write, "write-behind buffer full", count),
+            _ => {
+                self.dropped.fetch_add(count, Ordering::Relaxed);
+                warn!("Write-behind buffer full; dropped write to {}", key);
+            }
+        }
+    }
+
+    fn dead_letter(&self, key: &str, write: PendingWrite, error: &str, count: u64) {
+        let dead_letters = match &self.dead_letters {
+            Some(dead_letters) => dead_letters,
+            None => {
+                self.dropped.fetch_add(count, Ordering::Relaxed);
+                return;
+            }
+        };
+
+        match write {
+            PendingWrite::Set { value, ttl_sec } => dead_letters.push_set(key, &value, ttl_sec, error),
+            PendingWrite::Append { values, max_len, ttl_sec } => {
+                for value in values {
+                    dead_letters.push_append(key, &value, max_len, ttl_sec, error);
+                }
+            }
+        }
+        self.dead_lettered.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+/// Create a write-behind batcher and start its flush task
+pub fn create_write_behind_batcher(redis: Arc<dyn RedisClient>, config: WriteBehindConfig) -> Arc<WriteBehindBatcher> {
+    let batcher = Arc::new(WriteBehindBatcher::new(redis, config));
+    batcher.start();
+    batcher
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redis::{MockRedisClient, RedisConfig};
+
+    #[tokio::test]
+    async fn test_coalesces_per_key_and_flushes() {
+        let redis = Arc::new(MockRedisClient::new(RedisConfig::default()));
+        let batcher = WriteBehindBatcher::new(redis.clone(), WriteBehindConfig {
+            max_pending: 4,
+            overflow_policy: OverflowPolicy::DropOldest,
+            ..WriteBehindConfig::default()
+        });
+
+        for i in 0..3 {
+            batcher.set("metrics:BTC/USD", &i, None);
+        }
+        for i in 0..5 {
+            batcher.append("events:BTC/USD", &i, 10, None);
+        }
+
+        let stats = batcher.stats();
+        assert_eq!(stats.pending, 4);
+        assert_eq!(stats.coalesced, 6);
+        assert_eq!(stats.dropped, 2);
+
+        assert_eq!(batcher.flush().await, 2);
+        assert_eq!(redis.get::<i64>("metrics:BTC/USD").await.unwrap(), Some(2));
+        assert_eq!(redis.get::<Vec<i64>>("events:BTC/USD").await.unwrap(), Some(vec![2, 3, 4]));
+        assert_eq!(batcher.stats().pending, 0);
+    }
+}
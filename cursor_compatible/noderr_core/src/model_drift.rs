@@ -0,0 +1,475 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Drift monitoring for model-backed strategies
+//!
+//! Tracks two kinds of drift per strategy: input features whose live
+//! distribution has moved away from the training baseline (population
+//! stability index and Kolmogorov-Smirnov statistic), and predicted
+//! probabilities that no longer match realized outcomes (expected
+//! calibration error). Breaches raise telemetry alerts and, when a trust
+//! decay service is attached, penalize the strategy's trust score.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::strategy::StrategyId;
+use crate::telemetry::TelemetryReporter;
+use crate::trust_decay_service::TrustDecayService;
+
+/// Errors that can occur while monitoring model drift
+#[derive(Debug, Error)]
+pub enum ModelDriftError {
+    #[error("Baseline for {feature} has {samples} samples, need {required}")]
+    InsufficientBaseline { feature: String, samples: usize, required: usize },
+
+    #[error("Predicted probability {0} is outside [0, 1]")]
+    InvalidPrediction(f64),
+}
+
+/// Drift monitoring configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelDriftConfig {
+    /// Histogram bins for PSI and calibration
+    pub bins: usize,
+    /// Samples required in a baseline and in the live window before testing
+    pub min_samples: usize,
+    /// Live samples retained per feature and for calibration
+    pub window: usize,
+    /// PSI above which a feature has shifted (0.1 moderate, 0.25 major)
+    pub psi_threshold: f64,
+    /// KS statistic above which a feature has shifted
+    pub ks_threshold: f64,
+    /// Expected calibration error above which predictions are miscalibrated
+    pub calibration_threshold: f64,
+    /// Fraction of trust score removed per drift breach
+    pub trust_penalty: f64,
+    /// Minimum seconds between penalties for the same strategy
+    pub penalty_cooldown_secs: i64,
+    /// Seconds between background checks
+    pub check_interval_secs: u64,
+}
+
+impl Default for ModelDriftConfig {
+    fn default() -> Self {
+        Self {
+            bins: 10,
+            min_samples: 100,
+            window: 1_000,
+            psi_threshold: 0.25,
+            ks_threshold: 0.2,
+            calibration_threshold: 0.1,
+            trust_penalty: 0.1,
+            penalty_cooldown_secs: 3_600,
+            check_interval_secs: 300,
+        }
+    }
+}
+
+/// What drifted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DriftKind {
+    /// An input feature's distribution shifted
+    FeatureShift { feature: String, psi: f64, ks: f64 },
+    /// Predictions stopped matching outcomes
+    Calibration { expected_calibration_error: f64 },
+}
+
+/// A drift threshold breach
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriftAlert {
+    /// Strategy whose model drifted
+    pub strategy_id: StrategyId,
+    /// Drift detected
+    pub kind: DriftKind,
+    /// When the breach was detected
+    pub detected_at: DateTime<Utc>,
+    /// Whether a trust penalty was applied for this check
+    pub penalty_applied: bool,
+}
+
+/// Drift statistics for one strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DriftReport {
+    /// (PSI, KS) per feature with enough data
+    pub features: HashMap<String, (f64, f64)>,
+    /// Expected calibration error, if enough outcomes were recorded
+    pub expected_calibration_error: Option<f64>,
+}
+
+/// Per-strategy monitoring state
+#[derive(Debug, Default)]
+struct ModelState {
+    /// Sorted training-time values per feature
+    baselines: HashMap<String, Vec<f64>>,
+    /// Recent live values per feature
+    live: HashMap<String, VecDeque<f64>>,
+    /// Recent (predicted probability, outcome) pairs
+    predictions: VecDeque<(f64, bool)>,
+    /// Last time a trust penalty was applied
+    last_penalty: Option<DateTime<Utc>>,
+}
+
+/// Population stability index of `actual` against `expected`, binned on the
+/// expected sample's quantiles
+pub fn population_stability_index(expected: &[f64], actual: &[f64], bins: usize) -> f64 {
+    if expected.is_empty() || actual.is_empty() || bins == 0 {
+        return 0.0;
+    }
+    let mut sorted = expected.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let edges: Vec<f64> = (1..bins).map(|i| sorted[i * sorted.len() / bins]).collect();
+
+    let histogram = |values: &[f64]| {
+        let mut counts = vec![0usize; bins];
+        for value in values {
+            counts[edges.partition_point(|edge| edge <= value)] += 1;
+        }
+        counts
+    };
+    let (expected_counts, actual_counts) = (histogram(expected), histogram(actual));
+
+    // Floor empty buckets so the log term stays finite
+    const FLOOR: f64 = 1e-4;
+    expected_counts
+        .iter()
+        .zip(&actual_counts)
+        .map(|(&e, &a)| {
+            let e = (e as f64 / expected.len() as f64).max(FLOOR);
+            let a = (a as f64 / actual.len() as f64).max(FLOOR);
+            (a - e) * (a / e).ln()
+        })
+        .sum()
+}
+
+/// Two-sample Kolmogorov-Smirnov statistic (largest CDF gap)
+pub fn ks_statistic(a: &[f64], b: &[f64]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort_by(|x, y| x.total_cmp(y));
+    b.sort_by(|x, y| x.total_cmp(y));
+
+    let (mut i, mut j, mut max_gap) = (0, 0, 0.0f64);
+    while i < a.len() && j < b.len() {
+        let value = a[i].min(b[j]);
+        while i < a.len() && a[i] <= value {
+            i += 1;
+        }
+        while j < b.len() && b[j] <= value {
+            j += 1;
+        }
+        max_gap = max_gap.max((i as f64 / a.len() as f64 - j as f64 / b.len() as f64).abs());
+    }
+    max_gap
+}
+
+/// Expected calibration error of probability predictions against outcomes
+pub fn expected_calibration_error(predictions: &[(f64, bool)], bins: usize) -> f64 {
+    if predictions.is_empty() || bins == 0 {
+        return 0.0;
+    }
+    let mut buckets = vec![(0.0f64, 0.0f64, 0usize); bins];
+    for &(probability, outcome) in predictions {
+        let bucket = ((probability * bins as f64) as usize).min(bins - 1);
+        buckets[bucket].0 += probability;
+        buckets[bucket].1 += if outcome { 1.0 } else { 0.0 };
+        buckets[bucket].2 += 1;
+    }
+    buckets
+        .iter()
+        .filter(|(_, _, count)| *count > 0)
+        .map(|(confidence, hits, _)| (confidence - hits).abs() / predictions.len() as f64)
+        .sum()
+}
+
+/// Monitors model-backed strategies for drift
+pub struct ModelDriftMonitor {
+    /// Configuration
+    config: ModelDriftConfig,
+    /// State per strategy
+    models: RwLock<HashMap<StrategyId, ModelState>>,
+    /// Trust decay service used to penalize drifting strategies (optional)
+    trust_decay: Option<Arc<dyn TrustDecayService>>,
+    /// Telemetry for drift alerts (optional)
+    telemetry: Option<Arc<TelemetryReporter>>,
+    /// Recent alerts, newest last
+    alerts: RwLock<VecDeque<DriftAlert>>,
+    /// Background check task
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl ModelDriftMonitor {
+    /// Create a monitor with no strategies registered
+    pub fn new(config: ModelDriftConfig) -> Self {
+        Self {
+            config,
+            models: RwLock::new(HashMap::new()),
+            trust_decay: None,
+            telemetry: None,
+            alerts: RwLock::new(VecDeque::new()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Penalize drifting strategies through the trust decay service
+    pub fn with_trust_decay(mut self, trust_decay: Arc<dyn TrustDecayService>) -> Self {
+        self.trust_decay = Some(trust_decay);
+        self
+    }
+
+    /// Report drift alerts to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Set the training-time distribution of a model input feature
+    pub async fn set_baseline(&self, strategy_id: &str, feature: &str, mut values: Vec<f64>) -> Result<(), ModelDriftError> {
+        values.retain(|v| v.is_finite());
+        if values.len() < self.config.min_samples {
+            return Err(ModelDriftError::InsufficientBaseline {
+                feature: feature.to_string(),
+                samples: values.len(),
+                required: self.config.min_samples,
+            });
+        }
+        values.sort_by(|a, b| a.total_cmp(b));
+        let mut models = self.models.write().await;
+        let state = models.entry(strategy_id.to_string()).or_default();
+        state.baselines.insert(feature.to_string(), values);
+        state.live.remove(feature);
+        Ok(())
+    }
+
+    /// Record the live inputs a strategy's model was evaluated on
+    pub async fn observe_features(&self, strategy_id: &str, features: &HashMap<String, f64>) {
+        let mut models = self.models.write().await;
+        let state = models.entry(strategy_id.to_string()).or_default();
+        for (name, value) in features {
+            if !value.is_finite() || !state.baselines.contains_key(name) {
+                continue;
+            }
+            let live = state.live.entry(name.clone()).or_default();
+            live.push_back(*value);
+            while live.len() > self.config.window {
+                live.pop_front();
+            }
+        }
+    }
+
+    /// Record a predicted probability together with the realized outcome
+    pub async fn record_outcome(&self, strategy_id: &str, predicted: f64, outcome: bool) -> Result<(), ModelDriftError> {
+        if !(0.0..=1.0).contains(&predicted) {
+            return Err(ModelDriftError::InvalidPrediction(predicted));
+        }
+        let mut models = self.models.write().await;
+        let predictions = &mut models.entry(strategy_id.to_string()).or_default().predictions;
+        predictions.push_back((predicted, outcome));
+        while predictions.len() > self.config.window {
+            predictions.pop_front();
+        }
+        Ok(())
+    }
+
+    /// Current drift statistics for a strategy
+    pub async fn report(&self, strategy_id: &str) -> DriftReport {
+        let models = self.models.read().await;
+        let Some(state) = models.get(strategy_id) else {
+            return DriftReport::default();
+        };
+        let min_samples = self.config.min_samples;
+
+        let features = state
+            .live
+            .iter()
+            .filter(|(_, live)| live.len() >= min_samples)
+            .filter_map(|(name, live)| {
+                let baseline = state.baselines.get(name)?;
+                let live: Vec<f64> = live.iter().copied().collect();
+                let psi = population_stability_index(baseline, &live, self.config.bins);
+                Some((name.clone(), (psi, ks_statistic(baseline, &live))))
+            })
+            .collect();
+        let expected_calibration_error = (state.predictions.len() >= min_samples).then(|| {
+            let predictions: Vec<(f64, bool)> = state.predictions.iter().copied().collect();
+            expected_calibration_error(&predictions, self.config.bins)
+        });
+
+        DriftReport { features, expected_calibration_error }
+    }
+
+    /// Check one strategy, alerting and penalizing on threshold breaches
+    pub async fn check(&self, strategy_id: &str, now: DateTime<Utc>) -> Vec<DriftAlert> {
+        let report = self.report(strategy_id).await;
+        let mut kinds: Vec<DriftKind> = report
+            .features
+            .into_iter()
+            .filter(|(_, (psi, ks))| *psi > self.config.psi_threshold || *ks > self.config.ks_threshold)
+            .map(|(feature, (psi, ks))| DriftKind::FeatureShift { feature, psi, ks })
+            .collect();
+        if let Some(ece) = report.expected_calibration_error.filter(|ece| *ece > self.config.calibration_threshold) {
+            kinds.push(DriftKind::Calibration { expected_calibration_error: ece });
+        }
+        if kinds.is_empty() {
+            return Vec::new();
+        }
+
+        let penalty_applied = self.penalize(strategy_id, now).await;
+        let alerts: Vec<DriftAlert> = kinds
+            .into_iter()
+            .map(|kind| DriftAlert { strategy_id: strategy_id.to_string(), kind, detected_at: now, penalty_applied })
+            .collect();
+
+        for alert in &alerts {
+            warn!("Model drift for {}: {:?}", alert.strategy_id, alert.kind);
+            if let Some(telemetry) = &self.telemetry {
+                let mut data = HashMap::new();
+                data.insert("strategy_id".to_string(), serde_json::json!(alert.strategy_id));
+                data.insert("kind".to_string(), serde_json::json!(alert.kind));
+                data.insert("penalty_applied".to_string(), serde_json::json!(alert.penalty_applied));
+                telemetry.report_custom("model_drift", data).await;
+            }
+        }
+
+        let mut recent = self.alerts.write().await;
+        recent.extend(alerts.iter().cloned());
+        while recent.len() > 100 {
+            recent.pop_front();
+        }
+        alerts
+    }
+
+    /// Apply one trust penalty unless the strategy is still in cooldown
+    async fn penalize(&self, strategy_id: &str, now: DateTime<Utc>) -> bool {
+        let Some(trust_decay) = &self.trust_decay else {
+            return false;
+        };
+        {
+            let mut models = self.models.write().await;
+            let Some(state) = models.get_mut(strategy_id) else {
+                return false;
+            };
+            let cooldown = chrono::Duration::seconds(self.config.penalty_cooldown_secs);
+            if state.last_penalty.is_some_and(|last| now - last < cooldown) {
+                return false;
+            }
+            state.last_penalty = Some(now);
+        }
+
+        // The decay service takes a daily factor and applies its 24th root
+        let daily_factor = (1.0 - self.config.trust_penalty.clamp(0.0, 1.0)).powi(24);
+        match trust_decay.apply_decay_to_strategy(strategy_id, Some(daily_factor)).await {
+            Ok(score) => {
+                info!("Penalized trust of drifting strategy {} to {:.4}", strategy_id, score.score);
+                true
+            }
+            Err(e) => {
+                warn!("Failed to penalize trust of {}: {}", strategy_id, e);
+                false
+            }
+        }
+    }
+
+    /// Check every monitored strategy
+    pub async fn check_all(&self, now: DateTime<Utc>) -> Vec<DriftAlert> {
+        let strategy_ids: Vec<StrategyId> = self.models.read().await.keys().cloned().collect();
+        let mut alerts = Vec::new();
+        for strategy_id in strategy_ids {
+            alerts.extend(self.check(&strategy_id, now).await);
+        }
+        alerts
+    }
+
+    /// Recent drift alerts, newest last
+    pub async fn recent_alerts(&self) -> Vec<DriftAlert> {
+        self.alerts.read().await.iter().cloned().collect()
+    }
+
+    /// Start periodic drift checks
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let monitor = Arc::clone(self);
+        let interval_secs = self.config.check_interval_secs.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                monitor.check_all(Utc::now()).await;
+            }
+        }));
+    }
+
+    /// Stop periodic drift checks
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create a model drift monitor
+pub fn create_model_drift_monitor(config: ModelDriftConfig) -> Arc<ModelDriftMonitor> {
+    Arc::new(ModelDriftMonitor::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_detects_feature_shift_and_miscalibration() {
+        let monitor = create_model_drift_monitor(ModelDriftConfig::default());
+        let baseline: Vec<f64> = (0..500).map(|i| i as f64 / 500.0).collect();
+        assert!(monitor.set_baseline("ml", "momentum", baseline[..10].to_vec()).await.is_err());
+        monitor.set_baseline("ml", "momentum", baseline.clone()).await.unwrap();
+        monitor.set_baseline("ml", "spread", baseline.clone()).await.unwrap();
+
+        for i in 0..200 {
+            let x = (i % 100) as f64 / 100.0;
+            // momentum drifts upward, spread keeps its training distribution
+            let features = HashMap::from([("momentum".to_string(), 0.5 + x / 2.0), ("spread".to_string(), x)]);
+            monitor.observe_features("ml", &features).await;
+            // Confident predictions that come true only half the time
+            monitor.record_outcome("ml", 0.9, i % 2 == 0).await.unwrap();
+        }
+
+        let report = monitor.report("ml").await;
+        assert!(report.features["spread"].0 < 0.1);
+        assert!(report.features["momentum"].0 > 0.25);
+
+        let alerts = monitor.check("ml", Utc::now()).await;
+        assert_eq!(alerts.len(), 2);
+        assert!(alerts.iter().any(|a| matches!(&a.kind, DriftKind::FeatureShift { feature, .. } if feature == "momentum")));
+        assert!(alerts.iter().any(|a| matches!(a.kind, DriftKind::Calibration { expected_calibration_error } if expected_calibration_error > 0.3)));
+        assert!(alerts.iter().all(|a| !a.penalty_applied));
+        assert!(monitor.record_outcome("ml", 1.5, true).await.is_err());
+    }
+}
@@ -0,0 +1,328 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Multi-leg (spread) orders
+//!
+//! A multi-leg order trades two or more instruments in fixed ratios with a
+//! limit on the combined net price, as needed for pairs and basis trades.
+//! Legs are sent in order, hardest to fill first; later legs are sized to
+//! whatever the earlier legs actually filled. If a later leg still comes up
+//! short the position is left legged, and the configured fallback decides
+//! whether to chase the missing leg, unwind the excess or accept the
+//! imbalance. Execution itself lives on `SmartOrderRouter::execute_multi_leg`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::order_lineage::OrderLineage;
+use crate::order_router::{Order, OrderSide, TimeInForce};
+
+/// Quantities below this are treated as zero
+const QUANTITY_EPSILON: f64 = 1e-9;
+
+/// One leg of a multi-leg order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderLeg {
+    /// Symbol to trade
+    pub symbol: String,
+    /// Buy or sell
+    pub side: OrderSide,
+    /// Quantity of this leg per unit of the spread (hedge ratio)
+    pub ratio: f64,
+    /// Limit price for this leg
+    pub price: f64,
+    /// Potential venues for execution
+    pub venues: Vec<String>,
+}
+
+/// What to do when legs fill unevenly
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LeggingFallback {
+    /// Chase the short legs with immediate orders allowed to slip by up to
+    /// `max_slippage_pct` from their limit; anything still short is unwound
+    Complete { max_slippage_pct: f64 },
+    /// Trade the excess on over-filled legs back out
+    Unwind,
+    /// Leave the imbalance in place and report it
+    Accept,
+}
+
+impl Default for LeggingFallback {
+    fn default() -> Self {
+        Self::Complete { max_slippage_pct: 0.5 }
+    }
+}
+
+/// An order made of several legs executed as one spread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiLegOrder {
+    /// Order ID; leg orders are derived from it
+    pub id: String,
+    /// Legs, in the order they are executed
+    pub legs: Vec<OrderLeg>,
+    /// Spread units to trade; each leg trades `quantity * ratio`
+    pub quantity: f64,
+    /// Worst acceptable net price per spread unit (buys positive, sells negative)
+    pub max_net_price: Option<f64>,
+    /// Legging fallback
+    #[serde(default)]
+    pub fallback: LeggingFallback,
+    /// Additional parameters passed to every leg order
+    #[serde(default)]
+    pub additional_params: HashMap<String, serde_json::Value>,
+}
+
+impl MultiLegOrder {
+    /// Check the order is well formed
+    pub fn validate(&self) -> Result<(), String> {
+        if self.legs.len() < 2 {
+            return Err(format!("multi-leg order {} needs at least 2 legs", self.id));
+        }
+        if self.quantity <= 0.0 {
+            return Err(format!("multi-leg order {} has non-positive quantity", self.id));
+        }
+        if let Some(leg) = self.legs.iter().find(|leg| leg.ratio <= 0.0 || leg.price <= 0.0) {
+            return Err(format!("leg {} needs positive ratio and price", leg.symbol));
+        }
+        if let Some(limit) = self.max_net_price {
+            let expected = self.expected_net_price();
+            if expected > limit {
+                return Err(format!(
+                    "leg limits give net price {:.6}, worse than the {:.6} limit", expected, limit
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Net price per spread unit if every leg fills at its limit
+    pub fn expected_net_price(&self) -> f64 {
+        self.legs.iter().map(|leg| signed(leg.side) * leg.ratio * leg.price).sum()
+    }
+
+    /// Router order for one leg, tagged as a child of this order
+    pub fn leg_order(&self, index: usize, amount: f64) -> Order {
+        let leg = &self.legs[index];
+        let mut order = Order {
+            symbol: leg.symbol.clone(),
+            side: leg.side,
+            amount,
+            price: leg.price,
+            venues: leg.venues.clone(),
+            id: format!("{}-leg{}", self.id, index),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::IOC,
+            additional_params: self.additional_params.clone(),
+        };
+
+        let lineage = OrderLineage::of(&order);
+        OrderLineage {
+            parent_order_id: Some(lineage.parent_order_id.clone().unwrap_or_else(|| self.id.clone())),
+            child_index: Some(index as u32),
+            ..lineage
+        }
+        .write(&mut order.additional_params);
+        order
+    }
+
+    /// Spread units completed across all legs
+    pub fn completed_units(&self, fills: &[LegFill]) -> f64 {
+        self.legs.iter().zip(fills)
+            .map(|(leg, fill)| fill.filled / leg.ratio)
+            .fold(f64::INFINITY, f64::min)
+            .min(self.quantity)
+            .max(0.0)
+    }
+
+    /// Signed quantity each leg is off from `units` spread units; positive
+    /// means the leg is over-filled
+    pub fn imbalances(&self, fills: &[LegFill], units: f64) -> Vec<f64> {
+        self.legs.iter().zip(fills)
+            .map(|(leg, fill)| {
+                let diff = fill.filled - units * leg.ratio;
+                if diff.abs() < QUANTITY_EPSILON { 0.0 } else { diff }
+            })
+            .collect()
+    }
+
+    /// Realized net price per spread unit from the leg fills
+    pub fn net_price(&self, fills: &[LegFill]) -> Option<f64> {
+        fills.iter().zip(&self.legs)
+            .map(|(fill, leg)| fill.average_price.map(|price| signed(leg.side) * leg.ratio * price))
+            .sum()
+    }
+}
+
+/// Fill state of one leg
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegFill {
+    /// Symbol
+    pub symbol: String,
+    /// Quantity requested across all attempts
+    pub requested: f64,
+    /// Quantity filled, net of any unwind
+    pub filled: f64,
+    /// Volume-weighted fill price
+    pub average_price: Option<f64>,
+    /// Venue order IDs sent for this leg
+    pub order_ids: Vec<String>,
+}
+
+impl LegFill {
+    /// Empty fill state for a leg
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            requested: 0.0,
+            filled: 0.0,
+            average_price: None,
+            order_ids: Vec::new(),
+        }
+    }
+
+    /// Add a fill to the leg, updating the average price
+    pub fn add_fill(&mut self, quantity: f64, price: f64) {
+        if quantity <= 0.0 {
+            return;
+        }
+        let previous = self.average_price.unwrap_or(0.0) * self.filled;
+        self.filled += quantity;
+        self.average_price = Some((previous + quantity * price) / self.filled);
+    }
+
+    /// Remove quantity traded back out by an unwind
+    pub fn remove_fill(&mut self, quantity: f64) {
+        self.filled = (self.filled - quantity).max(0.0);
+        if self.filled <= QUANTITY_EPSILON {
+            self.filled = 0.0;
+            self.average_price = None;
+        }
+    }
+}
+
+/// Final state of a multi-leg order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MultiLegStatus {
+    /// Every leg filled in full
+    Filled,
+    /// Legs are balanced at less than the full quantity
+    PartiallyFilled,
+    /// Nothing remains filled
+    Unfilled,
+    /// Legs remain unbalanced
+    Legged,
+}
+
+/// Result of executing a multi-leg order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiLegResult {
+    /// Multi-leg order ID
+    pub order_id: String,
+    /// Per-leg fill state, in leg order
+    pub legs: Vec<LegFill>,
+    /// Spread units completed on every leg
+    pub completed_units: f64,
+    /// Realized net price per spread unit
+    pub net_price: Option<f64>,
+    /// Fallback applied, if legs filled unevenly
+    pub fallback_used: Option<LeggingFallback>,
+    /// Remaining per-leg imbalance (positive = over-filled)
+    pub imbalances: Vec<f64>,
+    /// Final status
+    pub status: MultiLegStatus,
+}
+
+impl MultiLegResult {
+    /// Build the result from the final leg fills
+    pub fn new(order: &MultiLegOrder, legs: Vec<LegFill>, fallback_used: Option<LeggingFallback>) -> Self {
+        let completed_units = order.completed_units(&legs);
+        let imbalances = order.imbalances(&legs, completed_units);
+        let status = if imbalances.iter().any(|diff| *diff != 0.0) {
+            MultiLegStatus::Legged
+        } else if completed_units <= QUANTITY_EPSILON {
+            MultiLegStatus::Unfilled
+        } else if completed_units + QUANTITY_EPSILON >= order.quantity {
+            MultiLegStatus::Filled
+        } else {
+            MultiLegStatus::PartiallyFilled
+        };
+
+        Self {
+            order_id: order.id.clone(),
+            net_price: order.net_price(&legs),
+            legs,
+            completed_units,
+            fallback_used,
+            imbalances,
+            status,
+        }
+    }
+}
+
+/// +1 for buys, -1 for sells
+fn signed(side: OrderSide) -> f64 {
+    match side {
+        OrderSide::Buy => 1.0,
+        OrderSide::Sell => -1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legged_fills_are_detected_and_priced() {
+        let leg = |symbol: &str, side, ratio, price| OrderLeg {
+            symbol: symbol.to_string(),
+            side,
+            ratio,
+            price,
+            venues: vec!["venue1".to_string()],
+        };
+        let order = MultiLegOrder {
+            id: "pair-1".to_string(),
+            legs: vec![leg("ETH-USD", OrderSide::Buy, 1.0, 3000.0), leg("BTC-USD", OrderSide::Sell, 0.05, 60000.0)],
+            quantity: 2.0,
+            max_net_price: Some(0.0),
+            fallback: LeggingFallback::Unwind,
+            additional_params: HashMap::new(),
+        };
+        assert!(order.validate().is_ok());
+        assert!(MultiLegOrder { max_net_price: Some(-1.0), ..order.clone() }.validate().is_err());
+
+        let mut eth = LegFill::new("ETH-USD");
+        eth.add_fill(2.0, 3000.0);
+        let mut btc = LegFill::new("BTC-USD");
+        btc.add_fill(0.05, 60000.0);
+
+        // Second leg only covered one spread unit
+        let result = MultiLegResult::new(&order, vec![eth.clone(), btc.clone()], None);
+        assert_eq!(result.status, MultiLegStatus::Legged);
+        assert!((result.completed_units - 1.0).abs() < 1e-9);
+        assert!((result.imbalances[0] - 1.0).abs() < 1e-9);
+
+        // Unwinding the excess leaves a balanced partial fill at zero net
+        eth.remove_fill(1.0);
+        let result = MultiLegResult::new(&order, vec![eth, btc], Some(LeggingFallback::Unwind));
+        assert_eq!(result.status, MultiLegStatus::PartiallyFilled);
+        assert!(result.net_price.unwrap().abs() < 1e-9);
+    }
+}
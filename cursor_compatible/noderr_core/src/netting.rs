@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Netting groups for correlated instruments
+//!
+//! Positions in instruments that move together — the same asset listed on
+//! several venues, or spot against its perpetual — offset each other, and
+//! adding their values up gross double-counts the risk. A netting group names
+//! such instruments and how much of an offsetting long/short pair is netted
+//! away: a ratio of 1.0 treats the members as the same instrument, 0.0 keeps
+//! them gross. Symbols outside every group are always counted gross.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Instruments whose offsetting positions are netted against each other
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NettingGroup {
+    /// Group name reported in netted exposures
+    pub name: String,
+    /// Member symbols
+    pub members: Vec<String>,
+    /// Fraction of offsetting long/short exposure that is netted away (0.0-1.0)
+    pub netting_ratio: f64,
+}
+
+impl NettingGroup {
+    /// Create a netting group
+    pub fn new(name: &str, members: &[&str], netting_ratio: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            members: members.iter().map(|m| m.to_string()).collect(),
+            netting_ratio,
+        }
+    }
+
+    /// Whether a symbol belongs to this group
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.members.iter().any(|m| m == symbol)
+    }
+
+    /// Netting ratio clamped to its valid range
+    pub fn ratio(&self) -> f64 {
+        if self.netting_ratio.is_finite() {
+            self.netting_ratio.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Net a set of signed legs, ignoring symbols outside the group
+    pub fn net<'a>(&self, legs: impl IntoIterator<Item = (&'a str, f64)>) -> NettedExposure {
+        let (mut long, mut short) = (0.0, 0.0);
+        for (symbol, signed) in legs {
+            if !self.contains(symbol) {
+                continue;
+            }
+            if signed >= 0.0 {
+                long += signed;
+            } else {
+                short -= signed;
+            }
+        }
+
+        let gross = long + short;
+        let net = (gross - 2.0 * self.ratio() * f64::min(long, short)).max(0.0);
+
+        NettedExposure {
+            group: self.name.clone(),
+            long,
+            short,
+            gross,
+            net,
+        }
+    }
+}
+
+/// Exposure of a netting group before and after netting
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NettedExposure {
+    /// Group name
+    pub group: String,
+    /// Sum of long legs
+    pub long: f64,
+    /// Sum of short legs (positive)
+    pub short: f64,
+    /// Long plus short
+    pub gross: f64,
+    /// Exposure after netting offsetting legs
+    pub net: f64,
+}
+
+impl NettedExposure {
+    /// Exposure removed by netting
+    pub fn offset(&self) -> f64 {
+        self.gross - self.net
+    }
+}
+
+/// Group a symbol nets in; a symbol listed in several groups uses the first
+pub fn find_group<'a>(groups: &'a [NettingGroup], symbol: &str) -> Option<&'a NettingGroup> {
+    groups.iter().find(|g| g.contains(symbol))
+}
+
+/// Netted exposure of every group with at least one leg
+pub fn group_exposures<'a>(
+    groups: &[NettingGroup],
+    legs: impl IntoIterator<Item = (&'a str, f64)>,
+) -> Vec<NettedExposure> {
+    let mut by_group: HashMap<&str, Vec<(&'a str, f64)>> = HashMap::new();
+    for (symbol, signed) in legs {
+        if let Some(group) = find_group(groups, symbol) {
+            by_group.entry(group.name.as_str()).or_default().push((symbol, signed));
+        }
+    }
+
+    groups
+        .iter()
+        .filter_map(|group| {
+            by_group
+                .remove(group.name.as_str())
+                .map(|legs| group.net(legs))
+        })
+        .collect()
+}
+
+/// Total exposure with grouped legs netted and ungrouped legs counted gross
+pub fn netted_total<'a>(
+    groups: &[NettingGroup],
+    legs: impl IntoIterator<Item = (&'a str, f64)>,
+) -> f64 {
+    let legs: Vec<(&'a str, f64)> = legs.into_iter().collect();
+    let ungrouped: f64 = legs
+        .iter()
+        .filter(|(symbol, _)| find_group(groups, symbol).is_none())
+        .map(|(_, signed)| signed.abs())
+        .sum();
+    let grouped: f64 = group_exposures(groups, legs).iter().map(|e| e.net).sum();
+
+    ungrouped + grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_netting_ratios() {
+        let full = NettingGroup::new("BTC", &["BTC-USD", "BTC-PERP"], 1.0);
+        let exposure = full.net([("BTC-USD", 100.0), ("BTC-PERP", -80.0), ("ETH-USD", 50.0)]);
+        assert_eq!(exposure.gross, 180.0);
+        assert_eq!(exposure.net, 20.0);
+        assert_eq!(exposure.offset(), 160.0);
+
+        let partial = NettingGroup::new("BTC", &["BTC-USD", "BTC-PERP"], 0.5);
+        assert_eq!(partial.net([("BTC-USD", 100.0), ("BTC-PERP", -80.0)]).net, 100.0);
+
+        // Same-direction legs never net
+        assert_eq!(full.net([("BTC-USD", 100.0), ("BTC-PERP", 80.0)]).net, 180.0);
+
+        let groups = vec![full];
+        let total = netted_total(&groups, [("BTC-USD", 100.0), ("BTC-PERP", -80.0), ("ETH-USD", -50.0)]);
+        assert_eq!(total, 70.0);
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Object pools for per-tick scratch buffers
+//!
+//! The market data and signal paths run on every tick, and each run used to
+//! build fresh `Vec`s, maps and strings only to drop them a few microseconds
+//! later. A pool keeps those buffers after use: acquiring one hands back a
+//! cleared buffer that still owns its capacity, so a warmed-up path stops
+//! touching the allocator. Pools are bounded and never shrink buffers they
+//! hand out; a buffer returned to a full pool is simply dropped.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+/// A buffer that can be cleared for reuse without giving up its capacity
+pub trait Reusable: Default {
+    /// Clear contents, keeping allocated capacity
+    fn reset(&mut self);
+}
+
+impl<T> Reusable for Vec<T> {
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Reusable for VecDeque<T> {
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+impl Reusable for String {
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> Reusable for HashMap<K, V, S> {
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+/// Usage counters of a pool
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolStats {
+    /// Buffers created because the pool was empty
+    pub created: u64,
+    /// Acquisitions served from the pool
+    pub reused: u64,
+    /// Buffers dropped because the pool was full
+    pub discarded: u64,
+    /// Buffers currently waiting in the pool
+    pub pooled: usize,
+}
+
+/// Bounded pool of reusable buffers
+pub struct ObjectPool<T: Reusable> {
+    /// Idle buffers
+    free: Mutex<Vec<T>>,
+    /// Most idle buffers kept
+    max_pooled: usize,
+    /// Buffers created
+    created: AtomicU64,
+    /// Acquisitions served from the pool
+    reused: AtomicU64,
+    /// Buffers dropped on return
+    discarded: AtomicU64,
+}
+
+impl<T: Reusable> ObjectPool<T> {
+    /// Create a pool keeping at most `max_pooled` idle buffers
+    pub fn new(max_pooled: usize) -> Self {
+        Self {
+            free: Mutex::new(Vec::with_capacity(max_pooled)),
+            max_pooled,
+            created: AtomicU64::new(0),
+            reused: AtomicU64::new(0),
+            discarded: AtomicU64::new(0),
+        }
+    }
+
+    /// Take a cleared buffer, creating one if the pool is empty
+    pub fn acquire(&self) -> Pooled<'_, T> {
+        let item = match self.free.lock().unwrap().pop() {
+            Some(item) => {
+                self.reused.fetch_add(1, Ordering::Relaxed);
+                item
+            }
+            None => {
+                self.created.fetch_add(1, Ordering::Relaxed);
+                T::default()
+            }
+        };
+        Pooled { pool: self, item: Some(item) }
+    }
+
+    /// Return a buffer to the pool
+    pub fn release(&self, mut item: T) {
+        item.reset();
+        let mut free = self.free.lock().unwrap();
+        if free.len() < self.max_pooled {
+            free.push(item);
+        } else {
+            self.discarded.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Usage counters
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            created: self.created.load(Ordering::Relaxed),
+            reused: self.reused.load(Ordering::Relaxed),
+            discarded: self.discarded.load(Ordering::Relaxed),
+            pooled: self.free.lock().unwrap().len(),
+        }
+    }
+}
+
+impl<T: Reusable> Default for ObjectPool<T> {
+    fn default() -> Self {
+        Self::new(16)
+    }
+}
+
+/// A buffer on loan from a pool; goes back to the pool when dropped
+pub struct Pooled<'a, T: Reusable> {
+    /// Owning pool
+    pool: &'a ObjectPool<T>,
+    /// The buffer; `None` only once taken out of the pool for good
+    item: Option<T>,
+}
+
+impl<T: Reusable> Pooled<'_, T> {
+    /// Keep the buffer instead of returning it to the pool
+    pub fn detach(mut self) -> T {
+        self.item.take().expect("pooled item present until dropped")
+    }
+}
+
+impl<T: Reusable> Deref for Pooled<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.item.as_ref().expect("pooled item present until dropped")
+    }
+}
+
+impl<T: Reusable> DerefMut for Pooled<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.item.as_mut().expect("pooled item present until dropped")
+    }
+}
+
+impl<T: Reusable> Drop for Pooled<'_, T> {
+    fn drop(&mut self) {
+        if let Some(item) = self.item.take() {
+            self.pool.release(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_reuses_buffers() {
+        let pool: ObjectPool<Vec<f64>> = ObjectPool::new(1);
+
+        {
+            let mut buffer = pool.acquire();
+            buffer.extend([1.0, 2.0, 3.0]);
+        }
+        let capacity = {
+            let buffer = pool.acquire();
+            assert!(buffer.is_empty());
+            buffer.capacity()
+        };
+        assert!(capacity >= 3);
+
+        // Steady state: every acquisition after the first is served from the pool
+        for _ in 0..100 {
+            pool.acquire().push(0.0);
+        }
+        let stats = pool.stats();
+        assert_eq!(stats.created, 1);
+        assert_eq!(stats.reused, 101);
+        assert_eq!(stats.pooled, 1);
+
+        // Two buffers out at once overflow a pool of one
+        let first = pool.acquire();
+        let second = pool.acquire();
+        drop(first);
+        drop(second);
+        assert_eq!(pool.stats().discarded, 1);
+
+        let kept = pool.acquire().detach();
+        assert!(kept.is_empty());
+        assert_eq!(pool.stats().pooled, 0);
+    }
+}
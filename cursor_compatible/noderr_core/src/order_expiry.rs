@@ -0,0 +1,402 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::execution::ExecutionStatus;
+use crate::order_router::{Order, TimeInForce};
+
+/// Cancels resting orders on a venue on behalf of the expiry scheduler
+#[async_trait]
+pub trait OrderCanceller: Send + Sync {
+    /// Cancel an expired order; an error leaves it to be retried on the next sweep
+    async fn cancel_expired(&self, expiry: &ScheduledExpiry) -> Result<(), String>;
+
+    /// Record that an order expired, whether cancelled client-side or by the venue
+    async fn order_expired(&self, expiry: &ScheduledExpiry);
+}
+
+/// Expiry scheduler configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderExpiryConfig {
+    /// How often to sweep for expired orders (ms)
+    pub check_interval_ms: u64,
+
+    /// Trading session close time (UTC) used for session-bound orders
+    pub session_close_utc: NaiveTime,
+
+    /// Time-in-force instructions each venue enforces natively (e.g. "GTT", "GTD", "SESSION")
+    pub native_tif_support: HashMap<String, HashSet<String>>,
+
+    /// Grace period after expiry before a natively-managed order is treated as expired (ms)
+    pub native_grace_ms: u64,
+}
+
+impl Default for OrderExpiryConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_ms: 250,
+            session_close_utc: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            native_tif_support: HashMap::new(),
+            native_grace_ms: 2000,
+        }
+    }
+}
+
+/// Expiry state of a tracked order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExpiryState {
+    /// Resting and not yet expired
+    Active,
+    /// Expired and cancelled (client-side or natively by the venue)
+    Expired,
+    /// Filled before expiry
+    Filled,
+    /// Cancelled before expiry for another reason
+    Cancelled,
+    /// Client-side cancel failed; will be retried on the next sweep
+    CancelFailed(String),
+}
+
+/// Order tracked by the expiry scheduler
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledExpiry {
+    /// Order ID
+    pub order_id: String,
+    /// Venue the order rests on
+    pub venue: String,
+    /// Venue-assigned order ID, needed by exchanges to cancel
+    #[serde(default)]
+    pub venue_order_id: Option<String>,
+    /// Symbol
+    pub symbol: String,
+    /// Time-in-force instruction
+    pub time_in_force: TimeInForce,
+    /// When the order expires
+    pub expires_at: DateTime<Utc>,
+    /// Whether the venue enforces this expiry natively
+    pub native: bool,
+    /// Current expiry state
+    pub state: ExpiryState,
+    /// Order status to report back into the OMS
+    pub execution_status: ExecutionStatus,
+    /// Last state change
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Local expiry scheduler that cancels time-bound orders client-side on
+/// venues lacking native support and reconciles expiry status
+pub struct OrderExpiryScheduler {
+    /// Configuration
+    config: RwLock<OrderExpiryConfig>,
+    /// Tracked orders by ID
+    orders: RwLock<HashMap<String, ScheduledExpiry>>,
+    /// Background sweep task
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl OrderExpiryScheduler {
+    /// Create a new expiry scheduler
+    pub fn new(config: OrderExpiryConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            orders: RwLock::new(HashMap::new()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Start tracking a resting order's expiry
+    pub async fn schedule(&self, order: &Order, venue: &str, venue_order_id: Option<&str>) {
+        let now = Utc::now();
+        let config = self.config.read().await;
+
+        let expires_at = match order.time_in_force {
+            TimeInForce::Session => next_session_close(now, config.session_close_utc),
+            tif => match tif.expires_at(now) {
+                Some(expires_at) => expires_at,
+                None => return,
+            },
+        };
+
+        let native = config
+            .native_tif_support
+            .get(venue)
+            .map_or(false, |supported| supported.contains(order.time_in_force.name()));
+        drop(config);
+
+        debug!(
+            "Scheduling {} expiry for order {} on {} at {} (native: {})",
+            order.time_in_force.name(), order.id, venue, expires_at, native
+        );
+
+        self.orders.write().await.insert(order.id.clone(), ScheduledExpiry {
+            order_id: order.id.clone(),
+            venue: venue.to_string(),
+            venue_order_id: venue_order_id.map(str::to_string),
+            symbol: order.symbol.clone(),
+            time_in_force: order.time_in_force,
+            expires_at,
+            native,
+            state: ExpiryState::Active,
+            execution_status: ExecutionStatus::InProgress,
+            updated_at: now,
+        });
+    }
+
+    /// Cancel expired orders client-side and mark natively-expired orders,
+    /// reporting each expiry back through `canceller`
+    pub async fn process_expirations(&self, now: DateTime<Utc>, canceller: &dyn OrderCanceller) -> Vec<ScheduledExpiry> {
+        let grace = Duration::milliseconds(self.config.read().await.native_grace_ms as i64);
+
+        let due: Vec<ScheduledExpiry> = self.orders
+            .read()
+            .await
+            .values()
+            .filter(|entry| matches!(entry.state, ExpiryState::Active | ExpiryState::CancelFailed(_)))
+            .filter(|entry| {
+                let deadline = if entry.native { entry.expires_at + grace } else { entry.expires_at };
+                deadline <= now
+            })
+            .cloned()
+            .collect();
+
+        let mut expired = Vec::with_capacity(due.len());
+        for entry in due {
+            let state = if entry.native {
+                // Venue should have expired it; treat as expired once past the grace period
+                ExpiryState::Expired
+            } else {
+                match canceller.cancel_expired(&entry).await {
+                    Ok(()) => ExpiryState::Expired,
+                    Err(e) => {
+                        warn!("Failed to cancel expired order {} on {}: {}", entry.order_id, entry.venue, e);
+                        ExpiryState::CancelFailed(e)
+                    }
+                }
+            };
+
+            if let Some(updated) = self.set_state(&entry.order_id, state, now).await {
+                if updated.state == ExpiryState::Expired {
+                    info!("Order {} on {} expired ({})", updated.order_id, updated.venue, updated.time_in_force.name());
+                    canceller.order_expired(&updated).await;
+                    expired.push(updated);
+                }
+            }
+        }
+
+        expired
+    }
+
+    /// Reconcile a venue-reported order status into the expiry state
+    pub async fn reconcile(&self, order_id: &str, status: ExecutionStatus) -> Option<ScheduledExpiry> {
+        let now = Utc::now();
+        let current = self.orders.read().await.get(order_id).cloned()?;
+
+        let state = match status {
+            ExecutionStatus::Completed => ExpiryState::Filled,
+            ExecutionStatus::Cancelled | ExecutionStatus::TimedOut if current.expires_at <= now => {
+                ExpiryState::Expired
+            }
+            ExecutionStatus::Cancelled | ExecutionStatus::Rejected | ExecutionStatus::Failed => {
+                ExpiryState::Cancelled
+            }
+            _ => return Some(current),
+        };
+
+        self.set_state(order_id, state, now).await
+    }
+
+    /// Get the tracked expiry for an order
+    pub async fn get(&self, order_id: &str) -> Option<ScheduledExpiry> {
+        self.orders.read().await.get(order_id).cloned()
+    }
+
+    /// Get all orders still awaiting expiry
+    pub async fn get_active(&self) -> Vec<ScheduledExpiry> {
+        self.orders
+            .read()
+            .await
+            .values()
+            .filter(|entry| entry.state == ExpiryState::Active)
+            .cloned()
+            .collect()
+    }
+
+    /// Remove orders in a terminal state
+    pub async fn prune_terminal(&self) -> usize {
+        let mut orders = self.orders.write().await;
+        let before = orders.len();
+        orders.retain(|_, entry| matches!(entry.state, ExpiryState::Active | ExpiryState::CancelFailed(_)));
+        before - orders.len()
+    }
+
+    /// Start the background sweep loop, cancelling and reporting through `canceller`
+    pub async fn start(self: &Arc<Self>, canceller: Arc<dyn OrderCanceller>) {
+        let mut handle = self.task_handle.write().await;
+        if handle.is_some() {
+            return;
+        }
+
+        let scheduler = Arc::clone(self);
+        let interval_ms = self.config.read().await.check_interval_ms;
+        *handle = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_millis(interval_ms));
+            loop {
+                interval.tick().await;
+                scheduler.process_expirations(Utc::now(), canceller.as_ref()).await;
+            }
+        }));
+
+        info!("Order expiry scheduler started");
+    }
+
+    /// Stop the background sweep loop
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+            info!("Order expiry scheduler stopped");
+        }
+    }
+
+    /// Update configuration
+    pub async fn update_config(&self, config: OrderExpiryConfig) {
+        *self.config.write().await = config;
+    }
+
+    /// Apply a new state and matching OMS status to a tracked order
+    async fn set_state(&self, order_id: &str, state: ExpiryState, now: DateTime<Utc>) -> Option<ScheduledExpiry> {
+        let mut orders = self.orders.write().await;
+        let entry = orders.get_mut(order_id)?;
+
+        entry.execution_status = match state {
+            ExpiryState::Active | ExpiryState::CancelFailed(_) => ExecutionStatus::InProgress,
+            ExpiryState::Expired => ExecutionStatus::TimedOut,
+            ExpiryState::Filled => ExecutionStatus::Completed,
+            ExpiryState::Cancelled => ExecutionStatus::Cancelled,
+        };
+        entry.state = state;
+        entry.updated_at = now;
+
+        Some(entry.clone())
+    }
+}
+
+/// Next session close at or after `now`
+fn next_session_close(now: DateTime<Utc>, close: NaiveTime) -> DateTime<Utc> {
+    let today = DateTime::<Utc>::from_utc(now.date_naive().and_time(close), Utc);
+    if today > now {
+        today
+    } else {
+        today + Duration::days(1)
+    }
+}
+
+/// Create an order expiry scheduler
+pub fn create_order_expiry_scheduler(config: OrderExpiryConfig) -> Arc<OrderExpiryScheduler> {
+    Arc::new(OrderExpiryScheduler::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingCanceller {
+        cancelled: Mutex<Vec<String>>,
+        expired: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl OrderCanceller for RecordingCanceller {
+        async fn cancel_expired(&self, expiry: &ScheduledExpiry) -> Result<(), String> {
+            assert_eq!(expiry.symbol, "BTC-USD");
+            assert_eq!(expiry.venue_order_id.as_deref(), Some("v-1"));
+            self.cancelled.lock().unwrap().push(expiry.order_id.clone());
+            Ok(())
+        }
+
+        async fn order_expired(&self, expiry: &ScheduledExpiry) {
+            self.expired.lock().unwrap().push(expiry.order_id.clone());
+        }
+    }
+
+    fn gtt_order(id: &str) -> Order {
+        Order {
+            symbol: "BTC-USD".to_string(),
+            side: crate::order_router::OrderSide::Buy,
+            amount: 1.0,
+            price: 50000.0,
+            venues: vec![],
+            id: id.to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTT { duration_ms: 1000 },
+            additional_params: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_side_gtt_expiry() {
+        let canceller = RecordingCanceller::default();
+        let mut config = OrderExpiryConfig::default();
+        config.native_tif_support.insert("native_venue".to_string(), ["GTT".to_string()].into_iter().collect());
+        let scheduler = OrderExpiryScheduler::new(config);
+
+        scheduler.schedule(&gtt_order("local"), "plain_venue", Some("v-1")).await;
+        scheduler.schedule(&gtt_order("native"), "native_venue", Some("v-1")).await;
+
+        // Nothing is due yet
+        assert!(scheduler.process_expirations(Utc::now(), &canceller).await.is_empty());
+
+        // Only the non-native order is cancelled client-side once expired
+        let expired = scheduler.process_expirations(Utc::now() + Duration::milliseconds(1500), &canceller).await;
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].order_id, "local");
+        assert_eq!(expired[0].execution_status, ExecutionStatus::TimedOut);
+        assert_eq!(*canceller.cancelled.lock().unwrap(), vec!["local".to_string()]);
+
+        // The native order is marked expired after the grace period without a cancel call
+        let expired = scheduler.process_expirations(Utc::now() + Duration::milliseconds(5000), &canceller).await;
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].order_id, "native");
+        assert_eq!(canceller.cancelled.lock().unwrap().len(), 1);
+
+        // Both expiries are reported back
+        assert_eq!(*canceller.expired.lock().unwrap(), vec!["local".to_string(), "native".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_fill() {
+        let canceller = RecordingCanceller::default();
+        let scheduler = OrderExpiryScheduler::new(OrderExpiryConfig::default());
+
+        scheduler.schedule(&gtt_order("filled"), "venue", Some("v-1")).await;
+        let entry = scheduler.reconcile("filled", ExecutionStatus::Completed).await.unwrap();
+        assert_eq!(entry.state, ExpiryState::Filled);
+
+        assert!(scheduler.process_expirations(Utc::now() + Duration::milliseconds(5000), &canceller).await.is_empty());
+        assert!(canceller.expired.lock().unwrap().is_empty());
+        assert_eq!(scheduler.prune_terminal().await, 1);
+    }
+}
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Parent context carried by child orders.
+//!
+//! Execution algorithms split a parent order into children (TWAP/VWAP slices,
+//! multi-leg legs, repricer replacements). Each child carries the strategy ID,
+//! root parent order ID and signal ID in its `additional_params`, and fills
+//! are tagged with the same fields so execution metrics and attribution can
+//! roll children back up to the order and strategy that caused them.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::execution::ExecutionResult;
+use crate::order_router::Order;
+
+/// Order parameter holding the originating strategy ID
+pub const STRATEGY_ID_PARAM: &str = "strategyId";
+/// Order parameter holding the originating signal ID
+pub const SIGNAL_ID_PARAM: &str = "signalId";
+/// Order parameter holding the root parent order ID
+pub const PARENT_ORDER_ID_PARAM: &str = "parentOrderId";
+/// Order parameter holding the child's position within its parent
+pub const CHILD_INDEX_PARAM: &str = "childIndex";
+
+/// Longest client order ID accepted by every supported venue
+pub const MAX_CLIENT_ORDER_ID_LEN: usize = 36;
+
+/// Strategy, parent order and signal an order descends from
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderLineage {
+    /// Strategy that generated the parent order
+    pub strategy_id: Option<String>,
+    /// Root parent order; `None` for orders that are not children
+    pub parent_order_id: Option<String>,
+    /// Signal that triggered the parent order
+    pub signal_id: Option<String>,
+    /// Position of the child within its parent
+    pub child_index: Option<u32>,
+}
+
+impl OrderLineage {
+    /// Read the lineage carried in an order's parameters
+    pub fn of(order: &Order) -> Self {
+        let text = |key: &str| order.additional_params.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        Self {
+            strategy_id: text(STRATEGY_ID_PARAM),
+            parent_order_id: text(PARENT_ORDER_ID_PARAM),
+            signal_id: text(SIGNAL_ID_PARAM),
+            child_index: order.additional_params
+                .get(CHILD_INDEX_PARAM)
+                .and_then(|v| v.as_u64())
+                .map(|i| i as u32),
+        }
+    }
+
+    /// The order attribution rolls up to: the root parent, or the order itself
+    pub fn root_order_id<'a>(&'a self, order: &'a Order) -> &'a str {
+        self.parent_order_id.as_deref().unwrap_or(&order.id)
+    }
+
+    /// Write the lineage into order parameters, leaving unset fields alone
+    pub fn write(&self, params: &mut HashMap<String, serde_json::Value>) {
+        let fields = [
+            (STRATEGY_ID_PARAM, &self.strategy_id),
+            (PARENT_ORDER_ID_PARAM, &self.parent_order_id),
+            (SIGNAL_ID_PARAM, &self.signal_id),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                params.insert(key.to_string(), serde_json::Value::String(value.clone()));
+            }
+        }
+        if let Some(index) = self.child_index {
+            params.insert(CHILD_INDEX_PARAM.to_string(), serde_json::Value::from(index));
+        }
+    }
+
+    /// Tag a fill with the lineage of the order that produced it; tags
+    /// already on the fill (e.g. from a more specific child) are kept
+    pub fn tag(&self, order: &Order, result: &mut ExecutionResult) {
+        if result.signal_id.is_empty() {
+            if let Some(signal_id) = &self.signal_id {
+                result.signal_id = signal_id.clone();
+            }
+        }
+
+        let root = Some(self.root_order_id(order).to_string());
+        let fields = [
+            ("strategy_id", &self.strategy_id),
+            ("signal_id", &self.signal_id),
+            ("parent_order_id", &root),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                result.additional_data
+                    .entry(key.to_string())
+                    .or_insert_with(|| serde_json::Value::String(value.clone()));
+            }
+        }
+        if let Some(index) = self.child_index {
+            result.additional_data.entry("child_index".to_string()).or_insert_with(|| index.into());
+        }
+    }
+}
+
+/// Derive child `index` of `parent` with the given amount.
+///
+/// Children always point at the root parent, so slicing a child again (e.g. a
+/// TWAP slice split across legs) still rolls up to the original order.
+pub fn child_order(parent: &Order, index: u32, amount: f64) -> Order {
+    let parent_lineage = OrderLineage::of(parent);
+    let lineage = OrderLineage {
+        parent_order_id: Some(parent_lineage.root_order_id(parent).to_string()),
+        child_index: Some(index),
+        ..parent_lineage
+    };
+
+    let mut child = Order {
+        id: format!("{}-c{}", parent.id, index),
+        amount,
+        ..parent.clone()
+    };
+    lineage.write(&mut child.additional_params);
+    child
+}
+
+/// Venue client order ID: `<strategy>-<root>-<order>`.
+///
+/// The strategy tag is the first 8 alphanumeric characters of the strategy ID
+/// and the other parts are short hashes, so the ID stays within venue length
+/// limits, is stable across restarts and lets venue fills be grouped by
+/// strategy and parent order without a lookup.
+pub fn client_order_id(order: &Order) -> String {
+    let lineage = OrderLineage::of(order);
+    let strategy: String = lineage.strategy_id
+        .as_deref()
+        .unwrap_or("none")
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .take(8)
+        .collect();
+    let strategy = if strategy.is_empty() { "none".to_string() } else { strategy };
+
+    let id = format!("{}-{}-{}", strategy, short_hash(lineage.root_order_id(order)), short_hash(&order.id));
+    debug_assert!(id.len() <= MAX_CLIENT_ORDER_ID_LEN);
+    id
+}
+
+/// Strategy tag and root order hash encoded in a client order ID
+pub fn parse_client_order_id(client_order_id: &str) -> Option<(&str, &str)> {
+    let mut parts = client_order_id.split('-');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(strategy), Some(root), Some(_), None) if !strategy.is_empty() && root.len() == 8 => Some((strategy, root)),
+        _ => None,
+    }
+}
+
+/// First 8 hex characters of the SHA-256 of `value`
+fn short_hash(value: &str) -> String {
+    let digest = format!("{:x}", Sha256::digest(value.as_bytes()));
+    digest[..8].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_router::{OrderSide, TimeInForce};
+
+    #[test]
+    fn test_children_roll_up_to_root() {
+        let parent = Order {
+            symbol: "BTC/USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 10.0,
+            price: 50_000.0,
+            venues: vec!["binance".to_string()],
+            id: "order-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::from([
+                (STRATEGY_ID_PARAM.to_string(), serde_json::json!("momentum_v2")),
+                (SIGNAL_ID_PARAM.to_string(), serde_json::json!("sig-9")),
+            ]),
+        };
+
+        let slice = child_order(&parent, 2, 2.5);
+        let leg = child_order(&slice, 0, 1.0);
+        let lineage = OrderLineage::of(&leg);
+        assert_eq!(leg.id, "order-1-c2-c0");
+        assert_eq!(lineage.parent_order_id.as_deref(), Some("order-1"));
+        assert_eq!(lineage.strategy_id.as_deref(), Some("momentum_v2"));
+        assert_eq!(lineage.signal_id.as_deref(), Some("sig-9"));
+        assert_eq!(lineage.child_index, Some(0));
+
+        let parent_id = client_order_id(&parent);
+        let child_id = client_order_id(&leg);
+        assert!(child_id.len() <= MAX_CLIENT_ORDER_ID_LEN);
+        assert_ne!(parent_id, child_id);
+        assert_eq!(parse_client_order_id(&parent_id), parse_client_order_id(&child_id));
+        assert_eq!(parse_client_order_id(&child_id).unwrap().0, "momentum");
+
+        let mut result = ExecutionResult::success(leg.id.clone(), String::new(), None, 1.0, 50_000.0);
+        lineage.tag(&leg, &mut result);
+        OrderLineage::of(&parent).tag(&parent, &mut result);
+        assert_eq!(result.signal_id, "sig-9");
+        assert_eq!(result.additional_data["parent_order_id"], "order-1");
+        assert_eq!(result.additional_data["child_index"], 0);
+    }
+}
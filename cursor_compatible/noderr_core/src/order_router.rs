@@ -13,14 +13,35 @@
 // copies or substantial portions of the Software.
 
 use std::sync::Arc;
-use std::collections::HashMap;
-use tokio::sync::{RwLock, Mutex};
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::Mutex;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
-use tracing::{info, error, warn, debug};
+use tracing::{info, error, warn, debug, Instrument};
 use uuid::Uuid;
+use futures::stream::{self, StreamExt};
 
 use crate::execution::{ExecutionResult, ExecutionStatus};
+use crate::quote_freshness::{FreshnessVerdict, QuoteFreshnessGuard};
+use crate::orderbook::OrderBookManager;
+use crate::position::PositionManager;
+use crate::order_expiry::{ExpiryState, OrderCanceller, OrderExpiryScheduler, ScheduledExpiry};
+use crate::trading_events::{TradingEvent, TradingEventBus};
+use crate::venue_control::{VenueControl, VenueMode};
+use crate::error_taxonomy::ClassifiedError;
+use crate::dex_venue::{DexVenueConnector, DexVenueError};
+use crate::connectors::{ConnectorError, OrderAck, VenueConnector, VenueOrderStatus};
+use crate::fix::{FixError, FixGateway};
+use crate::fill_surveillance::FillSurveillance;
+use crate::multi_leg::{LegFill, LeggingFallback, MultiLegOrder, MultiLegResult};
+use crate::ids::{new_id, IdKind};
+use crate::traceability::{TraceRegistry, TraceRelation};
+use crate::venue_trust::{VenueOutcome, VenueTrustService};
+use crate::routing_policy::RoutingPolicyEngine;
+use crate::order_lineage::{self, OrderLineage};
+use crate::logging::correlation_span;
+use crate::failover::FailoverCoordinator;
 
 /// Errors that can occur during order routing
 #[derive(Debug, Error)]
@@ -39,6 +60,42 @@ pub enum OrderRouterError {
 
     #[error("Timeout during execution")]
     ExecutionTimeout,
+
+    #[error("All venue quotes are stale for symbol: {0}")]
+    StaleQuotes(String),
+
+    #[error("Post-only order would take liquidity: {0}")]
+    PostOnlyWouldCross(String),
+
+    #[error("Post-only order cannot be checked against the book: {0}")]
+    PostOnlyUnverified(String),
+
+    #[error("Reduce-only order would increase position: {0}")]
+    ReduceOnlyViolation(String),
+
+    #[error("Batch rejected: {0}")]
+    BatchRejected(String),
+
+    #[error("Order not found: {0}")]
+    OrderNotFound(String),
+
+    #[error("Not configured: {0}")]
+    NotConfigured(String),
+
+    #[error("Routing policy violation: {0}")]
+    PolicyViolation(String),
+
+    #[error("Instance is on standby: {0}")]
+    Standby(String),
+
+    #[error("Cancel refused by venue: {0}")]
+    CancelRejected(String),
+
+    #[error("Original order cancelled but its replacement failed: {0}")]
+    ReplacementFailed(String),
+
+    #[error("Cancel not yet confirmed by venue: {0}")]
+    CancelPending(String),
 }
 
 /// Reasons for execution failure
@@ -69,12 +126,86 @@ pub struct Order {
     pub max_slippage: Option<f64>,
     /// Max retry attempts
     pub max_retries: Option<u32>,
+    /// Only rest on the book as a maker order; reject if it would take liquidity
+    #[serde(default)]
+    pub post_only: bool,
+    /// Only reduce an existing position; never increase or flip it
+    #[serde(default)]
+    pub reduce_only: bool,
+    /// Time-in-force instruction
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
     /// Additional parameters
     pub additional_params: HashMap<String, serde_json::Value>,
 }
 
-/// Order side (buy or sell)
+impl Order {
+    /// Intended liquidity role for this order
+    pub fn liquidity_intent(&self) -> &'static str {
+        if self.post_only { "maker" } else { "taker" }
+    }
+}
+
+/// Time-in-force instruction for an order
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeInForce {
+    /// Good till cancelled
+    GTC,
+    /// Immediate or cancel; unfilled remainder is cancelled
+    IOC,
+    /// Fill or kill; the whole order fills immediately or not at all
+    FOK,
+    /// Good till time; expires after a duration from submission
+    GTT { duration_ms: u64 },
+    /// Good till date; expires at an absolute time
+    GTD { expires_at: DateTime<Utc> },
+    /// Expires at the close of the current trading session
+    Session,
+}
+
+impl Default for TimeInForce {
+    fn default() -> Self {
+        TimeInForce::GTC
+    }
+}
+
+impl TimeInForce {
+    /// Whether the order must execute immediately rather than rest on the book
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, TimeInForce::IOC | TimeInForce::FOK)
+    }
+
+    /// Whether the order carries an expiry that must be managed
+    pub fn has_expiry(&self) -> bool {
+        matches!(self, TimeInForce::GTT { .. } | TimeInForce::GTD { .. } | TimeInForce::Session)
+    }
+
+    /// Short name used for venue capability lookups and tagging
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeInForce::GTC => "GTC",
+            TimeInForce::IOC => "IOC",
+            TimeInForce::FOK => "FOK",
+            TimeInForce::GTT { .. } => "GTT",
+            TimeInForce::GTD { .. } => "GTD",
+            TimeInForce::Session => "SESSION",
+        }
+    }
+
+    /// Absolute expiry for time-bound instructions submitted at `submitted_at`
+    pub fn expires_at(&self, submitted_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            TimeInForce::GTT { duration_ms } => {
+                Some(submitted_at + chrono::Duration::milliseconds(*duration_ms as i64))
+            }
+            TimeInForce::GTD { expires_at } => Some(*expires_at),
+            _ => None,
+        }
+    }
+}
+
+/// Order side (buy or sell)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OrderSide {
     Buy,
     Sell,
@@ -110,40 +241,1143 @@ pub struct VenueExecutionResult {
     pub details: Option<serde_json::Value>,
 }
 
+/// Configuration for bulk order operations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchConfig {
+    /// Maximum number of orders accepted in a single batch request
+    pub max_batch_size: usize,
+    /// Maximum in-flight requests when pipelining orders to venues
+    pub pipeline_concurrency: usize,
+    /// Validate every order before sending any; reject the whole batch on failure
+    pub validate_all_first: bool,
+    /// Distance from the touch at which a mass cancel halves an order's priority (basis points)
+    pub cancel_distance_scale_bps: f64,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 200,
+            pipeline_concurrency: 8,
+            validate_all_first: true,
+            cancel_distance_scale_bps: 50.0,
+        }
+    }
+}
+
+/// Outcome of a single order within a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemResult {
+    /// Order ID
+    pub order_id: String,
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Venue the operation was sent to (if any)
+    pub venue: Option<String>,
+    /// Execution result for submissions and replacements
+    pub execution: Option<ExecutionResult>,
+    /// Error message (if failed)
+    pub error: Option<String>,
+}
+
+impl BatchItemResult {
+    fn from_result(order_id: String, venue: Option<String>, result: Result<Option<ExecutionResult>, OrderRouterError>) -> Self {
+        match result {
+            Ok(execution) => Self {
+                order_id,
+                success: true,
+                venue,
+                execution,
+                error: None,
+            },
+            Err(e) => Self {
+                order_id,
+                success: false,
+                venue,
+                execution: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Result of a bulk order operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// Batch ID
+    pub batch_id: String,
+    /// Per-order outcomes, in request order
+    pub items: Vec<BatchItemResult>,
+    /// Number of successful operations
+    pub succeeded: usize,
+    /// Number of failed operations
+    pub failed: usize,
+}
+
+impl BatchResult {
+    fn new(items: Vec<BatchItemResult>) -> Self {
+        let succeeded = items.iter().filter(|item| item.success).count();
+        Self {
+            batch_id: new_id(IdKind::Batch),
+            failed: items.len() - succeeded,
+            items,
+            succeeded,
+        }
+    }
+}
+
+/// An order resting on a venue after routing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestingOrder {
+    /// Order ID
+    pub order_id: String,
+    /// Symbol
+    pub symbol: String,
+    /// Buy or sell
+    pub side: OrderSide,
+    /// Order amount
+    pub amount: f64,
+    /// Order price
+    pub price: f64,
+    /// Venue the order rests on
+    pub venue: String,
+    /// Time-in-force instruction
+    pub time_in_force: TimeInForce,
+    /// Strategy that placed the order, from the `strategyId` order parameter
+    pub strategy_id: Option<String>,
+    /// When the order was routed
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Where a resting order falls in a mass cancel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelPriority {
+    /// Order ID
+    pub order_id: String,
+    /// Venue the order rests on
+    pub venue: String,
+    /// Notional exposure the order would add if it filled
+    pub exposure_reduction: f64,
+    /// Distance from the same-side touch (basis points); zero when no book is available
+    pub distance_bps: f64,
+    /// Exposure reduction discounted by distance; higher is cancelled first
+    pub score: f64,
+}
+
+/// A non-flat position that would be closed by a flatten request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenPosition {
+    /// Agent holding the position
+    pub agent_id: String,
+    /// Symbol
+    pub symbol: String,
+    /// Signed position size
+    pub net_size: f64,
+    /// Average entry price
+    pub average_price: f64,
+    /// Unrealized PnL
+    pub unrealized_pnl: f64,
+}
+
+/// Outcome of draining a venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrainReport {
+    /// Venue drained
+    pub venue: String,
+    /// Orders cancelled
+    pub cancelled: Vec<String>,
+    /// Orders still resting when the drain finished
+    pub remaining: Vec<String>,
+    /// Whether the venue was fully drained and disabled
+    pub completed: bool,
+}
+
+/// Why a venue cancel did not go through
+enum CancelFailure {
+    /// The venue refused; the order is no longer open there and may have filled
+    Refused(String),
+    /// The request failed; the order is still resting
+    Failed(String),
+}
+
+/// Cancel-and-replace request for a resting order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplaceRequest {
+    /// ID of the order to cancel
+    pub order_id: String,
+    /// Replacement order
+    pub replacement: Order,
+}
+
+/// Venue results kept for cancels and reconciliation
+const EXECUTION_CACHE_CAPACITY: usize = 1000;
+
+/// Recent venue results, evicted oldest first
+///
+/// Results for orders still resting are never evicted; cancels need their
+/// venue order IDs for as long as the order is working.
+struct ExecutionCache {
+    /// Results by order ID
+    results: HashMap<String, VenueExecutionResult>,
+    /// Order IDs, oldest first
+    insertion_order: VecDeque<String>,
+    /// Results kept before the oldest are evicted
+    capacity: usize,
+}
+
+impl ExecutionCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            results: HashMap::new(),
+            insertion_order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn get(&self, order_id: &str) -> Option<&VenueExecutionResult> {
+        self.results.get(order_id)
+    }
+
+    fn insert(&mut self, order_id: String, result: VenueExecutionResult) {
+        if self.results.insert(order_id.clone(), result).is_none() {
+            self.insertion_order.push_back(order_id);
+        }
+    }
+
+    fn remove(&mut self, order_id: &str) -> Option<VenueExecutionResult> {
+        let result = self.results.remove(order_id)?;
+        self.insertion_order.retain(|id| id != order_id);
+        Some(result)
+    }
+
+    /// Evict the oldest results over capacity, skipping any `keep` selects
+    fn evict(&mut self, keep: impl Fn(&str) -> bool) {
+        let mut candidates = self.insertion_order.len();
+        while self.results.len() > self.capacity && candidates > 0 {
+            candidates -= 1;
+            let Some(order_id) = self.insertion_order.pop_front() else {
+                break;
+            };
+            if keep(&order_id) {
+                self.insertion_order.push_back(order_id);
+            } else {
+                self.results.remove(&order_id);
+            }
+        }
+    }
+}
+
+/// Limit price allowing `slippage_pct` of adverse movement from `price`
+fn slipped_price(price: f64, side: OrderSide, slippage_pct: f64) -> f64 {
+    match side {
+        OrderSide::Buy => price * (1.0 + slippage_pct / 100.0),
+        OrderSide::Sell => price * (1.0 - slippage_pct / 100.0),
+    }
+}
+
+/// Record a routed order and the signal that triggered it; trace failures never block routing
+async fn trace_order(registry: &TraceRegistry, order: &Order, signal_id: Option<&str>) {
+    let details = serde_json::json!({
+        "symbol": order.symbol,
+        "side": order.side,
+        "amount": order.amount,
+        "price": order.price,
+        "venues": order.venues,
+        "strategy_id": order.additional_params.get("strategyId"),
+    });
+    let summary = format!("{:?} {} {} @ {}", order.side, order.amount, order.symbol, order.price);
+    let mut traced = registry.record(&order.id, &summary, details).await;
+    if let (Ok(()), Some(signal_id)) = (&traced, signal_id) {
+        traced = registry.link(signal_id, &order.id, TraceRelation::Triggered).await;
+    }
+    if let Err(e) = traced {
+        warn!("Failed to trace order {}: {}", order.id, e);
+    }
+}
+
+/// Link an order to its fill, or to a decision recording why it was rejected
+async fn trace_result(registry: &TraceRegistry, order_id: &str, result: &Result<ExecutionResult, OrderRouterError>) {
+    let traced = match result {
+        Ok(execution) => {
+            let details = serde_json::json!({
+                "status": execution.status,
+                "quantity": execution.executed_quantity,
+                "price": execution.average_price,
+                "venue_order_id": execution.order_id,
+            });
+            match registry.record(&execution.id, "Order filled", details).await {
+                Ok(()) => registry.link(order_id, &execution.id, TraceRelation::FilledBy).await,
+                Err(e) => Err(e),
+            }
+        }
+        Err(e) => {
+            let decision_id = new_id(IdKind::Decision);
+            let details = serde_json::json!({ "code": e.code(), "error": e.to_string() });
+            match registry.record(&decision_id, "Order rejected", details).await {
+                Ok(()) => registry.link(order_id, &decision_id, TraceRelation::ExplainedBy).await,
+                Err(e) => Err(e),
+            }
+        }
+    };
+    if let Err(e) = traced {
+        warn!("Failed to trace result of order {}: {}", order_id, e);
+    }
+}
+
 /// Smart Order Router implemented in Rust for maximum performance
 pub struct SmartOrderRouter {
-    /// Trust scores for venues
-    trust_scores: Arc<RwLock<HashMap<String, f64>>>,
-    /// Order retry engine
+    /// Order retry engine; also owns the venue trust scores used for ranking
     retry_engine: Arc<OrderRetryEngine>,
     /// Recent execution results (cached)
-    recent_executions: Arc<Mutex<HashMap<String, VenueExecutionResult>>>,
+    recent_executions: Arc<Mutex<ExecutionCache>>,
+    /// Orders resting on venues, keyed by order ID
+    resting_orders: Arc<Mutex<HashMap<String, RestingOrder>>>,
+    /// Pre-send quote freshness guard (optional)
+    freshness_guard: Option<Arc<QuoteFreshnessGuard>>,
+    /// Order books used to enforce post-only orders (optional)
+    order_books: Option<Arc<OrderBookManager>>,
+    /// Position manager used to enforce reduce-only orders (optional)
+    position_manager: Option<Arc<PositionManager>>,
+    /// Local expiry scheduler for time-bound orders (optional)
+    expiry_scheduler: Option<Arc<OrderExpiryScheduler>>,
+    /// Bulk operation configuration
+    batch_config: BatchConfig,
+    /// Push notifications for fills and order state changes (optional)
+    event_bus: Option<Arc<TradingEventBus>>,
+    /// Operator venue modes (optional)
+    venue_control: Option<Arc<VenueControl>>,
+    /// Decentralized venues, keyed by venue ID
+    dex_venues: HashMap<String, Arc<DexVenueConnector>>,
+    /// Centralized exchange connectors, keyed by venue ID
+    venue_connectors: HashMap<String, Arc<dyn VenueConnector>>,
+    /// FIX sessions for venues that only offer FIX order entry, keyed by venue ID
+    fix_venues: HashMap<String, Arc<FixGateway>>,
+    /// Post-trade fill surveillance that matches fills to sent orders (optional)
+    fill_surveillance: Option<Arc<FillSurveillance>>,
+    /// Cross-reference links from signals to orders, fills and rejection reasons (optional)
+    trace_registry: Option<Arc<TraceRegistry>>,
+    /// Declarative routing rules checked before an order is routed (optional)
+    routing_policy: Option<Arc<RoutingPolicyEngine>>,
+    /// Leader election; orders are refused unless this instance leads (optional)
+    failover: Option<Arc<FailoverCoordinator>>,
 }
 
 impl SmartOrderRouter {
     /// Create a new instance with default settings
     pub fn new() -> Self {
         Self {
-            trust_scores: Arc::new(RwLock::new(HashMap::new())),
             retry_engine: Arc::new(OrderRetryEngine::new(3, 1000, 30000)),
-            recent_executions: Arc::new(Mutex::new(HashMap::new())),
+            recent_executions: Arc::new(Mutex::new(ExecutionCache::new(EXECUTION_CACHE_CAPACITY))),
+            resting_orders: Arc::new(Mutex::new(HashMap::new())),
+            freshness_guard: None,
+            order_books: None,
+            position_manager: None,
+            expiry_scheduler: None,
+            batch_config: BatchConfig::default(),
+            event_bus: None,
+            venue_control: None,
+            dex_venues: HashMap::new(),
+            venue_connectors: HashMap::new(),
+            fix_venues: HashMap::new(),
+            fill_surveillance: None,
+            trace_registry: None,
+            routing_policy: None,
+            failover: None,
         }
     }
 
-    /// Create a new instance with custom retry engine
+    /// Create a new instance with custom retry engine; `trust_scores` seed
+    /// venues the engine's trust service has no history for
     pub fn with_retry_engine(
         retry_engine: Arc<OrderRetryEngine>,
         trust_scores: HashMap<String, f64>,
     ) -> Self {
+        retry_engine.venue_trust().seed(trust_scores);
         Self {
-            trust_scores: Arc::new(RwLock::new(trust_scores)),
             retry_engine,
-            recent_executions: Arc::new(Mutex::new(HashMap::new())),
+            recent_executions: Arc::new(Mutex::new(ExecutionCache::new(EXECUTION_CACHE_CAPACITY))),
+            resting_orders: Arc::new(Mutex::new(HashMap::new())),
+            freshness_guard: None,
+            order_books: None,
+            position_manager: None,
+            expiry_scheduler: None,
+            batch_config: BatchConfig::default(),
+            event_bus: None,
+            venue_control: None,
+            dex_venues: HashMap::new(),
+            venue_connectors: HashMap::new(),
+            fix_venues: HashMap::new(),
+            fill_surveillance: None,
+            trace_registry: None,
+            routing_policy: None,
+            failover: None,
         }
     }
 
+    /// Share a venue trust service with the retry engine
+    pub fn with_venue_trust(mut self, venue_trust: Arc<VenueTrustService>) -> Self {
+        let engine = &self.retry_engine;
+        self.retry_engine = Arc::new(
+            OrderRetryEngine::new(engine.max_retries, engine.base_delay_ms, engine.max_delay_ms)
+                .with_venue_trust(venue_trust),
+        );
+        self
+    }
+
+    /// Venue trust scores used for ranking and retries
+    pub fn venue_trust(&self) -> &Arc<VenueTrustService> {
+        self.retry_engine.venue_trust()
+    }
+
+    /// Attach a quote freshness guard that is checked before routing
+    pub fn with_freshness_guard(mut self, guard: Arc<QuoteFreshnessGuard>) -> Self {
+        self.freshness_guard = Some(guard);
+        self
+    }
+
+    /// Attach order books used to reject post-only orders that would cross
+    pub fn with_order_books(mut self, order_books: Arc<OrderBookManager>) -> Self {
+        self.order_books = Some(order_books);
+        self
+    }
+
+    /// Attach a position manager used to enforce reduce-only orders
+    pub fn with_position_manager(mut self, position_manager: Arc<PositionManager>) -> Self {
+        self.position_manager = Some(position_manager);
+        self
+    }
+
+    /// Attach a local expiry scheduler for GTT/GTD/session orders
+    pub fn with_expiry_scheduler(mut self, scheduler: Arc<OrderExpiryScheduler>) -> Self {
+        self.expiry_scheduler = Some(scheduler);
+        self
+    }
+
+    /// Route orders for the connector's venue through on-chain swaps
+    pub fn with_dex_venue(mut self, connector: Arc<DexVenueConnector>) -> Self {
+        self.dex_venues.insert(connector.venue_id().to_string(), connector);
+        self
+    }
+
+    /// Route orders for the connector's venue to the live exchange
+    pub fn with_venue_connector(mut self, connector: Arc<dyn VenueConnector>) -> Self {
+        self.venue_connectors.insert(connector.venue_id().to_string(), connector);
+        self
+    }
+
+    /// Route orders for the gateway's venue over its FIX session
+    pub fn with_fix_venue(mut self, gateway: Arc<FixGateway>) -> Self {
+        self.fix_venues.insert(gateway.venue_id().to_string(), gateway);
+        self
+    }
+
+    /// Register every executed order with fill surveillance
+    pub fn with_fill_surveillance(mut self, surveillance: Arc<FillSurveillance>) -> Self {
+        self.fill_surveillance = Some(surveillance);
+        self
+    }
+
+    /// Record signal → order → fill links for routed orders
+    pub fn with_trace_registry(mut self, trace_registry: Arc<TraceRegistry>) -> Self {
+        self.trace_registry = Some(trace_registry);
+        self
+    }
+
+    /// Check orders against routing policy rules before routing
+    pub fn with_routing_policy(mut self, routing_policy: Arc<RoutingPolicyEngine>) -> Self {
+        self.routing_policy = Some(routing_policy);
+        self
+    }
+
+    /// Refuse new orders unless this instance holds the leader lease
+    pub fn with_failover(mut self, failover: Arc<FailoverCoordinator>) -> Self {
+        self.failover = Some(failover);
+        self
+    }
+
+    /// Set the bulk operation configuration
+    pub fn with_batch_config(mut self, batch_config: BatchConfig) -> Self {
+        self.batch_config = batch_config;
+        self
+    }
+
+    /// Submit many orders, pipelining them to their venues
+    ///
+    /// None of the connectors expose a batch order endpoint, so every order
+    /// is routed individually with up to `pipeline_concurrency` in flight.
+    pub async fn submit_batch(&self, orders: Vec<Order>) -> Result<BatchResult, OrderRouterError> {
+        self.check_batch_size(orders.len())?;
+        
+        // Validate everything up front so a bad order doesn't leave the batch half-sent
+        let mut validated = Vec::with_capacity(orders.len());
+        let mut items: Vec<Option<BatchItemResult>> = vec![None; orders.len()];
+        for (idx, order) in orders.into_iter().enumerate() {
+            let order_id = order.id.clone();
+            match self.enforce_order_flags(order).and_then(|order| {
+                self.validate_time_in_force(&order)?;
+                Ok(order)
+            }) {
+                Ok(order) => validated.push((idx, order)),
+                Err(e) if self.batch_config.validate_all_first => {
+                    return Err(OrderRouterError::BatchRejected(format!("order {}: {}", order_id, e)));
+                }
+                Err(e) => items[idx] = Some(BatchItemResult::from_result(order_id, None, Err(e))),
+            }
+        }
+        
+        let results: Vec<(usize, BatchItemResult)> = stream::iter(validated)
+            .map(|(idx, order)| async move {
+                let order_id = order.id.clone();
+                let result = self.execute_order(order).await;
+                let venue = result.as_ref().ok().and_then(|r| {
+                    r.additional_data.get("venue").and_then(|v| v.as_str()).map(String::from)
+                });
+                (idx, BatchItemResult::from_result(order_id, venue, result.map(Some)))
+            })
+            .buffered(self.batch_config.pipeline_concurrency.max(1))
+            .collect()
+            .await;
+        for (idx, item) in results {
+            items[idx] = Some(item);
+        }
+        
+        let batch = BatchResult::new(items.into_iter().flatten().collect());
+        info!("Batch {} submitted: {} ok, {} failed", batch.batch_id, batch.succeeded, batch.failed);
+        Ok(batch)
+    }
+
+    /// Cancel many resting orders
+    pub async fn cancel_batch(&self, order_ids: Vec<String>) -> Result<BatchResult, OrderRouterError> {
+        self.check_batch_size(order_ids.len())?;
+        
+        let mut items = Vec::with_capacity(order_ids.len());
+        for order_id in order_ids {
+            let venue = self.recent_executions.lock().await.get(&order_id).map(|r| r.venue.clone());
+            let result = self.cancel_order(&order_id).await.map(|_| None);
+            items.push(BatchItemResult::from_result(order_id, venue, result));
+        }
+        
+        Ok(BatchResult::new(items))
+    }
+
+    /// Cancel and replace many resting orders
+    ///
+    /// The original is cancelled before its replacement is sent so the two
+    /// can never both fill. Replacements are validated first, so one that
+    /// could never be sent leaves its original resting; a replacement that
+    /// fails once sent is reported as `ReplacementFailed` against the
+    /// original's venue, since the original is already gone. When the venue
+    /// only acknowledges the cancel the original can still fill, so the item
+    /// fails with `CancelPending` and no replacement is sent.
+    pub async fn replace_batch(&self, requests: Vec<ReplaceRequest>) -> Result<BatchResult, OrderRouterError> {
+        self.check_batch_size(requests.len())?;
+        
+        let items: Vec<BatchItemResult> = stream::iter(requests)
+            .map(|request| async move {
+                let ReplaceRequest { order_id, replacement } = request;
+                if let Err(e) = self.enforce_order_flags(replacement.clone())
+                    .and_then(|order| self.validate_time_in_force(&order))
+                {
+                    return BatchItemResult::from_result(order_id, None, Err(e));
+                }
+                
+                let venue = match self.request_cancel(&order_id).await {
+                    Ok((venue, true)) => venue,
+                    Ok((venue, false)) => {
+                        let pending = OrderRouterError::CancelPending(format!("order {} on {}", order_id, venue));
+                        return BatchItemResult::from_result(order_id, Some(venue), Err(pending));
+                    }
+                    Err(e) => return BatchItemResult::from_result(order_id, None, Err(e)),
+                };
+                let replacement_id = replacement.id.clone();
+                let result = self.execute_order(replacement).await.map(Some).map_err(|e| {
+                    warn!("Order {} was cancelled on {} but replacement {} failed: {}", order_id, venue, replacement_id, e);
+                    OrderRouterError::ReplacementFailed(format!("{} replacing {}: {}", replacement_id, order_id, e))
+                });
+                BatchItemResult::from_result(order_id, Some(venue), result)
+            })
+            .buffered(self.batch_config.pipeline_concurrency.max(1))
+            .collect()
+            .await;
+        
+        Ok(BatchResult::new(items))
+    }
+
+    /// Execute a multi-leg order as one spread
+    ///
+    /// The first leg sets how many spread units are attempted on the rest.
+    /// If the legs end up unbalanced the order's legging fallback is applied:
+    /// short legs are chased with immediate orders, and any excess still left
+    /// is traded back out unless the fallback accepts the imbalance.
+    pub async fn execute_multi_leg(&self, order: MultiLegOrder) -> Result<MultiLegResult, OrderRouterError> {
+        order.validate().map_err(OrderRouterError::InvalidOrderParameters)?;
+        
+        let mut fills: Vec<LegFill> = order.legs.iter().map(|leg| LegFill::new(&leg.symbol)).collect();
+        let mut target_units = order.quantity;
+        for (index, leg) in order.legs.iter().enumerate() {
+            let leg_order = order.leg_order(index, target_units * leg.ratio);
+            fills[index].requested += leg_order.amount;
+            fills[index].order_ids.push(leg_order.id.clone());
+            if let Some((quantity, price)) = self.execute_leg(leg_order).await {
+                fills[index].add_fill(quantity, price);
+            }
+            
+            if index == 0 {
+                target_units = fills[0].filled / leg.ratio;
+                if target_units <= 0.0 {
+                    return Ok(MultiLegResult::new(&order, fills, None));
+                }
+            }
+        }
+        
+        let imbalances = order.imbalances(&fills, target_units);
+        if imbalances.iter().all(|diff| *diff == 0.0) {
+            return Ok(MultiLegResult::new(&order, fills, None));
+        }
+        warn!("Multi-leg order {} is legged: {:?}", order.id, imbalances);
+        
+        let max_slippage_pct = match order.fallback {
+            LeggingFallback::Accept => return Ok(MultiLegResult::new(&order, fills, Some(order.fallback))),
+            LeggingFallback::Unwind => None,
+            LeggingFallback::Complete { max_slippage_pct } => Some(max_slippage_pct),
+        };
+        
+        // Chase the short legs up to the first leg's fill
+        if let Some(max_slippage_pct) = max_slippage_pct {
+            for (index, diff) in imbalances.into_iter().enumerate() {
+                if diff >= 0.0 {
+                    continue;
+                }
+                let mut chase = order.leg_order(index, -diff);
+                chase.id = format!("{}-complete", chase.id);
+                chase.max_slippage = Some(max_slippage_pct);
+                chase.price = slipped_price(chase.price, chase.side, max_slippage_pct);
+                fills[index].requested += chase.amount;
+                fills[index].order_ids.push(chase.id.clone());
+                if let Some((quantity, price)) = self.execute_leg(chase).await {
+                    fills[index].add_fill(quantity, price);
+                }
+            }
+        }
+        
+        // Trade back out whatever is still in excess of the balanced quantity
+        let completed_units = order.completed_units(&fills);
+        for (index, excess) in order.imbalances(&fills, completed_units).into_iter().enumerate() {
+            if excess <= 0.0 {
+                continue;
+            }
+            let mut unwind = order.leg_order(index, excess);
+            unwind.id = format!("{}-unwind", unwind.id);
+            unwind.side = match unwind.side {
+                OrderSide::Buy => OrderSide::Sell,
+                OrderSide::Sell => OrderSide::Buy,
+            };
+            unwind.max_slippage = max_slippage_pct;
+            unwind.price = slipped_price(fills[index].average_price.unwrap_or(unwind.price), unwind.side, max_slippage_pct.unwrap_or(0.0));
+            fills[index].order_ids.push(unwind.id.clone());
+            if let Some((quantity, _)) = self.execute_leg(unwind).await {
+                fills[index].remove_fill(quantity);
+            }
+        }
+        
+        Ok(MultiLegResult::new(&order, fills, Some(order.fallback)))
+    }
+    
+    /// Execute one leg order, returning the filled quantity and price
+    async fn execute_leg(&self, order: Order) -> Option<(f64, f64)> {
+        let order_id = order.id.clone();
+        let limit = order.price;
+        match self.execute_order(order).await {
+            Ok(result) => Some((
+                result.executed_quantity.unwrap_or(0.0),
+                result.average_price.unwrap_or(limit),
+            )),
+            Err(e) => {
+                warn!("Leg order {} failed: {}", order_id, e);
+                None
+            }
+        }
+    }
+    
+    /// Cancel a resting order, returning the venue it was resting on
+    ///
+    /// When the venue only acknowledges the request, the order keeps resting
+    /// until [`update_order_status`](Self::update_order_status) reports it
+    /// terminal.
+    pub async fn cancel_order(&self, order_id: &str) -> Result<String, OrderRouterError> {
+        self.request_cancel(order_id).await.map(|(venue, _)| venue)
+    }
+    
+    /// Cancel a resting order, returning its venue and whether the venue
+    /// confirmed the cancel rather than only acknowledging it
+    async fn request_cancel(&self, order_id: &str) -> Result<(String, bool), OrderRouterError> {
+        let result = self.recent_executions
+            .lock()
+            .await
+            .remove(order_id)
+            .ok_or_else(|| OrderRouterError::OrderNotFound(order_id.to_string()))?;
+        let venue = result.venue;
+        
+        let resting = self.resting_orders.lock().await.remove(order_id);
+        
+        let detail = |key: &str| result.details
+            .as_ref()
+            .and_then(|details| details.get(key))
+            .and_then(|id| id.as_str());
+        let venue_cancel = match (&resting, detail("venue_order_id"), detail("client_order_id")) {
+            (Some(resting), Some(venue_order_id), _) if self.venue_connectors.contains_key(&venue) => {
+                match self.venue_connectors[&venue].cancel_order(&resting.symbol, venue_order_id).await {
+                    Ok(ack) => Ok(!ack.status.is_open()),
+                    // The venue no longer has the order open; it filled or was already cancelled
+                    Err(e @ ConnectorError::Rejected { .. }) => Err(CancelFailure::Refused(e.to_string())),
+                    Err(e) => Err(CancelFailure::Failed(e.to_string())),
+                }
+            }
+            (Some(_), _, Some(client_order_id)) if self.fix_venues.contains_key(&venue) => {
+                match self.fix_venues[&venue].cancel(client_order_id).await {
+                    Ok(report) => Ok(!report.status.is_open()),
+                    Err(e @ (FixError::CancelRejected { .. } | FixError::UnknownOrder(_))) => {
+                        Err(CancelFailure::Refused(e.to_string()))
+                    }
+                    Err(e) => Err(CancelFailure::Failed(e.to_string())),
+                }
+            }
+            // Resting on a live venue, but without the identifier needed to pull it
+            (Some(_), _, _) if self.venue_connectors.contains_key(&venue) || self.fix_venues.contains_key(&venue) => {
+                Err(CancelFailure::Failed(format!("{} has no venue order id to cancel", venue)))
+            }
+            // Simulated venues hold nothing that needs pulling
+            _ => Ok(true),
+        };
+        match venue_cancel {
+            Ok(true) => {}
+            Ok(false) => {
+                // Accepted but not yet done; the order can still fill until the venue confirms,
+                // and the cancel can still be retried
+                debug!("Cancel of order {} pending on {}", order_id, venue);
+                if let Some(resting) = resting {
+                    self.resting_orders.lock().await.insert(order_id.to_string(), resting);
+                }
+                self.recent_executions.lock().await.insert(order_id.to_string(), VenueExecutionResult { venue: venue.clone(), ..result });
+                return Ok((venue, false));
+            }
+            Err(CancelFailure::Refused(e)) => {
+                // The order may have filled, so callers must not treat it as gone and replace it.
+                // Keep the execution so its fills can still be reconciled.
+                warn!("{} refused cancel of order {}: {}", venue, order_id, e);
+                let message = format!("order {} on {}: {}", order_id, venue, e);
+                self.recent_executions.lock().await.insert(order_id.to_string(), VenueExecutionResult { venue, ..result });
+                return Err(OrderRouterError::CancelRejected(message));
+            }
+            Err(CancelFailure::Failed(e)) => {
+                // Still resting on the venue; keep tracking it so the cancel can be retried
+                warn!("Failed to cancel order {} on {}: {}", order_id, venue, e);
+                if let Some(resting) = &resting {
+                    self.resting_orders.lock().await.insert(order_id.to_string(), resting.clone());
+                }
+                self.recent_executions.lock().await.insert(order_id.to_string(), VenueExecutionResult { venue, ..result });
+                return Err(OrderRouterError::VenueError(e));
+            }
+        }
+        debug!("Cancelled order {} on {}", order_id, venue);
+        
+        if let Some(scheduler) = &self.expiry_scheduler {
+            scheduler.reconcile(order_id, ExecutionStatus::Cancelled).await;
+        }
+        
+        Ok((venue, true))
+    }
+
+    /// List orders resting on venues, optionally filtered by symbol
+    pub async fn list_orders(&self, symbol: Option<&str>) -> Vec<RestingOrder> {
+        let mut orders: Vec<RestingOrder> = self.resting_orders
+            .lock()
+            .await
+            .values()
+            .filter(|order| symbol.map_or(true, |s| order.symbol == s))
+            .cloned()
+            .collect();
+        orders.sort_by_key(|order| order.submitted_at);
+        orders
+    }
+
+    /// Order resting orders for a mass cancel, most urgent first
+    ///
+    /// Cancels go out one at a time, so when a venue starts rate limiting or
+    /// the kill switch is racing fills, what was sent first matters most. An
+    /// order's urgency is the exposure it would add if it filled, given the
+    /// net position in its symbol, discounted by how far it rests from the
+    /// touch. Orders that only reduce a position come last, and among equals
+    /// the order nearest the market goes first.
+    pub async fn prioritize_cancels(&self, orders: Vec<RestingOrder>) -> Vec<CancelPriority> {
+        let mut net_sizes: HashMap<String, f64> = HashMap::new();
+        for position in self.open_positions(None).unwrap_or_default() {
+            *net_sizes.entry(position.symbol).or_default() += position.net_size;
+        }
+        let scale = self.batch_config.cancel_distance_scale_bps.max(f64::EPSILON);
+        
+        let mut priorities: Vec<(CancelPriority, DateTime<Utc>)> = orders
+            .into_iter()
+            .map(|order| {
+                let net = net_sizes.get(&order.symbol).copied().unwrap_or(0.0);
+                let signed = match order.side {
+                    OrderSide::Buy => order.amount,
+                    OrderSide::Sell => -order.amount,
+                };
+                let exposure_reduction = ((net + signed).abs() - net.abs()).max(0.0) * order.price;
+                let distance_bps = self.distance_from_touch(&order);
+                let priority = CancelPriority {
+                    score: exposure_reduction / (1.0 + distance_bps / scale),
+                    order_id: order.order_id,
+                    venue: order.venue,
+                    exposure_reduction,
+                    distance_bps,
+                };
+                (priority, order.submitted_at)
+            })
+            .collect();
+        
+        priorities.sort_by(|(a, a_at), (b, b_at)| {
+            b.score.total_cmp(&a.score)
+                .then_with(|| a.distance_bps.total_cmp(&b.distance_bps))
+                .then_with(|| a_at.cmp(b_at))
+        });
+        priorities.into_iter().map(|(priority, _)| priority).collect()
+    }
+
+    /// How far an order rests behind its same-side touch, in basis points of mid
+    fn distance_from_touch(&self, order: &RestingOrder) -> f64 {
+        let Some((bids, asks)) = self.order_books.as_ref().and_then(|books| books.get_snapshot(&order.symbol, 1)) else {
+            return 0.0;
+        };
+        let (Some(bid), Some(ask)) = (bids.first(), asks.first()) else {
+            return 0.0;
+        };
+        let mid = (bid.price + ask.price) / 2.0;
+        if mid <= 0.0 {
+            return 0.0;
+        }
+        let behind = match order.side {
+            OrderSide::Buy => bid.price - order.price,
+            OrderSide::Sell => order.price - ask.price,
+        };
+        behind.max(0.0) / mid * 10_000.0
+    }
+
+    /// Cancel every resting order, optionally only those for one symbol
+    pub async fn cancel_all(&self, symbol: Option<&str>) -> BatchResult {
+        let orders = self.list_orders(symbol).await;
+        let batch = self.cancel_ids(orders).await;
+        info!("Cancelled {} resting orders ({} failed)", batch.succeeded, batch.failed);
+        batch
+    }
+
+    /// Cancel every resting order placed by one strategy
+    pub async fn cancel_strategy_orders(&self, strategy_id: &str) -> BatchResult {
+        let orders: Vec<RestingOrder> = self.list_orders(None)
+            .await
+            .into_iter()
+            .filter(|order| order.strategy_id.as_deref() == Some(strategy_id))
+            .collect();
+        
+        let batch = self.cancel_ids(orders).await;
+        info!(
+            "Cancelled {} resting orders for strategy {} ({} failed)",
+            batch.succeeded, strategy_id, batch.failed
+        );
+        batch
+    }
+
+    async fn cancel_ids(&self, orders: Vec<RestingOrder>) -> BatchResult {
+        let priorities = self.prioritize_cancels(orders).await;
+        let mut items = Vec::with_capacity(priorities.len());
+        for CancelPriority { order_id, .. } in priorities {
+            let result = self.cancel_order(&order_id).await;
+            let venue = result.as_ref().ok().cloned();
+            items.push(BatchItemResult::from_result(order_id, venue, result.map(|_| None)));
+        }
+        
+        BatchResult::new(items)
+    }
+
+    /// Non-flat positions across all agents, optionally filtered by symbol
+    pub fn open_positions(&self, symbol: Option<&str>) -> Result<Vec<OpenPosition>, OrderRouterError> {
+        let position_manager = self.position_manager.as_ref()
+            .ok_or_else(|| OrderRouterError::NotConfigured("position manager".to_string()))?;
+        let agent_ids = position_manager.get_agent_ids()
+            .map_err(|e| OrderRouterError::VenueError(e.to_string()))?;
+        
+        let mut positions = Vec::new();
+        for agent_id in agent_ids {
+            let agent = match position_manager.get_position(&agent_id) {
+                Ok(agent) => agent,
+                Err(_) => continue,
+            };
+            for position in agent.positions.values() {
+                if position.net_size.abs() < f64::EPSILON || symbol.map_or(false, |s| position.symbol != s) {
+                    continue;
+                }
+                positions.push(OpenPosition {
+                    agent_id: agent_id.clone(),
+                    symbol: position.symbol.clone(),
+                    net_size: position.net_size,
+                    average_price: position.average_price,
+                    unrealized_pnl: position.unrealized_pnl,
+                });
+            }
+        }
+        
+        positions.sort_by(|a, b| a.agent_id.cmp(&b.agent_id).then_with(|| a.symbol.cmp(&b.symbol)));
+        Ok(positions)
+    }
+
+    /// Close open positions with reduce-only IOC orders
+    ///
+    /// Orders are priced from the order book when available, otherwise at the
+    /// position's average price. When `venues` is empty every venue with a
+    /// trust score is eligible.
+    pub async fn flatten_positions(&self, symbol: Option<&str>, venues: Vec<String>) -> Result<BatchResult, OrderRouterError> {
+        let positions = self.open_positions(symbol)?;
+        let venues = if venues.is_empty() {
+            self.venue_trust().scores().into_keys().collect()
+        } else {
+            venues
+        };
+        
+        let orders: Vec<Order> = positions
+            .into_iter()
+            .map(|position| {
+                let side = if position.net_size > 0.0 { OrderSide::Sell } else { OrderSide::Buy };
+                let price = self.order_books
+                    .as_ref()
+                    .and_then(|books| books.get_snapshot(&position.symbol, 1))
+                    .and_then(|(bids, asks)| match side {
+                        OrderSide::Sell => bids.first().map(|level| level.price),
+                        OrderSide::Buy => asks.first().map(|level| level.price),
+                    })
+                    .unwrap_or(position.average_price);
+                
+                Order {
+                    symbol: position.symbol,
+                    side,
+                    amount: position.net_size.abs(),
+                    price,
+                    venues: venues.clone(),
+                    id: new_id(IdKind::Order),
+                    max_slippage: None,
+                    max_retries: None,
+                    post_only: false,
+                    reduce_only: true,
+                    time_in_force: TimeInForce::IOC,
+                    additional_params: HashMap::from([
+                        ("agentId".to_string(), serde_json::Value::String(position.agent_id)),
+                    ]),
+                }
+            })
+            .collect();
+        
+        if orders.is_empty() {
+            return Ok(BatchResult::new(Vec::new()));
+        }
+        
+        warn!("Flattening {} positions", orders.len());
+        self.submit_batch(orders).await
+    }
+
+    /// Consult operator venue modes when ranking venues
+    pub fn with_venue_control(mut self, venue_control: Arc<VenueControl>) -> Self {
+        self.venue_control = Some(venue_control);
+        self
+    }
+
+    /// Operator venue modes, if configured
+    pub fn venue_control(&self) -> Option<&Arc<VenueControl>> {
+        self.venue_control.as_ref()
+    }
+
+    /// Stop routing to a venue, cancel its resting orders and wait for the rest
+    /// to fill or be cancelled
+    ///
+    /// An order stops counting once the venue reports it terminal or the
+    /// expiry scheduler has reconciled it. The venue is disabled once no
+    /// orders remain; if `timeout` elapses first it is left draining and the
+    /// remaining orders are reported.
+    pub async fn drain_venue(
+        &self,
+        venue: &str,
+        operator: &str,
+        reason: Option<String>,
+        timeout: std::time::Duration,
+    ) -> Result<DrainReport, OrderRouterError> {
+        let venue_control = self.venue_control.as_ref()
+            .ok_or_else(|| OrderRouterError::NotConfigured("venue control".to_string()))?;
+        venue_control.set_mode(venue, VenueMode::Draining, operator, reason.clone()).await;
+        
+        let mut cancelled = Vec::new();
+        for order in self.prioritize_cancels(self.resting_orders_on(venue).await).await {
+            match self.cancel_order(&order.order_id).await {
+                Ok(_) => cancelled.push(order.order_id),
+                Err(e) => warn!("Failed to cancel {} while draining {}: {}", order.order_id, venue, e),
+            }
+        }
+        
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut remaining = self.unsettled_orders_on(venue).await;
+        while !remaining.is_empty() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(std::time::Duration::from_millis(250).min(timeout)).await;
+            remaining = self.unsettled_orders_on(venue).await;
+        }
+        
+        let completed = remaining.is_empty();
+        if completed {
+            venue_control.set_mode(venue, VenueMode::Disabled, operator, reason).await;
+        } else {
+            warn!("Drain of {} timed out with {} orders resting", venue, remaining.len());
+        }
+        
+        Ok(DrainReport {
+            venue: venue.to_string(),
+            cancelled,
+            remaining: remaining.into_iter().map(|order| order.order_id).collect(),
+            completed,
+        })
+    }
+
+    /// Resting orders on a venue, dropping any the expiry scheduler has
+    /// already seen expire, fill or cancel
+    async fn unsettled_orders_on(&self, venue: &str) -> Vec<RestingOrder> {
+        let mut remaining = Vec::new();
+        for order in self.resting_orders_on(venue).await {
+            let settled = match &self.expiry_scheduler {
+                Some(scheduler) => scheduler.get(&order.order_id).await.is_some_and(|expiry| {
+                    matches!(expiry.state, ExpiryState::Expired | ExpiryState::Filled | ExpiryState::Cancelled)
+                }),
+                None => false,
+            };
+            if settled {
+                self.resting_orders.lock().await.remove(&order.order_id);
+            } else {
+                remaining.push(order);
+            }
+        }
+        remaining
+    }
+
+    /// Notional value of the orders resting on a venue
+    pub async fn open_order_notional(&self, venue: &str) -> f64 {
+        self.unsettled_orders_on(venue).await.iter().map(|order| order.amount * order.price).sum()
+    }
+    
+    /// Resting orders on a single venue
+    async fn resting_orders_on(&self, venue: &str) -> Vec<RestingOrder> {
+        self.resting_orders
+            .lock()
+            .await
+            .values()
+            .filter(|order| order.venue == venue)
+            .cloned()
+            .collect()
+    }
+
+    /// Publish fills and order state changes to an event bus
+    pub fn with_event_bus(mut self, event_bus: Arc<TradingEventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
     /// Execute an order across venues
     pub async fn execute_order(&self, order: Order) -> Result<ExecutionResult, OrderRouterError> {
+        // Registered before routing so even instant fills have an order to match
+        if let Some(surveillance) = &self.fill_surveillance {
+            surveillance.register_order(&order).await;
+        }
+        
+        let order_id = order.id.clone();
+        let symbol = order.symbol.clone();
+        let signal_id = order.additional_params
+            .get("signalId")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        if let Some(registry) = &self.trace_registry {
+            trace_order(registry, &order, signal_id.as_deref()).await;
+        }
+        
+        let mut result = self.route_order(order).instrument(correlation_span(&order_id)).await;
+        if let (Ok(execution), Some(signal_id)) = (&mut result, &signal_id) {
+            if execution.signal_id.is_empty() {
+                execution.signal_id = signal_id.clone();
+            }
+        }
+        if let Some(registry) = &self.trace_registry {
+            trace_result(registry, &order_id, &result).await;
+        }
+        
+        let Some(event_bus) = self.event_bus.clone() else {
+            return result;
+        };
+        
+        match &result {
+            Ok(execution) => {
+                let venue = execution.additional_data
+                    .get("venue")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+                // Orders resting untouched on the book have nothing to report as a fill
+                let filled = execution.executed_quantity.filter(|quantity| *quantity > 0.0);
+                if let (Some(quantity), Some(price)) = (filled, execution.average_price) {
+                    event_bus.publish(TradingEvent::Fill {
+                        order_id: order_id.clone(),
+                        symbol: symbol.clone(),
+                        venue: venue.clone().unwrap_or_default(),
+                        quantity,
+                        price,
+                        timestamp: execution.timestamp,
+                    });
+                }
+                event_bus.publish(TradingEvent::OrderState {
+                    order_id,
+                    symbol,
+                    status: execution.status,
+                    venue,
+                    reason: None,
+                    timestamp: Utc::now(),
+                });
+            }
+            Err(e) => {
+                event_bus.publish(TradingEvent::OrderState {
+                    order_id,
+                    symbol,
+                    status: ExecutionStatus::Rejected,
+                    venue: None,
+                    reason: Some(e.to_string()),
+                    timestamp: Utc::now(),
+                });
+            }
+        }
+        
+        result
+    }
+
+    /// Route an order across venues in trust score order
+    async fn route_order(&self, order: Order) -> Result<ExecutionResult, OrderRouterError> {
+        // Only the leader sends orders; a standby would double up on every venue
+        if let Some(failover) = &self.failover {
+            if !failover.is_leader() {
+                return Err(OrderRouterError::Standby(failover.instance_id().to_string()));
+            }
+        }
+        
+        // Enforce post-only and reduce-only flags before routing
+        let order = self.enforce_order_flags(order)?;
+        self.validate_time_in_force(&order)?;
+        
+        // Drop venues the routing policy forbids; the algorithm is chosen upstream
+        let order = match &self.routing_policy {
+            Some(policy) => policy.enforce(order, None).await
+                .map_err(|e| OrderRouterError::PolicyViolation(e.to_string()))?.0,
+            None => order,
+        };
+        
         // Sort venues by trust score
         let ranked_venues = self.get_ranked_venues(&order.venues).await;
         
@@ -152,23 +1386,27 @@ impl SmartOrderRouter {
             return Err(OrderRouterError::NoAvailableVenues(order.symbol));
         }
         
+        // Drop or re-price routes to venues with stale quotes
+        let (order, ranked_venues) = self.apply_freshness_guard(order, ranked_venues)?;
+        
         debug!("Attempting to execute order for {} across {} venues", order.symbol, ranked_venues.len());
         
         // Try each venue in order of trust score
         for venue in &ranked_venues {
             match self.execute_on_venue(&order, venue).await {
                 Ok(result) if result.success => {
-                    // Improve trust score on success
-                    self.improve_trust_score(venue, 0.01).await;
+                    // Improve trust score on success, less so for poor fills
+                    let slippage_bps = Self::fill_slippage_bps(&order, &result);
+                    self.venue_trust().record(venue, VenueOutcome::Filled { slippage_bps });
                     
                     let mut execution_result = ExecutionResult {
-                        id: Uuid::new_v4().to_string(),
+                        id: new_id(IdKind::Fill),
                         request_id: order.id.clone(),
                         signal_id: "".to_string(),  // To be filled by caller
-                        status: ExecutionStatus::Completed,
+                        status: Self::venue_status(&order, &result),
                         order_id: Some(format!("venue-{}-{}", venue, Uuid::new_v4())),
-                        executed_quantity: Some(order.amount),
-                        average_price: Some(order.price),
+                        executed_quantity: Some(Self::filled_amount(&order, &result)),
+                        average_price: Some(Self::fill_price(&order, &result)),
                         fee_info: None,
                         fees: None,
                         fee_currency: None,
@@ -180,7 +1418,7 @@ impl SmartOrderRouter {
                         realized_pnl: 0.0,
                         additional_data: HashMap::new(),
                         rejection_details: None,
-                        trust_score: Some(self.get_venue_trust_score(venue).await),
+                        trust_score: Some(self.venue_trust().score(venue)),
                     };
                     
                     // Store in cache
@@ -191,9 +1429,20 @@ impl SmartOrderRouter {
                         "venue".to_string(), 
                         serde_json::Value::String(venue.clone())
                     );
+                    Self::tag_order_flags(&order, &mut execution_result);
+                    self.schedule_expiry(&order, &result).await;
                     
                     return Ok(execution_result);
                 }
+                Ok(result) if order.time_in_force.is_immediate() => {
+                    // IOC/FOK orders are never re-submitted once anything has filled
+                    if Self::filled_amount(&order, &result) > 0.0 {
+                        warn!("{} order {} partly filled on {} before failing", order.time_in_force.name(), order.id, venue);
+                        break;
+                    }
+                    debug!("{} order {} not filled on {}", order.time_in_force.name(), order.id, venue);
+                    continue;
+                }
                 Ok(result) => {
                     // Handle failure with retry
                     let retry_context = RetryContext {
@@ -214,17 +1463,18 @@ impl SmartOrderRouter {
                         match self.execute_on_venue(&order, &retry_venue).await {
                             Ok(retry_result) if retry_result.success => {
                                 // Improve trust score on success
-                                self.improve_trust_score(&retry_venue, 0.005).await;
+                                let slippage_bps = Self::fill_slippage_bps(&order, &retry_result);
+                                self.venue_trust().record(&retry_venue, VenueOutcome::Filled { slippage_bps });
                                 
                                 // Convert to ExecutionResult
                                 let mut execution_result = ExecutionResult {
-                                    id: Uuid::new_v4().to_string(),
+                                    id: new_id(IdKind::Fill),
                                     request_id: order.id.clone(),
                                     signal_id: "".to_string(),  // To be filled by caller
-                                    status: ExecutionStatus::Completed,
+                                    status: Self::venue_status(&order, &retry_result),
                                     order_id: Some(format!("venue-{}-{}", retry_venue, Uuid::new_v4())),
-                                    executed_quantity: Some(order.amount),
-                                    average_price: Some(order.price),
+                                    executed_quantity: Some(Self::filled_amount(&order, &retry_result)),
+                                    average_price: Some(Self::fill_price(&order, &retry_result)),
                                     fee_info: None,
                                     fees: None,
                                     fee_currency: None,
@@ -236,9 +1486,11 @@ impl SmartOrderRouter {
                                     realized_pnl: 0.0,
                                     additional_data: HashMap::new(),
                                     rejection_details: None,
-                                    trust_score: Some(self.get_venue_trust_score(&retry_venue).await),
+                                    trust_score: Some(self.venue_trust().score(&retry_venue)),
                                 };
                                 
+                                self.cache_execution_result(&order, retry_result.clone()).await;
+                                
                                 execution_result.additional_data.insert(
                                     "venue".to_string(), 
                                     serde_json::Value::String(retry_venue)
@@ -247,17 +1499,29 @@ impl SmartOrderRouter {
                                     "retry_attempt".to_string(), 
                                     serde_json::Value::Number(serde_json::Number::from(1))
                                 );
+                                Self::tag_order_flags(&order, &mut execution_result);
+                                self.schedule_expiry(&order, &retry_result).await;
                                 
                                 return Ok(execution_result);
                             }
-                            _ => continue, // Try next venue
+                            // Record the outcome and try the next venue
+                            Ok(_) => {
+                                self.venue_trust().record(&retry_venue, VenueOutcome::Rejected);
+                            }
+                            Err(err) => {
+                                self.venue_trust().record(&retry_venue, Self::error_outcome(&err));
+                            }
                         }
                     }
                 }
                 Err(err) => {
                     warn!("Error executing on venue {}: {:?}", venue, err);
+                    // Permanent failures would fail on every venue and are not the venue's fault
+                    if !err.is_retryable() {
+                        return Err(err);
+                    }
                     // Decay trust score on error
-                    self.decay_trust_score(venue, 0.02).await;
+                    self.venue_trust().record(venue, Self::error_outcome(&err));
                 }
             }
         }
@@ -266,8 +1530,193 @@ impl SmartOrderRouter {
         Err(OrderRouterError::ExecutionFailedAllVenues)
     }
     
+    /// Reject empty or oversized batches
+    fn check_batch_size(&self, len: usize) -> Result<(), OrderRouterError> {
+        if len == 0 {
+            return Err(OrderRouterError::BatchRejected("empty batch".to_string()));
+        }
+        if len > self.batch_config.max_batch_size {
+            return Err(OrderRouterError::BatchRejected(format!(
+                "{} orders exceeds batch limit of {}", len, self.batch_config.max_batch_size
+            )));
+        }
+        Ok(())
+    }
+    
+    /// Validate post-only and reduce-only constraints, clamping reduce-only size
+    ///
+    /// Without a book to check against, post-only orders are only routed to
+    /// venues that enforce the flag natively.
+    fn enforce_order_flags(&self, mut order: Order) -> Result<Order, OrderRouterError> {
+        if order.post_only {
+            let snapshot = self.order_books
+                .as_ref()
+                .and_then(|books| books.get_snapshot(&order.symbol, 1));
+            if let Some((bids, asks)) = snapshot {
+                let crosses = match order.side {
+                    OrderSide::Buy => asks.first().map_or(false, |ask| order.price >= ask.price),
+                    OrderSide::Sell => bids.first().map_or(false, |bid| order.price <= bid.price),
+                };
+                
+                if crosses {
+                    warn!("Post-only order {} at {} would cross the book", order.id, order.price);
+                    return Err(OrderRouterError::PostOnlyWouldCross(order.id));
+                }
+            } else {
+                order.venues.retain(|venue| {
+                    self.venue_connectors.contains_key(venue) || self.fix_venues.contains_key(venue)
+                });
+                if order.venues.is_empty() {
+                    warn!("No book for post-only order {} on {} and no venue enforces it", order.id, order.symbol);
+                    return Err(OrderRouterError::PostOnlyUnverified(order.id));
+                }
+            }
+        }
+        
+        if order.reduce_only {
+            let agent_id = order.additional_params
+                .get("agentId")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| OrderRouterError::InvalidOrderParameters(
+                    "reduce-only order requires agentId".to_string()
+                ))?;
+            
+            let position_manager = self.position_manager.as_ref().ok_or_else(|| {
+                OrderRouterError::ReduceOnlyViolation(format!("no position source for order {}", order.id))
+            })?;
+            
+            let net_size = position_manager
+                .get_symbol_position(agent_id, &order.symbol)
+                .map(|position| position.net_size)
+                .unwrap_or(0.0);
+            
+            let reducible = match order.side {
+                OrderSide::Buy if net_size < 0.0 => -net_size,
+                OrderSide::Sell if net_size > 0.0 => net_size,
+                _ => {
+                    return Err(OrderRouterError::ReduceOnlyViolation(format!(
+                        "order {} on {} with net position {}", order.id, order.symbol, net_size
+                    )));
+                }
+            };
+            
+            if order.amount > reducible {
+                debug!("Clamping reduce-only order {} from {} to {}", order.id, order.amount, reducible);
+                order.amount = reducible;
+            }
+        }
+        
+        Ok(order)
+    }
+    
+    /// Tag an execution result with the order's maker/taker intent and flags
+    fn tag_order_flags(order: &Order, execution_result: &mut ExecutionResult) {
+        execution_result.additional_data.insert(
+            "liquidity_intent".to_string(),
+            serde_json::Value::String(order.liquidity_intent().to_string())
+        );
+        execution_result.additional_data.insert(
+            "post_only".to_string(),
+            serde_json::Value::Bool(order.post_only)
+        );
+        execution_result.additional_data.insert(
+            "reduce_only".to_string(),
+            serde_json::Value::Bool(order.reduce_only)
+        );
+        execution_result.additional_data.insert(
+            "time_in_force".to_string(),
+            serde_json::Value::String(order.time_in_force.name().to_string())
+        );
+        execution_result.additional_data.insert(
+            "client_order_id".to_string(),
+            serde_json::Value::String(order_lineage::client_order_id(order))
+        );
+        OrderLineage::of(order).tag(order, execution_result);
+    }
+    
+    /// Reject time-in-force instructions that are already expired or malformed
+    fn validate_time_in_force(&self, order: &Order) -> Result<(), OrderRouterError> {
+        match order.time_in_force {
+            TimeInForce::GTT { duration_ms: 0 } => Err(OrderRouterError::InvalidOrderParameters(
+                "GTT duration must be positive".to_string()
+            )),
+            TimeInForce::GTD { expires_at } if expires_at <= Utc::now() => {
+                Err(OrderRouterError::InvalidOrderParameters(format!(
+                    "GTD expiry {} is in the past", expires_at
+                )))
+            }
+            TimeInForce::FOK if order.post_only => Err(OrderRouterError::InvalidOrderParameters(
+                "FOK orders cannot be post-only".to_string()
+            )),
+            _ => Ok(()),
+        }
+    }
+    
+    /// Hand time-bound orders to the expiry scheduler once they rest on a venue
+    async fn schedule_expiry(&self, order: &Order, result: &VenueExecutionResult) {
+        let resting = matches!(
+            Self::venue_status(order, result),
+            ExecutionStatus::InProgress | ExecutionStatus::PartiallyFilled
+        );
+        if let Some(scheduler) = &self.expiry_scheduler {
+            if order.time_in_force.has_expiry() && resting {
+                let venue_order_id = result.details
+                    .as_ref()
+                    .and_then(|details| details.get("venue_order_id"))
+                    .and_then(|id| id.as_str());
+                scheduler.schedule(order, &result.venue, venue_order_id).await;
+            }
+        }
+    }
+    
+    /// Filter venues with stale market data, re-pricing the order where configured
+    fn apply_freshness_guard(
+        &self,
+        mut order: Order,
+        ranked_venues: Vec<String>,
+    ) -> Result<(Order, Vec<String>), OrderRouterError> {
+        let guard = match &self.freshness_guard {
+            Some(guard) => guard,
+            None => return Ok((order, ranked_venues)),
+        };
+        
+        let mut fresh_venues = Vec::with_capacity(ranked_venues.len());
+        for venue in ranked_venues {
+            match guard.check(&order, &venue) {
+                FreshnessVerdict::Fresh => fresh_venues.push(venue),
+                // Re-pricing moves to the aggressive side of the book, which a
+                // post-only order would cross; skip the venue instead
+                FreshnessVerdict::Repriced { .. } if order.post_only => {
+                    debug!("Skipping stale route to {} for post-only order {}", venue, order.id);
+                }
+                FreshnessVerdict::Repriced { new_price, .. } => {
+                    order.price = new_price;
+                    fresh_venues.push(venue);
+                }
+                FreshnessVerdict::Rejected { .. } => {}
+            }
+        }
+        
+        if fresh_venues.is_empty() {
+            warn!("All routes for order {} rejected as stale", order.id);
+            return Err(OrderRouterError::StaleQuotes(order.symbol));
+        }
+        
+        Ok((order, fresh_venues))
+    }
+    
     /// Execute on a specific venue
     async fn execute_on_venue(&self, order: &Order, venue: &str) -> Result<VenueExecutionResult, OrderRouterError> {
+        if let Some(connector) = self.dex_venues.get(venue) {
+            return Self::execute_on_dex(connector, order).await;
+        }
+        if let Some(connector) = self.venue_connectors.get(venue) {
+            return Self::execute_on_connector(connector.as_ref(), order).await;
+        }
+        if let Some(gateway) = self.fix_venues.get(venue) {
+            return Self::execute_on_fix(gateway, order).await;
+        }
+        
         // Placeholder for actual venue execution logic
         // TODO: Implement real venue execution
         
@@ -283,7 +1732,12 @@ impl SmartOrderRouter {
                     "execution_time": std::time::SystemTime::now()
                         .duration_since(std::time::UNIX_EPOCH)
                         .unwrap()
-                        .as_millis()
+                        .as_millis(),
+                    "post_only": order.post_only,
+                    "reduce_only": order.reduce_only,
+                    "liquidity": order.liquidity_intent(),
+                    "time_in_force": order.time_in_force.name(),
+                    "client_order_id": order_lineage::client_order_id(order),
                 })),
             })
         } else {
@@ -304,15 +1758,93 @@ impl SmartOrderRouter {
         }
     }
     
+    /// Execute a swap on a decentralized venue
+    async fn execute_on_dex(connector: &DexVenueConnector, order: &Order) -> Result<VenueExecutionResult, OrderRouterError> {
+        let venue = connector.venue_id().to_string();
+        let failure = |reason| Ok(VenueExecutionResult { success: false, venue: venue.clone(), reason: Some(reason), details: None });
+        
+        match connector.execute(order).await {
+            Ok(fill) => Ok(VenueExecutionResult {
+                success: true,
+                venue: venue.clone(),
+                reason: None,
+                details: Some(serde_json::json!({
+                    "tx_hash": fill.tx_hash,
+                    "amount_out": fill.amount_out,
+                    "fill_price": fill.price,
+                    "gas_cost": fill.gas_cost,
+                    "confirmations": fill.confirmations,
+                    "liquidity": "taker",
+                })),
+            }),
+            Err(DexVenueError::SlippageExceeded { .. }) => failure(ExecutionFailureReason::SlippageTooHigh),
+            Err(DexVenueError::Reverted(_)) => failure(ExecutionFailureReason::Revert),
+            Err(DexVenueError::OutOfGas(_)) => failure(ExecutionFailureReason::OutOfGas),
+            Err(e) => Err(OrderRouterError::VenueError(e.to_string())),
+        }
+    }
+    
+    /// Submit an order to a centralized exchange
+    async fn execute_on_connector(connector: &dyn VenueConnector, order: &Order) -> Result<VenueExecutionResult, OrderRouterError> {
+        let venue = connector.venue_id().to_string();
+        let failure = |reason| Ok(VenueExecutionResult { success: false, venue: venue.clone(), reason: Some(reason), details: None });
+        
+        match connector.submit_order(order).await {
+            Ok(OrderAck { status: VenueOrderStatus::Rejected, .. }) => failure(ExecutionFailureReason::Unknown),
+            // Immediate orders that expire untouched did not execute
+            Ok(OrderAck { status: VenueOrderStatus::Expired | VenueOrderStatus::Cancelled, filled_amount, .. }) if filled_amount <= 0.0 => {
+                failure(ExecutionFailureReason::Unknown)
+            }
+            Ok(ack) => Ok(VenueExecutionResult {
+                success: true,
+                venue: venue.clone(),
+                reason: None,
+                details: Some(serde_json::json!({
+                    "venue_order_id": ack.venue_order_id,
+                    "client_order_id": ack.client_order_id,
+                    "status": ack.status,
+                    "filled_amount": ack.filled_amount,
+                    "fill_price": ack.average_price,
+                    "post_only": order.post_only,
+                    "liquidity": order.liquidity_intent(),
+                    "time_in_force": order.time_in_force.name(),
+                })),
+            }),
+            Err(ConnectorError::Rejected { .. }) => failure(ExecutionFailureReason::Unknown),
+            Err(e) => Err(OrderRouterError::VenueError(e.to_string())),
+        }
+    }
+    
+    /// Send an order over a FIX session
+    async fn execute_on_fix(gateway: &FixGateway, order: &Order) -> Result<VenueExecutionResult, OrderRouterError> {
+        match gateway.submit(order).await {
+            Ok(report) => Ok(report.to_venue_result(order)),
+            Err(FixError::SessionRejected { .. }) => Ok(VenueExecutionResult {
+                success: false,
+                venue: gateway.venue_id().to_string(),
+                reason: Some(ExecutionFailureReason::Unknown),
+                details: None,
+            }),
+            Err(FixError::Timeout(_)) => Err(OrderRouterError::ExecutionTimeout),
+            Err(e) => Err(OrderRouterError::VenueError(e.to_string())),
+        }
+    }
+    
     /// Get venues sorted by trust score
     async fn get_ranked_venues(&self, available_venues: &[String]) -> Vec<String> {
         // Create a list of (venue, trust_score) pairs
-        let trust_scores = self.trust_scores.read().await;
+        let venue_trust = self.venue_trust();
         
         let mut venue_scores: Vec<(String, f64)> = available_venues
             .iter()
             .filter_map(|venue| {
-                let score = trust_scores.get(venue).copied().unwrap_or(0.5);
+                // Skip venues an operator has disabled or is draining
+                if let Some(control) = &self.venue_control {
+                    if !control.accepts_new_orders(venue) {
+                        return None;
+                    }
+                }
+                let score = venue_trust.score(venue);
                 Some((venue.clone(), score))
             })
             .collect();
@@ -324,40 +1856,172 @@ impl SmartOrderRouter {
         venue_scores.into_iter().map(|(venue, _)| venue).collect()
     }
     
-    /// Get trust score for a venue
-    async fn get_venue_trust_score(&self, venue: &str) -> f64 {
-        let trust_scores = self.trust_scores.read().await;
-        *trust_scores.get(venue).unwrap_or(&0.5)
+    /// Average fill price a venue result reports, if any
+    fn reported_fill_price(result: &VenueExecutionResult) -> Option<f64> {
+        result.details.as_ref()?.get("fill_price")?.as_f64().filter(|price| *price > 0.0)
+    }
+    
+    /// Price an order executed at, falling back to its limit when the venue reports none
+    fn fill_price(order: &Order, result: &VenueExecutionResult) -> f64 {
+        Self::reported_fill_price(result).unwrap_or(order.price)
+    }
+    
+    /// Adverse slippage of a fill against the order price, in basis points
+    fn fill_slippage_bps(order: &Order, result: &VenueExecutionResult) -> Option<f64> {
+        let fill_price = Self::reported_fill_price(result)?;
+        if order.price <= 0.0 {
+            return None;
+        }
+        let slippage = match order.side {
+            OrderSide::Buy => fill_price - order.price,
+            OrderSide::Sell => order.price - fill_price,
+        };
+        Some(slippage / order.price * 10_000.0)
+    }
+    
+    /// Venue trust outcome for a failed venue call
+    fn error_outcome(err: &OrderRouterError) -> VenueOutcome {
+        match err {
+            OrderRouterError::ExecutionTimeout => VenueOutcome::Timeout,
+            _ => VenueOutcome::Error,
+        }
+    }
+    
+    /// Order state implied by a successful venue result
+    ///
+    /// Venues that report an order status (connectors and FIX) are taken at
+    /// their word; DEX swaps and simulated executions settle immediately.
+    fn venue_status(order: &Order, result: &VenueExecutionResult) -> ExecutionStatus {
+        let details = result.details.as_ref();
+        let filled = Self::filled_amount(order, result);
+        let status = details
+            .and_then(|details| details.get("status"))
+            .and_then(|status| serde_json::from_value::<VenueOrderStatus>(status.clone()).ok());
+        match status {
+            Some(VenueOrderStatus::New) if filled <= 0.0 => ExecutionStatus::InProgress,
+            Some(VenueOrderStatus::New | VenueOrderStatus::PartiallyFilled) if filled < order.amount => {
+                ExecutionStatus::PartiallyFilled
+            }
+            Some(VenueOrderStatus::Cancelled | VenueOrderStatus::Expired) => ExecutionStatus::Cancelled,
+            Some(VenueOrderStatus::Rejected) => ExecutionStatus::Rejected,
+            _ => ExecutionStatus::Completed,
+        }
+    }
+    
+    /// Quantity a venue result reports as filled
+    ///
+    /// Results without a reported fill are treated as fully filled when
+    /// successful, matching [`Self::venue_status`].
+    fn filled_amount(order: &Order, result: &VenueExecutionResult) -> f64 {
+        let reported = result.details
+            .as_ref()
+            .and_then(|details| details.get("filled_amount"))
+            .and_then(|filled| filled.as_f64());
+        match reported {
+            Some(filled) => filled,
+            None if result.success => order.amount,
+            None => 0.0,
+        }
     }
     
-    /// Improve trust score for a venue
-    async fn improve_trust_score(&self, venue: &str, amount: f64) {
-        let mut trust_scores = self.trust_scores.write().await;
-        let current_score = trust_scores.entry(venue.to_string()).or_insert(0.5);
-        *current_score = (*current_score + amount).min(1.0);
+    /// Record a venue status update for a routed order
+    ///
+    /// Terminal statuses stop the order being tracked as resting, so it no
+    /// longer shows up in listings, mass cancels or venue drains.
+    pub async fn update_order_status(&self, order_id: &str, status: ExecutionStatus) {
+        if !matches!(
+            status,
+            ExecutionStatus::Received | ExecutionStatus::InProgress | ExecutionStatus::PartiallyFilled
+        ) {
+            self.resting_orders.lock().await.remove(order_id);
+        }
+        if let Some(scheduler) = &self.expiry_scheduler {
+            scheduler.reconcile(order_id, status).await;
+        }
     }
     
-    /// Decay trust score for a venue
-    async fn decay_trust_score(&self, venue: &str, amount: f64) {
-        let mut trust_scores = self.trust_scores.write().await;
-        let current_score = trust_scores.entry(venue.to_string()).or_insert(0.5);
-        *current_score = (*current_score - amount).max(0.0);
+    /// Start the expiry scheduler's sweep, cancelling expired orders through this router
+    pub async fn start_expiry_sweep(self: &Arc<Self>) -> Result<(), OrderRouterError> {
+        let scheduler = self.expiry_scheduler
+            .as_ref()
+            .ok_or_else(|| OrderRouterError::NotConfigured("expiry scheduler".to_string()))?;
+        scheduler.start(self.clone()).await;
+        Ok(())
     }
     
     /// Cache execution result
     async fn cache_execution_result(&self, order: &Order, result: VenueExecutionResult) {
+        let status = Self::venue_status(order, &result);
+        let open = matches!(
+            status,
+            ExecutionStatus::Received | ExecutionStatus::InProgress | ExecutionStatus::PartiallyFilled
+        );
+        // Immediate orders never rest on the book, and neither do orders already done
+        if order.time_in_force.is_immediate() || !open {
+            self.resting_orders.lock().await.remove(&order.id);
+        } else {
+            let mut resting_orders = self.resting_orders.lock().await;
+            resting_orders.insert(order.id.clone(), RestingOrder {
+                order_id: order.id.clone(),
+                symbol: order.symbol.clone(),
+                side: order.side,
+                amount: order.amount,
+                price: order.price,
+                venue: result.venue.clone(),
+                time_in_force: order.time_in_force,
+                strategy_id: order.additional_params
+                    .get("strategyId")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                submitted_at: Utc::now(),
+            });
+        }
+        
         let mut recent_executions = self.recent_executions.lock().await;
         recent_executions.insert(order.id.clone(), result);
         
-        // Limit cache size
-        if recent_executions.len() > 1000 {
-            // Remove oldest items
-            // This is inefficient but good enough for now
-            // TODO: Use a more efficient cache implementation
-            let keys: Vec<String> = recent_executions.keys().cloned().collect();
-            for key in keys.iter().take(keys.len() - 900) {
-                recent_executions.remove(key);
+        // Orders still working keep their results so they can be cancelled
+        let resting_orders = self.resting_orders.lock().await;
+        recent_executions.evict(|order_id| resting_orders.contains_key(order_id));
+    }
+}
+
+#[async_trait::async_trait]
+impl OrderCanceller for SmartOrderRouter {
+    async fn cancel_expired(&self, expiry: &ScheduledExpiry) -> Result<(), String> {
+        match self.request_cancel(&expiry.order_id).await {
+            Ok((_, true)) => Ok(()),
+            // Leave it for the next sweep; the venue's confirmation reconciles it in the meantime
+            Ok((venue, false)) => Err(format!("cancel pending on {}", venue)),
+            Err(OrderRouterError::OrderNotFound(_)) => {
+                // No longer cached here; pull it from the venue by its own identifiers
+                let (Some(connector), Some(venue_order_id)) =
+                    (self.venue_connectors.get(&expiry.venue), expiry.venue_order_id.as_deref())
+                else {
+                    return Ok(());
+                };
+                match connector.cancel_order(&expiry.symbol, venue_order_id).await {
+                    Ok(ack) if ack.status.is_open() => Err(format!("cancel pending on {}", expiry.venue)),
+                    Ok(_) => Ok(()),
+                    Err(e) => Err(e.to_string()),
+                }
             }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+    
+    async fn order_expired(&self, expiry: &ScheduledExpiry) {
+        self.update_order_status(&expiry.order_id, expiry.execution_status).await;
+        
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish(TradingEvent::OrderState {
+                order_id: expiry.order_id.clone(),
+                symbol: expiry.symbol.clone(),
+                status: expiry.execution_status,
+                venue: Some(expiry.venue.clone()),
+                reason: Some(format!("expired ({})", expiry.time_in_force.name())),
+                timestamp: expiry.updated_at,
+            });
         }
     }
 }
@@ -372,6 +2036,8 @@ pub struct OrderRetryEngine {
     max_delay_ms: u64,
     /// Recent retry attempts
     retry_history: Arc<Mutex<Vec<RetryContext>>>,
+    /// Venue trust scores, updated from failed attempts and used to pick retry venues
+    venue_trust: Arc<VenueTrustService>,
 }
 
 impl OrderRetryEngine {
@@ -382,11 +2048,26 @@ impl OrderRetryEngine {
             base_delay_ms,
             max_delay_ms,
             retry_history: Arc::new(Mutex::new(Vec::new())),
+            venue_trust: Arc::new(VenueTrustService::in_memory()),
         }
     }
     
+    /// Share a (typically persisted) venue trust service
+    pub fn with_venue_trust(mut self, venue_trust: Arc<VenueTrustService>) -> Self {
+        self.venue_trust = venue_trust;
+        self
+    }
+    
+    /// Venue trust scores used to pick retry venues
+    pub fn venue_trust(&self) -> &Arc<VenueTrustService> {
+        &self.venue_trust
+    }
+    
     /// Retry an execution
     pub async fn retry(&self, mut context: RetryContext) -> bool {
+        // The failed attempt counts against the venue either way
+        self.venue_trust.record(&context.venue, VenueOutcome::Rejected);
+        
         if context.attempt >= context.max_retries {
             warn!("Max retries exceeded for {} on {}", context.symbol, context.venue);
             return false;
@@ -424,22 +2105,30 @@ impl OrderRetryEngine {
         true
     }
     
-    /// Get next venue to try (for venue rotation)
+    /// Get next venue to try: the most trusted other venue, ties broken by
+    /// rotation order
     pub fn get_next_venue(&self, current_venue: &str, available_venues: &[String]) -> String {
         if available_venues.is_empty() {
             return current_venue.to_string();
         }
         
-        let current_index = available_venues.iter().position(|v| v == current_venue);
-        match current_index {
-            Some(idx) => {
-                let next_idx = (idx + 1) % available_venues.len();
-                available_venues[next_idx].clone()
+        let start = available_venues
+            .iter()
+            .position(|v| v == current_venue)
+            .map_or(0, |idx| idx + 1);
+        let mut best: Option<(&String, f64)> = None;
+        for offset in 0..available_venues.len() {
+            let venue = &available_venues[(start + offset) % available_venues.len()];
+            if venue == current_venue {
+                continue;
             }
-            None => {
-                available_venues[0].clone()
+            let score = self.venue_trust.score(venue);
+            if !best.is_some_and(|(_, best_score)| score <= best_score) {
+                best = Some((venue, score));
             }
         }
+        
+        best.map_or_else(|| current_venue.to_string(), |(venue, _)| venue.clone())
     }
 }
 
@@ -496,4 +2185,618 @@ mod tests {
         );
         assert_eq!(next_venue, "backup_venue");
     }
-} 
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_retry_prefers_trusted_venue() {
+        let venue_trust = Arc::new(VenueTrustService::in_memory());
+        let router = SmartOrderRouter::new().with_venue_trust(venue_trust.clone());
+        let venues = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        
+        venue_trust.record("b", VenueOutcome::Timeout);
+        venue_trust.record("c", VenueOutcome::Filled { slippage_bps: Some(1.0) });
+        
+        // Rotation would pick "b"; the shared trust scores steer the retry to "c"
+        assert_eq!(router.retry_engine.get_next_venue("a", &venues), "c");
+        assert_eq!(router.get_ranked_venues(&venues).await, vec!["c", "a", "b"]);
+    }
+    
+    #[test]
+    fn test_reduce_only_enforcement() {
+        let position_manager = crate::position::create_position_manager();
+        position_manager.update_position("agent1", &crate::position::OrderOrFill {
+            symbol: "ETH-USD".to_string(),
+            side: crate::position::Side::Buy,
+            size: 2.0,
+            price: 3000.0,
+            timestamp: chrono::Utc::now(),
+            order_id: "fill-order".to_string(),
+            fill_id: None,
+            is_fill: true,
+            venue: None,
+            strategy_id: None,
+        }).unwrap();
+        
+        let router = SmartOrderRouter::new().with_position_manager(position_manager);
+        
+        let mut additional_params = HashMap::new();
+        additional_params.insert("agentId".to_string(), serde_json::json!("agent1"));
+        let mut order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Sell,
+            amount: 5.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string()],
+            id: "reduce-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: true,
+            time_in_force: TimeInForce::GTC,
+            additional_params,
+        };
+        
+        // Sell is clamped to the long position
+        let clamped = router.enforce_order_flags(order.clone()).unwrap();
+        assert_eq!(clamped.amount, 2.0);
+        
+        // Buy would increase the long position
+        order.side = OrderSide::Buy;
+        assert!(matches!(
+            router.enforce_order_flags(order),
+            Err(OrderRouterError::ReduceOnlyViolation(_))
+        ));
+    }
+    
+    #[test]
+    fn test_post_only_without_book_fails_closed() {
+        let router = SmartOrderRouter::new().with_venue_connector(Arc::new(AckOnlyVenue));
+        let order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string(), "ack-only".to_string()],
+            id: "maker-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        
+        // Only the venue that enforces post-only itself is kept
+        let routed = router.enforce_order_flags(order.clone()).unwrap();
+        assert_eq!(routed.venues, vec!["ack-only".to_string()]);
+        
+        let unchecked = Order { venues: vec!["venue1".to_string()], ..order };
+        assert!(matches!(
+            router.enforce_order_flags(unchecked),
+            Err(OrderRouterError::PostOnlyUnverified(_))
+        ));
+    }
+    
+    #[tokio::test]
+    async fn test_cancel_prioritization() {
+        let position_manager = crate::position::create_position_manager();
+        position_manager.update_position("agent1", &crate::position::OrderOrFill {
+            symbol: "ETH-USD".to_string(),
+            side: crate::position::Side::Buy,
+            size: 2.0,
+            price: 3000.0,
+            timestamp: chrono::Utc::now(),
+            order_id: "fill-order".to_string(),
+            fill_id: None,
+            is_fill: true,
+            venue: None,
+            strategy_id: None,
+        }).unwrap();
+        let books = Arc::new(OrderBookManager::new());
+        books.process_update("ETH-USD", 3000.0, 5.0, crate::orderbook::OrderSide::Bid, 1);
+        books.process_update("ETH-USD", 3001.0, 5.0, crate::orderbook::OrderSide::Ask, 2);
+        let router = SmartOrderRouter::new()
+            .with_position_manager(position_manager)
+            .with_order_books(books);
+        
+        let resting = |id: &str, side: OrderSide, amount: f64, price: f64| RestingOrder {
+            order_id: id.to_string(),
+            symbol: "ETH-USD".to_string(),
+            side,
+            amount,
+            price,
+            venue: "venue1".to_string(),
+            time_in_force: TimeInForce::GTC,
+            strategy_id: None,
+            submitted_at: Utc::now(),
+        };
+        let orders = vec![
+            resting("reducing", OrderSide::Sell, 1.0, 3010.0),
+            resting("far", OrderSide::Buy, 1.0, 2700.0),
+            resting("near", OrderSide::Buy, 1.0, 3000.0),
+            resting("large", OrderSide::Buy, 3.0, 2985.0),
+        ];
+        
+        // Large exposure near the touch first; the position-reducing sell last
+        let priorities = router.prioritize_cancels(orders).await;
+        let ids: Vec<&str> = priorities.iter().map(|p| p.order_id.as_str()).collect();
+        assert_eq!(ids, vec!["large", "near", "far", "reducing"]);
+        assert_eq!(priorities[3].exposure_reduction, 0.0);
+        assert!(priorities[2].distance_bps > 900.0);
+    }
+    
+    #[test]
+    fn test_freshness_reprice_keeps_post_only_orders_passive() {
+        let books = Arc::new(OrderBookManager::new());
+        books.process_update("ETH-USD", 3000.0, 5.0, crate::orderbook::OrderSide::Bid, 1);
+        books.process_update("ETH-USD", 3001.0, 5.0, crate::orderbook::OrderSide::Ask, 2);
+        let guard = QuoteFreshnessGuard::new(crate::quote_freshness::QuoteFreshnessConfig {
+            action: crate::quote_freshness::StaleQuoteAction::Reprice,
+            ..Default::default()
+        })
+        .with_reference_books(books.clone());
+        guard.record_quote("stale", "ETH-USD", 0);
+        guard.record_quote("fresh", "ETH-USD", chrono::Utc::now().timestamp_millis() as u64);
+        let router = SmartOrderRouter::new()
+            .with_order_books(books)
+            .with_freshness_guard(Arc::new(guard));
+        
+        let mut order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 2999.0,
+            venues: vec!["stale".to_string(), "fresh".to_string()],
+            id: "maker-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        
+        // The post-only order keeps its passive price and drops the stale venue
+        let venues = vec!["stale".to_string(), "fresh".to_string()];
+        let (routed, fresh) = router.apply_freshness_guard(order.clone(), venues.clone()).unwrap();
+        assert_eq!(routed.price, 2999.0);
+        assert_eq!(fresh, vec!["fresh".to_string()]);
+        
+        // Taker orders are still re-priced against the reference book
+        order.post_only = false;
+        let (routed, fresh) = router.apply_freshness_guard(order, venues).unwrap();
+        assert_eq!(routed.price, 3001.0);
+        assert_eq!(fresh.len(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_batch_validation() {
+        let router = SmartOrderRouter::new().with_batch_config(BatchConfig {
+            max_batch_size: 2,
+            ..BatchConfig::default()
+        });
+        
+        let order = |id: &str, time_in_force: TimeInForce| Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string()],
+            id: id.to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force,
+            additional_params: HashMap::new(),
+        };
+        
+        // Oversized batches are rejected outright
+        let oversized = vec![
+            order("a", TimeInForce::GTC),
+            order("b", TimeInForce::GTC),
+            order("c", TimeInForce::GTC),
+        ];
+        assert!(matches!(router.submit_batch(oversized).await, Err(OrderRouterError::BatchRejected(_))));
+        
+        // One invalid order rejects the whole batch before anything is sent
+        let invalid = vec![
+            order("a", TimeInForce::GTC),
+            order("b", TimeInForce::GTT { duration_ms: 0 }),
+        ];
+        assert!(matches!(router.submit_batch(invalid).await, Err(OrderRouterError::BatchRejected(_))));
+        
+        // Cancelling unknown orders fails per item
+        let cancelled = router.cancel_batch(vec!["missing".to_string()]).await.unwrap();
+        assert_eq!(cancelled.failed, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_only_open_orders_rest() {
+        let router = SmartOrderRouter::new();
+        let order = |id: &str| Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 2.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string()],
+            id: id.to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        let result = |status: VenueOrderStatus, filled_amount: f64| VenueExecutionResult {
+            success: true,
+            venue: "venue1".to_string(),
+            reason: None,
+            details: Some(serde_json::json!({
+                "venue_order_id": "v-1",
+                "status": status,
+                "filled_amount": filled_amount,
+            })),
+        };
+        
+        router.cache_execution_result(&order("filled"), result(VenueOrderStatus::Filled, 2.0)).await;
+        router.cache_execution_result(&order("open"), result(VenueOrderStatus::New, 0.0)).await;
+        router.cache_execution_result(&order("partial"), result(VenueOrderStatus::PartiallyFilled, 1.0)).await;
+        let ids = |orders: Vec<RestingOrder>| {
+            let mut ids: Vec<String> = orders.into_iter().map(|order| order.order_id).collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids(router.list_orders(None).await), vec!["open", "partial"]);
+        
+        // A terminal status stops the order resting
+        router.update_order_status("partial", ExecutionStatus::Completed).await;
+        assert_eq!(ids(router.list_orders(None).await), vec!["open"]);
+    }
+    
+    #[tokio::test]
+    async fn test_replace_batch_checks_replacement() {
+        let books = Arc::new(OrderBookManager::new());
+        books.process_update("ETH-USD", 3000.0, 5.0, crate::orderbook::OrderSide::Bid, 1);
+        books.process_update("ETH-USD", 3001.0, 5.0, crate::orderbook::OrderSide::Ask, 2);
+        let router = SmartOrderRouter::new().with_order_books(books);
+        let order = |id: &str, price: f64, venues: Vec<String>| Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price,
+            venues,
+            id: id.to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        let resting = VenueExecutionResult {
+            success: true,
+            venue: "venue1".to_string(),
+            reason: None,
+            details: Some(serde_json::json!({ "status": VenueOrderStatus::New, "filled_amount": 0.0 })),
+        };
+        router.cache_execution_result(&order("maker-1", 2999.0, vec!["venue1".to_string()]), resting).await;
+        
+        // A replacement that would cross is refused before the original is touched
+        let crossing = ReplaceRequest {
+            order_id: "maker-1".to_string(),
+            replacement: order("maker-2", 3005.0, vec!["venue1".to_string()]),
+        };
+        let result = router.replace_batch(vec![crossing]).await.unwrap();
+        assert_eq!(result.failed, 1);
+        assert_eq!(router.list_orders(None).await.len(), 1);
+        
+        // Once the original is cancelled, a failed replacement says so
+        let unroutable = ReplaceRequest {
+            order_id: "maker-1".to_string(),
+            replacement: order("maker-2", 2999.5, Vec::new()),
+        };
+        let result = router.replace_batch(vec![unroutable]).await.unwrap();
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.items[0].venue.as_deref(), Some("venue1"));
+        assert!(result.items[0].error.as_ref().unwrap().contains("replacement failed"));
+        assert!(router.list_orders(None).await.is_empty());
+    }
+    
+    /// Venue that rests every order and acknowledges cancels without confirming them
+    struct AckOnlyVenue;
+    
+    #[async_trait::async_trait]
+    impl VenueConnector for AckOnlyVenue {
+        fn venue_id(&self) -> &str {
+            "ack-only"
+        }
+        
+        async fn subscribe_market_data(
+            &self,
+            _symbols: &[String],
+            _sink: tokio::sync::mpsc::Sender<crate::connectors::MarketDataEvent>,
+        ) -> crate::connectors::ConnectorResult<()> {
+            Ok(())
+        }
+        
+        async fn unsubscribe_market_data(&self, _symbols: &[String]) -> crate::connectors::ConnectorResult<()> {
+            Ok(())
+        }
+        
+        async fn submit_order(&self, order: &Order) -> crate::connectors::ConnectorResult<OrderAck> {
+            Ok(OrderAck {
+                venue: "ack-only".to_string(),
+                symbol: order.symbol.clone(),
+                venue_order_id: format!("v-{}", order.id),
+                client_order_id: None,
+                status: VenueOrderStatus::New,
+                filled_amount: 0.0,
+                average_price: None,
+            })
+        }
+        
+        async fn cancel_order(&self, symbol: &str, venue_order_id: &str) -> crate::connectors::ConnectorResult<OrderAck> {
+            Ok(OrderAck {
+                venue: "ack-only".to_string(),
+                symbol: symbol.to_string(),
+                venue_order_id: venue_order_id.to_string(),
+                client_order_id: None,
+                status: VenueOrderStatus::New,
+                filled_amount: 0.0,
+                average_price: None,
+            })
+        }
+        
+        async fn fetch_balances(&self) -> crate::connectors::ConnectorResult<Vec<crate::connectors::VenueBalance>> {
+            Ok(Vec::new())
+        }
+        
+        async fn disconnect(&self) {}
+    }
+    
+    #[tokio::test]
+    async fn test_resting_order_publishes_no_fill() {
+        let event_bus = Arc::new(TradingEventBus::new(16));
+        let mut events = event_bus.subscribe();
+        let router = SmartOrderRouter::new()
+            .with_venue_connector(Arc::new(AckOnlyVenue))
+            .with_event_bus(event_bus);
+        let order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["ack-only".to_string()],
+            id: "limit-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        
+        let execution = router.execute_order(order).await.unwrap();
+        assert_eq!(execution.executed_quantity, Some(0.0));
+        
+        // Only the order's state is published while it rests
+        match events.try_recv().unwrap() {
+            TradingEvent::OrderState { order_id, status, .. } => {
+                assert_eq!(order_id, "limit-1");
+                assert_eq!(status, ExecutionStatus::InProgress);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        assert!(events.try_recv().is_err());
+    }
+    
+    #[test]
+    fn test_fill_price_prefers_venue_report() {
+        let order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string()],
+            id: "market-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::IOC,
+            additional_params: HashMap::new(),
+        };
+        let result = |details: serde_json::Value| VenueExecutionResult {
+            success: true,
+            venue: "venue1".to_string(),
+            reason: None,
+            details: Some(details),
+        };
+        
+        let filled = result(serde_json::json!({ "filled_amount": 1.0, "fill_price": 3003.0 }));
+        assert_eq!(SmartOrderRouter::fill_price(&order, &filled), 3003.0);
+        assert!((SmartOrderRouter::fill_slippage_bps(&order, &filled).unwrap() - 10.0).abs() < 1e-9);
+        
+        // Venues that report no price are assumed to have filled at the limit
+        let unpriced = result(serde_json::json!({ "filled_amount": 1.0, "fill_price": null }));
+        assert_eq!(SmartOrderRouter::fill_price(&order, &unpriced), 3000.0);
+    }
+    
+    #[tokio::test]
+    async fn test_drain_waits_for_venue_to_confirm_cancels() {
+        let venue_control = Arc::new(VenueControl::new());
+        let router = Arc::new(
+            SmartOrderRouter::new()
+                .with_venue_connector(Arc::new(AckOnlyVenue))
+                .with_venue_control(venue_control.clone()),
+        );
+        let order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["ack-only".to_string()],
+            id: "resting-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        let resting = VenueExecutionResult {
+            success: true,
+            venue: "ack-only".to_string(),
+            reason: None,
+            details: Some(serde_json::json!({
+                "venue_order_id": "v-1",
+                "status": VenueOrderStatus::New,
+                "filled_amount": 0.0,
+            })),
+        };
+        router.cache_execution_result(&order, resting).await;
+        
+        // The cancel is only acknowledged, so the order can still fill and the drain times out
+        let report = router.drain_venue("ack-only", "ops", None, std::time::Duration::from_millis(50)).await.unwrap();
+        assert_eq!(report.cancelled, vec!["resting-1".to_string()]);
+        assert_eq!(report.remaining, vec!["resting-1".to_string()]);
+        assert!(!report.completed);
+        assert_eq!(venue_control.mode("ack-only"), VenueMode::Draining);
+        
+        // The pending cancel can be retried
+        assert_eq!(router.cancel_order("resting-1").await.unwrap(), "ack-only");
+        
+        // The venue's terminal report completes the drain
+        let confirming = router.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            confirming.update_order_status("resting-1", ExecutionStatus::Cancelled).await;
+        });
+        let report = router.drain_venue("ack-only", "ops", None, std::time::Duration::from_secs(5)).await.unwrap();
+        assert!(report.completed);
+        assert!(report.remaining.is_empty());
+        assert_eq!(venue_control.mode("ack-only"), VenueMode::Disabled);
+    }
+    
+    #[tokio::test]
+    async fn test_replace_waits_for_confirmed_cancel() {
+        let router = SmartOrderRouter::new().with_venue_connector(Arc::new(AckOnlyVenue));
+        let order = |id: &str| Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["ack-only".to_string()],
+            id: id.to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        let resting = VenueExecutionResult {
+            success: true,
+            venue: "ack-only".to_string(),
+            reason: None,
+            details: Some(serde_json::json!({
+                "venue_order_id": "v-1",
+                "status": VenueOrderStatus::New,
+                "filled_amount": 0.0,
+            })),
+        };
+        router.cache_execution_result(&order("original"), resting).await;
+        
+        // The venue only acknowledges the cancel, so the original could still fill
+        let request = ReplaceRequest {
+            order_id: "original".to_string(),
+            replacement: order("replacement"),
+        };
+        let result = router.replace_batch(vec![request]).await.unwrap();
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.items[0].venue.as_deref(), Some("ack-only"));
+        let error = result.items[0].error.as_ref().unwrap();
+        assert!(error.contains("not yet confirmed"), "{}", error);
+        assert!(result.items[0].execution.is_none());
+        
+        let resting: Vec<String> = router.list_orders(None).await.into_iter().map(|order| order.order_id).collect();
+        assert_eq!(resting, vec!["original".to_string()]);
+    }
+    
+    #[tokio::test]
+    async fn test_resting_orders_survive_cache_overflow() {
+        let router = SmartOrderRouter::new();
+        let order = |id: String| Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string()],
+            id,
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        };
+        let result = |status: VenueOrderStatus, filled_amount: f64| VenueExecutionResult {
+            success: true,
+            venue: "venue1".to_string(),
+            reason: None,
+            details: Some(serde_json::json!({ "status": status, "filled_amount": filled_amount })),
+        };
+        
+        router.cache_execution_result(&order("resting".to_string()), result(VenueOrderStatus::New, 0.0)).await;
+        for i in 0..EXECUTION_CACHE_CAPACITY + 100 {
+            router.cache_execution_result(&order(format!("filled-{}", i)), result(VenueOrderStatus::Filled, 1.0)).await;
+        }
+        
+        // The oldest finished results are evicted, but the resting order is still tracked
+        {
+            let recent_executions = router.recent_executions.lock().await;
+            assert_eq!(recent_executions.results.len(), EXECUTION_CACHE_CAPACITY);
+            assert!(recent_executions.get("filled-0").is_none());
+            assert!(recent_executions.get("resting").is_some());
+        }
+        let resting: Vec<String> = router.list_orders(None).await.into_iter().map(|order| order.order_id).collect();
+        assert_eq!(resting, vec!["resting".to_string()]);
+        assert_eq!(router.cancel_order("resting").await.unwrap(), "venue1");
+    }
+    
+    #[tokio::test]
+    async fn test_expired_order_stops_resting() {
+        use crate::order_expiry::OrderExpiryConfig;
+        
+        let scheduler = Arc::new(OrderExpiryScheduler::new(OrderExpiryConfig::default()));
+        let router = SmartOrderRouter::new().with_expiry_scheduler(scheduler.clone());
+        let order = Order {
+            symbol: "ETH-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 3000.0,
+            venues: vec!["venue1".to_string()],
+            id: "gtt-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTT { duration_ms: 1000 },
+            additional_params: HashMap::new(),
+        };
+        let result = VenueExecutionResult {
+            success: true,
+            venue: "venue1".to_string(),
+            reason: None,
+            details: Some(serde_json::json!({ "venue_order_id": "v-1", "status": VenueOrderStatus::New, "filled_amount": 0.0 })),
+        };
+        router.cache_execution_result(&order, result.clone()).await;
+        router.schedule_expiry(&order, &result).await;
+        assert_eq!(scheduler.get("gtt-1").await.unwrap().venue_order_id.as_deref(), Some("v-1"));
+        
+        let expired = scheduler.process_expirations(Utc::now() + chrono::Duration::milliseconds(1500), &router).await;
+        assert_eq!(expired.len(), 1);
+        assert!(router.list_orders(None).await.is_empty());
+        assert_eq!(scheduler.get("gtt-1").await.unwrap().state, ExpiryState::Expired);
+    }
+}
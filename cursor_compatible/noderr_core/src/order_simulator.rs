@@ -0,0 +1,322 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! What-if order simulation.
+//!
+//! Projects the effect of a hypothetical order without submitting it:
+//! slippage from the liquidity profiler, the pre-trade risk check outcome,
+//! exposures and margin before and after, and the change in concentration.
+//! Nothing is published or recorded, so the frontend can call it freely
+//! while a user edits an order ticket.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::market::Symbol;
+use crate::microstructure::liquidity::LiquidityProfiler;
+use crate::position::{PositionManager, Side};
+use crate::risk::PositionDirection;
+use crate::risk_calc::{ConcentrationImpact, PositionExposure, RiskCalculator, RiskCheckResult};
+
+/// Errors raised while simulating an order
+#[derive(Debug, Error)]
+pub enum OrderSimulatorError {
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+
+    #[error("No reference price for {0}")]
+    NoPrice(Symbol),
+}
+
+fn default_leverage() -> f64 {
+    1.0
+}
+
+/// Hypothetical order to simulate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedOrder {
+    /// Symbol to trade
+    pub symbol: Symbol,
+    /// Venue to trade on
+    pub venue: String,
+    /// Buy or sell
+    pub side: Side,
+    /// Quantity in base units
+    pub quantity: f64,
+    /// Limit price; the last known price is used when omitted
+    #[serde(default)]
+    pub price: Option<f64>,
+    /// Leverage applied to the position
+    #[serde(default = "default_leverage")]
+    pub leverage: f64,
+    /// Strategy submitting the order, for strategy-level exemptions
+    #[serde(default)]
+    pub strategy_id: Option<String>,
+}
+
+/// Projected slippage for the order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlippageProjection {
+    /// Slippage as a fraction of the reference price
+    pub slippage_pct: f64,
+    /// Expected average fill price
+    pub expected_fill_price: f64,
+    /// Expected cost of slippage in quote currency
+    pub cost: f64,
+}
+
+/// A quantity before and after the order
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BeforeAfter {
+    /// Value before the order
+    pub before: f64,
+    /// Value after the order
+    pub after: f64,
+}
+
+impl BeforeAfter {
+    fn new(before: f64, after: f64) -> Self {
+        Self { before, after }
+    }
+}
+
+/// Margin effect of the order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarginImpact {
+    /// Margin the order itself requires
+    pub required: f64,
+    /// Margin in use across all positions
+    pub used: BeforeAfter,
+    /// Portfolio value not tied up as margin
+    pub available: BeforeAfter,
+    /// Fraction of portfolio value used as margin
+    pub utilization: BeforeAfter,
+}
+
+/// Post-trade exposures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureProjection {
+    /// Exposure to the order's symbol
+    pub symbol: BeforeAfter,
+    /// Exposure on the order's venue
+    pub venue: BeforeAfter,
+    /// Total exposure across positions
+    pub total: BeforeAfter,
+    /// Total exposure over portfolio value
+    pub gross_leverage: BeforeAfter,
+}
+
+/// Outcome of a what-if simulation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderSimulation {
+    /// The simulated order
+    pub order: SimulatedOrder,
+    /// Price the order was valued at
+    pub reference_price: f64,
+    /// Order notional at the reference price
+    pub notional: f64,
+    /// Projected slippage, when the liquidity profiler knows the symbol
+    pub slippage: Option<SlippageProjection>,
+    /// Pre-trade risk check outcome
+    pub risk_check: RiskCheckResult,
+    /// Exposures before and after
+    pub exposure: ExposureProjection,
+    /// Margin before and after
+    pub margin: MarginImpact,
+    /// Concentration of the touched symbol, sector and venue buckets
+    pub concentration: ConcentrationImpact,
+    /// Whether the order would be accepted by the risk checks
+    pub would_pass: bool,
+    /// When the simulation ran
+    pub simulated_at: DateTime<Utc>,
+}
+
+/// Simulates orders against live risk state without executing them
+pub struct OrderSimulator {
+    /// Risk calculator holding current positions and limits
+    risk_calculator: Arc<RiskCalculator>,
+    /// Optional liquidity profiler for slippage projections
+    liquidity_profiler: Option<Arc<dyn LiquidityProfiler>>,
+    /// Optional position manager for reference prices
+    position_manager: Option<Arc<PositionManager>>,
+}
+
+impl OrderSimulator {
+    /// Create a new simulator
+    pub fn new(risk_calculator: Arc<RiskCalculator>) -> Self {
+        Self {
+            risk_calculator,
+            liquidity_profiler: None,
+            position_manager: None,
+        }
+    }
+
+    /// Project slippage with a liquidity profiler
+    pub fn with_liquidity_profiler(mut self, liquidity_profiler: Arc<dyn LiquidityProfiler>) -> Self {
+        self.liquidity_profiler = Some(liquidity_profiler);
+        self
+    }
+
+    /// Take reference prices from a position manager
+    pub fn with_position_manager(mut self, position_manager: Arc<PositionManager>) -> Self {
+        self.position_manager = Some(position_manager);
+        self
+    }
+
+    /// Simulate an order
+    pub async fn simulate(&self, order: SimulatedOrder) -> Result<OrderSimulation, OrderSimulatorError> {
+        if !order.quantity.is_finite() || order.quantity <= 0.0 {
+            return Err(OrderSimulatorError::InvalidOrder("quantity must be positive".to_string()));
+        }
+        if !order.leverage.is_finite() || order.leverage <= 0.0 {
+            return Err(OrderSimulatorError::InvalidOrder("leverage must be positive".to_string()));
+        }
+
+        let reference_price = match order.price {
+            Some(price) if price > 0.0 => price,
+            Some(_) => return Err(OrderSimulatorError::InvalidOrder("price must be positive".to_string())),
+            None => self
+                .position_manager
+                .as_ref()
+                .and_then(|pm| pm.get_current_price(&order.symbol).ok().flatten())
+                .ok_or_else(|| OrderSimulatorError::NoPrice(order.symbol.clone()))?,
+        };
+        let notional = order.quantity * reference_price;
+        let is_buy = order.side == Side::Buy;
+
+        let slippage = match &self.liquidity_profiler {
+            Some(profiler) => profiler
+                .calculate_slippage(&order.symbol, order.quantity, is_buy)
+                .await
+                .ok()
+                .map(|slippage_pct| SlippageProjection {
+                    slippage_pct,
+                    expected_fill_price: if is_buy {
+                        reference_price * (1.0 + slippage_pct)
+                    } else {
+                        reference_price * (1.0 - slippage_pct)
+                    },
+                    cost: notional * slippage_pct,
+                }),
+            None => None,
+        };
+
+        let trust_score = self.risk_calculator.get_trust_score(&order.venue).await;
+        let direction = if is_buy { PositionDirection::Long } else { PositionDirection::Short };
+        let proposed = PositionExposure::new(
+            &order.symbol,
+            &order.venue,
+            order.quantity,
+            notional,
+            order.leverage,
+            trust_score,
+            direction,
+        );
+
+        let risk_check = self
+            .risk_calculator
+            .evaluate_position(&proposed, order.strategy_id.as_deref())
+            .await;
+        let concentration = self.risk_calculator.what_if_concentration(&proposed).await;
+
+        let portfolio_value = self.risk_calculator.get_portfolio_value().await;
+        let positions = self.risk_calculator.get_all_positions().await;
+        let symbol_before: f64 = positions.iter().filter(|p| p.symbol == order.symbol).map(|p| p.value).sum();
+        let venue_before: f64 = positions.iter().filter(|p| p.venue == order.venue).map(|p| p.value).sum();
+        let total_before: f64 = positions.iter().map(|p| p.value).sum();
+        let used_before: f64 = positions.iter().map(|p| p.value / p.leverage.max(1.0)).sum();
+        let required = notional / order.leverage.max(1.0);
+        let ratio = |value: f64| if portfolio_value > 0.0 { value / portfolio_value } else { 0.0 };
+
+        let exposure = ExposureProjection {
+            symbol: BeforeAfter::new(symbol_before, symbol_before + notional),
+            venue: BeforeAfter::new(venue_before, venue_before + notional),
+            total: BeforeAfter::new(total_before, total_before + notional),
+            gross_leverage: BeforeAfter::new(ratio(total_before), ratio(total_before + notional)),
+        };
+        let margin = MarginImpact {
+            required,
+            used: BeforeAfter::new(used_before, used_before + required),
+            available: BeforeAfter::new(portfolio_value - used_before, portfolio_value - used_before - required),
+            utilization: BeforeAfter::new(ratio(used_before), ratio(used_before + required)),
+        };
+
+        let would_pass = risk_check.passed && concentration.allowed;
+        Ok(OrderSimulation {
+            order,
+            reference_price,
+            notional,
+            slippage,
+            risk_check,
+            exposure,
+            margin,
+            concentration,
+            would_pass,
+            simulated_at: Utc::now(),
+        })
+    }
+}
+
+/// Create an order simulator
+pub fn create_order_simulator(risk_calculator: Arc<RiskCalculator>) -> Arc<OrderSimulator> {
+    Arc::new(OrderSimulator::new(risk_calculator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::microstructure::liquidity::{LiquiditySnapshot, MockLiquidityProfiler};
+    use crate::risk_calc::RiskConfig;
+
+    #[tokio::test]
+    async fn projects_slippage_margin_and_risk_without_recording() {
+        let calculator = Arc::new(RiskCalculator::new(RiskConfig::default(), 100_000.0));
+        calculator.set_trust_score("binance", 0.9).await;
+
+        let profiler = MockLiquidityProfiler::new();
+        profiler.set_snapshot(
+            "BTC/USD".to_string(),
+            LiquiditySnapshot::new("BTC/USD".to_string(), 1.0, 0.0001, 1_000_000.0).with_slippage(0.001, 1.0),
+        );
+        let simulator = OrderSimulator::new(Arc::clone(&calculator)).with_liquidity_profiler(Arc::new(profiler));
+
+        let order = SimulatedOrder {
+            symbol: "BTC/USD".to_string(),
+            venue: "binance".to_string(),
+            side: Side::Buy,
+            quantity: 4.0,
+            price: Some(5_000.0),
+            leverage: 2.0,
+            strategy_id: None,
+        };
+        let simulation = simulator.simulate(order.clone()).await.unwrap();
+
+        let slippage = simulation.slippage.unwrap();
+        assert!((slippage.slippage_pct - 0.002).abs() < 1e-12);
+        assert!((slippage.expected_fill_price - 5_010.0).abs() < 1e-9);
+        assert!((simulation.margin.required - 10_000.0).abs() < 1e-9);
+        assert!((simulation.margin.utilization.after - 0.1).abs() < 1e-12);
+        assert!((simulation.exposure.gross_leverage.after - 0.2).abs() < 1e-12);
+        // 20% of the portfolio breaches the 10% position size limit
+        assert!(!simulation.would_pass);
+        assert!(calculator.get_all_positions().await.is_empty());
+
+        let small = SimulatedOrder { quantity: 1.0, ..order };
+        assert!(simulator.simulate(small).await.unwrap().would_pass);
+    }
+}
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Per-strategy order throttles.
+//!
+//! Each strategy gets a token bucket refilled at `orders_per_sec` and capped
+//! at `burst`, checked by the executor before a validated signal is routed.
+//! Strategies that keep hitting their limit inside the alert window are
+//! flagged as persistently throttled, which usually means a runaway loop.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::strategy::StrategyId;
+
+/// Sustained rate and burst allowance for one strategy
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ThrottleLimit {
+    /// Tokens added to the bucket per second
+    pub orders_per_sec: f64,
+    /// Bucket capacity, i.e. orders allowed back-to-back
+    pub burst: u32,
+}
+
+impl ThrottleLimit {
+    /// Create a new limit
+    pub fn new(orders_per_sec: f64, burst: u32) -> Self {
+        Self { orders_per_sec, burst }
+    }
+}
+
+/// Configuration for [`OrderThrottle`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrderThrottleConfig {
+    /// Whether throttling is enforced at all
+    pub enabled: bool,
+    /// Limit applied to strategies without an explicit override
+    pub default_limit: Option<ThrottleLimit>,
+    /// Per-strategy overrides
+    pub strategy_limits: HashMap<StrategyId, ThrottleLimit>,
+    /// Window over which throttled orders are counted for alerting
+    pub alert_window_secs: u64,
+    /// Throttled orders within the window that raise an alert
+    pub alert_threshold: usize,
+    /// Minimum time between alerts for the same strategy
+    pub alert_cooldown_secs: u64,
+}
+
+impl Default for OrderThrottleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_limit: Some(ThrottleLimit::new(5.0, 10)),
+            strategy_limits: HashMap::new(),
+            alert_window_secs: 60,
+            alert_threshold: 20,
+            alert_cooldown_secs: 300,
+        }
+    }
+}
+
+impl OrderThrottleConfig {
+    /// Limit in force for a strategy, if any
+    pub fn limit_for(&self, strategy_id: &StrategyId) -> Option<ThrottleLimit> {
+        self.strategy_limits.get(strategy_id).copied().or(self.default_limit)
+    }
+
+    /// Override the limit for a single strategy
+    pub fn with_strategy_limit(mut self, strategy_id: impl Into<StrategyId>, limit: ThrottleLimit) -> Self {
+        self.strategy_limits.insert(strategy_id.into(), limit);
+        self
+    }
+}
+
+/// Outcome of a throttle check
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThrottleDecision {
+    /// The order may be routed
+    Allowed,
+    /// The order must be dropped
+    Throttled {
+        /// Time until the next token becomes available
+        retry_after_ms: u64,
+        /// Whether this rejection crossed the persistent-throttling alert threshold
+        alert: bool,
+    },
+}
+
+impl ThrottleDecision {
+    /// Whether the order may be routed
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, ThrottleDecision::Allowed)
+    }
+}
+
+/// Throttle counters for one strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThrottleStats {
+    /// Orders let through
+    pub allowed: u64,
+    /// Orders dropped by the throttle
+    pub throttled: u64,
+    /// Throttled orders inside the current alert window
+    pub throttled_in_window: usize,
+    /// Alerts raised for persistent throttling
+    pub alerts: u64,
+    /// When the strategy was last throttled
+    pub last_throttled_at: Option<DateTime<Utc>>,
+}
+
+/// Token bucket and counters for one strategy
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: DateTime<Utc>,
+    recent_throttles: VecDeque<DateTime<Utc>>,
+    last_alert: Option<DateTime<Utc>>,
+    stats: ThrottleStats,
+}
+
+impl Bucket {
+    fn new(limit: &ThrottleLimit, now: DateTime<Utc>) -> Self {
+        Self {
+            tokens: limit.burst as f64,
+            last_refill: now,
+            recent_throttles: VecDeque::new(),
+            last_alert: None,
+            stats: ThrottleStats::default(),
+        }
+    }
+
+    fn refill(&mut self, limit: &ThrottleLimit, now: DateTime<Utc>) {
+        let elapsed = (now - self.last_refill).num_milliseconds().max(0) as f64 / 1000.0;
+        self.tokens = (self.tokens + elapsed * limit.orders_per_sec).min(limit.burst as f64);
+        self.last_refill = now;
+    }
+}
+
+/// Enforces per-strategy order rate limits
+#[derive(Debug)]
+pub struct OrderThrottle {
+    /// Configuration
+    config: OrderThrottleConfig,
+    /// Buckets keyed by strategy
+    buckets: Mutex<HashMap<StrategyId, Bucket>>,
+}
+
+impl OrderThrottle {
+    /// Create a new throttle
+    pub fn new(config: OrderThrottleConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Configuration in force
+    pub fn config(&self) -> &OrderThrottleConfig {
+        &self.config
+    }
+
+    /// Take a token for an order from `strategy_id` at the current time
+    pub fn try_acquire(&self, strategy_id: &StrategyId) -> ThrottleDecision {
+        self.try_acquire_at(strategy_id, Utc::now())
+    }
+
+    /// Take a token for an order from `strategy_id` at `now`
+    pub fn try_acquire_at(&self, strategy_id: &StrategyId, now: DateTime<Utc>) -> ThrottleDecision {
+        if !self.config.enabled {
+            return ThrottleDecision::Allowed;
+        }
+        let Some(limit) = self.config.limit_for(strategy_id) else {
+            return ThrottleDecision::Allowed;
+        };
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets
+            .entry(strategy_id.clone())
+            .or_insert_with(|| Bucket::new(&limit, now));
+        bucket.refill(&limit, now);
+
+        let window_start = now - Duration::seconds(self.config.alert_window_secs as i64);
+        while bucket.recent_throttles.front().is_some_and(|t| *t < window_start) {
+            bucket.recent_throttles.pop_front();
+        }
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            bucket.stats.allowed += 1;
+            bucket.stats.throttled_in_window = bucket.recent_throttles.len();
+            return ThrottleDecision::Allowed;
+        }
+
+        bucket.stats.throttled += 1;
+        bucket.stats.last_throttled_at = Some(now);
+        bucket.recent_throttles.push_back(now);
+        bucket.stats.throttled_in_window = bucket.recent_throttles.len();
+
+        let cooldown = Duration::seconds(self.config.alert_cooldown_secs as i64);
+        let alert = bucket.recent_throttles.len() >= self.config.alert_threshold
+            && !bucket.last_alert.is_some_and(|last| now - last < cooldown);
+        if alert {
+            bucket.last_alert = Some(now);
+            bucket.stats.alerts += 1;
+        }
+
+        let retry_after_ms = if limit.orders_per_sec > 0.0 {
+            ((1.0 - bucket.tokens) / limit.orders_per_sec * 1000.0).ceil() as u64
+        } else {
+            u64::MAX
+        };
+
+        ThrottleDecision::Throttled { retry_after_ms, alert }
+    }
+
+    /// Counters for a single strategy
+    pub fn stats(&self, strategy_id: &StrategyId) -> Option<ThrottleStats> {
+        self.buckets.lock().unwrap().get(strategy_id).map(|b| b.stats.clone())
+    }
+
+    /// Counters for every strategy that has submitted an order
+    pub fn all_stats(&self) -> HashMap<StrategyId, ThrottleStats> {
+        self.buckets
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, b)| (id.clone(), b.stats.clone()))
+            .collect()
+    }
+
+    /// Drop the bucket for a strategy, restoring its full burst
+    pub fn reset(&self, strategy_id: &StrategyId) {
+        self.buckets.lock().unwrap().remove(strategy_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burst_then_refill_and_persistent_alert() {
+        let config = OrderThrottleConfig {
+            default_limit: Some(ThrottleLimit::new(2.0, 3)),
+            alert_threshold: 3,
+            ..Default::default()
+        };
+        let throttle = OrderThrottle::new(config);
+        let id: StrategyId = "looper".to_string();
+        let t0 = Utc::now();
+
+        for _ in 0..3 {
+            assert!(throttle.try_acquire_at(&id, t0).is_allowed());
+        }
+        assert_eq!(
+            throttle.try_acquire_at(&id, t0),
+            ThrottleDecision::Throttled { retry_after_ms: 500, alert: false }
+        );
+
+        // Half a second refills exactly one token
+        let t1 = t0 + Duration::milliseconds(500);
+        assert!(throttle.try_acquire_at(&id, t1).is_allowed());
+
+        assert!(!throttle.try_acquire_at(&id, t1).is_allowed());
+        match throttle.try_acquire_at(&id, t1) {
+            ThrottleDecision::Throttled { alert, .. } => assert!(alert),
+            other => panic!("expected throttle, got {:?}", other),
+        }
+        // Cooldown suppresses a second alert
+        match throttle.try_acquire_at(&id, t1) {
+            ThrottleDecision::Throttled { alert, .. } => assert!(!alert),
+            other => panic!("expected throttle, got {:?}", other),
+        }
+
+        let stats = throttle.stats(&id).unwrap();
+        assert_eq!(stats.allowed, 4);
+        assert_eq!(stats.throttled, 4);
+        assert_eq!(stats.alerts, 1);
+    }
+}
@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Pairs-trading cointegration scanner
+//!
+//! Keeps a rolling price history per symbol and periodically tests every
+//! pair for cointegration with the Engle-Granger two-step method: log prices
+//! are regressed on each other to get the hedge ratio, and the residual
+//! spread is checked for a unit root with an augmented Dickey-Fuller test.
+//! Pairs whose spread is stationary and reverts on a tradeable horizon are
+//! published with their hedge ratio, half-life and current z-score.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// Pairs scanner configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PairsScannerConfig {
+    /// Most recent aligned observations used per test
+    pub window: usize,
+    /// Minimum aligned observations before a pair is tested
+    pub min_observations: usize,
+    /// ADF statistic the spread must fall below (-3.34 is the 5% Engle-Granger
+    /// critical value for two series, -3.90 the 1% value)
+    pub adf_critical_value: f64,
+    /// Shortest acceptable spread half-life, in observations
+    pub min_half_life: f64,
+    /// Longest acceptable spread half-life, in observations
+    pub max_half_life: f64,
+    /// Seconds between scans
+    pub scan_interval_secs: u64,
+    /// Prices kept per symbol
+    pub max_history: usize,
+}
+
+impl Default for PairsScannerConfig {
+    fn default() -> Self {
+        Self {
+            window: 500,
+            min_observations: 100,
+            adf_critical_value: -3.34,
+            min_half_life: 1.0,
+            max_half_life: 100.0,
+            scan_interval_secs: 300,
+            max_history: 2_000,
+        }
+    }
+}
+
+/// Engle-Granger test output for one ordering of a pair
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EngleGranger {
+    /// Regression intercept
+    pub intercept: f64,
+    /// Units of the independent series per unit of the dependent one
+    pub hedge_ratio: f64,
+    /// ADF t-statistic of the residual spread
+    pub adf_statistic: f64,
+    /// Spread mean-reversion half-life, in observations
+    pub half_life: Option<f64>,
+    /// Standard deviation of the residual spread
+    pub spread_std: f64,
+}
+
+/// A cointegrated pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CointegratedPair {
+    /// Symbol regressed on the other (traded one unit per spread unit)
+    pub dependent: String,
+    /// Hedge symbol
+    pub independent: String,
+    /// Regression intercept of log prices
+    pub intercept: f64,
+    /// Units of the hedge per unit of the dependent symbol, in log-price terms
+    pub hedge_ratio: f64,
+    /// ADF t-statistic of the spread
+    pub adf_statistic: f64,
+    /// Spread half-life, in observations
+    pub half_life: f64,
+    /// Standard deviation of the spread
+    pub spread_std: f64,
+    /// Spread z-score at the latest common observation
+    pub zscore: f64,
+    /// Observations the test used
+    pub observations: usize,
+    /// When the pair was tested
+    pub scanned_at: DateTime<Utc>,
+}
+
+impl CointegratedPair {
+    /// Spread z-score at the given prices
+    pub fn zscore_at(&self, dependent_price: f64, independent_price: f64) -> Option<f64> {
+        if dependent_price <= 0.0 || independent_price <= 0.0 || self.spread_std <= 0.0 {
+            return None;
+        }
+        let spread = dependent_price.ln() - self.intercept - self.hedge_ratio * independent_price.ln();
+        Some(spread / self.spread_std)
+    }
+
+    /// Whether the pair involves a symbol
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.dependent == symbol || self.independent == symbol
+    }
+}
+
+/// Ordinary least squares fit of `y = a + b x`, returning (a, b, residuals)
+fn ols(x: &[f64], y: &[f64]) -> Option<(f64, f64, Vec<f64>)> {
+    let n = x.len();
+    if n < 3 || n != y.len() {
+        return None;
+    }
+    let mean_x = x.iter().sum::<f64>() / n as f64;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+    let sxx: f64 = x.iter().map(|v| (v - mean_x).powi(2)).sum();
+    if sxx <= f64::EPSILON {
+        return None;
+    }
+    let sxy: f64 = x.iter().zip(y).map(|(a, b)| (a - mean_x) * (b - mean_y)).sum();
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let residuals = x.iter().zip(y).map(|(a, b)| b - intercept - slope * a).collect();
+    Some((intercept, slope, residuals))
+}
+
+/// Dickey-Fuller t-statistic of a zero-mean series: regress the change on
+/// the lagged level without a constant and return the slope's t-value
+pub fn adf_statistic(series: &[f64]) -> Option<f64> {
+    if series.len() < 4 {
+        return None;
+    }
+    let lagged = &series[..series.len() - 1];
+    let changes: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let sxx: f64 = lagged.iter().map(|v| v * v).sum();
+    if sxx <= f64::EPSILON {
+        return None;
+    }
+    let gamma = lagged.iter().zip(&changes).map(|(l, d)| l * d).sum::<f64>() / sxx;
+    let rss: f64 = lagged.iter().zip(&changes).map(|(l, d)| (d - gamma * l).powi(2)).sum();
+    let sigma2 = rss / (changes.len() - 1) as f64;
+    let se = (sigma2 / sxx).sqrt();
+    (se > 0.0).then(|| gamma / se)
+}
+
+/// Half-life of mean reversion, from `Δs = a + b s(t-1)`; `None` if the
+/// series does not revert
+pub fn half_life(series: &[f64]) -> Option<f64> {
+    if series.len() < 4 {
+        return None;
+    }
+    let changes: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+    let (_, b, _) = ols(&series[..series.len() - 1], &changes)?;
+    (b < 0.0 && b > -1.0).then(|| -std::f64::consts::LN_2 / (1.0 + b).ln())
+}
+
+/// Engle-Granger test of `y` on `x`
+pub fn engle_granger(y: &[f64], x: &[f64]) -> Option<EngleGranger> {
+    let (intercept, hedge_ratio, residuals) = ols(x, y)?;
+    let n = residuals.len() as f64;
+    let spread_std = (residuals.iter().map(|r| r * r).sum::<f64>() / (n - 1.0)).sqrt();
+    Some(EngleGranger {
+        intercept,
+        hedge_ratio,
+        adf_statistic: adf_statistic(&residuals)?,
+        half_life: half_life(&residuals),
+        spread_std,
+    })
+}
+
+/// Scans symbol pairs for cointegration
+pub struct PairsScanner {
+    /// Configuration
+    config: PairsScannerConfig,
+    /// Price history by symbol
+    prices: RwLock<HashMap<String, BTreeMap<DateTime<Utc>, f64>>>,
+    /// Pairs found by the last scan
+    pairs: RwLock<Vec<CointegratedPair>>,
+    /// Publishes each scan's tradeable pairs
+    publisher: broadcast::Sender<Vec<CointegratedPair>>,
+    /// Scan task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl PairsScanner {
+    /// Create a new pairs scanner
+    pub fn new(config: PairsScannerConfig) -> Self {
+        let (publisher, _) = broadcast::channel(16);
+        Self {
+            config,
+            prices: RwLock::new(HashMap::new()),
+            pairs: RwLock::new(Vec::new()),
+            publisher,
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Record a price observation
+    pub async fn record_price(&self, symbol: &str, timestamp: DateTime<Utc>, price: f64) {
+        if price <= 0.0 {
+            return;
+        }
+        let mut prices = self.prices.write().await;
+        let history = prices.entry(symbol.to_string()).or_default();
+        history.insert(timestamp, price);
+        while history.len() > self.config.max_history {
+            history.pop_first();
+        }
+    }
+
+    /// Most recent recorded price for a symbol
+    pub async fn latest_price(&self, symbol: &str) -> Option<f64> {
+        self.prices.read().await.get(symbol)?.values().next_back().copied()
+    }
+
+    /// Log prices of two symbols at their common timestamps, most recent `window`
+    async fn aligned_log_prices(&self, a: &str, b: &str) -> (Vec<f64>, Vec<f64>) {
+        let prices = self.prices.read().await;
+        let (Some(a), Some(b)) = (prices.get(a), prices.get(b)) else {
+            return (Vec::new(), Vec::new());
+        };
+        let mut aligned: Vec<(f64, f64)> = a.iter()
+            .rev()
+            .filter_map(|(ts, pa)| b.get(ts).map(|pb| (pa.ln(), pb.ln())))
+            .take(self.config.window)
+            .collect();
+        aligned.reverse();
+        aligned.into_iter().unzip()
+    }
+
+    /// Test one pair in both orderings, keeping the stronger result
+    pub async fn test_pair(&self, a: &str, b: &str) -> Option<CointegratedPair> {
+        let (log_a, log_b) = self.aligned_log_prices(a, b).await;
+        if log_a.len() < self.config.min_observations.max(4) {
+            return None;
+        }
+
+        let candidates = [
+            (a, b, engle_granger(&log_a, &log_b), &log_a, &log_b),
+            (b, a, engle_granger(&log_b, &log_a), &log_b, &log_a),
+        ];
+        let (dependent, independent, test, y, x) = candidates
+            .into_iter()
+            .filter_map(|(dep, ind, test, y, x)| test.map(|t| (dep, ind, t, y, x)))
+            .min_by(|l, r| l.2.adf_statistic.total_cmp(&r.2.adf_statistic))?;
+
+        let half_life = test.half_life?;
+        if test.adf_statistic > self.config.adf_critical_value
+            || half_life < self.config.min_half_life
+            || half_life > self.config.max_half_life
+            || test.spread_std <= 0.0
+        {
+            return None;
+        }
+
+        let last = y.len() - 1;
+        let spread = y[last] - test.intercept - test.hedge_ratio * x[last];
+        Some(CointegratedPair {
+            dependent: dependent.to_string(),
+            independent: independent.to_string(),
+            intercept: test.intercept,
+            hedge_ratio: test.hedge_ratio,
+            adf_statistic: test.adf_statistic,
+            half_life,
+            spread_std: test.spread_std,
+            zscore: spread / test.spread_std,
+            observations: y.len(),
+            scanned_at: Utc::now(),
+        })
+    }
+
+    /// Test every pair of recorded symbols and publish the tradeable ones
+    pub async fn scan(&self) -> Vec<CointegratedPair> {
+        let mut symbols: Vec<String> = self.prices.read().await.keys().cloned().collect();
+        symbols.sort();
+
+        let mut found = Vec::new();
+        for (i, a) in symbols.iter().enumerate() {
+            for b in &symbols[i + 1..] {
+                if let Some(pair) = self.test_pair(a, b).await {
+                    found.push(pair);
+                }
+            }
+        }
+        found.sort_by(|l, r| l.adf_statistic.total_cmp(&r.adf_statistic));
+
+        debug!("Pairs scan of {} symbols found {} cointegrated pairs", symbols.len(), found.len());
+        *self.pairs.write().await = found.clone();
+        // No subscribers is not an error
+        let _ = self.publisher.send(found.clone());
+        found
+    }
+
+    /// Pairs found by the last scan, strongest first
+    pub async fn tradeable_pairs(&self) -> Vec<CointegratedPair> {
+        self.pairs.read().await.clone()
+    }
+
+    /// Strongest pair involving a symbol
+    pub async fn pair_for(&self, symbol: &str) -> Option<CointegratedPair> {
+        self.pairs.read().await.iter().find(|pair| pair.contains(symbol)).cloned()
+    }
+
+    /// Receive the pairs published by every scan
+    pub fn subscribe(&self) -> broadcast::Receiver<Vec<CointegratedPair>> {
+        self.publisher.subscribe()
+    }
+
+    /// Start the periodic scan
+    pub async fn start(self: &Arc<Self>) {
+        let scanner = Arc::clone(self);
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(StdDuration::from_secs(scanner.config.scan_interval_secs.max(1)));
+            loop {
+                interval.tick().await;
+                scanner.scan().await;
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop the periodic scan
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Create a pairs scanner
+pub fn create_pairs_scanner(config: PairsScannerConfig) -> Arc<PairsScanner> {
+    Arc::new(PairsScanner::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[tokio::test]
+    async fn test_finds_cointegrated_pair_with_hedge_ratio() {
+        let scanner = PairsScanner::new(PairsScannerConfig::default());
+        let mut rng = StdRng::seed_from_u64(7);
+        let start = Utc::now();
+
+        // ETH follows BTC with a hedge ratio of 1.5 plus an AR(1) spread
+        let (mut log_btc, mut spread) = (10.0f64, 0.0f64);
+        for i in 0..400 {
+            log_btc += rng.gen_range(-0.01..0.01);
+            spread = 0.7 * spread + rng.gen_range(-0.005..0.005);
+            let log_eth = -7.0 + 1.5 * log_btc + spread;
+
+            let ts = start + Duration::minutes(i);
+            scanner.record_price("BTC", ts, log_btc.exp()).await;
+            scanner.record_price("ETH", ts, log_eth.exp()).await;
+        }
+
+        let pairs = scanner.scan().await;
+        let pair = pairs.iter().find(|p| p.contains("BTC") && p.contains("ETH")).expect("pair found");
+        let ratio = if pair.dependent == "ETH" { pair.hedge_ratio } else { 1.0 / pair.hedge_ratio };
+        assert!((ratio - 1.5).abs() < 0.1, "hedge ratio {}", ratio);
+        assert!(pair.half_life > 1.0 && pair.half_life < 10.0, "half-life {}", pair.half_life);
+        assert!(scanner.pair_for("ETH").await.is_some());
+    }
+}
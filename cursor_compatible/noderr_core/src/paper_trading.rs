@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Paper trading against live order books
+//!
+//! [`OrderBookPaperProvider`] stands in for a venue connection in
+//! [`ExecutionMode::Paper`]. Orders are matched against the books the
+//! exchange connectors maintain in the [`OrderBookManager`] once a sampled
+//! order latency has passed, so fills reflect the market as it was when the
+//! order would have arrived. A fill takes at most a share of the visible size
+//! at each level, and size already taken is not offered again until the book
+//! updates, which produces partial fills on thin books. Marketable orders pay
+//! the taker fee and market orders cancel whatever they cannot fill; the
+//! remainder of a limit order keeps working and fills at its limit, paying
+//! the maker fee, once the book trades through it.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration as StdDuration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::time;
+use tracing::debug;
+
+use crate::execution::{
+    ExecutionError, ExecutionMode, ExecutionProvider, ExecutionRequest, ExecutionResult, ExecutionStatus, FeeInfo,
+};
+use crate::orderbook::{OrderBookManager, OrderSide};
+use crate::runaway_detector::signal_side;
+use crate::simulation::latency_model::{LatencyModel, LatencyModelConfig};
+
+/// Paper trading configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaperTradingConfig {
+    /// Venue reported on results and used to pick the latency profile
+    pub venue: String,
+    /// Fee for fills that take liquidity (basis points of notional)
+    pub taker_fee_bps: f64,
+    /// Fee for resting fills that provide liquidity (basis points of notional)
+    pub maker_fee_bps: f64,
+    /// Largest share of each visible level one paper order may take (0.0-1.0)
+    pub max_level_participation: f64,
+    /// Book levels walked when filling an order
+    pub depth: usize,
+    /// Seconds an unfilled limit order keeps working; 0 keeps it until cancelled
+    pub max_resting_secs: i64,
+    /// Order latency model; orders are matched immediately when not set
+    pub latency: Option<LatencyModelConfig>,
+}
+
+impl Default for PaperTradingConfig {
+    fn default() -> Self {
+        Self {
+            venue: "paper".to_string(),
+            taker_fee_bps: 10.0,
+            maker_fee_bps: 2.0,
+            max_level_participation: 0.5,
+            depth: 20,
+            max_resting_secs: 300,
+            latency: Some(LatencyModelConfig::default()),
+        }
+    }
+}
+
+/// An order being filled on paper
+#[derive(Debug, Clone)]
+struct PaperOrder {
+    request_id: String,
+    symbol: String,
+    /// Book side the order joins: bids buy, asks sell
+    side: OrderSide,
+    remaining: f64,
+    limit: Option<f64>,
+    sent_at: DateTime<Utc>,
+    /// Mid when the order was sent, for slippage
+    reference_price: Option<f64>,
+}
+
+/// Size taken from a symbol's levels since its book last updated
+#[derive(Debug, Default)]
+struct ConsumedLiquidity {
+    update_id: u64,
+    /// Taken size by level price (as bits)
+    levels: HashMap<u64, f64>,
+}
+
+#[derive(Debug, Default)]
+struct PaperState {
+    /// Limit orders still working
+    resting: Vec<PaperOrder>,
+    results: HashMap<String, ExecutionResult>,
+    consumed: HashMap<String, ConsumedLiquidity>,
+}
+
+/// Execution provider that fills orders against live order books without sending them
+pub struct OrderBookPaperProvider {
+    config: PaperTradingConfig,
+    books: Arc<OrderBookManager>,
+    latency: Option<Mutex<LatencyModel>>,
+    state: Mutex<PaperState>,
+}
+
+impl OrderBookPaperProvider {
+    /// Create a new paper trading provider over `books`
+    pub fn new(config: PaperTradingConfig, books: Arc<OrderBookManager>) -> Self {
+        let latency = config.latency.clone().map(|c| Mutex::new(LatencyModel::new(c)));
+        Self {
+            config,
+            books,
+            latency,
+            state: Mutex::new(PaperState::default()),
+        }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &PaperTradingConfig {
+        &self.config
+    }
+
+    /// Request IDs of limit orders still working
+    pub fn open_orders(&self) -> Vec<String> {
+        self.state.lock().unwrap().resting.iter().map(|o| o.request_id.clone()).collect()
+    }
+
+    /// Match working limit orders against the current books
+    ///
+    /// Runs on every status check and cancel; callers can also run it on book
+    /// updates so resting orders fill without being polled. Returns the number
+    /// of orders that received fills.
+    pub fn match_resting(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        let now = Utc::now();
+        let max_age = Duration::seconds(self.config.max_resting_secs);
+        let mut filled = 0;
+        let mut working = Vec::new();
+
+        for mut order in std::mem::take(&mut state.resting) {
+            let Some(mut result) = state.results.remove(&order.request_id) else { continue };
+
+            let fills = self.take_liquidity(&mut state, &order);
+            if !fills.is_empty() {
+                filled += 1;
+            }
+            self.apply_fills(&mut result, &mut order, &fills, true);
+
+            if order.remaining > f64::EPSILON {
+                if self.config.max_resting_secs > 0 && now - order.sent_at > max_age {
+                    result.status = ExecutionStatus::Cancelled;
+                    result.error_message = Some("Resting order expired".to_string());
+                    result.timestamp = now;
+                } else {
+                    working.push(order);
+                }
+            }
+            state.results.insert(result.request_id.clone(), result);
+        }
+
+        state.resting = working;
+        filled
+    }
+
+    /// Sleep for a sampled order latency
+    async fn delay(&self) {
+        let Some(model) = &self.latency else { return };
+        let latency_ms = model.lock().unwrap().sample(&self.config.venue).total_ms();
+        time::sleep(StdDuration::from_micros((latency_ms * 1_000.0) as u64)).await;
+    }
+
+    /// Walk the opposite side of the book for `order`, returning (quantity, level price) fills
+    fn take_liquidity(&self, state: &mut PaperState, order: &PaperOrder) -> Vec<(f64, f64)> {
+        let Some((bids, asks)) = self.books.get_snapshot(&order.symbol, self.config.depth) else {
+            return Vec::new();
+        };
+        let update_id = self.books.get_order_book(&order.symbol).read().unwrap().get_last_update_id();
+
+        let consumed = state.consumed.entry(order.symbol.clone()).or_default();
+        if consumed.update_id != update_id {
+            consumed.update_id = update_id;
+            consumed.levels.clear();
+        }
+
+        let levels = match order.side {
+            OrderSide::Bid => asks,
+            OrderSide::Ask => bids,
+        };
+        let participation = self.config.max_level_participation.clamp(0.0, 1.0);
+        let mut remaining = order.remaining;
+        let mut fills = Vec::new();
+
+        for level in levels {
+            let crosses = match (order.limit, order.side) {
+                (None, _) => true,
+                (Some(limit), OrderSide::Bid) => level.price <= limit,
+                (Some(limit), OrderSide::Ask) => level.price >= limit,
+            };
+            if !crosses || remaining <= f64::EPSILON {
+                break;
+            }
+
+            let taken = consumed.levels.entry(level.price.to_bits()).or_insert(0.0);
+            let quantity = (level.size * participation - *taken).max(0.0).min(remaining);
+            if quantity > 0.0 {
+                *taken += quantity;
+                remaining -= quantity;
+                fills.push((quantity, level.price));
+            }
+        }
+        fills
+    }
+
+    /// Add fills to an order's result; resting fills trade at the order's limit
+    fn apply_fills(&self, result: &mut ExecutionResult, order: &mut PaperOrder, fills: &[(f64, f64)], maker: bool) {
+        if fills.is_empty() {
+            return;
+        }
+        let fee_bps = if maker { self.config.maker_fee_bps } else { self.config.taker_fee_bps };
+        let mut fees = result.fees.unwrap_or(0.0);
+
+        for &(quantity, level_price) in fills {
+            let price = if maker { order.limit.unwrap_or(level_price) } else { level_price };
+            let filled = result.executed_quantity.unwrap_or(0.0);
+            let total = filled + quantity;
+            result.average_price = Some((result.average_price.unwrap_or(0.0) * filled + price * quantity) / total);
+            result.executed_quantity = Some(total);
+            fees += quantity * price * fee_bps / 10_000.0;
+            order.remaining = (order.remaining - quantity).max(0.0);
+        }
+
+        let fee_type = match &result.fee_info {
+            Some(info) if info.fee_type != if maker { "maker" } else { "taker" } => "mixed",
+            _ if maker => "maker",
+            _ => "taker",
+        };
+        let executed = result.executed_quantity.unwrap_or(0.0);
+        let average_price = result.average_price.unwrap_or(0.0);
+        let notional = executed * average_price;
+        let percentage = if notional > 0.0 { fees / notional * 100.0 } else { 0.0 };
+        result.fee_info = Some(FeeInfo::new(fees, "quote", percentage, fee_type));
+        result.fees = Some(fees);
+        result.fee_currency = Some("quote".to_string());
+
+        if let Some(reference) = order.reference_price.filter(|p| *p > 0.0) {
+            let sign = if order.side == OrderSide::Bid { 1.0 } else { -1.0 };
+            let slippage_bps = (average_price - reference) / reference * 10_000.0 * sign;
+            result.additional_data.insert("slippage_bps".to_string(), serde_json::json!(slippage_bps));
+        }
+
+        let now = Utc::now();
+        result.status = if order.remaining <= f64::EPSILON {
+            ExecutionStatus::Completed
+        } else {
+            ExecutionStatus::PartiallyFilled
+        };
+        result.timestamp = now;
+        result.execution_time_ms = (now - order.sent_at).num_milliseconds().max(0) as u64;
+    }
+}
+
+#[async_trait]
+impl ExecutionProvider for OrderBookPaperProvider {
+    async fn execute(&self, request: ExecutionRequest) -> Result<ExecutionResult, ExecutionError> {
+        let signal = &request.signal;
+        let side = match signal_side(&signal.action, signal.direction) {
+            Some(1) => OrderSide::Bid,
+            Some(_) => OrderSide::Ask,
+            None => {
+                return Err(ExecutionError::ValidationError(format!(
+                    "Signal {} ({:?} {:?}) is not an order", signal.id, signal.action, signal.direction
+                )))
+            }
+        };
+        let quantity = signal.quantity.filter(|q| *q > 0.0).ok_or_else(|| {
+            ExecutionError::ValidationError(format!("Signal {} has no quantity", signal.id))
+        })?;
+
+        let sent_at = Utc::now();
+        let reference_price = self.books.get_mid_price(&signal.symbol);
+        if reference_price.is_none() {
+            let result = ExecutionResult::failure(
+                request.id.clone(),
+                signal.id.clone(),
+                ExecutionStatus::Rejected,
+                format!("No two-sided order book for {}", signal.symbol),
+                None,
+            );
+            self.state.lock().unwrap().results.insert(request.id.clone(), result.clone());
+            return Ok(result);
+        }
+
+        // The book keeps moving while the order is in flight
+        self.delay().await;
+
+        let mut order = PaperOrder {
+            request_id: request.id.clone(),
+            symbol: signal.symbol.clone(),
+            side,
+            remaining: quantity,
+            limit: signal.price,
+            sent_at,
+            reference_price,
+        };
+        let mut result = ExecutionResult::success(
+            request.id.clone(),
+            signal.id.clone(),
+            Some(format!("paper-{}", request.id)),
+            0.0,
+            0.0,
+        );
+        result.status = ExecutionStatus::InProgress;
+        result.executed_quantity = None;
+        result.average_price = None;
+        result.additional_data.insert("venue".to_string(), serde_json::json!(self.config.venue));
+
+        let mut state = self.state.lock().unwrap();
+        let fills = self.take_liquidity(&mut state, &order);
+        self.apply_fills(&mut result, &mut order, &fills, false);
+
+        if order.remaining > f64::EPSILON {
+            if order.limit.is_some() {
+                state.resting.push(order);
+            } else if fills.is_empty() {
+                result.status = ExecutionStatus::Rejected;
+                result.error_message = Some(format!("No liquidity for {}", signal.symbol));
+            } else {
+                // Market orders do not rest; whatever the book could not fill is cancelled
+                debug!("Paper order {} cancelled {} unfilled", request.id, order.remaining);
+                result.additional_data.insert("cancelled_quantity".to_string(), serde_json::json!(order.remaining));
+            }
+        }
+
+        state.results.insert(request.id.clone(), result.clone());
+        Ok(result)
+    }
+
+    async fn cancel(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+        // Fills that happened before the cancel arrives still count
+        self.match_resting();
+
+        let mut state = self.state.lock().unwrap();
+        let Some(index) = state.resting.iter().position(|o| o.request_id == request_id) else {
+            return match state.results.get(request_id) {
+                Some(result) => Err(ExecutionError::OrderRejected(
+                    format!("Cannot cancel order in state: {:?}", result.status)
+                )),
+                None => Err(ExecutionError::OrderRejected(format!("Order {} not found", request_id))),
+            };
+        };
+        state.resting.remove(index);
+
+        let result = state.results.get_mut(request_id)
+            .ok_or_else(|| ExecutionError::Internal(format!("Order {} has no result", request_id)))?;
+        result.status = ExecutionStatus::Cancelled;
+        result.timestamp = Utc::now();
+        Ok(result.clone())
+    }
+
+    async fn get_status(&self, request_id: &str) -> Result<ExecutionResult, ExecutionError> {
+        self.match_resting();
+
+        self.state.lock().unwrap().results
+            .get(request_id)
+            .cloned()
+            .ok_or_else(|| ExecutionError::OrderRejected(format!("Order {} not found", request_id)))
+    }
+
+    fn supports_mode(&self, mode: ExecutionMode) -> bool {
+        mode == ExecutionMode::Paper
+    }
+
+    fn name(&self) -> &str {
+        "OrderBookPaperProvider"
+    }
+}
+
+/// Create a paper trading provider over shared order books
+pub fn create_order_book_paper_provider(
+    config: PaperTradingConfig,
+    books: Arc<OrderBookManager>,
+) -> Arc<OrderBookPaperProvider> {
+    Arc::new(OrderBookPaperProvider::new(config, books))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{Signal, SignalAction};
+
+    fn books() -> Arc<OrderBookManager> {
+        let books = Arc::new(OrderBookManager::new());
+        books.process_updates("BTC/USDT", vec![
+            (99.0, 3.0, OrderSide::Bid, 1),
+            (100.0, 2.0, OrderSide::Ask, 2),
+            (101.0, 4.0, OrderSide::Ask, 3),
+        ]);
+        books
+    }
+
+    fn buy(quantity: f64, limit: Option<f64>) -> ExecutionRequest {
+        let mut signal = Signal::new("paper_test".to_string(), "BTC/USDT".to_string(), SignalAction::Enter)
+            .with_quantity(quantity);
+        signal.price = limit;
+        ExecutionRequest::new(signal, ExecutionMode::Paper)
+    }
+
+    #[tokio::test]
+    async fn test_fills_against_book_depth() {
+        let books = books();
+        let config = PaperTradingConfig { latency: None, ..PaperTradingConfig::default() };
+        let provider = OrderBookPaperProvider::new(config, books.clone());
+
+        // Half of each level is available: 1 @ 100 and 1.5 @ 101
+        let result = provider.execute(buy(2.5, None)).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::Completed);
+        assert!((result.average_price.unwrap() - 100.6).abs() < 1e-9);
+        assert!((result.fees.unwrap() - 251.5 * 10.0 / 10_000.0).abs() < 1e-9);
+
+        // Only 0.5 @ 101 is left until the book updates; the rest is cancelled
+        let result = provider.execute(buy(5.0, None)).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::PartiallyFilled);
+        assert_eq!(result.executed_quantity, Some(0.5));
+
+        // A bid below the ask rests, then fills at its limit once the book trades through it
+        let request = buy(1.0, Some(98.0));
+        let result = provider.execute(request.clone()).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::InProgress);
+        assert_eq!(provider.open_orders(), vec![request.id.clone()]);
+
+        books.process_update("BTC/USDT", 97.5, 4.0, OrderSide::Ask, 4);
+        let result = provider.get_status(&request.id).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::Completed);
+        assert_eq!(result.average_price, Some(98.0));
+        assert_eq!(result.fee_info.unwrap().fee_type, "maker");
+        assert!(provider.open_orders().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_service_status_reaches_resting_orders() {
+        let books = books();
+        let config = PaperTradingConfig { latency: None, ..PaperTradingConfig::default() };
+        let provider = create_order_book_paper_provider(config, books.clone());
+        let service = crate::execution::ExecutionService::new(provider.clone(), provider, None);
+
+        let request = buy(1.0, Some(98.0));
+        assert_eq!(service.execute(request.clone()).await.unwrap().status, ExecutionStatus::InProgress);
+
+        // The cached result is still working, so the status check goes to the provider
+        books.process_update("BTC/USDT", 97.5, 4.0, OrderSide::Ask, 4);
+        let result = service.get_execution_status(&request.id).await.unwrap();
+        assert_eq!(result.status, ExecutionStatus::Completed);
+        assert_eq!(service.get_recent_executions(Some(ExecutionStatus::Completed), None).len(), 1);
+    }
+}
@@ -4,6 +4,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::netting::{self, NettingGroup};
+
 /// Position manager error types
 #[derive(Error, Debug)]
 pub enum PositionError {
@@ -230,6 +232,15 @@ impl AgentPosition {
         total_exposure
     }
 
+    /// Calculate total exposure with correlated symbols netted within their groups
+    pub fn calculate_netted_exposure(&self, current_prices: &HashMap<String, f64>, groups: &[NettingGroup]) -> f64 {
+        let legs = self.positions.iter().filter_map(|(symbol, position)| {
+            current_prices.get(symbol).map(|price| (symbol.as_str(), position.net_size * price))
+        });
+        
+        netting::netted_total(groups, legs)
+    }
+
     /// Calculate exposure for a specific symbol
     pub fn calculate_symbol_exposure(&self, symbol: &str, current_price: f64) -> f64 {
         if let Some(position) = self.positions.get(symbol) {
@@ -267,6 +278,32 @@ impl AgentPosition {
     }
 }
 
+/// Position change notification emitted after every update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionChangeEvent {
+    pub agent_id: String,
+    pub symbol: String,
+    pub order_id: String,
+    pub is_fill: bool,
+    pub net_size: f64,
+    pub average_price: f64,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub cash_balance: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Realized and unrealized P&L summary
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PnlSummary {
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub total_pnl: f64,
+}
+
+/// Callback invoked on position changes
+pub type PositionListener = Arc<dyn Fn(&PositionChangeEvent) + Send + Sync>;
+
 /// Position manager configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionManagerConfig {
@@ -274,6 +311,10 @@ pub struct PositionManagerConfig {
     pub default_max_position: f64,
     pub max_total_exposure: f64,
     pub initial_cash_balance: f64,
+    /// Correlated symbols netted for limit checks; members should share an underlying
+    /// since symbol limits are in base units
+    #[serde(default)]
+    pub netting_groups: Vec<NettingGroup>,
 }
 
 impl Default for PositionManagerConfig {
@@ -283,6 +324,7 @@ impl Default for PositionManagerConfig {
             default_max_position: 10.0,
             max_total_exposure: 100.0,
             initial_cash_balance: 1000.0,
+            netting_groups: Vec::new(),
         }
     }
 }
@@ -292,6 +334,7 @@ pub struct PositionManager {
     positions: RwLock<HashMap<String, AgentPosition>>,
     current_prices: RwLock<HashMap<String, f64>>,
     config: RwLock<PositionManagerConfig>,
+    listeners: RwLock<Vec<PositionListener>>,
 }
 
 impl PositionManager {
@@ -301,6 +344,7 @@ impl PositionManager {
             positions: RwLock::new(HashMap::new()),
             current_prices: RwLock::new(HashMap::new()),
             config: RwLock::new(PositionManagerConfig::default()),
+            listeners: RwLock::new(Vec::new()),
         })
     }
 
@@ -310,6 +354,7 @@ impl PositionManager {
             positions: RwLock::new(HashMap::new()),
             current_prices: RwLock::new(HashMap::new()),
             config: RwLock::new(config),
+            listeners: RwLock::new(Vec::new()),
         })
     }
 
@@ -359,7 +404,104 @@ impl PositionManager {
                 AgentPosition::new(agent_id, config.initial_cash_balance)
             });
         
-        agent_position.update_position(order)
+        agent_position.update_position(order)?;
+        
+        let event = agent_position.positions.get(&order.symbol).map(|position| {
+            let mut position = position.clone();
+            position.update_unrealized_pnl(order.price);
+            PositionChangeEvent {
+                agent_id: agent_id.to_string(),
+                symbol: order.symbol.clone(),
+                order_id: order.order_id.clone(),
+                is_fill: order.is_fill,
+                net_size: position.net_size,
+                average_price: position.average_price,
+                realized_pnl: position.realized_pnl,
+                unrealized_pnl: position.unrealized_pnl,
+                cash_balance: agent_position.cash_balance,
+                timestamp: Utc::now(),
+            }
+        });
+        drop(positions);
+        
+        if let Some(event) = event {
+            self.notify_listeners(&event);
+        }
+        
+        Ok(())
+    }
+
+    /// Subscribe to position change events
+    pub fn subscribe(&self, listener: PositionListener) -> PositionResult<()> {
+        let mut listeners = self.listeners.write().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        listeners.push(listener);
+        Ok(())
+    }
+
+    /// Notify all listeners of a position change
+    fn notify_listeners(&self, event: &PositionChangeEvent) {
+        if let Ok(listeners) = self.listeners.read() {
+            for listener in listeners.iter() {
+                listener(event);
+            }
+        }
+    }
+
+    /// Calculate exposure for an agent in a single symbol
+    pub fn calculate_symbol_exposure(&self, agent_id: &str, symbol: &str) -> PositionResult<f64> {
+        let positions = self.positions.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        let agent_position = positions.get(agent_id).ok_or_else(|| PositionError::PositionNotFound(agent_id.to_string()))?;
+        
+        let prices = self.current_prices.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        let price = prices.get(symbol).cloned().unwrap_or(0.0);
+        
+        Ok(agent_position.calculate_symbol_exposure(symbol, price))
+    }
+
+    /// Get P&L for an agent across all symbols
+    pub fn get_pnl(&self, agent_id: &str) -> PositionResult<PnlSummary> {
+        let positions = self.positions.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        let agent_position = positions.get(agent_id).ok_or_else(|| PositionError::PositionNotFound(agent_id.to_string()))?;
+        
+        let prices = self.current_prices.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        
+        let mut summary = PnlSummary::default();
+        for (symbol, position) in &agent_position.positions {
+            let pnl = Self::position_pnl(position, prices.get(symbol).cloned());
+            summary.realized_pnl += pnl.realized_pnl;
+            summary.unrealized_pnl += pnl.unrealized_pnl;
+        }
+        summary.total_pnl = summary.realized_pnl + summary.unrealized_pnl;
+        
+        Ok(summary)
+    }
+
+    /// Get P&L for an agent in a single symbol
+    pub fn get_symbol_pnl(&self, agent_id: &str, symbol: &str) -> PositionResult<PnlSummary> {
+        let position = self.get_symbol_position(agent_id, symbol)?;
+        let prices = self.current_prices.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        
+        Ok(Self::position_pnl(&position, prices.get(symbol).cloned()))
+    }
+
+    /// Get IDs of all agents with positions
+    pub fn get_agent_ids(&self) -> PositionResult<Vec<String>> {
+        let positions = self.positions.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        Ok(positions.keys().cloned().collect())
+    }
+
+    /// P&L for a single position marked at the given price
+    fn position_pnl(position: &SymbolPosition, price: Option<f64>) -> PnlSummary {
+        let mut position = position.clone();
+        if let Some(price) = price {
+            position.update_unrealized_pnl(price);
+        }
+        
+        PnlSummary {
+            realized_pnl: position.realized_pnl,
+            unrealized_pnl: position.unrealized_pnl,
+            total_pnl: position.realized_pnl + position.unrealized_pnl,
+        }
     }
 
     /// Calculate exposure for an agent
@@ -389,8 +531,21 @@ impl PositionManager {
         // Get symbol-specific limit or default
         let symbol_limit = config.max_position_per_symbol.get(symbol).cloned().unwrap_or(config.default_max_position);
         
-        // Check if new position would exceed symbol limit
-        let would_exceed_symbol_limit = agent_position.check_limits(symbol, side, size, price, symbol_limit);
+        // Check if new position would exceed symbol limit; grouped symbols are limited on netted size
+        let would_exceed_symbol_limit = match netting::find_group(&config.netting_groups, symbol) {
+            Some(group) => {
+                let signed_size = match side {
+                    Side::Buy => size,
+                    Side::Sell => -size,
+                };
+                let current = agent_position.positions.get(symbol).map_or(0.0, |p| p.net_size);
+                let others = agent_position.positions.iter()
+                    .filter(|(s, _)| s.as_str() != symbol)
+                    .map(|(s, p)| (s.as_str(), p.net_size));
+                group.net(others.chain(std::iter::once((symbol, current + signed_size)))).net > symbol_limit
+            }
+            None => agent_position.check_limits(symbol, side, size, price, symbol_limit),
+        };
         
         // Check if new position would exceed total exposure limit
         let new_exposure = {
@@ -408,12 +563,12 @@ impl PositionManager {
                 strategy_id: None,
             };
             
-            // Calculate hypothetical exposure
+            // Calculate hypothetical exposure, netting correlated symbols
             if new_agent_position.update_position(&order).is_ok() {
-                new_agent_position.calculate_exposure(&prices)
+                new_agent_position.calculate_netted_exposure(&prices, &config.netting_groups)
             } else {
                 // If update fails, use current exposure
-                agent_position.calculate_exposure(&prices)
+                agent_position.calculate_netted_exposure(&prices, &config.netting_groups)
             }
         };
         
@@ -432,6 +587,12 @@ impl PositionManager {
         Ok(())
     }
 
+    /// Get the last known market price for a symbol
+    pub fn get_current_price(&self, symbol: &str) -> PositionResult<Option<f64>> {
+        let prices = self.current_prices.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        Ok(prices.get(symbol).copied())
+    }
+
     /// Get position for an agent
     pub fn get_position(&self, agent_id: &str) -> PositionResult<AgentPosition> {
         let positions = self.positions.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
@@ -460,6 +621,37 @@ impl PositionManager {
         let config = self.config.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
         Ok(config.clone())
     }
+
+    /// Set the position limit for one symbol; `None` falls back to the default limit
+    pub fn set_symbol_limit(&self, symbol: &str, limit: Option<f64>) -> PositionResult<()> {
+        let mut config = self.config.write().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        match limit {
+            Some(limit) => config.max_position_per_symbol.insert(symbol.to_string(), limit),
+            None => config.max_position_per_symbol.remove(symbol),
+        };
+        
+        Ok(())
+    }
+
+    /// Replace agents' positions with restored state, e.g. after failover.
+    ///
+    /// Listeners are not notified: the state already came from their journal.
+    pub fn restore(&self, agents: Vec<AgentPosition>) -> PositionResult<()> {
+        let mut positions = self.positions.write().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        for agent in agents {
+            positions.insert(agent.agent_id.clone(), agent);
+        }
+        
+        Ok(())
+    }
+
+    /// Whether any agent holds a position or open order in a symbol
+    pub fn has_exposure(&self, symbol: &str) -> PositionResult<bool> {
+        let positions = self.positions.read().map_err(|_| PositionError::InvalidUpdate("Poisoned lock".to_string()))?;
+        Ok(positions.values()
+            .filter_map(|agent| agent.positions.get(symbol))
+            .any(|position| position.net_size != 0.0 || !position.open_orders.is_empty()))
+    }
 }
 
 /// Create a new position manager with default configuration
@@ -511,6 +703,7 @@ mod tests {
             default_max_position: 1.0,
             max_total_exposure: 100000.0,
             initial_cash_balance: 100000.0,
+            ..Default::default()
         };
         
         let position_manager = create_position_manager_with_config(config);
@@ -538,4 +731,77 @@ mod tests {
         let exceeds = position_manager.check_limits("agent1", "BTC-USD", Side::Buy, 1.5).unwrap();
         assert!(exceeds);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_netted_limits() {
+        let config = PositionManagerConfig {
+            default_max_position: 2.0,
+            max_total_exposure: 150000.0,
+            netting_groups: vec![NettingGroup::new("BTC", &["BTC-USD", "BTC-PERP"], 1.0)],
+            ..Default::default()
+        };
+        let position_manager = create_position_manager_with_config(config);
+        
+        let fill = |symbol: &str, side| OrderOrFill {
+            symbol: symbol.to_string(),
+            side,
+            size: 1.5,
+            price: 50000.0,
+            timestamp: Utc::now(),
+            order_id: format!("{}-fill", symbol),
+            fill_id: None,
+            is_fill: true,
+            venue: None,
+            strategy_id: None,
+        };
+        position_manager.update_position("agent1", &fill("BTC-USD", Side::Buy)).unwrap();
+        position_manager.update_position("agent1", &fill("BTC-PERP", Side::Sell)).unwrap();
+        
+        // Gross BTC-USD would be 2.5, but the perp short nets the group to 1.0
+        let exceeds = position_manager.check_limits("agent1", "BTC-USD", Side::Buy, 1.0).unwrap();
+        assert!(!exceeds);
+        
+        // Gross exposure is 150k; netted it is zero
+        let agent = position_manager.get_position("agent1").unwrap();
+        let prices = HashMap::from([("BTC-USD".to_string(), 50000.0), ("BTC-PERP".to_string(), 50000.0)]);
+        assert_eq!(agent.calculate_exposure(&prices), 150000.0);
+        assert_eq!(agent.calculate_netted_exposure(&prices, &position_manager.get_config().unwrap().netting_groups), 0.0);
+        
+        // Net size past the symbol limit is still rejected
+        let exceeds = position_manager.check_limits("agent1", "BTC-USD", Side::Buy, 2.5).unwrap();
+        assert!(exceeds);
+    }
+
+    #[test]
+    fn test_position_events_and_pnl() {
+        let position_manager = create_position_manager();
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = received.clone();
+        position_manager.subscribe(Arc::new(move |event: &PositionChangeEvent| {
+            sink.lock().unwrap().push(event.clone());
+        })).unwrap();
+        
+        let order = OrderOrFill {
+            symbol: "ETH-USD".to_string(),
+            side: Side::Buy,
+            size: 2.0,
+            price: 3000.0,
+            timestamp: Utc::now(),
+            order_id: "order1".to_string(),
+            fill_id: None,
+            is_fill: true,
+            venue: None,
+            strategy_id: None,
+        };
+        position_manager.update_position("agent1", &order).unwrap();
+        position_manager.update_price("ETH-USD", 3100.0).unwrap();
+        
+        let events = received.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].net_size, 2.0);
+        
+        let pnl = position_manager.get_pnl("agent1").unwrap();
+        assert!(pnl.unrealized_pnl > 0.0);
+        assert_eq!(pnl.total_pnl, pnl.realized_pnl + pnl.unrealized_pnl);
+    }
+}
@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Percent-of-volume execution
+//!
+//! Trades alongside the market: child orders are only released while our
+//! fills are below the configured share of the volume the market data
+//! processor has seen since the order started. When volume is too thin to
+//! finish before the deadline, the remainder is worked as an evenly spaced
+//! TWAP over the last part of the window instead.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{debug, info, warn};
+
+use crate::execution::ExecutionResult;
+use crate::execution_schedule::ScheduleRandomizer;
+use crate::execution_strategy::{
+    AlgoModification, ExecutionAlgorithm, ExecutionStrategy, ExecutionStrategyDetails, ExecutionStrategyError,
+    POVConfig, TWAPConfig,
+};
+use crate::market_data::MarketDataProcessor;
+use crate::order_lineage::child_order;
+use crate::order_router::{Order, SmartOrderRouter};
+
+/// Order parameter marking a child sent by the TWAP fallback
+pub const POV_FALLBACK_PARAM: &str = "povFallback";
+
+/// Remaining quantity below which a parent counts as filled
+const QUANTITY_EPSILON: f64 = 1e-9;
+
+/// Operator controls and overrides for a parent being worked
+#[derive(Debug, Clone, Default)]
+struct PovControl {
+    paused: bool,
+    cancelled: bool,
+    participation_rate: Option<f64>,
+    deadline: Option<DateTime<Utc>>,
+    limit_price: Option<f64>,
+}
+
+/// Progress of one parent, shared by the POV and fallback phases
+struct PovRun {
+    order: Order,
+    remaining: f64,
+    children: u32,
+    failures: u32,
+}
+
+/// Executes orders at a fixed share of market volume
+pub struct POVExecutor {
+    /// Configuration
+    config: POVConfig,
+    /// Router child orders are sent through
+    router: Arc<SmartOrderRouter>,
+    /// Source of traded market volume
+    market_data: Arc<MarketDataProcessor>,
+    /// Controls of parents being worked, by parent order ID
+    controls: Mutex<HashMap<String, PovControl>>,
+}
+
+impl POVExecutor {
+    /// Create a new POV executor
+    pub fn new(config: POVConfig, router: Arc<SmartOrderRouter>, market_data: Arc<MarketDataProcessor>) -> Self {
+        Self {
+            config,
+            router,
+            market_data,
+            controls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Configuration in use
+    pub fn config(&self) -> &POVConfig {
+        &self.config
+    }
+
+    /// Size of the next child given the market volume since start and what we have filled
+    ///
+    /// Returns zero while the participation deficit is below the minimum clip,
+    /// unless the deficit covers everything that is left.
+    pub fn next_clip(&self, rate: f64, market_volume: f64, filled: f64, remaining: f64) -> f64 {
+        let deficit = rate.clamp(0.0, 1.0) * market_volume - filled;
+        if deficit <= QUANTITY_EPSILON || remaining <= QUANTITY_EPSILON {
+            return 0.0;
+        }
+        if deficit >= remaining {
+            return remaining.min(self.config.max_clip_quantity);
+        }
+        if deficit < self.config.min_clip_quantity {
+            return 0.0;
+        }
+        deficit.min(self.config.max_clip_quantity)
+    }
+
+    /// TWAP used to finish a parent when volume is too thin
+    pub fn fallback_twap(&self) -> TWAPConfig {
+        TWAPConfig {
+            slices: self.config.fallback_slices.max(1),
+            interval_ms: self.config.fallback_interval_ms,
+            max_interval_deviation_ms: 0,
+            randomize_sizes: false,
+            ..TWAPConfig::default()
+        }
+    }
+
+    fn control(&self, order_id: &str) -> PovControl {
+        self.controls.lock().unwrap().get(order_id).cloned().unwrap_or_default()
+    }
+
+    fn update_control(&self, order_id: &str, update: impl FnOnce(&mut PovControl)) -> Result<(), ExecutionStrategyError> {
+        let mut controls = self.controls.lock().unwrap();
+        let control = controls.get_mut(order_id).ok_or_else(|| {
+            ExecutionStrategyError::ExecutionFailed(format!("No POV execution found for order ID: {}", order_id))
+        })?;
+        update(control);
+        Ok(())
+    }
+
+    /// Send one child; router errors count towards the consecutive failure limit
+    async fn send_child(
+        &self,
+        run: &mut PovRun,
+        quantity: f64,
+        fallback: bool,
+        control: &PovControl,
+        callback: &Arc<dyn Fn(ExecutionResult) + Send + Sync>,
+    ) -> Result<(), ExecutionStrategyError> {
+        let mut child = child_order(&run.order, run.children, quantity);
+        if fallback {
+            child.additional_params.insert(POV_FALLBACK_PARAM.to_string(), serde_json::Value::Bool(true));
+        }
+        if let Some(limit_price) = control.limit_price {
+            child.price = limit_price;
+        }
+        run.children += 1;
+
+        match self.router.execute_order(child).await {
+            Ok(result) => {
+                run.failures = 0;
+                let filled = result.executed_quantity.unwrap_or(0.0).abs().min(run.remaining);
+                run.remaining -= filled;
+                debug!("POV {} child {} filled {} of {}, {} remaining", run.order.id, run.children, filled, quantity, run.remaining);
+                callback(result);
+                Ok(())
+            }
+            Err(e) => {
+                run.failures += 1;
+                warn!("POV {} child {} failed ({} in a row): {}", run.order.id, run.children, run.failures, e);
+                if run.failures >= self.config.max_consecutive_failures.max(1) {
+                    return Err(ExecutionStrategyError::ExecutionFailed(format!(
+                        "POV {} abandoned after {} consecutive child failures: {}", run.order.id, run.failures, e
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Work the remainder as a TWAP that ends at the deadline
+    async fn run_fallback(
+        &self,
+        run: &mut PovRun,
+        deadline: DateTime<Utc>,
+        callback: &Arc<dyn Fn(ExecutionResult) + Send + Sync>,
+    ) -> Result<(), ExecutionStrategyError> {
+        let twap = self.fallback_twap();
+        let start = (deadline - self.config.fallback_window()).max(Utc::now());
+        info!("POV {} falling back to TWAP for {} remaining", run.order.id, run.remaining);
+
+        let schedule = ScheduleRandomizer::baseline(&twap, start, run.remaining);
+        let last = schedule.len().saturating_sub(1);
+        for (i, slice) in schedule.iter().enumerate() {
+            if let Ok(wait) = (slice.at - Utc::now()).to_std() {
+                tokio::time::sleep(wait).await;
+            }
+            let control = self.control(&run.order.id);
+            if control.cancelled || run.remaining <= QUANTITY_EPSILON {
+                break;
+            }
+            // Earlier shortfalls roll into the last slice
+            let quantity = if i == last { run.remaining } else { slice.quantity.min(run.remaining) };
+            self.send_child(run, quantity, true, &control, callback).await?;
+        }
+        Ok(())
+    }
+
+    async fn work(
+        &self,
+        run: &mut PovRun,
+        callback: &Arc<dyn Fn(ExecutionResult) + Send + Sync>,
+    ) -> Result<(), ExecutionStrategyError> {
+        let started = Utc::now();
+        let poll = Duration::from_millis(self.config.poll_interval_ms.max(1));
+        let total = run.order.amount;
+
+        loop {
+            let control = self.control(&run.order.id);
+            if control.cancelled {
+                info!("POV {} cancelled with {} remaining", run.order.id, run.remaining);
+                return Ok(());
+            }
+            if run.remaining <= QUANTITY_EPSILON {
+                return Ok(());
+            }
+
+            let deadline = control.deadline
+                .unwrap_or_else(|| started + chrono::Duration::milliseconds(self.config.deadline_ms as i64));
+            if Utc::now() >= deadline - self.config.fallback_window() {
+                return self.run_fallback(run, deadline, callback).await;
+            }
+
+            if !control.paused {
+                let rate = control.participation_rate.unwrap_or(self.config.participation_rate);
+                let market_volume = self.market_data.traded_volume_since(&run.order.symbol, started);
+                let clip = self.next_clip(rate, market_volume, total - run.remaining, run.remaining);
+                if clip > 0.0 {
+                    self.send_child(run, clip, false, &control, callback).await?;
+                }
+            }
+            // At most one child per poll keeps submissions paced with the tape
+            tokio::time::sleep(poll).await;
+        }
+    }
+}
+
+#[async_trait]
+impl ExecutionStrategy for POVExecutor {
+    async fn execute(
+        &self,
+        order: Order,
+        callback: Arc<dyn Fn(ExecutionResult) + Send + Sync>,
+    ) -> Result<(), ExecutionStrategyError> {
+        if !(order.amount > 0.0 && order.amount.is_finite()) {
+            return Err(ExecutionStrategyError::InvalidParameters(format!(
+                "POV order {} has amount {}", order.id, order.amount
+            )));
+        }
+
+        self.controls.lock().unwrap().insert(order.id.clone(), PovControl::default());
+        let mut run = PovRun { remaining: order.amount, order, children: 0, failures: 0 };
+        let outcome = self.work(&mut run, &callback).await;
+
+        let cancelled = self.controls.lock().unwrap().remove(&run.order.id).is_some_and(|control| control.cancelled);
+        outcome?;
+
+        let filled = run.order.amount - run.remaining;
+        if !cancelled && filled < run.order.amount * self.config.min_execution_pct {
+            return Err(ExecutionStrategyError::ExecutionFailed(format!(
+                "POV {} filled {} of {} after {} children", run.order.id, filled, run.order.amount, run.children
+            )));
+        }
+        Ok(())
+    }
+
+    async fn estimate_impact(&self, _order: &Order) -> Result<f64, ExecutionStrategyError> {
+        // Impact grows with the square root of participation
+        Ok(0.002 * self.config.participation_rate.clamp(0.0, 1.0).sqrt())
+    }
+
+    async fn get_cost_estimate(&self, order: &Order) -> Result<f64, ExecutionStrategyError> {
+        Ok(order.amount * self.estimate_impact(order).await?)
+    }
+
+    fn get_details(&self) -> ExecutionStrategyDetails {
+        let parameters = match serde_json::to_value(&self.config) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().collect(),
+            _ => HashMap::new(),
+        };
+        ExecutionStrategyDetails {
+            strategy_type: ExecutionAlgorithm::POV,
+            name: "POV".to_string(),
+            description: "Participates at a fixed share of market volume, finishing with TWAP near the deadline"
+                .to_string(),
+            parameters,
+        }
+    }
+
+    async fn cancel(&self) -> Result<(), ExecutionStrategyError> {
+        for control in self.controls.lock().unwrap().values_mut() {
+            control.cancelled = true;
+        }
+        Ok(())
+    }
+
+    async fn pause(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+        self.update_control(order_id, |control| control.paused = true)
+    }
+
+    async fn resume(&self, order_id: &str) -> Result<(), ExecutionStrategyError> {
+        self.update_control(order_id, |control| control.paused = false)
+    }
+
+    async fn modify(&self, order_id: &str, modification: &AlgoModification) -> Result<(), ExecutionStrategyError> {
+        self.update_control(order_id, |control| {
+            if let Some(rate) = modification.participation_cap {
+                control.participation_rate = Some(rate);
+            }
+            if let Some(end_time) = modification.end_time {
+                control.deadline = Some(end_time);
+            }
+            if let Some(limit_price) = modification.limit_price {
+                control.limit_price = Some(limit_price);
+            }
+        })
+    }
+}
+
+/// Create a POV executor
+pub fn create_pov_executor(
+    config: POVConfig,
+    router: Arc<SmartOrderRouter>,
+    market_data: Arc<MarketDataProcessor>,
+) -> Arc<POVExecutor> {
+    Arc::new(POVExecutor::new(config, router, market_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::market_data::{MarketDataProcessorConfig, MarketTick};
+
+    #[test]
+    fn test_clips_track_participation_and_volume() {
+        let config = POVConfig { min_clip_quantity: 2.0, max_clip_quantity: 10.0, ..POVConfig::default() };
+        let market_data = Arc::new(MarketDataProcessor::new(MarketDataProcessorConfig::default()));
+        let executor = POVExecutor::new(config, Arc::new(SmartOrderRouter::new()), market_data.clone());
+
+        let start = Utc::now();
+        for (offset, volume) in [(-5, 500.0), (1, 40.0), (2, 60.0)] {
+            market_data.process_tick(MarketTick {
+                symbol: "BTC/USD".to_string(),
+                timestamp: start + chrono::Duration::seconds(offset),
+                price: 50_000.0,
+                volume,
+                bid: None,
+                ask: None,
+                fields: HashMap::new(),
+            }).unwrap();
+        }
+
+        // Only volume after the start counts
+        let volume = market_data.traded_volume_since("BTC/USD", start);
+        assert_eq!(volume, 100.0);
+
+        // 10% of 100 is 10; 9 already filled leaves a deficit below the minimum clip
+        assert_eq!(executor.next_clip(0.1, volume, 0.0, 50.0), 10.0);
+        assert_eq!(executor.next_clip(0.1, volume, 9.0, 50.0), 0.0);
+        assert_eq!(executor.next_clip(0.1, volume, 12.0, 50.0), 0.0);
+        // Clips are capped, and a small remainder goes out once volume covers it
+        assert_eq!(executor.next_clip(0.5, volume, 0.0, 50.0), 10.0);
+        assert_eq!(executor.next_clip(0.1, volume, 9.0, 1.0), 1.0);
+
+        let twap = executor.fallback_twap();
+        let schedule = ScheduleRandomizer::baseline(&twap, start, 25.0);
+        assert_eq!(schedule.len(), 5);
+        assert_eq!(schedule.last().unwrap().at - start, executor.config().fallback_window() - chrono::Duration::minutes(1));
+    }
+}
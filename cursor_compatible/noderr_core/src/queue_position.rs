@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Queue position estimation for resting passive orders
+//!
+//! Venues do not tell us where our order sits in the queue at its price
+//! level, so it is inferred from the public feed. We join at the back of
+//! the visible size, trade prints at our price consume the queue ahead of
+//! us, and size cancelled from the level is attributed to the orders ahead
+//! of us in proportion to their share of the level. Combined with the
+//! recent rate of prints hitting our side this gives the probability of
+//! being filled within a horizon, which the execution strategies use to
+//! decide when a passive order is worth repricing.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+use crate::orderbook::OrderSide;
+
+/// Errors that can occur while tracking queue position
+#[derive(Debug, Error)]
+pub enum QueuePositionError {
+    #[error("Order {0} is not being tracked")]
+    UnknownOrder(String),
+
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+}
+
+/// Queue position estimator configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueuePositionConfig {
+    /// Window of trade prints used to estimate the arrival rate (seconds)
+    pub rate_window_secs: i64,
+    /// Maximum prints kept per symbol and side
+    pub max_prints: usize,
+    /// Sizes below this are treated as zero
+    pub size_epsilon: f64,
+}
+
+impl Default for QueuePositionConfig {
+    fn default() -> Self {
+        Self {
+            rate_window_secs: 300,
+            max_prints: 10_000,
+            size_epsilon: 1e-9,
+        }
+    }
+}
+
+/// Estimated queue state of one resting order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueEstimate {
+    /// Order ID
+    pub order_id: String,
+    /// Symbol
+    pub symbol: String,
+    /// Book side the order rests on
+    pub side: OrderSide,
+    /// Limit price
+    pub price: f64,
+    /// Estimated size ahead of us at the level
+    pub queue_ahead: f64,
+    /// Our unfilled size
+    pub remaining: f64,
+    /// Last visible size at the level, including ours
+    pub level_size: f64,
+    /// Prints per second hitting our side
+    pub trade_rate_per_sec: f64,
+    /// Average size of those prints
+    pub avg_trade_size: f64,
+    /// Expected seconds until fully filled at the current print rate
+    pub expected_fill_secs: Option<f64>,
+    /// When the order started resting
+    pub placed_at: DateTime<Utc>,
+}
+
+/// A resting order being tracked
+#[derive(Debug, Clone)]
+struct TrackedOrder {
+    symbol: String,
+    side: OrderSide,
+    price: f64,
+    remaining: f64,
+    queue_ahead: f64,
+    level_size: f64,
+    /// Traded volume at our level not yet reflected in a book update
+    unmatched_traded: f64,
+    placed_at: DateTime<Utc>,
+}
+
+/// Tracks the likely queue position of our resting orders
+pub struct QueuePositionEstimator {
+    /// Configuration
+    config: QueuePositionConfig,
+    /// Tracked orders by order ID
+    orders: RwLock<HashMap<String, TrackedOrder>>,
+    /// Recent (timestamp, size) prints by symbol and the book side they hit
+    prints: RwLock<HashMap<(String, OrderSide), VecDeque<(DateTime<Utc>, f64)>>>,
+}
+
+impl QueuePositionEstimator {
+    /// Create a new queue position estimator
+    pub fn new(config: QueuePositionConfig) -> Self {
+        Self {
+            config,
+            orders: RwLock::new(HashMap::new()),
+            prints: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Start tracking an order that has just joined the book
+    ///
+    /// `level_size_before` is the visible size at the price before our order
+    /// was added; we are assumed to be behind all of it.
+    pub async fn register_order(
+        &self,
+        order_id: &str,
+        symbol: &str,
+        side: OrderSide,
+        price: f64,
+        size: f64,
+        level_size_before: f64,
+    ) -> Result<(), QueuePositionError> {
+        if !(price > 0.0 && size > 0.0) {
+            return Err(QueuePositionError::InvalidOrder(format!(
+                "{} needs positive price and size, got {} @ {}", order_id, size, price
+            )));
+        }
+        let queue_ahead = level_size_before.max(0.0);
+        self.orders.write().await.insert(order_id.to_string(), TrackedOrder {
+            symbol: symbol.to_string(),
+            side,
+            price,
+            remaining: size,
+            queue_ahead,
+            level_size: queue_ahead + size,
+            unmatched_traded: 0.0,
+            placed_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    /// Stop tracking an order (filled, cancelled or replaced)
+    pub async fn remove_order(&self, order_id: &str) -> bool {
+        self.orders.write().await.remove(order_id).is_some()
+    }
+
+    /// Record a fill of our own order; fully filled orders stop being tracked
+    pub async fn on_fill(&self, order_id: &str, quantity: f64) -> Result<(), QueuePositionError> {
+        let mut orders = self.orders.write().await;
+        let order = orders.get_mut(order_id)
+            .ok_or_else(|| QueuePositionError::UnknownOrder(order_id.to_string()))?;
+
+        // Anything ahead of us must have traded before we could be filled
+        order.queue_ahead = 0.0;
+        order.remaining = (order.remaining - quantity).max(0.0);
+        if order.remaining <= self.config.size_epsilon {
+            orders.remove(order_id);
+        }
+        Ok(())
+    }
+
+    /// Apply a book update (new visible size at a level), in the same form
+    /// the order book manager consumes
+    pub async fn on_book_update(&self, symbol: &str, side: OrderSide, price: f64, size: f64) {
+        let mut orders = self.orders.write().await;
+        for order in orders.values_mut() {
+            if order.symbol != symbol || order.side != side || order.price != price {
+                continue;
+            }
+
+            let decrease = order.level_size - size;
+            if decrease > 0.0 {
+                // Decreases already explained by prints are not cancellations
+                let traded = decrease.min(order.unmatched_traded);
+                order.unmatched_traded -= traded;
+                let cancelled = decrease - traded;
+
+                let others = (order.level_size - order.remaining).max(0.0);
+                if cancelled > 0.0 && others > self.config.size_epsilon {
+                    let ahead_share = (order.queue_ahead / others).min(1.0);
+                    order.queue_ahead = (order.queue_ahead - cancelled * ahead_share).max(0.0);
+                }
+            }
+
+            // Size added to the level queues behind us. If the visible level
+            // is smaller than our own order, nothing can be ahead of it.
+            order.level_size = size;
+            if size <= order.remaining + self.config.size_epsilon {
+                order.queue_ahead = 0.0;
+            } else {
+                order.queue_ahead = order.queue_ahead.min(size - order.remaining);
+            }
+        }
+    }
+
+    /// Apply a public trade print
+    pub async fn on_trade(&self, symbol: &str, price: f64, size: f64, is_buy: bool, timestamp: DateTime<Utc>) {
+        // A buying aggressor lifts asks, a selling aggressor hits bids
+        let hit_side = if is_buy { OrderSide::Ask } else { OrderSide::Bid };
+
+        {
+            let mut prints = self.prints.write().await;
+            let window = prints.entry((symbol.to_string(), hit_side)).or_default();
+            window.push_back((timestamp, size));
+            let cutoff = timestamp - Duration::seconds(self.config.rate_window_secs);
+            while window.front().is_some_and(|(ts, _)| *ts < cutoff) || window.len() > self.config.max_prints {
+                window.pop_front();
+            }
+        }
+
+        let mut orders = self.orders.write().await;
+        for order in orders.values_mut() {
+            if order.symbol != symbol || order.side != hit_side {
+                continue;
+            }
+
+            let through = match hit_side {
+                OrderSide::Bid => price < order.price,
+                OrderSide::Ask => price > order.price,
+            };
+            if through {
+                // The market traded through our level, so nothing is left ahead
+                order.queue_ahead = 0.0;
+            } else if price == order.price {
+                order.queue_ahead = (order.queue_ahead - size).max(0.0);
+                order.unmatched_traded += size;
+            }
+        }
+    }
+
+    /// Print rate (per second) and average print size hitting a side
+    pub async fn trade_rate(&self, symbol: &str, side: OrderSide) -> (f64, f64) {
+        let prints = self.prints.read().await;
+        let Some(window) = prints.get(&(symbol.to_string(), side)).filter(|w| !w.is_empty()) else {
+            return (0.0, 0.0);
+        };
+        let total: f64 = window.iter().map(|(_, size)| size).sum();
+        let rate = window.len() as f64 / self.config.rate_window_secs.max(1) as f64;
+        (rate, total / window.len() as f64)
+    }
+
+    /// Current queue estimate for an order
+    pub async fn estimate(&self, order_id: &str) -> Result<QueueEstimate, QueuePositionError> {
+        let order = self.orders.read().await.get(order_id).cloned()
+            .ok_or_else(|| QueuePositionError::UnknownOrder(order_id.to_string()))?;
+        let (rate, avg_size) = self.trade_rate(&order.symbol, order.side).await;
+
+        let volume_rate = rate * avg_size;
+        let expected_fill_secs = (volume_rate > 0.0)
+            .then(|| (order.queue_ahead + order.remaining) / volume_rate);
+
+        Ok(QueueEstimate {
+            order_id: order_id.to_string(),
+            symbol: order.symbol,
+            side: order.side,
+            price: order.price,
+            queue_ahead: order.queue_ahead,
+            remaining: order.remaining,
+            level_size: order.level_size,
+            trade_rate_per_sec: rate,
+            avg_trade_size: avg_size,
+            expected_fill_secs,
+            placed_at: order.placed_at,
+        })
+    }
+
+    /// Probability that the order is completely filled within `horizon`
+    ///
+    /// Prints are modelled as a Poisson process at the recent rate, each of
+    /// the recent average size; the order fills once enough prints arrive to
+    /// clear the queue ahead of it and its own remaining size.
+    pub async fn fill_probability(&self, order_id: &str, horizon: Duration) -> Result<f64, QueuePositionError> {
+        let estimate = self.estimate(order_id).await?;
+        Ok(poisson_fill_probability(
+            estimate.queue_ahead + estimate.remaining,
+            estimate.trade_rate_per_sec,
+            estimate.avg_trade_size,
+            horizon.num_milliseconds() as f64 / 1_000.0,
+        ))
+    }
+
+    /// Queue estimates for every tracked order
+    pub async fn estimates(&self) -> Vec<QueueEstimate> {
+        let ids: Vec<String> = self.orders.read().await.keys().cloned().collect();
+        let mut estimates = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Ok(estimate) = self.estimate(&id).await {
+                estimates.push(estimate);
+            }
+        }
+        estimates
+    }
+}
+
+/// P(at least `ceil(volume / avg_size)` arrivals in `horizon_secs`) for a
+/// Poisson process with the given rate
+fn poisson_fill_probability(volume: f64, rate_per_sec: f64, avg_size: f64, horizon_secs: f64) -> f64 {
+    if volume <= 0.0 {
+        return 1.0;
+    }
+    if rate_per_sec <= 0.0 || avg_size <= 0.0 || horizon_secs <= 0.0 {
+        return 0.0;
+    }
+
+    let needed = (volume / avg_size).ceil() as u64;
+    let mean = rate_per_sec * horizon_secs;
+
+    // Sum P(N = k) for k < needed, iterating the pmf to avoid factorials
+    let mut pmf = (-mean).exp();
+    let mut below = 0.0;
+    for k in 0..needed {
+        below += pmf;
+        pmf *= mean / (k + 1) as f64;
+        if pmf == 0.0 && k as f64 > mean {
+            break;
+        }
+    }
+    (1.0 - below).clamp(0.0, 1.0)
+}
+
+/// Create a queue position estimator
+pub fn create_queue_position_estimator(config: QueuePositionConfig) -> Arc<QueuePositionEstimator> {
+    Arc::new(QueuePositionEstimator::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_queue_advances_on_prints_and_cancels() {
+        let estimator = QueuePositionEstimator::new(QueuePositionConfig::default());
+        estimator.register_order("o1", "BTC/USDT", OrderSide::Bid, 100.0, 2.0, 10.0).await.unwrap();
+
+        // Prints at our price consume the queue; the matching book decrease
+        // is not double counted as a cancellation
+        let now = Utc::now();
+        estimator.on_trade("BTC/USDT", 100.0, 4.0, false, now).await;
+        estimator.on_book_update("BTC/USDT", OrderSide::Bid, 100.0, 8.0).await;
+        assert!((estimator.estimate("o1").await.unwrap().queue_ahead - 6.0).abs() < 1e-9);
+
+        // Size joining behind us, then a cancel split across ahead and behind
+        estimator.on_book_update("BTC/USDT", OrderSide::Bid, 100.0, 12.0).await;
+        estimator.on_book_update("BTC/USDT", OrderSide::Bid, 100.0, 10.0).await;
+        let estimate = estimator.estimate("o1").await.unwrap();
+        assert!((estimate.queue_ahead - (6.0 - 2.0 * 0.6)).abs() < 1e-9);
+
+        let short = estimator.fill_probability("o1", Duration::seconds(10)).await.unwrap();
+        let long = estimator.fill_probability("o1", Duration::seconds(3_600)).await.unwrap();
+        assert!(short < long && long > 0.99);
+
+        // A print through our price clears everything ahead of us
+        estimator.on_trade("BTC/USDT", 99.5, 1.0, false, now).await;
+        assert_eq!(estimator.estimate("o1").await.unwrap().queue_ahead, 0.0);
+    }
+}
@@ -0,0 +1,312 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use dashmap::DashMap;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::order_router::{Order, OrderSide};
+use crate::orderbook::OrderBookManager;
+
+/// Action to take when a route targets a venue with stale data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StaleQuoteAction {
+    /// Skip the venue entirely
+    Reject,
+    /// Re-price the order against the fresh reference book and continue
+    Reprice,
+}
+
+/// Quote freshness configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteFreshnessConfig {
+    /// Maximum age of a venue quote before it is considered stale (ms)
+    pub max_quote_age_ms: u64,
+
+    /// Maximum time since the last venue heartbeat (ms)
+    pub max_heartbeat_age_ms: u64,
+
+    /// Per-venue quote age overrides (ms)
+    pub venue_max_quote_age_ms: HashMap<String, u64>,
+
+    /// Action to take for stale routes
+    pub action: StaleQuoteAction,
+
+    /// Treat venues with no recorded quote as stale, so a venue whose feed
+    /// never connected is not routed to on an assumed-fresh book
+    pub reject_unknown: bool,
+}
+
+impl Default for QuoteFreshnessConfig {
+    fn default() -> Self {
+        Self {
+            max_quote_age_ms: 500,
+            max_heartbeat_age_ms: 5000,
+            venue_max_quote_age_ms: HashMap::new(),
+            action: StaleQuoteAction::Reject,
+            reject_unknown: true,
+        }
+    }
+}
+
+/// Reason a route was considered stale
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StaleReason {
+    /// Venue book is older than the threshold
+    QuoteAge { age_ms: u64, limit_ms: u64 },
+    /// Venue heartbeat is older than the threshold
+    HeartbeatAge { age_ms: u64, limit_ms: u64 },
+    /// No quote has ever been recorded for this venue/symbol
+    NoQuote,
+}
+
+/// Result of a pre-send freshness check
+#[derive(Debug, Clone, PartialEq)]
+pub enum FreshnessVerdict {
+    /// Venue data is fresh; route as-is
+    Fresh,
+    /// Venue data is stale but the order was re-priced to the given price
+    Repriced { new_price: f64, reason: StaleReason },
+    /// Venue data is stale; do not route
+    Rejected { reason: StaleReason },
+}
+
+/// Counters for stale route handling
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuoteFreshnessStats {
+    /// Total routes checked
+    pub checked: u64,
+    /// Routes rejected as stale
+    pub rejected: u64,
+    /// Routes re-priced due to staleness
+    pub repriced: u64,
+    /// Rejected routes by venue
+    pub rejected_by_venue: HashMap<String, u64>,
+}
+
+/// Pre-send guard that verifies quote freshness per venue before routing
+pub struct QuoteFreshnessGuard {
+    /// Configuration
+    config: QuoteFreshnessConfig,
+    /// Last quote timestamp (ms) per (venue, symbol)
+    quote_timestamps: DashMap<(String, String), u64>,
+    /// Last heartbeat timestamp (ms) per venue
+    heartbeats: DashMap<String, u64>,
+    /// Consolidated reference book used for re-pricing (optional)
+    reference_books: Option<Arc<OrderBookManager>>,
+    /// Counters
+    checked: AtomicU64,
+    rejected: AtomicU64,
+    repriced: AtomicU64,
+    rejected_by_venue: DashMap<String, u64>,
+}
+
+impl QuoteFreshnessGuard {
+    /// Create a new quote freshness guard
+    pub fn new(config: QuoteFreshnessConfig) -> Self {
+        Self {
+            config,
+            quote_timestamps: DashMap::new(),
+            heartbeats: DashMap::new(),
+            reference_books: None,
+            checked: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+            repriced: AtomicU64::new(0),
+            rejected_by_venue: DashMap::new(),
+        }
+    }
+
+    /// Attach a reference order book manager used when re-pricing
+    pub fn with_reference_books(mut self, books: Arc<OrderBookManager>) -> Self {
+        self.reference_books = Some(books);
+        self
+    }
+
+    /// Record that a quote/book update was received from a venue
+    pub fn record_quote(&self, venue: &str, symbol: &str, timestamp_ms: u64) {
+        self.quote_timestamps.insert((venue.to_string(), symbol.to_string()), timestamp_ms);
+    }
+
+    /// Record a venue heartbeat
+    pub fn record_heartbeat(&self, venue: &str, timestamp_ms: u64) {
+        self.heartbeats.insert(venue.to_string(), timestamp_ms);
+    }
+
+    /// Check whether an order may be routed to a venue
+    pub fn check(&self, order: &Order, venue: &str) -> FreshnessVerdict {
+        self.check_at(order, venue, unix_timestamp_ms())
+    }
+
+    /// Check freshness relative to an explicit timestamp
+    pub fn check_at(&self, order: &Order, venue: &str, now_ms: u64) -> FreshnessVerdict {
+        self.checked.fetch_add(1, Ordering::Relaxed);
+
+        let reason = match self.stale_reason(venue, &order.symbol, now_ms) {
+            Some(reason) => reason,
+            None => return FreshnessVerdict::Fresh,
+        };
+
+        if self.config.action == StaleQuoteAction::Reprice {
+            if let Some(new_price) = self.reference_price(order) {
+                self.repriced.fetch_add(1, Ordering::Relaxed);
+                debug!(
+                    "Re-priced order {} for {} from {} to {} ({:?})",
+                    order.id, venue, order.price, new_price, reason
+                );
+                return FreshnessVerdict::Repriced { new_price, reason };
+            }
+        }
+
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+        *self.rejected_by_venue.entry(venue.to_string()).or_insert(0) += 1;
+        warn!("Rejected stale route for order {} to {}: {:?}", order.id, venue, reason);
+
+        FreshnessVerdict::Rejected { reason }
+    }
+
+    /// Get stale route counters
+    pub fn get_stats(&self) -> QuoteFreshnessStats {
+        QuoteFreshnessStats {
+            checked: self.checked.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+            repriced: self.repriced.load(Ordering::Relaxed),
+            rejected_by_venue: self.rejected_by_venue
+                .iter()
+                .map(|entry| (entry.key().clone(), *entry.value()))
+                .collect(),
+        }
+    }
+
+    /// Determine why a venue's data is stale, if it is
+    fn stale_reason(&self, venue: &str, symbol: &str, now_ms: u64) -> Option<StaleReason> {
+        if let Some(heartbeat) = self.heartbeats.get(venue) {
+            let age_ms = now_ms.saturating_sub(*heartbeat);
+            if age_ms > self.config.max_heartbeat_age_ms {
+                return Some(StaleReason::HeartbeatAge {
+                    age_ms,
+                    limit_ms: self.config.max_heartbeat_age_ms,
+                });
+            }
+        }
+
+        let limit_ms = self.config.venue_max_quote_age_ms
+            .get(venue)
+            .copied()
+            .unwrap_or(self.config.max_quote_age_ms);
+
+        match self.quote_timestamps.get(&(venue.to_string(), symbol.to_string())) {
+            Some(timestamp) => {
+                let age_ms = now_ms.saturating_sub(*timestamp);
+                if age_ms > limit_ms {
+                    Some(StaleReason::QuoteAge { age_ms, limit_ms })
+                } else {
+                    None
+                }
+            }
+            None if self.config.reject_unknown => Some(StaleReason::NoQuote),
+            None => None,
+        }
+    }
+
+    /// Aggressive price from the reference book for the order's side
+    fn reference_price(&self, order: &Order) -> Option<f64> {
+        let books = self.reference_books.as_ref()?;
+        let (bids, asks) = books.get_snapshot(&order.symbol, 1)?;
+
+        match order.side {
+            OrderSide::Buy => asks.first().map(|level| level.price),
+            OrderSide::Sell => bids.first().map(|level| level.price),
+        }
+    }
+}
+
+/// Get current Unix timestamp in milliseconds
+fn unix_timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Create a quote freshness guard
+pub fn create_quote_freshness_guard(config: QuoteFreshnessConfig) -> Arc<QuoteFreshnessGuard> {
+    Arc::new(QuoteFreshnessGuard::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_router::TimeInForce;
+
+    fn test_order() -> Order {
+        Order {
+            symbol: "BTC-USD".to_string(),
+            side: OrderSide::Buy,
+            amount: 1.0,
+            price: 50000.0,
+            venues: vec!["binance".to_string()],
+            id: "order1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_stale_quote_rejected() {
+        let guard = QuoteFreshnessGuard::new(QuoteFreshnessConfig::default());
+        guard.record_quote("binance", "BTC-USD", 1_000);
+
+        assert_eq!(guard.check_at(&test_order(), "binance", 1_200), FreshnessVerdict::Fresh);
+
+        match guard.check_at(&test_order(), "binance", 2_000) {
+            FreshnessVerdict::Rejected { reason: StaleReason::QuoteAge { age_ms, .. } } => assert_eq!(age_ms, 1_000),
+            other => panic!("Expected stale rejection, got {:?}", other),
+        }
+
+        let stats = guard.get_stats();
+        assert_eq!(stats.checked, 2);
+        assert_eq!(stats.rejected, 1);
+        assert_eq!(stats.rejected_by_venue.get("binance"), Some(&1));
+    }
+
+    #[test]
+    fn test_stale_heartbeat_rejected() {
+        let guard = QuoteFreshnessGuard::new(QuoteFreshnessConfig::default());
+        guard.record_quote("kraken", "BTC-USD", 10_000);
+        guard.record_heartbeat("kraken", 1_000);
+
+        assert!(matches!(
+            guard.check_at(&test_order(), "kraken", 10_100),
+            FreshnessVerdict::Rejected { reason: StaleReason::HeartbeatAge { .. } }
+        ));
+    }
+
+    #[test]
+    fn test_unknown_venue_rejected_by_default() {
+        let guard = QuoteFreshnessGuard::new(QuoteFreshnessConfig::default());
+
+        assert_eq!(
+            guard.check_at(&test_order(), "binance", 1_000),
+            FreshnessVerdict::Rejected { reason: StaleReason::NoQuote }
+        );
+    }
+}
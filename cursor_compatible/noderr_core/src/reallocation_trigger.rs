@@ -0,0 +1,557 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Event-driven intraday reallocation
+//!
+//! The allocators normally recompute on a fixed interval, which can leave a
+//! stale allocation in place for hours after the market has moved. The
+//! reallocation trigger watches for the events that should invalidate an
+//! allocation straight away — a confirmed market regime change, a drawdown
+//! alert/breach/recovery, or a large shift in strategy correlations — and
+//! forces the attached allocators to recompute their (constrained)
+//! allocation immediately. Cooldowns stop a burst of events from thrashing
+//! the portfolio.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::TryRecvError;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::asset_allocator::AssetAllocator;
+use crate::correlation_engine::{CorrelationEngine, CorrelationMatrix, TimePeriod};
+use crate::drawdown_monitor::DrawdownEventType;
+use crate::market::Symbol;
+use crate::market_regime::{MarketRegimeDetector, MarketRegimeState};
+use crate::risk_allocation::RiskAllocator;
+use crate::strategy::StrategyId;
+use crate::telemetry::TelemetryReporter;
+use crate::trading_events::{TradingEvent, TradingEventBus};
+
+/// Kind of event that can trigger a reallocation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReallocationTriggerKind {
+    RegimeChange,
+    Drawdown,
+    CorrelationShift,
+}
+
+/// Reallocation trigger configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReallocationTriggerConfig {
+    /// How often regimes and correlations are polled (milliseconds)
+    pub check_interval_ms: u64,
+    /// Minimum time between any two forced reallocations (seconds)
+    pub cooldown_secs: i64,
+    /// Minimum time between reallocations caused by the same kind of trigger (seconds)
+    pub kind_cooldown_secs: HashMap<ReallocationTriggerKind, i64>,
+    /// Trigger kinds that are acted on
+    pub enabled: Vec<ReallocationTriggerKind>,
+    /// Minimum detector confidence for a regime change to count as confirmed
+    pub min_regime_confidence: f64,
+    /// Drawdown transitions that trigger a reallocation
+    pub drawdown_events: Vec<DrawdownEventType>,
+    /// Return period used for correlation monitoring
+    pub correlation_period: TimePeriod,
+    /// Absolute change in a pairwise correlation that counts as a shift
+    pub correlation_shift_threshold: f64,
+    /// Maximum number of trigger records kept
+    pub max_history: usize,
+}
+
+impl Default for ReallocationTriggerConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_ms: 5_000,
+            cooldown_secs: 300,
+            kind_cooldown_secs: HashMap::new(),
+            enabled: vec![
+                ReallocationTriggerKind::RegimeChange,
+                ReallocationTriggerKind::Drawdown,
+                ReallocationTriggerKind::CorrelationShift,
+            ],
+            min_regime_confidence: 0.7,
+            drawdown_events: vec![
+                DrawdownEventType::Alert,
+                DrawdownEventType::Breach,
+                DrawdownEventType::Recovery,
+            ],
+            correlation_period: TimePeriod::Hourly,
+            correlation_shift_threshold: 0.3,
+            max_history: 500,
+        }
+    }
+}
+
+/// An event that should invalidate the current allocation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ReallocationTrigger {
+    /// The detector confirmed a new market regime for a symbol
+    RegimeChange {
+        symbol: Symbol,
+        from: MarketRegimeState,
+        to: MarketRegimeState,
+        confidence: f64,
+    },
+    /// An agent's drawdown state changed
+    Drawdown {
+        agent_id: String,
+        event_type: DrawdownEventType,
+        drawdown_pct: f64,
+    },
+    /// The correlation between two strategies moved past the shift threshold
+    CorrelationShift {
+        strategy_a: StrategyId,
+        strategy_b: StrategyId,
+        previous: f64,
+        current: f64,
+    },
+}
+
+impl ReallocationTrigger {
+    /// Kind of this trigger
+    pub fn kind(&self) -> ReallocationTriggerKind {
+        match self {
+            ReallocationTrigger::RegimeChange { .. } => ReallocationTriggerKind::RegimeChange,
+            ReallocationTrigger::Drawdown { .. } => ReallocationTriggerKind::Drawdown,
+            ReallocationTrigger::CorrelationShift { .. } => ReallocationTriggerKind::CorrelationShift,
+        }
+    }
+}
+
+/// What happened when a trigger fired
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ReallocationOutcome {
+    /// The attached allocators recomputed their allocations
+    Reallocated {
+        /// Total strategy allocation after the risk allocator ran
+        risk_total: Option<f64>,
+        /// Regime the asset allocator allocated for
+        asset_regime: Option<MarketRegimeState>,
+    },
+    /// A cooldown was active; nothing was recomputed
+    Suppressed { remaining_secs: i64 },
+    /// The trigger kind is disabled in the configuration
+    Disabled,
+    /// An allocator failed to recompute
+    Failed { message: String },
+}
+
+/// A trigger together with what it caused
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReallocationRecord {
+    /// The triggering event
+    pub trigger: ReallocationTrigger,
+    /// When the trigger was handled
+    pub timestamp: DateTime<Utc>,
+    /// Result of handling it
+    pub outcome: ReallocationOutcome,
+}
+
+#[derive(Default)]
+struct TriggerState {
+    last_reallocation: Option<DateTime<Utc>>,
+    last_by_kind: HashMap<ReallocationTriggerKind, DateTime<Utc>>,
+    regimes: HashMap<Symbol, MarketRegimeState>,
+    correlations: Option<CorrelationMatrix>,
+    history: VecDeque<ReallocationRecord>,
+}
+
+/// Forces immediate reallocation when regime, drawdown or correlation events fire
+pub struct ReallocationTriggerService {
+    /// Configuration
+    config: ReallocationTriggerConfig,
+    /// Strategy risk allocator to recompute
+    risk_allocator: Option<Arc<dyn RiskAllocator>>,
+    /// Asset allocator to recompute
+    asset_allocator: Option<Arc<dyn AssetAllocator>>,
+    /// Regime detector polled for confirmed changes
+    regime_detector: Option<Arc<dyn MarketRegimeDetector>>,
+    /// Correlation engine polled for shifts
+    correlation_engine: Option<Arc<dyn CorrelationEngine>>,
+    /// Telemetry reporter for trigger records
+    telemetry: Option<Arc<TelemetryReporter>>,
+    /// Cooldown bookkeeping, last observations and history
+    state: RwLock<TriggerState>,
+    /// Broadcast channel for trigger records
+    records: broadcast::Sender<ReallocationRecord>,
+    /// Background task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl ReallocationTriggerService {
+    /// Create a trigger service with no allocators or sources attached
+    pub fn new(config: ReallocationTriggerConfig) -> Self {
+        let (records, _) = broadcast::channel(64);
+        Self {
+            config,
+            risk_allocator: None,
+            asset_allocator: None,
+            regime_detector: None,
+            correlation_engine: None,
+            telemetry: None,
+            state: RwLock::new(TriggerState::default()),
+            records,
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Recompute a strategy risk allocator when triggered
+    pub fn with_risk_allocator(mut self, allocator: Arc<dyn RiskAllocator>) -> Self {
+        self.risk_allocator = Some(allocator);
+        self
+    }
+
+    /// Recompute an asset allocator when triggered
+    pub fn with_asset_allocator(mut self, allocator: Arc<dyn AssetAllocator>) -> Self {
+        self.asset_allocator = Some(allocator);
+        self
+    }
+
+    /// Watch a regime detector for confirmed regime changes
+    pub fn with_regime_detector(mut self, detector: Arc<dyn MarketRegimeDetector>) -> Self {
+        self.regime_detector = Some(detector);
+        self
+    }
+
+    /// Watch a correlation engine for correlation shifts
+    pub fn with_correlation_engine(mut self, engine: Arc<dyn CorrelationEngine>) -> Self {
+        self.correlation_engine = Some(engine);
+        self
+    }
+
+    /// Report trigger records to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Subscribe to trigger records
+    pub fn subscribe(&self) -> broadcast::Receiver<ReallocationRecord> {
+        self.records.subscribe()
+    }
+
+    /// Handled triggers, oldest first
+    pub async fn history(&self) -> Vec<ReallocationRecord> {
+        self.state.read().await.history.iter().cloned().collect()
+    }
+
+    /// Handle a trigger now
+    pub async fn fire(&self, trigger: ReallocationTrigger) -> ReallocationOutcome {
+        self.fire_at(trigger, Utc::now()).await
+    }
+
+    /// Handle a trigger as of `now`, respecting the global and per-kind cooldowns
+    pub async fn fire_at(&self, trigger: ReallocationTrigger, now: DateTime<Utc>) -> ReallocationOutcome {
+        let kind = trigger.kind();
+        let outcome = if !self.config.enabled.contains(&kind) {
+            ReallocationOutcome::Disabled
+        } else if let Some(remaining_secs) = self.cooldown_remaining(kind, now).await {
+            ReallocationOutcome::Suppressed { remaining_secs }
+        } else {
+            // Claim the cooldown before recomputing so concurrent triggers are suppressed
+            {
+                let mut state = self.state.write().await;
+                state.last_reallocation = Some(now);
+                state.last_by_kind.insert(kind, now);
+            }
+            self.reallocate().await
+        };
+
+        match &outcome {
+            ReallocationOutcome::Reallocated { .. } => info!("Reallocated after {:?}", trigger),
+            ReallocationOutcome::Failed { message } => warn!("Reallocation after {:?} failed: {}", trigger, message),
+            _ => debug!("Reallocation trigger {:?}: {:?}", trigger, outcome),
+        }
+
+        let record = ReallocationRecord { trigger, timestamp: now, outcome: outcome.clone() };
+        if let Some(telemetry) = &self.telemetry {
+            let mut data = HashMap::new();
+            data.insert("trigger".to_string(), serde_json::to_value(&record.trigger).unwrap_or_default());
+            data.insert("outcome".to_string(), serde_json::to_value(&record.outcome).unwrap_or_default());
+            telemetry.report_custom("reallocation_trigger", data).await;
+        }
+        {
+            let mut state = self.state.write().await;
+            state.history.push_back(record.clone());
+            while state.history.len() > self.config.max_history {
+                state.history.pop_front();
+            }
+        }
+        let _ = self.records.send(record);
+
+        outcome
+    }
+
+    /// Seconds left on whichever cooldown blocks `kind`, if any
+    async fn cooldown_remaining(&self, kind: ReallocationTriggerKind, now: DateTime<Utc>) -> Option<i64> {
+        let state = self.state.read().await;
+        let global = state.last_reallocation
+            .map(|at| self.config.cooldown_secs - (now - at).num_seconds());
+        let per_kind = state.last_by_kind.get(&kind)
+            .zip(self.config.kind_cooldown_secs.get(&kind))
+            .map(|(at, secs)| secs - (now - *at).num_seconds());
+
+        global.into_iter().chain(per_kind).filter(|remaining| *remaining > 0).max()
+    }
+
+    async fn reallocate(&self) -> ReallocationOutcome {
+        let mut risk_total = None;
+        let mut asset_regime = None;
+
+        if let Some(allocator) = &self.risk_allocator {
+            match allocator.force_reallocation().await {
+                Ok(allocation) => risk_total = Some(allocation.total_allocation),
+                Err(e) => return ReallocationOutcome::Failed { message: e.to_string() },
+            }
+        }
+        if let Some(allocator) = &self.asset_allocator {
+            match allocator.force_reallocation().await {
+                Ok(allocation) => asset_regime = Some(allocation.market_regime),
+                Err(e) => return ReallocationOutcome::Failed { message: e.to_string() },
+            }
+        }
+
+        ReallocationOutcome::Reallocated { risk_total, asset_regime }
+    }
+
+    /// Confirmed regime changes since the last poll; the first sighting of a symbol only seeds its state
+    pub async fn poll_regimes(&self) -> Vec<ReallocationTrigger> {
+        let Some(detector) = &self.regime_detector else {
+            return Vec::new();
+        };
+        let regimes = detector.get_all_regimes().await;
+
+        let mut state = self.state.write().await;
+        let mut triggers = Vec::new();
+        for (symbol, regime) in regimes {
+            if regime.state == MarketRegimeState::Unknown || regime.confidence < self.config.min_regime_confidence {
+                continue;
+            }
+            match state.regimes.insert(symbol.clone(), regime.state) {
+                Some(from) if from != regime.state => triggers.push(ReallocationTrigger::RegimeChange {
+                    symbol,
+                    from,
+                    to: regime.state,
+                    confidence: regime.confidence,
+                }),
+                _ => {}
+            }
+        }
+        triggers
+    }
+
+    /// The largest correlation shift since the last poll, if it exceeds the threshold
+    pub async fn poll_correlations(&self) -> Option<ReallocationTrigger> {
+        let engine = self.correlation_engine.as_ref()?;
+        let matrix = match engine.generate_correlation_matrix(self.config.correlation_period).await {
+            Ok(matrix) => matrix,
+            Err(e) => {
+                debug!("Correlation matrix unavailable for shift detection: {}", e);
+                return None;
+            }
+        };
+
+        let mut state = self.state.write().await;
+        let previous = state.correlations.replace(matrix.clone())?;
+        largest_shift(&previous, &matrix)
+            .filter(|(_, _, before, after)| (after - before).abs() >= self.config.correlation_shift_threshold)
+            .map(|(strategy_a, strategy_b, previous, current)| ReallocationTrigger::CorrelationShift {
+                strategy_a,
+                strategy_b,
+                previous,
+                current,
+            })
+    }
+
+    /// Poll regimes and correlations and react to drawdown events on the bus
+    pub async fn start(self: &Arc<Self>, event_bus: Option<Arc<TradingEventBus>>) {
+        let service = Arc::clone(self);
+        let mut receiver = event_bus.map(|bus| bus.subscribe());
+        let interval_ms = self.config.check_interval_ms.max(1);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(StdDuration::from_millis(interval_ms));
+            loop {
+                interval.tick().await;
+
+                let mut triggers = Vec::new();
+                if let Some(receiver) = receiver.as_mut() {
+                    loop {
+                        match receiver.try_recv() {
+                            Ok(TradingEvent::Drawdown(event)) => {
+                                if service.config.drawdown_events.contains(&event.event_type) {
+                                    triggers.push(ReallocationTrigger::Drawdown {
+                                        agent_id: event.agent_id,
+                                        event_type: event.event_type,
+                                        drawdown_pct: event.drawdown_pct,
+                                    });
+                                }
+                            }
+                            Ok(_) => {}
+                            Err(TryRecvError::Lagged(skipped)) => {
+                                warn!("Reallocation trigger lagged; {} events were skipped", skipped);
+                            }
+                            Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => break,
+                        }
+                    }
+                }
+                triggers.extend(service.poll_regimes().await);
+                triggers.extend(service.poll_correlations().await);
+
+                for trigger in triggers {
+                    service.fire(trigger).await;
+                }
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop watching for triggers
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Pair with the largest absolute correlation change between two matrices, as (a, b, before, after)
+fn largest_shift(previous: &CorrelationMatrix, current: &CorrelationMatrix) -> Option<(StrategyId, StrategyId, f64, f64)> {
+    let index: HashMap<&StrategyId, usize> = previous.strategy_ids.iter().enumerate().map(|(i, id)| (id, i)).collect();
+    let mut largest: Option<(StrategyId, StrategyId, f64, f64)> = None;
+
+    for (i, a) in current.strategy_ids.iter().enumerate() {
+        for (j, b) in current.strategy_ids.iter().enumerate().skip(i + 1) {
+            let (Some(&pi), Some(&pj)) = (index.get(a), index.get(b)) else {
+                continue;
+            };
+            let before = previous.matrix[pi][pj];
+            let after = current.matrix[i][j];
+            if !before.is_finite() || !after.is_finite() {
+                continue;
+            }
+            let is_larger = match &largest {
+                Some((_, _, b0, a0)) => (after - before).abs() > (a0 - b0).abs(),
+                None => true,
+            };
+            if is_larger {
+                largest = Some((a.clone(), b.clone(), before, after));
+            }
+        }
+    }
+    largest
+}
+
+/// Create a reallocation trigger that recomputes the risk allocator on regime and correlation events
+pub fn create_reallocation_trigger(
+    config: ReallocationTriggerConfig,
+    risk_allocator: Arc<dyn RiskAllocator>,
+    regime_detector: Arc<dyn MarketRegimeDetector>,
+    correlation_engine: Arc<dyn CorrelationEngine>,
+) -> Arc<ReallocationTriggerService> {
+    Arc::new(
+        ReallocationTriggerService::new(config)
+            .with_risk_allocator(risk_allocator)
+            .with_regime_detector(regime_detector)
+            .with_correlation_engine(correlation_engine),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regime_change() -> ReallocationTrigger {
+        ReallocationTrigger::RegimeChange {
+            symbol: "BTC/USDT".to_string(),
+            from: MarketRegimeState::Bull,
+            to: MarketRegimeState::Bear,
+            confidence: 0.9,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cooldowns_suppress_repeat_triggers() {
+        let mut config = ReallocationTriggerConfig::default();
+        config.cooldown_secs = 60;
+        config.kind_cooldown_secs.insert(ReallocationTriggerKind::RegimeChange, 600);
+        config.enabled.retain(|kind| *kind != ReallocationTriggerKind::CorrelationShift);
+        let service = ReallocationTriggerService::new(config);
+        let start = Utc::now();
+
+        let first = service.fire_at(regime_change(), start).await;
+        assert_eq!(first, ReallocationOutcome::Reallocated { risk_total: None, asset_regime: None });
+
+        // Global cooldown blocks everything for a minute
+        let drawdown = ReallocationTrigger::Drawdown {
+            agent_id: "agent-1".to_string(),
+            event_type: DrawdownEventType::Breach,
+            drawdown_pct: 0.12,
+        };
+        let blocked = service.fire_at(drawdown.clone(), start + Duration::seconds(30)).await;
+        assert_eq!(blocked, ReallocationOutcome::Suppressed { remaining_secs: 30 });
+        let allowed = service.fire_at(drawdown, start + Duration::seconds(90)).await;
+        assert!(matches!(allowed, ReallocationOutcome::Reallocated { .. }));
+
+        // The regime cooldown outlasts the global one
+        let repeat = service.fire_at(regime_change(), start + Duration::seconds(200)).await;
+        assert_eq!(repeat, ReallocationOutcome::Suppressed { remaining_secs: 400 });
+
+        let shift = ReallocationTrigger::CorrelationShift {
+            strategy_a: "a".to_string(),
+            strategy_b: "b".to_string(),
+            previous: 0.1,
+            current: 0.8,
+        };
+        assert_eq!(service.fire_at(shift, start + Duration::seconds(1_000)).await, ReallocationOutcome::Disabled);
+        assert_eq!(service.history().await.len(), 5);
+    }
+
+    #[test]
+    fn test_largest_shift_matches_by_strategy_id() {
+        let matrix = |ids: &[&str], rows: Vec<Vec<f64>>| CorrelationMatrix {
+            timestamp: Utc::now(),
+            period: TimePeriod::Hourly,
+            data_points: 100,
+            strategy_ids: ids.iter().map(|id| id.to_string()).collect(),
+            matrix: rows,
+        };
+        let previous = matrix(&["a", "b", "c"], vec![
+            vec![1.0, 0.2, 0.1],
+            vec![0.2, 1.0, 0.5],
+            vec![0.1, 0.5, 1.0],
+        ]);
+        // Reordered ids; b/c moved from 0.5 to -0.1
+        let current = matrix(&["c", "a", "b"], vec![
+            vec![1.0, 0.15, -0.1],
+            vec![0.15, 1.0, 0.25],
+            vec![-0.1, 0.25, 1.0],
+        ]);
+
+        let (a, b, before, after) = largest_shift(&previous, &current).unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("c", "b"));
+        assert!((before - 0.5).abs() < 1e-12 && (after + 0.1).abs() < 1e-12);
+    }
+}
@@ -13,18 +13,26 @@
 // copies or substantial portions of the Software.
 
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
-use redis::{Client, aio::ConnectionManager, AsyncCommands, RedisError, RedisResult};
+use deadpool_redis::{
+    Config as DeadpoolConfig, Connection, Pool, PoolConfig, PoolError as DeadpoolError, Runtime, Timeouts,
+};
+use redis::{Client, aio::ConnectionManager, AsyncCommands, Cmd, FromRedisValue, Pipeline, RedisError, RedisResult};
+use serde::de::DeserializeOwned;
 use serde::{Serialize, Deserialize};
 use thiserror::Error;
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
+use crate::wire_format::WireFormat;
+
 /// Redis client configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RedisConfig {
     /// Redis connection URL
     pub url: String,
@@ -46,6 +54,24 @@ pub struct RedisConfig {
     
     /// Health check interval in seconds
     pub health_check_interval_sec: u64,
+    
+    /// Timeout for a single command or pipeline in milliseconds
+    pub command_timeout_ms: u64,
+    
+    /// How long to wait for a free pooled connection in milliseconds
+    pub pool_wait_timeout_ms: u64,
+    
+    /// Reconnect attempts before a command fails
+    pub max_reconnect_attempts: u32,
+    
+    /// Delay before the first reconnect attempt in milliseconds; doubles per attempt
+    pub reconnect_base_delay_ms: u64,
+    
+    /// Longest delay between reconnect attempts in milliseconds
+    pub reconnect_max_delay_ms: u64,
+    
+    /// Encoding for stored values and published messages; reads accept either format
+    pub value_format: WireFormat,
 }
 
 impl Default for RedisConfig {
@@ -58,10 +84,29 @@ impl Default for RedisConfig {
             max_connections: 10,
             enable_health_checks: true,
             health_check_interval_sec: 60,
+            command_timeout_ms: 2_000,
+            pool_wait_timeout_ms: 1_000,
+            max_reconnect_attempts: 5,
+            reconnect_base_delay_ms: 100,
+            reconnect_max_delay_ms: 5_000,
+            value_format: WireFormat::Json,
         }
     }
 }
 
+impl RedisConfig {
+    /// Encode a value or message in the configured format
+    pub fn encode_value<T: Serialize + ?Sized>(&self, value: &T) -> RedisClientResult<Vec<u8>> {
+        self.value_format.encode_tagged(value)
+            .map_err(|e| RedisClientError::SerializationError(e.to_string()))
+    }
+}
+
+/// Decode a stored value written in any supported format
+pub fn decode_value<T: DeserializeOwned>(data: &[u8]) -> RedisClientResult<T> {
+    WireFormat::decode_tagged(data).map_err(|e| RedisClientError::SerializationError(e.to_string()))
+}
+
 /// Errors that can occur with Redis operations
 #[derive(Debug, Error)]
 pub enum RedisClientError {
@@ -259,27 +304,22 @@ impl RedisClient for DefaultRedisClient {
     async fn get<T: for<'de> Deserialize<'de> + Send + Sync>(&self, key: &str) -> RedisClientResult<Option<T>> {
         let full_key = self.full_key(key);
         
-        let result: Option<String> = self.execute_command(|conn| {
+        let result: Option<Vec<u8>> = self.execute_command(|conn| {
             Box::pin(async move {
-                let result: RedisResult<Option<String>> = conn.get(&full_key).await;
+                let result: RedisResult<Option<Vec<u8>>> = conn.get(&full_key).await;
                 result
             })
         }).await?;
         
         match result {
-            Some(data) => {
-                let value = serde_json::from_str(&data)
-                    .map_err(|e| RedisClientError::SerializationError(e.to_string()))?;
-                Ok(Some(value))
-            }
+            Some(data) => Ok(Some(decode_value(&data)?)),
             None => Ok(None),
         }
     }
     
     async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T, ttl_sec: Option<u64>) -> RedisClientResult<()> {
         let full_key = self.full_key(key);
-        let data = serde_json::to_string(value)
-            .map_err(|e| RedisClientError::SerializationError(e.to_string()))?;
+        let data = self.config.encode_value(value)?;
         
         let ttl = ttl_sec.unwrap_or(self.config.default_ttl_sec);
         
@@ -361,8 +401,7 @@ impl RedisClient for DefaultRedisClient {
     
     async fn publish<T: Serialize + Send + Sync>(&self, channel: &str, message: &T) -> RedisClientResult<i64> {
         let full_channel = self.full_key(channel);
-        let data = serde_json::to_string(message)
-            .map_err(|e| RedisClientError::SerializationError(e.to_string()))?;
+        let data = self.config.encode_value(message)?;
         
         let result: i64 = self.execute_command(|conn| {
             Box::pin(async move {
@@ -398,10 +437,286 @@ impl RedisClient for DefaultRedisClient {
     }
 }
 
+/// Production Redis client backed by a deadpool connection pool
+///
+/// Every command borrows a pooled connection and runs under the configured
+/// command timeout. Connection failures mark the client unhealthy and are
+/// retried with exponential backoff on a fresh connection; the pool drops
+/// broken connections when they are returned, so retries reconnect.
+pub struct PooledRedisClient {
+    /// Redis configuration
+    config: RedisConfig,
+    
+    /// Connection pool, created by `initialize`
+    pool: Arc<RwLock<Option<Pool>>>,
+    
+    /// Result of the most recent command or health check
+    is_healthy: Arc<AtomicBool>,
+    
+    /// Connections re-established after a failure
+    reconnects: Arc<AtomicU64>,
+}
+
+impl PooledRedisClient {
+    /// Create a new pooled client; no connection is made until `initialize`
+    pub fn new(config: RedisConfig) -> Self {
+        Self {
+            config,
+            pool: Arc::new(RwLock::new(None)),
+            is_healthy: Arc::new(AtomicBool::new(false)),
+            reconnects: Arc::new(AtomicU64::new(0)),
+        }
+    }
+    
+    /// Generate a full Redis key with prefix; needed when building raw commands for `query`
+    pub fn full_key(&self, key: &str) -> String {
+        if self.config.key_prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}:{}", self.config.key_prefix, key)
+        }
+    }
+    
+    /// Number of times a command had to reconnect
+    pub fn reconnect_count(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+    
+    /// Pool size and idle connections, if initialized
+    pub async fn pool_status(&self) -> Option<(usize, usize)> {
+        self.pool.read().await.as_ref().map(|pool| {
+            let status = pool.status();
+            (status.size, status.available.max(0) as usize)
+        })
+    }
+    
+    /// Delay before reconnect attempt `attempt` (1-based)
+    fn reconnect_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self.config.reconnect_base_delay_ms.saturating_mul(1 << exponent);
+        Duration::from_millis(delay.min(self.config.reconnect_max_delay_ms))
+    }
+    
+    /// Build a pool from the configuration
+    fn build_pool(&self) -> RedisClientResult<Pool> {
+        let timeout = |ms: u64| Some(Duration::from_millis(ms));
+        let mut pool_config = PoolConfig::new(self.config.max_connections.max(1));
+        pool_config.timeouts = Timeouts {
+            wait: timeout(self.config.pool_wait_timeout_ms),
+            create: Some(Duration::from_secs(self.config.connection_timeout_sec)),
+            recycle: timeout(self.config.command_timeout_ms),
+        };
+        
+        let mut config = DeadpoolConfig::from_url(self.config.url.clone());
+        config.pool = Some(pool_config);
+        config.create_pool(Some(Runtime::Tokio1))
+            .map_err(|e| RedisClientError::ConnectionError(e.to_string()))
+    }
+    
+    /// Borrow a connection, retrying with backoff while the server is unreachable
+    async fn connection(&self) -> RedisClientResult<Connection> {
+        let pool = self.pool.read().await.clone()
+            .ok_or_else(|| RedisClientError::ConnectionError("Redis pool not initialized".to_string()))?;
+        
+        let mut attempt = 0;
+        loop {
+            match pool.get().await {
+                Ok(connection) => {
+                    if attempt > 0 {
+                        self.reconnects.fetch_add(1, Ordering::Relaxed);
+                        info!("Reconnected to Redis after {} attempts", attempt);
+                    }
+                    return Ok(connection);
+                }
+                Err(e) => {
+                    self.is_healthy.store(false, Ordering::Relaxed);
+                    attempt += 1;
+                    if attempt > self.config.max_reconnect_attempts {
+                        return Err(match e {
+                            DeadpoolError::Timeout(_) => RedisClientError::Timeout,
+                            e => RedisClientError::ConnectionError(e.to_string()),
+                        });
+                    }
+                    let delay = self.reconnect_delay(attempt);
+                    warn!("Redis connection unavailable ({}), retrying in {:?}", e, delay);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+    
+    /// Run a command on a pooled connection
+    ///
+    /// Commands that fail because the connection dropped are retried once on a
+    /// new connection; other errors are returned as is.
+    pub async fn query<T: FromRedisValue>(&self, cmd: &Cmd) -> RedisClientResult<T> {
+        self.run(|mut connection| async move { cmd.query_async(&mut connection).await }).await
+    }
+    
+    /// Send a pipeline in one round trip; use `Pipeline::atomic` for MULTI/EXEC
+    pub async fn pipeline<T: FromRedisValue>(&self, pipeline: &Pipeline) -> RedisClientResult<T> {
+        self.run(|mut connection| async move { pipeline.query_async(&mut connection).await }).await
+    }
+    
+    /// Set several values in one pipelined round trip
+    pub async fn set_many<T: Serialize + Send + Sync>(
+        &self,
+        entries: &[(&str, T)],
+        ttl_sec: Option<u64>,
+    ) -> RedisClientResult<()> {
+        let ttl = ttl_sec.unwrap_or(self.config.default_ttl_sec);
+        let mut pipeline = redis::pipe();
+        for (key, value) in entries {
+            let data = self.config.encode_value(value)?;
+            let cmd = pipeline.cmd("SET").arg(self.full_key(key)).arg(data);
+            if ttl > 0 {
+                cmd.arg("EX").arg(ttl);
+            }
+            cmd.ignore();
+        }
+        self.pipeline(&pipeline).await
+    }
+    
+    /// Get several values in one pipelined round trip
+    pub async fn get_many<T: for<'de> Deserialize<'de>>(&self, keys: &[&str]) -> RedisClientResult<Vec<Option<T>>> {
+        let mut pipeline = redis::pipe();
+        for key in keys {
+            pipeline.cmd("GET").arg(self.full_key(key));
+        }
+        let raw: Vec<Option<Vec<u8>>> = self.pipeline(&pipeline).await?;
+        raw.into_iter()
+            .map(|data| data.map(|data| decode_value(&data)).transpose())
+            .collect()
+    }
+    
+    async fn run<T, F, Fut>(&self, f: F) -> RedisClientResult<T>
+    where
+        F: Fn(Connection) -> Fut,
+        Fut: std::future::Future<Output = RedisResult<T>>,
+    {
+        let timeout = Duration::from_millis(self.config.command_timeout_ms);
+        let mut retried = false;
+        loop {
+            let connection = self.connection().await?;
+            match tokio::time::timeout(timeout, f(connection)).await {
+                Ok(Ok(value)) => {
+                    self.is_healthy.store(true, Ordering::Relaxed);
+                    return Ok(value);
+                }
+                Ok(Err(e)) if !retried && (e.is_connection_dropped() || e.is_io_error()) => {
+                    self.is_healthy.store(false, Ordering::Relaxed);
+                    warn!("Redis connection dropped ({}), retrying on a new connection", e);
+                    retried = true;
+                }
+                Ok(Err(e)) => {
+                    if e.is_connection_dropped() || e.is_io_error() {
+                        self.is_healthy.store(false, Ordering::Relaxed);
+                    }
+                    return Err(RedisClientError::RedisError(e));
+                }
+                Err(_) => return Err(RedisClientError::Timeout),
+            }
+        }
+    }
+}
+
+impl Clone for PooledRedisClient {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            pool: self.pool.clone(),
+            is_healthy: self.is_healthy.clone(),
+            reconnects: self.reconnects.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl RedisClient for PooledRedisClient {
+    async fn initialize(&self) -> RedisClientResult<()> {
+        let pool = self.build_pool()?;
+        *self.pool.write().await = Some(pool);
+        
+        let response: String = self.query(&redis::cmd("PING")).await?;
+        if response != "PONG" {
+            return Err(RedisClientError::ConnectionError(format!("Unexpected PING response: {}", response)));
+        }
+        
+        info!(
+            "Redis pool initialized with URL: {} ({} connections max)",
+            self.config.url, self.config.max_connections
+        );
+        Ok(())
+    }
+    
+    async fn health_check(&self) -> RedisClientResult<bool> {
+        let is_healthy = matches!(self.query::<String>(&redis::cmd("PING")).await.as_deref(), Ok("PONG"));
+        self.is_healthy.store(is_healthy, Ordering::Relaxed);
+        Ok(is_healthy)
+    }
+    
+    async fn get<T: for<'de> Deserialize<'de> + Send + Sync>(&self, key: &str) -> RedisClientResult<Option<T>> {
+        let result: Option<Vec<u8>> = self.query(redis::cmd("GET").arg(self.full_key(key))).await?;
+        result.map(|data| decode_value(&data)).transpose()
+    }
+    
+    async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T, ttl_sec: Option<u64>) -> RedisClientResult<()> {
+        let data = self.config.encode_value(value)?;
+        let ttl = ttl_sec.unwrap_or(self.config.default_ttl_sec);
+        
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(self.full_key(key)).arg(data);
+        if ttl > 0 {
+            cmd.arg("EX").arg(ttl);
+        }
+        self.query::<()>(&cmd).await
+    }
+    
+    async fn delete(&self, key: &str) -> RedisClientResult<bool> {
+        let removed: i64 = self.query(redis::cmd("DEL").arg(self.full_key(key))).await?;
+        Ok(removed > 0)
+    }
+    
+    async fn increment(&self, key: &str, by: i64) -> RedisClientResult<i64> {
+        self.query(redis::cmd("INCRBY").arg(self.full_key(key)).arg(by)).await
+    }
+    
+    async fn add_to_set(&self, key: &str, member: &str) -> RedisClientResult<bool> {
+        let added: i64 = self.query(redis::cmd("SADD").arg(self.full_key(key)).arg(member)).await?;
+        Ok(added > 0)
+    }
+    
+    async fn get_set_members(&self, key: &str) -> RedisClientResult<Vec<String>> {
+        self.query(redis::cmd("SMEMBERS").arg(self.full_key(key))).await
+    }
+    
+    async fn publish<T: Serialize + Send + Sync>(&self, channel: &str, message: &T) -> RedisClientResult<i64> {
+        let data = self.config.encode_value(message)?;
+        self.query(redis::cmd("PUBLISH").arg(self.full_key(channel)).arg(data)).await
+    }
+    
+    async fn execute_command<T, F>(&self, _f: F) -> RedisClientResult<T>
+    where
+        T: redis::FromRedisValue,
+        F: FnOnce(&mut ConnectionManager) -> RedisResult<T> + Send,
+    {
+        Err(RedisClientError::Internal(
+            "Pooled client has no connection manager; use query or pipeline".to_string()
+        ))
+    }
+}
+
+/// Create and connect a pooled Redis client
+pub async fn create_pooled_redis_client(config: RedisConfig) -> RedisClientResult<Arc<PooledRedisClient>> {
+    let client = PooledRedisClient::new(config);
+    client.initialize().await?;
+    Ok(Arc::new(client))
+}
+
 /// A simple in-memory mock Redis client for testing
 pub struct MockRedisClient {
     /// In-memory key-value store
-    data: Arc<RwLock<HashMap<String, (String, Option<Instant>)>>>,
+    data: Arc<RwLock<HashMap<String, (Vec<u8>, Option<Instant>)>>>,
     
     /// In-memory sets
     sets: Arc<RwLock<HashMap<String, HashSet<String>>>>,
@@ -413,7 +728,7 @@ pub struct MockRedisClient {
     is_healthy: Arc<RwLock<bool>>,
     
     /// Published messages for testing
-    published: Arc<RwLock<Vec<(String, String)>>>,
+    published: Arc<RwLock<Vec<(String, Vec<u8>)>>>,
 }
 
 impl MockRedisClient {
@@ -428,8 +743,15 @@ impl MockRedisClient {
         }
     }
     
-    /// Get published messages for testing verification
+    /// Get published messages as text for testing verification
     pub async fn get_published_messages(&self) -> Vec<(String, String)> {
+        self.published.read().await.iter()
+            .map(|(channel, data)| (channel.clone(), String::from_utf8_lossy(data).into_owned()))
+            .collect()
+    }
+    
+    /// Get published messages as raw payloads, for binary formats
+    pub async fn get_published_payloads(&self) -> Vec<(String, Vec<u8>)> {
         self.published.read().await.clone()
     }
     
@@ -505,9 +827,7 @@ impl RedisClient for MockRedisClient {
         let data_guard = self.data.read().await;
         
         if let Some((value, _)) = data_guard.get(&full_key) {
-            let result = serde_json::from_str(value)
-                .map_err(|e| RedisClientError::SerializationError(e.to_string()))?;
-            Ok(Some(result))
+            Ok(Some(decode_value(value)?))
         } else {
             Ok(None)
         }
@@ -515,8 +835,7 @@ impl RedisClient for MockRedisClient {
     
     async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T, ttl_sec: Option<u64>) -> RedisClientResult<()> {
         let full_key = self.full_key(key);
-        let data = serde_json::to_string(value)
-            .map_err(|e| RedisClientError::SerializationError(e.to_string()))?;
+        let data = self.config.encode_value(value)?;
         
         let ttl = ttl_sec.unwrap_or(self.config.default_ttl_sec);
         let expiry = if ttl > 0 {
@@ -542,11 +861,11 @@ impl RedisClient for MockRedisClient {
         let full_key = self.full_key(key);
         let mut data_guard = self.data.write().await;
         
-        let entry = data_guard.entry(full_key).or_insert(("0".to_string(), None));
+        let entry = data_guard.entry(full_key).or_insert((b"0".to_vec(), None));
         
-        let current: i64 = entry.0.parse().unwrap_or(0);
+        let current: i64 = std::str::from_utf8(&entry.0).ok().and_then(|s| s.parse().ok()).unwrap_or(0);
         let new_value = current + by;
-        entry.0 = new_value.to_string();
+        entry.0 = new_value.to_string().into_bytes();
         
         Ok(new_value)
     }
@@ -574,8 +893,7 @@ impl RedisClient for MockRedisClient {
     
     async fn publish<T: Serialize + Send + Sync>(&self, channel: &str, message: &T) -> RedisClientResult<i64> {
         let full_channel = self.full_key(channel);
-        let data = serde_json::to_string(message)
-            .map_err(|e| RedisClientError::SerializationError(e.to_string()))?;
+        let data = self.config.encode_value(message)?;
         
         let mut published_guard = self.published.write().await;
         published_guard.push((full_channel, data));
@@ -659,6 +977,52 @@ mod tests {
         assert_eq!(messages[0].0, "test:channel1");
     }
     
+    #[tokio::test]
+    async fn test_mock_redis_reads_either_value_format() {
+        let json_client = MockRedisClient::new(RedisConfig::default());
+        let data = TestData { id: "mixed".to_string(), value: 7 };
+        json_client.set("legacy", &data, None).await.unwrap();
+        
+        // A client switched to MessagePack shares the store and still reads old JSON values
+        let msgpack_client = MockRedisClient {
+            config: RedisConfig { value_format: WireFormat::MessagePack, ..Default::default() },
+            ..json_client.clone()
+        };
+        msgpack_client.set("binary", &data, None).await.unwrap();
+        
+        assert_eq!(msgpack_client.get::<TestData>("legacy").await.unwrap(), Some(data.clone()));
+        assert_eq!(json_client.get::<TestData>("binary").await.unwrap(), Some(data.clone()));
+        
+        msgpack_client.publish("updates", &data).await.unwrap();
+        let (_, payload) = msgpack_client.get_published_payloads().await.pop().unwrap();
+        assert_eq!(decode_value::<TestData>(&payload).unwrap(), data);
+    }
+    
+    #[tokio::test]
+    async fn test_pooled_client_backoff_and_uninitialized() {
+        let config = RedisConfig {
+            reconnect_base_delay_ms: 100,
+            reconnect_max_delay_ms: 1_000,
+            ..Default::default()
+        };
+        let client = PooledRedisClient::new(config);
+        
+        assert_eq!(client.reconnect_delay(1), Duration::from_millis(100));
+        assert_eq!(client.reconnect_delay(3), Duration::from_millis(400));
+        assert_eq!(client.reconnect_delay(10), Duration::from_millis(1_000));
+        assert_eq!(client.full_key("scores"), "noderr:scores");
+        
+        // Commands before initialize fail fast instead of waiting on reconnects
+        let result: RedisClientResult<Option<TestData>> = client.get("key1").await;
+        assert!(matches!(result, Err(RedisClientError::ConnectionError(_))));
+        assert!(client.pool_status().await.is_none());
+        
+        // Older configs without the pool settings still deserialize
+        let config: RedisConfig = serde_json::from_str(r#"{"url": "redis://cache:6379"}"#).unwrap();
+        assert_eq!(config.url, "redis://cache:6379");
+        assert_eq!(config.command_timeout_ms, 2_000);
+    }
+    
     #[tokio::test]
     async fn test_mock_redis_ttl() {
         let config = RedisConfig {
@@ -0,0 +1,613 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Adaptive repricing of unfilled passive child orders
+//!
+//! Execution schedules rest child orders passively to earn the spread, but
+//! an order stuck at the back of a slow queue can miss its slot entirely.
+//! The repricer periodically checks every tracked child: if the signal that
+//! motivated it has decayed away the order is cancelled, and if its queue
+//! estimate says it is unlikely to fill before the schedule slot ends it is
+//! moved towards the touch, more aggressively as the deadline approaches.
+//! Cancel/replace traffic is rate limited per order and per venue so the
+//! repricer never trips venue request limits. With a taker overlay attached,
+//! a child may instead cross the spread when timing signals say immediate
+//! liquidity is cheap, within its parent's taker budget.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::order_lineage::PARENT_ORDER_ID_PARAM;
+use crate::order_router::{Order, OrderSide, ReplaceRequest, SmartOrderRouter, TimeInForce};
+use crate::orderbook::{self, OrderBookManager};
+use crate::queue_position::QueuePositionEstimator;
+use crate::taker_overlay::TakerOverlay;
+
+/// Order parameter marking a child that crossed the spread opportunistically
+pub const EXECUTION_STYLE_PARAM: &str = "executionStyle";
+
+/// Errors that can occur while repricing passive orders
+#[derive(Debug, Error)]
+pub enum RepricerError {
+    #[error("Invalid child order: {0}")]
+    InvalidOrder(String),
+
+    #[error("Router error: {0}")]
+    Router(String),
+
+    #[error("Taker overlay error: {0}")]
+    Overlay(String),
+}
+
+/// Repricer configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepricerConfig {
+    /// How often tracked orders are evaluated (milliseconds)
+    pub check_interval_ms: u64,
+    /// Longest horizon the fill probability is evaluated over (seconds)
+    pub max_horizon_secs: i64,
+    /// Orders at or above this fill probability are left alone
+    pub min_fill_probability: f64,
+    /// Half-life of the originating signal's strength (seconds)
+    pub signal_half_life_secs: f64,
+    /// Orders whose decayed signal strength falls below this are cancelled
+    pub min_signal_strength: f64,
+    /// Price improvement per reprice with the whole slot remaining (basis points)
+    pub base_step_bps: f64,
+    /// Minimum time between replaces of the same order (milliseconds)
+    pub min_replace_interval_ms: i64,
+    /// Maximum replaces of one child order
+    pub max_replaces_per_order: u32,
+    /// Maximum cancel/replace requests per venue per minute
+    pub max_replaces_per_venue_per_minute: usize,
+}
+
+impl Default for RepricerConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_ms: 500,
+            max_horizon_secs: 60,
+            min_fill_probability: 0.5,
+            signal_half_life_secs: 120.0,
+            min_signal_strength: 0.1,
+            base_step_bps: 1.0,
+            min_replace_interval_ms: 2_000,
+            max_replaces_per_order: 10,
+            max_replaces_per_venue_per_minute: 60,
+        }
+    }
+}
+
+/// A passive child order handed to the repricer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassiveChildOrder {
+    /// The resting order
+    pub order: Order,
+    /// Venue the order rests on
+    pub venue: String,
+    /// Parent order or schedule this child belongs to
+    pub parent_id: String,
+    /// Strength of the originating signal when the child was created (0.0-1.0)
+    pub signal_strength: f64,
+    /// When the originating signal fired
+    pub signal_at: DateTime<Utc>,
+    /// End of the schedule slot the child should fill in
+    pub deadline: DateTime<Utc>,
+    /// Worst price the repricer may move the order to
+    pub limit_price: f64,
+}
+
+/// What the repricer decided for one order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RepriceAction {
+    /// Leave the order where it is
+    Hold,
+    /// Cancel and replace at a new price
+    Reprice { new_price: f64 },
+    /// Cancel without replacement
+    Cancel,
+    /// Cancel and replace with an IOC order crossing the spread at `price`
+    Take { price: f64 },
+}
+
+/// Why the repricer made its decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RepriceReason {
+    /// Fill probability is high enough
+    LikelyToFill,
+    /// Fill probability is too low for the remaining schedule
+    UnlikelyToFill,
+    /// The originating signal has decayed
+    SignalDecayed,
+    /// The order is already at its limit price
+    AtLimit,
+    /// A cancel/replace rate limit was hit
+    RateLimited,
+    /// No queue estimate is available for the order
+    NoEstimate,
+    /// A timing signal says immediate liquidity is cheap
+    CheapLiquidity,
+}
+
+/// Outcome of evaluating one tracked order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepriceDecision {
+    /// Order ID evaluated
+    pub order_id: String,
+    /// Venue the order rests on
+    pub venue: String,
+    /// Action taken
+    pub action: RepriceAction,
+    /// Reason for the action
+    pub reason: RepriceReason,
+    /// Estimated fill probability within the horizon
+    pub fill_probability: Option<f64>,
+    /// Decayed signal strength
+    pub signal_strength: f64,
+}
+
+/// A tracked child with its repricing history
+#[derive(Debug, Clone)]
+struct TrackedChild {
+    child: PassiveChildOrder,
+    /// ID of the first order in the replace chain
+    root_order_id: String,
+    tracked_at: DateTime<Utc>,
+    replaces: u32,
+    last_replaced_at: Option<DateTime<Utc>>,
+}
+
+/// Reprices unfilled passive child orders
+pub struct PassiveRepricer {
+    /// Configuration
+    config: RepricerConfig,
+    /// Queue position estimator fed by the market data path
+    queue: Arc<QueuePositionEstimator>,
+    /// Router used to cancel and replace orders
+    router: Arc<SmartOrderRouter>,
+    /// Books used to find the queue ahead of a replacement
+    order_books: Option<Arc<OrderBookManager>>,
+    /// Overlay allowed to turn children into taker orders
+    taker_overlay: Option<Arc<TakerOverlay>>,
+    /// Tracked children by current order ID
+    children: RwLock<HashMap<String, TrackedChild>>,
+    /// Recent cancel/replace request times by venue
+    venue_requests: RwLock<HashMap<String, VecDeque<DateTime<Utc>>>>,
+    /// Evaluation task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl PassiveRepricer {
+    /// Create a new repricer
+    pub fn new(config: RepricerConfig, queue: Arc<QueuePositionEstimator>, router: Arc<SmartOrderRouter>) -> Self {
+        Self {
+            config,
+            queue,
+            router,
+            order_books: None,
+            taker_overlay: None,
+            children: RwLock::new(HashMap::new()),
+            venue_requests: RwLock::new(HashMap::new()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Look up the visible size at a replacement's price level
+    pub fn with_order_books(mut self, order_books: Arc<OrderBookManager>) -> Self {
+        self.order_books = Some(order_books);
+        self
+    }
+
+    /// Let children cross the spread opportunistically within their parent's taker budget
+    pub fn with_taker_overlay(mut self, overlay: Arc<TakerOverlay>) -> Self {
+        self.taker_overlay = Some(overlay);
+        self
+    }
+
+    /// Start tracking a resting passive child order
+    pub async fn track(&self, mut child: PassiveChildOrder, level_size_before: f64) -> Result<(), RepricerError> {
+        let valid_limit = match child.order.side {
+            OrderSide::Buy => child.limit_price >= child.order.price,
+            OrderSide::Sell => child.limit_price <= child.order.price,
+        };
+        if !valid_limit {
+            return Err(RepricerError::InvalidOrder(format!(
+                "{} limit {} is on the passive side of price {}",
+                child.order.id, child.limit_price, child.order.price
+            )));
+        }
+
+        // Replacements copy the child's parameters, so they keep its parent too
+        child.order.additional_params
+            .entry(PARENT_ORDER_ID_PARAM.to_string())
+            .or_insert_with(|| serde_json::Value::String(child.parent_id.clone()));
+
+        self.queue
+            .register_order(
+                &child.order.id,
+                &child.order.symbol,
+                book_side(child.order.side),
+                child.order.price,
+                child.order.amount,
+                level_size_before,
+            )
+            .await
+            .map_err(|e| RepricerError::InvalidOrder(e.to_string()))?;
+
+        self.children.write().await.insert(child.order.id.clone(), TrackedChild {
+            root_order_id: child.order.id.clone(),
+            child,
+            tracked_at: Utc::now(),
+            replaces: 0,
+            last_replaced_at: None,
+        });
+        Ok(())
+    }
+
+    /// Stop tracking an order (filled or cancelled elsewhere)
+    pub async fn untrack(&self, order_id: &str) -> bool {
+        self.queue.remove_order(order_id).await;
+        self.children.write().await.remove(order_id).is_some()
+    }
+
+    /// Order IDs currently tracked
+    pub async fn tracked(&self) -> Vec<String> {
+        self.children.read().await.keys().cloned().collect()
+    }
+
+    /// Decide what to do with every tracked order, without acting
+    pub async fn evaluate(&self, now: DateTime<Utc>) -> Vec<RepriceDecision> {
+        let children: Vec<TrackedChild> = self.children.read().await.values().cloned().collect();
+        let mut decisions = Vec::with_capacity(children.len());
+        for tracked in &children {
+            decisions.push(self.decide(tracked, now).await);
+        }
+        decisions
+    }
+
+    /// Evaluate every tracked order and send the resulting cancels and replaces
+    pub async fn run_cycle(&self) -> Vec<RepriceDecision> {
+        let now = Utc::now();
+        let decisions = self.evaluate(now).await;
+
+        for decision in &decisions {
+            let result = match decision.action {
+                RepriceAction::Hold => continue,
+                RepriceAction::Cancel => self.cancel(&decision.order_id, now).await,
+                RepriceAction::Reprice { new_price } => self.replace(&decision.order_id, new_price, now).await,
+                RepriceAction::Take { price } => self.take(&decision.order_id, price, now).await,
+            };
+            if let Err(e) = result {
+                warn!("Repricer failed to act on {}: {}", decision.order_id, e);
+            }
+        }
+        decisions
+    }
+
+    /// Start the periodic evaluation loop
+    pub async fn start(self: &Arc<Self>) {
+        let repricer = Arc::clone(self);
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(StdDuration::from_millis(repricer.config.check_interval_ms.max(1)));
+            loop {
+                interval.tick().await;
+                repricer.run_cycle().await;
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop the evaluation loop
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+
+    async fn decide(&self, tracked: &TrackedChild, now: DateTime<Utc>) -> RepriceDecision {
+        let child = &tracked.child;
+        let age_secs = (now - child.signal_at).num_milliseconds().max(0) as f64 / 1_000.0;
+        let signal_strength = child.signal_strength * 0.5f64.powf(age_secs / self.config.signal_half_life_secs.max(1e-9));
+
+        let decision = |action, reason, fill_probability| RepriceDecision {
+            order_id: child.order.id.clone(),
+            venue: child.venue.clone(),
+            action,
+            reason,
+            fill_probability,
+            signal_strength,
+        };
+
+        // Without the signal there is nothing left worth paying up for
+        if signal_strength < self.config.min_signal_strength {
+            return decision(RepriceAction::Cancel, RepriceReason::SignalDecayed, None);
+        }
+
+        if let Some(overlay) = &self.taker_overlay {
+            let remaining = self.remaining(&child.order).await;
+            let opportunity = overlay.evaluate(&child.parent_id, &child.order, remaining, child.limit_price, now).await;
+            if let Some(opportunity) = opportunity {
+                if self.within_rate_limits(tracked, now).await {
+                    return decision(RepriceAction::Take { price: opportunity.price }, RepriceReason::CheapLiquidity, None);
+                }
+            }
+        }
+
+        let remaining = (child.deadline - now).max(Duration::zero());
+        let horizon = remaining.min(Duration::seconds(self.config.max_horizon_secs));
+        let Ok(fill_probability) = self.queue.fill_probability(&child.order.id, horizon).await else {
+            return decision(RepriceAction::Hold, RepriceReason::NoEstimate, None);
+        };
+        if fill_probability >= self.config.min_fill_probability {
+            return decision(RepriceAction::Hold, RepriceReason::LikelyToFill, Some(fill_probability));
+        }
+
+        let at_limit = match child.order.side {
+            OrderSide::Buy => child.order.price >= child.limit_price,
+            OrderSide::Sell => child.order.price <= child.limit_price,
+        };
+        if at_limit {
+            return decision(RepriceAction::Hold, RepriceReason::AtLimit, Some(fill_probability));
+        }
+
+        if !self.within_rate_limits(tracked, now).await {
+            return decision(RepriceAction::Hold, RepriceReason::RateLimited, Some(fill_probability));
+        }
+
+        // Step size grows from base_step_bps to twice that as the slot runs out
+        let slot = (child.deadline - tracked.tracked_at).num_milliseconds().max(1) as f64;
+        let urgency = 1.0 - (remaining.num_milliseconds() as f64 / slot).clamp(0.0, 1.0);
+        let step = child.order.price * self.config.base_step_bps * (1.0 + urgency) / 10_000.0;
+        let new_price = match child.order.side {
+            OrderSide::Buy => (child.order.price + step).min(child.limit_price),
+            OrderSide::Sell => (child.order.price - step).max(child.limit_price),
+        };
+
+        decision(RepriceAction::Reprice { new_price }, RepriceReason::UnlikelyToFill, Some(fill_probability))
+    }
+
+    async fn within_rate_limits(&self, tracked: &TrackedChild, now: DateTime<Utc>) -> bool {
+        if tracked.replaces >= self.config.max_replaces_per_order {
+            return false;
+        }
+        if tracked.last_replaced_at
+            .is_some_and(|at| now - at < Duration::milliseconds(self.config.min_replace_interval_ms))
+        {
+            return false;
+        }
+
+        let requests = self.venue_requests.read().await;
+        let recent = requests.get(&tracked.child.venue)
+            .map(|times| times.iter().filter(|at| now - **at < Duration::minutes(1)).count())
+            .unwrap_or(0);
+        recent < self.config.max_replaces_per_venue_per_minute
+    }
+
+    async fn record_request(&self, venue: &str, now: DateTime<Utc>) {
+        let mut requests = self.venue_requests.write().await;
+        let times = requests.entry(venue.to_string()).or_default();
+        times.push_back(now);
+        while times.front().is_some_and(|at| now - *at >= Duration::minutes(1)) {
+            times.pop_front();
+        }
+    }
+
+    async fn cancel(&self, order_id: &str, now: DateTime<Utc>) -> Result<(), RepricerError> {
+        let Some(tracked) = self.children.write().await.remove(order_id) else {
+            return Ok(());
+        };
+        self.queue.remove_order(order_id).await;
+        self.record_request(&tracked.child.venue, now).await;
+
+        self.router.cancel_order(order_id).await
+            .map_err(|e| RepricerError::Router(e.to_string()))?;
+        debug!("Cancelled passive order {} after its signal decayed", order_id);
+        Ok(())
+    }
+
+    async fn replace(&self, order_id: &str, new_price: f64, now: DateTime<Utc>) -> Result<(), RepricerError> {
+        let Some(mut tracked) = self.children.write().await.remove(order_id) else {
+            return Ok(());
+        };
+        let remaining = self.remaining(&tracked.child.order).await;
+        self.queue.remove_order(order_id).await;
+        self.record_request(&tracked.child.venue, now).await;
+
+        tracked.replaces += 1;
+        tracked.last_replaced_at = Some(now);
+        let replacement = Order {
+            id: format!("{}-r{}", tracked.root_order_id, tracked.replaces),
+            price: new_price,
+            amount: remaining,
+            ..tracked.child.order.clone()
+        };
+
+        let result = self.router
+            .replace_batch(vec![ReplaceRequest { order_id: order_id.to_string(), replacement: replacement.clone() }])
+            .await
+            .map_err(|e| RepricerError::Router(e.to_string()))?;
+        if result.failed > 0 {
+            return Err(RepricerError::Router(format!("replace of {} was rejected", order_id)));
+        }
+
+        let level_size_before = self.visible_size(&replacement).await;
+        self.queue
+            .register_order(&replacement.id, &replacement.symbol, book_side(replacement.side), new_price, remaining, level_size_before)
+            .await
+            .map_err(|e| RepricerError::InvalidOrder(e.to_string()))?;
+
+        debug!("Repriced {} -> {} at {}", order_id, replacement.id, new_price);
+        tracked.child.order = replacement;
+        self.children.write().await.insert(tracked.child.order.id.clone(), tracked);
+        Ok(())
+    }
+
+    async fn take(&self, order_id: &str, price: f64, now: DateTime<Utc>) -> Result<(), RepricerError> {
+        let Some(overlay) = &self.taker_overlay else {
+            return Ok(());
+        };
+        let Some(tracked) = self.children.write().await.remove(order_id) else {
+            return Ok(());
+        };
+        let remaining = self.remaining(&tracked.child.order).await;
+        let parent_id = tracked.child.parent_id.clone();
+
+        // Charge the budget before sending so concurrent cycles cannot overspend it
+        if let Err(e) = overlay.consume(&parent_id, remaining, now).await {
+            self.children.write().await.insert(order_id.to_string(), tracked);
+            return Err(RepricerError::Overlay(e.to_string()));
+        }
+        self.queue.remove_order(order_id).await;
+        self.record_request(&tracked.child.venue, now).await;
+
+        let mut taker = Order {
+            id: format!("{}-t", tracked.root_order_id),
+            price,
+            amount: remaining,
+            post_only: false,
+            time_in_force: TimeInForce::IOC,
+            ..tracked.child.order.clone()
+        };
+        taker.additional_params.insert(
+            EXECUTION_STYLE_PARAM.to_string(),
+            serde_json::Value::String("opportunistic_take".to_string()),
+        );
+
+        let result = self.router
+            .replace_batch(vec![ReplaceRequest { order_id: order_id.to_string(), replacement: taker.clone() }])
+            .await;
+        match result {
+            Ok(result) if result.failed == 0 => {
+                debug!("Crossed {} -> {} at {} for parent {}", order_id, taker.id, price, parent_id);
+                Ok(())
+            }
+            Ok(_) => {
+                overlay.refund(&parent_id, remaining).await;
+                Err(RepricerError::Router(format!("taker replace of {} was rejected", order_id)))
+            }
+            Err(e) => {
+                overlay.refund(&parent_id, remaining).await;
+                Err(RepricerError::Router(e.to_string()))
+            }
+        }
+    }
+
+    /// Unfilled size of a tracked order
+    async fn remaining(&self, order: &Order) -> f64 {
+        self.queue.estimate(&order.id).await
+            .map(|estimate| estimate.remaining)
+            .unwrap_or(order.amount)
+    }
+
+    /// Visible size at an order's price before it joins the book
+    async fn visible_size(&self, order: &Order) -> f64 {
+        let Some(books) = &self.order_books else {
+            return 0.0;
+        };
+        let Some((bids, asks)) = books.get_snapshot(&order.symbol, 50) else {
+            return 0.0;
+        };
+        let levels = match order.side {
+            OrderSide::Buy => bids,
+            OrderSide::Sell => asks,
+        };
+        levels.iter().find(|level| level.price == order.price).map_or(0.0, |level| level.size)
+    }
+}
+
+/// Book side an order rests on
+fn book_side(side: OrderSide) -> orderbook::OrderSide {
+    match side {
+        OrderSide::Buy => orderbook::OrderSide::Bid,
+        OrderSide::Sell => orderbook::OrderSide::Ask,
+    }
+}
+
+/// Create a passive repricer
+pub fn create_passive_repricer(
+    config: RepricerConfig,
+    queue: Arc<QueuePositionEstimator>,
+    router: Arc<SmartOrderRouter>,
+) -> Arc<PassiveRepricer> {
+    Arc::new(PassiveRepricer::new(config, queue, router))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queue_position::QueuePositionConfig;
+
+    fn child(id: &str, price: f64, limit_price: f64, signal_age_secs: i64) -> PassiveChildOrder {
+        let now = Utc::now();
+        PassiveChildOrder {
+            order: Order {
+                symbol: "BTC/USDT".to_string(),
+                side: OrderSide::Buy,
+                amount: 1.0,
+                price,
+                venues: vec!["binance".to_string()],
+                id: id.to_string(),
+                max_slippage: None,
+                max_retries: None,
+                post_only: true,
+                reduce_only: false,
+                time_in_force: Default::default(),
+                additional_params: HashMap::new(),
+            },
+            venue: "binance".to_string(),
+            parent_id: "parent".to_string(),
+            signal_strength: 1.0,
+            signal_at: now - Duration::seconds(signal_age_secs),
+            deadline: now + Duration::seconds(30),
+            limit_price,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reprices_stuck_orders_and_cancels_decayed_ones() {
+        let queue = Arc::new(QueuePositionEstimator::new(QueuePositionConfig::default()));
+        let repricer = PassiveRepricer::new(RepricerConfig::default(), queue, Arc::new(SmartOrderRouter::new()));
+
+        // No prints have been seen, so nothing is expected to fill
+        repricer.track(child("stuck", 100.0, 100.5, 0), 50.0).await.unwrap();
+        repricer.track(child("capped", 100.0, 100.0, 0), 50.0).await.unwrap();
+        repricer.track(child("stale", 100.0, 100.5, 3_600), 50.0).await.unwrap();
+        assert!(repricer.track(child("bad", 100.0, 99.0, 0), 0.0).await.is_err());
+
+        let decisions: HashMap<String, RepriceDecision> = repricer.evaluate(Utc::now()).await
+            .into_iter()
+            .map(|d| (d.order_id.clone(), d))
+            .collect();
+
+        let RepriceAction::Reprice { new_price } = decisions["stuck"].action else {
+            panic!("expected a reprice, got {:?}", decisions["stuck"].action);
+        };
+        assert!(new_price > 100.0 && new_price <= 100.5);
+        assert_eq!(decisions["capped"].reason, RepriceReason::AtLimit);
+        assert_eq!(decisions["stale"].action, RepriceAction::Cancel);
+    }
+}
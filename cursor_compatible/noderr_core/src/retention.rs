@@ -0,0 +1,558 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Data retention and purge policies
+//!
+//! Each class of data the core keeps — raw trades, telemetry, reason chains
+//! and performance snapshots — has its own time-to-live. The retention
+//! manager works out each class's cutoff, asks every registered sink (the
+//! strategy store, Redis, the trace registry, ...) to delete what has
+//! expired, and then re-counts to verify that nothing older than the cutoff
+//! survived. Every run produces a retention report that is recorded in the
+//! audit vault, so auditors can see what was deleted, where and when.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::audit_vault::AuditVault;
+use crate::redis::{RedisClient, RedisClientError};
+use crate::storage::{StorageError, StrategyStorage};
+use crate::traceability::{TraceError, TraceRegistry};
+
+/// Actor recorded on retention audit entries
+const RETENTION_ACTOR: &str = "retention_manager";
+
+/// Errors that can occur while purging a sink
+#[derive(Debug, Error)]
+pub enum RetentionError {
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    #[error("Redis error: {0}")]
+    Redis(#[from] RedisClientError),
+
+    #[error("Trace error: {0}")]
+    Trace(#[from] TraceError),
+}
+
+/// Class of retained data, each with its own retention policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataClass {
+    /// Executions and fills
+    RawTrades,
+    /// Telemetry events
+    Telemetry,
+    /// Decision trails: signal reason chains and trace links
+    ReasonChains,
+    /// Periodic performance and state snapshots
+    Snapshots,
+}
+
+impl DataClass {
+    /// Every data class
+    pub const ALL: [DataClass; 4] = [
+        DataClass::RawTrades,
+        DataClass::Telemetry,
+        DataClass::ReasonChains,
+        DataClass::Snapshots,
+    ];
+}
+
+impl fmt::Display for DataClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataClass::RawTrades => write!(f, "raw_trades"),
+            DataClass::Telemetry => write!(f, "telemetry"),
+            DataClass::ReasonChains => write!(f, "reason_chains"),
+            DataClass::Snapshots => write!(f, "snapshots"),
+        }
+    }
+}
+
+/// Retention policy for one data class
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// How long data is kept (days)
+    pub ttl_days: u32,
+    /// Suspend purging, e.g. during an investigation or legal hold
+    #[serde(default)]
+    pub hold: bool,
+}
+
+/// Retention manager configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionConfig {
+    /// Policy per data class; classes without a policy are never purged
+    pub policies: HashMap<DataClass, RetentionPolicy>,
+    /// How often scheduled purges run (seconds)
+    pub purge_interval_secs: u64,
+    /// Maximum number of reports kept
+    pub max_reports: usize,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        let policy = |ttl_days| RetentionPolicy { ttl_days, hold: false };
+        Self {
+            policies: HashMap::from([
+                (DataClass::RawTrades, policy(7 * 365)),
+                (DataClass::Telemetry, policy(30)),
+                (DataClass::ReasonChains, policy(365)),
+                (DataClass::Snapshots, policy(90)),
+            ]),
+            purge_interval_secs: 24 * 3600,
+            max_reports: 100,
+        }
+    }
+}
+
+/// A store that holds retained data and can delete it by age
+#[async_trait]
+pub trait RetentionSink: Send + Sync {
+    /// Sink name used in reports
+    fn name(&self) -> &str;
+
+    /// Data classes this sink stores
+    fn classes(&self) -> Vec<DataClass>;
+
+    /// Delete data of `class` older than `cutoff`, returning how many items were deleted
+    async fn purge(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError>;
+
+    /// Count data of `class` older than `cutoff`
+    async fn count_expired(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError>;
+}
+
+/// Strategy storage (SQL/file backends) as a retention sink
+pub struct StorageRetentionSink {
+    storage: Arc<dyn StrategyStorage>,
+}
+
+impl StorageRetentionSink {
+    /// Wrap a strategy store
+    pub fn new(storage: Arc<dyn StrategyStorage>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl RetentionSink for StorageRetentionSink {
+    fn name(&self) -> &str {
+        "strategy_storage"
+    }
+
+    fn classes(&self) -> Vec<DataClass> {
+        DataClass::ALL.to_vec()
+    }
+
+    async fn purge(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError> {
+        Ok(self.storage.purge_before(class, cutoff).await?)
+    }
+
+    async fn count_expired(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError> {
+        Ok(self.storage.count_before(class, cutoff).await?)
+    }
+}
+
+/// Trace registry links and decisions as a reason chain sink
+pub struct TraceRetentionSink {
+    registry: Arc<TraceRegistry>,
+}
+
+impl TraceRetentionSink {
+    /// Wrap a trace registry
+    pub fn new(registry: Arc<TraceRegistry>) -> Self {
+        Self { registry }
+    }
+}
+
+#[async_trait]
+impl RetentionSink for TraceRetentionSink {
+    fn name(&self) -> &str {
+        "trace_registry"
+    }
+
+    fn classes(&self) -> Vec<DataClass> {
+        vec![DataClass::ReasonChains]
+    }
+
+    async fn purge(&self, _class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError> {
+        Ok(self.registry.purge_before(cutoff).await?)
+    }
+
+    async fn count_expired(&self, _class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError> {
+        Ok(self.registry.count_before(cutoff).await)
+    }
+}
+
+/// Redis keys indexed by data class and day
+///
+/// Writers register each key with [`RedisRetentionSink::track`]; keys are
+/// grouped into one index set per class and UTC day, so a purge deletes
+/// whole expired days without scanning the keyspace.
+pub struct RedisRetentionSink<R: RedisClient + ?Sized> {
+    redis: Arc<R>,
+    prefix: String,
+}
+
+impl<R: RedisClient + ?Sized> RedisRetentionSink<R> {
+    /// Index keys under `prefix`
+    pub fn new(redis: Arc<R>, prefix: &str) -> Self {
+        Self { redis, prefix: prefix.to_string() }
+    }
+
+    fn days_key(&self, class: DataClass) -> String {
+        format!("{}:retention:{}:days", self.prefix, class)
+    }
+
+    fn day_key(&self, class: DataClass, day: &str) -> String {
+        format!("{}:retention:{}:{}", self.prefix, class, day)
+    }
+
+    /// Register a key holding `class` data written at `at`
+    pub async fn track(&self, class: DataClass, key: &str, at: DateTime<Utc>) -> Result<(), RetentionError> {
+        let day = at.format("%Y-%m-%d").to_string();
+        self.redis.add_to_set(&self.days_key(class), &day).await?;
+        self.redis.add_to_set(&self.day_key(class, &day), key).await?;
+        Ok(())
+    }
+
+    /// Indexed days that end before `cutoff`
+    async fn expired_days(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<Vec<String>, RetentionError> {
+        let cutoff_day = cutoff.format("%Y-%m-%d").to_string();
+        let mut days: Vec<String> = self.redis
+            .get_set_members(&self.days_key(class))
+            .await?
+            .into_iter()
+            // ISO dates order lexically; the cutoff's own day is kept whole
+            .filter(|day| *day < cutoff_day)
+            .collect();
+        days.sort();
+        Ok(days)
+    }
+}
+
+#[async_trait]
+impl<R: RedisClient + ?Sized + 'static> RetentionSink for RedisRetentionSink<R> {
+    fn name(&self) -> &str {
+        "redis"
+    }
+
+    fn classes(&self) -> Vec<DataClass> {
+        DataClass::ALL.to_vec()
+    }
+
+    async fn purge(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError> {
+        let mut purged = 0;
+        for day in self.expired_days(class, cutoff).await? {
+            let day_key = self.day_key(class, &day);
+            for key in self.redis.get_set_members(&day_key).await? {
+                if self.redis.delete(&key).await? {
+                    purged += 1;
+                }
+            }
+            // The day stays listed in the class index but its key set is gone, so it counts as empty
+            self.redis.delete(&day_key).await?;
+        }
+        Ok(purged)
+    }
+
+    async fn count_expired(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, RetentionError> {
+        let mut count = 0;
+        for day in self.expired_days(class, cutoff).await? {
+            count += self.redis.get_set_members(&self.day_key(class, &day)).await?.len();
+        }
+        Ok(count)
+    }
+}
+
+/// Result of purging (or, in a dry run, counting) one class in one sink
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SinkPurge {
+    /// Sink name
+    pub sink: String,
+    /// Data class
+    pub class: DataClass,
+    /// Data older than this was expired
+    pub cutoff: DateTime<Utc>,
+    /// Expired items found before purging
+    pub expired: usize,
+    /// Items deleted
+    pub purged: usize,
+    /// Expired items still present after purging
+    pub remaining: usize,
+    /// Whether the post-purge count confirmed nothing expired remains
+    pub verified: bool,
+    /// Error raised by the sink, if any
+    pub error: Option<String>,
+}
+
+/// Audit record of one retention run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionReport {
+    /// When the run happened
+    pub generated_at: DateTime<Utc>,
+    /// Whether data was only counted, not deleted
+    pub dry_run: bool,
+    /// Classes skipped because their policy is on hold
+    pub held: Vec<DataClass>,
+    /// Per sink and class results
+    pub results: Vec<SinkPurge>,
+    /// Audit vault entry holding this report
+    pub audit_entry_id: Option<String>,
+}
+
+impl RetentionReport {
+    /// Whether every purge was verified
+    pub fn verified(&self) -> bool {
+        self.results.iter().all(|r| r.verified)
+    }
+
+    /// Total items deleted
+    pub fn total_purged(&self) -> usize {
+        self.results.iter().map(|r| r.purged).sum()
+    }
+}
+
+/// Applies per-class retention policies across all sinks
+pub struct RetentionManager {
+    /// Configuration
+    config: RetentionConfig,
+    /// Stores holding retained data
+    sinks: Vec<Arc<dyn RetentionSink>>,
+    /// Audit vault receiving retention reports
+    audit_vault: Option<Arc<AuditVault>>,
+    /// Reports of past runs
+    reports: RwLock<Vec<RetentionReport>>,
+    /// Background task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl RetentionManager {
+    /// Create a retention manager with no sinks
+    pub fn new(config: RetentionConfig) -> Self {
+        Self {
+            config,
+            sinks: Vec::new(),
+            audit_vault: None,
+            reports: RwLock::new(Vec::new()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Purge a sink
+    pub fn with_sink(mut self, sink: Arc<dyn RetentionSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Record retention reports in an audit vault
+    pub fn with_audit_vault(mut self, audit_vault: Arc<AuditVault>) -> Self {
+        self.audit_vault = Some(audit_vault);
+        self
+    }
+
+    /// Cutoff for a class as of `now`; None if the class is unmanaged or on hold
+    pub fn cutoff(&self, class: DataClass, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.config.policies
+            .get(&class)
+            .filter(|policy| !policy.hold)
+            .map(|policy| now - Duration::days(policy.ttl_days as i64))
+    }
+
+    /// Count expired data without deleting anything
+    pub async fn report(&self, now: DateTime<Utc>) -> RetentionReport {
+        self.run(now, true).await
+    }
+
+    /// Delete expired data from every sink and verify the deletion
+    pub async fn purge(&self, now: DateTime<Utc>) -> RetentionReport {
+        self.run(now, false).await
+    }
+
+    /// Reports of past runs, oldest first
+    pub async fn reports(&self) -> Vec<RetentionReport> {
+        self.reports.read().await.clone()
+    }
+
+    async fn run(&self, now: DateTime<Utc>, dry_run: bool) -> RetentionReport {
+        let held: Vec<DataClass> = DataClass::ALL
+            .into_iter()
+            .filter(|class| self.config.policies.get(class).is_some_and(|policy| policy.hold))
+            .collect();
+
+        let mut results = Vec::new();
+        for class in DataClass::ALL {
+            let Some(cutoff) = self.cutoff(class, now) else {
+                continue;
+            };
+            for sink in self.sinks.iter().filter(|sink| sink.classes().contains(&class)) {
+                results.push(purge_sink(sink.as_ref(), class, cutoff, dry_run).await);
+            }
+        }
+
+        let mut report = RetentionReport { generated_at: now, dry_run, held, results, audit_entry_id: None };
+        if !dry_run {
+            if !report.verified() {
+                error!("Retention purge could not be verified: {:?}", report.results.iter().filter(|r| !r.verified).collect::<Vec<_>>());
+            }
+            info!("Retention purge deleted {} items", report.total_purged());
+
+            if let Some(vault) = &self.audit_vault {
+                let description = format!("Retention purge deleted {} items", report.total_purged());
+                match vault.record("retention_purge", RETENTION_ACTOR, &description, serde_json::to_value(&report).unwrap_or_default()).await {
+                    Ok(entry) => report.audit_entry_id = Some(entry.entry_id),
+                    Err(e) => error!("Failed to record retention purge in the audit vault: {}", e),
+                }
+            }
+        }
+
+        let mut reports = self.reports.write().await;
+        reports.push(report.clone());
+        if reports.len() > self.config.max_reports {
+            let excess = reports.len() - self.config.max_reports;
+            reports.drain(..excess);
+        }
+        report
+    }
+
+    /// Purge on the configured interval
+    pub async fn start(self: &Arc<Self>) {
+        let manager = Arc::clone(self);
+        let interval_secs = self.config.purge_interval_secs.max(1);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(StdDuration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                manager.purge(Utc::now()).await;
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop scheduled purges
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+async fn purge_sink(sink: &dyn RetentionSink, class: DataClass, cutoff: DateTime<Utc>, dry_run: bool) -> SinkPurge {
+    let mut result = SinkPurge {
+        sink: sink.name().to_string(),
+        class,
+        cutoff,
+        expired: 0,
+        purged: 0,
+        remaining: 0,
+        verified: false,
+        error: None,
+    };
+
+    let outcome: Result<(), RetentionError> = async {
+        result.expired = sink.count_expired(class, cutoff).await?;
+        if dry_run {
+            result.remaining = result.expired;
+            return Ok(());
+        }
+        result.purged = sink.purge(class, cutoff).await?;
+        result.remaining = sink.count_expired(class, cutoff).await?;
+        result.verified = result.remaining == 0;
+        Ok(())
+    }.await;
+
+    if let Err(e) = outcome {
+        warn!("Retention of {} in {} failed: {}", class, sink.name(), e);
+        result.error = Some(e.to_string());
+    }
+    result
+}
+
+/// Create a retention manager over the strategy store
+pub fn create_retention_manager(config: RetentionConfig, storage: Arc<dyn StrategyStorage>) -> Arc<RetentionManager> {
+    Arc::new(RetentionManager::new(config).with_sink(Arc::new(StorageRetentionSink::new(storage))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::ExecutionResult;
+    use crate::storage::{InMemoryStorage, StorageConfig, StorageType, StoredExecution};
+    use crate::strategy::{Signal, SignalAction};
+
+    fn execution(id: &str, age_days: i64, now: DateTime<Utc>) -> StoredExecution {
+        let mut signal = Signal::new("strategy-1".to_string(), "BTC/USD".to_string(), SignalAction::Buy);
+        signal.reason_chain.push("risk check passed".to_string());
+        let result = ExecutionResult::success(id.to_string(), signal.id.clone(), None, 1.0, 100.0);
+        StoredExecution {
+            id: id.to_string(),
+            strategy_id: "strategy-1".to_string(),
+            symbol: "BTC/USD".to_string(),
+            timestamp: now - Duration::days(age_days),
+            signal,
+            result,
+            performance_impact: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_purge_applies_per_class_ttls_and_verifies() {
+        let now = Utc::now();
+        let storage = Arc::new(InMemoryStorage::new(StorageConfig {
+            storage_type: StorageType::Memory,
+            ..Default::default()
+        }));
+        for (id, age) in [("old", 400), ("mid", 200), ("new", 1)] {
+            storage.store_execution(execution(id, age, now)).await.unwrap();
+        }
+
+        let mut config = RetentionConfig::default();
+        config.policies.insert(DataClass::RawTrades, RetentionPolicy { ttl_days: 300, hold: false });
+        config.policies.insert(DataClass::ReasonChains, RetentionPolicy { ttl_days: 100, hold: false });
+        config.policies.insert(DataClass::Telemetry, RetentionPolicy { ttl_days: 1, hold: true });
+        let manager = create_retention_manager(config, storage.clone());
+
+        let dry = manager.report(now).await;
+        assert_eq!(storage.count_before(DataClass::RawTrades, now).await.unwrap(), 3);
+        assert_eq!(dry.held, vec![DataClass::Telemetry]);
+        assert_eq!(dry.total_purged(), 0);
+
+        let report = manager.purge(now).await;
+        assert!(report.verified());
+        let trades = report.results.iter().find(|r| r.class == DataClass::RawTrades).unwrap();
+        assert_eq!((trades.expired, trades.purged, trades.remaining), (1, 1, 0));
+
+        // The 200-day trade is kept but its reason chain is gone
+        assert!(storage.get_execution("old").await.is_err());
+        assert!(storage.get_execution("mid").await.unwrap().signal.reason_chain.is_empty());
+        assert_eq!(storage.get_execution("new").await.unwrap().signal.reason_chain.len(), 1);
+    }
+}
@@ -2007,6 +2007,7 @@ mod tests {
             execution_horizon: crate::strategy::ExecutionHorizon::Immediate,
             expected_slippage_pct: None,
             fill_confidence: None,
+            reason_chain: Vec::new(),
         }
     }
     
@@ -23,6 +23,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 use serde::{Serialize, Deserialize};
 
+use crate::allocation_constraints::{AllocationConstraints, ConstraintError};
 use crate::correlation_engine::{CorrelationEngine, CorrelationError, StrategyRiskWeights, TimePeriod};
 use crate::risk::{RiskManager, RiskManagerConfig, RiskMetrics, PositionSizing};
 use crate::strategy::{StrategyId, SignalAction, StrategyPerformance};
@@ -40,6 +41,9 @@ pub enum RiskAllocationError {
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
     
+    #[error("Constraint error: {0}")]
+    Constraints(#[from] ConstraintError),
+    
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -79,6 +83,10 @@ pub struct RiskAllocationConfig {
     
     /// Penalty factor for correlated strategies (multiplicative)
     pub correlation_penalty: f64,
+    
+    /// Portfolio constraints applied to the final allocation
+    #[serde(default)]
+    pub constraints: Option<AllocationConstraints>,
 }
 
 impl Default for RiskAllocationConfig {
@@ -94,6 +102,7 @@ impl Default for RiskAllocationConfig {
             volatility_weight: 0.3,
             sharpe_weight: 0.4,
             correlation_penalty: 0.5,
+            constraints: None,
         }
     }
 }
@@ -176,6 +185,13 @@ pub trait RiskAllocator: Send + Sync {
     /// Update allocation based on latest performance metrics
     async fn update_allocation(&self) -> RiskAllocationResult<()>;
     
+    /// Recalculate the allocation immediately, ignoring the cached one
+    async fn force_reallocation(&self) -> RiskAllocationResult<PortfolioAllocation>;
+    
+    /// Replace the current allocation with a saved one, e.g. from a state bundle;
+    /// it stands until the next scheduled recalculation
+    async fn restore_allocation(&self, allocation: PortfolioAllocation) -> RiskAllocationResult<()>;
+    
     /// Apply allocation to risk manager
     async fn apply_to_risk_manager(&self, risk_manager: &dyn RiskManager) -> RiskAllocationResult<()>;
     
@@ -423,15 +439,31 @@ impl RiskAllocator for CorrelationRiskAllocator {
             base_allocations.clone()
         };
         
+        // Project onto the portfolio constraints, limiting turnover against the current allocation
+        let unconstrained = final_allocations.clone();
+        let final_allocations = match &self.config.constraints {
+            Some(constraints) => {
+                let previous: Option<HashMap<StrategyId, f64>> = self.current_allocation.read().await
+                    .as_ref()
+                    .map(|current| current.allocations.iter().map(|a| (a.strategy_id.clone(), a.allocation)).collect());
+                constraints.apply(&final_allocations, previous.as_ref())?.weights
+            }
+            None => final_allocations,
+        };
+        
         // Convert to StrategyAllocation format with adjustments
         let mut allocations = Vec::with_capacity(final_allocations.len());
         for (strategy_id, allocation) in &final_allocations {
             let base = base_allocations.get(strategy_id).cloned().unwrap_or(0.0);
+            let adjusted = unconstrained.get(strategy_id).cloned().unwrap_or(0.0);
             
             let mut adjustments = HashMap::new();
-            if (base - allocation).abs() > 0.001 {
+            if (base - adjusted).abs() > 0.001 {
                 // Add correlation adjustment factor
-                adjustments.insert("correlation".to_string(), allocation / base);
+                adjustments.insert("correlation".to_string(), adjusted / base);
+            }
+            if (adjusted - allocation).abs() > 0.001 && adjusted > 0.0 {
+                adjustments.insert("constraints".to_string(), allocation / adjusted);
             }
             
             allocations.push(StrategyAllocation {
@@ -491,6 +523,40 @@ impl RiskAllocator for CorrelationRiskAllocator {
         Ok(())
     }
     
+    async fn force_reallocation(&self) -> RiskAllocationResult<PortfolioAllocation> {
+        // Age the cache past the update interval so the next optimization recomputes
+        {
+            let mut last_update = self.last_update.write().await;
+            *last_update = Instant::now() - Duration::from_secs(self.config.update_interval_sec + 1);
+        }
+        
+        let allocation = self.optimize_allocation().await?;
+        info!(
+            "Forced risk reallocation for {} strategies, total allocation: {:.2}%",
+            allocation.allocations.len(),
+            allocation.total_allocation * 100.0
+        );
+        
+        Ok(allocation)
+    }
+    
+    async fn restore_allocation(&self, allocation: PortfolioAllocation) -> RiskAllocationResult<()> {
+        if allocation.total_allocation > 1.0 + 1e-6 {
+            return Err(RiskAllocationError::InvalidConfig(format!(
+                "Restored allocation totals {:.2}%", allocation.total_allocation * 100.0
+            )));
+        }
+        
+        info!(
+            "Restored risk allocation for {} strategies from {}",
+            allocation.allocations.len(),
+            allocation.timestamp
+        );
+        *self.current_allocation.write().await = Some(allocation);
+        *self.last_update.write().await = Instant::now();
+        Ok(())
+    }
+    
     async fn apply_to_risk_manager(&self, risk_manager: &dyn RiskManager) -> RiskAllocationResult<()> {
         let allocation = self.optimize_allocation().await?;
         
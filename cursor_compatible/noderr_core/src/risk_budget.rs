@@ -0,0 +1,606 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, NaiveDate, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::position::{PositionChangeEvent, PositionManager, PositionResult};
+use crate::telemetry::TelemetryReporter;
+
+/// Errors that can occur while tracking risk budgets
+#[derive(Debug, Error)]
+pub enum RiskBudgetError {
+    #[error("Invalid budget: {0}")]
+    InvalidBudget(String),
+
+    #[error("Strategy not tracked: {0}")]
+    StrategyNotFound(String),
+}
+
+/// Result type for risk budget operations
+pub type RiskBudgetResult<T> = Result<T, RiskBudgetError>;
+
+/// Kind of risk budget being consumed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RiskBudgetKind {
+    /// Maximum intraday loss in quote currency
+    DailyLoss,
+    /// Maximum value-at-risk held at any point in the day
+    ValueAtRisk,
+}
+
+/// Risk budget configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskBudgetConfig {
+    /// Daily loss budget applied to strategies without an override
+    pub default_daily_loss_budget: f64,
+
+    /// VaR budget applied to strategies without an override
+    pub default_var_budget: f64,
+
+    /// Per-strategy daily loss budgets
+    pub strategy_daily_loss_budgets: HashMap<String, f64>,
+
+    /// Per-strategy VaR budgets
+    pub strategy_var_budgets: HashMap<String, f64>,
+
+    /// Fraction of budget used at which sizing starts to taper (0.0-1.0)
+    pub taper_start_pct: f64,
+
+    /// Fraction of budget used at which a warning is emitted (0.0-1.0)
+    pub warning_threshold_pct: f64,
+
+    /// Hour (UTC) at which daily budgets reset
+    pub reset_hour_utc: u32,
+
+    /// Adverse move applied to open notional when estimating VaR from positions (0.0-1.0)
+    #[serde(default = "default_var_move_pct")]
+    pub var_move_pct: f64,
+
+    /// Interval at which usage is published while tracking positions, in milliseconds
+    #[serde(default = "default_publish_interval_ms")]
+    pub publish_interval_ms: u64,
+}
+
+fn default_var_move_pct() -> f64 {
+    0.05
+}
+
+fn default_publish_interval_ms() -> u64 {
+    60_000
+}
+
+impl Default for RiskBudgetConfig {
+    fn default() -> Self {
+        Self {
+            default_daily_loss_budget: 10000.0,
+            default_var_budget: 50000.0,
+            strategy_daily_loss_budgets: HashMap::new(),
+            strategy_var_budgets: HashMap::new(),
+            taper_start_pct: 0.5,         // Start tapering at 50% used
+            warning_threshold_pct: 0.8,   // Warn at 80% used
+            reset_hour_utc: 0,
+            var_move_pct: default_var_move_pct(),
+            publish_interval_ms: default_publish_interval_ms(),
+        }
+    }
+}
+
+impl RiskBudgetConfig {
+    /// Get the configured budget of a given kind for a strategy
+    pub fn budget_for(&self, strategy_id: &str, kind: RiskBudgetKind) -> f64 {
+        match kind {
+            RiskBudgetKind::DailyLoss => self.strategy_daily_loss_budgets
+                .get(strategy_id)
+                .copied()
+                .unwrap_or(self.default_daily_loss_budget),
+            RiskBudgetKind::ValueAtRisk => self.strategy_var_budgets
+                .get(strategy_id)
+                .copied()
+                .unwrap_or(self.default_var_budget),
+        }
+    }
+}
+
+/// Budget consumption snapshot for a strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskBudgetUsage {
+    /// Strategy ID
+    pub strategy_id: String,
+
+    /// Budget kind
+    pub kind: RiskBudgetKind,
+
+    /// Budget for the current trading day
+    pub budget: f64,
+
+    /// Amount of budget consumed
+    pub consumed: f64,
+
+    /// Fraction of budget consumed (0.0-1.0, may exceed 1.0 on breach)
+    pub pct_used: f64,
+
+    /// Whether the budget is exhausted
+    pub exhausted: bool,
+
+    /// Last update time
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Intraday consumption state for a single strategy
+#[derive(Debug, Clone)]
+struct StrategyBudgetState {
+    /// Trading day this state belongs to
+    trading_day: NaiveDate,
+
+    /// Cumulative intraday PnL
+    intraday_pnl: f64,
+
+    /// Latest VaR estimate
+    current_var: f64,
+
+    /// Highest VaR observed today
+    peak_var: f64,
+
+    /// Whether a warning has already been emitted today
+    warned: bool,
+
+    /// Last update time
+    updated_at: DateTime<Utc>,
+}
+
+impl StrategyBudgetState {
+    fn new(trading_day: NaiveDate) -> Self {
+        Self {
+            trading_day,
+            intraday_pnl: 0.0,
+            current_var: 0.0,
+            peak_var: 0.0,
+            warned: false,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn consumed(&self, kind: RiskBudgetKind) -> f64 {
+        match kind {
+            RiskBudgetKind::DailyLoss => (-self.intraday_pnl).max(0.0),
+            RiskBudgetKind::ValueAtRisk => self.current_var.max(0.0),
+        }
+    }
+}
+
+/// Last seen P&L and open notional of a position feeding a budget
+#[derive(Debug, Clone, Copy, Default)]
+struct PositionMark {
+    /// Realized plus unrealized P&L
+    pnl: f64,
+
+    /// Marked-to-market notional
+    notional: f64,
+}
+
+/// Tracks real-time consumption of per-strategy risk budgets
+pub struct RiskBudgetTracker {
+    /// Budget configuration
+    config: RwLock<RiskBudgetConfig>,
+
+    /// Consumption state per strategy
+    states: RwLock<HashMap<String, StrategyBudgetState>>,
+
+    /// Position marks by strategy and symbol, used to turn position changes into budget updates
+    position_marks: RwLock<HashMap<String, HashMap<String, PositionMark>>>,
+
+    /// Telemetry reporter (optional)
+    telemetry: Option<Arc<TelemetryReporter>>,
+}
+
+impl RiskBudgetTracker {
+    /// Create a new risk budget tracker
+    pub fn new(config: RiskBudgetConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            states: RwLock::new(HashMap::new()),
+            position_marks: RwLock::new(HashMap::new()),
+            telemetry: None,
+        }
+    }
+
+    /// Attach a telemetry reporter for usage and warning events
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Record a realized or marked-to-market PnL change for a strategy
+    pub async fn record_pnl(&self, strategy_id: &str, pnl_delta: f64) -> RiskBudgetResult<()> {
+        if !pnl_delta.is_finite() {
+            return Err(RiskBudgetError::InvalidBudget(format!("Non-finite PnL: {}", pnl_delta)));
+        }
+
+        let trading_day = self.current_trading_day().await;
+        {
+            let mut states = self.states.write().await;
+            let state = Self::state_for_day(&mut states, strategy_id, trading_day);
+            state.intraday_pnl += pnl_delta;
+            state.updated_at = Utc::now();
+        }
+
+        self.check_warning(strategy_id).await;
+        Ok(())
+    }
+
+    /// Update the current VaR estimate for a strategy
+    pub async fn update_var(&self, strategy_id: &str, var: f64) -> RiskBudgetResult<()> {
+        if !var.is_finite() || var < 0.0 {
+            return Err(RiskBudgetError::InvalidBudget(format!("Invalid VaR: {}", var)));
+        }
+
+        let trading_day = self.current_trading_day().await;
+        {
+            let mut states = self.states.write().await;
+            let state = Self::state_for_day(&mut states, strategy_id, trading_day);
+            state.current_var = var;
+            state.peak_var = state.peak_var.max(var);
+            state.updated_at = Utc::now();
+        }
+
+        self.check_warning(strategy_id).await;
+        Ok(())
+    }
+
+    /// Consume budgets from a position manager's changes, treating each agent as a strategy
+    ///
+    /// P&L moves are recorded against the daily loss budget and open notional,
+    /// scaled by `var_move_pct`, against the VaR budget. Usage is published on
+    /// `publish_interval_ms` while the returned task runs.
+    pub fn track_positions(self: &Arc<Self>, position_manager: &PositionManager) -> PositionResult<JoinHandle<()>> {
+        let (tx, mut rx) = mpsc::unbounded_channel::<PositionChangeEvent>();
+        position_manager.subscribe(Arc::new(move |event: &PositionChangeEvent| {
+            let _ = tx.send(event.clone());
+        }))?;
+
+        let tracker = self.clone();
+        Ok(tokio::spawn(async move {
+            let publish_interval_ms = tracker.config.read().await.publish_interval_ms.max(1);
+            let mut publish = tokio::time::interval(std::time::Duration::from_millis(publish_interval_ms));
+            loop {
+                tokio::select! {
+                    event = rx.recv() => match event {
+                        Some(event) => tracker.apply_position_change(&event).await,
+                        None => break,
+                    },
+                    _ = publish.tick() => tracker.publish_usage().await,
+                }
+            }
+        }))
+    }
+
+    /// Record the P&L and VaR implied by a position change
+    async fn apply_position_change(&self, event: &PositionChangeEvent) {
+        let mark = PositionMark {
+            pnl: event.realized_pnl + event.unrealized_pnl,
+            // Average price plus unrealized P&L per unit is the mark price, for longs and shorts alike
+            notional: (event.net_size * event.average_price + event.unrealized_pnl).abs(),
+        };
+        let (pnl_delta, notional) = {
+            let mut marks = self.position_marks.write().await;
+            let strategy_marks = marks.entry(event.agent_id.clone()).or_default();
+            let previous = strategy_marks.insert(event.symbol.clone(), mark).unwrap_or_default();
+            (mark.pnl - previous.pnl, strategy_marks.values().map(|m| m.notional).sum::<f64>())
+        };
+        let var = notional * self.config.read().await.var_move_pct;
+
+        if pnl_delta != 0.0 {
+            if let Err(e) = self.record_pnl(&event.agent_id, pnl_delta).await {
+                warn!("Dropped P&L update for {}: {}", event.agent_id, e);
+            }
+        }
+        if let Err(e) = self.update_var(&event.agent_id, var).await {
+            warn!("Dropped VaR update for {}: {}", event.agent_id, e);
+        }
+    }
+
+    /// Get budget usage for a strategy
+    pub async fn get_usage(&self, strategy_id: &str) -> RiskBudgetResult<Vec<RiskBudgetUsage>> {
+        let trading_day = self.current_trading_day().await;
+        let config = self.config.read().await;
+        let states = self.states.read().await;
+        let state = states
+            .get(strategy_id)
+            .ok_or_else(|| RiskBudgetError::StrategyNotFound(strategy_id.to_string()))?;
+
+        Ok(Self::usage_for(strategy_id, state, &config, trading_day))
+    }
+
+    /// Get budget usage for all tracked strategies
+    pub async fn get_all_usage(&self) -> Vec<RiskBudgetUsage> {
+        let trading_day = self.current_trading_day().await;
+        let config = self.config.read().await;
+        let states = self.states.read().await;
+
+        states
+            .iter()
+            .flat_map(|(strategy_id, state)| Self::usage_for(strategy_id, state, &config, trading_day))
+            .collect()
+    }
+
+    /// Highest fraction of any budget used by a strategy
+    pub async fn max_pct_used(&self, strategy_id: &str) -> f64 {
+        self.get_usage(strategy_id)
+            .await
+            .map(|usage| usage.iter().fold(0.0, |max, u| u.pct_used.max(max)))
+            .unwrap_or(0.0)
+    }
+
+    /// Size multiplier (0.0-1.0) that tapers linearly once the taper threshold is crossed
+    pub async fn size_multiplier(&self, strategy_id: &str) -> f64 {
+        let pct_used = self.max_pct_used(strategy_id).await;
+        let taper_start = self.config.read().await.taper_start_pct.clamp(0.0, 0.99);
+
+        if pct_used <= taper_start {
+            1.0
+        } else if pct_used >= 1.0 {
+            0.0
+        } else {
+            1.0 - (pct_used - taper_start) / (1.0 - taper_start)
+        }
+    }
+
+    /// Publish usage for all strategies through telemetry
+    pub async fn publish_usage(&self) {
+        let telemetry = match &self.telemetry {
+            Some(telemetry) => telemetry,
+            None => return,
+        };
+
+        for usage in self.get_all_usage().await {
+            let mut data = HashMap::new();
+            data.insert("strategy_id".to_string(), serde_json::json!(usage.strategy_id));
+            data.insert("kind".to_string(), serde_json::json!(usage.kind));
+            data.insert("budget".to_string(), serde_json::json!(usage.budget));
+            data.insert("consumed".to_string(), serde_json::json!(usage.consumed));
+            data.insert("pct_used".to_string(), serde_json::json!(usage.pct_used));
+            telemetry.report_custom("risk_budget_usage", data).await;
+        }
+    }
+
+    /// Reset all budgets (e.g. on manual intervention)
+    pub async fn reset_all(&self) {
+        let trading_day = self.current_trading_day().await;
+        let mut states = self.states.write().await;
+        for state in states.values_mut() {
+            *state = StrategyBudgetState::new(trading_day);
+        }
+        info!("Reset risk budgets for {} strategies", states.len());
+    }
+
+    /// Update configuration
+    pub async fn update_config(&self, config: RiskBudgetConfig) {
+        let mut current_config = self.config.write().await;
+        *current_config = config;
+    }
+
+    /// Get current configuration
+    pub async fn get_config(&self) -> RiskBudgetConfig {
+        self.config.read().await.clone()
+    }
+
+    /// Trading day for the current instant, honouring the configured reset hour
+    async fn current_trading_day(&self) -> NaiveDate {
+        let reset_hour = self.config.read().await.reset_hour_utc.min(23);
+        let now = Utc::now();
+        if now.hour() < reset_hour {
+            (now - Duration::days(1)).date_naive()
+        } else {
+            now.date_naive()
+        }
+    }
+
+    /// Get the state for a strategy, rolling it over if the trading day changed
+    fn state_for_day<'a>(
+        states: &'a mut HashMap<String, StrategyBudgetState>,
+        strategy_id: &str,
+        trading_day: NaiveDate,
+    ) -> &'a mut StrategyBudgetState {
+        let state = states
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| StrategyBudgetState::new(trading_day));
+
+        if state.trading_day != trading_day {
+            debug!("Rolling over risk budget for {} to {}", strategy_id, trading_day);
+            *state = StrategyBudgetState::new(trading_day);
+        }
+
+        state
+    }
+
+    /// Build usage snapshots from a strategy state
+    ///
+    /// State left over from an earlier trading day reads as a fresh day, so a
+    /// strategy that stopped updating is not held to yesterday's losses.
+    fn usage_for(
+        strategy_id: &str,
+        state: &StrategyBudgetState,
+        config: &RiskBudgetConfig,
+        trading_day: NaiveDate,
+    ) -> Vec<RiskBudgetUsage> {
+        let fresh;
+        let state = if state.trading_day == trading_day {
+            state
+        } else {
+            fresh = StrategyBudgetState::new(trading_day);
+            &fresh
+        };
+
+        [RiskBudgetKind::DailyLoss, RiskBudgetKind::ValueAtRisk]
+            .iter()
+            .map(|kind| {
+                let budget = config.budget_for(strategy_id, *kind);
+                let consumed = state.consumed(*kind);
+                let pct_used = if budget > 0.0 { consumed / budget } else { 1.0 };
+
+                RiskBudgetUsage {
+                    strategy_id: strategy_id.to_string(),
+                    kind: *kind,
+                    budget,
+                    consumed,
+                    pct_used,
+                    exhausted: pct_used >= 1.0,
+                    updated_at: state.updated_at,
+                }
+            })
+            .collect()
+    }
+
+    /// Emit a warning the first time a strategy crosses the warning threshold
+    async fn check_warning(&self, strategy_id: &str) {
+        let threshold = self.config.read().await.warning_threshold_pct;
+        let pct_used = self.max_pct_used(strategy_id).await;
+
+        let should_warn = {
+            let mut states = self.states.write().await;
+            match states.get_mut(strategy_id) {
+                Some(state) if pct_used >= threshold && !state.warned => {
+                    state.warned = true;
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        if should_warn {
+            warn!(
+                "Risk budget for {} at {:.1}% (warning threshold {:.1}%)",
+                strategy_id,
+                pct_used * 100.0,
+                threshold * 100.0
+            );
+
+            if let Some(telemetry) = &self.telemetry {
+                let mut data = HashMap::new();
+                data.insert("strategy_id".to_string(), serde_json::json!(strategy_id));
+                data.insert("pct_used".to_string(), serde_json::json!(pct_used));
+                data.insert("threshold".to_string(), serde_json::json!(threshold));
+                telemetry.report_custom("risk_budget_warning", data).await;
+            }
+        }
+    }
+}
+
+/// Create a risk budget tracker with default configuration
+pub fn create_risk_budget_tracker() -> Arc<RiskBudgetTracker> {
+    Arc::new(RiskBudgetTracker::new(RiskBudgetConfig::default()))
+}
+
+/// Create a risk budget tracker with custom configuration
+pub fn create_risk_budget_tracker_with_config(config: RiskBudgetConfig) -> Arc<RiskBudgetTracker> {
+    Arc::new(RiskBudgetTracker::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_daily_loss_consumption() {
+        let config = RiskBudgetConfig {
+            default_daily_loss_budget: 1000.0,
+            ..Default::default()
+        };
+        let tracker = RiskBudgetTracker::new(config);
+
+        tracker.record_pnl("strat1", -250.0).await.unwrap();
+        tracker.record_pnl("strat1", 50.0).await.unwrap();
+
+        let usage = tracker.get_usage("strat1").await.unwrap();
+        let daily = usage.iter().find(|u| u.kind == RiskBudgetKind::DailyLoss).unwrap();
+        assert!((daily.consumed - 200.0).abs() < 1e-9);
+        assert!((daily.pct_used - 0.2).abs() < 1e-9);
+        assert!(!daily.exhausted);
+    }
+
+    #[tokio::test]
+    async fn test_size_multiplier_tapers() {
+        let config = RiskBudgetConfig {
+            default_daily_loss_budget: 1000.0,
+            taper_start_pct: 0.5,
+            ..Default::default()
+        };
+        let tracker = RiskBudgetTracker::new(config);
+
+        // Below the taper threshold
+        tracker.record_pnl("strat1", -400.0).await.unwrap();
+        assert_eq!(tracker.size_multiplier("strat1").await, 1.0);
+
+        // Halfway between threshold and exhaustion
+        tracker.record_pnl("strat1", -350.0).await.unwrap();
+        assert!((tracker.size_multiplier("strat1").await - 0.5).abs() < 1e-9);
+
+        // Exhausted
+        tracker.record_pnl("strat1", -500.0).await.unwrap();
+        assert_eq!(tracker.size_multiplier("strat1").await, 0.0);
+
+        // Without further updates, the next trading day starts with a full budget
+        let yesterday = tracker.current_trading_day().await - Duration::days(1);
+        tracker.states.write().await.get_mut("strat1").unwrap().trading_day = yesterday;
+        assert_eq!(tracker.size_multiplier("strat1").await, 1.0);
+        assert_eq!(tracker.get_usage("strat1").await.unwrap()[0].consumed, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_position_changes_consume_budgets() {
+        use crate::position::{OrderOrFill, Side};
+
+        let config = RiskBudgetConfig {
+            default_daily_loss_budget: 100.0,
+            default_var_budget: 1000.0,
+            var_move_pct: 0.1,
+            ..Default::default()
+        };
+        let tracker = Arc::new(RiskBudgetTracker::new(config));
+        let position_manager = PositionManager::new();
+        let task = tracker.track_positions(&position_manager).unwrap();
+
+        let fill = |side: Side, price: f64| OrderOrFill {
+            symbol: "BTC/USD".to_string(),
+            side,
+            size: 1.0,
+            price,
+            timestamp: Utc::now(),
+            order_id: "order-1".to_string(),
+            fill_id: None,
+            is_fill: true,
+            venue: None,
+            strategy_id: None,
+        };
+        position_manager.update_position("strat1", &fill(Side::Buy, 100.0)).unwrap();
+        position_manager.update_position("strat1", &fill(Side::Buy, 80.0)).unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        // Two units marked at 80 against an average of 90
+        let usage = tracker.get_usage("strat1").await.unwrap();
+        let daily = usage.iter().find(|u| u.kind == RiskBudgetKind::DailyLoss).unwrap();
+        let var = usage.iter().find(|u| u.kind == RiskBudgetKind::ValueAtRisk).unwrap();
+        assert!((daily.consumed - 20.0).abs() < 1e-9);
+        assert!((var.consumed - 16.0).abs() < 1e-9);
+
+        task.abort();
+    }
+}
@@ -24,6 +24,11 @@ use chrono::{DateTime, Utc};
 use crate::strategy::{Signal, Strategy, StrategyId};
 use crate::risk::{RiskError, RiskManager, PositionDirection};
 use crate::market::MarketData;
+use crate::netting::{self, NettedExposure, NettingGroup};
+use crate::trading_events::{TradingEvent, TradingEventBus};
+use crate::volatility::{VolatilityEstimator, VolatilityService};
+use crate::connectors::{ConnectorConfig, ConnectorResult, VenueConnector};
+use crate::order_router::SmartOrderRouter;
 
 /// Risk manager configuration optimized for latency-critical operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +62,31 @@ pub struct RiskConfig {
     
     /// Fast risk check mode (skips some non-critical checks)
     pub fast_risk_mode: bool,
+    
+    /// Maximum exposure per sector as percentage of portfolio (0.0-1.0)
+    pub max_exposure_per_sector: f64,
+    
+    /// Sector classification by symbol (unclassified symbols are not sector-checked)
+    pub symbol_sectors: HashMap<String, String>,
+    
+    /// Fraction of a concentration limit at which a warning is reported (0.0-1.0)
+    pub concentration_warning_pct: f64,
+    
+    /// Absolute limit on capital at risk per venue (balance held + open order notional)
+    pub venue_exposure_limits: HashMap<String, f64>,
+    
+    /// Default absolute venue limit for venues without an explicit limit
+    pub default_venue_exposure_limit: Option<f64>,
+    
+    /// Fraction of a venue limit at which an alert is raised (0.0-1.0)
+    pub venue_alert_threshold_pct: f64,
+    
+    /// Target utilization of a venue limit that withdrawals should bring it back to (0.0-1.0)
+    pub venue_target_utilization_pct: f64,
+    
+    /// Correlated instruments whose symbol limit applies to their netted exposure
+    #[serde(default)]
+    pub netting_groups: Vec<NettingGroup>,
 }
 
 impl Default for RiskConfig {
@@ -72,10 +102,31 @@ impl Default for RiskConfig {
             webhook_url: None,
             exempt_strategies: HashSet::new(),
             fast_risk_mode: false,
+            max_exposure_per_sector: 0.5,    // 50% per sector
+            symbol_sectors: HashMap::new(),
+            concentration_warning_pct: 0.8,  // Warn at 80% of a limit
+            venue_exposure_limits: HashMap::new(),
+            default_venue_exposure_limit: None,
+            venue_alert_threshold_pct: 0.8,  // Alert at 80% of a venue limit
+            venue_target_utilization_pct: 0.6, // Withdraw back down to 60%
+            netting_groups: Vec::new(),
         }
     }
 }
 
+/// Volatility ceiling checked against the shared volatility service
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VolatilityLimit {
+    /// Estimator to read
+    pub estimator: VolatilityEstimator,
+    /// Horizon the estimate covers (seconds)
+    pub horizon_secs: u64,
+    /// Highest volatility allowed over the horizon
+    pub max_volatility: f64,
+    /// Severity of a breach
+    pub severity: RiskViolationSeverity,
+}
+
 /// Position exposure tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionExposure {
@@ -130,6 +181,14 @@ impl PositionExposure {
             id: Uuid::new_v4().to_string(),
         }
     }
+    
+    /// Position value signed by direction (shorts negative)
+    pub fn signed_value(&self) -> f64 {
+        match self.direction {
+            PositionDirection::Short => -self.value,
+            _ => self.value,
+        }
+    }
 }
 
 /// Venue exposure tracking
@@ -146,6 +205,85 @@ pub struct VenueExposure {
     
     /// Positions count on this venue
     pub position_count: usize,
+    
+    /// Balance currently held on this venue (unsettled capital at risk)
+    pub balance_held: f64,
+    
+    /// Notional value of resting orders on this venue
+    pub open_order_notional: f64,
+}
+
+impl VenueExposure {
+    /// Create an empty venue exposure
+    pub fn new(venue: &str, trust_score: f64) -> Self {
+        Self {
+            venue: venue.to_string(),
+            total_value: 0.0,
+            trust_score,
+            position_count: 0,
+            balance_held: 0.0,
+            open_order_notional: 0.0,
+        }
+    }
+    
+    /// Capital at risk if this venue fails (balance held + open order notional)
+    pub fn settlement_exposure(&self) -> f64 {
+        self.balance_held + self.open_order_notional
+    }
+}
+
+/// Alert raised when a venue approaches or exceeds its exposure limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueLimitAlert {
+    /// Venue name
+    pub venue: String,
+    
+    /// Balance held + open order notional
+    pub settlement_exposure: f64,
+    
+    /// Configured limit
+    pub limit: f64,
+    
+    /// Fraction of limit used
+    pub utilization: f64,
+    
+    /// Alert severity
+    pub severity: RiskViolationSeverity,
+}
+
+/// Recommended withdrawal to reduce capital parked on a venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalRecommendation {
+    /// Venue to withdraw from
+    pub venue: String,
+    
+    /// Amount to withdraw in quote currency
+    pub amount: f64,
+    
+    /// Free balance available to withdraw (balance not backing open orders)
+    pub withdrawable: f64,
+    
+    /// Utilization after the withdrawal
+    pub utilization_after: f64,
+    
+    /// Reason for the recommendation
+    pub reason: String,
+}
+
+/// Withdrawal recommendation report across all venues
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalReport {
+    /// Report timestamp
+    pub timestamp: DateTime<Utc>,
+    
+    /// Total capital held across venues
+    pub total_settlement_exposure: f64,
+    
+    /// Venue alerts at the time of the report
+    pub alerts: Vec<VenueLimitAlert>,
+    
+    /// Recommended withdrawals, largest first
+    pub recommendations: Vec<WithdrawalRecommendation>,
 }
 
 /// Risk calculation result
@@ -245,6 +383,9 @@ pub enum RiskViolationType {
     /// Symbol exposure too high
     SymbolExposure,
     
+    /// Sector exposure too high
+    SectorExposure,
+    
     /// Drawdown too high
     Drawdown,
     
@@ -271,6 +412,68 @@ pub enum RiskViolationSeverity {
     Critical,
 }
 
+/// Dimension along which portfolio concentration is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConcentrationDimension {
+    /// Single-name exposure
+    Symbol,
+    /// Sector exposure
+    Sector,
+    /// Venue exposure
+    Venue,
+}
+
+/// Concentration of a single bucket (symbol, sector or venue)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcentrationEntry {
+    /// Dimension of this bucket
+    pub dimension: ConcentrationDimension,
+    
+    /// Bucket key (symbol, sector or venue name)
+    pub key: String,
+    
+    /// Exposure value in quote currency
+    pub value: f64,
+    
+    /// Exposure as percentage of portfolio (0.0-1.0)
+    pub pct_of_portfolio: f64,
+    
+    /// Configured limit (0.0-1.0)
+    pub limit: f64,
+}
+
+/// Portfolio concentration report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcentrationReport {
+    /// Report timestamp
+    pub timestamp: DateTime<Utc>,
+    
+    /// Portfolio value used for percentages
+    pub portfolio_value: f64,
+    
+    /// Concentration by bucket, largest first
+    pub entries: Vec<ConcentrationEntry>,
+    
+    /// Limit breaches and warnings
+    pub violations: Vec<RiskViolation>,
+}
+
+/// Effect of a proposed position on portfolio concentration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConcentrationImpact {
+    /// Buckets touched by the proposed position, before the change
+    pub before: Vec<ConcentrationEntry>,
+    
+    /// The same buckets after the change
+    pub after: Vec<ConcentrationEntry>,
+    
+    /// Violations the proposed position would introduce
+    pub violations: Vec<RiskViolation>,
+    
+    /// Whether the position can be submitted without a critical breach
+    pub allowed: bool,
+}
+
 /// Risk calculator for fast risk limit checks
 pub struct RiskCalculator {
     /// Risk configuration
@@ -293,6 +496,12 @@ pub struct RiskCalculator {
     
     /// Last check timestamp
     last_check: Arc<RwLock<DateTime<Utc>>>,
+    
+    /// Push notifications for risk violations (optional)
+    event_bus: Option<Arc<TradingEventBus>>,
+    
+    /// Symbol volatility ceiling and its data source (optional)
+    volatility_limit: Option<(Arc<VolatilityService>, VolatilityLimit)>,
 }
 
 impl RiskCalculator {
@@ -306,9 +515,28 @@ impl RiskCalculator {
             symbol_exposures: Arc::new(RwLock::new(HashMap::new())),
             trust_scores: Arc::new(RwLock::new(HashMap::new())),
             last_check: Arc::new(RwLock::new(Utc::now())),
+            event_bus: None,
+            volatility_limit: None,
         }
     }
     
+    /// Publish risk violations to an event bus
+    pub fn with_event_bus(mut self, event_bus: Arc<TradingEventBus>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+    
+    /// Flag positions in symbols whose volatility exceeds a limit
+    pub fn with_volatility_limit(mut self, service: Arc<VolatilityService>, limit: VolatilityLimit) -> Self {
+        self.volatility_limit = Some((service, limit));
+        self
+    }
+    
+    /// Get current portfolio value
+    pub async fn get_portfolio_value(&self) -> f64 {
+        *self.portfolio_value.read().await
+    }
+    
     /// Update portfolio value
     pub async fn update_portfolio_value(&self, value: f64) {
         let mut portfolio_value = self.portfolio_value.write().await;
@@ -374,12 +602,7 @@ impl RiskCalculator {
         
         let venue_exposure = venue_exposures
             .entry(position.venue.clone())
-            .or_insert(VenueExposure {
-                venue: position.venue.clone(),
-                total_value: 0.0,
-                trust_score: position.trust_score,
-                position_count: 0,
-            });
+            .or_insert_with(|| VenueExposure::new(&position.venue, position.trust_score));
         
         if is_removal {
             venue_exposure.total_value -= position.value;
@@ -433,6 +656,193 @@ impl RiskCalculator {
         }
     }
     
+    /// Update the balance held on a venue
+    pub async fn update_venue_balance(&self, venue: &str, balance: f64) -> Vec<VenueLimitAlert> {
+        {
+            let trust_score = self.get_trust_score(venue).await;
+            let mut venue_exposures = self.venue_exposures.write().await;
+            let venue_exposure = venue_exposures
+                .entry(venue.to_string())
+                .or_insert_with(|| VenueExposure::new(venue, trust_score));
+            venue_exposure.balance_held = balance.max(0.0);
+        }
+        
+        self.check_venue_limits().await
+    }
+    
+    /// Update the notional value of resting orders on a venue
+    pub async fn update_open_order_notional(&self, venue: &str, notional: f64) -> Vec<VenueLimitAlert> {
+        {
+            let trust_score = self.get_trust_score(venue).await;
+            let mut venue_exposures = self.venue_exposures.write().await;
+            let venue_exposure = venue_exposures
+                .entry(venue.to_string())
+                .or_insert_with(|| VenueExposure::new(venue, trust_score));
+            venue_exposure.open_order_notional = notional.max(0.0);
+        }
+        
+        self.check_venue_limits().await
+    }
+    
+    /// Check all venues against their absolute exposure limits
+    pub async fn check_venue_limits(&self) -> Vec<VenueLimitAlert> {
+        let config = self.config.read().await;
+        let venue_exposures = self.venue_exposures.read().await;
+        
+        let mut alerts = Vec::new();
+        for venue_exposure in venue_exposures.values() {
+            let limit = match Self::venue_limit(&config, &venue_exposure.venue) {
+                Some(limit) if limit > 0.0 => limit,
+                _ => continue,
+            };
+            
+            let exposure = venue_exposure.settlement_exposure();
+            let utilization = exposure / limit;
+            
+            let severity = if utilization > 1.0 {
+                RiskViolationSeverity::Critical
+            } else if utilization >= config.venue_alert_threshold_pct {
+                RiskViolationSeverity::Warning
+            } else {
+                continue;
+            };
+            
+            warn!(
+                "Venue {} settlement exposure at {:.2}% of limit ({:.2} / {:.2})",
+                venue_exposure.venue,
+                utilization * 100.0,
+                exposure,
+                limit
+            );
+            
+            alerts.push(VenueLimitAlert {
+                venue: venue_exposure.venue.clone(),
+                settlement_exposure: exposure,
+                limit,
+                utilization,
+                severity,
+            });
+        }
+        
+        alerts
+    }
+    
+    /// Build a report recommending withdrawals from venues holding too much capital
+    pub async fn withdrawal_recommendations(&self) -> WithdrawalReport {
+        let alerts = self.check_venue_limits().await;
+        let config = self.config.read().await;
+        let venue_exposures = self.venue_exposures.read().await;
+        
+        let mut recommendations = Vec::new();
+        let mut total_settlement_exposure = 0.0;
+        
+        for venue_exposure in venue_exposures.values() {
+            total_settlement_exposure += venue_exposure.settlement_exposure();
+            
+            let limit = match Self::venue_limit(&config, &venue_exposure.venue) {
+                Some(limit) if limit > 0.0 => limit,
+                _ => continue,
+            };
+            
+            let exposure = venue_exposure.settlement_exposure();
+            if exposure / limit < config.venue_alert_threshold_pct {
+                continue;
+            }
+            
+            // Only free balance can be withdrawn; capital backing open orders stays
+            let target = limit * config.venue_target_utilization_pct;
+            let withdrawable = (venue_exposure.balance_held - venue_exposure.open_order_notional).max(0.0);
+            let amount = (exposure - target).max(0.0).min(withdrawable);
+            
+            if amount <= 0.0 {
+                continue;
+            }
+            
+            recommendations.push(WithdrawalRecommendation {
+                venue: venue_exposure.venue.clone(),
+                amount,
+                withdrawable,
+                utilization_after: (exposure - amount) / limit,
+                reason: format!(
+                    "Settlement exposure {:.2} is {:.1}% of limit {:.2}",
+                    exposure,
+                    exposure / limit * 100.0,
+                    limit
+                ),
+            });
+        }
+        
+        recommendations.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap_or(std::cmp::Ordering::Equal));
+        
+        WithdrawalReport {
+            timestamp: Utc::now(),
+            total_settlement_exposure,
+            alerts,
+            recommendations,
+        }
+    }
+    
+    /// Set or clear the absolute exposure limit of a venue
+    pub async fn set_venue_exposure_limit(&self, venue: &str, limit: Option<f64>) {
+        let mut config = self.config.write().await;
+        match limit {
+            Some(limit) => config.venue_exposure_limits.insert(venue.to_string(), limit),
+            None => config.venue_exposure_limits.remove(venue),
+        };
+    }
+    
+    /// Refresh a venue's limit from its connector config, its balance from the
+    /// venue and its open order notional from the router
+    pub async fn sync_venue_exposure(
+        &self,
+        venue_config: &ConnectorConfig,
+        connector: &dyn VenueConnector,
+        order_router: &SmartOrderRouter,
+    ) -> ConnectorResult<Vec<VenueLimitAlert>> {
+        let venue = connector.venue_id();
+        self.set_venue_exposure_limit(venue, venue_config.exposure_limit).await;
+        
+        let balances = connector.fetch_balances().await?;
+        let balance_held: f64 = balances
+            .iter()
+            .filter(|balance| venue_config.settlement_assets.iter().any(|asset| asset.eq_ignore_ascii_case(&balance.asset)))
+            .map(|balance| balance.total())
+            .sum();
+        self.update_venue_balance(venue, balance_held).await;
+        
+        let notional = order_router.open_order_notional(venue).await;
+        Ok(self.update_open_order_notional(venue, notional).await)
+    }
+    
+    /// Periodically sync the exposure of each connected venue
+    pub fn start_venue_sync(
+        self: &Arc<Self>,
+        venues: Vec<(ConnectorConfig, Arc<dyn VenueConnector>)>,
+        order_router: Arc<SmartOrderRouter>,
+        interval: std::time::Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let calculator = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                for (venue_config, connector) in &venues {
+                    if let Err(e) = calculator.sync_venue_exposure(venue_config, connector.as_ref(), &order_router).await {
+                        warn!("Failed to sync exposure for venue {}: {}", connector.venue_id(), e);
+                    }
+                }
+            }
+        })
+    }
+    
+    /// Absolute exposure limit configured for a venue
+    fn venue_limit(config: &RiskConfig, venue: &str) -> Option<f64> {
+        config.venue_exposure_limits
+            .get(venue)
+            .copied()
+            .or(config.default_venue_exposure_limit)
+    }
+    
     /// Set trust score for a venue
     pub async fn set_trust_score(&self, venue: &str, score: f64) {
         let mut trust_scores = self.trust_scores.write().await;
@@ -445,11 +855,35 @@ impl RiskCalculator {
         *trust_scores.get(venue).unwrap_or(&0.5)
     }
     
-    /// Perform fast risk check for a position
+    /// Perform fast risk check for a position, publishing any violations
     pub async fn fast_risk_check(
         &self,
         position: &PositionExposure,
         strategy_id: Option<&str>,
+    ) -> RiskCheckResult {
+        let result = self.evaluate_position(position, strategy_id).await;
+        
+        if !result.passed {
+            if let Some(event_bus) = &self.event_bus {
+                event_bus.publish(TradingEvent::RiskViolation {
+                    symbol: position.symbol.clone(),
+                    venue: position.venue.clone(),
+                    strategy_id: strategy_id.map(String::from),
+                    violations: result.violations.clone(),
+                    risk_level: result.risk_level,
+                    timestamp: Utc::now(),
+                });
+            }
+        }
+        
+        result
+    }
+    
+    /// Run the pre-trade risk checks for a position without side effects
+    pub async fn evaluate_position(
+        &self,
+        position: &PositionExposure,
+        strategy_id: Option<&str>,
     ) -> RiskCheckResult {
         let config = self.config.read().await;
         let portfolio_value = *self.portfolio_value.read().await;
@@ -528,16 +962,55 @@ impl RiskCalculator {
             }
         }
         
-        // Check symbol exposure (current + new position)
-        let symbol_exposures = self.symbol_exposures.read().await;
-        let current_symbol_exposure = symbol_exposures.get(&position.symbol).copied().unwrap_or(0.0);
-        let new_symbol_exposure = (current_symbol_exposure + position.value) / portfolio_value;
+        // Check the venue's absolute settlement limit; the new order adds to the
+        // capital parked on the venue until it fills and settles
+        if let Some(limit) = Self::venue_limit(&config, &position.venue).filter(|limit| *limit > 0.0) {
+            let current = venue_exposures
+                .get(&position.venue)
+                .map(VenueExposure::settlement_exposure)
+                .unwrap_or(0.0);
+            let new_settlement_exposure = current + position.value;
+            if new_settlement_exposure > limit {
+                violations.push(RiskViolation {
+                    violation_type: RiskViolationType::VenueExposure,
+                    description: format!(
+                        "Settlement exposure on {} exceeds limit: {:.2} > {:.2}",
+                        position.venue,
+                        new_settlement_exposure,
+                        limit
+                    ),
+                    actual_value: new_settlement_exposure,
+                    limit_value: limit,
+                    severity: RiskViolationSeverity::Critical,
+                });
+            }
+        }
+        
+        // Check symbol exposure (current + new position), netted across the symbol's group
+        let (new_symbol_exposure, exposure_label) = match netting::find_group(&config.netting_groups, &position.symbol) {
+            Some(group) => {
+                let positions = self.positions.read().await;
+                let netted = group.net(
+                    positions
+                        .values()
+                        .chain(std::iter::once(position))
+                        .map(|p| (p.symbol.as_str(), p.signed_value())),
+                );
+                (netted.net / portfolio_value, format!("Netted exposure for {}", group.name))
+            }
+            None => {
+                let symbol_exposures = self.symbol_exposures.read().await;
+                let current_symbol_exposure = symbol_exposures.get(&position.symbol).copied().unwrap_or(0.0);
+                ((current_symbol_exposure + position.value) / portfolio_value, "Symbol exposure".to_string())
+            }
+        };
         
         if new_symbol_exposure > config.max_exposure_per_symbol {
             violations.push(RiskViolation {
                 violation_type: RiskViolationType::SymbolExposure,
                 description: format!(
-                    "Symbol exposure exceeds limit: {:.2}% > {:.2}%", 
+                    "{} exceeds limit: {:.2}% > {:.2}%", 
+                    exposure_label,
                     new_symbol_exposure * 100.0, 
                     config.max_exposure_per_symbol * 100.0
                 ),
@@ -547,6 +1020,53 @@ impl RiskCalculator {
             });
         }
         
+        // Check sector exposure (current + new position)
+        if let Some(sector) = config.symbol_sectors.get(&position.symbol) {
+            let positions = self.positions.read().await;
+            let current_sector_exposure: f64 = positions
+                .values()
+                .filter(|p| config.symbol_sectors.get(&p.symbol) == Some(sector))
+                .map(|p| p.value)
+                .sum();
+            let new_sector_exposure = (current_sector_exposure + position.value) / portfolio_value;
+            
+            if new_sector_exposure > config.max_exposure_per_sector {
+                violations.push(RiskViolation {
+                    violation_type: RiskViolationType::SectorExposure,
+                    description: format!(
+                        "Sector exposure for {} exceeds limit: {:.2}% > {:.2}%", 
+                        sector,
+                        new_sector_exposure * 100.0, 
+                        config.max_exposure_per_sector * 100.0
+                    ),
+                    actual_value: new_sector_exposure,
+                    limit_value: config.max_exposure_per_sector,
+                    severity: RiskViolationSeverity::Critical,
+                });
+            }
+        }
+        
+        // Check symbol volatility; symbols without estimates yet are not flagged
+        if let Some((service, limit)) = &self.volatility_limit {
+            if let Some(volatility) = service.volatility(&position.symbol, limit.estimator, limit.horizon_secs) {
+                if volatility > limit.max_volatility {
+                    violations.push(RiskViolation {
+                        violation_type: RiskViolationType::HighVolatility,
+                        description: format!(
+                            "{} volatility over {}s exceeds limit: {:.2}% > {:.2}%",
+                            position.symbol,
+                            limit.horizon_secs,
+                            volatility * 100.0,
+                            limit.max_volatility * 100.0
+                        ),
+                        actual_value: volatility,
+                        limit_value: limit.max_volatility,
+                        severity: limit.severity,
+                    });
+                }
+            }
+        }
+        
         // Return result
         if violations.is_empty() {
             RiskCheckResult::pass()
@@ -559,6 +1079,8 @@ impl RiskCalculator {
                     RiskViolationType::TrustScore => (v.limit_value - v.actual_value) / v.limit_value,
                     RiskViolationType::VenueExposure => v.actual_value / v.limit_value,
                     RiskViolationType::SymbolExposure => v.actual_value / v.limit_value,
+                    RiskViolationType::SectorExposure => v.actual_value / v.limit_value,
+                    RiskViolationType::HighVolatility => v.actual_value / v.limit_value,
                     _ => 1.0,
                 };
                 
@@ -569,6 +1091,170 @@ impl RiskCalculator {
         }
     }
     
+    /// Build a concentration report for the current portfolio
+    pub async fn check_concentration(&self) -> ConcentrationReport {
+        let config = self.config.read().await;
+        let portfolio_value = *self.portfolio_value.read().await;
+        let positions = self.positions.read().await;
+        
+        let buckets = Self::concentration_buckets(positions.values(), &config);
+        let mut entries: Vec<ConcentrationEntry> = buckets
+            .into_iter()
+            .map(|((dimension, key), value)| {
+                Self::concentration_entry(dimension, key, value, portfolio_value, &config)
+            })
+            .collect();
+        entries.sort_by(|a, b| b.pct_of_portfolio.partial_cmp(&a.pct_of_portfolio).unwrap_or(std::cmp::Ordering::Equal));
+        
+        let violations = entries
+            .iter()
+            .filter_map(|entry| Self::concentration_violation(entry, &config))
+            .collect();
+        
+        ConcentrationReport {
+            timestamp: Utc::now(),
+            portfolio_value,
+            entries,
+            violations,
+        }
+    }
+    
+    /// Evaluate a proposed position's effect on concentration without applying it
+    pub async fn what_if_concentration(&self, proposed: &PositionExposure) -> ConcentrationImpact {
+        let config = self.config.read().await;
+        let portfolio_value = *self.portfolio_value.read().await;
+        let positions = self.positions.read().await;
+        
+        let before_buckets = Self::concentration_buckets(positions.values(), &config);
+        let after_buckets = Self::concentration_buckets(
+            positions.values().chain(std::iter::once(proposed)),
+            &config,
+        );
+        
+        // Only the buckets the proposed position touches can change
+        let symbol_key = netting::find_group(&config.netting_groups, &proposed.symbol)
+            .map(|group| group.name.clone())
+            .unwrap_or_else(|| proposed.symbol.clone());
+        let mut touched = vec![
+            (ConcentrationDimension::Symbol, symbol_key),
+            (ConcentrationDimension::Venue, proposed.venue.clone()),
+        ];
+        if let Some(sector) = config.symbol_sectors.get(&proposed.symbol) {
+            touched.push((ConcentrationDimension::Sector, sector.clone()));
+        }
+        
+        let mut before = Vec::new();
+        let mut after = Vec::new();
+        let mut violations = Vec::new();
+        
+        for bucket in touched {
+            let before_value = before_buckets.get(&bucket).copied().unwrap_or(0.0);
+            let after_value = after_buckets.get(&bucket).copied().unwrap_or(0.0);
+            let (dimension, key) = bucket;
+            
+            let before_entry = Self::concentration_entry(dimension, key.clone(), before_value, portfolio_value, &config);
+            let after_entry = Self::concentration_entry(dimension, key, after_value, portfolio_value, &config);
+            
+            if let Some(violation) = Self::concentration_violation(&after_entry, &config) {
+                violations.push(violation);
+            }
+            
+            before.push(before_entry);
+            after.push(after_entry);
+        }
+        
+        let allowed = !violations.iter().any(|v| v.severity == RiskViolationSeverity::Critical);
+        
+        ConcentrationImpact {
+            before,
+            after,
+            violations,
+            allowed,
+        }
+    }
+    
+    /// Aggregate position values by concentration bucket; netting groups form one netted symbol bucket
+    fn concentration_buckets<'a>(
+        positions: impl Iterator<Item = &'a PositionExposure>,
+        config: &RiskConfig,
+    ) -> HashMap<(ConcentrationDimension, String), f64> {
+        let mut buckets = HashMap::new();
+        let mut grouped_legs = Vec::new();
+        
+        for position in positions {
+            if netting::find_group(&config.netting_groups, &position.symbol).is_some() {
+                grouped_legs.push((position.symbol.as_str(), position.signed_value()));
+            } else {
+                *buckets.entry((ConcentrationDimension::Symbol, position.symbol.clone())).or_insert(0.0) += position.value;
+            }
+            *buckets.entry((ConcentrationDimension::Venue, position.venue.clone())).or_insert(0.0) += position.value;
+            
+            if let Some(sector) = config.symbol_sectors.get(&position.symbol) {
+                *buckets.entry((ConcentrationDimension::Sector, sector.clone())).or_insert(0.0) += position.value;
+            }
+        }
+        
+        for exposure in netting::group_exposures(&config.netting_groups, grouped_legs) {
+            buckets.insert((ConcentrationDimension::Symbol, exposure.group), exposure.net);
+        }
+        
+        buckets
+    }
+    
+    /// Build a concentration entry for a bucket
+    fn concentration_entry(
+        dimension: ConcentrationDimension,
+        key: String,
+        value: f64,
+        portfolio_value: f64,
+        config: &RiskConfig,
+    ) -> ConcentrationEntry {
+        let limit = match dimension {
+            ConcentrationDimension::Symbol => config.max_exposure_per_symbol,
+            ConcentrationDimension::Sector => config.max_exposure_per_sector,
+            ConcentrationDimension::Venue => config.max_exposure_per_venue,
+        };
+        
+        ConcentrationEntry {
+            dimension,
+            key,
+            value,
+            pct_of_portfolio: if portfolio_value > 0.0 { value / portfolio_value } else { 0.0 },
+            limit,
+        }
+    }
+    
+    /// Convert a concentration entry into a violation if it breaches or nears its limit
+    fn concentration_violation(entry: &ConcentrationEntry, config: &RiskConfig) -> Option<RiskViolation> {
+        let severity = if entry.pct_of_portfolio > entry.limit {
+            RiskViolationSeverity::Critical
+        } else if entry.pct_of_portfolio > entry.limit * config.concentration_warning_pct {
+            RiskViolationSeverity::Warning
+        } else {
+            return None;
+        };
+        
+        let (violation_type, label) = match entry.dimension {
+            ConcentrationDimension::Symbol => (RiskViolationType::SymbolExposure, "Symbol"),
+            ConcentrationDimension::Sector => (RiskViolationType::SectorExposure, "Sector"),
+            ConcentrationDimension::Venue => (RiskViolationType::VenueExposure, "Venue"),
+        };
+        
+        Some(RiskViolation {
+            violation_type,
+            description: format!(
+                "{} concentration for {}: {:.2}% (limit: {:.2}%)",
+                label,
+                entry.key,
+                entry.pct_of_portfolio * 100.0,
+                entry.limit * 100.0
+            ),
+            actual_value: entry.pct_of_portfolio,
+            limit_value: entry.limit,
+            severity,
+        })
+    }
+    
     /// Validate a position against risk limits
     async fn validate_position(&self, position: &PositionExposure) -> Result<(), RiskError> {
         let check_result = self.fast_risk_check(position, None).await;
@@ -610,6 +1296,26 @@ impl RiskCalculator {
         positions.values().fold(0.0, |acc, pos| acc + pos.value)
     }
     
+    /// Get total exposure with correlated positions netted within their groups
+    pub async fn get_netted_exposure(&self) -> f64 {
+        let config = self.config.read().await;
+        let positions = self.positions.read().await;
+        netting::netted_total(
+            &config.netting_groups,
+            positions.values().map(|p| (p.symbol.as_str(), p.signed_value())),
+        )
+    }
+    
+    /// Get the gross and netted exposure of every netting group holding positions
+    pub async fn get_group_exposures(&self) -> Vec<NettedExposure> {
+        let config = self.config.read().await;
+        let positions = self.positions.read().await;
+        netting::group_exposures(
+            &config.netting_groups,
+            positions.values().map(|p| (p.symbol.as_str(), p.signed_value())),
+        )
+    }
+    
     /// Update configuration
     pub async fn update_config(&self, config: RiskConfig) {
         let mut current_config = self.config.write().await;
@@ -742,4 +1448,215 @@ mod tests {
         assert_eq!(venue_exposures[0].venue, "binance");
         assert_eq!(venue_exposures[0].total_value, 10000.0);
     }
+    
+    #[tokio::test]
+    async fn test_what_if_concentration() {
+        let mut symbol_sectors = HashMap::new();
+        symbol_sectors.insert("SOL-USD".to_string(), "L1".to_string());
+        symbol_sectors.insert("AVAX-USD".to_string(), "L1".to_string());
+        
+        let config = RiskConfig {
+            max_exposure_per_sector: 0.15,
+            symbol_sectors,
+            ..Default::default()
+        };
+        
+        let calculator = RiskCalculator::new(config, 100000.0);
+        
+        let position = PositionExposure::new(
+            "SOL-USD",
+            "binance",
+            100.0,
+            10000.0,  // 10% of portfolio
+            1.0,
+            0.8,
+            PositionDirection::Long,
+        );
+        calculator.add_position(position).await.unwrap();
+        
+        // Another L1 position would push the sector to 18%
+        let proposed = PositionExposure::new(
+            "AVAX-USD",
+            "coinbase",
+            100.0,
+            8000.0,
+            1.0,
+            0.8,
+            PositionDirection::Long,
+        );
+        
+        let impact = calculator.what_if_concentration(&proposed).await;
+        assert!(!impact.allowed);
+        assert!(impact.violations.iter().any(|v| v.violation_type == RiskViolationType::SectorExposure));
+        
+        // The what-if must not mutate state
+        let report = calculator.check_concentration().await;
+        let sector = report.entries.iter()
+            .find(|e| e.dimension == ConcentrationDimension::Sector)
+            .unwrap();
+        assert!((sector.pct_of_portfolio - 0.1).abs() < 1e-9);
+    }
+    
+    #[tokio::test]
+    async fn test_withdrawal_recommendations() {
+        let mut venue_exposure_limits = HashMap::new();
+        venue_exposure_limits.insert("binance".to_string(), 100000.0);
+        
+        let config = RiskConfig {
+            venue_exposure_limits,
+            venue_alert_threshold_pct: 0.8,
+            venue_target_utilization_pct: 0.6,
+            ..Default::default()
+        };
+        
+        let calculator = RiskCalculator::new(config, 1000000.0);
+        
+        // 70k balance + 20k resting orders = 90% of limit
+        calculator.update_venue_balance("binance", 70000.0).await;
+        let alerts = calculator.update_open_order_notional("binance", 20000.0).await;
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].severity, RiskViolationSeverity::Warning);
+        
+        let report = calculator.withdrawal_recommendations().await;
+        assert_eq!(report.recommendations.len(), 1);
+        
+        // Withdraw down to 60k: 90k - 60k = 30k (free balance is 50k)
+        let recommendation = &report.recommendations[0];
+        assert!((recommendation.amount - 30000.0).abs() < 1e-9);
+        assert!((recommendation.utilization_after - 0.6).abs() < 1e-9);
+    }
+    
+    struct FundedConnector;
+    
+    #[async_trait::async_trait]
+    impl VenueConnector for FundedConnector {
+        fn venue_id(&self) -> &str {
+            "binance"
+        }
+        
+        async fn subscribe_market_data(
+            &self,
+            _symbols: &[String],
+            _sink: tokio::sync::mpsc::Sender<crate::connectors::MarketDataEvent>,
+        ) -> ConnectorResult<()> {
+            Ok(())
+        }
+        
+        async fn unsubscribe_market_data(&self, _symbols: &[String]) -> ConnectorResult<()> {
+            Ok(())
+        }
+        
+        async fn submit_order(&self, _order: &crate::order_router::Order) -> ConnectorResult<crate::connectors::OrderAck> {
+            Err(crate::connectors::ConnectorError::MissingCredentials("binance".to_string()))
+        }
+        
+        async fn cancel_order(&self, _symbol: &str, _venue_order_id: &str) -> ConnectorResult<crate::connectors::OrderAck> {
+            Err(crate::connectors::ConnectorError::MissingCredentials("binance".to_string()))
+        }
+        
+        async fn fetch_balances(&self) -> ConnectorResult<Vec<crate::connectors::VenueBalance>> {
+            Ok(vec![
+                crate::connectors::VenueBalance { asset: "USDT".to_string(), free: 60000.0, locked: 20000.0 },
+                crate::connectors::VenueBalance { asset: "BTC".to_string(), free: 5.0, locked: 0.0 },
+            ])
+        }
+        
+        async fn disconnect(&self) {}
+    }
+    
+    #[tokio::test]
+    async fn test_venue_limit_from_connector_config_blocks_orders() {
+        let calculator = RiskCalculator::new(RiskConfig::default(), 1000000.0);
+        let venue_config = ConnectorConfig {
+            venue_id: "binance".to_string(),
+            exposure_limit: Some(100000.0),
+            ..ConnectorConfig::default()
+        };
+        
+        // Only settlement assets count towards the 80k held on the venue
+        let alerts = calculator
+            .sync_venue_exposure(&venue_config, &FundedConnector, &SmartOrderRouter::new())
+            .await
+            .unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].settlement_exposure, 80000.0);
+        
+        let small = PositionExposure::new("BTC-USD", "binance", 0.2, 10000.0, 1.0, 0.9, PositionDirection::Long);
+        assert!(calculator.evaluate_position(&small, None).await.passed);
+        
+        let large = PositionExposure::new("BTC-USD", "binance", 0.6, 30000.0, 1.0, 0.9, PositionDirection::Long);
+        let result = calculator.evaluate_position(&large, None).await;
+        assert!(!result.passed);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].violation_type, RiskViolationType::VenueExposure);
+        assert_eq!(result.violations[0].limit_value, 100000.0);
+    }
+    
+    #[tokio::test]
+    async fn test_netted_symbol_exposure() {
+        let config = RiskConfig {
+            netting_groups: vec![NettingGroup::new("BTC", &["BTC-USD", "BTC-PERP"], 1.0)],
+            ..Default::default()
+        };
+        
+        let calculator = RiskCalculator::new(config, 100000.0);
+        let btc = |symbol: &str, venue: &str, direction| {
+            PositionExposure::new(symbol, venue, 0.2, 9000.0, 1.0, 0.8, direction)
+        };
+        
+        for venue in ["binance", "coinbase", "kraken"] {
+            calculator.add_position(btc("BTC-USD", venue, PositionDirection::Long)).await.unwrap();
+        }
+        calculator.add_position(btc("BTC-PERP", "bybit", PositionDirection::Short)).await.unwrap();
+        
+        // Gross BTC-USD would reach 36%, but the perp short nets the group down to 27%
+        let proposed = btc("BTC-USD", "okx", PositionDirection::Long);
+        let result = calculator.evaluate_position(&proposed, None).await;
+        assert!(result.passed);
+        calculator.add_position(proposed).await.unwrap();
+        
+        assert!((calculator.get_total_exposure().await - 45000.0).abs() < 1e-9);
+        assert!((calculator.get_netted_exposure().await - 27000.0).abs() < 1e-9);
+        
+        let groups = calculator.get_group_exposures().await;
+        assert_eq!(groups.len(), 1);
+        assert!((groups[0].offset() - 18000.0).abs() < 1e-9);
+        
+        // Without the group the same book breaches the symbol limit
+        calculator.update_config(RiskConfig::default()).await;
+        let result = calculator.evaluate_position(&btc("BTC-USD", "bitstamp", PositionDirection::Long), None).await;
+        assert!(result.violations.iter().any(|v| v.violation_type == RiskViolationType::SymbolExposure));
+    }
+    
+    #[tokio::test]
+    async fn test_failed_fast_check_publishes_risk_violation() {
+        let config = RiskConfig {
+            max_leverage: 3.0,
+            ..Default::default()
+        };
+        
+        let event_bus = crate::trading_events::create_trading_event_bus(16);
+        let mut events = event_bus.subscribe();
+        let calculator = RiskCalculator::new(config, 100000.0).with_event_bus(event_bus.clone());
+        
+        // A passing check publishes nothing
+        let valid_position = PositionExposure::new("BTC-USD", "binance", 0.1, 5000.0, 1.0, 0.8, PositionDirection::Long);
+        assert!(calculator.fast_risk_check(&valid_position, None).await.passed);
+        assert_eq!(event_bus.published_count(), 0);
+        
+        let leveraged_position = PositionExposure::new("BTC-USD", "binance", 0.1, 5000.0, 5.0, 0.8, PositionDirection::Long);
+        let result = calculator.fast_risk_check(&leveraged_position, Some("momentum")).await;
+        assert!(!result.passed);
+        assert_eq!(event_bus.published_count(), 1);
+        
+        match events.recv().await.unwrap() {
+            TradingEvent::RiskViolation { symbol, venue, strategy_id, violations, .. } => {
+                assert_eq!(symbol, "BTC-USD");
+                assert_eq!(venue, "binance");
+                assert_eq!(strategy_id.as_deref(), Some("momentum"));
+                assert!(violations.iter().any(|v| v.violation_type == RiskViolationType::Leverage));
+            }
+            other => panic!("expected a risk violation, got {:?}", other.kind()),
+        }
+    }
 } 
\ No newline at end of file
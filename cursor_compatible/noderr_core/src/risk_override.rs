@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::audit_vault::{AuditVault, AuditVaultError};
+use crate::risk_calc::{RiskCalculator, RiskConfig};
+
+/// Errors that can occur when applying or reverting a risk override
+#[derive(Debug, Error)]
+pub enum RiskOverrideError {
+    #[error("Invalid override request: {0}")]
+    InvalidRequest(String),
+
+    #[error("Limit already overridden: {0}")]
+    AlreadyActive(String),
+
+    #[error("Override not found: {0}")]
+    NotFound(String),
+
+    #[error("Audit vault error: {0}")]
+    Audit(#[from] AuditVaultError),
+}
+
+/// Risk limits that may be temporarily overridden
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLimit {
+    MaxPositionSizePct,
+    MaxLeverage,
+    MaxDrawdownPct,
+    MinTrustScore,
+    MaxExposurePerSymbol,
+    MaxExposurePerVenue,
+    MaxExposurePerSector,
+}
+
+impl RiskLimit {
+    /// All overridable limits
+    pub const ALL: [RiskLimit; 7] = [
+        RiskLimit::MaxPositionSizePct,
+        RiskLimit::MaxLeverage,
+        RiskLimit::MaxDrawdownPct,
+        RiskLimit::MinTrustScore,
+        RiskLimit::MaxExposurePerSymbol,
+        RiskLimit::MaxExposurePerVenue,
+        RiskLimit::MaxExposurePerSector,
+    ];
+
+    /// Config field name of this limit
+    pub fn name(&self) -> &'static str {
+        match self {
+            RiskLimit::MaxPositionSizePct => "max_position_size_pct",
+            RiskLimit::MaxLeverage => "max_leverage",
+            RiskLimit::MaxDrawdownPct => "max_drawdown_pct",
+            RiskLimit::MinTrustScore => "min_trust_score",
+            RiskLimit::MaxExposurePerSymbol => "max_exposure_per_symbol",
+            RiskLimit::MaxExposurePerVenue => "max_exposure_per_venue",
+            RiskLimit::MaxExposurePerSector => "max_exposure_per_sector",
+        }
+    }
+
+    /// Current value of this limit in a config
+    pub fn get(&self, config: &RiskConfig) -> f64 {
+        match self {
+            RiskLimit::MaxPositionSizePct => config.max_position_size_pct,
+            RiskLimit::MaxLeverage => config.max_leverage,
+            RiskLimit::MaxDrawdownPct => config.max_drawdown_pct,
+            RiskLimit::MinTrustScore => config.min_trust_score,
+            RiskLimit::MaxExposurePerSymbol => config.max_exposure_per_symbol,
+            RiskLimit::MaxExposurePerVenue => config.max_exposure_per_venue,
+            RiskLimit::MaxExposurePerSector => config.max_exposure_per_sector,
+        }
+    }
+
+    /// Set this limit in a config
+    pub fn set(&self, config: &mut RiskConfig, value: f64) {
+        match self {
+            RiskLimit::MaxPositionSizePct => config.max_position_size_pct = value,
+            RiskLimit::MaxLeverage => config.max_leverage = value,
+            RiskLimit::MaxDrawdownPct => config.max_drawdown_pct = value,
+            RiskLimit::MinTrustScore => config.min_trust_score = value,
+            RiskLimit::MaxExposurePerSymbol => config.max_exposure_per_symbol = value,
+            RiskLimit::MaxExposurePerVenue => config.max_exposure_per_venue = value,
+            RiskLimit::MaxExposurePerSector => config.max_exposure_per_sector = value,
+        }
+    }
+
+    /// Whether `value` is in range for this limit
+    fn is_valid(&self, value: f64) -> bool {
+        match self {
+            RiskLimit::MaxLeverage => value > 0.0 && value.is_finite(),
+            _ => (0.0..=1.0).contains(&value),
+        }
+    }
+}
+
+impl FromStr for RiskLimit {
+    type Err = RiskOverrideError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RiskLimit::ALL
+            .iter()
+            .copied()
+            .find(|limit| limit.name() == s)
+            .ok_or_else(|| RiskOverrideError::InvalidRequest(format!("unknown limit '{}'", s)))
+    }
+}
+
+/// Risk override configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskOverrideConfig {
+    /// Longest an override may stay active (seconds)
+    pub max_duration_secs: i64,
+
+    /// Minimum justification length (characters)
+    pub min_justification_len: usize,
+
+    /// How often expired overrides are reverted (ms)
+    pub check_interval_ms: u64,
+}
+
+impl Default for RiskOverrideConfig {
+    fn default() -> Self {
+        Self {
+            max_duration_secs: 24 * 60 * 60, // 1 day
+            min_justification_len: 20,
+            check_interval_ms: 1000,
+        }
+    }
+}
+
+/// Request to temporarily change a risk limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskOverrideRequest {
+    /// Limit to change
+    pub limit: RiskLimit,
+    /// Temporary value
+    pub value: f64,
+    /// When the limit reverts
+    pub expires_at: DateTime<Utc>,
+    /// Why the override is needed
+    pub justification: String,
+    /// Operator requesting the override; the API fills this in from the
+    /// authenticated caller and ignores any value in the request body
+    #[serde(default)]
+    pub operator: String,
+}
+
+/// An active risk override
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskOverride {
+    /// Override ID
+    pub override_id: String,
+    /// Limit changed
+    pub limit: RiskLimit,
+    /// Value restored on reversion
+    pub original_value: f64,
+    /// Temporary value
+    pub override_value: f64,
+    /// Why the override is needed
+    pub justification: String,
+    /// Operator who applied the override
+    pub operator: String,
+    /// When the override was applied
+    pub created_at: DateTime<Utc>,
+    /// When the limit reverts
+    pub expires_at: DateTime<Utc>,
+    /// Audit entry recording the override
+    pub audit_entry_id: String,
+}
+
+/// Applies time-boxed risk limit overrides and reverts them on expiry,
+/// recording both in the audit vault
+pub struct RiskOverrideManager {
+    /// Configuration
+    config: RiskOverrideConfig,
+    /// Risk calculator whose limits are overridden
+    calculator: Arc<RiskCalculator>,
+    /// Audit vault
+    vault: Arc<AuditVault>,
+    /// Active overrides by limit
+    active: RwLock<HashMap<RiskLimit, RiskOverride>>,
+    /// Background reversion task
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl RiskOverrideManager {
+    /// Create a new override manager
+    pub fn new(config: RiskOverrideConfig, calculator: Arc<RiskCalculator>, vault: Arc<AuditVault>) -> Self {
+        Self {
+            config,
+            calculator,
+            vault,
+            active: RwLock::new(HashMap::new()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Apply an override
+    ///
+    /// The audit entry is written before the limit changes, so an override
+    /// that cannot be audited is never applied.
+    pub async fn apply(&self, request: RiskOverrideRequest) -> Result<RiskOverride, RiskOverrideError> {
+        let now = Utc::now();
+        self.validate(&request, now)?;
+
+        let mut active = self.active.write().await;
+        if active.contains_key(&request.limit) {
+            return Err(RiskOverrideError::AlreadyActive(request.limit.name().to_string()));
+        }
+
+        let mut config = self.calculator.get_config().await;
+        let original_value = request.limit.get(&config);
+        let override_id = Uuid::new_v4().to_string();
+
+        let entry = self.vault.record(
+            "risk_override",
+            &request.operator,
+            &format!("{} {} -> {} until {}", request.limit.name(), original_value, request.value, request.expires_at),
+            serde_json::json!({
+                "override_id": override_id,
+                "limit": request.limit,
+                "original_value": original_value,
+                "override_value": request.value,
+                "expires_at": request.expires_at,
+                "justification": request.justification,
+            }),
+        ).await?;
+
+        request.limit.set(&mut config, request.value);
+        self.calculator.update_config(config).await;
+
+        let applied = RiskOverride {
+            override_id,
+            limit: request.limit,
+            original_value,
+            override_value: request.value,
+            justification: request.justification,
+            operator: request.operator,
+            created_at: now,
+            expires_at: request.expires_at,
+            audit_entry_id: entry.entry_id,
+        };
+
+        warn!(
+            "Risk override {} applied by {}: {} = {} until {}",
+            applied.override_id, applied.operator, applied.limit.name(), applied.override_value, applied.expires_at
+        );
+        active.insert(applied.limit, applied.clone());
+
+        Ok(applied)
+    }
+
+    /// Revert an override before it expires
+    pub async fn revoke(&self, override_id: &str, operator: &str) -> Result<RiskOverride, RiskOverrideError> {
+        let mut active = self.active.write().await;
+        let limit = active
+            .values()
+            .find(|o| o.override_id == override_id)
+            .map(|o| o.limit)
+            .ok_or_else(|| RiskOverrideError::NotFound(override_id.to_string()))?;
+
+        let revoked = active.remove(&limit).expect("override present");
+        self.revert(&revoked, operator, "revoked").await?;
+        Ok(revoked)
+    }
+
+    /// Revert every override that has expired at `now`
+    pub async fn revert_expired(&self, now: DateTime<Utc>) -> Vec<RiskOverride> {
+        let mut active = self.active.write().await;
+        let expired: Vec<RiskLimit> = active
+            .values()
+            .filter(|o| o.expires_at <= now)
+            .map(|o| o.limit)
+            .collect();
+
+        let mut reverted = Vec::with_capacity(expired.len());
+        for limit in expired {
+            let expired_override = active.remove(&limit).expect("override present");
+            // The limit is restored even if the audit write fails; the failure is logged
+            if let Err(e) = self.revert(&expired_override, "system", "expired").await {
+                warn!("Failed to audit reversion of override {}: {}", expired_override.override_id, e);
+            }
+            reverted.push(expired_override);
+        }
+
+        reverted
+    }
+
+    /// Active overrides
+    pub async fn list_active(&self) -> Vec<RiskOverride> {
+        let mut overrides: Vec<RiskOverride> = self.active.read().await.values().cloned().collect();
+        overrides.sort_by_key(|o| o.expires_at);
+        overrides
+    }
+
+    /// Start the background reversion loop
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle = self.task_handle.write().await;
+        if handle.is_some() {
+            return;
+        }
+
+        let manager = Arc::clone(self);
+        let interval_ms = self.config.check_interval_ms.max(1);
+        *handle = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_millis(interval_ms));
+            loop {
+                interval.tick().await;
+                manager.revert_expired(Utc::now()).await;
+            }
+        }));
+
+        info!("Risk override reversion task started");
+    }
+
+    /// Stop the background reversion loop
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+            info!("Risk override reversion task stopped");
+        }
+    }
+
+    fn validate(&self, request: &RiskOverrideRequest, now: DateTime<Utc>) -> Result<(), RiskOverrideError> {
+        if request.operator.trim().is_empty() {
+            return Err(RiskOverrideError::InvalidRequest("operator is required".to_string()));
+        }
+        if request.justification.trim().len() < self.config.min_justification_len {
+            return Err(RiskOverrideError::InvalidRequest(format!(
+                "justification must be at least {} characters",
+                self.config.min_justification_len
+            )));
+        }
+        if !request.limit.is_valid(request.value) {
+            return Err(RiskOverrideError::InvalidRequest(format!(
+                "{} is out of range for {}", request.value, request.limit.name()
+            )));
+        }
+        if request.expires_at <= now {
+            return Err(RiskOverrideError::InvalidRequest("expiry must be in the future".to_string()));
+        }
+        if request.expires_at - now > Duration::seconds(self.config.max_duration_secs) {
+            return Err(RiskOverrideError::InvalidRequest(format!(
+                "overrides may last at most {} seconds", self.config.max_duration_secs
+            )));
+        }
+        Ok(())
+    }
+
+    /// Restore the original limit and record the reversion
+    async fn revert(&self, applied: &RiskOverride, actor: &str, reason: &str) -> Result<(), RiskOverrideError> {
+        let mut config = self.calculator.get_config().await;
+        applied.limit.set(&mut config, applied.original_value);
+        self.calculator.update_config(config).await;
+
+        info!(
+            "Risk override {} {}: {} restored to {}",
+            applied.override_id, reason, applied.limit.name(), applied.original_value
+        );
+
+        self.vault.record(
+            "risk_override_reverted",
+            actor,
+            &format!("{} restored to {} ({})", applied.limit.name(), applied.original_value, reason),
+            serde_json::json!({
+                "override_id": applied.override_id,
+                "limit": applied.limit,
+                "restored_value": applied.original_value,
+                "reason": reason,
+                "override_audit_entry_id": applied.audit_entry_id,
+            }),
+        ).await?;
+
+        Ok(())
+    }
+}
+
+/// Create a risk override manager
+pub fn create_risk_override_manager(
+    config: RiskOverrideConfig,
+    calculator: Arc<RiskCalculator>,
+    vault: Arc<AuditVault>,
+) -> Arc<RiskOverrideManager> {
+    Arc::new(RiskOverrideManager::new(config, calculator, vault))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_override_applies_and_reverts() {
+        let path = std::env::temp_dir().join(format!("noderr_override_{}.jsonl", Uuid::new_v4()));
+        let vault = Arc::new(AuditVault::open(&path).unwrap());
+        let calculator = Arc::new(RiskCalculator::new(RiskConfig::default(), 100_000.0));
+        let manager = RiskOverrideManager::new(RiskOverrideConfig::default(), calculator.clone(), vault.clone());
+
+        let request = RiskOverrideRequest {
+            limit: RiskLimit::MaxLeverage,
+            value: 5.0,
+            expires_at: Utc::now() + Duration::minutes(30),
+            justification: "Rebalancing into new venue during migration".to_string(),
+            operator: "ops@noderr".to_string(),
+        };
+
+        let applied = manager.apply(request.clone()).await.unwrap();
+        assert_eq!(applied.original_value, 3.0);
+        assert_eq!(calculator.get_config().await.max_leverage, 5.0);
+        assert!(matches!(manager.apply(request).await, Err(RiskOverrideError::AlreadyActive(_))));
+
+        assert!(manager.revert_expired(Utc::now()).await.is_empty());
+        let reverted = manager.revert_expired(Utc::now() + Duration::hours(1)).await;
+        assert_eq!(reverted.len(), 1);
+        assert_eq!(calculator.get_config().await.max_leverage, 3.0);
+
+        let entries = vault.entries().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].event_type, "risk_override_reverted");
+        assert_eq!(vault.verify().unwrap(), 2);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
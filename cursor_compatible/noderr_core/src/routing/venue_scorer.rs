@@ -19,6 +19,11 @@ use tracing::{debug, info, warn, error};
 use crate::routing::venue_telemetry::VenueTelemetryManager;
 use crate::market::Symbol;
 use crate::execution::{OrderIntent, OrderSide, OrderType};
+use crate::venue_control::{VenueControl, VenueMode};
+use crate::venue_trust::VenueTrustService;
+use crate::liquidity_probe::LiquidityProber;
+use crate::dex_venue::DexVenueConnector;
+use crate::order_router::OrderSide as RouterSide;
 use std::sync::Arc;
 use chrono::{DateTime, Utc};
 
@@ -68,6 +73,10 @@ pub struct VenueMetrics {
     /// Current taker fee in basis points
     pub taker_fee_bps: f64,
     
+    /// Estimated network gas cost in basis points of notional (DEX venues)
+    #[serde(default)]
+    pub gas_cost_bps: f64,
+    
     /// Estimated latency in milliseconds
     pub estimated_latency_ms: f64,
     
@@ -89,6 +98,7 @@ impl VenueMetrics {
             liquidity_depth: HashMap::new(),
             maker_fee_bps: 0.0,
             taker_fee_bps: 0.0,
+            gas_cost_bps: 0.0,
             estimated_latency_ms: 0.0,
             last_updated: Utc::now(),
             status: "unknown".to_string(),
@@ -114,10 +124,23 @@ impl VenueMetrics {
         }
     }
     
+    /// Taker fee including gas, used when comparing venues
+    pub fn total_taker_cost_bps(&self) -> f64 {
+        self.taker_fee_bps + self.gas_cost_bps
+    }
+    
     /// Check if the venue is available for trading
     pub fn is_available(&self) -> bool {
         self.status == "healthy" || self.status == "degraded"
     }
+    
+    /// Overlay an operator venue mode; disabled and draining venues report
+    /// that mode as their status and become unavailable
+    pub fn apply_mode(&mut self, mode: VenueMode) {
+        if !mode.accepts_new_orders() {
+            self.status = mode.as_status().to_string();
+        }
+    }
 }
 
 /// Configuration for the venue scoring algorithm
@@ -135,6 +158,14 @@ pub struct VenueScorerConfig {
     pub historical_weight: f64,
     /// Minimum health score required for a venue to be considered (0.0 to 1.0)
     pub min_health_score: f64,
+    /// Weight for learned venue trust in the score (0.0 to 1.0); only applies
+    /// when a venue trust service is attached
+    #[serde(default = "default_trust_weight")]
+    pub trust_weight: f64,
+}
+
+fn default_trust_weight() -> f64 {
+    0.10
 }
 
 impl Default for VenueScorerConfig {
@@ -146,6 +177,7 @@ impl Default for VenueScorerConfig {
             fee_weight: 0.15,
             historical_weight: 0.10,
             min_health_score: 0.6,
+            trust_weight: default_trust_weight(),
         }
     }
 }
@@ -300,7 +332,7 @@ impl VenueScorer {
         // Lower fees are better - assume 50 bps (0.5%) or more is bad (score 0.0)
         // and 0 bps is perfect (score 1.0)
         
-        let fee_bps = venue.taker_fee_bps;
+        let fee_bps = venue.total_taker_cost_bps();
         
         if fee_bps <= 0.0 {
             1.0
@@ -393,17 +425,93 @@ pub trait VenueScorer: Send + Sync {
 pub struct DefaultVenueScorer {
     /// Configuration
     config: VenueScorerConfig,
+    /// Operator venue modes (optional)
+    venue_control: Option<Arc<VenueControl>>,
+    /// Learned venue trust shared with the order router (optional)
+    venue_trust: Option<Arc<VenueTrustService>>,
+    /// Hidden depth found by liquidity probes (optional)
+    liquidity_prober: Option<Arc<LiquidityProber>>,
+    /// DEX venues whose gas is priced into their taker cost
+    dex_venues: HashMap<VenueId, Arc<DexVenueConnector>>,
 }
 
 impl DefaultVenueScorer {
     /// Create a new DefaultVenueScorer with the provided configuration
     pub fn new(config: VenueScorerConfig) -> Self {
-        Self { config }
+        Self { config, venue_control: None, venue_trust: None, liquidity_prober: None, dex_venues: HashMap::new() }
     }
     
     /// Create a new DefaultVenueScorer with default configuration
     pub fn default() -> Self {
-        Self { config: VenueScorerConfig::default() }
+        Self {
+            config: VenueScorerConfig::default(),
+            venue_control: None,
+            venue_trust: None,
+            liquidity_prober: None,
+            dex_venues: HashMap::new(),
+        }
+    }
+    
+    /// Factor learned venue trust from realized outcomes into scores
+    pub fn with_venue_trust(mut self, venue_trust: Arc<VenueTrustService>) -> Self {
+        self.venue_trust = Some(venue_trust);
+        self
+    }
+    
+    /// Exclude venues an operator has disabled or is draining
+    pub fn with_venue_control(mut self, venue_control: Arc<VenueControl>) -> Self {
+        self.venue_control = Some(venue_control);
+        self
+    }
+    
+    /// Count hidden depth found by liquidity probes towards venue liquidity
+    pub fn with_liquidity_prober(mut self, prober: Arc<LiquidityProber>) -> Self {
+        self.liquidity_prober = Some(prober);
+        self
+    }
+    
+    /// Price gas into the taker cost of the connector's venue
+    pub fn with_dex_venue(mut self, connector: Arc<DexVenueConnector>) -> Self {
+        self.dex_venues.insert(connector.venue_id().to_string(), connector);
+        self
+    }
+    
+    /// Fill `gas_cost_bps` for DEX venues from a fresh quote of the order
+    ///
+    /// Scoring itself cannot wait on the chain, so call this on the metrics
+    /// before [`VenueScorer::score`]. A venue that cannot be quoted keeps its
+    /// previous estimate.
+    pub async fn apply_gas_costs(&self, venues: &mut [VenueMetrics], order: &OrderIntent) {
+        let router_side = match order.side {
+            OrderSide::Buy => RouterSide::Buy,
+            OrderSide::Sell => RouterSide::Sell,
+        };
+        for metrics in venues.iter_mut() {
+            let Some(connector) = self.dex_venues.get(&metrics.venue_id) else {
+                continue;
+            };
+            match connector.swap_gas_cost_bps(&order.symbol, router_side, order.quantity).await {
+                Ok(gas_cost_bps) => metrics.gas_cost_bps = gas_cost_bps,
+                Err(e) => warn!("Could not price gas on {}: {}", metrics.venue_id, e),
+            }
+        }
+    }
+    
+    /// Append probed hidden depth to the displayed depth for the order's side
+    fn add_hidden_depth(&self, metrics: &mut VenueMetrics, side: OrderSide, now: DateTime<Utc>) {
+        let Some(prober) = &self.liquidity_prober else {
+            return;
+        };
+        let router_side = match side {
+            OrderSide::Buy => RouterSide::Buy,
+            OrderSide::Sell => RouterSide::Sell,
+        };
+        if let Some(estimate) = prober.estimate(&metrics.venue_id, &metrics.symbol, router_side, now) {
+            if estimate.hidden_quantity > 0.0 {
+                metrics.liquidity_depth.entry(side).or_default()
+                    .push((estimate.price, estimate.hidden_quantity));
+            }
+        }
     }
     
     /// Score a venue on price
@@ -470,7 +578,7 @@ impl DefaultVenueScorer {
     
     /// Score a venue on fees
     fn score_fee(&self, metrics: &VenueMetrics, order: &OrderIntent) -> f64 {
-        let fee_bps = metrics.taker_fee_bps;
+        let fee_bps = metrics.total_taker_cost_bps();
         
         // Higher fee means worse score
         // Normalize fee between 0.0 and 1.0 (assuming 0.5% is the maximum expected fee)
@@ -499,6 +607,7 @@ impl DefaultVenueScorer {
         let liquidity_score = component_scores.get("liquidity").copied().unwrap_or(0.0);
         let fee_score = component_scores.get("fee").copied().unwrap_or(0.0);
         let fill_rate_score = component_scores.get("fill_rate").copied().unwrap_or(0.0);
+        let trust_score = component_scores.get("venue_trust").copied();
         
         // Combine scores with weights
         let overall_score = 
@@ -506,7 +615,8 @@ impl DefaultVenueScorer {
             latency_score * self.config.latency_weight +
             liquidity_score * self.config.liquidity_weight +
             fee_score * self.config.fee_weight +
-            fill_rate_score * self.config.fill_rate_weight;
+            fill_rate_score * self.config.fill_rate_weight +
+            trust_score.map_or(0.0, |score| score * self.config.trust_weight);
         
         overall_score
     }
@@ -546,8 +656,19 @@ impl VenueScorer for DefaultVenueScorer {
             .unwrap_or_default()
             .as_millis() as u64;
             
+        let venues: Vec<VenueMetrics> = venues.iter()
+            .cloned()
+            .map(|mut m| {
+                if let Some(control) = &self.venue_control {
+                    m.apply_mode(control.mode(&m.venue_id));
+                }
+                self.add_hidden_depth(&mut m, order.side, Utc::now());
+                m
+            })
+            .collect();
+            
         let valid_venues: Vec<&VenueMetrics> = venues.iter()
-            .filter(|m| m.status == "healthy" || m.status == "degraded")
+            .filter(|m| m.is_available())
             .filter(|m| (now - m.last_updated.timestamp_millis()) <= 30000)
             .collect();
             
@@ -556,7 +677,7 @@ impl VenueScorer for DefaultVenueScorer {
         }
         
         // Find the best price across all venues for comparison
-        let best_price = self.find_best_price(venues, order);
+        let best_price = self.find_best_price(&venues, order);
         if best_price == 0.0 {
             warn!("Could not find a valid best price across venues");
         }
@@ -580,6 +701,9 @@ impl VenueScorer for DefaultVenueScorer {
             component_scores.insert("liquidity".to_string(), liquidity_score);
             component_scores.insert("fee".to_string(), fee_score);
             component_scores.insert("fill_rate".to_string(), fill_rate_score);
+            if let Some(venue_trust) = &self.venue_trust {
+                component_scores.insert("venue_trust".to_string(), venue_trust.score(&metrics.venue_id));
+            }
             
             // Calculate overall score
             let overall_score = self.calculate_overall_score(&component_scores);
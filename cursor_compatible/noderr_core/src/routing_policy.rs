@@ -0,0 +1,592 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Declarative routing policies evaluated before an order is routed.
+//!
+//! A policy is a versioned list of rules loaded from config. Each rule matches
+//! orders by symbol, side, strategy and notional, then constrains them:
+//!
+//! ```json
+//! {
+//!   "version": 3,
+//!   "name": "desk-default",
+//!   "rules": [{
+//!     "id": "btc-block-orders",
+//!     "description": "Large BTC orders go through IS and never touch venue-x",
+//!     "match": { "symbols": ["BTC"], "min_notional": 100000.0 },
+//!     "require_algorithm": "ImplementationShortfall",
+//!     "deny_venues": ["venue-x"]
+//!   }]
+//! }
+//! ```
+//!
+//! Rules in `monitor` mode only report what they would have done, which lets a
+//! new rule run against live flow before it is enforced. Every violation is
+//! written to the audit vault.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+use crate::audit_vault::AuditVault;
+use crate::execution_strategy::ExecutionAlgorithm;
+use crate::order_router::{Order, OrderSide};
+
+/// Actor recorded on audit entries written by the policy engine
+const POLICY_ACTOR: &str = "routing_policy";
+
+/// Errors raised by routing policy evaluation and management
+#[derive(Debug, Error)]
+pub enum RoutingPolicyError {
+    #[error("Order {order_id} rejected by routing policy v{version} rule {rule_id}: {reason}")]
+    Rejected { order_id: String, version: u32, rule_id: String, reason: String },
+
+    #[error("Invalid routing policy: {0}")]
+    InvalidPolicy(String),
+
+    #[error("Unknown routing policy version: {0}")]
+    UnknownVersion(u32),
+}
+
+/// Result type for routing policy operations
+pub type RoutingPolicyResult<T> = Result<T, RoutingPolicyError>;
+
+/// Whether a rule's constraints are applied or only reported
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyEnforcement {
+    /// Constraints are applied to the order
+    #[default]
+    Enforce,
+    /// Constraints are evaluated and logged but not applied
+    Monitor,
+}
+
+/// Orders a rule applies to; empty lists and unset bounds match everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolicyMatch {
+    /// Symbols or base assets (e.g. "BTC" matches "BTC/USD"); "*" matches all
+    pub symbols: Vec<String>,
+    /// Order sides
+    pub sides: Vec<OrderSide>,
+    /// Strategy IDs, read from the order's `strategyId` parameter
+    pub strategies: Vec<String>,
+    /// Minimum notional (amount x price), inclusive
+    pub min_notional: Option<f64>,
+    /// Maximum notional (amount x price), exclusive
+    pub max_notional: Option<f64>,
+}
+
+impl PolicyMatch {
+    /// Whether an order falls under this match
+    pub fn matches(&self, order: &Order) -> bool {
+        let notional = order.amount.abs() * order.price;
+        let strategy = order.additional_params.get("strategyId").and_then(|v| v.as_str());
+
+        (self.symbols.is_empty() || self.symbols.iter().any(|s| symbol_matches(s, &order.symbol)))
+            && (self.sides.is_empty() || self.sides.contains(&order.side))
+            && (self.strategies.is_empty() || strategy.is_some_and(|id| self.strategies.iter().any(|s| s == id)))
+            && !self.min_notional.is_some_and(|min| notional < min)
+            && !self.max_notional.is_some_and(|max| notional >= max)
+    }
+}
+
+/// Exact symbol, base asset or wildcard match
+fn symbol_matches(pattern: &str, symbol: &str) -> bool {
+    if pattern == "*" || pattern.eq_ignore_ascii_case(symbol) {
+        return true;
+    }
+    symbol
+        .split(['/', '-', '_'])
+        .next()
+        .is_some_and(|base| base.eq_ignore_ascii_case(pattern))
+}
+
+/// A single routing rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyRule {
+    /// Rule ID, unique within a policy
+    pub id: String,
+    /// Human-readable intent
+    #[serde(default)]
+    pub description: String,
+    /// Orders this rule applies to
+    #[serde(rename = "match", default)]
+    pub when: PolicyMatch,
+    /// Execution algorithm matching orders must use
+    #[serde(default)]
+    pub require_algorithm: Option<ExecutionAlgorithm>,
+    /// Venues matching orders may not route to
+    #[serde(default)]
+    pub deny_venues: Vec<String>,
+    /// If non-empty, the only venues matching orders may route to
+    #[serde(default)]
+    pub allow_venues: Vec<String>,
+    /// Reject matching orders outright
+    #[serde(default)]
+    pub reject: bool,
+    /// Whether the rule is applied or only reported
+    #[serde(default)]
+    pub enforcement: PolicyEnforcement,
+}
+
+/// Versioned set of routing rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingPolicy {
+    /// Policy version; each published policy must increase it
+    pub version: u32,
+    /// Policy name
+    #[serde(default)]
+    pub name: String,
+    /// Rules, evaluated in order
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+}
+
+impl Default for RoutingPolicy {
+    fn default() -> Self {
+        Self {
+            version: 0,
+            name: "empty".to_string(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl RoutingPolicy {
+    /// Check rule IDs are unique and constraints are consistent
+    pub fn validate(&self) -> RoutingPolicyResult<()> {
+        let mut seen = std::collections::HashSet::new();
+        for rule in &self.rules {
+            if rule.id.is_empty() {
+                return Err(RoutingPolicyError::InvalidPolicy("rule with empty id".to_string()));
+            }
+            if !seen.insert(rule.id.as_str()) {
+                return Err(RoutingPolicyError::InvalidPolicy(format!("duplicate rule id {}", rule.id)));
+            }
+            if let (Some(min), Some(max)) = (rule.when.min_notional, rule.when.max_notional) {
+                if min >= max {
+                    return Err(RoutingPolicyError::InvalidPolicy(format!(
+                        "rule {}: min_notional {} is not below max_notional {}", rule.id, min, max
+                    )));
+                }
+            }
+            if let Some(venue) = rule.allow_venues.iter().find(|v| rule.deny_venues.contains(v)) {
+                return Err(RoutingPolicyError::InvalidPolicy(format!(
+                    "rule {}: venue {} is both allowed and denied", rule.id, venue
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate an order against this policy without side effects
+    pub fn evaluate(&self, order: &Order, algorithm: Option<ExecutionAlgorithm>) -> PolicyEvaluation {
+        let mut evaluation = PolicyEvaluation {
+            policy_version: self.version,
+            order_id: order.id.clone(),
+            matched_rules: Vec::new(),
+            venues: order.venues.clone(),
+            algorithm,
+            rejected: false,
+            violations: Vec::new(),
+        };
+
+        for rule in self.rules.iter().filter(|rule| rule.when.matches(order)) {
+            evaluation.matched_rules.push(rule.id.clone());
+            let enforced = rule.enforcement == PolicyEnforcement::Enforce;
+            let mut violate = |kind| evaluation.violations.push(PolicyViolation {
+                rule_id: rule.id.clone(),
+                kind,
+                enforced,
+            });
+
+            if rule.reject {
+                violate(ViolationKind::Rejected);
+                if enforced {
+                    evaluation.rejected = true;
+                }
+                continue;
+            }
+
+            // Orders that did not ask for an algorithm just take the required one
+            match (rule.require_algorithm, evaluation.algorithm) {
+                (Some(required), Some(requested)) if requested != required => {
+                    violate(ViolationKind::AlgorithmOverridden { requested, required });
+                    if enforced {
+                        evaluation.algorithm = Some(required);
+                    }
+                }
+                (Some(required), None) if enforced => evaluation.algorithm = Some(required),
+                _ => {}
+            }
+
+            let removed: Vec<String> = evaluation
+                .venues
+                .iter()
+                .filter(|v| {
+                    rule.deny_venues.contains(v) || (!rule.allow_venues.is_empty() && !rule.allow_venues.contains(v))
+                })
+                .cloned()
+                .collect();
+            if !removed.is_empty() {
+                violate(ViolationKind::VenuesRemoved { venues: removed.clone() });
+                if enforced {
+                    evaluation.venues.retain(|v| !removed.contains(v));
+                    if evaluation.venues.is_empty() {
+                        evaluation.violations.push(PolicyViolation {
+                            rule_id: rule.id.clone(),
+                            kind: ViolationKind::NoVenuesLeft,
+                            enforced,
+                        });
+                        evaluation.rejected = true;
+                    }
+                }
+            }
+        }
+
+        evaluation
+    }
+}
+
+/// What a rule changed, or would have changed, about an order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ViolationKind {
+    /// The rule rejects the order
+    Rejected,
+    /// The order's requested algorithm was replaced
+    AlgorithmOverridden { requested: ExecutionAlgorithm, required: ExecutionAlgorithm },
+    /// Denied or non-allowed venues were removed from the order
+    VenuesRemoved { venues: Vec<String> },
+    /// Venue restrictions left the order nowhere to route
+    NoVenuesLeft,
+}
+
+/// A rule constraint the order did not satisfy as submitted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyViolation {
+    /// Rule that raised the violation
+    pub rule_id: String,
+    /// What was violated
+    #[serde(flatten)]
+    pub kind: ViolationKind,
+    /// Whether the rule was enforced or only monitored
+    pub enforced: bool,
+}
+
+/// Outcome of evaluating an order against a policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyEvaluation {
+    /// Version of the policy evaluated
+    pub policy_version: u32,
+    /// Order ID
+    pub order_id: String,
+    /// Rules that matched the order
+    pub matched_rules: Vec<String>,
+    /// Venues the order may route to
+    pub venues: Vec<String>,
+    /// Algorithm the order must use, if any
+    pub algorithm: Option<ExecutionAlgorithm>,
+    /// Whether the order is rejected
+    pub rejected: bool,
+    /// Constraints the order violated as submitted
+    pub violations: Vec<PolicyViolation>,
+}
+
+impl PolicyEvaluation {
+    /// Apply the evaluation to an order, failing if it was rejected
+    pub fn apply(&self, mut order: Order) -> RoutingPolicyResult<Order> {
+        if self.rejected {
+            let violation = self.violations.iter().find(|v| {
+                v.enforced && matches!(v.kind, ViolationKind::Rejected | ViolationKind::NoVenuesLeft)
+            });
+            return Err(RoutingPolicyError::Rejected {
+                order_id: order.id,
+                version: self.policy_version,
+                rule_id: violation.map(|v| v.rule_id.clone()).unwrap_or_default(),
+                reason: match violation.map(|v| &v.kind) {
+                    Some(ViolationKind::NoVenuesLeft) => "no permitted venues".to_string(),
+                    _ => "rejected by rule".to_string(),
+                },
+            });
+        }
+        order.venues = self.venues.clone();
+        Ok(order)
+    }
+}
+
+/// Summary of a published policy version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyVersionInfo {
+    /// Policy version
+    pub version: u32,
+    /// Policy name
+    pub name: String,
+    /// Number of rules
+    pub rules: usize,
+    /// When the version was published
+    pub published_at: DateTime<Utc>,
+    /// Whether this is the active version
+    pub active: bool,
+}
+
+/// Published policy and when it was published
+struct PublishedPolicy {
+    policy: Arc<RoutingPolicy>,
+    published_at: DateTime<Utc>,
+}
+
+/// Holds the active routing policy and its version history
+pub struct RoutingPolicyEngine {
+    /// Every published version, oldest first
+    history: RwLock<Vec<PublishedPolicy>>,
+    /// Version currently enforced
+    active_version: RwLock<u32>,
+    /// Audit vault violations and policy changes are written to (optional)
+    audit_vault: Option<Arc<AuditVault>>,
+}
+
+impl RoutingPolicyEngine {
+    /// Create an engine enforcing `policy`
+    pub fn new(policy: RoutingPolicy) -> RoutingPolicyResult<Self> {
+        policy.validate()?;
+        let version = policy.version;
+        Ok(Self {
+            history: RwLock::new(vec![PublishedPolicy { policy: Arc::new(policy), published_at: Utc::now() }]),
+            active_version: RwLock::new(version),
+            audit_vault: None,
+        })
+    }
+
+    /// Record violations and policy changes in an audit vault
+    pub fn with_audit_vault(mut self, audit_vault: Arc<AuditVault>) -> Self {
+        self.audit_vault = Some(audit_vault);
+        self
+    }
+
+    /// The policy currently enforced
+    pub async fn active(&self) -> Arc<RoutingPolicy> {
+        let version = *self.active_version.read().await;
+        self.history
+            .read()
+            .await
+            .iter()
+            .rev()
+            .find(|p| p.policy.version == version)
+            .map(|p| Arc::clone(&p.policy))
+            .expect("active policy version is always in history")
+    }
+
+    /// Published versions, oldest first
+    pub async fn versions(&self) -> Vec<PolicyVersionInfo> {
+        let active = *self.active_version.read().await;
+        self.history
+            .read()
+            .await
+            .iter()
+            .map(|p| PolicyVersionInfo {
+                version: p.policy.version,
+                name: p.policy.name.clone(),
+                rules: p.policy.rules.len(),
+                published_at: p.published_at,
+                active: p.policy.version == active,
+            })
+            .collect()
+    }
+
+    /// Publish and activate a new policy version
+    pub async fn publish(&self, policy: RoutingPolicy, actor: &str) -> RoutingPolicyResult<u32> {
+        policy.validate()?;
+        let mut history = self.history.write().await;
+        let latest = history.iter().map(|p| p.policy.version).max().unwrap_or(0);
+        if policy.version <= latest {
+            return Err(RoutingPolicyError::InvalidPolicy(format!(
+                "version {} must be greater than latest version {}", policy.version, latest
+            )));
+        }
+
+        let version = policy.version;
+        let summary = serde_json::json!({ "version": version, "name": policy.name, "rules": policy.rules });
+        history.push(PublishedPolicy { policy: Arc::new(policy), published_at: Utc::now() });
+        *self.active_version.write().await = version;
+        drop(history);
+
+        info!("Routing policy v{} published by {}", version, actor);
+        self.audit("routing_policy_published", actor, &format!("Routing policy v{} published", version), summary).await;
+        Ok(version)
+    }
+
+    /// Re-activate a previously published version
+    pub async fn rollback(&self, version: u32, actor: &str) -> RoutingPolicyResult<()> {
+        if !self.history.read().await.iter().any(|p| p.policy.version == version) {
+            return Err(RoutingPolicyError::UnknownVersion(version));
+        }
+        let previous = std::mem::replace(&mut *self.active_version.write().await, version);
+
+        warn!("Routing policy rolled back from v{} to v{} by {}", previous, version, actor);
+        self.audit(
+            "routing_policy_rollback",
+            actor,
+            &format!("Routing policy rolled back from v{} to v{}", previous, version),
+            serde_json::json!({ "from": previous, "to": version }),
+        )
+        .await;
+        Ok(())
+    }
+
+    /// Evaluate an order against the active policy, or a candidate policy,
+    /// without logging or changing anything
+    pub async fn dry_run(
+        &self,
+        order: &Order,
+        algorithm: Option<ExecutionAlgorithm>,
+        candidate: Option<&RoutingPolicy>,
+    ) -> PolicyEvaluation {
+        match candidate {
+            Some(policy) => policy.evaluate(order, algorithm),
+            None => self.active().await.evaluate(order, algorithm),
+        }
+    }
+
+    /// Evaluate an order against the active policy, audit any violations and
+    /// return the constrained order
+    pub async fn enforce(
+        &self,
+        order: Order,
+        algorithm: Option<ExecutionAlgorithm>,
+    ) -> RoutingPolicyResult<(Order, PolicyEvaluation)> {
+        let evaluation = self.active().await.evaluate(&order, algorithm);
+
+        if !evaluation.violations.is_empty() {
+            warn!(
+                "Order {} violated routing policy v{} rules {:?}{}",
+                order.id,
+                evaluation.policy_version,
+                evaluation.violations.iter().map(|v| v.rule_id.as_str()).collect::<Vec<_>>(),
+                if evaluation.rejected { " and was rejected" } else { "" }
+            );
+            let data = serde_json::json!({
+                "order_id": order.id,
+                "symbol": order.symbol,
+                "side": order.side,
+                "notional": order.amount.abs() * order.price,
+                "requested_venues": order.venues,
+                "evaluation": evaluation,
+            });
+            let description = format!("Order {} violated routing policy v{}", order.id, evaluation.policy_version);
+            self.audit("routing_policy_violation", POLICY_ACTOR, &description, data).await;
+        }
+
+        let order = evaluation.apply(order)?;
+        Ok((order, evaluation))
+    }
+
+    async fn audit(&self, event_type: &str, actor: &str, description: &str, data: serde_json::Value) {
+        if let Some(vault) = &self.audit_vault {
+            if let Err(e) = vault.record(event_type, actor, description, data).await {
+                error!("Failed to record {} in the audit vault: {}", event_type, e);
+            }
+        }
+    }
+}
+
+/// Create a routing policy engine enforcing `policy`
+pub fn create_routing_policy_engine(policy: RoutingPolicy) -> RoutingPolicyResult<Arc<RoutingPolicyEngine>> {
+    Ok(Arc::new(RoutingPolicyEngine::new(policy)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_router::TimeInForce;
+    use std::collections::HashMap;
+
+    fn order(symbol: &str, amount: f64, price: f64) -> Order {
+        Order {
+            symbol: symbol.to_string(),
+            side: OrderSide::Buy,
+            amount,
+            price,
+            venues: vec!["binance".to_string(), "venue-x".to_string()],
+            id: format!("{}-{}", symbol, amount),
+            max_slippage: None,
+            max_retries: None,
+            post_only: false,
+            reduce_only: false,
+            time_in_force: TimeInForce::GTC,
+            additional_params: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_policy_rules_versions_and_audit() {
+        let policy: RoutingPolicy = serde_json::from_value(serde_json::json!({
+            "version": 1,
+            "name": "desk",
+            "rules": [
+                {
+                    "id": "btc-block",
+                    "match": { "symbols": ["BTC"], "min_notional": 100000.0 },
+                    "require_algorithm": "ImplementationShortfall",
+                    "deny_venues": ["venue-x"]
+                },
+                { "id": "no-doge", "match": { "symbols": ["DOGE"] }, "reject": true, "enforcement": "monitor" }
+            ]
+        }))
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!("noderr-policy-audit-{}.jsonl", uuid::Uuid::new_v4()));
+        let vault = Arc::new(AuditVault::open(&path).unwrap());
+        let engine = RoutingPolicyEngine::new(policy).unwrap().with_audit_vault(vault.clone());
+
+        // Large BTC order: IS required, venue-x stripped
+        let (routed, evaluation) = engine
+            .enforce(order("BTC/USD", 3.0, 50_000.0), Some(ExecutionAlgorithm::TWAP))
+            .await
+            .unwrap();
+        assert_eq!(routed.venues, vec!["binance"]);
+        assert_eq!(evaluation.algorithm, Some(ExecutionAlgorithm::ImplementationShortfall));
+
+        // Small BTC orders are untouched; monitored rules report but do not reject
+        let (small, evaluation) = engine.enforce(order("BTC-USD", 1.0, 50_000.0), None).await.unwrap();
+        assert_eq!((small.venues.len(), evaluation.violations.len()), (2, 0));
+        let (_, evaluation) = engine.enforce(order("DOGE/USD", 10.0, 0.1), None).await.unwrap();
+        assert!(!evaluation.rejected && !evaluation.violations[0].enforced);
+
+        // Dry-run a stricter candidate without activating it
+        let mut candidate = (*engine.active().await).clone();
+        candidate.version = 2;
+        candidate.rules[1].enforcement = PolicyEnforcement::Enforce;
+        assert!(engine.dry_run(&order("DOGE/USD", 10.0, 0.1), None, Some(&candidate)).await.rejected);
+        assert_eq!(vault.entries().unwrap().len(), 2);
+
+        engine.publish(candidate, "ops").await.unwrap();
+        assert!(matches!(
+            engine.enforce(order("DOGE/USD", 10.0, 0.1), None).await,
+            Err(RoutingPolicyError::Rejected { version: 2, .. })
+        ));
+        engine.rollback(1, "ops").await.unwrap();
+        assert_eq!(engine.active().await.version, 1);
+        assert!(engine.publish(RoutingPolicy { version: 2, ..RoutingPolicy::default() }, "ops").await.is_err());
+
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -0,0 +1,391 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Runaway-strategy detection.
+//!
+//! Watches the recent activity of each strategy for pathological patterns
+//! that usually indicate a logic bug rather than a market view:
+//! - rapid-fire flip-flopping between buy and sell signals on one symbol
+//! - order/cancel loops where nearly every submitted order is cancelled
+//! - position ratcheting, an unbroken run of fills growing one position
+//!
+//! A detection yields [`RunawayEvidence`] which the executor attaches to the
+//! quarantine and healing incident it raises.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::market::Symbol;
+use crate::risk::PositionDirection;
+use crate::strategy::{SignalAction, StrategyId};
+
+/// Configuration for [`RunawayDetector`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunawayDetectorConfig {
+    /// Whether detection is active
+    pub enabled: bool,
+    /// Window of activity inspected, in seconds
+    pub window_secs: u64,
+    /// Buy/sell reversals on one symbol within the window that count as flip-flopping
+    pub max_direction_flips: usize,
+    /// Cancels within the window before an order/cancel loop is considered
+    pub max_cancels: usize,
+    /// Minimum cancels-per-submission ratio for an order/cancel loop
+    pub min_cancel_ratio: f64,
+    /// Consecutive same-side fills on one symbol that count as ratcheting
+    pub max_ratchet_steps: usize,
+    /// Activity records kept as evidence samples
+    pub evidence_samples: usize,
+}
+
+impl Default for RunawayDetectorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_secs: 60,
+            max_direction_flips: 6,
+            max_cancels: 20,
+            min_cancel_ratio: 0.9,
+            max_ratchet_steps: 10,
+            evidence_samples: 20,
+        }
+    }
+}
+
+/// Activity reported for a strategy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum StrategyActivity {
+    /// The strategy emitted a signal
+    Signal {
+        symbol: Symbol,
+        action: SignalAction,
+        direction: PositionDirection,
+    },
+    /// An order from the strategy was submitted
+    OrderSubmitted { order_id: String, symbol: Symbol },
+    /// An order from the strategy was cancelled
+    OrderCancelled { order_id: String, symbol: Symbol },
+    /// An order from the strategy filled; quantity is signed, positive for buys
+    Fill { symbol: Symbol, quantity: f64 },
+}
+
+impl StrategyActivity {
+    /// Symbol the activity concerns
+    pub fn symbol(&self) -> &Symbol {
+        match self {
+            StrategyActivity::Signal { symbol, .. }
+            | StrategyActivity::OrderSubmitted { symbol, .. }
+            | StrategyActivity::OrderCancelled { symbol, .. }
+            | StrategyActivity::Fill { symbol, .. } => symbol,
+        }
+    }
+
+    /// Buy (+1) or sell (-1) side of a signal or fill, if it has one
+    fn side(&self) -> Option<i8> {
+        match self {
+            StrategyActivity::Signal { action, direction, .. } => signal_side(action, *direction),
+            StrategyActivity::Fill { quantity, .. } if *quantity > 0.0 => Some(1),
+            StrategyActivity::Fill { quantity, .. } if *quantity < 0.0 => Some(-1),
+            _ => None,
+        }
+    }
+}
+
+/// Buy (+1) or sell (-1) side implied by a signal's action and direction
+pub fn signal_side(action: &SignalAction, direction: PositionDirection) -> Option<i8> {
+    match (action, direction) {
+        (SignalAction::Enter, PositionDirection::Long) | (SignalAction::Exit, PositionDirection::Short) => Some(1),
+        (SignalAction::Enter, PositionDirection::Short) | (SignalAction::Exit, PositionDirection::Long) => Some(-1),
+        _ => None,
+    }
+}
+
+/// Timestamped activity record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityRecord {
+    /// When the activity happened
+    pub timestamp: DateTime<Utc>,
+    /// What happened
+    pub activity: StrategyActivity,
+}
+
+/// Pathological pattern recognized by the detector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RunawayPattern {
+    /// Rapid alternation between buy and sell signals
+    FlipFlop,
+    /// Orders submitted and cancelled in a loop
+    OrderCancelLoop,
+    /// A position grown by an unbroken run of same-side fills
+    PositionRatchet,
+}
+
+impl fmt::Display for RunawayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunawayPattern::FlipFlop => write!(f, "flip_flop"),
+            RunawayPattern::OrderCancelLoop => write!(f, "order_cancel_loop"),
+            RunawayPattern::PositionRatchet => write!(f, "position_ratchet"),
+        }
+    }
+}
+
+/// Evidence backing a runaway detection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunawayEvidence {
+    /// Strategy the pattern was observed on
+    pub strategy_id: StrategyId,
+    /// Pattern recognized
+    pub pattern: RunawayPattern,
+    /// Symbol involved, when the pattern is per-symbol
+    pub symbol: Option<Symbol>,
+    /// Occurrences observed within the window
+    pub occurrences: usize,
+    /// Configured threshold that was reached
+    pub threshold: usize,
+    /// Window inspected, in seconds
+    pub window_secs: u64,
+    /// When the pattern was detected
+    pub detected_at: DateTime<Utc>,
+    /// Most recent activity records that made up the pattern
+    pub samples: Vec<ActivityRecord>,
+}
+
+impl RunawayEvidence {
+    /// One-line description used as the quarantine reason
+    pub fn summary(&self) -> String {
+        match &self.symbol {
+            Some(symbol) => format!(
+                "runaway {} on {}: {} occurrences in {}s (threshold {})",
+                self.pattern, symbol, self.occurrences, self.window_secs, self.threshold
+            ),
+            None => format!(
+                "runaway {}: {} occurrences in {}s (threshold {})",
+                self.pattern, self.occurrences, self.window_secs, self.threshold
+            ),
+        }
+    }
+}
+
+/// Detects runaway behaviour from per-strategy activity
+#[derive(Debug)]
+pub struct RunawayDetector {
+    /// Configuration
+    config: RunawayDetectorConfig,
+    /// Recent activity keyed by strategy
+    history: Mutex<HashMap<StrategyId, VecDeque<ActivityRecord>>>,
+}
+
+impl RunawayDetector {
+    /// Create a new detector
+    pub fn new(config: RunawayDetectorConfig) -> Self {
+        Self {
+            config,
+            history: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Configuration in force
+    pub fn config(&self) -> &RunawayDetectorConfig {
+        &self.config
+    }
+
+    /// Record activity at the current time
+    pub fn record(&self, strategy_id: &StrategyId, activity: StrategyActivity) -> Option<RunawayEvidence> {
+        self.record_at(strategy_id, activity, Utc::now())
+    }
+
+    /// Record activity at `now`, returning evidence if a runaway pattern is detected.
+    ///
+    /// The strategy's history is cleared on detection so the same pattern is
+    /// reported once.
+    pub fn record_at(
+        &self,
+        strategy_id: &StrategyId,
+        activity: StrategyActivity,
+        now: DateTime<Utc>,
+    ) -> Option<RunawayEvidence> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let mut history = self.history.lock().unwrap();
+        let records = history.entry(strategy_id.clone()).or_default();
+        let window_start = now - Duration::seconds(self.config.window_secs as i64);
+        while records.front().is_some_and(|r| r.timestamp < window_start) {
+            records.pop_front();
+        }
+        let symbol = activity.symbol().clone();
+        records.push_back(ActivityRecord { timestamp: now, activity });
+
+        let evidence = self
+            .detect_flip_flop(records, &symbol)
+            .or_else(|| self.detect_cancel_loop(records))
+            .or_else(|| self.detect_ratchet(records, &symbol))
+            .map(|(pattern, symbol, occurrences, threshold, samples)| RunawayEvidence {
+                strategy_id: strategy_id.clone(),
+                pattern,
+                symbol,
+                occurrences,
+                threshold,
+                window_secs: self.config.window_secs,
+                detected_at: now,
+                samples: samples
+                    .into_iter()
+                    .rev()
+                    .take(self.config.evidence_samples)
+                    .rev()
+                    .collect(),
+            });
+
+        if evidence.is_some() {
+            history.remove(strategy_id);
+        }
+        evidence
+    }
+
+    /// Forget all activity for a strategy, e.g. after it is released from quarantine
+    pub fn clear(&self, strategy_id: &StrategyId) {
+        self.history.lock().unwrap().remove(strategy_id);
+    }
+
+    fn detect_flip_flop(&self, records: &VecDeque<ActivityRecord>, symbol: &Symbol) -> Option<Detection> {
+        let signals: Vec<&ActivityRecord> = records
+            .iter()
+            .filter(|r| matches!(r.activity, StrategyActivity::Signal { .. }) && r.activity.symbol() == symbol)
+            .filter(|r| r.activity.side().is_some())
+            .collect();
+        let flips = signals
+            .windows(2)
+            .filter(|pair| pair[0].activity.side() != pair[1].activity.side())
+            .count();
+
+        (flips >= self.config.max_direction_flips).then(|| {
+            let samples = signals.into_iter().cloned().collect();
+            (RunawayPattern::FlipFlop, Some(symbol.clone()), flips, self.config.max_direction_flips, samples)
+        })
+    }
+
+    fn detect_cancel_loop(&self, records: &VecDeque<ActivityRecord>) -> Option<Detection> {
+        let submitted = records
+            .iter()
+            .filter(|r| matches!(r.activity, StrategyActivity::OrderSubmitted { .. }))
+            .count();
+        let cancelled = records
+            .iter()
+            .filter(|r| matches!(r.activity, StrategyActivity::OrderCancelled { .. }))
+            .count();
+        if cancelled < self.config.max_cancels {
+            return None;
+        }
+        let ratio = cancelled as f64 / submitted.max(1) as f64;
+        (ratio >= self.config.min_cancel_ratio).then(|| {
+            let samples = records
+                .iter()
+                .filter(|r| {
+                    matches!(
+                        r.activity,
+                        StrategyActivity::OrderSubmitted { .. } | StrategyActivity::OrderCancelled { .. }
+                    )
+                })
+                .cloned()
+                .collect();
+            (RunawayPattern::OrderCancelLoop, None, cancelled, self.config.max_cancels, samples)
+        })
+    }
+
+    fn detect_ratchet(&self, records: &VecDeque<ActivityRecord>, symbol: &Symbol) -> Option<Detection> {
+        let fills: Vec<&ActivityRecord> = records
+            .iter()
+            .filter(|r| matches!(r.activity, StrategyActivity::Fill { .. }) && r.activity.symbol() == symbol)
+            .collect();
+        let side = fills.last()?.activity.side()?;
+        let run = fills.iter().rev().take_while(|r| r.activity.side() == Some(side)).count();
+
+        (run >= self.config.max_ratchet_steps).then(|| {
+            let samples = fills[fills.len() - run..].iter().map(|r| (*r).clone()).collect();
+            (RunawayPattern::PositionRatchet, Some(symbol.clone()), run, self.config.max_ratchet_steps, samples)
+        })
+    }
+}
+
+/// Pattern, symbol, occurrences, threshold and samples of a detection
+type Detection = (RunawayPattern, Option<Symbol>, usize, usize, Vec<ActivityRecord>);
+
+/// Create a runaway detector
+pub fn create_runaway_detector(config: RunawayDetectorConfig) -> Arc<RunawayDetector> {
+    Arc::new(RunawayDetector::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(action: SignalAction) -> StrategyActivity {
+        StrategyActivity::Signal {
+            symbol: "BTC/USD".to_string(),
+            action,
+            direction: PositionDirection::Long,
+        }
+    }
+
+    #[test]
+    fn detects_flip_flop_cancel_loop_and_ratchet() {
+        let config = RunawayDetectorConfig {
+            max_direction_flips: 3,
+            max_cancels: 3,
+            max_ratchet_steps: 3,
+            ..Default::default()
+        };
+        let detector = RunawayDetector::new(config);
+        let id: StrategyId = "looper".to_string();
+        let t0 = Utc::now();
+
+        let actions = [SignalAction::Enter, SignalAction::Exit, SignalAction::Enter];
+        for (i, action) in actions.into_iter().enumerate() {
+            assert!(detector.record_at(&id, signal(action), t0 + Duration::seconds(i as i64)).is_none());
+        }
+        let evidence = detector.record_at(&id, signal(SignalAction::Exit), t0 + Duration::seconds(3)).unwrap();
+        assert_eq!(evidence.pattern, RunawayPattern::FlipFlop);
+        assert_eq!(evidence.occurrences, 3);
+        assert_eq!(evidence.samples.len(), 4);
+
+        let mut detected = None;
+        for i in 0..3 {
+            let order_id = format!("o{}", i);
+            let symbol = "ETH/USD".to_string();
+            detector.record_at(&id, StrategyActivity::OrderSubmitted { order_id: order_id.clone(), symbol: symbol.clone() }, t0);
+            detected = detector.record_at(&id, StrategyActivity::OrderCancelled { order_id, symbol }, t0);
+        }
+        assert_eq!(detected.unwrap().pattern, RunawayPattern::OrderCancelLoop);
+
+        // Outside the window old fills no longer count towards the run
+        let fill = |q: f64| StrategyActivity::Fill { symbol: "SOL/USD".to_string(), quantity: q };
+        assert!(detector.record_at(&id, fill(1.0), t0).is_none());
+        assert!(detector.record_at(&id, fill(1.0), t0).is_none());
+        assert!(detector.record_at(&id, fill(1.0), t0 + Duration::seconds(120)).is_none());
+        assert!(detector.record_at(&id, fill(-1.0), t0 + Duration::seconds(121)).is_none());
+        assert!(detector.record_at(&id, fill(-1.0), t0 + Duration::seconds(122)).is_none());
+        let evidence = detector.record_at(&id, fill(-2.0), t0 + Duration::seconds(123)).unwrap();
+        assert_eq!(evidence.pattern, RunawayPattern::PositionRatchet);
+        assert_eq!(evidence.symbol.as_deref(), Some("SOL/USD"));
+    }
+}
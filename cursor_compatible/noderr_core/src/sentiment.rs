@@ -0,0 +1,454 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Sentiment ingestion: pluggable providers post scored items per symbol,
+//! which are aggregated into a decaying per-symbol sentiment index and
+//! recorded as the `AlphaFactor::Sentiment` return series.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::factor_analysis::redis_engine::FACTOR_DATA_KEY;
+use crate::factor_analysis::AlphaFactor;
+use crate::market::Symbol;
+use crate::redis::RedisClient;
+
+/// Errors raised by the sentiment pipeline
+#[derive(Debug, Error)]
+pub enum SentimentError {
+    #[error("Sentiment provider {provider_name} failed: {message}")]
+    Provider { provider_name: String, message: String },
+
+    #[error("Invalid sentiment item: {0}")]
+    InvalidItem(String),
+
+    #[error("Failed to persist sentiment factor series: {0}")]
+    Storage(String),
+}
+
+/// Result type for sentiment operations
+pub type SentimentResult<T> = Result<T, SentimentError>;
+
+/// A single scored piece of sentiment (news headline, social post, analyst note)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentimentItem {
+    /// Symbol the item refers to
+    pub symbol: Symbol,
+    /// Score from -1.0 (bearish) to 1.0 (bullish)
+    pub score: f64,
+    /// Relative weight of the item, e.g. reach or source credibility
+    #[serde(default = "default_item_weight")]
+    pub weight: f64,
+    /// When the item was published
+    pub published_at: DateTime<Utc>,
+    /// Provider that produced the item; filled in on ingestion
+    #[serde(default)]
+    pub provider: String,
+}
+
+fn default_item_weight() -> f64 {
+    1.0
+}
+
+impl SentimentItem {
+    /// Check that the score and weight are usable
+    pub fn validate(&self) -> SentimentResult<()> {
+        if !self.score.is_finite() || !(-1.0..=1.0).contains(&self.score) {
+            return Err(SentimentError::InvalidItem(format!(
+                "score {} for {} is outside [-1, 1]", self.score, self.symbol
+            )));
+        }
+        if !self.weight.is_finite() || self.weight <= 0.0 {
+            return Err(SentimentError::InvalidItem(format!(
+                "weight {} for {} must be positive", self.weight, self.symbol
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Source of scored sentiment items
+#[async_trait]
+pub trait SentimentProvider: Send + Sync {
+    /// Provider name, recorded on every item it produces
+    fn name(&self) -> &str;
+
+    /// Fetch items published after `since`
+    async fn fetch_items(&self, since: DateTime<Utc>) -> SentimentResult<Vec<SentimentItem>>;
+}
+
+/// Sentiment pipeline configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SentimentConfig {
+    /// Time for an item's influence on the index to halve (seconds)
+    pub half_life_secs: u64,
+    /// Weight of the implicit neutral observation; pulls quiet symbols back to zero
+    pub neutral_prior_weight: f64,
+    /// How often providers are polled (seconds)
+    pub poll_interval_secs: u64,
+    /// How far back the first poll looks (seconds)
+    pub initial_lookback_secs: i64,
+    /// Maximum points kept in the factor return series
+    pub max_series_points: usize,
+}
+
+impl Default for SentimentConfig {
+    fn default() -> Self {
+        Self {
+            half_life_secs: 6 * 3600,
+            neutral_prior_weight: 1.0,
+            poll_interval_secs: 300,
+            initial_lookback_secs: 24 * 3600,
+            max_series_points: 2000,
+        }
+    }
+}
+
+/// Current sentiment for a symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolSentiment {
+    /// Symbol
+    pub symbol: Symbol,
+    /// Decayed sentiment index in [-1, 1]
+    pub index: f64,
+    /// Decayed weight of the items behind the index
+    pub confidence_weight: f64,
+    /// Number of items ingested for the symbol
+    pub item_count: u64,
+    /// Time the decay was last applied
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Decayed accumulators for one symbol
+#[derive(Debug, Clone)]
+struct SentimentAccumulator {
+    weighted_score: f64,
+    weight: f64,
+    item_count: u64,
+    updated_at: DateTime<Utc>,
+}
+
+impl SentimentAccumulator {
+    fn decay_to(&mut self, now: DateTime<Utc>, half_life_secs: u64) {
+        let elapsed = (now - self.updated_at).num_milliseconds() as f64 / 1000.0;
+        if elapsed <= 0.0 {
+            return;
+        }
+        let factor = 0.5f64.powf(elapsed / half_life_secs.max(1) as f64);
+        self.weighted_score *= factor;
+        self.weight *= factor;
+        self.updated_at = now;
+    }
+
+    fn index(&self, prior_weight: f64) -> f64 {
+        let denominator = self.weight + prior_weight.max(0.0);
+        if denominator <= 0.0 {
+            0.0
+        } else {
+            (self.weighted_score / denominator).clamp(-1.0, 1.0)
+        }
+    }
+}
+
+/// Aggregates provider items into per-symbol sentiment and the sentiment factor series
+pub struct SentimentPipeline {
+    /// Configuration
+    config: SentimentConfig,
+    /// Registered providers
+    providers: RwLock<Vec<Arc<dyn SentimentProvider>>>,
+    /// Accumulators by symbol
+    symbols: RwLock<HashMap<Symbol, SentimentAccumulator>>,
+    /// Factor return series: change in the cross-sectional mean index per snapshot
+    factor_series: RwLock<Vec<(DateTime<Utc>, f64)>>,
+    /// Cross-sectional mean index at the last snapshot
+    last_mean_index: RwLock<Option<f64>>,
+    /// Time of the last provider poll
+    last_poll: RwLock<Option<DateTime<Utc>>>,
+    /// Redis client the factor series is written to for factor analysis
+    redis: Option<Arc<dyn RedisClient>>,
+    /// Polling task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl SentimentPipeline {
+    /// Create a pipeline with no providers
+    pub fn new(config: SentimentConfig, redis: Option<Arc<dyn RedisClient>>) -> Self {
+        Self {
+            config,
+            providers: RwLock::new(Vec::new()),
+            symbols: RwLock::new(HashMap::new()),
+            factor_series: RwLock::new(Vec::new()),
+            last_mean_index: RwLock::new(None),
+            last_poll: RwLock::new(None),
+            redis,
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Register a sentiment provider
+    pub async fn add_provider(&self, provider: Arc<dyn SentimentProvider>) {
+        debug!("Registered sentiment provider {}", provider.name());
+        self.providers.write().await.push(provider);
+    }
+
+    /// Fold items into the per-symbol index; invalid items are skipped.
+    ///
+    /// Returns the number of items accepted.
+    pub async fn ingest(&self, items: Vec<SentimentItem>) -> usize {
+        let mut items: Vec<SentimentItem> = items
+            .into_iter()
+            .filter(|item| match item.validate() {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Dropping sentiment item from {}: {}", item.provider, e);
+                    false
+                }
+            })
+            .collect();
+        items.sort_by_key(|item| item.published_at);
+
+        let mut symbols = self.symbols.write().await;
+        for item in &items {
+            let accumulator = symbols.entry(item.symbol.clone()).or_insert(SentimentAccumulator {
+                weighted_score: 0.0,
+                weight: 0.0,
+                item_count: 0,
+                updated_at: item.published_at,
+            });
+            accumulator.decay_to(item.published_at, self.config.half_life_secs);
+
+            // Late items are discounted by their age rather than rewinding the accumulator
+            let age = (accumulator.updated_at - item.published_at).num_milliseconds().max(0) as f64 / 1000.0;
+            let weight = item.weight * 0.5f64.powf(age / self.config.half_life_secs.max(1) as f64);
+            accumulator.weighted_score += item.score * weight;
+            accumulator.weight += weight;
+            accumulator.item_count += 1;
+        }
+
+        items.len()
+    }
+
+    /// Sentiment for a symbol at `now`, if any items have been seen for it
+    pub async fn sentiment(&self, symbol: &Symbol, now: DateTime<Utc>) -> Option<SymbolSentiment> {
+        let mut accumulator = self.symbols.read().await.get(symbol)?.clone();
+        accumulator.decay_to(now, self.config.half_life_secs);
+        Some(self.to_sentiment(symbol, &accumulator))
+    }
+
+    /// Sentiment index for a symbol at `now`; neutral (0.0) when unknown
+    pub async fn sentiment_index(&self, symbol: &Symbol, now: DateTime<Utc>) -> f64 {
+        self.sentiment(symbol, now).await.map(|s| s.index).unwrap_or(0.0)
+    }
+
+    /// Sentiment for every tracked symbol at `now`
+    pub async fn snapshot(&self, now: DateTime<Utc>) -> Vec<SymbolSentiment> {
+        let mut symbols = self.symbols.write().await;
+        let mut snapshot: Vec<SymbolSentiment> = symbols
+            .iter_mut()
+            .map(|(symbol, accumulator)| {
+                accumulator.decay_to(now, self.config.half_life_secs);
+                self.to_sentiment(symbol, accumulator)
+            })
+            .collect();
+        snapshot.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        snapshot
+    }
+
+    /// Append a point to the sentiment factor series and persist it.
+    ///
+    /// The factor return is the change in the cross-sectional mean index since
+    /// the previous point, so it lines up with the other factor return series.
+    pub async fn record_factor_point(&self, now: DateTime<Utc>) -> SentimentResult<Option<f64>> {
+        let snapshot = self.snapshot(now).await;
+        if snapshot.is_empty() {
+            return Ok(None);
+        }
+        let mean_index = snapshot.iter().map(|s| s.index).sum::<f64>() / snapshot.len() as f64;
+
+        let factor_return = {
+            let mut last = self.last_mean_index.write().await;
+            let change = last.map(|previous| mean_index - previous);
+            *last = Some(mean_index);
+            match change {
+                Some(change) => change,
+                None => return Ok(None),
+            }
+        };
+
+        let series = {
+            let mut series = self.factor_series.write().await;
+            series.push((now, factor_return));
+            let excess = series.len().saturating_sub(self.config.max_series_points);
+            series.drain(..excess);
+            series.clone()
+        };
+
+        if let Some(redis) = &self.redis {
+            let key = format!("{}{}", FACTOR_DATA_KEY, AlphaFactor::Sentiment.as_str());
+            redis
+                .set(&key, &series, None)
+                .await
+                .map_err(|e| SentimentError::Storage(e.to_string()))?;
+        }
+
+        Ok(Some(factor_return))
+    }
+
+    /// Sentiment factor return series, oldest first
+    pub async fn factor_series(&self) -> Vec<(DateTime<Utc>, f64)> {
+        self.factor_series.read().await.clone()
+    }
+
+    /// Poll every provider, ingest new items and record a factor point.
+    ///
+    /// A failing provider is skipped for this poll. Returns the number of items ingested.
+    pub async fn poll(&self, now: DateTime<Utc>) -> usize {
+        let since = self
+            .last_poll
+            .read()
+            .await
+            .unwrap_or_else(|| now - chrono::Duration::seconds(self.config.initial_lookback_secs));
+
+        let providers = self.providers.read().await.clone();
+        let mut items = Vec::new();
+        for provider in providers {
+            match provider.fetch_items(since).await {
+                Ok(fetched) => {
+                    debug!("Sentiment provider {} returned {} items", provider.name(), fetched.len());
+                    items.extend(fetched.into_iter().map(|mut item| {
+                        item.provider = provider.name().to_string();
+                        item
+                    }));
+                }
+                Err(e) => warn!("Sentiment provider {} failed: {}", provider.name(), e),
+            }
+        }
+
+        let ingested = self.ingest(items).await;
+        *self.last_poll.write().await = Some(now);
+        if let Err(e) = self.record_factor_point(now).await {
+            warn!("Failed to record sentiment factor point: {}", e);
+        }
+        ingested
+    }
+
+    /// Start polling providers in the background
+    pub async fn start(self: &Arc<Self>) {
+        let mut handle_guard = self.task_handle.write().await;
+        if handle_guard.is_some() {
+            return;
+        }
+
+        let pipeline = Arc::clone(self);
+        let interval_secs = self.config.poll_interval_secs.max(1);
+        *handle_guard = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                pipeline.poll(Utc::now()).await;
+            }
+        }));
+    }
+
+    /// Stop polling providers
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+
+    fn to_sentiment(&self, symbol: &Symbol, accumulator: &SentimentAccumulator) -> SymbolSentiment {
+        SymbolSentiment {
+            symbol: symbol.clone(),
+            index: accumulator.index(self.config.neutral_prior_weight),
+            confidence_weight: accumulator.weight,
+            item_count: accumulator.item_count,
+            updated_at: accumulator.updated_at,
+        }
+    }
+}
+
+/// Create a sentiment pipeline
+pub fn create_sentiment_pipeline(
+    config: SentimentConfig,
+    redis: Option<Arc<dyn RedisClient>>,
+) -> Arc<SentimentPipeline> {
+    Arc::new(SentimentPipeline::new(config, redis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedProvider(Vec<SentimentItem>);
+
+    #[async_trait]
+    impl SentimentProvider for FixedProvider {
+        fn name(&self) -> &str {
+            "fixed"
+        }
+
+        async fn fetch_items(&self, since: DateTime<Utc>) -> SentimentResult<Vec<SentimentItem>> {
+            Ok(self.0.iter().filter(|item| item.published_at > since).cloned().collect())
+        }
+    }
+
+    fn item(symbol: &str, score: f64, published_at: DateTime<Utc>) -> SentimentItem {
+        SentimentItem {
+            symbol: symbol.to_string(),
+            score,
+            weight: 1.0,
+            published_at,
+            provider: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_index_decays_and_feeds_factor_series() {
+        let now = Utc::now();
+        let config = SentimentConfig { half_life_secs: 3600, ..SentimentConfig::default() };
+        let pipeline = create_sentiment_pipeline(config, None);
+        pipeline.add_provider(Arc::new(FixedProvider(vec![
+            item("BTC/USD", 0.8, now - chrono::Duration::minutes(1)),
+            item("BTC/USD", 0.6, now - chrono::Duration::minutes(2)),
+            item("BTC/USD", 3.0, now),
+        ]))).await;
+
+        assert_eq!(pipeline.poll(now).await, 2);
+        let btc = "BTC/USD".to_string();
+        let fresh = pipeline.sentiment_index(&btc, now).await;
+        assert!(fresh > 0.4 && fresh < 0.7);
+
+        // With no new items the index drifts back towards neutral
+        let later = now + chrono::Duration::hours(3);
+        let stale = pipeline.sentiment_index(&btc, later).await;
+        assert!(stale > 0.0 && stale < fresh);
+
+        pipeline.poll(later).await;
+        let series = pipeline.factor_series().await;
+        assert_eq!(series.len(), 1);
+        assert!((series[0].1 - (stale - fresh)).abs() < 1e-9);
+    }
+}
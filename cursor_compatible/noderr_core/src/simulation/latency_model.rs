@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Order latency model for paper trading and backtests
+//!
+//! Splits the time between deciding to trade and being filled into network
+//! transit and venue matching delay, each drawn from a configurable
+//! distribution. Sampling is seeded so that replays are reproducible.
+
+use std::collections::HashMap;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// z-score of the 99th percentile of a standard normal distribution
+const Z_P99: f64 = 2.326_347_874;
+
+/// Distribution of a single latency component, in milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LatencyDistribution {
+    /// Always the same delay
+    Constant { ms: f64 },
+    /// Uniformly distributed between the two bounds
+    Uniform { min_ms: f64, max_ms: f64 },
+    /// Normally distributed, truncated at zero
+    Normal { mean_ms: f64, std_dev_ms: f64 },
+    /// Log-normal with the given median and 99th percentile, which captures
+    /// the long right tail typical of network and matching delays
+    LogNormal { median_ms: f64, p99_ms: f64 },
+    /// Resampled from observed delays
+    Empirical { samples_ms: Vec<f64> },
+}
+
+impl Default for LatencyDistribution {
+    fn default() -> Self {
+        Self::Constant { ms: 0.0 }
+    }
+}
+
+impl LatencyDistribution {
+    /// Log-normal fitted to an observed median and 99th percentile
+    pub fn from_percentiles(median_ms: f64, p99_ms: f64) -> Self {
+        Self::LogNormal {
+            median_ms: median_ms.max(0.0),
+            p99_ms: p99_ms.max(median_ms).max(0.0),
+        }
+    }
+
+    /// Draw one delay in milliseconds; never negative
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let ms = match self {
+            Self::Constant { ms } => *ms,
+            Self::Uniform { min_ms, max_ms } => {
+                let (lo, hi) = if min_ms <= max_ms { (*min_ms, *max_ms) } else { (*max_ms, *min_ms) };
+                lo + rng.gen::<f64>() * (hi - lo)
+            }
+            Self::Normal { mean_ms, std_dev_ms } => mean_ms + std_dev_ms * standard_normal(rng),
+            Self::LogNormal { median_ms, p99_ms } => {
+                if *median_ms <= 0.0 {
+                    return 0.0;
+                }
+                let sigma = (p99_ms / median_ms).max(1.0).ln() / Z_P99;
+                median_ms * (sigma * standard_normal(rng)).exp()
+            }
+            Self::Empirical { samples_ms } => {
+                if samples_ms.is_empty() {
+                    return 0.0;
+                }
+                samples_ms[rng.gen_range(0..samples_ms.len())]
+            }
+        };
+        ms.max(0.0)
+    }
+
+    /// Median delay in milliseconds
+    pub fn median_ms(&self) -> f64 {
+        match self {
+            Self::Constant { ms } => ms.max(0.0),
+            Self::Uniform { min_ms, max_ms } => ((min_ms + max_ms) / 2.0).max(0.0),
+            Self::Normal { mean_ms, .. } => mean_ms.max(0.0),
+            Self::LogNormal { median_ms, .. } => median_ms.max(0.0),
+            Self::Empirical { samples_ms } => {
+                if samples_ms.is_empty() {
+                    return 0.0;
+                }
+                let mut sorted = samples_ms.clone();
+                sorted.sort_by(|a, b| a.total_cmp(b));
+                sorted[sorted.len() / 2].max(0.0)
+            }
+        }
+    }
+}
+
+/// Standard normal draw via Box-Muller
+pub(crate) fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // 1 - gen() keeps u1 in (0, 1] so the log is finite
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Transit and matching delay distributions for one venue
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VenueLatencyProfile {
+    /// Time for the order to reach the venue
+    pub transit: LatencyDistribution,
+    /// Time the venue takes to accept and match the order once received
+    pub matching: LatencyDistribution,
+}
+
+/// Latency model configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LatencyModelConfig {
+    /// Profile used for venues without an override
+    pub default_profile: VenueLatencyProfile,
+    /// Per-venue profiles, keyed by venue ID
+    pub venues: HashMap<String, VenueLatencyProfile>,
+    /// RNG seed; the same seed replays the same delays
+    pub seed: u64,
+}
+
+impl Default for LatencyModelConfig {
+    fn default() -> Self {
+        Self {
+            default_profile: VenueLatencyProfile {
+                transit: LatencyDistribution::from_percentiles(20.0, 120.0),
+                matching: LatencyDistribution::from_percentiles(2.0, 25.0),
+            },
+            venues: HashMap::new(),
+            seed: 42,
+        }
+    }
+}
+
+/// One sampled order latency
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LatencySample {
+    /// Network transit delay in milliseconds
+    pub transit_ms: f64,
+    /// Venue matching delay in milliseconds
+    pub matching_ms: f64,
+}
+
+impl LatencySample {
+    /// Total delay from decision to the order being live on the book
+    pub fn total_ms(&self) -> f64 {
+        self.transit_ms + self.matching_ms
+    }
+}
+
+/// Seeded sampler over a latency model configuration
+#[derive(Debug, Clone)]
+pub struct LatencyModel {
+    /// Configuration
+    config: LatencyModelConfig,
+    /// Deterministic RNG
+    rng: StdRng,
+}
+
+impl LatencyModel {
+    /// Create a new latency model
+    pub fn new(config: LatencyModelConfig) -> Self {
+        let rng = StdRng::seed_from_u64(config.seed);
+        Self { config, rng }
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &LatencyModelConfig {
+        &self.config
+    }
+
+    /// Replace the profile used for a venue
+    pub fn set_venue_profile(&mut self, venue: &str, profile: VenueLatencyProfile) {
+        self.config.venues.insert(venue.to_string(), profile);
+    }
+
+    /// Profile in effect for a venue
+    pub fn profile(&self, venue: &str) -> &VenueLatencyProfile {
+        self.config.venues.get(venue).unwrap_or(&self.config.default_profile)
+    }
+
+    /// Draw the transit and matching delay for one order sent to `venue`
+    pub fn sample(&mut self, venue: &str) -> LatencySample {
+        let profile = self.config.venues.get(venue).unwrap_or(&self.config.default_profile);
+        LatencySample {
+            transit_ms: profile.transit.sample(&mut self.rng),
+            matching_ms: profile.matching.sample(&mut self.rng),
+        }
+    }
+
+    /// Restart the RNG from the configured seed
+    pub fn reset(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.config.seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lognormal_matches_percentiles_and_replays() {
+        let config = LatencyModelConfig {
+            default_profile: VenueLatencyProfile {
+                transit: LatencyDistribution::from_percentiles(10.0, 100.0),
+                matching: LatencyDistribution::Constant { ms: 1.0 },
+            },
+            ..LatencyModelConfig::default()
+        };
+        let mut model = LatencyModel::new(config);
+
+        let mut samples: Vec<f64> = (0..20_000).map(|_| model.sample("binance").transit_ms).collect();
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let median = samples[samples.len() / 2];
+        let p99 = samples[samples.len() * 99 / 100];
+        assert!((median - 10.0).abs() < 1.0, "median {}", median);
+        assert!((p99 - 100.0).abs() < 15.0, "p99 {}", p99);
+
+        model.reset();
+        let first = model.sample("binance");
+        model.reset();
+        assert_eq!(first, model.sample("binance"));
+        assert_eq!(first.matching_ms, 1.0);
+    }
+}
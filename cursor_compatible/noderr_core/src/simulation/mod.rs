@@ -1 +1,2 @@
-pub mod trust_decay_simulator; 
\ No newline at end of file
+pub mod trust_decay_simulator;
+pub mod latency_model;
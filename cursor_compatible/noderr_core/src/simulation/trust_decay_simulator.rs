@@ -1,7 +1,12 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Duration, Utc};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
 /// Simulation parameters for trust decay
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DecaySimulationParams {
     /// Strategy ID for which the decay is simulated
     pub strategy_id: String,
@@ -16,6 +21,7 @@ pub struct DecaySimulationParams {
 }
 
 /// Simulated trust score entry with timestamp and score value
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulatedTrustScore {
     /// Timestamp of the simulated trust score entry
     pub timestamp: DateTime<Utc>,
@@ -133,6 +139,195 @@ pub fn apply_recovery_events(
     scores
 }
 
+/// How often a strategy's trust score has historically recovered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryProfile {
+    /// Days covered by the history
+    pub observed_days: usize,
+    /// Day-over-day increases counted as recovery events
+    pub events: usize,
+    /// Probability of a recovery event on any given day
+    pub daily_probability: f64,
+    /// Average score gained per recovery event
+    pub mean_boost: f64,
+}
+
+impl RecoveryProfile {
+    /// Smallest day-over-day rise counted as a recovery by default
+    pub const DEFAULT_MIN_BOOST: f64 = 0.02;
+
+    /// Derive a profile from historical `(timestamp, score)` observations
+    ///
+    /// Observations are reduced to one closing score per calendar day; a rise
+    /// of at least `min_boost` from one observed day to the next counts as a
+    /// recovery event.
+    pub fn from_history(observations: &[(DateTime<Utc>, f64)], min_boost: f64) -> Self {
+        let mut daily: BTreeMap<i64, (DateTime<Utc>, f64)> = BTreeMap::new();
+        for &(timestamp, score) in observations {
+            let day = timestamp.timestamp().div_euclid(86_400);
+            let slot = daily.entry(day).or_insert((timestamp, score));
+            if timestamp >= slot.0 {
+                *slot = (timestamp, score);
+            }
+        }
+
+        let (first, last) = match (daily.keys().next(), daily.keys().next_back()) {
+            (Some(&first), Some(&last)) if last > first => (first, last),
+            _ => return Self::default(),
+        };
+        let observed_days = (last - first) as usize;
+
+        let closes: Vec<f64> = daily.values().map(|&(_, score)| score).collect();
+        let boosts: Vec<f64> = closes
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|&delta| delta >= min_boost)
+            .collect();
+
+        Self {
+            observed_days,
+            events: boosts.len(),
+            daily_probability: (boosts.len() as f64 / observed_days as f64).min(1.0),
+            mean_boost: if boosts.is_empty() { 0.0 } else { boosts.iter().sum::<f64>() / boosts.len() as f64 },
+        }
+    }
+}
+
+/// Parameters for a trust score forecast
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustForecastParams {
+    /// Decay curve to project; `days` is the forecast horizon
+    pub decay: DecaySimulationParams,
+    /// Historical recovery behaviour of the strategy
+    pub recovery: RecoveryProfile,
+    /// Number of simulated paths
+    pub paths: usize,
+    /// Thresholds to report breach probabilities for
+    pub thresholds: Vec<f64>,
+    /// Fixed RNG seed for reproducible forecasts
+    pub seed: Option<u64>,
+}
+
+/// Projected score distribution on one forecast day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustForecastPoint {
+    /// Days ahead of the forecast start
+    pub day: usize,
+    /// Timestamp of the projected day
+    pub timestamp: DateTime<Utc>,
+    /// Mean projected score
+    pub expected: f64,
+    /// 10th percentile projected score
+    pub p10: f64,
+    /// Median projected score
+    pub p50: f64,
+    /// 90th percentile projected score
+    pub p90: f64,
+}
+
+/// Probability that the score ends the horizon below a threshold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdBreach {
+    /// Threshold score
+    pub threshold: f64,
+    /// Share of paths finishing below the threshold
+    pub probability: f64,
+}
+
+/// Projected trust score distribution for a strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustScoreForecast {
+    /// Strategy ID
+    pub strategy_id: String,
+    /// Score the forecast starts from
+    pub initial_score: f64,
+    /// Recovery behaviour used for the projection
+    pub recovery: RecoveryProfile,
+    /// One point per forecast day
+    pub points: Vec<TrustForecastPoint>,
+    /// End-of-horizon breach probabilities
+    pub breaches: Vec<ThresholdBreach>,
+}
+
+/// Project trust score distributions by Monte Carlo over the decay curve
+///
+/// Each path decays by `decay_factor_per_day` with uniform jitter, and on each
+/// day receives a recovery boost of `mean_boost` with the profile's historical
+/// daily probability.
+pub fn forecast_trust_score(params: TrustForecastParams) -> TrustScoreForecast {
+    let mut rng = match params.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let decay = &params.decay;
+    let recovery = params.recovery;
+    let paths = params.paths.max(1);
+    let initial_score = decay.initial_score.max(0.0).min(1.0);
+
+    // samples[day][path]
+    let mut samples = vec![Vec::with_capacity(paths); decay.days];
+    for _ in 0..paths {
+        let mut score = initial_score;
+        for day_samples in samples.iter_mut() {
+            score *= decay.decay_factor_per_day;
+            if decay.jitter > 0.0 {
+                score += rng.gen_range(-decay.jitter..=decay.jitter);
+            }
+            if recovery.daily_probability > 0.0 && rng.gen_bool(recovery.daily_probability.min(1.0)) {
+                score += recovery.mean_boost;
+            }
+            score = score.max(0.0).min(1.0);
+            day_samples.push(score);
+        }
+    }
+
+    let start_time = Utc::now();
+    let points = samples
+        .iter_mut()
+        .enumerate()
+        .map(|(day, day_samples)| {
+            day_samples.sort_by(|a, b| a.total_cmp(b));
+            TrustForecastPoint {
+                day: day + 1,
+                timestamp: start_time + Duration::days(day as i64 + 1),
+                expected: day_samples.iter().sum::<f64>() / day_samples.len() as f64,
+                p10: percentile(day_samples, 0.10),
+                p50: percentile(day_samples, 0.50),
+                p90: percentile(day_samples, 0.90),
+            }
+        })
+        .collect();
+
+    let breaches = params
+        .thresholds
+        .iter()
+        .map(|&threshold| ThresholdBreach {
+            threshold,
+            probability: samples
+                .last()
+                .map(|last| last.iter().filter(|&&s| s < threshold).count() as f64 / paths as f64)
+                .unwrap_or(if initial_score < threshold { 1.0 } else { 0.0 }),
+        })
+        .collect();
+
+    TrustScoreForecast {
+        strategy_id: decay.strategy_id.clone(),
+        initial_score,
+        recovery,
+        points,
+        breaches,
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((sorted.len() as f64 * q).ceil() as usize).clamp(1, sorted.len());
+    sorted[rank - 1]
+}
+
 // Unit tests for the trust score decay simulator
 #[cfg(test)]
 mod tests {
@@ -212,6 +407,50 @@ mod tests {
                 "Recovery effect should persist to following days");
     }
     
+    #[test]
+    fn test_forecast_with_recovery_profile() {
+        let start = Utc::now() - Duration::days(20);
+        // Decays 0.01/day with a 0.1 jump every fifth day
+        let history: Vec<_> = (0..=20)
+            .map(|d| {
+                let score = 0.8 - 0.01 * d as f64 + if d >= 5 { 0.1 * (d / 5) as f64 } else { 0.0 };
+                (start + Duration::days(d), score)
+            })
+            .collect();
+        let recovery = RecoveryProfile::from_history(&history, 0.05);
+        assert_eq!((recovery.observed_days, recovery.events), (20, 4));
+        assert!((recovery.daily_probability - 0.2).abs() < 1e-9);
+        assert!((recovery.mean_boost - 0.09).abs() < 1e-9);
+
+        let params = |recovery| TrustForecastParams {
+            decay: DecaySimulationParams {
+                strategy_id: "test-strategy".to_string(),
+                initial_score: 0.8,
+                decay_factor_per_day: 0.95,
+                jitter: 0.0,
+                days: 14,
+            },
+            recovery,
+            paths: 500,
+            thresholds: vec![0.5],
+            seed: Some(7),
+        };
+
+        let baseline = forecast_trust_score(params(RecoveryProfile::default()));
+        let recovering = forecast_trust_score(params(recovery));
+        assert_eq!(recovering.points.len(), 14);
+
+        // Without recovery the forecast is the plain decay curve
+        let last = baseline.points.last().unwrap();
+        assert!((last.p50 - 0.8 * 0.95f64.powi(14)).abs() < 1e-9);
+        assert_eq!(baseline.breaches[0].probability, 1.0);
+
+        let last = recovering.points.last().unwrap();
+        assert!(last.p10 <= last.p50 && last.p50 <= last.p90);
+        assert!(last.expected > baseline.points.last().unwrap().expected);
+        assert!(recovering.breaches[0].probability < 1.0);
+    }
+    
     // This is a future enhancement we're not implementing now, but keeping as a comment
     // to guide future development
     /*
@@ -0,0 +1,515 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Signed state bundles for moving a node's configuration between
+//! environments (staging → prod) and for disaster-recovery restores.
+//!
+//! A bundle carries strategy descriptors, trust score histories, the current
+//! risk allocation and governance rules. It is exported as a JSON envelope
+//! whose payload is signed with the exporting node's Ed25519 key; imports
+//! are refused unless the signature verifies against a trusted key.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::governance::{GovernanceEnforcer, GovernanceRule};
+use crate::risk_allocation::{PortfolioAllocation, RiskAllocator};
+use crate::strategy::StrategyId;
+use crate::strategy_executor::StrategyExecutor;
+use crate::trust_history::TrustHistoryStore;
+use crate::trust_score_engine::TrustScoreHistoryEntry;
+
+/// Envelope format written by this version
+pub const STATE_BUNDLE_FORMAT: &str = "noderr-state-bundle/1";
+
+/// Errors that can occur exporting or importing a state bundle
+#[derive(Debug, Error)]
+pub enum StateBundleError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+
+    #[error("No signing key configured")]
+    NoSigningKey,
+
+    #[error("Unsupported bundle format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Bundle signed by untrusted key {0}")]
+    UntrustedKey(String),
+
+    #[error("Bundle signature does not verify")]
+    BadSignature,
+
+    #[error("Failed to read {component}: {reason}")]
+    Export { component: &'static str, reason: String },
+
+    #[error("Failed to restore {component}: {reason}")]
+    Import { component: &'static str, reason: String },
+}
+
+/// State bundle configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StateBundleConfig {
+    /// Name of this environment, recorded in exported bundles
+    pub environment: String,
+    /// File holding the hex-encoded 32-byte Ed25519 signing key; exports are refused without one
+    pub signing_key_path: Option<PathBuf>,
+    /// Hex-encoded Ed25519 public keys whose bundles may be imported,
+    /// besides this node's own key
+    pub trusted_keys: Vec<String>,
+}
+
+impl Default for StateBundleConfig {
+    fn default() -> Self {
+        Self {
+            environment: "default".to_string(),
+            signing_key_path: None,
+            trusted_keys: Vec::new(),
+        }
+    }
+}
+
+/// Exported node state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StateBundle {
+    /// Environment the bundle was exported from
+    pub environment: String,
+    /// When the bundle was exported
+    pub exported_at: Option<DateTime<Utc>>,
+    /// Strategy descriptors by strategy ID
+    pub strategies: BTreeMap<StrategyId, serde_json::Value>,
+    /// Trust score history by strategy ID, oldest first
+    pub trust_histories: BTreeMap<StrategyId, Vec<TrustScoreHistoryEntry>>,
+    /// Current risk allocation
+    pub allocation: Option<PortfolioAllocation>,
+    /// Governance rules
+    pub governance_rules: Vec<GovernanceRule>,
+}
+
+/// Signed envelope around a serialized [`StateBundle`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedStateBundle {
+    /// Envelope format, [`STATE_BUNDLE_FORMAT`]
+    pub format: String,
+    /// Hex-encoded public key of the signer
+    pub key_id: String,
+    /// Hex-encoded Ed25519 signature over `payload`
+    pub signature: String,
+    /// The bundle as JSON; kept as a string so the signed bytes survive re-encoding
+    pub payload: String,
+}
+
+/// What an import changed, or would change on a dry run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// Environment the bundle came from
+    pub source_environment: String,
+    /// When the bundle was exported
+    pub exported_at: Option<DateTime<Utc>>,
+    /// Whether nothing was written
+    pub dry_run: bool,
+    /// Trust history entries newer than the local series, by strategy
+    pub trust_entries: BTreeMap<StrategyId, usize>,
+    /// Whether the allocation was (or would be) restored
+    pub allocation_restored: bool,
+    /// Governance rules added or updated
+    pub governance_rules: usize,
+    /// Strategies in the bundle that this node does not run; strategies are
+    /// code, so they must be deployed before their state is useful
+    pub missing_strategies: Vec<StrategyId>,
+}
+
+/// Exports and imports signed state bundles
+pub struct StateBundleManager {
+    /// Configuration
+    config: StateBundleConfig,
+    /// Key exports are signed with
+    signing_key: Option<SigningKey>,
+    /// Keys whose bundles are accepted
+    trusted_keys: Vec<VerifyingKey>,
+    /// Source of strategy descriptors (optional)
+    strategy_executor: Option<Arc<StrategyExecutor>>,
+    /// Trust score history store (optional)
+    trust_history: Option<Arc<TrustHistoryStore>>,
+    /// Risk allocator (optional)
+    risk_allocator: Option<Arc<dyn RiskAllocator>>,
+    /// Governance rule store (optional)
+    governance: Option<Arc<dyn GovernanceEnforcer>>,
+}
+
+impl StateBundleManager {
+    /// Create a manager, loading the signing key and trusted keys
+    pub fn new(config: StateBundleConfig) -> Result<Self, StateBundleError> {
+        let signing_key = match &config.signing_key_path {
+            Some(path) => Some(load_signing_key(path)?),
+            None => None,
+        };
+
+        let mut trusted_keys = config.trusted_keys.iter()
+            .map(|key| verifying_key_from_hex(key))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(key) = &signing_key {
+            trusted_keys.push(key.verifying_key());
+        }
+
+        Ok(Self {
+            config,
+            signing_key,
+            trusted_keys,
+            strategy_executor: None,
+            trust_history: None,
+            risk_allocator: None,
+            governance: None,
+        })
+    }
+
+    /// Sign exports with `key` instead of a key file
+    pub fn with_signing_key(mut self, key: SigningKey) -> Self {
+        self.trusted_keys.push(key.verifying_key());
+        self.signing_key = Some(key);
+        self
+    }
+
+    /// Export strategy descriptors and check imported strategies are deployed
+    pub fn with_strategy_executor(mut self, executor: Arc<StrategyExecutor>) -> Self {
+        self.strategy_executor = Some(executor);
+        self
+    }
+
+    /// Export and import trust score histories
+    pub fn with_trust_history(mut self, store: Arc<TrustHistoryStore>) -> Self {
+        self.trust_history = Some(store);
+        self
+    }
+
+    /// Export and restore the risk allocation
+    pub fn with_risk_allocator(mut self, allocator: Arc<dyn RiskAllocator>) -> Self {
+        self.risk_allocator = Some(allocator);
+        self
+    }
+
+    /// Export and import governance rules
+    pub fn with_governance(mut self, governance: Arc<dyn GovernanceEnforcer>) -> Self {
+        self.governance = Some(governance);
+        self
+    }
+
+    /// Hex-encoded public key of this node's signing key
+    pub fn key_id(&self) -> Option<String> {
+        self.signing_key.as_ref().map(|key| to_hex(key.verifying_key().as_bytes()))
+    }
+
+    /// Gather the current state
+    pub async fn collect(&self) -> Result<StateBundle, StateBundleError> {
+        let mut bundle = StateBundle {
+            environment: self.config.environment.clone(),
+            exported_at: Some(Utc::now()),
+            ..StateBundle::default()
+        };
+
+        if let Some(executor) = &self.strategy_executor {
+            bundle.strategies = executor.strategy_descriptors().into_iter().collect();
+        }
+
+        if let Some(store) = &self.trust_history {
+            let export_error = |e: crate::trust_history::TrustHistoryError| StateBundleError::Export {
+                component: "trust history",
+                reason: e.to_string(),
+            };
+            for strategy_id in store.strategy_ids().map_err(export_error)? {
+                let entries = store.range(&strategy_id, None, None).map_err(export_error)?;
+                bundle.trust_histories.insert(strategy_id, entries);
+            }
+        }
+
+        if let Some(allocator) = &self.risk_allocator {
+            bundle.allocation = allocator.get_portfolio_allocation().await;
+        }
+
+        if let Some(governance) = &self.governance {
+            bundle.governance_rules = governance.get_active_rules().await;
+        }
+
+        Ok(bundle)
+    }
+
+    /// Gather and sign the current state
+    pub async fn export(&self) -> Result<SignedStateBundle, StateBundleError> {
+        let key = self.signing_key.as_ref().ok_or(StateBundleError::NoSigningKey)?;
+        let bundle = self.collect().await?;
+        let signed = sign_bundle(&bundle, key)?;
+
+        info!(
+            "Exported state bundle from {}: {} strategies, {} trust histories, {} governance rules",
+            bundle.environment,
+            bundle.strategies.len(),
+            bundle.trust_histories.len(),
+            bundle.governance_rules.len()
+        );
+        Ok(signed)
+    }
+
+    /// Verify a bundle's signature against the trusted keys and decode it
+    pub fn verify(&self, signed: &SignedStateBundle) -> Result<StateBundle, StateBundleError> {
+        verify_bundle(signed, &self.trusted_keys)
+    }
+
+    /// Verify and apply a bundle. Trust histories only gain entries newer
+    /// than the local series, so importing the same bundle twice is harmless.
+    pub async fn import(&self, signed: &SignedStateBundle, dry_run: bool) -> Result<ImportReport, StateBundleError> {
+        let bundle = self.verify(signed)?;
+        let mut report = ImportReport {
+            source_environment: bundle.environment.clone(),
+            exported_at: bundle.exported_at,
+            dry_run,
+            ..ImportReport::default()
+        };
+
+        if let Some(executor) = &self.strategy_executor {
+            let deployed = executor.list_strategies();
+            report.missing_strategies = bundle.strategies.keys()
+                .filter(|id| !deployed.contains(id))
+                .cloned()
+                .collect();
+        }
+
+        if let Some(store) = &self.trust_history {
+            let import_error = |e: crate::trust_history::TrustHistoryError| StateBundleError::Import {
+                component: "trust history",
+                reason: e.to_string(),
+            };
+            for (strategy_id, entries) in &bundle.trust_histories {
+                let latest = store.range(strategy_id, None, None).map_err(import_error)?
+                    .last()
+                    .map(|entry| entry.timestamp);
+                let newer: Vec<_> = entries.iter()
+                    .filter(|entry| !latest.is_some_and(|latest| entry.timestamp <= latest))
+                    .collect();
+                if !dry_run {
+                    for entry in &newer {
+                        store.append(strategy_id, entry).map_err(import_error)?;
+                    }
+                }
+                if !newer.is_empty() {
+                    report.trust_entries.insert(strategy_id.clone(), newer.len());
+                }
+            }
+        }
+
+        if let (Some(allocator), Some(allocation)) = (&self.risk_allocator, &bundle.allocation) {
+            if !dry_run {
+                allocator.restore_allocation(allocation.clone()).await
+                    .map_err(|e| StateBundleError::Import { component: "allocation", reason: e.to_string() })?;
+            }
+            report.allocation_restored = true;
+        }
+
+        if let Some(governance) = &self.governance {
+            for rule in &bundle.governance_rules {
+                if !dry_run {
+                    // Rules new to this environment are added; existing ones are overwritten
+                    if governance.update_rule(rule.clone()).await.is_err() {
+                        governance.add_rule(rule.clone()).await
+                            .map_err(|reason| StateBundleError::Import { component: "governance rules", reason })?;
+                    }
+                }
+                report.governance_rules += 1;
+            }
+        }
+
+        if !report.missing_strategies.is_empty() {
+            warn!(
+                "State bundle from {} references strategies not deployed here: {}",
+                report.source_environment,
+                report.missing_strategies.join(", ")
+            );
+        }
+        info!(
+            "{} state bundle from {}: {} trust entries, {} governance rules, allocation {}",
+            if dry_run { "Checked" } else { "Imported" },
+            report.source_environment,
+            report.trust_entries.values().sum::<usize>(),
+            report.governance_rules,
+            if report.allocation_restored { "restored" } else { "unchanged" }
+        );
+        Ok(report)
+    }
+
+    /// Export to a file
+    pub async fn export_to_file(&self, path: &Path) -> Result<SignedStateBundle, StateBundleError> {
+        let signed = self.export().await?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_vec_pretty(&signed)?)?;
+        Ok(signed)
+    }
+
+    /// Import from a file
+    pub async fn import_from_file(&self, path: &Path, dry_run: bool) -> Result<ImportReport, StateBundleError> {
+        let signed: SignedStateBundle = serde_json::from_slice(&std::fs::read(path)?)?;
+        self.import(&signed, dry_run).await
+    }
+}
+
+/// Serialize and sign a bundle
+pub fn sign_bundle(bundle: &StateBundle, key: &SigningKey) -> Result<SignedStateBundle, StateBundleError> {
+    let payload = serde_json::to_string(bundle)?;
+    let signature = key.sign(payload.as_bytes());
+    Ok(SignedStateBundle {
+        format: STATE_BUNDLE_FORMAT.to_string(),
+        key_id: to_hex(key.verifying_key().as_bytes()),
+        signature: to_hex(&signature.to_bytes()),
+        payload,
+    })
+}
+
+/// Check a bundle was signed by one of `trusted` and decode it
+pub fn verify_bundle(signed: &SignedStateBundle, trusted: &[VerifyingKey]) -> Result<StateBundle, StateBundleError> {
+    if signed.format != STATE_BUNDLE_FORMAT {
+        return Err(StateBundleError::UnsupportedFormat(signed.format.clone()));
+    }
+
+    let signer = verifying_key_from_hex(&signed.key_id)?;
+    if !trusted.contains(&signer) {
+        return Err(StateBundleError::UntrustedKey(signed.key_id.clone()));
+    }
+
+    let signature: [u8; 64] = from_hex(&signed.signature)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(StateBundleError::BadSignature)?;
+    signer.verify(signed.payload.as_bytes(), &Signature::from_bytes(&signature))
+        .map_err(|_| StateBundleError::BadSignature)?;
+
+    Ok(serde_json::from_str(&signed.payload)?)
+}
+
+/// Read a hex-encoded 32-byte Ed25519 signing key from a file
+pub fn load_signing_key(path: &Path) -> Result<SigningKey, StateBundleError> {
+    let text = std::fs::read_to_string(path)?;
+    let bytes: [u8; 32] = from_hex(text.trim())
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| StateBundleError::InvalidKey(format!("{} does not hold a 32-byte hex key", path.display())))?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+/// Parse a hex-encoded Ed25519 public key
+pub fn verifying_key_from_hex(hex: &str) -> Result<VerifyingKey, StateBundleError> {
+    let bytes: [u8; 32] = from_hex(hex)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| StateBundleError::InvalidKey(hex.to_string()))?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| StateBundleError::InvalidKey(e.to_string()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Create a state bundle manager
+pub fn create_state_bundle_manager(config: StateBundleConfig) -> Result<Arc<StateBundleManager>, StateBundleError> {
+    Ok(Arc::new(StateBundleManager::new(config)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust_history::TrustHistoryConfig;
+    use crate::trust_score_engine::TrustScoreFeatures;
+    use chrono::TimeZone;
+
+    fn entry(score: f64, day: u32) -> TrustScoreHistoryEntry {
+        TrustScoreHistoryEntry {
+            score,
+            features: TrustScoreFeatures::default(),
+            timestamp: Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap(),
+        }
+    }
+
+    fn store(name: &str) -> (Arc<TrustHistoryStore>, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("noderr-bundle-{}-{}", name, uuid::Uuid::new_v4()));
+        let config = TrustHistoryConfig { dir: dir.clone(), compact_every: 0, ..TrustHistoryConfig::default() };
+        (Arc::new(TrustHistoryStore::open(config).unwrap()), dir)
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let (staging_store, staging_dir) = store("staging");
+        staging_store.append("momentum", &entry(0.7, 1)).unwrap();
+        staging_store.append("momentum", &entry(0.8, 2)).unwrap();
+
+        let staging = StateBundleManager::new(StateBundleConfig { environment: "staging".to_string(), ..StateBundleConfig::default() })
+            .unwrap()
+            .with_signing_key(SigningKey::from_bytes(&[7; 32]))
+            .with_trust_history(staging_store);
+        let signed = staging.export().await.unwrap();
+
+        let (prod_store, prod_dir) = store("prod");
+        prod_store.append("momentum", &entry(0.7, 1)).unwrap();
+        let prod = StateBundleManager::new(StateBundleConfig {
+            environment: "prod".to_string(),
+            trusted_keys: vec![staging.key_id().unwrap()],
+            ..StateBundleConfig::default()
+        })
+        .unwrap()
+        .with_trust_history(prod_store.clone());
+
+        // A dry run reports without writing
+        let report = prod.import(&signed, true).await.unwrap();
+        assert_eq!(report.source_environment, "staging");
+        assert_eq!(report.trust_entries["momentum"], 1);
+        assert_eq!(prod_store.range("momentum", None, None).unwrap().len(), 1);
+
+        // Only entries newer than the local series are appended, so re-imports change nothing
+        prod.import(&signed, false).await.unwrap();
+        let scores: Vec<_> = prod_store.range("momentum", None, None).unwrap().iter().map(|e| e.score).collect();
+        assert_eq!(scores, vec![0.7, 0.8]);
+        assert!(prod.import(&signed, false).await.unwrap().trust_entries.is_empty());
+
+        // Tampered payloads and unknown signers are refused
+        let mut tampered = signed.clone();
+        tampered.payload = tampered.payload.replace("0.8", "0.9");
+        assert!(matches!(prod.import(&tampered, false).await, Err(StateBundleError::BadSignature)));
+
+        let stranger = sign_bundle(&StateBundle::default(), &SigningKey::from_bytes(&[9; 32])).unwrap();
+        assert!(matches!(prod.import(&stranger, false).await, Err(StateBundleError::UntrustedKey(_))));
+
+        std::fs::remove_dir_all(&staging_dir).ok();
+        std::fs::remove_dir_all(&prod_dir).ok();
+    }
+}
@@ -22,6 +22,7 @@ use thiserror::Error;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 
+use crate::retention::DataClass;
 use crate::strategy::{Signal, StrategyId, StrategyPerformance};
 use crate::execution::ExecutionResult;
 use crate::telemetry::{TelemetryEvent, TelemetryLevel};
@@ -121,6 +122,29 @@ pub struct PerformanceImpact {
     pub trust_score_change: f64,
 }
 
+/// Configuration and full history of a retired strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyArchive {
+    /// Strategy ID
+    pub strategy_id: StrategyId,
+    /// When the strategy was retired
+    pub retired_at: DateTime<Utc>,
+    /// Why the strategy was retired
+    pub reason: String,
+    /// Strategy configuration at retirement (name, description, risk profile, metrics)
+    pub config: serde_json::Value,
+    /// Performance at retirement
+    pub final_performance: Option<StrategyPerformance>,
+    /// Performance snapshots over the strategy's lifetime
+    pub performance_history: Vec<(DateTime<Utc>, StrategyPerformance)>,
+    /// Trust score history as (timestamp, score)
+    pub trust_history: Vec<(DateTime<Utc>, f64)>,
+    /// Every stored execution of the strategy
+    pub executions: Vec<StoredExecution>,
+    /// Telemetry events attributed to the strategy
+    pub telemetry: Vec<TelemetryEvent>,
+}
+
 /// Time range for querying data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TimeRange {
@@ -187,6 +211,18 @@ pub trait StrategyStorage: Send + Sync {
         interval: &str, // "hour", "day", "week"
     ) -> Result<Vec<(DateTime<Utc>, StrategyPerformance)>, StorageError>;
     
+    /// Archive a retired strategy; archives are exempt from retention cleanup
+    async fn store_archive(&self, archive: StrategyArchive) -> Result<(), StorageError>;
+    
+    /// Get the archive of a retired strategy
+    async fn get_archive(&self, strategy_id: &StrategyId) -> Result<StrategyArchive, StorageError>;
+    
+    /// Delete records of a data class older than `cutoff`, returning how many were deleted
+    async fn purge_before(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, StorageError>;
+    
+    /// Count records of a data class older than `cutoff`
+    async fn count_before(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, StorageError>;
+    
     /// Run database maintenance tasks
     async fn run_maintenance(&self) -> Result<(), StorageError>;
 }
@@ -199,6 +235,8 @@ pub struct InMemoryStorage {
     events: Arc<RwLock<Vec<TelemetryEvent>>>,
     /// Strategy performance history
     performance: Arc<RwLock<HashMap<StrategyId, Vec<(DateTime<Utc>, StrategyPerformance)>>>>,
+    /// Archives of retired strategies
+    archives: Arc<RwLock<HashMap<StrategyId, StrategyArchive>>>,
     /// Configuration
     config: StorageConfig,
 }
@@ -210,6 +248,7 @@ impl InMemoryStorage {
             executions: Arc::new(RwLock::new(HashMap::new())),
             events: Arc::new(RwLock::new(Vec::new())),
             performance: Arc::new(RwLock::new(HashMap::new())),
+            archives: Arc::new(RwLock::new(HashMap::new())),
             config,
         }
     }
@@ -453,6 +492,85 @@ impl StrategyStorage for InMemoryStorage {
         }
     }
     
+    async fn store_archive(&self, archive: StrategyArchive) -> Result<(), StorageError> {
+        let mut archives = self.archives.write().await;
+        archives.insert(archive.strategy_id.clone(), archive);
+        Ok(())
+    }
+    
+    async fn get_archive(&self, strategy_id: &StrategyId) -> Result<StrategyArchive, StorageError> {
+        let archives = self.archives.read().await;
+        archives.get(strategy_id)
+            .cloned()
+            .ok_or_else(|| StorageError::NotFound(format!("No archive for strategy {}", strategy_id)))
+    }
+    
+    async fn purge_before(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, StorageError> {
+        let purged = match class {
+            DataClass::RawTrades => {
+                let mut executions = self.executions.write().await;
+                let before = executions.len();
+                executions.retain(|_, exec| exec.timestamp >= cutoff);
+                before - executions.len()
+            }
+            DataClass::Telemetry => {
+                let mut events = self.events.write().await;
+                let before = events.len();
+                events.retain(|event| event.timestamp() >= cutoff);
+                before - events.len()
+            }
+            DataClass::ReasonChains => {
+                // The trade record is kept; only the decision trail attached to its signal is dropped
+                let mut executions = self.executions.write().await;
+                let mut purged = 0;
+                for exec in executions.values_mut().filter(|exec| exec.timestamp < cutoff) {
+                    if !exec.signal.reason_chain.is_empty() {
+                        exec.signal.reason_chain.clear();
+                        purged += 1;
+                    }
+                }
+                purged
+            }
+            DataClass::Snapshots => {
+                let mut performances = self.performance.write().await;
+                let mut purged = 0;
+                for history in performances.values_mut() {
+                    let before = history.len();
+                    history.retain(|(timestamp, _)| *timestamp >= cutoff);
+                    purged += before - history.len();
+                }
+                performances.retain(|_, history| !history.is_empty());
+                purged
+            }
+        };
+        
+        Ok(purged)
+    }
+    
+    async fn count_before(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, StorageError> {
+        let count = match class {
+            DataClass::RawTrades => self.executions.read().await
+                .values()
+                .filter(|exec| exec.timestamp < cutoff)
+                .count(),
+            DataClass::Telemetry => self.events.read().await
+                .iter()
+                .filter(|event| event.timestamp() < cutoff)
+                .count(),
+            DataClass::ReasonChains => self.executions.read().await
+                .values()
+                .filter(|exec| exec.timestamp < cutoff && !exec.signal.reason_chain.is_empty())
+                .count(),
+            DataClass::Snapshots => self.performance.read().await
+                .values()
+                .flatten()
+                .filter(|(timestamp, _)| *timestamp < cutoff)
+                .count(),
+        };
+        
+        Ok(count)
+    }
+    
     async fn run_maintenance(&self) -> Result<(), StorageError> {
         // For in-memory storage, we don't need complex maintenance
         // Just clean up old data based on retention policy
@@ -490,6 +608,180 @@ impl StrategyStorage for InMemoryStorage {
     }
 }
 
+/// Name of the snapshot file kept under `StorageConfig::base_dir`
+const SNAPSHOT_FILE: &str = "strategy_storage.json";
+
+/// On-disk contents of a [`FileStorage`]
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StorageSnapshot {
+    executions: HashMap<String, StoredExecution>,
+    events: Vec<TelemetryEvent>,
+    performance: HashMap<StrategyId, Vec<(DateTime<Utc>, StrategyPerformance)>>,
+    archives: HashMap<StrategyId, StrategyArchive>,
+}
+
+/// Strategy storage persisted as a JSON snapshot under `base_dir`.
+///
+/// Reads are served from memory; every write rewrites the snapshot so the
+/// data survives restarts.
+pub struct FileStorage {
+    /// In-memory working copy
+    inner: InMemoryStorage,
+    /// Snapshot file path
+    path: PathBuf,
+    /// Serializes snapshot writes
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl FileStorage {
+    /// Open the storage under `config.base_dir`, creating it if missing.
+    ///
+    /// Fails if the directory or snapshot cannot be created, written or parsed.
+    pub fn open(config: StorageConfig) -> Result<Self, StorageError> {
+        let base_dir = config
+            .base_dir
+            .clone()
+            .map(PathBuf::from)
+            .ok_or_else(|| StorageError::Internal("File storage requires base_dir".to_string()))?;
+        std::fs::create_dir_all(&base_dir)?;
+        let path = base_dir.join(SNAPSHOT_FILE);
+
+        // Opening for append checks write access and creates an empty snapshot
+        std::fs::OpenOptions::new().append(true).create(true).open(&path)?;
+        let bytes = std::fs::read(&path)?;
+        let snapshot: StorageSnapshot = if bytes.is_empty() {
+            StorageSnapshot::default()
+        } else {
+            serde_json::from_slice(&bytes)
+                .map_err(|e| StorageError::SerializationError(format!("{}: {}", path.display(), e)))?
+        };
+        info!(
+            "Opened strategy storage at {} ({} executions, {} archives)",
+            path.display(),
+            snapshot.executions.len(),
+            snapshot.archives.len()
+        );
+
+        Ok(Self {
+            inner: InMemoryStorage {
+                executions: Arc::new(RwLock::new(snapshot.executions)),
+                events: Arc::new(RwLock::new(snapshot.events)),
+                performance: Arc::new(RwLock::new(snapshot.performance)),
+                archives: Arc::new(RwLock::new(snapshot.archives)),
+                config,
+            },
+            path,
+            write_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    /// Write the current contents to disk, replacing the snapshot atomically
+    async fn persist(&self) -> Result<(), StorageError> {
+        let _guard = self.write_lock.lock().await;
+        let snapshot = StorageSnapshot {
+            executions: self.inner.executions.read().await.clone(),
+            events: self.inner.events.read().await.clone(),
+            performance: self.inner.performance.read().await.clone(),
+            archives: self.inner.archives.read().await.clone(),
+        };
+        let bytes = serde_json::to_vec(&snapshot)
+            .map_err(|e| StorageError::SerializationError(e.to_string()))?;
+
+        let tmp_path = self.path.with_extension("json.tmp");
+        tokio::fs::write(&tmp_path, bytes).await?;
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+        debug!("Persisted strategy storage to {}", self.path.display());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StrategyStorage for FileStorage {
+    async fn store_execution(&self, execution: StoredExecution) -> Result<(), StorageError> {
+        self.inner.store_execution(execution).await?;
+        self.persist().await
+    }
+
+    async fn get_execution(&self, execution_id: &str) -> Result<StoredExecution, StorageError> {
+        self.inner.get_execution(execution_id).await
+    }
+
+    async fn query_executions_by_strategy(
+        &self,
+        strategy_id: &StrategyId,
+        time_range: TimeRange,
+        limit: Option<usize>,
+    ) -> Result<Vec<StoredExecution>, StorageError> {
+        self.inner.query_executions_by_strategy(strategy_id, time_range, limit).await
+    }
+
+    async fn store_telemetry_event(&self, event: TelemetryEvent) -> Result<(), StorageError> {
+        self.inner.store_telemetry_event(event).await?;
+        self.persist().await
+    }
+
+    async fn query_telemetry_events(
+        &self,
+        strategy_id: Option<&StrategyId>,
+        level: Option<TelemetryLevel>,
+        time_range: TimeRange,
+        limit: Option<usize>,
+    ) -> Result<Vec<TelemetryEvent>, StorageError> {
+        self.inner.query_telemetry_events(strategy_id, level, time_range, limit).await
+    }
+
+    async fn store_performance(
+        &self,
+        strategy_id: &StrategyId,
+        performance: &StrategyPerformance,
+    ) -> Result<(), StorageError> {
+        self.inner.store_performance(strategy_id, performance).await?;
+        self.persist().await
+    }
+
+    async fn get_latest_performance(
+        &self,
+        strategy_id: &StrategyId,
+    ) -> Result<StrategyPerformance, StorageError> {
+        self.inner.get_latest_performance(strategy_id).await
+    }
+
+    async fn get_performance_history(
+        &self,
+        strategy_id: &StrategyId,
+        time_range: TimeRange,
+        interval: &str,
+    ) -> Result<Vec<(DateTime<Utc>, StrategyPerformance)>, StorageError> {
+        self.inner.get_performance_history(strategy_id, time_range, interval).await
+    }
+
+    async fn store_archive(&self, archive: StrategyArchive) -> Result<(), StorageError> {
+        self.inner.store_archive(archive).await?;
+        self.persist().await
+    }
+
+    async fn get_archive(&self, strategy_id: &StrategyId) -> Result<StrategyArchive, StorageError> {
+        self.inner.get_archive(strategy_id).await
+    }
+
+    async fn purge_before(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, StorageError> {
+        let purged = self.inner.purge_before(class, cutoff).await?;
+        if purged > 0 {
+            self.persist().await?;
+        }
+        Ok(purged)
+    }
+
+    async fn count_before(&self, class: DataClass, cutoff: DateTime<Utc>) -> Result<usize, StorageError> {
+        self.inner.count_before(class, cutoff).await
+    }
+
+    async fn run_maintenance(&self) -> Result<(), StorageError> {
+        self.inner.run_maintenance().await?;
+        self.persist().await
+    }
+}
+
 /// Create a new strategy storage with the given configuration
 pub fn create_storage(config: StorageConfig) -> Arc<dyn StrategyStorage> {
     match config.storage_type {
@@ -511,6 +803,17 @@ pub fn create_storage(config: StorageConfig) -> Arc<dyn StrategyStorage> {
     }
 }
 
+/// Open a persistent strategy storage, failing instead of falling back to memory
+pub fn open_storage(config: StorageConfig) -> Result<Arc<dyn StrategyStorage>, StorageError> {
+    match config.storage_type {
+        StorageType::Memory => Ok(Arc::new(InMemoryStorage::new(config))),
+        StorageType::File => Ok(Arc::new(FileStorage::open(config)?)),
+        StorageType::SQLite => Err(StorageError::DatabaseError(
+            "SQLite strategy storage is not supported; use file storage".to_string(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -561,4 +864,44 @@ mod tests {
         assert_eq!(executions.len(), 1);
         assert_eq!(executions[0].id, "exec-1");
     }
+
+    #[tokio::test]
+    async fn test_file_storage_survives_reopen() {
+        let base_dir = std::env::temp_dir().join(format!("noderr-storage-{}", uuid::Uuid::new_v4()));
+        let config = StorageConfig {
+            storage_type: StorageType::File,
+            base_dir: Some(base_dir.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+
+        let strategy_id = "test-strategy".to_string();
+        let storage = open_storage(config.clone()).unwrap();
+        storage.store_execution(StoredExecution {
+            id: "exec-1".to_string(),
+            strategy_id: strategy_id.clone(),
+            symbol: "BTC/USD".to_string(),
+            timestamp: Utc::now(),
+            signal: Signal::new(&strategy_id, "BTC/USD", SignalAction::Buy),
+            result: ExecutionResult::default(),
+            performance_impact: None,
+        }).await.unwrap();
+        drop(storage);
+
+        let reopened = open_storage(config).unwrap();
+        assert_eq!(reopened.get_execution("exec-1").await.unwrap().strategy_id, strategy_id);
+
+        // A corrupt snapshot is an error, not an empty store
+        std::fs::write(base_dir.join(SNAPSHOT_FILE), b"{not json").unwrap();
+        let corrupt = StorageConfig {
+            storage_type: StorageType::File,
+            base_dir: Some(base_dir.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        assert!(matches!(open_storage(corrupt), Err(StorageError::SerializationError(_))));
+
+        let sqlite = StorageConfig { storage_type: StorageType::SQLite, ..Default::default() };
+        assert!(open_storage(sqlite).is_err());
+
+        std::fs::remove_dir_all(base_dir).unwrap();
+    }
 } 
\ No newline at end of file
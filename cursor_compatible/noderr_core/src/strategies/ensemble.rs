@@ -0,0 +1,416 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Ensemble meta-strategy: blend child strategy signals into one.
+//!
+//! Each child's signal is reduced to a vote in -1..1 (long positive, short
+//! negative, exit/hold/no signal zero) scaled by its trust score. In
+//! weighted-vote mode the ensemble takes the blend-weighted average of the
+//! votes; in stacking mode the blend weights are the coefficients of an
+//! online linear model fitted to realized trade outcomes. Either way the
+//! blend weights are also nudged by the strategy feedback loop's scores.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+use crate::execution::ExecutionResult;
+use crate::market::{MarketData, Symbol};
+use crate::risk::PositionDirection;
+use crate::strategy::{RiskProfile, RiskProfileBuilder, Signal, SignalAction, Strategy, StrategyError, StrategyId};
+use crate::trust_score_engine::TrustScoreEngine;
+
+/// How child votes are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnsembleMode {
+    /// Blend-weighted average of trust-scaled votes
+    WeightedVote,
+    /// Learned linear combination of trust-scaled votes
+    Stacking,
+}
+
+/// Ensemble configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnsembleConfig {
+    /// Combination mode
+    pub mode: EnsembleMode,
+    /// Blended score (-1..1) at which to enter
+    pub entry_threshold: f64,
+    /// Share of trust weight voting to exit that forces an exit
+    pub exit_quorum: f64,
+    /// Follow negative blended scores short instead of only exiting
+    pub allow_short: bool,
+    /// Trust assumed for children without a trust score
+    pub default_trust: f64,
+    /// Step size for outcome and feedback weight updates
+    pub learning_rate: f64,
+    /// Floor for any blend weight so children can recover
+    pub min_weight: f64,
+    /// Position size as a fraction of portfolio
+    pub position_size: f64,
+}
+
+impl Default for EnsembleConfig {
+    fn default() -> Self {
+        Self {
+            mode: EnsembleMode::WeightedVote,
+            entry_threshold: 0.3,
+            exit_quorum: 0.5,
+            allow_short: false,
+            default_trust: 0.5,
+            learning_rate: 0.1,
+            min_weight: 0.05,
+            position_size: 0.05,
+        }
+    }
+}
+
+/// A child's contribution to one ensemble decision
+#[derive(Debug, Clone, Copy)]
+struct ChildVote {
+    /// Directional vote in -1..1 before trust scaling
+    vote: f64,
+    /// Trust score applied to the vote
+    trust: f64,
+    /// Whether the child asked to exit
+    exit: bool,
+}
+
+/// Meta-strategy combining several child strategies
+pub struct EnsembleStrategy {
+    /// Strategy ID
+    id: String,
+    /// Configuration
+    config: EnsembleConfig,
+    /// Child strategies, in registration order
+    children: Vec<Arc<dyn Strategy>>,
+    /// Trust scores for weighting children
+    trust_engine: Option<Arc<dyn TrustScoreEngine>>,
+    /// Blend weight per child strategy ID
+    weights: RwLock<HashMap<StrategyId, f64>>,
+    /// Child votes behind the latest decision per symbol, for outcome learning
+    last_votes: RwLock<HashMap<Symbol, HashMap<StrategyId, ChildVote>>>,
+}
+
+impl EnsembleStrategy {
+    /// Create an ensemble over the given children with equal starting weights
+    pub fn new(id: &str, config: EnsembleConfig, children: Vec<Arc<dyn Strategy>>) -> Result<Self, StrategyError> {
+        if children.is_empty() {
+            return Err(StrategyError::InvalidConfig("ensemble needs at least one child".to_string()));
+        }
+        if config.entry_threshold <= 0.0 || config.entry_threshold > 1.0 {
+            return Err(StrategyError::InvalidConfig(format!(
+                "entry_threshold {} must be in (0, 1]",
+                config.entry_threshold
+            )));
+        }
+        let mut seen = HashSet::new();
+        for child in &children {
+            if !seen.insert(child.name().to_string()) {
+                return Err(StrategyError::InvalidConfig(format!("duplicate child strategy {}", child.name())));
+            }
+        }
+
+        let weights = children.iter().map(|c| (c.name().to_string(), 1.0)).collect();
+        Ok(Self {
+            id: id.to_string(),
+            config,
+            children,
+            trust_engine: None,
+            weights: RwLock::new(weights),
+            last_votes: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Weight children by their trust scores
+    pub fn with_trust_engine(mut self, trust_engine: Arc<dyn TrustScoreEngine>) -> Self {
+        self.trust_engine = Some(trust_engine);
+        self
+    }
+
+    /// Current blend weight per child
+    pub async fn blend_weights(&self) -> HashMap<StrategyId, f64> {
+        self.weights.read().await.clone()
+    }
+
+    /// Override a child's blend weight
+    pub async fn set_blend_weight(&self, strategy_id: &str, weight: f64) -> Result<(), StrategyError> {
+        let mut weights = self.weights.write().await;
+        let Some(current) = weights.get_mut(strategy_id) else {
+            return Err(StrategyError::InvalidConfig(format!("{} is not a child of {}", strategy_id, self.id)));
+        };
+        *current = weight.max(self.config.min_weight);
+        Ok(())
+    }
+
+    /// Reweight children by feedback-loop scores: above-average scores gain
+    /// weight, below-average scores lose it
+    pub async fn apply_feedback(&self, scores: &HashMap<StrategyId, f64>) {
+        let mut weights = self.weights.write().await;
+        let known: Vec<f64> = weights.keys().filter_map(|id| scores.get(id).copied()).collect();
+        if known.is_empty() {
+            return;
+        }
+        let mean = known.iter().sum::<f64>() / known.len() as f64;
+        for (id, weight) in weights.iter_mut() {
+            if let Some(score) = scores.get(id) {
+                *weight = (*weight * (1.0 + self.config.learning_rate * (score - mean))).max(self.config.min_weight);
+            }
+        }
+        debug!("Ensemble {} blend weights after feedback: {:?}", self.id, *weights);
+    }
+
+    /// Learn from a realized trade outcome on a symbol using the votes that produced it
+    pub async fn record_outcome(&self, symbol: &str, realized_pnl: f64) {
+        if realized_pnl == 0.0 {
+            return;
+        }
+        let Some(votes) = self.last_votes.read().await.get(symbol).cloned() else {
+            return;
+        };
+        let target = realized_pnl.signum();
+        let rate = self.config.learning_rate;
+        let mut weights = self.weights.write().await;
+
+        match self.config.mode {
+            EnsembleMode::WeightedVote => {
+                // Multiplicative update: children that voted with the outcome gain weight
+                for (id, vote) in &votes {
+                    if let Some(weight) = weights.get_mut(id) {
+                        *weight = (*weight * (rate * target * vote.vote).exp()).max(self.config.min_weight);
+                    }
+                }
+            }
+            EnsembleMode::Stacking => {
+                // One least-squares gradient step on the squashed linear blend
+                let prediction = Self::stacked_score(&weights, &votes);
+                let error = target - prediction;
+                for (id, vote) in &votes {
+                    if let Some(weight) = weights.get_mut(id) {
+                        *weight = (*weight + rate * error * vote.vote * vote.trust).max(self.config.min_weight);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Trust score for a child
+    async fn trust(&self, strategy_id: &str) -> f64 {
+        match &self.trust_engine {
+            Some(engine) => match engine.get_trust_score(strategy_id).await {
+                Ok(score) => score.score.clamp(0.0, 1.0),
+                Err(_) => self.config.default_trust,
+            },
+            None => self.config.default_trust,
+        }
+    }
+
+    /// Weighted average of trust-scaled votes
+    fn voted_score(weights: &HashMap<StrategyId, f64>, votes: &HashMap<StrategyId, ChildVote>) -> f64 {
+        let (sum, total) = votes.iter().fold((0.0, 0.0), |(sum, total), (id, vote)| {
+            let w = weights.get(id).copied().unwrap_or(0.0) * vote.trust;
+            (sum + w * vote.vote, total + w)
+        });
+        if total > 0.0 { sum / total } else { 0.0 }
+    }
+
+    /// Linear blend of trust-scaled votes squashed into -1..1
+    fn stacked_score(weights: &HashMap<StrategyId, f64>, votes: &HashMap<StrategyId, ChildVote>) -> f64 {
+        votes
+            .iter()
+            .map(|(id, vote)| weights.get(id).copied().unwrap_or(0.0) * vote.trust * vote.vote)
+            .sum::<f64>()
+            .tanh()
+    }
+}
+
+/// Reduce a child signal to a vote in -1..1
+fn to_vote(signal: Option<&Signal>, trust: f64) -> ChildVote {
+    let (vote, exit) = match signal {
+        Some(signal) if signal.action == SignalAction::Enter => {
+            let magnitude = (signal.strength * signal.confidence).clamp(0.0, 1.0);
+            match signal.direction {
+                PositionDirection::Long => (magnitude, false),
+                PositionDirection::Short => (-magnitude, false),
+                PositionDirection::Neutral => (0.0, false),
+            }
+        }
+        Some(signal) => (0.0, signal.action == SignalAction::Exit),
+        None => (0.0, false),
+    };
+    ChildVote { vote, trust, exit }
+}
+
+#[async_trait]
+impl Strategy for EnsembleStrategy {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let mut votes = HashMap::with_capacity(self.children.len());
+        for child in &self.children {
+            // A failing child abstains rather than blocking the ensemble
+            let signal = match child.generate_signal(market_data).await {
+                Ok(signal) => signal,
+                Err(e) => {
+                    warn!("Ensemble {} child {} failed: {}", self.id, child.name(), e);
+                    None
+                }
+            };
+            let trust = self.trust(child.name()).await;
+            votes.insert(child.name().to_string(), to_vote(signal.as_ref(), trust));
+        }
+
+        let weights = self.weights.read().await.clone();
+        let score = match self.config.mode {
+            EnsembleMode::WeightedVote => Self::voted_score(&weights, &votes),
+            EnsembleMode::Stacking => Self::stacked_score(&weights, &votes),
+        };
+        let (exit_weight, total_weight) = votes.iter().fold((0.0, 0.0), |(exit, total), (id, vote)| {
+            let w = weights.get(id).copied().unwrap_or(0.0) * vote.trust;
+            (if vote.exit { exit + w } else { exit }, total + w)
+        });
+        let exit_share = if total_weight > 0.0 { exit_weight / total_weight } else { 0.0 };
+        self.last_votes.write().await.insert(market_data.symbol.clone(), votes);
+
+        let (action, direction) = if score >= self.config.entry_threshold {
+            (SignalAction::Enter, PositionDirection::Long)
+        } else if score <= -self.config.entry_threshold && self.config.allow_short {
+            (SignalAction::Enter, PositionDirection::Short)
+        } else if score <= -self.config.entry_threshold || exit_share >= self.config.exit_quorum {
+            (SignalAction::Exit, PositionDirection::Neutral)
+        } else {
+            return Ok(None);
+        };
+        let strength = score.abs().min(1.0);
+
+        Ok(Some(
+            Signal::new(self.id.clone(), market_data.symbol.clone(), action)
+                .with_direction(direction)
+                .with_strength(strength)
+                .with_confidence(0.5 + strength / 2.0)
+                .with_price(market_data.ticker.last)
+                .with_metadata("template", "ensemble")
+                .with_metadata("blended_score", &format!("{:.4}", score))
+                .with_metadata("exit_share", &format!("{:.4}", exit_share)),
+        ))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfileBuilder::new().position_size(self.config.position_size).build()
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    async fn get_metrics(&self) -> HashMap<String, f64> {
+        self.weights
+            .read()
+            .await
+            .iter()
+            .map(|(id, weight)| (format!("blend_weight.{}", id), *weight))
+            .collect()
+    }
+
+    async fn on_signal_executed(&self, signal: &Signal, result: &ExecutionResult) -> Result<(), StrategyError> {
+        self.record_outcome(&signal.symbol, result.realized_pnl).await;
+        Ok(())
+    }
+
+    fn description(&self) -> String {
+        format!("Ensemble ({:?}) of {} strategies", self.config.mode, self.children.len())
+    }
+
+    fn dependencies(&self) -> Vec<StrategyId> {
+        self.children.iter().map(|c| c.name().to_string()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Child that always emits the same signal
+    struct FixedStrategy {
+        id: String,
+        direction: Option<PositionDirection>,
+    }
+
+    #[async_trait]
+    impl Strategy for FixedStrategy {
+        async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+            Ok(self.direction.map(|direction| {
+                Signal::new(self.id.clone(), market_data.symbol.clone(), SignalAction::Enter)
+                    .with_direction(direction)
+                    .with_strength(1.0)
+                    .with_confidence(1.0)
+            }))
+        }
+
+        async fn get_risk_profile(&self) -> RiskProfile {
+            RiskProfileBuilder::new().build()
+        }
+
+        fn name(&self) -> &str {
+            &self.id
+        }
+    }
+
+    fn child(id: &str, direction: Option<PositionDirection>) -> Arc<dyn Strategy> {
+        Arc::new(FixedStrategy { id: id.to_string(), direction })
+    }
+
+    #[tokio::test]
+    async fn test_vote_and_weight_adaptation() {
+        use crate::strategies::test_support::market_data;
+
+        let ensemble = EnsembleStrategy::new(
+            "ens",
+            EnsembleConfig::default(),
+            vec![
+                child("bull_a", Some(PositionDirection::Long)),
+                child("bull_b", Some(PositionDirection::Long)),
+                child("bear", Some(PositionDirection::Short)),
+            ],
+        )
+        .unwrap();
+        let data = market_data(&[100.0]);
+
+        // Two of three equally weighted children long: blended score 1/3
+        let signal = ensemble.generate_signal(&data).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Enter);
+        assert_eq!(signal.direction, PositionDirection::Long);
+
+        // A losing trade shifts weight toward the dissenting child
+        ensemble.record_outcome(&data.symbol, -50.0).await;
+        let weights = ensemble.blend_weights().await;
+        assert!(weights["bear"] > weights["bull_a"]);
+
+        // Feedback scores can overturn the majority
+        let scores = HashMap::from([
+            ("bull_a".to_string(), -5.0),
+            ("bull_b".to_string(), -5.0),
+            ("bear".to_string(), 10.0),
+        ]);
+        ensemble.apply_feedback(&scores).await;
+        let signal = ensemble.generate_signal(&data).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Exit);
+        assert_eq!(ensemble.dependencies().len(), 3);
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Z-score mean reversion: buy stretched-down prices, exit once they revert.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::market::MarketData;
+use crate::risk::PositionDirection;
+use crate::strategy::{RiskProfile, RiskProfileBuilder, Signal, SignalAction, Strategy, StrategyError};
+use crate::strategies::{candles, closes, sma, std_dev};
+
+/// Mean reversion configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeanReversionConfig {
+    /// Candle timeframe to read
+    pub timeframe: String,
+    /// Lookback for the mean and standard deviation
+    pub lookback: usize,
+    /// Absolute z-score at which to enter
+    pub entry_z: f64,
+    /// Absolute z-score below which to exit
+    pub exit_z: f64,
+    /// Short stretched-up prices instead of only exiting
+    pub allow_short: bool,
+    /// Position size as a fraction of portfolio
+    pub position_size: f64,
+}
+
+impl Default for MeanReversionConfig {
+    fn default() -> Self {
+        Self {
+            timeframe: "1h".to_string(),
+            lookback: 20,
+            entry_z: 2.0,
+            exit_z: 0.5,
+            allow_short: false,
+            position_size: 0.03,
+        }
+    }
+}
+
+/// Z-score mean reversion strategy
+pub struct MeanReversionStrategy {
+    /// Strategy ID
+    id: String,
+    /// Configuration
+    config: MeanReversionConfig,
+}
+
+impl MeanReversionStrategy {
+    /// Create the strategy, validating its thresholds
+    pub fn new(id: &str, config: MeanReversionConfig) -> Result<Self, StrategyError> {
+        if config.lookback < 2 {
+            return Err(StrategyError::InvalidConfig("lookback must be at least 2".to_string()));
+        }
+        if config.exit_z < 0.0 || config.exit_z >= config.entry_z {
+            return Err(StrategyError::InvalidConfig(format!(
+                "exit_z {} must be non-negative and below entry_z {}",
+                config.exit_z, config.entry_z
+            )));
+        }
+        Ok(Self { id: id.to_string(), config })
+    }
+}
+
+#[async_trait]
+impl Strategy for MeanReversionStrategy {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let closes = closes(candles(market_data, &self.config.timeframe)?);
+        let (Some(mean), Some(std_dev)) = (sma(&closes, self.config.lookback), std_dev(&closes, self.config.lookback)) else {
+            return Ok(None);
+        };
+        if std_dev <= f64::EPSILON {
+            return Ok(None);
+        }
+
+        let last = closes[closes.len() - 1];
+        let z = (last - mean) / std_dev;
+        let (action, direction) = if z <= -self.config.entry_z {
+            (SignalAction::Enter, PositionDirection::Long)
+        } else if z >= self.config.entry_z && self.config.allow_short {
+            (SignalAction::Enter, PositionDirection::Short)
+        } else if z.abs() <= self.config.exit_z || z >= self.config.entry_z {
+            (SignalAction::Exit, PositionDirection::Neutral)
+        } else {
+            return Ok(None);
+        };
+        let strength = (z.abs() / (self.config.entry_z * 2.0)).min(1.0);
+
+        Ok(Some(
+            Signal::new(self.id.clone(), market_data.symbol.clone(), action)
+                .with_direction(direction)
+                .with_strength(strength)
+                .with_confidence(0.5 + strength / 2.0)
+                .with_price(last)
+                .with_metadata("template", "mean_reversion")
+                .with_metadata("z_score", &format!("{:.4}", z)),
+        ))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfileBuilder::new().position_size(self.config.position_size).build()
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Mean reversion (z {}/{} over {} {} candles)",
+            self.config.entry_z, self.config.exit_z, self.config.lookback, self.config.timeframe
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::test_support::market_data;
+
+    #[tokio::test]
+    async fn test_enters_on_stretch_and_exits_near_mean() {
+        let config = MeanReversionConfig { lookback: 10, ..MeanReversionConfig::default() };
+        let strategy = MeanReversionStrategy::new("mr", config).unwrap();
+
+        let mut closes = vec![100.0, 101.0, 99.0, 100.0, 101.0, 99.0, 100.0, 101.0, 99.0];
+        closes.push(90.0);
+        let signal = strategy.generate_signal(&market_data(&closes)).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Enter);
+        assert_eq!(signal.direction, PositionDirection::Long);
+
+        let calm = vec![100.0, 101.0, 99.0, 100.0, 101.0, 99.0, 100.0, 101.0, 99.0, 100.0];
+        let signal = strategy.generate_signal(&market_data(&calm)).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Exit);
+
+        // Stretched up without shorting allowed: take profit rather than short
+        let mut spike = calm.clone();
+        spike[9] = 110.0;
+        let signal = strategy.generate_signal(&market_data(&spike)).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Exit);
+    }
+}
@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Reference strategy library
+//!
+//! Ready-to-run implementations of common strategy templates. Each strategy
+//! is configured through a serde config, is stateless between calls so it
+//! can be replayed by the backtest engine, and only reads data available at
+//! the time of the call. The ensemble meta-strategy combines any of them
+//! (or other strategies) into one signal stream, and the pairs strategy
+//! trades spreads published by the pairs scanner.
+
+pub mod momentum_crossover;
+pub mod mean_reversion;
+pub mod orderbook_imbalance;
+pub mod volatility_breakout;
+pub mod ensemble;
+pub mod pairs_trading;
+
+use std::sync::Arc;
+
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::market::{Candle, MarketData};
+use crate::strategy::{Strategy, StrategyError};
+
+pub use momentum_crossover::{MomentumCrossoverConfig, MomentumCrossoverStrategy};
+pub use mean_reversion::{MeanReversionConfig, MeanReversionStrategy};
+pub use orderbook_imbalance::{OrderbookImbalanceConfig, OrderbookImbalanceStrategy};
+pub use volatility_breakout::{VolatilityBreakoutConfig, VolatilityBreakoutStrategy};
+pub use ensemble::{EnsembleConfig, EnsembleMode, EnsembleStrategy};
+pub use pairs_trading::{PairsTradingConfig, PairsTradingStrategy};
+
+/// Serializable choice of reference strategy and its configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "template", rename_all = "snake_case")]
+pub enum StrategyTemplate {
+    /// Fast/slow moving average crossover
+    MomentumCrossover(MomentumCrossoverConfig),
+    /// Z-score mean reversion
+    MeanReversion(MeanReversionConfig),
+    /// Top-of-book imbalance scalper
+    OrderbookImbalance(OrderbookImbalanceConfig),
+    /// Range breakout filtered by volatility expansion
+    VolatilityBreakout(VolatilityBreakoutConfig),
+}
+
+impl StrategyTemplate {
+    /// Template name
+    pub fn name(&self) -> &'static str {
+        match self {
+            StrategyTemplate::MomentumCrossover(_) => "momentum_crossover",
+            StrategyTemplate::MeanReversion(_) => "mean_reversion",
+            StrategyTemplate::OrderbookImbalance(_) => "orderbook_imbalance",
+            StrategyTemplate::VolatilityBreakout(_) => "volatility_breakout",
+        }
+    }
+
+    /// Build the strategy under the given ID, validating its configuration
+    pub fn build(self, id: &str) -> Result<Arc<dyn Strategy>, StrategyError> {
+        Ok(match self {
+            StrategyTemplate::MomentumCrossover(config) => Arc::new(MomentumCrossoverStrategy::new(id, config)?),
+            StrategyTemplate::MeanReversion(config) => Arc::new(MeanReversionStrategy::new(id, config)?),
+            StrategyTemplate::OrderbookImbalance(config) => Arc::new(OrderbookImbalanceStrategy::new(id, config)?),
+            StrategyTemplate::VolatilityBreakout(config) => Arc::new(VolatilityBreakoutStrategy::new(id, config)?),
+        })
+    }
+}
+
+/// Candles for a timeframe, oldest first
+pub(crate) fn candles<'a>(market_data: &'a MarketData, timeframe: &str) -> Result<&'a [Candle], StrategyError> {
+    market_data
+        .candles
+        .get(timeframe)
+        .map(|c| c.as_slice())
+        .ok_or_else(|| StrategyError::MissingData(format!("no {} candles for {}", timeframe, market_data.symbol)))
+}
+
+/// Closing prices of candles as f64, oldest first
+pub(crate) fn closes(candles: &[Candle]) -> Vec<f64> {
+    candles.iter().map(|c| c.close.to_f64().unwrap_or(0.0)).collect()
+}
+
+/// Simple moving average of the last `period` values
+pub(crate) fn sma(values: &[f64], period: usize) -> Option<f64> {
+    if period == 0 || values.len() < period {
+        return None;
+    }
+    Some(values[values.len() - period..].iter().sum::<f64>() / period as f64)
+}
+
+/// Sample standard deviation of the last `period` values
+pub(crate) fn std_dev(values: &[f64], period: usize) -> Option<f64> {
+    let mean = sma(values, period)?;
+    if period < 2 {
+        return None;
+    }
+    let window = &values[values.len() - period..];
+    Some((window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (period - 1) as f64).sqrt())
+}
+
+/// Average true range over the last `period` candles
+pub(crate) fn average_true_range(candles: &[Candle], period: usize) -> Option<f64> {
+    if period == 0 || candles.len() < period + 1 {
+        return None;
+    }
+    let window = &candles[candles.len() - period - 1..];
+    let total: f64 = window
+        .windows(2)
+        .map(|pair| {
+            let previous_close = pair[0].close.to_f64().unwrap_or(0.0);
+            let high = pair[1].high.to_f64().unwrap_or(0.0);
+            let low = pair[1].low.to_f64().unwrap_or(0.0);
+            (high - low).max((high - previous_close).abs()).max((low - previous_close).abs())
+        })
+        .sum();
+    Some(total / period as f64)
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use chrono::{Duration, Utc};
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal::Decimal;
+
+    use crate::backtest::BacktestBar;
+    use crate::market::{Candle, MarketData, Ticker};
+
+    /// Market data with hourly candles at the given closes (high/low one unit away)
+    pub fn market_data(closes: &[f64]) -> MarketData {
+        let start = Utc::now() - Duration::hours(closes.len() as i64);
+        let candles: Vec<Candle> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| {
+                let d = |v: f64| Decimal::from_f64(v).unwrap_or_default();
+                Candle::new(start + Duration::hours(i as i64), d(*close), d(close + 1.0), d(close - 1.0), d(*close), d(10.0))
+            })
+            .collect();
+        let last = closes.last().copied().unwrap_or(0.0);
+        let ticker = Ticker {
+            bid: last,
+            ask: last,
+            last,
+            volume: 10.0,
+            change_24h: 0.0,
+            high_24h: last,
+            low_24h: last,
+            quote_volume: 10.0 * last,
+        };
+        let mut market_data = MarketData::new("test".to_string(), "BTC/USDT".to_string(), ticker);
+        market_data.candles.insert("1h".to_string(), candles);
+        market_data
+    }
+
+    /// Backtest bars at the given closes
+    pub fn bars(closes: &[f64]) -> Vec<BacktestBar> {
+        let start = Utc::now();
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| BacktestBar {
+                timestamp: start + Duration::hours(i as i64),
+                open: *close,
+                high: close + 1.0,
+                low: close - 1.0,
+                close: *close,
+                volume: 10.0,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Moving average crossover: long while the fast average is above the slow one.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::market::MarketData;
+use crate::risk::PositionDirection;
+use crate::strategy::{RiskProfile, RiskProfileBuilder, Signal, SignalAction, Strategy, StrategyError};
+use crate::strategies::{candles, closes, sma};
+
+/// Momentum crossover configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MomentumCrossoverConfig {
+    /// Candle timeframe to read
+    pub timeframe: String,
+    /// Fast moving average period
+    pub fast_period: usize,
+    /// Slow moving average period
+    pub slow_period: usize,
+    /// Minimum separation between the averages, as a fraction of the slow average, before acting
+    pub min_separation: f64,
+    /// Go short when the fast average is below the slow one instead of only exiting
+    pub allow_short: bool,
+    /// Position size as a fraction of portfolio
+    pub position_size: f64,
+}
+
+impl Default for MomentumCrossoverConfig {
+    fn default() -> Self {
+        Self {
+            timeframe: "1h".to_string(),
+            fast_period: 10,
+            slow_period: 30,
+            min_separation: 0.001,
+            allow_short: false,
+            position_size: 0.05,
+        }
+    }
+}
+
+/// Fast/slow moving average crossover strategy
+pub struct MomentumCrossoverStrategy {
+    /// Strategy ID
+    id: String,
+    /// Configuration
+    config: MomentumCrossoverConfig,
+}
+
+impl MomentumCrossoverStrategy {
+    /// Create the strategy, validating its periods
+    pub fn new(id: &str, config: MomentumCrossoverConfig) -> Result<Self, StrategyError> {
+        if config.fast_period == 0 || config.fast_period >= config.slow_period {
+            return Err(StrategyError::InvalidConfig(format!(
+                "fast_period {} must be positive and below slow_period {}",
+                config.fast_period, config.slow_period
+            )));
+        }
+        Ok(Self { id: id.to_string(), config })
+    }
+}
+
+#[async_trait]
+impl Strategy for MomentumCrossoverStrategy {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let closes = closes(candles(market_data, &self.config.timeframe)?);
+        let (Some(fast), Some(slow)) = (sma(&closes, self.config.fast_period), sma(&closes, self.config.slow_period)) else {
+            return Ok(None);
+        };
+        if slow <= 0.0 {
+            return Ok(None);
+        }
+
+        let separation = (fast - slow) / slow;
+        if separation.abs() < self.config.min_separation {
+            return Ok(None);
+        }
+
+        let (action, direction) = match (separation > 0.0, self.config.allow_short) {
+            (true, _) => (SignalAction::Enter, PositionDirection::Long),
+            (false, true) => (SignalAction::Enter, PositionDirection::Short),
+            (false, false) => (SignalAction::Exit, PositionDirection::Long),
+        };
+        // Full strength at a 5% gap between the averages
+        let strength = (separation.abs() / 0.05).min(1.0);
+
+        Ok(Some(
+            Signal::new(self.id.clone(), market_data.symbol.clone(), action)
+                .with_direction(direction)
+                .with_strength(strength)
+                .with_confidence(0.5 + strength / 2.0)
+                .with_price(market_data.ticker.last)
+                .with_metadata("template", "momentum_crossover")
+                .with_metadata("separation", &format!("{:.6}", separation)),
+        ))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfileBuilder::new().position_size(self.config.position_size).build()
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Momentum crossover ({}/{} on {})",
+            self.config.fast_period, self.config.slow_period, self.config.timeframe
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::{BacktestConfig, BacktestEngine};
+    use crate::strategies::test_support::bars;
+
+    #[tokio::test]
+    async fn test_rides_trend_and_exits_on_reversal() {
+        let config = MomentumCrossoverConfig { fast_period: 3, slow_period: 6, ..MomentumCrossoverConfig::default() };
+        let strategy = MomentumCrossoverStrategy::new("momentum", config).unwrap();
+        assert!(MomentumCrossoverStrategy::new("bad", MomentumCrossoverConfig { fast_period: 6, slow_period: 3, ..MomentumCrossoverConfig::default() }).is_err());
+
+        let mut closes: Vec<f64> = (0..12).map(|i| 100.0 + i as f64 * 2.0).collect();
+        closes.extend((0..12).map(|i| 122.0 - i as f64 * 3.0));
+        let engine = BacktestEngine::new(BacktestConfig { fee_bps: 0.0, slippage_bps: 0.0, close_at_end: false, ..BacktestConfig::default() });
+        let report = engine.run(&strategy, &bars(&closes)).await.unwrap();
+
+        // One entry during the rise, one exit after the fast average rolls over
+        assert_eq!(report.trades.len(), 2);
+        assert!(report.trades[1].realized_pnl.unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Orderbook imbalance scalper: lean with top-of-book pressure in tight markets.
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::market::MarketData;
+use crate::risk::PositionDirection;
+use crate::strategy::{RiskProfile, RiskProfileBuilder, Signal, SignalAction, Strategy, StrategyError};
+
+/// Orderbook imbalance configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrderbookImbalanceConfig {
+    /// Number of book levels per side to sum
+    pub depth_levels: usize,
+    /// Imbalance (-1..1) at which to enter
+    pub entry_imbalance: f64,
+    /// Absolute imbalance below which to exit
+    pub exit_imbalance: f64,
+    /// Widest spread in basis points to trade into
+    pub max_spread_bps: f64,
+    /// Follow ask-heavy books short instead of only exiting
+    pub allow_short: bool,
+    /// Seconds before an emitted signal expires
+    pub signal_ttl_secs: i64,
+    /// Position size as a fraction of portfolio
+    pub position_size: f64,
+}
+
+impl Default for OrderbookImbalanceConfig {
+    fn default() -> Self {
+        Self {
+            depth_levels: 5,
+            entry_imbalance: 0.3,
+            exit_imbalance: 0.05,
+            max_spread_bps: 10.0,
+            allow_short: false,
+            signal_ttl_secs: 30,
+            position_size: 0.01,
+        }
+    }
+}
+
+/// Orderbook imbalance scalping strategy
+pub struct OrderbookImbalanceStrategy {
+    /// Strategy ID
+    id: String,
+    /// Configuration
+    config: OrderbookImbalanceConfig,
+}
+
+impl OrderbookImbalanceStrategy {
+    /// Create the strategy, validating its thresholds
+    pub fn new(id: &str, config: OrderbookImbalanceConfig) -> Result<Self, StrategyError> {
+        if config.depth_levels == 0 {
+            return Err(StrategyError::InvalidConfig("depth_levels must be positive".to_string()));
+        }
+        if !(0.0..1.0).contains(&config.exit_imbalance) || config.exit_imbalance >= config.entry_imbalance || config.entry_imbalance > 1.0 {
+            return Err(StrategyError::InvalidConfig(format!(
+                "thresholds must satisfy 0 <= exit_imbalance {} < entry_imbalance {} <= 1",
+                config.exit_imbalance, config.entry_imbalance
+            )));
+        }
+        Ok(Self { id: id.to_string(), config })
+    }
+}
+
+#[async_trait]
+impl Strategy for OrderbookImbalanceStrategy {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let Some(orderbook) = &market_data.orderbook else {
+            return Ok(None);
+        };
+        let (Some(spread_pct), Some(mid)) = (orderbook.spread_pct(), orderbook.mid_price()) else {
+            return Ok(None);
+        };
+        // Scalping edge disappears once the spread is wide
+        let spread_bps = spread_pct * 100.0;
+        if spread_bps > self.config.max_spread_bps {
+            return Ok(None);
+        }
+
+        let imbalance = orderbook.imbalance(self.config.depth_levels);
+        let (action, direction) = if imbalance >= self.config.entry_imbalance {
+            (SignalAction::Enter, PositionDirection::Long)
+        } else if imbalance <= -self.config.entry_imbalance && self.config.allow_short {
+            (SignalAction::Enter, PositionDirection::Short)
+        } else if imbalance.abs() <= self.config.exit_imbalance || imbalance <= -self.config.entry_imbalance {
+            (SignalAction::Exit, PositionDirection::Neutral)
+        } else {
+            return Ok(None);
+        };
+        let strength = imbalance.abs().min(1.0);
+
+        Ok(Some(
+            Signal::new(self.id.clone(), market_data.symbol.clone(), action)
+                .with_direction(direction)
+                .with_strength(strength)
+                .with_confidence(0.5 + strength / 2.0)
+                .with_price(mid.to_f64().unwrap_or(0.0))
+                .with_expiration(Utc::now() + Duration::seconds(self.config.signal_ttl_secs))
+                .with_metadata("template", "orderbook_imbalance")
+                .with_metadata("imbalance", &format!("{:.4}", imbalance))
+                .with_metadata("spread_bps", &format!("{:.2}", spread_bps)),
+        ))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfileBuilder::new().position_size(self.config.position_size).build()
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Orderbook imbalance scalper ({} levels, entry {}, max spread {}bps)",
+            self.config.depth_levels, self.config.entry_imbalance, self.config.max_spread_bps
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::market::{Orderbook, OrderbookEntry};
+    use crate::strategies::test_support::market_data;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_follows_bid_pressure_only_in_tight_books() {
+        let strategy = OrderbookImbalanceStrategy::new("obi", OrderbookImbalanceConfig::default()).unwrap();
+        let mut data = market_data(&[100.0]);
+        assert!(strategy.generate_signal(&data).await.unwrap().is_none());
+
+        data.update_orderbook(Orderbook::new(
+            vec![OrderbookEntry::new(dec!(100.00), dec!(8)), OrderbookEntry::new(dec!(99.99), dec!(4))],
+            vec![OrderbookEntry::new(dec!(100.02), dec!(2)), OrderbookEntry::new(dec!(100.03), dec!(2))],
+        ));
+        let signal = strategy.generate_signal(&data).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Enter);
+        assert_eq!(signal.direction, PositionDirection::Long);
+        assert!(signal.expiration.is_some());
+
+        // Same pressure behind a 1% spread is ignored
+        data.update_orderbook(Orderbook::new(
+            vec![OrderbookEntry::new(dec!(99.50), dec!(8))],
+            vec![OrderbookEntry::new(dec!(100.50), dec!(2))],
+        ));
+        assert!(strategy.generate_signal(&data).await.unwrap().is_none());
+    }
+}
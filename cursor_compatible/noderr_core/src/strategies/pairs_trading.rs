@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Pairs trading: trade the spread of a cointegrated pair when it stretches
+//! away from its mean, hedged in the ratio published by the pairs scanner.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::market::MarketData;
+use crate::pairs_scanner::PairsScanner;
+use crate::risk::PositionDirection;
+use crate::strategy::{RiskProfile, RiskProfileBuilder, Signal, SignalAction, Strategy, StrategyError};
+
+/// Pairs trading configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PairsTradingConfig {
+    /// Absolute spread z-score at which to enter
+    pub entry_z: f64,
+    /// Absolute spread z-score below which to exit
+    pub exit_z: f64,
+    /// Position size as a fraction of portfolio
+    pub position_size: f64,
+}
+
+impl Default for PairsTradingConfig {
+    fn default() -> Self {
+        Self {
+            entry_z: 2.0,
+            exit_z: 0.5,
+            position_size: 0.05,
+        }
+    }
+}
+
+/// Reference pairs trading strategy driven by the pairs scanner
+///
+/// Signals are issued on the dependent symbol of a pair; the hedge symbol
+/// and ratio travel in the signal metadata so execution can build the
+/// second leg.
+pub struct PairsTradingStrategy {
+    /// Strategy ID
+    id: String,
+    /// Configuration
+    config: PairsTradingConfig,
+    /// Source of cointegrated pairs and hedge prices
+    scanner: Arc<PairsScanner>,
+}
+
+impl PairsTradingStrategy {
+    /// Create the strategy, validating its thresholds
+    pub fn new(id: &str, config: PairsTradingConfig, scanner: Arc<PairsScanner>) -> Result<Self, StrategyError> {
+        if config.exit_z < 0.0 || config.exit_z >= config.entry_z {
+            return Err(StrategyError::InvalidConfig(format!(
+                "exit_z {} must be non-negative and below entry_z {}",
+                config.exit_z, config.entry_z
+            )));
+        }
+        Ok(Self { id: id.to_string(), config, scanner })
+    }
+}
+
+#[async_trait]
+impl Strategy for PairsTradingStrategy {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let Some(pair) = self.scanner.pair_for(&market_data.symbol).await else {
+            return Ok(None);
+        };
+        if pair.dependent != market_data.symbol {
+            return Ok(None);
+        }
+        let Some(hedge_price) = self.scanner.latest_price(&pair.independent).await else {
+            return Ok(None);
+        };
+
+        let last = market_data.ticker.last;
+        let Some(z) = pair.zscore_at(last, hedge_price) else {
+            return Ok(None);
+        };
+
+        // A rich spread is sold (short the dependent, long the hedge) and vice versa
+        let (action, direction) = if z >= self.config.entry_z {
+            (SignalAction::Enter, PositionDirection::Short)
+        } else if z <= -self.config.entry_z {
+            (SignalAction::Enter, PositionDirection::Long)
+        } else if z.abs() <= self.config.exit_z {
+            (SignalAction::Exit, PositionDirection::Neutral)
+        } else {
+            return Ok(None);
+        };
+        let strength = (z.abs() / (self.config.entry_z * 2.0)).min(1.0);
+
+        Ok(Some(
+            Signal::new(self.id.clone(), market_data.symbol.clone(), action)
+                .with_direction(direction)
+                .with_strength(strength)
+                .with_confidence(0.5 + strength / 2.0)
+                .with_price(last)
+                .with_metadata("template", "pairs_trading")
+                .with_metadata("hedge_symbol", &pair.independent)
+                .with_metadata("hedge_ratio", &format!("{:.6}", pair.hedge_ratio))
+                .with_metadata("half_life", &format!("{:.2}", pair.half_life))
+                .with_metadata("z_score", &format!("{:.4}", z)),
+        ))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfileBuilder::new().position_size(self.config.position_size).build()
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> String {
+        format!("Pairs trading (spread z {}/{})", self.config.entry_z, self.config.exit_z)
+    }
+}
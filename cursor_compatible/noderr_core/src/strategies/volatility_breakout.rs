@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Volatility breakout: trade closes that escape the recent range by an ATR buffer.
+
+use async_trait::async_trait;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::market::{Candle, MarketData};
+use crate::risk::PositionDirection;
+use crate::strategy::{RiskProfile, RiskProfileBuilder, Signal, SignalAction, Strategy, StrategyError};
+use crate::strategies::{average_true_range, candles};
+
+/// Volatility breakout configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolatilityBreakoutConfig {
+    /// Candle timeframe to read
+    pub timeframe: String,
+    /// Candles forming the breakout channel
+    pub channel_period: usize,
+    /// Candles in the average true range
+    pub atr_period: usize,
+    /// ATR multiples the close must clear beyond the channel
+    pub atr_buffer: f64,
+    /// Candles forming the trailing exit channel
+    pub exit_period: usize,
+    /// Trade downside breakouts short
+    pub allow_short: bool,
+    /// Position size as a fraction of portfolio
+    pub position_size: f64,
+}
+
+impl Default for VolatilityBreakoutConfig {
+    fn default() -> Self {
+        Self {
+            timeframe: "1h".to_string(),
+            channel_period: 20,
+            atr_period: 14,
+            atr_buffer: 0.0,
+            exit_period: 10,
+            allow_short: false,
+            position_size: 0.04,
+        }
+    }
+}
+
+/// Channel breakout strategy with an ATR filter
+pub struct VolatilityBreakoutStrategy {
+    /// Strategy ID
+    id: String,
+    /// Configuration
+    config: VolatilityBreakoutConfig,
+}
+
+impl VolatilityBreakoutStrategy {
+    /// Create the strategy, validating its periods
+    pub fn new(id: &str, config: VolatilityBreakoutConfig) -> Result<Self, StrategyError> {
+        if config.channel_period == 0 || config.atr_period == 0 || config.exit_period == 0 {
+            return Err(StrategyError::InvalidConfig("periods must be positive".to_string()));
+        }
+        if config.exit_period > config.channel_period {
+            return Err(StrategyError::InvalidConfig(format!(
+                "exit_period {} must not exceed channel_period {}",
+                config.exit_period, config.channel_period
+            )));
+        }
+        if config.atr_buffer < 0.0 {
+            return Err(StrategyError::InvalidConfig("atr_buffer must be non-negative".to_string()));
+        }
+        Ok(Self { id: id.to_string(), config })
+    }
+}
+
+/// Highest high and lowest low across candles
+fn channel(candles: &[Candle]) -> (f64, f64) {
+    candles.iter().fold((f64::MIN, f64::MAX), |(high, low), c| {
+        (high.max(c.high.to_f64().unwrap_or(0.0)), low.min(c.low.to_f64().unwrap_or(0.0)))
+    })
+}
+
+#[async_trait]
+impl Strategy for VolatilityBreakoutStrategy {
+    async fn generate_signal(&self, market_data: &MarketData) -> Result<Option<Signal>, StrategyError> {
+        let candles = candles(market_data, &self.config.timeframe)?;
+        let required = self.config.channel_period.max(self.config.atr_period) + 1;
+        if candles.len() < required {
+            return Ok(None);
+        }
+        let Some(atr) = average_true_range(candles, self.config.atr_period) else {
+            return Ok(None);
+        };
+
+        // Channels exclude the current candle so a close can break them
+        let (current, previous) = candles.split_last().expect("length checked above");
+        let close = current.close.to_f64().unwrap_or(0.0);
+        let (channel_high, channel_low) = channel(&previous[previous.len() - self.config.channel_period..]);
+        let (_, exit_low) = channel(&previous[previous.len() - self.config.exit_period..]);
+        let buffer = self.config.atr_buffer * atr;
+
+        let (action, direction, distance) = if close > channel_high + buffer {
+            (SignalAction::Enter, PositionDirection::Long, close - channel_high)
+        } else if close < channel_low - buffer && self.config.allow_short {
+            (SignalAction::Enter, PositionDirection::Short, channel_low - close)
+        } else if close < exit_low && !self.config.allow_short {
+            (SignalAction::Exit, PositionDirection::Neutral, exit_low - close)
+        } else {
+            return Ok(None);
+        };
+        let strength = if atr > 0.0 { (distance / atr).min(1.0) } else { 1.0 };
+
+        Ok(Some(
+            Signal::new(self.id.clone(), market_data.symbol.clone(), action)
+                .with_direction(direction)
+                .with_strength(strength)
+                .with_confidence(0.5 + strength / 2.0)
+                .with_price(close)
+                .with_metadata("template", "volatility_breakout")
+                .with_metadata("atr", &format!("{:.4}", atr)),
+        ))
+    }
+
+    async fn get_risk_profile(&self) -> RiskProfile {
+        RiskProfileBuilder::new().position_size(self.config.position_size).build()
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Volatility breakout ({}-bar channel, {}x ATR{} buffer, {} candles)",
+            self.config.channel_period, self.config.atr_buffer, self.config.atr_period, self.config.timeframe
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::test_support::market_data;
+
+    #[tokio::test]
+    async fn test_breakout_above_channel_enters_long() {
+        let config = VolatilityBreakoutConfig {
+            channel_period: 5,
+            atr_period: 3,
+            atr_buffer: 0.5,
+            exit_period: 3,
+            ..VolatilityBreakoutConfig::default()
+        };
+        let strategy = VolatilityBreakoutStrategy::new("vb", config).unwrap();
+
+        let range = [100.0, 101.0, 100.0, 99.0, 100.0, 101.0];
+        assert!(strategy.generate_signal(&market_data(&range)).await.unwrap().is_none());
+
+        let mut breakout = range.to_vec();
+        breakout.push(105.0);
+        let signal = strategy.generate_signal(&market_data(&breakout)).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Enter);
+        assert_eq!(signal.direction, PositionDirection::Long);
+
+        let mut breakdown = range.to_vec();
+        breakdown.push(95.0);
+        let signal = strategy.generate_signal(&market_data(&breakdown)).await.unwrap().unwrap();
+        assert_eq!(signal.action, SignalAction::Exit);
+    }
+}
@@ -24,10 +24,10 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 use thiserror::Error;
 
 use crate::execution::{ExecutionResult, ExecutionStatus};
+use crate::ids::{new_id, IdKind};
 use crate::market::{MarketData, Symbol};
 use crate::risk::PositionDirection;
 
@@ -332,13 +332,17 @@ pub struct Signal {
     /// Fill confidence (0.0 - 1.0)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fill_confidence: Option<f64>,
+    
+    /// Decisions taken on the signal after generation, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reason_chain: Vec<String>,
 }
 
 impl Signal {
     /// Create a new signal with default values
     pub fn new(strategy_id: StrategyId, symbol: Symbol, action: SignalAction) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: new_id(IdKind::Signal),
             strategy_id,
             symbol,
             action,
@@ -358,6 +362,7 @@ impl Signal {
             execution_horizon: ExecutionHorizon::default(),
             expected_slippage_pct: None,
             fill_confidence: None,
+            reason_chain: Vec::new(),
         }
     }
 
@@ -390,6 +395,11 @@ impl Signal {
         format!("signal:{}", self.id)
     }
     
+    /// Append a decision to the signal's reason chain
+    pub fn push_reason(&mut self, reason: impl Into<String>) {
+        self.reason_chain.push(reason.into());
+    }
+    
     /// Add metadata to the signal
     pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
         let metadata = self.metadata.get_or_insert_with(HashMap::new);
@@ -1115,6 +1125,11 @@ pub trait EntropyInjector: Send + Sync {
     /// Determine if a signal should be skipped entirely
     fn should_skip_signal(&self, signal: &Signal) -> bool;
     
+    /// Draw a uniform value in [0, 1), e.g. to jitter execution schedules
+    fn sample_unit(&self) -> f64 {
+        rand::random::<f64>()
+    }
+    
     /// Allow downcasting to concrete implementation
     fn as_any(&self) -> &dyn std::any::Any where Self: 'static;
 }
@@ -1134,6 +1149,9 @@ pub enum StrategyError {
     #[error("Execution pipeline blocked: {0}")]
     PipelineHalted(String),
     
+    #[error("Strategy panicked: {0}")]
+    Panicked(String),
+    
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -1177,6 +1195,12 @@ pub trait Strategy: Send + Sync {
     fn description(&self) -> String {
         format!("Strategy: {}", self.name())
     }
+    
+    /// IDs of strategies that must be enabled for this strategy to run
+    /// (e.g. a hedger depends on the market maker whose inventory it hedges)
+    fn dependencies(&self) -> Vec<StrategyId> {
+        Vec::new()
+    }
 }
 
 /// Builder for creating strategy implementations
@@ -1187,6 +1211,8 @@ pub struct StrategyBuilder {
     risk_profile: RiskProfile,
     /// Description
     description: Option<String>,
+    /// Strategies this strategy depends on
+    dependencies: Vec<StrategyId>,
 }
 
 impl StrategyBuilder {
@@ -1196,6 +1222,7 @@ impl StrategyBuilder {
             id: id.to_string(),
             risk_profile: RiskProfile::default(),
             description: None,
+            dependencies: Vec::new(),
         }
     }
     
@@ -1211,12 +1238,19 @@ impl StrategyBuilder {
         self
     }
     
+    /// Declare a strategy this strategy depends on
+    pub fn depends_on(mut self, strategy_id: &str) -> Self {
+        self.dependencies.push(strategy_id.to_string());
+        self
+    }
+    
     /// Build a simple "hold" strategy that never generates signals
     pub fn build_hold_strategy(self) -> impl Strategy {
         struct HoldStrategy {
             id: String,
             risk_profile: RiskProfile,
             description: Option<String>,
+            dependencies: Vec<StrategyId>,
         }
         
         #[async_trait]
@@ -1237,12 +1271,17 @@ impl StrategyBuilder {
             fn description(&self) -> String {
                 self.description.clone().unwrap_or_else(|| format!("Hold Strategy: {}", self.id))
             }
+            
+            fn dependencies(&self) -> Vec<StrategyId> {
+                self.dependencies.clone()
+            }
         }
         
         HoldStrategy {
             id: self.id,
             risk_profile: self.risk_profile,
             description: self.description,
+            dependencies: self.dependencies,
         }
     }
 }
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Dependency graph between strategies.
+//!
+//! Strategies declare the strategies they rely on (e.g. a hedger depends on the
+//! market maker whose inventory it hedges). The graph yields the order in which
+//! strategies must be enabled (dependencies first) and disabled (dependents first).
+
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+use tracing::warn;
+
+use crate::strategy::{Strategy, StrategyId};
+
+/// Errors raised by dependency checks
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum DependencyError {
+    #[error("Strategy {strategy} cannot depend on itself")]
+    SelfDependency { strategy: StrategyId },
+
+    #[error("Dependency cycle: {}", .0.join(" -> "))]
+    Cycle(Vec<StrategyId>),
+
+    #[error("Strategy {strategy} depends on {dependency}, which is not registered")]
+    MissingDependency { strategy: StrategyId, dependency: StrategyId },
+
+    #[error("Strategy {strategy} depends on {dependency}, which is quarantined")]
+    QuarantinedDependency { strategy: StrategyId, dependency: StrategyId },
+
+    #[error("Strategy {strategy} is still required by {}", .dependents.join(", "))]
+    HasDependents { strategy: StrategyId, dependents: Vec<StrategyId> },
+}
+
+/// Directed graph of strategy dependencies
+#[derive(Debug, Clone, Default)]
+pub struct StrategyDependencyGraph {
+    /// Direct dependencies by strategy
+    dependencies: HashMap<StrategyId, Vec<StrategyId>>,
+}
+
+impl StrategyDependencyGraph {
+    /// Create an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a graph from the dependencies strategies declare, skipping declarations that would form a cycle
+    pub fn from_strategies(strategies: &[Box<dyn Strategy>]) -> Self {
+        let mut graph = Self::new();
+        for strategy in strategies {
+            if let Err(e) = graph.declare(strategy.name(), strategy.dependencies()) {
+                warn!("Ignoring dependencies of strategy {}: {}", strategy.name(), e);
+            }
+        }
+        graph
+    }
+
+    /// Declare the direct dependencies of a strategy, replacing any previous declaration
+    pub fn declare(&mut self, strategy_id: &str, dependencies: Vec<StrategyId>) -> Result<(), DependencyError> {
+        if dependencies.iter().any(|d| d == strategy_id) {
+            return Err(DependencyError::SelfDependency { strategy: strategy_id.to_string() });
+        }
+
+        let previous = self.dependencies.insert(strategy_id.to_string(), dependencies);
+        if let Some(cycle) = self.find_cycle(strategy_id) {
+            match previous {
+                Some(previous) => self.dependencies.insert(strategy_id.to_string(), previous),
+                None => self.dependencies.remove(strategy_id),
+            };
+            return Err(DependencyError::Cycle(cycle));
+        }
+
+        Ok(())
+    }
+
+    /// Forget a strategy's declared dependencies
+    pub fn remove(&mut self, strategy_id: &str) {
+        self.dependencies.remove(strategy_id);
+    }
+
+    /// Direct dependencies of a strategy
+    pub fn dependencies_of(&self, strategy_id: &str) -> Vec<StrategyId> {
+        self.dependencies.get(strategy_id).cloned().unwrap_or_default()
+    }
+
+    /// Strategies that directly depend on a strategy
+    pub fn dependents_of(&self, strategy_id: &str) -> Vec<StrategyId> {
+        let mut dependents: Vec<StrategyId> = self.dependencies
+            .iter()
+            .filter(|(_, deps)| deps.iter().any(|d| d == strategy_id))
+            .map(|(id, _)| id.clone())
+            .collect();
+        dependents.sort();
+        dependents
+    }
+
+    /// Order in which strategies must be enabled to enable `strategy_id`:
+    /// transitive dependencies first, the strategy itself last
+    pub fn enable_order(&self, strategy_id: &str) -> Vec<StrategyId> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        self.visit_dependencies(strategy_id, &mut visited, &mut order);
+        order
+    }
+
+    /// Order in which strategies must be disabled to disable `strategy_id`:
+    /// transitive dependents first, the strategy itself last
+    pub fn disable_order(&self, strategy_id: &str) -> Vec<StrategyId> {
+        let mut order = Vec::new();
+        let mut visited = HashSet::new();
+        self.visit_dependents(strategy_id, &mut visited, &mut order);
+        order
+    }
+
+    fn visit_dependencies(&self, strategy_id: &str, visited: &mut HashSet<StrategyId>, order: &mut Vec<StrategyId>) {
+        if !visited.insert(strategy_id.to_string()) {
+            return;
+        }
+        for dependency in self.dependencies_of(strategy_id) {
+            self.visit_dependencies(&dependency, visited, order);
+        }
+        order.push(strategy_id.to_string());
+    }
+
+    fn visit_dependents(&self, strategy_id: &str, visited: &mut HashSet<StrategyId>, order: &mut Vec<StrategyId>) {
+        if !visited.insert(strategy_id.to_string()) {
+            return;
+        }
+        for dependent in self.dependents_of(strategy_id) {
+            self.visit_dependents(&dependent, visited, order);
+        }
+        order.push(strategy_id.to_string());
+    }
+
+    /// Find a cycle reachable from `start`, returned as the path that closes it
+    fn find_cycle(&self, start: &str) -> Option<Vec<StrategyId>> {
+        fn walk(
+            graph: &StrategyDependencyGraph,
+            node: &str,
+            path: &mut Vec<StrategyId>,
+            done: &mut HashSet<StrategyId>,
+        ) -> Option<Vec<StrategyId>> {
+            if let Some(pos) = path.iter().position(|n| n == node) {
+                let mut cycle = path[pos..].to_vec();
+                cycle.push(node.to_string());
+                return Some(cycle);
+            }
+            if done.contains(node) {
+                return None;
+            }
+
+            path.push(node.to_string());
+            for dependency in graph.dependencies_of(node) {
+                if let Some(cycle) = walk(graph, &dependency, path, done) {
+                    return Some(cycle);
+                }
+            }
+            path.pop();
+            done.insert(node.to_string());
+            None
+        }
+
+        walk(self, start, &mut Vec::new(), &mut HashSet::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enable_and_disable_order() {
+        let mut graph = StrategyDependencyGraph::new();
+        graph.declare("hedger", vec!["market_maker".to_string()]).unwrap();
+        graph.declare("rebalancer", vec!["hedger".to_string()]).unwrap();
+
+        assert_eq!(graph.enable_order("rebalancer"), vec!["market_maker", "hedger", "rebalancer"]);
+        assert_eq!(graph.disable_order("market_maker"), vec!["rebalancer", "hedger", "market_maker"]);
+    }
+
+    #[test]
+    fn test_cycle_rejected() {
+        let mut graph = StrategyDependencyGraph::new();
+        graph.declare("a", vec!["b".to_string()]).unwrap();
+        graph.declare("b", vec!["c".to_string()]).unwrap();
+
+        let err = graph.declare("c", vec!["a".to_string()]).unwrap_err();
+        assert!(matches!(err, DependencyError::Cycle(_)));
+        assert!(graph.dependencies_of("c").is_empty());
+    }
+}
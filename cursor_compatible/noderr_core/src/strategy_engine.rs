@@ -13,16 +13,23 @@
 // copies or substantial portions of the Software.
 
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::sync::{Arc, RwLock};
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use thiserror::Error;
-use crate::strategy::{Signal, SignalStatus, RiskGrade, ExecutionHorizon, SignalExt};
+use crate::strategy::{Signal, SignalAction, SignalStatus, RiskGrade, ExecutionHorizon, SignalExt};
 use crate::order_router::{SmartOrderRouter, Order, ExecutionFailureReason};
 use crate::execution::{ExecutionResult, ExecutionStatus};
 use crate::risk_calc::{RiskCalculator, RiskViolation};
 use crate::market::Symbol;
 use crate::risk::PositionDirection;
+use crate::telemetry::TelemetryReporter;
+use crate::event_calendar::EventCalendar;
+use crate::object_pool::ObjectPool;
+use crate::warmup::WarmupGate;
+use crate::trade_sizer::DynamicTradeSizer;
 use uuid::Uuid;
 use tracing::{info, warn, error, debug};
 
@@ -58,6 +65,18 @@ pub struct StrategyEngineConfig {
     
     /// Whether to enforce latency budgets
     pub enforce_latency_budgets: bool,
+    
+    /// Per-strategy signal evaluation time budgets
+    #[serde(default)]
+    pub evaluation_budget: EvaluationBudgetConfig,
+    
+    /// Signal deduplication and cooldowns
+    #[serde(default)]
+    pub signal_filter: SignalFilterConfig,
+    
+    /// Resolution of opposing signals on the same symbol
+    #[serde(default)]
+    pub conflict_resolution: ConflictResolutionConfig,
 }
 
 impl Default for StrategyEngineConfig {
@@ -73,10 +92,262 @@ impl Default for StrategyEngineConfig {
             max_slippage_pct: 0.5, // 0.5%
             engine_mode: StrategyEngineMode::Async,
             enforce_latency_budgets: true,
+            evaluation_budget: EvaluationBudgetConfig::default(),
+            signal_filter: SignalFilterConfig::default(),
+            conflict_resolution: ConflictResolutionConfig::default(),
+        }
+    }
+}
+
+/// How opposing entry signals on the same symbol are resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConflictPolicy {
+    /// Net the two sides; the larger side proceeds at the net size
+    Netting,
+    /// The side backed by the most trusted strategy proceeds
+    PriorityByTrust,
+    /// Every conflicting signal is rejected
+    RejectAll,
+}
+
+impl std::fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConflictPolicy::Netting => write!(f, "netting"),
+            ConflictPolicy::PriorityByTrust => write!(f, "priority_by_trust"),
+            ConflictPolicy::RejectAll => write!(f, "reject_all"),
+        }
+    }
+}
+
+/// Conflict policy for a group of symbols
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolGroup {
+    /// Group name
+    pub name: String,
+    
+    /// Symbols in the group
+    pub symbols: Vec<Symbol>,
+    
+    /// Policy applied to conflicts on these symbols
+    pub policy: ConflictPolicy,
+}
+
+/// Conflict resolution configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConflictResolutionConfig {
+    /// Whether opposing signals are resolved before execution
+    pub enabled: bool,
+    
+    /// Policy for symbols outside every group
+    pub default_policy: ConflictPolicy,
+    
+    /// Per-group policies; the first group containing a symbol applies
+    pub groups: Vec<SymbolGroup>,
+    
+    /// Trust score assumed for signals without a trust vector
+    pub default_trust_score: f64,
+    
+    /// Net size below which both sides are considered cancelled out
+    pub min_net_fraction: f64,
+}
+
+impl Default for ConflictResolutionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_policy: ConflictPolicy::PriorityByTrust,
+            groups: Vec::new(),
+            default_trust_score: 0.75,
+            min_net_fraction: 0.05,
+        }
+    }
+}
+
+impl ConflictResolutionConfig {
+    /// Policy for a symbol
+    pub fn policy_for(&self, symbol: &Symbol) -> ConflictPolicy {
+        self.groups
+            .iter()
+            .find(|group| group.symbols.contains(symbol))
+            .map(|group| group.policy)
+            .unwrap_or(self.default_policy)
+    }
+}
+
+/// Signals left after conflict resolution
+#[derive(Debug, Clone, Default)]
+pub struct ConflictOutcome {
+    /// Signals that may proceed, possibly resized
+    pub accepted: Vec<Signal>,
+    
+    /// Signals rejected by conflict resolution
+    pub rejected: Vec<Signal>,
+}
+
+/// Cooldown between accepted signals from a strategy, optionally for a single symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CooldownRule {
+    /// Strategy the rule applies to
+    pub strategy_id: String,
+    
+    /// Symbol the rule applies to; all symbols if not set
+    pub symbol: Option<Symbol>,
+    
+    /// Minimum time between accepted signals in milliseconds
+    pub cooldown_ms: u64,
+}
+
+/// Signal deduplication and cooldown configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SignalFilterConfig {
+    /// Whether duplicate and rapid-fire signals are suppressed
+    pub enabled: bool,
+    
+    /// Cooldown per strategy and symbol when no rule matches, in milliseconds
+    pub default_cooldown_ms: u64,
+    
+    /// Cooldown rules; a rule for the exact symbol wins over a strategy-wide rule
+    pub cooldown_rules: Vec<CooldownRule>,
+    
+    /// Window in which a signal with identical content is a duplicate, in milliseconds
+    pub dedup_window_ms: u64,
+    
+    /// Decimal places prices and quantities are rounded to when comparing content
+    pub dedup_precision: u32,
+    
+    /// Whether exit signals bypass deduplication and cooldowns so positions can always be closed
+    pub exempt_exits: bool,
+}
+
+impl Default for SignalFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_cooldown_ms: 1_000,
+            cooldown_rules: Vec::new(),
+            dedup_window_ms: 30_000,
+            dedup_precision: 4,
+            exempt_exits: true,
+        }
+    }
+}
+
+impl SignalFilterConfig {
+    /// Cooldown for a strategy and symbol in milliseconds
+    pub fn cooldown_for(&self, strategy_id: &str, symbol: &Symbol) -> u64 {
+        let mut strategy_wide = None;
+        for rule in self.cooldown_rules.iter().filter(|rule| rule.strategy_id == strategy_id) {
+            match &rule.symbol {
+                Some(rule_symbol) if rule_symbol == symbol => return rule.cooldown_ms,
+                None => strategy_wide = Some(rule.cooldown_ms),
+                _ => {}
+            }
+        }
+        strategy_wide.unwrap_or(self.default_cooldown_ms)
+    }
+}
+
+/// Signals suppressed for a strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuppressedSignalCounts {
+    /// Signals dropped as duplicates
+    pub duplicates: u64,
+    
+    /// Signals dropped during a cooldown
+    pub cooldowns: u64,
+}
+
+/// Recent signal history used for deduplication and cooldowns
+#[derive(Debug, Default)]
+struct SignalFilterState {
+    /// Last executed signal time by strategy and symbol
+    last_accepted: HashMap<(String, Symbol), DateTime<Utc>>,
+    
+    /// Content fingerprints of recently executed signals
+    fingerprints: HashMap<String, DateTime<Utc>>,
+    
+    /// Suppression counters by strategy ID
+    suppressed: HashMap<String, SuppressedSignalCounts>,
+}
+
+/// Time budget for evaluating a strategy's signals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvaluationBudgetConfig {
+    /// Whether evaluation budgets are enforced
+    pub enabled: bool,
+    
+    /// Default evaluation budget in milliseconds
+    pub budget_ms: u64,
+    
+    /// Budget overrides by strategy ID
+    pub strategy_budgets_ms: HashMap<String, u64>,
+    
+    /// Consecutive overruns before the strategy's evaluation frequency is halved
+    pub overruns_before_throttle: u32,
+    
+    /// Largest throttle factor; a factor of N evaluates one signal in N
+    pub max_throttle_factor: u32,
+    
+    /// Total overruns before the strategy is flagged for review
+    pub overruns_before_review: u32,
+    
+    /// Consecutive in-budget evaluations before the throttle factor is halved
+    pub recovery_evaluations: u32,
+}
+
+impl Default for EvaluationBudgetConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            budget_ms: 50,
+            strategy_budgets_ms: HashMap::new(),
+            overruns_before_throttle: 3,
+            max_throttle_factor: 16,
+            overruns_before_review: 20,
+            recovery_evaluations: 50,
         }
     }
 }
 
+impl EvaluationBudgetConfig {
+    /// Budget for a strategy in milliseconds
+    pub fn budget_for(&self, strategy_id: &str) -> u64 {
+        self.strategy_budgets_ms.get(strategy_id).copied().unwrap_or(self.budget_ms)
+    }
+}
+
+/// Evaluation budget usage for a strategy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EvaluationBudgetStatus {
+    /// Strategy ID
+    pub strategy_id: String,
+    
+    /// Duration of the last evaluation in milliseconds
+    pub last_elapsed_ms: u64,
+    
+    /// Overruns since the last in-budget evaluation
+    pub consecutive_overruns: u32,
+    
+    /// Overruns since the strategy was last reviewed
+    pub total_overruns: u32,
+    
+    /// In-budget evaluations since the last overrun
+    pub within_budget_streak: u32,
+    
+    /// One signal in this many is evaluated; 1 means unthrottled
+    pub throttle_factor: u32,
+    
+    /// Signals skipped since the last evaluation
+    pub skipped: u32,
+    
+    /// Whether the strategy has been flagged for operator review
+    pub flagged_for_review: bool,
+}
+
 /// Engine operation mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StrategyEngineMode {
@@ -211,6 +482,18 @@ pub enum StrategyEngineError {
     #[error("Latency budget exceeded")]
     LatencyBudgetExceeded,
     
+    #[error("Signal suppressed: {0}")]
+    SignalSuppressed(String),
+    
+    #[error("Signal rejected by conflict resolution: {0}")]
+    SignalConflict(String),
+    
+    #[error("Trading paused around scheduled events: {0}")]
+    TradingPaused(String),
+    
+    #[error("Symbol is still warming up: {0}")]
+    SymbolWarmingUp(String),
+    
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -228,6 +511,30 @@ pub struct StrategyEngine {
     
     /// Signal metrics store
     metrics: RwLock<HashMap<String, SignalMetrics>>,
+    
+    /// Evaluation budget usage by strategy ID
+    evaluation_budgets: RwLock<HashMap<String, EvaluationBudgetStatus>>,
+    
+    /// Optional telemetry for budget overruns
+    telemetry: Option<Arc<TelemetryReporter>>,
+    
+    /// Signal deduplication and cooldown state
+    signal_filter: RwLock<SignalFilterState>,
+    
+    /// Optional event calendar for pre/post-event pauses and size reductions
+    event_calendar: Option<Arc<EventCalendar>>,
+    
+    /// Optional warm-up gate refusing entries on symbols without stable history
+    warmup_gate: Option<Arc<WarmupGate>>,
+    
+    /// Optional sizer scaling new positions by volatility and remaining risk budget
+    trade_sizer: Option<Arc<DynamicTradeSizer>>,
+    
+    /// Scratch buffers for signal fingerprints
+    fingerprint_buffers: ObjectPool<String>,
+    
+    /// Scratch maps for grouping entry signals by symbol during conflict resolution
+    conflict_buffers: ObjectPool<HashMap<Symbol, Vec<Signal>>>,
 }
 
 impl StrategyEngine {
@@ -242,9 +549,41 @@ impl StrategyEngine {
             router,
             risk_calculator,
             metrics: RwLock::new(HashMap::new()),
+            evaluation_budgets: RwLock::new(HashMap::new()),
+            telemetry: None,
+            signal_filter: RwLock::new(SignalFilterState::default()),
+            event_calendar: None,
+            warmup_gate: None,
+            trade_sizer: None,
+            fingerprint_buffers: ObjectPool::default(),
+            conflict_buffers: ObjectPool::default(),
         }
     }
     
+    /// Apply the event calendar's trading pauses and size reductions to signals
+    pub fn with_event_calendar(mut self, event_calendar: Arc<EventCalendar>) -> Self {
+        self.event_calendar = Some(event_calendar);
+        self
+    }
+    
+    /// Refuse entry signals on symbols that are still warming up
+    pub fn with_warmup_gate(mut self, warmup_gate: Arc<WarmupGate>) -> Self {
+        self.warmup_gate = Some(warmup_gate);
+        self
+    }
+    
+    /// Size new positions through a trade sizer, tapering them by the strategy's risk budget
+    pub fn with_trade_sizer(mut self, trade_sizer: Arc<DynamicTradeSizer>) -> Self {
+        self.trade_sizer = Some(trade_sizer);
+        self
+    }
+    
+    /// Report evaluation budget overruns to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+    
     /// Execute a signal
     pub async fn execute_strategy(&self, signal: &Signal) -> Result<ExecutionResult, StrategyEngineError> {
         // Check if signal has expired
@@ -252,7 +591,18 @@ impl StrategyEngine {
             return Err(StrategyEngineError::SignalExpired);
         }
         
-        // Evaluate signal
+        // Symbols without stable history take no new positions; exits always proceed
+        if let Some(warmup_gate) = &self.warmup_gate {
+            if !signal.is_exit() && !warmup_gate.is_ready(&signal.symbol) {
+                return Err(StrategyEngineError::SymbolWarmingUp(signal.symbol.clone()));
+            }
+        }
+        
+        // Drop duplicate and rapid-fire signals before they reach the execution layer
+        self.filter_signal(signal, Utc::now())?;
+        
+        // Evaluate signal; evaluation budgets are enforced where strategies generate signals,
+        // so a signal that got this far, exits included, is never dropped for its strategy's budget
         let evaluation = self.evaluate_signal(signal).await?;
         
         // Check if evaluation passed
@@ -263,7 +613,37 @@ impl StrategyEngine {
         }
         
         // Create order from signal
-        let order = self.create_order_from_signal(signal, &evaluation)?;
+        let mut order = self.create_order_from_signal(signal, &evaluation)?;
+        
+        // Pause or shrink new positions around scheduled high-impact events; exits always proceed
+        if let Some(calendar) = &self.event_calendar {
+            if !signal.is_exit() {
+                let restriction = calendar.restriction(&signal.strategy_id, &signal.symbol, Utc::now()).await;
+                if restriction.paused {
+                    info!("Signal {} paused around events: {:?}", signal.id, restriction.events);
+                    return Err(StrategyEngineError::TradingPaused(restriction.events.join(", ")));
+                }
+                if restriction.size_multiplier < 1.0 {
+                    debug!("Signal {} size scaled by {} around events: {:?}", signal.id, restriction.size_multiplier, restriction.events);
+                    order.amount *= restriction.size_multiplier;
+                }
+            }
+        }
+        
+        // Scale new positions by volatility and the strategy's remaining risk budget; exits always proceed
+        if let Some(trade_sizer) = &self.trade_sizer {
+            if !signal.is_exit() {
+                order.amount = trade_sizer
+                    .calculate_strategy_position_size(&signal.strategy_id, &signal.symbol, order.amount)
+                    .await
+                    .map_err(|e| StrategyEngineError::Internal(e.to_string()))?;
+                if order.amount <= 0.0 {
+                    return Err(StrategyEngineError::RiskCheckFailed(format!(
+                        "{} has exhausted its risk budget", signal.strategy_id
+                    )));
+                }
+            }
+        }
         
         // Execute order
         let execution_start = Utc::now();
@@ -281,6 +661,9 @@ impl StrategyEngine {
         // Calculate latency
         let execution_latency = (Utc::now() - execution_start).num_milliseconds() as u64;
         
+        // Only executed signals start a cooldown, so a failed signal can be retried
+        self.record_executed_signal(signal, Utc::now());
+        
         // Update metrics
         self.update_metrics_for_execution(signal, &evaluation, &execution_result, execution_latency).await;
         
@@ -432,6 +815,242 @@ impl StrategyEngine {
         self.config.read().unwrap().clone()
     }
     
+    /// Resolve conflicts, then execute the surviving signals in order
+    pub async fn execute_signals(&self, signals: Vec<Signal>) -> Vec<(Signal, Result<ExecutionResult, StrategyEngineError>)> {
+        let outcome = self.resolve_conflicts(signals);
+        let mut results = Vec::with_capacity(outcome.accepted.len() + outcome.rejected.len());
+        
+        for signal in outcome.accepted {
+            let result = self.execute_strategy(&signal).await;
+            results.push((signal, result));
+        }
+        for signal in outcome.rejected {
+            let reason = signal.reason_chain.last().cloned().unwrap_or_default();
+            results.push((signal, Err(StrategyEngineError::SignalConflict(reason))));
+        }
+        
+        results
+    }
+    
+    /// Resolve opposing entry signals on the same symbol according to the symbol's policy.
+    ///
+    /// Exits, holds and unopposed entries pass through unchanged. Every signal involved
+    /// in a conflict has the outcome appended to its reason chain.
+    pub fn resolve_conflicts(&self, signals: Vec<Signal>) -> ConflictOutcome {
+        let engine_config = self.config.read().unwrap();
+        let config = &engine_config.conflict_resolution;
+        let mut outcome = ConflictOutcome::default();
+        if !config.enabled {
+            outcome.accepted = signals;
+            return outcome;
+        }
+        
+        let mut entries_by_symbol = self.conflict_buffers.acquire();
+        for signal in signals {
+            let directional = signal.action == SignalAction::Enter && signal.direction != PositionDirection::Neutral;
+            if directional {
+                entries_by_symbol.entry(signal.symbol.clone()).or_default().push(signal);
+            } else {
+                outcome.accepted.push(signal);
+            }
+        }
+        
+        for (symbol, entries) in entries_by_symbol.drain() {
+            let (longs, shorts): (Vec<Signal>, Vec<Signal>) = entries
+                .into_iter()
+                .partition(|signal| signal.direction == PositionDirection::Long);
+            if longs.is_empty() || shorts.is_empty() {
+                outcome.accepted.extend(longs);
+                outcome.accepted.extend(shorts);
+                continue;
+            }
+            
+            let policy = config.policy_for(&symbol);
+            info!("Resolving {} long vs {} short signals on {} by {}", longs.len(), shorts.len(), symbol, policy);
+            match policy {
+                ConflictPolicy::RejectAll => {
+                    let reason = format!("conflict:{}: opposing signals on {}", policy, symbol);
+                    Self::reject_all(longs, &reason, &mut outcome);
+                    Self::reject_all(shorts, &reason, &mut outcome);
+                }
+                ConflictPolicy::PriorityByTrust => {
+                    let trust = |side: &[Signal]| side
+                        .iter()
+                        .map(|s| s.average_trust_score().unwrap_or(config.default_trust_score))
+                        .fold(0.0, f64::max);
+                    let (long_trust, short_trust) = (trust(&longs), trust(&shorts));
+                    
+                    if (long_trust - short_trust).abs() < f64::EPSILON {
+                        let reason = format!("conflict:{}: tied at trust {:.2} on {}", policy, long_trust, symbol);
+                        Self::reject_all(longs, &reason, &mut outcome);
+                        Self::reject_all(shorts, &reason, &mut outcome);
+                        continue;
+                    }
+                    
+                    let (winners, losers, won, lost) = if long_trust > short_trust {
+                        (longs, shorts, long_trust, short_trust)
+                    } else {
+                        (shorts, longs, short_trust, long_trust)
+                    };
+                    for mut signal in winners {
+                        signal.push_reason(format!("conflict:{}: kept at trust {:.2} over {:.2}", policy, won, lost));
+                        outcome.accepted.push(signal);
+                    }
+                    let reason = format!("conflict:{}: outranked at trust {:.2} by {:.2}", policy, lost, won);
+                    Self::reject_all(losers, &reason, &mut outcome);
+                }
+                ConflictPolicy::Netting => {
+                    let size = |signal: &Signal| signal.quantity.unwrap_or(signal.strength * signal.confidence).abs();
+                    let long_size: f64 = longs.iter().map(size).sum();
+                    let short_size: f64 = shorts.iter().map(size).sum();
+                    let net = long_size - short_size;
+                    
+                    if net.abs() <= config.min_net_fraction * long_size.max(short_size) {
+                        let reason = format!("conflict:{}: {:.4} long and {:.4} short cancel out on {}", policy, long_size, short_size, symbol);
+                        Self::reject_all(longs, &reason, &mut outcome);
+                        Self::reject_all(shorts, &reason, &mut outcome);
+                        continue;
+                    }
+                    
+                    let (winners, losers, winning_size) = if net > 0.0 {
+                        (longs, shorts, long_size)
+                    } else {
+                        (shorts, longs, short_size)
+                    };
+                    let scale = net.abs() / winning_size;
+                    for mut signal in winners {
+                        signal.quantity = signal.quantity.map(|q| q * scale);
+                        signal.strength *= scale;
+                        signal.push_reason(format!("conflict:{}: scaled by {:.3} to net size {:.4}", policy, scale, net.abs()));
+                        outcome.accepted.push(signal);
+                    }
+                    let reason = format!("conflict:{}: netted against the opposing side", policy);
+                    Self::reject_all(losers, &reason, &mut outcome);
+                }
+            }
+        }
+        
+        outcome
+    }
+    
+    /// Reject signals, recording the reason
+    fn reject_all(signals: Vec<Signal>, reason: &str, outcome: &mut ConflictOutcome) {
+        for mut signal in signals {
+            signal.status = SignalStatus::Rejected;
+            signal.system_code = Some(signal.status.to_system_code().to_string());
+            signal.push_reason(reason);
+            outcome.rejected.push(signal);
+        }
+    }
+    
+    /// Suppressed signal counts by strategy ID
+    pub fn suppressed_signal_counts(&self) -> HashMap<String, SuppressedSignalCounts> {
+        self.signal_filter.read().unwrap().suppressed.clone()
+    }
+    
+    /// Suppress a signal that duplicates a recently executed one or arrives during its cooldown
+    fn filter_signal(&self, signal: &Signal, now: DateTime<Utc>) -> Result<(), StrategyEngineError> {
+        let engine_config = self.config.read().unwrap();
+        let config = &engine_config.signal_filter;
+        // Exits must always be able to close a position, including retries of a failed exit
+        if !config.enabled || (config.exempt_exits && signal.is_exit()) {
+            return Ok(());
+        }
+        
+        let mut state = self.signal_filter.write().unwrap();
+        let dedup_window = chrono::Duration::milliseconds(config.dedup_window_ms as i64);
+        state.fingerprints.retain(|_, accepted_at| now - *accepted_at < dedup_window);
+        
+        let mut fingerprint = self.fingerprint_buffers.acquire();
+        Self::write_signal_fingerprint(&mut fingerprint, signal, config.dedup_precision);
+        if state.fingerprints.contains_key(fingerprint.as_str()) {
+            state.suppressed.entry(signal.strategy_id.clone()).or_default().duplicates += 1;
+            debug!("Suppressed duplicate signal {} from {}", signal.id, signal.strategy_id);
+            return Err(StrategyEngineError::SignalSuppressed(format!(
+                "duplicate of a signal from {} on {}", signal.strategy_id, signal.symbol
+            )));
+        }
+        
+        let key = (signal.strategy_id.clone(), signal.symbol.clone());
+        let cooldown = chrono::Duration::milliseconds(config.cooldown_for(&signal.strategy_id, &signal.symbol) as i64);
+        if let Some(last) = state.last_accepted.get(&key) {
+            if now - *last < cooldown {
+                state.suppressed.entry(signal.strategy_id.clone()).or_default().cooldowns += 1;
+                debug!("Suppressed signal {} from {} during cooldown", signal.id, signal.strategy_id);
+                return Err(StrategyEngineError::SignalSuppressed(format!(
+                    "{} is cooling down on {}", signal.strategy_id, signal.symbol
+                )));
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Start the dedup window and cooldown for a signal that was executed
+    fn record_executed_signal(&self, signal: &Signal, now: DateTime<Utc>) {
+        let engine_config = self.config.read().unwrap();
+        let config = &engine_config.signal_filter;
+        if !config.enabled {
+            return;
+        }
+        
+        let mut fingerprint = self.fingerprint_buffers.acquire();
+        Self::write_signal_fingerprint(&mut fingerprint, signal, config.dedup_precision);
+        let mut state = self.signal_filter.write().unwrap();
+        state.last_accepted.insert((signal.strategy_id.clone(), signal.symbol.clone()), now);
+        state.fingerprints.insert(fingerprint.clone(), now);
+    }
+    
+    /// Write the content fingerprint of a signal, ignoring its ID and timestamps
+    fn write_signal_fingerprint(out: &mut String, signal: &Signal, precision: u32) {
+        let scale = 10f64.powi(precision as i32);
+        let round = |value: Option<f64>| value.map(|v| (v * scale).round() as i64);
+        let _ = write!(
+            out,
+            "{}|{}|{:?}|{:?}|{:?}|{:?}",
+            signal.strategy_id,
+            signal.symbol,
+            signal.action,
+            signal.direction,
+            round(signal.price),
+            round(signal.quantity),
+        );
+    }
+    
+    /// Evaluation budget usage for a strategy
+    pub fn evaluation_budget_status(&self, strategy_id: &str) -> Option<EvaluationBudgetStatus> {
+        self.evaluation_budgets.read().unwrap().get(strategy_id).cloned()
+    }
+    
+    /// Strategies flagged for review after repeatedly overrunning their budget
+    pub fn strategies_flagged_for_review(&self) -> Vec<String> {
+        let mut flagged: Vec<String> = self.evaluation_budgets
+            .read()
+            .unwrap()
+            .values()
+            .filter(|status| status.flagged_for_review)
+            .map(|status| status.strategy_id.clone())
+            .collect();
+        flagged.sort();
+        flagged
+    }
+    
+    /// Clear a strategy's review flag and restore its full evaluation frequency
+    pub fn clear_budget_review(&self, strategy_id: &str) -> bool {
+        let mut budgets = self.evaluation_budgets.write().unwrap();
+        match budgets.get_mut(strategy_id) {
+            Some(status) => {
+                *status = EvaluationBudgetStatus {
+                    strategy_id: strategy_id.to_string(),
+                    throttle_factor: 1,
+                    ..EvaluationBudgetStatus::default()
+                };
+                true
+            }
+            None => false,
+        }
+    }
+    
     /// Apply risk checks to a signal
     async fn apply_risk_checks(&self, signal: &Signal) -> Result<Vec<RiskViolation>, StrategyEngineError> {
         match self.risk_calculator.check_signal(signal).await {
@@ -578,6 +1197,106 @@ impl StrategyEngine {
     }
 }
 
+/// Per-strategy evaluation time budget, consulted wherever strategies are evaluated
+#[async_trait]
+pub trait EvaluationBudget: Send + Sync {
+    /// Whether the strategy should be evaluated this round; throttled strategies skip rounds
+    fn should_evaluate(&self, strategy_id: &str) -> bool;
+    
+    /// Track evaluation time against the strategy's budget, throttling and flagging repeat offenders
+    async fn record_evaluation_time(&self, strategy_id: &str, elapsed_ms: u64);
+}
+
+#[async_trait]
+impl EvaluationBudget for StrategyEngine {
+    fn should_evaluate(&self, strategy_id: &str) -> bool {
+        if !self.config.read().unwrap().evaluation_budget.enabled {
+            return true;
+        }
+        
+        let mut budgets = self.evaluation_budgets.write().unwrap();
+        match budgets.get_mut(strategy_id) {
+            Some(status) if status.throttle_factor > 1 => {
+                if status.skipped + 1 >= status.throttle_factor {
+                    status.skipped = 0;
+                    true
+                } else {
+                    status.skipped += 1;
+                    false
+                }
+            }
+            _ => true,
+        }
+    }
+    
+    async fn record_evaluation_time(&self, strategy_id: &str, elapsed_ms: u64) {
+        let budget = self.config.read().unwrap().evaluation_budget.clone();
+        if !budget.enabled {
+            return;
+        }
+        let budget_ms = budget.budget_for(strategy_id);
+        
+        let (status, newly_throttled, newly_flagged) = {
+            let mut budgets = self.evaluation_budgets.write().unwrap();
+            let status = budgets.entry(strategy_id.to_string()).or_insert_with(|| EvaluationBudgetStatus {
+                strategy_id: strategy_id.to_string(),
+                throttle_factor: 1,
+                ..EvaluationBudgetStatus::default()
+            });
+            status.last_elapsed_ms = elapsed_ms;
+            
+            if elapsed_ms <= budget_ms {
+                status.consecutive_overruns = 0;
+                status.within_budget_streak += 1;
+                if status.throttle_factor > 1 && status.within_budget_streak >= budget.recovery_evaluations {
+                    status.throttle_factor /= 2;
+                    status.within_budget_streak = 0;
+                    info!("Strategy {} back within evaluation budget; throttle factor now {}", strategy_id, status.throttle_factor);
+                }
+                return;
+            }
+            
+            status.consecutive_overruns += 1;
+            status.total_overruns += 1;
+            status.within_budget_streak = 0;
+            
+            let max_factor = budget.max_throttle_factor.max(1);
+            let newly_throttled = status.consecutive_overruns >= budget.overruns_before_throttle
+                && status.throttle_factor < max_factor;
+            if newly_throttled {
+                status.throttle_factor = (status.throttle_factor * 2).min(max_factor);
+                status.consecutive_overruns = 0;
+            }
+            
+            let newly_flagged = !status.flagged_for_review && status.total_overruns >= budget.overruns_before_review;
+            if newly_flagged {
+                status.flagged_for_review = true;
+            }
+            
+            (status.clone(), newly_throttled, newly_flagged)
+        };
+        
+        warn!("Strategy {} evaluation took {}ms (budget {}ms)", strategy_id, elapsed_ms, budget_ms);
+        if newly_throttled {
+            warn!("Strategy {} throttled to one evaluation in {}", strategy_id, status.throttle_factor);
+        }
+        if newly_flagged {
+            error!("Strategy {} flagged for review after {} evaluation budget overruns", strategy_id, status.total_overruns);
+        }
+        
+        if let Some(telemetry) = &self.telemetry {
+            let mut data = HashMap::new();
+            data.insert("strategy_id".to_string(), serde_json::json!(strategy_id));
+            data.insert("elapsed_ms".to_string(), serde_json::json!(elapsed_ms));
+            data.insert("budget_ms".to_string(), serde_json::json!(budget_ms));
+            data.insert("total_overruns".to_string(), serde_json::json!(status.total_overruns));
+            data.insert("throttle_factor".to_string(), serde_json::json!(status.throttle_factor));
+            data.insert("flagged_for_review".to_string(), serde_json::json!(status.flagged_for_review));
+            telemetry.report_custom("strategy_evaluation_budget_exceeded", data).await;
+        }
+    }
+}
+
 /// Create a singleton strategy engine
 pub fn create_strategy_engine(
     router: Arc<SmartOrderRouter>,
@@ -925,4 +1644,142 @@ mod tests {
         assert!(got.is_some());
         assert_eq!(got.unwrap().signal_id, "sig1");
     }
-} 
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_evaluation_budget_throttles_and_flags() {
+        let router = Arc::new(MockRouter::new(None));
+        let risk_calculator = Arc::new(MockRiskCalculator::new(None));
+        let mut config = StrategyEngineConfig::default();
+        config.evaluation_budget = EvaluationBudgetConfig {
+            budget_ms: 10,
+            overruns_before_throttle: 2,
+            max_throttle_factor: 4,
+            overruns_before_review: 6,
+            ..EvaluationBudgetConfig::default()
+        };
+        let engine = create_strategy_engine(router, risk_calculator, Some(config));
+
+        engine.record_evaluation_time("slow", 25).await;
+        engine.record_evaluation_time("slow", 25).await;
+        assert_eq!(engine.evaluation_budget_status("slow").unwrap().throttle_factor, 2);
+        assert!(!engine.should_evaluate("slow"));
+        assert!(engine.should_evaluate("slow"));
+        assert!(!engine.should_evaluate("slow"));
+
+        for _ in 0..4 {
+            engine.record_evaluation_time("slow", 25).await;
+        }
+        let status = engine.evaluation_budget_status("slow").unwrap();
+        assert_eq!(status.throttle_factor, 4);
+        assert!(status.flagged_for_review);
+        assert_eq!(engine.strategies_flagged_for_review(), vec!["slow".to_string()]);
+
+        assert!(engine.clear_budget_review("slow"));
+        assert!(engine.should_evaluate("slow"));
+        assert!(engine.strategies_flagged_for_review().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_signal_dedup_and_cooldown() {
+        let router = Arc::new(MockRouter::new(None));
+        let risk_calculator = Arc::new(MockRiskCalculator::new(None));
+        let mut config = StrategyEngineConfig::default();
+        config.signal_filter.cooldown_rules.push(CooldownRule {
+            strategy_id: "momentum".to_string(),
+            symbol: Some("ETH/USD".to_string()),
+            cooldown_ms: 0,
+        });
+        let engine = create_strategy_engine(router, risk_calculator, Some(config));
+        let now = Utc::now();
+
+        let entry = Signal::new("momentum".to_string(), "BTC/USD".to_string(), SignalAction::Enter).with_price(100.0);
+        assert!(engine.filter_signal(&entry, now).is_ok());
+        engine.record_executed_signal(&entry, now);
+
+        // Same content under a new ID is a duplicate
+        let repeat = Signal::new("momentum".to_string(), "BTC/USD".to_string(), SignalAction::Enter).with_price(100.0);
+        assert!(engine.filter_signal(&repeat, now + chrono::Duration::seconds(5)).is_err());
+
+        // Different price inside the default cooldown is rate limited
+        let rapid = Signal::new("momentum".to_string(), "BTC/USD".to_string(), SignalAction::Enter).with_price(101.0);
+        assert!(engine.filter_signal(&rapid, now + chrono::Duration::milliseconds(200)).is_err());
+
+        // Exits bypass the cooldown and symbols with their own rule are unaffected
+        let exit = Signal::new("momentum".to_string(), "BTC/USD".to_string(), SignalAction::Exit).with_price(101.0);
+        assert!(engine.filter_signal(&exit, now + chrono::Duration::milliseconds(300)).is_ok());
+        engine.record_executed_signal(&exit, now + chrono::Duration::milliseconds(300));
+        assert!(engine.filter_signal(&exit, now + chrono::Duration::milliseconds(400)).is_ok());
+        let eth = Signal::new("momentum".to_string(), "ETH/USD".to_string(), SignalAction::Enter).with_price(10.0);
+        assert!(engine.filter_signal(&eth, now).is_ok());
+
+        let counts = engine.suppressed_signal_counts();
+        assert_eq!(counts["momentum"].duplicates, 1);
+        assert_eq!(counts["momentum"].cooldowns, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_exit_can_be_retried() {
+        let router = Arc::new(MockRouter::new(Some(Err(ExecutionFailureReason::Unknown))));
+        let risk_calculator = Arc::new(MockRiskCalculator::new(None));
+        let engine = create_strategy_engine(router.clone(), risk_calculator, None);
+        let exit = Signal::new("momentum".to_string(), "BTC/USD".to_string(), SignalAction::Exit)
+            .with_direction(PositionDirection::Long)
+            .with_confidence(0.8)
+            .with_strength(0.9)
+            .with_price(100.0);
+
+        // The failed attempt neither counts as a duplicate nor starts a cooldown
+        assert!(matches!(engine.execute_strategy(&exit).await, Err(StrategyEngineError::ExecutionFailed(_))));
+
+        router.set_result(Ok(ExecutionResult {
+            id: "exit-execution".to_string(),
+            signal_id: exit.id.clone(),
+            order_id: Some("exit-order".to_string()),
+            status: ExecutionStatus::Completed,
+            executed_quantity: Some(1.0),
+            average_price: Some(100.0),
+            timestamp: Utc::now(),
+        }));
+        assert!(engine.execute_strategy(&exit).await.is_ok());
+        assert!(engine.suppressed_signal_counts().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_conflict_resolution_policies() {
+        let router = Arc::new(MockRouter::new(None));
+        let risk_calculator = Arc::new(MockRiskCalculator::new(None));
+        let mut config = StrategyEngineConfig::default();
+        config.conflict_resolution.groups.push(SymbolGroup {
+            name: "majors".to_string(),
+            symbols: vec!["BTC/USD".to_string()],
+            policy: ConflictPolicy::Netting,
+        });
+        let engine = create_strategy_engine(router, risk_calculator, Some(config));
+
+        let entry = |strategy: &str, symbol: &str, direction: PositionDirection, quantity: f64| {
+            let mut signal = Signal::new(strategy.to_string(), symbol.to_string(), SignalAction::Enter)
+                .with_direction(direction);
+            signal.quantity = Some(quantity);
+            signal
+        };
+
+        // Netting on BTC: 3 long against 1 short leaves 2 long
+        let outcome = engine.resolve_conflicts(vec![
+            entry("trend", "BTC/USD", PositionDirection::Long, 3.0),
+            entry("revert", "BTC/USD", PositionDirection::Short, 1.0),
+        ]);
+        assert_eq!(outcome.accepted.len(), 1);
+        assert_eq!(outcome.accepted[0].quantity, Some(2.0));
+        assert!(outcome.accepted[0].reason_chain[0].starts_with("conflict:netting"));
+        assert_eq!(outcome.rejected[0].strategy_id, "revert");
+
+        // Default policy on ETH: equal trust rejects both sides
+        let outcome = engine.resolve_conflicts(vec![
+            entry("trend", "ETH/USD", PositionDirection::Long, 1.0),
+            entry("revert", "ETH/USD", PositionDirection::Short, 1.0),
+        ]);
+        assert!(outcome.accepted.is_empty());
+        assert_eq!(outcome.rejected.len(), 2);
+        assert!(outcome.rejected.iter().all(|s| s.status == SignalStatus::Rejected));
+    }
+}
@@ -12,11 +12,12 @@
 // The above copyright notice and this permission notice shall be included in all
 // copies or substantial portions of the Software.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::Duration as StdDuration;
 use std::any::Any;
 use std::fmt;
+use std::panic::AssertUnwindSafe;
 
 use async_trait::async_trait;
 use tokio::time;
@@ -24,6 +25,7 @@ use tracing::{debug, error, info, warn, trace};
 use chrono::Utc;
 use thiserror::Error;
 use futures::executor;
+use futures::FutureExt;
 use serde_json;
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
@@ -41,6 +43,15 @@ use crate::execution_metrics::{ExecutionMetricsCollector};
 use crate::strategy_attribution::{AttributionEngine, StrategyAttribution};
 use crate::factor_analysis::{FactorAnalysisEngine, FactorAlert, FactorAlertType, StrategyFactorProfile};
 use crate::governance::{GovernanceEnforcer, GovernanceActionType, EnforcementResult};
+use crate::healing_orchestrator::{HealingIncident, IncidentKind, StrategyQuarantine};
+use crate::incidents::{IncidentEvidence, IncidentManager};
+use crate::strategy_dependencies::{DependencyError, StrategyDependencyGraph};
+use crate::order_throttle::{OrderThrottle, OrderThrottleConfig, ThrottleDecision, ThrottleStats};
+use crate::runaway_detector::{signal_side, RunawayDetector, RunawayEvidence, StrategyActivity};
+use crate::order_router::OrderRouter;
+use crate::warmup::WarmupGate;
+use crate::strategy_engine::EvaluationBudget;
+use crate::trust_monitor::{SlaMonitor, SlaTier, TrustMonitor};
 
 /// Errors that can occur during strategy execution
 #[derive(Debug, Error)]
@@ -57,6 +68,9 @@ pub enum ExecutorError {
     #[error("Strategy not found: {name}")]
     StrategyNotFound { name: String },
     
+    #[error("Dependency error: {0}")]
+    Dependency(#[from] DependencyError),
+    
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -80,8 +94,12 @@ pub struct StrategyExecutorConfig {
     pub strategy_execution_timeout_ms: u64,
     /// Execution mode (live, paper, sandbox)
     pub execution_mode: ExecutionMode,
+    /// Strategies that paper trade whatever `execution_mode` is
+    pub paper_trading_strategies: HashSet<StrategyId>,
     /// Trust policy configuration
     pub trust_policy: TrustPolicyConfig,
+    /// Per-strategy order rate limits enforced before routing
+    pub order_throttle: OrderThrottleConfig,
 }
 
 /// Trust policy thresholds
@@ -119,7 +137,20 @@ impl Default for StrategyExecutorConfig {
             max_consecutive_errors: 3,
             strategy_execution_timeout_ms: 2000,
             execution_mode: ExecutionMode::Paper,
+            paper_trading_strategies: HashSet::new(),
             trust_policy: TrustPolicyConfig::default(),
+            order_throttle: OrderThrottleConfig::default(),
+        }
+    }
+}
+
+impl StrategyExecutorConfig {
+    /// Execution mode for a strategy's orders
+    pub fn execution_mode_for(&self, strategy_id: &str) -> ExecutionMode {
+        if self.paper_trading_strategies.contains(strategy_id) {
+            ExecutionMode::Paper
+        } else {
+            self.execution_mode
         }
     }
 }
@@ -160,6 +191,12 @@ struct StrategyExecutionState {
     metadata: Option<HashMap<String, String>>,
     /// Last factor analysis timestamp
     last_factor_analysis: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the strategy is enabled for execution
+    enabled: bool,
+    /// Reason the strategy is quarantined, if it is
+    quarantine_reason: Option<String>,
+    /// Quarantined strategy whose quarantine disabled this strategy
+    disabled_by: Option<StrategyId>,
 }
 
 impl Default for StrategyExecutionState {
@@ -173,6 +210,9 @@ impl Default for StrategyExecutionState {
             trust_state: StrategyTrustState::default(),
             metadata: Some(HashMap::new()),
             last_factor_analysis: None,
+            enabled: true,
+            quarantine_reason: None,
+            disabled_by: None,
         }
     }
 }
@@ -205,6 +245,24 @@ pub struct StrategyExecutor {
     factor_analysis_engine: Option<Arc<dyn FactorAnalysisEngine>>,
     /// Optional governance enforcer for meta-protocol rule enforcement
     governance_enforcer: Option<Arc<dyn GovernanceEnforcer>>,
+    /// Declared dependencies between strategies
+    dependency_graph: Arc<RwLock<StrategyDependencyGraph>>,
+    /// Optional incident manager that records strategy quarantines
+    incident_manager: Option<Arc<IncidentManager>>,
+    /// Per-strategy order throttle
+    order_throttle: Arc<OrderThrottle>,
+    /// Optional detector quarantining strategies that exhibit runaway behaviour
+    runaway_detector: Option<Arc<RunawayDetector>>,
+    /// Optional order router used to cancel a quarantined strategy's resting orders
+    order_router: Option<Arc<OrderRouter>>,
+    /// Optional warm-up gate keeping new symbols out of signal generation
+    warmup_gate: Option<Arc<WarmupGate>>,
+    /// Optional evaluation time budget throttling slow strategies
+    evaluation_budget: Option<Arc<dyn EvaluationBudget>>,
+    /// Optional SLA monitor whose tier gates and scales the strategy's orders
+    sla_monitor: Option<Arc<SlaMonitor>>,
+    /// Optional trust monitor feeding trust scores into the SLA monitor
+    trust_monitor: Option<Arc<dyn TrustMonitor>>,
 }
 
 impl StrategyExecutor {
@@ -265,6 +323,7 @@ impl StrategyExecutor {
         }
 
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -272,12 +331,20 @@ impl StrategyExecutor {
             execution_service,
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(states)),
+            order_throttle: Arc::new(OrderThrottle::new(config.order_throttle.clone())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             config,
             drawdown_tracker,
             execution_metrics: None,
             attribution_engine: None,
             factor_analysis_engine: None,
             governance_enforcer: None,
+            incident_manager: None,
         }
     }
 
@@ -291,6 +358,7 @@ impl StrategyExecutor {
         execution_metrics: Arc<dyn ExecutionMetricsCollector>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -299,11 +367,19 @@ impl StrategyExecutor {
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
             config: StrategyExecutorConfig::default(),
+            order_throttle: Arc::new(OrderThrottle::new(OrderThrottleConfig::default())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             drawdown_tracker: None,
             execution_metrics: Some(execution_metrics),
             attribution_engine: None,
             factor_analysis_engine: None,
             governance_enforcer: None,
+            incident_manager: None,
         }
     }
 
@@ -318,6 +394,7 @@ impl StrategyExecutor {
         execution_metrics: Arc<dyn ExecutionMetricsCollector>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -326,11 +403,19 @@ impl StrategyExecutor {
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
             config: StrategyExecutorConfig::default(),
+            order_throttle: Arc::new(OrderThrottle::new(OrderThrottleConfig::default())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             drawdown_tracker: Some(drawdown_tracker),
             execution_metrics: Some(execution_metrics),
             attribution_engine: None,
             factor_analysis_engine: None,
             governance_enforcer: None,
+            incident_manager: None,
         }
     }
 
@@ -344,6 +429,7 @@ impl StrategyExecutor {
         attribution_engine: Arc<dyn AttributionEngine>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -352,11 +438,19 @@ impl StrategyExecutor {
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
             config: StrategyExecutorConfig::default(),
+            order_throttle: Arc::new(OrderThrottle::new(OrderThrottleConfig::default())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             drawdown_tracker: None,
             execution_metrics: None,
             attribution_engine: Some(attribution_engine),
             factor_analysis_engine: None,
             governance_enforcer: None,
+            incident_manager: None,
         }
     }
     
@@ -375,6 +469,7 @@ impl StrategyExecutor {
         governance_enforcer: Option<Arc<dyn GovernanceEnforcer>>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -382,6 +477,13 @@ impl StrategyExecutor {
             execution_service,
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
+            order_throttle: Arc::new(OrderThrottle::new(config.order_throttle.clone())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             config,
             drawdown_tracker,
             execution_metrics,
@@ -403,6 +505,7 @@ impl StrategyExecutor {
         execution_metrics: Option<Arc<dyn ExecutionMetricsCollector>>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -410,12 +513,20 @@ impl StrategyExecutor {
             execution_service,
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
+            order_throttle: Arc::new(OrderThrottle::new(config.order_throttle.clone())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             config,
             drawdown_tracker,
             execution_metrics,
             attribution_engine: None,
             factor_analysis_engine: None,
             governance_enforcer: None,
+            incident_manager: None,
         }
     }
 
@@ -429,6 +540,7 @@ impl StrategyExecutor {
         factor_analysis_engine: Arc<dyn FactorAnalysisEngine>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -437,11 +549,19 @@ impl StrategyExecutor {
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
             config: StrategyExecutorConfig::default(),
+            order_throttle: Arc::new(OrderThrottle::new(OrderThrottleConfig::default())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             drawdown_tracker: None,
             execution_metrics: None,
             attribution_engine: None,
             factor_analysis_engine: Some(factor_analysis_engine),
             governance_enforcer: None,
+            incident_manager: None,
         }
     }
 
@@ -455,6 +575,7 @@ impl StrategyExecutor {
         governance_enforcer: Arc<dyn GovernanceEnforcer>,
     ) -> Self {
         Self {
+            dependency_graph: Arc::new(RwLock::new(StrategyDependencyGraph::from_strategies(&strategies))),
             strategies: Arc::new(RwLock::new(strategies)),
             risk_manager,
             entropy_injector,
@@ -463,18 +584,75 @@ impl StrategyExecutor {
             strategy_states: Arc::new(Mutex::new(HashMap::new())),
             execution_states: Arc::new(RwLock::new(HashMap::new())),
             config: StrategyExecutorConfig::default(),
+            order_throttle: Arc::new(OrderThrottle::new(OrderThrottleConfig::default())),
+            runaway_detector: None,
+            order_router: None,
+            warmup_gate: None,
+            evaluation_budget: None,
+            sla_monitor: None,
+            trust_monitor: None,
             drawdown_tracker: None,
             execution_metrics: None,
             attribution_engine: None,
             factor_analysis_engine: None,
             governance_enforcer: Some(governance_enforcer),
+            incident_manager: None,
         }
     }
 
+    /// Open incidents for strategy quarantines
+    pub fn with_incident_manager(mut self, incident_manager: Arc<IncidentManager>) -> Self {
+        self.incident_manager = Some(incident_manager);
+        self
+    }
+    
+    /// Set the runaway detector
+    pub fn with_runaway_detector(mut self, runaway_detector: Arc<RunawayDetector>) -> Self {
+        self.runaway_detector = Some(runaway_detector);
+        self
+    }
+    
+    /// Set the order router used to cancel orders of quarantined strategies
+    pub fn with_order_router(mut self, order_router: Arc<OrderRouter>) -> Self {
+        self.order_router = Some(order_router);
+        self
+    }
+    
+    /// Generate no signals for symbols that are still warming up
+    pub fn with_warmup_gate(mut self, warmup_gate: Arc<WarmupGate>) -> Self {
+        self.warmup_gate = Some(warmup_gate);
+        self
+    }
+    
+    /// Time each strategy's signal generation against an evaluation budget
+    pub fn with_evaluation_budget(mut self, evaluation_budget: Arc<dyn EvaluationBudget>) -> Self {
+        self.evaluation_budget = Some(evaluation_budget);
+        self
+    }
+    
+    /// Track strategies against their SLA and size their orders by execution tier
+    pub fn with_sla_monitor(mut self, sla_monitor: Arc<SlaMonitor>) -> Self {
+        self.sla_monitor = Some(sla_monitor);
+        self
+    }
+    
+    /// Feed the SLA monitor trust scores from a trust monitor instead of the risk manager
+    pub fn with_trust_monitor(mut self, trust_monitor: Arc<dyn TrustMonitor>) -> Self {
+        self.trust_monitor = Some(trust_monitor);
+        self
+    }
+
     /// Executes a complete strategy cycle, analyzing market data and generating signals
     pub async fn execute_cycle(&self, market_data: &MarketData) -> Vec<ExecutionResult> {
         let mut results = Vec::new();
         
+        // Symbols without stable history are ineligible for new entries; strategies
+        // still run so positions held across a reset can be exited
+        let warming_up = match &self.warmup_gate {
+            Some(warmup_gate) => !warmup_gate.observe(market_data).await,
+            None => false,
+        };
+        
         // Get a read lock on strategies
         let strategies = match self.strategies.read() {
             Ok(guard) => guard,
@@ -487,10 +665,24 @@ impl StrategyExecutor {
         for strategy in strategies.iter() {
             let strategy_id = strategy.id();
             
+            // Skip strategies that are disabled or whose dependencies are not running
+            if !self.is_strategy_enabled(&strategy_id) {
+                trace!("Skipping disabled strategy {}", strategy_id);
+                continue;
+            }
+            
+            // Re-evaluate the strategy's SLA tier; disabled strategies sit out entirely
+            let sla_tier = self.evaluate_sla_tier(&strategy_id).await;
+            if sla_tier == SlaTier::Disabled {
+                trace!("Skipping strategy {}: disabled by SLA tier", strategy_id);
+                continue;
+            }
+            
             // Check if we should skip this strategy due to health
             if let Some(should_skip) = self.should_skip_strategy(&strategy_id) {
                 if should_skip {
                     debug!("Skipping strategy {} due to health status", strategy_id);
+                    self.record_sla_downtime(&strategy_id).await;
                     continue;
                 }
             }
@@ -498,6 +690,7 @@ impl StrategyExecutor {
             // Check if strategy is disabled by risk manager
             if self.risk_manager.is_strategy_disabled(&strategy_id) {
                 debug!("Skipping strategy {} due to risk cooldown period", strategy_id);
+                self.record_sla_downtime(&strategy_id).await;
                 self.telemetry.report_risk_limit(
                     &strategy_id,
                     &RiskError::StrategyDisabled(format!("Strategy {} is in cooldown period", strategy_id))
@@ -621,6 +814,15 @@ impl StrategyExecutor {
                 }
             }
             
+            // Strategies throttled for overrunning their budget sit out some rounds;
+            // they still run every few rounds, so their exits are delayed rather than lost
+            if let Some(budget) = &self.evaluation_budget {
+                if !budget.should_evaluate(&strategy_id) {
+                    trace!("Skipping {} for {}: throttled by evaluation budget", market_data.symbol, strategy_id);
+                    continue;
+                }
+            }
+            
             // Report telemetry before execution
             self.telemetry.report_execution_start(&strategy_id).await;
             
@@ -630,7 +832,13 @@ impl StrategyExecutor {
             });
             
             // Analyze market data with strategy
-            let signal = match self.execute_strategy_with_timeout(strategy.as_ref(), market_data).await {
+            let evaluation_start = std::time::Instant::now();
+            let evaluation = self.execute_strategy_with_timeout(strategy.as_ref(), market_data).await;
+            if let Some(budget) = &self.evaluation_budget {
+                budget.record_evaluation_time(&strategy_id, evaluation_start.elapsed().as_millis() as u64).await;
+            }
+            self.record_sla_observation(&strategy_id, evaluation.is_err()).await;
+            let signal = match evaluation {
                 Ok(Some(signal)) => signal,
                 Ok(None) => {
                     // No signal generated, continue to next strategy
@@ -640,6 +848,10 @@ impl StrategyExecutor {
                     });
                     continue;
                 },
+                Err(StrategyError::Panicked(message)) => {
+                    self.handle_strategy_panic(&strategy_id, &message).await;
+                    continue;
+                }
                 Err(e) => {
                     self.telemetry.report_error(&strategy_id, &e).await;
                     self.handle_strategy_error(&strategy_id, &e).await;
@@ -647,6 +859,11 @@ impl StrategyExecutor {
                 }
             };
             
+            if warming_up && !signal.is_exit() {
+                trace!("Dropping {} signal from {}: still warming up", market_data.symbol, strategy_id);
+                continue;
+            }
+            
             // Apply entropy to signal (for unpredictability) if enabled
             let modified_signal = if self.config.apply_entropy {
                 if let Some(injector) = &self.entropy_injector {
@@ -670,6 +887,18 @@ impl StrategyExecutor {
             // Update signal status
             final_signal.update_status(SignalStatus::Created);
             
+            // Freeze the signal if it completes a runaway pattern
+            let activity = StrategyActivity::Signal {
+                symbol: final_signal.symbol.clone(),
+                action: final_signal.action.clone(),
+                direction: final_signal.direction,
+            };
+            if self.record_strategy_activity(&strategy_id, activity).await {
+                final_signal.update_status(SignalStatus::Rejected);
+                self.record_sla_observation(&strategy_id, true).await;
+                continue;
+            }
+            
             // Validate signal with risk manager
             if let Err(risk_error) = self.risk_manager.validate_signal(&strategy_id, &final_signal, market_data) {
                 final_signal.update_status(SignalStatus::Rejected);
@@ -681,7 +910,22 @@ impl StrategyExecutor {
             }
             
             // Signal passed risk validation, calculate position size
-            let position_sizing = self.risk_manager.calculate_position_size(&strategy_id, &final_signal, market_data);
+            let mut position_sizing = self.risk_manager.calculate_position_size(&strategy_id, &final_signal, market_data);
+            
+            // Shadow strategies keep generating signals so they can earn their way back,
+            // but nothing they produce is routed
+            if !sla_tier.can_trade() {
+                final_signal.update_status(SignalStatus::Rejected);
+                debug!("Signal from strategy {} not routed: {:?} SLA tier", strategy_id, sla_tier);
+                
+                let mut data = HashMap::new();
+                data.insert("strategy_id".to_string(), serde_json::to_value(&strategy_id).unwrap());
+                data.insert("symbol".to_string(), serde_json::to_value(&final_signal.symbol).unwrap());
+                data.insert("size".to_string(), serde_json::to_value(position_sizing.adjusted_size).unwrap());
+                self.telemetry.report_custom("sla_shadow_signal", data).await;
+                continue;
+            }
+            position_sizing.adjusted_size *= sla_tier.allocation_multiplier();
             
             // Set adjusted position size from risk manager
             final_signal.set_size(position_sizing.adjusted_size);
@@ -699,11 +943,28 @@ impl StrategyExecutor {
                 position_sizing.max_size
             );
             
+            // Enforce the per-strategy order throttle before routing
+            if let ThrottleDecision::Throttled { retry_after_ms, alert } = self.order_throttle.try_acquire(&strategy_id) {
+                final_signal.update_status(SignalStatus::Rejected);
+                debug!("Signal from strategy {} throttled, retry after {}ms", strategy_id, retry_after_ms);
+                
+                let mut data = HashMap::new();
+                data.insert("strategy_id".to_string(), serde_json::to_value(&strategy_id).unwrap());
+                data.insert("retry_after_ms".to_string(), serde_json::to_value(retry_after_ms).unwrap());
+                self.telemetry.report_custom("order_throttled", data).await;
+                
+                if alert {
+                    self.report_throttle_alert(&strategy_id).await;
+                }
+                continue;
+            }
+            
             // Execute the signal
             match self.execute_signal(&final_signal, position_sizing).await {
                 Ok(result) => {
                     // Process execution result
                     self.update_strategy_state(&strategy_id, &final_signal, &result).await;
+                    self.record_execution_activity(&strategy_id, &final_signal, &result).await;
                     results.push(result);
                 }
                 Err(e) => {
@@ -716,15 +977,113 @@ impl StrategyExecutor {
         results
     }
     
+    /// Feed an order and its fill to the runaway detector
+    async fn record_execution_activity(&self, strategy_id: &StrategyId, signal: &Signal, result: &ExecutionResult) {
+        if let Some(order_id) = &result.order_id {
+            let activity = StrategyActivity::OrderSubmitted {
+                order_id: order_id.clone(),
+                symbol: signal.symbol.clone(),
+            };
+            if self.record_strategy_activity(strategy_id, activity).await {
+                return;
+            }
+        }
+        
+        let side = signal_side(&signal.action, signal.direction);
+        if let (Some(side), Some(quantity)) = (side, result.executed_quantity) {
+            if quantity > 0.0 {
+                let activity = StrategyActivity::Fill {
+                    symbol: signal.symbol.clone(),
+                    quantity: quantity * side as f64,
+                };
+                self.record_strategy_activity(strategy_id, activity).await;
+            }
+        }
+    }
+    
+    /// Quarantine a runaway strategy, cancel its orders and raise a healing incident
+    async fn handle_runaway(&self, evidence: RunawayEvidence) {
+        let strategy_id = &evidence.strategy_id;
+        let reason = evidence.summary();
+        warn!("Strategy {} detected as runaway: {}", strategy_id, reason);
+        
+        let quarantined = match self.quarantine_strategy(strategy_id, &reason).await {
+            Ok(ids) => ids,
+            Err(e) => {
+                error!("Failed to quarantine runaway strategy {}: {}", strategy_id, e);
+                Vec::new()
+            }
+        };
+        
+        let mut cancelled = 0;
+        if let Some(router) = &self.order_router {
+            for id in &quarantined {
+                let result = router.cancel_strategy_orders(id).await;
+                cancelled += result.succeeded;
+                if result.failed > 0 {
+                    warn!("Failed to cancel {} orders of quarantined strategy {}", result.failed, id);
+                }
+            }
+        }
+        
+        let evidence_value = serde_json::to_value(&evidence).ok();
+        let mut data = HashMap::new();
+        data.insert("strategy_id".to_string(), serde_json::to_value(strategy_id).unwrap());
+        data.insert("pattern".to_string(), serde_json::to_value(evidence.pattern).unwrap());
+        data.insert("quarantined".to_string(), serde_json::to_value(&quarantined).unwrap());
+        data.insert("cancelled_orders".to_string(), serde_json::to_value(cancelled).unwrap());
+        if let Some(value) = &evidence_value {
+            data.insert("evidence".to_string(), value.clone());
+        }
+        let snapshot = IncidentEvidence::telemetry_snapshot(
+            "strategy_runaway",
+            serde_json::to_value(&data).unwrap_or_default(),
+            evidence.detected_at,
+        );
+        self.telemetry.report_custom("strategy_runaway", data).await;
+        
+        let incident = HealingIncident {
+            strategy_id: strategy_id.clone(),
+            kind: IncidentKind::Runaway,
+            message: reason,
+            quarantined,
+            occurred_at: evidence.detected_at.timestamp_millis(),
+            evidence: evidence_value,
+        };
+        if let Some(incidents) = &self.incident_manager {
+            incidents.report_quarantine(&incident, vec![snapshot]).await;
+        }
+    }
+    
+    /// Raise an alert for a strategy that keeps hitting its order throttle
+    async fn report_throttle_alert(&self, strategy_id: &StrategyId) {
+        let stats = self.order_throttle.stats(strategy_id).unwrap_or_default();
+        warn!(
+            "Strategy {} persistently throttled: {} orders throttled in the last {}s, possible runaway loop",
+            strategy_id,
+            stats.throttled_in_window,
+            self.config.order_throttle.alert_window_secs
+        );
+        
+        let mut data = HashMap::new();
+        data.insert("strategy_id".to_string(), serde_json::to_value(strategy_id).unwrap());
+        data.insert("throttled_in_window".to_string(), serde_json::to_value(stats.throttled_in_window).unwrap());
+        data.insert("throttled_total".to_string(), serde_json::to_value(stats.throttled).unwrap());
+        data.insert("window_secs".to_string(), serde_json::to_value(self.config.order_throttle.alert_window_secs).unwrap());
+        self.telemetry.report_custom("order_throttle_alert", data).await;
+    }
+    
     /// Execute a strategy with timeout protection
     async fn execute_strategy_with_timeout(&self, strategy: &dyn Strategy, market_data: &MarketData) 
         -> Result<Option<Signal>, StrategyError> {
         // Create a timeout future
         let timeout_duration = StdDuration::from_millis(self.config.strategy_execution_timeout_ms);
         
-        // Execute the strategy with timeout
-        match time::timeout(timeout_duration, strategy.generate_signal(market_data)).await {
-            Ok(result) => {
+        // Execute the strategy with timeout, containing any panic to this strategy
+        let evaluation = AssertUnwindSafe(strategy.generate_signal(market_data)).catch_unwind();
+        match time::timeout(timeout_duration, evaluation).await {
+            Ok(Err(payload)) => Err(StrategyError::Panicked(panic_message(payload.as_ref()))),
+            Ok(Ok(result)) => {
                 // Strategy completed within timeout
                 result.map_err(|e| StrategyError::SignalGenerationError(e))
             },
@@ -739,6 +1098,39 @@ impl StrategyExecutor {
         }
     }
     
+    /// Quarantine a strategy whose evaluation panicked and raise a healing incident
+    async fn handle_strategy_panic(&self, strategy_id: &StrategyId, message: &str) {
+        error!("Strategy {} panicked during evaluation: {}", strategy_id, message);
+        
+        let error = StrategyError::Panicked(message.to_string());
+        self.telemetry.report_error(strategy_id, &error).await;
+        self.update_execution_state(strategy_id, |state| {
+            state.consecutive_errors += 1;
+            state.health = StrategyHealth::Critical;
+        });
+        
+        let reason = format!("panicked: {}", message);
+        let quarantined = match self.quarantine_strategy(strategy_id, &reason).await {
+            Ok(ids) => ids,
+            Err(e) => {
+                error!("Failed to quarantine panicking strategy {}: {}", strategy_id, e);
+                Vec::new()
+            }
+        };
+        
+        let incident = HealingIncident {
+            strategy_id: strategy_id.clone(),
+            kind: IncidentKind::Panic,
+            message: message.to_string(),
+            quarantined,
+            occurred_at: Utc::now().timestamp_millis(),
+            evidence: None,
+        };
+        if let Some(incidents) = &self.incident_manager {
+            incidents.report_quarantine(&incident, Vec::new()).await;
+        }
+    }
+    
     /// Handle strategy errors and update metrics
     async fn handle_strategy_error(&self, strategy_id: &StrategyId, error: &StrategyError) {
         // Update execution state to track errors
@@ -795,12 +1187,52 @@ impl StrategyExecutor {
         None
     }
     
+    /// Refresh a strategy's trust score and re-evaluate its SLA tier
+    async fn evaluate_sla_tier(&self, strategy_id: &StrategyId) -> SlaTier {
+        let Some(sla_monitor) = &self.sla_monitor else {
+            return SlaTier::Full;
+        };
+        
+        match &self.trust_monitor {
+            Some(trust_monitor) => {
+                if let Err(e) = sla_monitor.sync_trust(trust_monitor.as_ref(), strategy_id).await {
+                    warn!("Failed to capture trust snapshot for strategy {}: {}", strategy_id, e);
+                }
+            }
+            None => {
+                if let Some(risk_metrics) = self.risk_manager.get_risk_metrics(strategy_id) {
+                    sla_monitor.record_trust_score(strategy_id, risk_metrics.trust_score).await;
+                }
+            }
+        }
+        
+        if let Some(change) = sla_monitor.evaluate(strategy_id, Utc::now().timestamp_millis()).await {
+            info!("Strategy {} moved from {:?} to {:?} SLA tier", strategy_id, change.from, change.to);
+        }
+        sla_monitor.tier(strategy_id).await
+    }
+    
+    /// Count a skipped cycle as downtime against a strategy's SLA
+    async fn record_sla_downtime(&self, strategy_id: &StrategyId) {
+        if let Some(sla_monitor) = &self.sla_monitor {
+            let downtime_secs = (self.config.execution_interval_ms / 1000).max(1) as i64;
+            sla_monitor.record_downtime(strategy_id, downtime_secs, Utc::now().timestamp_millis()).await;
+        }
+    }
+    
+    /// Record whether a strategy's latest evaluation behaved anomalously
+    async fn record_sla_observation(&self, strategy_id: &StrategyId, anomalous: bool) {
+        if let Some(sla_monitor) = &self.sla_monitor {
+            sla_monitor.record_observation(strategy_id, anomalous, Utc::now().timestamp_millis()).await;
+        }
+    }
+    
     /// Execute a validated signal
     async fn execute_signal(&self, signal: &Signal, position_sizing: PositionSizing) -> Result<ExecutionResult, ExecutorError> {
         debug!("Executing signal {} from strategy {}", signal.id, signal.strategy_id);
         
-        // Create execution request
-        let mut request = ExecutionRequest::new(signal.clone(), self.config.execution_mode);
+        // Create execution request; paper-traded strategies never reach a venue
+        let mut request = ExecutionRequest::new(signal.clone(), self.config.execution_mode_for(&signal.strategy_id));
         
         // Add position sizing information to the request parameters
         request = request
@@ -954,6 +1386,13 @@ impl StrategyExecutor {
     pub async fn add_strategy(&self, strategy: Box<dyn Strategy>) -> Result<(), ExecutorError> {
         let strategy_id = strategy.id();
         
+        // Record declared dependencies; rejects self-dependencies and cycles
+        let dependencies = strategy.dependencies();
+        self.dependency_graph
+            .write()
+            .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on dependency graph: {}", e)))?
+            .declare(&strategy_id, dependencies.clone())?;
+        
         // Get risk profile and register with risk manager
         let risk_profile = strategy.get_risk_profile().await;
         self.risk_manager.register_strategy(
@@ -994,7 +1433,13 @@ impl StrategyExecutor {
                 }
             };
             
-            states.insert(strategy_id.clone(), StrategyExecutionState::default());
+            // A strategy only starts enabled if everything it depends on is running
+            let mut state = StrategyExecutionState::default();
+            if let Some(blocking) = dependencies.iter().find(|d| !states.get(*d).map_or(false, |s| s.enabled)) {
+                warn!("Strategy {} added disabled: dependency {} is not enabled", strategy_id, blocking);
+                state.enabled = false;
+            }
+            states.insert(strategy_id.clone(), state);
         }
         
         // Add to strategies collection
@@ -1021,8 +1466,27 @@ impl StrategyExecutor {
         Ok(())
     }
     
-    /// Removes a strategy by ID
-    pub fn remove_strategy(&self, strategy_id: &StrategyId) -> Result<(), ExecutorError> {
+    /// Removes a strategy by ID, handing back the removed strategy
+    pub fn remove_strategy(&self, strategy_id: &StrategyId) -> Result<Box<dyn Strategy>, ExecutorError> {
+        // Refuse to remove a strategy other registered strategies depend on
+        {
+            let mut graph = self.dependency_graph
+                .write()
+                .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on dependency graph: {}", e)))?;
+            
+            let registered = self.list_strategies();
+            let dependents: Vec<StrategyId> = graph
+                .dependents_of(strategy_id)
+                .into_iter()
+                .filter(|d| registered.contains(d))
+                .collect();
+            if !dependents.is_empty() {
+                return Err(DependencyError::HasDependents { strategy: strategy_id.clone(), dependents }.into());
+            }
+            
+            graph.remove(strategy_id);
+        }
+        
         // Unregister from risk manager
         self.risk_manager.unregister_strategy(strategy_id);
         
@@ -1055,7 +1519,7 @@ impl StrategyExecutor {
         }
         
         // Remove from strategies collection
-        {
+        let removed = {
             let mut strategies = match self.strategies.write() {
                 Ok(guard) => guard,
                 Err(e) => {
@@ -1068,8 +1532,8 @@ impl StrategyExecutor {
             let position = strategies.iter().position(|s| s.id() == *strategy_id)
                 .ok_or(ExecutorError::StrategyNotFound { name: strategy_id.clone() })?;
             
-            strategies.remove(position);
-        }
+            strategies.remove(position)
+        };
         
         // Report strategy removed to telemetry
         tokio::spawn({
@@ -1084,7 +1548,7 @@ impl StrategyExecutor {
             }
         });
         
-        Ok(())
+        Ok(removed)
     }
     
     /// Get all strategy performance statistics
@@ -1132,6 +1596,175 @@ impl StrategyExecutor {
         Ok(())
     }
     
+    /// Whether a strategy is enabled for execution
+    pub fn is_strategy_enabled(&self, strategy_id: &StrategyId) -> bool {
+        match self.execution_states.read() {
+            Ok(states) => states.get(strategy_id).map_or(true, |state| state.enabled),
+            Err(_) => false,
+        }
+    }
+    
+    /// Strategies a strategy declares as dependencies
+    pub fn get_strategy_dependencies(&self, strategy_id: &StrategyId) -> Vec<StrategyId> {
+        self.dependency_graph
+            .read()
+            .map(|graph| graph.dependencies_of(strategy_id))
+            .unwrap_or_default()
+    }
+    
+    /// Enable a strategy, enabling its dependencies first.
+    ///
+    /// Returns the strategies that were enabled, in order.
+    pub async fn enable_strategy(&self, strategy_id: &StrategyId) -> Result<Vec<StrategyId>, ExecutorError> {
+        let order = self.dependency_graph
+            .read()
+            .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on dependency graph: {}", e)))?
+            .enable_order(strategy_id);
+        
+        let registered = self.list_strategies();
+        if !registered.contains(strategy_id) {
+            return Err(ExecutorError::StrategyNotFound { name: strategy_id.clone() });
+        }
+        
+        let mut enabled = Vec::new();
+        {
+            let mut states = self.execution_states
+                .write()
+                .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on execution states: {}", e)))?;
+            
+            // Validate the whole chain before changing anything
+            for id in &order {
+                if !registered.contains(id) {
+                    return Err(DependencyError::MissingDependency {
+                        strategy: strategy_id.clone(),
+                        dependency: id.clone(),
+                    }.into());
+                }
+                if states.get(id).map_or(false, |s| s.quarantine_reason.is_some()) {
+                    return Err(DependencyError::QuarantinedDependency {
+                        strategy: strategy_id.clone(),
+                        dependency: id.clone(),
+                    }.into());
+                }
+            }
+            
+            for id in &order {
+                let state = states.entry(id.clone()).or_default();
+                if !state.enabled {
+                    state.enabled = true;
+                    state.disabled_by = None;
+                    enabled.push(id.clone());
+                }
+            }
+        }
+        
+        for id in &enabled {
+            info!("Strategy {} enabled", id);
+            self.telemetry.report_custom("strategy_enabled",
+                HashMap::from([
+                    ("strategy_id", serde_json::to_value(id).unwrap()),
+                    ("requested_by", serde_json::to_value(strategy_id).unwrap()),
+                ])
+            ).await;
+        }
+        
+        Ok(enabled)
+    }
+    
+    /// Disable a strategy, disabling everything that depends on it first.
+    ///
+    /// Returns the strategies that were disabled, in order.
+    pub async fn disable_strategy(&self, strategy_id: &StrategyId) -> Result<Vec<StrategyId>, ExecutorError> {
+        self.disable_with_dependents(strategy_id, None).await
+    }
+    
+    /// Quarantine a strategy: disable it and its dependents, and keep it disabled until released
+    pub async fn quarantine_strategy(&self, strategy_id: &StrategyId, reason: &str) -> Result<Vec<StrategyId>, ExecutorError> {
+        if !self.list_strategies().contains(strategy_id) {
+            return Err(ExecutorError::StrategyNotFound { name: strategy_id.clone() });
+        }
+        
+        let disabled = self.disable_with_dependents(strategy_id, Some(reason)).await?;
+        warn!("Strategy {} quarantined ({}); disabled {:?}", strategy_id, reason, disabled);
+        Ok(disabled)
+    }
+    
+    /// Release a quarantined strategy and re-enable the dependents its quarantine disabled.
+    ///
+    /// Returns the strategies that were enabled, in order.
+    pub async fn release_quarantine(&self, strategy_id: &StrategyId) -> Result<Vec<StrategyId>, ExecutorError> {
+        let dependents = {
+            let mut states = self.execution_states
+                .write()
+                .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on execution states: {}", e)))?;
+            
+            let state = states.get_mut(strategy_id)
+                .ok_or(ExecutorError::StrategyNotFound { name: strategy_id.clone() })?;
+            state.quarantine_reason = None;
+            
+            states.iter()
+                .filter(|(_, s)| s.disabled_by.as_deref() == Some(strategy_id.as_str()))
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>()
+        };
+        
+        let mut enabled = self.enable_strategy(strategy_id).await?;
+        for dependent in dependents {
+            match self.enable_strategy(&dependent).await {
+                Ok(ids) => enabled.extend(ids),
+                Err(e) => warn!("Dependent {} of {} left disabled: {}", dependent, strategy_id, e),
+            }
+        }
+        
+        info!("Strategy {} released from quarantine", strategy_id);
+        Ok(enabled)
+    }
+    
+    /// Disable a strategy and its transitive dependents, dependents first
+    async fn disable_with_dependents(&self, strategy_id: &StrategyId, quarantine_reason: Option<&str>) -> Result<Vec<StrategyId>, ExecutorError> {
+        let order = self.dependency_graph
+            .read()
+            .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on dependency graph: {}", e)))?
+            .disable_order(strategy_id);
+        
+        let registered = self.list_strategies();
+        let mut disabled = Vec::new();
+        {
+            let mut states = self.execution_states
+                .write()
+                .map_err(|e| ExecutorError::Internal(format!("Failed to acquire lock on execution states: {}", e)))?;
+            
+            for id in order.iter().filter(|id| registered.contains(id)) {
+                let state = states.entry(id.clone()).or_default();
+                if id == strategy_id {
+                    if let Some(reason) = quarantine_reason {
+                        state.quarantine_reason = Some(reason.to_string());
+                    }
+                } else if quarantine_reason.is_some() && state.enabled {
+                    state.disabled_by = Some(strategy_id.clone());
+                }
+                
+                if state.enabled {
+                    state.enabled = false;
+                    disabled.push(id.clone());
+                }
+            }
+        }
+        
+        for id in &disabled {
+            info!("Strategy {} disabled", id);
+            self.telemetry.report_custom("strategy_disabled",
+                HashMap::from([
+                    ("strategy_id", serde_json::to_value(id).unwrap()),
+                    ("requested_by", serde_json::to_value(strategy_id).unwrap()),
+                    ("quarantine_reason", serde_json::to_value(quarantine_reason).unwrap()),
+                ])
+            ).await;
+        }
+        
+        Ok(disabled)
+    }
+    
     /// Get trust state for a strategy
     pub fn get_strategy_trust_state(&self, strategy_id: &StrategyId) -> Option<StrategyTrustState> {
         if let Ok(states) = self.execution_states.read() {
@@ -1146,6 +1779,43 @@ impl StrategyExecutor {
         self.risk_manager.get_risk_metrics(strategy_id)
     }
     
+    /// Report strategy activity observed outside the executor, such as cancels.
+    ///
+    /// Returns true if the activity completed a runaway pattern and the strategy
+    /// was quarantined.
+    pub async fn record_strategy_activity(&self, strategy_id: &StrategyId, activity: StrategyActivity) -> bool {
+        let Some(detector) = &self.runaway_detector else {
+            return false;
+        };
+        match detector.record(strategy_id, activity) {
+            Some(evidence) => {
+                self.handle_runaway(evidence).await;
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Get order throttle counters for every strategy
+    pub fn throttle_stats(&self) -> HashMap<StrategyId, ThrottleStats> {
+        self.order_throttle.all_stats()
+    }
+    
+    /// Static description of every strategy (name, description, dependencies, entropy score)
+    pub fn strategy_descriptors(&self) -> Vec<(StrategyId, serde_json::Value)> {
+        match self.strategies.read() {
+            Ok(strategies) => strategies.iter()
+                .map(|strategy| (strategy.id(), serde_json::json!({
+                    "name": strategy.name(),
+                    "description": strategy.description(),
+                    "dependencies": strategy.dependencies(),
+                    "entropy_score": strategy.entropy_score(),
+                })))
+                .collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+    
     /// Get list of all strategies 
     pub fn list_strategies(&self) -> Vec<StrategyId> {
         let mut result = Vec::new();
@@ -1902,6 +2572,28 @@ impl StrategyExecutor {
     }
 }
 
+#[async_trait]
+impl StrategyQuarantine for StrategyExecutor {
+    async fn quarantine(&self, strategy_id: &str, reason: &str) -> anyhow::Result<Vec<String>> {
+        Ok(self.quarantine_strategy(&strategy_id.to_string(), reason).await?)
+    }
+    
+    async fn release(&self, strategy_id: &str) -> anyhow::Result<Vec<String>> {
+        Ok(self.release_quarantine(&strategy_id.to_string()).await?)
+    }
+}
+
+/// Extract a readable message from a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
 /// Provider for market data for strategy execution
 #[async_trait]
 pub trait MarketDataProvider: Send + Sync {
@@ -2311,4 +3003,161 @@ mod tests {
         assert!(result.rejection_details.is_some());
         assert!(result.rejection_details.as_ref().unwrap().contains("Trust score (0.20) below critical threshold (0.30)"));
     }
-} 
\ No newline at end of file
+    
+    // Budget that throttles a fixed set of strategies and records evaluation times
+    struct RecordingBudget {
+        throttled: HashSet<String>,
+        recorded: Mutex<Vec<(String, u64)>>,
+    }
+    
+    #[async_trait]
+    impl EvaluationBudget for RecordingBudget {
+        fn should_evaluate(&self, strategy_id: &str) -> bool {
+            !self.throttled.contains(strategy_id)
+        }
+        
+        async fn record_evaluation_time(&self, strategy_id: &str, elapsed_ms: u64) {
+            self.recorded.lock().unwrap().push((strategy_id.to_string(), elapsed_ms));
+        }
+    }
+    
+    struct SlowStrategy {
+        id: StrategyId,
+    }
+    
+    #[async_trait]
+    impl Strategy for SlowStrategy {
+        async fn generate_signal(&self, _market_data: &crate::market::MarketData)
+            -> Result<Option<Signal>, String> {
+            tokio::time::sleep(StdDuration::from_millis(30)).await;
+            Ok(None)
+        }
+        
+        async fn get_risk_profile(&self) -> RiskProfile {
+            RiskProfile::default()
+        }
+        
+        fn name(&self) -> &str {
+            &self.id
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_evaluation_budget_times_signal_generation() {
+        let budget = Arc::new(RecordingBudget {
+            throttled: HashSet::from(["throttled".to_string()]),
+            recorded: Mutex::new(Vec::new()),
+        });
+        let executor = StrategyExecutor::new(
+            vec![
+                Box::new(SlowStrategy { id: "slow".to_string() }),
+                Box::new(SlowStrategy { id: "throttled".to_string() }),
+            ],
+            Arc::new(MockRiskManager::new()),
+            None,
+            Arc::new(MockTelemetryReporter::new()),
+            Arc::new(MockExecutionService::new()),
+        )
+        .with_evaluation_budget(budget.clone());
+        
+        executor.execute_cycle(&create_test_market_data()).await;
+        
+        // The strategy's own evaluation is timed, and throttled strategies are not run
+        let recorded = budget.recorded.lock().unwrap().clone();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].0, "slow");
+        assert!(recorded[0].1 >= 30);
+    }
+    
+    #[tokio::test]
+    async fn test_sla_tier_scales_and_gates_orders() {
+        let sla_monitor = Arc::new(SlaMonitor::new(crate::trust_monitor::SlaPolicy::default()));
+        sla_monitor.set_tier("reduced", SlaTier::Reduced, "test").await;
+        sla_monitor.set_tier("shadow", SlaTier::Shadow, "test").await;
+        sla_monitor.set_tier("disabled", SlaTier::Disabled, "test").await;
+        
+        let strategies: Vec<Box<dyn Strategy>> = ["full", "reduced", "shadow", "disabled"]
+            .iter()
+            .map(|id| Box::new(MockStrategy { id: id.to_string(), risk_profile: RiskProfile::default() }) as Box<dyn Strategy>)
+            .collect();
+        let executor = StrategyExecutor::new(
+            strategies,
+            Arc::new(MockRiskManager::new()),
+            None,
+            Arc::new(MockTelemetryReporter::new()),
+            Arc::new(MockExecutionService::new()),
+        )
+        .with_sla_monitor(sla_monitor.clone());
+        
+        let results = executor.execute_cycle(&create_test_market_data()).await;
+        
+        // Reduced strategies trade at half size; shadow and disabled strategies send nothing
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].executed_quantity, Some(0.8));
+        assert_eq!(results[1].executed_quantity, Some(0.4));
+    }
+    
+    struct PanickingStrategy {
+        id: StrategyId,
+    }
+    
+    #[async_trait]
+    impl Strategy for PanickingStrategy {
+        async fn generate_signal(&self, _market_data: &crate::market::MarketData)
+            -> Result<Option<Signal>, String> {
+            panic!("index out of bounds in {}", self.id);
+        }
+        
+        async fn get_risk_profile(&self) -> RiskProfile {
+            RiskProfile::default()
+        }
+        
+        fn name(&self) -> &str {
+            &self.id
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_panicking_strategy_is_quarantined_while_others_run() {
+        let incidents = crate::incidents::create_incident_manager(crate::incidents::IncidentConfig::default());
+        let executor = StrategyExecutor::new(
+            vec![
+                Box::new(PanickingStrategy { id: "panicky".to_string() }),
+                Box::new(MockStrategy { id: "healthy".to_string(), risk_profile: RiskProfile::default() }),
+            ],
+            Arc::new(MockRiskManager::new()),
+            None,
+            Arc::new(MockTelemetryReporter::new()),
+            Arc::new(MockExecutionService::new()),
+        )
+        .with_incident_manager(incidents.clone());
+        
+        // The panic is contained to its strategy and the rest of the cycle still trades
+        let results = executor.execute_cycle(&create_test_market_data()).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, ExecutionStatus::Completed);
+        assert!(!executor.is_strategy_enabled(&"panicky".to_string()));
+        assert!(executor.is_strategy_enabled(&"healthy".to_string()));
+        
+        let opened = incidents.list(true).await;
+        assert_eq!(opened.len(), 1);
+        assert_eq!(opened[0].trigger, crate::incidents::IncidentTrigger::StrategyQuarantine);
+        assert_eq!(opened[0].subject, "panicky");
+        assert!(opened[0].timeline[0].message.contains("index out of bounds in panicky"));
+        
+        // The quarantined strategy sits out later cycles until released
+        let results = executor.execute_cycle(&create_test_market_data()).await;
+        assert_eq!(results.len(), 1);
+        assert!(!executor.is_strategy_enabled(&"panicky".to_string()));
+        assert_eq!(incidents.list(true).await.len(), 1);
+    }
+    
+    #[test]
+    fn test_panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("boom")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "boom");
+        
+        let payload = std::panic::catch_unwind(|| panic!("bad price {}", 0)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "bad price 0");
+    }
+} 
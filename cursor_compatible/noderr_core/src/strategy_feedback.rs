@@ -24,9 +24,10 @@ use tokio::sync::Mutex;
 use tracing::{debug, error, info, warn};
 
 use crate::execution_metrics::{ExecutionMetricsCollector, ExecutionMetricsError};
-use crate::strategy::StrategyId;
+use crate::strategy::{Strategy, StrategyId};
 use crate::risk_allocation::{RiskAllocator, StrategyAllocation, PortfolioAllocation};
 use crate::redis::{RedisClient, RedisClientResult};
+use crate::strategies::EnsembleStrategy;
 
 /// Errors that can occur in the strategy feedback system
 #[derive(Debug, Error)]
@@ -201,6 +202,9 @@ pub trait StrategyFeedbackLoop: Send + Sync {
         status: AdaptiveStrategyStatus,
         reason: &str
     ) -> StrategyFeedbackResult<()>;
+    
+    /// Register an ensemble whose blend weights should follow child strategy scores
+    async fn register_ensemble(&self, ensemble: Arc<EnsembleStrategy>) -> StrategyFeedbackResult<()>;
 }
 
 /// Default implementation of the strategy feedback loop
@@ -219,6 +223,8 @@ pub struct DefaultStrategyFeedbackLoop {
     recent_adaptations: Arc<Mutex<Vec<AdaptationEvent>>>,
     /// Flag to indicate if the loop is running
     is_running: Arc<RwLock<bool>>,
+    /// Ensembles receiving composite scores each cycle
+    ensembles: Arc<RwLock<Vec<Arc<EnsembleStrategy>>>>,
 }
 
 impl DefaultStrategyFeedbackLoop {
@@ -237,6 +243,7 @@ impl DefaultStrategyFeedbackLoop {
             strategy_statuses: Arc::new(RwLock::new(HashMap::new())),
             recent_adaptations: Arc::new(Mutex::new(Vec::new())),
             is_running: Arc::new(RwLock::new(false)),
+            ensembles: Arc::new(RwLock::new(Vec::new())),
         }
     }
     
@@ -432,6 +439,18 @@ impl StrategyFeedbackLoop for DefaultStrategyFeedbackLoop {
         // Sort strategies by composite score (descending)
         strategy_scores.sort_by(|a, b| b.composite_score.partial_cmp(&a.composite_score).unwrap());
         
+        // Let ensembles reweight their children by the same scores
+        let ensembles = self.ensembles.read().unwrap().clone();
+        if !ensembles.is_empty() {
+            let composite_scores: HashMap<StrategyId, f64> = strategy_scores
+                .iter()
+                .map(|s| (s.strategy_id.clone(), s.composite_score))
+                .collect();
+            for ensemble in &ensembles {
+                ensemble.apply_feedback(&composite_scores).await;
+            }
+        }
+        
         // Calculate new allocations
         let mut new_allocations = HashMap::new();
         let mut adaptation_events = Vec::new();
@@ -633,6 +652,17 @@ impl StrategyFeedbackLoop for DefaultStrategyFeedbackLoop {
         
         Ok(())
     }
+    
+    async fn register_ensemble(&self, ensemble: Arc<EnsembleStrategy>) -> StrategyFeedbackResult<()> {
+        let mut ensembles = self.ensembles.write().unwrap();
+        if ensembles.iter().any(|e| e.name() == ensemble.name()) {
+            return Err(StrategyFeedbackError::InvalidParameter(format!(
+                "Ensemble {} already registered", ensemble.name()
+            )));
+        }
+        ensembles.push(ensemble);
+        Ok(())
+    }
 }
 
 impl Clone for DefaultStrategyFeedbackLoop {
@@ -645,6 +675,7 @@ impl Clone for DefaultStrategyFeedbackLoop {
             strategy_statuses: self.strategy_statuses.clone(),
             recent_adaptations: self.recent_adaptations.clone(),
             is_running: self.is_running.clone(),
+            ensembles: self.ensembles.clone(),
         }
     }
 }
@@ -0,0 +1,526 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Strategy retirement and archival
+//!
+//! The end of a strategy's lifecycle. A strategy whose trust score or
+//! performance stays below the retirement thresholds for the whole grace
+//! period is retired: it is removed from the executor (which drops it from
+//! the next allocation), its resting orders are cancelled, the risk
+//! allocation is recomputed, and its configuration and full history are
+//! archived to storage. Every retirement produces a report that is
+//! recorded in the audit vault for governance review.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::audit_vault::AuditVault;
+use crate::order_router::SmartOrderRouter;
+use crate::risk_allocation::RiskAllocator;
+use crate::storage::{StorageError, StrategyArchive, StrategyStorage, TimeRange};
+use crate::strategy::{StrategyId, StrategyPerformance};
+use crate::strategy_executor::{ExecutorError, StrategyExecutor};
+use crate::telemetry::TelemetryReporter;
+use crate::trust_score_engine::TrustScoreEngine;
+
+/// Actor recorded on retirement audit entries
+const RETIREMENT_ACTOR: &str = "strategy_retirement";
+
+/// Errors that can occur while retiring a strategy
+#[derive(Debug, Error)]
+pub enum RetirementError {
+    #[error("Executor error: {0}")]
+    Executor(#[from] ExecutorError),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+}
+
+/// Retirement thresholds and schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetirementConfig {
+    /// Strategies with a trust score below this are failing
+    pub min_trust_score: f64,
+    /// Strategies with a Sharpe ratio below this are failing (None disables the check)
+    pub min_sharpe: Option<f64>,
+    /// Strategies with a larger maximum drawdown are failing
+    pub max_drawdown: f64,
+    /// Trades a strategy must have made before its performance is judged
+    pub min_trades: u32,
+    /// How long a strategy must keep failing before it is retired (seconds)
+    pub grace_period_secs: i64,
+    /// How often strategies are evaluated (seconds)
+    pub check_interval_secs: u64,
+}
+
+impl Default for RetirementConfig {
+    fn default() -> Self {
+        Self {
+            min_trust_score: 0.3,
+            min_sharpe: Some(0.0),
+            max_drawdown: 0.25,
+            min_trades: 20,
+            grace_period_secs: 7 * 24 * 3600,
+            check_interval_secs: 3600,
+        }
+    }
+}
+
+/// A retirement threshold a strategy is failing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "criterion")]
+pub enum RetirementBreach {
+    /// Trust score below the minimum
+    TrustScore { score: f64, threshold: f64 },
+    /// Sharpe ratio below the minimum
+    Sharpe { sharpe: f64, threshold: f64 },
+    /// Maximum drawdown above the limit
+    Drawdown { drawdown: f64, threshold: f64 },
+}
+
+/// Governance report produced for every retirement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetirementReport {
+    /// Retired strategy
+    pub strategy_id: StrategyId,
+    /// When the strategy was retired
+    pub retired_at: DateTime<Utc>,
+    /// When the strategy first failed its thresholds (None for manual retirements)
+    pub failing_since: Option<DateTime<Utc>>,
+    /// Why the strategy was retired
+    pub reason: String,
+    /// Thresholds failed at retirement
+    pub breaches: Vec<RetirementBreach>,
+    /// Trust score at retirement
+    pub final_trust_score: Option<f64>,
+    /// Performance at retirement
+    pub final_performance: Option<StrategyPerformance>,
+    /// Risk allocation the strategy held before retirement
+    pub allocation_before: Option<f64>,
+    /// Whether the risk allocation was recomputed without the strategy
+    pub reallocated: bool,
+    /// Resting orders cancelled
+    pub orders_cancelled: usize,
+    /// Resting orders that could not be cancelled
+    pub orders_failed: usize,
+    /// Executions written to the archive
+    pub archived_executions: usize,
+    /// Audit vault entry holding this report
+    pub audit_entry_id: Option<String>,
+}
+
+#[derive(Default)]
+struct RetirementState {
+    failing_since: HashMap<StrategyId, DateTime<Utc>>,
+    reports: Vec<RetirementReport>,
+}
+
+/// Retires strategies that stay below trust/performance thresholds
+pub struct StrategyRetirementService {
+    /// Configuration
+    config: RetirementConfig,
+    /// Executor running the strategies
+    executor: Arc<StrategyExecutor>,
+    /// Storage holding strategy history and archives
+    storage: Arc<dyn StrategyStorage>,
+    /// Trust score source; falls back to the performance trust history
+    trust_engine: Option<Arc<dyn TrustScoreEngine>>,
+    /// Router holding the strategies' resting orders
+    router: Option<Arc<SmartOrderRouter>>,
+    /// Risk allocator recomputed after a retirement
+    risk_allocator: Option<Arc<dyn RiskAllocator>>,
+    /// Audit vault receiving retirement reports
+    audit_vault: Option<Arc<AuditVault>>,
+    /// Telemetry reporter
+    telemetry: Option<Arc<TelemetryReporter>>,
+    /// Failing streaks and past reports
+    state: RwLock<RetirementState>,
+    /// Background task handle
+    task_handle: RwLock<Option<JoinHandle<()>>>,
+}
+
+impl StrategyRetirementService {
+    /// Create a retirement service over an executor and its storage
+    pub fn new(config: RetirementConfig, executor: Arc<StrategyExecutor>, storage: Arc<dyn StrategyStorage>) -> Self {
+        Self {
+            config,
+            executor,
+            storage,
+            trust_engine: None,
+            router: None,
+            risk_allocator: None,
+            audit_vault: None,
+            telemetry: None,
+            state: RwLock::new(RetirementState::default()),
+            task_handle: RwLock::new(None),
+        }
+    }
+
+    /// Read trust scores from a trust score engine
+    pub fn with_trust_engine(mut self, trust_engine: Arc<dyn TrustScoreEngine>) -> Self {
+        self.trust_engine = Some(trust_engine);
+        self
+    }
+
+    /// Cancel retired strategies' resting orders on this router
+    pub fn with_router(mut self, router: Arc<SmartOrderRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Recompute this allocator after a retirement
+    pub fn with_risk_allocator(mut self, risk_allocator: Arc<dyn RiskAllocator>) -> Self {
+        self.risk_allocator = Some(risk_allocator);
+        self
+    }
+
+    /// Record retirement reports in an audit vault
+    pub fn with_audit_vault(mut self, audit_vault: Arc<AuditVault>) -> Self {
+        self.audit_vault = Some(audit_vault);
+        self
+    }
+
+    /// Report retirements to telemetry
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryReporter>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// Reports of every retirement so far, oldest first
+    pub async fn reports(&self) -> Vec<RetirementReport> {
+        self.state.read().await.reports.clone()
+    }
+
+    /// Strategies currently failing their thresholds and since when
+    pub async fn failing(&self) -> HashMap<StrategyId, DateTime<Utc>> {
+        self.state.read().await.failing_since.clone()
+    }
+
+    async fn trust_score(&self, strategy_id: &StrategyId, performance: Option<&StrategyPerformance>) -> Option<f64> {
+        if let Some(engine) = &self.trust_engine {
+            match engine.get_trust_score(strategy_id).await {
+                Ok(score) => return Some(score.score),
+                Err(e) => warn!("No trust score for strategy {}: {}", strategy_id, e),
+            }
+        }
+        performance.and_then(|p| p.trust_history.back()).map(|entry| entry.score)
+    }
+
+    /// Update failing streaks as of `now` and return the strategies due for retirement
+    pub async fn evaluate(&self, now: DateTime<Utc>) -> Vec<(StrategyId, Vec<RetirementBreach>)> {
+        let strategies = self.executor.list_strategies();
+        let mut checked = Vec::with_capacity(strategies.len());
+        for strategy_id in strategies {
+            let performance = self.executor.get_strategy_performance(&strategy_id);
+            let trust = self.trust_score(&strategy_id, performance.as_ref()).await;
+            let breaches = check_thresholds(&self.config, trust, performance.as_ref());
+            checked.push((strategy_id, breaches));
+        }
+
+        let mut state = self.state.write().await;
+        let live: Vec<&StrategyId> = checked.iter().map(|(id, _)| id).collect();
+        state.failing_since.retain(|id, _| live.contains(&id));
+
+        let grace = Duration::seconds(self.config.grace_period_secs);
+        let mut due = Vec::new();
+        for (strategy_id, breaches) in checked {
+            if breaches.is_empty() {
+                state.failing_since.remove(&strategy_id);
+                continue;
+            }
+            let since = *state.failing_since.entry(strategy_id.clone()).or_insert(now);
+            if now - since >= grace {
+                due.push((strategy_id, breaches));
+            }
+        }
+        due
+    }
+
+    /// Retire every strategy that has been failing for the whole grace period
+    pub async fn run_cycle(&self) -> Vec<RetirementReport> {
+        let mut reports = Vec::new();
+        for (strategy_id, breaches) in self.evaluate(Utc::now()).await {
+            let reason = format!(
+                "Below retirement thresholds for {} days",
+                self.config.grace_period_secs / (24 * 3600)
+            );
+            match self.retire_with(&strategy_id, &reason, breaches).await {
+                Ok(report) => reports.push(report),
+                Err(e) => error!("Failed to retire strategy {}: {}", strategy_id, e),
+            }
+        }
+        reports
+    }
+
+    /// Retire a strategy now, regardless of its thresholds
+    pub async fn retire(&self, strategy_id: &StrategyId, reason: &str) -> Result<RetirementReport, RetirementError> {
+        let performance = self.executor.get_strategy_performance(strategy_id);
+        let trust = self.trust_score(strategy_id, performance.as_ref()).await;
+        let breaches = check_thresholds(&self.config, trust, performance.as_ref());
+        self.retire_with(strategy_id, reason, breaches).await
+    }
+
+    async fn retire_with(
+        &self,
+        strategy_id: &StrategyId,
+        reason: &str,
+        breaches: Vec<RetirementBreach>,
+    ) -> Result<RetirementReport, RetirementError> {
+        // Snapshot everything that removal from the executor discards
+        let final_performance = self.executor.get_strategy_performance(strategy_id);
+        let final_trust_score = self.trust_score(strategy_id, final_performance.as_ref()).await;
+        let allocation_before = match &self.risk_allocator {
+            Some(allocator) => allocator.get_strategy_allocation(strategy_id).await,
+            None => None,
+        };
+        let failing_since = self.state.read().await.failing_since.get(strategy_id).copied();
+
+        // Stop the strategy trading; fails without side effects if other strategies depend on it
+        let strategy = self.executor.remove_strategy(strategy_id)?;
+        let retired_at = Utc::now();
+
+        let (orders_cancelled, orders_failed) = match &self.router {
+            Some(router) => {
+                let batch = router.cancel_strategy_orders(strategy_id).await;
+                (batch.succeeded, batch.failed)
+            }
+            None => (0, 0),
+        };
+
+        let reallocated = match &self.risk_allocator {
+            Some(allocator) => match allocator.force_reallocation().await {
+                Ok(_) => true,
+                Err(e) => {
+                    warn!("Reallocation after retiring {} failed: {}", strategy_id, e);
+                    false
+                }
+            },
+            None => false,
+        };
+
+        let config = serde_json::json!({
+            "name": strategy.name(),
+            "description": strategy.description(),
+            "risk_profile": strategy.get_risk_profile().await,
+            "metrics": strategy.get_metrics().await,
+            "dependencies": strategy.dependencies(),
+            "entropy_score": strategy.entropy_score(),
+        });
+        if let Err(e) = strategy.shutdown().await {
+            warn!("Retired strategy {} did not shut down cleanly: {}", strategy_id, e);
+        }
+
+        let archive = self.build_archive(strategy_id, retired_at, reason, config, final_performance.clone()).await;
+        let archived_executions = archive.executions.len();
+        self.storage.store_archive(archive).await?;
+
+        let mut report = RetirementReport {
+            strategy_id: strategy_id.clone(),
+            retired_at,
+            failing_since,
+            reason: reason.to_string(),
+            breaches,
+            final_trust_score,
+            final_performance,
+            allocation_before,
+            reallocated,
+            orders_cancelled,
+            orders_failed,
+            archived_executions,
+            audit_entry_id: None,
+        };
+
+        if let Some(vault) = &self.audit_vault {
+            let description = format!("Strategy {} retired: {}", strategy_id, reason);
+            match vault.record("strategy_retirement", RETIREMENT_ACTOR, &description, serde_json::to_value(&report).unwrap_or_default()).await {
+                Ok(entry) => report.audit_entry_id = Some(entry.entry_id),
+                Err(e) => error!("Failed to record retirement of {} in the audit vault: {}", strategy_id, e),
+            }
+        }
+        if let Some(telemetry) = &self.telemetry {
+            let mut data = HashMap::new();
+            data.insert("strategy_id".to_string(), serde_json::json!(strategy_id));
+            data.insert("reason".to_string(), serde_json::json!(reason));
+            data.insert("orders_cancelled".to_string(), serde_json::json!(orders_cancelled));
+            data.insert("reallocated".to_string(), serde_json::json!(reallocated));
+            telemetry.report_custom("strategy_retired", data).await;
+        }
+
+        info!(
+            "Retired strategy {} ({}); cancelled {} orders, archived {} executions",
+            strategy_id, reason, orders_cancelled, archived_executions
+        );
+
+        let mut state = self.state.write().await;
+        state.failing_since.remove(strategy_id);
+        state.reports.push(report.clone());
+        Ok(report)
+    }
+
+    async fn build_archive(
+        &self,
+        strategy_id: &StrategyId,
+        retired_at: DateTime<Utc>,
+        reason: &str,
+        config: serde_json::Value,
+        final_performance: Option<StrategyPerformance>,
+    ) -> StrategyArchive {
+        // Missing history is archived as empty rather than blocking the retirement
+        let performance_history = self.storage
+            .get_performance_history(strategy_id, TimeRange::All, "day")
+            .await
+            .unwrap_or_default();
+        let executions = self.storage
+            .query_executions_by_strategy(strategy_id, TimeRange::All, None)
+            .await
+            .unwrap_or_default();
+        let telemetry = self.storage
+            .query_telemetry_events(Some(strategy_id), None, TimeRange::All, None)
+            .await
+            .unwrap_or_default();
+
+        let trust_history = match &self.trust_engine {
+            Some(engine) => engine.get_trust_history(strategy_id).await
+                .map(|history| history.entries.iter().map(|e| (e.timestamp, e.score)).collect())
+                .unwrap_or_default(),
+            None => final_performance.as_ref()
+                .map(|p| p.trust_history.iter().map(|e| (e.timestamp, e.score)).collect())
+                .unwrap_or_default(),
+        };
+
+        StrategyArchive {
+            strategy_id: strategy_id.clone(),
+            retired_at,
+            reason: reason.to_string(),
+            config,
+            final_performance,
+            performance_history,
+            trust_history,
+            executions,
+            telemetry,
+        }
+    }
+
+    /// Evaluate strategies on the configured interval
+    pub async fn start(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+        let interval_secs = self.config.check_interval_secs.max(1);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(StdDuration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                service.run_cycle().await;
+            }
+        });
+
+        if let Some(previous) = self.task_handle.write().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop evaluating strategies
+    pub async fn stop(&self) {
+        if let Some(handle) = self.task_handle.write().await.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Thresholds a strategy is failing; performance is only judged after `min_trades` trades
+pub fn check_thresholds(
+    config: &RetirementConfig,
+    trust_score: Option<f64>,
+    performance: Option<&StrategyPerformance>,
+) -> Vec<RetirementBreach> {
+    let mut breaches = Vec::new();
+
+    if let Some(score) = trust_score.filter(|score| *score < config.min_trust_score) {
+        breaches.push(RetirementBreach::TrustScore { score, threshold: config.min_trust_score });
+    }
+
+    let Some(performance) = performance else {
+        return breaches;
+    };
+    if performance.successful_trades + performance.unsuccessful_trades < config.min_trades {
+        return breaches;
+    }
+    if let (Some(sharpe), Some(threshold)) = (performance.sharpe, config.min_sharpe) {
+        if sharpe < threshold {
+            breaches.push(RetirementBreach::Sharpe { sharpe, threshold });
+        }
+    }
+    if performance.max_drawdown > config.max_drawdown {
+        breaches.push(RetirementBreach::Drawdown {
+            drawdown: performance.max_drawdown,
+            threshold: config.max_drawdown,
+        });
+    }
+
+    breaches
+}
+
+/// Create a strategy retirement service
+pub fn create_strategy_retirement(
+    config: RetirementConfig,
+    executor: Arc<StrategyExecutor>,
+    storage: Arc<dyn StrategyStorage>,
+) -> Arc<StrategyRetirementService> {
+    Arc::new(StrategyRetirementService::new(config, executor, storage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thresholds_wait_for_enough_trades() {
+        let config = RetirementConfig::default();
+        let mut performance = StrategyPerformance {
+            successful_trades: 5,
+            unsuccessful_trades: 5,
+            sharpe: Some(-0.4),
+            max_drawdown: 0.4,
+            ..Default::default()
+        };
+
+        // Too few trades to judge performance; trust is still checked
+        assert!(check_thresholds(&config, Some(0.6), Some(&performance)).is_empty());
+        assert_eq!(
+            check_thresholds(&config, Some(0.1), Some(&performance)),
+            vec![RetirementBreach::TrustScore { score: 0.1, threshold: 0.3 }]
+        );
+
+        performance.unsuccessful_trades = 15;
+        let breaches = check_thresholds(&config, Some(0.6), Some(&performance));
+        assert_eq!(breaches, vec![
+            RetirementBreach::Sharpe { sharpe: -0.4, threshold: 0.0 },
+            RetirementBreach::Drawdown { drawdown: 0.4, threshold: 0.25 },
+        ]);
+
+        performance.sharpe = Some(1.2);
+        performance.max_drawdown = 0.1;
+        assert!(check_thresholds(&config, None, Some(&performance)).is_empty());
+    }
+}
@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Opportunistic liquidity taking for passive execution algorithms
+//!
+//! A passive TWAP rests its slices to earn the spread, but sometimes the
+//! book offers liquidity cheaply enough that waiting in the queue is the
+//! worse trade: the spread has collapsed, or depth on the far side is
+//! stacked in the order's favour. The overlay watches the timing signal
+//! engine for those moments and lets a resting child cross the spread,
+//! while a per-parent taker budget caps how much of the parent order may
+//! ever be filled aggressively.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use crate::microstructure::spread_forecast::SpreadForecaster;
+use crate::microstructure::timing_signals::{ExecutionTimingSignal, SignalType, TimingSignalEngine};
+use crate::order_router::{Order, OrderSide};
+use crate::orderbook::OrderBookManager;
+
+/// Errors that can occur while managing taker budgets
+#[derive(Debug, Error)]
+pub enum TakerOverlayError {
+    #[error("Parent order {0} has no taker budget")]
+    UnknownParent(String),
+
+    #[error("Taker budget for {parent_id} exhausted: requested {requested}, remaining {remaining}")]
+    BudgetExhausted { parent_id: String, requested: f64, remaining: f64 },
+
+    #[error("Invalid quantity: {0}")]
+    InvalidQuantity(String),
+}
+
+/// Taker overlay configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TakerOverlayConfig {
+    /// Signal types that indicate cheap immediate liquidity
+    pub signal_types: Vec<SignalType>,
+    /// Minimum signal confidence (0.0-1.0)
+    pub min_confidence: f64,
+    /// Fraction of each parent order that may be filled as taker
+    pub taker_budget_fraction: f64,
+    /// Widest spread a child may cross (basis points of mid)
+    pub max_spread_bps: f64,
+    /// Minimum time between crosses for the same parent (milliseconds)
+    pub min_cross_interval_ms: i64,
+}
+
+impl Default for TakerOverlayConfig {
+    fn default() -> Self {
+        Self {
+            signal_types: vec![SignalType::SpreadTightening, SignalType::OrderbookImbalance],
+            min_confidence: 0.7,
+            taker_budget_fraction: 0.2,
+            max_spread_bps: 5.0,
+            min_cross_interval_ms: 5_000,
+        }
+    }
+}
+
+/// Taker allowance of one parent order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakerBudget {
+    /// Parent order the budget belongs to
+    pub parent_id: String,
+    /// Total quantity that may be filled as taker
+    pub total: f64,
+    /// Quantity already sent as taker orders
+    pub used: f64,
+    /// When a child of this parent last crossed the spread
+    pub last_cross_at: Option<DateTime<Utc>>,
+}
+
+impl TakerBudget {
+    /// Quantity still available for taker orders
+    pub fn remaining(&self) -> f64 {
+        (self.total - self.used).max(0.0)
+    }
+}
+
+/// A chance to cross the spread found by the overlay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeOpportunity {
+    /// Parent order the child belongs to
+    pub parent_id: String,
+    /// Price that takes the opposite touch
+    pub price: f64,
+    /// Quantity to take
+    pub quantity: f64,
+    /// Current spread (basis points of mid)
+    pub spread_bps: f64,
+    /// Signal that triggered the cross
+    pub signal_type: SignalType,
+    /// Confidence of that signal (0.0-1.0)
+    pub confidence: f64,
+}
+
+/// Lets passive children cross the spread when liquidity is cheap
+pub struct TakerOverlay {
+    /// Configuration
+    config: TakerOverlayConfig,
+    /// Source of execution timing signals
+    timing: Arc<dyn TimingSignalEngine>,
+    /// Books used to find the opposite touch
+    order_books: Arc<OrderBookManager>,
+    /// Taker budgets by parent order ID
+    budgets: RwLock<HashMap<String, TakerBudget>>,
+    /// Forecaster that can advise waiting for a tighter spread (optional)
+    spread_forecaster: Option<Arc<SpreadForecaster>>,
+}
+
+impl TakerOverlay {
+    /// Create a new overlay
+    pub fn new(
+        config: TakerOverlayConfig,
+        timing: Arc<dyn TimingSignalEngine>,
+        order_books: Arc<OrderBookManager>,
+    ) -> Self {
+        Self {
+            config,
+            timing,
+            order_books,
+            budgets: RwLock::new(HashMap::new()),
+            spread_forecaster: None,
+        }
+    }
+
+    /// Hold off crossing while the spread is forecast to tighten
+    pub fn with_spread_forecaster(mut self, spread_forecaster: Arc<SpreadForecaster>) -> Self {
+        self.spread_forecaster = Some(spread_forecaster);
+        self
+    }
+
+    /// Give a parent order its taker budget; re-registering keeps what was already used
+    pub async fn register_parent(&self, parent_id: &str, quantity: f64) -> Result<TakerBudget, TakerOverlayError> {
+        if !quantity.is_finite() || quantity <= 0.0 {
+            return Err(TakerOverlayError::InvalidQuantity(format!("parent {} quantity {}", parent_id, quantity)));
+        }
+
+        let total = quantity * self.config.taker_budget_fraction.clamp(0.0, 1.0);
+        let mut budgets = self.budgets.write().await;
+        let budget = budgets.entry(parent_id.to_string()).or_insert_with(|| TakerBudget {
+            parent_id: parent_id.to_string(),
+            total,
+            used: 0.0,
+            last_cross_at: None,
+        });
+        budget.total = total;
+        Ok(budget.clone())
+    }
+
+    /// Drop a finished parent's budget
+    pub async fn remove_parent(&self, parent_id: &str) -> Option<TakerBudget> {
+        self.budgets.write().await.remove(parent_id)
+    }
+
+    /// Current budget of a parent order
+    pub async fn budget(&self, parent_id: &str) -> Option<TakerBudget> {
+        self.budgets.read().await.get(parent_id).cloned()
+    }
+
+    /// Decide whether a resting child should cross the spread now
+    ///
+    /// `quantity` is the child's unfilled size; the cross only happens when
+    /// the parent's remaining budget covers all of it and the opposite touch
+    /// is within `limit_price`.
+    pub async fn evaluate(
+        &self,
+        parent_id: &str,
+        order: &Order,
+        quantity: f64,
+        limit_price: f64,
+        now: DateTime<Utc>,
+    ) -> Option<TakeOpportunity> {
+        let budget = self.budget(parent_id).await?;
+        if quantity <= 0.0 || quantity > budget.remaining() {
+            return None;
+        }
+        if budget.last_cross_at
+            .is_some_and(|at| now - at < Duration::milliseconds(self.config.min_cross_interval_ms))
+        {
+            return None;
+        }
+
+        let (bids, asks) = self.order_books.get_snapshot(&order.symbol, 1)?;
+        let (bid, ask) = (bids.first()?.price, asks.first()?.price);
+        let mid = (bid + ask) / 2.0;
+        if mid <= 0.0 {
+            return None;
+        }
+        let spread_bps = (ask - bid) / mid * 10_000.0;
+        if spread_bps > self.config.max_spread_bps {
+            return None;
+        }
+        if self.spread_forecaster.as_ref().is_some_and(|forecaster| forecaster.should_wait(&order.symbol, now)) {
+            return None;
+        }
+
+        let price = match order.side {
+            OrderSide::Buy if ask <= limit_price => ask,
+            OrderSide::Sell if bid >= limit_price => bid,
+            _ => return None,
+        };
+
+        let signals = self.timing.get_active_signals(&order.symbol).await.ok()?;
+        let signal = signals.iter()
+            .filter(|signal| self.qualifies(signal, order.side))
+            .max_by(|a, b| a.confidence.to_value().total_cmp(&b.confidence.to_value()))?;
+
+        Some(TakeOpportunity {
+            parent_id: parent_id.to_string(),
+            price,
+            quantity,
+            spread_bps,
+            signal_type: signal.signal_type,
+            confidence: signal.confidence.to_value(),
+        })
+    }
+
+    /// Charge a cross against the parent's budget
+    pub async fn consume(&self, parent_id: &str, quantity: f64, now: DateTime<Utc>) -> Result<TakerBudget, TakerOverlayError> {
+        let mut budgets = self.budgets.write().await;
+        let budget = budgets.get_mut(parent_id)
+            .ok_or_else(|| TakerOverlayError::UnknownParent(parent_id.to_string()))?;
+        if quantity > budget.remaining() {
+            return Err(TakerOverlayError::BudgetExhausted {
+                parent_id: parent_id.to_string(),
+                requested: quantity,
+                remaining: budget.remaining(),
+            });
+        }
+
+        budget.used += quantity;
+        budget.last_cross_at = Some(now);
+        debug!("Taker budget for {}: {:.6} of {:.6} used", parent_id, budget.used, budget.total);
+        Ok(budget.clone())
+    }
+
+    /// Return quantity a taker order did not fill, or that was never sent
+    pub async fn refund(&self, parent_id: &str, quantity: f64) {
+        if let Some(budget) = self.budgets.write().await.get_mut(parent_id) {
+            budget.used = (budget.used - quantity.max(0.0)).max(0.0);
+        }
+    }
+
+    /// Whether a signal says taking liquidity on this side is cheap
+    fn qualifies(&self, signal: &ExecutionTimingSignal, side: OrderSide) -> bool {
+        if !self.config.signal_types.contains(&signal.signal_type)
+            || signal.confidence.to_value() < self.config.min_confidence
+            || !signal.is_valid()
+        {
+            return false;
+        }
+        // A tight spread is cheap for either side; other signals must point our way
+        signal.signal_type == SignalType::SpreadTightening || signal.is_buy == (side == OrderSide::Buy)
+    }
+}
+
+/// Create a taker overlay
+pub fn create_taker_overlay(
+    config: TakerOverlayConfig,
+    timing: Arc<dyn TimingSignalEngine>,
+    order_books: Arc<OrderBookManager>,
+) -> Arc<TakerOverlay> {
+    Arc::new(TakerOverlay::new(config, timing, order_books))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::microstructure::timing_signals::{MockTimingSignalEngine, SignalConfidence};
+    use crate::orderbook;
+
+    fn buy(symbol: &str, amount: f64) -> Order {
+        Order {
+            symbol: symbol.to_string(),
+            side: OrderSide::Buy,
+            amount,
+            price: 100.0,
+            venues: vec!["binance".to_string()],
+            id: "child-1".to_string(),
+            max_slippage: None,
+            max_retries: None,
+            post_only: true,
+            reduce_only: false,
+            time_in_force: Default::default(),
+            additional_params: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_crosses_on_signal_within_budget() {
+        let timing = Arc::new(MockTimingSignalEngine::new());
+        let books = Arc::new(OrderBookManager::new());
+        books.process_update("BTC/USDT", 100.00, 5.0, orderbook::OrderSide::Bid, 1);
+        books.process_update("BTC/USDT", 100.02, 5.0, orderbook::OrderSide::Ask, 2);
+        let overlay = TakerOverlay::new(TakerOverlayConfig::default(), timing.clone(), books);
+        overlay.register_parent("parent", 10.0).await.unwrap();
+        let now = Utc::now();
+
+        // No signal yet, so the child keeps resting
+        assert!(overlay.evaluate("parent", &buy("BTC/USDT", 1.0), 1.0, 100.05, now).await.is_none());
+
+        // Imbalance favouring sellers does not help a buyer
+        timing.add_signal(ExecutionTimingSignal::new(
+            "BTC/USDT".to_string(), SignalType::OrderbookImbalance, SignalConfidence::VeryHigh, false, 100.0,
+        ));
+        assert!(overlay.evaluate("parent", &buy("BTC/USDT", 1.0), 1.0, 100.05, now).await.is_none());
+
+        timing.add_signal(ExecutionTimingSignal::new(
+            "BTC/USDT".to_string(), SignalType::SpreadTightening, SignalConfidence::High, false, 100.0,
+        ));
+        let take = overlay.evaluate("parent", &buy("BTC/USDT", 1.0), 1.0, 100.05, now).await.unwrap();
+        assert_eq!(take.price, 100.02);
+        assert_eq!(take.signal_type, SignalType::SpreadTightening);
+
+        // Touch above the child's limit, or more than the budget, is never taken
+        assert!(overlay.evaluate("parent", &buy("BTC/USDT", 1.0), 1.0, 100.01, now).await.is_none());
+        assert!(overlay.evaluate("parent", &buy("BTC/USDT", 3.0), 3.0, 100.05, now).await.is_none());
+
+        overlay.consume("parent", 1.5, now).await.unwrap();
+        assert!(overlay.consume("parent", 1.0, now).await.is_err());
+
+        // Refunds restore the budget, and crosses are spaced out
+        overlay.refund("parent", 1.0).await;
+        assert_eq!(overlay.budget("parent").await.unwrap().remaining(), 1.5);
+        assert!(overlay.evaluate("parent", &buy("BTC/USDT", 1.0), 1.0, 100.05, now).await.is_none());
+        let later = now + Duration::seconds(10);
+        assert!(overlay.evaluate("parent", &buy("BTC/USDT", 1.0), 1.0, 100.05, later).await.is_some());
+    }
+}
@@ -21,11 +21,12 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::sync::broadcast;
 use tracing::{debug, error, info, warn};
-use uuid::Uuid;
 
+use crate::ids::{new_id, IdKind};
 use crate::strategy::{Signal, Strategy, StrategyError};
 use crate::execution::{ExecutionResult, ExecutionError};
 use crate::risk::RiskError;
+use crate::error_taxonomy::ClassifiedError;
 
 /// Errors that can occur in the telemetry system
 #[derive(Debug, Error)]
@@ -535,6 +536,19 @@ impl TelemetryReporter {
         ).await;
     }
     
+    /// Report a classified error from any component with its code and retry class
+    pub async fn report_classified_error(&self, component: &str, error: &dyn ClassifiedError) {
+        let report = error.report();
+        let mut data = HashMap::new();
+        data.insert("component".to_string(), serde_json::json!(component));
+        data.insert("code".to_string(), serde_json::json!(report.code));
+        data.insert("class".to_string(), serde_json::json!(report.class));
+        data.insert("retryable".to_string(), serde_json::json!(report.retryable));
+        data.insert("message".to_string(), serde_json::json!(report.message));
+        
+        self.report_custom("classified_error", data).await;
+    }
+    
     /// Report execution completion
     pub async fn report_execution_complete(&self, strategy_id: &str, result: &ExecutionResult) {
         let event = TelemetryEvent::ExecutionComplete {
@@ -681,7 +695,7 @@ impl TelemetryReporter {
         let uptime_seconds = (Utc::now() - self.session_start).num_seconds().max(0) as u64;
         
         let snapshot = SessionTelemetrySnapshot {
-            id: Uuid::new_v4().to_string(),
+            id: new_id(IdKind::Telemetry),
             timestamp: Utc::now(),
             active_strategies: trust_scores.iter()
                 .filter(|(id, _)| id.starts_with("strategy:"))
@@ -1,8 +1,14 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use metrics::{counter, gauge, histogram, describe_counter, describe_gauge, describe_histogram};
-use tracing::{info, warn, error};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn, error};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::redis::RedisClient;
 
 /// Enhanced telemetry configuration
 #[derive(Debug, Clone)]
@@ -13,8 +19,12 @@ pub struct TelemetryConfig {
     pub latency_buckets: Vec<f64>,
     /// Alert thresholds
     pub alert_thresholds: LatencyThresholds,
-    /// Export interval
+    /// Export interval; aggregated telemetry is flushed at this rate
     pub export_interval: Duration,
+    /// Sampling of high-cardinality events
+    pub sampling: SamplingConfig,
+    /// Maximum sampled events buffered between flushes; further samples are dropped but still counted
+    pub max_buffered_events: usize,
 }
 
 impl Default for TelemetryConfig {
@@ -26,7 +36,43 @@ impl Default for TelemetryConfig {
             ],
             alert_thresholds: LatencyThresholds::default(),
             export_interval: Duration::from_secs(10),
+            sampling: SamplingConfig::default(),
+            max_buffered_events: 10_000,
+        }
+    }
+}
+
+/// Event sampling configuration
+#[derive(Debug, Clone)]
+pub struct SamplingConfig {
+    /// Fraction of events forwarded for types without an explicit rate (0.0-1.0)
+    pub default_rate: f64,
+    /// Per-event-type sample rates
+    pub rates: HashMap<String, f64>,
+    /// Event types that are never sampled or dropped
+    pub critical_events: HashSet<String>,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            default_rate: 1.0,
+            rates: HashMap::new(),
+            critical_events: ["order_rejected", "risk_limit_breach", "circuit_breaker", "kill_switch"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl SamplingConfig {
+    /// Sample rate for an event type; critical events are always 1.0
+    pub fn rate_for(&self, event_type: &str) -> f64 {
+        if self.critical_events.contains(event_type) {
+            return 1.0;
         }
+        self.rates.get(event_type).copied().unwrap_or(self.default_rate).clamp(0.0, 1.0)
     }
 }
 
@@ -318,6 +364,317 @@ pub struct LatencyReport {
     pub p99_micros: u64,
 }
 
+/// Sampled event forwarded to the sink
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampledEvent {
+    pub event_type: String,
+    pub timestamp: DateTime<Utc>,
+    /// Rate the event was sampled at; consumers weight it by `1 / sample_rate`
+    pub sample_rate: f64,
+    pub payload: serde_json::Value,
+}
+
+/// Exact event counts for an interval, independent of sampling
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventCounts {
+    /// Events recorded
+    pub seen: u64,
+    /// Events forwarded to the sink
+    pub emitted: u64,
+    /// Sampled events dropped because the buffer was full
+    pub overflowed: u64,
+}
+
+/// Pre-aggregated histogram
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistogramSnapshot {
+    pub count: u64,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+    /// (upper bound, count of observations at or below it)
+    pub buckets: Vec<(f64, u64)>,
+}
+
+impl HistogramSnapshot {
+    fn new(bounds: &[f64]) -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            min: f64::MAX,
+            max: f64::MIN,
+            buckets: bounds.iter().map(|&b| (b, 0)).collect(),
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        for (bound, count) in &mut self.buckets {
+            if value <= *bound {
+                *count += 1;
+            }
+        }
+    }
+}
+
+/// Everything aggregated over one flush interval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedBatch {
+    pub interval_start: DateTime<Utc>,
+    pub interval_end: DateTime<Utc>,
+    pub counters: BTreeMap<String, u64>,
+    pub histograms: BTreeMap<String, HistogramSnapshot>,
+    pub event_counts: BTreeMap<String, EventCounts>,
+    pub events: Vec<SampledEvent>,
+}
+
+impl AggregatedBatch {
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.histograms.is_empty() && self.event_counts.is_empty()
+    }
+}
+
+/// Destination for flushed telemetry batches
+#[async_trait]
+pub trait TelemetrySink: Send + Sync {
+    async fn write_batch(&self, batch: &AggregatedBatch) -> Result<(), String>;
+}
+
+/// Writes each batch to Redis in two round trips: the latest batch is stored and published
+pub struct RedisTelemetrySink<R: RedisClient> {
+    redis: Arc<R>,
+    key_prefix: String,
+}
+
+impl<R: RedisClient> RedisTelemetrySink<R> {
+    pub fn new(redis: Arc<R>, key_prefix: &str) -> Self {
+        Self { redis, key_prefix: key_prefix.to_string() }
+    }
+}
+
+#[async_trait]
+impl<R: RedisClient + 'static> TelemetrySink for RedisTelemetrySink<R> {
+    async fn write_batch(&self, batch: &AggregatedBatch) -> Result<(), String> {
+        let key = format!("{}:aggregates:latest", self.key_prefix);
+        let channel = format!("{}:channel:aggregates", self.key_prefix);
+
+        self.redis.set(&key, batch, None).await.map_err(|e| e.to_string())?;
+        self.redis.publish(&channel, batch).await.map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+/// Pending aggregates for the current interval
+struct AggregationState {
+    interval_start: DateTime<Utc>,
+    counters: BTreeMap<String, u64>,
+    histograms: BTreeMap<String, HistogramSnapshot>,
+    event_counts: BTreeMap<String, EventCounts>,
+    events: Vec<SampledEvent>,
+}
+
+impl AggregationState {
+    fn new() -> Self {
+        Self {
+            interval_start: Utc::now(),
+            counters: BTreeMap::new(),
+            histograms: BTreeMap::new(),
+            event_counts: BTreeMap::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+/// Client-side telemetry aggregator.
+///
+/// Counters and histograms are accumulated in memory and written once per
+/// interval; high-cardinality events are sampled while their counts stay exact.
+pub struct TelemetryAggregator {
+    config: TelemetryConfig,
+    state: Mutex<AggregationState>,
+    /// Lifetime per-type event totals, used for stride sampling
+    totals: Mutex<HashMap<String, u64>>,
+    task_handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl TelemetryAggregator {
+    pub fn new(config: TelemetryConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(AggregationState::new()),
+            totals: Mutex::new(HashMap::new()),
+            task_handle: Mutex::new(None),
+        }
+    }
+
+    /// Add to a counter
+    pub fn increment(&self, name: &str, by: u64) {
+        if !self.config.enabled {
+            return;
+        }
+        *self.state.lock().unwrap().counters.entry(name.to_string()).or_insert(0) += by;
+    }
+
+    /// Record a value in a pre-aggregated histogram
+    pub fn observe(&self, name: &str, value: f64) {
+        if !self.config.enabled {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        state.histograms
+            .entry(name.to_string())
+            .or_insert_with(|| HistogramSnapshot::new(&self.config.latency_buckets))
+            .observe(value);
+    }
+
+    /// Record an event, returning whether it was sampled for forwarding.
+    ///
+    /// Sampling is deterministic: with rate r, every event whose running count
+    /// crosses a multiple of 1/r is kept, so counts are exact and sampling is even.
+    pub fn record_event(&self, event_type: &str, payload: serde_json::Value) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+
+        let rate = self.config.sampling.rate_for(event_type);
+        let critical = self.config.sampling.critical_events.contains(event_type);
+
+        let seen = {
+            let mut totals = self.totals.lock().unwrap();
+            let total = totals.entry(event_type.to_string()).or_insert(0);
+            *total += 1;
+            *total
+        };
+        let sampled = critical || (seen as f64 * rate).floor() > ((seen - 1) as f64 * rate).floor();
+
+        let mut state = self.state.lock().unwrap();
+        let buffer_full = state.events.len() >= self.config.max_buffered_events;
+        let counts = state.event_counts.entry(event_type.to_string()).or_default();
+        counts.seen += 1;
+
+        if !sampled {
+            return false;
+        }
+        if buffer_full && !critical {
+            counts.overflowed += 1;
+            return false;
+        }
+
+        counts.emitted += 1;
+        state.events.push(SampledEvent {
+            event_type: event_type.to_string(),
+            timestamp: Utc::now(),
+            sample_rate: rate,
+            payload,
+        });
+        true
+    }
+
+    /// Take everything aggregated since the last flush
+    pub fn drain(&self) -> AggregatedBatch {
+        let state = std::mem::replace(&mut *self.state.lock().unwrap(), AggregationState::new());
+        AggregatedBatch {
+            interval_start: state.interval_start,
+            interval_end: Utc::now(),
+            counters: state.counters,
+            histograms: state.histograms,
+            event_counts: state.event_counts,
+            events: state.events,
+        }
+    }
+
+    /// Flush the current interval to a sink; on failure the batch is merged back for the next attempt
+    pub async fn flush(&self, sink: &dyn TelemetrySink) -> Result<(), String> {
+        let batch = self.drain();
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        match sink.write_batch(&batch).await {
+            Ok(()) => {
+                debug!("Flushed telemetry batch: {} counters, {} histograms, {} events",
+                    batch.counters.len(), batch.histograms.len(), batch.events.len());
+                Ok(())
+            }
+            Err(e) => {
+                self.restore(batch);
+                Err(e)
+            }
+        }
+    }
+
+    /// Start flushing to the sink every export interval
+    pub fn start(self: &Arc<Self>, sink: Arc<dyn TelemetrySink>) {
+        let mut handle = self.task_handle.lock().unwrap();
+        if handle.is_some() {
+            return;
+        }
+
+        let aggregator = Arc::clone(self);
+        let interval = self.config.export_interval;
+        *handle = Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = aggregator.flush(sink.as_ref()).await {
+                    warn!("Telemetry flush failed, retrying next interval: {}", e);
+                }
+            }
+        }));
+        info!("Telemetry aggregation started (flush every {:?})", interval);
+    }
+
+    /// Stop the flush task
+    pub fn stop(&self) {
+        if let Some(handle) = self.task_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+    }
+
+    fn restore(&self, batch: AggregatedBatch) {
+        let mut state = self.state.lock().unwrap();
+        state.interval_start = state.interval_start.min(batch.interval_start);
+
+        for (name, value) in batch.counters {
+            *state.counters.entry(name).or_insert(0) += value;
+        }
+        for (name, hist) in batch.histograms {
+            match state.histograms.get_mut(&name) {
+                Some(current) => {
+                    current.count += hist.count;
+                    current.sum += hist.sum;
+                    current.min = current.min.min(hist.min);
+                    current.max = current.max.max(hist.max);
+                    for ((_, count), (_, restored)) in current.buckets.iter_mut().zip(hist.buckets) {
+                        *count += restored;
+                    }
+                }
+                None => {
+                    state.histograms.insert(name, hist);
+                }
+            }
+        }
+        for (name, counts) in batch.event_counts {
+            let current = state.event_counts.entry(name).or_default();
+            current.seen += counts.seen;
+            current.emitted += counts.emitted;
+            current.overflowed += counts.overflowed;
+        }
+
+        // Keep critical events, then as many older samples as fit
+        let capacity = self.config.max_buffered_events.saturating_sub(state.events.len());
+        let critical = &self.config.sampling.critical_events;
+        let (mut restored, rest): (Vec<_>, Vec<_>) =
+            batch.events.into_iter().partition(|e| critical.contains(&e.event_type));
+        restored.extend(rest.into_iter().take(capacity.saturating_sub(restored.len())));
+        restored.append(&mut state.events);
+        state.events = restored;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,4 +711,28 @@ mod tests {
         assert_eq!(report.count, 1);
         assert!(report.mean_micros > 1000.0); // Should be > 1ms
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn test_sampling_preserves_counts() {
+        let mut config = TelemetryConfig::default();
+        config.sampling.rates.insert("quote".to_string(), 0.1);
+        let aggregator = TelemetryAggregator::new(config);
+        
+        for _ in 0..1000 {
+            aggregator.record_event("quote", serde_json::json!({}));
+        }
+        for _ in 0..5 {
+            aggregator.record_event("order_rejected", serde_json::json!({}));
+        }
+        aggregator.increment("orders", 3);
+        aggregator.observe("fill_latency_ms", 12.0);
+        
+        let batch = aggregator.drain();
+        assert_eq!(batch.event_counts["quote"].seen, 1000);
+        assert_eq!(batch.event_counts["quote"].emitted, 100);
+        assert_eq!(batch.event_counts["order_rejected"].emitted, 5);
+        assert_eq!(batch.counters["orders"], 3);
+        assert_eq!(batch.histograms["fill_latency_ms"].count, 1);
+        assert!(aggregator.drain().is_empty());
+    }
+}
@@ -0,0 +1,460 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Versioned schemas for telemetry messages.
+//!
+//! Every message type published by the telemetry streamer has a registered
+//! schema. New versions must stay backward compatible with the previous one
+//! (fields may be added, never removed or retyped), payloads are validated on
+//! publish, and a migration shim converts payloads between versions so consumers
+//! pinned to an older version keep working.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::telemetry_streamer::TelemetryMessageType;
+
+/// Errors raised by the schema registry
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum SchemaError {
+    #[error("No schema registered for {0:?}")]
+    UnknownMessageType(TelemetryMessageType),
+
+    #[error("{message_type:?} has no schema version {version}")]
+    UnknownVersion { message_type: TelemetryMessageType, version: u32 },
+
+    #[error("{message_type:?} v{version} must follow v{current}")]
+    VersionOutOfOrder { message_type: TelemetryMessageType, version: u32, current: u32 },
+
+    #[error("{message_type:?} v{version} is incompatible: {}", .reasons.join("; "))]
+    Incompatible { message_type: TelemetryMessageType, version: u32, reasons: Vec<String> },
+
+    #[error("Payload does not match {message_type:?} v{version}: {}", .reasons.join("; "))]
+    InvalidPayload { message_type: TelemetryMessageType, version: u32, reasons: Vec<String> },
+}
+
+/// JSON type of a payload field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldType {
+    String,
+    Number,
+    Integer,
+    Boolean,
+    /// RFC 3339 timestamp string
+    Timestamp,
+    Object,
+    Array,
+    /// Any JSON value
+    Any,
+}
+
+impl FieldType {
+    /// Whether a value is of this type
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            FieldType::String => value.is_string(),
+            FieldType::Number => value.is_number(),
+            FieldType::Integer => value.is_i64() || value.is_u64(),
+            FieldType::Boolean => value.is_boolean(),
+            FieldType::Timestamp => value
+                .as_str()
+                .map_or(false, |s| chrono::DateTime::parse_from_rfc3339(s).is_ok()),
+            FieldType::Object => value.is_object(),
+            FieldType::Array => value.is_array(),
+            FieldType::Any => true,
+        }
+    }
+}
+
+/// Payload field definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldDef {
+    /// Field name
+    pub name: String,
+    /// Field type
+    pub field_type: FieldType,
+    /// Whether the field must be present and non-null
+    pub required: bool,
+    /// Value filled in when migrating a payload from a version without this field
+    pub default: Option<Value>,
+}
+
+impl FieldDef {
+    /// Required field
+    pub fn required(name: &str, field_type: FieldType) -> Self {
+        Self { name: name.to_string(), field_type, required: true, default: None }
+    }
+
+    /// Optional field
+    pub fn optional(name: &str, field_type: FieldType) -> Self {
+        Self { name: name.to_string(), field_type, required: false, default: None }
+    }
+
+    /// Set the value used when upgrading older payloads
+    pub fn with_default(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+}
+
+/// Versioned definition of a telemetry message payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventSchema {
+    /// Message type the schema describes
+    pub message_type: TelemetryMessageType,
+    /// Schema version, starting at 1
+    pub version: u32,
+    /// Declared payload fields
+    pub fields: Vec<FieldDef>,
+    /// Whether fields not declared in the schema are rejected
+    pub closed: bool,
+}
+
+impl EventSchema {
+    /// Create an open schema
+    pub fn new(message_type: TelemetryMessageType, version: u32, fields: Vec<FieldDef>) -> Self {
+        Self { message_type, version, fields, closed: false }
+    }
+
+    /// Reject undeclared fields
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Look up a field
+    pub fn field(&self, name: &str) -> Option<&FieldDef> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Problems preventing `payload` from matching this schema
+    pub fn violations(&self, payload: &Value) -> Vec<String> {
+        let object = match payload.as_object() {
+            Some(object) => object,
+            None => return vec!["payload is not an object".to_string()],
+        };
+
+        let mut violations = Vec::new();
+        for field in &self.fields {
+            match object.get(&field.name) {
+                None | Some(Value::Null) if field.required => {
+                    violations.push(format!("missing required field '{}'", field.name));
+                }
+                Some(value) if !value.is_null() && !field.field_type.matches(value) => {
+                    violations.push(format!("field '{}' is not {:?}", field.name, field.field_type));
+                }
+                _ => {}
+            }
+        }
+
+        if self.closed {
+            for name in object.keys().filter(|name| self.field(name).is_none()) {
+                violations.push(format!("undeclared field '{}'", name));
+            }
+        }
+
+        violations
+    }
+
+    /// Reasons this schema cannot replace `previous` without breaking consumers
+    pub fn incompatibilities(&self, previous: &EventSchema) -> Vec<String> {
+        let mut reasons = Vec::new();
+
+        for old in &previous.fields {
+            match self.field(&old.name) {
+                None => reasons.push(format!("field '{}' was removed", old.name)),
+                Some(new) if new.field_type != old.field_type && new.field_type != FieldType::Any => {
+                    reasons.push(format!("field '{}' changed from {:?} to {:?}", old.name, old.field_type, new.field_type));
+                }
+                Some(new) if new.required && !old.required && new.default.is_none() => {
+                    reasons.push(format!("field '{}' became required without a default", old.name));
+                }
+                _ => {}
+            }
+        }
+
+        for new in self.fields.iter().filter(|f| previous.field(&f.name).is_none()) {
+            if new.required && new.default.is_none() {
+                reasons.push(format!("new required field '{}' has no default", new.name));
+            }
+        }
+
+        if self.closed && !previous.closed {
+            reasons.push("schema became closed".to_string());
+        }
+
+        reasons
+    }
+}
+
+/// Converts a payload from one version to the next
+pub type PayloadMigration = Arc<dyn Fn(Value) -> Value + Send + Sync>;
+
+/// Registry of telemetry message schemas and migrations between their versions
+pub struct TelemetrySchemaRegistry {
+    /// Schemas by message type and version
+    schemas: RwLock<HashMap<TelemetryMessageType, BTreeMap<u32, EventSchema>>>,
+    /// Custom upgrade steps keyed by message type and source version
+    migrations: RwLock<HashMap<(TelemetryMessageType, u32), PayloadMigration>>,
+}
+
+impl TelemetrySchemaRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            schemas: RwLock::new(HashMap::new()),
+            migrations: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Create a registry with v1 schemas for every message type the streamer publishes
+    pub fn with_defaults() -> Self {
+        use FieldType::*;
+
+        let registry = Self::new();
+        let defaults = vec![
+            EventSchema::new(TelemetryMessageType::Trendline, 1, vec![
+                FieldDef::required("data_points", Array),
+                FieldDef::required("trend_direction", Any),
+                FieldDef::required("percent_change", Number),
+                FieldDef::required("time_period", Any),
+            ]),
+            EventSchema::new(TelemetryMessageType::PerformanceSummary, 1, vec![
+                FieldDef::required("total_trades", Integer),
+                FieldDef::required("win_rate", Number),
+                FieldDef::required("total_pnl", Number),
+                FieldDef::required("max_drawdown", Number),
+                FieldDef::required("sharpe_ratio", Number),
+            ]),
+            EventSchema::new(TelemetryMessageType::ExecutionStats, 1, vec![
+                FieldDef::required("total_executions", Integer),
+                FieldDef::required("filled_count", Integer),
+                FieldDef::required("failed_count", Integer),
+                FieldDef::required("avg_fill_rate", Number),
+                FieldDef::required("avg_latency_ms", Integer),
+            ]),
+            EventSchema::new(TelemetryMessageType::AnomalyAlert, 1, vec![
+                FieldDef::required("timestamp", Timestamp),
+                FieldDef::required("anomaly_type", Any),
+                FieldDef::required("severity", Number),
+                FieldDef::required("description", String),
+            ]),
+            EventSchema::new(TelemetryMessageType::TrustScoreUpdate, 1, vec![
+                FieldDef::required("strategy_id", String),
+                FieldDef::required("score", Number),
+                FieldDef::required("timestamp", Timestamp),
+            ]),
+            EventSchema::new(TelemetryMessageType::HealthCheck, 1, vec![]),
+            EventSchema::new(TelemetryMessageType::PositionUpdate, 1, vec![]),
+            EventSchema::new(TelemetryMessageType::DrawdownUpdate, 1, vec![]),
+            EventSchema::new(TelemetryMessageType::VenueHealth, 1, vec![]),
+            EventSchema::new(TelemetryMessageType::RegimeWarning, 1, vec![]),
+            EventSchema::new(TelemetryMessageType::ExecutionProgress, 1, vec![
+                FieldDef::required("order_id", String),
+                FieldDef::required("state", String),
+                FieldDef::required("slices_done", Integer),
+                FieldDef::required("percent_filled", Number),
+                FieldDef::required("schedule_deviation_pct", Number),
+            ]),
+        ];
+
+        for schema in defaults {
+            registry.register(schema).expect("default telemetry schemas are valid");
+        }
+        registry
+    }
+
+    /// Register a schema version; it must be the next version and compatible with the previous one
+    pub fn register(&self, schema: EventSchema) -> Result<(), SchemaError> {
+        let mut schemas = self.schemas.write().unwrap();
+        let versions = schemas.entry(schema.message_type.clone()).or_default();
+        let current = versions.keys().next_back().copied().unwrap_or(0);
+
+        if schema.version != current + 1 {
+            return Err(SchemaError::VersionOutOfOrder {
+                message_type: schema.message_type,
+                version: schema.version,
+                current,
+            });
+        }
+
+        if let Some(previous) = versions.get(&current) {
+            let reasons = schema.incompatibilities(previous);
+            if !reasons.is_empty() {
+                return Err(SchemaError::Incompatible {
+                    message_type: schema.message_type,
+                    version: schema.version,
+                    reasons,
+                });
+            }
+        }
+
+        versions.insert(schema.version, schema);
+        Ok(())
+    }
+
+    /// Register a custom upgrade from `from_version` to `from_version + 1`,
+    /// used instead of filling in field defaults
+    pub fn register_migration(&self, message_type: TelemetryMessageType, from_version: u32, migration: PayloadMigration) {
+        self.migrations.write().unwrap().insert((message_type, from_version), migration);
+    }
+
+    /// Latest version of a message type
+    pub fn current_version(&self, message_type: &TelemetryMessageType) -> Option<u32> {
+        self.schemas
+            .read()
+            .unwrap()
+            .get(message_type)
+            .and_then(|versions| versions.keys().next_back().copied())
+    }
+
+    /// Look up a schema version
+    pub fn schema(&self, message_type: &TelemetryMessageType, version: u32) -> Option<EventSchema> {
+        self.schemas
+            .read()
+            .unwrap()
+            .get(message_type)
+            .and_then(|versions| versions.get(&version).cloned())
+    }
+
+    /// All registered schemas, for publishing to consumers
+    pub fn catalog(&self) -> Vec<EventSchema> {
+        let schemas = self.schemas.read().unwrap();
+        let mut catalog: Vec<EventSchema> = schemas.values().flat_map(|v| v.values().cloned()).collect();
+        catalog.sort_by(|a, b| {
+            a.message_type.system_channel()
+                .cmp(b.message_type.system_channel())
+                .then(a.version.cmp(&b.version))
+        });
+        catalog
+    }
+
+    /// Validate a payload against the latest schema, returning the version it conforms to
+    pub fn validate(&self, message_type: &TelemetryMessageType, payload: &Value) -> Result<u32, SchemaError> {
+        let version = self.current_version(message_type)
+            .ok_or_else(|| SchemaError::UnknownMessageType(message_type.clone()))?;
+        let schema = self.require_schema(message_type, version)?;
+
+        let reasons = schema.violations(payload);
+        if !reasons.is_empty() {
+            return Err(SchemaError::InvalidPayload { message_type: message_type.clone(), version, reasons });
+        }
+        Ok(version)
+    }
+
+    /// Convert a payload between schema versions.
+    ///
+    /// Upgrades apply registered migrations or fill in field defaults; downgrades
+    /// drop the fields introduced after the target version.
+    pub fn migrate(
+        &self,
+        message_type: &TelemetryMessageType,
+        mut payload: Value,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<Value, SchemaError> {
+        self.require_schema(message_type, from_version)?;
+        let target = self.require_schema(message_type, to_version)?;
+
+        if to_version < from_version {
+            if let Some(object) = payload.as_object_mut() {
+                let source = self.require_schema(message_type, from_version)?;
+                object.retain(|name, _| target.field(name).is_some() || source.field(name).is_none());
+            }
+            return Ok(payload);
+        }
+
+        for version in from_version..to_version {
+            let migration = self.migrations.read().unwrap().get(&(message_type.clone(), version)).cloned();
+            payload = match migration {
+                Some(migration) => migration(payload),
+                None => {
+                    let next = self.require_schema(message_type, version + 1)?;
+                    if let Some(object) = payload.as_object_mut() {
+                        for field in &next.fields {
+                            if let (false, Some(default)) = (object.contains_key(&field.name), &field.default) {
+                                object.insert(field.name.clone(), default.clone());
+                            }
+                        }
+                    }
+                    payload
+                }
+            };
+        }
+
+        Ok(payload)
+    }
+
+    fn require_schema(&self, message_type: &TelemetryMessageType, version: u32) -> Result<EventSchema, SchemaError> {
+        self.schema(message_type, version).ok_or_else(|| SchemaError::UnknownVersion {
+            message_type: message_type.clone(),
+            version,
+        })
+    }
+}
+
+impl Default for TelemetrySchemaRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+/// Create a schema registry pre-populated with the default schemas
+pub fn create_telemetry_schema_registry() -> Arc<TelemetrySchemaRegistry> {
+    Arc::new(TelemetrySchemaRegistry::with_defaults())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_incompatible_version_rejected() {
+        let registry = TelemetrySchemaRegistry::with_defaults();
+        let schema = EventSchema::new(TelemetryMessageType::TrustScoreUpdate, 2, vec![
+            FieldDef::required("strategy_id", FieldType::String),
+            FieldDef::required("timestamp", FieldType::Timestamp),
+        ]);
+
+        let err = registry.register(schema).unwrap_err();
+        assert!(matches!(err, SchemaError::Incompatible { .. }));
+        assert_eq!(registry.current_version(&TelemetryMessageType::TrustScoreUpdate), Some(1));
+    }
+
+    #[test]
+    fn test_migrate_between_versions() {
+        let registry = TelemetrySchemaRegistry::with_defaults();
+        registry.register(EventSchema::new(TelemetryMessageType::TrustScoreUpdate, 2, vec![
+            FieldDef::required("strategy_id", FieldType::String),
+            FieldDef::required("score", FieldType::Number),
+            FieldDef::required("timestamp", FieldType::Timestamp),
+            FieldDef::required("confidence", FieldType::Number).with_default(json!(1.0)),
+        ])).unwrap();
+
+        let v1 = json!({ "strategy_id": "s1", "score": 0.8, "timestamp": "2025-01-01T00:00:00Z" });
+        let v2 = registry.migrate(&TelemetryMessageType::TrustScoreUpdate, v1.clone(), 1, 2).unwrap();
+        assert_eq!(v2["confidence"], json!(1.0));
+        assert_eq!(registry.validate(&TelemetryMessageType::TrustScoreUpdate, &v2), Ok(2));
+
+        let back = registry.migrate(&TelemetryMessageType::TrustScoreUpdate, v2, 2, 1).unwrap();
+        assert_eq!(back, v1);
+    }
+}
@@ -24,6 +24,12 @@ use async_trait::async_trait;
 
 use crate::analytics::{TrendLine, PerformanceSummary, ExecutionStats, Anomaly};
 use crate::trust_score_engine::TrustScore;
+use crate::drawdown_monitor::DrawdownEventType;
+use crate::position::{PositionChangeEvent, PositionManager};
+use crate::trading_events::{TradingEvent, TradingEventBus};
+use crate::dead_letter::{DeadLetterOp, DeadLetterQueue, DeadLetterReplayer};
+use crate::telemetry_schema::{create_telemetry_schema_registry, SchemaError, TelemetrySchemaRegistry};
+use crate::wire_format::WireFormat;
 
 /// Errors that can occur in the telemetry streaming system
 #[derive(Debug, Error)]
@@ -45,6 +51,9 @@ pub enum TelemetryStreamError {
     
     #[error("Stream error: {0}")]
     StreamError(String),
+    
+    #[error("Schema error: {0}")]
+    SchemaError(#[from] SchemaError),
 }
 
 /// Configuration for the telemetry streamer
@@ -70,6 +79,10 @@ pub struct TelemetryStreamerConfig {
     
     /// Prefix for all Redis keys
     pub key_prefix: String,
+    
+    /// Encoding of stored and published telemetry; subscribers accept either format
+    #[serde(default)]
+    pub value_format: WireFormat,
 }
 
 impl Default for TelemetryStreamerConfig {
@@ -82,6 +95,7 @@ impl Default for TelemetryStreamerConfig {
             max_trendline_entries: 1000,
             max_anomalies: 100,
             key_prefix: "noderr:telemetry".to_string(),
+            value_format: WireFormat::Json,
         }
     }
 }
@@ -98,10 +112,18 @@ pub struct TelemetryMessage<T> {
     /// Timestamp of the message
     pub timestamp: chrono::DateTime<chrono::Utc>,
     
+    /// Version of the payload schema; messages predating the registry are version 1
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+    
     /// Message payload
     pub payload: T,
 }
 
+fn default_schema_version() -> u32 {
+    1
+}
+
 /// Types of telemetry messages
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum TelemetryMessageType {
@@ -122,6 +144,40 @@ pub enum TelemetryMessageType {
     
     /// Health check
     HealthCheck,
+    
+    /// Position or PnL change
+    PositionUpdate,
+    
+    /// Drawdown state change
+    DrawdownUpdate,
+    
+    /// Venue health/status change
+    VenueHealth,
+    
+    /// Market regime warning
+    RegimeWarning,
+    
+    /// Progress of a running TWAP/VWAP/IS execution
+    ExecutionProgress,
+}
+
+impl TelemetryMessageType {
+    /// Channel/key suffix used for system-wide message types
+    pub fn system_channel(&self) -> &'static str {
+        match self {
+            TelemetryMessageType::Trendline => "trendline",
+            TelemetryMessageType::PerformanceSummary => "performance_summary",
+            TelemetryMessageType::ExecutionStats => "execution_stats",
+            TelemetryMessageType::AnomalyAlert => "anomaly",
+            TelemetryMessageType::TrustScoreUpdate => "trust_score",
+            TelemetryMessageType::HealthCheck => "health",
+            TelemetryMessageType::PositionUpdate => "positions",
+            TelemetryMessageType::DrawdownUpdate => "drawdown",
+            TelemetryMessageType::VenueHealth => "venues",
+            TelemetryMessageType::RegimeWarning => "regime_warnings",
+            TelemetryMessageType::ExecutionProgress => "execution_progress",
+        }
+    }
 }
 
 /// Redis channel names
@@ -161,8 +217,19 @@ pub trait TelemetryStreamer: Send + Sync {
     /// Publish trust score update
     async fn publish_trust_score_update(&self, strategy_id: &str, trust_score: &TrustScore) -> Result<(), TelemetryStreamError>;
     
+    /// Publish a system-wide update (positions, drawdown, venue health, regime warnings)
+    async fn publish_system_update(
+        &self,
+        message_type: TelemetryMessageType,
+        source_id: &str,
+        payload: serde_json::Value,
+    ) -> Result<(), TelemetryStreamError>;
+    
     /// Get channel names for a specific strategy
     fn get_channel_names(&self, strategy_id: &str) -> RedisChannels;
+    
+    /// Pattern matching every channel this streamer publishes to
+    fn channel_pattern(&self) -> String;
 }
 
 /// Redis-based implementation of the TelemetryStreamer
@@ -175,6 +242,12 @@ pub struct RedisTelemetryStreamer {
     
     /// Redis cluster connection (if using cluster mode)
     cluster_connection: Arc<RwLock<Option<ClusterConnection>>>,
+    
+    /// Schema registry payloads are validated against before publishing
+    schema_registry: Option<Arc<TelemetrySchemaRegistry>>,
+    
+    /// Dead-letter queue for writes that fail
+    dead_letters: Option<Arc<DeadLetterQueue>>,
 }
 
 impl RedisTelemetryStreamer {
@@ -184,9 +257,23 @@ impl RedisTelemetryStreamer {
             config,
             connection: Arc::new(RwLock::new(None)),
             cluster_connection: Arc::new(RwLock::new(None)),
+            schema_registry: None,
+            dead_letters: None,
         }
     }
     
+    /// Capture failed Redis writes for replay instead of dropping them
+    pub fn with_dead_letter_queue(mut self, queue: Arc<DeadLetterQueue>) -> Self {
+        self.dead_letters = Some(queue);
+        self
+    }
+    
+    /// Validate payloads against a schema registry before publishing
+    pub fn with_schema_registry(mut self, registry: Arc<TelemetrySchemaRegistry>) -> Self {
+        self.schema_registry = Some(registry);
+        self
+    }
+    
     /// Initialize the Redis connection
     pub async fn initialize(&self) -> Result<(), TelemetryStreamError> {
         if self.config.use_cluster {
@@ -218,9 +305,37 @@ impl RedisTelemetryStreamer {
             info!("Connected to Redis at {}", self.config.redis_url);
         }
         
+        // Publish the schema catalog so consumers can check what they will receive
+        if let Some(registry) = &self.schema_registry {
+            let key = format!("{}:schemas", self.config.key_prefix);
+            let catalog = registry.catalog();
+            let count = catalog.len();
+            let serialized = serde_json::to_string(&catalog)
+                .map_err(|e| TelemetryStreamError::SerializationError(e.to_string()))?;
+            self.execute_redis_command(|conn| {
+                redis::cmd("SET").arg(&key).arg(serialized).query(conn)
+            }).await?;
+            info!("Published {} telemetry schemas to {}", count, key);
+        }
+        
         Ok(())
     }
     
+    /// Check a payload against its registered schema, returning the schema version to stamp on the message
+    fn check_schema<T: Serialize>(&self, message_type: &TelemetryMessageType, payload: &T) -> Result<u32, TelemetryStreamError> {
+        let registry = match &self.schema_registry {
+            Some(registry) => registry,
+            None => return Ok(default_schema_version()),
+        };
+        
+        let value = serde_json::to_value(payload)
+            .map_err(|e| TelemetryStreamError::SerializationError(e.to_string()))?;
+        registry.validate(message_type, &value).map_err(|e| {
+            warn!("Rejected telemetry payload: {}", e);
+            TelemetryStreamError::from(e)
+        })
+    }
+    
     /// Generate a Redis key for a strategy and data type
     fn generate_key(&self, strategy_id: &str, data_type: &str) -> String {
         format!("{}:strategy:{}:{}", self.config.key_prefix, strategy_id, data_type)
@@ -231,6 +346,11 @@ impl RedisTelemetryStreamer {
         format!("{}:channel:strategy:{}:{}", self.config.key_prefix, strategy_id, event_type)
     }
     
+    /// Generate a Redis channel name for a system-wide event type
+    fn generate_system_channel(&self, event_type: &str) -> String {
+        format!("{}:channel:system:{}", self.config.key_prefix, event_type)
+    }
+    
     /// Execute a Redis command
     async fn execute_redis_command<T, F>(&self, f: F) -> Result<T, TelemetryStreamError>
     where
@@ -256,42 +376,62 @@ impl RedisTelemetryStreamer {
         }
     }
     
+    /// Encode a message or value in the configured format
+    fn encode<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, TelemetryStreamError> {
+        self.config.value_format.encode_tagged(value)
+            .map_err(|e| TelemetryStreamError::SerializationError(e.to_string()))
+    }
+    
     /// Publish a message to a Redis channel
     async fn publish_to_channel<T: Serialize>(&self, channel: &str, message: T) -> Result<(), TelemetryStreamError> {
-        let serialized = serde_json::to_string(&message)
-            .map_err(|e| TelemetryStreamError::SerializationError(e.to_string()))?;
+        let serialized = self.encode(&message)?;
         
-        self.execute_redis_command(|conn| {
+        let result: Result<(), _> = self.execute_redis_command(|conn| {
             redis::cmd("PUBLISH")
                 .arg(channel)
                 .arg(serialized)
                 .query(conn)
-        }).await?;
+        }).await;
+        if let Err(e) = result {
+            if let Some(dead_letters) = &self.dead_letters {
+                dead_letters.push_publish(channel, &message, &e);
+            }
+            return Err(e);
+        }
         
         debug!("Published message to channel: {}", channel);
         Ok(())
     }
     
-    /// Store data with optional TTL
-    async fn store_with_ttl<T: Serialize>(&self, key: &str, data: T) -> Result<(), TelemetryStreamError> {
-        let serialized = serde_json::to_string(&data)
-            .map_err(|e| TelemetryStreamError::SerializationError(e.to_string()))?;
-        
-        if self.config.cache_ttl_seconds > 0 {
-            self.execute_redis_command(|conn| {
+    /// SET a serialized value, with SETEX when a TTL is given
+    async fn set_raw(&self, key: &str, serialized: Vec<u8>, ttl: Option<u64>) -> Result<(), TelemetryStreamError> {
+        match ttl {
+            Some(ttl) => self.execute_redis_command(|conn| {
                 redis::cmd("SETEX")
                     .arg(key)
-                    .arg(self.config.cache_ttl_seconds)
+                    .arg(ttl)
                     .arg(serialized)
                     .query(conn)
-            }).await?;
-        } else {
-            self.execute_redis_command(|conn| {
+            }).await,
+            None => self.execute_redis_command(|conn| {
                 redis::cmd("SET")
                     .arg(key)
                     .arg(serialized)
                     .query(conn)
-            }).await?;
+            }).await,
+        }
+    }
+    
+    /// Store data with optional TTL
+    async fn store_with_ttl<T: Serialize>(&self, key: &str, data: T) -> Result<(), TelemetryStreamError> {
+        let serialized = self.encode(&data)?;
+        
+        let ttl = (self.config.cache_ttl_seconds > 0).then(|| self.config.cache_ttl_seconds);
+        if let Err(e) = self.set_raw(key, serialized, ttl).await {
+            if let Some(dead_letters) = &self.dead_letters {
+                dead_letters.push_set(key, &data, ttl, &e);
+            }
+            return Err(e);
         }
         
         debug!("Stored data with key: {}", key);
@@ -310,6 +450,7 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
             message_type: TelemetryMessageType::Trendline,
             strategy_id: strategy_id.to_string(),
             timestamp: chrono::Utc::now(),
+            schema_version: self.check_schema(&TelemetryMessageType::Trendline, &trendline)?,
             payload: trendline.clone(),
         };
         
@@ -331,6 +472,7 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
             message_type: TelemetryMessageType::PerformanceSummary,
             strategy_id: strategy_id.to_string(),
             timestamp: chrono::Utc::now(),
+            schema_version: self.check_schema(&TelemetryMessageType::PerformanceSummary, &summary)?,
             payload: summary.clone(),
         };
         
@@ -352,6 +494,7 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
             message_type: TelemetryMessageType::ExecutionStats,
             strategy_id: strategy_id.to_string(),
             timestamp: chrono::Utc::now(),
+            schema_version: self.check_schema(&TelemetryMessageType::ExecutionStats, &stats)?,
             payload: stats.clone(),
         };
         
@@ -373,12 +516,12 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
             message_type: TelemetryMessageType::AnomalyAlert,
             strategy_id: strategy_id.to_string(),
             timestamp: chrono::Utc::now(),
+            schema_version: self.check_schema(&TelemetryMessageType::AnomalyAlert, &anomaly)?,
             payload: anomaly.clone(),
         };
         
         // Store the anomaly in a list (we'll use LPUSH to maintain recency)
-        let serialized = serde_json::to_string(&message)
-            .map_err(|e| TelemetryStreamError::SerializationError(e.to_string()))?;
+        let serialized = self.encode(&message)?;
             
         // Add to list and trim to max length
         self.execute_redis_command(|conn| {
@@ -404,6 +547,7 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
             message_type: TelemetryMessageType::TrustScoreUpdate,
             strategy_id: strategy_id.to_string(),
             timestamp: chrono::Utc::now(),
+            schema_version: self.check_schema(&TelemetryMessageType::TrustScoreUpdate, &trust_score)?,
             payload: trust_score.clone(),
         };
         
@@ -416,6 +560,34 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
         Ok(())
     }
     
+    async fn publish_system_update(
+        &self,
+        message_type: TelemetryMessageType,
+        source_id: &str,
+        payload: serde_json::Value,
+    ) -> Result<(), TelemetryStreamError> {
+        let event_type = message_type.system_channel();
+        let key = format!("{}:system:{}:{}", self.config.key_prefix, event_type, source_id);
+        let channel = self.generate_system_channel(event_type);
+        
+        // Create telemetry message; strategy_id carries the source (agent, venue, symbol)
+        let message = TelemetryMessage {
+            schema_version: self.check_schema(&message_type, &payload)?,
+            message_type,
+            strategy_id: source_id.to_string(),
+            timestamp: chrono::Utc::now(),
+            payload,
+        };
+        
+        // Store latest state so new subscribers can catch up
+        self.store_with_ttl(&key, &message).await?;
+        
+        // Publish to channel
+        self.publish_to_channel(&channel, message).await?;
+        
+        Ok(())
+    }
+    
     fn get_channel_names(&self, strategy_id: &str) -> RedisChannels {
         RedisChannels {
             trendline: self.generate_channel(strategy_id, "trendline"),
@@ -425,11 +597,106 @@ impl TelemetryStreamer for RedisTelemetryStreamer {
             trust_score: self.generate_channel(strategy_id, "trust_score"),
         }
     }
+    
+    fn channel_pattern(&self) -> String {
+        format!("{}:channel:*", self.config.key_prefix)
+    }
+}
+
+#[async_trait]
+impl DeadLetterReplayer for RedisTelemetryStreamer {
+    async fn is_available(&self) -> bool {
+        let pong: Result<String, _> = self.execute_redis_command(|conn| redis::cmd("PING").query(conn)).await;
+        pong.is_ok()
+    }
+    
+    async fn replay(&self, op: &DeadLetterOp) -> Result<(), String> {
+        let result = match op {
+            DeadLetterOp::Set { key, value, ttl_sec } => {
+                let serialized = self.encode(value).map_err(|e| e.to_string())?;
+                self.set_raw(key, serialized, *ttl_sec).await
+            }
+            DeadLetterOp::Publish { channel, message } => {
+                let serialized = self.encode(message).map_err(|e| e.to_string())?;
+                self.execute_redis_command(|conn| {
+                    redis::cmd("PUBLISH").arg(channel).arg(serialized).query::<i64>(conn)
+                }).await.map(|_| ())
+            }
+            DeadLetterOp::AppendCapped { key, .. } => {
+                return Err(format!("Unsupported dead-letter op for telemetry key {}", key));
+            }
+        };
+        result.map_err(|e| e.to_string())
+    }
+}
+
+/// Forward position changes and drawdown events to the telemetry streamer so
+/// dashboards subscribed to the system channels can follow them
+pub fn spawn_dashboard_bridge(
+    streamer: Arc<dyn TelemetryStreamer>,
+    event_bus: Arc<TradingEventBus>,
+    positions: Option<Arc<PositionManager>>,
+) -> tokio::task::JoinHandle<()> {
+    if let Some(positions) = positions {
+        let position_streamer = streamer.clone();
+        let listener = Arc::new(move |event: &PositionChangeEvent| {
+            let streamer = position_streamer.clone();
+            let source_id = format!("{}:{}", event.agent_id, event.symbol);
+            let payload = serde_json::to_value(event).unwrap_or_default();
+
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                handle.spawn(async move {
+                    if let Err(e) = streamer
+                        .publish_system_update(TelemetryMessageType::PositionUpdate, &source_id, payload)
+                        .await
+                    {
+                        warn!("Failed to publish position update: {}", e);
+                    }
+                });
+            }
+        });
+
+        if let Err(e) = positions.subscribe(listener) {
+            error!("Failed to subscribe to position changes: {}", e);
+        }
+    }
+
+    let mut receiver = event_bus.subscribe();
+    tokio::spawn(async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(TradingEvent::Drawdown(event)) => event,
+                Ok(_) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Dashboard bridge lagged, skipped {} events", skipped);
+                    continue;
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            };
+
+            let payload = serde_json::json!({
+                "agent_id": event.agent_id,
+                "is_active": event.event_type != DrawdownEventType::Breach,
+                "current_drawdown_pct": event.drawdown_pct,
+                "peak_equity": event.peak_equity,
+                "current_equity": event.current_equity,
+                "event_type": event.event_type,
+                "message": event.message,
+            });
+
+            if let Err(e) = streamer
+                .publish_system_update(TelemetryMessageType::DrawdownUpdate, &event.agent_id, payload)
+                .await
+            {
+                warn!("Failed to publish drawdown update: {}", e);
+            }
+        }
+    })
 }
 
 /// Create a new telemetry streamer instance
 pub fn create_telemetry_streamer(config: TelemetryStreamerConfig) -> Arc<RedisTelemetryStreamer> {
-    Arc::new(RedisTelemetryStreamer::new(config))
+    Arc::new(RedisTelemetryStreamer::new(config).with_schema_registry(create_telemetry_schema_registry()))
 }
 
 #[cfg(test)]
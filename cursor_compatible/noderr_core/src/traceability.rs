@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! End-to-end traceability between artifacts
+//!
+//! Components record cross-reference links as they work — the signal that
+//! triggered an order, the fills an order produced, the decisions and
+//! reasons recorded along the way, the telemetry events that reported them.
+//! Given any one ID, the registry walks those links in both directions and
+//! returns the whole chain. Links are optionally appended to a JSONL journal
+//! so a trace can be reconstructed offline with the `trace_inspector` CLI.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+use crate::ids::IdKind;
+
+/// Errors that can occur when journaling or replaying traces
+#[derive(Debug, Error)]
+pub enum TraceError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// How one artifact relates to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceRelation {
+    /// A signal or parent order triggered an order
+    Triggered,
+    /// An order produced a fill
+    FilledBy,
+    /// An order was replaced by another
+    ReplacedBy,
+    /// A decision or reason was recorded about an artifact
+    ExplainedBy,
+    /// A telemetry event reported an artifact
+    ReportedAs,
+}
+
+/// A recorded artifact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceNode {
+    /// Artifact ID
+    pub id: String,
+    /// Kind of artifact, when the ID is a typed ID
+    pub kind: Option<IdKind>,
+    /// Short human-readable description
+    pub summary: Option<String>,
+    /// Structured details
+    pub details: serde_json::Value,
+    /// When the artifact was recorded
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// A directed cross-reference between two artifacts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceLink {
+    /// Upstream artifact
+    pub from: String,
+    /// Downstream artifact
+    pub to: String,
+    /// How the two relate
+    pub relation: TraceRelation,
+    /// When the link was recorded
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Everything reachable from one artifact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trace {
+    /// ID the trace was requested for
+    pub root: String,
+    /// Artifacts in the trace, oldest first
+    pub nodes: Vec<TraceNode>,
+    /// Links between them, oldest first
+    pub links: Vec<TraceLink>,
+}
+
+/// Journal line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+enum TraceRecord {
+    Node(TraceNode),
+    Link(TraceLink),
+}
+
+/// Trace registry configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TraceRegistryConfig {
+    /// Maximum artifacts held in memory; the oldest are evicted with their links
+    pub max_nodes: usize,
+    /// Maximum number of links followed from the root
+    pub max_depth: usize,
+    /// JSONL journal the registry appends to
+    pub journal_path: Option<PathBuf>,
+}
+
+impl Default for TraceRegistryConfig {
+    fn default() -> Self {
+        Self {
+            max_nodes: 100_000,
+            max_depth: 16,
+            journal_path: None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct TraceState {
+    nodes: HashMap<String, TraceNode>,
+    /// Links touching each ID, in either direction
+    links: HashMap<String, Vec<TraceLink>>,
+    /// IDs in the order they were first seen
+    order: VecDeque<String>,
+}
+
+impl TraceState {
+    fn replay(path: &Path, max_nodes: usize) -> Result<Self, TraceError> {
+        let mut state = Self::default();
+        if path.exists() {
+            for line in BufReader::new(File::open(path)?).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                state.apply(serde_json::from_str(&line)?, max_nodes);
+            }
+        }
+        Ok(state)
+    }
+
+    fn touch(&mut self, id: &str, at: DateTime<Utc>) {
+        if !self.nodes.contains_key(id) {
+            self.nodes.insert(id.to_string(), TraceNode {
+                id: id.to_string(),
+                kind: IdKind::of(id),
+                summary: None,
+                details: serde_json::Value::Null,
+                recorded_at: at,
+            });
+            self.order.push_back(id.to_string());
+        }
+    }
+
+    fn apply(&mut self, record: TraceRecord, max_nodes: usize) {
+        match record {
+            TraceRecord::Node(node) => {
+                self.touch(&node.id, node.recorded_at);
+                self.nodes.insert(node.id.clone(), node);
+            }
+            TraceRecord::Link(link) => {
+                self.touch(&link.from, link.timestamp);
+                self.touch(&link.to, link.timestamp);
+                self.links.entry(link.from.clone()).or_default().push(link.clone());
+                if link.to != link.from {
+                    self.links.entry(link.to.clone()).or_default().push(link);
+                }
+            }
+        }
+
+        while self.order.len() > max_nodes {
+            let Some(evicted) = self.order.pop_front() else {
+                break;
+            };
+            self.remove(&evicted);
+        }
+    }
+
+    /// Drop a node and every link touching it (the caller maintains `order`)
+    fn remove(&mut self, id: &str) {
+        self.nodes.remove(id);
+        for link in self.links.remove(id).unwrap_or_default() {
+            let other = if link.from == id { &link.to } else { &link.from };
+            if let Some(links) = self.links.get_mut(other) {
+                links.retain(|l| l.from != id && l.to != id);
+            }
+        }
+    }
+
+    /// Journal records that rebuild the current state
+    fn records(&self) -> Vec<TraceRecord> {
+        let mut nodes: Vec<&TraceNode> = self.nodes.values().collect();
+        nodes.sort_by_key(|node| node.recorded_at);
+        let mut links: Vec<&TraceLink> = self.links
+            .iter()
+            .flat_map(|(id, links)| links.iter().filter(move |link| link.from == *id))
+            .collect();
+        links.sort_by_key(|link| link.timestamp);
+
+        nodes.into_iter().cloned().map(TraceRecord::Node)
+            .chain(links.into_iter().cloned().map(TraceRecord::Link))
+            .collect()
+    }
+
+    fn trace(&self, id: &str, max_depth: usize) -> Option<Trace> {
+        if !self.nodes.contains_key(id) {
+            return None;
+        }
+
+        let mut seen: HashSet<&str> = HashSet::from([id]);
+        let mut frontier = vec![id];
+        let mut links: Vec<TraceLink> = Vec::new();
+        for _ in 0..max_depth {
+            let mut next = Vec::new();
+            for current in frontier {
+                for link in self.links.get(current).into_iter().flatten() {
+                    let other = if link.from == current { link.to.as_str() } else { link.from.as_str() };
+                    if seen.insert(other) {
+                        next.push(other);
+                    }
+                    // Each link is reached from both of its ends
+                    if !links.contains(link) {
+                        links.push(link.clone());
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        links.sort_by_key(|link| link.timestamp);
+
+        let mut nodes: Vec<TraceNode> = seen.iter().filter_map(|id| self.nodes.get(*id).cloned()).collect();
+        nodes.sort_by(|a, b| a.recorded_at.cmp(&b.recorded_at).then_with(|| a.id.cmp(&b.id)));
+
+        Some(Trace { root: id.to_string(), nodes, links })
+    }
+}
+
+/// Registry of cross-reference links between artifacts
+pub struct TraceRegistry {
+    /// Configuration
+    config: TraceRegistryConfig,
+    /// Artifacts and links
+    state: RwLock<TraceState>,
+}
+
+impl TraceRegistry {
+    /// Create an empty registry
+    pub fn new(config: TraceRegistryConfig) -> Self {
+        Self { config, state: RwLock::new(TraceState::default()) }
+    }
+
+    /// Rebuild a registry from a journal; new records are appended to the same journal
+    pub fn load(mut config: TraceRegistryConfig, path: impl Into<PathBuf>) -> Result<Self, TraceError> {
+        let path = path.into();
+        let state = TraceState::replay(&path, config.max_nodes)?;
+        config.journal_path = Some(path);
+        Ok(Self { config, state: RwLock::new(state) })
+    }
+
+    fn journal(&self, record: &TraceRecord) -> Result<(), TraceError> {
+        let Some(path) = &self.config.journal_path else {
+            return Ok(());
+        };
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+
+    async fn apply(&self, record: TraceRecord) -> Result<(), TraceError> {
+        // Held across the journal write so the journal replays in memory order
+        let mut state = self.state.write().await;
+        self.journal(&record)?;
+        state.apply(record, self.config.max_nodes);
+        Ok(())
+    }
+
+    /// Record an artifact with a description and details
+    pub async fn record(&self, id: &str, summary: &str, details: serde_json::Value) -> Result<(), TraceError> {
+        self.apply(TraceRecord::Node(TraceNode {
+            id: id.to_string(),
+            kind: IdKind::of(id),
+            summary: Some(summary.to_string()),
+            details,
+            recorded_at: Utc::now(),
+        })).await
+    }
+
+    /// Link two artifacts
+    pub async fn link(&self, from: &str, to: &str, relation: TraceRelation) -> Result<(), TraceError> {
+        self.apply(TraceRecord::Link(TraceLink {
+            from: from.to_string(),
+            to: to.to_string(),
+            relation,
+            timestamp: Utc::now(),
+        })).await
+    }
+
+    /// Artifacts recorded before `cutoff`
+    pub async fn count_before(&self, cutoff: DateTime<Utc>) -> usize {
+        self.state.read().await.nodes.values().filter(|node| node.recorded_at < cutoff).count()
+    }
+
+    /// Drop artifacts recorded before `cutoff` and their links, compacting the journal to match
+    pub async fn purge_before(&self, cutoff: DateTime<Utc>) -> Result<usize, TraceError> {
+        let mut state = self.state.write().await;
+        let expired: HashSet<String> = state.nodes
+            .values()
+            .filter(|node| node.recorded_at < cutoff)
+            .map(|node| node.id.clone())
+            .collect();
+        for id in &expired {
+            state.remove(id);
+        }
+        state.order.retain(|id| !expired.contains(id));
+
+        // The journal must not outlive the data it recorded
+        if let Some(path) = &self.config.journal_path {
+            let compacted = path.with_extension("compact");
+            {
+                let mut file = File::create(&compacted)?;
+                for record in state.records() {
+                    writeln!(file, "{}", serde_json::to_string(&record)?)?;
+                }
+                file.sync_all()?;
+            }
+            std::fs::rename(&compacted, path)?;
+        }
+
+        Ok(expired.len())
+    }
+
+    /// A recorded artifact
+    pub async fn node(&self, id: &str) -> Option<TraceNode> {
+        self.state.read().await.nodes.get(id).cloned()
+    }
+
+    /// Everything linked to `id`, upstream and downstream, or None if the ID is unknown
+    pub async fn trace(&self, id: &str) -> Option<Trace> {
+        self.state.read().await.trace(id, self.config.max_depth)
+    }
+}
+
+/// Create a trace registry
+pub fn create_trace_registry(config: TraceRegistryConfig) -> Arc<TraceRegistry> {
+    Arc::new(TraceRegistry::new(config))
+}
+
+/// Rebuild a trace from a journal file without a running core
+pub fn trace_from_journal(path: &Path, id: &str) -> Result<Option<Trace>, TraceError> {
+    let config = TraceRegistryConfig::default();
+    Ok(TraceState::replay(path, config.max_nodes)?.trace(id, config.max_depth))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::new_id;
+
+    #[tokio::test]
+    async fn test_trace_follows_links_both_ways_and_replays() {
+        let path = std::env::temp_dir().join(format!("noderr_trace_{}.jsonl", new_id(IdKind::Decision)));
+        let config = TraceRegistryConfig { journal_path: Some(path.clone()), ..Default::default() };
+        let registry = TraceRegistry::new(config);
+
+        let signal = new_id(IdKind::Signal);
+        let order = new_id(IdKind::Order);
+        let fill = new_id(IdKind::Fill);
+        let reason = new_id(IdKind::Decision);
+        let unrelated = new_id(IdKind::Order);
+
+        registry.record(&signal, "Buy BTC/USDT", serde_json::json!({ "strength": 0.8 })).await.unwrap();
+        registry.link(&signal, &order, TraceRelation::Triggered).await.unwrap();
+        registry.link(&order, &fill, TraceRelation::FilledBy).await.unwrap();
+        registry.link(&order, &reason, TraceRelation::ExplainedBy).await.unwrap();
+        registry.record(&unrelated, "Other order", serde_json::Value::Null).await.unwrap();
+
+        // Tracing from the fill reaches the signal upstream and the reason beside it
+        let trace = registry.trace(&fill).await.unwrap();
+        let ids: HashSet<&str> = trace.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, HashSet::from([signal.as_str(), order.as_str(), fill.as_str(), reason.as_str()]));
+        assert_eq!(trace.links.len(), 3);
+        assert_eq!(registry.node(&order).await.unwrap().kind, Some(IdKind::Order));
+        assert!(registry.trace("ord_missing").await.is_none());
+
+        let replayed = trace_from_journal(&path, &signal).unwrap().unwrap();
+        assert_eq!(replayed.links, trace.links);
+        assert_eq!(replayed.nodes[0].summary.as_deref(), Some("Buy BTC/USDT"));
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -24,6 +24,8 @@ use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
 use crate::telemetry::TelemetryEvent;
+use crate::risk_budget::RiskBudgetTracker;
+use crate::volatility::{VolatilityEstimator, VolatilityService};
 
 /// Dynamic trade sizer errors
 #[derive(Debug, Error)]
@@ -151,6 +153,12 @@ pub struct DynamicTradeSizer {
     
     /// Telemetry sender (optional)
     telemetry_sender: Option<tokio::sync::mpsc::Sender<TelemetryEvent>>,
+    
+    /// Risk budget tracker used to taper sizes as budgets are consumed (optional)
+    risk_budget: Option<Arc<RiskBudgetTracker>>,
+    
+    /// Shared volatility estimates preferred over the sizer's own (optional)
+    volatility_service: Option<(Arc<VolatilityService>, VolatilityEstimator)>,
 }
 
 impl DynamicTradeSizer {
@@ -174,6 +182,8 @@ impl DynamicTradeSizer {
             config: Arc::new(RwLock::new(config)),
             symbol_states: Arc::new(RwLock::new(HashMap::new())),
             telemetry_sender: None,
+            risk_budget: None,
+            volatility_service: None,
         }
     }
     
@@ -186,6 +196,18 @@ impl DynamicTradeSizer {
         self
     }
     
+    /// Set risk budget tracker
+    pub fn with_risk_budget(mut self, risk_budget: Arc<RiskBudgetTracker>) -> Self {
+        self.risk_budget = Some(risk_budget);
+        self
+    }
+    
+    /// Size from the shared volatility service's per-bar estimate when it has one
+    pub fn with_volatility_service(mut self, service: Arc<VolatilityService>, estimator: VolatilityEstimator) -> Self {
+        self.volatility_service = Some((service, estimator));
+        self
+    }
+    
     /// Update configuration
     pub async fn update_config(&self, config: TradeSizerConfig) {
         let mut current_config = self.config.write().await;
@@ -200,9 +222,10 @@ impl DynamicTradeSizer {
     ) -> Result<f64, TradeSizerError> {
         let state = self.get_or_create_state(symbol).await;
         let config = self.config.read().await;
+        let volatility = self.shared_volatility(symbol).unwrap_or(state.current_volatility);
         
         // Calculate volatility factor
-        let volatility_factor = self.calculate_volatility_factor(state.current_volatility, &config);
+        let volatility_factor = self.calculate_volatility_factor(volatility, &config);
         
         // Determine size factor
         let size_factor = f64::max(
@@ -221,7 +244,7 @@ impl DynamicTradeSizer {
         
         // Log sizing decision
         if config.enable_logging {
-            self.log_sizing(symbol, base_size, size_factor, size, state.current_volatility, &config).await;
+            self.log_sizing(symbol, base_size, size_factor, size, volatility, &config).await;
         }
         
         // Send telemetry event
@@ -232,7 +255,7 @@ impl DynamicTradeSizer {
                     "symbol": symbol,
                     "base_size": base_size,
                     "size_factor": size_factor,
-                    "volatility": state.current_volatility,
+                    "volatility": volatility,
                     "final_size": size,
                 }),
             );
@@ -246,12 +269,52 @@ impl DynamicTradeSizer {
             "[DynamicTradeSizer] {}: {} (volatility: {:.2}%)",
             symbol,
             size,
-            state.current_volatility * 100.0
+            volatility * 100.0
         );
         
         Ok(size)
     }
     
+    /// Calculate position size for a strategy, tapering by its remaining risk budget
+    pub async fn calculate_strategy_position_size(
+        &self,
+        strategy_id: &str,
+        symbol: &str,
+        base_size: f64,
+    ) -> Result<f64, TradeSizerError> {
+        let size = self.calculate_position_size(symbol, base_size).await?;
+        
+        let budget_factor = match &self.risk_budget {
+            Some(risk_budget) => risk_budget.size_multiplier(strategy_id).await,
+            None => return Ok(size),
+        };
+        
+        if budget_factor < 1.0 {
+            debug!(
+                "[DynamicTradeSizer] {} risk budget taper for {}: {:.2}",
+                strategy_id, symbol, budget_factor
+            );
+            
+            if let Some(sender) = &self.telemetry_sender {
+                let event = TelemetryEvent::new(
+                    "risk_budget_taper",
+                    serde_json::json!({
+                        "strategy_id": strategy_id,
+                        "symbol": symbol,
+                        "budget_factor": budget_factor,
+                        "final_size": size * budget_factor,
+                    }),
+                );
+                
+                if let Err(e) = sender.try_send(event) {
+                    warn!("Failed to send telemetry event: {}", e);
+                }
+            }
+        }
+        
+        Ok(size * budget_factor)
+    }
+    
     /// Update volatility for a symbol based on new price data
     pub async fn update_volatility(
         &self,
@@ -325,6 +388,12 @@ impl DynamicTradeSizer {
         }
     }
     
+    /// Per-bar volatility from the shared service, if attached and warmed up
+    fn shared_volatility(&self, symbol: &str) -> Option<f64> {
+        let (service, estimator) = self.volatility_service.as_ref()?;
+        service.per_bar(symbol, *estimator)
+    }
+    
     /// Get or create state for a symbol
     async fn get_or_create_state(&self, symbol: &str) -> SymbolState {
         let mut states = self.symbol_states.write().await;
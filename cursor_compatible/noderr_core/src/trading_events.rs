@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::drawdown_monitor::DrawdownEvent;
+use crate::execution::ExecutionStatus;
+use crate::risk_calc::RiskViolation;
+
+/// Kind of trading event, used to filter subscriptions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TradingEventKind {
+    Fill,
+    OrderState,
+    RiskViolation,
+    Drawdown,
+}
+
+/// Push notification emitted by the trading core
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TradingEvent {
+    /// An order was filled on a venue
+    Fill {
+        order_id: String,
+        symbol: String,
+        venue: String,
+        quantity: f64,
+        price: f64,
+        timestamp: DateTime<Utc>,
+    },
+    /// An order changed state
+    OrderState {
+        order_id: String,
+        symbol: String,
+        status: ExecutionStatus,
+        venue: Option<String>,
+        reason: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
+    /// A pre-trade risk check failed
+    RiskViolation {
+        symbol: String,
+        venue: String,
+        strategy_id: Option<String>,
+        violations: Vec<RiskViolation>,
+        risk_level: f64,
+        timestamp: DateTime<Utc>,
+    },
+    /// A drawdown alert, breach or recovery
+    Drawdown(DrawdownEvent),
+}
+
+impl TradingEvent {
+    /// Kind of this event
+    pub fn kind(&self) -> TradingEventKind {
+        match self {
+            TradingEvent::Fill { .. } => TradingEventKind::Fill,
+            TradingEvent::OrderState { .. } => TradingEventKind::OrderState,
+            TradingEvent::RiskViolation { .. } => TradingEventKind::RiskViolation,
+            TradingEvent::Drawdown(_) => TradingEventKind::Drawdown,
+        }
+    }
+}
+
+/// Bounded broadcast bus for trading events
+pub struct TradingEventBus {
+    /// Broadcast sender
+    sender: broadcast::Sender<TradingEvent>,
+    /// Events published
+    published: AtomicU64,
+}
+
+impl TradingEventBus {
+    /// Create a new event bus holding at most `capacity` undelivered events per subscriber
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self {
+            sender,
+            published: AtomicU64::new(0),
+        }
+    }
+
+    /// Publish an event; events are dropped when there are no subscribers
+    pub fn publish(&self, event: TradingEvent) {
+        self.published.fetch_add(1, Ordering::Relaxed);
+        let _ = self.sender.send(event);
+    }
+
+    /// Subscribe to all events
+    pub fn subscribe(&self) -> broadcast::Receiver<TradingEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Number of events published
+    pub fn published_count(&self) -> u64 {
+        self.published.load(Ordering::Relaxed)
+    }
+
+    /// Number of active subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+/// Create a trading event bus
+pub fn create_trading_event_bus(capacity: usize) -> Arc<TradingEventBus> {
+    Arc::new(TradingEventBus::new(capacity))
+}
+
+/// Forward events of one kind to `deliver` until the bus is dropped.
+///
+/// Events the receiver lagged past, and events `deliver` rejects, are counted in `dropped`.
+pub async fn forward_events<F>(
+    mut receiver: broadcast::Receiver<TradingEvent>,
+    kind: TradingEventKind,
+    dropped: Arc<AtomicU64>,
+    mut deliver: F,
+) where
+    F: FnMut(TradingEvent) -> bool,
+{
+    loop {
+        match receiver.recv().await {
+            Ok(event) if event.kind() == kind => {
+                if !deliver(event) {
+                    dropped.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            Ok(_) => {}
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                dropped.fetch_add(missed, Ordering::Relaxed);
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(order_id: &str) -> TradingEvent {
+        TradingEvent::Fill {
+            order_id: order_id.to_string(),
+            symbol: "BTC-USD".to_string(),
+            venue: "binance".to_string(),
+            quantity: 1.0,
+            price: 50000.0,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn order_state(order_id: &str) -> TradingEvent {
+        TradingEvent::OrderState {
+            order_id: order_id.to_string(),
+            symbol: "BTC-USD".to_string(),
+            status: ExecutionStatus::Cancelled,
+            venue: None,
+            reason: Some("user request".to_string()),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_published_events() {
+        let bus = TradingEventBus::new(16);
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+
+        bus.publish(fill("order-1"));
+        assert_eq!(bus.published_count(), 1);
+
+        for receiver in [&mut first, &mut second] {
+            match receiver.recv().await.unwrap() {
+                TradingEvent::Fill { order_id, .. } => assert_eq!(order_id, "order-1"),
+                other => panic!("expected a fill, got {:?}", other.kind()),
+            }
+        }
+
+        drop(second);
+        assert_eq!(bus.subscriber_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_publish_without_subscribers_is_counted_and_dropped() {
+        let bus = TradingEventBus::new(16);
+        bus.publish(fill("order-1"));
+        assert_eq!(bus.published_count(), 1);
+
+        // Late subscribers only see events published after they subscribe
+        let mut receiver = bus.subscribe();
+        bus.publish(fill("order-2"));
+        match receiver.recv().await.unwrap() {
+            TradingEvent::Fill { order_id, .. } => assert_eq!(order_id, "order-2"),
+            other => panic!("expected a fill, got {:?}", other.kind()),
+        }
+        assert_eq!(bus.published_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_lags_once_capacity_is_exceeded() {
+        let bus = TradingEventBus::new(2);
+        let mut receiver = bus.subscribe();
+        for i in 0..5 {
+            bus.publish(fill(&format!("order-{}", i)));
+        }
+
+        match receiver.recv().await {
+            Err(broadcast::error::RecvError::Lagged(missed)) => assert_eq!(missed, 3),
+            other => panic!("expected the receiver to lag, got {:?}", other.map(|e| e.kind())),
+        }
+        match receiver.recv().await.unwrap() {
+            TradingEvent::Fill { order_id, .. } => assert_eq!(order_id, "order-3"),
+            other => panic!("expected a fill, got {:?}", other.kind()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_forward_events_filters_by_kind_and_counts_drops() {
+        let bus = TradingEventBus::new(16);
+        let receiver = bus.subscribe();
+        let dropped = Arc::new(AtomicU64::new(0));
+
+        bus.publish(fill("order-1"));
+        bus.publish(order_state("order-1"));
+        bus.publish(fill("order-2"));
+        bus.publish(fill("order-3"));
+        drop(bus);
+
+        // Accept the first two fills and reject the rest, as a full JS queue would
+        let mut delivered = Vec::new();
+        forward_events(receiver, TradingEventKind::Fill, dropped.clone(), |event| {
+            if delivered.len() == 2 {
+                return false;
+            }
+            delivered.push(event);
+            true
+        })
+        .await;
+
+        assert_eq!(delivered.len(), 2);
+        assert!(delivered.iter().all(|event| event.kind() == TradingEventKind::Fill));
+        assert_eq!(dropped.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_forward_events_counts_lagged_events_as_dropped() {
+        let bus = TradingEventBus::new(2);
+        let receiver = bus.subscribe();
+        let dropped = Arc::new(AtomicU64::new(0));
+
+        for i in 0..5 {
+            bus.publish(fill(&format!("order-{}", i)));
+        }
+        drop(bus);
+
+        let mut delivered = 0;
+        forward_events(receiver, TradingEventKind::Fill, dropped.clone(), |_| {
+            delivered += 1;
+            true
+        })
+        .await;
+
+        assert_eq!(delivered, 2);
+        assert_eq!(dropped.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_events_serialize_with_type_tag() {
+        let value = serde_json::to_value(fill("order-1")).unwrap();
+        assert_eq!(value["type"], "Fill");
+        assert_eq!(value["order_id"], "order-1");
+
+        let value = serde_json::to_value(order_state("order-1")).unwrap();
+        assert_eq!(value["type"], "OrderState");
+        assert_eq!(order_state("order-1").kind(), TradingEventKind::OrderState);
+    }
+}
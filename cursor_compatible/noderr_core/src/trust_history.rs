@@ -0,0 +1,354 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Append-only on-disk trust score history.
+//!
+//! Each strategy's scores are appended to their own JSON-lines file. Old
+//! entries are periodically compacted: recent scores are kept verbatim, older
+//! ones are thinned to one per hour and then one per day, so long ranges stay
+//! cheap to read without losing the shape of the series.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{debug, warn};
+
+use crate::trust_score_engine::TrustScoreHistoryEntry;
+
+/// Errors raised by the trust history store
+#[derive(Debug, Error)]
+pub enum TrustHistoryError {
+    #[error("Trust history I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Trust history serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Result type for trust history operations
+pub type TrustHistoryResult<T> = Result<T, TrustHistoryError>;
+
+/// Trust history store configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrustHistoryConfig {
+    /// Directory the per-strategy series are written to
+    pub dir: PathBuf,
+    /// Entries younger than this are never compacted
+    pub raw_retention_hours: u64,
+    /// Entries younger than this are thinned to one per hour, older ones to one per day
+    pub hourly_retention_days: u64,
+    /// Entries older than this are dropped; 0 keeps them forever
+    pub retention_days: u64,
+    /// Compact a series after this many appends
+    pub compact_every: usize,
+}
+
+impl Default for TrustHistoryConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("data/trust_history"),
+            raw_retention_hours: 48,
+            hourly_retention_days: 30,
+            retention_days: 0,
+            compact_every: 500,
+        }
+    }
+}
+
+/// Outcome of compacting a series
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrustHistoryCompaction {
+    /// Entries before compaction
+    pub before: usize,
+    /// Entries kept
+    pub after: usize,
+}
+
+/// Thin a series according to the retention tiers, returning it oldest first
+pub fn compact_entries(
+    mut entries: Vec<TrustScoreHistoryEntry>,
+    now: DateTime<Utc>,
+    config: &TrustHistoryConfig,
+) -> Vec<TrustScoreHistoryEntry> {
+    entries.sort_by_key(|e| e.timestamp);
+
+    let raw_cutoff = now - Duration::hours(config.raw_retention_hours as i64);
+    let hourly_cutoff = now - Duration::days(config.hourly_retention_days as i64);
+    let drop_cutoff = (config.retention_days > 0).then(|| now - Duration::days(config.retention_days as i64));
+
+    // Keep the last entry of each bucket; raw entries get a bucket of their own
+    let mut buckets: BTreeMap<(u8, i64), TrustScoreHistoryEntry> = BTreeMap::new();
+    let mut raw = Vec::new();
+    for entry in entries {
+        if drop_cutoff.is_some_and(|cutoff| entry.timestamp < cutoff) {
+            continue;
+        }
+        let ts = entry.timestamp.timestamp();
+        if entry.timestamp >= raw_cutoff {
+            raw.push(entry);
+        } else if entry.timestamp >= hourly_cutoff {
+            buckets.insert((1, ts.div_euclid(3_600)), entry);
+        } else {
+            buckets.insert((0, ts.div_euclid(86_400)), entry);
+        }
+    }
+
+    let mut compacted: Vec<_> = buckets.into_values().collect();
+    compacted.sort_by_key(|e| e.timestamp);
+    compacted.extend(raw);
+    compacted
+}
+
+/// Append counts per series, used to decide when to compact
+#[derive(Default)]
+struct SeriesState {
+    appends_since_compaction: usize,
+}
+
+/// File-backed, append-only trust score history
+pub struct TrustHistoryStore {
+    /// Configuration
+    config: TrustHistoryConfig,
+    /// Per-strategy bookkeeping; the lock also serialises file access
+    series: Mutex<HashMap<String, SeriesState>>,
+}
+
+impl TrustHistoryStore {
+    /// Open a store, creating its directory if needed
+    pub fn open(config: TrustHistoryConfig) -> TrustHistoryResult<Self> {
+        fs::create_dir_all(&config.dir)?;
+        Ok(Self {
+            config,
+            series: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Append an entry to a strategy's series, compacting it when due
+    pub fn append(&self, strategy_id: &str, entry: &TrustScoreHistoryEntry) -> TrustHistoryResult<()> {
+        let mut series = self.series.lock().unwrap();
+        let mut file = OpenOptions::new().create(true).append(true).open(self.path(strategy_id))?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+
+        let state = series.entry(strategy_id.to_string()).or_default();
+        state.appends_since_compaction += 1;
+        if self.config.compact_every > 0 && state.appends_since_compaction >= self.config.compact_every {
+            state.appends_since_compaction = 0;
+            let result = self.compact_locked(strategy_id, Utc::now())?;
+            debug!(
+                "Compacted trust history for {}: {} -> {} entries",
+                strategy_id, result.before, result.after
+            );
+        }
+        Ok(())
+    }
+
+    /// Entries for a strategy within `[from, to]`, oldest first
+    pub fn range(
+        &self,
+        strategy_id: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> TrustHistoryResult<Vec<TrustScoreHistoryEntry>> {
+        let _series = self.series.lock().unwrap();
+        let mut entries = self.read(strategy_id)?;
+        entries.retain(|e| {
+            !from.is_some_and(|from| e.timestamp < from) && !to.is_some_and(|to| e.timestamp > to)
+        });
+        entries.sort_by_key(|e| e.timestamp);
+        Ok(entries)
+    }
+
+    /// Compact a strategy's series now
+    pub fn compact(&self, strategy_id: &str) -> TrustHistoryResult<TrustHistoryCompaction> {
+        self.compact_at(strategy_id, Utc::now())
+    }
+
+    /// Compact a strategy's series as of `now`
+    pub fn compact_at(&self, strategy_id: &str, now: DateTime<Utc>) -> TrustHistoryResult<TrustHistoryCompaction> {
+        let mut series = self.series.lock().unwrap();
+        series.entry(strategy_id.to_string()).or_default().appends_since_compaction = 0;
+        self.compact_locked(strategy_id, now)
+    }
+
+    /// Compact every stored series, returning the number of entries removed
+    pub fn compact_all(&self) -> TrustHistoryResult<usize> {
+        let mut removed = 0;
+        for strategy_id in self.strategy_ids()? {
+            let result = self.compact(&strategy_id)?;
+            removed += result.before - result.after;
+        }
+        Ok(removed)
+    }
+
+    /// Strategies with a stored series
+    pub fn strategy_ids(&self) -> TrustHistoryResult<Vec<String>> {
+        let mut ids = Vec::new();
+        for entry in fs::read_dir(&self.config.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+                continue;
+            }
+            if let Some(id) = path.file_stem().and_then(|s| s.to_str()).and_then(decode_file_stem) {
+                ids.push(id);
+            }
+        }
+        ids.sort();
+        Ok(ids)
+    }
+
+    fn compact_locked(&self, strategy_id: &str, now: DateTime<Utc>) -> TrustHistoryResult<TrustHistoryCompaction> {
+        let entries = self.read(strategy_id)?;
+        let before = entries.len();
+        if before == 0 {
+            return Ok(TrustHistoryCompaction::default());
+        }
+
+        let compacted = compact_entries(entries, now, &self.config);
+        let path = self.path(strategy_id);
+        let tmp = path.with_extension("jsonl.tmp");
+        {
+            let mut file = File::create(&tmp)?;
+            for entry in &compacted {
+                writeln!(file, "{}", serde_json::to_string(entry)?)?;
+            }
+            file.sync_all()?;
+        }
+        fs::rename(tmp, &path)?;
+
+        Ok(TrustHistoryCompaction { before, after: compacted.len() })
+    }
+
+    fn read(&self, strategy_id: &str) -> TrustHistoryResult<Vec<TrustScoreHistoryEntry>> {
+        let path = self.path(strategy_id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for line in BufReader::new(File::open(&path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<TrustScoreHistoryEntry>(&line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => warn!("Skipping corrupt trust history entry in {}: {}", path.display(), e),
+            }
+        }
+        Ok(entries)
+    }
+
+    fn path(&self, strategy_id: &str) -> PathBuf {
+        self.config.dir.join(format!("{}.jsonl", encode_file_stem(strategy_id)))
+    }
+}
+
+/// Percent-encode everything but `[A-Za-z0-9_-]` so any strategy ID maps to a safe, reversible file name
+fn encode_file_stem(strategy_id: &str) -> String {
+    let mut stem = String::with_capacity(strategy_id.len());
+    for byte in strategy_id.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-' {
+            stem.push(byte as char);
+        } else {
+            stem.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    stem
+}
+
+fn decode_file_stem(stem: &str) -> Option<String> {
+    let bytes = stem.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = stem.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Open a trust history store
+pub fn create_trust_history_store(config: TrustHistoryConfig) -> TrustHistoryResult<Arc<TrustHistoryStore>> {
+    Ok(Arc::new(TrustHistoryStore::open(config)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trust_score_engine::TrustScoreFeatures;
+    use chrono::TimeZone;
+
+    fn entry(timestamp: DateTime<Utc>, score: f64) -> TrustScoreHistoryEntry {
+        TrustScoreHistoryEntry {
+            score,
+            features: TrustScoreFeatures::default(),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_append_range_and_compact() {
+        let config = TrustHistoryConfig {
+            dir: std::env::temp_dir().join(format!("noderr-trust-history-{}", uuid::Uuid::new_v4())),
+            raw_retention_hours: 1,
+            hourly_retention_days: 1,
+            compact_every: 0,
+            ..TrustHistoryConfig::default()
+        };
+        let store = TrustHistoryStore::open(config.clone()).unwrap();
+        let now = Utc::now();
+        let strategy = "mean/reversion v2";
+
+        // Three raw points, four in one hour a few hours back, three on one day last week
+        for minutes in [5, 10, 15] {
+            store.append(strategy, &entry(now - Duration::minutes(minutes), 0.8)).unwrap();
+        }
+        let hour = Utc.timestamp_opt((now - Duration::hours(5)).timestamp() / 3_600 * 3_600, 0).unwrap();
+        for minutes in [1, 2, 3, 4] {
+            store.append(strategy, &entry(hour + Duration::minutes(minutes), 0.6 + minutes as f64 / 100.0)).unwrap();
+        }
+        let day = Utc.timestamp_opt((now - Duration::days(7)).timestamp() / 86_400 * 86_400, 0).unwrap();
+        for hours in [1, 2, 3] {
+            store.append(strategy, &entry(day + Duration::hours(hours), 0.5)).unwrap();
+        }
+
+        let recent = store.range(strategy, Some(now - Duration::hours(1)), None).unwrap();
+        assert_eq!(recent.len(), 3);
+        assert!(recent.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+
+        let result = store.compact_at(strategy, now).unwrap();
+        assert_eq!(result, TrustHistoryCompaction { before: 10, after: 5 });
+
+        let all = store.range(strategy, None, None).unwrap();
+        assert_eq!(all[0].timestamp, day + Duration::hours(3));
+        assert!((all[1].score - 0.64).abs() < 1e-9);
+        assert_eq!(store.strategy_ids().unwrap(), vec![strategy.to_string()]);
+
+        fs::remove_dir_all(config.dir).ok();
+    }
+}
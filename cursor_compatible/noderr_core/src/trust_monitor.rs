@@ -1,7 +1,11 @@
 use anyhow::Result;
 use std::any::Any;
 use async_trait::async_trait;
-use std::collections::HashMap;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::{broadcast, RwLock};
+use tracing::{info, warn};
 
 /// Interface for monitoring agent trust and validation
 #[async_trait]
@@ -126,4 +130,535 @@ pub struct TrustComparison {
     
     /// List of worsened metrics
     pub worsened_metrics: Vec<String>,
-} 
\ No newline at end of file
+} 
+
+/// Service level agreement a strategy must meet to keep its execution tier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySla {
+    /// Minimum acceptable trust score
+    pub min_trust_score: f64,
+
+    /// Maximum fraction of observations flagged as anomalous (0.0-1.0)
+    pub max_anomaly_rate: f64,
+
+    /// Maximum cumulative downtime within the window (seconds)
+    pub max_downtime_secs: i64,
+
+    /// Rolling window the anomaly rate and downtime are measured over (seconds)
+    pub window_secs: i64,
+}
+
+impl Default for StrategySla {
+    fn default() -> Self {
+        Self {
+            min_trust_score: 0.6,
+            max_anomaly_rate: 0.05,
+            max_downtime_secs: 300,
+            window_secs: 3600,
+        }
+    }
+}
+
+/// Execution tier a strategy runs at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlaTier {
+    /// Trading with full allocation
+    Full,
+
+    /// Trading with reduced allocation
+    Reduced,
+
+    /// Signals are generated and recorded but no orders are sent
+    Shadow,
+
+    /// Strategy is switched off
+    Disabled,
+}
+
+impl SlaTier {
+    /// Next tier down, if any
+    pub fn demoted(&self) -> Option<SlaTier> {
+        match self {
+            SlaTier::Full => Some(SlaTier::Reduced),
+            SlaTier::Reduced => Some(SlaTier::Shadow),
+            SlaTier::Shadow => Some(SlaTier::Disabled),
+            SlaTier::Disabled => None,
+        }
+    }
+
+    /// Next tier up, if any
+    pub fn promoted(&self) -> Option<SlaTier> {
+        match self {
+            SlaTier::Full => None,
+            SlaTier::Reduced => Some(SlaTier::Full),
+            SlaTier::Shadow => Some(SlaTier::Reduced),
+            SlaTier::Disabled => Some(SlaTier::Shadow),
+        }
+    }
+
+    /// Allocation multiplier applied to the strategy's position sizes
+    pub fn allocation_multiplier(&self) -> f64 {
+        match self {
+            SlaTier::Full => 1.0,
+            SlaTier::Reduced => 0.5,
+            SlaTier::Shadow | SlaTier::Disabled => 0.0,
+        }
+    }
+
+    /// Whether the strategy may send live orders
+    pub fn can_trade(&self) -> bool {
+        matches!(self, SlaTier::Full | SlaTier::Reduced)
+    }
+}
+
+/// SLA dimension that was breached
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlaBreach {
+    /// Trust score fell below the minimum
+    TrustScore { actual: f64, minimum: f64 },
+
+    /// Too many anomalous observations in the window
+    AnomalyRate { actual: f64, maximum: f64 },
+
+    /// Too much downtime in the window
+    Downtime { actual_secs: i64, maximum_secs: i64 },
+}
+
+/// Demotion and promotion policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicy {
+    /// SLA applied to strategies without an override
+    pub default_sla: StrategySla,
+
+    /// Consecutive breaching evaluations before a strategy is demoted one tier
+    pub demotion_after_breaches: u32,
+
+    /// Consecutive healthy evaluations before a strategy is promoted one tier
+    pub promotion_after_healthy: u32,
+
+    /// Minimum time a strategy stays in a tier before it can be promoted (seconds)
+    pub min_tier_dwell_secs: i64,
+
+    /// Whether disabled strategies can be promoted automatically
+    pub allow_promotion_from_disabled: bool,
+
+    /// Maximum tier changes kept per strategy
+    pub max_history: usize,
+}
+
+impl Default for SlaPolicy {
+    fn default() -> Self {
+        Self {
+            default_sla: StrategySla::default(),
+            demotion_after_breaches: 3,
+            promotion_after_healthy: 12,
+            min_tier_dwell_secs: 3600,
+            allow_promotion_from_disabled: false,
+            max_history: 100,
+        }
+    }
+}
+
+/// Recorded change of a strategy's tier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TierChange {
+    /// Strategy ID
+    pub strategy_id: String,
+
+    /// Tier before the change
+    pub from: SlaTier,
+
+    /// Tier after the change
+    pub to: SlaTier,
+
+    /// Breaches that caused a demotion (empty for promotions and manual changes)
+    pub breaches: Vec<SlaBreach>,
+
+    /// Human-readable reason
+    pub reason: String,
+
+    /// When the change happened (milliseconds since epoch)
+    pub timestamp: i64,
+}
+
+/// Current SLA standing of a strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaStatus {
+    /// Strategy ID
+    pub strategy_id: String,
+
+    /// Current tier
+    pub tier: SlaTier,
+
+    /// SLA in force for the strategy
+    pub sla: StrategySla,
+
+    /// Latest trust score, if one has been recorded
+    pub trust_score: Option<f64>,
+
+    /// Anomaly rate within the window
+    pub anomaly_rate: f64,
+
+    /// Downtime within the window (seconds)
+    pub downtime_secs: i64,
+
+    /// Breaches found by the latest evaluation
+    pub breaches: Vec<SlaBreach>,
+
+    /// Consecutive breaching evaluations
+    pub consecutive_breaches: u32,
+
+    /// Consecutive healthy evaluations
+    pub consecutive_healthy: u32,
+
+    /// When the strategy entered its current tier (milliseconds since epoch)
+    pub tier_since: i64,
+}
+
+/// Per-strategy SLA bookkeeping
+#[derive(Debug, Clone)]
+struct SlaState {
+    sla: Option<StrategySla>,
+    tier: SlaTier,
+    tier_since: i64,
+    trust_score: Option<f64>,
+    /// (timestamp, anomalous)
+    observations: VecDeque<(i64, bool)>,
+    /// (timestamp, downtime seconds)
+    downtime: VecDeque<(i64, i64)>,
+    last_breaches: Vec<SlaBreach>,
+    consecutive_breaches: u32,
+    consecutive_healthy: u32,
+    history: VecDeque<TierChange>,
+}
+
+impl SlaState {
+    fn new(now: i64) -> Self {
+        Self {
+            sla: None,
+            tier: SlaTier::Full,
+            tier_since: now,
+            trust_score: None,
+            observations: VecDeque::new(),
+            downtime: VecDeque::new(),
+            last_breaches: Vec::new(),
+            consecutive_breaches: 0,
+            consecutive_healthy: 0,
+            history: VecDeque::new(),
+        }
+    }
+
+    fn prune(&mut self, cutoff: i64) {
+        while matches!(self.observations.front(), Some((ts, _)) if *ts < cutoff) {
+            self.observations.pop_front();
+        }
+        while matches!(self.downtime.front(), Some((ts, _)) if *ts < cutoff) {
+            self.downtime.pop_front();
+        }
+    }
+
+    fn anomaly_rate(&self) -> f64 {
+        if self.observations.is_empty() {
+            return 0.0;
+        }
+        let anomalies = self.observations.iter().filter(|(_, anomalous)| *anomalous).count();
+        anomalies as f64 / self.observations.len() as f64
+    }
+
+    fn downtime_secs(&self) -> i64 {
+        self.downtime.iter().map(|(_, secs)| secs).sum()
+    }
+
+    fn breaches(&self, sla: &StrategySla) -> Vec<SlaBreach> {
+        let mut breaches = Vec::new();
+
+        if let Some(score) = self.trust_score {
+            if score < sla.min_trust_score {
+                breaches.push(SlaBreach::TrustScore { actual: score, minimum: sla.min_trust_score });
+            }
+        }
+
+        let anomaly_rate = self.anomaly_rate();
+        if anomaly_rate > sla.max_anomaly_rate {
+            breaches.push(SlaBreach::AnomalyRate { actual: anomaly_rate, maximum: sla.max_anomaly_rate });
+        }
+
+        let downtime = self.downtime_secs();
+        if downtime > sla.max_downtime_secs {
+            breaches.push(SlaBreach::Downtime { actual_secs: downtime, maximum_secs: sla.max_downtime_secs });
+        }
+
+        breaches
+    }
+}
+
+/// Tracks strategy SLAs and moves strategies between execution tiers
+pub struct SlaMonitor {
+    /// Demotion and promotion policy
+    policy: SlaPolicy,
+
+    /// State by strategy ID
+    states: RwLock<HashMap<String, SlaState>>,
+
+    /// Tier change notifications
+    events: broadcast::Sender<TierChange>,
+}
+
+impl SlaMonitor {
+    /// Create a new SLA monitor
+    pub fn new(policy: SlaPolicy) -> Self {
+        let (events, _) = broadcast::channel(256);
+        Self {
+            policy,
+            states: RwLock::new(HashMap::new()),
+            events,
+        }
+    }
+
+    /// Subscribe to tier changes
+    pub fn subscribe(&self) -> broadcast::Receiver<TierChange> {
+        self.events.subscribe()
+    }
+
+    /// Override the SLA for a strategy
+    pub async fn set_sla(&self, strategy_id: &str, sla: StrategySla) {
+        let now = Utc::now().timestamp_millis();
+        let mut states = self.states.write().await;
+        states.entry(strategy_id.to_string()).or_insert_with(|| SlaState::new(now)).sla = Some(sla);
+    }
+
+    /// Record the latest trust score for a strategy
+    pub async fn record_trust_score(&self, strategy_id: &str, trust_score: f64) {
+        let now = Utc::now().timestamp_millis();
+        let mut states = self.states.write().await;
+        states.entry(strategy_id.to_string()).or_insert_with(|| SlaState::new(now)).trust_score = Some(trust_score);
+    }
+
+    /// Record the trust score from a trust monitor snapshot
+    pub async fn record_snapshot(&self, snapshot: &TrustSnapshot) {
+        self.record_trust_score(&snapshot.agent_id, snapshot.trust_score).await;
+    }
+
+    /// Capture a snapshot from a trust monitor and record its trust score
+    pub async fn sync_trust(&self, trust_monitor: &dyn TrustMonitor, strategy_id: &str) -> Result<()> {
+        let snapshot = trust_monitor.capture_snapshot(strategy_id).await?;
+        self.record_snapshot(&snapshot).await;
+        Ok(())
+    }
+
+    /// Record an observation of strategy behavior, flagged if anomalous
+    pub async fn record_observation(&self, strategy_id: &str, anomalous: bool, timestamp: i64) {
+        let mut states = self.states.write().await;
+        states
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| SlaState::new(timestamp))
+            .observations
+            .push_back((timestamp, anomalous));
+    }
+
+    /// Record a period of downtime for a strategy
+    pub async fn record_downtime(&self, strategy_id: &str, downtime_secs: i64, timestamp: i64) {
+        let mut states = self.states.write().await;
+        states
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| SlaState::new(timestamp))
+            .downtime
+            .push_back((timestamp, downtime_secs.max(0)));
+    }
+
+    /// Evaluate a strategy against its SLA, demoting or promoting it if warranted
+    pub async fn evaluate(&self, strategy_id: &str, now: i64) -> Option<TierChange> {
+        let change = {
+            let mut states = self.states.write().await;
+            let state = states.get_mut(strategy_id)?;
+            let sla = state.sla.clone().unwrap_or_else(|| self.policy.default_sla.clone());
+
+            state.prune(now - sla.window_secs * 1000);
+            let breaches = state.breaches(&sla);
+
+            if breaches.is_empty() {
+                state.consecutive_breaches = 0;
+                state.consecutive_healthy += 1;
+            } else {
+                state.consecutive_healthy = 0;
+                state.consecutive_breaches += 1;
+            }
+            state.last_breaches = breaches.clone();
+
+            let change = if state.consecutive_breaches >= self.policy.demotion_after_breaches {
+                state.tier.demoted().map(|to| TierChange {
+                    strategy_id: strategy_id.to_string(),
+                    from: state.tier,
+                    to,
+                    reason: format!("{} consecutive SLA breaches", state.consecutive_breaches),
+                    breaches,
+                    timestamp: now,
+                })
+            } else if state.consecutive_healthy >= self.policy.promotion_after_healthy
+                && now - state.tier_since >= self.policy.min_tier_dwell_secs * 1000
+                && (state.tier != SlaTier::Disabled || self.policy.allow_promotion_from_disabled)
+            {
+                state.tier.promoted().map(|to| TierChange {
+                    strategy_id: strategy_id.to_string(),
+                    from: state.tier,
+                    to,
+                    reason: format!("{} consecutive healthy evaluations", state.consecutive_healthy),
+                    breaches: Vec::new(),
+                    timestamp: now,
+                })
+            } else {
+                None
+            };
+
+            if let Some(change) = &change {
+                self.apply_change(state, change.clone());
+            }
+            change
+        };
+
+        if let Some(change) = &change {
+            if change.to > change.from {
+                warn!("Strategy {} demoted from {:?} to {:?}: {}", strategy_id, change.from, change.to, change.reason);
+            } else {
+                info!("Strategy {} promoted from {:?} to {:?}: {}", strategy_id, change.from, change.to, change.reason);
+            }
+            let _ = self.events.send(change.clone());
+        }
+
+        change
+    }
+
+    /// Evaluate every tracked strategy
+    pub async fn evaluate_all(&self, now: i64) -> Vec<TierChange> {
+        let strategy_ids: Vec<String> = self.states.read().await.keys().cloned().collect();
+        let mut changes = Vec::new();
+        for strategy_id in strategy_ids {
+            if let Some(change) = self.evaluate(&strategy_id, now).await {
+                changes.push(change);
+            }
+        }
+        changes
+    }
+
+    /// Manually move a strategy to a tier, e.g. to re-enable a disabled strategy
+    pub async fn set_tier(&self, strategy_id: &str, tier: SlaTier, reason: &str) -> Option<TierChange> {
+        let now = Utc::now().timestamp_millis();
+        let change = {
+            let mut states = self.states.write().await;
+            let state = states.entry(strategy_id.to_string()).or_insert_with(|| SlaState::new(now));
+            if state.tier == tier {
+                return None;
+            }
+
+            let change = TierChange {
+                strategy_id: strategy_id.to_string(),
+                from: state.tier,
+                to: tier,
+                breaches: Vec::new(),
+                reason: reason.to_string(),
+                timestamp: now,
+            };
+            self.apply_change(state, change.clone());
+            change
+        };
+
+        info!("Strategy {} moved from {:?} to {:?}: {}", strategy_id, change.from, change.to, reason);
+        let _ = self.events.send(change.clone());
+        Some(change)
+    }
+
+    /// Current tier of a strategy; untracked strategies run at full allocation
+    pub async fn tier(&self, strategy_id: &str) -> SlaTier {
+        self.states.read().await.get(strategy_id).map(|s| s.tier).unwrap_or(SlaTier::Full)
+    }
+
+    /// Current SLA standing of a strategy
+    pub async fn status(&self, strategy_id: &str) -> Option<SlaStatus> {
+        let states = self.states.read().await;
+        states.get(strategy_id).map(|state| self.to_status(strategy_id, state))
+    }
+
+    /// SLA standing of every tracked strategy
+    pub async fn all_statuses(&self) -> Vec<SlaStatus> {
+        let states = self.states.read().await;
+        let mut statuses: Vec<SlaStatus> = states.iter().map(|(id, state)| self.to_status(id, state)).collect();
+        statuses.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+        statuses
+    }
+
+    /// Tier changes for a strategy, oldest first
+    pub async fn tier_history(&self, strategy_id: &str) -> Vec<TierChange> {
+        self.states
+            .read()
+            .await
+            .get(strategy_id)
+            .map(|state| state.history.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn apply_change(&self, state: &mut SlaState, change: TierChange) {
+        state.tier = change.to;
+        state.tier_since = change.timestamp;
+        state.consecutive_breaches = 0;
+        state.consecutive_healthy = 0;
+        state.history.push_back(change);
+        while state.history.len() > self.policy.max_history {
+            state.history.pop_front();
+        }
+    }
+
+    fn to_status(&self, strategy_id: &str, state: &SlaState) -> SlaStatus {
+        SlaStatus {
+            strategy_id: strategy_id.to_string(),
+            tier: state.tier,
+            sla: state.sla.clone().unwrap_or_else(|| self.policy.default_sla.clone()),
+            trust_score: state.trust_score,
+            anomaly_rate: state.anomaly_rate(),
+            downtime_secs: state.downtime_secs(),
+            breaches: state.last_breaches.clone(),
+            consecutive_breaches: state.consecutive_breaches,
+            consecutive_healthy: state.consecutive_healthy,
+            tier_since: state.tier_since,
+        }
+    }
+}
+
+/// Create an SLA monitor
+pub fn create_sla_monitor(policy: SlaPolicy) -> std::sync::Arc<SlaMonitor> {
+    std::sync::Arc::new(SlaMonitor::new(policy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_persistent_breaches_demote_and_recovery_promotes() {
+        let monitor = SlaMonitor::new(SlaPolicy {
+            demotion_after_breaches: 2,
+            promotion_after_healthy: 2,
+            min_tier_dwell_secs: 0,
+            ..SlaPolicy::default()
+        });
+
+        monitor.record_trust_score("hedger", 0.4).await;
+        assert!(monitor.evaluate("hedger", 1_000).await.is_none());
+        let change = monitor.evaluate("hedger", 2_000).await.unwrap();
+        assert_eq!((change.from, change.to), (SlaTier::Full, SlaTier::Reduced));
+
+        monitor.evaluate("hedger", 3_000).await;
+        monitor.evaluate("hedger", 4_000).await;
+        assert_eq!(monitor.tier("hedger").await, SlaTier::Shadow);
+
+        monitor.record_trust_score("hedger", 0.9).await;
+        monitor.evaluate("hedger", 5_000).await;
+        let change = monitor.evaluate("hedger", 6_000).await.unwrap();
+        assert_eq!((change.from, change.to), (SlaTier::Shadow, SlaTier::Reduced));
+
+        let history = monitor.tier_history("hedger").await;
+        assert_eq!(history.len(), 3);
+        assert!(!history[0].breaches.is_empty());
+    }
+}
@@ -26,6 +26,8 @@ use redis::{Client, AsyncCommands, aio::ConnectionManager};
 use crate::analytics::{Analytics, AnalyticsResult, AnalyticsError, PerformanceSummary, ExecutionStats, Anomaly};
 use crate::strategy::StrategyId;
 use crate::telemetry_streamer::{TelemetryStreamer, TelemetryStreamError};
+use crate::simulation::trust_decay_simulator::RecoveryProfile;
+use crate::trust_history::{TrustHistoryError, TrustHistoryStore};
 
 /// Error types for trust score operations
 #[derive(Debug, Error)]
@@ -48,6 +50,9 @@ pub enum TrustScoreError {
     #[error("Serialization error: {0}")]
     SerializationError(String),
     
+    #[error("Trust history error: {0}")]
+    HistoryError(#[from] TrustHistoryError),
+    
     #[error("Internal error: {0}")]
     InternalError(String),
 }
@@ -243,6 +248,45 @@ pub struct TrustScoreHistory {
     pub entries: Vec<TrustScoreHistoryEntry>,
 }
 
+impl TrustScoreHistory {
+    /// Keep only entries within `[from, to]` and order them oldest first
+    pub fn retain_range(&mut self, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) {
+        self.entries.retain(|e| {
+            !from.is_some_and(|from| e.timestamp < from) && !to.is_some_and(|to| e.timestamp > to)
+        });
+        self.entries.sort_by_key(|e| e.timestamp);
+    }
+    
+    /// How often this history shows daily score rises of at least `min_boost`
+    pub fn recovery_profile(&self, min_boost: f64) -> RecoveryProfile {
+        let observations: Vec<_> = self.entries.iter().map(|e| (e.timestamp, e.score)).collect();
+        RecoveryProfile::from_history(&observations, min_boost)
+    }
+}
+
+/// Point on a trust score chart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustScoreSnapshot {
+    /// When the score was recorded
+    pub timestamp: DateTime<Utc>,
+    
+    /// Trust score
+    pub score: f64,
+    
+    /// Why the score changed, if known
+    pub reason: Option<String>,
+}
+
+impl From<&TrustScoreHistoryEntry> for TrustScoreSnapshot {
+    fn from(entry: &TrustScoreHistoryEntry) -> Self {
+        Self {
+            timestamp: entry.timestamp,
+            score: entry.score,
+            reason: None,
+        }
+    }
+}
+
 /// Trust score engine trait
 #[async_trait]
 pub trait TrustScoreEngine: Send + Sync {
@@ -258,6 +302,18 @@ pub trait TrustScoreEngine: Send + Sync {
     /// Get trust score history for a strategy
     async fn get_trust_history(&self, strategy_id: &str) -> TrustScoreResult<TrustScoreHistory>;
     
+    /// Get trust score history within `[from, to]`, oldest first
+    async fn get_trust_history_range(
+        &self,
+        strategy_id: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> TrustScoreResult<TrustScoreHistory> {
+        let mut history = self.get_trust_history(strategy_id).await?;
+        history.retain_range(from, to);
+        Ok(history)
+    }
+    
     /// Record a trust score update in history
     async fn record_history(&self, score: &TrustScore) -> TrustScoreResult<()>;
     
@@ -284,6 +340,9 @@ pub struct DefaultTrustScoreEngine {
     
     /// Last cache refresh timestamps
     last_refresh: Arc<RwLock<HashMap<String, Instant>>>,
+    
+    /// Long-term history store; Redis only keeps the newest entries
+    history_store: Option<Arc<TrustHistoryStore>>,
 }
 
 impl DefaultTrustScoreEngine {
@@ -300,9 +359,16 @@ impl DefaultTrustScoreEngine {
             redis: Arc::new(RwLock::new(None)),
             scores_cache: Arc::new(RwLock::new(HashMap::new())),
             last_refresh: Arc::new(RwLock::new(HashMap::new())),
+            history_store: None,
         }
     }
     
+    /// Persist every recorded score to a long-term history store
+    pub fn with_history_store(mut self, store: Arc<TrustHistoryStore>) -> Self {
+        self.history_store = Some(store);
+        self
+    }
+    
     /// Initialize the Redis connection
     pub async fn initialize(&self) -> TrustScoreResult<()> {
         let client = Client::open(self.config.redis_url.clone())
@@ -666,10 +732,28 @@ impl TrustScoreEngine for DefaultTrustScoreEngine {
         })
     }
     
-    async fn record_history(&self, score: &TrustScore) -> TrustScoreResult<()> {
-        // Load existing history
-        let mut history = self.get_trust_history(&score.strategy_id).await?;
+    async fn get_trust_history_range(
+        &self,
+        strategy_id: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> TrustScoreResult<TrustScoreHistory> {
+        let store = match &self.history_store {
+            Some(store) => store,
+            None => {
+                let mut history = self.get_trust_history(strategy_id).await?;
+                history.retain_range(from, to);
+                return Ok(history);
+            }
+        };
         
+        Ok(TrustScoreHistory {
+            strategy_id: strategy_id.to_string(),
+            entries: store.range(strategy_id, from, to)?,
+        })
+    }
+    
+    async fn record_history(&self, score: &TrustScore) -> TrustScoreResult<()> {
         // Create a new history entry
         let entry = TrustScoreHistoryEntry {
             score: score.score,
@@ -677,6 +761,14 @@ impl TrustScoreEngine for DefaultTrustScoreEngine {
             timestamp: score.timestamp,
         };
         
+        // Append to the long-term series first so a Redis outage loses nothing
+        if let Some(store) = &self.history_store {
+            store.append(&score.strategy_id, &entry)?;
+        }
+        
+        // Load existing history
+        let mut history = self.get_trust_history(&score.strategy_id).await?;
+        
         // Add entry to history
         history.entries.push(entry);
         
@@ -0,0 +1,394 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Symbol universe management
+//!
+//! Listing a symbol provisions everything that trades it: an order book,
+//! clean microstructure state, a position limit taken from a risk template
+//! and a market data subscription on its primary venue. Delisting tears the
+//! same pieces down, and is refused while any agent still holds a position
+//! or open order in the symbol. A newly listed symbol restarts its warm-up
+//! (see [`crate::warmup`]) before strategies may trade it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::connectors::ConnectorHub;
+use crate::microstructure::liquidity::LiquidityProfiler;
+use crate::microstructure::order_flow::OrderFlowAnalyzer;
+use crate::orderbook::OrderBookManager;
+use crate::position::PositionManager;
+use crate::warmup::WarmupGate;
+
+/// Errors raised by the universe manager
+#[derive(Debug, Error)]
+pub enum UniverseError {
+    #[error("Invalid symbol '{0}': expected BASE/QUOTE")]
+    InvalidSymbol(String),
+
+    #[error("Symbol {0} is already listed")]
+    AlreadyListed(String),
+
+    #[error("Symbol {0} is not listed")]
+    NotListed(String),
+
+    #[error("Unknown risk template: {0}")]
+    UnknownTemplate(String),
+
+    #[error("Symbol {0} still has open positions or orders")]
+    OpenExposure(String),
+
+    #[error("Failed to provision {component} for {symbol}: {reason}")]
+    Provision { symbol: String, component: &'static str, reason: String },
+}
+
+/// Result type for universe operations
+pub type UniverseResult<T> = Result<T, UniverseError>;
+
+/// Risk limits applied to a newly listed symbol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RiskTemplate {
+    /// Maximum position per agent, in base units
+    pub max_position: f64,
+}
+
+impl Default for RiskTemplate {
+    fn default() -> Self {
+        Self { max_position: 10.0 }
+    }
+}
+
+/// Universe configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UniverseConfig {
+    /// Symbols listed at startup, with the template each uses
+    pub symbols: BTreeMap<String, String>,
+    /// Template used when a listing does not name one
+    pub default_template: String,
+    /// Risk templates by name
+    pub templates: HashMap<String, RiskTemplate>,
+}
+
+impl Default for UniverseConfig {
+    fn default() -> Self {
+        Self {
+            symbols: BTreeMap::new(),
+            default_template: "default".to_string(),
+            templates: HashMap::from([("default".to_string(), RiskTemplate::default())]),
+        }
+    }
+}
+
+/// A listed symbol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymbolListing {
+    /// Symbol in `BASE/QUOTE` form
+    pub symbol: String,
+    /// Risk template the limits came from
+    pub template: String,
+    /// Limits applied
+    pub limits: RiskTemplate,
+    /// Primary venue streaming the symbol, if connectors are attached
+    pub venue: Option<String>,
+    /// When the symbol was listed
+    pub listed_at: DateTime<Utc>,
+}
+
+/// Lists and delists symbols at runtime
+pub struct UniverseManager {
+    /// Configuration
+    config: UniverseConfig,
+    /// Listed symbols; the lock also serializes listing and delisting
+    listings: Mutex<BTreeMap<String, SymbolListing>>,
+    /// Order books (optional)
+    order_books: Option<Arc<OrderBookManager>>,
+    /// Order flow analyzer (optional)
+    order_flow: Option<Arc<dyn OrderFlowAnalyzer>>,
+    /// Liquidity profiler (optional)
+    liquidity: Option<Arc<dyn LiquidityProfiler>>,
+    /// Position limits and exposure (optional)
+    position_manager: Option<Arc<PositionManager>>,
+    /// Market data subscriptions (optional)
+    connector_hub: Option<Arc<ConnectorHub>>,
+    /// Signal warm-up gating (optional)
+    warmup_gate: Option<Arc<WarmupGate>>,
+}
+
+impl UniverseManager {
+    /// Create a manager with nothing listed
+    pub fn new(config: UniverseConfig) -> Self {
+        Self {
+            config,
+            listings: Mutex::new(BTreeMap::new()),
+            order_books: None,
+            order_flow: None,
+            liquidity: None,
+            position_manager: None,
+            connector_hub: None,
+            warmup_gate: None,
+        }
+    }
+
+    /// Provision order books for listed symbols
+    pub fn with_order_books(mut self, order_books: Arc<OrderBookManager>) -> Self {
+        self.order_books = Some(order_books);
+        self
+    }
+
+    /// Reset order flow state when symbols are listed or delisted
+    pub fn with_order_flow(mut self, order_flow: Arc<dyn OrderFlowAnalyzer>) -> Self {
+        self.order_flow = Some(order_flow);
+        self
+    }
+
+    /// Reset liquidity profiles when symbols are listed or delisted
+    pub fn with_liquidity_profiler(mut self, liquidity: Arc<dyn LiquidityProfiler>) -> Self {
+        self.liquidity = Some(liquidity);
+        self
+    }
+
+    /// Apply template limits and refuse delisting symbols with exposure
+    pub fn with_position_manager(mut self, position_manager: Arc<PositionManager>) -> Self {
+        self.position_manager = Some(position_manager);
+        self
+    }
+
+    /// Subscribe listed symbols to market data
+    pub fn with_connector_hub(mut self, connector_hub: Arc<ConnectorHub>) -> Self {
+        self.connector_hub = Some(connector_hub);
+        self
+    }
+
+    /// Restart warm-up when symbols are listed or delisted
+    pub fn with_warmup_gate(mut self, warmup_gate: Arc<WarmupGate>) -> Self {
+        self.warmup_gate = Some(warmup_gate);
+        self
+    }
+
+    /// Listed symbols, sorted
+    pub async fn listings(&self) -> Vec<SymbolListing> {
+        self.listings.lock().await.values().cloned().collect()
+    }
+
+    /// Listing for a symbol
+    pub async fn listing(&self, symbol: &str) -> Option<SymbolListing> {
+        self.listings.lock().await.get(&normalize(symbol)).cloned()
+    }
+
+    /// Whether a symbol is listed
+    pub async fn is_listed(&self, symbol: &str) -> bool {
+        self.listings.lock().await.contains_key(&normalize(symbol))
+    }
+
+    /// List the configured startup symbols, returning how many were listed
+    pub async fn list_configured(&self) -> UniverseResult<usize> {
+        let mut listed = 0;
+        for (symbol, template) in &self.config.symbols {
+            match self.list(symbol, Some(template)).await {
+                Ok(_) => listed += 1,
+                Err(UniverseError::AlreadyListed(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(listed)
+    }
+
+    /// List a symbol, provisioning its book, microstructure state, limits
+    /// and market data. Nothing is left behind if a step fails.
+    pub async fn list(&self, symbol: &str, template: Option<&str>) -> UniverseResult<SymbolListing> {
+        let symbol = normalize(symbol);
+        validate(&symbol)?;
+        let template_name = template.unwrap_or(&self.config.default_template);
+        let limits = self.config.templates
+            .get(template_name)
+            .cloned()
+            .ok_or_else(|| UniverseError::UnknownTemplate(template_name.to_string()))?;
+
+        let mut listings = self.listings.lock().await;
+        if listings.contains_key(&symbol) {
+            return Err(UniverseError::AlreadyListed(symbol));
+        }
+
+        // Leftovers from an earlier listing would skew the new one
+        self.reset_microstructure(&symbol).await;
+        if let Some(books) = &self.order_books {
+            books.get_order_book(&symbol).write().unwrap().clear();
+        }
+        if let Some(position_manager) = &self.position_manager {
+            if let Err(e) = position_manager.set_symbol_limit(&symbol, Some(limits.max_position)) {
+                self.release(&symbol).await;
+                return Err(UniverseError::Provision { symbol, component: "position limit", reason: e.to_string() });
+            }
+        }
+        if let Some(hub) = &self.connector_hub {
+            if let Err(e) = hub.subscribe(std::slice::from_ref(&symbol)).await {
+                self.release(&symbol).await;
+                return Err(UniverseError::Provision { symbol, component: "market data", reason: e.to_string() });
+            }
+        }
+
+        let listing = SymbolListing {
+            venue: self.connector_hub.as_ref().and_then(|hub| hub.primary_venue(&symbol)).map(str::to_string),
+            symbol: symbol.clone(),
+            template: template_name.to_string(),
+            limits,
+            listed_at: Utc::now(),
+        };
+        listings.insert(symbol.clone(), listing.clone());
+        info!("Listed {} with template {}", symbol, listing.template);
+        Ok(listing)
+    }
+
+    /// Delist a symbol, tearing down its subscription, book, microstructure
+    /// state and limits. Refused while the symbol has exposure.
+    pub async fn delist(&self, symbol: &str) -> UniverseResult<SymbolListing> {
+        let symbol = normalize(symbol);
+        let mut listings = self.listings.lock().await;
+        if !listings.contains_key(&symbol) {
+            return Err(UniverseError::NotListed(symbol));
+        }
+
+        if let Some(position_manager) = &self.position_manager {
+            let has_exposure = position_manager.has_exposure(&symbol)
+                .map_err(|e| UniverseError::Provision { symbol: symbol.clone(), component: "position check", reason: e.to_string() })?;
+            if has_exposure {
+                return Err(UniverseError::OpenExposure(symbol));
+            }
+        }
+
+        // Stop the data first so nothing repopulates the state being removed
+        if let Some(hub) = &self.connector_hub {
+            hub.unsubscribe(std::slice::from_ref(&symbol)).await
+                .map_err(|e| UniverseError::Provision { symbol: symbol.clone(), component: "market data", reason: e.to_string() })?;
+        }
+        self.release(&symbol).await;
+
+        let listing = listings.remove(&symbol).expect("checked above");
+        info!("Delisted {}", symbol);
+        Ok(listing)
+    }
+
+    /// Remove a symbol's book, microstructure state and limits
+    async fn release(&self, symbol: &str) {
+        self.reset_microstructure(symbol).await;
+        if let Some(books) = &self.order_books {
+            books.remove_order_book(symbol);
+        }
+        if let Some(position_manager) = &self.position_manager {
+            if let Err(e) = position_manager.set_symbol_limit(symbol, None) {
+                warn!("Failed to clear position limit for {}: {}", symbol, e);
+            }
+        }
+    }
+
+    async fn reset_microstructure(&self, symbol: &str) {
+        // Warm-up measured the state being reset
+        if let Some(warmup_gate) = &self.warmup_gate {
+            warmup_gate.reset(symbol);
+        }
+        let symbol = symbol.to_string();
+        if let Some(order_flow) = &self.order_flow {
+            if let Err(e) = order_flow.reset_metrics(&symbol).await {
+                warn!("Failed to reset order flow for {}: {}", symbol, e);
+            }
+        }
+        if let Some(liquidity) = &self.liquidity {
+            if let Err(e) = liquidity.reset(&symbol).await {
+                warn!("Failed to reset liquidity profile for {}: {}", symbol, e);
+            }
+        }
+    }
+}
+
+fn normalize(symbol: &str) -> String {
+    symbol.trim().to_uppercase()
+}
+
+fn validate(symbol: &str) -> UniverseResult<()> {
+    let valid_part = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric());
+    match symbol.split_once('/') {
+        Some((base, quote)) if valid_part(base) && valid_part(quote) => Ok(()),
+        _ => Err(UniverseError::InvalidSymbol(symbol.to_string())),
+    }
+}
+
+/// Create a universe manager
+pub fn create_universe_manager(config: UniverseConfig) -> Arc<UniverseManager> {
+    Arc::new(UniverseManager::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orderbook::{create_order_book_manager, OrderSide};
+    use crate::position::{create_position_manager, OrderOrFill, Side};
+
+    #[tokio::test]
+    async fn test_list_and_delist() {
+        let books = create_order_book_manager();
+        let positions = create_position_manager();
+        let config = UniverseConfig {
+            templates: HashMap::from([
+                ("default".to_string(), RiskTemplate::default()),
+                ("thin".to_string(), RiskTemplate { max_position: 0.5 }),
+            ]),
+            ..UniverseConfig::default()
+        };
+        let universe = UniverseManager::new(config)
+            .with_order_books(books.clone())
+            .with_position_manager(positions.clone());
+
+        assert!(matches!(universe.list("BTCUSDT", None).await, Err(UniverseError::InvalidSymbol(_))));
+        assert!(matches!(universe.list("BTC/USDT", Some("missing")).await, Err(UniverseError::UnknownTemplate(_))));
+
+        let listing = universe.list(" sol/usdt ", Some("thin")).await.unwrap();
+        assert_eq!(listing.symbol, "SOL/USDT");
+        assert!(books.list_symbols().contains(&"SOL/USDT".to_string()));
+        assert_eq!(positions.get_config().unwrap().max_position_per_symbol["SOL/USDT"], 0.5);
+        assert!(matches!(universe.list("SOL/USDT", None).await, Err(UniverseError::AlreadyListed(_))));
+
+        // Exposure blocks delisting until it is closed out
+        let fill = |side, size| OrderOrFill {
+            symbol: "SOL/USDT".to_string(),
+            side,
+            size,
+            price: 20.0,
+            timestamp: Utc::now(),
+            order_id: uuid::Uuid::new_v4().to_string(),
+            fill_id: None,
+            is_fill: true,
+            venue: None,
+            strategy_id: None,
+        };
+        positions.update_position("agent-1", &fill(Side::Buy, 0.25)).unwrap();
+        assert!(matches!(universe.delist("SOL/USDT").await, Err(UniverseError::OpenExposure(_))));
+        positions.update_position("agent-1", &fill(Side::Sell, 0.25)).unwrap();
+
+        books.process_update("SOL/USDT", 20.0, 5.0, OrderSide::Bid, 1);
+        universe.delist("sol/usdt").await.unwrap();
+        assert!(!books.list_symbols().contains(&"SOL/USDT".to_string()));
+        assert!(!positions.get_config().unwrap().max_position_per_symbol.contains_key("SOL/USDT"));
+        assert!(universe.listings().await.is_empty());
+        assert!(matches!(universe.delist("SOL/USDT").await, Err(UniverseError::NotListed(_))));
+    }
+}
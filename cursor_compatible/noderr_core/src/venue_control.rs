@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+use dashmap::DashMap;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::telemetry_streamer::{TelemetryMessageType, TelemetryStreamer};
+
+/// Operator-controlled routing mode of a venue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VenueMode {
+    /// Venue accepts new orders
+    Enabled,
+    /// Venue accepts no new orders; resting orders are left alone
+    Disabled,
+    /// Venue accepts no new orders while resting orders are cancelled or filled
+    Draining,
+}
+
+impl VenueMode {
+    /// Status string reported to venue scoring and telemetry
+    pub fn as_status(&self) -> &'static str {
+        match self {
+            VenueMode::Enabled => "enabled",
+            VenueMode::Disabled => "disabled",
+            VenueMode::Draining => "draining",
+        }
+    }
+
+    /// Whether new orders may be routed to a venue in this mode
+    pub fn accepts_new_orders(&self) -> bool {
+        matches!(self, VenueMode::Enabled)
+    }
+}
+
+/// Current mode of a venue and who set it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueModeState {
+    /// Venue ID
+    pub venue: String,
+    /// Current mode
+    pub mode: VenueMode,
+    /// Operator who last changed the mode
+    pub operator: String,
+    /// Reason given for the change
+    pub reason: Option<String>,
+    /// When the mode last changed
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Registry of operator venue modes consulted by routing and venue scoring
+///
+/// Venues without an entry are enabled.
+pub struct VenueControl {
+    /// Modes by venue
+    modes: DashMap<String, VenueModeState>,
+    /// Telemetry streamer for venue status updates (optional)
+    streamer: Option<Arc<dyn TelemetryStreamer>>,
+}
+
+impl VenueControl {
+    /// Create a new venue control registry
+    pub fn new() -> Self {
+        Self {
+            modes: DashMap::new(),
+            streamer: None,
+        }
+    }
+
+    /// Publish mode changes as venue health telemetry
+    pub fn with_streamer(mut self, streamer: Arc<dyn TelemetryStreamer>) -> Self {
+        self.streamer = Some(streamer);
+        self
+    }
+
+    /// Current mode of a venue
+    pub fn mode(&self, venue: &str) -> VenueMode {
+        self.modes.get(venue).map(|state| state.mode).unwrap_or(VenueMode::Enabled)
+    }
+
+    /// Whether new orders may be routed to a venue
+    pub fn accepts_new_orders(&self, venue: &str) -> bool {
+        self.mode(venue).accepts_new_orders()
+    }
+
+    /// Change a venue's mode, returning the new state
+    pub async fn set_mode(&self, venue: &str, mode: VenueMode, operator: &str, reason: Option<String>) -> VenueModeState {
+        let state = VenueModeState {
+            venue: venue.to_string(),
+            mode,
+            operator: operator.to_string(),
+            reason,
+            changed_at: Utc::now(),
+        };
+
+        let previous = self.modes.insert(venue.to_string(), state.clone()).map(|s| s.mode);
+        if mode.accepts_new_orders() {
+            info!("Venue {} {} by {}", venue, mode.as_status(), operator);
+        } else {
+            warn!("Venue {} {} by {}: {}", venue, mode.as_status(), operator, state.reason.as_deref().unwrap_or("no reason given"));
+        }
+
+        if previous != Some(mode) {
+            self.publish(&state).await;
+        }
+
+        state
+    }
+
+    /// All venues with an explicit mode
+    pub fn list(&self) -> Vec<VenueModeState> {
+        let mut states: Vec<VenueModeState> = self.modes.iter().map(|entry| entry.value().clone()).collect();
+        states.sort_by(|a, b| a.venue.cmp(&b.venue));
+        states
+    }
+
+    async fn publish(&self, state: &VenueModeState) {
+        let streamer = match &self.streamer {
+            Some(streamer) => streamer,
+            None => return,
+        };
+
+        let payload = serde_json::json!({
+            "venue_id": state.venue,
+            "status": state.mode.as_status(),
+            "operator": state.operator,
+            "reason": state.reason,
+            "changed_at": state.changed_at,
+        });
+
+        if let Err(e) = streamer.publish_system_update(TelemetryMessageType::VenueHealth, &state.venue, payload).await {
+            warn!("Failed to publish venue status for {}: {}", state.venue, e);
+        }
+    }
+}
+
+impl Default for VenueControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Create a venue control registry
+pub fn create_venue_control() -> Arc<VenueControl> {
+    Arc::new(VenueControl::new())
+}
@@ -17,6 +17,8 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::collections::VecDeque;
 
+use crate::simulation::latency_model::{LatencyDistribution, LatencyModelConfig, VenueLatencyProfile};
+
 /// Maximum number of measurements to keep per venue
 const MAX_LATENCY_HISTORY: usize = 1000;
 
@@ -187,6 +189,33 @@ impl VenueLatencyTracker {
     pub fn get_tracked_venues(&self) -> Vec<String> {
         self.latencies.iter().map(|entry| entry.key().clone()).collect()
     }
+
+    /// Log-normal transit distribution (in milliseconds) fitted to the
+    /// observed median and p99 for a venue
+    pub fn transit_distribution(&self, venue_id: &str) -> Option<LatencyDistribution> {
+        let stats = self.get_latency_stats(venue_id)?;
+        if stats.sample_count == 0 {
+            return None;
+        }
+        Some(LatencyDistribution::from_percentiles(stats.p50_ns / 1e6, stats.p99_ns / 1e6))
+    }
+
+    /// Replace the transit distribution of every tracked venue in a
+    /// simulation latency model with one fitted to live measurements.
+    /// Matching delay is kept from the venue's existing profile, or the
+    /// default profile for venues not yet configured.
+    pub fn calibrate_latency_model(&self, config: &mut LatencyModelConfig) {
+        for venue_id in self.get_tracked_venues() {
+            let Some(transit) = self.transit_distribution(&venue_id) else {
+                continue;
+            };
+            let matching = config.venues.get(&venue_id)
+                .unwrap_or(&config.default_profile)
+                .matching
+                .clone();
+            config.venues.insert(venue_id, VenueLatencyProfile { transit, matching });
+        }
+    }
 }
 
 impl Default for VenueLatencyTracker {
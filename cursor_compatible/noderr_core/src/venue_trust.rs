@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Per-venue trust scores learned from execution outcomes.
+//!
+//! Rejects, timeouts and errors lower a venue's score; fills raise it, less so
+//! when they slip past the expected price. Scores are persisted so a restart
+//! does not forget a misbehaving venue, and the same service is shared by the
+//! order router, its retry engine and the venue scorer.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Errors raised by the venue trust service
+#[derive(Debug, Error)]
+pub enum VenueTrustError {
+    #[error("Venue trust I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Venue trust serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Result type for venue trust operations
+pub type VenueTrustResult<T> = Result<T, VenueTrustError>;
+
+/// Venue trust configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VenueTrustConfig {
+    /// Score assigned to venues with no history
+    pub initial_score: f64,
+    /// Score gained per clean fill
+    pub fill_reward: f64,
+    /// Score lost per rejected order
+    pub reject_penalty: f64,
+    /// Score lost per timed-out order
+    pub timeout_penalty: f64,
+    /// Score lost per venue or transport error
+    pub error_penalty: f64,
+    /// Adverse slippage tolerated before a fill is penalised, in basis points
+    pub slippage_tolerance_bps: f64,
+    /// Score lost per basis point of slippage beyond the tolerance
+    pub slippage_penalty_per_bps: f64,
+    /// Largest penalty a single fill can incur
+    pub max_slippage_penalty: f64,
+    /// File scores are persisted to; `None` keeps them in memory only
+    pub persist_path: Option<PathBuf>,
+    /// How often changed scores are flushed to disk
+    pub flush_interval_secs: u64,
+}
+
+impl Default for VenueTrustConfig {
+    fn default() -> Self {
+        Self {
+            initial_score: 0.5,
+            fill_reward: 0.01,
+            reject_penalty: 0.02,
+            timeout_penalty: 0.03,
+            error_penalty: 0.02,
+            slippage_tolerance_bps: 10.0,
+            slippage_penalty_per_bps: 0.001,
+            max_slippage_penalty: 0.03,
+            persist_path: Some(PathBuf::from("data/venue_trust.json")),
+            flush_interval_secs: 30,
+        }
+    }
+}
+
+/// Realized outcome of sending an order to a venue
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum VenueOutcome {
+    /// Order filled; adverse slippage against the expected price if known
+    Filled { slippage_bps: Option<f64> },
+    /// Venue rejected or failed to execute the order
+    Rejected,
+    /// Venue did not answer in time
+    Timeout,
+    /// Venue or transport error
+    Error,
+}
+
+/// Trust score and outcome counts for a venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueTrustRecord {
+    /// Current trust score (0.0-1.0)
+    pub score: f64,
+    /// Fills recorded
+    pub fills: u64,
+    /// Rejects recorded
+    pub rejects: u64,
+    /// Timeouts recorded
+    pub timeouts: u64,
+    /// Errors recorded
+    pub errors: u64,
+    /// Mean adverse slippage of fills with a known expected price, in basis points
+    pub avg_slippage_bps: f64,
+    /// Fills contributing to `avg_slippage_bps`
+    pub slippage_samples: u64,
+    /// Last update
+    pub updated_at: DateTime<Utc>,
+}
+
+impl VenueTrustRecord {
+    fn new(score: f64) -> Self {
+        Self {
+            score,
+            fills: 0,
+            rejects: 0,
+            timeouts: 0,
+            errors: 0,
+            avg_slippage_bps: 0.0,
+            slippage_samples: 0,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// Shared, persisted venue trust scores
+pub struct VenueTrustService {
+    /// Configuration
+    config: VenueTrustConfig,
+    /// Records by venue ID
+    records: RwLock<HashMap<String, VenueTrustRecord>>,
+    /// Whether records changed since the last flush
+    dirty: AtomicBool,
+    /// Flush task handle
+    task_handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl VenueTrustService {
+    /// Create a service, loading scores persisted by a previous run
+    pub fn open(config: VenueTrustConfig) -> VenueTrustResult<Self> {
+        let records = match &config.persist_path {
+            Some(path) if path.exists() => {
+                let records: HashMap<String, VenueTrustRecord> = serde_json::from_reader(File::open(path)?)?;
+                info!("Loaded trust scores for {} venues from {}", records.len(), path.display());
+                records
+            }
+            _ => HashMap::new(),
+        };
+
+        Ok(Self {
+            config,
+            records: RwLock::new(records),
+            dirty: AtomicBool::new(false),
+            task_handle: Mutex::new(None),
+        })
+    }
+
+    /// Create a service that keeps scores in memory only
+    pub fn in_memory() -> Self {
+        Self {
+            config: VenueTrustConfig { persist_path: None, ..VenueTrustConfig::default() },
+            records: RwLock::new(HashMap::new()),
+            dirty: AtomicBool::new(false),
+            task_handle: Mutex::new(None),
+        }
+    }
+
+    /// Set starting scores for venues that have no history yet
+    pub fn seed(&self, scores: HashMap<String, f64>) {
+        let mut records = self.records.write().unwrap();
+        for (venue, score) in scores {
+            records.entry(venue).or_insert_with(|| VenueTrustRecord::new(score.clamp(0.0, 1.0)));
+        }
+    }
+
+    /// Current score for a venue
+    pub fn score(&self, venue: &str) -> f64 {
+        self.records
+            .read()
+            .unwrap()
+            .get(venue)
+            .map(|r| r.score)
+            .unwrap_or(self.config.initial_score)
+    }
+
+    /// Current scores for every known venue
+    pub fn scores(&self) -> HashMap<String, f64> {
+        self.records.read().unwrap().iter().map(|(venue, r)| (venue.clone(), r.score)).collect()
+    }
+
+    /// Full record for a venue
+    pub fn record_for(&self, venue: &str) -> Option<VenueTrustRecord> {
+        self.records.read().unwrap().get(venue).cloned()
+    }
+
+    /// Full records for every known venue
+    pub fn records(&self) -> HashMap<String, VenueTrustRecord> {
+        self.records.read().unwrap().clone()
+    }
+
+    /// Apply an execution outcome, returning the venue's new score
+    pub fn record(&self, venue: &str, outcome: VenueOutcome) -> f64 {
+        let delta = match outcome {
+            VenueOutcome::Filled { slippage_bps } => {
+                let excess = slippage_bps.map_or(0.0, |bps| (bps - self.config.slippage_tolerance_bps).max(0.0));
+                self.config.fill_reward
+                    - (excess * self.config.slippage_penalty_per_bps).min(self.config.max_slippage_penalty)
+            }
+            VenueOutcome::Rejected => -self.config.reject_penalty,
+            VenueOutcome::Timeout => -self.config.timeout_penalty,
+            VenueOutcome::Error => -self.config.error_penalty,
+        };
+
+        let mut records = self.records.write().unwrap();
+        let record = records
+            .entry(venue.to_string())
+            .or_insert_with(|| VenueTrustRecord::new(self.config.initial_score));
+        match outcome {
+            VenueOutcome::Filled { slippage_bps } => {
+                record.fills += 1;
+                if let Some(bps) = slippage_bps.filter(|bps| bps.is_finite()) {
+                    record.slippage_samples += 1;
+                    record.avg_slippage_bps += (bps - record.avg_slippage_bps) / record.slippage_samples as f64;
+                }
+            }
+            VenueOutcome::Rejected => record.rejects += 1,
+            VenueOutcome::Timeout => record.timeouts += 1,
+            VenueOutcome::Error => record.errors += 1,
+        }
+        record.score = (record.score + delta).clamp(0.0, 1.0);
+        record.updated_at = Utc::now();
+        self.dirty.store(true, Ordering::SeqCst);
+
+        debug!("Venue {} trust {:+.4} -> {:.4} after {:?}", venue, delta, record.score, outcome);
+        record.score
+    }
+
+    /// Write scores to disk if they changed since the last flush
+    pub fn flush(&self) -> VenueTrustResult<()> {
+        let Some(path) = &self.config.persist_path else {
+            return Ok(());
+        };
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let result = self.write(path);
+        if result.is_err() {
+            // Try again on the next flush
+            self.dirty.store(true, Ordering::SeqCst);
+        }
+        result
+    }
+
+    /// Start flushing changed scores in the background
+    pub fn start(self: &Arc<Self>) {
+        if self.config.persist_path.is_none() {
+            return;
+        }
+
+        let service = Arc::clone(self);
+        let interval = Duration::from_secs(self.config.flush_interval_secs.max(1));
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = service.flush() {
+                    warn!("Failed to persist venue trust scores: {}", e);
+                }
+            }
+        });
+
+        if let Some(previous) = self.task_handle.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop the flush task and persist any pending changes
+    pub fn stop(&self) {
+        if let Some(handle) = self.task_handle.lock().unwrap().take() {
+            handle.abort();
+        }
+        if let Err(e) = self.flush() {
+            warn!("Failed to persist venue trust scores: {}", e);
+        }
+    }
+
+    fn write(&self, path: &Path) -> VenueTrustResult<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let data = serde_json::to_vec_pretty(&*self.records.read().unwrap())?;
+
+        let tmp = path.with_extension("json.tmp");
+        {
+            let mut file = File::create(&tmp)?;
+            file.write_all(&data)?;
+            file.sync_all()?;
+        }
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+}
+
+/// Open a venue trust service
+pub fn create_venue_trust_service(config: VenueTrustConfig) -> VenueTrustResult<Arc<VenueTrustService>> {
+    Ok(Arc::new(VenueTrustService::open(config)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcomes_update_and_persist() {
+        let dir = std::env::temp_dir().join(format!("noderr-venue-trust-{}", uuid::Uuid::new_v4()));
+        let config = VenueTrustConfig {
+            persist_path: Some(dir.join("venue_trust.json")),
+            ..VenueTrustConfig::default()
+        };
+        let service = VenueTrustService::open(config.clone()).unwrap();
+        service.seed(HashMap::from([("binance".to_string(), 0.8)]));
+
+        service.record("binance", VenueOutcome::Filled { slippage_bps: Some(2.0) });
+        assert!((service.score("binance") - 0.81).abs() < 1e-9);
+
+        // Slippage past the tolerance outweighs the fill reward
+        service.record("binance", VenueOutcome::Filled { slippage_bps: Some(40.0) });
+        assert!((service.score("binance") - 0.79).abs() < 1e-9);
+
+        service.record("kraken", VenueOutcome::Timeout);
+        service.record("kraken", VenueOutcome::Rejected);
+        assert!((service.score("kraken") - 0.45).abs() < 1e-9);
+        assert_eq!(service.score("unknown"), 0.5);
+
+        // Seeding never overrides learned scores
+        service.seed(HashMap::from([("kraken".to_string(), 0.9)]));
+        assert!((service.score("kraken") - 0.45).abs() < 1e-9);
+
+        service.flush().unwrap();
+        let reloaded = VenueTrustService::open(config).unwrap();
+        let record = reloaded.record_for("binance").unwrap();
+        assert_eq!((record.fills, record.slippage_samples), (2, 2));
+        assert!((record.avg_slippage_bps - 21.0).abs() < 1e-9);
+        assert_eq!(reloaded.scores(), service.scores());
+
+        fs::remove_dir_all(dir).ok();
+    }
+}
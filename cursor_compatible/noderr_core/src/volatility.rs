@@ -0,0 +1,415 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Per-symbol volatility estimates shared across the engine
+//!
+//! The trade sizer, regime detector and risk calculator each used to derive
+//! volatility from their own price buffers with slightly different maths.
+//! This service keeps one set of estimators per symbol, updated from bars or
+//! prices, and answers for any horizon:
+//!
+//! - close-to-close: sample variance of log returns over a rolling window
+//! - EWMA: RiskMetrics-style exponentially weighted variance
+//! - GARCH-lite: GARCH(1,1) with fixed alpha/beta, anchored to the window's
+//!   long-run variance so multi-step forecasts mean-revert
+//! - Parkinson: high/low range estimator, efficient when bars carry ranges
+//!
+//! Per-bar variances scale to longer horizons by the number of bars, except
+//! GARCH-lite whose forecast decays towards the long-run variance. Updated
+//! estimates are broadcast per symbol to subscribers.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::broadcast;
+
+/// Seconds in a year, used for annualized volatility
+const YEAR_SECS: u64 = 365 * 86_400;
+
+/// Errors that can occur while updating volatility estimates
+#[derive(Debug, Error)]
+pub enum VolatilityError {
+    #[error("Invalid bar for {symbol}: {reason}")]
+    InvalidBar { symbol: String, reason: String },
+}
+
+/// Volatility estimator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VolatilityEstimator {
+    /// Sample standard deviation of log returns over the window
+    CloseToClose,
+    /// Exponentially weighted moving average of squared returns
+    Ewma,
+    /// GARCH(1,1) with fixed parameters
+    GarchLite,
+    /// High/low range estimator
+    Parkinson,
+}
+
+impl VolatilityEstimator {
+    /// All estimators, in reporting order
+    pub const ALL: [VolatilityEstimator; 4] = [
+        VolatilityEstimator::CloseToClose,
+        VolatilityEstimator::Ewma,
+        VolatilityEstimator::GarchLite,
+        VolatilityEstimator::Parkinson,
+    ];
+}
+
+/// Volatility service configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolatilityConfig {
+    /// Length of one bar or price sample (seconds)
+    pub bar_interval_secs: u64,
+    /// Bars kept for the close-to-close and Parkinson estimators
+    pub window: usize,
+    /// EWMA decay factor (0.0-1.0)
+    pub ewma_lambda: f64,
+    /// GARCH-lite weight on the latest squared return
+    pub garch_alpha: f64,
+    /// GARCH-lite weight on the previous variance
+    pub garch_beta: f64,
+    /// Returns required before estimates are published
+    pub min_observations: usize,
+    /// Horizons included in published snapshots (seconds)
+    pub horizons_secs: Vec<u64>,
+    /// Capacity of the snapshot broadcast channel
+    pub channel_capacity: usize,
+}
+
+impl Default for VolatilityConfig {
+    fn default() -> Self {
+        Self {
+            bar_interval_secs: 60,
+            window: 120,
+            ewma_lambda: 0.94,
+            garch_alpha: 0.06,
+            garch_beta: 0.92,
+            min_observations: 10,
+            horizons_secs: vec![60, 3_600, 86_400],
+            channel_capacity: 256,
+        }
+    }
+}
+
+/// One estimator's volatility over one horizon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolatilityEstimate {
+    /// Estimator used
+    pub estimator: VolatilityEstimator,
+    /// Horizon (seconds)
+    pub horizon_secs: u64,
+    /// Standard deviation of log returns over the horizon
+    pub volatility: f64,
+}
+
+/// Published volatility estimates for one symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolatilitySnapshot {
+    /// Symbol
+    pub symbol: String,
+    /// Time of the bar that produced the snapshot
+    pub updated_at: DateTime<Utc>,
+    /// Returns observed so far
+    pub observations: usize,
+    /// Estimates for every estimator and configured horizon
+    pub estimates: Vec<VolatilityEstimate>,
+}
+
+impl VolatilitySnapshot {
+    /// Estimate for one estimator and horizon, if it was published
+    pub fn get(&self, estimator: VolatilityEstimator, horizon_secs: u64) -> Option<f64> {
+        self.estimates
+            .iter()
+            .find(|e| e.estimator == estimator && e.horizon_secs == horizon_secs)
+            .map(|e| e.volatility)
+    }
+}
+
+/// Running estimator state for one symbol
+#[derive(Debug, Clone, Default)]
+struct SymbolVolatility {
+    last_close: Option<f64>,
+    /// Log returns, newest last
+    returns: VecDeque<f64>,
+    /// Squared log high/low ranges, newest last
+    ranges: VecDeque<f64>,
+    ewma_variance: Option<f64>,
+    garch_variance: Option<f64>,
+    observations: usize,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl SymbolVolatility {
+    /// Sample variance of the return window
+    fn sample_variance(&self) -> Option<f64> {
+        let n = self.returns.len();
+        if n < 2 {
+            return None;
+        }
+        let mean = self.returns.iter().sum::<f64>() / n as f64;
+        Some(self.returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1) as f64)
+    }
+
+    /// One-bar variance for an estimator
+    fn bar_variance(&self, estimator: VolatilityEstimator) -> Option<f64> {
+        match estimator {
+            VolatilityEstimator::CloseToClose => self.sample_variance(),
+            VolatilityEstimator::Ewma => self.ewma_variance,
+            VolatilityEstimator::GarchLite => self.garch_variance,
+            VolatilityEstimator::Parkinson => {
+                if self.ranges.is_empty() {
+                    return None;
+                }
+                let mean = self.ranges.iter().sum::<f64>() / self.ranges.len() as f64;
+                Some(mean / (4.0 * std::f64::consts::LN_2))
+            }
+        }
+    }
+}
+
+/// Central per-symbol volatility service
+pub struct VolatilityService {
+    /// Configuration
+    config: VolatilityConfig,
+    /// Estimator state by symbol
+    symbols: RwLock<HashMap<String, SymbolVolatility>>,
+    /// Snapshot publisher
+    sender: broadcast::Sender<VolatilitySnapshot>,
+}
+
+impl VolatilityService {
+    /// Create a new volatility service
+    pub fn new(config: VolatilityConfig) -> Self {
+        let (sender, _) = broadcast::channel(config.channel_capacity.max(1));
+        Self {
+            config,
+            symbols: RwLock::new(HashMap::new()),
+            sender,
+        }
+    }
+
+    /// Service configuration
+    pub fn config(&self) -> &VolatilityConfig {
+        &self.config
+    }
+
+    /// Feed a completed bar; returns the published snapshot once enough returns are seen
+    pub fn record_bar(
+        &self,
+        symbol: &str,
+        high: f64,
+        low: f64,
+        close: f64,
+        at: DateTime<Utc>,
+    ) -> Result<Option<VolatilitySnapshot>, VolatilityError> {
+        let invalid = |reason: String| VolatilityError::InvalidBar { symbol: symbol.to_string(), reason };
+        if !(close.is_finite() && close > 0.0 && low > 0.0 && high.is_finite()) {
+            return Err(invalid(format!("non-positive price (high {}, low {}, close {})", high, low, close)));
+        }
+        if high < low {
+            return Err(invalid(format!("high {} below low {}", high, low)));
+        }
+
+        let mut symbols = self.symbols.write().unwrap();
+        let state = symbols.entry(symbol.to_string()).or_default();
+        let window = self.config.window.max(2);
+
+        state.ranges.push_back((high / low).ln().powi(2));
+        if state.ranges.len() > window {
+            state.ranges.pop_front();
+        }
+
+        if let Some(last_close) = state.last_close.replace(close) {
+            let r = (close / last_close).ln();
+            state.returns.push_back(r);
+            if state.returns.len() > window {
+                state.returns.pop_front();
+            }
+            state.observations += 1;
+
+            let lambda = self.config.ewma_lambda.clamp(0.0, 1.0);
+            state.ewma_variance = Some(match state.ewma_variance {
+                Some(variance) => lambda * variance + (1.0 - lambda) * r * r,
+                None => r * r,
+            });
+
+            let long_run = state.sample_variance().unwrap_or(r * r);
+            let (alpha, beta) = self.garch_params();
+            let previous = state.garch_variance.unwrap_or(long_run);
+            state.garch_variance = Some(long_run * (1.0 - alpha - beta) + alpha * r * r + beta * previous);
+        }
+        state.updated_at = Some(at);
+
+        if state.observations < self.config.min_observations {
+            return Ok(None);
+        }
+        let snapshot = self.snapshot_of(symbol, state);
+        drop(symbols);
+
+        // No subscribers is fine; consumers can still poll
+        let _ = self.sender.send(snapshot.clone());
+        Ok(Some(snapshot))
+    }
+
+    /// Feed a single price sample, treated as a bar with no range
+    pub fn record_price(&self, symbol: &str, price: f64, at: DateTime<Utc>) -> Result<Option<VolatilitySnapshot>, VolatilityError> {
+        self.record_bar(symbol, price, price, price, at)
+    }
+
+    /// Volatility of log returns over `horizon_secs`, once enough data is seen
+    pub fn volatility(&self, symbol: &str, estimator: VolatilityEstimator, horizon_secs: u64) -> Option<f64> {
+        let symbols = self.symbols.read().unwrap();
+        let state = symbols.get(symbol)?;
+        if state.observations < self.config.min_observations {
+            return None;
+        }
+        self.horizon_volatility(state, estimator, horizon_secs)
+    }
+
+    /// Volatility over a single bar
+    pub fn per_bar(&self, symbol: &str, estimator: VolatilityEstimator) -> Option<f64> {
+        self.volatility(symbol, estimator, self.config.bar_interval_secs)
+    }
+
+    /// Annualized volatility, e.g. for option pricing
+    pub fn annualized(&self, symbol: &str, estimator: VolatilityEstimator) -> Option<f64> {
+        self.volatility(symbol, estimator, YEAR_SECS)
+    }
+
+    /// Latest snapshot for a symbol
+    pub fn snapshot(&self, symbol: &str) -> Option<VolatilitySnapshot> {
+        let symbols = self.symbols.read().unwrap();
+        let state = symbols.get(symbol)?;
+        if state.observations < self.config.min_observations {
+            return None;
+        }
+        Some(self.snapshot_of(symbol, state))
+    }
+
+    /// Symbols with published estimates
+    pub fn symbols(&self) -> Vec<String> {
+        let symbols = self.symbols.read().unwrap();
+        let mut ready: Vec<String> = symbols
+            .iter()
+            .filter(|(_, state)| state.observations >= self.config.min_observations)
+            .map(|(symbol, _)| symbol.clone())
+            .collect();
+        ready.sort();
+        ready
+    }
+
+    /// Subscribe to snapshots as they are published
+    pub fn subscribe(&self) -> broadcast::Receiver<VolatilitySnapshot> {
+        self.sender.subscribe()
+    }
+
+    /// GARCH-lite parameters, kept stationary
+    fn garch_params(&self) -> (f64, f64) {
+        let alpha = self.config.garch_alpha.max(0.0);
+        let beta = self.config.garch_beta.max(0.0);
+        let persistence = alpha + beta;
+        if persistence < 0.999 {
+            (alpha, beta)
+        } else {
+            (alpha * 0.999 / persistence, beta * 0.999 / persistence)
+        }
+    }
+
+    fn horizon_volatility(&self, state: &SymbolVolatility, estimator: VolatilityEstimator, horizon_secs: u64) -> Option<f64> {
+        let bar_variance = state.bar_variance(estimator)?;
+        let bars = horizon_secs as f64 / self.config.bar_interval_secs.max(1) as f64;
+
+        let variance = match (estimator, state.sample_variance()) {
+            // Sum of k-step forecasts: sigma²_{t+i} = LR + p^i (sigma²_{t+1} - LR)
+            (VolatilityEstimator::GarchLite, Some(long_run)) => {
+                let (alpha, beta) = self.garch_params();
+                let persistence = alpha + beta;
+                bars * long_run + (bar_variance - long_run) * (1.0 - persistence.powf(bars)) / (1.0 - persistence)
+            }
+            _ => bar_variance * bars,
+        };
+        Some(variance.max(0.0).sqrt())
+    }
+
+    fn snapshot_of(&self, symbol: &str, state: &SymbolVolatility) -> VolatilitySnapshot {
+        let estimates = VolatilityEstimator::ALL
+            .iter()
+            .flat_map(|estimator| {
+                self.config.horizons_secs.iter().filter_map(move |horizon_secs| {
+                    self.horizon_volatility(state, *estimator, *horizon_secs).map(|volatility| VolatilityEstimate {
+                        estimator: *estimator,
+                        horizon_secs: *horizon_secs,
+                        volatility,
+                    })
+                })
+            })
+            .collect();
+
+        VolatilitySnapshot {
+            symbol: symbol.to_string(),
+            updated_at: state.updated_at.unwrap_or_else(Utc::now),
+            observations: state.observations,
+            estimates,
+        }
+    }
+}
+
+/// Create a volatility service
+pub fn create_volatility_service(config: VolatilityConfig) -> Arc<VolatilityService> {
+    Arc::new(VolatilityService::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_estimators_agree_on_constant_volatility() {
+        let service = VolatilityService::new(VolatilityConfig::default());
+        let start = Utc::now();
+
+        // Alternating +/-1% moves with a 2% high/low range on every bar
+        let mut close = 100.0;
+        for i in 0..200 {
+            close *= if i % 2 == 0 { 1.01 } else { 1.0 / 1.01 };
+            let at = start + Duration::minutes(i);
+            let snapshot = service.record_bar("BTC/USDT", close * 1.01, close / 1.01, close, at).unwrap();
+            assert_eq!(snapshot.is_some(), i >= 10);
+        }
+
+        let per_bar = 1.01f64.ln();
+        for estimator in [VolatilityEstimator::CloseToClose, VolatilityEstimator::Ewma, VolatilityEstimator::GarchLite] {
+            let vol = service.per_bar("BTC/USDT", estimator).unwrap();
+            assert!((vol - per_bar).abs() / per_bar < 0.05, "{:?} gave {}", estimator, vol);
+        }
+        let parkinson = service.per_bar("BTC/USDT", VolatilityEstimator::Parkinson).unwrap();
+        assert!((parkinson - 2.0 * per_bar / (4.0 * std::f64::consts::LN_2).sqrt()).abs() < 1e-9);
+
+        // Variance scales with the number of bars
+        let hourly = service.volatility("BTC/USDT", VolatilityEstimator::CloseToClose, 3_600).unwrap();
+        let per_bar = service.per_bar("BTC/USDT", VolatilityEstimator::CloseToClose).unwrap();
+        assert!((hourly - per_bar * 60f64.sqrt()).abs() < 1e-9);
+        assert!(service.snapshot("BTC/USDT").unwrap().get(VolatilityEstimator::Ewma, 86_400).is_some());
+
+        assert!(service.record_bar("BTC/USDT", 99.0, 101.0, 100.0, start).is_err());
+        assert!(service.per_bar("ETH/USDT", VolatilityEstimator::Ewma).is_none());
+    }
+}
@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Pure footprint / volume-profile kernels shared by the footprint pipeline
+//! and the WebAssembly build. Nothing in this module may depend on tokio,
+//! Redis or any other native-only crate.
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+
+/// Default share of volume contained in the value area
+pub const DEFAULT_VALUE_AREA_PCT: f64 = 0.7;
+
+/// A single trade used to build a volume profile
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ProfileTrade {
+    /// Trade price
+    pub price: f64,
+    /// Trade size
+    pub size: f64,
+    /// Whether the aggressor was a buyer
+    pub is_buy: bool,
+}
+
+/// Volume at a single price bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeProfileLevel {
+    /// Bucket price
+    pub price: f64,
+    /// Buy volume at this price
+    pub buy_volume: f64,
+    /// Sell volume at this price
+    pub sell_volume: f64,
+    /// Number of buy trades
+    pub buy_trades: usize,
+    /// Number of sell trades
+    pub sell_trades: usize,
+    /// Delta (buy - sell volume)
+    pub delta: f64,
+    /// Share of total profile volume
+    pub pct_of_total: f64,
+}
+
+impl VolumeProfileLevel {
+    /// Total volume at this price
+    pub fn total_volume(&self) -> f64 {
+        self.buy_volume + self.sell_volume
+    }
+}
+
+/// Volume profile over a set of trades
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VolumeProfile {
+    /// Price levels sorted by ascending price
+    pub levels: Vec<VolumeProfileLevel>,
+    /// Bucket size used to group prices
+    pub bucket_size: f64,
+    /// Total buy volume
+    pub total_buy_volume: f64,
+    /// Total sell volume
+    pub total_sell_volume: f64,
+    /// Volume delta (buy - sell)
+    pub delta: f64,
+    /// Delta percent (delta / total volume)
+    pub delta_pct: f64,
+    /// Volume weighted average price
+    pub vwap: f64,
+    /// Price with maximum volume (point of control)
+    pub poc_price: f64,
+    /// Upper bound of the value area
+    pub value_area_high: Option<f64>,
+    /// Lower bound of the value area
+    pub value_area_low: Option<f64>,
+}
+
+/// Bucket size chosen automatically from the price magnitude
+pub fn automatic_bucket_size(price: f64) -> f64 {
+    if price < 0.1 {
+        0.00001
+    } else if price < 1.0 {
+        0.0001
+    } else if price < 10.0 {
+        0.001
+    } else if price < 100.0 {
+        0.01
+    } else if price < 1000.0 {
+        0.1
+    } else {
+        1.0
+    }
+}
+
+/// Value area around the point of control
+///
+/// `levels` are `(price, volume)` pairs. Levels are added in order of distance
+/// from the POC until `value_area_pct` of the volume is included. Returns
+/// `(low, high)`.
+pub fn value_area(levels: &[(f64, f64)], poc_price: f64, value_area_pct: f64) -> Option<(f64, f64)> {
+    if levels.is_empty() {
+        return None;
+    }
+
+    let total_volume: f64 = levels.iter().map(|(_, volume)| volume).sum();
+    let target_volume = total_volume * value_area_pct;
+
+    // Start from POC and expand
+    let mut low = poc_price;
+    let mut high = poc_price;
+    let mut included_volume = levels.iter()
+        .find(|(price, _)| (price - poc_price).abs() < 0.00001)
+        .map(|(_, volume)| *volume)
+        .unwrap_or(0.0);
+
+    // Sort remaining levels by distance from POC
+    let mut remaining: Vec<&(f64, f64)> = levels.iter()
+        .filter(|(price, _)| (price - poc_price).abs() >= 0.00001)
+        .collect();
+
+    remaining.sort_by(|a, b| {
+        let a_dist = (a.0 - poc_price).abs();
+        let b_dist = (b.0 - poc_price).abs();
+        a_dist.partial_cmp(&b_dist).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Add levels to value area until we reach target volume
+    for (price, volume) in remaining {
+        if included_volume >= target_volume {
+            break;
+        }
+
+        low = low.min(*price);
+        high = high.max(*price);
+        included_volume += volume;
+    }
+
+    Some((low, high))
+}
+
+/// Build a volume profile from trades
+///
+/// When `bucket_size` is `None` it is chosen from the first trade's price.
+pub fn build_volume_profile(trades: &[ProfileTrade], bucket_size: Option<f64>, value_area_pct: f64) -> VolumeProfile {
+    let bucket_size = bucket_size
+        .filter(|size| *size > 0.0)
+        .or_else(|| trades.first().map(|t| automatic_bucket_size(t.price)))
+        .unwrap_or(1.0);
+
+    let mut profile = VolumeProfile {
+        bucket_size,
+        ..VolumeProfile::default()
+    };
+
+    // Group trades by bucket index so float prices never become map keys
+    let mut buckets: BTreeMap<i64, VolumeProfileLevel> = BTreeMap::new();
+
+    for trade in trades {
+        let index = (trade.price / bucket_size).round() as i64;
+        let level = buckets.entry(index).or_insert_with(|| VolumeProfileLevel {
+            price: index as f64 * bucket_size,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+            buy_trades: 0,
+            sell_trades: 0,
+            delta: 0.0,
+            pct_of_total: 0.0,
+        });
+
+        if trade.is_buy {
+            level.buy_volume += trade.size;
+            level.buy_trades += 1;
+            profile.total_buy_volume += trade.size;
+        } else {
+            level.sell_volume += trade.size;
+            level.sell_trades += 1;
+            profile.total_sell_volume += trade.size;
+        }
+        level.delta = level.buy_volume - level.sell_volume;
+    }
+
+    profile.levels = buckets.into_values().collect();
+
+    let total_volume = profile.total_buy_volume + profile.total_sell_volume;
+    profile.delta = profile.total_buy_volume - profile.total_sell_volume;
+
+    if total_volume > 0.0 {
+        profile.delta_pct = profile.delta / total_volume;
+        profile.vwap = profile.levels.iter()
+            .map(|l| l.price * l.total_volume())
+            .sum::<f64>() / total_volume;
+
+        for level in &mut profile.levels {
+            level.pct_of_total = level.total_volume() / total_volume;
+        }
+    }
+
+    if let Some(poc) = profile.levels.iter()
+        .max_by(|a, b| a.total_volume().partial_cmp(&b.total_volume()).unwrap_or(std::cmp::Ordering::Equal))
+    {
+        profile.poc_price = poc.price;
+    }
+
+    let volumes: Vec<(f64, f64)> = profile.levels.iter().map(|l| (l.price, l.total_volume())).collect();
+    if let Some((low, high)) = value_area(&volumes, profile.poc_price, value_area_pct) {
+        profile.value_area_low = Some(low);
+        profile.value_area_high = Some(high);
+    }
+
+    profile
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(price: f64, size: f64, is_buy: bool) -> ProfileTrade {
+        ProfileTrade { price, size, is_buy }
+    }
+
+    #[test]
+    fn test_volume_profile_poc_and_value_area() {
+        let trades = vec![
+            trade(100.0, 1.0, true),
+            trade(101.0, 4.0, true),
+            trade(101.0, 1.0, false),
+            trade(102.0, 1.0, false),
+            trade(105.0, 0.5, true),
+        ];
+
+        let profile = build_volume_profile(&trades, Some(1.0), DEFAULT_VALUE_AREA_PCT);
+
+        assert_eq!(profile.levels.len(), 4);
+        assert_eq!(profile.poc_price, 101.0);
+        assert!((profile.delta - 3.5).abs() < 1e-9);
+        assert_eq!(profile.value_area_low, Some(100.0));
+        assert_eq!(profile.value_area_high, Some(101.0));
+    }
+}
@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Per-symbol warm-up gating
+//!
+//! A symbol newly seen by the strategy layer has no feature history and no
+//! stable liquidity profile, so signals on it are unreliable. The gate keeps
+//! it ineligible until enough candles have been observed, the liquidity
+//! profiler holds enough recent snapshots and the regime detector has
+//! classified it. Once warm, a symbol stays eligible until it is reset, which
+//! the universe manager does whenever the symbol is listed or delisted.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::market::{MarketData, Symbol};
+use crate::market_regime::{MarketRegimeDetector, MarketRegimeState};
+use crate::microstructure::liquidity::LiquidityProfiler;
+
+/// Warm-up requirements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WarmupConfig {
+    /// Gate signals on warm-up; when false every symbol is eligible
+    pub enabled: bool,
+    /// Candle timeframe counted towards history (a `MarketData::candles` key)
+    pub candle_timeframe: String,
+    /// Candles required before a symbol is eligible
+    pub min_candles: usize,
+    /// Liquidity snapshots required; ignored without a liquidity profiler
+    pub min_liquidity_snapshots: usize,
+    /// Only snapshots taken within this window count (seconds)
+    pub liquidity_window_secs: u64,
+    /// Require a known regime classification; ignored without a regime detector
+    pub require_regime: bool,
+}
+
+impl Default for WarmupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            candle_timeframe: "1m".to_string(),
+            min_candles: 100,
+            min_liquidity_snapshots: 10,
+            liquidity_window_secs: 86_400,
+            require_regime: true,
+        }
+    }
+}
+
+/// Warm-up progress of one symbol
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupProgress {
+    /// Symbol warming up
+    pub symbol: Symbol,
+    /// Candles observed so far
+    pub candles: usize,
+    /// Candles required
+    pub required_candles: usize,
+    /// Recent liquidity snapshots held by the profiler
+    pub liquidity_snapshots: usize,
+    /// Liquidity snapshots required
+    pub required_liquidity_snapshots: usize,
+    /// Regime classification, once one other than unknown is available
+    pub regime: Option<MarketRegimeState>,
+    /// Whether a regime classification is required
+    pub regime_required: bool,
+    /// When the symbol was first observed
+    pub started_at: DateTime<Utc>,
+    /// When every requirement was first met
+    pub ready_at: Option<DateTime<Utc>>,
+}
+
+impl WarmupProgress {
+    /// Whether the symbol is eligible for signals
+    pub fn is_ready(&self) -> bool {
+        self.ready_at.is_some()
+    }
+
+    /// Share of the warm-up completed (0.0-1.0); each requirement weighs equally
+    pub fn completion(&self) -> f64 {
+        let ratio = |have: usize, need: usize| if need == 0 { 1.0 } else { (have as f64 / need as f64).min(1.0) };
+        let mut parts = vec![
+            ratio(self.candles, self.required_candles),
+            ratio(self.liquidity_snapshots, self.required_liquidity_snapshots),
+        ];
+        if self.regime_required {
+            parts.push(if self.regime.is_some() { 1.0 } else { 0.0 });
+        }
+        parts.iter().sum::<f64>() / parts.len() as f64
+    }
+
+    fn requirements_met(&self) -> bool {
+        self.candles >= self.required_candles
+            && self.liquidity_snapshots >= self.required_liquidity_snapshots
+            && (!self.regime_required || self.regime.is_some())
+    }
+}
+
+/// Warm-up state tracked per symbol
+struct SymbolWarmup {
+    progress: WarmupProgress,
+    /// Open time of the newest candle counted
+    last_candle: Option<DateTime<Utc>>,
+}
+
+/// Keeps symbols ineligible for signals until their history has stabilized
+pub struct WarmupGate {
+    /// Requirements
+    config: WarmupConfig,
+    /// Warm-up state by symbol
+    symbols: RwLock<HashMap<Symbol, SymbolWarmup>>,
+    /// Source of liquidity snapshots (optional)
+    liquidity: Option<Arc<dyn LiquidityProfiler>>,
+    /// Source of regime classifications (optional)
+    regimes: Option<Arc<dyn MarketRegimeDetector>>,
+}
+
+impl WarmupGate {
+    /// Create a gate with no symbols warmed up
+    pub fn new(config: WarmupConfig) -> Self {
+        Self {
+            config,
+            symbols: RwLock::new(HashMap::new()),
+            liquidity: None,
+            regimes: None,
+        }
+    }
+
+    /// Require liquidity snapshots from the profiler
+    pub fn with_liquidity_profiler(mut self, liquidity: Arc<dyn LiquidityProfiler>) -> Self {
+        self.liquidity = Some(liquidity);
+        self
+    }
+
+    /// Require a regime classification from the detector
+    pub fn with_regime_detector(mut self, regimes: Arc<dyn MarketRegimeDetector>) -> Self {
+        self.regimes = Some(regimes);
+        self
+    }
+
+    /// Requirements
+    pub fn config(&self) -> &WarmupConfig {
+        &self.config
+    }
+
+    /// Record a market data update and return whether its symbol is eligible
+    pub async fn observe(&self, market_data: &MarketData) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+        let symbol = &market_data.symbol;
+        if self.is_ready(symbol) {
+            return true;
+        }
+
+        let now = Utc::now();
+        let required_liquidity_snapshots = if self.liquidity.is_some() { self.config.min_liquidity_snapshots } else { 0 };
+        let regime_required = self.config.require_regime && self.regimes.is_some();
+
+        let mut liquidity_snapshots = 0;
+        if let (Some(liquidity), true) = (&self.liquidity, required_liquidity_snapshots > 0) {
+            let from = now - Duration::seconds(self.config.liquidity_window_secs as i64);
+            match liquidity.get_historical_snapshots(symbol, from, now, Some(required_liquidity_snapshots)).await {
+                Ok(snapshots) => liquidity_snapshots = snapshots.len(),
+                Err(e) => debug!("No liquidity history for {} yet: {}", symbol, e),
+            }
+        }
+        let regime = match (&self.regimes, regime_required) {
+            (Some(regimes), true) => regimes.get_current_regime(symbol)
+                .await
+                .map(|regime| regime.state)
+                .filter(|state| *state != MarketRegimeState::Unknown),
+            _ => None,
+        };
+
+        let candles = market_data.candles
+            .get(&self.config.candle_timeframe)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let mut symbols = self.symbols.write().unwrap();
+        let warmup = symbols.entry(symbol.clone()).or_insert_with(|| SymbolWarmup {
+            progress: WarmupProgress {
+                symbol: symbol.clone(),
+                candles: 0,
+                required_candles: self.config.min_candles,
+                liquidity_snapshots: 0,
+                required_liquidity_snapshots,
+                regime: None,
+                regime_required,
+                started_at: now,
+                ready_at: None,
+            },
+            last_candle: None,
+        });
+
+        // Candle history is resent on every update; count each candle once
+        let last_candle = warmup.last_candle;
+        warmup.progress.candles += candles.iter()
+            .filter(|candle| !last_candle.is_some_and(|last| candle.timestamp <= last))
+            .count();
+        warmup.last_candle = candles.iter().map(|candle| candle.timestamp).chain(last_candle).max();
+        warmup.progress.liquidity_snapshots = liquidity_snapshots;
+        warmup.progress.regime = regime;
+
+        if warmup.progress.ready_at.is_none() && warmup.progress.requirements_met() {
+            warmup.progress.ready_at = Some(now);
+            info!(
+                "{} warmed up after {} candles and {} liquidity snapshots",
+                symbol, warmup.progress.candles, warmup.progress.liquidity_snapshots
+            );
+        }
+        warmup.progress.is_ready()
+    }
+
+    /// Whether a symbol is eligible for signals; symbols never observed are not
+    pub fn is_ready(&self, symbol: &str) -> bool {
+        !self.config.enabled
+            || self.symbols.read().unwrap().get(symbol).is_some_and(|warmup| warmup.progress.is_ready())
+    }
+
+    /// Warm-up progress of a symbol
+    pub fn progress(&self, symbol: &str) -> Option<WarmupProgress> {
+        self.symbols.read().unwrap().get(symbol).map(|warmup| warmup.progress.clone())
+    }
+
+    /// Warm-up progress of every observed symbol, sorted by symbol
+    pub fn all_progress(&self) -> Vec<WarmupProgress> {
+        let mut progress: Vec<_> = self.symbols.read().unwrap().values().map(|warmup| warmup.progress.clone()).collect();
+        progress.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        progress
+    }
+
+    /// Forget a symbol so it warms up from scratch
+    pub fn reset(&self, symbol: &str) {
+        if self.symbols.write().unwrap().remove(symbol).is_some() {
+            debug!("Reset warm-up for {}", symbol);
+        }
+    }
+}
+
+/// Create a warm-up gate
+pub fn create_warmup_gate(config: WarmupConfig) -> Arc<WarmupGate> {
+    Arc::new(WarmupGate::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::market::{Candle, Ticker};
+    use crate::microstructure::liquidity::{LiquiditySnapshot, MockLiquidityProfiler};
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+
+    fn market_data(symbol: &str, candle_times: &[i64]) -> MarketData {
+        let ticker = Ticker {
+            bid: 100.0,
+            ask: 100.1,
+            last: 100.0,
+            volume: 1000.0,
+            change_24h: 0.0,
+            high_24h: 101.0,
+            low_24h: 99.0,
+            quote_volume: 100_000.0,
+        };
+        let mut data = MarketData::new("binance".to_string(), symbol.to_string(), ticker);
+        let candles = candle_times
+            .iter()
+            .map(|minute| {
+                let price = Decimal::new(100, 0);
+                let timestamp = Utc.timestamp_opt(minute * 60, 0).unwrap();
+                Candle::new(timestamp, price, price, price, price, Decimal::ONE)
+            })
+            .collect();
+        data.candles.insert("1m".to_string(), candles);
+        data
+    }
+
+    #[tokio::test]
+    async fn test_symbol_warms_up_once_requirements_are_met() {
+        let profiler = Arc::new(MockLiquidityProfiler::new());
+        let gate = WarmupGate::new(WarmupConfig {
+            min_candles: 3,
+            min_liquidity_snapshots: 1,
+            require_regime: false,
+            ..Default::default()
+        })
+        .with_liquidity_profiler(profiler.clone());
+
+        // Overlapping candle history is only counted once
+        assert!(!gate.observe(&market_data("BTC/USDT", &[1, 2])).await);
+        assert!(!gate.observe(&market_data("BTC/USDT", &[1, 2, 3])).await);
+        let progress = gate.progress("BTC/USDT").unwrap();
+        assert_eq!(progress.candles, 3);
+        assert_eq!(progress.liquidity_snapshots, 0);
+        assert!(!gate.is_ready("BTC/USDT"));
+
+        profiler.set_snapshot("BTC/USDT".to_string(), LiquiditySnapshot::new("BTC/USDT".to_string(), 0.01, 0.001, 10_000.0));
+        assert!(gate.observe(&market_data("BTC/USDT", &[2, 3])).await);
+        assert!(gate.is_ready("BTC/USDT"));
+        assert_eq!(gate.progress("BTC/USDT").unwrap().completion(), 1.0);
+
+        // Never observed, and reset, symbols are ineligible
+        assert!(!gate.is_ready("ETH/USDT"));
+        gate.reset("BTC/USDT");
+        assert!(!gate.is_ready("BTC/USDT"));
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! WebAssembly bindings so the web dashboard can run what-if simulations
+//! client-side. Build with:
+//!
+//! ```text
+//! wasm-pack build --target web -- --no-default-features --features wasm
+//! ```
+//!
+//! Only the dependency-free kernels are exported; nothing here touches tokio
+//! or Redis.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::analytics_math;
+use crate::simulation::trust_decay_simulator::{
+    apply_recovery_events, simulate_trust_score_decay, DecaySimulationParams,
+};
+use crate::volume_profile::{self, ProfileTrade};
+
+/// Performance metrics for an equity curve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EquityCurveMetrics {
+    /// Sharpe ratio of period returns
+    pub sharpe_ratio: Option<f64>,
+    /// Sortino ratio of period returns
+    pub sortino_ratio: Option<f64>,
+    /// Maximum drawdown (%)
+    pub max_drawdown: f64,
+    /// Current drawdown (%)
+    pub current_drawdown: f64,
+    /// Percent change from first to last value
+    pub percent_change: Option<f64>,
+    /// Trend sign: 1 up, -1 down, 0 neutral
+    pub trend: i8,
+}
+
+/// A recovery event applied to a decay simulation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryEvent {
+    /// Day index the boost is applied on
+    pub day: usize,
+    /// Trust boost amount
+    pub boost: f64,
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(value).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+fn from_js<T: for<'de> Deserialize<'de>>(value: JsValue) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Compute Sharpe/Sortino, drawdown and trend for an equity curve
+#[wasm_bindgen(js_name = equityCurveMetrics)]
+pub fn equity_curve_metrics(values: &[f64], risk_free_rate: f64) -> Result<JsValue, JsValue> {
+    let returns = analytics_math::period_returns(values);
+    let xs: Vec<f64> = (0..values.len()).map(|i| i as f64).collect();
+    let (max_drawdown, current_drawdown) = analytics_math::drawdown_metrics(values);
+
+    let percent_change = match (values.first(), values.last()) {
+        (Some(first), Some(last)) if values.len() > 1 => analytics_math::percent_change(*first, *last),
+        _ => None,
+    };
+
+    to_js(&EquityCurveMetrics {
+        sharpe_ratio: analytics_math::sharpe_ratio(&returns, risk_free_rate),
+        sortino_ratio: analytics_math::sortino_ratio(&returns, risk_free_rate),
+        max_drawdown,
+        current_drawdown,
+        percent_change,
+        trend: analytics_math::trend_sign(analytics_math::linear_regression_slope(&xs, values)),
+    })
+}
+
+/// Sharpe ratio of a return series
+#[wasm_bindgen(js_name = sharpeRatio)]
+pub fn sharpe_ratio(returns: &[f64], risk_free_rate: f64) -> Option<f64> {
+    analytics_math::sharpe_ratio(returns, risk_free_rate)
+}
+
+/// Sortino ratio of a return series
+#[wasm_bindgen(js_name = sortinoRatio)]
+pub fn sortino_ratio(returns: &[f64], risk_free_rate: f64) -> Option<f64> {
+    analytics_math::sortino_ratio(returns, risk_free_rate)
+}
+
+/// Run a trust score decay simulation, optionally applying recovery events
+///
+/// `params` is a `DecaySimulationParams` object and `recovery_events` an
+/// optional array of `{ day, boost }`.
+#[wasm_bindgen(js_name = simulateTrustDecay)]
+pub fn simulate_trust_decay(params: JsValue, recovery_events: JsValue) -> Result<JsValue, JsValue> {
+    let params: DecaySimulationParams = from_js(params)?;
+    let mut scores = simulate_trust_score_decay(params);
+
+    if !recovery_events.is_undefined() && !recovery_events.is_null() {
+        let events: Vec<RecoveryEvent> = from_js(recovery_events)?;
+        scores = apply_recovery_events(
+            scores,
+            events.into_iter().map(|e| (e.day, e.boost)).collect(),
+        );
+    }
+
+    to_js(&scores)
+}
+
+/// Build a footprint / volume profile from an array of `{ price, size, is_buy }` trades
+///
+/// A `bucket_size` of 0 selects the bucket size automatically.
+#[wasm_bindgen(js_name = volumeProfile)]
+pub fn build_profile(trades: JsValue, bucket_size: f64, value_area_pct: Option<f64>) -> Result<JsValue, JsValue> {
+    let trades: Vec<ProfileTrade> = from_js(trades)?;
+    let profile = volume_profile::build_volume_profile(
+        &trades,
+        Some(bucket_size),
+        value_area_pct.unwrap_or(volume_profile::DEFAULT_VALUE_AREA_PCT),
+    );
+
+    to_js(&profile)
+}
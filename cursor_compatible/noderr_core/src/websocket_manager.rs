@@ -25,8 +25,11 @@ use thiserror::Error;
 use tracing::{debug, error, info, warn};
 use redis::{Client, RedisResult, AsyncCommands, aio::ConnectionManager};
 
+use crate::book_feed::{BookFeed, BookFeedConfig, BookMessage};
+use crate::orderbook::PriceLevel;
 use crate::telemetry::TelemetryPermissions;
 use crate::telemetry_streamer::{TelemetryMessage, TelemetryMessageType, RedisChannels, TelemetryStreamError};
+use crate::wire_format::{EncodedFrame, WireFormat};
 
 /// Errors for WebSocket operations
 #[derive(Debug, Error)]
@@ -65,6 +68,10 @@ pub struct SubscriptionRequest {
     /// Message types to subscribe to
     pub message_types: Vec<String>,
     
+    /// Symbols whose order books to stream
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    
     /// Authentication token
     pub auth_token: Option<String>,
 }
@@ -97,6 +104,15 @@ pub struct ClientSubscription {
     /// Subscribed message types
     pub message_types: HashSet<TelemetryMessageType>,
     
+    /// Symbols whose order books are streamed
+    pub book_symbols: HashSet<String>,
+    
+    /// Last book sequence sent per symbol; a missing entry means the next message is a snapshot
+    pub book_sequences: HashMap<String, u64>,
+    
+    /// Encoding of messages sent to the client
+    pub encoding: WireFormat,
+    
     /// Last activity timestamp
     pub last_activity: Instant,
     
@@ -128,6 +144,18 @@ pub enum ClientMessageType {
     /// Authentication
     #[serde(rename = "auth")]
     Auth,
+    
+    /// Choose the encoding of server messages
+    #[serde(rename = "negotiate")]
+    Negotiate,
+}
+
+/// Encoding negotiation request; formats are listed in order of preference
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NegotiationRequest {
+    /// Accepted format names, e.g. `["msgpack", "json"]`
+    #[serde(default)]
+    pub formats: Vec<String>,
 }
 
 /// WebSocket message to be sent to clients
@@ -146,6 +174,9 @@ pub struct WebSocketMessage {
     pub payload: serde_json::Value,
 }
 
+/// Message type of order book snapshots and deltas
+pub const BOOK_MESSAGE_TYPE: &str = "orderbook";
+
 /// Manages WebSocket connections and message broadcasting
 pub struct WebSocketManager {
     /// Client subscriptions by client ID
@@ -171,11 +202,19 @@ pub struct WebSocketManager {
     
     /// Channel for unregistering clients
     client_unregister_tx: mpsc::Sender<String>,
+    
+    /// Delta encoder for order book fan-out
+    book_feed: Arc<BookFeed>,
 }
 
 impl WebSocketManager {
     /// Create a new WebSocket manager
     pub fn new(redis_url: String, key_prefix: String) -> Self {
+        Self::with_book_feed_config(redis_url, key_prefix, BookFeedConfig::default())
+    }
+    
+    /// Create a new WebSocket manager with a custom order book feed configuration
+    pub fn with_book_feed_config(redis_url: String, key_prefix: String, book_config: BookFeedConfig) -> Self {
         let (broadcast_tx, _) = broadcast::channel(1000); // Buffer for 1000 messages
         let (client_register_tx, client_register_rx) = mpsc::channel(100);
         let (client_unregister_tx, client_unregister_rx) = mpsc::channel(100);
@@ -189,6 +228,7 @@ impl WebSocketManager {
             pubsub_handles: Arc::new(RwLock::new(Vec::new())),
             client_register_tx,
             client_unregister_tx,
+            book_feed: Arc::new(BookFeed::new(book_config)),
         };
         
         // Spawn the client manager task
@@ -234,6 +274,9 @@ impl WebSocketManager {
             client_id: client_id.clone(),
             strategy_ids: HashSet::new(),
             message_types: HashSet::new(),
+            book_symbols: HashSet::new(),
+            book_sequences: HashMap::new(),
+            encoding: WireFormat::Json,
             last_activity: Instant::now(),
             permissions,
         };
@@ -323,6 +366,75 @@ impl WebSocketManager {
         Ok(())
     }
     
+    /// Update a client's order book subscriptions
+    ///
+    /// Subscribing to a symbol again discards the client's sequence, so the
+    /// next book message it receives is a full snapshot.
+    pub async fn update_book_subscription(
+        &self,
+        client_id: &str,
+        action: SubscriptionAction,
+        symbols: Vec<String>,
+    ) -> Result<(), WebSocketError> {
+        let mut clients = self.clients.write().await;
+        
+        let subscription = clients.get_mut(client_id)
+            .ok_or_else(|| WebSocketError::SubscriptionError(format!("Client not found: {}", client_id)))?;
+        
+        subscription.last_activity = Instant::now();
+        
+        match action {
+            SubscriptionAction::Subscribe => {
+                for symbol in symbols {
+                    subscription.book_sequences.remove(&symbol);
+                    subscription.book_symbols.insert(symbol);
+                }
+            },
+            SubscriptionAction::Unsubscribe => {
+                for symbol in symbols {
+                    subscription.book_sequences.remove(&symbol);
+                    subscription.book_symbols.remove(&symbol);
+                }
+            },
+            SubscriptionAction::List => {},
+        }
+        
+        Ok(())
+    }
+    
+    /// Publish the current order book of a symbol to subscribed clients
+    ///
+    /// Only changed levels are broadcast; clients that are out of sequence
+    /// receive a snapshot instead. Returns the encoded message, or `None`
+    /// if the top of book did not change.
+    pub fn publish_order_book(
+        &self,
+        symbol: &str,
+        bids: &[PriceLevel],
+        asks: &[PriceLevel],
+    ) -> Result<Option<BookMessage>, WebSocketError> {
+        let Some(book_message) = self.book_feed.update(symbol, bids, asks, chrono::Utc::now()) else {
+            return Ok(None);
+        };
+        
+        let message = WebSocketMessage {
+            message_type: BOOK_MESSAGE_TYPE.to_string(),
+            source: symbol.to_string(),
+            timestamp: chrono::Utc::now(),
+            payload: serde_json::to_value(&book_message)
+                .map_err(|e| WebSocketError::SerializationError(e.to_string()))?,
+        };
+        
+        // No receivers just means no client is connected yet
+        let _ = self.broadcast_tx.send(message);
+        Ok(Some(book_message))
+    }
+    
+    /// Order book feed used for fan-out
+    pub fn book_feed(&self) -> Arc<BookFeed> {
+        self.book_feed.clone()
+    }
+    
     /// Get current subscriptions for a client
     pub async fn get_client_subscriptions(&self, client_id: &str) -> Result<ClientSubscription, WebSocketError> {
         let clients = self.clients.read().await;
@@ -331,7 +443,31 @@ impl WebSocketManager {
             .ok_or_else(|| WebSocketError::SubscriptionError(format!("Client not found: {}", client_id)))
     }
     
-    /// Process a client message
+    /// Select the encoding of messages sent to a client
+    ///
+    /// Picks the first offered format the server supports and falls back to
+    /// JSON, so old clients that never negotiate are unaffected.
+    pub async fn negotiate_encoding(&self, client_id: &str, formats: &[String]) -> Result<WireFormat, WebSocketError> {
+        let mut clients = self.clients.write().await;
+        let subscription = clients.get_mut(client_id)
+            .ok_or_else(|| WebSocketError::SubscriptionError(format!("Client not found: {}", client_id)))?;
+        
+        subscription.encoding = WireFormat::negotiate(formats);
+        subscription.last_activity = Instant::now();
+        debug!("Client {} negotiated {} encoding", client_id, subscription.encoding.name());
+        Ok(subscription.encoding)
+    }
+    
+    /// Encode a message in the client's negotiated format
+    pub async fn encode_for_client(&self, client_id: &str, message: &WebSocketMessage) -> Result<EncodedFrame, WebSocketError> {
+        let encoding = self.clients.read().await
+            .get(client_id)
+            .map(|subscription| subscription.encoding)
+            .unwrap_or_default();
+        encoding.frame(message).map_err(|e| WebSocketError::SerializationError(e.to_string()))
+    }
+    
+    /// Process a text (JSON) client message
     pub async fn process_client_message(
         &self,
         client_id: &str,
@@ -341,6 +477,26 @@ impl WebSocketManager {
         let client_message: ClientMessage = serde_json::from_str(message)
             .map_err(|e| WebSocketError::SerializationError(format!("Invalid message format: {}", e)))?;
         
+        self.handle_client_message(client_id, client_message).await
+    }
+    
+    /// Process a binary (MessagePack) client message
+    pub async fn process_client_binary(
+        &self,
+        client_id: &str,
+        message: &[u8],
+    ) -> Result<Option<WebSocketMessage>, WebSocketError> {
+        let client_message: ClientMessage = WireFormat::MessagePack.decode(message)
+            .map_err(|e| WebSocketError::SerializationError(format!("Invalid message format: {}", e)))?;
+        
+        self.handle_client_message(client_id, client_message).await
+    }
+    
+    async fn handle_client_message(
+        &self,
+        client_id: &str,
+        client_message: ClientMessage,
+    ) -> Result<Option<WebSocketMessage>, WebSocketError> {
         match client_message.message_type {
             ClientMessageType::Subscription => {
                 // Handle subscription message
@@ -354,6 +510,11 @@ impl WebSocketManager {
                     subscription_request.strategy_ids,
                     subscription_request.message_types,
                 ).await?;
+                self.update_book_subscription(
+                    client_id,
+                    subscription_request.action.clone(),
+                    subscription_request.symbols,
+                ).await?;
                 
                 // If this is a list request, return the current subscriptions
                 if subscription_request.action == SubscriptionAction::List {
@@ -361,6 +522,7 @@ impl WebSocketManager {
                     
                     // Convert to a message
                     let strategy_ids: Vec<String> = subscription.strategy_ids.iter().cloned().collect();
+                    let symbols: Vec<String> = subscription.book_symbols.iter().cloned().collect();
                     let message_types: Vec<String> = subscription.message_types.iter()
                         .map(|msg_type| match msg_type {
                             TelemetryMessageType::Trendline => "trendline",
@@ -379,6 +541,7 @@ impl WebSocketManager {
                         payload: serde_json::json!({
                             "strategy_ids": strategy_ids,
                             "message_types": message_types,
+                            "symbols": symbols,
                         }),
                     };
                     
@@ -421,6 +584,24 @@ impl WebSocketManager {
                     }),
                 };
                 
+                Ok(Some(response))
+            },
+            ClientMessageType::Negotiate => {
+                let request: NegotiationRequest = serde_json::from_value(client_message.payload)
+                    .map_err(|e| WebSocketError::SerializationError(format!("Invalid negotiation request: {}", e)))?;
+                let encoding = self.negotiate_encoding(client_id, &request.formats).await?;
+                
+                // Sent in the new encoding, so the client can switch decoders on receipt
+                let response = WebSocketMessage {
+                    message_type: "encoding_selected".to_string(),
+                    source: "system".to_string(),
+                    timestamp: chrono::Utc::now(),
+                    payload: serde_json::json!({
+                        "encoding": encoding.name(),
+                        "supported": WireFormat::SUPPORTED.iter().map(|format| format.name()).collect::<Vec<_>>(),
+                    }),
+                };
+                
                 Ok(Some(response))
             },
         }
@@ -476,7 +657,7 @@ impl WebSocketManager {
                     }
                 };
                 
-                let payload: String = match msg.get_payload() {
+                let payload: Vec<u8> = match msg.get_payload() {
                     Ok(payload) => payload,
                     Err(e) => {
                         error!("Failed to get message payload: {}", e);
@@ -484,8 +665,8 @@ impl WebSocketManager {
                     }
                 };
                 
-                // Deserialize the message
-                let telemetry_message: serde_json::Value = match serde_json::from_str(&payload) {
+                // Deserialize the message; publishers may use JSON or MessagePack
+                let telemetry_message: serde_json::Value = match WireFormat::decode_tagged(&payload) {
                     Ok(message) => message,
                     Err(e) => {
                         error!("Failed to deserialize message: {}", e);
@@ -535,6 +716,7 @@ impl WebSocketManager {
     ) {
         let broadcast_tx = self.broadcast_tx.clone();
         let clients = self.clients.clone();
+        let book_feed = self.book_feed.clone();
         
         tokio::spawn(async move {
             // Map of client_id to message sender
@@ -559,12 +741,30 @@ impl WebSocketManager {
                         let client_id_clone = client_id.clone();
                         let client_tx_clone = client_tx.clone();
                         let clients_clone = clients.clone();
+                        let book_feed_clone = book_feed.clone();
                         
                         // Spawn a task to forward messages to this client
                         let handle = tokio::spawn(async move {
                             let mut stream = BroadcastStream::new(broadcast_rx);
                             
                             while let Some(Ok(message)) = stream.next().await {
+                                // Book messages depend on what this client has already seen
+                                if message.message_type == BOOK_MESSAGE_TYPE {
+                                    let outgoing = {
+                                        let mut clients_guard = clients_clone.write().await;
+                                        clients_guard.get_mut(&client_id_clone)
+                                            .and_then(|subscription| book_message_for_client(&book_feed_clone, subscription, &message))
+                                    };
+                                    
+                                    if let Some(outgoing) = outgoing {
+                                        if let Err(e) = client_tx_clone.send(outgoing).await {
+                                            error!("Failed to send message to client {}: {}", client_id_clone, e);
+                                            break;
+                                        }
+                                    }
+                                    continue;
+                                }
+                                
                                 // Check if client is still subscribed to this message type and strategy
                                 let send_message = {
                                     let clients_guard = clients_clone.read().await;
@@ -632,6 +832,38 @@ impl WebSocketManager {
     }
 }
 
+/// Translate a broadcast book message into what a client should receive
+///
+/// Advances the client's sequence for the symbol when something is sent.
+fn book_message_for_client(
+    feed: &BookFeed,
+    subscription: &mut ClientSubscription,
+    message: &WebSocketMessage,
+) -> Option<WebSocketMessage> {
+    if !subscription.book_symbols.contains(&message.source) {
+        return None;
+    }
+    
+    let book_message: BookMessage = match serde_json::from_value(message.payload.clone()) {
+        Ok(book_message) => book_message,
+        Err(e) => {
+            warn!("Dropping malformed book message for {}: {}", message.source, e);
+            return None;
+        }
+    };
+    
+    let cursor = subscription.book_sequences.get(&message.source).copied();
+    let outgoing = feed.for_client(cursor, &book_message)?;
+    subscription.book_sequences.insert(message.source.clone(), outgoing.sequence());
+    
+    Some(WebSocketMessage {
+        message_type: BOOK_MESSAGE_TYPE.to_string(),
+        source: message.source.clone(),
+        timestamp: message.timestamp,
+        payload: serde_json::to_value(&outgoing).ok()?,
+    })
+}
+
 /// Create a new WebSocket manager
 pub fn create_websocket_manager(redis_url: String, key_prefix: String) -> Arc<WebSocketManager> {
     Arc::new(WebSocketManager::new(redis_url, key_prefix))
@@ -690,4 +922,83 @@ mod tests {
         assert!(subscription.message_types.contains(&TelemetryMessageType::Trendline));
         assert!(subscription.message_types.contains(&TelemetryMessageType::AnomalyAlert));
     }
-} 
\ No newline at end of file
+    
+    // Test that clients get a snapshot first and deltas after
+    #[tokio::test]
+    async fn test_book_fan_out_tracks_client_sequence() {
+        let manager = WebSocketManager::new(
+            "redis://127.0.0.1:6379".to_string(),
+            "test:websocket".to_string(),
+        );
+        
+        let (tx, _rx) = mpsc::channel(100);
+        let client_id = Uuid::new_v4().to_string();
+        manager.register_client(client_id.clone(), tx, create_test_permissions()).await.unwrap();
+        manager.update_book_subscription(&client_id, SubscriptionAction::Subscribe, vec!["ETH/USD".to_string()])
+            .await
+            .unwrap();
+        
+        let mut broadcasts = manager.subscribe_to_broadcasts();
+        let level = |price: f64, size: f64| PriceLevel { price, size, order_count: 1, timestamp: 0 };
+        manager.publish_order_book("ETH/USD", &[level(100.0, 1.0)], &[level(101.0, 1.0)]).unwrap();
+        manager.publish_order_book("ETH/USD", &[level(100.0, 2.0)], &[level(101.0, 1.0)]).unwrap();
+        let first = broadcasts.recv().await.unwrap();
+        let second = broadcasts.recv().await.unwrap();
+        
+        let mut subscription = manager.get_client_subscriptions(&client_id).await.unwrap();
+        
+        // Joining after the first update: the delta is replaced by a snapshot
+        let sent = book_message_for_client(&manager.book_feed(), &mut subscription, &second).unwrap();
+        assert_eq!(sent.payload["kind"], "snapshot");
+        assert_eq!(subscription.book_sequences["ETH/USD"], 2);
+        assert!(book_message_for_client(&manager.book_feed(), &mut subscription, &first).is_none());
+        
+        manager.publish_order_book("ETH/USD", &[level(100.0, 2.0)], &[level(101.5, 3.0)]).unwrap();
+        let third = broadcasts.recv().await.unwrap();
+        let sent = book_message_for_client(&manager.book_feed(), &mut subscription, &third).unwrap();
+        assert_eq!(sent.payload["kind"], "delta");
+        assert_eq!(sent.payload["asks"], serde_json::json!([[101.5, 3.0], [101.0, 0.0]]));
+        
+        subscription.book_symbols.clear();
+        assert!(book_message_for_client(&manager.book_feed(), &mut subscription, &third).is_none());
+    }
+    
+    // Test that negotiated clients get binary frames and others keep JSON
+    #[tokio::test]
+    async fn test_encoding_negotiation() {
+        let manager = WebSocketManager::new(
+            "redis://127.0.0.1:6379".to_string(),
+            "test:websocket".to_string(),
+        );
+        
+        let (tx, _rx) = mpsc::channel(100);
+        let client_id = Uuid::new_v4().to_string();
+        manager.register_client(client_id.clone(), tx, create_test_permissions()).await.unwrap();
+        
+        let message = WebSocketMessage {
+            message_type: "trendline".to_string(),
+            source: "test_strategy".to_string(),
+            timestamp: chrono::Utc::now(),
+            payload: serde_json::json!({"value": 1.5}),
+        };
+        assert!(matches!(manager.encode_for_client(&client_id, &message).await.unwrap(), EncodedFrame::Text(_)));
+        
+        let request = r#"{"message_type": "negotiate", "payload": {"formats": ["cbor", "msgpack", "json"]}}"#;
+        let response = manager.process_client_message(&client_id, request).await.unwrap().unwrap();
+        assert_eq!(response.payload["encoding"], "msgpack");
+        
+        match manager.encode_for_client(&client_id, &message).await.unwrap() {
+            EncodedFrame::Binary(bytes) => {
+                let decoded: WebSocketMessage = WireFormat::MessagePack.decode(&bytes).unwrap();
+                assert_eq!(decoded.payload, message.payload);
+            }
+            other => panic!("expected binary frame, got {:?}", other),
+        }
+        
+        // Binary client messages are understood once negotiated
+        let ping = ClientMessage { message_type: ClientMessageType::Ping, payload: serde_json::json!({}) };
+        let ping = WireFormat::MessagePack.encode(&ping).unwrap();
+        let pong = manager.process_client_binary(&client_id, &ping).await.unwrap().unwrap();
+        assert_eq!(pong.message_type, "pong");
+    }
+}
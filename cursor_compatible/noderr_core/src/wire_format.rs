@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+//
+// Copyright (c) 2025 Noderr Protocol Foundation
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+
+//! Payload encodings for WebSocket fan-out and Redis persistence
+//!
+//! JSON stays the default everywhere so existing clients and stored values
+//! keep working. MessagePack is offered as a cheaper binary alternative:
+//! WebSocket clients opt in by negotiation, and Redis values are written in
+//! whichever format is configured. Stored MessagePack values start with a tag
+//! byte that can never begin a JSON document, so readers decode both formats
+//! and a deployment can switch formats without migrating existing keys.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Leading byte of tagged MessagePack values; reserved in MessagePack and invalid in JSON
+pub const MSGPACK_TAG: u8 = 0xc1;
+
+/// Errors encoding or decoding payloads
+#[derive(Debug, Error)]
+pub enum WireFormatError {
+    #[error("Failed to encode {format} payload: {reason}")]
+    Encode { format: &'static str, reason: String },
+
+    #[error("Failed to decode {format} payload: {reason}")]
+    Decode { format: &'static str, reason: String },
+
+    #[error("Unsupported wire format: {0}")]
+    Unsupported(String),
+}
+
+/// Payload encoding
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WireFormat {
+    /// UTF-8 JSON text
+    #[default]
+    #[serde(rename = "json")]
+    Json,
+    /// MessagePack with named fields
+    #[serde(rename = "msgpack")]
+    MessagePack,
+}
+
+/// A payload ready to be written to a WebSocket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodedFrame {
+    /// Text frame
+    Text(String),
+    /// Binary frame
+    Binary(Vec<u8>),
+}
+
+impl WireFormat {
+    /// Formats the server can negotiate, in order of preference
+    pub const SUPPORTED: [WireFormat; 2] = [WireFormat::MessagePack, WireFormat::Json];
+
+    /// Name used in negotiation and configuration
+    pub fn name(&self) -> &'static str {
+        match self {
+            WireFormat::Json => "json",
+            WireFormat::MessagePack => "msgpack",
+        }
+    }
+
+    /// Parse a format name; `messagepack` is accepted as an alias
+    pub fn from_name(name: &str) -> Result<Self, WireFormatError> {
+        match name.to_ascii_lowercase().as_str() {
+            "json" => Ok(WireFormat::Json),
+            "msgpack" | "messagepack" => Ok(WireFormat::MessagePack),
+            _ => Err(WireFormatError::Unsupported(name.to_string())),
+        }
+    }
+
+    /// First format in the client's preference list that we support; JSON otherwise
+    pub fn negotiate<S: AsRef<str>>(offered: &[S]) -> Self {
+        offered.iter()
+            .find_map(|name| Self::from_name(name.as_ref()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Encode a value
+    pub fn encode<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, WireFormatError> {
+        let encoded = match self {
+            WireFormat::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            WireFormat::MessagePack => rmp_serde::to_vec_named(value).map_err(|e| e.to_string()),
+        };
+        encoded.map_err(|reason| WireFormatError::Encode { format: self.name(), reason })
+    }
+
+    /// Decode a value
+    pub fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, WireFormatError> {
+        let decoded = match self {
+            WireFormat::Json => serde_json::from_slice(bytes).map_err(|e| e.to_string()),
+            WireFormat::MessagePack => rmp_serde::from_slice(bytes).map_err(|e| e.to_string()),
+        };
+        decoded.map_err(|reason| WireFormatError::Decode { format: self.name(), reason })
+    }
+
+    /// Encode a value for storage; MessagePack values are prefixed with [`MSGPACK_TAG`]
+    pub fn encode_tagged<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, WireFormatError> {
+        match self {
+            WireFormat::Json => self.encode(value),
+            WireFormat::MessagePack => {
+                let mut tagged = vec![MSGPACK_TAG];
+                rmp_serde::encode::write_named(&mut tagged, value)
+                    .map_err(|e| WireFormatError::Encode { format: self.name(), reason: e.to_string() })?;
+                Ok(tagged)
+            }
+        }
+    }
+
+    /// Format of a stored value written by [`WireFormat::encode_tagged`]
+    pub fn detect(bytes: &[u8]) -> Self {
+        match bytes.first() {
+            Some(&MSGPACK_TAG) => WireFormat::MessagePack,
+            _ => WireFormat::Json,
+        }
+    }
+
+    /// Decode a stored value in whichever format it was written
+    pub fn decode_tagged<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, WireFormatError> {
+        match Self::detect(bytes) {
+            WireFormat::MessagePack => WireFormat::MessagePack.decode(&bytes[1..]),
+            WireFormat::Json => WireFormat::Json.decode(bytes),
+        }
+    }
+
+    /// Encode a value as a WebSocket frame: text for JSON, binary otherwise
+    pub fn frame<T: Serialize + ?Sized>(&self, value: &T) -> Result<EncodedFrame, WireFormatError> {
+        match self {
+            WireFormat::Json => serde_json::to_string(value)
+                .map(EncodedFrame::Text)
+                .map_err(|e| WireFormatError::Encode { format: self.name(), reason: e.to_string() }),
+            WireFormat::MessagePack => self.encode(value).map(EncodedFrame::Binary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Stored {
+        symbol: String,
+        prices: Vec<f64>,
+        #[serde(default)]
+        tags: HashMap<String, String>,
+        meta: serde_json::Value,
+    }
+
+    #[test]
+    fn test_tagged_values_round_trip_in_both_formats() {
+        let value = Stored {
+            symbol: "BTC/USD".to_string(),
+            prices: vec![50_000.5, 50_001.25],
+            tags: HashMap::from([("venue".to_string(), "binance".to_string())]),
+            meta: serde_json::json!({"kind": "delta", "levels": [[1.0, 2.0]]}),
+        };
+
+        let json = WireFormat::Json.encode_tagged(&value).unwrap();
+        let msgpack = WireFormat::MessagePack.encode_tagged(&value).unwrap();
+        assert_eq!(WireFormat::detect(&json), WireFormat::Json);
+        assert_eq!(WireFormat::detect(&msgpack), WireFormat::MessagePack);
+        assert!(msgpack.len() < json.len());
+        assert_eq!(WireFormat::decode_tagged::<Stored>(&json).unwrap(), value);
+        assert_eq!(WireFormat::decode_tagged::<Stored>(&msgpack).unwrap(), value);
+
+        // Values written before the format existed are plain JSON
+        let legacy = br#"{"symbol":"ETH/USD","prices":[],"meta":null}"#;
+        assert_eq!(WireFormat::decode_tagged::<Stored>(legacy).unwrap().symbol, "ETH/USD");
+
+        assert!(matches!(WireFormat::Json.frame(&value).unwrap(), EncodedFrame::Text(_)));
+        assert!(matches!(WireFormat::MessagePack.frame(&value).unwrap(), EncodedFrame::Binary(_)));
+
+        assert_eq!(WireFormat::negotiate(&["cbor", "MessagePack", "json"]), WireFormat::MessagePack);
+        assert_eq!(WireFormat::negotiate(&["cbor"]), WireFormat::Json);
+        assert!(WireFormat::from_name("cbor").is_err());
+    }
+}
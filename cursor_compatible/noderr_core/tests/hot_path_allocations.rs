@@ -0,0 +1,89 @@
+//! Allocation counts on the per-tick market data path
+//!
+//! A counting global allocator records allocations made by the current
+//! thread, so tests running in parallel do not disturb each other.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use chrono::{Duration, TimeZone, Utc};
+use noderr_core::market_data::{MarketDataProcessor, MarketDataProcessorConfig, MarketTick};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Allocations made on this thread while running `f`
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, u64) {
+    let before = ALLOCATIONS.with(|count| count.get());
+    let result = f();
+    (result, ALLOCATIONS.with(|count| count.get()) - before)
+}
+
+fn ticks(count: usize) -> Vec<MarketTick> {
+    let start = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+    (0..count)
+        .map(|i| MarketTick {
+            symbol: "BTC/USD".to_string(),
+            timestamp: start + Duration::seconds(i as i64),
+            price: 100.0 + (i as f64 * 0.1).sin(),
+            volume: 1.0 + (i % 7) as f64,
+            bid: Some(99.9),
+            ask: Some(100.1),
+            fields: HashMap::new(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_market_data_hot_path_allocations() {
+    let config = MarketDataProcessorConfig { max_history_size: 500, ..MarketDataProcessorConfig::default() };
+    let processor = MarketDataProcessor::new(config);
+
+    // Warm up: fill history past its cap and populate the buffer pool and feature cache
+    for tick in ticks(600) {
+        processor.process_tick(tick).unwrap();
+    }
+    processor.calculate_features("BTC/USD").unwrap();
+
+    // Ticks are built up front so only the processor's own allocations are counted
+    let batch = ticks(200);
+    let (_, allocations) = count_allocations(|| {
+        for tick in batch {
+            processor.process_tick(tick).unwrap();
+        }
+    });
+    // Dropping each trimmed tick frees memory but must not allocate
+    assert_eq!(allocations, 0, "process_tick allocated in steady state");
+
+    let (features, allocations) = count_allocations(|| processor.calculate_features("BTC/USD").unwrap());
+    assert_eq!(features.symbol, "BTC/USD");
+    // One symbol string for the returned features and one for the cached copy
+    assert!(allocations <= 2, "calculate_features made {} allocations", allocations);
+
+    let stats = processor.buffer_pool_stats();
+    assert_eq!(stats.created, 1);
+    assert_eq!(stats.reused, 1);
+}